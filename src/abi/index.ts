@@ -0,0 +1,7 @@
+/**
+ * Klever contract ABIs: the typed model of ABI JSON and loaders for the
+ * places ABIs come from.
+ */
+
+export * from './model.js';
+export * from './loaders.js';
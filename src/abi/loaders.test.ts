@@ -0,0 +1,57 @@
+import { mkdir, mkdtemp, rm, writeFile } from 'node:fs/promises';
+import { tmpdir } from 'node:os';
+import { join } from 'node:path';
+import type { KleverChainClient } from '../chain/index.js';
+import { codeHash } from '../toolchain/verify.js';
+import { loadAbiFile, loadBuildAbi, loadVerifiedAbi } from './loaders.js';
+
+const ABI = {
+  buildInfo: { framework: { name: 'klever-sc', version: '0.45.0' } },
+  name: 'Adder',
+  constructor: { inputs: [{ name: 'initial_value', type: 'BigUint' }] },
+  endpoints: [{ name: 'getSum', mutability: 'readonly', outputs: [{ type: 'BigUint' }] }],
+  types: {},
+};
+const WASM = Buffer.from([0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00]);
+
+const clientWithCodeHash = (hash: string) =>
+  ({ getCodeHash: async () => hash }) as unknown as KleverChainClient;
+
+describe('ABI loaders', () => {
+  let project: string;
+
+  beforeEach(async () => {
+    project = await mkdtemp(join(tmpdir(), 'klever-abi-'));
+    await mkdir(join(project, 'output'));
+    await writeFile(join(project, 'output', 'adder.abi.json'), JSON.stringify(ABI));
+    await writeFile(join(project, 'output', 'adder.wasm'), WASM);
+  });
+
+  afterEach(async () => {
+    await rm(project, { recursive: true, force: true });
+  });
+
+  it('parses ABI files into the typed model', async () => {
+    const abi = await loadAbiFile(join(project, 'output', 'adder.abi.json'));
+    expect(abi.buildInfo?.framework?.version).toBe('0.45.0');
+    expect(abi.constructor?.outputs).toEqual([]);
+    expect(abi.endpoints[0].inputs).toEqual([]);
+    await expect(loadAbiFile(join(project, 'missing.abi.json'))).rejects.toThrow('not found');
+  });
+
+  it('finds the ABI of the build output and its wasm', async () => {
+    const build = await loadBuildAbi(project);
+    expect(build.abi.name).toBe('Adder');
+    expect(build.wasmPath).toBe(join(project, 'output', 'adder.wasm'));
+    await expect(loadBuildAbi(project, 'vault')).rejects.toThrow('No vault.abi.json');
+  });
+
+  it('loads the build ABI only when its wasm is the deployed code', async () => {
+    const address = 'klv1qqqqqqqqqqqqqpgqxwakt2g7u9atsnr03gqcgmhcv38pt7mkd94q6shuwt';
+    const verified = await loadVerifiedAbi(clientWithCodeHash(codeHash(WASM)), address, project);
+    expect(verified.codeHash).toBe(codeHash(WASM));
+    await expect(
+      loadVerifiedAbi(clientWithCodeHash('00'.repeat(32)), address, project)
+    ).rejects.toThrow('is not the code deployed');
+  });
+});
@@ -0,0 +1,95 @@
+/**
+ * Loaders that turn ABI sources into the typed model: a file on disk, the
+ * output/ directory of a contract build, and a build verified to be the code
+ * deployed on chain.
+ */
+
+import { readFile } from 'node:fs/promises';
+import { basename, dirname, join, resolve } from 'node:path';
+import type { KleverChainClient, KleverNetwork } from '../chain/index.js';
+import { collectArtifacts } from '../toolchain/build.js';
+import { codeHash } from '../toolchain/verify.js';
+import { parseAbi, type Abi } from './model.js';
+
+export interface BuildAbi {
+  abi: Abi;
+  abiPath: string;
+  /** The wasm built next to the ABI */
+  wasmPath: string;
+}
+
+export interface VerifiedAbi extends BuildAbi {
+  /** Code hash shared by the built wasm and the deployed contract (hex) */
+  codeHash: string;
+}
+
+/** Read and parse the ABI JSON file at `path` */
+export async function loadAbiFile(path: string): Promise<Abi> {
+  let json: string;
+  try {
+    json = await readFile(path, 'utf8');
+  } catch {
+    throw new Error(`ABI file not found: ${path}`);
+  }
+  try {
+    return parseAbi(json);
+  } catch (error) {
+    throw new Error(`${path}: ${(error as Error).message}`);
+  }
+}
+
+/**
+ * The ABI a build of the project at `projectPath` wrote to output/ (or to the
+ * output/ of a contract one level below). `contract` picks one of several.
+ */
+export async function loadBuildAbi(projectPath: string, contract?: string): Promise<BuildAbi> {
+  const root = resolve(projectPath);
+  const artifacts = await collectArtifacts(root).catch(() => []);
+  const abis = artifacts.filter(a => a.kind === 'abi').map(a => a.path);
+  const names = abis.map(path => basename(path, '.abi.json'));
+  let abiPath: string | undefined;
+  if (contract) {
+    abiPath = abis[names.indexOf(contract)];
+    if (!abiPath) {
+      throw new Error(`No ${contract}.abi.json in the build output (found: ${names.join(', ')}).`);
+    }
+  } else if (abis.length === 1) {
+    abiPath = abis[0];
+  } else if (abis.length === 0) {
+    throw new Error(`No .abi.json in ${join(root, 'output')}; build the project first.`);
+  } else {
+    throw new Error(`The build output has several ABIs (${names.join(', ')}); pass contract.`);
+  }
+  const wasmPath = join(dirname(abiPath), `${basename(abiPath, '.abi.json')}.wasm`);
+  return { abi: await loadAbiFile(abiPath), abiPath, wasmPath };
+}
+
+/**
+ * The build ABI of the project at `projectPath`, only when the wasm built with
+ * it is the code deployed at `address`: an ABI copied from a source tree may
+ * describe another version than the one on chain.
+ */
+export async function loadVerifiedAbi(
+  client: KleverChainClient,
+  address: string,
+  projectPath: string,
+  options: { network?: KleverNetwork; contract?: string } = {}
+): Promise<VerifiedAbi> {
+  const build = await loadBuildAbi(projectPath, options.contract);
+  let wasm: Buffer;
+  try {
+    wasm = await readFile(build.wasmPath);
+  } catch {
+    throw new Error(
+      `No ${basename(build.wasmPath)} next to ${build.abiPath}; rebuild the project.`
+    );
+  }
+  const local = codeHash(wasm);
+  const deployed = await client.getCodeHash(address, options.network);
+  if (local !== deployed) {
+    throw new Error(
+      `${basename(build.wasmPath)} (code hash ${local}) is not the code deployed at ${address} (${deployed}); build the deployed version, e.g. with verify_build.`
+    );
+  }
+  return { ...build, codeHash: local };
+}
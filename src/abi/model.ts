@@ -0,0 +1,115 @@
+/**
+ * Typed model of a klever-sc ABI file (`output/<name>.abi.json`): endpoints
+ * with their inputs and outputs, events and the custom structs and enums
+ * they use. Tools parse ABI JSON once with `parseAbi` and work on this model.
+ */
+
+export interface AbiInput {
+  name: string;
+  type: string;
+  multi_arg?: boolean;
+}
+
+export interface AbiOutput {
+  type: string;
+  multi_result?: boolean;
+}
+
+export interface AbiEndpoint {
+  name: string;
+  docs?: string[];
+  onlyOwner?: boolean;
+  mutability?: 'mutable' | 'readonly';
+  payableInTokens?: string[];
+  inputs: AbiInput[];
+  outputs: AbiOutput[];
+}
+
+export interface AbiField {
+  name: string;
+  type: string;
+}
+
+export interface AbiEnumVariant {
+  name: string;
+  discriminant: number;
+  fields?: AbiField[];
+}
+
+export interface AbiEventInput {
+  name: string;
+  type: string;
+  indexed?: boolean;
+}
+
+export interface AbiEvent {
+  identifier: string;
+  docs?: string[];
+  inputs: AbiEventInput[];
+}
+
+export type AbiTypeDef =
+  | { type: 'struct'; docs?: string[]; fields: AbiField[] }
+  | { type: 'enum'; docs?: string[]; variants: AbiEnumVariant[] };
+
+/** Toolchain the contract was built with, as `ksc` records it */
+export interface AbiBuildInfo {
+  rustc?: { version: string; commitHash?: string; channel?: string };
+  contractCrate?: { name: string; version: string; gitVersion?: string };
+  framework?: { name: string; version: string };
+}
+
+export interface Abi {
+  buildInfo?: AbiBuildInfo;
+  docs?: string[];
+  name: string;
+  constructor?: Omit<AbiEndpoint, 'name'>;
+  /** Arguments of the #[upgrade] function, in ABIs from newer framework versions */
+  upgradeConstructor?: Omit<AbiEndpoint, 'name'>;
+  endpoints: AbiEndpoint[];
+  events?: AbiEvent[];
+  types?: Record<string, AbiTypeDef>;
+}
+
+/** Parse ABI JSON (string or already-parsed object) and check its basic shape */
+export function parseAbi(input: string | object): Abi {
+  let abi: unknown = input;
+  if (typeof input === 'string') {
+    try {
+      abi = JSON.parse(input);
+    } catch (error) {
+      throw new Error(`ABI is not valid JSON: ${(error as Error).message}`);
+    }
+  }
+  const candidate = abi as Partial<Abi>;
+  if (!candidate || typeof candidate.name !== 'string' || !Array.isArray(candidate.endpoints)) {
+    throw new Error('ABI must be an object with a "name" and an "endpoints" array.');
+  }
+  const signatures = [
+    ...candidate.endpoints,
+    ...(Object.hasOwn(candidate, 'constructor') ? [candidate.constructor!] : []),
+    ...(candidate.upgradeConstructor ? [candidate.upgradeConstructor] : []),
+  ];
+  for (const signature of signatures) {
+    signature.inputs = signature.inputs || [];
+    signature.outputs = signature.outputs || [];
+  }
+  return candidate as Abi;
+}
+
+export function findAbiEndpoint(abi: Abi, name: string): AbiEndpoint {
+  const endpoint = abi.endpoints.find(e => e.name === name);
+  if (!endpoint) {
+    const available = abi.endpoints.map(e => e.name).join(', ');
+    throw new Error(`Endpoint "${name}" not found in ABI "${abi.name}". Available: ${available}`);
+  }
+  return endpoint;
+}
+
+/**
+ * Signature of the #[upgrade] function: `upgradeConstructor`, or the `upgrade`
+ * endpoint older ABIs list instead. Undefined when the contract has neither.
+ */
+export function findUpgradeSignature(abi: Abi): Omit<AbiEndpoint, 'name'> | undefined {
+  return abi.upgradeConstructor || abi.endpoints.find(e => e.name === 'upgrade');
+}
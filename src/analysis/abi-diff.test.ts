@@ -0,0 +1,185 @@
+import { diffAbi } from './abi-diff.js';
+import type { Abi } from '../abi/index.js';
+
+const BEFORE: Abi = {
+  name: 'Vault',
+  constructor: { inputs: [{ name: 'fee', type: 'u32' }], outputs: [] },
+  endpoints: [
+    {
+      name: 'deposit',
+      mutability: 'mutable',
+      payableInTokens: ['KLV'],
+      inputs: [],
+      outputs: [],
+    },
+    {
+      name: 'withdraw',
+      mutability: 'mutable',
+      inputs: [{ name: 'amount', type: 'BigUint' }],
+      outputs: [],
+    },
+    {
+      name: 'getBalance',
+      mutability: 'readonly',
+      inputs: [{ name: 'address', type: 'Address' }],
+      outputs: [{ type: 'BigUint' }],
+    },
+    { name: 'legacy', mutability: 'mutable', inputs: [], outputs: [] },
+  ],
+  events: [
+    {
+      identifier: 'deposit',
+      inputs: [
+        { name: 'caller', type: 'Address', indexed: true },
+        { name: 'amount', type: 'BigUint' },
+      ],
+    },
+    {
+      identifier: 'withdraw',
+      inputs: [{ name: 'caller', type: 'Address', indexed: true }],
+    },
+  ],
+  types: {
+    Status: {
+      type: 'enum',
+      variants: [
+        { name: 'Active', discriminant: 0 },
+        { name: 'Closed', discriminant: 1 },
+      ],
+    },
+    Position: {
+      type: 'struct',
+      fields: [{ name: 'amount', type: 'BigUint' }],
+    },
+  },
+};
+
+function withChanges(change: (abi: Abi) => void): Abi {
+  const abi = structuredClone(BEFORE);
+  change(abi);
+  return abi;
+}
+
+describe('diffAbi', () => {
+  it('reports nothing for identical ABIs', () => {
+    const result = diffAbi(BEFORE, structuredClone(BEFORE));
+    expect(result.changes).toEqual([]);
+    expect(result.compatible).toBe(true);
+  });
+
+  it('classifies removed endpoints as breaking and new ones as additive', () => {
+    const result = diffAbi(
+      BEFORE,
+      withChanges(abi => {
+        abi.endpoints = abi.endpoints.filter(e => e.name !== 'legacy');
+        abi.endpoints.push({ name: 'claim', mutability: 'mutable', inputs: [], outputs: [] });
+      })
+    );
+    expect(result.changes).toEqual([
+      { impact: 'breaking', category: 'endpoint', name: 'legacy', detail: 'endpoint removed' },
+      { impact: 'additive', category: 'endpoint', name: 'claim', detail: 'endpoint added' },
+    ]);
+    expect(result.summary).toEqual({ breaking: 1, additive: 1, compatible: 0 });
+    expect(result.compatible).toBe(false);
+  });
+
+  it('flags changed argument and result types', () => {
+    const result = diffAbi(
+      BEFORE,
+      withChanges(abi => {
+        abi.endpoints[1].inputs[0].type = 'u64';
+        abi.endpoints[2].outputs[0].type = 'u64';
+      })
+    );
+    expect(result.changes.map(c => [c.name, c.impact, c.detail])).toEqual([
+      ['withdraw', 'breaking', 'argument 1 (amount) changed type from BigUint to u64'],
+      ['getBalance', 'breaking', 'result changed from (BigUint) to (u64)'],
+    ]);
+  });
+
+  it('treats trailing optional arguments as additive and renamed arguments as compatible', () => {
+    const result = diffAbi(
+      BEFORE,
+      withChanges(abi => {
+        abi.endpoints[1].inputs[0].name = 'value';
+        abi.endpoints[1].inputs.push({ name: 'to', type: 'optional<Address>' });
+        abi.endpoints[2].inputs.push({ name: 'token', type: 'TokenIdentifier' });
+      })
+    );
+    expect(result.changes.map(c => [c.name, c.impact])).toEqual([
+      ['withdraw', 'compatible'],
+      ['withdraw', 'additive'],
+      ['getBalance', 'breaking'],
+    ]);
+  });
+
+  it('detects owner-only, payable and mutability changes', () => {
+    const result = diffAbi(
+      BEFORE,
+      withChanges(abi => {
+        abi.endpoints[0].payableInTokens = ['USDT-A1B2'];
+        abi.endpoints[1].onlyOwner = true;
+        abi.endpoints[2].mutability = 'mutable';
+      })
+    );
+    expect(result.changes.map(c => c.detail)).toEqual([
+      'no longer accepts payment in KLV',
+      'now accepts payment in USDT-A1B2',
+      'now owner-only',
+      'view became a mutable endpoint; queries now fail',
+    ]);
+  });
+
+  it('compares constructor arguments', () => {
+    const result = diffAbi(
+      BEFORE,
+      withChanges(abi => {
+        abi.constructor!.inputs.push({ name: 'owner', type: 'Address' });
+      })
+    );
+    expect(result.changes).toEqual([
+      {
+        impact: 'breaking',
+        category: 'constructor',
+        name: 'init',
+        detail: 'argument(s) added: owner: Address',
+      },
+    ]);
+  });
+
+  it('reports renamed, removed and reshaped events', () => {
+    const result = diffAbi(
+      BEFORE,
+      withChanges(abi => {
+        abi.events![0].identifier = 'depositMade';
+        abi.events![1].inputs[0].indexed = false;
+        abi.events!.push({ identifier: 'claim', inputs: [] });
+      })
+    );
+    expect(result.changes.map(c => [c.name, c.impact, c.detail])).toEqual([
+      [
+        'deposit',
+        'breaking',
+        'renamed to depositMade; indexers filtering on "deposit" stop matching',
+      ],
+      ['claim', 'additive', 'event added'],
+      ['withdraw', 'breaking', 'fields changed from (indexed Address) to (Address)'],
+    ]);
+  });
+
+  it('allows appended enum variants but not struct changes', () => {
+    const result = diffAbi(
+      BEFORE,
+      withChanges(abi => {
+        const status = abi.types!.Status;
+        if (status.type === 'enum') status.variants.push({ name: 'Frozen', discriminant: 2 });
+        const position = abi.types!.Position;
+        if (position.type === 'struct') position.fields.push({ name: 'since', type: 'u64' });
+      })
+    );
+    expect(result.changes.map(c => [c.name, c.impact])).toEqual([
+      ['Status', 'additive'],
+      ['Position', 'breaking'],
+    ]);
+  });
+});
@@ -0,0 +1,242 @@
+/**
+ * Compare two versions of a contract ABI and classify every difference as
+ * breaking (existing callers, scripts or indexers stop working), additive
+ * (new surface) or compatible (metadata only).
+ */
+
+import type { Abi, AbiEndpoint, AbiEnumVariant, AbiEvent, AbiTypeDef } from '../abi/index.js';
+
+export type AbiChangeImpact = 'breaking' | 'additive' | 'compatible';
+
+export interface AbiChange {
+  impact: AbiChangeImpact;
+  category: 'constructor' | 'endpoint' | 'event' | 'type';
+  /** Endpoint name, event identifier or type name */
+  name: string;
+  detail: string;
+}
+
+export interface AbiDiff {
+  changes: AbiChange[];
+  summary: Record<AbiChangeImpact, number>;
+  /** True when no change is breaking */
+  compatible: boolean;
+}
+
+type Signature = Omit<AbiEndpoint, 'name'>;
+
+/** Trailing arguments callers may omit */
+const OMITTABLE = /^(optional|variadic)</;
+
+function typeList(items: { type: string }[]): string {
+  return items.map(i => i.type).join(', ');
+}
+
+function diffSignature(
+  category: 'constructor' | 'endpoint',
+  name: string,
+  before: Signature,
+  after: Signature
+): AbiChange[] {
+  const changes: AbiChange[] = [];
+  const change = (impact: AbiChangeImpact, detail: string) =>
+    changes.push({ impact, category, name, detail });
+
+  const shared = Math.min(before.inputs.length, after.inputs.length);
+  for (let i = 0; i < shared; i++) {
+    const a = before.inputs[i];
+    const b = after.inputs[i];
+    if (a.type !== b.type) {
+      change('breaking', `argument ${i + 1} (${b.name}) changed type from ${a.type} to ${b.type}`);
+    } else if (a.name !== b.name) {
+      change('compatible', `argument ${i + 1} renamed from ${a.name} to ${b.name}`);
+    }
+  }
+  const added = after.inputs.slice(shared);
+  if (added.length > 0) {
+    const optional = added.every(i => OMITTABLE.test(i.type));
+    change(
+      optional ? 'additive' : 'breaking',
+      `${optional ? 'optional ' : ''}argument(s) added: ${added.map(i => `${i.name}: ${i.type}`).join(', ')}`
+    );
+  }
+  const removed = before.inputs.slice(shared);
+  if (removed.length > 0) {
+    change(
+      'breaking',
+      `argument(s) removed: ${removed.map(i => `${i.name}: ${i.type}`).join(', ')}`
+    );
+  }
+
+  if (typeList(before.outputs) !== typeList(after.outputs)) {
+    change(
+      'breaking',
+      `result changed from (${typeList(before.outputs)}) to (${typeList(after.outputs)})`
+    );
+  }
+
+  const wasView = before.mutability === 'readonly';
+  const isView = after.mutability === 'readonly';
+  if (wasView && !isView) change('breaking', 'view became a mutable endpoint; queries now fail');
+  if (!wasView && isView) change('compatible', 'endpoint became a readonly view');
+
+  if (!before.onlyOwner && after.onlyOwner) change('breaking', 'now owner-only');
+  if (before.onlyOwner && !after.onlyOwner) change('additive', 'no longer owner-only');
+
+  const tokensBefore = before.payableInTokens || [];
+  const tokensAfter = after.payableInTokens || [];
+  const accepts = (tokens: string[], token: string) =>
+    tokens.includes('*') || tokens.includes(token);
+  const dropped = tokensBefore.filter(t => !accepts(tokensAfter, t));
+  const gained = tokensAfter.filter(t => !accepts(tokensBefore, t));
+  if (dropped.length > 0) change('breaking', `no longer accepts payment in ${dropped.join(', ')}`);
+  if (gained.length > 0) change('additive', `now accepts payment in ${gained.join(', ')}`);
+  return changes;
+}
+
+function eventSignature(event: AbiEvent): string {
+  return event.inputs.map(i => `${i.indexed ? 'indexed ' : ''}${i.type}`).join(', ');
+}
+
+function diffEvents(before: AbiEvent[], after: AbiEvent[]): AbiChange[] {
+  const changes: AbiChange[] = [];
+  const removed = before.filter(e => !after.some(n => n.identifier === e.identifier));
+  const added = after.filter(e => !before.some(o => o.identifier === e.identifier));
+
+  for (const event of removed) {
+    // Same fields under a new identifier: a rename, which indexers still notice
+    const renamed = added.find(n => eventSignature(n) === eventSignature(event));
+    if (renamed) {
+      added.splice(added.indexOf(renamed), 1);
+      changes.push({
+        impact: 'breaking',
+        category: 'event',
+        name: event.identifier,
+        detail: `renamed to ${renamed.identifier}; indexers filtering on "${event.identifier}" stop matching`,
+      });
+    } else {
+      changes.push({
+        impact: 'breaking',
+        category: 'event',
+        name: event.identifier,
+        detail: 'event removed',
+      });
+    }
+  }
+  for (const event of added) {
+    changes.push({
+      impact: 'additive',
+      category: 'event',
+      name: event.identifier,
+      detail: 'event added',
+    });
+  }
+  for (const event of before) {
+    const next = after.find(n => n.identifier === event.identifier);
+    if (next && eventSignature(next) !== eventSignature(event)) {
+      changes.push({
+        impact: 'breaking',
+        category: 'event',
+        name: event.identifier,
+        detail: `fields changed from (${eventSignature(event)}) to (${eventSignature(next)})`,
+      });
+    }
+  }
+  return changes;
+}
+
+function variantShape(variant: AbiEnumVariant): string {
+  return `${variant.discriminant}:${variant.name}(${typeList(variant.fields || [])})`;
+}
+
+function typeShape(def: AbiTypeDef): string {
+  return def.type === 'struct'
+    ? `struct {${def.fields.map(f => `${f.name}: ${f.type}`).join(', ')}}`
+    : `enum {${def.variants.map(variantShape).join(', ')}}`;
+}
+
+function diffTypes(
+  before: Record<string, AbiTypeDef>,
+  after: Record<string, AbiTypeDef>
+): AbiChange[] {
+  const changes: AbiChange[] = [];
+  for (const name of Object.keys(before)) {
+    if (!Object.hasOwn(after, name)) {
+      changes.push({ impact: 'breaking', category: 'type', name, detail: 'type removed' });
+      continue;
+    }
+    const a = before[name];
+    const b = after[name];
+    if (typeShape(a) === typeShape(b)) continue;
+    // New enum variants keep every existing discriminant decodable
+    if (a.type === 'enum' && b.type === 'enum') {
+      const existing = new Set(b.variants.map(variantShape));
+      if (a.variants.every(v => existing.has(variantShape(v)))) {
+        const added = b.variants.length - a.variants.length;
+        changes.push({
+          impact: 'additive',
+          category: 'type',
+          name,
+          detail: `${added} enum variant(s) added`,
+        });
+        continue;
+      }
+    }
+    changes.push({
+      impact: 'breaking',
+      category: 'type',
+      name,
+      detail: `encoding changed from ${typeShape(a)} to ${typeShape(b)}`,
+    });
+  }
+  for (const name of Object.keys(after)) {
+    if (!Object.hasOwn(before, name)) {
+      changes.push({ impact: 'additive', category: 'type', name, detail: 'type added' });
+    }
+  }
+  return changes;
+}
+
+/**
+ * Diff `before` (e.g. the deployed ABI) against `after` (the new build)
+ */
+export function diffAbi(before: Abi, after: Abi): AbiDiff {
+  const changes: AbiChange[] = [];
+
+  const initBefore = Object.hasOwn(before, 'constructor') ? before.constructor : undefined;
+  const initAfter = Object.hasOwn(after, 'constructor') ? after.constructor : undefined;
+  if (initBefore && initAfter) {
+    changes.push(...diffSignature('constructor', 'init', initBefore, initAfter));
+  }
+
+  for (const endpoint of before.endpoints) {
+    const next = after.endpoints.find(e => e.name === endpoint.name);
+    if (next) {
+      changes.push(...diffSignature('endpoint', endpoint.name, endpoint, next));
+    } else {
+      changes.push({
+        impact: 'breaking',
+        category: 'endpoint',
+        name: endpoint.name,
+        detail: `${endpoint.mutability === 'readonly' ? 'view' : 'endpoint'} removed`,
+      });
+    }
+  }
+  for (const endpoint of after.endpoints) {
+    if (!before.endpoints.some(e => e.name === endpoint.name)) {
+      changes.push({
+        impact: 'additive',
+        category: 'endpoint',
+        name: endpoint.name,
+        detail: `${endpoint.mutability === 'readonly' ? 'view' : 'endpoint'} added`,
+      });
+    }
+  }
+
+  changes.push(...diffEvents(before.events || [], after.events || []));
+  changes.push(...diffTypes(before.types || {}, after.types || {}));
+
+  const summary: Record<AbiChangeImpact, number> = { breaking: 0, additive: 0, compatible: 0 };
+  for (const change of changes) summary[change.impact]++;
+  return { changes, summary, compatible: summary.breaking === 0 };
+}
@@ -0,0 +1,81 @@
+import { readFileSync } from 'fs';
+import { auditAccessControl } from './access-control.js';
+
+const EXAMPLE = readFileSync('examples/token_contract.rs', 'utf8');
+
+const OPEN_MINT = `#[klever_sc::contract]
+pub trait Token: roles::RolesModule {
+    #[endpoint]
+    fn mint(&self, to: ManagedAddress, amount: BigUint) {
+        self.balance(&to).update(|b| *b += &amount);
+    }
+
+    #[only_owner]
+    #[endpoint(setMinter)]
+    fn set_minter(&self, minter: ManagedAddress) {
+        self.minters().insert(minter);
+    }
+
+    #[endpoint(mintAsMinter)]
+    fn mint_as_minter(&self, to: ManagedAddress, amount: BigUint) {
+        self.verify_caller();
+        self.balance(&to).update(|b| *b += &amount);
+    }
+
+    #[endpoint]
+    fn ping(&self) {}
+
+    #[storage_mapper("balance")]
+    fn balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
+`;
+
+const ROLES = `#[klever_sc::module]
+pub trait RolesModule {
+    fn verify_caller(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.minters().contains(&caller), "Not a minter");
+    }
+
+    #[storage_mapper("minters")]
+    fn minters(&self) -> UnorderedSetMapper<ManagedAddress>;
+}
+`;
+
+describe('auditAccessControl', () => {
+  it('classifies the token example endpoints', () => {
+    const report = auditAccessControl(EXAMPLE);
+
+    expect(report.endpoints.map(e => [e.name, e.access, e.writes])).toEqual([
+      ['transfer', 'public', ['balance']],
+      ['mint', 'owner-only', ['balance', 'total_supply']],
+      ['burn', 'public', ['balance', 'total_supply']],
+    ]);
+    expect(report.endpoints[1].guards).toEqual(['require_owner()']);
+    expect(report.findings.map(f => [f.rule, f.severity])).toEqual([
+      ['public-state-mutation', 'info'],
+      ['public-state-mutation', 'info'],
+    ]);
+  });
+
+  it('flags a public mint and follows guards into module helpers', () => {
+    const report = auditAccessControl(OPEN_MINT, { 'src/roles.rs': ROLES });
+
+    expect(report.endpoints.map(e => [e.endpoint, e.access])).toEqual([
+      ['mint', 'public'],
+      ['setMinter', 'owner-only'],
+      ['mintAsMinter', 'role-gated'],
+      ['ping', 'public'],
+    ]);
+    expect(report.endpoints[2].guards).toEqual([
+      'verify_caller() -> require!(caller has role)',
+    ]);
+    expect(report.findings).toHaveLength(1);
+    expect(report.findings[0]).toMatchObject({
+      rule: 'public-privileged-endpoint',
+      severity: 'warning',
+      function: 'mint',
+    });
+    expect(report.summary).toEqual({ 'owner-only': 1, 'role-gated': 1, public: 2, flagged: 1 });
+  });
+});
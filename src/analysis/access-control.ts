@@ -0,0 +1,208 @@
+/**
+ * Access-control audit: classify every endpoint as owner-only, role-gated or
+ * public from its attributes and guard calls, and flag public endpoints that
+ * change state so someone confirms anyone may call them.
+ */
+
+import { listStorageMappers } from '../codegen/rust-source.js';
+import {
+  contractFunctions,
+  maskNonCode,
+  SENDS_FUNDS,
+  storageWrites,
+  type ContractFunction,
+} from './source.js';
+import type { AnalysisCheck, Finding, ModuleSources } from './types.js';
+
+export type AccessLevel = 'owner-only' | 'role-gated' | 'public';
+
+export interface EndpointAccess {
+  /** Rust function name */
+  name: string;
+  /** Name in the ABI, from #[endpoint(name)] */
+  endpoint: string;
+  file?: string;
+  line: number;
+  access: AccessLevel;
+  /** Attributes and calls that restrict the caller */
+  guards: string[];
+  /** Storage mappers written, directly or through private helpers */
+  writes: string[];
+  sendsFunds: boolean;
+  payable: boolean;
+}
+
+export interface AccessControlReport {
+  endpoints: EndpointAccess[];
+  findings: Finding[];
+  summary: Record<AccessLevel, number> & { flagged: number };
+}
+
+const OWNER_HELPER = /^(require|ensure|check|only|assert)_(only_)?owner\w*$/;
+const ROLE_HELPER =
+  /^(require|ensure|check|only|assert)_\w*(admin|role|whitelist|manager|operator|minter|auth|permission|allowed|governance|member)\w*$/;
+/** Endpoint names that usually need a privileged caller */
+const SENSITIVE =
+  /^(mint|set_|pause|unpause|freeze|unfreeze|wipe|blacklist|whitelist|sweep|rescue|emergency|withdraw_fees|claim_fees|change_|transfer_ownership|upgrade|airdrop)|(_admin|_owner|_config|_fee|_fees|_role)$/;
+
+/** Inline require! conditions comparing the caller with the owner or a role */
+const OWNER_CONDITION =
+  /get_owner_address\(\)|\bcaller\b[\s\S]*\bowner\b|\bowner\b[\s\S]*\bcaller\b/;
+const ROLE_CONDITION =
+  /\.contains\(\s*&?caller\s*\)|has_role|\bcaller\b[\s\S]*\b(admin|role|manager|operator)/;
+
+interface Guard {
+  level: Exclude<AccessLevel, 'public'>;
+  label: string;
+}
+
+/** Guards written directly in a body: helper calls and inline caller checks */
+function bodyGuards(body: string): Guard[] {
+  const guards: Guard[] = [];
+  for (const match of body.matchAll(/self\s*\.\s*(\w+)\s*\(/g)) {
+    if (OWNER_HELPER.test(match[1])) {
+      guards.push({ level: 'owner-only', label: `${match[1]}()` });
+    } else if (ROLE_HELPER.test(match[1])) {
+      guards.push({ level: 'role-gated', label: `${match[1]}()` });
+    }
+  }
+  for (const match of body.matchAll(/require!\s*\(([\s\S]*?),\s*"/g)) {
+    const condition = match[1];
+    if (OWNER_CONDITION.test(condition)) {
+      guards.push({ level: 'owner-only', label: 'require!(caller == owner)' });
+    } else if (ROLE_CONDITION.test(condition)) {
+      guards.push({ level: 'role-gated', label: 'require!(caller has role)' });
+    }
+  }
+  return guards;
+}
+
+function classify(
+  fn: ContractFunction,
+  helpers: Map<string, ContractFunction>
+): { access: AccessLevel; guards: string[] } {
+  const guards: Guard[] = [];
+  if (fn.attributes.some(a => a === '#[only_owner]')) {
+    guards.push({ level: 'owner-only', label: '#[only_owner]' });
+  }
+  if (fn.attributes.some(a => a === '#[only_admin]')) {
+    guards.push({ level: 'role-gated', label: '#[only_admin]' });
+  }
+  const code = maskNonCode(fn.body);
+  guards.push(...bodyGuards(code));
+  // One level into private helpers, e.g. a `check_caller()` that compares against the owner
+  for (const match of code.matchAll(/self\s*\.\s*(\w+)\s*\(/g)) {
+    const helper = helpers.get(match[1]);
+    if (!helper || OWNER_HELPER.test(helper.name) || ROLE_HELPER.test(helper.name)) continue;
+    for (const guard of bodyGuards(maskNonCode(helper.body))) {
+      guards.push({ level: guard.level, label: `${helper.name}() -> ${guard.label}` });
+    }
+  }
+  const access: AccessLevel = guards.some(g => g.level === 'owner-only')
+    ? 'owner-only'
+    : guards.length > 0
+      ? 'role-gated'
+      : 'public';
+  return { access, guards: [...new Set(guards.map(g => g.label))] };
+}
+
+/**
+ * Audit the endpoints of `source` and its modules
+ */
+export function auditAccessControl(
+  source: string,
+  modules: ModuleSources = {}
+): AccessControlReport {
+  const files: [string | undefined, string][] = [
+    [undefined, source],
+    ...Object.entries(modules).filter(([, text]) => /#\[klever_sc::(contract|module)\]/.test(text)),
+  ];
+  const functions = files.flatMap(([file, text]) =>
+    contractFunctions(text).map(fn => ({ fn, file }))
+  );
+  const mappers = new Set(
+    files.flatMap(([, text]) => listStorageMappers(text).map(m => m.functionName))
+  );
+  const helpers = new Map(
+    functions.filter(({ fn }) => fn.kind === 'private').map(({ fn }) => [fn.name, fn])
+  );
+
+  const endpoints: EndpointAccess[] = [];
+  const findings: Finding[] = [];
+  for (const { fn, file } of functions) {
+    if (fn.kind !== 'endpoint') continue;
+    const { access, guards } = classify(fn, helpers);
+    const code = maskNonCode(fn.body);
+    const called = [...code.matchAll(/self\s*\.\s*(\w+)\s*\(/g)]
+      .map(m => helpers.get(m[1]))
+      .filter((h): h is ContractFunction => h !== undefined);
+    const bodies = [code, ...called.map(h => maskNonCode(h.body))];
+    const writes = [...new Set(bodies.flatMap(b => storageWrites(b, mappers).map(w => w.mapper)))];
+    const sendsFunds = bodies.some(b => SENDS_FUNDS.test(b));
+    const abiName = fn.attributes.map(a => /^#\[endpoint\((\w+)\)\]/.exec(a)).find(Boolean);
+
+    endpoints.push({
+      name: fn.name,
+      endpoint: abiName ? abiName[1] : fn.name,
+      file,
+      line: fn.line,
+      access,
+      guards,
+      writes,
+      sendsFunds,
+      payable: fn.attributes.some(a => a.startsWith('#[payable')),
+    });
+
+    if (access !== 'public' || (writes.length === 0 && !sendsFunds)) continue;
+    const effects = [
+      ...(writes.length > 0 ? [`writes ${writes.join(', ')}`] : []),
+      ...(sendsFunds ? ['sends funds'] : []),
+    ].join(' and ');
+    const sensitive = SENSITIVE.test(fn.name);
+    findings.push({
+      rule: sensitive ? 'public-privileged-endpoint' : 'public-state-mutation',
+      severity: sensitive ? 'warning' : 'info',
+      message: sensitive
+        ? `${fn.name} is callable by anyone and ${effects}; endpoints like this are normally owner-only`
+        : `${fn.name} is callable by anyone and ${effects}; confirm that is intended`,
+      function: fn.name,
+      file,
+      line: fn.line,
+      suggestion: sensitive
+        ? 'Add self.require_owner() (or #[only_owner]) as the first statement.'
+        : 'If only some accounts may call it, add an owner or role check.',
+    });
+  }
+
+  const count = (level: AccessLevel) => endpoints.filter(e => e.access === level).length;
+  return {
+    endpoints,
+    findings,
+    summary: {
+      'owner-only': count('owner-only'),
+      'role-gated': count('role-gated'),
+      public: count('public'),
+      flagged: findings.length,
+    },
+  };
+}
+
+export const accessControlCheck: AnalysisCheck = {
+  name: 'access-control',
+  description:
+    'Public endpoints that write storage or send funds, especially privileged-looking ones',
+  crossModule: true,
+  rules: [
+    {
+      id: 'public-privileged-endpoint',
+      severity: 'warning',
+      description: 'Privileged-looking endpoint (mint, set_*, pause, ...) callable by anyone',
+    },
+    {
+      id: 'public-state-mutation',
+      severity: 'info',
+      description: 'Public endpoint that writes storage or sends funds',
+    },
+  ],
+  run: (source, modules) => auditAccessControl(source, modules).findings,
+};
@@ -0,0 +1,165 @@
+import { readFileSync } from 'fs';
+import { architectureGraph, contractArchitecture, foldHelpers } from './architecture.js';
+
+const EXAMPLE = readFileSync('examples/token_contract.rs', 'utf8');
+
+const VAULT = `#[klever_sc::contract]
+pub trait Vault: fees::FeesModule {
+    #[init]
+    fn init(&self, dex: ManagedAddress) {
+        self.dex_address().set(dex);
+    }
+
+    #[endpoint]
+    #[payable("KLV")]
+    fn deposit(&self) {
+        let caller = self.blockchain().get_caller();
+        let amount = self.call_value().klv_value().clone_value();
+        self.credit(&caller, &amount);
+        self.deposit_event(&caller, &amount);
+    }
+
+    #[endpoint(swapAll)]
+    fn swap_all(&self) {
+        let dex = self.dex_address().get();
+        self.dex_contract(dex)
+            .swap(self.total().get())
+            .async_call()
+            .with_callback(self.callbacks().swap_done())
+            .call_and_exit();
+    }
+
+    #[endpoint]
+    fn ping(&self) {
+        let _: () = self
+            .send()
+            .contract_call::<()>(self.oracle().get(), "ping")
+            .execute_on_dest_context();
+    }
+
+    #[callback]
+    fn swap_done(&self) {
+        self.total().clear();
+    }
+
+    fn credit(&self, caller: &ManagedAddress, amount: &BigUint) {
+        self.balance(caller).update(|b| *b += amount);
+        self.add_total(amount);
+    }
+
+    fn add_total(&self, amount: &BigUint) {
+        self.total().update(|t| *t += amount);
+    }
+
+    #[view(getBalance)]
+    #[storage_mapper("balance")]
+    fn balance(&self, who: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("total")]
+    fn total(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("dex")]
+    fn dex_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("oracle")]
+    fn oracle(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[event("deposit")]
+    fn deposit_event(&self, #[indexed] caller: &ManagedAddress, amount: &BigUint);
+
+    #[proxy]
+    fn dex_contract(&self, sc_address: ManagedAddress) -> dex_proxy::Proxy<Self::Api>;
+}
+`;
+
+const FEES = `#[klever_sc::module]
+pub trait FeesModule {
+    #[only_owner]
+    #[endpoint(setFee)]
+    fn set_fee(&self, fee: u64) {
+        self.fee().set(fee);
+    }
+
+    #[storage_mapper("fee")]
+    fn fee(&self) -> SingleValueMapper<u64>;
+}
+`;
+
+describe('contractArchitecture', () => {
+  const architecture = contractArchitecture(VAULT, { 'src/fees.rs': FEES });
+  const fn = (name: string) => architecture.functions.find(f => f.name === name);
+
+  it('records the storage, events, helpers and external calls of each function', () => {
+    expect(architecture.contract).toBe('Vault');
+    expect(fn('deposit')).toMatchObject({
+      abiName: 'deposit',
+      kind: 'endpoint',
+      writes: [],
+      emits: ['deposit_event'],
+      helpers: ['credit'],
+    });
+    expect(fn('swap_all')).toMatchObject({
+      abiName: 'swapAll',
+      reads: ['dex_address', 'total'],
+      calls: [{ target: 'dex_proxy', endpoint: 'swap', async: true, callback: 'swap_done' }],
+    });
+    expect(fn('ping')?.calls).toEqual([{ target: 'oracle', endpoint: 'ping', async: false }]);
+    expect(fn('getBalance')).toMatchObject({ kind: 'view', reads: ['balance'] });
+    expect(fn('set_fee')).toMatchObject({
+      abiName: 'setFee',
+      file: 'src/fees.rs',
+      writes: ['fee'],
+    });
+    expect(architecture.storage.map(s => s.key)).toEqual([
+      'balance',
+      'total',
+      'dex',
+      'oracle',
+      'fee',
+    ]);
+    expect(architecture.events).toEqual([{ name: 'deposit_event', identifier: 'deposit' }]);
+  });
+
+  it('folds private helpers into the functions calling them', () => {
+    const folded = foldHelpers(architecture);
+    expect(folded.functions.find(f => f.name === 'credit')).toBeUndefined();
+    expect(folded.functions.find(f => f.name === 'deposit')).toMatchObject({
+      writes: ['balance', 'total'],
+      helpers: ['credit', 'add_total'],
+    });
+  });
+
+  it('maps the example token contract', () => {
+    const token = contractArchitecture(EXAMPLE);
+    expect(token.functions.filter(f => f.kind === 'endpoint').length).toBeGreaterThan(0);
+    expect(token.functions.some(f => f.writes.length > 0)).toBe(true);
+  });
+});
+
+describe('architectureGraph', () => {
+  const architecture = foldHelpers(contractArchitecture(VAULT, { 'src/fees.rs': FEES }));
+
+  it('draws a Mermaid flowchart grouped by role', () => {
+    const graph = architectureGraph(architecture);
+    expect(graph.startsWith('flowchart LR\n')).toBe(true);
+    expect(graph).toContain(
+      '    subgraph group2["Storage"]\n        st_balance[("balance: SingleValueMapper")]'
+    );
+    expect(graph).toContain('        ext_dex_proxy{{"dex_proxy"}}');
+    expect(graph).toContain('    fn_deposit -->|"write"| st_balance');
+    expect(graph).toContain('    fn_swap_all -.->|"read"| st_total');
+    expect(graph).toContain('    fn_swap_all ==>|"swap (async)"| ext_dex_proxy');
+    expect(graph).toContain('    ext_dex_proxy -.->|"callback"| fn_swap_done');
+    expect(graph).toContain('    fn_deposit -->|"emits"| ev_deposit_event');
+    expect(graph).not.toContain('Helpers');
+  });
+
+  it('draws a DOT digraph with one cluster per role', () => {
+    const graph = architectureGraph(architecture, 'dot');
+    expect(graph.startsWith('digraph "Vault" {\n    rankdir=LR;\n')).toBe(true);
+    expect(graph).toContain('        st_fee [label="fee: SingleValueMapper", shape=cylinder];');
+    expect(graph).toContain('    fn_ping -> ext_oracle [label="ping", penwidth=2];');
+    expect(graph).toContain('    fn_getBalance -> st_balance [label="read", style=dashed];');
+    expect(graph.endsWith('}\n')).toBe(true);
+  });
+});
@@ -0,0 +1,387 @@
+/**
+ * Contract architecture map: the endpoints, views and callbacks of a contract
+ * and its modules with the storage mappers they read and write, the events
+ * they emit and the other contracts they call (through `#[proxy]` accessors,
+ * typed proxies or `contract_call`), rendered as a Mermaid or DOT graph.
+ * Private helpers are folded into the functions that call them unless they
+ * are asked for as nodes of their own.
+ */
+
+import { findContractTrait, listEvents, listStorageMappers } from '../codegen/rust-source.js';
+import { contractFunctions, maskNonCode, storageAccesses, type FunctionKind } from './source.js';
+import type { ModuleSources } from './types.js';
+
+export interface ExternalCall {
+  /** Proxy module or type, or the address expression of a `contract_call` */
+  target: string;
+  /** Endpoint called on the target, when the source names it */
+  endpoint?: string;
+  async: boolean;
+  /** Callback the result goes to */
+  callback?: string;
+}
+
+export interface ArchitectureFunction {
+  /** Rust function name */
+  name: string;
+  /** Name in the ABI, from #[endpoint(name)] or #[view(name)] */
+  abiName: string;
+  kind: Extract<FunctionKind, 'init' | 'upgrade' | 'endpoint' | 'view' | 'callback' | 'private'>;
+  file?: string;
+  line: number;
+  /** Storage mappers read and written */
+  reads: string[];
+  writes: string[];
+  /** Event functions called */
+  emits: string[];
+  calls: ExternalCall[];
+  /** Private helpers called */
+  helpers: string[];
+}
+
+export interface ArchitectureStorage {
+  name: string;
+  key: string;
+  mapper: string;
+}
+
+export interface ContractArchitecture {
+  contract: string;
+  functions: ArchitectureFunction[];
+  storage: ArchitectureStorage[];
+  events: { name: string; identifier: string }[];
+}
+
+export type GraphFormat = 'mermaid' | 'dot';
+
+const SHOWN_KINDS = new Set<FunctionKind>([
+  'init',
+  'upgrade',
+  'endpoint',
+  'view',
+  'callback',
+  'private',
+]);
+const SELF_CALL = /self\s*\.\s*(\w+)\s*\(/g;
+const ARGS = '\\((?:[^()]|\\([^()]*\\))*\\)';
+const TYPED = /\.\s*typed\s*\(\s*(?:\w+\s*::\s*)*(\w+)\s*\)\s*\.\s*(\w+)\s*\(/g;
+const CONTRACT_CALL =
+  /contract_call\s*(?:::\s*<[\s\S]*?>)?\s*\(\s*([^,]+?)\s*,\s*(?:ManagedBuffer::from\(\s*)?b?"([^"]*)"/g;
+
+const unique = <T>(values: T[]) => [...new Set(values)];
+
+/** How a call statement starting at `index` of `code` runs, and its callback */
+function callMode(code: string, index: number): Pick<ExternalCall, 'async' | 'callback'> {
+  const end = code.indexOf(';', index);
+  const statement = code.slice(index, end === -1 ? undefined : end);
+  const callback = /callbacks\(\)\s*\.\s*(\w+)\s*\(/.exec(statement);
+  return {
+    async: /\.\s*(async_call|register_promise|transfer_execute)\b/.test(statement),
+    ...(callback ? { callback: callback[1] } : {}),
+  };
+}
+
+/** Calls to other contracts in a function body */
+function externalCalls(body: string, proxies: Map<string, string>): ExternalCall[] {
+  const code = maskNonCode(body);
+  const calls: ExternalCall[] = [];
+  if (proxies.size > 0) {
+    const accessor = new RegExp(
+      `self\\s*\\.\\s*(${[...proxies.keys()].join('|')})\\s*${ARGS}\\s*\\.\\s*(\\w+)\\s*\\(`,
+      'g'
+    );
+    for (const m of code.matchAll(accessor)) {
+      calls.push({ target: proxies.get(m[1])!, endpoint: m[2], ...callMode(code, m.index!) });
+    }
+  }
+  for (const m of code.matchAll(TYPED)) {
+    calls.push({ target: m[1], endpoint: m[2], ...callMode(code, m.index!) });
+  }
+  // Endpoint names are string literals, which masking blanks out
+  for (const m of body.matchAll(CONTRACT_CALL)) {
+    const address = m[1].replace(/\s+/g, '');
+    const mapper = /^&?self\.(\w+)\(/.exec(address);
+    calls.push({
+      target: mapper ? mapper[1] : address,
+      endpoint: m[2] || undefined,
+      ...callMode(code, m.index!),
+    });
+  }
+  return calls;
+}
+
+/** Map of the contract in `source` and its modules, one node per function */
+export function contractArchitecture(
+  source: string,
+  modules: ModuleSources = {}
+): ContractArchitecture {
+  const files: [string | undefined, string][] = [
+    [undefined, source],
+    ...Object.entries(modules).filter(([, text]) => /#\[klever_sc::(contract|module)\]/.test(text)),
+  ];
+  const storage = files.flatMap(([, text]) =>
+    listStorageMappers(text).map(m => ({
+      name: m.functionName,
+      key: m.storageKey,
+      mapper: m.mapper,
+    }))
+  );
+  const events = files.flatMap(([, text]) =>
+    listEvents(text).map(e => ({ name: e.functionName, identifier: e.eventName }))
+  );
+  const declared = files.flatMap(([file, text]) =>
+    contractFunctions(text).map(fn => ({ fn, file }))
+  );
+  // `#[proxy] fn dex_contract(&self, ..) -> dex_proxy::Proxy<Self::Api>` calls into `dex_proxy`
+  const proxies = new Map<string, string>();
+  for (const { fn } of declared.filter(d => d.fn.kind === 'proxy')) {
+    proxies.set(fn.name, /->\s*(\w+)\s*::\s*Proxy\b/.exec(fn.signature)?.[1] || fn.name);
+  }
+  const mapperNames = new Set(storage.map(s => s.name));
+  const eventNames = new Set(events.map(e => e.name));
+  const privateNames = new Set(
+    declared.filter(d => d.fn.kind === 'private').map(d => d.fn.name)
+  );
+
+  const functions: ArchitectureFunction[] = [];
+  for (const { fn, file } of declared) {
+    const abiName = fn.attributes
+      .map(a => /^#\[(?:endpoint|view)\((\w+)\)\]/.exec(a))
+      .find(Boolean)?.[1];
+    // Storage mappers exposed with #[view(name)] are views reading their own storage
+    if (fn.kind === 'storage') {
+      if (abiName) {
+        functions.push({
+          name: abiName,
+          abiName,
+          kind: 'view',
+          file,
+          line: fn.line,
+          reads: [fn.name],
+          writes: [],
+          emits: [],
+          calls: [],
+          helpers: [],
+        });
+      }
+      continue;
+    }
+    if (!SHOWN_KINDS.has(fn.kind)) continue;
+    const code = maskNonCode(fn.body);
+    const accesses = storageAccesses(code, mapperNames);
+    const called = [...code.matchAll(SELF_CALL)].map(m => m[1]);
+    functions.push({
+      name: fn.name,
+      abiName: abiName || fn.name,
+      kind: fn.kind as ArchitectureFunction['kind'],
+      file,
+      line: fn.line,
+      reads: unique(accesses.filter(a => a.access === 'read').map(a => a.mapper)),
+      writes: unique(accesses.filter(a => a.access === 'write').map(a => a.mapper)),
+      emits: unique(called.filter(name => eventNames.has(name))),
+      calls: externalCalls(fn.body, proxies),
+      helpers: unique(called.filter(name => privateNames.has(name) && name !== fn.name)),
+    });
+  }
+  return { contract: findContractTrait(source).name, functions, storage, events };
+}
+
+/** The architecture without private helpers, their effects added to the functions calling them */
+export function foldHelpers(architecture: ContractArchitecture): ContractArchitecture {
+  const byName = new Map(architecture.functions.map(fn => [fn.name, fn]));
+  const fold = (fn: ArchitectureFunction): ArchitectureFunction => {
+    const reached = new Set<string>();
+    const pending = [...fn.helpers];
+    while (pending.length > 0) {
+      const name = pending.pop()!;
+      if (reached.has(name) || !byName.has(name)) continue;
+      reached.add(name);
+      pending.push(...byName.get(name)!.helpers);
+    }
+    const all = [fn, ...[...reached].map(name => byName.get(name)!)];
+    const calls = new Map(
+      all.flatMap(f => f.calls).map(c => [`${c.target}.${c.endpoint}.${c.callback}`, c])
+    );
+    return {
+      ...fn,
+      reads: unique(all.flatMap(f => f.reads)),
+      writes: unique(all.flatMap(f => f.writes)),
+      emits: unique(all.flatMap(f => f.emits)),
+      calls: [...calls.values()],
+      helpers: [...reached],
+    };
+  };
+  return {
+    ...architecture,
+    functions: architecture.functions.filter(fn => fn.kind !== 'private').map(fold),
+  };
+}
+
+interface Node {
+  id: string;
+  label: string;
+  shape: 'endpoint' | 'view' | 'callback' | 'helper' | 'storage' | 'event' | 'contract';
+}
+
+interface Edge {
+  from: string;
+  to: string;
+  label: string;
+  style: 'solid' | 'dashed' | 'thick';
+}
+
+const GROUPS: [string, Node['shape'][]][] = [
+  ['Endpoints', ['endpoint', 'view', 'callback']],
+  ['Helpers', ['helper']],
+  ['Storage', ['storage']],
+  ['Events', ['event']],
+  ['External contracts', ['contract']],
+];
+
+const nodeId = (prefix: string, name: string) => `${prefix}_${name.replace(/[^A-Za-z0-9_]/g, '_')}`;
+
+function graphElements(architecture: ContractArchitecture): { nodes: Node[]; edges: Edge[] } {
+  const nodes = new Map<string, Node>();
+  const edges: Edge[] = [];
+  const fnId = (name: string) => nodeId('fn', name);
+  const add = (node: Node) => {
+    if (!nodes.has(node.id)) nodes.set(node.id, node);
+    return node.id;
+  };
+
+  for (const fn of architecture.functions) {
+    const shape: Node['shape'] =
+      fn.kind === 'private'
+        ? 'helper'
+        : fn.kind === 'view' || fn.kind === 'callback'
+          ? fn.kind
+          : 'endpoint';
+    add({ id: fnId(fn.name), label: fn.abiName, shape });
+  }
+  // Storage and events nothing touches are still part of the map
+  for (const mapper of architecture.storage) {
+    const label = `${mapper.name}: ${mapper.mapper}`;
+    add({ id: nodeId('st', mapper.name), label, shape: 'storage' });
+  }
+  for (const event of architecture.events) {
+    add({ id: nodeId('ev', event.name), label: event.identifier, shape: 'event' });
+  }
+  for (const fn of architecture.functions) {
+    const from = fnId(fn.name);
+    const storage = (name: string) =>
+      add({ id: nodeId('st', name), label: name, shape: 'storage' });
+    for (const name of fn.writes) {
+      const access = fn.reads.includes(name) ? 'read, write' : 'write';
+      edges.push({ from, to: storage(name), label: access, style: 'solid' });
+    }
+    for (const name of fn.reads.filter(name => !fn.writes.includes(name))) {
+      edges.push({ from, to: storage(name), label: 'read', style: 'dashed' });
+    }
+    for (const name of fn.emits) {
+      const id = add({ id: nodeId('ev', name), label: name, shape: 'event' });
+      edges.push({ from, to: id, label: 'emits', style: 'solid' });
+    }
+    for (const call of fn.calls) {
+      const id = add({ id: nodeId('ext', call.target), label: call.target, shape: 'contract' });
+      const label = `${call.endpoint || 'call'}${call.async ? ' (async)' : ''}`;
+      edges.push({ from, to: id, label, style: 'thick' });
+      if (call.callback && nodes.has(fnId(call.callback))) {
+        edges.push({ from: id, to: fnId(call.callback), label: 'callback', style: 'dashed' });
+      }
+    }
+    for (const helper of fn.helpers) {
+      if (nodes.has(fnId(helper))) {
+        edges.push({ from, to: fnId(helper), label: 'calls', style: 'solid' });
+      }
+    }
+  }
+  const seen = new Set<string>();
+  return {
+    nodes: [...nodes.values()],
+    edges: edges.filter(e => {
+      const key = `${e.from}>${e.to}>${e.label}`;
+      if (seen.has(key)) return false;
+      seen.add(key);
+      return true;
+    }),
+  };
+}
+
+const MERMAID_SHAPES: Record<Node['shape'], [string, string]> = {
+  endpoint: ['[', ']'],
+  view: ['([', '])'],
+  callback: ['[/', '/]'],
+  helper: ['(', ')'],
+  storage: ['[(', ')]'],
+  event: ['>', ']'],
+  contract: ['{{', '}}'],
+};
+const MERMAID_ARROWS: Record<Edge['style'], string> = {
+  solid: '-->',
+  dashed: '-.->',
+  thick: '==>',
+};
+const DOT_SHAPES: Record<Node['shape'], string> = {
+  endpoint: 'box',
+  view: 'box, style=rounded',
+  callback: 'parallelogram',
+  helper: 'ellipse',
+  storage: 'cylinder',
+  event: 'note',
+  contract: 'hexagon',
+};
+const DOT_STYLES: Record<Edge['style'], string> = {
+  solid: '',
+  dashed: ', style=dashed',
+  thick: ', penwidth=2',
+};
+
+function mermaid(name: string, nodes: Node[], edges: Edge[]): string {
+  const lines = ['flowchart LR', `    %% ${name}`];
+  GROUPS.forEach(([title, shapes], i) => {
+    const members = nodes.filter(n => shapes.includes(n.shape));
+    if (members.length === 0) return;
+    lines.push(`    subgraph group${i}["${title}"]`);
+    for (const node of members) {
+      const [open, close] = MERMAID_SHAPES[node.shape];
+      lines.push(`        ${node.id}${open}"${node.label.replace(/"/g, '#quot;')}"${close}`);
+    }
+    lines.push('    end');
+  });
+  for (const edge of edges) {
+    lines.push(`    ${edge.from} ${MERMAID_ARROWS[edge.style]}|"${edge.label}"| ${edge.to}`);
+  }
+  return lines.join('\n') + '\n';
+}
+
+function dot(name: string, nodes: Node[], edges: Edge[]): string {
+  const quote = (text: string) => `"${text.replace(/["\\]/g, '\\$&')}"`;
+  const lines = [`digraph ${quote(name)} {`, '    rankdir=LR;'];
+  GROUPS.forEach(([title, shapes], i) => {
+    const members = nodes.filter(n => shapes.includes(n.shape));
+    if (members.length === 0) return;
+    lines.push(`    subgraph cluster_${i} {`, `        label=${quote(title)};`);
+    for (const node of members) {
+      const shape = DOT_SHAPES[node.shape];
+      lines.push(`        ${node.id} [label=${quote(node.label)}, shape=${shape}];`);
+    }
+    lines.push('    }');
+  });
+  for (const edge of edges) {
+    const style = DOT_STYLES[edge.style];
+    lines.push(`    ${edge.from} -> ${edge.to} [label=${quote(edge.label)}${style}];`);
+  }
+  lines.push('}');
+  return lines.join('\n') + '\n';
+}
+
+/** The architecture as a Mermaid flowchart or Graphviz DOT digraph */
+export function architectureGraph(
+  architecture: ContractArchitecture,
+  format: GraphFormat = 'mermaid'
+): string {
+  const { nodes, edges } = graphElements(architecture);
+  return format === 'dot'
+    ? dot(architecture.contract, nodes, edges)
+    : mermaid(architecture.contract, nodes, edges);
+}
@@ -0,0 +1,47 @@
+import { readFileSync } from 'fs';
+import { checkArithmetic } from './arithmetic.js';
+
+const EXAMPLE = readFileSync('examples/token_contract.rs', 'utf8');
+
+const POOL = `#[klever_sc::contract]
+pub trait Pool {
+    #[endpoint]
+    fn remove(&self, amount: BigUint) {
+        let reserve = self.reserve().get();
+        // reserve - amount is checked by the caller
+        self.reserve().set(&(reserve - &amount));
+    }
+
+    #[endpoint]
+    fn decrement(&self) {
+        let count = self.count().get();
+        require!(count > 0, "Nothing to decrement");
+        self.count().set(count - 1);
+    }
+
+    #[endpoint]
+    fn set_slot(&self, value: u64) {
+        let slot = value as u32;
+        let big = self.reserve().get().to_u64().unwrap();
+        self.slot().set(slot + big as u32);
+    }
+}
+`;
+
+describe('checkArithmetic', () => {
+  it('recognizes the guarded subtractions of the token example as safe', () => {
+    expect(checkArithmetic(EXAMPLE)).toEqual([]);
+  });
+
+  it('flags unguarded subtractions, truncating casts and unwrapped conversions', () => {
+    const findings = checkArithmetic(POOL);
+
+    expect(findings.map(f => [f.rule, f.function, f.line])).toEqual([
+      ['unchecked-subtraction', 'remove', 7],
+      ['truncating-cast', 'set_slot', 19],
+      ['truncating-cast', 'set_slot', 21],
+      ['unchecked-conversion', 'set_slot', 20],
+    ]);
+    expect(findings[0].suggestion).toBe('require!(reserve >= amount, "Insufficient reserve");');
+  });
+});
@@ -0,0 +1,200 @@
+/**
+ * Arithmetic hazards in BigUint/u64 code: subtractions that can underflow
+ * (BigUint panics, unsigned integers wrap or panic) without a preceding
+ * bound check, and `as` casts that silently truncate.
+ *
+ * A subtraction `a - b` is safe after `require!(a >= b, ...)` (or `a > b`,
+ * `b <= a`, an equivalent `if`). A compound `x -= b` is also accepted once `b`
+ * has been bounded by any value, which is the token `burn` pattern: the
+ * balance check on `amount` also covers `total_supply -= amount`.
+ */
+
+import { contractFunctions, lineAt, maskNonCode } from './source.js';
+import type { AnalysisCheck, Finding } from './types.js';
+
+interface Subtraction {
+  index: number;
+  left: string;
+  right: string;
+  compound: boolean;
+}
+
+interface Condition {
+  index: number;
+  text: string;
+}
+
+const NARROWING_CAST = /\bas\s+(u8|u16|u32|usize|i8|i16|i32|isize)\b/g;
+
+/** Canonical operand text: no borrows, derefs, clones, spacing or literal suffixes */
+function normalize(operand: string): string {
+  let text = operand
+    .replace(/\s+/g, '')
+    .replace(/\.clone\(\)/g, '')
+    .replace(/^[&*]+/, '')
+    .replace(/^(\d[\d_]*)(u8|u16|u32|u64|usize|i32|i64)?$/, '$1');
+  while (/^\(.*\)$/.test(text)) text = text.slice(1, -1).replace(/^[&*]+/, '');
+  return text;
+}
+
+function operandBefore(text: string, end: number): string {
+  let i = end - 1;
+  while (i >= 0 && /\s/.test(text[i])) i--;
+  const stop = i + 1;
+  while (i >= 0) {
+    if (text[i] === ')') {
+      let depth = 0;
+      for (; i >= 0; i--) {
+        if (text[i] === ')') depth++;
+        else if (text[i] === '(' && --depth === 0) break;
+      }
+      i--;
+    } else if (/[\w.&*]/.test(text[i])) {
+      i--;
+    } else {
+      break;
+    }
+  }
+  return text.slice(i + 1, stop);
+}
+
+function operandAfter(text: string, start: number): string {
+  let i = start;
+  while (i < text.length && /\s/.test(text[i])) i++;
+  const begin = i;
+  while (i < text.length) {
+    if (text[i] === '(') {
+      let depth = 0;
+      for (; i < text.length; i++) {
+        if (text[i] === '(') depth++;
+        else if (text[i] === ')' && --depth === 0) break;
+      }
+      i++;
+    } else if (/[\w.&*:]/.test(text[i])) {
+      i++;
+    } else {
+      break;
+    }
+  }
+  return text.slice(begin, i);
+}
+
+function subtractions(body: string): Subtraction[] {
+  const found: Subtraction[] = [];
+  // Binary minus is written with spaces on both sides; `->` and negative literals are not
+  for (const match of body.matchAll(/\s(-=?)\s/g)) {
+    const operator = match.index! + 1;
+    const left = operandBefore(body, match.index!);
+    const right = operandAfter(body, operator + match[1].length);
+    if (!left || !right) continue;
+    if (/^\d/.test(normalize(left)) && /^\d/.test(normalize(right))) continue;
+    found.push({ index: operator, left, right, compound: match[1] === '-=' });
+  }
+  return found;
+}
+
+function conditions(body: string): Condition[] {
+  const found: Condition[] = [];
+  for (const match of body.matchAll(/require!\s*\(([\s\S]*?),\s*"/g)) {
+    found.push({ index: match.index!, text: match[1] });
+  }
+  for (const match of body.matchAll(/\b(?:if|while)\s+([^{]+)\{/g)) {
+    found.push({ index: match.index!, text: match[1] });
+  }
+  return found;
+}
+
+/** Comparisons in a condition as [greater, smaller] pairs of normalized operands */
+function bounds(condition: string): [string, string][] {
+  const pairs: [string, string][] = [];
+  for (const part of condition.split(/&&/)) {
+    const match = /^(.+?)\s*(>=|<=|>|<)\s*(.+)$/.exec(part.trim());
+    if (!match) continue;
+    const [, a, op, b] = match;
+    const pair: [string, string] = op.startsWith('>') ? [a, b] : [b, a];
+    pairs.push([normalize(pair[0]), normalize(pair[1])]);
+  }
+  return pairs;
+}
+
+function isGuarded(sub: Subtraction, guards: Condition[]): boolean {
+  const left = normalize(sub.left);
+  const right = normalize(sub.right);
+  const pairs = guards.filter(g => g.index < sub.index).flatMap(g => bounds(g.text));
+  if (pairs.some(([big, small]) => big === left && small === right)) return true;
+  if (right === '1' && pairs.some(([big, small]) => big === left && small === '0')) return true;
+  return sub.compound && pairs.some(([, small]) => small === right);
+}
+
+export function checkArithmetic(source: string): Finding[] {
+  const findings: Finding[] = [];
+  for (const fn of contractFunctions(source)) {
+    if (!fn.body || fn.kind === 'storage' || fn.kind === 'event') continue;
+    const line = (offset: number) => lineAt(source, fn.bodyStart + offset);
+    const code = maskNonCode(fn.body);
+    const guards = conditions(code);
+
+    for (const sub of subtractions(code)) {
+      if (isGuarded(sub, guards)) continue;
+      const left = normalize(sub.left);
+      const right = normalize(sub.right);
+      findings.push({
+        rule: 'unchecked-subtraction',
+        severity: 'warning',
+        message: `${fn.name}: \`${sub.left.trim()} ${sub.compound ? '-=' : '-'} ${sub.right}\` can underflow; no preceding check that ${left} >= ${right}`,
+        function: fn.name,
+        line: line(sub.index),
+        suggestion: `require!(${left} >= ${right}, "Insufficient ${left.split('.').pop()}");`,
+      });
+    }
+
+    for (const match of code.matchAll(NARROWING_CAST)) {
+      const operand = operandBefore(code, match.index!);
+      if (/^\d/.test(normalize(operand))) continue;
+      findings.push({
+        rule: 'truncating-cast',
+        severity: 'warning',
+        message: `${fn.name}: \`${operand} as ${match[1]}\` silently truncates values that do not fit`,
+        function: fn.name,
+        line: line(match.index!),
+        suggestion: `Use ${match[1]}::try_from(${operand}) and require! the result, or keep the wider type.`,
+      });
+    }
+
+    for (const match of code.matchAll(/\.to_u64\(\)\s*\.\s*unwrap\(\)/g)) {
+      findings.push({
+        rule: 'unchecked-conversion',
+        severity: 'info',
+        message: `${fn.name}: BigUint::to_u64().unwrap() panics for values above u64::MAX`,
+        function: fn.name,
+        line: line(match.index!),
+        suggestion:
+          'Handle the None case with a clear error, e.g. .unwrap_or_else(|| sc_panic!("Value too large")).',
+      });
+    }
+  }
+  return findings;
+}
+
+export const arithmeticCheck: AnalysisCheck = {
+  name: 'arithmetic',
+  description: 'Subtractions that can underflow without a bound check, and truncating casts',
+  rules: [
+    {
+      id: 'unchecked-subtraction',
+      severity: 'warning',
+      description: 'Subtraction that can underflow without a preceding bound check',
+    },
+    {
+      id: 'truncating-cast',
+      severity: 'warning',
+      description: '`as` cast to a narrower integer type that silently truncates',
+    },
+    {
+      id: 'unchecked-conversion',
+      severity: 'info',
+      description: 'BigUint to fixed-width conversion that fails when the value does not fit',
+    },
+  ],
+  run: checkArithmetic,
+};
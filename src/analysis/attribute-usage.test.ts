@@ -0,0 +1,116 @@
+import { readFileSync } from 'fs';
+import { checkAttributeUsage } from './attribute-usage.js';
+import { CONTRACT_ATTRIBUTES, findContractAttribute } from '../knowledge/index.js';
+import { CONTRACT_TEMPLATES, generateContractProject } from '../scaffold/index.js';
+
+const EXAMPLE = readFileSync('examples/token_contract.rs', 'utf8');
+
+const MISUSED = `#[klever_sc::contract]
+pub trait Market {
+    #[init]
+    fn init(&self) {}
+
+    #[onlyowner]
+    #[endpoint]
+    fn set_fee(&self, fee: BigUint) {
+        self.fee().set(fee);
+    }
+
+    #[payable(KLV)]
+    #[endpoint(buy)]
+    fn buy(&self) {}
+
+    #[endpoint]
+    #[view(getFee)]
+    fn get_fee(&self) -> BigUint {
+        self.fee().get()
+    }
+
+    #[payable("*")]
+    fn collect(&self) {}
+
+    #[view(getPrice)]
+    fn get_price(&self) -> BigUint;
+
+    #[storage_mapper("fee")]
+    fn fee(&self) -> SingleValueMapper<BigUint> {
+        SingleValueMapper::new()
+    }
+
+    #[endpoint]
+    fn log(&self, #[indexed] value: u64) {}
+
+    #[event("sale")]
+    fn sale_event(&self, #[indexed] buyer: &ManagedAddress, price: &BigUint, fee: &BigUint);
+
+    #[inline]
+    fn helper(&self) {}
+}
+`;
+
+describe('checkAttributeUsage', () => {
+  it('flags typos, bad arguments, conflicts, misplaced attributes and bodies', () => {
+    const findings = checkAttributeUsage(MISUSED);
+    expect(findings.map(f => [f.rule, f.function, f.line])).toEqual([
+      ['unknown-attribute', 'set_fee', 6],
+      ['attribute-argument', 'buy', 12],
+      ['attribute-conflict', 'get_fee', 17],
+      ['attribute-placement', 'collect', 22],
+      ['attribute-body', 'get_price', 26],
+      ['attribute-body', 'fee', 29],
+      ['attribute-placement', 'log', 34],
+      ['event-data', 'sale_event', 37],
+    ]);
+    expect(findings[0].severity).toBe('warning');
+    expect(findings[0].suggestion).toContain('Did you mean #[only_owner]?');
+    expect(findings[1].message).toContain('needs a non-empty string literal argument');
+    expect(findings[7].message).toContain('(price, fee)');
+  });
+
+  it('rejects a quoted exported name', () => {
+    const source = `#[klever_sc::contract]
+pub trait Named {
+    #[endpoint("doIt")]
+    fn do_it(&self) {}
+}
+`;
+    const [finding] = checkAttributeUsage(source);
+    expect(finding.rule).toBe('attribute-argument');
+    expect(finding.message).toContain('without quotes');
+  });
+
+  it('accepts the example contract and every scaffold template', () => {
+    expect(checkAttributeUsage(EXAMPLE)).toEqual([]);
+    for (const template of CONTRACT_TEMPLATES) {
+      const { files } = generateContractProject(template.name, template.name);
+      const sources = Object.values(files).filter(text =>
+        /#\[klever_sc::(contract|module)\]/.test(text)
+      );
+      for (const source of sources) {
+        expect(checkAttributeUsage(source)).toEqual([]);
+      }
+    }
+  });
+});
+
+describe('CONTRACT_ATTRIBUTES', () => {
+  it('documents rules for every attribute and only refers to known ones', () => {
+    const names = new Set(CONTRACT_ATTRIBUTES.map(a => a.name));
+    expect(names.size).toBe(CONTRACT_ATTRIBUTES.length);
+    for (const attribute of CONTRACT_ATTRIBUTES) {
+      expect(attribute.rules.length).toBeGreaterThan(0);
+      expect(attribute.example).toContain(`#[${attribute.name}`);
+      const related = [...(attribute.requiresOneOf || []), ...(attribute.conflictsWith || [])];
+      for (const other of related) {
+        expect(names).toContain(other);
+      }
+    }
+  });
+
+  it('finds an attribute as written in source', () => {
+    expect(findContractAttribute('#[payable("KLV")]')?.name).toBe('payable');
+    expect(findContractAttribute('#[view(getFee)]')?.name).toBe('view');
+    expect(findContractAttribute('indexed')?.target).toBe('parameter');
+    expect(findContractAttribute('#[nonexistent]')).toBeUndefined();
+  });
+});
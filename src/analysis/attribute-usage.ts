@@ -0,0 +1,262 @@
+/**
+ * Attribute usage: misspelled, misplaced, conflicting or malformed klever-sc
+ * attributes on trait functions and their parameters, checked against the
+ * attribute catalog. Most of these fail the build with a macro error that does
+ * not point at the cause; some (a typo in `#[only_owner]`) compile and silently
+ * drop the check.
+ */
+
+import { parseParams } from '../codegen/rust-source.js';
+import { CONTRACT_ATTRIBUTES, type ContractAttribute } from '../knowledge/index.js';
+import { contractFunctions, lineAt } from './source.js';
+import type { AnalysisCheck, Finding } from './types.js';
+
+/** Rust attributes that are valid on trait functions */
+const RUST_ATTRIBUTES = new Set([
+  'allow',
+  'warn',
+  'deny',
+  'expect',
+  'inline',
+  'cfg',
+  'cfg_attr',
+  'doc',
+  'must_use',
+  'deprecated',
+  'track_caller',
+  'rustfmt::skip',
+]);
+
+const BY_NAME = new Map(CONTRACT_ATTRIBUTES.map(a => [a.name, a]));
+
+const ENTRY_POINTS = new Set([
+  'init',
+  'upgrade',
+  'endpoint',
+  'view',
+  'callback',
+  'promises_callback',
+]);
+
+interface ParsedAttribute {
+  text: string;
+  name: string;
+  /** Text between the parentheses; undefined without them */
+  argument?: string;
+}
+
+function parseAttribute(text: string): ParsedAttribute | undefined {
+  const match = /^#\[\s*([\w:]+)\s*(?:\(([\s\S]*)\))?\s*\]/.exec(text);
+  return match ? { text, name: match[1], argument: match[2]?.trim() } : undefined;
+}
+
+/** Levenshtein distance, for suggesting the name a typo was meant to be */
+export function editDistance(a: string, b: string): number {
+  let previous = Array.from({ length: b.length + 1 }, (_, i) => i);
+  for (let i = 1; i <= a.length; i++) {
+    const current = [i];
+    for (let j = 1; j <= b.length; j++) {
+      const cost = a[i - 1] === b[j - 1] ? 0 : 1;
+      current[j] = Math.min(previous[j] + 1, current[j - 1] + 1, previous[j - 1] + cost);
+    }
+    previous = current;
+  }
+  return previous[b.length];
+}
+
+/** The catalog attribute `name` is most likely a typo of, if any */
+function closestAttribute(name: string): ContractAttribute | undefined {
+  const ranked = CONTRACT_ATTRIBUTES.filter(a => a.target === 'function')
+    .map(a => ({ attribute: a, distance: editDistance(name, a.name) }))
+    .sort((a, b) => a.distance - b.distance);
+  return ranked[0] && ranked[0].distance <= 2 ? ranked[0].attribute : undefined;
+}
+
+/** Why `argument` does not fit the attribute, or undefined when it does */
+function argumentProblem(attribute: ContractAttribute, argument?: string): string | undefined {
+  switch (attribute.argument) {
+    case 'none':
+      return argument === undefined ? undefined : 'takes no argument';
+    case 'optional-name':
+      if (argument === undefined || /^\w+$/.test(argument)) return undefined;
+      return argument.startsWith('"')
+        ? 'takes the exported name as an identifier, without quotes'
+        : 'takes an identifier as the exported name';
+    case 'string':
+      return argument !== undefined && /^"(?:[^"\\]|\\.)+"$/.test(argument)
+        ? undefined
+        : 'needs a non-empty string literal argument';
+  }
+}
+
+function fixFor(attribute: ContractAttribute): string {
+  return `Use ${attribute.syntax}: ${attribute.rules.join('; ')}.`;
+}
+
+export function checkAttributeUsage(source: string): Finding[] {
+  const findings: Finding[] = [];
+  for (const fn of contractFunctions(source)) {
+    const attributes = fn.attributes.flatMap(text => parseAttribute(text) ?? []);
+    const names = new Set(attributes.map(a => a.name));
+    const at = (text: string) => {
+      const index = source.indexOf(text, fn.start);
+      return index === -1 || index > fn.end ? fn.line : lineAt(source, index);
+    };
+    const report = (rule: string, line: number, message: string, suggestion: string) =>
+      findings.push({
+        rule,
+        severity: rule === 'unknown-attribute' ? 'warning' : 'error',
+        message,
+        function: fn.name,
+        line,
+        suggestion,
+      });
+
+    for (const parsed of attributes) {
+      const attribute = BY_NAME.get(parsed.name);
+      if (!attribute) {
+        if (RUST_ATTRIBUTES.has(parsed.name)) continue;
+        const closest = closestAttribute(parsed.name);
+        report(
+          'unknown-attribute',
+          at(parsed.text),
+          `Unknown attribute #[${parsed.name}] on ${fn.name}`,
+          closest
+            ? `Did you mean ${closest.syntax}? Unknown attributes are rejected or silently ignored.`
+            : 'Check the spelling against the klever://reference/attributes resource.'
+        );
+        continue;
+      }
+      if (attribute.target !== 'function') {
+        report(
+          'attribute-placement',
+          at(parsed.text),
+          `#[${parsed.name}] belongs on ${attribute.target === 'parameter' ? 'a parameter' : `a ${attribute.target}`}, not on function ${fn.name}`,
+          fixFor(attribute)
+        );
+        continue;
+      }
+      const problem = argumentProblem(attribute, parsed.argument);
+      if (problem) {
+        report(
+          'attribute-argument',
+          at(parsed.text),
+          `${parsed.text} on ${fn.name}: #[${attribute.name}] ${problem}`,
+          fixFor(attribute)
+        );
+      }
+      const required = attribute.requiresOneOf;
+      if (required && !required.some(name => names.has(name))) {
+        report(
+          'attribute-placement',
+          at(parsed.text),
+          `#[${attribute.name}] on ${fn.name} needs one of ${required.map(r => `#[${r}]`).join(', ')}`,
+          fixFor(attribute)
+        );
+      }
+    }
+
+    // Each conflicting pair once, reported on the later attribute
+    for (const [i, parsed] of attributes.entries()) {
+      const conflict = attributes
+        .slice(0, i)
+        .find(earlier => BY_NAME.get(parsed.name)?.conflictsWith?.includes(earlier.name));
+      if (conflict) {
+        report(
+          'attribute-conflict',
+          at(parsed.text),
+          `${fn.name} has both #[${conflict.name}] and #[${parsed.name}], which cannot be combined`,
+          'Keep one of them; split the function in two if it needs both roles.'
+        );
+      }
+    }
+
+    const declaration = attributes.find(a => BY_NAME.get(a.name)?.declaration);
+    const entryPoint = attributes.find(a => ENTRY_POINTS.has(a.name));
+    if (declaration && fn.bodyStart !== -1) {
+      report(
+        'attribute-body',
+        fn.line,
+        `${fn.name} is declared with #[${declaration.name}] but has a body; the framework generates it`,
+        'Remove the body and end the declaration with a semicolon.'
+      );
+    } else if (!declaration && entryPoint && fn.bodyStart === -1) {
+      report(
+        'attribute-body',
+        fn.line,
+        `#[${entryPoint.name}] ${fn.name} has no body`,
+        entryPoint.name === 'view'
+          ? 'Add a body, or add #[storage_mapper("key")] to expose a mapper as a view.'
+          : 'Add a body with the function logic.'
+      );
+    }
+
+    const params = parseParams(fn.signature);
+    for (const param of params) {
+      for (const name of param.attributes) {
+        const attribute = BY_NAME.get(name);
+        if (!attribute || attribute.target !== 'parameter') continue;
+        const required = attribute.requiresOneOf || [];
+        if (required.length > 0 && !required.some(r => names.has(r))) {
+          report(
+            'attribute-placement',
+            fn.line,
+            `#[${name}] on parameter ${param.name} of ${fn.name} needs the function to have one of ${required.map(r => `#[${r}]`).join(', ')}`,
+            fixFor(attribute)
+          );
+        }
+      }
+    }
+    if (names.has('event')) {
+      const data = params.filter(p => !p.attributes.includes('indexed'));
+      if (data.length > 1) {
+        report(
+          'event-data',
+          fn.line,
+          `Event ${fn.name} has ${data.length} non-indexed parameters (${data.map(p => p.name).join(', ')}); only one can be the event data`,
+          'Mark all but one parameter #[indexed], or group the data in a struct.'
+        );
+      }
+    }
+  }
+  return findings.sort((a, b) => a.line - b.line);
+}
+
+export const attributeUsageCheck: AnalysisCheck = {
+  name: 'attribute-usage',
+  description: 'Misspelled, misplaced, conflicting or malformed klever-sc attributes',
+  rules: [
+    {
+      id: 'unknown-attribute',
+      severity: 'warning',
+      description: 'Attribute that is neither a klever-sc nor a common Rust attribute, e.g. a typo',
+    },
+    {
+      id: 'attribute-argument',
+      severity: 'error',
+      description: 'Missing, extra or malformed attribute argument, e.g. #[payable(KLV)]',
+    },
+    {
+      id: 'attribute-placement',
+      severity: 'error',
+      description:
+        'Attribute without the attribute it depends on, e.g. #[payable] on a helper or #[indexed] outside an event',
+    },
+    {
+      id: 'attribute-conflict',
+      severity: 'error',
+      description: 'Attributes that cannot be combined, e.g. #[endpoint] and #[view]',
+    },
+    {
+      id: 'attribute-body',
+      severity: 'error',
+      description: 'Declaration attribute on a function with a body, or an endpoint without one',
+    },
+    {
+      id: 'event-data',
+      severity: 'error',
+      description: 'Event with more than one non-indexed parameter',
+    },
+  ],
+  run: checkAttributeUsage,
+};
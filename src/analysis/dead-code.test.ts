@@ -0,0 +1,56 @@
+import { readFileSync } from 'fs';
+import { checkDeadCode } from './dead-code.js';
+
+const EXAMPLE = readFileSync('examples/token_contract.rs', 'utf8');
+
+const LIB = `#[klever_sc::contract]
+pub trait Counter: storage::StorageModule {
+    #[endpoint]
+    fn increment(&self) {
+        self.count().update(|c| *c += 1u64);
+        self.last_caller().set(self.blockchain().get_caller());
+    }
+
+    // self.helper() is only mentioned in this comment
+    fn helper(&self) -> u64 {
+        42
+    }
+
+    #[event("incremented")]
+    fn incremented_event(&self, #[indexed] by: &ManagedAddress);
+
+    #[storage_mapper("lastCaller")]
+    fn last_caller(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("legacy")]
+    fn legacy(&self) -> SingleValueMapper<u64>;
+}
+`;
+
+const STORAGE = `#[klever_sc::module]
+pub trait StorageModule {
+    #[storage_mapper("count")]
+    fn count(&self) -> SingleValueMapper<u64>;
+
+    #[view(getLimit)]
+    #[storage_mapper("limit")]
+    fn limit(&self) -> SingleValueMapper<u64>;
+}
+`;
+
+describe('checkDeadCode', () => {
+  it('finds nothing to remove in the token example', () => {
+    expect(checkDeadCode(EXAMPLE, {})).toEqual([]);
+  });
+
+  it('reports unused mappers, events and functions across modules', () => {
+    const findings = checkDeadCode(LIB, { 'src/storage.rs': STORAGE });
+
+    expect(findings.map(f => [f.rule, f.function])).toEqual([
+      ['unused-function', 'helper'],
+      ['unused-event', 'incremented_event'],
+      ['write-only-storage', 'last_caller'],
+      ['unused-storage', 'legacy'],
+    ]);
+  });
+});
@@ -0,0 +1,111 @@
+/**
+ * Unused code across the contract and its modules: storage mappers that are
+ * never read, events that are never emitted and private functions that are
+ * never called. Each one still costs wasm size.
+ */
+
+import { contractFunctions, maskNonCode, type ContractFunction } from './source.js';
+import type { AnalysisCheck, Finding, ModuleSources } from './types.js';
+
+interface Declared {
+  fn: ContractFunction;
+  file?: string;
+}
+
+/** Mapper methods that only write; any other use of the mapper reads it */
+const WRITE_ONLY = /^\s*\.\s*(set|clear|set_if_empty)\s*\(/;
+
+function hasTrait(text: string): boolean {
+  return /#\[klever_sc::(contract|module)\]/.test(text);
+}
+
+export function checkDeadCode(source: string, modules: ModuleSources): Finding[] {
+  const files: [string | undefined, string][] = [
+    [undefined, source],
+    ...Object.entries(modules).filter(([, text]) => hasTrait(text)),
+  ];
+  const declared: Declared[] = files.flatMap(([file, text]) =>
+    contractFunctions(text).map(fn => ({ fn, file }))
+  );
+  // Only function bodies contain uses; declarations and attributes do not
+  const code = files
+    .flatMap(([, text]) => contractFunctions(text).map(fn => maskNonCode(fn.body)))
+    .join('\n');
+
+  const findings: Finding[] = [];
+  for (const { fn, file } of declared) {
+    const uses = [...code.matchAll(new RegExp(`\\bself\\s*\\.\\s*${fn.name}\\s*\\(`, 'g'))];
+    const at = { function: fn.name, file, line: fn.line };
+
+    if (fn.kind === 'storage') {
+      if (fn.attributes.some(a => /^#\[view[(\]]/.test(a))) continue;
+      if (uses.length === 0) {
+        findings.push({
+          rule: 'unused-storage',
+          severity: 'warning',
+          message: `Storage mapper ${fn.name}() is never used`,
+          ...at,
+          suggestion: `Remove ${fn.name}(), or expose it with #[view] if clients read it.`,
+        });
+        continue;
+      }
+      // Skip the argument list of the mapper call, then look at the method applied to it
+      const reads = uses.filter(use => {
+        let i = use.index! + use[0].length;
+        for (let depth = 1; depth > 0 && i < code.length; i++) {
+          if (code[i] === '(') depth++;
+          else if (code[i] === ')') depth--;
+        }
+        return !WRITE_ONLY.test(code.slice(i));
+      });
+      if (reads.length === 0) {
+        findings.push({
+          rule: 'write-only-storage',
+          severity: 'info',
+          message: `Storage mapper ${fn.name}() is written but never read`,
+          ...at,
+          suggestion: 'Read it somewhere, add #[view] so clients can query it, or drop the writes.',
+        });
+      }
+    } else if (fn.kind === 'event' && uses.length === 0) {
+      findings.push({
+        rule: 'unused-event',
+        severity: 'warning',
+        message: `Event ${fn.name}() is never emitted`,
+        ...at,
+        suggestion: 'Emit it where the state change happens, or remove it.',
+      });
+    } else if ((fn.kind === 'private' || fn.kind === 'proxy') && uses.length === 0) {
+      findings.push({
+        rule: 'unused-function',
+        severity: 'warning',
+        message: `${fn.kind === 'proxy' ? 'Proxy accessor' : 'Function'} ${fn.name}() is never called`,
+        ...at,
+        suggestion: `Remove ${fn.name}().`,
+      });
+    }
+  }
+  return findings;
+}
+
+export const deadCodeCheck: AnalysisCheck = {
+  name: 'dead-code',
+  description:
+    'Storage mappers never read, events never emitted and private functions never called',
+  crossModule: true,
+  rules: [
+    { id: 'unused-storage', severity: 'warning', description: 'Storage mapper that is never used' },
+    {
+      id: 'write-only-storage',
+      severity: 'info',
+      description: 'Storage mapper that is written but never read',
+    },
+    { id: 'unused-event', severity: 'warning', description: 'Event that is never emitted' },
+    {
+      id: 'unused-function',
+      severity: 'warning',
+      description: 'Private function that is never called',
+    },
+  ],
+  run: checkDeadCode,
+};
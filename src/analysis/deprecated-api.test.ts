@@ -0,0 +1,101 @@
+import { readFileSync } from 'fs';
+import { checkDeprecatedApis, fixDeprecatedApis, kleverScVersion } from './deprecated-api.js';
+
+const EXAMPLE = readFileSync('examples/token_contract.rs', 'utf8');
+
+const LEGACY = `#![no_std]
+
+klever_sc::imports!();
+
+#[klever_sc::contract]
+pub trait Legacy {
+    #[endpoint]
+    fn register(&self, #[var_args] names: VarArgs<ManagedBuffer>) -> SCResult<()> {
+        // OptionalArg is only mentioned in this comment
+        if names.is_empty() {
+            return sc_error!("No names");
+        }
+        Ok(())
+    }
+
+    #[view]
+    fn pair(&self, limit: OptionalArg<u32>) -> MultiResult2<u32, u32> {
+        (1, 2).into()
+    }
+}
+`;
+
+const CARGO = (version: string) => `[package]
+name = "legacy"
+version = "0.0.0"
+
+[dependencies.klever-sc]
+version = "${version}"
+`;
+
+describe('kleverScVersion', () => {
+  it('reads the version from the usual Cargo.toml forms', () => {
+    expect(kleverScVersion(CARGO('0.44.1'))).toBe('0.44.1');
+    expect(kleverScVersion('[dependencies]\nklever-sc = "^0.45.0"\n')).toBe('0.45.0');
+    expect(kleverScVersion('[dependencies]\nklever-sc = { version = "=0.43.2" }\n')).toBe(
+      '0.43.2'
+    );
+    expect(kleverScVersion('[dependencies]\nserde = "1"\n')).toBeUndefined();
+  });
+});
+
+describe('checkDeprecatedApis', () => {
+  it('flags removed and deprecated APIs outside comments', () => {
+    const findings = checkDeprecatedApis(LEGACY, { 'Cargo.toml': CARGO('0.45.0') });
+    expect(findings.map(f => [f.rule, f.line, f.message])).toEqual([
+      ['deprecated-api', 3, 'klever_sc::imports!() is deprecated in klever-sc 0.45.0'],
+      ['removed-api', 8, '#[var_args] no longer exists in klever-sc 0.45.0'],
+      [
+        'removed-api',
+        8,
+        'VarArgs / MultiArgVec / MultiResultVec no longer exists in klever-sc 0.45.0',
+      ],
+      ['removed-api', 8, 'SCResult<T> no longer exists in klever-sc 0.45.0'],
+      ['removed-api', 11, 'sc_error! no longer exists in klever-sc 0.45.0'],
+      ['removed-api', 17, 'OptionalArg / OptionalResult no longer exists in klever-sc 0.45.0'],
+      ['removed-api', 17, 'MultiArgN / MultiResultN no longer exists in klever-sc 0.45.0'],
+    ]);
+    expect(findings[2].suggestion).toBe('Replace with MultiValueEncoded');
+  });
+
+  it('only applies changes made after the declared version', () => {
+    const findings = checkDeprecatedApis(LEGACY, { 'Cargo.toml': CARGO('0.44.0') });
+    expect(findings.some(f => f.rule === 'deprecated-api')).toBe(false);
+    expect(findings[findings.length - 1]).toEqual({
+      rule: 'outdated-framework',
+      severity: 'info',
+      message: 'Cargo.toml pins klever-sc 0.44.0; generated code and templates target 0.45.0',
+      file: 'Cargo.toml',
+      line: 6,
+      suggestion:
+        'Update klever-sc, klever-sc-scenario, klever-sc-meta and klever-sc-wasm-adapter to 0.45.0 together.',
+    });
+  });
+
+  it('reports nothing for the example token contract', () => {
+    expect(checkDeprecatedApis(EXAMPLE, {})).toEqual([]);
+  });
+});
+
+describe('fixDeprecatedApis', () => {
+  it('rewrites mechanical replacements and leaves the rest as findings', () => {
+    const result = fixDeprecatedApis(LEGACY, { 'Cargo.toml': CARGO('0.45.0') });
+    const lib = result.files['src/lib.rs'];
+    expect(lib).toContain('\nuse klever_sc::imports::*;\n');
+    expect(lib).toContain(
+      'fn register(&self, names: MultiValueEncoded<ManagedBuffer>) -> SCResult<()> {'
+    );
+    expect(lib).toContain('fn pair(&self, limit: OptionalValue<u32>) -> MultiValue2<u32, u32> {');
+    expect(lib).toContain('// OptionalArg is only mentioned in this comment');
+    expect(result.fixed).toBe(5);
+    expect(result.remaining.map(f => [f.line, f.message])).toEqual([
+      [8, 'SCResult<T> no longer exists in klever-sc 0.45.0'],
+      [11, 'sc_error! no longer exists in klever-sc 0.45.0'],
+    ]);
+  });
+});
@@ -0,0 +1,234 @@
+/**
+ * Deprecated and removed klever-sc APIs: legacy macros and the pre-rename
+ * argument/result types inherited from older framework code, checked against
+ * the klever-sc version declared in Cargo.toml. Most have a mechanical
+ * replacement that fixDeprecatedApis applies.
+ */
+
+import {
+  KLEVER_SC_VERSION,
+  compareVersions,
+  kleverScVersion,
+} from '../scaffold/framework-version.js';
+import type { FileMap } from '../scaffold/types.js';
+import { lineAt, maskNonCode } from './source.js';
+import type { AnalysisCheck, Finding, ModuleSources } from './types.js';
+
+export interface ApiChange {
+  /** API as users know it, e.g. `SCResult<T>` */
+  name: string;
+  pattern: RegExp;
+  status: 'deprecated' | 'removed';
+  /** First klever-sc version the change applies to; omitted when it predates every release */
+  since?: string;
+  replacement: string;
+  /** Replacement applied by fixDeprecatedApis; omitted when the change needs a manual edit */
+  rewrite?: string;
+}
+
+export const API_CHANGES: ApiChange[] = [
+  {
+    name: 'klever_sc::imports!()',
+    pattern: /\bklever_sc::imports!\s*\(\s*\)\s*;/g,
+    status: 'deprecated',
+    since: '0.45.0',
+    replacement: 'use klever_sc::imports::*;',
+    rewrite: 'use klever_sc::imports::*;',
+  },
+  {
+    name: 'klever_sc::derive_imports!()',
+    pattern: /\bklever_sc::derive_imports!\s*\(\s*\)\s*;/g,
+    status: 'deprecated',
+    since: '0.45.0',
+    replacement: 'use klever_sc::derive_imports::*;',
+    rewrite: 'use klever_sc::derive_imports::*;',
+  },
+  {
+    name: 'SCResult<T>',
+    pattern: /\bSCResult\s*</g,
+    status: 'removed',
+    replacement: 'Return T directly, fail with require!/sc_panic! and drop the Ok(...) wrapper',
+  },
+  {
+    name: 'sc_error!',
+    pattern: /\bsc_error!\s*\(/g,
+    status: 'removed',
+    replacement: 'sc_panic!(...) (it never returns, so drop the surrounding return/Err)',
+  },
+  {
+    name: 'OptionalArg / OptionalResult',
+    pattern: /\bOptional(?:Arg|Result)\b/g,
+    status: 'removed',
+    replacement: 'OptionalValue',
+    rewrite: 'OptionalValue',
+  },
+  {
+    name: 'VarArgs / MultiArgVec / MultiResultVec',
+    pattern: /\b(?:ManagedVarArgs|VarArgs|MultiArgVec|MultiResultVec|ManagedMultiResultVec)\b/g,
+    status: 'removed',
+    replacement: 'MultiValueEncoded',
+    rewrite: 'MultiValueEncoded',
+  },
+  {
+    name: 'MultiArgN / MultiResultN',
+    pattern: /\bMulti(?:Arg|Result)(\d)\b/g,
+    status: 'removed',
+    replacement: 'MultiValueN',
+    rewrite: 'MultiValue$1',
+  },
+  {
+    name: '#[var_args]',
+    pattern: /#\[var_args\]\s*/g,
+    status: 'removed',
+    replacement: 'nothing: variadic arguments are recognised from their type',
+    rewrite: '',
+  },
+];
+
+export interface DeprecatedApiOptions {
+  /** Contents of the contract Cargo.toml; also read from modules['Cargo.toml'] */
+  cargoToml?: string;
+}
+
+export interface DeprecatedApiFix {
+  /** Files that changed, keyed by path (the contract source as src/lib.rs) */
+  files: FileMap;
+  /** Number of replacements applied */
+  fixed: number;
+  /** Findings that still need a manual edit */
+  remaining: Finding[];
+}
+
+export { kleverScVersion };
+
+/** Changes that apply to `version`; every change applies when the version is unknown */
+function applicable(version: string | undefined): ApiChange[] {
+  return API_CHANGES.filter(
+    change => !change.since || !version || compareVersions(version, change.since) >= 0
+  );
+}
+
+function sourceFiles(source: string, modules: ModuleSources): [string | undefined, string][] {
+  return [
+    [undefined, source],
+    ...Object.entries(modules).filter(([file]) => file.endsWith('.rs')),
+  ];
+}
+
+interface Match {
+  change: ApiChange;
+  index: number;
+  text: string;
+}
+
+function matchesIn(text: string, changes: ApiChange[]): Match[] {
+  const code = maskNonCode(text);
+  return changes
+    .flatMap(change =>
+      [...code.matchAll(change.pattern)].map(m => ({
+        change,
+        index: m.index!,
+        text: text.slice(m.index!, m.index! + m[0].length),
+      }))
+    )
+    .sort((a, b) => a.index - b.index);
+}
+
+function finding(match: Match, text: string, file: string | undefined, version?: string): Finding {
+  const { change } = match;
+  const removed = change.status === 'removed';
+  const where = version ? `klever-sc ${version}` : 'current klever-sc';
+  return {
+    rule: removed ? 'removed-api' : 'deprecated-api',
+    severity: removed ? 'error' : 'warning',
+    message: `${change.name} ${removed ? 'no longer exists in' : 'is deprecated in'} ${where}`,
+    file,
+    line: lineAt(text, match.index),
+    suggestion: `${change.rewrite !== undefined ? 'Replace with' : 'Use'} ${change.replacement}`,
+  };
+}
+
+export function checkDeprecatedApis(
+  source: string,
+  modules: ModuleSources,
+  options: DeprecatedApiOptions = {}
+): Finding[] {
+  const cargoToml = options.cargoToml ?? modules['Cargo.toml'];
+  const version = cargoToml ? kleverScVersion(cargoToml) : undefined;
+  const changes = applicable(version);
+  const findings = sourceFiles(source, modules).flatMap(([file, text]) =>
+    matchesIn(text, changes).map(match => finding(match, text, file, version))
+  );
+
+  if (cargoToml && version && compareVersions(version, KLEVER_SC_VERSION) < 0) {
+    findings.push({
+      rule: 'outdated-framework',
+      severity: 'info',
+      message: `Cargo.toml pins klever-sc ${version}; generated code and templates target ${KLEVER_SC_VERSION}`,
+      file: 'Cargo.toml',
+      line: lineAt(cargoToml, cargoToml.indexOf(version, cargoToml.search(/\bklever-sc\b/))),
+      suggestion: `Update klever-sc, klever-sc-scenario, klever-sc-meta and klever-sc-wasm-adapter to ${KLEVER_SC_VERSION} together.`,
+    });
+  }
+  return findings;
+}
+
+/**
+ * Apply every mechanical replacement for the declared klever-sc version and
+ * report what is left to change by hand
+ */
+export function fixDeprecatedApis(
+  source: string,
+  modules: ModuleSources = {},
+  options: DeprecatedApiOptions = {}
+): DeprecatedApiFix {
+  const cargoToml = options.cargoToml ?? modules['Cargo.toml'];
+  const version = cargoToml ? kleverScVersion(cargoToml) : undefined;
+  const changes = applicable(version);
+  const files: FileMap = {};
+  const remaining: Finding[] = [];
+  let fixed = 0;
+
+  for (const [file, text] of sourceFiles(source, modules)) {
+    let updated = text;
+    // Back to front so earlier offsets stay valid
+    for (const match of matchesIn(text, changes).reverse()) {
+      const { rewrite, pattern } = match.change;
+      if (rewrite === undefined) continue;
+      const replaced = match.text.replace(new RegExp(pattern.source), rewrite);
+      updated =
+        updated.slice(0, match.index) + replaced + updated.slice(match.index + match.text.length);
+      fixed++;
+    }
+    if (updated !== text) files[file ?? 'src/lib.rs'] = updated;
+    remaining.push(
+      ...matchesIn(updated, changes).map(match => finding(match, updated, file, version))
+    );
+  }
+  return { files, fixed, remaining };
+}
+
+export const deprecatedApiCheck: AnalysisCheck = {
+  name: 'deprecated-api',
+  description:
+    'Deprecated or removed klever-sc APIs for the version in Cargo.toml (pass it as modules["Cargo.toml"])',
+  crossModule: true,
+  rules: [
+    {
+      id: 'removed-api',
+      severity: 'error',
+      description: 'klever-sc API that no longer exists in the declared version',
+    },
+    {
+      id: 'deprecated-api',
+      severity: 'warning',
+      description: 'klever-sc API deprecated in the declared version',
+    },
+    {
+      id: 'outdated-framework',
+      severity: 'info',
+      description: 'Cargo.toml pins a klever-sc version older than the one templates target',
+    },
+  ],
+  run: (source, modules) => checkDeprecatedApis(source, modules),
+};
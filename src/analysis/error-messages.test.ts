@@ -0,0 +1,137 @@
+import { readFileSync } from 'fs';
+import {
+  checkErrorMessages,
+  collectErrorMessages,
+  generateErrorConstants,
+} from './error-messages.js';
+
+const EXAMPLE = readFileSync('examples/token_contract.rs', 'utf8');
+
+const LIB = `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait Vault: storage::StorageModule {
+    #[endpoint]
+    fn withdraw(&self, amount: BigUint) {
+        require!(amount > 0, "Amount must be positive");
+        require!(self.balance().get() >= amount, "Insufficient balance");
+        // require!(false, "Commented out")
+        self.balance().update(|b| *b -= &amount);
+    }
+
+    #[endpoint]
+    fn transfer(&self, to: ManagedAddress, amount: BigUint) {
+        require!(self.check(&to, &amount), "insufficient balance.");
+        if amount > 1_000u64 {
+            sc_panic!("Transfers above the daily limit need to be split into several smaller transfers");
+        }
+        require!(amount > 0, "Amount must be positive");
+        require!(!to.is_zero(), "Invalid address {}", 1);
+    }
+
+    fn check(&self, _to: &ManagedAddress, _amount: &BigUint) -> bool {
+        true
+    }
+}
+`;
+
+const STORAGE = `use klever_sc::imports::*;
+
+#[klever_sc::module]
+pub trait StorageModule {
+    fn require_funds(&self) {
+        require!(!self.balance().is_empty(), "Insufficient balance");
+    }
+
+    #[storage_mapper("balance")]
+    fn balance(&self) -> SingleValueMapper<BigUint>;
+}
+`;
+
+describe('collectErrorMessages', () => {
+  it('collects literal messages with their location, skipping comments', () => {
+    const messages = collectErrorMessages(LIB, { 'src/storage.rs': STORAGE });
+    expect(messages.map(m => [m.message, m.function, m.file, m.line, m.formatted])).toEqual([
+      ['Amount must be positive', 'withdraw', undefined, 9, false],
+      ['Insufficient balance', 'withdraw', undefined, 10, false],
+      ['insufficient balance.', 'transfer', undefined, 17, false],
+      [
+        'Transfers above the daily limit need to be split into several smaller transfers',
+        'transfer',
+        undefined,
+        19,
+        false,
+      ],
+      ['Amount must be positive', 'transfer', undefined, 21, false],
+      ['Invalid address {}', 'transfer', undefined, 22, true],
+      ['Insufficient balance', 'require_funds', 'src/storage.rs', 6, false],
+    ]);
+    expect(messages[3].macro).toBe('sc_panic');
+  });
+});
+
+describe('checkErrorMessages', () => {
+  it('flags differently worded duplicates, long messages and repeated literals', () => {
+    const findings = checkErrorMessages(LIB, { 'src/storage.rs': STORAGE });
+    expect(findings.map(f => [f.rule, f.file, f.line])).toEqual([
+      ['inconsistent-error-message', undefined, 17],
+      ['long-error-message', undefined, 19],
+      ['repeated-error-message', undefined, 21],
+      ['repeated-error-message', 'src/storage.rs', 6],
+    ]);
+    expect(findings[0].message).toBe(
+      '"insufficient balance." reports the same error as "Insufficient balance" with different wording'
+    );
+    expect(findings[3].message).toBe('"Insufficient balance" is written out 2 times');
+  });
+
+  it('only suggests constants for the example token contract', () => {
+    const findings = checkErrorMessages(EXAMPLE, {});
+    expect(findings.map(f => [f.rule, f.line])).toEqual([
+      ['repeated-error-message', 33],
+      ['repeated-error-message', 47],
+    ]);
+    expect(findings[0].message).toBe('"Amount must be positive" is written out 3 times');
+  });
+});
+
+describe('generateErrorConstants', () => {
+  it('moves messages into src/errors.rs and rewrites the calls', () => {
+    const result = generateErrorConstants(LIB, { 'src/storage.rs': STORAGE });
+    expect(result.constants.map(c => [c.name, c.occurrences, c.variants])).toEqual([
+      ['ERR_AMOUNT_MUST_BE_POSITIVE', 2, []],
+      ['ERR_INSUFFICIENT_BALANCE', 3, ['insufficient balance.']],
+      ['ERR_TRANSFERS_ABOVE_THE_DAILY_LIMIT', 1, []],
+    ]);
+    expect(result.files['src/errors.rs']).toContain(
+      'pub const ERR_INSUFFICIENT_BALANCE: &str = "Insufficient balance";'
+    );
+
+    const lib = result.files['src/lib.rs'];
+    expect(lib).toContain('use klever_sc::imports::*;\nuse crate::errors::*;\n\npub mod errors;\n');
+    expect(lib).toContain('require!(amount > 0, ERR_AMOUNT_MUST_BE_POSITIVE);');
+    expect(lib).toContain('require!(self.check(&to, &amount), ERR_INSUFFICIENT_BALANCE);');
+    expect(lib).toContain('sc_panic!(ERR_TRANSFERS_ABOVE_THE_DAILY_LIMIT);');
+    expect(lib).toContain('require!(!to.is_zero(), "Invalid address {}", 1);');
+    expect(lib).toContain('// require!(false, "Commented out")');
+
+    expect(result.files['src/storage.rs']).toContain(
+      'use crate::errors::*;\n\n#[klever_sc::module]'
+    );
+    expect(result.files['src/storage.rs']).toContain(
+      'require!(!self.balance().is_empty(), ERR_INSUFFICIENT_BALANCE);'
+    );
+    expect(result.notes).toEqual([
+      'ERR_INSUFFICIENT_BALANCE replaces "insufficient balance."; those calls now fail with "Insufficient balance".',
+      '1 formatted message(s) with arguments were left inline.',
+    ]);
+  });
+
+  it('rejects contracts without literal messages', () => {
+    expect(() => generateErrorConstants('#[klever_sc::contract]\npub trait Empty {}\n')).toThrow(
+      'No literal require!/sc_panic! messages found.'
+    );
+  });
+});
@@ -0,0 +1,289 @@
+/**
+ * Error messages passed to require! and sc_panic!: the same failure worded
+ * differently in different places, messages long enough to bloat the wasm,
+ * and literals repeated often enough to belong in a shared errors module.
+ */
+
+import { addModDeclarations } from '../codegen/rust-source.js';
+import type { FileMap } from '../scaffold/types.js';
+import { contractFunctions, lineAt, maskNonCode } from './source.js';
+import type { AnalysisCheck, Finding, ModuleSources } from './types.js';
+
+export interface ErrorMessage {
+  /** Literal contents as written, escapes included */
+  message: string;
+  macro: 'require' | 'sc_panic';
+  file?: string;
+  function?: string;
+  line: number;
+  /** Offsets of the opening and closing quote in the file */
+  start: number;
+  end: number;
+  /** Uses format arguments, so it cannot become a constant */
+  formatted: boolean;
+}
+
+export interface ErrorConstant {
+  name: string;
+  message: string;
+  occurrences: number;
+  /** Other wordings of the same message that now use this constant */
+  variants: string[];
+}
+
+export interface ErrorConstantsResult {
+  files: FileMap;
+  constants: ErrorConstant[];
+  notes: string[];
+}
+
+/** Every byte of a message literal ends up in the wasm data section */
+export const MAX_ERROR_MESSAGE_BYTES = 64;
+
+const FILLER = new Set(['a', 'an', 'the', 'is', 'are', 'be', 'been']);
+
+function hasTrait(text: string): boolean {
+  return /#\[klever_sc::(contract|module)\]/.test(text);
+}
+
+/** Wording-insensitive key: lowercase words without punctuation or articles */
+function normalize(message: string): string {
+  return message
+    .toLowerCase()
+    .split(/[^a-z0-9]+/)
+    .filter(word => word && !FILLER.has(word))
+    .join(' ');
+}
+
+/** Index of the first top-level comma after `from`, or of the closing paren */
+function argumentEnd(code: string, from: number): number {
+  let depth = 0;
+  for (let i = from; i < code.length; i++) {
+    const ch = code[i];
+    if (ch === '(' || ch === '[' || ch === '{') depth++;
+    else if (ch === ')' || ch === ']' || ch === '}') {
+      if (depth === 0) return i;
+      depth--;
+    } else if (ch === ',' && depth === 0) return i;
+  }
+  return code.length;
+}
+
+function messagesIn(text: string, file: string | undefined): ErrorMessage[] {
+  const code = maskNonCode(text);
+  const functions = hasTrait(text) ? contractFunctions(text) : [];
+  const messages: ErrorMessage[] = [];
+  for (const match of code.matchAll(/\b(require|sc_panic)!\s*\(/g)) {
+    const macro = match[1] as ErrorMessage['macro'];
+    let i = match.index! + match[0].length;
+    // require!(condition, "message"): skip the condition
+    if (macro === 'require') {
+      i = argumentEnd(code, i);
+      if (code[i] !== ',') continue;
+      i++;
+    }
+    while (/\s/.test(code[i] || '')) i++;
+    if (code[i] !== '"') continue;
+    const end = code.indexOf('"', i + 1);
+    const message = text.slice(i + 1, end);
+    const after = code.slice(end + 1).match(/^\s*(,\s*)?(\))?/)!;
+    const fn = functions.find(f => f.bodyStart <= i && i < f.end);
+    messages.push({
+      message,
+      macro,
+      file,
+      function: fn?.name,
+      line: lineAt(text, i),
+      start: i,
+      end,
+      formatted: message.includes('{}') || (after[1] !== undefined && after[2] === undefined),
+    });
+  }
+  return messages;
+}
+
+/** All literal require!/sc_panic! messages in `source` and its modules */
+export function collectErrorMessages(
+  source: string,
+  modules: ModuleSources = {}
+): ErrorMessage[] {
+  return [
+    ...messagesIn(source, undefined),
+    ...Object.entries(modules).flatMap(([file, text]) => messagesIn(text, file)),
+  ];
+}
+
+/** Group messages by normalized wording; the most frequent wording comes first */
+function groupByMeaning(messages: ErrorMessage[]): ErrorMessage[][][] {
+  const groups = new Map<string, Map<string, ErrorMessage[]>>();
+  for (const message of messages) {
+    const key = normalize(message.message) || message.message;
+    if (!groups.has(key)) groups.set(key, new Map());
+    const wordings = groups.get(key)!;
+    if (!wordings.has(message.message)) wordings.set(message.message, []);
+    wordings.get(message.message)!.push(message);
+  }
+  return [...groups.values()].map(wordings =>
+    [...wordings.values()].sort((a, b) => b.length - a.length)
+  );
+}
+
+export function checkErrorMessages(source: string, modules: ModuleSources): Finding[] {
+  const messages = collectErrorMessages(source, modules);
+  const findings: Finding[] = [];
+  const at = (m: ErrorMessage) => ({ function: m.function, file: m.file, line: m.line });
+
+  for (const wordings of groupByMeaning(messages)) {
+    const canonical = wordings[0][0].message;
+    for (const variant of wordings.slice(1)) {
+      findings.push({
+        rule: 'inconsistent-error-message',
+        severity: 'info',
+        message: `"${variant[0].message}" reports the same error as "${canonical}" with different wording`,
+        ...at(variant[0]),
+        suggestion: `Use "${canonical}" everywhere so clients can match on one message.`,
+      });
+    }
+  }
+
+  const byText = new Map<string, ErrorMessage[]>();
+  for (const message of messages) {
+    if (!byText.has(message.message)) byText.set(message.message, []);
+    byText.get(message.message)!.push(message);
+  }
+  for (const [text, uses] of byText) {
+    const bytes = Buffer.byteLength(text, 'utf8');
+    if (bytes > MAX_ERROR_MESSAGE_BYTES) {
+      findings.push({
+        rule: 'long-error-message',
+        severity: 'info',
+        message: `Error message is ${bytes} bytes (over ${MAX_ERROR_MESSAGE_BYTES}); every byte is stored in the wasm`,
+        ...at(uses[0]),
+        suggestion: 'Shorten it to a few words; details belong in documentation.',
+      });
+    }
+    const literal = uses.filter(u => !u.formatted);
+    if (literal.length > 1) {
+      findings.push({
+        rule: 'repeated-error-message',
+        severity: 'info',
+        message: `"${text}" is written out ${literal.length} times`,
+        ...at(literal[1]),
+        suggestion:
+          'Move shared messages into an errors module of constants (generate_error_constants does this).',
+      });
+    }
+  }
+  return findings.sort((a, b) => (a.file || '').localeCompare(b.file || '') || a.line - b.line);
+}
+
+function constantName(message: string, taken: Set<string>): string {
+  const words = message
+    .toLowerCase()
+    .split(/[^a-z0-9]+/)
+    .filter(Boolean)
+    .slice(0, 5);
+  const base = `ERR_${words.join('_').toUpperCase() || 'UNKNOWN'}`;
+  let name = base;
+  for (let n = 2; taken.has(name); n++) name = `${base}_${n}`;
+  taken.add(name);
+  return name;
+}
+
+function addErrorsImport(text: string): string {
+  const imports = /^use klever_sc::imports::\*;[ \t]*\n/m.exec(text);
+  if (!imports) return `use crate::errors::*;\n${text}`;
+  const at = imports.index + imports[0].length;
+  return `${text.slice(0, at)}use crate::errors::*;\n${text.slice(at)}`;
+}
+
+/**
+ * Move every literal error message into src/errors.rs constants and point
+ * require!/sc_panic! calls at them. Differently worded copies of the same
+ * message share one constant with the most common wording.
+ */
+export function generateErrorConstants(
+  source: string,
+  modules: ModuleSources = {}
+): ErrorConstantsResult {
+  if (/^\s*(pub\s+)?mod\s+errors\s*;/m.test(source)) {
+    throw new Error('Contract already declares an errors module.');
+  }
+  const messages = collectErrorMessages(source, modules).filter(m => !m.formatted);
+  if (messages.length === 0) {
+    throw new Error('No literal require!/sc_panic! messages found.');
+  }
+
+  const taken = new Set<string>();
+  const constants: ErrorConstant[] = [];
+  const replacements = new Map<ErrorMessage, string>();
+  for (const wordings of groupByMeaning(messages)) {
+    const message = wordings[0][0].message;
+    const name = constantName(message, taken);
+    for (const use of wordings.flat()) replacements.set(use, name);
+    constants.push({
+      name,
+      message,
+      occurrences: wordings.flat().length,
+      variants: wordings.slice(1).map(w => w[0].message),
+    });
+  }
+
+  const rewrite = (text: string, file: string | undefined): string => {
+    const uses = messages.filter(m => m.file === file).sort((a, b) => b.start - a.start);
+    let result = text;
+    for (const use of uses) {
+      result = `${result.slice(0, use.start)}${replacements.get(use)}${result.slice(use.end + 1)}`;
+    }
+    return uses.length > 0 ? addErrorsImport(result) : result;
+  };
+
+  const files: FileMap = {
+    'src/errors.rs': `// Error messages shared by require! and sc_panic! calls
+
+${constants.map(c => `pub const ${c.name}: &str = "${c.message}";`).join('\n')}
+`,
+    'src/lib.rs': addModDeclarations(rewrite(source, undefined), ['errors']),
+  };
+  for (const [file, text] of Object.entries(modules)) {
+    const updated = rewrite(text, file);
+    if (updated !== text) files[file] = updated;
+  }
+
+  const notes: string[] = [];
+  for (const constant of constants.filter(c => c.variants.length > 0)) {
+    notes.push(
+      `${constant.name} replaces ${constant.variants.map(v => `"${v}"`).join(', ')}; those calls now fail with "${constant.message}".`
+    );
+  }
+  const formatted = collectErrorMessages(source, modules).filter(m => m.formatted).length;
+  if (formatted > 0) {
+    notes.push(`${formatted} formatted message(s) with arguments were left inline.`);
+  }
+  return { files, constants, notes };
+}
+
+export const errorMessageCheck: AnalysisCheck = {
+  name: 'error-messages',
+  description:
+    'Error messages worded differently for the same failure, over-long or repeated literals',
+  crossModule: true,
+  rules: [
+    {
+      id: 'inconsistent-error-message',
+      severity: 'info',
+      description: 'The same error message worded differently in different places',
+    },
+    {
+      id: 'long-error-message',
+      severity: 'info',
+      description: 'Error message longer than MAX_ERROR_MESSAGE_BYTES',
+    },
+    {
+      id: 'repeated-error-message',
+      severity: 'info',
+      description: 'Error message literal written out more than once',
+    },
+  ],
+  run: checkErrorMessages,
+};
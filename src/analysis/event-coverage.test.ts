@@ -0,0 +1,57 @@
+import { readFileSync } from 'fs';
+import { checkEventCoverage } from './event-coverage.js';
+
+const EXAMPLE = readFileSync('examples/token_contract.rs', 'utf8');
+
+const CONFIG = `#[klever_sc::contract]
+pub trait Config {
+    #[endpoint(setLimit)]
+    fn set_limit(&self, limit: BigUint) {
+        self.require_owner();
+        self.limit().set(&limit);
+    }
+
+    #[endpoint(resetCounter)]
+    fn reset_counter(&self) {
+        self.clear_counter();
+    }
+
+    #[endpoint(bump)]
+    fn bump(&self) {
+        self.counter().update(|c| *c += 1u64);
+        self.bumped_event(&self.counter().get());
+    }
+
+    fn clear_counter(&self) {
+        self.counter().clear();
+    }
+
+    #[storage_mapper("limit")]
+    fn limit(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("counter")]
+    fn counter(&self) -> SingleValueMapper<u64>;
+
+    #[event("bumped")]
+    fn bumped_event(&self, value: &u64);
+}
+`;
+
+describe('checkEventCoverage', () => {
+  it('accepts the token example, where every set/update emits an event', () => {
+    expect(checkEventCoverage(EXAMPLE, {})).toEqual([]);
+  });
+
+  it('reports endpoints that change storage silently', () => {
+    const findings = checkEventCoverage(CONFIG, {});
+
+    expect(findings.map(f => [f.function, f.line])).toEqual([
+      ['set_limit', 6],
+      ['reset_counter', 10],
+    ]);
+    expect(findings[1].message).toBe(
+      'reset_counter changes counter without emitting an event; indexers cannot track it'
+    );
+    expect(findings[0].suggestion).toContain('#[event("setLimit")]');
+  });
+});
@@ -0,0 +1,85 @@
+/**
+ * Event coverage for state changes: every endpoint that writes storage should
+ * emit an event, otherwise indexers and wallets cannot follow the change
+ * without re-reading storage.
+ */
+
+import { listEvents, listStorageMappers, snakeToCamel } from '../codegen/rust-source.js';
+import {
+  contractFunctions,
+  lineAt,
+  maskNonCode,
+  storageWrites,
+  type ContractFunction,
+} from './source.js';
+import type { AnalysisCheck, Finding, ModuleSources } from './types.js';
+
+interface Located {
+  fn: ContractFunction;
+  file?: string;
+  text: string;
+}
+
+function calls(body: string): string[] {
+  return [...body.matchAll(/self\s*\.\s*(\w+)\s*\(/g)].map(m => m[1]);
+}
+
+export function checkEventCoverage(source: string, modules: ModuleSources): Finding[] {
+  const files: [string | undefined, string][] = [
+    [undefined, source],
+    ...Object.entries(modules).filter(([, text]) => /#\[klever_sc::(contract|module)\]/.test(text)),
+  ];
+  const functions: Located[] = files.flatMap(([file, text]) =>
+    contractFunctions(text).map(fn => ({ fn, file, text }))
+  );
+  const mappers = new Set(
+    files.flatMap(([, text]) => listStorageMappers(text).map(m => m.functionName))
+  );
+  const events = new Set(files.flatMap(([, text]) => listEvents(text).map(e => e.functionName)));
+  const helpers = new Map(
+    functions.filter(({ fn }) => fn.kind === 'private').map(({ fn }) => [fn.name, fn])
+  );
+
+  const findings: Finding[] = [];
+  for (const { fn, file, text } of functions) {
+    if (fn.kind !== 'endpoint' && fn.kind !== 'callback') continue;
+    const code = maskNonCode(fn.body);
+    const helperBodies = calls(code)
+      .map(name => helpers.get(name))
+      .filter((h): h is ContractFunction => h !== undefined)
+      .map(h => maskNonCode(h.body));
+
+    const emitted = [code, ...helperBodies].flatMap(calls).filter(name => events.has(name));
+    if (emitted.length > 0) continue;
+
+    const direct = storageWrites(code, mappers);
+    const indirect = helperBodies.flatMap(body => storageWrites(body, mappers));
+    const written = [...new Set([...direct, ...indirect].map(w => w.mapper))];
+    if (written.length === 0) continue;
+
+    findings.push({
+      rule: 'mutation-without-event',
+      severity: 'warning',
+      message: `${fn.name} changes ${written.join(', ')} without emitting an event; indexers cannot track it`,
+      function: fn.name,
+      file,
+      line: direct.length > 0 ? lineAt(text, fn.bodyStart + direct[0].index) : fn.line,
+      suggestion: `Declare an event such as #[event("${snakeToCamel(fn.name)}")] with the changed values and emit it after the last write.`,
+    });
+  }
+  return findings;
+}
+
+export const eventCoverageCheck: AnalysisCheck = {
+  name: 'event-coverage',
+  description: 'Endpoints and callbacks that write storage without emitting any event',
+  crossModule: true,
+  rules: [
+    {
+      id: 'mutation-without-event',
+      severity: 'warning',
+      description: 'Endpoint or callback that writes storage without emitting an event',
+    },
+  ],
+  run: checkEventCoverage,
+};
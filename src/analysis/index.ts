@@ -0,0 +1,25 @@
+/**
+ * Static analysis of klever-sc contract source. Each check returns findings
+ * with the rule that produced them and the line they point at.
+ */
+
+export * from './types.js';
+export * from './rules.js';
+export * from './source.js';
+export * from './validation.js';
+export * from './reentrancy.js';
+export * from './arithmetic.js';
+export * from './storage-keys.js';
+export * from './dead-code.js';
+export * from './access-control.js';
+export * from './architecture.js';
+export * from './event-coverage.js';
+export * from './iteration.js';
+export * from './view-purity.js';
+export * from './error-messages.js';
+export * from './deprecated-api.js';
+export * from './attribute-usage.js';
+export * from './abi-diff.js';
+export * from './upgrade-safety.js';
+export * from './wasm-size.js';
+export * from './vm-errors.js';
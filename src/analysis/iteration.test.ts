@@ -0,0 +1,110 @@
+import { readFileSync } from 'fs';
+import { checkUnboundedIteration } from './iteration.js';
+
+const EXAMPLE = readFileSync('examples/token_contract.rs', 'utf8');
+
+const LOTTERY = `#[klever_sc::contract]
+pub trait Lottery {
+    #[endpoint]
+    fn register(&self) {
+        let caller = self.blockchain().get_caller();
+        self.participants().push(&caller);
+    }
+
+    #[endpoint(addToken)]
+    fn add_token(&self, token: TokenIdentifier) {
+        self.require_owner();
+        self.tokens().insert(token);
+    }
+
+    #[endpoint]
+    fn distribute(&self) {
+        for participant in self.participants().iter() {
+            self.send().direct_klv(&participant, &BigUint::from(1u64));
+        }
+        for token in self.tokens().iter() {
+            self.check_token(&token);
+        }
+    }
+
+    #[view(getParticipants)]
+    fn get_participants(&self, from: usize, size: usize) -> MultiValueEncoded<ManagedAddress> {
+        self.participants().iter().skip(from).take(size).collect()
+    }
+
+    #[view(countParticipants)]
+    fn count_participants(&self) -> usize {
+        let mut total = 0;
+        for i in 1..=self.participants().len() {
+            total += i;
+        }
+        total
+    }
+
+    fn check_token(&self, _token: &TokenIdentifier) {}
+
+    #[storage_mapper("participants")]
+    fn participants(&self) -> VecMapper<ManagedAddress>;
+
+    #[storage_mapper("tokens")]
+    fn tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+}
+`;
+
+describe('checkUnboundedIteration', () => {
+  it('flags loops over collections that public endpoints grow', () => {
+    const findings = checkUnboundedIteration(LOTTERY, {});
+    expect(findings.map(f => [f.function, f.severity, f.line])).toEqual([
+      ['distribute', 'warning', 17],
+      ['count_participants', 'info', 33],
+    ]);
+    expect(findings[0].message).toContain('participants (VecMapper)');
+    expect(findings[0].message).toContain('through register');
+    expect(findings[0].suggestion).toContain('claim-pull');
+    expect(findings[1].suggestion).toContain('.skip(from).take(size)');
+  });
+
+  it('ignores collections only the owner can grow and paginated reads', () => {
+    const findings = checkUnboundedIteration(LOTTERY, {});
+    expect(findings.some(f => f.message.includes('tokens'))).toBe(false);
+    expect(findings.some(f => f.function === 'get_participants')).toBe(false);
+  });
+
+  it('follows growth through private helpers and modules', () => {
+    const lib = `#[klever_sc::contract]
+pub trait Registry: storage::StorageModule {
+    #[endpoint]
+    fn join(&self) {
+        self.add_member();
+    }
+
+    fn add_member(&self) {
+        self.members().insert(self.blockchain().get_caller());
+    }
+}
+`;
+    const storage = `#[klever_sc::module]
+pub trait StorageModule {
+    #[view(getMembers)]
+    fn get_members(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.members().iter().collect()
+    }
+
+    #[storage_mapper("members")]
+    fn members(&self) -> SetMapper<ManagedAddress>;
+}
+`;
+    const findings = checkUnboundedIteration(lib, { 'src/storage.rs': storage });
+    expect(findings).toHaveLength(1);
+    expect(findings[0]).toMatchObject({
+      rule: 'unbounded-iteration',
+      function: 'get_members',
+      file: 'src/storage.rs',
+      line: 5,
+    });
+  });
+
+  it('reports nothing for the example token contract', () => {
+    expect(checkUnboundedIteration(EXAMPLE, {})).toEqual([]);
+  });
+});
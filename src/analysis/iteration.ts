@@ -0,0 +1,139 @@
+/**
+ * Unbounded iteration: loops over collection mappers that any caller can grow.
+ * Gas cost rises with every entry until the function no longer fits in a
+ * transaction (or a query), at which point it fails permanently.
+ */
+
+import { listStorageMappers } from '../codegen/rust-source.js';
+import { auditAccessControl } from './access-control.js';
+import { contractFunctions, lineAt, maskNonCode, type ContractFunction } from './source.js';
+import type { AnalysisCheck, Finding, ModuleSources } from './types.js';
+
+const COLLECTIONS = new Set([
+  'VecMapper',
+  'SetMapper',
+  'UnorderedSetMapper',
+  'MapMapper',
+  'LinkedListMapper',
+  'QueueMapper',
+]);
+
+const MAPPER_CALL = 'self\\s*\\.\\s*(\\w+)\\s*\\((?:[^()]|\\([^()]*\\))*\\)';
+const GROW = new RegExp(
+  `${MAPPER_CALL}\\s*\\.\\s*(push|insert|push_back|push_front|append)\\s*\\(`,
+  'g'
+);
+/** `.iter()`, `.keys()` or `.values()` on a mapper */
+const ITERATE = new RegExp(`${MAPPER_CALL}\\s*\\.\\s*(iter|keys|values)\\s*\\(\\s*\\)`, 'g');
+/** Index loops such as `for i in 1..=self.items().len()` */
+const RANGE = new RegExp(`\\.\\.=?\\s*${MAPPER_CALL}\\s*\\.\\s*len\\s*\\(\\s*\\)`, 'g');
+
+interface Located {
+  fn: ContractFunction;
+  file?: string;
+  text: string;
+}
+
+function calls(body: string): string[] {
+  return [...body.matchAll(/self\s*\.\s*(\w+)\s*\(/g)].map(m => m[1]);
+}
+
+/** Rest of the statement or loop header after `index` */
+function statementAfter(code: string, index: number): string {
+  const end = code.slice(index).search(/[;{]/);
+  return end === -1 ? code.slice(index) : code.slice(index, index + end);
+}
+
+export function checkUnboundedIteration(source: string, modules: ModuleSources): Finding[] {
+  const files: [string | undefined, string][] = [
+    [undefined, source],
+    ...Object.entries(modules).filter(([, text]) => /#\[klever_sc::(contract|module)\]/.test(text)),
+  ];
+  const functions: Located[] = files.flatMap(([file, text]) =>
+    contractFunctions(text).map(fn => ({ fn, file, text }))
+  );
+  const collections = new Map(
+    files
+      .flatMap(([, text]) => listStorageMappers(text))
+      .filter(m => COLLECTIONS.has(m.mapper))
+      .map(m => [m.functionName, m.mapper])
+  );
+  const helpers = new Map(
+    functions.filter(({ fn }) => fn.kind === 'private').map(({ fn }) => [fn.name, fn])
+  );
+
+  // Collections that public endpoints grow, directly or through a private helper
+  const growers = new Map<string, string>();
+  const publicEndpoints = auditAccessControl(source, modules).endpoints.filter(
+    e => e.access === 'public'
+  );
+  for (const endpoint of publicEndpoints) {
+    const located = functions.find(
+      ({ fn, file }) =>
+        fn.kind === 'endpoint' && fn.name === endpoint.name && file === endpoint.file
+    );
+    if (!located) continue;
+    const code = maskNonCode(located.fn.body);
+    const bodies = [
+      code,
+      ...calls(code)
+        .map(name => helpers.get(name))
+        .filter((h): h is ContractFunction => h !== undefined)
+        .map(h => maskNonCode(h.body)),
+    ];
+    for (const body of bodies) {
+      for (const match of body.matchAll(GROW)) {
+        if (collections.has(match[1]) && !growers.has(match[1])) {
+          growers.set(match[1], endpoint.endpoint);
+        }
+      }
+    }
+  }
+  if (growers.size === 0) return [];
+
+  const findings: Finding[] = [];
+  for (const { fn, file, text } of functions) {
+    if (fn.kind === 'storage' || fn.kind === 'event' || fn.kind === 'proxy') continue;
+    const code = maskNonCode(fn.body);
+    const seen = new Set<string>();
+    const loops = [...code.matchAll(ITERATE), ...code.matchAll(RANGE)].sort(
+      (a, b) => a.index! - b.index!
+    );
+    for (const match of loops) {
+      const mapper = match[1];
+      if (!growers.has(mapper) || seen.has(mapper)) continue;
+      // Already paginated or capped
+      if (/\.\s*take\s*\(|\bmin\s*\(/.test(statementAfter(code, match.index!))) {
+        continue;
+      }
+      seen.add(mapper);
+      const view = fn.kind === 'view';
+      findings.push({
+        rule: 'unbounded-iteration',
+        severity: view ? 'info' : 'warning',
+        message: `${fn.name} iterates over every entry of ${mapper} (${collections.get(mapper)}), which anyone can grow through ${growers.get(mapper)}; gas cost grows with each entry until the call no longer fits in the gas limit`,
+        function: fn.name,
+        file,
+        line: lineAt(text, fn.bodyStart + match.index!),
+        suggestion: view
+          ? 'Paginate: take (from, size) arguments and iterate with .iter().skip(from).take(size).'
+          : 'Process a bounded batch per call and store a cursor, or switch to a claim-pull pattern where each user withdraws their own entry.',
+      });
+    }
+  }
+  return findings;
+}
+
+export const unboundedIterationCheck: AnalysisCheck = {
+  name: 'unbounded-iteration',
+  description: 'Loops over collection mappers that public endpoints let anyone grow',
+  crossModule: true,
+  rules: [
+    {
+      id: 'unbounded-iteration',
+      severity: 'warning',
+      description: 'Loop over a collection mapper that public endpoints let anyone grow',
+    },
+  ],
+  run: checkUnboundedIteration,
+};
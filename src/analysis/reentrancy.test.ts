@@ -0,0 +1,65 @@
+import { checkStateOrdering } from './reentrancy.js';
+
+const VAULT = `#[klever_sc::contract]
+pub trait Vault {
+    #[endpoint]
+    fn withdraw(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.send().direct_klv(&caller, &amount);
+        self.deposits(&caller)
+            .update(|d| *d -= &amount);
+    }
+
+    #[endpoint]
+    fn withdraw_safe(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.deposits(&caller).update(|d| *d -= &amount);
+        self.send().direct_klv(&caller, &amount);
+    }
+
+    #[endpoint]
+    fn stake(&self, target: ManagedAddress) {
+        self.staking_contract(target).stake().async_call().call_and_exit();
+        self.record_stake();
+    }
+
+    #[callback]
+    fn stake_callback(&self, #[call_result] result: ManagedAsyncCallResult<()>) {
+        self.staked().set(true);
+    }
+
+    fn record_stake(&self) {
+        self.staked().set(true);
+    }
+
+    #[storage_mapper("deposits")]
+    fn deposits(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("staked")]
+    fn staked(&self) -> SingleValueMapper<bool>;
+}
+`;
+
+describe('checkStateOrdering', () => {
+  it('reports writes after transfers and calls with their line span', () => {
+    const findings = checkStateOrdering(VAULT);
+
+    expect(findings.map(f => [f.rule, f.function, f.line, f.endLine])).toEqual([
+      ['state-write-after-call', 'withdraw', 6, 7],
+      ['state-write-after-exit', 'stake', 20, 21],
+      ['callback-without-revalidation', 'stake_callback', 25, 26],
+    ]);
+    expect(findings[0].message).toContain('self.deposits().update(..)');
+    expect(findings[1].message).toContain('self.record_stake() (writes staked)');
+  });
+
+  it('accepts callbacks that check state before writing', () => {
+    const checked = VAULT.replace(
+      '        self.staked().set(true);\n    }\n\n    fn record_stake',
+      '        require!(!self.staked().get(), "Already staked");\n        self.staked().set(true);\n    }\n\n    fn record_stake'
+    );
+    expect(checkStateOrdering(checked).map(f => f.rule)).not.toContain(
+      'callback-without-revalidation'
+    );
+  });
+});
@@ -0,0 +1,154 @@
+/**
+ * State-ordering checks around external interactions: storage written after a
+ * transfer or contract call in the same endpoint (checks-effects-interactions),
+ * and callbacks that apply a result without re-validating the state it depends on.
+ */
+
+import { listStorageMappers } from '../codegen/rust-source.js';
+import {
+  contractFunctions,
+  isCallable,
+  lineAt,
+  storageWrites,
+  type ContractFunction,
+  type StorageWrite,
+} from './source.js';
+import type { AnalysisCheck, Finding } from './types.js';
+
+/** Calls that hand control or value to another account */
+const INTERACTION =
+  /self\s*\.\s*send\(\)\s*\.\s*\w+|\.\s*(async_call|call_and_exit|transfer_execute|execute_on_dest_context|sync_call)\s*\(/g;
+
+interface Interaction {
+  index: number;
+  text: string;
+  /** Nothing after call_and_exit() runs in this execution */
+  exits: boolean;
+}
+
+function interactions(body: string): Interaction[] {
+  return [...body.matchAll(INTERACTION)].map(m => ({
+    index: m.index!,
+    text: m[0].replace(/\s+/g, ''),
+    exits: m[1] === 'call_and_exit',
+  }));
+}
+
+function writeAfterInteraction(
+  source: string,
+  fn: ContractFunction,
+  mappers: Set<string>,
+  helpers: Map<string, ContractFunction>
+): Finding[] {
+  const calls = interactions(fn.body);
+  if (calls.length === 0) return [];
+  const first = calls[0];
+  const exit = calls.find(c => c.exits);
+  const line = (offset: number) => lineAt(source, fn.bodyStart + offset);
+
+  // Direct writes, plus writes made by private helpers called after the interaction
+  const later: StorageWrite[] = storageWrites(fn.body, mappers).filter(w => w.index > first.index);
+  for (const match of fn.body.matchAll(/self\s*\.\s*(\w+)\s*\(/g)) {
+    const helper = helpers.get(match[1]);
+    if (!helper || match.index! < first.index) continue;
+    const inner = storageWrites(helper.body, mappers);
+    if (inner.length > 0) {
+      later.push({
+        index: match.index!,
+        mapper: inner[0].mapper,
+        text: `self.${helper.name}() (writes ${inner.map(w => w.mapper).join(', ')})`,
+      });
+    }
+  }
+
+  return later
+    .sort((a, b) => a.index - b.index)
+    .map(write => {
+      const unreachable = exit !== undefined && write.index > exit.index;
+      const trigger = unreachable ? exit : [...calls].reverse().find(c => c.index < write.index)!;
+      return unreachable
+        ? {
+            rule: 'state-write-after-exit',
+            severity: 'error' as const,
+            message: `${fn.name}: ${write.text} comes after call_and_exit() and never runs`,
+            function: fn.name,
+            line: line(trigger.index),
+            endLine: line(write.index),
+            suggestion:
+              'Move the write before the async call, or into the callback if it depends on the result.',
+          }
+        : {
+            rule: 'state-write-after-call',
+            severity: 'warning' as const,
+            message: `${fn.name}: ${write.text} happens after ${trigger.text}; a re-entrant call would see the old state`,
+            function: fn.name,
+            line: line(trigger.index),
+            endLine: line(write.index),
+            suggestion: `Update storage before ${trigger.text} (checks-effects-interactions).`,
+          };
+    });
+}
+
+/** Callbacks must re-check state: it may have changed while the call was in flight */
+function callbackRevalidation(
+  source: string,
+  fn: ContractFunction,
+  mappers: Set<string>
+): Finding[] {
+  const firstWrite = storageWrites(fn.body, mappers)[0];
+  if (!firstWrite) return [];
+  const before = fn.body.slice(0, firstWrite.index);
+  const readsState = [...before.matchAll(/self\s*\.\s*(\w+)\s*\(/g)].some(m =>
+    mappers.has(m[1])
+  );
+  if (readsState || /require!|sc_panic!/.test(before)) return [];
+  return [
+    {
+      rule: 'callback-without-revalidation',
+      severity: 'warning',
+      message: `${fn.name}: callback writes ${firstWrite.mapper} without re-reading or checking state first`,
+      function: fn.name,
+      line: fn.line,
+      endLine: lineAt(source, fn.bodyStart + firstWrite.index),
+      suggestion:
+        'Re-read the storage the call depended on (e.g. that the pending entry still exists) and require! it before applying the result.',
+    },
+  ];
+}
+
+export function checkStateOrdering(source: string): Finding[] {
+  const functions = contractFunctions(source);
+  const mappers = new Set(listStorageMappers(source).map(m => m.functionName));
+  const helpers = new Map(
+    functions.filter(f => f.kind === 'private' && f.body).map(f => [f.name, f])
+  );
+  return functions.flatMap(fn => {
+    if (isCallable(fn)) return writeAfterInteraction(source, fn, mappers, helpers);
+    if (fn.kind === 'callback') return callbackRevalidation(source, fn, mappers);
+    return [];
+  });
+}
+
+export const stateOrderingCheck: AnalysisCheck = {
+  name: 'state-ordering',
+  description:
+    'Storage written after transfers or contract calls, and callbacks that do not re-validate state',
+  rules: [
+    {
+      id: 'state-write-after-call',
+      severity: 'warning',
+      description: 'Storage written after a transfer or synchronous contract call',
+    },
+    {
+      id: 'state-write-after-exit',
+      severity: 'error',
+      description: 'Storage written after call_and_exit(), which never runs',
+    },
+    {
+      id: 'callback-without-revalidation',
+      severity: 'warning',
+      description: 'Callback writes storage without re-reading or checking state first',
+    },
+  ],
+  run: checkStateOrdering,
+};
@@ -0,0 +1,62 @@
+import { CHECKS, describeRules, lintContract } from './rules.js';
+
+const UNCHECKED = `#[klever_sc::contract]
+pub trait Vault {
+    #[endpoint]
+    fn send_to(&self, to: ManagedAddress, amount: BigUint) {
+        require!(amount >= 1u32, "Amount too small");
+        self.send().direct_klv(&to, &amount);
+    }
+
+    #[endpoint]
+    fn withdraw(&self, amount: BigUint) {
+        let balance = self.balance().get();
+        require!(balance >= amount, "Insufficient balance");
+    }
+}
+`;
+
+describe('describeRules', () => {
+  it('lists every check with rule ids unique across checks', () => {
+    const described = describeRules();
+    expect(described.map(c => c.name)).toEqual(CHECKS.map(c => c.name));
+    const ids = described.flatMap(c => c.rules.map(r => r.id));
+    expect(new Set(ids).size).toBe(ids.length);
+    expect(described.find(c => c.name === 'view-purity')!.rules[0]).toEqual({
+      id: 'view-mutates-storage',
+      severity: 'error',
+      description: 'View that writes storage, directly or through a helper',
+    });
+  });
+});
+
+describe('lintContract config', () => {
+  it('overrides severities per rule and per check', () => {
+    const { findings, summary } = lintContract(UNCHECKED, {
+      config: { rules: { 'input-validation': 'info', 'missing-amount-check': 'error' } },
+    });
+    expect(findings.map(f => [f.rule, f.severity])).toEqual([
+      ['missing-address-check', 'info'],
+      ['missing-amount-check', 'error'],
+    ]);
+    expect(summary).toEqual({ error: 1, warning: 0, info: 1 });
+  });
+
+  it('drops rules and skips checks that are turned off', () => {
+    const report = lintContract(UNCHECKED, {
+      config: { rules: { 'missing-address-check': 'off', arithmetic: 'off' } },
+    });
+    expect(report.findings.map(f => f.rule)).toEqual(['missing-amount-check']);
+    expect(report.checks).not.toContain('arithmetic');
+    expect(report.checks).toContain('input-validation');
+  });
+
+  it('rejects unknown rules and settings', () => {
+    expect(() => lintContract(UNCHECKED, { config: { rules: { 'no-such-rule': 'off' } } })).toThrow(
+      'Unknown rule(s) in config: no-such-rule'
+    );
+    expect(() =>
+      lintContract(UNCHECKED, { config: { rules: { 'unused-event': 'fatal' as 'error' } } })
+    ).toThrow('Invalid rule setting(s): unused-event=fatal');
+  });
+});
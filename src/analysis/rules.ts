@@ -0,0 +1,145 @@
+/**
+ * Rule engine for the contract linter: the registered checks, the rules each
+ * one reports, and per-project configuration that turns rules off or changes
+ * their severity.
+ */
+
+import { inputValidationCheck } from './validation.js';
+import { stateOrderingCheck } from './reentrancy.js';
+import { arithmeticCheck } from './arithmetic.js';
+import { storageKeyCheck } from './storage-keys.js';
+import { deadCodeCheck } from './dead-code.js';
+import { accessControlCheck } from './access-control.js';
+import { eventCoverageCheck } from './event-coverage.js';
+import { unboundedIterationCheck } from './iteration.js';
+import { viewPurityCheck } from './view-purity.js';
+import { errorMessageCheck } from './error-messages.js';
+import { deprecatedApiCheck } from './deprecated-api.js';
+import { attributeUsageCheck } from './attribute-usage.js';
+import type { AnalysisCheck, Finding, ModuleSources, RuleInfo, Severity } from './types.js';
+
+export const CHECKS: AnalysisCheck[] = [
+  inputValidationCheck,
+  stateOrderingCheck,
+  arithmeticCheck,
+  storageKeyCheck,
+  deadCodeCheck,
+  accessControlCheck,
+  eventCoverageCheck,
+  unboundedIterationCheck,
+  viewPurityCheck,
+  errorMessageCheck,
+  deprecatedApiCheck,
+  attributeUsageCheck,
+];
+
+/** Severity to report a rule with, or `off` to drop it */
+export type RuleSetting = Severity | 'off';
+
+export interface RuleConfig {
+  /**
+   * Settings keyed by rule id or check name. A check name applies to every
+   * rule of the check; a rule id takes precedence over its check.
+   */
+  rules?: Record<string, RuleSetting>;
+}
+
+export interface LintOptions {
+  /** Checks to run (default: all) */
+  checks?: string[];
+  /** Module files of the same crate, for checks that span the whole contract */
+  modules?: ModuleSources;
+  /** Per-project rule settings */
+  config?: RuleConfig;
+}
+
+export interface LintReport {
+  findings: Finding[];
+  summary: Record<Severity, number>;
+  checks: string[];
+}
+
+export interface CheckDescription {
+  name: string;
+  description: string;
+  crossModule: boolean;
+  rules: RuleInfo[];
+}
+
+const SETTINGS: RuleSetting[] = ['error', 'warning', 'info', 'off'];
+
+/** Every check with the rules it reports, for clients deciding what to enable */
+export function describeRules(): CheckDescription[] {
+  return CHECKS.map(check => ({
+    name: check.name,
+    description: check.description,
+    crossModule: !!check.crossModule,
+    rules: check.rules,
+  }));
+}
+
+/** Throw on settings for unknown rules or checks, or values other than a severity or `off` */
+export function validateRuleConfig(config: RuleConfig): void {
+  const entries = Object.entries(config.rules || {});
+  const known = new Set(CHECKS.flatMap(c => [c.name, ...c.rules.map(r => r.id)]));
+  const unknown = entries.filter(([id]) => !known.has(id)).map(([id]) => id);
+  if (unknown.length > 0) {
+    throw new Error(
+      `Unknown rule(s) in config: ${unknown.join(', ')}. Read klever://analysis/rules for the available rules.`
+    );
+  }
+  const invalid = entries.filter(([, setting]) => !SETTINGS.includes(setting));
+  if (invalid.length > 0) {
+    throw new Error(
+      `Invalid rule setting(s): ${invalid.map(([id, s]) => `${id}=${s}`).join(', ')}. Use ${SETTINGS.join(', ')}.`
+    );
+  }
+}
+
+/** Setting for a rule of `check`, or undefined to keep the severity the check reported */
+function ruleSetting(
+  check: AnalysisCheck,
+  rule: string,
+  config: RuleConfig
+): RuleSetting | undefined {
+  const rules = config.rules || {};
+  return rules[rule] ?? rules[check.name];
+}
+
+/** Run the selected checks over `source` and its modules */
+export function lintContract(source: string, options: LintOptions = {}): LintReport {
+  const { checks, modules = {}, config = {} } = options;
+  const unknown = (checks || []).filter(name => !CHECKS.some(c => c.name === name));
+  if (unknown.length > 0) {
+    const available = CHECKS.map(c => c.name).join(', ');
+    throw new Error(`Unknown check(s): ${unknown.join(', ')}. Available: ${available}`);
+  }
+  validateRuleConfig(config);
+  const selected = (checks ? CHECKS.filter(c => checks.includes(c.name)) : CHECKS).filter(
+    check => check.rules.some(rule => ruleSetting(check, rule.id, config) !== 'off')
+  );
+  // Per-file checks also run over every module that declares a trait
+  const traitModules = Object.entries(modules).filter(([, text]) =>
+    /#\[klever_sc::(contract|module)\]/.test(text)
+  );
+  const findings = selected
+    .flatMap(check => {
+      const raw = check.crossModule
+        ? check.run(source, modules)
+        : [
+            ...check.run(source, {}),
+            ...traitModules.flatMap(([file, text]) =>
+              check.run(text, {}).map(finding => ({ ...finding, file }))
+            ),
+          ];
+      return raw.flatMap(finding => {
+        const setting = ruleSetting(check, finding.rule, config);
+        if (setting === 'off') return [];
+        return [setting ? { ...finding, severity: setting } : finding];
+      });
+    })
+    .sort((a, b) => (a.file || '').localeCompare(b.file || '') || a.line - b.line);
+  const summary: Record<Severity, number> = { error: 0, warning: 0, info: 0 };
+  for (const finding of findings) summary[finding.severity]++;
+  return { findings, summary, checks: selected.map(c => c.name) };
+}
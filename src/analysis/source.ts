@@ -0,0 +1,130 @@
+/**
+ * Contract source model shared by the analyzers: trait functions with their
+ * role, body text and line numbers.
+ */
+
+import { listTraitFunctions, type FunctionLocation } from '../codegen/rust-source.js';
+
+export type FunctionKind =
+  | 'init'
+  | 'upgrade'
+  | 'endpoint'
+  | 'view'
+  | 'callback'
+  | 'storage'
+  | 'event'
+  | 'proxy'
+  | 'private';
+
+export interface ContractFunction extends FunctionLocation {
+  kind: FunctionKind;
+  /** Body text between the braces; empty for declarations */
+  body: string;
+  /** 1-based line of the `fn` keyword */
+  line: number;
+  /** 1-based line of the closing brace or semicolon */
+  endLine: number;
+}
+
+const KINDS: [RegExp, FunctionKind][] = [
+  [/^#\[storage_mapper\(/, 'storage'],
+  [/^#\[event\(/, 'event'],
+  [/^#\[proxy\]/, 'proxy'],
+  [/^#\[init\]/, 'init'],
+  [/^#\[upgrade\]/, 'upgrade'],
+  [/^#\[(promises_)?callback\]/, 'callback'],
+  [/^#\[view[(\]]/, 'view'],
+  [/^#\[endpoint[(\]]/, 'endpoint'],
+];
+
+export interface StorageWrite {
+  /** Offset of the `self.<mapper>` call in the searched text */
+  index: number;
+  mapper: string;
+  /** Short form for messages, e.g. `self.balance().set(..)` */
+  text: string;
+}
+
+const WRITE_METHODS =
+  'set|update|clear|insert|push|remove|swap_remove|set_if_empty|take|append|push_back|push_front|pop_front|pop_back';
+
+/** Token transfers and contract calls that move funds */
+export const SENDS_FUNDS =
+  /self\s*\.\s*send\(\)|\.\s*(async_call|transfer_execute|with_klv_transfer|with_kda_transfer)\s*\(/;
+
+export function lineAt(source: string, index: number): number {
+  let line = 1;
+  for (let i = source.indexOf('\n'); i !== -1 && i < index; i = source.indexOf('\n', i + 1)) {
+    line++;
+  }
+  return line;
+}
+
+/** Blank out comments and string contents (keeping the quotes) so code patterns skip them */
+export function maskNonCode(text: string): string {
+  return text.replace(/\/\/[^\n]*|"(?:[^"\\]|\\.)*"/g, m =>
+    m.startsWith('"') ? `"${' '.repeat(m.length - 2)}"` : ' '.repeat(m.length)
+  );
+}
+
+function functionKind(fn: FunctionLocation): FunctionKind {
+  for (const [pattern, kind] of KINDS) {
+    if (fn.attributes.some(a => pattern.test(a))) return kind;
+  }
+  return 'private';
+}
+
+export function contractFunctions(source: string): ContractFunction[] {
+  return listTraitFunctions(source).map(fn => ({
+    ...fn,
+    kind: functionKind(fn),
+    body: fn.bodyStart === -1 ? '' : source.slice(fn.bodyStart, fn.end),
+    line: lineAt(source, source.indexOf('fn ', fn.start)),
+    endLine: lineAt(source, fn.end),
+  }));
+}
+
+/** Endpoints callers can reach with a transaction (not views, storage or events) */
+export function isCallable(fn: ContractFunction): boolean {
+  return fn.kind === 'endpoint' || fn.kind === 'init' || fn.kind === 'upgrade';
+}
+
+/** Storage writes through `self.<mapper>(...).<method>(`, including multi-line chains */
+export function storageWrites(body: string, mappers: Set<string>): StorageWrite[] {
+  const pattern = new RegExp(
+    `self\\s*\\.\\s*(\\w+)\\s*\\((?:[^()]|\\([^()]*\\))*\\)\\s*\\.\\s*(${WRITE_METHODS})\\s*\\(`,
+    'g'
+  );
+  return [...body.matchAll(pattern)]
+    .filter(m => mappers.has(m[1]))
+    .map(m => ({ index: m.index!, mapper: m[1], text: `self.${m[1]}().${m[2]}(..)` }));
+}
+
+export interface StorageAccess {
+  /** Offset of the `self.<mapper>` call in the searched text */
+  index: number;
+  mapper: string;
+  /** Mapper method called, e.g. `get` or `update` */
+  method: string;
+  access: 'read' | 'write';
+  /** Key arguments of the mapper call as written, e.g. `&caller` */
+  key: string;
+}
+
+/** Reads and writes through `self.<mapper>(...).<method>(`, in text order */
+export function storageAccesses(body: string, mappers: Set<string>): StorageAccess[] {
+  if (mappers.size === 0) return [];
+  const names = [...mappers].join('|');
+  const pattern = new RegExp(
+    `self\\s*\\.\\s*(${names})\\s*\\(((?:[^()]|\\([^()]*\\))*)\\)\\s*\\.\\s*(\\w+)\\s*\\(`,
+    'g'
+  );
+  const writes = new RegExp(`^(${WRITE_METHODS})$`);
+  return [...body.matchAll(pattern)].map(m => ({
+    index: m.index!,
+    mapper: m[1],
+    method: m[3],
+    access: writes.test(m[3]) ? 'write' : 'read',
+    key: m[2].replace(/\s+/g, ' ').trim(),
+  }));
+}
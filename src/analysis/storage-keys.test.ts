@@ -0,0 +1,60 @@
+import { checkStorageKeys } from './storage-keys.js';
+import { lintContract } from './index.js';
+
+const LIB = `#[klever_sc::contract]
+pub trait Token: storage::StorageModule {
+    #[storage_mapper("balance")]
+    fn balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("balanceOf")]
+    fn balance_of(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("fee")]
+    fn fee(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("feeBps")]
+    fn fee_bps(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("items")]
+    fn items(&self) -> VecMapper<u64>;
+
+    #[storage_mapper("items.len")]
+    fn items_len(&self) -> SingleValueMapper<usize>;
+}
+`;
+
+const STORAGE = `use klever_sc::imports::*;
+
+#[klever_sc::module]
+pub trait StorageModule {
+    #[storage_mapper("totalSupply")]
+    fn total_supply(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("fee")]
+    fn protocol_fee(&self) -> SingleValueMapper<u32>;
+}
+`;
+
+describe('checkStorageKeys', () => {
+  it('reports duplicate and prefix-colliding keys across modules', () => {
+    const findings = checkStorageKeys(LIB, { 'src/storage.rs': STORAGE });
+
+    expect(findings.map(f => [f.rule, f.severity, f.function, f.file])).toEqual([
+      ['storage-key-prefix', 'warning', 'balance_of', undefined],
+      ['storage-key-prefix', 'info', 'fee_bps', undefined],
+      ['storage-key-duplicate', 'error', 'protocol_fee', 'src/storage.rs'],
+      ['storage-key-prefix', 'error', 'items_len', undefined],
+    ]);
+    expect(findings[2].message).toBe(
+      'Storage key "fee" is declared by both fee() and protocol_fee() in src/storage.rs; they read and write the same data'
+    );
+  });
+
+  it('runs once over the whole crate from lintContract', () => {
+    const { findings } = lintContract(LIB, {
+      checks: ['storage-keys'],
+      modules: { 'src/storage.rs': STORAGE },
+    });
+    expect(findings.filter(f => f.rule === 'storage-key-duplicate')).toHaveLength(1);
+  });
+});
@@ -0,0 +1,109 @@
+/**
+ * Storage key collisions across the contract and its modules. Mappers with key
+ * arguments, and collection mappers, store entries under `<key><suffix>`, so a
+ * key that is a prefix of another ("balance" vs "balanceOf") can address the
+ * same storage slot and silently overwrite it.
+ */
+
+import { contractFunctions, type ContractFunction } from './source.js';
+import type { AnalysisCheck, Finding, ModuleSources } from './types.js';
+
+interface KeyDecl {
+  key: string;
+  fn: ContractFunction;
+  file?: string;
+  mapper: string;
+}
+
+/** Suffixes collection mappers append to their base key for internal entries */
+const INTERNAL_SUFFIX =
+  /^\.(len|item|index|value|mapped|info|node|node_links|node_id|id|address|count)/;
+
+function declarations(source: string, file?: string): KeyDecl[] {
+  return contractFunctions(source)
+    .filter(fn => fn.kind === 'storage')
+    .map(fn => {
+      const key = fn.attributes
+        .map(a => /^#\[storage_mapper\("([^"]*)"\)\]/.exec(a))
+        .find(Boolean)!;
+      const mapper = /->\s*(\w+)/.exec(fn.signature);
+      return { key: key[1], fn, file, mapper: mapper ? mapper[1] : '' };
+    });
+}
+
+/** A plain SingleValueMapper without key arguments occupies exactly its own key */
+function isExact(decl: KeyDecl): boolean {
+  return decl.mapper === 'SingleValueMapper' && !/\(\s*&?self\s*,/.test(decl.fn.signature);
+}
+
+function where(decl: KeyDecl): string {
+  return `${decl.fn.name}()${decl.file ? ` in ${decl.file}` : ''}`;
+}
+
+export function checkStorageKeys(source: string, modules: ModuleSources): Finding[] {
+  const decls = [
+    ...declarations(source),
+    ...Object.entries(modules)
+      .filter(([, text]) => /#\[klever_sc::(contract|module)\]/.test(text))
+      .flatMap(([file, text]) => declarations(text, file)),
+  ];
+  const findings: Finding[] = [];
+  // Duplicated keys are reported once; prefix checks only use their first declaration
+  const firstOf = (decl: KeyDecl) => decls.findIndex(d => d.key === decl.key);
+
+  for (let i = 0; i < decls.length; i++) {
+    for (let j = 0; j < decls.length; j++) {
+      if (i === j) continue;
+      const a = decls[i];
+      const b = decls[j];
+      const at = { function: b.fn.name, file: b.file, line: b.fn.line };
+
+      if (a.key === b.key) {
+        // Report each duplicate pair once, on the later declaration
+        if (j < i) continue;
+        findings.push({
+          rule: 'storage-key-duplicate',
+          severity: 'error',
+          message: `Storage key "${b.key}" is declared by both ${where(a)} and ${where(b)}; they read and write the same data`,
+          ...at,
+          suggestion: `Give ${b.fn.name}() its own key, or reuse ${a.fn.name}() instead of declaring a second mapper.`,
+        });
+      } else if (b.key.startsWith(a.key) && firstOf(a) === i && firstOf(b) === j) {
+        const suffix = b.key.slice(a.key.length);
+        const internal = INTERNAL_SUFFIX.test(suffix) && !isExact(a);
+        findings.push({
+          rule: 'storage-key-prefix',
+          severity: internal ? 'error' : isExact(a) ? 'info' : 'warning',
+          message: internal
+            ? `Storage key "${b.key}" of ${where(b)} matches an internal entry of ${a.mapper} "${a.key}" (${where(a)})`
+            : isExact(a)
+              ? `Storage key "${a.key}" (${where(a)}) is a prefix of "${b.key}" (${where(b)}); harmless today, but collides if ${a.fn.name}() gains key arguments`
+              : `Storage key "${a.key}" (${where(a)}) is a prefix of "${b.key}" (${where(b)}); entries of ${a.fn.name}() whose encoded key starts with "${suffix}" overwrite ${b.fn.name}()`,
+          ...at,
+          suggestion: `Rename "${b.key}" so it no longer starts with "${a.key}"; keys are compared as raw bytes.`,
+        });
+      }
+    }
+  }
+  return findings;
+}
+
+export const storageKeyCheck: AnalysisCheck = {
+  name: 'storage-keys',
+  description:
+    'Duplicate or prefix-colliding #[storage_mapper] keys across the contract and its modules',
+  crossModule: true,
+  rules: [
+    {
+      id: 'storage-key-duplicate',
+      severity: 'error',
+      description: 'Two storage mappers declared with the same key',
+    },
+    {
+      id: 'storage-key-prefix',
+      severity: 'warning',
+      description: 'A storage key that is a prefix of another and may collide with its entries',
+    },
+  ],
+  run: checkStorageKeys,
+};
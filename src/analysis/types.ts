@@ -0,0 +1,37 @@
+export type Severity = 'error' | 'warning' | 'info';
+
+export interface Finding {
+  /** Rule identifier, e.g. `missing-address-check` */
+  rule: string;
+  severity: Severity;
+  message: string;
+  /** Trait function the finding belongs to */
+  function?: string;
+  /** Module file the finding is in; omitted for the contract source itself */
+  file?: string;
+  /** 1-based line in the analyzed source */
+  line: number;
+  /** Last line of the flagged span when it covers more than one line */
+  endLine?: number;
+  suggestion?: string;
+}
+
+/** Module sources keyed by path relative to the crate root, e.g. `src/storage.rs` */
+export type ModuleSources = Record<string, string>;
+
+/** A rule a check can report, as listed in findings' `rule` field */
+export interface RuleInfo {
+  id: string;
+  /** Severity reported unless a lint config overrides it */
+  severity: Severity;
+  description: string;
+}
+
+export interface AnalysisCheck {
+  name: string;
+  description: string;
+  /** Checks the contract and its modules together instead of one file at a time */
+  crossModule?: boolean;
+  rules: RuleInfo[];
+  run(source: string, modules: ModuleSources): Finding[];
+}
@@ -0,0 +1,99 @@
+import { checkUpgradeSafety } from './upgrade-safety.js';
+
+const OLD = `#[klever_sc::contract]
+pub trait Vault {
+    #[storage_mapper("owner")]
+    fn owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("fee")]
+    fn fee(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("deposits")]
+    fn deposits(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("limits")]
+    fn limits(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("legacy")]
+    fn legacy(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("users")]
+    fn users(&self) -> SetMapper<ManagedAddress>;
+}
+`;
+
+const NEW = `#[klever_sc::contract]
+pub trait Vault {
+    #[storage_mapper("owner")]
+    fn admin(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("fee")]
+    fn fee(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("deposits")]
+    fn deposits(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("limitsV2")]
+    fn limits(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("members")]
+    fn users(&self) -> SetMapper<ManagedAddress>;
+
+    #[upgrade]
+    fn upgrade(&self) {
+        let old = SetMapper::<Self::Api, ManagedAddress>::new(StorageKey::new(b"users"));
+        for user in old.iter() {
+            self.users().insert(user);
+        }
+    }
+}
+`;
+
+describe('checkUpgradeSafety', () => {
+  it('classifies reinterpreted, widened, orphaned and migrated storage', () => {
+    const report = checkUpgradeSafety(OLD, NEW);
+    expect(report.findings.map(f => [f.rule, f.severity, f.function, f.line])).toEqual([
+      ['storage-type-widened', 'info', 'fee', 7],
+      ['storage-reinterpreted', 'error', 'deposits', 10],
+      ['storage-orphaned', 'warning', 'limits', 13],
+      ['storage-orphaned', 'info', 'legacy', 16],
+      ['storage-key-migrated', 'info', 'users', 19],
+    ]);
+    expect(report.findings[1].message).toBe(
+      'Key "deposits" of deposits: SingleValueMapper<BigUint> -> SingleValueMapper<u64> is not a widening; stored values would no longer decode'
+    );
+    expect(report.findings[2].suggestion).toContain('#[storage_mapper("limits")]');
+    expect(report.summary).toEqual({ error: 1, warning: 1, info: 3 });
+    expect(report.safe).toBe(false);
+  });
+
+  it('accepts mappers renamed or moved into modules under the same key', () => {
+    const lib = `#[klever_sc::contract]
+pub trait Vault: storage::StorageModule {}
+`;
+    const storage = `#[klever_sc::module]
+pub trait StorageModule {
+    #[storage_mapper("owner")]
+    fn admin(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("fee")]
+    fn fee(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("deposits")]
+    fn deposits(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("limits")]
+    fn limits(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("legacy")]
+    fn legacy(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("users")]
+    fn users(&self) -> SetMapper<ManagedAddress>;
+}
+`;
+    const report = checkUpgradeSafety(OLD, lib, { newModules: { 'src/storage.rs': storage } });
+    expect(report.findings).toEqual([]);
+    expect(report.safe).toBe(true);
+  });
+});
@@ -0,0 +1,129 @@
+/**
+ * Upgrade safety: compare the storage layout of the deployed contract with
+ * the version about to replace it. Storage survives an upgrade byte for
+ * byte, so a key the new code no longer reads is orphaned and a key it reads
+ * with a different type is reinterpreted.
+ */
+
+import { diffStorageMapper } from '../codegen/migration.js';
+import { listStorageMappers, type StorageMapperDecl } from '../codegen/rust-source.js';
+import { contractFunctions } from './source.js';
+import type { Finding, ModuleSources, Severity } from './types.js';
+
+export interface UpgradeSafetyOptions {
+  /** Module files of the deployed version, keyed by path */
+  oldModules?: ModuleSources;
+  /** Module files of the new version, keyed by path */
+  newModules?: ModuleSources;
+}
+
+export interface UpgradeSafetyReport {
+  /** False when existing storage would be reinterpreted */
+  safe: boolean;
+  findings: Finding[];
+  summary: Record<Severity, number>;
+}
+
+interface Located {
+  decl: StorageMapperDecl;
+  file?: string;
+  line: number;
+}
+
+function storageLayout(source: string, modules: ModuleSources): Located[] {
+  const files: [string | undefined, string][] = [
+    [undefined, source],
+    ...Object.entries(modules).filter(([, text]) => /#\[klever_sc::(contract|module)\]/.test(text)),
+  ];
+  return files.flatMap(([file, text]) => {
+    const functions = contractFunctions(text);
+    return listStorageMappers(text).map(decl => ({
+      decl,
+      file,
+      line: functions.find(f => f.name === decl.functionName)!.line,
+    }));
+  });
+}
+
+/** Old keys read back with raw StorageKey::new(b"...") mappers, e.g. by a migration */
+function rawKeys(source: string, modules: ModuleSources): Set<string> {
+  const text = [source, ...Object.values(modules)].join('\n');
+  return new Set([...text.matchAll(/StorageKey::new\(\s*b"([^"]*)"/g)].map(m => m[1]));
+}
+
+/**
+ * Check that `newSource` can be deployed as an upgrade of `oldSource`
+ * without losing or misreading existing storage. Findings about mappers the
+ * new version no longer declares point at the deployed source.
+ */
+export function checkUpgradeSafety(
+  oldSource: string,
+  newSource: string,
+  options: UpgradeSafetyOptions = {}
+): UpgradeSafetyReport {
+  const before = storageLayout(oldSource, options.oldModules || {});
+  const after = storageLayout(newSource, options.newModules || {});
+  const migrated = rawKeys(newSource, options.newModules || {});
+  const findings: Finding[] = [];
+
+  for (const old of before) {
+    const { storageKey, functionName } = old.decl;
+    const readers = after.filter(n => n.decl.storageKey === storageKey);
+
+    for (const next of readers) {
+      const { change } = diffStorageMapper(old.decl, next.decl);
+      if (!change) continue;
+      const renamed =
+        next.decl.functionName !== functionName ? ` (now ${next.decl.functionName})` : '';
+      findings.push({
+        rule: change.safe ? 'storage-type-widened' : 'storage-reinterpreted',
+        severity: change.safe ? 'info' : 'error',
+        message: `Key "${storageKey}" of ${functionName}${renamed}: ${change.detail}`,
+        function: next.decl.functionName,
+        file: next.file,
+        line: next.line,
+        suggestion: change.safe
+          ? undefined
+          : `Keep ${old.decl.returnType} under "${storageKey}" and store the new layout under a new key, migrating the data with generate_migration.`,
+      });
+    }
+    if (readers.length > 0) continue;
+
+    const moved = after.find(n => n.decl.functionName === functionName);
+    if (migrated.has(storageKey)) {
+      findings.push({
+        rule: 'storage-key-migrated',
+        severity: 'info',
+        message: `Key "${storageKey}" of ${functionName} is no longer declared but the new version reads it through a raw StorageKey, presumably to migrate or clear it`,
+        function: functionName,
+        file: old.file,
+        line: old.line,
+      });
+    } else if (moved) {
+      findings.push({
+        rule: 'storage-orphaned',
+        severity: 'warning',
+        message: `${functionName} moved from key "${storageKey}" to "${moved.decl.storageKey}"; data already stored under "${storageKey}" is no longer read and the mapper starts empty`,
+        function: functionName,
+        file: moved.file,
+        line: moved.line,
+        suggestion: `Keep #[storage_mapper("${storageKey}")], or copy the data in #[upgrade] with generate_migration.`,
+      });
+    } else {
+      findings.push({
+        rule: 'storage-orphaned',
+        severity: 'info',
+        message: `${functionName} (key "${storageKey}") was removed; its data stays in storage unless #[upgrade] clears it`,
+        function: functionName,
+        file: old.file,
+        line: old.line,
+        suggestion:
+          'If the data is obsolete, clear it in #[upgrade] through a raw StorageKey mapper (generate_migration emits this).',
+      });
+    }
+  }
+
+  const summary: Record<Severity, number> = { error: 0, warning: 0, info: 0 };
+  for (const finding of findings) summary[finding.severity]++;
+  return { safe: summary.error === 0, findings, summary };
+}
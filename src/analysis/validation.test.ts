@@ -0,0 +1,57 @@
+import { readFileSync } from 'fs';
+import { checkInputValidation } from './validation.js';
+import { lintContract } from './index.js';
+
+const EXAMPLE = readFileSync('examples/token_contract.rs', 'utf8');
+
+const UNCHECKED = `#[klever_sc::contract]
+pub trait Vault {
+    #[endpoint]
+    fn send_to(&self, to: ManagedAddress, amount: BigUint) {
+        require!(amount >= 1u32, "Amount too small");
+        self.send().direct_klv(&to, &amount);
+    }
+
+    #[endpoint]
+    fn set_treasury(&self, treasury: ManagedAddress) {
+        if treasury.is_zero() {
+            sc_panic!("Invalid treasury");
+        }
+        self.treasury().set(&treasury);
+    }
+
+    #[endpoint]
+    fn withdraw(&self, amount: BigUint) {
+        let balance = self.balance().get();
+        require!(balance >= amount, "Insufficient balance");
+    }
+
+    #[view(getBalanceOf)]
+    fn get_balance_of(&self, address: ManagedAddress) -> BigUint {
+        self.balance().get()
+    }
+}
+`;
+
+describe('checkInputValidation', () => {
+  it('accepts the checks used by the token example', () => {
+    expect(checkInputValidation(EXAMPLE)).toEqual([]);
+  });
+
+  it('flags unchecked address and amount arguments of endpoints', () => {
+    const findings = checkInputValidation(UNCHECKED);
+
+    expect(findings.map(f => [f.rule, f.function, f.line])).toEqual([
+      ['missing-address-check', 'send_to', 4],
+      ['missing-amount-check', 'withdraw', 18],
+    ]);
+    expect(findings[1].suggestion).toBe('require!(amount > 0, "Amount must be positive");');
+  });
+});
+
+describe('lintContract', () => {
+  it('summarises findings and rejects unknown checks', () => {
+    expect(lintContract(UNCHECKED).summary).toEqual({ error: 0, warning: 2, info: 0 });
+    expect(() => lintContract(UNCHECKED, { checks: ['nope'] })).toThrow('Unknown check(s): nope');
+  });
+});
@@ -0,0 +1,88 @@
+/**
+ * Flag endpoints that accept addresses or amounts without validating them,
+ * following the checks in examples/token_contract.rs:
+ * `require!(!to.is_zero(), ...)` and `require!(amount > 0, ...)`.
+ */
+
+import { parseParams } from '../codegen/rust-source.js';
+import { contractFunctions } from './source.js';
+import type { AnalysisCheck, Finding } from './types.js';
+
+const ADDRESS_TYPE = /^&?ManagedAddress\b/;
+const AMOUNT_TYPE = /^&?BigUint\b/;
+
+/** Conditions of every require!/if in the body; sc_panic! branches count as checks too */
+function guardConditions(body: string): string[] {
+  const conditions: string[] = [];
+  for (const match of body.matchAll(/require!\s*\(([\s\S]*?),\s*"/g)) {
+    conditions.push(match[1]);
+  }
+  for (const match of body.matchAll(/\bif\s+([^{]+)\{[^}]*sc_panic!/g)) {
+    conditions.push(match[1]);
+  }
+  return conditions;
+}
+
+function checksAddress(conditions: string[], n: string): boolean {
+  const pattern = new RegExp(
+    `\\b${n}\\.is_zero\\(\\)|\\b${n}\\s*!=\\s*ManagedAddress::zero\\(\\)`
+  );
+  return conditions.some(c => pattern.test(c));
+}
+
+function checksAmount(conditions: string[], n: string): boolean {
+  const pattern = new RegExp(
+    `\\b${n}\\s*(>|>=|!=)\\s*[^=]|\\b${n}\\.is_zero\\(\\)|\\b(0\\w*|BigUint::zero\\(\\))\\s*(<|!=)\\s*&?${n}\\b`
+  );
+  return conditions.some(c => pattern.test(c));
+}
+
+export function checkInputValidation(source: string): Finding[] {
+  const findings: Finding[] = [];
+  for (const fn of contractFunctions(source)) {
+    if (fn.kind !== 'endpoint') continue;
+    const conditions = guardConditions(fn.body);
+    for (const param of parseParams(fn.signature)) {
+      if (ADDRESS_TYPE.test(param.type) && !checksAddress(conditions, param.name)) {
+        findings.push({
+          rule: 'missing-address-check',
+          severity: 'warning',
+          message: `${fn.name}: address argument \`${param.name}\` is not checked against the zero address`,
+          function: fn.name,
+          line: fn.line,
+          suggestion: `require!(!${param.name}.is_zero(), "Invalid address");`,
+        });
+      }
+      if (AMOUNT_TYPE.test(param.type) && !checksAmount(conditions, param.name)) {
+        findings.push({
+          rule: 'missing-amount-check',
+          severity: 'warning',
+          message: `${fn.name}: amount argument \`${param.name}\` is not checked to be positive`,
+          function: fn.name,
+          line: fn.line,
+          suggestion: `require!(${param.name} > 0, "Amount must be positive");`,
+        });
+      }
+    }
+  }
+  return findings;
+}
+
+export const inputValidationCheck: AnalysisCheck = {
+  name: 'input-validation',
+  description:
+    'Endpoints taking ManagedAddress/BigUint arguments without zero-address or zero-amount checks',
+  rules: [
+    {
+      id: 'missing-address-check',
+      severity: 'warning',
+      description: 'ManagedAddress argument stored or paid to without a zero-address check',
+    },
+    {
+      id: 'missing-amount-check',
+      severity: 'warning',
+      description: 'BigUint amount argument used without a zero-amount check',
+    },
+  ],
+  run: checkInputValidation,
+};
@@ -0,0 +1,97 @@
+import { readFileSync } from 'fs';
+import { checkViewPurity } from './view-purity.js';
+
+const EXAMPLE = readFileSync('examples/token_contract.rs', 'utf8');
+
+const BANK = `#[klever_sc::contract]
+pub trait Bank {
+    #[view(getBalance)]
+    fn get_balance(&self, address: ManagedAddress) -> BigUint {
+        let balance = self.balances(&address).get();
+        self.last_query().set(&address);
+        balance
+    }
+
+    #[view(getRewards)]
+    fn get_rewards(&self) -> BigUint {
+        self.accrue();
+        self.accrue();
+        self.rewards().get()
+    }
+
+    #[view(payOut)]
+    fn pay_out(&self, to: ManagedAddress) {
+        // self.total().clear() is only mentioned here
+        self.send().direct_klv(&to, &self.rewards().get());
+    }
+
+    #[view(getTotal)]
+    fn get_total(&self) -> BigUint {
+        self.total().get()
+    }
+
+    #[endpoint]
+    fn claim(&self) {
+        self.rewards().clear();
+    }
+
+    fn accrue(&self) {
+        self.rewards().update(|r| *r += 1u64);
+    }
+
+    #[storage_mapper("balances")]
+    fn balances(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("lastQuery")]
+    fn last_query(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("rewards")]
+    fn rewards(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getTotalSupply)]
+    #[storage_mapper("total")]
+    fn total(&self) -> SingleValueMapper<BigUint>;
+}
+`;
+
+describe('checkViewPurity', () => {
+  it('flags views that write storage, directly or through a helper, or send funds', () => {
+    const findings = checkViewPurity(BANK, {});
+    expect(findings.map(f => [f.rule, f.function, f.line])).toEqual([
+      ['view-mutates-storage', 'get_balance', 6],
+      ['view-mutates-storage', 'get_rewards', 12],
+      ['view-sends-funds', 'pay_out', 20],
+    ]);
+    expect(findings[0].message).toContain('self.last_query().set(..)');
+    expect(findings[1].message).toContain('calls accrue(), which writes rewards');
+    expect(findings.every(f => f.severity === 'error')).toBe(true);
+  });
+
+  it('checks views declared in modules', () => {
+    const storage = `#[klever_sc::module]
+pub trait StorageModule {
+    #[view(getCount)]
+    fn get_count(&self) -> u64 {
+        self.count().update(|c| *c += 1);
+        self.count().get()
+    }
+
+    #[storage_mapper("count")]
+    fn count(&self) -> SingleValueMapper<u64>;
+}
+`;
+    const findings = checkViewPurity(EXAMPLE, { 'src/storage.rs': storage });
+    expect(findings).toEqual([
+      expect.objectContaining({
+        rule: 'view-mutates-storage',
+        function: 'get_count',
+        file: 'src/storage.rs',
+        line: 5,
+      }),
+    ]);
+  });
+
+  it('reports nothing for the example token contract', () => {
+    expect(checkViewPurity(EXAMPLE, {})).toEqual([]);
+  });
+});
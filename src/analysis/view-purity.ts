@@ -0,0 +1,100 @@
+/**
+ * View purity: #[view] functions run as read-only queries, so a storage write
+ * or transfer inside one compiles fine but fails every query at runtime.
+ */
+
+import { listStorageMappers } from '../codegen/rust-source.js';
+import { contractFunctions, lineAt, maskNonCode, SENDS_FUNDS, storageWrites } from './source.js';
+import type { AnalysisCheck, Finding, ModuleSources } from './types.js';
+
+export function checkViewPurity(source: string, modules: ModuleSources): Finding[] {
+  const files: [string | undefined, string][] = [
+    [undefined, source],
+    ...Object.entries(modules).filter(([, text]) => /#\[klever_sc::(contract|module)\]/.test(text)),
+  ];
+  const functions = files.flatMap(([file, text]) =>
+    contractFunctions(text).map(fn => ({ fn, file, text }))
+  );
+  const mappers = new Set(
+    files.flatMap(([, text]) => listStorageMappers(text).map(m => m.functionName))
+  );
+  const helpers = new Map(
+    functions.filter(({ fn }) => fn.kind === 'private').map(({ fn }) => [fn.name, fn])
+  );
+
+  const findings: Finding[] = [];
+  for (const { fn, file, text } of functions) {
+    if (fn.kind !== 'view') continue;
+    const code = maskNonCode(fn.body);
+    const at = (index: number) => lineAt(text, fn.bodyStart + index);
+
+    const writes = storageWrites(code, mappers);
+    if (writes.length > 0) {
+      findings.push({
+        rule: 'view-mutates-storage',
+        severity: 'error',
+        message: `View ${fn.name} writes storage (${writes.map(w => w.text).join(', ')}); queries run read-only and will fail`,
+        function: fn.name,
+        file,
+        line: at(writes[0].index),
+        suggestion:
+          'Move the write into an #[endpoint], or compute the value without storing it.',
+      });
+    }
+    const send = SENDS_FUNDS.exec(code);
+    if (send) {
+      findings.push({
+        rule: 'view-sends-funds',
+        severity: 'error',
+        message: `View ${fn.name} transfers funds or calls another contract; views cannot change state`,
+        function: fn.name,
+        file,
+        line: at(send.index),
+        suggestion: 'Turn it into an #[endpoint] so it runs in a transaction.',
+      });
+    }
+
+    // Mutations hidden one level down in a private helper
+    const checked = new Set<string>();
+    for (const match of code.matchAll(/self\s*\.\s*(\w+)\s*\(/g)) {
+      const helper = helpers.get(match[1]);
+      if (!helper || checked.has(helper.name)) continue;
+      checked.add(helper.name);
+      const helperCode = maskNonCode(helper.body);
+      const effects = [
+        ...new Set(storageWrites(helperCode, mappers).map(w => `writes ${w.mapper}`)),
+        ...(SENDS_FUNDS.test(helperCode) ? ['sends funds'] : []),
+      ];
+      if (effects.length === 0) continue;
+      findings.push({
+        rule: 'view-mutates-storage',
+        severity: 'error',
+        message: `View ${fn.name} calls ${helper.name}(), which ${effects.join(' and ')}; queries run read-only and will fail`,
+        function: fn.name,
+        file,
+        line: at(match.index!),
+        suggestion: `Call ${helper.name}() from an #[endpoint] instead, or split out a read-only variant for the view.`,
+      });
+    }
+  }
+  return findings;
+}
+
+export const viewPurityCheck: AnalysisCheck = {
+  name: 'view-purity',
+  description: 'Views that write storage or send funds, directly or through a helper',
+  crossModule: true,
+  rules: [
+    {
+      id: 'view-mutates-storage',
+      severity: 'error',
+      description: 'View that writes storage, directly or through a helper',
+    },
+    {
+      id: 'view-sends-funds',
+      severity: 'error',
+      description: 'View that transfers funds or calls another contract',
+    },
+  ],
+  run: checkViewPurity,
+};
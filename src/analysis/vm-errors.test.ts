@@ -0,0 +1,89 @@
+import { parseAbi } from '../abi/index.js';
+import { explainVmError, VM_ERRORS } from './vm-errors.js';
+
+const ABI = parseAbi({
+  name: 'Staking',
+  endpoints: [
+    {
+      name: 'stake',
+      mutability: 'mutable',
+      payableInTokens: ['KLV'],
+      inputs: [
+        { name: 'duration', type: 'u64' },
+        { name: 'referrer', type: 'optional<Address>' },
+      ],
+      outputs: [],
+    },
+    {
+      name: 'setRate',
+      mutability: 'mutable',
+      onlyOwner: true,
+      inputs: [{ name: 'rate', type: 'u32' }],
+      outputs: [],
+    },
+    { name: 'claim', mutability: 'mutable', inputs: [], outputs: [] },
+  ],
+});
+
+const ids = (error: string | string[]) => explainVmError(error).matches.map(m => m.id);
+
+describe('VM_ERRORS', () => {
+  it('has unique ids and one generic fallback', () => {
+    expect(new Set(VM_ERRORS.map(e => e.id)).size).toBe(VM_ERRORS.length);
+    expect(VM_ERRORS.filter(e => e.generic).map(e => e.id)).toEqual(['execution-failed']);
+  });
+});
+
+describe('explainVmError', () => {
+  it('matches common VM and framework errors', () => {
+    expect(ids('not enough gas')).toEqual(['out-of-gas']);
+    expect(ids('invalid function (not found)')).toEqual(['function-not-found']);
+    expect(ids('storage decode error (key: totalStaked): input too short')).toEqual([
+      'storage-decode',
+    ]);
+    expect(ids('function does not accept KLV payment')).toEqual(['non-payable']);
+    expect(ids('Endpoint can only be called by owner')).toEqual(['owner-only']);
+  });
+
+  it('reports execution failed only when nothing more specific matches', () => {
+    expect(ids('ExecutionFailed')).toEqual(['execution-failed']);
+    expect(ids(['ExecutionFailed', 'out of gas'])).toEqual(['out-of-gas']);
+  });
+
+  it('takes an unknown text next to a failure as the contract message', () => {
+    const result = explainVmError(['ExecutionFailed', 'Amount must be positive']);
+    expect(result.matches.map(m => m.id)).toEqual(['user-error']);
+    expect(result.message).toBe('Amount must be positive');
+    expect(explainVmError('user error: Pool is closed').message).toBe('Pool is closed');
+  });
+
+  it('leaves the message out when the error carries none', () => {
+    expect(explainVmError('ExecutionFailed').message).toBeUndefined();
+    expect(ids('user error')).toEqual(['user-error']);
+    expect(explainVmError('user error').message).toBeUndefined();
+  });
+
+  it('suggests the closest endpoint from the ABI', () => {
+    const result = explainVmError('invalid function (not found)', { abi: ABI, endpoint: 'stak' });
+    expect(result.details).toEqual([
+      'Staking has no endpoint stak',
+      'Did you mean stake?',
+      'Endpoints: stake, setRate, claim',
+    ]);
+  });
+
+  it('adds the endpoint signature, payments and owner check', () => {
+    const context = { abi: ABI, endpoint: 'stake' };
+    const decode = explainVmError('argument decode error (duration): input too long', context);
+    expect(decode.details).toEqual([
+      'Expected: stake(duration: u64, referrer: optional<Address>)',
+      'duration is u64',
+    ]);
+    expect(explainVmError('bad token provided', context).details).toEqual(['stake accepts KLV']);
+    const owner = explainVmError('Endpoint can only be called by owner', {
+      abi: ABI,
+      endpoint: 'setRate',
+    });
+    expect(owner.details).toEqual(['setRate is #[only_owner]']);
+  });
+});
@@ -0,0 +1,268 @@
+/**
+ * Catalog of the errors the Klever VM and klever-sc report for failed
+ * contract calls, and matching of an error text against it. With the
+ * contract ABI the explanation adds what the called endpoint expects.
+ */
+
+import type { Abi, AbiEndpoint } from '../abi/index.js';
+import { editDistance } from './attribute-usage.js';
+
+export interface VmErrorEntry {
+  id: string;
+  title: string;
+  /** Error texts that identify it, matched case-insensitively */
+  patterns: RegExp[];
+  cause: string;
+  fix: string;
+  /** Only reported when no more specific entry matches */
+  generic?: boolean;
+}
+
+export const VM_ERRORS: VmErrorEntry[] = [
+  {
+    id: 'user-error',
+    title: 'Rejected by the contract',
+    patterns: [/user error/i, /signalError/i],
+    cause:
+      "The contract stopped the call with require!, sc_panic! or signal_error; the message is the contract's own.",
+    fix:
+      'Search the contract source for the message and check the condition in front of it: arguments, caller, payment or contract state. Reproduce with estimate_gas, which simulates the call without sending it.',
+  },
+  {
+    id: 'out-of-gas',
+    title: 'Out of gas',
+    patterns: [
+      /out of gas/i,
+      /not enough gas/i,
+      /gas limit (exceeded|too low)/i,
+      /insufficient gas/i,
+    ],
+    cause:
+      'The gas limit was below what the execution needed, including the gas forwarded to other contracts and reserved for callbacks.',
+    fix:
+      'Run estimate_gas with the same arguments and payment and send with its recommendedGasLimit. Async calls need the gas of the called endpoint plus the callback reserve.',
+  },
+  {
+    id: 'function-not-found',
+    title: 'Endpoint not found',
+    patterns: [/invalid function/i, /function not found/i, /unknown function/i],
+    cause:
+      'The deployed code has no endpoint with that name: a typo, the wrong contract or network, or an endpoint added after the code was deployed.',
+    fix:
+      'Compare the name with the deployed ABI (names are case-sensitive) and upgrade the contract if the endpoint is new.',
+  },
+  {
+    id: 'wrong-argument-count',
+    title: 'Wrong number of arguments',
+    patterns: [/wrong number of arguments/i, /too (few|many) arguments/i],
+    cause: 'The call carries more or fewer arguments than the endpoint takes.',
+    fix:
+      'Pass every input in ABI order. optional<T> may only be left out at the end; variadic<T> and multi<...> values count one argument per item.',
+  },
+  {
+    id: 'argument-decode',
+    title: 'Argument does not decode',
+    patterns: [/argument decode error/i, /arg(ument)? decode/i],
+    cause:
+      'The bytes of an argument do not fit its type: too short or long for a fixed-size type, an address that is not 32 bytes, invalid UTF-8 or an unknown enum discriminant.',
+    fix:
+      'Encode the arguments from the ABI (invoke_endpoint, generate_koperator_command or decode_tx_data to check existing call data) instead of by hand.',
+  },
+  {
+    id: 'storage-decode',
+    title: 'Stored value does not decode',
+    patterns: [/storage decode error/i],
+    cause:
+      "A storage value does not fit the type the code reads it as: usually an upgrade changed a mapper's type or a struct's fields, or two mappers share a storage key.",
+    fix:
+      'Run check_upgrade_safety against the previous version and lint_contract for colliding storage keys; migrate the stored values in #[upgrade] when a type has to change.',
+  },
+  {
+    id: 'non-payable',
+    title: 'Endpoint does not accept payments',
+    patterns: [/does not accept (klv|kda|esdt)?\s*payment/i, /non[- ]?payable/i, /not payable/i],
+    cause:
+      'A payment was sent to an endpoint without #[payable], or a transfer to a contract deployed without the payable code metadata.',
+    fix:
+      'Send the call without klv/kda, or add #[payable("...")] to the endpoint (or deploy with payable metadata) and upgrade.',
+  },
+  {
+    id: 'bad-token',
+    title: 'Unexpected payment token',
+    patterns: [
+      /bad token provided/i,
+      /wrong token/i,
+      /incorrect number of (kda|esdt) transfers/i,
+      /token not accepted/i,
+    ],
+    cause:
+      'The payment is not in the token, or not the number of transfers, the endpoint reads: single_kda() expects exactly one KDA transfer and payable("TOKEN") accepts only that token.',
+    fix:
+      'Pay with the token the endpoint accepts, one transfer unless it reads all_kda_transfers().',
+  },
+  {
+    id: 'owner-only',
+    title: 'Caller is not the owner',
+    patterns: [/can only be called by (the )?owner/i, /only owner/i, /caller is not (the )?owner/i],
+    cause: 'The endpoint is #[only_owner] (or checks the owner) and the sender is another account.',
+    fix: 'Sign with the owner key; get_account on the contract shows its owner.',
+  },
+  {
+    id: 'insufficient-funds',
+    title: 'Insufficient funds',
+    patterns: [/insufficient (funds|balance)/i, /not enough (balance|funds)/i],
+    cause:
+      'The sender cannot pay the fees plus the payment, or the contract lacks the balance for a transfer it makes.',
+    fix:
+      'Check balances with get_account (sender and contract); on testnet fund the account with request_test_funds.',
+  },
+  {
+    id: 'contract-not-found',
+    title: 'No contract at the address',
+    patterns: [/contract not found/i, /invalid contract code/i, /missing (contract )?code/i],
+    cause: 'The address has no deployed code: wrong address, wrong network, or the deploy failed.',
+    fix:
+      'Check the address and network with get_account, and the deploy transaction with get_transaction.',
+  },
+  {
+    id: 'upgrade-not-allowed',
+    title: 'Upgrade not allowed',
+    patterns: [/upgrade not allowed/i, /not upgradeable/i],
+    cause:
+      'The contract was deployed without the upgradeable code metadata, or the sender is not its owner.',
+    fix:
+      'Upgrade from the owner account. A contract deployed as not upgradeable cannot change; deploy a new one and migrate its state.',
+  },
+  {
+    id: 'panic',
+    title: 'Contract panicked',
+    patterns: [/panic/i, /unreachable/i, /wasm trap/i],
+    cause:
+      'The wasm aborted on a Rust panic: arithmetic overflow, division by zero, unwrap on None, or an index out of range.',
+    fix:
+      'Reproduce the call in a scenario test (generate_scenario) and check the arithmetic and unwraps on its path; build with panic messages to get the location.',
+  },
+  {
+    id: 'execution-failed',
+    title: 'Execution failed',
+    patterns: [/execution failed/i, /executionfailed/i, /contract invalid/i],
+    cause:
+      'The VM aborted the call without a more specific reason, typically a panic in a build without panic messages.',
+    fix:
+      'Look for a signalError event or error message in the transaction (get_transaction), then reproduce the call with estimate_gas or a scenario test.',
+    generic: true,
+  },
+];
+
+export interface VmErrorMatch {
+  id: string;
+  title: string;
+  cause: string;
+  fix: string;
+}
+
+export interface VmErrorExplanation {
+  error: string;
+  /** The contract's own message, for errors raised by the contract */
+  message?: string;
+  /** Catalog entries the error matches, most specific first; empty when none does */
+  matches: VmErrorMatch[];
+  /** What the ABI says about the called endpoint, as it bears on the error */
+  details: string[];
+}
+
+export interface VmErrorContext {
+  abi?: Abi;
+  /** Called endpoint, when known */
+  endpoint?: string;
+}
+
+/** Text after `user error:` or similar prefixes, when the error carries the contract's message */
+function userMessage(error: string): string | undefined {
+  const match = /(?:user error|signalError)\s*[:=-]?\s*(.+)/i.exec(error);
+  const text = match?.[1].trim();
+  return text && !/^(execution ?failed|user error)$/i.test(text) ? text : undefined;
+}
+
+function signature(endpoint: AbiEndpoint): string {
+  return `${endpoint.name}(${endpoint.inputs.map(i => `${i.name}: ${i.type}`).join(', ')})`;
+}
+
+function endpointDetails(id: string, error: string, abi: Abi, name: string | undefined): string[] {
+  const endpoint = name ? abi.endpoints.find(e => e.name === name) : undefined;
+  if (id === 'function-not-found') {
+    const named = /(?:function|endpoint)(?: not found)?\s*[:"'`]\s*(\w+)/i.exec(error);
+    const wanted = name || named?.[1];
+    if (!wanted || abi.endpoints.some(e => e.name === wanted)) {
+      return wanted ? [`${abi.name} declares ${wanted}; the deployed code is likely older`] : [];
+    }
+    const closest = abi.endpoints
+      .map(e => ({ name: e.name, distance: editDistance(wanted, e.name) }))
+      .sort((a, b) => a.distance - b.distance)[0];
+    return [
+      `${abi.name} has no endpoint ${wanted}`,
+      ...(closest && closest.distance <= 3 ? [`Did you mean ${closest.name}?`] : []),
+      `Endpoints: ${abi.endpoints.map(e => e.name).join(', ')}`,
+    ];
+  }
+  if (!endpoint) return [];
+  switch (id) {
+    case 'wrong-argument-count':
+    case 'argument-decode': {
+      const details = [`Expected: ${signature(endpoint)}`];
+      const arg = /argument decode error\s*\((\w+)\)/i.exec(error)?.[1];
+      const input = arg ? endpoint.inputs.find(i => i.name === arg) : undefined;
+      if (input) details.push(`${input.name} is ${input.type}`);
+      return details;
+    }
+    case 'non-payable':
+    case 'bad-token': {
+      const accepted = endpoint.payableInTokens || [];
+      if (accepted.length === 0) return [`${endpoint.name} is not payable`];
+      const tokens = accepted.includes('*') ? 'any token' : accepted.join(', ');
+      return [`${endpoint.name} accepts ${tokens}`];
+    }
+    case 'owner-only':
+      return endpoint.onlyOwner ? [`${endpoint.name} is #[only_owner]`] : [];
+    default:
+      return [];
+  }
+}
+
+function matches(entry: VmErrorEntry, text: string): boolean {
+  return entry.patterns.some(pattern => pattern.test(text));
+}
+
+/**
+ * Likely cause and fix of a failed contract call, from its error text or the
+ * error texts of its transaction. A text the catalog does not know, next to
+ * at most a generic failure, is taken as the contract's own message.
+ */
+export function explainVmError(
+  error: string | string[],
+  context: VmErrorContext = {}
+): VmErrorExplanation {
+  const texts = Array.isArray(error) ? error : [error];
+  const text = texts.join('\n');
+  const unknown = texts.filter(t => !VM_ERRORS.some(entry => matches(entry, t)));
+  const matched = VM_ERRORS.filter(entry => matches(entry, text));
+  const specific = matched.filter(entry => !entry.generic);
+  const entries =
+    specific.length > 0
+      ? specific
+      : unknown.length > 0
+        ? VM_ERRORS.filter(entry => entry.id === 'user-error')
+        : matched;
+  const details = context.abi
+    ? entries.flatMap(entry => endpointDetails(entry.id, text, context.abi!, context.endpoint))
+    : [];
+  const message = entries.some(entry => entry.id === 'user-error')
+    ? unknown.join('; ') || userMessage(text)
+    : undefined;
+  return {
+    error: text,
+    ...(message ? { message } : {}),
+    matches: entries.map(({ id, title, cause, fix }) => ({ id, title, cause, fix })),
+    details: [...new Set(details)],
+  };
+}
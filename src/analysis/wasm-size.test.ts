@@ -0,0 +1,85 @@
+import { analyzeWasmSize } from './wasm-size.js';
+
+function leb(value: number): number[] {
+  const out: number[] = [];
+  do {
+    let byte = value & 0x7f;
+    value >>>= 7;
+    if (value) byte |= 0x80;
+    out.push(byte);
+  } while (value);
+  return out;
+}
+
+const str = (text: string) => [...leb(Buffer.byteLength(text)), ...Buffer.from(text)];
+const section = (id: number, body: number[]) => [id, ...leb(body.length), ...body];
+
+const UNWRAP = 'called `Option::unwrap()` on a `None` value';
+const LOCATION = 'src/lib.rs';
+const OVERFLOW = 'attempt to add with overflow';
+
+const names = [1, ...leb(1), ...str('init'), ...leb(2), ...str('core::fmt::write')];
+const data = Buffer.from(`${UNWRAP}\0${LOCATION}\0${OVERFLOW}`);
+
+const WASM = Uint8Array.from([
+  ...[0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00],
+  ...section(1, [1, 0x60, 0, 0]),
+  ...section(2, [1, ...str('env'), ...str('signalError'), 0, 0]),
+  ...section(3, [2, 0, 0]),
+  ...section(7, [1, ...str('init'), 0, 1]),
+  ...section(10, [2, 2, 0, 0x0b, 12, 0, ...Array(10).fill(0x01), 0x0b]),
+  ...section(11, [1, 0, 0x41, 0x80, 0x08, 0x0b, ...leb(data.length), ...data]),
+  ...section(0, [...str('name'), 1, ...leb(names.length), ...names]),
+]);
+
+const SOURCE = `#[klever_sc::contract]
+pub trait Vault {
+    #[endpoint]
+    fn claim(&self) {
+        let reward = self.rewards().get().unwrap();
+        self.total().update(|t| *t += reward);
+    }
+}
+`;
+
+describe('analyzeWasmSize', () => {
+  it('reports section sizes, imports, exports and the largest functions', () => {
+    const report = analyzeWasmSize(WASM);
+    expect(report.totalSize).toBe(WASM.length);
+    expect(report.sections.map(s => s.name)).toEqual([
+      'type',
+      'import',
+      'function',
+      'export',
+      'code',
+      'data',
+      'name',
+    ]);
+    expect(report.imports).toEqual(['env.signalError']);
+    expect(report.exports).toEqual(['init']);
+    expect(report.functionCount).toBe(2);
+    expect(report.largestFunctions).toEqual([
+      { index: 2, name: 'core::fmt::write', size: 12 },
+      { index: 1, name: 'init', size: 2 },
+    ]);
+    expect(report.dataSize).toBe(data.length);
+  });
+
+  it('suggests fixes for panic, formatting and overflow-check bloat, biggest first', () => {
+    const report = analyzeWasmSize(WASM, { source: SOURCE });
+    expect(report.suggestions.map(s => [s.id, s.estimatedSavings])).toEqual([
+      ['std-panics', UNWRAP.length + LOCATION.length + 40],
+      ['overflow-checks', OVERFLOW.length + 40],
+      ['custom-sections', 5 + 1 + 1 + names.length],
+      ['core-fmt', 12],
+    ]);
+    expect(report.suggestions[0].evidence[0]).toBe(
+      'src/lib.rs:5: let reward = self.rewards().get().unwrap();'
+    );
+    expect(report.suggestions[3].evidence).toEqual(['core::fmt::write']);
+  });
+
+  it('rejects files that are not wasm', () => {
+    expect(() => analyzeWasmSize(Buffer.from('not wasm'))).toThrow('Not a wasm binary');
+  });
+});
@@ -0,0 +1,378 @@
+/**
+ * Size report for a built contract `.wasm`: section and function sizes read
+ * from the binary, plus the usual sources of bloat in klever-sc contracts
+ * (panic locations and messages, core::fmt, overflow checks, the heap
+ * allocator) with what to change and roughly how many bytes it saves.
+ */
+
+import { lineAt, maskNonCode } from './source.js';
+
+export interface WasmSection {
+  id: number;
+  /** Standard section name, or the name of a custom section */
+  name: string;
+  size: number;
+}
+
+export interface WasmFunctionSize {
+  index: number;
+  /** From the `name` custom section, when the build kept it */
+  name?: string;
+  size: number;
+}
+
+export interface SizeSuggestion {
+  id: string;
+  message: string;
+  /** Rough number of bytes the change saves */
+  estimatedSavings: number;
+  /** Strings, functions or source lines that triggered the suggestion */
+  evidence: string[];
+}
+
+export interface WasmSizeReport {
+  totalSize: number;
+  sections: WasmSection[];
+  functionCount: number;
+  imports: string[];
+  exports: string[];
+  /** Largest function bodies, biggest first */
+  largestFunctions: WasmFunctionSize[];
+  dataSize: number;
+  suggestions: SizeSuggestion[];
+}
+
+export interface WasmSizeOptions {
+  /** Contract source, to point suggestions at the lines that cause them */
+  source?: string;
+  /** Number of functions listed in largestFunctions (default: 10) */
+  topFunctions?: number;
+}
+
+const SECTION_NAMES = [
+  'custom',
+  'type',
+  'import',
+  'function',
+  'table',
+  'memory',
+  'global',
+  'export',
+  'start',
+  'element',
+  'code',
+  'data',
+  'datacount',
+];
+
+/** Rough code size behind each panic site that carries a message and location */
+const PANIC_SITE_BYTES = 40;
+
+// Data strings left behind by std panics, formatting and overflow checks
+const PANIC_LOCATION = /(^|\/)(src|tests)\/[\w/]+\.rs|\.cargo\/registry|\/rustc\//;
+const UNWRAP_MESSAGE = /called `(Option|Result)::(unwrap|expect)\(\)`|unwrap_failed/;
+const PANIC_MESSAGE = /panicked at|explicit panic|index out of bounds|slice index/;
+const OVERFLOW_MESSAGE = /attempt to (add|subtract|multiply|negate|divide|shift)[\w ]* overflow/;
+
+// Function names (from the name section) of formatting and allocator code
+const FMT_FUNCTION = /core::fmt|alloc::fmt|\bfmt::/;
+const ALLOC_FUNCTION = /alloc::(raw_vec|vec|string|alloc)|__rust_alloc|dlmalloc|wee_alloc/;
+
+// Source constructs behind them
+const UNWRAP_CALL = /\.(unwrap|expect)\s*\(/;
+const PANIC_CALL = /\b(panic|unreachable|todo|unimplemented|assert(_eq|_ne)?)!\s*\(/;
+const FORMAT_CALL = /\b(format|write|writeln|println)!\s*\(|\.to_string\s*\(/;
+const HEAP_TYPE = /\b(Vec|String|Box|BTreeMap|HashMap)\s*(<|::)/;
+
+class Reader {
+  constructor(
+    private readonly bytes: Uint8Array,
+    public offset = 0
+  ) {}
+
+  get done(): boolean {
+    return this.offset >= this.bytes.length;
+  }
+
+  byte(): number {
+    if (this.offset >= this.bytes.length) throw new Error('Unexpected end of wasm binary');
+    return this.bytes[this.offset++];
+  }
+
+  u32(): number {
+    let result = 0;
+    let shift = 0;
+    for (;;) {
+      const b = this.byte();
+      result += (b & 0x7f) * 2 ** shift;
+      if ((b & 0x80) === 0) return result;
+      shift += 7;
+    }
+  }
+
+  /** Skip a signed LEB128 value */
+  skipLeb(): void {
+    let b = this.byte();
+    while (b & 0x80) b = this.byte();
+  }
+
+  bytesOf(length: number): Uint8Array {
+    const slice = this.bytes.subarray(this.offset, this.offset + length);
+    this.offset += length;
+    return slice;
+  }
+
+  name(): string {
+    return Buffer.from(this.bytesOf(this.u32())).toString('utf8');
+  }
+
+  limits(): void {
+    const flags = this.byte();
+    this.u32();
+    if (flags & 1) this.u32();
+  }
+
+  /** Skip a constant expression up to its `end` opcode */
+  constExpr(): void {
+    for (let op = this.byte(); op !== 0x0b; op = this.byte()) {
+      if (op === 0x41 || op === 0x42 || op === 0x23) this.skipLeb();
+    }
+  }
+}
+
+interface ParsedWasm {
+  sections: WasmSection[];
+  imports: string[];
+  importedFunctions: number;
+  exports: { name: string; kind: number; index: number }[];
+  functionSizes: number[];
+  functionNames: Map<number, string>;
+  data: Uint8Array[];
+}
+
+function parseWasm(bytes: Uint8Array): ParsedWasm {
+  const header = Buffer.from(bytes.subarray(0, 8)).toString('hex');
+  if (header !== '0061736d01000000') throw new Error('Not a wasm binary (bad magic or version)');
+
+  const parsed: ParsedWasm = {
+    sections: [],
+    imports: [],
+    importedFunctions: 0,
+    exports: [],
+    functionSizes: [],
+    functionNames: new Map(),
+    data: [],
+  };
+  const reader = new Reader(bytes, 8);
+  while (!reader.done) {
+    const id = reader.byte();
+    const size = reader.u32();
+    const start = reader.offset;
+    const section = new Reader(reader.bytesOf(size));
+    let name = SECTION_NAMES[id] || `unknown-${id}`;
+
+    switch (id) {
+      case 0:
+        name = section.name();
+        if (name === 'name') readNames(section, parsed.functionNames);
+        break;
+      case 2:
+        for (let n = section.u32(); n > 0; n--) {
+          const module = section.name();
+          const field = section.name();
+          const kind = section.byte();
+          if (kind === 0) {
+            section.u32();
+            parsed.importedFunctions++;
+            parsed.imports.push(`${module}.${field}`);
+          } else if (kind === 1) {
+            section.byte();
+            section.limits();
+          } else if (kind === 2) {
+            section.limits();
+          } else {
+            section.byte();
+            section.byte();
+          }
+        }
+        break;
+      case 7:
+        for (let n = section.u32(); n > 0; n--) {
+          parsed.exports.push({ name: section.name(), kind: section.byte(), index: section.u32() });
+        }
+        break;
+      case 10:
+        for (let n = section.u32(); n > 0; n--) {
+          const bodySize = section.u32();
+          section.bytesOf(bodySize);
+          parsed.functionSizes.push(bodySize);
+        }
+        break;
+      case 11:
+        for (let n = section.u32(); n > 0; n--) {
+          const flags = section.u32();
+          if (flags === 2) section.u32();
+          if (flags !== 1) section.constExpr();
+          parsed.data.push(section.bytesOf(section.u32()));
+        }
+        break;
+    }
+    parsed.sections.push({ id, name, size: reader.offset - start });
+  }
+  return parsed;
+}
+
+function readNames(section: Reader, names: Map<number, string>): void {
+  while (!section.done) {
+    const kind = section.byte();
+    const size = section.u32();
+    const sub = new Reader(section.bytesOf(size));
+    if (kind !== 1) continue;
+    for (let n = sub.u32(); n > 0; n--) names.set(sub.u32(), sub.name());
+  }
+}
+
+/** Printable ASCII runs of at least `min` characters */
+function dataStrings(segments: Uint8Array[], min = 6): string[] {
+  const strings: string[] = [];
+  for (const segment of segments) {
+    const text = Buffer.from(segment).toString('latin1');
+    for (const match of text.matchAll(new RegExp(`[\\x20-\\x7e]{${min},}`, 'g'))) {
+      strings.push(match[0]);
+    }
+  }
+  return strings;
+}
+
+function sourceLines(source: string | undefined, pattern: RegExp): string[] {
+  if (!source) return [];
+  const code = maskNonCode(source);
+  return [...code.matchAll(new RegExp(pattern.source, 'g'))].map(m => {
+    const line = lineAt(source, m.index!);
+    return `src/lib.rs:${line}: ${source.split('\n')[line - 1].trim()}`;
+  });
+}
+
+function sum(strings: string[]): number {
+  return strings.reduce((total, s) => total + s.length, 0);
+}
+
+/** Collect suggestions from the data strings, function names and source */
+function suggest(parsed: ParsedWasm, strings: string[], source?: string): SizeSuggestion[] {
+  const suggestions: SizeSuggestion[] = [];
+  const names = [...parsed.functionNames.values()];
+  const sizeOf = (pattern: RegExp) =>
+    [...parsed.functionNames]
+      .filter(([, name]) => pattern.test(name))
+      .map(([index]) => parsed.functionSizes[index - parsed.importedFunctions] || 0)
+      .reduce((total, size) => total + size, 0);
+
+  const locations = strings.filter(s => PANIC_LOCATION.test(s));
+  const unwraps = strings.filter(s => UNWRAP_MESSAGE.test(s));
+  const panics = strings.filter(s => PANIC_MESSAGE.test(s));
+  const unwrapLines = sourceLines(source, UNWRAP_CALL);
+  const panicLines = sourceLines(source, PANIC_CALL);
+  if (locations.length > 0 || unwraps.length > 0 || panics.length > 0) {
+    const sites = Math.max(locations.length, unwrapLines.length + panicLines.length);
+    suggestions.push({
+      id: 'std-panics',
+      message:
+        'Rust panics (panic!, assert!, .unwrap(), .expect(), slice indexing) embed their message and source location. Fail with require!/sc_panic! instead, and replace .unwrap() with .unwrap_or_else(|| sc_panic!("...")) or an explicit check.',
+      estimatedSavings: sum(locations) + sum(unwraps) + sum(panics) + sites * PANIC_SITE_BYTES,
+      evidence: [
+        ...unwrapLines,
+        ...panicLines,
+        ...new Set([...unwraps, ...panics, ...locations]),
+      ].slice(0, 20),
+    });
+  }
+
+  const fmtNames = names.filter(n => FMT_FUNCTION.test(n));
+  const formatLines = sourceLines(source, FORMAT_CALL);
+  if (fmtNames.length > 0 || formatLines.length > 0) {
+    suggestions.push({
+      id: 'core-fmt',
+      message:
+        'core::fmt is linked in (format!, to_string(), {:?} in panic messages). Build messages with ManagedBuffer (append, sc_format! / ManagedBufferBuilder) and drop Debug formatting from panics; this removes the formatting machinery entirely.',
+      estimatedSavings: fmtNames.length > 0 ? sizeOf(FMT_FUNCTION) : 2000,
+      evidence: [...formatLines, ...fmtNames].slice(0, 20),
+    });
+  }
+
+  const overflow = strings.filter(s => OVERFLOW_MESSAGE.test(s));
+  if (overflow.length > 0) {
+    suggestions.push({
+      id: 'overflow-checks',
+      message:
+        'Overflow checks are compiled in for primitive integer arithmetic. Use BigUint for amounts, checked_* / saturating_* where overflow is possible, and set overflow-checks = false in the wasm crate [profile.release] once every overflow is handled explicitly.',
+      estimatedSavings: sum(overflow) + overflow.length * PANIC_SITE_BYTES,
+      evidence: [...new Set(overflow)],
+    });
+  }
+
+  const allocNames = names.filter(n => ALLOC_FUNCTION.test(n));
+  const heapLines = sourceLines(source, HEAP_TYPE);
+  const capacity = strings.some(s => s.includes('capacity overflow'));
+  if (allocNames.length > 0 || heapLines.length > 0 || capacity) {
+    suggestions.push({
+      id: 'heap-types',
+      message:
+        'Heap types (Vec, String, Box, BTreeMap) pull in an allocator. Use the managed equivalents (ManagedVec, ManagedBuffer, MultiValueEncoded) and keep the "alloc" feature of klever-sc disabled in Cargo.toml.',
+      estimatedSavings: allocNames.length > 0 ? sizeOf(ALLOC_FUNCTION) : 1500,
+      evidence: [...heapLines, ...allocNames].slice(0, 20),
+    });
+  }
+
+  const framework = [PANIC_LOCATION, UNWRAP_MESSAGE, PANIC_MESSAGE, OVERFLOW_MESSAGE];
+  const messages = strings.filter(
+    s => s.length > 64 && /[a-z] [a-z]/i.test(s) && !framework.some(p => p.test(s))
+  );
+  if (messages.length > 0) {
+    suggestions.push({
+      id: 'long-messages',
+      message:
+        'Long error messages are stored verbatim in the data section. Shorten them or move them to constants (generate_error_constants) so repeated messages are stored once.',
+      estimatedSavings: sum(messages) - messages.length * 32,
+      evidence: messages.slice(0, 10),
+    });
+  }
+
+  const custom = parsed.sections.filter(s => s.id === 0);
+  if (custom.length > 0) {
+    suggestions.push({
+      id: 'custom-sections',
+      message:
+        'The wasm still carries custom sections (debug names, producers). Build in release through ksc / the meta crate with wasm-opt enabled, which strips them.',
+      estimatedSavings: custom.reduce((total, s) => total + s.size, 0),
+      evidence: custom.map(s => `${s.name}: ${s.size} bytes`),
+    });
+  }
+
+  return suggestions
+    .filter(s => s.estimatedSavings > 0)
+    .sort((a, b) => b.estimatedSavings - a.estimatedSavings);
+}
+
+/** Analyze a built contract and suggest ways to make it smaller */
+export function analyzeWasmSize(bytes: Uint8Array, options: WasmSizeOptions = {}): WasmSizeReport {
+  const parsed = parseWasm(bytes);
+  const strings = dataStrings(parsed.data);
+  const largestFunctions = parsed.functionSizes
+    .map((size, i) => {
+      const index = parsed.importedFunctions + i;
+      return { index, name: parsed.functionNames.get(index), size };
+    })
+    .sort((a, b) => b.size - a.size || a.index - b.index)
+    .slice(0, options.topFunctions ?? 10);
+
+  return {
+    totalSize: bytes.length,
+    sections: parsed.sections,
+    functionCount: parsed.functionSizes.length,
+    imports: parsed.imports,
+    exports: parsed.exports.filter(e => e.kind === 0).map(e => e.name),
+    largestFunctions,
+    dataSize: parsed.data.reduce((total, segment) => total + segment.length, 0),
+    suggestions: suggest(parsed, strings, options.source),
+  };
+}
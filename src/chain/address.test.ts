@@ -0,0 +1,28 @@
+import { contractAddress, isContractAddress } from './address.js';
+
+const DEPLOYER = 'klv1qyu5wthldzr8wx5c9ucg8kjagg0jfs53s8nr3zpz3hypefsdd8sslccgef';
+
+describe('contractAddress', () => {
+  it('derives the address from the deployer and nonce', () => {
+    expect(contractAddress(DEPLOYER, 0)).toBe(
+      'klv1qqqqqqqqqqqqqpgqak8zt22wl2ph4tswtyc39namqx6ysa2sd8sswxqdq0'
+    );
+    expect(contractAddress(DEPLOYER, 5n)).toBe(
+      'klv1qqqqqqqqqqqqqpgqfzydqmdw7m2vazsp6u5p95yxz76t2p9rd8ss5z6hew'
+    );
+  });
+
+  it('rejects invalid deployers and nonces', () => {
+    expect(() => contractAddress('klv1abc', 0)).toThrow('Invalid bech32');
+    expect(() => contractAddress(DEPLOYER, -1)).toThrow('must not be negative');
+  });
+});
+
+describe('isContractAddress', () => {
+  it('recognises the contract form', () => {
+    expect(isContractAddress(contractAddress(DEPLOYER, 1))).toBe(true);
+    expect(isContractAddress(DEPLOYER)).toBe(false);
+    expect(isContractAddress(Buffer.alloc(32))).toBe(true);
+    expect(isContractAddress('not an address')).toBe(false);
+  });
+});
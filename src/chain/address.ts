@@ -0,0 +1,39 @@
+/**
+ * Smart contract addresses. The VM derives them from the deployer and its
+ * account nonce at deploy time: keccak256(deployer || nonce as 8 bytes
+ * little-endian), with the first 10 bytes replaced by 8 zero bytes and the VM
+ * type, and the last 2 by the deployer's last 2 bytes.
+ */
+
+import { decodeAddress, encodeAddress } from './bech32.js';
+import { keccak256 } from './keccak.js';
+
+/** VM type of WASM contracts, bytes 8-9 of their addresses */
+export const WASM_VM_TYPE = Buffer.from([0x05, 0x00]);
+
+/** Whether the address has the smart contract form (eight leading zero bytes) */
+export function isContractAddress(address: string | Uint8Array): boolean {
+  try {
+    const bytes = typeof address === 'string' ? decodeAddress(address) : Buffer.from(address);
+    return bytes.length === 32 && bytes.subarray(0, 8).every(byte => byte === 0);
+  } catch {
+    return false;
+  }
+}
+
+/** Address of the contract `deployer` creates with a deploy transaction of nonce `nonce` */
+export function contractAddress(
+  deployer: string,
+  nonce: number | bigint,
+  vmType: Uint8Array = WASM_VM_TYPE
+): string {
+  const creator = decodeAddress(deployer);
+  if (BigInt(nonce) < 0n) throw new Error(`Nonce must not be negative, got ${nonce}`);
+  const nonceBytes = Buffer.alloc(8);
+  nonceBytes.writeBigUInt64LE(BigInt(nonce));
+  const address = Buffer.from(keccak256(Buffer.concat([creator, nonceBytes])));
+  address.fill(0, 0, 8);
+  address.set(vmType.subarray(0, 2), 8);
+  creator.copy(address, 30, 30);
+  return encodeAddress(address);
+}
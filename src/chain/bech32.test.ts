@@ -0,0 +1,25 @@
+import { decodeAddress, decodeBech32, encodeAddress } from './bech32.js';
+
+describe('bech32', () => {
+  it('decodes the BIP-173 test vector', () => {
+    const { hrp, bytes } = decodeBech32('abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw');
+    expect(hrp).toBe('abcdef');
+    expect(bytes.toString('hex')).toBe('00443214c74254b635cf84653a56d7c675be77df');
+  });
+
+  it('round-trips Klever addresses', () => {
+    const bytes = Buffer.from(Array.from({ length: 32 }, (_, i) => i * 7));
+    const address = encodeAddress(bytes);
+    expect(address).toBe('klv1qqrsu9guyv4rzwplgex4gkmzd9c8wl593jfe4gdg47mtm3xt6tvs6kad8h');
+    expect(decodeAddress(address)).toEqual(bytes);
+  });
+
+  it('rejects bad checksums and foreign prefixes', () => {
+    expect(() => decodeBech32('abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxq')).toThrow(
+      'bad checksum'
+    );
+    expect(() => decodeAddress('abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw')).toThrow(
+      'is not a Klever address'
+    );
+  });
+});
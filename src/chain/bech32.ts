@@ -0,0 +1,89 @@
+/**
+ * Bech32 (BIP-173) encoding of Klever addresses: `klv1...` strings carry the
+ * 32-byte public key or contract address.
+ */
+
+const CHARSET = 'qpzry9x8gf2tvdw0s3jn54khce6mua7l';
+const GENERATOR = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+export const KLEVER_HRP = 'klv';
+
+function polymod(values: number[]): number {
+  let chk = 1;
+  for (const value of values) {
+    const top = chk >>> 25;
+    chk = ((chk & 0x1ffffff) << 5) ^ value;
+    for (let i = 0; i < 5; i++) {
+      if ((top >>> i) & 1) chk ^= GENERATOR[i];
+    }
+  }
+  return chk;
+}
+
+function hrpExpand(hrp: string): number[] {
+  const codes = [...hrp].map(c => c.charCodeAt(0));
+  return [...codes.map(c => c >>> 5), 0, ...codes.map(c => c & 31)];
+}
+
+function convertBits(data: number[], from: number, to: number, pad: boolean): number[] {
+  let acc = 0;
+  let bits = 0;
+  const result: number[] = [];
+  const maxv = (1 << to) - 1;
+  const maxAcc = (1 << (from + to - 1)) - 1;
+  for (const value of data) {
+    acc = ((acc << from) | value) & maxAcc;
+    bits += from;
+    while (bits >= to) {
+      bits -= to;
+      result.push((acc >>> bits) & maxv);
+    }
+  }
+  if (pad && bits > 0) {
+    result.push((acc << (to - bits)) & maxv);
+  } else if (!pad && (bits >= from || ((acc << (to - bits)) & maxv) !== 0)) {
+    throw new Error('Invalid bech32 padding');
+  }
+  return result;
+}
+
+/** Decode a bech32 string into its human-readable part and payload bytes */
+export function decodeBech32(value: string): { hrp: string; bytes: Buffer } {
+  if (value !== value.toLowerCase() && value !== value.toUpperCase()) {
+    throw new Error(`Invalid bech32 "${value}": mixed case`);
+  }
+  const lower = value.toLowerCase();
+  const separator = lower.lastIndexOf('1');
+  if (separator < 1 || separator + 7 > lower.length) {
+    throw new Error(`Invalid bech32 "${value}": missing separator or checksum`);
+  }
+  const hrp = lower.slice(0, separator);
+  const data = [...lower.slice(separator + 1)].map(c => CHARSET.indexOf(c));
+  if (data.includes(-1)) throw new Error(`Invalid bech32 "${value}": unexpected character`);
+  if (polymod([...hrpExpand(hrp), ...data]) !== 1) {
+    throw new Error(`Invalid bech32 "${value}": bad checksum`);
+  }
+  return { hrp, bytes: Buffer.from(convertBits(data.slice(0, -6), 5, 8, false)) };
+}
+
+/** Encode payload bytes as bech32 with the given human-readable part */
+export function encodeBech32(hrp: string, bytes: Uint8Array): string {
+  const data = convertBits([...bytes], 8, 5, true);
+  const mod = polymod([...hrpExpand(hrp), ...data, 0, 0, 0, 0, 0, 0]) ^ 1;
+  const checksum = [0, 1, 2, 3, 4, 5].map(i => (mod >>> (5 * (5 - i))) & 31);
+  return `${hrp}1${[...data, ...checksum].map(v => CHARSET[v]).join('')}`;
+}
+
+/** The 32 bytes behind a `klv1...` address */
+export function decodeAddress(address: string): Buffer {
+  const { hrp, bytes } = decodeBech32(address);
+  if (hrp !== KLEVER_HRP || bytes.length !== 32) {
+    throw new Error(`"${address}" is not a Klever address (expected klv1... with 32 bytes)`);
+  }
+  return bytes;
+}
+
+export function encodeAddress(bytes: Uint8Array): string {
+  if (bytes.length !== 32) throw new Error(`Klever addresses are 32 bytes, got ${bytes.length}`);
+  return encodeBech32(KLEVER_HRP, bytes);
+}
@@ -0,0 +1,31 @@
+import { blake2b } from './blake2b.js';
+
+const hex = (bytes: Uint8Array) => Buffer.from(bytes).toString('hex');
+
+describe('blake2b', () => {
+  it('matches the reference 32-byte digests', () => {
+    expect(hex(blake2b(new Uint8Array()))).toBe(
+      '0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8'
+    );
+    expect(hex(blake2b(Buffer.from('abc')))).toBe(
+      'bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319'
+    );
+  });
+
+  it('handles inputs spanning several blocks and exact block sizes', () => {
+    const data = Uint8Array.from({ length: 768 }, (_, i) => i % 256);
+    expect(hex(blake2b(data))).toBe(
+      'b8007121274217790e2923e0ad7027986e5a99d5531ef6ae7d294140fc81615d'
+    );
+    expect(hex(blake2b(new Uint8Array(128)))).toBe(
+      '378d0caaaa3855f1b38693c1d6ef004fd118691c95c959d4efa950d6d6fcf7c1'
+    );
+  });
+
+  it('supports the 64-byte digest', () => {
+    expect(hex(blake2b(Buffer.from('abc'), 64))).toBe(
+      'ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923'
+    );
+    expect(() => blake2b(new Uint8Array(), 65)).toThrow('1-64 bytes');
+  });
+});
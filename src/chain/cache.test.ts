@@ -0,0 +1,84 @@
+import { ResponseCache } from './cache.js';
+
+describe('ResponseCache', () => {
+  let now: number;
+  let cache: ResponseCache;
+  let calls: number;
+  const load = (response: unknown) => () => {
+    calls++;
+    return Promise.resolve(response);
+  };
+
+  beforeEach(() => {
+    now = 0;
+    calls = 0;
+    cache = new ResponseCache({ now: () => now });
+  });
+
+  it('keeps account state until its TTL passes', async () => {
+    const url = 'https://node/address/klv1a';
+    await cache.get(url, load({ data: { balance: 1 } }));
+    await expect(cache.get(url, load({ data: { balance: 2 } }))).resolves.toEqual({
+      data: { balance: 1 },
+    });
+    now = 3_000;
+    await expect(cache.get(url, load({ data: { balance: 2 } }))).resolves.toEqual({
+      data: { balance: 2 },
+    });
+    expect(cache.stats()).toEqual({ entries: 1, hits: 1, misses: 2 });
+  });
+
+  it('keeps past blocks indefinitely and never caches nonces', async () => {
+    await cache.get('https://api/v1.0/block/by-nonce/5', load({ data: { block: {} } }));
+    now = 1e12;
+    await cache.get('https://api/v1.0/block/by-nonce/5', load({ data: { block: {} } }));
+    await cache.get('https://node/address/klv1a/nonce', load({ data: { nonce: 1 } }));
+    await cache.get('https://node/address/klv1a/nonce', load({ data: { nonce: 1 } }));
+    expect(calls).toBe(3);
+  });
+
+  it('caches only processed transactions and successful responses', async () => {
+    const url = 'https://api/v1.0/transaction/ab12';
+    await cache.get(url, load({ data: { transaction: { hash: 'ab12' } } }));
+    await cache.get(url, load({ data: null, error: 'transaction not found' }));
+    await cache.get(url, load({ data: { transaction: { hash: 'ab12', status: 'success' } } }));
+    await cache.get(url, load({ data: null, error: 'unexpected' }));
+    expect(calls).toBe(3);
+  });
+
+  it('shares one request between concurrent callers and returns copies', async () => {
+    const url = 'https://node/node/status';
+    const [a, b] = await Promise.all([
+      cache.get(url, load({ data: { nonce: 1 } })),
+      cache.get(url, load({ data: { nonce: 1 } })),
+    ]);
+    expect(calls).toBe(1);
+    expect(a).toEqual(b);
+    expect(a).not.toBe(b);
+  });
+
+  it('does not keep failed requests', async () => {
+    const url = 'https://node/asset/KLV';
+    await expect(cache.get(url, () => Promise.reject(new Error('HTTP 502')))).rejects.toThrow();
+    await cache.get(url, load({ data: { asset: {} } }));
+    expect(calls).toBe(1);
+    expect(cache.stats().entries).toBe(1);
+  });
+
+  it('clears by URL prefix or pattern and evicts the oldest entries', async () => {
+    await cache.get('https://node-a/address/klv1a', load({}));
+    await cache.get('https://node-b/address/klv1a', load({}));
+    await cache.get('https://node-b/asset/KLV', load({}));
+    expect(cache.clear(['https://node-a'])).toBe(1);
+    cache.invalidate(/\/address\//);
+    expect(cache.stats().entries).toBe(1);
+    expect(cache.clear()).toBe(1);
+
+    const small = new ResponseCache({ maxEntries: 1 });
+    await small.get('https://node/asset/A', load({}));
+    await small.get('https://node/asset/B', load({}));
+    await small.get('https://node/asset/B', load({}));
+    expect(calls).toBe(5);
+    expect(small.stats().entries).toBe(1);
+  });
+});
@@ -0,0 +1,147 @@
+/**
+ * Cache of GET responses from nodes and API proxies, with a time to live per
+ * endpoint: data that cannot change (past blocks, processed transactions) is
+ * kept until evicted, account state only for a few seconds. Concurrent
+ * requests for the same URL share one fetch.
+ */
+
+export interface CacheRule {
+  /** Matched against the request URL */
+  pattern: RegExp;
+  /** How long a response stays fresh, in milliseconds; Infinity for data that never changes */
+  ttlMs: number;
+  /** Cache only responses this accepts, e.g. transactions that are processed */
+  cacheable?: (response: unknown) => boolean;
+}
+
+export interface CacheStats {
+  entries: number;
+  hits: number;
+  misses: number;
+}
+
+export interface ResponseCacheOptions {
+  /** First matching rule wins; URLs no rule matches are not cached */
+  rules?: CacheRule[];
+  /** Oldest entries are evicted beyond this (default: 1000) */
+  maxEntries?: number;
+  now?: () => number;
+}
+
+const processedTransaction = (response: unknown) =>
+  !!(response as { data?: { transaction?: { status?: string } } }).data?.transaction?.status;
+
+/** TTLs of the client's GET endpoints; nonces are never cached so new transactions see them */
+export const DEFAULT_CACHE_RULES: CacheRule[] = [
+  { pattern: /\/v1\.0\/block\/by-nonce\/\d+$/, ttlMs: Infinity },
+  {
+    pattern: /\/v1\.0\/transaction\/[0-9a-fA-F]+$/,
+    ttlMs: Infinity,
+    cacheable: processedTransaction,
+  },
+  { pattern: /\/address\/[^/]+\/nonce$/, ttlMs: 0 },
+  { pattern: /\/address\//, ttlMs: 3_000 },
+  { pattern: /\/asset\//, ttlMs: 60_000 },
+  { pattern: /\/v1\.0\/assets\/[^/]+\/holders/, ttlMs: 30_000 },
+  { pattern: /\/v1\.0\/transaction\/list/, ttlMs: 5_000 },
+  { pattern: /\/v1\.0\/block\/list/, ttlMs: 2_000 },
+  { pattern: /\/v1\.0\/validators$/, ttlMs: 30_000 },
+  { pattern: /\/node\/status$/, ttlMs: 2_000 },
+  { pattern: /\/network\/config$/, ttlMs: 300_000 },
+];
+
+interface CacheEntry {
+  /** Undefined while the request is in flight */
+  expires?: number;
+  value: Promise<unknown>;
+}
+
+export class ResponseCache {
+  private entries = new Map<string, CacheEntry>();
+  private rules: CacheRule[];
+  private maxEntries: number;
+  private now: () => number;
+  private hits = 0;
+  private misses = 0;
+
+  constructor(options: ResponseCacheOptions = {}) {
+    this.rules = options.rules || DEFAULT_CACHE_RULES;
+    this.maxEntries = options.maxEntries ?? 1000;
+    this.now = options.now || Date.now;
+  }
+
+  /** The rule that applies to `url`, if it is cached at all */
+  ruleFor(url: string): CacheRule | undefined {
+    const rule = this.rules.find(r => r.pattern.test(url));
+    return rule && rule.ttlMs > 0 ? rule : undefined;
+  }
+
+  /**
+   * The cached response for `url`, or the result of `load`, kept when its rule
+   * accepts it. Error responses (an `error` field) are never kept.
+   */
+  async get<T>(url: string, load: () => Promise<T>): Promise<T> {
+    const rule = this.ruleFor(url);
+    if (!rule) return load();
+
+    const cached = this.entries.get(url);
+    if (cached && (cached.expires === undefined || cached.expires > this.now())) {
+      this.hits++;
+      return structuredClone((await cached.value) as T);
+    }
+    this.misses++;
+
+    const value = load();
+    const entry: CacheEntry = { value };
+    this.entries.delete(url);
+    this.entries.set(url, entry);
+    this.evict();
+    try {
+      const response = await value;
+      const failed = !!(response as { error?: unknown } | null)?.error;
+      if (failed || (rule.cacheable && !rule.cacheable(response))) {
+        this.remove(url, entry);
+      } else {
+        entry.expires = this.now() + rule.ttlMs;
+      }
+      return structuredClone(response);
+    } catch (error) {
+      this.remove(url, entry);
+      throw error;
+    }
+  }
+
+  /** Drop cached responses under any of the URL `prefixes` (all when none); returns how many */
+  clear(prefixes: string[] = []): number {
+    let removed = 0;
+    for (const url of [...this.entries.keys()]) {
+      if (prefixes.length === 0 || prefixes.some(prefix => url.startsWith(prefix))) {
+        this.entries.delete(url);
+        removed++;
+      }
+    }
+    return removed;
+  }
+
+  /** Drop cached responses whose URL matches `pattern` */
+  invalidate(pattern: RegExp): void {
+    for (const url of [...this.entries.keys()]) {
+      if (pattern.test(url)) this.entries.delete(url);
+    }
+  }
+
+  stats(): CacheStats {
+    return { entries: this.entries.size, hits: this.hits, misses: this.misses };
+  }
+
+  private remove(url: string, entry: CacheEntry): void {
+    if (this.entries.get(url) === entry) this.entries.delete(url);
+  }
+
+  private evict(): void {
+    for (const url of this.entries.keys()) {
+      if (this.entries.size <= this.maxEntries) break;
+      this.entries.delete(url);
+    }
+  }
+}
@@ -1,5 +1,6 @@
 import { jest } from '@jest/globals';
-import { KleverChainClient, NETWORK_CONFIGS } from './client.js';
+import { KleverChainClient } from './client.js';
+import { NETWORK_PROFILES } from './networks.js';
 
 // Mock global fetch (save original and restore in afterAll)
 const originalFetch = global.fetch;
@@ -43,8 +44,8 @@ describe('KleverChainClient', () => {
     it('uses default mainnet when no network specified', () => {
       const defaultClient = new KleverChainClient();
       expect(defaultClient.getDefaultNetwork()).toBe('mainnet');
-      expect(defaultClient.getNodeUrl()).toBe(NETWORK_CONFIGS.mainnet.nodeUrl);
-      expect(defaultClient.getApiUrl()).toBe(NETWORK_CONFIGS.mainnet.apiUrl);
+      expect(defaultClient.getNodeUrl()).toBe(NETWORK_PROFILES.mainnet.nodeUrl);
+      expect(defaultClient.getApiUrl()).toBe(NETWORK_PROFILES.mainnet.apiUrl);
     });
 
     it('uses specified network', () => {
@@ -63,10 +64,34 @@ describe('KleverChainClient', () => {
         nodeUrl: 'http://custom-node:8080',
         apiUrl: 'http://custom-api:9090',
       });
+      expect(customClient.getDefaultNetwork()).toBe('custom');
       expect(customClient.getNodeUrl()).toBe('http://custom-node:8080');
       expect(customClient.getApiUrl()).toBe('http://custom-api:9090');
-      // Custom URLs ignore network override
-      expect(customClient.getNodeUrl('devnet')).toBe('http://custom-node:8080');
+      // Other networks keep their own URLs
+      expect(customClient.getNodeUrl('devnet')).toBe('https://node.devnet.klever.org');
+    });
+
+    it('applies custom URLs to an explicit default network only', () => {
+      const customClient = new KleverChainClient({
+        network: 'testnet',
+        nodeUrl: 'http://custom-node:8080',
+      });
+      expect(customClient.getNodeUrl()).toBe('http://custom-node:8080');
+      expect(customClient.getNodeUrl('custom')).toBe('http://custom-node:8080');
+      expect(customClient.getNodeUrl('mainnet')).toBe('https://node.mainnet.klever.org');
+    });
+
+    it('resolves network profiles with chain ID and explorer', () => {
+      const profile = client.getNetworkProfile('mainnet');
+      expect(profile.chainId).toBe('108');
+      expect(client.getExplorerUrl('transaction', 'abc', 'mainnet')).toBe(
+        'https://kleverscan.org/transaction/abc'
+      );
+      expect(client.getExplorerUrl('transaction', 'abc', 'local')).toBeUndefined();
+    });
+
+    it('rejects the custom network when it is not configured', () => {
+      expect(() => client.getNodeUrl('custom')).toThrow('not configured');
     });
 
     it('uses local network URLs', () => {
@@ -74,6 +99,16 @@ describe('KleverChainClient', () => {
       expect(localClient.getNodeUrl()).toBe('http://localhost:8080');
       expect(localClient.getApiUrl()).toBe('http://localhost:9090');
     });
+
+    it('uses and restores per-network URL overrides', () => {
+      const urls = { nodeUrl: 'http://127.0.0.1:18080', apiUrl: 'http://127.0.0.1:19090' };
+      client.setNetworkUrls('local', urls);
+      expect(client.getNodeUrl('local')).toBe(urls.nodeUrl);
+      expect(client.getApiUrl('local')).toBe(urls.apiUrl);
+      expect(client.getNodeUrl()).toBe('https://node.testnet.klever.org');
+      client.setNetworkUrls('local', undefined);
+      expect(client.getNodeUrl('local')).toBe('http://localhost:8080');
+    });
   });
 
   describe('getBalance', () => {
@@ -153,6 +188,34 @@ describe('KleverChainClient', () => {
     });
   });
 
+  describe('getCodeHash', () => {
+    const account = { address: 'klv1sc', nonce: 0, balance: 0, permissions: [] };
+
+    it('decodes the base64 code hash of a contract account', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({
+          data: {
+            account: { ...account, codeHash: '9qXb8IDpydeDQUVlO85MjN7WLmZNfdzbXFJvWHcAbXQ=' },
+          },
+          error: '',
+          code: 'successful',
+        })
+      );
+
+      await expect(client.getCodeHash('klv1sc')).resolves.toBe(
+        'f6a5dbf080e9c9d7834145653bce4c8cded62e664d7ddcdb5c526f5877006d74'
+      );
+    });
+
+    it('rejects accounts without code', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({ data: { account }, error: '', code: 'successful' })
+      );
+
+      await expect(client.getCodeHash('klv1sc')).rejects.toThrow('not a smart contract');
+    });
+  });
+
   describe('getAssetInfo', () => {
     it('fetches asset properties', async () => {
       const assetData = {
@@ -273,6 +336,36 @@ describe('KleverChainClient', () => {
     });
   });
 
+  describe('storage', () => {
+    it('fetches all storage pairs of an account', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({ data: { pairs: { '6f776e6572': '01' } }, error: '', code: 'successful' })
+      );
+
+      const pairs = await client.getStorage('klv1contract');
+
+      expect(pairs).toEqual({ '6f776e6572': '01' });
+      expect(mockFetch).toHaveBeenCalledWith(
+        'https://node.testnet.klever.org/address/klv1contract/keys',
+        expect.anything()
+      );
+    });
+
+    it('fetches a single storage value by hex key', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({ data: { value: '0f4240' }, error: '', code: 'successful' })
+      );
+
+      const value = await client.getStorageValue('klv1contract', '6f776e6572');
+
+      expect(value).toBe('0f4240');
+      expect(mockFetch).toHaveBeenCalledWith(
+        'https://node.testnet.klever.org/address/klv1contract/key/6f776e6572',
+        expect.anything()
+      );
+    });
+  });
+
   describe('getTransaction', () => {
     it('fetches transaction from API proxy', async () => {
       const txData = {
@@ -303,6 +396,103 @@ describe('KleverChainClient', () => {
     });
   });
 
+  describe('broadcastTransaction', () => {
+    it('posts the built transaction with its signature', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({ data: { txHash: 'hash1' }, error: '', code: 'successful' })
+      );
+
+      await expect(client.broadcastTransaction('proto', 'ab12')).resolves.toBe('hash1');
+      const [url, init] = mockFetch.mock.calls[0];
+      expect(url).toBe('https://node.testnet.klever.org/transactions/broadcast');
+      expect(JSON.parse(init?.body as string)).toEqual({ tx: 'proto', signature: 'ab12' });
+    });
+  });
+
+  describe('response cache', () => {
+    const account = (balance: number) =>
+      jsonResponse({ data: { account: { address: 'klv1abc', balance } }, code: 'successful' });
+
+    it('serves repeated GETs from the cache until a broadcast', async () => {
+      mockFetch
+        .mockResolvedValueOnce(account(1))
+        .mockResolvedValueOnce(
+          jsonResponse({ data: { txHash: 'hash1' }, error: '', code: 'successful' })
+        )
+        .mockResolvedValueOnce(account(2));
+
+      await client.getAccount('klv1abc');
+      await expect(client.getAccount('klv1abc')).resolves.toMatchObject({ balance: 1 });
+      await client.broadcastTransaction('proto', 'ab12');
+      await expect(client.getAccount('klv1abc')).resolves.toMatchObject({ balance: 2 });
+      expect(mockFetch).toHaveBeenCalledTimes(3);
+      expect(client.cacheStats()).toMatchObject({ entries: 1, hits: 1 });
+    });
+
+    it('clears one network and can be turned off', async () => {
+      mockFetch.mockResolvedValue(account(1));
+
+      await client.getAccount('klv1abc');
+      await client.getAccount('klv1abc', 'devnet');
+      expect(client.clearCache('testnet')).toBe(1);
+      expect(client.clearCache()).toBe(1);
+
+      const uncached = new KleverChainClient({ network: 'testnet', cache: false });
+      await uncached.getAccount('klv1abc');
+      await uncached.getAccount('klv1abc');
+      expect(mockFetch).toHaveBeenCalledTimes(4);
+      expect(uncached.cacheStats()).toBeUndefined();
+      mockFetch.mockReset();
+    });
+  });
+
+  describe('requestTestFunds', () => {
+    it('asks the API proxy faucet of the network', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({ data: { txHash: 'faucet1' }, error: '', code: 'successful' })
+      );
+
+      await expect(client.requestTestFunds('klv1abc')).resolves.toEqual({ txHash: 'faucet1' });
+      const [url, init] = mockFetch.mock.calls[0];
+      expect(url).toBe('https://api.testnet.klever.org/v1.0/transaction/send-user-funds/klv1abc');
+      expect(init?.method).toBe('POST');
+    });
+
+    it('reports rate limiting and refuses networks without a faucet', async () => {
+      mockFetch.mockResolvedValueOnce(jsonResponse({ error: 'Too Many Requests' }, 429));
+
+      await expect(client.requestTestFunds('klv1abc')).rejects.toThrow(
+        /^Faucet rate limit: HTTP 429/
+      );
+      await expect(client.requestTestFunds('klv1abc', 'mainnet')).rejects.toThrow(
+        'no faucet on mainnet'
+      );
+    });
+  });
+
+  describe('waitForTransaction', () => {
+    it('polls until the transaction is indexed with a status', async () => {
+      mockFetch
+        .mockResolvedValueOnce(jsonResponse({ data: null, error: 'transaction not found' }))
+        .mockResolvedValueOnce(
+          jsonResponse({ data: { transaction: { hash: 'h', sender: 's', status: 'success' } } })
+        );
+
+      const tx = await client.waitForTransaction('h', undefined, { intervalMs: 1 });
+      expect(tx.status).toBe('success');
+      expect(mockFetch).toHaveBeenCalledTimes(2);
+    });
+
+    it('gives up after the timeout', async () => {
+      mockFetch.mockResolvedValue(jsonResponse({ data: null, error: 'transaction not found' }));
+
+      await expect(
+        client.waitForTransaction('h', undefined, { timeoutMs: 5, intervalMs: 1 })
+      ).rejects.toThrow('Transaction h was not processed within 5ms');
+      mockFetch.mockReset();
+    });
+  });
+
   describe('getBlock', () => {
     it('fetches block by nonce', async () => {
       const blockData = {
@@ -447,6 +637,85 @@ describe('KleverChainClient', () => {
     });
   });
 
+  describe('getAssetHolders', () => {
+    it('fetches a page of holders from the API', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({
+          data: { accounts: [{ address: 'klv1alice', balance: 5000 }] },
+          pagination: {
+            self: 1,
+            next: 2,
+            previous: 1,
+            perPage: 5,
+            totalPages: 3,
+            totalRecords: 12,
+          },
+          error: '',
+          code: 'successful',
+        })
+      );
+
+      const result = await client.getAssetHolders('KFI', { limit: 5 });
+      expect(result.holders[0].address).toBe('klv1alice');
+      expect(result.pagination?.totalRecords).toBe(12);
+      expect(mockFetch.mock.calls[0][0]).toBe(
+        `${NETWORK_PROFILES.testnet.apiUrl}/v1.0/assets/KFI/holders?page=1&limit=5`
+      );
+    });
+  });
+
+  describe('listTransactions', () => {
+    it('passes the filters as query parameters', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({
+          data: { transactions: [{ hash: 'tx1', sender: 'klv1sender' }] },
+          pagination: {
+            self: 2,
+            next: 3,
+            previous: 1,
+            perPage: 10,
+            totalPages: 5,
+            totalRecords: 42,
+          },
+          error: '',
+          code: 'successful',
+        })
+      );
+
+      const result = await client.listTransactions({
+        address: 'klv1contract',
+        fromAddress: 'klv1sender',
+        status: 'success',
+        startTime: 1700000000000,
+        page: 2,
+        limit: 10,
+      });
+
+      expect(result.transactions[0].hash).toBe('tx1');
+      expect(result.pagination?.next).toBe(3);
+      const url = new URL(mockFetch.mock.calls[0][0] as string);
+      expect(url.pathname).toBe('/v1.0/transaction/list');
+      expect(Object.fromEntries(url.searchParams)).toEqual({
+        address: 'klv1contract',
+        fromAddress: 'klv1sender',
+        status: 'success',
+        startdate: '1700000000000',
+        page: '2',
+        limit: '10',
+      });
+    });
+
+    it('throws on API errors', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({ data: null, error: 'invalid address', code: 'internal_issue' })
+      );
+
+      await expect(client.listTransactions({ address: 'bad' })).rejects.toThrow(
+        'listTransactions: invalid address'
+      );
+    });
+  });
+
   describe('buildTransfer', () => {
     it('fetches nonce and builds a transfer transaction', async () => {
       // Mock getNonce
@@ -478,6 +747,43 @@ describe('KleverChainClient', () => {
     });
   });
 
+  describe('buildMultiTransfer', () => {
+    it('builds one transfer contract per asset', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({ data: { nonce: 4 }, error: '', code: 'successful' })
+      );
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({
+          data: { result: { txHash: 'multi1', tx: 'multi_proto' } },
+          error: '',
+          code: 'successful',
+        })
+      );
+
+      await client.buildMultiTransfer({
+        sender: 'klv1sender',
+        transfers: [
+          { receiver: 'klv1receiver', amount: 1000000 },
+          { receiver: 'klv1receiver', amount: 25, assetId: 'USDT-A1B2' },
+        ],
+      });
+
+      const body = JSON.parse(mockFetch.mock.calls[1][1]?.body as string);
+      expect(body.type).toBe(0);
+      expect(body.nonce).toBe(4);
+      expect(body.contracts).toEqual([
+        { amount: 1000000, toAddress: 'klv1receiver' },
+        { amount: 25, toAddress: 'klv1receiver', assetId: 'USDT-A1B2' },
+      ]);
+    });
+
+    it('rejects an empty transfer list', async () => {
+      await expect(
+        client.buildMultiTransfer({ sender: 'klv1sender', transfers: [] })
+      ).rejects.toThrow('at least one transfer');
+    });
+  });
+
   describe('buildDeploy', () => {
     it('builds a deploy transaction with correct type 63 and scType 1', async () => {
       mockFetch.mockResolvedValueOnce(
@@ -505,6 +811,35 @@ describe('KleverChainClient', () => {
     });
   });
 
+  describe('buildUpgrade', () => {
+    it('builds an upgrade transaction for the contract address with scType 2', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({ data: { nonce: 4 }, error: '', code: 'successful' })
+      );
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({
+          data: { result: { txHash: 'upgrade1', tx: 'upgrade_proto' } },
+          error: '',
+          code: 'successful',
+        })
+      );
+
+      const result = await client.buildUpgrade({
+        sender: 'klv1owner',
+        scAddress: 'klv1contract',
+        wasmHex: 'deadbeef',
+        upgradeArgs: ['AQ=='],
+      });
+
+      expect(result.result.txHash).toBe('upgrade1');
+      const body = JSON.parse(mockFetch.mock.calls[1][1]?.body as string);
+      expect(body.type).toBe(63);
+      expect(body.nonce).toBe(4);
+      expect(body.contracts[0]).toEqual({ scType: 2, address: 'klv1contract' });
+      expect(body.data).toEqual(['deadbeef', 'AQ==']);
+    });
+  });
+
   describe('buildInvoke', () => {
     it('builds an invoke transaction with correct type 63, scType 0, and callValue map', async () => {
       mockFetch.mockResolvedValueOnce(
@@ -534,6 +869,30 @@ describe('KleverChainClient', () => {
       expect(body.contracts[0].address).toBe('klv1contract');
       expect(body.contracts[0].callValue).toEqual({ KLV: 1000000 });
       expect(body.data).toEqual(['doSomething', 'AQID']);
+      expect(body.gasLimit).toBeUndefined();
+    });
+
+    it('passes the gas limit when given', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({ data: { nonce: 1 }, error: '', code: 'successful' })
+      );
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({
+          data: { result: { txHash: 'invoke2', tx: 'invoke_proto' } },
+          error: '',
+          code: 'successful',
+        })
+      );
+
+      await client.buildInvoke({
+        sender: 'klv1caller',
+        scAddress: 'klv1contract',
+        funcName: 'claim',
+        gasLimit: 5_000_000,
+      });
+
+      const body = JSON.parse(mockFetch.mock.calls[1][1]?.body as string);
+      expect(body.gasLimit).toBe(5_000_000);
     });
   });
 
@@ -563,6 +922,51 @@ describe('KleverChainClient', () => {
     });
   });
 
+  describe('nonce management', () => {
+    const nonceResponse = (nonce: number) =>
+      jsonResponse({ data: { nonce }, error: '', code: 'successful' });
+    const built = (txHash: string) =>
+      jsonResponse({ data: { result: { txHash, tx: 'proto' } }, error: '', code: 'successful' });
+    const sentNonce = (call: number) =>
+      JSON.parse(mockFetch.mock.calls[call][1]?.body as string).nonce;
+    const transfer = { sender: 'klv1sender', receiver: 'klv1receiver', amount: 1 };
+
+    it('does not reuse the nonce of a transaction that has not executed yet', async () => {
+      mockFetch
+        .mockResolvedValueOnce(nonceResponse(10))
+        .mockResolvedValueOnce(built('first'))
+        .mockResolvedValueOnce(nonceResponse(10))
+        .mockResolvedValueOnce(built('second'));
+
+      await client.buildTransfer(transfer);
+      await client.buildTransfer(transfer);
+      expect([sentNonce(1), sentNonce(3)]).toEqual([10, 11]);
+      expect(client.pendingNonces('klv1sender').map(p => p.txHash)).toEqual(['first', 'second']);
+    });
+
+    it('gives the nonce back after failures and resyncs on request', async () => {
+      mockFetch
+        .mockResolvedValueOnce(nonceResponse(4))
+        .mockResolvedValueOnce(built('unsent'))
+        .mockResolvedValueOnce(nonceResponse(4))
+        .mockResolvedValueOnce(jsonResponse({ data: null, error: 'bad tx', code: 'internal' }))
+        .mockResolvedValueOnce(nonceResponse(4))
+        .mockResolvedValueOnce(built('retry'))
+        .mockResolvedValueOnce(nonceResponse(4))
+        .mockResolvedValueOnce(built('after-resync'));
+
+      await client.buildTransfer(transfer);
+      expect(client.releaseTransaction('unsent')).toBe(true);
+      await expect(client.buildTransfer(transfer)).rejects.toThrow('bad tx');
+      await client.buildTransfer(transfer);
+      expect(sentNonce(5)).toBe(4);
+      client.setNonce('klv1sender', 9);
+      client.resyncNonce('klv1sender');
+      await client.buildTransfer(transfer);
+      expect(sentNonce(7)).toBe(4);
+    });
+  });
+
   describe('getNodeStatus', () => {
     it('fetches node health status', async () => {
       const statusData = {
@@ -583,6 +987,22 @@ describe('KleverChainClient', () => {
     });
   });
 
+  describe('getNetworkConfig', () => {
+    it('fetches network parameters', async () => {
+      mockFetch.mockResolvedValueOnce(
+        jsonResponse({
+          data: { config: { klv_chain_id: '109', klv_round_duration: 4000 } },
+          error: '',
+          code: 'successful',
+        })
+      );
+
+      const config = await client.getNetworkConfig();
+      expect(config.klv_chain_id).toBe('109');
+      expect(mockFetch.mock.calls[0][0]).toContain('/network/config');
+    });
+  });
+
   describe('error handling', () => {
     it('handles fetch timeout', async () => {
       const timeoutClient = new KleverChainClient({ network: 'testnet', timeout: 1 });
@@ -5,6 +5,18 @@
  * Uses native fetch (Node 18+) — no external HTTP dependencies.
  */
 
+import { ResponseCache, type CacheStats } from './cache.js';
+import { explorerLink, NETWORK_PROFILES } from './networks.js';
+import { NonceManager, type NonceReservation } from './nonce.js';
+import { withSpan } from '../logging/index.js';
+import {
+  CancelledError,
+  cancellable,
+  currentSignal,
+  onCancel,
+  runDetached,
+  sleep,
+} from '../utils/cancellation.js';
 import {
   ContractType,
   SCType,
@@ -12,54 +24,54 @@ import {
 import type {
   KleverNetwork,
   NetworkConfig,
+  NetworkProfile,
   KleverResponse,
   AccountData,
   BalanceData,
   AssetData,
   KDAData,
+  AssetHolderData,
+  APIAssetHoldersData,
   VMQueryRequest,
   VMQueryData,
+  StorageData,
+  StorageValueData,
   TransactionData,
+  TransactionListFilter,
+  APITransactionListData,
   BlockData,
   ValidatorData,
   NodeStatusData,
+  NetworkConfigData,
   TransactionBuildRequest,
   TransactionBuildData,
+  TransactionBroadcastData,
   TransferParams,
+  MultiTransferParams,
   DeployParams,
+  UpgradeParams,
   InvokeParams,
   FreezeParams,
+  FaucetData,
 } from './types.js';
 
-/** Network URL mapping */
-const NETWORK_CONFIGS: Record<KleverNetwork, NetworkConfig> = {
-  mainnet: {
-    nodeUrl: 'https://node.mainnet.klever.org',
-    apiUrl: 'https://api.mainnet.klever.org',
-  },
-  testnet: {
-    nodeUrl: 'https://node.testnet.klever.org',
-    apiUrl: 'https://api.testnet.klever.org',
-  },
-  devnet: {
-    nodeUrl: 'https://node.devnet.klever.org',
-    apiUrl: 'https://api.devnet.klever.org',
-  },
-  local: {
-    nodeUrl: 'http://localhost:8080',
-    apiUrl: 'http://localhost:9090',
-  },
-};
-
 export interface ChainClientOptions {
-  /** Default network (can be overridden per-call) */
+  /** Default network (can be overridden per-call; `custom` when only custom URLs are given) */
   network?: KleverNetwork;
   /** Request timeout in milliseconds (default: 15000) */
   timeout?: number;
-  /** Custom node URL (overrides network-based URL) */
+  /** Custom node URL: the `custom` network's, and the default network's */
   nodeUrl?: string;
-  /** Custom API URL (overrides network-based URL) */
+  /** Custom API URL: the `custom` network's, and the default network's */
   apiUrl?: string;
+  /** Chain ID of the `custom` network */
+  chainId?: string;
+  /** Explorer base URL of the `custom` network */
+  explorerUrl?: string;
+  /** Event websocket URL of the `custom` network */
+  eventsUrl?: string;
+  /** Cache of GET responses (default: one with the default TTLs); false to always fetch */
+  cache?: ResponseCache | false;
 }
 
 export class KleverChainClient {
@@ -67,12 +79,25 @@ export class KleverChainClient {
   private timeout: number;
   private customNodeUrl?: string;
   private customApiUrl?: string;
+  private customProfile: NetworkProfile;
+  private networkOverrides: Partial<Record<KleverNetwork, NetworkConfig>> = {};
+  private nonces = new NonceManager();
+  private cache?: ResponseCache;
 
   constructor(options: ChainClientOptions = {}) {
-    this.defaultNetwork = options.network || 'mainnet';
+    const custom = !!(options.nodeUrl || options.apiUrl);
+    this.defaultNetwork = options.network || (custom ? 'custom' : 'mainnet');
     this.timeout = options.timeout || 15000;
     this.customNodeUrl = options.nodeUrl;
     this.customApiUrl = options.apiUrl;
+    this.customProfile = {
+      nodeUrl: options.nodeUrl || options.apiUrl || '',
+      apiUrl: options.apiUrl || options.nodeUrl || '',
+      chainId: options.chainId || '',
+      explorerUrl: options.explorerUrl,
+      eventsUrl: options.eventsUrl,
+    };
+    if (options.cache !== false) this.cache = options.cache || new ResponseCache();
   }
 
   /** Get the configured default network */
@@ -80,48 +105,113 @@ export class KleverChainClient {
     return this.defaultNetwork;
   }
 
+  /**
+   * Point `network` at other URLs, e.g. `local` at a node started by the
+   * local_network tool; these win over custom URLs. Pass undefined to restore.
+   */
+  setNetworkUrls(network: KleverNetwork, config: NetworkConfig | undefined): void {
+    // A restarted local node reuses its URLs with a new chain
+    this.cache?.clear();
+    if (config) {
+      this.networkOverrides[network] = config;
+    } else {
+      delete this.networkOverrides[network];
+    }
+  }
+
+  /**
+   * Resolve the profile of a network: URL overrides first, then custom URLs
+   * (for the default network), then the built-in profile.
+   */
+  getNetworkProfile(network?: KleverNetwork): NetworkProfile {
+    const net = network || this.defaultNetwork;
+    const profile = net === 'custom' ? this.customProfile : NETWORK_PROFILES[net];
+    const override = this.networkOverrides[net];
+    const isDefault = net === this.defaultNetwork;
+    const nodeUrl = override?.nodeUrl || (isDefault && this.customNodeUrl) || profile.nodeUrl;
+    const apiUrl = override?.apiUrl || (isDefault && this.customApiUrl) || profile.apiUrl;
+    if (!nodeUrl) {
+      throw new Error(
+        'Network "custom" is not configured. Set KLEVER_NODE_URL (and KLEVER_API_URL) to use it.'
+      );
+    }
+    return { ...profile, nodeUrl, apiUrl };
+  }
+
   /** Resolve node URL for a given network */
   getNodeUrl(network?: KleverNetwork): string {
-    if (this.customNodeUrl) return this.customNodeUrl;
-    return NETWORK_CONFIGS[network || this.defaultNetwork].nodeUrl;
+    return this.getNetworkProfile(network).nodeUrl;
   }
 
   /** Resolve API proxy URL for a given network */
   getApiUrl(network?: KleverNetwork): string {
-    if (this.customApiUrl) return this.customApiUrl;
-    return NETWORK_CONFIGS[network || this.defaultNetwork].apiUrl;
+    return this.getNetworkProfile(network).apiUrl;
   }
 
-  // ─── Core HTTP Methods ───────────────────────────────────
+  /** Explorer page of a transaction, account or asset on a network, when it has an explorer */
+  getExplorerUrl(
+    kind: 'transaction' | 'account' | 'asset',
+    id: string,
+    network?: KleverNetwork
+  ): string | undefined {
+    return explorerLink(this.getNetworkProfile(network), kind, id);
+  }
 
-  private async fetchWithTimeout(url: string, init?: RequestInit): Promise<Response> {
-    const controller = new AbortController();
-    const timeoutId = setTimeout(() => controller.abort(), this.timeout);
+  /**
+   * Drop cached responses of `network` (every network when omitted), e.g.
+   * after changes made outside this server; returns how many were dropped
+   */
+  clearCache(network?: KleverNetwork): number {
+    if (!this.cache) return 0;
+    if (!network) return this.cache.clear();
+    const { nodeUrl, apiUrl } = this.getNetworkProfile(network);
+    return this.cache.clear([nodeUrl, apiUrl]);
+  }
 
-    try {
-      const response = await fetch(url, { ...init, signal: controller.signal });
+  /** Entries and hit counts of the response cache; undefined when caching is off */
+  cacheStats(): CacheStats | undefined {
+    return this.cache?.stats();
+  }
 
-      if (!response.ok) {
-        const text = await response.text().catch(() => '');
-        throw new Error(`HTTP ${response.status}: ${text || response.statusText}`);
-      }
+  // ─── Core HTTP Methods ───────────────────────────────────
 
-      return response;
-    } catch (error) {
-      if (error instanceof Error && error.name === 'AbortError') {
-        throw new Error(`Request timed out after ${this.timeout}ms: ${url}`);
+  private async fetchWithTimeout(url: string, init?: RequestInit): Promise<Response> {
+    return withSpan('http', { method: init?.method || 'GET', url }, async () => {
+      const controller = new AbortController();
+      const timeoutId = setTimeout(() => controller.abort(), this.timeout);
+      const stopListening = onCancel(() => controller.abort());
+
+      try {
+        const response = await fetch(url, { ...init, signal: controller.signal });
+
+        if (!response.ok) {
+          const text = await response.text().catch(() => '');
+          throw new Error(`HTTP ${response.status}: ${text || response.statusText}`);
+        }
+
+        return response;
+      } catch (error) {
+        if (error instanceof Error && error.name === 'AbortError') {
+          if (currentSignal()?.aborted) throw new CancelledError();
+          throw new Error(`Request timed out after ${this.timeout}ms: ${url}`);
+        }
+        throw error;
+      } finally {
+        clearTimeout(timeoutId);
+        stopListening();
       }
-      throw error;
-    } finally {
-      clearTimeout(timeoutId);
-    }
+    });
   }
 
   private async fetchJson<T>(url: string): Promise<T> {
-    const response = await this.fetchWithTimeout(url, {
-      headers: { Accept: 'application/json' },
-    });
-    return (await response.json()) as T;
+    const load = async () => {
+      const response = await this.fetchWithTimeout(url, {
+        headers: { Accept: 'application/json' },
+      });
+      return (await response.json()) as T;
+    };
+    // A cached load is shared with other calls, so cancelling one only stops waiting for it
+    return this.cache ? cancellable(this.cache.get(url, () => runDetached(load))) : load();
   }
 
   private async postJson<T>(url: string, body: unknown): Promise<T> {
@@ -158,6 +248,27 @@ export class KleverChainClient {
     return this.unwrap(response, `getAccount(${address})`).account;
   }
 
+  /**
+   * Get the code hash of a deployed contract as lowercase hex. Nodes report
+   * it base64-encoded; hex is accepted as well.
+   */
+  async getCodeHash(
+    address: string,
+    network?: KleverNetwork
+  ): Promise<string> {
+    const account = await this.getAccount(address, network);
+    const value = account.codeHash;
+    if (!value) {
+      throw new Error(`getCodeHash(${address}): account has no code (not a smart contract)`);
+    }
+    if (/^(0x)?[0-9a-fA-F]{64}$/.test(value)) return value.replace(/^0x/, '').toLowerCase();
+    const bytes = Buffer.from(value, 'base64');
+    if (bytes.length !== 32) {
+      throw new Error(`getCodeHash(${address}): unexpected code hash format "${value}"`);
+    }
+    return bytes.toString('hex');
+  }
+
   /** Get KLV balance for an address */
   async getBalance(
     address: string,
@@ -213,6 +324,30 @@ export class KleverChainClient {
     return data.asset;
   }
 
+  /** List the holders of an asset, largest balance first (uses API proxy) */
+  async getAssetHolders(
+    assetId: string,
+    options: { page?: number; limit?: number } = {},
+    network?: KleverNetwork
+  ): Promise<Omit<APIAssetHoldersData, 'data'> & { holders: AssetHolderData[] }> {
+    const apiUrl = this.getApiUrl(network);
+    const params = new URLSearchParams({
+      page: String(options.page ?? 1),
+      limit: String(options.limit ?? 10),
+    });
+    const response = await this.fetchJson<APIAssetHoldersData & { error: string }>(
+      `${apiUrl}/v1.0/assets/${encodeURIComponent(assetId)}/holders?${params}`
+    );
+
+    if (response.error) {
+      throw new Error(`getAssetHolders(${assetId}): ${response.error}`);
+    }
+    return {
+      holders: response.data?.accounts || [],
+      pagination: response.pagination,
+    };
+  }
+
   // ─── Smart Contract Operations ───────────────────────────
 
   /** Execute a read-only smart contract query */
@@ -228,6 +363,34 @@ export class KleverChainClient {
     return this.unwrap(response, `querySmartContract(${request.scAddress}::${request.funcName})`);
   }
 
+  /**
+   * Get every storage entry of an account as hex key -> hex value, from the
+   * node's `/address/{address}/keys` endpoint.
+   */
+  async getStorage(
+    address: string,
+    network?: KleverNetwork
+  ): Promise<Record<string, string>> {
+    const nodeUrl = this.getNodeUrl(network);
+    const response = await this.fetchJson<KleverResponse<StorageData>>(
+      `${nodeUrl}/address/${address}/keys`
+    );
+    return this.unwrap(response, `getStorage(${address})`).pairs || {};
+  }
+
+  /** Get one storage value (hex, empty when unset) of an account by its hex key */
+  async getStorageValue(
+    address: string,
+    key: string,
+    network?: KleverNetwork
+  ): Promise<string> {
+    const nodeUrl = this.getNodeUrl(network);
+    const response = await this.fetchJson<KleverResponse<StorageValueData>>(
+      `${nodeUrl}/address/${address}/key/${key}`
+    );
+    return this.unwrap(response, `getStorageValue(${address}, ${key})`).value || '';
+  }
+
   // ─── Transaction Builder Methods ────────────────────────
 
   /** Build an unsigned transfer (KLV or KDA) transaction */
@@ -235,18 +398,26 @@ export class KleverChainClient {
     params: TransferParams,
     network?: KleverNetwork
   ): Promise<TransactionBuildData> {
-    const nonce = await this.getNonce(params.sender, network);
-
-    const contracts: Array<Record<string, unknown>> = [
-      {
-        amount: params.amount,
-        toAddress: params.receiver,
-        ...(params.assetId ? { assetId: params.assetId } : {}),
-      },
-    ];
+    const { sender, ...transfer } = params;
+    return this.buildMultiTransfer({ sender, transfers: [transfer] }, network);
+  }
 
-    return this.buildTransaction(
-      { type: ContractType.Transfer, sender: params.sender, nonce, contracts },
+  /** Build an unsigned transaction with one transfer contract per asset and receiver */
+  async buildMultiTransfer(
+    params: MultiTransferParams,
+    network?: KleverNetwork
+  ): Promise<TransactionBuildData> {
+    if (params.transfers.length === 0) {
+      throw new Error('buildMultiTransfer: at least one transfer is required');
+    }
+    const contracts: Array<Record<string, unknown>> = params.transfers.map(transfer => ({
+      amount: transfer.amount,
+      toAddress: transfer.receiver,
+      ...(transfer.assetId ? { assetId: transfer.assetId } : {}),
+    }));
+
+    return this.buildWithNonce(
+      { type: ContractType.Transfer, sender: params.sender, contracts },
       network
     );
   }
@@ -256,16 +427,31 @@ export class KleverChainClient {
     params: DeployParams,
     network?: KleverNetwork
   ): Promise<TransactionBuildData> {
-    const nonce = await this.getNonce(params.sender, network);
-
     const data = [params.wasmHex, ...(params.initArgs || [])];
 
     const contracts: Array<Record<string, unknown>> = [
       { scType: SCType.SCDeploy },
     ];
 
-    return this.buildTransaction(
-      { type: ContractType.SmartContract, sender: params.sender, nonce, contracts, data },
+    return this.buildWithNonce(
+      { type: ContractType.SmartContract, sender: params.sender, contracts, data },
+      network
+    );
+  }
+
+  /** Build an unsigned smart contract upgrade transaction (new code for an existing address) */
+  async buildUpgrade(
+    params: UpgradeParams,
+    network?: KleverNetwork
+  ): Promise<TransactionBuildData> {
+    const data = [params.wasmHex, ...(params.upgradeArgs || [])];
+
+    const contracts: Array<Record<string, unknown>> = [
+      { scType: SCType.SCUpgrade, address: params.scAddress },
+    ];
+
+    return this.buildWithNonce(
+      { type: ContractType.SmartContract, sender: params.sender, contracts, data },
       network
     );
   }
@@ -275,8 +461,6 @@ export class KleverChainClient {
     params: InvokeParams,
     network?: KleverNetwork
   ): Promise<TransactionBuildData> {
-    const nonce = await this.getNonce(params.sender, network);
-
     const data = [params.funcName, ...(params.args || [])];
 
     const contracts: Array<Record<string, unknown>> = [
@@ -287,8 +471,14 @@ export class KleverChainClient {
       },
     ];
 
-    return this.buildTransaction(
-      { type: ContractType.SmartContract, sender: params.sender, nonce, contracts, data },
+    return this.buildWithNonce(
+      {
+        type: ContractType.SmartContract,
+        sender: params.sender,
+        contracts,
+        data,
+        ...(params.gasLimit ? { gasLimit: params.gasLimit } : {}),
+      },
       network
     );
   }
@@ -298,18 +488,62 @@ export class KleverChainClient {
     params: FreezeParams,
     network?: KleverNetwork
   ): Promise<TransactionBuildData> {
-    const nonce = await this.getNonce(params.sender, network);
-
     const contracts: Array<Record<string, unknown>> = [
       { amount: params.amount },
     ];
 
-    return this.buildTransaction(
-      { type: ContractType.Freeze, sender: params.sender, nonce, contracts },
+    return this.buildWithNonce(
+      { type: ContractType.Freeze, sender: params.sender, contracts },
       network
     );
   }
 
+  // ─── Nonce Management ────────────────────────────────────
+
+  private nonceKey(address: string, network?: KleverNetwork): string {
+    return `${network || this.defaultNetwork}:${address}`;
+  }
+
+  /**
+   * Build with the next nonce of the sender, past transactions built earlier
+   * that have not executed yet. The nonce is given back when the build fails.
+   */
+  private async buildWithNonce(
+    request: Omit<TransactionBuildRequest, 'nonce'>,
+    network?: KleverNetwork
+  ): Promise<TransactionBuildData> {
+    const key = this.nonceKey(request.sender, network);
+    const nonce = await this.nonces.reserve(key, () => this.getNonce(request.sender, network));
+    try {
+      const built = await this.buildTransaction({ ...request, nonce }, network);
+      this.nonces.track(key, nonce, built.result.txHash);
+      return built;
+    } catch (error) {
+      this.nonces.release(key, nonce);
+      throw error;
+    }
+  }
+
+  /** Use `nonce` for the next transaction built for `address` */
+  setNonce(address: string, nonce: number, network?: KleverNetwork): void {
+    this.nonces.set(this.nonceKey(address, network), nonce);
+  }
+
+  /** Forget nonces reserved for `address` (or every address) and use the chain nonce again */
+  resyncNonce(address?: string, network?: KleverNetwork): void {
+    this.nonces.resync(address === undefined ? undefined : this.nonceKey(address, network));
+  }
+
+  /** Transactions built for `address` that had not executed at the last build */
+  pendingNonces(address: string, network?: KleverNetwork): NonceReservation[] {
+    return this.nonces.pending(this.nonceKey(address, network));
+  }
+
+  /** Give back the nonce of a built transaction that will not be broadcast */
+  releaseTransaction(txHash: string): boolean {
+    return this.nonces.releaseTransaction(txHash);
+  }
+
   // ─── Transaction Operations ──────────────────────────────
 
   /** Get transaction details by hash (uses API proxy for indexed data) */
@@ -331,6 +565,36 @@ export class KleverChainClient {
     return response.data.transaction;
   }
 
+  /** List indexed transactions, newest first (uses API proxy) */
+  async listTransactions(
+    filter: TransactionListFilter,
+    network?: KleverNetwork
+  ): Promise<Omit<APITransactionListData, 'data'> & { transactions: TransactionData[] }> {
+    const apiUrl = this.getApiUrl(network);
+    const params = new URLSearchParams();
+    const set = (key: string, value: string | number | undefined) => {
+      if (value !== undefined && value !== '') params.set(key, String(value));
+    };
+    set('address', filter.address);
+    set('fromAddress', filter.fromAddress);
+    set('status', filter.status);
+    set('startdate', filter.startTime);
+    set('enddate', filter.endTime);
+    set('page', filter.page);
+    set('limit', filter.limit);
+    const response = await this.fetchJson<APITransactionListData & { error: string }>(
+      `${apiUrl}/v1.0/transaction/list?${params}`
+    );
+
+    if (response.error) {
+      throw new Error(`listTransactions: ${response.error}`);
+    }
+    return {
+      transactions: response.data?.transactions || [],
+      pagination: response.pagination,
+    };
+  }
+
   /** Build an unsigned transaction */
   async buildTransaction(
     request: TransactionBuildRequest,
@@ -344,6 +608,78 @@ export class KleverChainClient {
     return this.unwrap(response, 'buildTransaction');
   }
 
+  /** Broadcast a transaction built by the node together with its signature (hex) */
+  async broadcastTransaction(
+    tx: string,
+    signature: string,
+    network?: KleverNetwork
+  ): Promise<string> {
+    const nodeUrl = this.getNodeUrl(network);
+    const response = await this.postJson<KleverResponse<TransactionBroadcastData>>(
+      `${nodeUrl}/transactions/broadcast`,
+      { tx, signature }
+    );
+    const txHash = this.unwrap(response, 'broadcastTransaction').txHash;
+    // Balances, storage and transaction lists are about to change
+    this.cache?.invalidate(/\/address\/|\/v1\.0\/transaction\/list/);
+    return txHash;
+  }
+
+  /**
+   * Poll the API proxy until the transaction is indexed with a status, or
+   * throw after `timeoutMs` (default: 60 seconds)
+   */
+  async waitForTransaction(
+    hash: string,
+    network?: KleverNetwork,
+    options: { timeoutMs?: number; intervalMs?: number } = {}
+  ): Promise<TransactionData> {
+    const { timeoutMs = 60_000, intervalMs = 2000 } = options;
+    const deadline = Date.now() + timeoutMs;
+    for (;;) {
+      try {
+        const tx = await this.getTransaction(hash, network);
+        if (tx && tx.status) return tx;
+      } catch (error) {
+        if (error instanceof CancelledError) throw error;
+        // Not indexed yet
+      }
+      if (Date.now() + intervalMs > deadline) {
+        throw new Error(`Transaction ${hash} was not processed within ${timeoutMs}ms`);
+      }
+      await sleep(intervalMs);
+    }
+  }
+
+  // ─── Faucet ──────────────────────────────────────────────
+
+  /**
+   * Ask the API proxy faucet of testnet or devnet to send test KLV to
+   * `address`. Faucets limit how often an address may be funded; such a
+   * refusal throws an error starting with "Faucet rate limit".
+   */
+  async requestTestFunds(address: string, network?: KleverNetwork): Promise<FaucetData> {
+    const net = network || this.defaultNetwork;
+    if (net !== 'testnet' && net !== 'devnet') {
+      throw new Error(`requestTestFunds: there is no faucet on ${net}; use testnet or devnet`);
+    }
+    const apiUrl = this.getApiUrl(net);
+    const rateLimited = /HTTP 429|too many|rate.?limit|already (been )?(funded|requested)|try again/i;
+    try {
+      const response = await this.postJson<KleverResponse<FaucetData>>(
+        `${apiUrl}/v1.0/transaction/send-user-funds/${address}`,
+        {}
+      );
+      return this.unwrap(response, `requestTestFunds(${address})`);
+    } catch (error) {
+      const message = (error as Error).message;
+      if (rateLimited.test(message)) {
+        throw new Error(`Faucet rate limit: ${message}`);
+      }
+      throw error;
+    }
+  }
+
   // ─── Block Operations ────────────────────────────────────
 
   /** Get block by nonce (number) */
@@ -404,6 +740,13 @@ export class KleverChainClient {
     );
     return this.unwrap(response, 'getNodeStatus');
   }
-}
 
-export { NETWORK_CONFIGS };
+  /** Get network parameters (chain ID, round duration, min gas price, ...) */
+  async getNetworkConfig(network?: KleverNetwork): Promise<Record<string, unknown>> {
+    const nodeUrl = this.getNodeUrl(network);
+    const response = await this.fetchJson<KleverResponse<NetworkConfigData>>(
+      `${nodeUrl}/network/config`
+    );
+    return this.unwrap(response, 'getNetworkConfig').config || {};
+  }
+}
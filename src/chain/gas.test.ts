@@ -0,0 +1,39 @@
+import { estimateGas, recommendedGasLimit } from './gas.js';
+
+describe('recommendedGasLimit', () => {
+  it('adds the margin and rounds up to a multiple of 1,000', () => {
+    expect(recommendedGasLimit(1_234_567)).toBe(1_482_000);
+    expect(recommendedGasLimit(10_000, 0)).toBe(10_000);
+  });
+});
+
+describe('estimateGas', () => {
+  it('lists gas forwarded to other contracts', () => {
+    const estimate = estimateGas(
+      {
+        returnCode: 'ok',
+        gasUsed: 500_000,
+        outputAccounts: {
+          a1: {
+            address: 'klv1pool',
+            outputTransfers: [
+              { gasLimit: 200_000, data: Buffer.from('swap@01').toString('base64'), callType: 1 },
+              { value: 10 },
+            ],
+          },
+        },
+      },
+      10
+    );
+    expect(estimate).toEqual({
+      gasUsed: 500_000,
+      recommendedGasLimit: 550_000,
+      marginPercent: 10,
+      subCalls: [{ to: 'klv1pool', function: 'swap', gasLimit: 200_000, callType: 1 }],
+    });
+  });
+
+  it('is undefined without gasUsed', () => {
+    expect(estimateGas({ returnCode: 'ok' })).toBeUndefined();
+  });
+});
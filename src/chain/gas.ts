@@ -0,0 +1,63 @@
+/**
+ * Gas estimates from simulated contract calls (VM queries): the gas the call
+ * used, a limit with a safety margin, and the gas forwarded to other
+ * contracts it called.
+ */
+
+import type { VMQueryData } from './types.js';
+
+export interface SubCallGas {
+  /** Called contract */
+  to: string;
+  /** Called function, when the transfer carries one */
+  function?: string;
+  /** Gas forwarded to the call */
+  gasLimit: number;
+  callType?: number | string;
+}
+
+export interface GasEstimate {
+  gasUsed: number;
+  /** gasUsed plus the margin, rounded up to a multiple of 1,000 */
+  recommendedGasLimit: number;
+  marginPercent: number;
+  subCalls: SubCallGas[];
+}
+
+/** The function name in transfer data (`function@arg@...`), which may be base64 */
+function functionName(data: string | undefined): string | undefined {
+  if (!data) return undefined;
+  for (const text of [Buffer.from(data, 'base64').toString('utf8'), data]) {
+    const name = text.split('@')[0];
+    if (/^[A-Za-z_]\w*$/.test(name)) return name;
+  }
+  return undefined;
+}
+
+/** gasUsed plus `marginPercent`, rounded up to a multiple of 1,000 */
+export function recommendedGasLimit(gasUsed: number, marginPercent = 20): number {
+  return Math.ceil((gasUsed * (1 + marginPercent / 100)) / 1000) * 1000;
+}
+
+/** Estimate from a successful VM query; undefined when the node did not report gasUsed */
+export function estimateGas(result: VMQueryData, marginPercent = 20): GasEstimate | undefined {
+  if (typeof result.gasUsed !== 'number') return undefined;
+  const subCalls: SubCallGas[] = [];
+  for (const [key, account] of Object.entries(result.outputAccounts || {})) {
+    for (const transfer of account.outputTransfers || []) {
+      if (!transfer.gasLimit) continue;
+      subCalls.push({
+        to: account.address || key,
+        function: functionName(transfer.data),
+        gasLimit: transfer.gasLimit,
+        callType: transfer.callType,
+      });
+    }
+  }
+  return {
+    gasUsed: result.gasUsed,
+    recommendedGasLimit: recommendedGasLimit(result.gasUsed, marginPercent),
+    marginPercent,
+    subCalls,
+  };
+}
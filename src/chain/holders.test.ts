@@ -0,0 +1,36 @@
+import { holderDistribution } from './holders.js';
+
+describe('holderDistribution', () => {
+  const balances = [
+    { address: 'klv1alice', balance: 500n },
+    { address: 'klv1bob', balance: 300n },
+    { address: 'klv1carol', balance: 0n },
+    { address: 'klv1dave', balance: 200n },
+  ];
+
+  it('counts non-zero holders and ranks the largest', () => {
+    const distribution = holderDistribution(balances, 2);
+    expect(distribution.holderCount).toBe(3);
+    expect(distribution.total).toBe(1000n);
+    expect(distribution.top).toEqual([
+      { address: 'klv1alice', balance: 500n, share: 0.5 },
+      { address: 'klv1bob', balance: 300n, share: 0.3 },
+    ]);
+    expect(distribution.topShare).toBe(0.8);
+  });
+
+  it('computes shares against the given supply', () => {
+    const distribution = holderDistribution(balances, 1, 4000n);
+    expect(distribution.top[0].share).toBe(0.125);
+    expect(distribution.topShare).toBe(0.125);
+  });
+
+  it('handles no holders', () => {
+    expect(holderDistribution([], 10)).toEqual({
+      holderCount: 0,
+      total: 0n,
+      top: [],
+      topShare: 0,
+    });
+  });
+});
@@ -0,0 +1,44 @@
+/**
+ * Holder distribution of a token from per-address balances: holder count,
+ * the largest holders and the share of the supply they hold.
+ */
+
+export interface HolderBalance {
+  address: string;
+  balance: bigint;
+}
+
+export interface HolderDistribution {
+  /** Addresses with a non-zero balance */
+  holderCount: number;
+  /** The supply shares are computed against: the given one, else the sum of the balances */
+  total: bigint;
+  top: Array<HolderBalance & { share: number }>;
+  /** Share of the total held by the top holders, 0-1 */
+  topShare: number;
+}
+
+/** `part / total` as a number with six decimals; 0 when the total is 0 */
+function share(part: bigint, total: bigint): number {
+  return total > 0n ? Number((part * 1_000_000n) / total) / 1_000_000 : 0;
+}
+
+/** Distribution of the balances among their holders, with the `topN` largest */
+export function holderDistribution(
+  balances: HolderBalance[],
+  topN: number,
+  supply?: bigint
+): HolderDistribution {
+  const holders = balances
+    .filter(holder => holder.balance > 0n)
+    .sort((a, b) => (b.balance > a.balance ? 1 : b.balance < a.balance ? -1 : 0));
+  const total = supply ?? holders.reduce((sum, holder) => sum + holder.balance, 0n);
+  const top = holders.slice(0, topN);
+  const topTotal = top.reduce((sum, holder) => sum + holder.balance, 0n);
+  return {
+    holderCount: holders.length,
+    total,
+    top: top.map(holder => ({ ...holder, share: share(holder.balance, total) })),
+    topShare: share(topTotal, total),
+  };
+}
@@ -1,5 +1,23 @@
-export { KleverChainClient, NETWORK_CONFIGS } from './client.js';
+export { KleverChainClient } from './client.js';
+export { DEFAULT_CACHE_RULES, ResponseCache } from './cache.js';
+export type { CacheRule, CacheStats, ResponseCacheOptions } from './cache.js';
+export { explorerLink, NETWORK_NAMES, NETWORK_PROFILES, validateNetwork } from './networks.js';
+export { blake2b } from './blake2b.js';
+export { keccak256 } from './keccak.js';
+export { contractAddress, isContractAddress, WASM_VM_TYPE } from './address.js';
+export { estimateGas, recommendedGasLimit } from './gas.js';
+export { NonceManager } from './nonce.js';
+export { networkStatus } from './network-status.js';
+export { holderDistribution } from './holders.js';
+export type { HolderBalance, HolderDistribution } from './holders.js';
+export { formatUnits } from './units.js';
+export type { NetworkStatus } from './network-status.js';
+export type { NonceManagerOptions, NonceReservation } from './nonce.js';
+export type { GasEstimate, SubCallGas } from './gas.js';
+export { loadSigner, parseWalletKey, signerFromSeed } from './signer.js';
+export type { Signer } from './signer.js';
 export type { ChainClientOptions } from './client.js';
+export { decodeAddress, decodeBech32, encodeAddress, encodeBech32, KLEVER_HRP } from './bech32.js';
 export {
   ContractType,
   SCType,
@@ -7,21 +25,34 @@ export {
 export type {
   KleverNetwork,
   NetworkConfig,
+  NetworkProfile,
   KleverResponse,
   AccountData,
   BalanceData,
   AssetData,
   KDAData,
+  AssetHolderData,
+  APIAssetHoldersData,
   VMQueryRequest,
   VMQueryData,
+  VMOutputTransfer,
+  StorageData,
+  StorageValueData,
   TransactionData,
+  TransactionListFilter,
+  APITransactionListData,
   BlockData,
   ValidatorData,
   NodeStatusData,
+  NetworkConfigData,
   TransactionBuildRequest,
   TransactionBuildData,
+  TransactionBroadcastData,
   TransferParams,
+  MultiTransferParams,
   DeployParams,
+  UpgradeParams,
   InvokeParams,
   FreezeParams,
+  FaucetData,
 } from './types.js';
@@ -0,0 +1,23 @@
+import { keccak256 } from './keccak.js';
+
+const hex = (bytes: Uint8Array) => Buffer.from(bytes).toString('hex');
+
+describe('keccak256', () => {
+  it('matches the reference digests', () => {
+    expect(hex(keccak256(new Uint8Array()))).toBe(
+      'c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470'
+    );
+    expect(hex(keccak256(Buffer.from('abc')))).toBe(
+      '4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45'
+    );
+  });
+
+  it('pads inputs filling the rate to a second block', () => {
+    expect(hex(keccak256(Buffer.alloc(135, 'a')))).toBe(
+      '34367dc248bbd832f4e3e69dfaac2f92638bd0bbd18f2912ba4ef454919cf446'
+    );
+    expect(hex(keccak256(Buffer.alloc(136, 'a')))).toBe(
+      'a6c4d403279fe3e0af03729caada8374b5ca54d8065329a3ebcaeb4b60aa386e'
+    );
+  });
+});
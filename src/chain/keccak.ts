@@ -0,0 +1,86 @@
+/**
+ * Keccak-256, the original Keccak submission that Ethereum and the VM's
+ * `keccak256` use. It differs from NIST SHA3-256 only in the padding byte, so
+ * Node's `sha3-256` gives different digests.
+ */
+
+const MASK = (1n << 64n) - 1n;
+const RATE = 136;
+
+const ROUND_CONSTANTS = [
+  0x0000000000000001n,
+  0x0000000000008082n,
+  0x800000000000808an,
+  0x8000000080008000n,
+  0x000000000000808bn,
+  0x0000000080000001n,
+  0x8000000080008081n,
+  0x8000000000008009n,
+  0x000000000000008an,
+  0x0000000000000088n,
+  0x0000000080008009n,
+  0x000000008000000an,
+  0x000000008000808bn,
+  0x800000000000008bn,
+  0x8000000000008089n,
+  0x8000000000008003n,
+  0x8000000000008002n,
+  0x8000000000000080n,
+  0x000000000000800an,
+  0x800000008000000an,
+  0x8000000080008081n,
+  0x8000000000008080n,
+  0x0000000080000001n,
+  0x8000000080008008n,
+];
+
+/** Rotation offsets, indexed by x + 5 * y */
+const ROTATIONS = [
+  0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+].map(BigInt);
+
+const rotl = (x: bigint, n: bigint) => (n === 0n ? x : ((x << n) | (x >> (64n - n))) & MASK);
+
+function permute(state: bigint[]): void {
+  for (const rc of ROUND_CONSTANTS) {
+    const c = [0, 1, 2, 3, 4].map(
+      x => state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20]
+    );
+    for (let x = 0; x < 5; x++) {
+      const d = c[(x + 4) % 5] ^ rotl(c[(x + 1) % 5], 1n);
+      for (let y = 0; y < 25; y += 5) state[x + y] ^= d;
+    }
+    const b = new Array<bigint>(25);
+    for (let x = 0; x < 5; x++) {
+      for (let y = 0; y < 5; y++) {
+        b[y + 5 * ((2 * x + 3 * y) % 5)] = rotl(state[x + 5 * y], ROTATIONS[x + 5 * y]);
+      }
+    }
+    for (let i = 0; i < 25; i += 5) {
+      for (let x = 0; x < 5; x++) {
+        state[i + x] = b[i + x] ^ (~b[i + ((x + 1) % 5)] & MASK & b[i + ((x + 2) % 5)]);
+      }
+    }
+    state[0] ^= rc;
+  }
+}
+
+/** Keccak-256 digest of `data` (32 bytes) */
+export function keccak256(data: Uint8Array): Uint8Array {
+  const padded = new Uint8Array(Math.floor(data.length / RATE + 1) * RATE);
+  padded.set(data);
+  padded[data.length] ^= 0x01;
+  padded[padded.length - 1] ^= 0x80;
+
+  const state = new Array<bigint>(25).fill(0n);
+  const view = new DataView(padded.buffer);
+  for (let offset = 0; offset < padded.length; offset += RATE) {
+    for (let i = 0; i < RATE / 8; i++) state[i] ^= view.getBigUint64(offset + i * 8, true);
+    permute(state);
+  }
+
+  const out = new Uint8Array(32);
+  const outView = new DataView(out.buffer);
+  for (let i = 0; i < 4; i++) outView.setBigUint64(i * 8, state[i], true);
+  return out;
+}
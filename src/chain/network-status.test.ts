@@ -0,0 +1,43 @@
+import { networkStatus } from './network-status.js';
+
+describe('networkStatus', () => {
+  it('reads prefixed snake_case metrics from status and config', () => {
+    const status = networkStatus(
+      { metrics: { klv_nonce: 1200, klv_epoch_number: 7, klv_is_syncing: 0 } },
+      { klv_chain_id: '109', klv_round_duration: 4000, klv_min_gas_price: '1000000' }
+    );
+    expect(status).toEqual({
+      blockNonce: 1200,
+      epoch: 7,
+      roundTimeMs: 4000,
+      minGasPrice: 1000000,
+      chainId: '109',
+      isSynced: true,
+      appVersion: undefined,
+    });
+  });
+
+  it('reads camelCase fields', () => {
+    const status = networkStatus({
+      isSynced: false,
+      currentNonce: 55,
+      appVersion: 'v1.7.0',
+      chainId: 108,
+    });
+    expect(status.blockNonce).toBe(55);
+    expect(status.isSynced).toBe(false);
+    expect(status.appVersion).toBe('v1.7.0');
+    expect(status.chainId).toBe('108');
+  });
+
+  it('prefers the first source', () => {
+    expect(networkStatus({ epoch: 3 }, { epoch: 4 }).epoch).toBe(3);
+  });
+
+  it('leaves missing metrics undefined', () => {
+    const status = networkStatus(undefined, {});
+    expect(status.epoch).toBeUndefined();
+    expect(status.minGasPrice).toBeUndefined();
+    expect(status.isSynced).toBeUndefined();
+  });
+});
@@ -0,0 +1,81 @@
+/**
+ * Network status from node metrics. Nodes report metrics under prefixed
+ * snake_case keys (`klv_nonce`, `klv_epoch_number`, ...), possibly nested in
+ * a `metrics` or `config` object; camelCase and unprefixed keys are accepted
+ * too.
+ */
+
+export interface NetworkStatus {
+  blockNonce?: number;
+  epoch?: number;
+  /** Round (block) time in milliseconds */
+  roundTimeMs?: number;
+  minGasPrice?: number;
+  chainId?: string;
+  isSynced?: boolean;
+  appVersion?: string;
+}
+
+/** Names a metric may have, without prefix, in snake_case */
+const METRICS: Record<keyof NetworkStatus, string[]> = {
+  blockNonce: ['current_nonce', 'nonce', 'block_nonce'],
+  epoch: ['epoch_number', 'epoch', 'current_epoch'],
+  roundTimeMs: ['round_duration', 'round_time'],
+  minGasPrice: ['min_gas_price'],
+  chainId: ['chain_id'],
+  isSynced: ['is_synced'],
+  appVersion: ['app_version'],
+};
+
+/** `klv_epoch_number`, `epochNumber` and `EpochNumber` -> `epoch_number` */
+function metricName(key: string): string {
+  return key
+    .replace(/^(klv|erd)_/, '')
+    .replace(/([a-z0-9])([A-Z])/g, '$1_$2')
+    .toLowerCase();
+}
+
+/** All metrics of the given objects and their `metrics`/`config`/`status` members */
+function flatten(sources: unknown[]): Map<string, unknown> {
+  const metrics = new Map<string, unknown>();
+  const visit = (value: unknown) => {
+    if (!value || typeof value !== 'object' || Array.isArray(value)) return;
+    for (const [key, item] of Object.entries(value)) {
+      if (['metrics', 'config', 'status'].includes(key)) visit(item);
+      else if (!metrics.has(metricName(key))) metrics.set(metricName(key), item);
+    }
+  };
+  sources.forEach(visit);
+  return metrics;
+}
+
+function numeric(value: unknown): number | undefined {
+  const n = typeof value === 'string' ? Number(value) : value;
+  return typeof n === 'number' && Number.isFinite(n) ? n : undefined;
+}
+
+/** Status fields found in node status and network config responses */
+export function networkStatus(...sources: unknown[]): NetworkStatus {
+  const metrics = flatten(sources);
+  const find = (field: keyof NetworkStatus) =>
+    METRICS[field].map(name => metrics.get(name)).find(value => value !== undefined);
+
+  const syncing = metrics.get('is_syncing');
+  const synced = metrics.get('is_synced');
+  const chainId = find('chainId');
+  const appVersion = find('appVersion');
+  return {
+    blockNonce: numeric(find('blockNonce')),
+    epoch: numeric(find('epoch')),
+    roundTimeMs: numeric(find('roundTimeMs')),
+    minGasPrice: numeric(find('minGasPrice')),
+    chainId: chainId === undefined ? undefined : String(chainId),
+    isSynced:
+      typeof synced === 'boolean'
+        ? synced
+        : syncing !== undefined
+          ? Number(syncing) === 0
+          : undefined,
+    appVersion: typeof appVersion === 'string' ? appVersion : undefined,
+  };
+}
@@ -0,0 +1,30 @@
+import { explorerLink, NETWORK_PROFILES, validateNetwork } from './networks.js';
+
+describe('validateNetwork', () => {
+  it('accepts network names and aliases', () => {
+    expect(validateNetwork(undefined)).toBeUndefined();
+    expect(validateNetwork('devnet')).toBe('devnet');
+    expect(validateNetwork('custom')).toBe('custom');
+    expect(validateNetwork('localnet')).toBe('local');
+  });
+
+  it('rejects unknown networks with the valid options', () => {
+    expect(() => validateNetwork('moonnet')).toThrow(
+      'Invalid network "moonnet". Valid options: mainnet, testnet, devnet, local, custom.'
+    );
+  });
+});
+
+describe('explorerLink', () => {
+  it('links to the explorer page of the network', () => {
+    expect(explorerLink(NETWORK_PROFILES.testnet, 'account', 'klv1abc')).toBe(
+      'https://testnet.kleverscan.org/account/klv1abc'
+    );
+    const custom = { ...NETWORK_PROFILES.custom, explorerUrl: 'http://scan/' };
+    expect(explorerLink(custom, 'asset', 'KLV')).toBe('http://scan/asset/KLV');
+  });
+
+  it('returns undefined without an explorer', () => {
+    expect(explorerLink(NETWORK_PROFILES.local, 'transaction', 'abc')).toBeUndefined();
+  });
+});
@@ -0,0 +1,67 @@
+/**
+ * Klever network profiles: where each network's node and API proxy live, its
+ * chain ID, and the explorer that shows its transactions. Tools pick one per
+ * call with their `network` parameter; the chain client resolves it here.
+ */
+
+import type { KleverNetwork, NetworkProfile } from './types.js';
+
+/** Network names in the order tools list them */
+export const NETWORK_NAMES: KleverNetwork[] = ['mainnet', 'testnet', 'devnet', 'local', 'custom'];
+
+/** Other names accepted for a network */
+const NETWORK_ALIASES: Record<string, KleverNetwork> = {
+  localnet: 'local',
+};
+
+/** Built-in profiles; `custom` has no URLs until configured (KLEVER_NODE_URL, KLEVER_API_URL) */
+export const NETWORK_PROFILES: Record<KleverNetwork, NetworkProfile> = {
+  mainnet: {
+    nodeUrl: 'https://node.mainnet.klever.org',
+    apiUrl: 'https://api.mainnet.klever.org',
+    chainId: '108',
+    explorerUrl: 'https://kleverscan.org',
+  },
+  testnet: {
+    nodeUrl: 'https://node.testnet.klever.org',
+    apiUrl: 'https://api.testnet.klever.org',
+    chainId: '109',
+    explorerUrl: 'https://testnet.kleverscan.org',
+  },
+  devnet: {
+    nodeUrl: 'https://node.devnet.klever.org',
+    apiUrl: 'https://api.devnet.klever.org',
+    chainId: '1001',
+    explorerUrl: 'https://devnet.kleverscan.org',
+  },
+  local: {
+    nodeUrl: 'http://localhost:8080',
+    apiUrl: 'http://localhost:9090',
+    chainId: '420',
+  },
+  custom: {
+    nodeUrl: '',
+    apiUrl: '',
+    chainId: '',
+  },
+};
+
+/** Check a user-supplied network name; undefined selects the client default */
+export function validateNetwork(network: string | undefined): KleverNetwork | undefined {
+  if (network === undefined) return undefined;
+  const name = NETWORK_ALIASES[network] || network;
+  if (!(NETWORK_NAMES as string[]).includes(name)) {
+    throw new Error(`Invalid network "${network}". Valid options: ${NETWORK_NAMES.join(', ')}.`);
+  }
+  return name as KleverNetwork;
+}
+
+/** Explorer page of a transaction, address or asset; undefined when the network has no explorer */
+export function explorerLink(
+  profile: NetworkProfile,
+  kind: 'transaction' | 'account' | 'asset',
+  id: string
+): string | undefined {
+  if (!profile.explorerUrl) return undefined;
+  return `${profile.explorerUrl.replace(/\/+$/, '')}/${kind}/${id}`;
+}
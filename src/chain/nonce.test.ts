@@ -0,0 +1,54 @@
+import { NonceManager } from './nonce.js';
+
+describe('NonceManager', () => {
+  let now: number;
+  let manager: NonceManager;
+  const chain = (nonce: number) => () => Promise.resolve(nonce);
+
+  beforeEach(() => {
+    now = 0;
+    manager = new NonceManager({ staleAfterMs: 1000, now: () => now });
+  });
+
+  it('hands out consecutive nonces while earlier transactions are pending', async () => {
+    const nonces = await Promise.all([
+      manager.reserve('klv1a', chain(5)),
+      manager.reserve('klv1a', chain(5)),
+      manager.reserve('klv1b', chain(0)),
+    ]);
+    expect(nonces).toEqual([5, 6, 0]);
+    // The first transaction executed
+    await expect(manager.reserve('klv1a', chain(6))).resolves.toBe(7);
+    expect(manager.pending('klv1a').map(p => p.nonce)).toEqual([6, 7]);
+  });
+
+  it('follows the chain when it moves past the reservations', async () => {
+    await manager.reserve('klv1a', chain(1));
+    await expect(manager.reserve('klv1a', chain(10))).resolves.toBe(10);
+  });
+
+  it('reuses released and stale nonces', async () => {
+    await manager.reserve('klv1a', chain(3));
+    const second = await manager.reserve('klv1a', chain(3));
+    manager.track('klv1a', second, 'hash4');
+    expect(manager.releaseTransaction('hash4')).toBe(true);
+    await expect(manager.reserve('klv1a', chain(3))).resolves.toBe(4);
+
+    // Nonce 3 was never broadcast: after staleAfterMs the chain nonce is used again
+    now = 2000;
+    await expect(manager.reserve('klv1a', chain(3))).resolves.toBe(3);
+  });
+
+  it('supports manual overrides and resync', async () => {
+    manager.set('klv1a', 20);
+    await expect(manager.reserve('klv1a', chain(3))).resolves.toBe(20);
+    manager.resync('klv1a');
+    await expect(manager.reserve('klv1a', chain(3))).resolves.toBe(3);
+  });
+
+  it('keeps serving a sender after a failed chain lookup', async () => {
+    const failing = () => Promise.reject(new Error('node down'));
+    await expect(manager.reserve('klv1a', failing)).rejects.toThrow('node down');
+    await expect(manager.reserve('klv1a', chain(2))).resolves.toBe(2);
+  });
+});
@@ -0,0 +1,124 @@
+/**
+ * Nonce tracking for transactions built by this process. The node only
+ * knows the nonce of executed transactions, so two transactions built before
+ * the first one executes would otherwise share a nonce. Each sender's
+ * reservations are serialized and remembered until the chain catches up.
+ */
+
+export interface NonceReservation {
+  nonce: number;
+  reservedAt: number;
+  /** Hash of the transaction built with the nonce */
+  txHash?: string;
+}
+
+export interface NonceManagerOptions {
+  /**
+   * Start over from the chain nonce when the reservation the chain waits for
+   * is older than this (it was never broadcast). Default: 60 seconds.
+   */
+  staleAfterMs?: number;
+  now?: () => number;
+}
+
+interface SenderState {
+  next: number;
+  pending: NonceReservation[];
+}
+
+export class NonceManager {
+  private senders = new Map<string, SenderState>();
+  private queues = new Map<string, Promise<unknown>>();
+  private staleAfterMs: number;
+  private now: () => number;
+
+  constructor(options: NonceManagerOptions = {}) {
+    this.staleAfterMs = options.staleAfterMs ?? 60_000;
+    this.now = options.now ?? Date.now;
+  }
+
+  /**
+   * Reserve the next nonce of `sender`: the chain nonce, or past the
+   * transactions reserved here that have not executed yet.
+   */
+  reserve(sender: string, chainNonce: () => Promise<number>): Promise<number> {
+    const run = async () => {
+      const state = this.sync(sender, await chainNonce());
+      const nonce = state.next++;
+      state.pending.push({ nonce, reservedAt: this.now() });
+      return nonce;
+    };
+    // Chain after the previous reservation of the sender, whether it succeeded or not
+    const previous = this.queues.get(sender) ?? Promise.resolve();
+    const result = previous.then(run, run);
+    this.queues.set(sender, result.catch(() => undefined));
+    return result;
+  }
+
+  /** Record the hash of the transaction built with a reserved nonce */
+  track(sender: string, nonce: number, txHash: string): void {
+    const reservation = this.senders.get(sender)?.pending.find(p => p.nonce === nonce);
+    if (reservation) reservation.txHash = txHash;
+  }
+
+  /**
+   * Give back a nonce whose transaction will not be sent. Only the latest
+   * reservation can be reused as is; otherwise the sender starts over from
+   * the chain nonce.
+   */
+  release(sender: string, nonce: number): void {
+    const state = this.senders.get(sender);
+    if (!state) return;
+    if (nonce === state.next - 1) {
+      state.next = nonce;
+      state.pending = state.pending.filter(p => p.nonce !== nonce);
+    } else {
+      this.senders.delete(sender);
+    }
+  }
+
+  /** Give back the nonce of the transaction with `txHash`; false when it is not tracked */
+  releaseTransaction(txHash: string): boolean {
+    for (const [sender, state] of this.senders) {
+      const reservation = state.pending.find(p => p.txHash === txHash);
+      if (reservation) {
+        this.release(sender, reservation.nonce);
+        return true;
+      }
+    }
+    return false;
+  }
+
+  /** Use `nonce` for the next transaction of `sender` (unless the chain is already past it) */
+  set(sender: string, nonce: number): void {
+    this.senders.set(sender, { next: nonce, pending: [] });
+  }
+
+  /** Forget what was reserved for `sender`, or for every sender */
+  resync(sender?: string): void {
+    if (sender === undefined) this.senders.clear();
+    else this.senders.delete(sender);
+  }
+
+  /** Reservations of `sender` the chain has not caught up with, as of the last reservation */
+  pending(sender: string): NonceReservation[] {
+    return [...(this.senders.get(sender)?.pending || [])];
+  }
+
+  private sync(sender: string, onChain: number): SenderState {
+    let state = this.senders.get(sender);
+    if (state) {
+      state.pending = state.pending.filter(p => p.nonce >= onChain);
+      const awaited = state.pending[0];
+      if (awaited?.nonce === onChain && this.now() - awaited.reservedAt > this.staleAfterMs) {
+        state = undefined;
+      }
+    }
+    if (!state) {
+      state = { next: onChain, pending: [] };
+      this.senders.set(sender, state);
+    }
+    state.next = Math.max(state.next, onChain);
+    return state;
+  }
+}
@@ -0,0 +1,69 @@
+/**
+ * Ed25519 transaction signing with a wallet key file as written by koperator
+ * (`walletKey.pem`). Tools pass the path of the key file, so the key itself
+ * never travels through the MCP client; only the signature leaves this module.
+ */
+
+import { createPrivateKey, createPublicKey, sign } from 'crypto';
+import { readFile } from 'fs/promises';
+import { encodeAddress } from './bech32.js';
+
+/** PKCS#8 DER header of an Ed25519 private key; the 32-byte seed follows it */
+const PKCS8_ED25519_PREFIX = Buffer.from('302e020100300506032b657004220420', 'hex');
+
+export interface Signer {
+  /** klv1... address of the key */
+  address: string;
+  /** Ed25519 signature of `message`, hex */
+  sign(message: Uint8Array): string;
+}
+
+/**
+ * The 32-byte seed in a wallet key file: PEM with the base64 of the hex seed
+ * (optionally followed by the public key), PEM with raw bytes, or plain hex.
+ */
+export function parseWalletKey(contents: string): Buffer {
+  const trimmed = contents.trim();
+  let bytes: Buffer;
+  if (/^([0-9a-fA-F]{64}){1,2}$/.test(trimmed)) {
+    bytes = Buffer.from(trimmed, 'hex');
+  } else {
+    const body = trimmed.replace(/-----[^-]+-----/g, '').replace(/\s+/g, '');
+    const decoded = Buffer.from(body, 'base64');
+    const text = decoded.toString('latin1');
+    bytes = /^([0-9a-fA-F]{2})+$/.test(text) ? Buffer.from(text, 'hex') : decoded;
+  }
+  if (bytes.length !== 32 && bytes.length !== 64) {
+    throw new Error(
+      'Unrecognized wallet key format: expected a 32-byte seed, optionally followed by the 32-byte public key'
+    );
+  }
+  return bytes.subarray(0, 32);
+}
+
+/** Signer for an Ed25519 seed */
+export function signerFromSeed(seed: Uint8Array): Signer {
+  const key = createPrivateKey({
+    key: Buffer.concat([PKCS8_ED25519_PREFIX, seed]),
+    format: 'der',
+    type: 'pkcs8',
+  });
+  const spki = createPublicKey(key).export({ format: 'der', type: 'spki' });
+  return {
+    address: encodeAddress(spki.subarray(spki.length - 32)),
+    sign: message => sign(null, message, key).toString('hex'),
+  };
+}
+
+/** Load the wallet key at `path`, checking the address in the PEM header when present */
+export async function loadSigner(path: string): Promise<Signer> {
+  const contents = await readFile(path, 'utf8');
+  const signer = signerFromSeed(parseWalletKey(contents));
+  const declared = /BEGIN PRIVATE KEY for (klv1\w+)/.exec(contents);
+  if (declared && declared[1] !== signer.address) {
+    throw new Error(
+      `Wallet key ${path} is labelled ${declared[1]} but its key belongs to ${signer.address}`
+    );
+  }
+  return signer;
+}
@@ -3,7 +3,7 @@
  */
 
 /** Supported network environments */
-export type KleverNetwork = 'mainnet' | 'testnet' | 'devnet' | 'local';
+export type KleverNetwork = 'mainnet' | 'testnet' | 'devnet' | 'local' | 'custom';
 
 /** Network URL configuration */
 export interface NetworkConfig {
@@ -11,6 +11,15 @@ export interface NetworkConfig {
   apiUrl: string;
 }
 
+/** Everything known about a network: its URLs, chain ID and block explorer */
+export interface NetworkProfile extends NetworkConfig {
+  chainId: string;
+  /** Explorer base URL (none for local networks) */
+  explorerUrl?: string;
+  /** Websocket pushing contract events, when one is configured */
+  eventsUrl?: string;
+}
+
 /** Standard Klever API response wrapper */
 export interface KleverResponse<T> {
   data: T;
@@ -28,6 +37,10 @@ export interface AccountData {
   permissions: string[];
   timestamp: number;
   rootHash?: string;
+  /** Hash of the deployed code (smart contract accounts only), hex or base64 */
+  codeHash?: string;
+  /** Address of the contract owner (smart contract accounts only) */
+  ownerAddress?: string;
   assets?: Record<string, AssetBalance>;
 }
 
@@ -109,6 +122,30 @@ export interface VMQueryRequest {
   funcName: string;
   args?: string[];
   caller?: string;
+  /** Payments of a simulated call, as in InvokeParams */
+  callValue?: Record<string, number>;
+}
+
+/** Call or transfer to another account produced by a VM execution */
+export interface VMOutputTransfer {
+  value?: number | string;
+  gasLimit?: number;
+  /** `function@arg@...`, plain or base64 */
+  data?: string;
+  callType?: number | string;
+  senderAddress?: string;
+}
+
+/** Storage of an account from GET /address/{address}/keys */
+export interface StorageData {
+  /** Hex key -> hex value */
+  pairs: Record<string, string>;
+}
+
+/** One storage value from GET /address/{address}/key/{key} */
+export interface StorageValueData {
+  /** Hex value */
+  value: string;
 }
 
 /** VM query response from POST /vm/query */
@@ -117,6 +154,12 @@ export interface VMQueryData {
   returnCode?: string;
   returnMessage?: string;
   gasUsed?: number;
+  gasRemaining?: number;
+  /** Accounts touched by the execution, keyed by address */
+  outputAccounts?: Record<
+    string,
+    { address?: string; outputTransfers?: VMOutputTransfer[]; [key: string]: unknown }
+  >;
 }
 
 /** Transaction data from GET /transaction/{hash} */
@@ -177,6 +220,11 @@ export interface NodeStatusData {
   [key: string]: unknown;
 }
 
+/** Network parameters from GET /network/config, keyed by metric name */
+export interface NetworkConfigData {
+  config: Record<string, unknown>;
+}
+
 /**
  * Klever contract type numbers (from transaction.proto).
  *
@@ -194,6 +242,7 @@ export const ContractType = {
 export const SCType = {
   SCInvoke: 0,
   SCDeploy: 1,
+  SCUpgrade: 2,
 } as const;
 
 /** Transaction build request for POST /transaction/send */
@@ -203,6 +252,8 @@ export interface TransactionBuildRequest {
   nonce: number;
   contracts: Array<Record<string, unknown>>;
   data?: string[];
+  /** Gas limit for smart contract execution (node default when omitted) */
+  gasLimit?: number;
   kdaFee?: {
     kda: string;
     amount: number;
@@ -217,6 +268,12 @@ export interface TransferParams {
   assetId?: string;
 }
 
+/** Parameters for building one transaction with several transfers */
+export interface MultiTransferParams {
+  sender: string;
+  transfers: Array<Omit<TransferParams, 'sender'>>;
+}
+
 /** Parameters for building a deploy SC transaction */
 export interface DeployParams {
   sender: string;
@@ -224,6 +281,14 @@ export interface DeployParams {
   initArgs?: string[];
 }
 
+/** Parameters for building an upgrade SC transaction */
+export interface UpgradeParams {
+  sender: string;
+  scAddress: string;
+  wasmHex: string;
+  upgradeArgs?: string[];
+}
+
 /** Parameters for building an invoke SC transaction */
 export interface InvokeParams {
   sender: string;
@@ -231,6 +296,7 @@ export interface InvokeParams {
   funcName: string;
   args?: string[];
   callValue?: Record<string, number>;
+  gasLimit?: number;
 }
 
 /** Parameters for building a freeze KLV transaction */
@@ -252,6 +318,12 @@ export interface TransactionBroadcastData {
   txHash: string;
 }
 
+/** Faucet response from POST /v1.0/transaction/send-user-funds/{address} */
+export interface FaucetData {
+  txHash?: string;
+  [key: string]: unknown;
+}
+
 /** API proxy transaction response (indexed data) */
 export interface APITransactionData {
   data: {
@@ -259,6 +331,57 @@ export interface APITransactionData {
   };
 }
 
+/** Filters of GET /v1.0/transaction/list; times are Unix milliseconds */
+export interface TransactionListFilter {
+  /** Transactions sent to or from this address */
+  address?: string;
+  fromAddress?: string;
+  status?: 'success' | 'fail';
+  startTime?: number;
+  endTime?: number;
+  /** 1-based page */
+  page?: number;
+  limit?: number;
+}
+
+/** API proxy transaction list response */
+export interface APITransactionListData {
+  data: {
+    transactions: TransactionData[];
+  };
+  pagination?: {
+    self: number;
+    next: number;
+    previous: number;
+    perPage: number;
+    totalPages: number;
+    totalRecords: number;
+  };
+}
+
+/** Account holding an asset, from GET /v1.0/assets/{assetID}/holders */
+export interface AssetHolderData {
+  address: string;
+  balance: number | string;
+  frozenBalance?: number | string;
+  [key: string]: unknown;
+}
+
+/** API proxy asset holders response */
+export interface APIAssetHoldersData {
+  data: {
+    accounts: AssetHolderData[];
+  };
+  pagination?: {
+    self: number;
+    next: number;
+    previous: number;
+    perPage: number;
+    totalPages: number;
+    totalRecords: number;
+  };
+}
+
 /** API proxy block list response */
 export interface APIBlockListData {
   data: {
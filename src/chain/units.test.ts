@@ -0,0 +1,15 @@
+import { formatUnits } from './units.js';
+
+describe('formatUnits', () => {
+  it('places the decimal point by precision', () => {
+    expect(formatUnits(1_500_000, 6)).toBe('1.5');
+    expect(formatUnits(25, 6)).toBe('0.000025');
+    expect(formatUnits(3_000_000, 6)).toBe('3');
+    expect(formatUnits(42, 0)).toBe('42');
+  });
+
+  it('handles bigints beyond the safe integer range and negative amounts', () => {
+    expect(formatUnits(123456789012345678901234n, 18)).toBe('123456.789012345678901234');
+    expect(formatUnits(-1_250_000n, 6)).toBe('-1.25');
+  });
+});
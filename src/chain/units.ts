@@ -0,0 +1,10 @@
+/** An amount in smallest units as a decimal string with `precision` decimals */
+export function formatUnits(value: number | bigint, precision: number): string {
+  const units = BigInt(typeof value === 'bigint' ? value : Math.trunc(value));
+  const digits = (units < 0n ? -units : units).toString().padStart(precision + 1, '0');
+  const sign = units < 0n ? '-' : '';
+  if (precision === 0) return `${sign}${digits}`;
+  const whole = digits.slice(0, -precision);
+  const fraction = digits.slice(-precision).replace(/0+$/, '');
+  return fraction ? `${sign}${whole}.${fraction}` : `${sign}${whole}`;
+}
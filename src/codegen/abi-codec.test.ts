@@ -0,0 +1,166 @@
+import { parseAbi } from '../abi/index.js';
+import {
+  decodeEvent,
+  decodeNested,
+  decodeNestedPrefix,
+  decodeResults,
+  decodeTopLevel,
+  encodeArguments,
+  encodeNested,
+  encodeTopLevel,
+} from './abi-codec.js';
+import { AbiValueError } from './abi-values.js';
+
+const ABI = parseAbi({
+  name: 'Vault',
+  constructor: {
+    inputs: [
+      { name: 'owner', type: 'Address' },
+      { name: 'supply', type: 'BigUint' },
+      { name: 'fee', type: 'u64' },
+      { name: 'label', type: 'optional<bytes>' },
+    ],
+    outputs: [],
+  },
+  endpoints: [],
+  events: [
+    {
+      identifier: 'deposit',
+      inputs: [
+        { name: 'from', type: 'Address', indexed: true },
+        { name: 'amount', type: 'BigUint' },
+      ],
+    },
+  ],
+  types: {
+    Config: {
+      type: 'struct',
+      fields: [
+        { name: 'enabled', type: 'bool' },
+        { name: 'limit', type: 'u32' },
+      ],
+    },
+    Status: {
+      type: 'enum',
+      variants: [
+        { name: 'Active', discriminant: 0 },
+        { name: 'Paused', discriminant: 1 },
+        { name: 'Limited', discriminant: 2, fields: [{ name: 'max', type: 'BigUint' }] },
+      ],
+    },
+  },
+});
+
+const OWNER = 'klv1qqrsu9guyv4rzwplgex4gkmzd9c8wl593jfe4gdg47mtm3xt6tvs6kad8h';
+const OWNER_BYTES = Buffer.from(Array.from({ length: 32 }, (_, i) => i * 7));
+
+const top = (type: string, value: unknown) => encodeTopLevel(type, value, ABI).toString('hex');
+const nested = (type: string, value: unknown) => encodeNested(type, value, ABI).toString('hex');
+
+describe('encodeTopLevel', () => {
+  it('encodes integers minimally and keeps the sign of signed values', () => {
+    expect(top('u8', 0)).toBe('');
+    expect(top('u64', '1_000_000')).toBe('0f4240');
+    expect(top('BigUint', '256')).toBe('0100');
+    expect(top('i8', -1)).toBe('ff');
+    expect(top('i64', 128)).toBe('0080');
+    expect(top('BigInt', -129)).toBe('ff7f');
+    expect(() => top('u8', 256)).toThrow('u8 cannot hold 256');
+    expect(() => top('u32', -1)).toThrow('u32 cannot hold -1');
+  });
+
+  it('encodes addresses, bytes, options, lists and custom types', () => {
+    expect(top('Address', OWNER)).toBe(OWNER_BYTES.toString('hex'));
+    expect(top('bytes', 'abc')).toBe('616263');
+    expect(top('bytes', '0xff00')).toBe('ff00');
+    expect(top('bool', false)).toBe('');
+    expect(top('Option<u8>', null)).toBe('');
+    expect(top('Option<u8>', 1)).toBe('0101');
+    expect(top('List<u16>', [1, 2])).toBe('00010002');
+    expect(top('Config', { enabled: true, limit: 5 })).toBe('0100000005');
+    expect(top('Status', 'Paused')).toBe('01');
+    expect(top('Status', 'Active')).toBe('');
+    expect(top('Status', { Limited: { max: 1000 } })).toBe('020000000203e8');
+    expect(() => top('Address', 'alice')).toThrow('Expected a klv1... address');
+  });
+
+  it('takes only true and false as booleans', () => {
+    expect(top('bool', true)).toBe('01');
+    expect(top('bool', 'true')).toBe('01');
+    expect(top('bool', 'false')).toBe('');
+    expect(nested('bool', 'false')).toBe('00');
+    for (const value of ['0', 'False', 'yes', 1, 0, null]) {
+      expect(() => top('bool', value)).toThrow(AbiValueError);
+      expect(() => nested('bool', value)).toThrow('Expected true or false for bool');
+    }
+  });
+});
+
+describe('encodeNested', () => {
+  it('uses fixed widths and length prefixes', () => {
+    expect(nested('u32', 5)).toBe('00000005');
+    expect(nested('BigUint', 256)).toBe('000000020100');
+    expect(nested('bytes', 'abc')).toBe('00000003616263');
+    expect(nested('Option<u8>', null)).toBe('00');
+    expect(nested('List<u16>', [1, 2])).toBe('0000000200010002');
+    expect(nested('Status', 'Active')).toBe('00');
+    expect(nested('array2<u8>', '0xabcd')).toBe('abcd');
+  });
+});
+
+describe('encodeArguments', () => {
+  const inputs = ABI.constructor!.inputs;
+  const encode = (values: unknown[] | Record<string, unknown>) =>
+    encodeArguments(inputs, values, ABI, 'constructor').map(a => a.toString('hex'));
+
+  it('encodes constructor arguments and drops omitted optional ones', () => {
+    const owner = OWNER_BYTES.toString('hex');
+    expect(encode([OWNER, '1000000', 5])).toEqual([owner, '0f4240', '05']);
+    expect(encode({ owner: OWNER, supply: 0, fee: 0, label: 'x' })).toEqual([owner, '', '', '78']);
+    expect(() => encode([OWNER])).toThrow('constructor is missing argument supply');
+  });
+});
+
+describe('decoding', () => {
+  const hex = (value: string) => Buffer.from(value, 'hex');
+
+  it('inverts the top-level and nested encodings', () => {
+    expect(decodeTopLevel('BigUint', hex('0f4240'), ABI)).toBe(1000000);
+    expect(decodeTopLevel('u64', hex(''), ABI)).toBe(0);
+    expect(decodeTopLevel('i8', hex('ff'), ABI)).toBe(-1);
+    expect(decodeTopLevel('BigUint', hex('ff'.repeat(9)), ABI)).toBe('4722366482869645213695');
+    expect(decodeTopLevel('Address', OWNER_BYTES, ABI)).toBe(OWNER);
+    expect(decodeTopLevel('bytes', hex('616263'), ABI)).toBe('abc');
+    expect(decodeTopLevel('bytes', hex('ff00'), ABI)).toBe('0xff00');
+    expect(decodeTopLevel('Option<u8>', hex(''), ABI)).toBeNull();
+    expect(decodeTopLevel('List<u16>', hex('00010002'), ABI)).toEqual([1, 2]);
+    expect(decodeTopLevel('Status', hex('01'), ABI)).toBe('Paused');
+    expect(decodeTopLevel('Config', hex('0100000005'), ABI)).toEqual({ enabled: true, limit: 5 });
+    expect(decodeNested('Option<bytes>', hex('0100000003616263'), ABI)).toBe('abc');
+    expect(() => decodeNested('u32', hex('0000000500'), ABI)).toThrow('1 unexpected trailing byte');
+  });
+
+  it('decodes nested values from the start of a buffer', () => {
+    const bytes = hex('0000000500000001612e6c656e');
+    const { values, rest } = decodeNestedPrefix(['u32', 'bytes'], bytes, ABI);
+    expect(values).toEqual([5, 'a']);
+    expect(rest.toString()).toBe('.len');
+  });
+
+  it('decodes multi-value results', () => {
+    const outputs = [{ type: 'u32' }, { type: 'variadic<multi<Address,BigUint>>' }];
+    const results = [hex('07'), OWNER_BYTES, hex('64'), OWNER_BYTES, hex('')];
+    expect(decodeResults(outputs, results, ABI)).toEqual([7, [[OWNER, 100], [OWNER, 0]]]);
+    expect(decodeResults([{ type: 'optional<u8>' }], [], ABI)).toEqual([null]);
+  });
+
+  it('decodes event topics and data by name', () => {
+    const topics = [Buffer.from('deposit'), OWNER_BYTES];
+    expect(decodeEvent(topics, [hex('64')], ABI)).toEqual({
+      identifier: 'deposit',
+      fields: { from: OWNER, amount: 100 },
+    });
+    expect(() => decodeEvent([Buffer.from('withdraw')], [], ABI)).toThrow('Available: deposit');
+    expect(() => decodeEvent([Buffer.from('deposit')], [], ABI)).toThrow('1 indexed input(s)');
+  });
+});
@@ -0,0 +1,438 @@
+/**
+ * Binary ABI encoding of plain-JSON values, as the VM reads endpoint and
+ * constructor arguments: top-level values are minimal (integers without
+ * leading zeros, empty for zero/false/None), nested values are fixed-width
+ * or length-prefixed. Accepts the same values as the scenario generator,
+ * except that addresses must be klv1... or 32-byte 0x hex.
+ */
+
+import type { Abi } from '../abi/index.js';
+import {
+  booleanValue,
+  enumVariant,
+  expandArguments,
+  fieldValues,
+  integerValue,
+  parseAbiType,
+  type AbiArgs,
+} from './abi-values.js';
+import { decodeAddress, encodeAddress } from '../chain/bech32.js';
+
+const WIDTHS: Record<string, number> = {
+  u8: 1,
+  u16: 2,
+  u32: 4,
+  usize: 4,
+  u64: 8,
+  i8: 1,
+  i16: 2,
+  i32: 4,
+  isize: 4,
+  i64: 8,
+};
+
+/** Big-endian two's complement of `n` in exactly `width` bytes */
+function fixedBytes(n: bigint, width: number, type: string): Buffer {
+  const bits = BigInt(width * 8);
+  const signed = type.startsWith('i');
+  const min = signed ? -(1n << (bits - 1n)) : 0n;
+  const max = signed ? (1n << (bits - 1n)) - 1n : (1n << bits) - 1n;
+  if (n < min || n > max) throw new Error(`${type} cannot hold ${n}`);
+  const unsigned = n < 0n ? n + (1n << bits) : n;
+  return Buffer.from(unsigned.toString(16).padStart(width * 2, '0'), 'hex');
+}
+
+/** Shortest big-endian encoding of `n`; signed values keep their sign bit */
+function minimalBytes(n: bigint, signed: boolean): Buffer {
+  if (n === 0n) return Buffer.alloc(0);
+  if (!signed || n > 0n) {
+    let hex = n.toString(16);
+    if (hex.length % 2) hex = `0${hex}`;
+    // A positive signed value must not look negative
+    if (signed && parseInt(hex[0], 16) >= 8) hex = `00${hex}`;
+    return Buffer.from(hex, 'hex');
+  }
+  let width = 1;
+  while (n < -(1n << BigInt(width * 8 - 1))) width++;
+  return fixedBytes(n, width, 'i');
+}
+
+function lengthPrefixed(bytes: Buffer): Buffer {
+  return Buffer.concat([fixedBytes(BigInt(bytes.length), 4, 'u32'), bytes]);
+}
+
+function addressBytes(value: unknown): Buffer {
+  if (typeof value === 'string' && /^klv1/.test(value)) return decodeAddress(value);
+  if (typeof value === 'string' && /^0x[0-9a-fA-F]{64}$/.test(value)) {
+    return Buffer.from(value.slice(2), 'hex');
+  }
+  throw new Error(`Expected a klv1... address or 32-byte 0x hex, got ${JSON.stringify(value)}`);
+}
+
+/** Text as UTF-8, or `0x` hex as raw bytes */
+function rawBytes(value: unknown, type: string): Buffer {
+  if (typeof value !== 'string') {
+    throw new Error(`Expected a string for ${type}, got ${JSON.stringify(value)}`);
+  }
+  return /^0x([0-9a-fA-F]{2})*$/.test(value)
+    ? Buffer.from(value.slice(2), 'hex')
+    : Buffer.from(value, 'utf8');
+}
+
+function arrayBytes(type: string, name: string, item: string, value: unknown, abi: Abi): Buffer {
+  const length = parseInt(name.slice('array'.length), 10);
+  if (item === 'u8' && typeof value === 'string') {
+    const bytes = rawBytes(value, type);
+    if (bytes.length !== length) throw new Error(`Expected ${length} bytes for ${type}`);
+    return bytes;
+  }
+  if (!Array.isArray(value) || value.length !== length) {
+    throw new Error(`Expected ${length} values for ${type}`);
+  }
+  return Buffer.concat(value.map(v => encodeNested(item, v, abi)));
+}
+
+function customBytes(type: string, value: unknown, abi: Abi, topLevel: boolean): Buffer {
+  const def = abi.types?.[type];
+  if (!def) throw new Error(`Unknown ABI type ${type}`);
+  if (def.type === 'struct') {
+    const values = fieldValues(value, def.fields.map(f => f.name), type);
+    return Buffer.concat(values.map((v, i) => encodeNested(def.fields[i].type, v, abi)));
+  }
+  const { variant, values } = enumVariant(abi, type, value);
+  // Field-less enums encode like a u8 discriminant
+  if (topLevel && values.length === 0) return minimalBytes(BigInt(variant.discriminant), false);
+  return Buffer.concat([
+    Buffer.from([variant.discriminant]),
+    ...values.map((v, i) => encodeNested(variant.fields![i].type, v, abi)),
+  ]);
+}
+
+/** Nested encoding of `value`, as inside a struct, list or option */
+export function encodeNested(type: string, value: unknown, abi: Abi): Buffer {
+  const { name, args } = parseAbiType(type);
+  if (Object.hasOwn(WIDTHS, name)) {
+    return fixedBytes(BigInt(integerValue(value, name)), WIDTHS[name], name);
+  }
+  switch (name) {
+    case 'BigUint':
+    case 'BigInt':
+      return lengthPrefixed(encodeTopLevel(name, value, abi));
+    case 'bool':
+      return Buffer.from([booleanValue(value) ? 1 : 0]);
+    case 'Address':
+      return addressBytes(value);
+    case 'bytes':
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return lengthPrefixed(rawBytes(value, name));
+    case 'Option':
+      return value === null || value === undefined
+        ? Buffer.from([0])
+        : Buffer.concat([Buffer.from([1]), encodeNested(args[0], value, abi)]);
+    case 'List': {
+      if (!Array.isArray(value)) throw new Error(`Expected an array for ${type}`);
+      return Buffer.concat([
+        fixedBytes(BigInt(value.length), 4, 'u32'),
+        ...value.map(v => encodeNested(args[0], v, abi)),
+      ]);
+    }
+    case 'tuple':
+      return Buffer.concat(
+        fieldValues(value, args, type).map((v, i) => encodeNested(args[i], v, abi))
+      );
+  }
+  if (/^array\d+$/.test(name)) return arrayBytes(type, name, args[0], value, abi);
+  return customBytes(type, value, abi, false);
+}
+
+/** Top-level encoding of a single (non multi-value) argument or result */
+export function encodeTopLevel(type: string, value: unknown, abi: Abi): Buffer {
+  const { name, args } = parseAbiType(type);
+  if (Object.hasOwn(WIDTHS, name)) {
+    const n = BigInt(integerValue(value, name));
+    // Top-level integers are minimal, but must still fit the declared width
+    fixedBytes(n, WIDTHS[name], name);
+    return minimalBytes(n, name.startsWith('i'));
+  }
+  switch (name) {
+    case 'BigUint':
+    case 'BigInt':
+      return minimalBytes(BigInt(integerValue(value, name)), name === 'BigInt');
+    case 'bool':
+      return booleanValue(value) ? Buffer.from([1]) : Buffer.alloc(0);
+    case 'Address':
+      return addressBytes(value);
+    case 'bytes':
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return rawBytes(value, name);
+    case 'Option':
+      return value === null || value === undefined
+        ? Buffer.alloc(0)
+        : Buffer.concat([Buffer.from([1]), encodeNested(args[0], value, abi)]);
+    case 'List':
+      if (!Array.isArray(value)) throw new Error(`Expected an array for ${type}`);
+      return Buffer.concat(value.map(v => encodeNested(args[0], v, abi)));
+    case 'tuple':
+      return encodeNested(type, value, abi);
+  }
+  if (/^array\d+$/.test(name)) return arrayBytes(type, name, args[0], value, abi);
+  return customBytes(type, value, abi, true);
+}
+
+/** Binary arguments for a constructor or endpoint call, one buffer per VM argument */
+export function encodeArguments(
+  inputs: { name: string; type: string }[],
+  values: AbiArgs | undefined,
+  abi: Abi,
+  what: string
+): Buffer[] {
+  return expandArguments(inputs, values, what, (type, value) => encodeTopLevel(type, value, abi));
+}
+
+class NestedReader {
+  offset = 0;
+
+  constructor(private readonly bytes: Buffer) {}
+
+  take(length: number, type: string): Buffer {
+    if (this.offset + length > this.bytes.length) {
+      throw new Error(`Not enough bytes to decode ${type}`);
+    }
+    const slice = this.bytes.subarray(this.offset, this.offset + length);
+    this.offset += length;
+    return slice;
+  }
+
+  length(type: string): number {
+    return this.take(4, type).readUInt32BE(0);
+  }
+}
+
+/** Integers as numbers while exact, larger ones as decimal strings */
+function integerResult(bytes: Buffer, signed: boolean): number | string {
+  let n = bytes.length === 0 ? 0n : BigInt(`0x${bytes.toString('hex')}`);
+  if (signed && bytes.length > 0 && bytes[0] & 0x80) n -= 1n << BigInt(bytes.length * 8);
+  return n >= BigInt(Number.MIN_SAFE_INTEGER) && n <= BigInt(Number.MAX_SAFE_INTEGER)
+    ? Number(n)
+    : n.toString();
+}
+
+/** Printable UTF-8 as text, anything else as `0x` hex */
+function bytesResult(bytes: Buffer): string {
+  const text = bytes.toString('utf8');
+  const printable = /^[\t\n\x20-\x7e\u00a0-\uffff]*$/.test(text) && !text.includes('\ufffd');
+  return printable ? text : `0x${bytes.toString('hex')}`;
+}
+
+function customResult(type: string, reader: NestedReader, abi: Abi): unknown {
+  const def = abi.types?.[type];
+  if (!def) throw new Error(`Unknown ABI type ${type}`);
+  if (def.type === 'struct') {
+    return Object.fromEntries(def.fields.map(f => [f.name, readNested(f.type, reader, abi)]));
+  }
+  const discriminant = reader.take(1, type)[0];
+  return enumResult(type, discriminant, reader, abi);
+}
+
+function enumResult(type: string, discriminant: number, reader: NestedReader, abi: Abi): unknown {
+  const def = abi.types![type];
+  if (def.type !== 'enum') throw new Error(`${type} is not an enum`);
+  const variant = def.variants.find(v => v.discriminant === discriminant);
+  if (!variant) throw new Error(`${type} has no variant with discriminant ${discriminant}`);
+  if (!variant.fields || variant.fields.length === 0) return variant.name;
+  return {
+    [variant.name]: Object.fromEntries(
+      variant.fields.map(f => [f.name, readNested(f.type, reader, abi)])
+    ),
+  };
+}
+
+function readNested(type: string, reader: NestedReader, abi: Abi): unknown {
+  const { name, args } = parseAbiType(type);
+  if (Object.hasOwn(WIDTHS, name)) {
+    return integerResult(reader.take(WIDTHS[name], name), name.startsWith('i'));
+  }
+  switch (name) {
+    case 'BigUint':
+    case 'BigInt':
+      return integerResult(reader.take(reader.length(name), name), name === 'BigInt');
+    case 'bool':
+      return reader.take(1, name)[0] === 1;
+    case 'Address':
+      return encodeAddress(reader.take(32, name));
+    case 'bytes':
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return bytesResult(reader.take(reader.length(name), name));
+    case 'Option':
+      return reader.take(1, type)[0] === 0 ? null : readNested(args[0], reader, abi);
+    case 'List': {
+      const count = reader.length(type);
+      return Array.from({ length: count }, () => readNested(args[0], reader, abi));
+    }
+    case 'tuple':
+      return args.map(arg => readNested(arg, reader, abi));
+  }
+  if (/^array\d+$/.test(name)) {
+    const length = parseInt(name.slice('array'.length), 10);
+    if (args[0] === 'u8') return `0x${reader.take(length, type).toString('hex')}`;
+    return Array.from({ length }, () => readNested(args[0], reader, abi));
+  }
+  return customResult(type, reader, abi);
+}
+
+/** Decode a nested-encoded value that fills `bytes` completely */
+export function decodeNested(type: string, bytes: Buffer, abi: Abi): unknown {
+  const reader = new NestedReader(bytes);
+  const value = readNested(type, reader, abi);
+  if (reader.offset !== bytes.length) {
+    throw new Error(`${bytes.length - reader.offset} unexpected trailing byte(s) after ${type}`);
+  }
+  return value;
+}
+
+/**
+ * Decode nested-encoded values of `types` from the start of `bytes`, e.g. the
+ * arguments of a storage key, and return them with the bytes that follow
+ */
+export function decodeNestedPrefix(
+  types: string[],
+  bytes: Buffer,
+  abi: Abi
+): { values: unknown[]; rest: Buffer } {
+  const reader = new NestedReader(bytes);
+  const values = types.map(type => readNested(type, reader, abi));
+  return { values, rest: bytes.subarray(reader.offset) };
+}
+
+/** Decode a single top-level value, the inverse of `encodeTopLevel` */
+export function decodeTopLevel(type: string, bytes: Buffer, abi: Abi): unknown {
+  const { name, args } = parseAbiType(type);
+  if (Object.hasOwn(WIDTHS, name) || name === 'BigUint' || name === 'BigInt') {
+    return integerResult(bytes, name.startsWith('i') || name === 'BigInt');
+  }
+  switch (name) {
+    case 'bool':
+      return bytes.length > 0 && bytes[bytes.length - 1] === 1;
+    case 'Address':
+      return encodeAddress(bytes);
+    case 'bytes':
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return bytesResult(bytes);
+    case 'Option':
+      return bytes.length === 0 ? null : decodeNested(args[0], bytes.subarray(1), abi);
+    case 'List': {
+      const reader = new NestedReader(bytes);
+      const items: unknown[] = [];
+      while (reader.offset < bytes.length) items.push(readNested(args[0], reader, abi));
+      return items;
+    }
+  }
+  const def = abi.types?.[name];
+  // Field-less enums are a top-level integer discriminant
+  if (def?.type === 'enum' && def.variants.every(v => !v.fields || v.fields.length === 0)) {
+    const discriminant = Number(integerResult(bytes, false));
+    return enumResult(name, discriminant, new NestedReader(Buffer.alloc(0)), abi);
+  }
+  return decodeNested(type, bytes, abi);
+}
+
+/** Decode VM values with ABI types; returns the values and how many buffers they used */
+function decodeValues(
+  types: string[],
+  buffers: Buffer[],
+  abi: Abi
+): { values: unknown[]; used: number } {
+  let index = 0;
+  const next = (type: string) => {
+    if (index >= buffers.length) throw new Error(`Missing value for ${type}`);
+    return decodeTopLevel(type, buffers[index++], abi);
+  };
+  const multiValue = (type: string): unknown => {
+    const { name, args } = parseAbiType(type);
+    if (name === 'multi') return args.map(multiValue);
+    return next(type);
+  };
+
+  const values = types.map(type => {
+    const { name, args } = parseAbiType(type);
+    if (name === 'variadic') {
+      const items: unknown[] = [];
+      while (index < buffers.length) items.push(multiValue(args[0]));
+      return items;
+    }
+    if (name === 'optional') return index < buffers.length ? multiValue(args[0]) : null;
+    return multiValue(type);
+  });
+  return { values, used: index };
+}
+
+/**
+ * Decode endpoint results (one buffer per returned value) with the ABI
+ * outputs; `variadic<T>` takes the remaining values, `optional<T>` the next
+ * one when present and `multi<...>` one per type.
+ */
+export function decodeResults(outputs: { type: string }[], results: Buffer[], abi: Abi): unknown[] {
+  return decodeValues(outputs.map(output => output.type), results, abi).values;
+}
+
+/**
+ * Decode call arguments (one buffer per VM argument) into values keyed by
+ * input name, the inverse of `encodeArguments`; extra arguments are an error.
+ */
+export function decodeArguments(
+  inputs: { name: string; type: string }[],
+  args: Buffer[],
+  abi: Abi,
+  what: string
+): Record<string, unknown> {
+  const { values, used } = decodeValues(inputs.map(input => input.type), args, abi);
+  if (used < args.length) {
+    throw new Error(`${what} takes ${used} argument(s), got ${args.length}`);
+  }
+  return Object.fromEntries(inputs.map((input, i) => [input.name, values[i]]));
+}
+
+export interface DecodedEvent {
+  identifier: string;
+  fields: Record<string, unknown>;
+}
+
+/**
+ * Decode a contract event log: the first topic is the event identifier, the
+ * remaining topics are its indexed inputs in order and `data` holds the
+ * non-indexed inputs, one top-level value each.
+ */
+export function decodeEvent(topics: Buffer[], data: Buffer[], abi: Abi): DecodedEvent {
+  const identifier = topics.length > 0 ? topics[0].toString('utf8') : '';
+  const def = (abi.events || []).find(e => e.identifier === identifier);
+  if (!def) {
+    const available = (abi.events || []).map(e => e.identifier).join(', ') || 'none';
+    throw new Error(`Event "${identifier}" not found in the ABI. Available: ${available}`);
+  }
+  const indexed = def.inputs.filter(input => input.indexed);
+  const other = def.inputs.filter(input => !input.indexed);
+  if (topics.length - 1 !== indexed.length) {
+    throw new Error(
+      `Event ${identifier} has ${indexed.length} indexed input(s) but the log has ${topics.length - 1} topic(s) after the identifier`
+    );
+  }
+  // A single empty data entry stands for "no data" in logs of events without data inputs
+  const values = other.length === 0 && data.every(d => d.length === 0) ? [] : data;
+  if (values.length !== other.length) {
+    throw new Error(
+      `Event ${identifier} has ${other.length} data input(s) but the log has ${values.length} data value(s)`
+    );
+  }
+  const fields: Record<string, unknown> = {};
+  indexed.forEach((input, i) => {
+    fields[input.name] = decodeTopLevel(input.type, topics[i + 1], abi);
+  });
+  other.forEach((input, i) => {
+    fields[input.name] = decodeTopLevel(input.type, values[i], abi);
+  });
+  return { identifier, fields };
+}
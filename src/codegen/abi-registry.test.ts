@@ -0,0 +1,28 @@
+import { parseAbi } from '../abi/index.js';
+import { AbiRegistry } from './abi-registry.js';
+
+const tokenAbi = (name: string) =>
+  parseAbi({
+    name,
+    endpoints: [],
+    events: [
+      { identifier: 'transfer', inputs: [] },
+      { identifier: name === 'Token' ? 'mint' : 'burn', inputs: [] },
+    ],
+  });
+
+describe('AbiRegistry', () => {
+  it('prefers the ABI of the emitting contract and falls back to any declaring ABI', () => {
+    const registry = new AbiRegistry();
+    const token = tokenAbi('Token');
+    const other = tokenAbi('Other');
+    registry.register('klv1token', token);
+    registry.register('klv1other', other);
+
+    expect(registry.get('klv1token')).toBe(token);
+    expect(registry.forEvent('transfer', 'klv1other')).toBe(other);
+    expect(registry.forEvent('mint', 'klv1other')).toBe(token);
+    expect(registry.forEvent('mint')).toBe(token);
+    expect(registry.forEvent('approve', 'klv1token')).toBeUndefined();
+  });
+});
@@ -0,0 +1,30 @@
+/**
+ * ABIs of contracts the server has worked with during a session, keyed by
+ * contract address, so later transactions of those contracts can be decoded
+ * without passing the ABI again.
+ */
+
+import type { Abi } from '../abi/index.js';
+
+export class AbiRegistry {
+  private byAddress = new Map<string, Abi>();
+
+  register(address: string, abi: Abi): void {
+    this.byAddress.set(address, abi);
+  }
+
+  get(address: string): Abi | undefined {
+    return this.byAddress.get(address);
+  }
+
+  /**
+   * ABI to decode an event with: the emitting contract's when it is known and
+   * declares the event, otherwise the first registered ABI that declares it.
+   */
+  forEvent(identifier: string, address?: string): Abi | undefined {
+    const declares = (abi: Abi) => (abi.events || []).some(e => e.identifier === identifier);
+    const own = address ? this.byAddress.get(address) : undefined;
+    if (own && declares(own)) return own;
+    return [...this.byAddress.values()].find(declares);
+  }
+}
@@ -0,0 +1,139 @@
+/**
+ * Plain-JSON argument values checked against ABI types. Shared by the
+ * scenario generator and the binary codec so both accept the same inputs:
+ * integers as numbers or strings, structs as objects or arrays, enum values
+ * as the variant name or `{ Variant: fields }`, Option as null or the value.
+ */
+
+import type { Abi } from '../abi/index.js';
+import { splitTopLevel } from './rust-source.js';
+
+/** A plain-JSON value that does not fit its ABI type */
+export class AbiValueError extends Error {
+  constructor(
+    message: string,
+    public type: string
+  ) {
+    super(message);
+    this.name = 'AbiValueError';
+  }
+}
+
+/** Argument values in ABI input order, or keyed by input name */
+export type AbiArgs = unknown[] | Record<string, unknown>;
+
+/** Split `Name<A, B>` into its name and type arguments */
+export function parseAbiType(type: string): { name: string; args: string[] } {
+  const generic = /^(\w+)<(.*)>$/.exec(type.trim());
+  return generic
+    ? { name: generic[1], args: splitTopLevel(generic[2]).map(a => a.trim()) }
+    : { name: type.trim(), args: [] };
+}
+
+/** Integer value of `type` as a decimal string; `_` and `,` separators are allowed */
+export function integerValue(value: unknown, type: string): string {
+  const text = typeof value === 'string' ? value.replace(/[_,]/g, '') : String(value);
+  if (!/^-?\d+$/.test(text)) {
+    throw new Error(`Expected an integer for ${type}, got ${JSON.stringify(value)}`);
+  }
+  if (text.startsWith('-') && !/^(i\d+|isize|BigInt)$/.test(type)) {
+    throw new Error(`${type} cannot hold ${text}`);
+  }
+  return BigInt(text).toString();
+}
+
+/** Boolean value: `true` or `false`, as JSON booleans or the exact strings */
+export function booleanValue(value: unknown, type = 'bool'): boolean {
+  if (value === true || value === 'true') return true;
+  if (value === false || value === 'false') return false;
+  throw new AbiValueError(`Expected true or false for ${type}, got ${JSON.stringify(value)}`, type);
+}
+
+/** Values of named fields given as an array in order or as an object */
+export function fieldValues(values: unknown, names: string[], what: string): unknown[] {
+  if (Array.isArray(values)) {
+    if (values.length !== names.length) {
+      throw new Error(`${what} takes ${names.length} value(s), got ${values.length}`);
+    }
+    return values;
+  }
+  if (values && typeof values === 'object') {
+    const record = values as Record<string, unknown>;
+    const missing = names.filter(name => !Object.hasOwn(record, name));
+    if (missing.length > 0) throw new Error(`${what} is missing ${missing.join(', ')}`);
+    return names.map(name => record[name]);
+  }
+  throw new Error(`Expected an array or object for ${what}, got ${JSON.stringify(values)}`);
+}
+
+/** The enum variant `value` selects and its field values */
+export function enumVariant(abi: Abi, type: string, value: unknown) {
+  const def = abi.types![type];
+  if (def.type !== 'enum') throw new Error(`${type} is not an enum`);
+  // Unit variants are given by name, variants with fields as { Variant: fields }
+  let name: string | undefined;
+  let fields: unknown;
+  if (typeof value === 'string') {
+    name = value;
+  } else if (value && typeof value === 'object' && Object.keys(value).length === 1) {
+    [name, fields] = Object.entries(value)[0];
+  }
+  const variant = def.variants.find(v => v.name === name);
+  if (!variant) {
+    const available = def.variants.map(v => v.name).join(', ');
+    throw new Error(`Unknown ${type} variant ${JSON.stringify(value)}. Available: ${available}`);
+  }
+  const names = (variant.fields || []).map(f => f.name);
+  const values = names.length > 0 ? fieldValues(fields, names, `${type}::${variant.name}`) : [];
+  return { variant, values };
+}
+
+/**
+ * Encode a list of typed values with `encode` for each top-level value;
+ * `optional<T>` may be omitted at the end, `variadic<T>` takes an array and
+ * `multi<...>` a tuple.
+ */
+export function expandArguments<T>(
+  inputs: { name: string; type: string }[],
+  values: AbiArgs | undefined,
+  what: string,
+  encode: (type: string, value: unknown) => T
+): T[] {
+  const names = inputs.map(i => i.name);
+  const given = values ?? [];
+  const list = Array.isArray(given)
+    ? given
+    : names.map(name => (given as Record<string, unknown>)[name]);
+  if (Array.isArray(given) && given.length > inputs.length) {
+    throw new Error(`${what} takes ${inputs.length} argument(s), got ${given.length}`);
+  }
+  if (!Array.isArray(given)) {
+    const unknown = Object.keys(given).filter(key => !names.includes(key));
+    if (unknown.length > 0) {
+      const expected = names.join(', ');
+      throw new Error(`${what} has no argument(s) ${unknown.join(', ')}. Expected: ${expected}`);
+    }
+  }
+
+  const multiValue = (type: string, value: unknown): T[] => {
+    const { name, args } = parseAbiType(type);
+    if (name === 'variadic') {
+      if (!Array.isArray(value)) throw new Error(`Expected an array for ${type}`);
+      return value.flatMap(v => multiValue(args[0], v));
+    }
+    if (name === 'multi') {
+      return fieldValues(value, args, type).flatMap((v, i) => multiValue(args[i], v));
+    }
+    return [encode(type, value)];
+  };
+
+  return inputs.flatMap((input, i) => {
+    const { name, args } = parseAbiType(input.type);
+    const value = list[i];
+    if (name === 'optional') {
+      return value === undefined || value === null ? [] : multiValue(args[0], value);
+    }
+    if (value === undefined) throw new Error(`${what} is missing argument ${input.name}`);
+    return multiValue(input.type, value);
+  });
+}
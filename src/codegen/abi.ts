@@ -0,0 +1,144 @@
+/**
+ * Mapping between ABI type names (see `src/abi/`) and the Rust types used in
+ * contract code.
+ */
+
+import type { Abi, AbiOutput } from '../abi/index.js';
+import { splitTopLevel } from './rust-source.js';
+
+/** ABI names of managed types; all of them take the API as a type parameter */
+const MANAGED_TYPES: Record<string, string> = {
+  Address: 'ManagedAddress',
+  bytes: 'ManagedBuffer',
+  BigUint: 'BigUint',
+  BigInt: 'BigInt',
+  TokenIdentifier: 'TokenIdentifier',
+  utf8string: 'ManagedBuffer',
+};
+
+/**
+ * Rust type for an ABI type name, e.g. `List<Address>` -> `ManagedVec<ManagedAddress>`.
+ * Custom struct/enum names listed in `managedTypes` get `<Self::Api>`. Pass `api`
+ * (e.g. `M`) to spell the API out on every managed type, as struct fields require.
+ */
+export function abiTypeToRust(type: string, managedTypes: string[] = [], api?: string): string {
+  const trimmed = type.trim();
+  if (Object.hasOwn(MANAGED_TYPES, trimmed)) {
+    return api ? `${MANAGED_TYPES[trimmed]}<${api}>` : MANAGED_TYPES[trimmed];
+  }
+
+  const generic = /^(\w+)<(.*)>$/.exec(trimmed);
+  if (generic) {
+    const [, name, inner] = generic;
+    const args = splitTopLevel(inner).map(arg => abiTypeToRust(arg, managedTypes, api));
+    const array = /^array(\d+)$/.exec(name);
+    if (array && args[0] === 'u8') {
+      return api ? `ManagedByteArray<${api}, ${array[1]}>` : `ManagedByteArray<${array[1]}>`;
+    }
+    switch (name) {
+      case 'List':
+        return api ? `ManagedVec<${api}, ${args[0]}>` : `ManagedVec<${args[0]}>`;
+      case 'Option':
+        return `Option<${args[0]}>`;
+      case 'optional':
+        return `OptionalValue<${args[0]}>`;
+      case 'variadic':
+        return `MultiValueEncoded<${args[0]}>`;
+      case 'multi':
+        return `MultiValue${args.length}<${args.join(', ')}>`;
+      case 'tuple':
+        return `(${args.join(', ')})`;
+      default:
+        return `${name}<${args.join(', ')}>`;
+    }
+  }
+  if (managedTypes.includes(trimmed)) return `${trimmed}<${api || 'Self::Api'}>`;
+  return trimmed;
+}
+
+const RUST_TO_ABI: Record<string, string> = {
+  ManagedAddress: 'Address',
+  ManagedBuffer: 'bytes',
+  BigUint: 'BigUint',
+  BigInt: 'BigInt',
+  TokenIdentifier: 'TokenIdentifier',
+  String: 'utf8string',
+};
+
+/**
+ * ABI type name for a Rust type as written in contract code, e.g.
+ * `ManagedVec<Self::Api, ManagedAddress>` -> `List<Address>`. The API
+ * parameter of managed and custom types is dropped.
+ */
+export function rustTypeToAbi(type: string): string {
+  const trimmed = type.trim().replace(/^&\s*(mut\s+)?/, '');
+  const tuple = /^\((.*)\)$/.exec(trimmed);
+  if (tuple) return `tuple<${splitTopLevel(tuple[1]).map(rustTypeToAbi).join(',')}>`;
+
+  const generic = /^(\w+)\s*<(.*)>$/.exec(trimmed);
+  const name = generic ? generic[1] : trimmed;
+  const args = generic
+    ? splitTopLevel(generic[2]).filter(arg => !/^(Self::Api|M|A)$/.test(arg.trim()))
+    : [];
+  if (Object.hasOwn(RUST_TO_ABI, name)) return RUST_TO_ABI[name];
+  switch (name) {
+    case 'ManagedVec':
+    case 'Vec':
+      return `List<${rustTypeToAbi(args[0])}>`;
+    case 'ManagedByteArray':
+      return `array${args[0].trim()}<u8>`;
+    case 'Option':
+      return `Option<${rustTypeToAbi(args[0])}>`;
+    case 'OptionalValue':
+      return `optional<${rustTypeToAbi(args[0])}>`;
+    case 'MultiValueEncoded':
+      return `variadic<${rustTypeToAbi(args[0])}>`;
+  }
+  if (/^MultiValue\d+$/.test(name)) return `multi<${args.map(rustTypeToAbi).join(',')}>`;
+  if (args.length === 0) return name;
+  return `${name}<${args.map(rustTypeToAbi).join(',')}>`;
+}
+
+/** True when the ABI type mentions a managed type, directly or through a custom type */
+export function isManagedAbiType(type: string, managedTypes: string[] = []): boolean {
+  const names = type.match(/\w+/g) || [];
+  return names.some(
+    n =>
+      Object.hasOwn(MANAGED_TYPES, n) ||
+      n === 'List' ||
+      /^array\d+$/.test(n) ||
+      managedTypes.includes(n)
+  );
+}
+
+/** Custom types whose Rust definition needs a `<M: ManagedTypeApi>` parameter */
+export function managedCustomTypes(abi: Abi): string[] {
+  const types = abi.types || {};
+  const managed: string[] = [];
+  let changed = true;
+  while (changed) {
+    changed = false;
+    for (const [name, def] of Object.entries(types)) {
+      if (managed.includes(name)) continue;
+      const fieldTypes =
+        def.type === 'struct'
+          ? def.fields.map(f => f.type)
+          : def.variants.flatMap(v => (v.fields || []).map(f => f.type));
+      if (fieldTypes.some(t => isManagedAbiType(t, managed))) {
+        managed.push(name);
+        changed = true;
+      }
+    }
+  }
+  return managed;
+}
+
+/** Rust return type for an endpoint's outputs, or undefined when it returns nothing */
+export function abiReturnType(
+  outputs: AbiOutput[],
+  managedTypes: string[] = []
+): string | undefined {
+  if (outputs.length === 0) return undefined;
+  const types = outputs.map(o => abiTypeToRust(o.type, managedTypes));
+  return types.length === 1 ? types[0] : `MultiValue${types.length}<${types.join(', ')}>`;
+}
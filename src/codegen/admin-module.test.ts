@@ -0,0 +1,76 @@
+import { addAdminModule } from './admin-module.js';
+import { findTraitFunction } from './rust-source.js';
+import { generateContractProject } from '../scaffold/index.js';
+
+const TOKEN = generateContractProject('basic_token', 'token').files['src/lib.rs'];
+
+const COUNTER = `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait Counter {
+    #[init]
+    fn init(&self) {}
+
+    #[endpoint]
+    fn increment(&self) {
+        self.count().update(|c| *c += 1u32);
+    }
+
+    #[storage_mapper("count")]
+    fn count(&self) -> SingleValueMapper<u64>;
+}
+`;
+
+describe('addAdminModule', () => {
+  it('replaces the owner mapper and require_owner with the module', () => {
+    const { files, changes } = addAdminModule(TOKEN, { maxFeeBps: 500 });
+    const lib = files['src/lib.rs'];
+
+    expect(findTraitFunction(lib, 'owner')).toBeUndefined();
+    expect(findTraitFunction(lib, 'require_owner')).toBeUndefined();
+    expect(lib).not.toContain('// Helper functions');
+    expect(lib).toContain('use klever_sc::imports::*;\n\npub mod admin;\n\n#[klever_sc::contract]');
+    expect(lib).toContain('pub trait Token: admin::AdminModule {');
+    expect(lib).toContain('self.owner().set(&caller);');
+
+    const admin = files['src/admin.rs'];
+    expect(admin).toContain('#[storage_mapper("owner")]\n    fn owner(&self)');
+    expect(admin).toContain('#[endpoint(acceptOwnership)]');
+    expect(admin).toContain('pub const MAX_FEE_BPS: u32 = 500;');
+    expect(admin).toContain('fn require_not_paused(&self)');
+    expect(changes).toContain('Added require_not_paused() to transfer, burn');
+  });
+
+  it('guards public endpoints but not owner-only ones', () => {
+    const lib = addAdminModule(TOKEN).files['src/lib.rs'];
+    const body = (name: string) => {
+      const fn = findTraitFunction(lib, name)!;
+      return lib.slice(fn.bodyStart, fn.end);
+    };
+
+    expect(body('transfer')).toMatch(/^\n {8}self\.require_not_paused\(\);\n/);
+    expect(body('mint')).not.toContain('require_not_paused');
+  });
+
+  it('records the owner in init and honours disabled features', () => {
+    const { files, notes } = addAdminModule(COUNTER, { pause: false, fee: false });
+
+    expect(files['src/lib.rs']).toContain(
+      'fn init(&self) {\n        self.owner().set(&self.blockchain().get_caller());\n    }'
+    );
+    expect(files['src/lib.rs']).not.toContain('require_not_paused');
+    expect(files['src/admin.rs']).not.toContain('fn pause');
+    expect(files['src/admin.rs']).not.toContain('fee_bps');
+    expect(notes.some(n => n.includes('#[upgrade]'))).toBe(true);
+  });
+
+  it('rejects contracts that already declare module functions', () => {
+    const source = COUNTER.replace(
+      '    #[storage_mapper("count")]',
+      '    #[endpoint]\n    fn pause(&self) {}\n\n    #[storage_mapper("count")]'
+    );
+    expect(() => addAdminModule(source)).toThrow('Contract already declares pause');
+  });
+});
@@ -0,0 +1,260 @@
+/**
+ * Inject a standard admin module into an existing contract: two-step
+ * ownership transfer, a pause switch and a configurable fee in basis points.
+ */
+
+import {
+  addContractSupertraits,
+  addModDeclarations,
+  appendStatement,
+  findFunctionWithAttribute,
+  findTraitFunction,
+  listStorageMappers,
+  listTraitFunctions,
+  prependStatement,
+  removeTraitFunctions,
+} from './rust-source.js';
+import type { FileMap } from '../scaffold/types.js';
+
+export interface AdminModuleOptions {
+  /** Include pause/unpause and require_not_paused() (default true) */
+  pause?: boolean;
+  /** Include fee_bps storage, setFee and compute_fee() (default true) */
+  fee?: boolean;
+  /** Upper bound accepted by setFee, in basis points (default 1000 = 10%) */
+  maxFeeBps?: number;
+  /** Add require_not_paused() to public endpoints that are not owner-only (default true) */
+  guardEndpoints?: boolean;
+}
+
+export interface AdminModuleResult {
+  files: FileMap;
+  changes: string[];
+  notes: string[];
+}
+
+const FEE_DENOMINATOR = 10_000;
+
+function ownershipSection(ownerKey: string): string {
+  return `    // Ownership
+
+    /// Propose a new owner; the transfer completes once they call acceptOwnership.
+    #[endpoint(transferOwnership)]
+    fn transfer_ownership(&self, new_owner: ManagedAddress) {
+        self.require_owner();
+        require!(!new_owner.is_zero(), "Invalid new owner");
+        self.pending_owner().set(&new_owner);
+        self.ownership_transfer_started_event(&self.owner().get(), &new_owner);
+    }
+
+    #[endpoint(acceptOwnership)]
+    fn accept_ownership(&self) {
+        let caller = self.blockchain().get_caller();
+        let pending = self.pending_owner();
+        require!(!pending.is_empty() && caller == pending.get(), "Caller is not the pending owner");
+
+        let previous = self.owner().get();
+        self.owner().set(&caller);
+        self.pending_owner().clear();
+        self.ownership_transferred_event(&previous, &caller);
+    }
+
+    #[endpoint(cancelOwnershipTransfer)]
+    fn cancel_ownership_transfer(&self) {
+        self.require_owner();
+        self.pending_owner().clear();
+    }
+
+    fn require_owner(&self) {
+        let owner = self.owner();
+        require!(!owner.is_empty(), "Owner not set");
+        require!(
+            self.blockchain().get_caller() == owner.get(),
+            "Only owner can call this function"
+        );
+    }
+
+    #[view(getOwner)]
+    #[storage_mapper("${ownerKey}")]
+    fn owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getPendingOwner)]
+    #[storage_mapper("pendingOwner")]
+    fn pending_owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[event("ownershipTransferStarted")]
+    fn ownership_transfer_started_event(
+        &self,
+        #[indexed] previous_owner: &ManagedAddress,
+        #[indexed] new_owner: &ManagedAddress,
+    );
+
+    #[event("ownershipTransferred")]
+    fn ownership_transferred_event(
+        &self,
+        #[indexed] previous_owner: &ManagedAddress,
+        #[indexed] new_owner: &ManagedAddress,
+    );`;
+}
+
+const PAUSE_SECTION = `    // Pause
+
+    #[endpoint]
+    fn pause(&self) {
+        self.require_owner();
+        require!(!self.paused().get(), "Contract is already paused");
+        self.paused().set(true);
+        self.paused_event(&self.blockchain().get_caller());
+    }
+
+    #[endpoint]
+    fn unpause(&self) {
+        self.require_owner();
+        require!(self.paused().get(), "Contract is not paused");
+        self.paused().set(false);
+        self.unpaused_event(&self.blockchain().get_caller());
+    }
+
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), "Contract is paused");
+    }
+
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[event("paused")]
+    fn paused_event(&self, #[indexed] by: &ManagedAddress);
+
+    #[event("unpaused")]
+    fn unpaused_event(&self, #[indexed] by: &ManagedAddress);`;
+
+const FEE_SECTION = `    // Fees
+
+    #[endpoint(setFee)]
+    fn set_fee(&self, fee_bps: u32) {
+        self.require_owner();
+        require!(fee_bps <= MAX_FEE_BPS, "Fee above maximum");
+        self.fee_bps().set(fee_bps);
+        self.fee_updated_event(fee_bps);
+    }
+
+    /// Fee owed on \`amount\` at the configured rate, rounded down
+    fn compute_fee(&self, amount: &BigUint) -> BigUint {
+        amount * self.fee_bps().get() / FEE_DENOMINATOR
+    }
+
+    #[view(getFeeBps)]
+    #[storage_mapper("feeBps")]
+    fn fee_bps(&self) -> SingleValueMapper<u32>;
+
+    #[event("feeUpdated")]
+    fn fee_updated_event(&self, #[indexed] fee_bps: u32);`;
+
+/**
+ * Move ownership into an AdminModule in src/admin.rs and wire it into `source` (src/lib.rs)
+ */
+export function addAdminModule(
+  source: string,
+  options: AdminModuleOptions = {}
+): AdminModuleResult {
+  const pause = options.pause !== false;
+  const fee = options.fee !== false;
+  const maxFeeBps = options.maxFeeBps ?? 1000;
+  if (!Number.isInteger(maxFeeBps) || maxFeeBps < 0 || maxFeeBps > FEE_DENOMINATOR) {
+    throw new Error(`maxFeeBps must be an integer between 0 and ${FEE_DENOMINATOR}.`);
+  }
+  if (/^\s*(pub\s+)?mod\s+admin\s*;/m.test(source)) {
+    throw new Error('Contract already declares an admin module.');
+  }
+
+  const changes: string[] = [];
+  const notes: string[] = [];
+
+  // Keep the existing owner storage key so deployed state carries over
+  const existingOwner = listStorageMappers(source).find(m => m.functionName === 'owner');
+  if (existingOwner && existingOwner.returnType !== 'SingleValueMapper<ManagedAddress>') {
+    throw new Error(
+      `owner() is a ${existingOwner.returnType}; expected SingleValueMapper<ManagedAddress>.`
+    );
+  }
+  const ownerKey = existingOwner ? existingOwner.storageKey : 'owner';
+
+  const sections = [ownershipSection(ownerKey)];
+  if (pause) sections.push(PAUSE_SECTION);
+  if (fee) sections.push(FEE_SECTION);
+  const constants = fee
+    ? `/// Basis points denominator: a fee_bps of 10_000 is 100%
+pub const FEE_DENOMINATOR: u32 = ${FEE_DENOMINATOR};
+/// Highest fee setFee accepts, in basis points
+pub const MAX_FEE_BPS: u32 = ${maxFeeBps};
+
+`
+    : '';
+  const module = `use klever_sc::imports::*;
+
+${constants}/// Owner with two-step transfer${pause ? ', pause switch' : ''}${
+    fee ? ' and fee configuration' : ''
+  }
+#[klever_sc::module]
+pub trait AdminModule {
+${sections.join('\n\n')}
+}
+`;
+
+  // The contract's owner() mapper and plain require_owner() helper are replaced by the module
+  const replaced = existingOwner ? ['owner'] : [];
+  const guard = findTraitFunction(source, 'require_owner');
+  if (guard && guard.attributes.length === 0) replaced.push('require_owner');
+  const conflicts = [...module.matchAll(/\bfn (\w+)/g)]
+    .map(match => match[1])
+    .filter(name => !replaced.includes(name) && findTraitFunction(source, name));
+  if (conflicts.length > 0) {
+    throw new Error(`Contract already declares ${conflicts.join(', ')}; rename them first.`);
+  }
+
+  let lib = removeTraitFunctions(source, replaced);
+  if (existingOwner) changes.push(`Moved owner() into AdminModule (storage key "${ownerKey}")`);
+  if (replaced.includes('require_owner')) {
+    changes.push('Replaced require_owner() with the AdminModule version');
+  }
+
+  const init = findFunctionWithAttribute(lib, 'init');
+  if (init && !/self\.owner\(\)\s*\.set\(/.test(lib.slice(init.bodyStart, init.end))) {
+    lib = appendStatement(lib, init, 'self.owner().set(&self.blockchain().get_caller());');
+    changes.push('init() records the deployer as owner');
+  } else if (!init) {
+    notes.push('No #[init] found; set owner() on deploy or every owner-only call will fail.');
+  }
+
+  if (pause && options.guardEndpoints !== false) {
+    const guarded: string[] = [];
+    // Work backwards so earlier function offsets stay valid
+    for (const fn of listTraitFunctions(lib).reverse()) {
+      if (!fn.attributes.some(a => /^#\[endpoint[(\]]/.test(a))) continue;
+      if (fn.attributes.some(a => /^#\[only_owner\]/.test(a))) continue;
+      if (/self\.require_owner\(\)/.test(lib.slice(fn.bodyStart, fn.end))) continue;
+      lib = prependStatement(lib, fn, 'self.require_not_paused();');
+      guarded.unshift(fn.name);
+    }
+    if (guarded.length > 0) changes.push(`Added require_not_paused() to ${guarded.join(', ')}`);
+  }
+
+  lib = addContractSupertraits(lib, ['admin::AdminModule']);
+  lib = addModDeclarations(lib, ['admin']);
+  changes.push('Added src/admin.rs with AdminModule as a supertrait of the contract');
+
+  if (/#\[only_owner\]/.test(lib)) {
+    notes.push(
+      '#[only_owner] checks the deploying account, not owner(); switch those endpoints to self.require_owner() so transfers apply to them.'
+    );
+  }
+  if (fee) notes.push('Call self.compute_fee(&amount) where the fee should be charged.');
+  if (!existingOwner) {
+    notes.push(
+      'Existing deployments have no owner() value; set it in #[upgrade] before relying on it.'
+    );
+  }
+
+  return { files: { 'src/admin.rs': module, 'src/lib.rs': lib }, changes, notes };
+}
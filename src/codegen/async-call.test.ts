@@ -0,0 +1,71 @@
+import { parseAbi } from '../abi/index.js';
+import { abiTypeToRust } from './abi.js';
+import { generateAsyncCall } from './async-call.js';
+
+const ABI = parseAbi(
+  JSON.stringify({
+    name: 'Marketplace',
+    endpoints: [
+      {
+        name: 'buyItem',
+        mutability: 'mutable',
+        payableInTokens: ['KLV'],
+        inputs: [{ name: 'item_id', type: 'u64' }],
+        outputs: [{ type: 'BigUint' }],
+      },
+      {
+        name: 'listItems',
+        mutability: 'mutable',
+        payableInTokens: ['*'],
+        inputs: [{ name: 'prices', type: 'List<BigUint>' }],
+        outputs: [],
+      },
+      { name: 'getItem', mutability: 'readonly', inputs: [], outputs: [{ type: 'bytes' }] },
+    ],
+  })
+);
+
+describe('abiTypeToRust', () => {
+  it.each([
+    ['Address', 'ManagedAddress'],
+    ['List<Address>', 'ManagedVec<ManagedAddress>'],
+    ['optional<multi<Address,BigUint>>', 'OptionalValue<MultiValue2<ManagedAddress, BigUint>>'],
+    ['array32<u8>', 'ManagedByteArray<32>'],
+  ])('maps %s', (abiType, rust) => {
+    expect(abiTypeToRust(abiType)).toBe(rust);
+  });
+});
+
+describe('generateAsyncCall', () => {
+  it('forwards KLV and gas and refunds in the callback', () => {
+    const call = generateAsyncCall(ABI, 'buyItem');
+
+    expect(call.proxyFile.path).toBe('src/marketplace_proxy.rs');
+    expect(call.proxyFile.content).toContain(
+      '    #[payable("KLV")]\n    #[endpoint(buyItem)]\n    fn buy_item(&self, item_id: u64) -> BigUint;'
+    );
+    expect(call.callerEndpoint).toContain('#[payable("KLV")]\n    #[endpoint(callBuyItem)]');
+    expect(call.callerEndpoint).toContain('.with_klv_transfer(payment.clone())');
+    expect(call.callerEndpoint).toContain('.with_gas_limit(gas_left - CALLBACK_GAS)');
+    expect(call.callerEndpoint).toContain(
+      '.with_callback(self.callbacks().buy_item_callback(caller, payment))'
+    );
+    expect(call.callback).toContain('#[call_result] result: ManagedAsyncCallResult<BigUint>,');
+    expect(call.callback).toContain('self.send().direct_klv(&caller, &payment);');
+    expect(call.constant).toContain('const CALLBACK_GAS: u64 = 10000000;');
+  });
+
+  it('forwards a single KDA payment', () => {
+    const call = generateAsyncCall(ABI, 'listItems', { callerName: 'listOnMarket' });
+
+    expect(call.callerEndpoint).toContain('fn list_on_market(&self, sc_address: ManagedAddress');
+    expect(call.callerEndpoint).toContain('.with_kda_transfer(payment.clone())');
+    expect(call.callback).toContain('ManagedAsyncCallResult<()>');
+    expect(call.callback).toContain('self.send().direct_kda(');
+  });
+
+  it('rejects views and unknown endpoints', () => {
+    expect(() => generateAsyncCall(ABI, 'getItem')).toThrow('is a view');
+    expect(() => generateAsyncCall(ABI, 'missing')).toThrow('Available: buyItem');
+  });
+});
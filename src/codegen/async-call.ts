@@ -0,0 +1,180 @@
+/**
+ * Scaffold a cross-contract async call: the proxy declaration for the target
+ * endpoint, the calling endpoint with payment and gas forwarding, and the
+ * #[callback] that handles both ManagedAsyncCallResult branches.
+ */
+
+import { findAbiEndpoint, type Abi, type AbiEndpoint } from '../abi/index.js';
+import { abiReturnType, abiTypeToRust, managedCustomTypes } from './abi.js';
+import { generateProxy } from './proxy.js';
+import { camelToSnake, snakeToCamel } from './rust-source.js';
+
+export interface AsyncCallOptions {
+  /** Gas kept back for the callback (default 10_000_000) */
+  callbackGas?: number;
+  /** Name of the generated calling endpoint (default call<Endpoint>) */
+  callerName?: string;
+}
+
+export interface GeneratedAsyncCall {
+  /** Proxy module file, relative to the project root */
+  proxyFile: { path: string; content: string };
+  /** `pub mod ...;` line for src/lib.rs */
+  modDeclaration: string;
+  /** CALLBACK_GAS constant to place above the contract trait */
+  constant: string;
+  /** #[proxy] accessor to add to the contract trait */
+  proxyAccessor: string;
+  callerEndpoint: string;
+  callback: string;
+  /** Everything to paste into the contract trait, in order */
+  traitItems: string;
+  notes: string[];
+}
+
+type PaymentKind = 'none' | 'klv' | 'kda';
+
+function paymentKind(endpoint: AbiEndpoint): PaymentKind {
+  const tokens = endpoint.payableInTokens || [];
+  if (tokens.length === 0) return 'none';
+  return tokens.length === 1 && tokens[0] === 'KLV' ? 'klv' : 'kda';
+}
+
+/**
+ * Generate the async-call scaffolding for `endpointName` of the contract described by `abi`
+ */
+export function generateAsyncCall(
+  abi: Abi,
+  endpointName: string,
+  options: AsyncCallOptions = {}
+): GeneratedAsyncCall {
+  const endpoint = findAbiEndpoint(abi, endpointName);
+  if (endpoint.mutability === 'readonly') {
+    throw new Error(
+      `"${endpointName}" is a view; query it with execute_on_dest_context instead of an async call.`
+    );
+  }
+  const callbackGas = options.callbackGas ?? 10_000_000;
+  if (!Number.isInteger(callbackGas) || callbackGas < 0) {
+    throw new Error('callbackGas must be a non-negative integer.');
+  }
+
+  const proxy = generateProxy(abi, { endpoints: [endpoint.name], includeInit: false });
+  const managed = managedCustomTypes(abi);
+  const accessor = `${camelToSnake(abi.name)}_contract`;
+  const targetFn = camelToSnake(endpoint.name);
+  const callerFn = options.callerName ? camelToSnake(options.callerName) : `call_${targetFn}`;
+  const callbackFn = `${targetFn}_callback`;
+  const resultType = abiReturnType(endpoint.outputs, managed) || '()';
+  const payment = paymentKind(endpoint);
+  const notes: string[] = [];
+
+  const args = endpoint.inputs.map(i => ({
+    name: camelToSnake(i.name),
+    type: abiTypeToRust(i.type, managed),
+  }));
+  const params = ['&self', 'sc_address: ManagedAddress', ...args.map(a => `${a.name}: ${a.type}`)];
+
+  // Calling endpoint
+  const attributes: string[] = [];
+  const body = ['let caller = self.blockchain().get_caller();'];
+  const chain = [`.${targetFn}(${args.map(a => a.name).join(', ')})`];
+  const callbackArgs = ['caller'];
+  const callbackParams = ['caller: ManagedAddress'];
+  let refund = '';
+
+  if (payment === 'klv') {
+    attributes.push('#[payable("KLV")]');
+    body.push('let payment = self.call_value().klv_value().clone_value();');
+    chain.push('.with_klv_transfer(payment.clone())');
+    callbackArgs.push('payment');
+    callbackParams.push('payment: BigUint');
+    refund = `                if payment > 0 {
+                    self.send().direct_klv(&caller, &payment);
+                }
+`;
+  } else if (payment === 'kda') {
+    attributes.push('#[payable("*")]');
+    body.push('let payment = self.call_value().single_kda();');
+    const accepted = (endpoint.payableInTokens || []).filter(t => t !== '*');
+    if (accepted.length > 0) {
+      notes.push(`Target accepts only ${accepted.join(', ')}; add a token check before calling.`);
+    }
+    chain.push('.with_kda_transfer(payment.clone())');
+    callbackArgs.push('payment');
+    callbackParams.push('payment: KdaTokenPayment<Self::Api>');
+    refund = `                self.send().direct_kda(
+                    &caller,
+                    &payment.token_identifier,
+                    payment.token_nonce,
+                    &payment.amount,
+                );
+`;
+  }
+  attributes.push(`#[endpoint(${snakeToCamel(callerFn)})]`);
+
+  body.push(
+    'let gas_left = self.blockchain().get_gas_left();',
+    'require!(gas_left > CALLBACK_GAS, "Not enough gas for the async call and its callback");',
+    '',
+    `self.${accessor}(sc_address)`
+  );
+  chain.push(
+    '.with_gas_limit(gas_left - CALLBACK_GAS)',
+    '.async_call()',
+    `.with_callback(self.callbacks().${callbackFn}(${callbackArgs.join(', ')}))`,
+    '.call_and_exit();'
+  );
+
+  const callerEndpoint = [
+    ...attributes.map(a => `    ${a}`),
+    `    fn ${callerFn}(${params.join(', ')}) {`,
+    ...body.map(line => (line ? `        ${line}` : '')),
+    ...chain.map(line => `            ${line}`),
+    '    }',
+  ].join('\n');
+
+  const okBinding = resultType === '()' ? '_' : 'value';
+  const callback = `    #[callback]
+    fn ${callbackFn}(
+        &self,
+${callbackParams.map(p => `        ${p},`).join('\n')}
+        #[call_result] result: ManagedAsyncCallResult<${resultType}>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(${okBinding}) => {
+                // TODO: record the outcome of ${abi.name}::${endpoint.name}
+            },
+            ManagedAsyncCallResult::Err(err) => {
+                // The call failed and its state changes were reverted; return what was sent
+${refund}                self.async_call_failed_event(&caller, &err.err_msg);
+            },
+        }
+    }`;
+
+  const failedEvent = `    #[event("asyncCallFailed")]
+    fn async_call_failed_event(&self, #[indexed] caller: &ManagedAddress, message: &ManagedBuffer);`;
+  const constant = `/// Gas reserved for the callback when forwarding gas to an async call
+const CALLBACK_GAS: u64 = ${callbackGas};`;
+
+  notes.push(
+    'call_and_exit() ends the current execution: do all state changes before the call and finish the work in the callback.',
+    'Callback arguments are stored with the call; keep them small.'
+  );
+  if (endpoint.onlyOwner) {
+    notes.push(
+      `${abi.name}::${endpoint.name} is owner-only; the call only succeeds if this contract owns the target.`
+    );
+  }
+
+  return {
+    proxyFile: { path: proxy.path, content: proxy.content },
+    modDeclaration: proxy.modDeclaration,
+    constant,
+    proxyAccessor: proxy.proxyAccessor,
+    callerEndpoint,
+    callback,
+    traitItems: [callerEndpoint, callback, proxy.proxyAccessor, failedEvent].join('\n\n'),
+    notes,
+  };
+}
@@ -0,0 +1,54 @@
+import { parseAbi } from '../abi/index.js';
+import { encodeAddress } from '../chain/bech32.js';
+import { decodeCallData, parseCallData } from './call-data.js';
+
+const ABI = parseAbi({
+  name: 'Token',
+  endpoints: [
+    {
+      name: 'transfer',
+      mutability: 'mutable',
+      inputs: [
+        { name: 'to', type: 'Address' },
+        { name: 'amount', type: 'BigUint' },
+        { name: 'memo', type: 'optional<bytes>' },
+      ],
+      outputs: [],
+    },
+  ],
+});
+
+const TO_BYTES = Buffer.alloc(32, 2);
+const TO = encodeAddress(TO_BYTES);
+const DATA = `transfer@${TO_BYTES.toString('hex')}@0f4240`;
+
+describe('parseCallData', () => {
+  it('splits text and base64 call data', () => {
+    const parsed = parseCallData(DATA);
+    expect(parsed.functionName).toBe('transfer');
+    expect(parsed.args).toEqual([TO_BYTES, Buffer.from('0f4240', 'hex')]);
+    expect(parseCallData(Buffer.from('claim').toString('base64'))).toEqual({
+      functionName: 'claim',
+      args: [],
+    });
+  });
+
+  it('rejects data that is not a call', () => {
+    expect(() => parseCallData('transfer@abc')).toThrow('whole-byte hex');
+  });
+});
+
+describe('decodeCallData', () => {
+  it('decodes arguments by input name', () => {
+    const call = decodeCallData(DATA, ABI);
+    expect(call.endpoint.name).toBe('transfer');
+    expect(call.args).toEqual({ to: TO, amount: 1000000, memo: null });
+  });
+
+  it('reports unknown endpoints and extra arguments', () => {
+    expect(() => decodeCallData('burn@01', ABI)).toThrow('Endpoint "burn" not found');
+    expect(() => decodeCallData(`${DATA}@6d656d6f@01`, ABI)).toThrow(
+      'transfer takes 3 argument(s), got 4'
+    );
+  });
+});
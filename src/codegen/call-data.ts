@@ -0,0 +1,43 @@
+/**
+ * Contract call data as carried by invoke transactions: the endpoint name
+ * followed by `@`-separated hex arguments (`transfer@<addr>@<amount>`), as
+ * text or base64-encoded as nodes return it.
+ */
+
+import { findAbiEndpoint, type Abi, type AbiEndpoint } from '../abi/index.js';
+import { decodeArguments } from './abi-codec.js';
+
+export interface CallData {
+  functionName: string;
+  args: Buffer[];
+}
+
+export interface DecodedCall {
+  endpoint: AbiEndpoint;
+  args: Record<string, unknown>;
+}
+
+const CALL_DATA = /^[A-Za-z_]\w*(@([0-9a-fA-F]{2})*)*$/;
+
+/** Split call data into the function name and its arguments */
+export function parseCallData(data: string): CallData {
+  const trimmed = data.trim();
+  // Base64 first: plain call data rarely decodes to valid call data, the reverse is common
+  const text = [Buffer.from(trimmed, 'base64').toString('utf8'), trimmed].find(candidate =>
+    CALL_DATA.test(candidate)
+  );
+  if (!text) {
+    throw new Error(
+      'Call data must be function@hexArg@... (as text or base64) with whole-byte hex arguments'
+    );
+  }
+  const [functionName, ...args] = text.split('@');
+  return { functionName, args: args.map(arg => Buffer.from(arg, 'hex')) };
+}
+
+/** The endpoint call data invokes and its arguments decoded with the ABI input types */
+export function decodeCallData(data: string, abi: Abi): DecodedCall {
+  const { functionName, args } = parseCallData(data);
+  const endpoint = findAbiEndpoint(abi, functionName);
+  return { endpoint, args: decodeArguments(endpoint.inputs, args, abi, endpoint.name) };
+}
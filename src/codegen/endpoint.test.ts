@@ -0,0 +1,81 @@
+import { generateEndpoint, parseEndpointSpec } from './endpoint.js';
+import { listEvents, listStorageMappers } from './rust-source.js';
+
+const VAULT = `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait Vault {
+    #[init]
+    fn init(&self) {}
+
+    #[view(getFeePercent)]
+    #[storage_mapper("feePercent")]
+    fn fee_percent(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("frozenAccounts")]
+    fn frozen_accounts(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[event("feePercentUpdated")]
+    fn fee_percent_updated_event(&self, value: &u32);
+}
+`;
+
+describe('parseEndpointSpec', () => {
+  it('detects owner-only flag actions on an address', () => {
+    const spec = parseEndpointSpec('Owner-only endpoint that freezes an account');
+    expect(spec.ownerOnly).toBe(true);
+    expect(spec.action).toMatchObject({ kind: 'flag_on', verb: 'freeze', state: 'frozen' });
+    expect(spec.subject).toEqual(['account']);
+  });
+
+  it('reads the target collection after "to"', () => {
+    const spec = parseEndpointSpec('add an address to the whitelist');
+    expect(spec.ownerOnly).toBe(false);
+    expect(spec.action).toMatchObject({ kind: 'collection_add', state: 'whitelist' });
+  });
+
+  it('rejects specs without a supported verb', () => {
+    expect(() => parseEndpointSpec('do something clever')).toThrow('supported action');
+  });
+});
+
+describe('generateEndpoint', () => {
+  it('reuses a declared set mapper for a flag action', () => {
+    const result = generateEndpoint('owner-only endpoint that freezes an account', VAULT);
+
+    expect(result.functionName).toBe('freeze_account');
+    expect(result.newStorage).toEqual([]);
+    expect(result.endpoint).toContain('#[only_owner]\n    #[endpoint(freezeAccount)]');
+    expect(result.endpoint).toContain(
+      'require!(self.frozen_accounts().insert(address.clone()), "Account already frozen");'
+    );
+    expect(result.newEvent).toContain('#[event("accountFrozen")]');
+    expect(result.source).toContain('self.account_frozen_event(&address);');
+    expect(listEvents(result.source).map(e => e.eventName)).toContain('accountFrozen');
+  });
+
+  it('reuses existing storage and events when assigning a field', () => {
+    const result = generateEndpoint('only the owner can set the fee percent', VAULT);
+
+    expect(result.endpoint).toContain('fn set_fee_percent(&self, value: u32) {');
+    expect(result.endpoint).toContain('self.fee_percent().set(&value);');
+    expect(result.endpoint).toContain('self.fee_percent_updated_event(&value);');
+    expect(result.newEvent).toBeUndefined();
+    expect(result.reused).toContain('fee_percent_updated_event() event');
+  });
+
+  it('declares missing storage for a payable deposit', () => {
+    const result = generateEndpoint('deposit KLV', VAULT);
+
+    expect(result.endpoint).toContain('#[payable("KLV")]');
+    expect(result.newStorage[0]).toContain('fn deposits(&self, address: &ManagedAddress)');
+    expect(listStorageMappers(result.source).map(m => m.functionName)).toContain('deposits');
+  });
+
+  it('refuses to overwrite an existing function', () => {
+    const once = generateEndpoint('freeze an account', VAULT).source;
+    expect(() => generateEndpoint('freeze an account', once)).toThrow('already has a function');
+  });
+});
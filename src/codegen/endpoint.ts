@@ -0,0 +1,503 @@
+/**
+ * Generate an endpoint from a short natural-language spec, reusing the storage
+ * mappers, events and owner checks the contract already declares.
+ */
+
+import {
+  appendToTrait,
+  camelToSnake,
+  findTraitFunction,
+  listEvents,
+  listStorageMappers,
+  snakeToCamel,
+  type StorageMapperDecl,
+} from './rust-source.js';
+
+type ActionKind =
+  | 'flag_on'
+  | 'flag_off'
+  | 'collection_add'
+  | 'collection_remove'
+  | 'assign'
+  | 'deposit'
+  | 'withdraw';
+
+interface Action {
+  kind: ActionKind;
+  /** Normalized verb used in the function name */
+  verb: string;
+  /** Storage stem, e.g. "frozen" for freeze/unfreeze */
+  state: string;
+  /** Past-tense suffix for the event name */
+  pastTense: string;
+}
+
+export interface EndpointSpec {
+  ownerOnly: boolean;
+  action: Action;
+  /** Subject words after the verb, e.g. ["max", "supply"] */
+  subject: string[];
+}
+
+export interface GeneratedEndpoint {
+  functionName: string;
+  externalName: string;
+  /** Endpoint code only */
+  endpoint: string;
+  /** Storage mapper declarations that had to be added */
+  newStorage: string[];
+  /** Event declaration, if a new one was added */
+  newEvent?: string;
+  /** Contract source with everything inserted */
+  source: string;
+  /** Existing declarations the endpoint builds on */
+  reused: string[];
+  notes: string[];
+}
+
+/** Flag verbs: storage stem plus the event suffixes for setting and clearing the flag */
+const FLAG_VERBS: Record<string, [state: string, on: string, off: string]> = {
+  freeze: ['frozen', 'Frozen', 'Unfrozen'],
+  ban: ['banned', 'Banned', 'Unbanned'],
+  block: ['blocked', 'Blocked', 'Unblocked'],
+  suspend: ['suspended', 'Suspended', 'Resumed'],
+  pause: ['paused', 'Paused', 'Unpaused'],
+  lock: ['locked', 'Locked', 'Unlocked'],
+  enable: ['enabled', 'Enabled', 'Disabled'],
+  verify: ['verified', 'Verified', 'Unverified'],
+};
+
+const FLAG_OFF_ALIASES: Record<string, string> = {
+  unfreeze: 'freeze',
+  unban: 'ban',
+  unblock: 'block',
+  resume: 'suspend',
+  unpause: 'pause',
+  unlock: 'lock',
+  disable: 'enable',
+  unverify: 'verify',
+};
+
+const ASSIGN_VERBS = new Set(['set', 'update', 'change', 'configure']);
+const ADD_VERBS = new Set(['add', 'whitelist', 'register', 'allow', 'approve', 'include']);
+const REMOVE_VERBS = new Set(['remove', 'delist', 'unregister', 'revoke', 'exclude', 'delete']);
+
+const FILLER_WORDS = ['a', 'an', 'the', 'its', 'their', 'given', 'specific', 'new'];
+const STOP_WORDS = ['to', 'from', 'for', 'with', 'by', 'and', 'that', 'which', 'when', 'if'];
+
+const ADDRESS_SUBJECTS =
+  /^(account|address|user|wallet|member|holder|recipient|beneficiary|spender|operator|admin|minter)s?$/;
+const TOKEN_SUBJECTS = /^(token|kda|asset|collection)s?$/;
+
+function isKnownVerb(word: string): boolean {
+  return (
+    word in FLAG_VERBS ||
+    word in FLAG_OFF_ALIASES ||
+    ASSIGN_VERBS.has(word) ||
+    ADD_VERBS.has(word) ||
+    REMOVE_VERBS.has(word) ||
+    word === 'deposit' ||
+    word === 'withdraw'
+  );
+}
+
+/** Reduce conjugated verbs ("freezes", "freezing", "froze") to the base form */
+function baseVerb(word: string): string {
+  if (word === 'froze' || word === 'frozen') return 'freeze';
+  for (const suffix of ['ing', 'es', 's', 'ed']) {
+    if (word.endsWith(suffix) && word.length > suffix.length + 2) {
+      const stem = word.slice(0, -suffix.length);
+      const known = [stem, `${stem}e`].find(isKnownVerb);
+      if (known) return known;
+    }
+  }
+  return word;
+}
+
+/**
+ * Extract access control, action and subject from a spec such as
+ * "owner-only endpoint that freezes an account"
+ */
+export function parseEndpointSpec(spec: string): EndpointSpec {
+  const text = spec.toLowerCase().replace(/[^a-z0-9\s-]/g, ' ');
+  const ownerOnly =
+    /\b(owner[- ]only|only (the )?owner|admin[- ]only|only (the )?admin|restricted to (the )?owner)\b/.test(
+      text
+    );
+  const words = text.split(/\s+/).filter(Boolean);
+
+  for (let i = 0; i < words.length; i++) {
+    const verb = baseVerb(words[i]);
+    if (!isKnownVerb(verb)) continue;
+
+    const following = words.slice(i + 1).filter(w => !FILLER_WORDS.includes(w));
+    const stop = following.findIndex(w => STOP_WORDS.includes(w));
+    const subject = (stop === -1 ? following : following.slice(0, stop)).slice(0, 3);
+    const rest = stop === -1 ? [] : following.slice(stop + 1);
+
+    if (verb in FLAG_VERBS || verb in FLAG_OFF_ALIASES) {
+      const on = verb in FLAG_VERBS;
+      const [state, onPast, offPast] = FLAG_VERBS[on ? verb : FLAG_OFF_ALIASES[verb]];
+      return {
+        ownerOnly,
+        action: {
+          kind: on ? 'flag_on' : 'flag_off',
+          verb,
+          state,
+          pastTense: on ? onPast : offPast,
+        },
+        subject,
+      };
+    }
+    if (ASSIGN_VERBS.has(verb)) {
+      if (subject.length === 0) continue;
+      return {
+        ownerOnly,
+        action: { kind: 'assign', verb: 'set', state: subject.join('_'), pastTense: 'Updated' },
+        subject,
+      };
+    }
+    if (ADD_VERBS.has(verb) || REMOVE_VERBS.has(verb)) {
+      const adding = ADD_VERBS.has(verb);
+      // "add an address to the whitelist" stores into "whitelist"; so does "whitelist an address"
+      const target = rest.find(w => !STOP_WORDS.includes(w));
+      const state =
+        verb === 'whitelist' ? 'whitelist' : target || `${subject.join('_') || 'item'}s`;
+      return {
+        ownerOnly,
+        action: {
+          kind: adding ? 'collection_add' : 'collection_remove',
+          verb,
+          state,
+          pastTense: adding ? 'Added' : 'Removed',
+        },
+        subject,
+      };
+    }
+    const depositing = verb === 'deposit';
+    return {
+      ownerOnly,
+      action: {
+        kind: depositing ? 'deposit' : 'withdraw',
+        verb,
+        state: 'deposits',
+        pastTense: depositing ? 'Deposited' : 'Withdrawn',
+      },
+      subject,
+    };
+  }
+  throw new Error(
+    'Could not find a supported action in the spec. Use a verb such as freeze/unfreeze, pause, ban, set/update <field>, add/remove <item> to <list>, deposit or withdraw.'
+  );
+}
+
+/** Best Rust type for a field inferred from its name */
+function inferValueType(words: string[]): string {
+  const name = words.join(' ');
+  if (/(address|owner|admin|treasury|recipient|wallet|account)/.test(name)) {
+    return 'ManagedAddress';
+  }
+  if (/(token|kda)( id)?$/.test(name)) return 'TokenIdentifier';
+  if (/(name|uri|url|description|metadata|label)/.test(name)) return 'ManagedBuffer';
+  if (/(enabled|active|flag|allowed)/.test(name)) return 'bool';
+  if (/(duration|period|epoch|timestamp|deadline|delay|timeout|nonce)/.test(name)) return 'u64';
+  if (/(percent|bps|basis|ratio|count)/.test(name)) return 'u32';
+  return 'BigUint';
+}
+
+/** Find a declared mapper whose name matches the state stem */
+function findMapper(mappers: StorageMapperDecl[], state: string): StorageMapperDecl | undefined {
+  const stem = state.replace(/_/g, '');
+  const flat = (m: StorageMapperDecl) => m.functionName.replace(/_/g, '');
+  return (
+    mappers.find(m => m.functionName === state) ||
+    mappers.find(m => flat(m).includes(stem)) ||
+    mappers.find(m => m.functionName.length > 3 && stem.includes(flat(m)))
+  );
+}
+
+function capitalize(text: string): string {
+  return text.length > 0 ? text[0].toUpperCase() + text.slice(1) : text;
+}
+
+function declaredMapper(
+  name: string,
+  mapper: string,
+  typeArg: string,
+  keyParams: StorageMapperDecl['keyParams'] = []
+): StorageMapperDecl {
+  return {
+    functionName: name,
+    storageKey: snakeToCamel(name),
+    keyParams,
+    mapper,
+    typeArgs: [typeArg],
+    returnType: `${mapper}<${typeArg}>`,
+  };
+}
+
+function flagStatements(
+  mapper: StorageMapperDecl,
+  keyName: string,
+  on: boolean,
+  label: string,
+  state: string
+): string[] {
+  const keyArgs = mapper.keyParams.length > 0 ? `&${keyName}` : '';
+  const call = `self.${mapper.functionName}(${keyArgs})`;
+  const already = `"${label} already ${on ? state : `not ${state}`}"`;
+
+  switch (mapper.mapper) {
+    case 'SetMapper':
+      return on
+        ? [`require!(${call}.insert(${keyName}.clone()), ${already});`]
+        : [`require!(${call}.remove(&${keyName}), ${already});`];
+    case 'UnorderedSetMapper':
+      return on
+        ? [`require!(${call}.insert(${keyName}.clone()), ${already});`]
+        : [`require!(${call}.swap_remove(&${keyName}), ${already});`];
+    case 'WhitelistMapper':
+      return [
+        `require!(${on ? '!' : ''}${call}.contains(&${keyName}), ${already});`,
+        `${call}.${on ? 'add' : 'remove'}(&${keyName});`,
+      ];
+    default:
+      return [
+        `require!(${on ? '!' : ''}${call}.get(), ${already});`,
+        on ? `${call}.set(true);` : `${call}.clear();`,
+      ];
+  }
+}
+
+function collectionStatements(
+  mapper: StorageMapperDecl,
+  keyName: string,
+  adding: boolean
+): string[] {
+  const call = `self.${mapper.functionName}()`;
+  switch (mapper.mapper) {
+    case 'WhitelistMapper':
+      return adding
+        ? [`require!(!${call}.contains(&${keyName}), "Already added");`, `${call}.add(&${keyName});`]
+        : [`require!(${call}.contains(&${keyName}), "Not found");`, `${call}.remove(&${keyName});`];
+    case 'SetMapper':
+      return adding
+        ? [`require!(${call}.insert(${keyName}.clone()), "Already added");`]
+        : [`require!(${call}.remove(&${keyName}), "Not found");`];
+    case 'VecMapper':
+      if (!adding) {
+        throw new Error(
+          `Storage "${mapper.functionName}" is a VecMapper, which cannot remove by value; use a set mapper.`
+        );
+      }
+      return [`${call}.push(&${keyName});`];
+    default:
+      return adding
+        ? [`require!(${call}.insert(${keyName}.clone()), "Already added");`]
+        : [`require!(${call}.swap_remove(&${keyName}), "Not found");`];
+  }
+}
+
+interface EventArg {
+  name: string;
+  type: string;
+  indexed: boolean;
+}
+
+/**
+ * Generate an endpoint for `spec` and insert it, plus any storage and event it
+ * needs, into the contract trait of `source`
+ */
+export function generateEndpoint(spec: string, source: string): GeneratedEndpoint {
+  const { ownerOnly, action, subject } = parseEndpointSpec(spec);
+  const mappers = listStorageMappers(source);
+  const notes: string[] = [];
+  const reused: string[] = [];
+  const newStorage: string[] = [];
+
+  const subjectName = camelToSnake(subject.join('_'));
+  const lastWord = subject[subject.length - 1] || '';
+  const isAddress = ADDRESS_SUBJECTS.test(lastWord);
+  const isToken = TOKEN_SUBJECTS.test(lastWord);
+  const keyName = isAddress ? 'address' : isToken ? 'token_id' : subjectName || 'item';
+  const keyType = isAddress
+    ? 'ManagedAddress'
+    : isToken
+      ? 'TokenIdentifier'
+      : inferValueType(subject);
+
+  const functionName = [action.verb, subjectName].filter(Boolean).join('_');
+  if (findTraitFunction(source, functionName)) {
+    throw new Error(`Contract already has a function named "${functionName}".`);
+  }
+  const externalName = snakeToCamel(functionName);
+
+  // Access control: reuse an existing helper rather than mixing patterns
+  const attributes: string[] = [];
+  const body: string[] = [];
+  if (ownerOnly) {
+    if (findTraitFunction(source, 'require_owner')) {
+      body.push('self.require_owner();');
+      reused.push('require_owner()');
+    } else {
+      attributes.push('#[only_owner]');
+    }
+  }
+  attributes.push(`#[endpoint(${externalName})]`);
+
+  const params: string[] = [];
+  const eventArgs: EventArg[] = [];
+  const addressCheck = (name: string) => `require!(!${name}.is_zero(), "Invalid address");`;
+  let mapper = action.state === 'deposits' ? undefined : findMapper(mappers, action.state);
+  const mapperName = mapper ? mapper.functionName : camelToSnake(action.state);
+  if (mapper) reused.push(`${mapper.functionName}() storage (${mapper.returnType})`);
+
+  switch (action.kind) {
+    case 'flag_on':
+    case 'flag_off': {
+      const on = action.kind === 'flag_on';
+      const perSubject = subject.length > 0 && action.state !== 'paused';
+      if (perSubject) {
+        params.push(`${keyName}: ${keyType}`);
+        if (keyType === 'ManagedAddress') body.push(addressCheck(keyName));
+        eventArgs.push({ name: keyName, type: `&${keyType}`, indexed: true });
+      }
+      if (!mapper) {
+        const keyParams = perSubject ? [{ name: keyName, type: `&${keyType}`, attributes: [] }] : [];
+        mapper = declaredMapper(mapperName, 'SingleValueMapper', 'bool', keyParams);
+        const keyParam = perSubject ? `, ${keyName}: &${keyType}` : '';
+        newStorage.push(
+          `#[storage_mapper("${mapper.storageKey}")]\n    fn ${mapperName}(&self${keyParam}) -> SingleValueMapper<bool>;`
+        );
+      }
+      const label = capitalize(subject.length > 0 ? subject.join(' ') : 'contract');
+      body.push(...flagStatements(mapper, keyName, on, label, action.state));
+      break;
+    }
+    case 'collection_add':
+    case 'collection_remove': {
+      const elementType = mapper?.typeArgs[0] || keyType;
+      params.push(`${keyName}: ${elementType}`);
+      if (elementType === 'ManagedAddress') body.push(addressCheck(keyName));
+      if (!mapper) {
+        mapper = declaredMapper(mapperName, 'UnorderedSetMapper', elementType);
+        newStorage.push(
+          `#[storage_mapper("${mapper.storageKey}")]\n    fn ${mapperName}(&self) -> UnorderedSetMapper<${elementType}>;`
+        );
+      }
+      body.push(...collectionStatements(mapper, keyName, action.kind === 'collection_add'));
+      eventArgs.push({ name: keyName, type: `&${elementType}`, indexed: true });
+      break;
+    }
+    case 'assign': {
+      if (mapper && mapper.mapper !== 'SingleValueMapper') {
+        throw new Error(
+          `Storage "${mapper.functionName}" is a ${mapper.mapper}; "set" needs a SingleValueMapper.`
+        );
+      }
+      const valueType = mapper?.typeArgs[0] || inferValueType(subject);
+      const keys = mapper ? mapper.keyParams : [];
+      for (const key of keys) {
+        const owned = key.type.replace(/^&/, '');
+        params.push(`${key.name}: ${owned}`);
+        eventArgs.push({ name: key.name, type: `&${owned}`, indexed: true });
+      }
+      params.push(`value: ${valueType}`);
+      if (valueType === 'BigUint' && /(fee|price|rate|limit|cap|min|max)/.test(subjectName)) {
+        body.push(`require!(value > 0, "${capitalize(subject.join(' '))} must be positive");`);
+      } else if (valueType === 'ManagedAddress') {
+        body.push(addressCheck('value'));
+      }
+      if (!mapper) {
+        const key = snakeToCamel(mapperName);
+        newStorage.push(
+          `#[view(get${capitalize(key)})]\n    #[storage_mapper("${key}")]\n    fn ${mapperName}(&self) -> SingleValueMapper<${valueType}>;`
+        );
+      }
+      body.push(`self.${mapperName}(${keys.map(k => `&${k.name}`).join(', ')}).set(&value);`);
+      eventArgs.push({ name: 'value', type: `&${valueType}`, indexed: false });
+      break;
+    }
+    case 'deposit':
+    case 'withdraw': {
+      const balance = mappers.find(
+        m =>
+          m.mapper === 'SingleValueMapper' &&
+          m.keyParams.length === 1 &&
+          /balance|deposit/.test(m.functionName)
+      );
+      const balanceName = balance ? balance.functionName : 'deposits';
+      if (balance) {
+        reused.push(`${balance.functionName}() storage (${balance.returnType})`);
+      } else {
+        newStorage.push(
+          '#[view(getDeposit)]\n    #[storage_mapper("deposits")]\n    fn deposits(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;'
+        );
+      }
+      body.unshift('let caller = self.blockchain().get_caller();');
+      if (action.kind === 'deposit') {
+        attributes.unshift('#[payable("KLV")]');
+        body.push(
+          'let amount = self.call_value().klv_value().clone_value();',
+          'require!(amount > 0, "Deposit must be positive");',
+          `self.${balanceName}(&caller).update(|balance| *balance += &amount);`
+        );
+      } else {
+        params.push('amount: BigUint');
+        body.push(
+          'require!(amount > 0, "Amount must be positive");',
+          `let balance = self.${balanceName}(&caller).get();`,
+          'require!(balance >= amount, "Insufficient balance");',
+          `self.${balanceName}(&caller).set(&(balance - &amount));`,
+          'self.send().direct_klv(&caller, &amount);'
+        );
+      }
+      notes.push(
+        'Uses KLV; switch to call_value().single_kda() / send().direct_kda() for a KDA token.'
+      );
+      eventArgs.push({ name: 'caller', type: '&ManagedAddress', indexed: true });
+      eventArgs.push({ name: 'amount', type: '&BigUint', indexed: false });
+      break;
+    }
+  }
+
+  // Event: reuse one with the same identifier if it exists, otherwise declare it
+  const eventName =
+    action.kind === 'deposit' || action.kind === 'withdraw'
+      ? action.kind
+      : `${snakeToCamel(subjectName) || action.state}${action.pastTense}`;
+  const existingEvent = listEvents(source).find(e => e.eventName === eventName);
+  const eventFn = existingEvent ? existingEvent.functionName : `${camelToSnake(eventName)}_event`;
+  let newEvent: string | undefined;
+  if (existingEvent) {
+    reused.push(`${existingEvent.functionName}() event`);
+  } else {
+    const eventParams = eventArgs.map(
+      a => `        ${a.indexed ? '#[indexed] ' : ''}${a.name}: ${a.type},`
+    );
+    newEvent = `#[event("${eventName}")]\n    fn ${eventFn}(\n        &self,\n${eventParams.join('\n')}\n    );`;
+  }
+  body.push(`self.${eventFn}(${eventArgs.map(a => `&${a.name}`).join(', ')});`);
+
+  const endpoint = [
+    ...attributes.map(a => `    ${a}`),
+    `    fn ${functionName}(${['&self', ...params].join(', ')}) {`,
+    ...body.map(line => `        ${line}`),
+    '    }',
+  ].join('\n');
+
+  const insertion = [endpoint, ...newStorage, ...(newEvent ? [newEvent] : [])]
+    .map((item, index) => (index === 0 ? item : `    ${item}`))
+    .join('\n\n');
+
+  return {
+    functionName,
+    externalName,
+    endpoint,
+    newStorage,
+    newEvent,
+    source: appendToTrait(source, insertion),
+    reused,
+    notes,
+  };
+}
@@ -0,0 +1,69 @@
+import { generateEvent, planEventFields } from './events.js';
+
+const CONTRACT = `#[klever_sc::contract]
+pub trait Minter {
+    #[init]
+    fn init(&self) {}
+}
+`;
+
+describe('planEventFields', () => {
+  it('indexes addresses and ids and keeps the amount as data', () => {
+    const { fields, warnings } = planEventFields([
+      { name: 'caller', type: 'ManagedAddress' },
+      { name: 'token_id', type: 'TokenIdentifier' },
+      { name: 'amount', type: 'BigUint' },
+    ]);
+    expect(fields.map(f => [f.name, f.indexed])).toEqual([
+      ['caller', true],
+      ['token_id', true],
+      ['amount', false],
+    ]);
+    expect(warnings).toEqual([]);
+  });
+
+  it('warns when too many topics or data fields are requested', () => {
+    const { fields, warnings } = planEventFields(
+      [
+        { name: 'from', type: 'ManagedAddress' },
+        { name: 'to', type: 'ManagedAddress' },
+        { name: 'amount', type: 'BigUint', indexed: false },
+        { name: 'memo', type: 'ManagedBuffer', indexed: false },
+      ],
+      2
+    );
+    expect(fields.filter(f => !f.indexed)).toHaveLength(1);
+    expect(warnings[0]).toContain('Only one non-indexed field');
+    expect(warnings[1]).toContain('exceed the recommended maximum of 2');
+  });
+});
+
+describe('generateEvent', () => {
+  it('renders the declaration and emit call', () => {
+    const event = generateEvent('tokens_minted', [
+      { name: 'to', type: 'ManagedAddress' },
+      { name: 'nonce', type: 'u64' },
+      { name: 'amount', type: 'BigUint' },
+    ]);
+    expect(event.eventName).toBe('tokensMinted');
+    expect(event.declaration).toBe(
+      [
+        '    #[event("tokensMinted")]',
+        '    fn tokens_minted_event(',
+        '        &self,',
+        '        #[indexed] to: &ManagedAddress,',
+        '        #[indexed] nonce: u64,',
+        '        amount: &BigUint,',
+        '    );',
+      ].join('\n')
+    );
+    expect(event.emitCall).toBe('self.tokens_minted_event(&to, nonce, &amount);');
+  });
+
+  it('inserts into source and rejects duplicates', () => {
+    const fields = [{ name: 'caller', type: 'ManagedAddress' }];
+    const { source } = generateEvent('paused', fields, { source: CONTRACT });
+    expect(source).toContain('fn paused_event(');
+    expect(() => generateEvent('paused', fields, { source })).toThrow('already declares');
+  });
+});
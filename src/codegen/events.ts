@@ -0,0 +1,204 @@
+/**
+ * Generate #[event] declarations and emit calls, choosing which fields become
+ * #[indexed] topics from how they are likely to be queried.
+ */
+
+import {
+  appendToTrait,
+  camelToSnake,
+  findTraitFunction,
+  listEvents,
+  snakeToCamel,
+} from './rust-source.js';
+
+/** Topics beyond the event identifier that indexers filter on efficiently */
+export const DEFAULT_MAX_INDEXED = 3;
+
+export interface EventFieldSpec {
+  name: string;
+  /** Rust type as it appears in the contract, e.g. ManagedAddress, BigUint, u64 */
+  type: string;
+  /** Force the field to be a topic (true) or the data field (false) */
+  indexed?: boolean;
+}
+
+export interface EventFieldPlan {
+  name: string;
+  type: string;
+  /** Parameter type in the declaration (managed types are passed by reference) */
+  paramType: string;
+  indexed: boolean;
+  reason: string;
+}
+
+export interface GeneratedEvent {
+  eventName: string;
+  functionName: string;
+  declaration: string;
+  /** Emit call with field names as arguments */
+  emitCall: string;
+  fields: EventFieldPlan[];
+  warnings: string[];
+  /** Contract source with the declaration appended, when source was given */
+  source?: string;
+}
+
+export interface GenerateEventOptions {
+  maxIndexed?: number;
+  /** Contract source to insert the declaration into */
+  source?: string;
+}
+
+const PRIMITIVES = /^(u8|u16|u32|u64|usize|i8|i16|i32|i64|isize|bool)$/;
+
+/** Lower score = stronger topic candidate */
+function topicScore(field: EventFieldSpec): { score: number; reason: string } {
+  const name = field.name.toLowerCase();
+  const type = field.type.replace(/<.*>$/, '');
+
+  if (type === 'ManagedAddress' || /(address|caller|from|to|owner|sender|recipient)$/.test(name)) {
+    return { score: 0, reason: 'Address: filter logs by participant' };
+  }
+  if (/TokenIdentifier$/.test(type) || /(token|token_id)$/.test(name)) {
+    return { score: 1, reason: 'Token identifier: filter logs by asset' };
+  }
+  if (/(^id$|_id$|nonce|epoch|round)/.test(name) && PRIMITIVES.test(type)) {
+    return { score: 1, reason: 'Identifier: look up every event for one entity' };
+  }
+  if (type === 'bool' || /(status|kind|type|action)$/.test(name)) {
+    return { score: 2, reason: 'Small enumerable value: cheap to filter on' };
+  }
+  if (PRIMITIVES.test(type)) {
+    return { score: 3, reason: 'Small scalar: usable as a topic' };
+  }
+  if (type === 'BigUint' || /(amount|value|balance|price)$/.test(name)) {
+    return { score: 4, reason: 'Amount: read per event, rarely filtered on' };
+  }
+  return { score: 5, reason: 'Opaque payload: best kept as the data field' };
+}
+
+/** Managed and composite types are passed by reference, primitives by value */
+function paramTypeFor(type: string): string {
+  return PRIMITIVES.test(type) || type.startsWith('&') ? type : `&${type}`;
+}
+
+/**
+ * Plan which fields are indexed. klever-sc allows at most one non-indexed (data)
+ * field per event; every other field must be a topic.
+ */
+export function planEventFields(
+  fields: EventFieldSpec[],
+  maxIndexed = DEFAULT_MAX_INDEXED
+): { fields: EventFieldPlan[]; warnings: string[] } {
+  const warnings: string[] = [];
+  const scored = fields.map(f => ({ field: f, ...topicScore(f) }));
+
+  const forcedData = scored.filter(s => s.field.indexed === false);
+  if (forcedData.length > 1) {
+    warnings.push(
+      `Only one non-indexed field is allowed; ${forcedData
+        .map(s => s.field.name)
+        .join(', ')} were requested as data. Combine them into a #[derive(TopEncode)] struct or MultiValueN.`
+    );
+  }
+
+  // Pick the data field: an explicit request, otherwise the weakest topic candidate
+  let dataField = forcedData[0]?.field;
+  if (!dataField) {
+    const candidates = scored
+      .filter(s => s.field.indexed === undefined && s.score >= 4)
+      .sort((a, b) => b.score - a.score);
+    dataField = candidates[0]?.field;
+  }
+
+  const plans = scored.map(s => {
+    const indexed = s.field !== dataField;
+    const reason =
+      s.field.indexed !== undefined
+        ? `Requested as ${s.field.indexed ? 'indexed' : 'data'}`
+        : indexed && s.score >= 4
+          ? `${s.reason}; indexed because only one data field is allowed`
+          : s.reason;
+    return {
+      name: s.field.name,
+      type: s.field.type,
+      paramType: paramTypeFor(s.field.type),
+      indexed,
+      reason,
+    };
+  });
+
+  const indexedCount = plans.filter(p => p.indexed).length;
+  if (indexedCount > maxIndexed) {
+    warnings.push(
+      `${indexedCount} indexed fields exceed the recommended maximum of ${maxIndexed}. Every topic is stored and hashed per log; move rarely-filtered fields into a struct used as the single data field.`
+    );
+  }
+  const payloads = plans.filter(p => p.indexed && topicScore(p).score === 5);
+  for (const p of payloads) {
+    warnings.push(
+      `"${p.name}" (${p.type}) is indexed but looks like an opaque payload; large topics are costly and hard to query.`
+    );
+  }
+  return { fields: plans, warnings };
+}
+
+/**
+ * Generate an event declaration and emit call, optionally inserting the
+ * declaration into the contract trait of `options.source`
+ */
+export function generateEvent(
+  eventName: string,
+  fields: EventFieldSpec[],
+  options: GenerateEventOptions = {}
+): GeneratedEvent {
+  if (!/^[A-Za-z_]\w*$/.test(eventName)) {
+    throw new Error(`Invalid event name "${eventName}"; use an identifier such as "tokensMinted".`);
+  }
+  if (fields.length === 0) {
+    throw new Error('An event needs at least one field.');
+  }
+  const names = new Set<string>();
+  for (const f of fields) {
+    if (names.has(f.name)) throw new Error(`Duplicate event field "${f.name}".`);
+    names.add(f.name);
+  }
+
+  const identifier = snakeToCamel(eventName);
+  const functionName = `${camelToSnake(identifier)}_event`;
+  const plan = planEventFields(fields, options.maxIndexed);
+
+  const params = plan.fields.map(
+    f => `        ${f.indexed ? '#[indexed] ' : ''}${f.name}: ${f.paramType},`
+  );
+  const declaration = [
+    `    #[event("${identifier}")]`,
+    `    fn ${functionName}(`,
+    '        &self,',
+    ...params,
+    '    );',
+  ].join('\n');
+  const args = plan.fields.map(f => (f.paramType.startsWith('&') ? `&${f.name}` : f.name));
+  const emitCall = `self.${functionName}(${args.join(', ')});`;
+
+  let source: string | undefined;
+  if (options.source !== undefined) {
+    if (listEvents(options.source).some(e => e.eventName === identifier)) {
+      throw new Error(`Contract already declares event "${identifier}".`);
+    }
+    if (findTraitFunction(options.source, functionName)) {
+      throw new Error(`Contract already has a function named "${functionName}".`);
+    }
+    source = appendToTrait(options.source, declaration);
+  }
+
+  return {
+    eventName: identifier,
+    functionName,
+    declaration,
+    emitCall,
+    fields: plan.fields,
+    warnings: plan.warnings,
+    source,
+  };
+}
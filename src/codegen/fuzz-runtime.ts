@@ -0,0 +1,385 @@
+/**
+ * Rust sources shared by every generated fuzz crate (see `fuzz.ts`): the
+ * klever-sc encoding of fuzzed argument values, and the harness helpers that
+ * deploy, call and query the contract in a scenario world.
+ */
+
+/** src/codec.rs: top-level and nested encoding of the fuzzed Rust values ABI types map to */
+export const FUZZ_CODEC_RS = String.raw`//! ABI encoding of fuzzed arguments: top-level values (whole arguments) drop
+//! leading zero bytes, nested values (struct fields, list items, ...) have a
+//! fixed size or a u32 length prefix, as in klever-sc.
+
+use arbitrary::Arbitrary;
+
+pub trait Encode {
+    fn dep_encode(&self, out: &mut Vec<u8>);
+
+    /// Top-level encoding; the nested encoding for structs, arrays and tuples
+    fn top_encode(&self) -> Vec<u8> {
+        encode_nested(self)
+    }
+}
+
+pub fn encode_nested<T: Encode + ?Sized>(value: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    value.dep_encode(&mut out);
+    out
+}
+
+/// Minimal big-endian bytes of a two's complement value: no redundant sign bytes
+fn minimal(bytes: &[u8], signed: bool) -> Vec<u8> {
+    if bytes.iter().all(|b| *b == 0) {
+        return Vec::new();
+    }
+    let fill = if signed && bytes[0] & 0x80 != 0 { 0xff } else { 0 };
+    let mut start = 0;
+    while start < bytes.len() && bytes[start] == fill {
+        start += 1;
+    }
+    // Keep one fill byte when the next byte would read as the other sign
+    if signed && (start == bytes.len() || bytes[start] & 0x80 != fill & 0x80) {
+        start -= 1;
+    }
+    bytes[start..].to_vec()
+}
+
+macro_rules! integers {
+    ($($t:ty: $signed:expr),*) => {$(
+        impl Encode for $t {
+            fn dep_encode(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_be_bytes());
+            }
+
+            fn top_encode(&self) -> Vec<u8> {
+                minimal(&self.to_be_bytes(), $signed)
+            }
+        }
+    )*};
+}
+
+integers!(u8: false, u16: false, u32: false, u64: false, i8: true, i16: true, i32: true, i64: true);
+
+impl Encode for bool {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn top_encode(&self) -> Vec<u8> {
+        (*self as u8).top_encode()
+    }
+}
+
+/// BigUint argument, fuzzed up to u128
+#[derive(Arbitrary, Clone, Copy, Debug)]
+pub struct Big(pub u128);
+
+impl Encode for Big {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        self.top_encode().dep_encode(out);
+    }
+
+    fn top_encode(&self) -> Vec<u8> {
+        minimal(&self.0.to_be_bytes(), false)
+    }
+}
+
+/// BigInt argument, fuzzed within i128
+#[derive(Arbitrary, Clone, Copy, Debug)]
+pub struct BigSigned(pub i128);
+
+impl Encode for BigSigned {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        self.top_encode().dep_encode(out);
+    }
+
+    fn top_encode(&self) -> Vec<u8> {
+        minimal(&self.0.to_be_bytes(), true)
+    }
+}
+
+impl Encode for String {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        self.as_bytes().to_vec().dep_encode(out);
+    }
+
+    fn top_encode(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+}
+
+/// Lists, and bytes as a list of u8: nested with a u32 item count, top-level without
+impl<T: Encode> Encode for Vec<T> {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        (self.len() as u32).dep_encode(out);
+        for item in self {
+            item.dep_encode(out);
+        }
+    }
+
+    fn top_encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        for item in self {
+            item.dep_encode(&mut out);
+        }
+        out
+    }
+}
+
+/// Option: a 0/1 flag before the value; top-level None is empty
+impl<T: Encode> Encode for Option<T> {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(0),
+            Some(value) => {
+                out.push(1);
+                value.dep_encode(out);
+            }
+        }
+    }
+
+    fn top_encode(&self) -> Vec<u8> {
+        match self {
+            None => Vec::new(),
+            Some(_) => encode_nested(self),
+        }
+    }
+}
+
+impl<T: Encode, const N: usize> Encode for [T; N] {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        for item in self {
+            item.dep_encode(out);
+        }
+    }
+}
+
+macro_rules! tuples {
+    ($(($($name:ident $index:tt),+)),*) => {$(
+        impl<$($name: Encode),+> Encode for ($($name,)+) {
+            fn dep_encode(&self, out: &mut Vec<u8>) {
+                $(self.$index.dep_encode(out);)+
+            }
+        }
+    )*};
+}
+
+tuples!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+);
+`;
+
+/** src/harness.rs: the scenario world and the calls a fuzz target makes through it */
+export const FUZZ_HARNESS_RS = String.raw`//! Scenario world for fuzz targets: funded accounts, the deployed contract,
+//! endpoint calls with payments, view queries and failure reporting. The
+//! accounts, contract and tokens come from the crate root (lib.rs).
+
+use std::{fmt, sync::Once};
+
+use arbitrary::Arbitrary;
+use klever_sc_scenario::{scenario_model::*, *};
+use num_bigint::BigUint;
+
+use crate::{codec::Encode, ACCOUNTS, BALANCE, CODE, CONTRACT, TOKENS};
+
+/// Account sending a call: one of ACCOUNTS
+#[derive(Arbitrary, Clone, Copy)]
+pub struct Caller(pub u8);
+
+impl Caller {
+    pub fn name(&self) -> &'static str {
+        ACCOUNTS[self.0 as usize % ACCOUNTS.len()]
+    }
+}
+
+/// Address argument: one of ACCOUNTS or the contract itself
+#[derive(Arbitrary, Clone, Copy)]
+pub struct Addr(pub u8);
+
+impl Addr {
+    pub fn name(&self) -> &'static str {
+        let index = self.0 as usize % (ACCOUNTS.len() + 1);
+        if index == ACCOUNTS.len() {
+            CONTRACT
+        } else {
+            ACCOUNTS[index]
+        }
+    }
+}
+
+impl Encode for Addr {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&address(self.name()));
+    }
+}
+
+/// Token identifier argument: one of TOKENS
+#[derive(Arbitrary, Clone, Copy)]
+pub struct Token(pub u8);
+
+impl Token {
+    pub fn identifier(&self) -> &'static str {
+        TOKENS[self.0 as usize % TOKENS.len()]
+    }
+}
+
+impl Encode for Token {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        self.identifier().as_bytes().to_vec().dep_encode(out);
+    }
+
+    fn top_encode(&self) -> Vec<u8> {
+        self.identifier().as_bytes().to_vec()
+    }
+}
+
+macro_rules! named_debug {
+    ($($t:ty: $name:ident),*) => {$(
+        impl fmt::Debug for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(self.$name())
+            }
+        }
+    )*};
+}
+
+named_debug!(Caller: name, Addr: name, Token: identifier);
+
+/// Value sent with a call to a payable endpoint
+#[derive(Arbitrary, Debug)]
+pub enum Payment {
+    None,
+    Klv(u64),
+    Kda(Token, u64),
+}
+
+/// Report a broken invariant and stop the fuzzer, which saves the input
+pub fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    std::process::abort()
+}
+
+/// The VM catches the panics that reject a transaction; keep them off stderr
+pub fn quiet_panics() {
+    static QUIET: Once = Once::new();
+    QUIET.call_once(|| std::panic::set_hook(Box::new(|_| {})));
+}
+
+/// Scenario value expression of encoded bytes
+pub fn expression(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return String::new();
+    }
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("0x{}", hex)
+}
+
+pub fn from_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("hex digits"))
+        .collect()
+}
+
+/// Address bytes of a scenario account (address:...) or contract (sc:...)
+pub fn address(name: &str) -> Vec<u8> {
+    AddressValue::from(name).value.as_bytes().to_vec()
+}
+
+pub fn biguint(bytes: &[u8]) -> BigUint {
+    BigUint::from_bytes_be(bytes)
+}
+
+/// First result of a view returning a BigUint
+pub fn query_biguint(world: &mut ScenarioWorld, view: &str, args: &[Vec<u8>]) -> BigUint {
+    biguint(query(world, view, args).first().map_or(&[][..], Vec::as_slice))
+}
+
+/// A world with every account funded in KLV and the test tokens
+pub fn world() -> ScenarioWorld {
+    static ROOT: Once = Once::new();
+    // Contract code paths are relative to the contract crate, one level up
+    ROOT.call_once(|| {
+        std::env::set_current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+            .expect("contract crate directory")
+    });
+
+    let mut world = ScenarioWorld::new();
+    world.register_contract(CODE, crate::ContractBuilder);
+
+    let mut state = SetStateStep::new();
+    for name in ACCOUNTS {
+        let mut account = Account::new().nonce(1).balance(BALANCE);
+        for token in TOKENS {
+            account = account.kda_balance(format!("str:{}", token).as_str(), BALANCE);
+        }
+        state = state.put_account(name, account);
+    }
+    world.set_state_step(state.new_address(ACCOUNTS[0], 1, CONTRACT));
+    world
+}
+
+/// Deploy the contract from the first account; false when init rejects the arguments
+pub fn deploy(world: &mut ScenarioWorld, args: &[Vec<u8>]) -> bool {
+    let mut step = ScDeployStep::new().from(ACCOUNTS[0]).code(CODE);
+    for arg in args {
+        step = step.argument(expression(arg).as_str());
+    }
+    world.sc_deploy(&mut step);
+    step.response().tx_error.status == 0
+}
+
+/// Call an endpoint with a payment; the response may be an error
+pub fn call(
+    world: &mut ScenarioWorld,
+    caller: Caller,
+    endpoint: &str,
+    args: &[Vec<u8>],
+    payment: &Payment,
+) -> TxResponse {
+    let mut step = ScCallStep::new().from(caller.name()).to(CONTRACT).function(endpoint);
+    for arg in args {
+        step = step.argument(expression(arg).as_str());
+    }
+    step = match payment {
+        Payment::None => step,
+        Payment::Klv(amount) => step.klv_value(amount.to_string().as_str()),
+        Payment::Kda(token, amount) => step.kda_transfer(
+            format!("str:{}", token.identifier()).as_str(),
+            0,
+            amount.to_string().as_str(),
+        ),
+    };
+    world.sc_call(&mut step);
+    step.response().clone()
+}
+
+/// Results of a view; a failed query breaks the run
+pub fn query(world: &mut ScenarioWorld, view: &str, args: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    let mut step = ScQueryStep::new().to(CONTRACT).function(view);
+    for arg in args {
+        step = step.argument(expression(arg).as_str());
+    }
+    world.sc_query(&mut step);
+    let response = step.response();
+    if response.tx_error.status != 0 {
+        fail(&format!("query {} failed: {}", view, response.tx_error.message));
+    }
+    response.out.clone()
+}
+
+/// Calls may succeed or fail with a user error (require!, sc_panic!); anything else is a bug
+pub fn check_status(endpoint: &str, response: &TxResponse, call: &impl fmt::Debug) {
+    let error = &response.tx_error;
+    if (error.status != 0 && error.status != 4) || error.message.starts_with("panic occurred") {
+        fail(&format!(
+            "{} failed with status {} ({}) for {:?}",
+            endpoint, error.status, error.message, call
+        ));
+    }
+}
+`;
@@ -0,0 +1,178 @@
+import { parseAbi } from '../abi/index.js';
+import { generateFuzzTarget } from './fuzz.js';
+
+const TOKEN = parseAbi({
+  buildInfo: {
+    contractCrate: { name: 'fuzz_token', version: '0.1.0' },
+    framework: { name: 'klever-sc', version: '0.45.0' },
+  },
+  name: 'FuzzToken',
+  constructor: { inputs: [{ name: 'initial_supply', type: 'BigUint' }], outputs: [] },
+  endpoints: [
+    {
+      name: 'mint',
+      onlyOwner: true,
+      mutability: 'mutable',
+      inputs: [
+        { name: 'to', type: 'Address' },
+        { name: 'amount', type: 'BigUint' },
+      ],
+      outputs: [],
+    },
+    {
+      name: 'transfer',
+      mutability: 'mutable',
+      inputs: [
+        { name: 'to', type: 'Address' },
+        { name: 'amount', type: 'BigUint' },
+      ],
+      outputs: [],
+    },
+    {
+      name: 'totalSupply',
+      mutability: 'readonly',
+      inputs: [],
+      outputs: [{ type: 'BigUint' }],
+    },
+    {
+      name: 'balanceOf',
+      mutability: 'readonly',
+      inputs: [{ name: 'account', type: 'Address' }],
+      outputs: [{ type: 'BigUint' }],
+    },
+  ],
+});
+
+const VAULT = parseAbi({
+  name: 'Vault',
+  constructor: { inputs: [], outputs: [] },
+  endpoints: [
+    {
+      name: 'deposit',
+      mutability: 'mutable',
+      payableInTokens: ['*'],
+      inputs: [
+        { name: 'kind', type: 'Kind' },
+        { name: 'lock', type: 'optional<Lock>' },
+      ],
+      outputs: [],
+    },
+    {
+      name: 'setHook',
+      mutability: 'mutable',
+      inputs: [{ name: 'hook', type: 'Hook' }],
+      outputs: [],
+    },
+  ],
+  types: {
+    Kind: {
+      type: 'enum',
+      variants: [
+        { name: 'Daily', discriminant: 0 },
+        { name: 'Fixed', discriminant: 1, fields: [{ name: 'days', type: 'u32' }] },
+      ],
+    },
+    Lock: {
+      type: 'struct',
+      fields: [
+        { name: 'until', type: 'u64' },
+        { name: 'beneficiary', type: 'Address' },
+      ],
+    },
+  },
+});
+
+describe('generateFuzzTarget', () => {
+  it('fuzzes every endpoint of a token with a supply invariant', () => {
+    const generated = generateFuzzTarget(TOKEN);
+    expect(generated).toMatchObject({
+      crateName: 'fuzz-token-fuzz',
+      directory: 'fuzz',
+      target: 'endpoints',
+      endpoints: ['mint', 'transfer'],
+      skipped: [],
+      invariants: ['supply_equals_balances'],
+    });
+    expect(Object.keys(generated.files).sort()).toEqual([
+      '.gitignore',
+      'Cargo.toml',
+      'fuzz_targets/endpoints.rs',
+      'src/codec.rs',
+      'src/harness.rs',
+      'src/lib.rs',
+    ]);
+
+    const target = generated.files['fuzz_targets/endpoints.rs'];
+    expect(target).toContain('    Mint { caller: Caller, to: Addr, amount: Big },');
+    expect(target).toContain('    Transfer { caller: Caller, to: Addr, amount: Big },');
+    expect(target).toContain(
+      '("transfer", call(world, *caller, "transfer", &args, &Payment::None))'
+    );
+    expect(target).toContain('struct Init {\n    initial_supply: Big,\n}');
+    expect(target).toContain('let supply = query_biguint(world, "totalSupply", &[]);');
+    expect(target).toContain('sum += query_biguint(world, "balanceOf", &[address(name)]);');
+    expect(target).toContain('    supply_equals_balances(world);');
+
+    expect(generated.files['Cargo.toml']).toContain('[dependencies.fuzz_token]\npath = ".."');
+    expect(generated.files['src/lib.rs']).toContain('pub const CONTRACT: &str = "sc:fuzz_token";');
+    expect(generated.files['src/lib.rs']).toContain('"file:output/fuzz-token.wasm"');
+  });
+
+  it('renders custom types and payments, skipping endpoints it cannot fuzz', () => {
+    const generated = generateFuzzTarget(VAULT);
+    expect(generated.target).toBe('deposit');
+    expect(generated.endpoints).toEqual(['deposit']);
+    expect(generated.skipped).toEqual([
+      { endpoint: 'setHook', reason: 'ABI type Hook cannot be fuzzed' },
+    ]);
+    expect(generated.invariants).toEqual([]);
+
+    const target = generated.files['fuzz_targets/deposit.rs'];
+    expect(target).toContain(
+      '    Deposit { caller: Caller, kind: Kind, lock: Option<Lock>, payment: Payment },'
+    );
+    expect(target).toContain('pub enum Kind {\n    Daily,\n    Fixed { days: u32 },\n}');
+    expect(target).toContain('            Kind::Daily => 0u8.top_encode(),');
+    expect(target).toContain(
+      'pub struct Lock {\n    pub until: u64,\n    pub beneficiary: Addr,\n}'
+    );
+    expect(target).toContain('if let Some(value) = lock {');
+    expect(target).toContain('const INIT_ARGS: &[&str] = &[];');
+    expect(target).toContain('fn check_invariants(_world: &mut ScenarioWorld) {\n}');
+
+    expect(() => generateFuzzTarget(VAULT, { endpoints: ['setHook'] })).toThrow(
+      'ABI type Hook cannot be fuzzed'
+    );
+  });
+
+  it('deploys with fixed arguments and runs custom invariants', () => {
+    const generated = generateFuzzTarget(TOKEN, {
+      endpoints: ['transfer'],
+      deployArgs: ['1000'],
+      tokens: ['USDT-1A2B'],
+      maxCalls: 4,
+      invariants: [
+        {
+          name: 'owner_keeps_balance',
+          description: 'The owner never ends up with nothing',
+          body: 'if query_biguint(world, "balanceOf", &[address(ACCOUNTS[0])]) == BigUint::default() {\n    fail("owner drained");\n}',
+        },
+      ],
+    });
+    expect(generated.target).toBe('transfer');
+    expect(generated.invariants).toEqual(['supply_equals_balances', 'owner_keeps_balance']);
+
+    const target = generated.files['fuzz_targets/transfer.rs'];
+    expect(target).toContain('const INIT_ARGS: &[&str] = &["03e8"];');
+    expect(target).not.toContain('struct Init');
+    expect(target).toContain('const MAX_CALLS: usize = 4;');
+    expect(target).toContain(
+      '/// The owner never ends up with nothing\nfn owner_keeps_balance(world: &mut ScenarioWorld) {\n    if query_biguint'
+    );
+    expect(generated.files['src/lib.rs']).toContain('pub const TOKENS: &[&str] = &["USDT-1A2B"];');
+
+    expect(() =>
+      generateFuzzTarget(TOKEN, { invariants: [{ name: 'Bad Name', body: '' }] })
+    ).toThrow('must be a snake_case Rust identifier');
+  });
+});
@@ -0,0 +1,640 @@
+/**
+ * Generate a cargo-fuzz crate for a contract from its ABI: a libFuzzer target
+ * whose input (derived with `arbitrary`) is the constructor arguments and a
+ * sequence of endpoint calls with fuzzed callers, arguments and payments, run
+ * against the Rust VM of klever-sc-scenario. After every call the target
+ * checks that the call succeeded or failed with a user error, then runs the
+ * invariant hooks; a token ABI with supply and balance views gets a
+ * supply-equals-balances invariant.
+ */
+
+import type { Abi, AbiEndpoint, AbiField, AbiInput, AbiTypeDef } from '../abi/index.js';
+import type { FileMap } from '../scaffold/types.js';
+import { KLEVER_SC_VERSION } from '../scaffold/framework-version.js';
+import { escapeRustString } from '../scaffold/rust.js';
+import { encodeArguments } from './abi-codec.js';
+import { parseAbiType, type AbiArgs } from './abi-values.js';
+import { FUZZ_CODEC_RS, FUZZ_HARNESS_RS } from './fuzz-runtime.js';
+import { rustIdentifier } from './interactor.js';
+import { camelToSnake } from './rust-source.js';
+
+export interface FuzzInvariant {
+  /** Rust function name (snake_case) */
+  name: string;
+  /**
+   * Rust statements run after the deploy and every call, with `world` the
+   * scenario world; `query`, `query_biguint`, `address`, `fail`, `ACCOUNTS`
+   * and `CONTRACT` come from the fuzz crate
+   */
+  body: string;
+  /** What the check asserts, written as its doc comment */
+  description?: string;
+}
+
+export interface FuzzOptions {
+  /** Endpoints the calls pick from (default: every endpoint that is not a view) */
+  endpoints?: string[];
+  /** Constructor arguments, in ABI order or keyed by name; fuzzed when left out */
+  deployArgs?: AbiArgs;
+  /** Checks run after every call, next to the ones derived from the ABI */
+  invariants?: FuzzInvariant[];
+  /** Token identifiers the accounts hold, for KDA payments and token arguments */
+  tokens?: string[];
+  /** Most calls one input makes after the deploy (default: 16) */
+  maxCalls?: number;
+  /** Fuzz target name (default: the endpoint when fuzzing one, otherwise `endpoints`) */
+  target?: string;
+}
+
+export interface GeneratedFuzzTarget {
+  crateName: string;
+  /** Crate directory, relative to the contract project root */
+  directory: string;
+  /** Crate files, relative to `directory` */
+  files: FileMap;
+  /** Name to pass to `cargo fuzz run` */
+  target: string;
+  /** Endpoints the calls pick from */
+  endpoints: string[];
+  /** Endpoints left out because an argument type cannot be fuzzed */
+  skipped: { endpoint: string; reason: string }[];
+  /** Invariant checks run after every call */
+  invariants: string[];
+}
+
+const DEFAULT_TOKENS = ['FUZZ-0001'];
+const DEFAULT_MAX_CALLS = 16;
+
+/** Names the fuzz crate defines; ABI types with one of them are renamed */
+const HARNESS_TYPES = [
+  'Addr',
+  'Big',
+  'BigSigned',
+  'BigUint',
+  'Call',
+  'Caller',
+  'Encode',
+  'Init',
+  'Input',
+  'Payment',
+  'ScenarioWorld',
+  'Token',
+  'TxResponse',
+];
+
+const MAX_TUPLE = 6;
+
+function pascalCase(name: string): string {
+  return camelToSnake(name.replace(/[-\s]+/g, '_'))
+    .split('_')
+    .filter(Boolean)
+    .map(part => part.charAt(0).toUpperCase() + part.slice(1))
+    .join('');
+}
+
+const isTupleFields = (fields: AbiField[]) => fields.every(f => /^\d+$/.test(f.name));
+
+/** Rust names of the ABI types the fuzz input uses */
+class FuzzTypes {
+  readonly used = new Set<string>();
+
+  constructor(private readonly abi: Abi) {}
+
+  rustName(name: string): string {
+    return HARNESS_TYPES.includes(name) ? `Abi${name}` : name;
+  }
+
+  /** Fuzzed Rust type of ABI `type`; throws when it has none */
+  rustType(type: string): string {
+    const { name, args } = parseAbiType(type);
+    const inner = (arg: string) => this.rustType(arg);
+    if (/^[ui](8|16|32|64)$/.test(name) || name === 'bool') return name;
+    switch (name) {
+      case 'usize':
+        return 'u32';
+      case 'isize':
+        return 'i32';
+      case 'BigUint':
+        return 'Big';
+      case 'BigInt':
+        return 'BigSigned';
+      case 'Address':
+        return 'Addr';
+      case 'TokenIdentifier':
+        return 'Token';
+      case 'bytes':
+        return 'Vec<u8>';
+      case 'utf8string':
+        return 'String';
+      case 'Option':
+      case 'optional':
+        return `Option<${inner(args[0])}>`;
+      case 'List':
+      case 'variadic':
+        return `Vec<${inner(args[0])}>`;
+      case 'tuple':
+      case 'multi':
+        if (args.length > MAX_TUPLE) throw new Error(`${type} has more than ${MAX_TUPLE} items`);
+        return `(${args.map(inner).join(', ')}${args.length === 1 ? ',' : ''})`;
+    }
+    const array = /^array(\d+)$/.exec(name);
+    if (array) return `[${inner(args[0])}; ${array[1]}]`;
+    const def = this.abi.types?.[name];
+    if (!def) throw new Error(`ABI type ${type} cannot be fuzzed`);
+    if (!this.used.has(name)) {
+      this.used.add(name);
+      const fields =
+        def.type === 'struct' ? def.fields : def.variants.flatMap(v => v.fields || []);
+      for (const field of fields) this.rustType(field.type);
+    }
+    return this.rustName(name);
+  }
+}
+
+/** Statements pushing the top encoding of `value` (of ABI `type`) onto `args` */
+function pushArgument(value: string, type: string, indent: string, depth = 0): string[] {
+  const { name, args } = parseAbiType(type);
+  const suffix = depth === 0 ? '' : String(depth);
+  const nested = (inner: string, innerType: string) =>
+    pushArgument(inner, innerType, `${indent}    `, depth + 1);
+  switch (name) {
+    case 'optional':
+      return [
+        `${indent}if let Some(value${suffix}) = ${value} {`,
+        ...nested(`value${suffix}`, args[0]),
+        `${indent}}`,
+      ];
+    case 'variadic':
+      return [
+        `${indent}for item${suffix} in ${value} {`,
+        ...nested(`item${suffix}`, args[0]),
+        `${indent}}`,
+      ];
+    case 'multi': {
+      const parts = args.map((_, i) => `part${suffix}_${i}`);
+      return [
+        `${indent}let (${parts.join(', ')}${parts.length === 1 ? ',' : ''}) = ${value};`,
+        ...args.flatMap((arg, i) => pushArgument(parts[i], arg, indent, depth + 1)),
+      ];
+    }
+    default:
+      return [`${indent}args.push(${value}.top_encode());`];
+  }
+}
+
+function renderStruct(name: string, fields: AbiField[], types: FuzzTypes): string {
+  const tupleFields = fields.length > 0 && isTupleFields(fields);
+  const names = fields.map((f, i) => (tupleFields ? String(i) : rustIdentifier(f.name)));
+  const declaration =
+    fields.length === 0
+      ? `pub struct ${name};`
+      : tupleFields
+        ? `pub struct ${name}(${fields.map(f => `pub ${types.rustType(f.type)}`).join(', ')});`
+        : [
+            `pub struct ${name} {`,
+            ...fields.map((f, i) => `    pub ${names[i]}: ${types.rustType(f.type)},`),
+            '}',
+          ].join('\n');
+  return [
+    '#[derive(Arbitrary, Debug)]',
+    declaration,
+    '',
+    `impl Encode for ${name} {`,
+    `    fn dep_encode(&self, ${fields.length > 0 ? 'out' : '_out'}: &mut Vec<u8>) {`,
+    ...names.map(field => `        self.${field}.dep_encode(out);`),
+    '    }',
+    '}',
+  ].join('\n');
+}
+
+function renderEnum(
+  name: string,
+  def: Extract<AbiTypeDef, { type: 'enum' }>,
+  types: FuzzTypes
+): string {
+  const variants = [...def.variants].sort((a, b) => a.discriminant - b.discriminant);
+  const patterns = variants.map(variant => {
+    const fields = variant.fields || [];
+    const path = `${name}::${variant.name}`;
+    const tupleFields = isTupleFields(fields);
+    const bindings = fields.map((f, i) => (tupleFields ? `f${i}` : rustIdentifier(f.name)));
+    const list = bindings.join(', ');
+    const pattern =
+      fields.length === 0 ? path : tupleFields ? `${path}(${list})` : `${path} { ${list} }`;
+    const fieldTypes = fields.map(f => types.rustType(f.type));
+    const named = bindings.map((binding, i) => `${binding}: ${fieldTypes[i]}`);
+    const declaration =
+      fields.length === 0
+        ? `    ${variant.name},`
+        : tupleFields
+          ? `    ${variant.name}(${fieldTypes.join(', ')}),`
+          : `    ${variant.name} { ${named.join(', ')} },`;
+    return { variant, pattern, bindings, declaration };
+  });
+
+  const encodeArms = patterns.flatMap(({ variant, pattern, bindings }) =>
+    bindings.length === 0
+      ? [`            ${pattern} => out.push(${variant.discriminant}),`]
+      : [
+          `            ${pattern} => {`,
+          `                out.push(${variant.discriminant});`,
+          ...bindings.map(binding => `                ${binding}.dep_encode(out);`),
+          '            }',
+        ]
+  );
+  const units = patterns.filter(({ bindings }) => bindings.length === 0);
+  // Field-less variants encode like a u8 discriminant at the top level
+  const topEncode =
+    units.length === 0
+      ? []
+      : [
+          '',
+          '    fn top_encode(&self) -> Vec<u8> {',
+          '        match self {',
+          ...units.map(
+            ({ variant, pattern }) =>
+              `            ${pattern} => ${variant.discriminant}u8.top_encode(),`
+          ),
+          ...(units.length < patterns.length ? ['            _ => encode_nested(self),'] : []),
+          '        }',
+          '    }',
+        ];
+
+  return [
+    '#[derive(Arbitrary, Debug)]',
+    `pub enum ${name} {`,
+    ...patterns.map(p => p.declaration),
+    '}',
+    '',
+    `impl Encode for ${name} {`,
+    '    fn dep_encode(&self, out: &mut Vec<u8>) {',
+    '        match self {',
+    ...encodeArms,
+    '        }',
+    '    }',
+    ...topEncode,
+    '}',
+  ].join('\n');
+}
+
+function renderTypes(types: FuzzTypes, abi: Abi): string[] {
+  // Rendering a type can reach types not seen yet; repeat until none are new
+  const rendered = new Map<string, string>();
+  while (rendered.size < types.used.size) {
+    for (const name of [...types.used]) {
+      if (rendered.has(name)) continue;
+      const def = abi.types![name];
+      const rustName = types.rustName(name);
+      rendered.set(
+        name,
+        def.type === 'struct'
+          ? renderStruct(rustName, def.fields, types)
+          : renderEnum(rustName, def, types)
+      );
+    }
+  }
+  return [...rendered.keys()].sort().map(name => rendered.get(name)!);
+}
+
+interface FuzzedCall {
+  endpoint: AbiEndpoint;
+  variant: string;
+  fields: { name: string; input: AbiInput; type: string }[];
+  payable: boolean;
+}
+
+/** A call of `endpoint` as an enum variant of the fuzz input; throws when it cannot be fuzzed */
+function fuzzedCall(endpoint: AbiEndpoint, variant: string, types: FuzzTypes): FuzzedCall {
+  const taken = ['caller', 'payment', 'args', 'world'];
+  const fields = endpoint.inputs.map(input => {
+    const name = rustIdentifier(input.name, taken);
+    taken.push(name);
+    return { name, input, type: types.rustType(input.type) };
+  });
+  return { endpoint, variant, fields, payable: (endpoint.payableInTokens || []).length > 0 };
+}
+
+function renderCalls(calls: FuzzedCall[]): string[] {
+  const declarations = calls.map(call => {
+    const fields = [
+      'caller: Caller',
+      ...call.fields.map(f => `${f.name}: ${f.type}`),
+      ...(call.payable ? ['payment: Payment'] : []),
+    ];
+    return `    ${call.variant} { ${fields.join(', ')} },`;
+  });
+  const arms = calls.flatMap(call => {
+    const bindings = ['caller', ...call.fields.map(f => f.name)];
+    if (call.payable) bindings.push('payment');
+    const payment = call.payable ? 'payment' : '&Payment::None';
+    const name = escapeRustString(call.endpoint.name);
+    return [
+      `            Call::${call.variant} { ${bindings.join(', ')} } => {`,
+      ...call.fields.flatMap(f => pushArgument(f.name, f.input.type, '                ')),
+      `                ("${name}", call(world, *caller, "${name}", &args, ${payment}))`,
+      '            }',
+    ];
+  });
+  const usesArgs = calls.some(call => call.fields.length > 0);
+  return [
+    '/// One call of the sequence: the endpoint with its caller, arguments and payment',
+    '#[derive(Arbitrary, Debug)]',
+    'enum Call {',
+    ...declarations,
+    '}',
+    '',
+    'impl Call {',
+    '    fn run(&self, world: &mut ScenarioWorld) -> (&\'static str, TxResponse) {',
+    `        let ${usesArgs ? 'mut ' : ''}args: Vec<Vec<u8>> = Vec::new();`,
+    '        match self {',
+    ...arms,
+    '        }',
+    '    }',
+    '}',
+  ];
+}
+
+/** supply == sum of balances, when the ABI has a supply view and a balance-by-address view */
+function supplyInvariant(abi: Abi): FuzzInvariant | undefined {
+  const views = abi.endpoints.filter(e => e.mutability === 'readonly');
+  const returnsBigUint = (view: AbiEndpoint) =>
+    view.outputs.length === 1 && view.outputs[0].type === 'BigUint';
+  const supply = views.find(
+    view => view.inputs.length === 0 && returnsBigUint(view) && /supply/i.test(view.name)
+  );
+  const balance = views.find(
+    view =>
+      view.inputs.length === 1 &&
+      view.inputs[0].type === 'Address' &&
+      returnsBigUint(view) &&
+      /balance/i.test(view.name)
+  );
+  if (!supply || !balance) return undefined;
+  const supplyName = escapeRustString(supply.name);
+  const balanceName = escapeRustString(balance.name);
+  return {
+    name: 'supply_equals_balances',
+    description: `${supply.name} equals the sum of ${balance.name} over the accounts and contract`,
+    body: [
+      `let supply = query_biguint(world, "${supplyName}", &[]);`,
+      'let mut sum = BigUint::default();',
+      'for name in ACCOUNTS.iter().chain([CONTRACT].iter()) {',
+      `    sum += query_biguint(world, "${balanceName}", &[address(name)]);`,
+      '}',
+      'if supply != sum {',
+      `    fail(&format!("${supplyName} is {} but ${balanceName} adds up to {}", supply, sum));`,
+      '}',
+    ].join('\n'),
+  };
+}
+
+function renderInvariant(invariant: FuzzInvariant): string {
+  const body = invariant.body
+    .trim()
+    .split('\n')
+    .map(line => (line.trim() ? `    ${line}` : ''));
+  return [
+    ...(invariant.description ? [`/// ${invariant.description}`] : []),
+    `fn ${invariant.name}(world: &mut ScenarioWorld) {`,
+    ...body,
+    '}',
+  ].join('\n');
+}
+
+function renderLib(abi: Abi, crate: string, wasm: string, tokens: string[]): string {
+  const contract = `sc:${camelToSnake(abi.name)}`;
+  return `//! Fuzzing support for the ${abi.name} contract: argument encoding (codec.rs)
+//! and the scenario world the fuzz targets drive (harness.rs).
+
+pub mod codec;
+pub mod harness;
+
+pub use codec::*;
+pub use harness::*;
+pub use klever_sc_scenario::{scenario_model::TxResponse, ScenarioWorld};
+pub use num_bigint::BigUint;
+pub use ${crate}::ContractBuilder;
+
+/// Accounts calls come from; the first deploys the contract
+pub const ACCOUNTS: [&str; 4] = ["address:owner", "address:alice", "address:bob", "address:carol"];
+pub const CONTRACT: &str = "${escapeRustString(contract)}";
+/// Contract code, relative to the contract crate (build it with \`ksc all build\` first)
+pub const CODE: &str = "file:${escapeRustString(wasm)}";
+/// Tokens every account holds; KDA payments and token arguments pick from them
+pub const TOKENS: &[&str] = &[${tokens.map(t => `"${escapeRustString(t)}"`).join(', ')}];
+/// KLV and token balance of every account, more than any sequence of fuzzed payments spends
+pub const BALANCE: &str = "1000000000000000000000000000000";
+`;
+}
+
+function cargoToml(crateName: string, contractCrate: string, version: string, target: string) {
+  return `[package]
+name = "${crateName}"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[package.metadata]
+cargo-fuzz = true
+
+[lib]
+path = "src/lib.rs"
+
+[dependencies]
+arbitrary = { version = "1", features = ["derive"] }
+libfuzzer-sys = "0.4"
+num-bigint = "0.4"
+
+[dependencies.${contractCrate}]
+path = ".."
+
+[dependencies.klever-sc-scenario]
+version = "${version}"
+
+[[bin]]
+name = "${target}"
+path = "fuzz_targets/${target}.rs"
+test = false
+doc = false
+bench = false
+
+[workspace]
+members = ["."]
+`;
+}
+
+/** Render the fuzz crate for `abi` */
+export function generateFuzzTarget(abi: Abi, options: FuzzOptions = {}): GeneratedFuzzTarget {
+  const contractCrate = abi.buildInfo?.contractCrate?.name || camelToSnake(abi.name);
+  const crateName = `${contractCrate.replace(/_/g, '-')}-fuzz`;
+  const tokens = options.tokens && options.tokens.length > 0 ? options.tokens : DEFAULT_TOKENS;
+  const maxCalls = options.maxCalls ?? DEFAULT_MAX_CALLS;
+  if (!Number.isInteger(maxCalls) || maxCalls < 1) {
+    throw new Error('maxCalls must be a positive integer');
+  }
+
+  const types = new FuzzTypes(abi);
+  const calls: FuzzedCall[] = [];
+  const skipped: { endpoint: string; reason: string }[] = [];
+  const variants: string[] = [];
+  const variantName = (endpoint: string) => {
+    let name = pascalCase(endpoint) || 'Endpoint';
+    while (variants.includes(name)) name = `${name}_`;
+    variants.push(name);
+    return name;
+  };
+
+  if (options.endpoints && options.endpoints.length > 0) {
+    for (const name of options.endpoints) {
+      const endpoint = abi.endpoints.find(e => e.name === name);
+      if (!endpoint) throw new Error(`Endpoint ${name} not found in the ABI`);
+      calls.push(fuzzedCall(endpoint, variantName(name), types));
+    }
+  } else {
+    for (const endpoint of abi.endpoints.filter(e => e.mutability !== 'readonly')) {
+      // Check the argument types before taking a variant name or marking types used
+      try {
+        fuzzedCall(endpoint, '', new FuzzTypes(abi));
+      } catch (error) {
+        skipped.push({ endpoint: endpoint.name, reason: (error as Error).message });
+        continue;
+      }
+      calls.push(fuzzedCall(endpoint, variantName(endpoint.name), types));
+    }
+  }
+  if (calls.length === 0) throw new Error('The ABI has no endpoints to fuzz');
+
+  const target =
+    options.target || (calls.length === 1 ? camelToSnake(calls[0].endpoint.name) : 'endpoints');
+  if (!/^[a-z][a-z0-9_]*$/.test(target)) {
+    throw new Error(`Fuzz target name ${target} must be snake_case`);
+  }
+
+  // Constructor: fixed encoded arguments, or fuzzed fields of the input
+  const constructorInputs = abi.constructor?.inputs || [];
+  const fixedInit = options.deployArgs !== undefined || constructorInputs.length === 0;
+  const initArgs = fixedInit
+    ? encodeArguments(constructorInputs, options.deployArgs, abi, 'the constructor')
+    : [];
+  const initFields = fixedInit
+    ? []
+    : constructorInputs.map(input => ({
+        name: rustIdentifier(input.name),
+        input,
+        type: types.rustType(input.type),
+      }));
+
+  const invariants: FuzzInvariant[] = [];
+  const derived = supplyInvariant(abi);
+  if (derived) invariants.push(derived);
+  for (const invariant of options.invariants || []) {
+    if (!/^[a-z][a-z0-9_]*$/.test(invariant.name)) {
+      throw new Error(`Invariant name ${invariant.name} must be a snake_case Rust identifier`);
+    }
+    if (invariants.some(i => i.name === invariant.name)) {
+      throw new Error(`Invariant ${invariant.name} is defined twice`);
+    }
+    invariants.push(invariant);
+  }
+
+  const hexArgs = initArgs.map(arg => `"${arg.toString('hex')}"`);
+  const init = fixedInit
+    ? [
+        '/// Constructor arguments, top-level encoded',
+        `const INIT_ARGS: &[&str] = &[${hexArgs.join(', ')}];`,
+      ]
+    : [
+        '/// Constructor arguments',
+        '#[derive(Arbitrary, Debug)]',
+        'struct Init {',
+        ...initFields.map(f => `    ${f.name}: ${f.type},`),
+        '}',
+        '',
+        'impl Init {',
+        '    fn args(&self) -> Vec<Vec<u8>> {',
+        `        let Init { ${initFields.map(f => f.name).join(', ')} } = self;`,
+        '        let mut args = Vec::new();',
+        ...initFields.flatMap(f => pushArgument(f.name, f.input.type, '        ')),
+        '        args',
+        '    }',
+        '}',
+      ];
+  const deployArgs = fixedInit
+    ? 'INIT_ARGS.iter().map(|arg| from_hex(arg)).collect::<Vec<_>>()'
+    : 'input.init.args()';
+
+  const library = crateName.replace(/-/g, '_');
+  const typeDeclarations = renderTypes(types, abi);
+  const source = [
+    `//! Fuzz target for the ${abi.name} contract: each input deploys it, then makes`,
+    '//! a sequence of calls with fuzzed callers, arguments and payments, checking the',
+    '//! invariants after every call.',
+    `//! Run it from the contract crate with \`cargo fuzz run ${target}\`.`,
+    '',
+    '#![no_main]',
+    '',
+    'use arbitrary::Arbitrary;',
+    'use libfuzzer_sys::fuzz_target;',
+    `use ${library}::*;`,
+    '',
+    '/// Most calls one input makes after the deploy',
+    `const MAX_CALLS: usize = ${maxCalls};`,
+    '',
+    ...typeDeclarations.flatMap(declaration => [declaration, '']),
+    ...init,
+    '',
+    ...renderCalls(calls),
+    '',
+    '#[derive(Arbitrary, Debug)]',
+    'struct Input {',
+    ...(fixedInit ? [] : ['    init: Init,']),
+    '    calls: Vec<Call>,',
+    '}',
+    '',
+    '/// Checks run after the deploy and after every call; add your own here',
+    `fn check_invariants(${invariants.length > 0 ? 'world' : '_world'}: &mut ScenarioWorld) {`,
+    ...invariants.map(invariant => `    ${invariant.name}(world);`),
+    '}',
+    ...invariants.flatMap(invariant => ['', renderInvariant(invariant)]),
+    '',
+    'fuzz_target!(|input: Input| {',
+    '    quiet_panics();',
+    '    let mut world = world();',
+    `    if !deploy(&mut world, &${deployArgs}) {`,
+    '        return;',
+    '    }',
+    '    check_invariants(&mut world);',
+    '    for call in input.calls.iter().take(MAX_CALLS) {',
+    '        let (endpoint, response) = call.run(&mut world);',
+    '        check_status(endpoint, &response, call);',
+    '        check_invariants(&mut world);',
+    '    }',
+    '});',
+    '',
+  ].join('\n');
+
+  const wasm = `output/${contractCrate.replace(/_/g, '-')}.wasm`;
+  const files: FileMap = {
+    'Cargo.toml': cargoToml(
+      crateName,
+      contractCrate,
+      abi.buildInfo?.framework?.version || KLEVER_SC_VERSION,
+      target
+    ),
+    'src/lib.rs': renderLib(abi, contractCrate.replace(/-/g, '_'), wasm, tokens),
+    'src/codec.rs': FUZZ_CODEC_RS,
+    'src/harness.rs': FUZZ_HARNESS_RS,
+    [`fuzz_targets/${target}.rs`]: source,
+    '.gitignore': '/target\n/corpus\n/artifacts\n/coverage\n',
+  };
+
+  return {
+    crateName,
+    directory: 'fuzz',
+    files,
+    target,
+    endpoints: calls.map(c => c.endpoint.name),
+    skipped,
+    invariants: invariants.map(i => i.name),
+  };
+}
@@ -0,0 +1,122 @@
+import { parseAbi } from '../abi/index.js';
+import { generateGraphql } from './graphql.js';
+import type { StorageSlot } from './storage-layout.js';
+
+const ABI = parseAbi({
+  name: 'token_vault',
+  endpoints: [
+    {
+      name: 'deposit',
+      mutability: 'mutable',
+      inputs: [{ name: 'lock_until', type: 'optional<u64>' }],
+      outputs: [],
+    },
+    {
+      name: 'getPosition',
+      docs: ['Position of an account'],
+      mutability: 'readonly',
+      inputs: [{ name: 'owner', type: 'Address' }],
+      outputs: [{ type: 'Option<Position>' }],
+    },
+    {
+      name: 'getLimits',
+      mutability: 'readonly',
+      inputs: [{ name: 'kind', type: 'Kind' }],
+      outputs: [{ type: 'u32' }, { type: 'u32' }],
+    },
+  ],
+  events: [
+    {
+      identifier: 'deposit',
+      inputs: [
+        { name: 'caller', type: 'Address', indexed: true },
+        { name: 'amount', type: 'BigUint' },
+      ],
+    },
+  ],
+  types: {
+    Position: {
+      type: 'struct',
+      fields: [
+        { name: 'amount', type: 'BigUint' },
+        { name: 'status', type: 'Status' },
+      ],
+    },
+    Status: {
+      type: 'enum',
+      variants: [
+        { name: 'Open', discriminant: 0 },
+        { name: 'Locked', discriminant: 1, fields: [{ name: 'until', type: 'u64' }] },
+      ],
+    },
+    Kind: {
+      type: 'enum',
+      variants: [
+        { name: 'Daily', discriminant: 0 },
+        { name: 'Weekly', discriminant: 1 },
+      ],
+    },
+  },
+});
+
+const slot = (functionName: string, mapper: string, typeArgs: string[], keyParams = []) =>
+  ({ functionName, storageKey: functionName, mapper, keyParams, typeArgs }) as StorageSlot;
+
+const STORAGE: StorageSlot[] = [
+  {
+    ...slot('balance', 'SingleValueMapper', ['BigUint']),
+    keyParams: [{ name: 'account', type: 'Address' }],
+  },
+  slot('positions', 'MapMapper', ['Address', 'Position']),
+  slot('holders', 'UnorderedSetMapper', ['Address']),
+  slot('owners', 'UserMapper', []),
+];
+
+describe('generateGraphql', () => {
+  const generated = generateGraphql(ABI, { storage: STORAGE });
+  const schema = generated.files['schema.graphql'];
+  const resolvers = generated.files['resolvers.ts'];
+
+  it('queries views and storage mappers', () => {
+    expect(generated.queries).toEqual(['getPosition', 'getLimits']);
+    expect(generated.storage).toEqual(['balance', 'positions', 'holders']);
+    expect(generated.skipped).toEqual(['owners: UserMapper is not supported']);
+    expect(schema).toContain(
+      '  """Position of an account"""\n  getPosition(owner: Address!): Position\n  getLimits(kind: Kind!): JSON\n'
+    );
+    expect(schema).toContain(
+      'type Storage {\n  balance(account: Address!): BigInt!\n  positions: [PositionsEntry!]!\n  holders: [Address!]!\n}'
+    );
+    expect(schema).toContain('type PositionsEntry {\n  key: Address!\n  value: Position!\n}');
+  });
+
+  it('declares events and custom types', () => {
+    expect(generated.subscriptions).toEqual(['deposit']);
+    expect(schema).toContain('type Subscription {\n  deposit: DepositEvent!\n}');
+    expect(schema).toContain('type DepositEvent {\n  caller: Address!\n  amount: BigInt!\n}');
+    expect(schema).toContain('enum Kind {\n  Daily\n  Weekly\n}');
+    // Enums with fields name the variant and carry the fields of the variant they are
+    expect(schema).toContain('type Status {\n  variant: String!\n  Locked: StatusLocked\n}');
+  });
+
+  it('proxies every field to the query tools', () => {
+    expect(resolvers).toContain(
+      "      getPosition: (_: unknown, args: Args) => view('getPosition', args, true),"
+    );
+    expect(resolvers).toContain(
+      "      balance: (_: unknown, args: Args) => single('balance', args),"
+    );
+    expect(resolvers).toContain("      positions: () => pairs('positions', undefined, 'mapped'),");
+    expect(resolvers).toContain(
+      "      deposit: { subscribe: () => events('deposit'), resolve: eventFields },"
+    );
+    expect(resolvers).toContain("      Locked: (value: unknown) => fieldsOf(value, 'Locked'),");
+    expect(resolvers).toContain("callTool('get_contract_storage', {");
+    expect(resolvers).not.toContain('${');
+  });
+
+  it('needs something to query', () => {
+    const writeOnly = parseAbi({ name: 'sink', endpoints: [ABI.endpoints[0]] });
+    expect(() => generateGraphql(writeOnly)).toThrow('no views and no supported storage');
+  });
+});
@@ -0,0 +1,392 @@
+/**
+ * Generate a GraphQL schema for a contract from its ABI and storage mappers:
+ * a Query field per view, a Storage field per supported mapper and a
+ * Subscription field per event, with object types for the custom structs and
+ * enums. The resolver stub next to it answers every field through the query
+ * tools of this server (query_view, get_contract_storage, subscribe_events),
+ * called with an MCP client's callTool.
+ */
+
+import type { Abi, AbiEvent, AbiTypeDef } from '../abi/index.js';
+import type { FileMap } from '../scaffold/types.js';
+import { parseAbiType } from './abi-values.js';
+import { snakeToCamel } from './rust-source.js';
+import type { StorageSlot } from './storage-layout.js';
+
+export interface GraphqlOptions {
+  /** Storage mappers of the contract, from `storageLayout` */
+  storage?: StorageSlot[];
+}
+
+export interface GeneratedGraphql {
+  /** Directory the files are meant for, relative to the API project */
+  directory: string;
+  /** schema.graphql and resolvers.ts */
+  files: FileMap;
+  /** Query fields, one per view */
+  queries: string[];
+  /** Storage fields, one per supported mapper */
+  storage: string[];
+  /** Subscription fields, one per event */
+  subscriptions: string[];
+  /** Custom types declared in the schema */
+  types: string[];
+  /** Storage mappers left out, with the reason */
+  skipped: string[];
+}
+
+type Direction = 'input' | 'output';
+
+const SMALL_INTEGERS = ['u8', 'u16', 'u32', 'usize', 'i8', 'i16', 'i32', 'isize'];
+const LARGE_INTEGERS = ['u64', 'i64', 'BigUint', 'BigInt'];
+
+/** How a collection mapper's entries become a list: the internal entry and the resolver helper */
+const COLLECTIONS: Record<string, { entry?: string; helper: string }> = {
+  VecMapper: { entry: 'item', helper: 'items' },
+  UnorderedSetMapper: { entry: 'item', helper: 'items' },
+  SetMapper: { entry: 'value', helper: 'items' },
+  MapMapper: { entry: 'mapped', helper: 'pairs' },
+  WhitelistMapper: { helper: 'keys' },
+};
+
+function pascalCase(name: string): string {
+  const camel = snakeToCamel(name.replace(/[-\s]+/g, '_'));
+  return camel.charAt(0).toUpperCase() + camel.slice(1);
+}
+
+/** A GraphQL name for an ABI name */
+const fieldName = (name: string) => name.replace(/\W/g, '_').replace(/^(\d)/, '_$1');
+
+function description(docs: string[], indent: string): string[] {
+  const lines = docs.map(d => d.trim().replace(/"""/g, '\\"""')).filter(Boolean);
+  if (lines.length === 0) return [];
+  if (lines.length === 1) return [`${indent}"""${lines[0]}"""`];
+  return [`${indent}"""`, ...lines.map(line => `${indent}${line}`), `${indent}"""`];
+}
+
+const isFieldless = (def: AbiTypeDef) =>
+  def.type === 'enum' && def.variants.every(v => !v.fields || v.fields.length === 0);
+
+/** GraphQL type of ABI `type`, nullable only for Option and optional */
+function graphqlType(type: string, direction: Direction, abi: Abi): string {
+  const { name, args } = parseAbiType(type);
+  if (name === 'Option' || name === 'optional') {
+    return graphqlType(args[0], direction, abi).replace(/!$/, '');
+  }
+  return `${namedType(name, args, direction, abi)}!`;
+}
+
+function namedType(name: string, args: string[], direction: Direction, abi: Abi): string {
+  if (SMALL_INTEGERS.includes(name)) return 'Int';
+  if (LARGE_INTEGERS.includes(name)) return 'BigInt';
+  switch (name) {
+    case 'bool':
+      return 'Boolean';
+    case 'Address':
+      return 'Address';
+    case 'bytes':
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return 'String';
+    case 'List':
+    case 'variadic':
+      return `[${graphqlType(args[0], direction, abi)}]`;
+  }
+  if (/^array\d+$/.test(name)) {
+    return args[0] === 'u8' ? 'String' : `[${graphqlType(args[0], direction, abi)}]`;
+  }
+  const def = abi.types?.[name];
+  // Inputs of custom types are plain JSON, as the query tools take them
+  if (def && (direction === 'output' || isFieldless(def))) return name;
+  return 'JSON';
+}
+
+function renderType(name: string, def: AbiTypeDef, abi: Abi): string[] {
+  const docs = description(def.docs || [], '');
+  const field = (f: { name: string; type: string }) =>
+    `  ${fieldName(f.name)}: ${graphqlType(f.type, 'output', abi)}`;
+  if (def.type === 'struct') {
+    return [...docs, `type ${name} {`, ...def.fields.map(field), '}'];
+  }
+  if (isFieldless(def)) {
+    return [...docs, `enum ${name} {`, ...def.variants.map(v => `  ${v.name}`), '}'];
+  }
+  // Enums with fields: the variant name, and the fields of the variant the value is
+  const fielded = def.variants.filter(v => v.fields && v.fields.length > 0);
+  return [
+    ...docs,
+    `type ${name} {`,
+    '  variant: String!',
+    ...fielded.map(v => `  ${v.name}: ${name}${v.name}`),
+    '}',
+    ...fielded.flatMap(v => ['', `type ${name}${v.name} {`, ...(v.fields || []).map(field), '}']),
+  ];
+}
+
+function argumentList(inputs: { name: string; type: string }[], abi: Abi): string {
+  if (inputs.length === 0) return '';
+  const args = inputs.map(i => `${fieldName(i.name)}: ${graphqlType(i.type, 'input', abi)}`);
+  return `(${args.join(', ')})`;
+}
+
+interface StorageField {
+  slot: StorageSlot;
+  schema: string[];
+  resolver: string;
+  entryType?: string[];
+}
+
+function storageField(slot: StorageSlot, abi: Abi): StorageField | string {
+  const name = fieldName(slot.functionName);
+  const args = argumentList(slot.keyParams, abi);
+  const keyArgs = slot.keyParams.length > 0 ? 'args' : 'undefined';
+  const params = slot.keyParams.length > 0 ? '_: unknown, args: Args' : '';
+  const mapper = `'${slot.functionName}'`;
+  if (slot.mapper === 'SingleValueMapper') {
+    return {
+      slot,
+      schema: [`  ${name}${args}: ${graphqlType(slot.typeArgs[0], 'output', abi)}`],
+      resolver: `    ${name}: (${params}) => single(${mapper}, ${keyArgs}),`,
+    };
+  }
+  const collection = COLLECTIONS[slot.mapper];
+  if (!collection) return `${slot.functionName}: ${slot.mapper} is not supported`;
+  if (slot.typeArgs.length < (slot.mapper === 'MapMapper' ? 2 : 1)) {
+    return `${slot.functionName}: the ${slot.mapper} value types are unknown`;
+  }
+  const entry = collection.entry ? `'${collection.entry}'` : 'undefined';
+  const call = `${collection.helper}(${mapper}, ${keyArgs}, ${entry})`;
+  const resolver = `    ${name}: (${params}) => ${call},`;
+  if (slot.mapper !== 'MapMapper') {
+    const item = graphqlType(slot.typeArgs[0], 'output', abi);
+    return { slot, schema: [`  ${name}${args}: [${item}]!`], resolver };
+  }
+  const entryName = `${pascalCase(slot.functionName)}Entry`;
+  return {
+    slot,
+    schema: [`  ${name}${args}: [${entryName}!]!`],
+    resolver,
+    entryType: [
+      `type ${entryName} {`,
+      `  key: ${graphqlType(slot.typeArgs[0], 'output', abi)}`,
+      `  value: ${graphqlType(slot.typeArgs[1], 'output', abi)}`,
+      '}',
+    ],
+  };
+}
+
+function renderEvent(event: AbiEvent, abi: Abi): string[] {
+  return [
+    ...description(event.docs || [], ''),
+    `type ${pascalCase(event.identifier)}Event {`,
+    ...event.inputs.map(i => `  ${fieldName(i.name)}: ${graphqlType(i.type, 'output', abi)}`),
+    '}',
+  ];
+}
+
+/** Render schema.graphql and resolvers.ts for `abi` */
+export function generateGraphql(abi: Abi, options: GraphqlOptions = {}): GeneratedGraphql {
+  const definitions = abi.types || {};
+  const types = Object.keys(definitions);
+  const views = abi.endpoints.filter(e => e.mutability === 'readonly');
+  const events = abi.events || [];
+  const skipped: string[] = [];
+  const storage: StorageField[] = [];
+  for (const slot of options.storage || []) {
+    const field = storageField(slot, abi);
+    if (typeof field === 'string') skipped.push(field);
+    else storage.push(field);
+  }
+  if (views.length === 0 && storage.length === 0) {
+    throw new Error(`${abi.name} has no views and no supported storage mappers to query`);
+  }
+  if (storage.length > 0 && views.some(v => fieldName(v.name) === 'storage')) {
+    throw new Error('A view named "storage" clashes with the Storage field of Query');
+  }
+
+  const query = [
+    'type Query {',
+    ...views.flatMap(view => {
+      const outputs = view.outputs;
+      const result = outputs.length === 1 ? graphqlType(outputs[0].type, 'output', abi) : 'JSON';
+      return [
+        ...description(view.docs || [], '  '),
+        `  ${fieldName(view.name)}${argumentList(view.inputs, abi)}: ${result}`,
+      ];
+    }),
+    ...(storage.length > 0
+      ? ['  """Values of the storage mappers, read from contract storage"""', '  storage: Storage!']
+      : []),
+    '}',
+  ];
+  const sections = [
+    [
+      `# GraphQL schema of the ${abi.name} contract, generated from its ABI and storage mappers.`,
+      '',
+      '"""Integer beyond 32 bits: a number while exact, otherwise a decimal string"""',
+      'scalar BigInt',
+      '',
+      '"""Bech32 address (klv1...)"""',
+      'scalar Address',
+      '',
+      '"""Plain JSON value, as the ABI tools take and return it"""',
+      'scalar JSON',
+    ],
+    query,
+    ...(storage.length > 0
+      ? [
+          ['type Storage {', ...storage.flatMap(field => field.schema), '}'],
+          ...storage.flatMap(field => (field.entryType ? [field.entryType] : [])),
+        ]
+      : []),
+    ...(events.length > 0
+      ? [
+          [
+            'type Subscription {',
+            ...events.map(e => `  ${fieldName(e.identifier)}: ${pascalCase(e.identifier)}Event!`),
+            '}',
+          ],
+          ...events.map(event => renderEvent(event, abi)),
+        ]
+      : []),
+    ...types.map(name => renderType(name, definitions[name], abi)),
+  ];
+  const schema = `${sections.map(lines => lines.join('\n')).join('\n\n')}\n`;
+
+  const mixedEnums = types.filter(
+    name => definitions[name].type === 'enum' && !isFieldless(definitions[name])
+  );
+  const resolverSections = [
+    '  return {',
+    '    Query: {',
+    ...views.map(view => {
+      const name = fieldName(view.name);
+      const single = view.outputs.length === 1;
+      return `      ${name}: (_: unknown, args: Args) => view('${view.name}', args, ${single}),`;
+    }),
+    ...(storage.length > 0 ? ['      storage: () => ({}),'] : []),
+    '    },',
+    ...(storage.length > 0
+      ? ['    Storage: {', ...storage.map(field => `  ${field.resolver}`), '    },']
+      : []),
+    ...(events.length > 0
+      ? [
+          '    Subscription: {',
+          ...events.map(e => {
+            const subscribe = `subscribe: () => events('${e.identifier}')`;
+            return `      ${fieldName(e.identifier)}: { ${subscribe}, resolve: eventFields },`;
+          }),
+          '    },',
+        ]
+      : []),
+    ...mixedEnums.flatMap(name => {
+      const def = definitions[name];
+      const fielded = def.type === 'enum' ? def.variants.filter(v => v.fields?.length) : [];
+      return [
+        `    ${name}: {`,
+        '      variant,',
+        ...fielded.map(v => `      ${v.name}: (value: unknown) => fieldsOf(value, '${v.name}'),`),
+        '    },',
+      ];
+    }),
+    '  };',
+  ];
+
+  const resolvers = `/**
+ * Resolvers of the ${abi.name} GraphQL schema (schema.graphql), generated from its
+ * ABI and storage mappers. Every field is answered by a query tool of the
+ * Klever MCP server, called through callTool (e.g. an MCP client's callTool
+ * returning the tool's JSON result).
+ */
+
+type ToolResult = Record<string, unknown>;
+type Args = Record<string, unknown>;
+type Entry = { entry?: string; entryKey?: unknown; value: unknown };
+
+export type CallTool = (name: string, args: Record<string, unknown>) => Promise<ToolResult>;
+
+export interface ResolverOptions {
+  /** Contract address (klv1...) */
+  address: string;
+  /** ABI JSON of the contract */
+  abi: string;
+  /** Contract source (src/lib.rs) declaring the storage mappers */
+  source?: string;
+  /** Module files of the contract keyed by path */
+  modules?: Record<string, string>;
+  network?: string;
+  /** Delay between event polls in milliseconds (default: 2000) */
+  pollInterval?: number;
+}
+
+/** Variant name of an enum value: the name itself, or the key of { Variant: fields } */
+const variant = (value: unknown) =>
+  typeof value === 'string' ? value : Object.keys(value as object)[0];
+
+const fieldsOf = (value: unknown, name: string) =>
+  typeof value === 'object' && value !== null ? ((value as Args)[name] ?? null) : null;
+
+const eventFields = (event: ToolResult) => event.fields;
+
+export function createResolvers(callTool: CallTool, options: ResolverOptions) {
+  const { address, abi, source, modules, network, pollInterval = 2000 } = options;
+
+  const view = async (name: string, args: Args, unwrap: boolean) => {
+    const result = await callTool('query_view', { address, view: name, args, abi, network });
+    const code = String(result.returnCode || 'ok');
+    if (code.toLowerCase() !== 'ok') {
+      throw new Error(name + ' failed (' + code + '): ' + String(result.returnMessage || ''));
+    }
+    const results = result.results as unknown[];
+    return unwrap ? results[0] : results;
+  };
+
+  const read = (mapper: string, keyArgs: Args | undefined) =>
+    callTool('get_contract_storage', { address, source, modules, abi, mapper, keyArgs, network });
+
+  const entries = async (mapper: string, keyArgs: Args | undefined, entry: string | undefined) => {
+    const result = await read(mapper, keyArgs);
+    return (result.entries as Entry[])
+      .filter(e => e.entry === entry)
+      .sort((a, b) => Number(a.entryKey) - Number(b.entryKey));
+  };
+
+  const single = async (mapper: string, keyArgs: Args | undefined) =>
+    (await read(mapper, keyArgs)).value;
+  const items = async (mapper: string, keyArgs: Args | undefined, entry: string | undefined) =>
+    (await entries(mapper, keyArgs, entry)).map(e => e.value);
+  const keys = async (mapper: string, keyArgs: Args | undefined, entry: string | undefined) =>
+    (await entries(mapper, keyArgs, entry)).map(e => e.entryKey);
+  const pairs = async (mapper: string, keyArgs: Args | undefined, entry: string | undefined) =>
+    (await entries(mapper, keyArgs, entry)).map(e => ({ key: e.entryKey, value: e.value }));
+
+  /** Events of one identifier, polled from an event subscription until the connection closes */
+  async function* events(identifier: string): AsyncGenerator<ToolResult> {
+    const subscribe = { contract: address, identifier, abi, network };
+    const { id } = await callTool('subscribe_events', subscribe);
+    try {
+      for (;;) {
+        const poll = await callTool('subscribe_events', { action: 'poll', id });
+        for (const event of poll.events as ToolResult[]) yield event;
+        if (poll.status === 'closed') return;
+        await new Promise(resolve => setTimeout(resolve, pollInterval));
+      }
+    } finally {
+      await callTool('subscribe_events', { action: 'unsubscribe', id });
+    }
+  }
+
+${resolverSections.join('\n')}
+}
+`;
+
+  return {
+    directory: 'graphql',
+    files: { 'schema.graphql': schema, 'resolvers.ts': resolvers },
+    queries: views.map(v => fieldName(v.name)),
+    storage: storage.map(field => fieldName(field.slot.functionName)),
+    subscriptions: events.map(e => fieldName(e.identifier)),
+    types,
+    skipped,
+  };
+}
@@ -0,0 +1,28 @@
+export * from './rust-source.js';
+export * from './upgrade.js';
+export * from './storage-mappers.js';
+export * from './storage-mapper-examples.js';
+export * from './storage-benchmark.js';
+export * from './endpoint.js';
+export * from './events.js';
+export * from './modules.js';
+export * from './abi.js';
+export * from './async-call.js';
+export * from './proxy.js';
+export * from './ts-client.js';
+export * from './interactor.js';
+export * from './py-client.js';
+export * from './openapi.js';
+export * from './graphql.js';
+export * from './fuzz.js';
+export * from './proptest.js';
+export * from './payable.js';
+export * from './admin-module.js';
+export * from './migration.js';
+export * from './abi-values.js';
+export * from './abi-codec.js';
+export * from './abi-registry.js';
+export * from './scenario.js';
+export * from './storage-layout.js';
+export * from './call-data.js';
+export * from './koperator.js';
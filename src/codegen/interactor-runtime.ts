@@ -0,0 +1,740 @@
+/**
+ * Rust sources shared by every generated interactor crate (see
+ * `interactor.ts`): the klever-sc ABI codec and the node client that builds,
+ * signs and broadcasts transactions and runs view queries.
+ */
+
+/** src/codec.rs: top-level and nested encoding of the Rust types ABI types map to */
+export const INTERACTOR_CODEC_RS = String.raw`//! ABI encoding of contract arguments and results:
+//! top-level values (whole arguments and results) drop leading zero bytes,
+//! nested values (struct fields, list items, ...) have a fixed size or a u32
+//! length prefix, as in klever-sc.
+
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, bail, Result};
+use bech32::{Bech32, Hrp};
+use num_bigint::{BigInt, BigUint};
+
+pub trait NestedEncode {
+    fn dep_encode(&self, out: &mut Vec<u8>);
+}
+
+pub trait TopEncode {
+    fn top_encode(&self) -> Vec<u8>;
+}
+
+pub trait NestedDecode: Sized {
+    fn dep_decode(input: &mut Reader) -> Result<Self>;
+}
+
+pub trait TopDecode: Sized {
+    fn top_decode(bytes: &[u8]) -> Result<Self>;
+}
+
+/// Cursor over nested-encoded bytes
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes, position: 0 }
+    }
+
+    pub fn take(&mut self, count: usize) -> Result<&'a [u8]> {
+        let end = self.position + count;
+        if end > self.bytes.len() {
+            bail!("input too short: needed {} byte(s) at offset {}", count, self.position);
+        }
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+}
+
+/// Nested encoding of a value, the top encoding of structs, arrays and tuples
+pub fn encode_nested<T: NestedEncode + ?Sized>(value: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    value.dep_encode(&mut out);
+    out
+}
+
+/// Decode all of the bytes as one nested value
+pub fn decode_nested<T: NestedDecode>(bytes: &[u8]) -> Result<T> {
+    let mut reader = Reader::new(bytes);
+    let value = T::dep_decode(&mut reader)?;
+    if !reader.is_empty() {
+        bail!("{} unexpected trailing byte(s)", bytes.len() - reader.position);
+    }
+    Ok(value)
+}
+
+fn length(input: &mut Reader) -> Result<usize> {
+    Ok(u32::dep_decode(input)? as usize)
+}
+
+macro_rules! integers {
+    ($($t:ty: $signed:expr),*) => {$(
+        impl NestedEncode for $t {
+            fn dep_encode(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_be_bytes());
+            }
+        }
+
+        impl TopEncode for $t {
+            fn top_encode(&self) -> Vec<u8> {
+                if *self == 0 {
+                    return Vec::new();
+                }
+                let bytes = self.to_be_bytes();
+                let fill = if $signed && bytes[0] & 0x80 != 0 { 0xff } else { 0 };
+                // Drop fill bytes while the next byte keeps the sign
+                let mut start = 0;
+                while start + 1 < bytes.len()
+                    && bytes[start] == fill
+                    && (bytes[start + 1] & 0x80 == fill & 0x80 || !$signed)
+                {
+                    start += 1;
+                }
+                bytes[start..].to_vec()
+            }
+        }
+
+        impl NestedDecode for $t {
+            fn dep_decode(input: &mut Reader) -> Result<Self> {
+                const SIZE: usize = std::mem::size_of::<$t>();
+                let mut bytes = [0u8; SIZE];
+                bytes.copy_from_slice(input.take(SIZE)?);
+                Ok(<$t>::from_be_bytes(bytes))
+            }
+        }
+
+        impl TopDecode for $t {
+            fn top_decode(bytes: &[u8]) -> Result<Self> {
+                const SIZE: usize = std::mem::size_of::<$t>();
+                if bytes.len() > SIZE {
+                    bail!("{} bytes do not fit in {}", bytes.len(), stringify!($t));
+                }
+                let negative = $signed && bytes.first().map_or(false, |b| b & 0x80 != 0);
+                let mut buffer = [if negative { 0xff } else { 0 }; SIZE];
+                buffer[SIZE - bytes.len()..].copy_from_slice(bytes);
+                Ok(<$t>::from_be_bytes(buffer))
+            }
+        }
+    )*};
+}
+
+integers!(u8: false, u16: false, u32: false, u64: false, i8: true, i16: true, i32: true, i64: true);
+
+impl NestedEncode for bool {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+}
+
+impl TopEncode for bool {
+    fn top_encode(&self) -> Vec<u8> {
+        (*self as u8).top_encode()
+    }
+}
+
+impl NestedDecode for bool {
+    fn dep_decode(input: &mut Reader) -> Result<Self> {
+        match u8::dep_decode(input)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            other => bail!("invalid bool {}", other),
+        }
+    }
+}
+
+impl TopDecode for bool {
+    fn top_decode(bytes: &[u8]) -> Result<Self> {
+        match u8::top_decode(bytes)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            other => bail!("invalid bool {}", other),
+        }
+    }
+}
+
+impl TopEncode for BigUint {
+    fn top_encode(&self) -> Vec<u8> {
+        if self.bits() == 0 {
+            Vec::new()
+        } else {
+            self.to_bytes_be()
+        }
+    }
+}
+
+impl NestedEncode for BigUint {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        self.top_encode().dep_encode(out);
+    }
+}
+
+impl TopDecode for BigUint {
+    fn top_decode(bytes: &[u8]) -> Result<Self> {
+        Ok(BigUint::from_bytes_be(bytes))
+    }
+}
+
+impl NestedDecode for BigUint {
+    fn dep_decode(input: &mut Reader) -> Result<Self> {
+        let count = length(input)?;
+        BigUint::top_decode(input.take(count)?)
+    }
+}
+
+impl TopEncode for BigInt {
+    fn top_encode(&self) -> Vec<u8> {
+        if self.bits() == 0 {
+            Vec::new()
+        } else {
+            self.to_signed_bytes_be()
+        }
+    }
+}
+
+impl NestedEncode for BigInt {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        self.top_encode().dep_encode(out);
+    }
+}
+
+impl TopDecode for BigInt {
+    fn top_decode(bytes: &[u8]) -> Result<Self> {
+        Ok(BigInt::from_signed_bytes_be(bytes))
+    }
+}
+
+impl NestedDecode for BigInt {
+    fn dep_decode(input: &mut Reader) -> Result<Self> {
+        let count = length(input)?;
+        BigInt::top_decode(input.take(count)?)
+    }
+}
+
+/// Klever account or contract address: the 32-byte public key, written as klv1... bech32
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Address(pub [u8; 32]);
+
+impl FromStr for Address {
+    type Err = anyhow::Error;
+
+    fn from_str(text: &str) -> Result<Self> {
+        let (hrp, data) =
+            bech32::decode(text).map_err(|e| anyhow!("invalid address {}: {}", text, e))?;
+        if hrp.to_lowercase() != "klv" {
+            bail!("{} is not a klv1... address", text);
+        }
+        let bytes: [u8; 32] =
+            data.try_into().map_err(|_| anyhow!("{} does not hold 32 bytes", text))?;
+        Ok(Address(bytes))
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let hrp = Hrp::parse("klv").expect("valid prefix");
+        let text = bech32::encode::<Bech32>(hrp, &self.0).map_err(|_| fmt::Error)?;
+        f.write_str(&text)
+    }
+}
+
+impl fmt::Debug for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl NestedEncode for Address {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.0);
+    }
+}
+
+impl TopEncode for Address {
+    fn top_encode(&self) -> Vec<u8> {
+        self.0.to_vec()
+    }
+}
+
+impl NestedDecode for Address {
+    fn dep_decode(input: &mut Reader) -> Result<Self> {
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(input.take(32)?);
+        Ok(Address(bytes))
+    }
+}
+
+impl TopDecode for Address {
+    fn top_decode(bytes: &[u8]) -> Result<Self> {
+        decode_nested(bytes)
+    }
+}
+
+impl NestedEncode for String {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        self.as_bytes().to_vec().dep_encode(out);
+    }
+}
+
+impl TopEncode for String {
+    fn top_encode(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+}
+
+impl NestedDecode for String {
+    fn dep_decode(input: &mut Reader) -> Result<Self> {
+        Ok(String::from_utf8(Vec::<u8>::dep_decode(input)?)?)
+    }
+}
+
+impl TopDecode for String {
+    fn top_decode(bytes: &[u8]) -> Result<Self> {
+        Ok(String::from_utf8(bytes.to_vec())?)
+    }
+}
+
+/// Lists, and bytes as a list of u8: nested with a u32 item count, top-level without
+impl<T: NestedEncode> NestedEncode for Vec<T> {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        (self.len() as u32).dep_encode(out);
+        for item in self {
+            item.dep_encode(out);
+        }
+    }
+}
+
+impl<T: NestedEncode> TopEncode for Vec<T> {
+    fn top_encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        for item in self {
+            item.dep_encode(&mut out);
+        }
+        out
+    }
+}
+
+impl<T: NestedDecode> NestedDecode for Vec<T> {
+    fn dep_decode(input: &mut Reader) -> Result<Self> {
+        let count = length(input)?;
+        (0..count).map(|_| T::dep_decode(input)).collect()
+    }
+}
+
+impl<T: NestedDecode> TopDecode for Vec<T> {
+    fn top_decode(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(bytes);
+        let mut items = Vec::new();
+        while !reader.is_empty() {
+            items.push(T::dep_decode(&mut reader)?);
+        }
+        Ok(items)
+    }
+}
+
+impl<T: NestedEncode> NestedEncode for Option<T> {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        match self {
+            None => out.push(0),
+            Some(value) => {
+                out.push(1);
+                value.dep_encode(out);
+            }
+        }
+    }
+}
+
+impl<T: NestedEncode> TopEncode for Option<T> {
+    fn top_encode(&self) -> Vec<u8> {
+        match self {
+            None => Vec::new(),
+            Some(_) => encode_nested(self),
+        }
+    }
+}
+
+impl<T: NestedDecode> NestedDecode for Option<T> {
+    fn dep_decode(input: &mut Reader) -> Result<Self> {
+        match u8::dep_decode(input)? {
+            0 => Ok(None),
+            1 => Ok(Some(T::dep_decode(input)?)),
+            other => bail!("invalid Option tag {}", other),
+        }
+    }
+}
+
+impl<T: NestedDecode> TopDecode for Option<T> {
+    fn top_decode(bytes: &[u8]) -> Result<Self> {
+        if bytes.is_empty() {
+            Ok(None)
+        } else {
+            decode_nested(bytes)
+        }
+    }
+}
+
+impl<T: NestedEncode, const N: usize> NestedEncode for [T; N] {
+    fn dep_encode(&self, out: &mut Vec<u8>) {
+        for item in self {
+            item.dep_encode(out);
+        }
+    }
+}
+
+impl<T: NestedEncode, const N: usize> TopEncode for [T; N] {
+    fn top_encode(&self) -> Vec<u8> {
+        encode_nested(self)
+    }
+}
+
+impl<T: NestedDecode, const N: usize> NestedDecode for [T; N] {
+    fn dep_decode(input: &mut Reader) -> Result<Self> {
+        let items = (0..N).map(|_| T::dep_decode(input)).collect::<Result<Vec<T>>>()?;
+        items.try_into().map_err(|_| anyhow!("expected {} items", N))
+    }
+}
+
+impl<T: NestedDecode, const N: usize> TopDecode for [T; N] {
+    fn top_decode(bytes: &[u8]) -> Result<Self> {
+        decode_nested(bytes)
+    }
+}
+
+macro_rules! tuples {
+    ($(($($name:ident: $index:tt),+)),*) => {$(
+        impl<$($name: NestedEncode),+> NestedEncode for ($($name,)+) {
+            fn dep_encode(&self, out: &mut Vec<u8>) {
+                $(self.$index.dep_encode(out);)+
+            }
+        }
+
+        impl<$($name: NestedEncode),+> TopEncode for ($($name,)+) {
+            fn top_encode(&self) -> Vec<u8> {
+                encode_nested(self)
+            }
+        }
+
+        impl<$($name: NestedDecode),+> NestedDecode for ($($name,)+) {
+            fn dep_decode(input: &mut Reader) -> Result<Self> {
+                Ok(($($name::dep_decode(input)?,)+))
+            }
+        }
+
+        impl<$($name: NestedDecode),+> TopDecode for ($($name,)+) {
+            fn top_decode(bytes: &[u8]) -> Result<Self> {
+                decode_nested(bytes)
+            }
+        }
+    )*};
+}
+
+tuples!(
+    (A: 0, B: 1),
+    (A: 0, B: 1, C: 2),
+    (A: 0, B: 1, C: 2, D: 3),
+    (A: 0, B: 1, C: 2, D: 3, E: 4),
+    (A: 0, B: 1, C: 2, D: 3, E: 4, F: 5)
+);
+
+/// Results of a call or query, decoded in order
+pub struct Results {
+    items: std::collections::VecDeque<Vec<u8>>,
+}
+
+impl Results {
+    pub fn new(items: Vec<Vec<u8>>) -> Self {
+        Results { items: items.into() }
+    }
+
+    pub fn next<T: TopDecode>(&mut self) -> Result<T> {
+        let item = self.items.pop_front().ok_or_else(|| anyhow!("missing result"))?;
+        T::top_decode(&item)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+`;
+
+/** src/interactor.rs: configuration, wallet and node access */
+export const INTERACTOR_CLIENT_RS = String.raw`//! Klever node access: transactions are
+//! built by the node, signed with the wallet key and broadcast; views run as
+//! VM queries. Settings default to the values in config.rs and can be
+//! overridden by environment variables.
+
+use std::{env, fs, time::Duration};
+
+use anyhow::{anyhow, bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use ed25519_dalek::{Signer, SigningKey};
+use serde_json::{json, Value};
+
+use crate::{codec::Address, config};
+
+/// Transaction type of smart contract calls
+const SMART_CONTRACT: u32 = 63;
+const SC_INVOKE: u32 = 0;
+const SC_DEPLOY: u32 = 1;
+
+pub struct Config {
+    pub node_url: String,
+    pub api_url: String,
+    /// Wallet PEM file as koperator writes it
+    pub wallet: String,
+    pub contract_address: Option<String>,
+    /// Gas limit sent with endpoint calls; None leaves it to the node
+    pub gas_limit: Option<u64>,
+    pub timeout: Duration,
+}
+
+impl Config {
+    /// config.rs, overridden by KLEVER_NODE_URL, KLEVER_API_URL, KLEVER_WALLET,
+    /// CONTRACT_ADDRESS and KLEVER_GAS_LIMIT
+    pub fn from_env() -> Result<Self> {
+        let var = |name: &str, default: &str| {
+            env::var(name).unwrap_or_else(|_| default.to_string())
+        };
+        let gas_limit = match env::var("KLEVER_GAS_LIMIT") {
+            Ok(value) => Some(value.parse().context("KLEVER_GAS_LIMIT is not an integer")?),
+            Err(_) => config::GAS_LIMIT,
+        };
+        Ok(Config {
+            node_url: var("KLEVER_NODE_URL", config::NODE_URL),
+            api_url: var("KLEVER_API_URL", config::API_URL),
+            wallet: var("KLEVER_WALLET", config::WALLET),
+            contract_address: env::var("CONTRACT_ADDRESS")
+                .ok()
+                .or_else(|| config::CONTRACT_ADDRESS.map(String::from)),
+            gas_limit,
+            timeout: Duration::from_secs(60),
+        })
+    }
+}
+
+/// Signing key of a wallet PEM: the base64 of the hex-encoded 32-byte seed,
+/// optionally followed by the public key
+pub struct Wallet {
+    key: SigningKey,
+    pub address: Address,
+}
+
+impl Wallet {
+    pub fn load(path: &str) -> Result<Self> {
+        let contents =
+            fs::read_to_string(path).with_context(|| format!("reading wallet {}", path))?;
+        let body: String = contents
+            .lines()
+            .filter(|line| !line.starts_with("-----"))
+            .flat_map(|line| line.chars())
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let decoded = BASE64.decode(body).with_context(|| format!("{} is not a PEM file", path))?;
+        let bytes = match std::str::from_utf8(&decoded) {
+            Ok(text) if text.len() % 2 == 0 && text.chars().all(|c| c.is_ascii_hexdigit()) => {
+                hex::decode(text)?
+            }
+            _ => decoded,
+        };
+        if bytes.len() != 32 && bytes.len() != 64 {
+            bail!("{}: expected a 32-byte seed, optionally followed by the public key", path);
+        }
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&bytes[..32]);
+        let key = SigningKey::from_bytes(&seed);
+        let address = Address(key.verifying_key().to_bytes());
+        Ok(Wallet { key, address })
+    }
+
+    pub fn sign(&self, message: &[u8]) -> Vec<u8> {
+        self.key.sign(message).to_bytes().to_vec()
+    }
+}
+
+/// A processed transaction
+#[derive(Debug)]
+pub struct Outcome {
+    pub hash: String,
+    /// Transaction as the API returns it, receipts included
+    pub transaction: Value,
+}
+
+pub struct Interactor {
+    pub config: Config,
+    pub wallet: Wallet,
+    http: reqwest::Client,
+}
+
+impl Interactor {
+    pub fn new(config: Config) -> Result<Self> {
+        let wallet = Wallet::load(&config.wallet)?;
+        Ok(Interactor { config, wallet, http: reqwest::Client::new() })
+    }
+
+    pub fn contract_address(&self) -> Result<Address> {
+        let address = self.config.contract_address.as_deref().ok_or_else(|| {
+            anyhow!("no contract address: set CONTRACT_ADDRESS or deploy the contract first")
+        })?;
+        address.parse()
+    }
+
+    async fn get(&self, url: String) -> Result<Value> {
+        let response: Value = self.http.get(&url).send().await?.json().await?;
+        response_data(&url, response)
+    }
+
+    async fn post(&self, url: String, body: Value) -> Result<Value> {
+        let response: Value = self.http.post(&url).json(&body).send().await?.json().await?;
+        response_data(&url, response)
+    }
+
+    pub async fn nonce(&self) -> Result<u64> {
+        let url = format!("{}/address/{}/nonce", self.config.node_url, self.wallet.address);
+        let data = self.get(url).await?;
+        data["nonce"].as_u64().ok_or_else(|| anyhow!("the node returned no nonce"))
+    }
+
+    /// Build a smart contract transaction on the node, sign it and broadcast it; returns its hash
+    async fn send(&self, contract: Value, data: Vec<String>) -> Result<String> {
+        let mut request = json!({
+            "type": SMART_CONTRACT,
+            "sender": self.wallet.address.to_string(),
+            "nonce": self.nonce().await?,
+            "contracts": [contract],
+            "data": data,
+        });
+        if let Some(gas_limit) = self.config.gas_limit {
+            request["gasLimit"] = json!(gas_limit);
+        }
+        let built = self.post(format!("{}/transaction/send", self.config.node_url), request).await?;
+        let tx_hash = built["result"]["txHash"]
+            .as_str()
+            .ok_or_else(|| anyhow!("the node returned no transaction hash"))?;
+        let signature = hex::encode(self.wallet.sign(&hex::decode(tx_hash)?));
+        let body = json!({ "tx": built["result"]["tx"], "signature": signature });
+        let url = format!("{}/transactions/broadcast", self.config.node_url);
+        let sent = self.post(url, body).await?;
+        Ok(sent["txHash"].as_str().unwrap_or(tx_hash).to_string())
+    }
+
+    /// Poll the API until the transaction is processed; fails when it did not succeed
+    pub async fn wait(&self, hash: String) -> Result<Outcome> {
+        let url = format!("{}/v1.0/transaction/{}", self.config.api_url, hash);
+        let deadline = tokio::time::Instant::now() + self.config.timeout;
+        loop {
+            if let Ok(data) = self.get(url.clone()).await {
+                let transaction = &data["transaction"];
+                match transaction["status"].as_str() {
+                    Some("success") => {
+                        let transaction = transaction.clone();
+                        return Ok(Outcome { hash, transaction });
+                    }
+                    Some(status) => bail!(
+                        "transaction {} failed ({}): {}",
+                        hash,
+                        status,
+                        transaction["resultCode"].as_str().unwrap_or("no result code")
+                    ),
+                    None => {}
+                }
+            }
+            if tokio::time::Instant::now() >= deadline {
+                bail!("transaction {} was not processed within {:?}", hash, self.config.timeout);
+            }
+            tokio::time::sleep(Duration::from_secs(2)).await;
+        }
+    }
+
+    /// Deploy the wasm file with encoded init arguments; returns the new contract address
+    pub async fn deploy(&self, wasm_path: &str, args: Vec<Vec<u8>>) -> Result<(Address, Outcome)> {
+        let wasm = fs::read(wasm_path).with_context(|| format!("reading {}", wasm_path))?;
+        let mut data = vec![hex::encode(wasm)];
+        data.extend(args.iter().map(|arg| BASE64.encode(arg)));
+        let hash = self.send(json!({ "scType": SC_DEPLOY }), data).await?;
+        let outcome = self.wait(hash).await?;
+        // The new contract is the first address in the receipts that is not the deployer
+        let deployer = self.wallet.address.to_string();
+        let mut found = Vec::new();
+        collect_addresses(&outcome.transaction["receipts"], &mut found);
+        let address = found
+            .into_iter()
+            .find(|address| *address != deployer)
+            .ok_or_else(|| anyhow!("no contract address in the receipts of {}", outcome.hash))?;
+        Ok((address.parse()?, outcome))
+    }
+
+    /// Call an endpoint with encoded arguments and tokens sent along as (token, amount)
+    pub async fn call(
+        &self,
+        endpoint: &str,
+        args: Vec<Vec<u8>>,
+        call_value: &[(&str, u64)],
+    ) -> Result<Outcome> {
+        let mut contract = json!({
+            "scType": SC_INVOKE,
+            "address": self.contract_address()?.to_string(),
+        });
+        if !call_value.is_empty() {
+            let value: serde_json::Map<String, Value> = call_value
+                .iter()
+                .map(|(token, amount)| (token.to_string(), json!(amount)))
+                .collect();
+            contract["callValue"] = Value::Object(value);
+        }
+        let mut data = vec![endpoint.to_string()];
+        data.extend(args.iter().map(|arg| BASE64.encode(arg)));
+        let hash = self.send(contract, data).await?;
+        self.wait(hash).await
+    }
+
+    /// Run a view as a VM query; returns the raw results
+    pub async fn query(&self, endpoint: &str, args: Vec<Vec<u8>>) -> Result<Vec<Vec<u8>>> {
+        let body = json!({
+            "scAddress": self.contract_address()?.to_string(),
+            "funcName": endpoint,
+            "args": args.iter().map(|arg| BASE64.encode(arg)).collect::<Vec<_>>(),
+        });
+        let result = self.post(format!("{}/vm/query", self.config.node_url), body).await?;
+        let code = result["returnCode"].as_str().unwrap_or("ok");
+        if !code.eq_ignore_ascii_case("ok") {
+            let message = result["returnMessage"].as_str().unwrap_or("");
+            bail!("{} failed ({}): {}", endpoint, code, message);
+        }
+        let items = result["returnData"].as_array().cloned().unwrap_or_default();
+        items
+            .iter()
+            .map(|item| BASE64.decode(item.as_str().unwrap_or("")).map_err(Into::into))
+            .collect()
+    }
+}
+
+/// The data of a Klever API response, or its error
+fn response_data(url: &str, response: Value) -> Result<Value> {
+    match response["error"].as_str() {
+        Some(error) if !error.is_empty() => bail!("{}: {}", url, error),
+        _ => Ok(response["data"].clone()),
+    }
+}
+
+fn collect_addresses(value: &Value, found: &mut Vec<String>) {
+    match value {
+        Value::String(text) if text.starts_with("klv1") && text.len() == 62 => {
+            found.push(text.clone())
+        }
+        Value::Array(items) => items.iter().for_each(|item| collect_addresses(item, found)),
+        Value::Object(fields) => fields.values().for_each(|item| collect_addresses(item, found)),
+        _ => {}
+    }
+}
+`;
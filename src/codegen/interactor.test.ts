@@ -0,0 +1,128 @@
+import { parseAbi } from '../abi/index.js';
+import { generateInteractor } from './interactor.js';
+
+const ABI = parseAbi({
+  name: 'token_vault',
+  constructor: { inputs: [{ name: 'fee', type: 'BigUint' }], outputs: [] },
+  endpoints: [
+    {
+      name: 'deposit',
+      mutability: 'mutable',
+      payableInTokens: ['KLV'],
+      inputs: [{ name: 'lock_until', type: 'optional<u64>' }],
+      outputs: [],
+    },
+    {
+      name: 'getPosition',
+      docs: ['Position of an account'],
+      mutability: 'readonly',
+      inputs: [{ name: 'owner', type: 'Address' }],
+      outputs: [{ type: 'Option<Position>' }],
+    },
+    {
+      name: 'getTotal',
+      mutability: 'readonly',
+      inputs: [],
+      outputs: [{ type: 'BigUint' }],
+    },
+    {
+      name: 'remove',
+      mutability: 'mutable',
+      onlyOwner: true,
+      inputs: [{ name: 'accounts', type: 'variadic<multi<Address,BigUint>>' }],
+      outputs: [],
+    },
+  ],
+  types: {
+    Position: {
+      type: 'struct',
+      fields: [
+        { name: 'amount', type: 'BigUint' },
+        { name: 'status', type: 'Status' },
+      ],
+    },
+    Status: {
+      type: 'enum',
+      variants: [
+        { name: 'Open', discriminant: 0 },
+        { name: 'Locked', discriminant: 1, fields: [{ name: 'until', type: 'u64' }] },
+      ],
+    },
+  },
+});
+
+describe('generateInteractor', () => {
+  const interactor = generateInteractor(ABI, {
+    nodeUrl: 'https://node.testnet.klever.org',
+    apiUrl: 'https://api.testnet.klever.org',
+    gasLimit: 5_000_000,
+  });
+  const proxy = interactor.files['src/proxy.rs'];
+
+  it('lays out a standalone binary crate', () => {
+    expect(interactor.crateName).toBe('token-vault-interactor');
+    expect(Object.keys(interactor.files).sort()).toEqual([
+      '.gitignore',
+      'Cargo.toml',
+      'src/codec.rs',
+      'src/config.rs',
+      'src/interactor.rs',
+      'src/main.rs',
+      'src/proxy.rs',
+      'src/types.rs',
+    ]);
+    expect(interactor.files['Cargo.toml']).toContain('name = "token-vault-interactor"');
+    expect(interactor.files['Cargo.toml']).toContain('[workspace]\nmembers = ["."]');
+    expect(interactor.files['src/config.rs']).toContain(
+      'pub const GAS_LIMIT: Option<u64> = Some(5000000);'
+    );
+    expect(interactor.files['src/config.rs']).toContain(
+      'pub const CONTRACT_ADDRESS: Option<&str> = None;'
+    );
+  });
+
+  it('declares the custom types with their ABI encoding', () => {
+    const types = interactor.files['src/types.rs'];
+    expect(interactor.types).toEqual(['Position', 'Status']);
+    expect(types).toContain(
+      'pub struct Position {\n    pub amount: BigUint,\n    pub status: Status,\n}'
+    );
+    expect(types).toContain('pub enum Status {\n    Open,\n    Locked { until: u64 },\n}');
+    expect(types).toContain(
+      '            1 => Status::Locked { until: <u64>::dep_decode(input)? },'
+    );
+    // Field-less variants are a top-level discriminant
+    expect(types).toContain('            Status::Open => 0u8.top_encode(),');
+  });
+
+  it('renders typed methods mirroring the endpoints', () => {
+    expect(proxy).toContain(
+      'pub async fn deploy(&self, wasm_path: &str, fee: BigUint) -> Result<(Address, Outcome)> {'
+    );
+    expect(proxy).toContain(
+      '        lock_until: Option<u64>,\n        call_value: &[(&str, u64)],\n    ) -> Result<Outcome> {'
+    );
+    expect(proxy).toContain(
+      '        if let Some(value) = lock_until {\n            args.push(value.top_encode());'
+    );
+    expect(proxy).toContain(
+      'pub async fn get_position(&self, owner: Address) -> Result<Option<Position>> {\n        let args = vec![owner.top_encode()];'
+    );
+    expect(proxy).toContain(
+      '        for item in accounts {\n            let (part1_0, part1_1) = item;'
+    );
+    expect(proxy).toContain('        self.interactor.call("remove", args, &[]).await');
+    expect(proxy).toContain(
+      '    /// Only the contract owner may call it.\n    pub async fn remove('
+    );
+  });
+
+  it('exposes the calls without arguments as commands', () => {
+    expect(interactor.views).toEqual(['getPosition', 'getTotal']);
+    expect(interactor.endpoints).toEqual(['deposit', 'remove']);
+    expect(interactor.commands).toEqual(['getTotal']);
+    expect(interactor.files['src/main.rs']).toContain(
+      '        "getTotal" => println!("{:?}", contract.get_total().await?),'
+    );
+  });
+});
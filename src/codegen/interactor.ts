@@ -0,0 +1,632 @@
+/**
+ * Generate a Rust interactor crate for a contract from its ABI: a standalone
+ * binary that loads a wallet PEM, deploys the contract and calls its
+ * endpoints and views through typed methods mirroring the contract trait,
+ * with arguments and results encoded by a bundled ABI codec.
+ */
+
+import type { Abi, AbiEndpoint, AbiField, AbiInput, AbiOutput, AbiTypeDef } from '../abi/index.js';
+import type { FileMap } from '../scaffold/types.js';
+import { parseAbiType } from './abi-values.js';
+import { INTERACTOR_CLIENT_RS, INTERACTOR_CODEC_RS } from './interactor-runtime.js';
+import { camelToSnake } from './rust-source.js';
+
+export interface InteractorOptions {
+  nodeUrl: string;
+  apiUrl: string;
+  /** Contract address (klv1...) the interactor calls by default */
+  address?: string;
+  /** Gas limit sent with endpoint calls; the node's default when left out */
+  gasLimit?: number;
+}
+
+export interface GeneratedInteractor {
+  crateName: string;
+  /** Crate directory, relative to the contract project root */
+  directory: string;
+  /** Crate files, relative to `directory` */
+  files: FileMap;
+  endpoints: string[];
+  views: string[];
+  /** Custom types declared in src/types.rs */
+  types: string[];
+  /** Command-line commands of the binary: deploy and the calls that take no arguments */
+  commands: string[];
+}
+
+const RUST_KEYWORDS = new Set(
+  (
+    'as async await break const continue crate dyn else enum extern false fn for if impl in let ' +
+    'loop match mod move mut pub ref return self Self static struct super trait true type unsafe ' +
+    'use where while abstract become box do final macro override priv try typeof unsized ' +
+    'virtual yield'
+  ).split(' ')
+);
+
+/** snake_case Rust identifier for an ABI name, unlike any of `taken` */
+export function rustIdentifier(name: string, taken: string[] = []): string {
+  let id = camelToSnake(name).replace(/\W/g, '_');
+  if (/^\d/.test(id)) id = `_${id}`;
+  while (RUST_KEYWORDS.has(id) || taken.includes(id)) id = `${id}_`;
+  return id;
+}
+
+function pascalCase(name: string): string {
+  return camelToSnake(name.replace(/[-\s]+/g, '_'))
+    .split('_')
+    .filter(Boolean)
+    .map(part => part.charAt(0).toUpperCase() + part.slice(1))
+    .join('');
+}
+
+function docLines(docs: string[] | undefined, indent: string): string[] {
+  return (docs || []).map(d => `${indent}///${d.startsWith(' ') || d === '' ? '' : ' '}${d}`);
+}
+
+/** Rust tuple (type, pattern or value) of `items` */
+const tuple = (items: string[]) => `(${items.join(', ')}${items.length === 1 ? ',' : ''})`;
+
+const isMultiValue = (type: string) =>
+  ['optional', 'variadic', 'multi'].includes(parseAbiType(type).name);
+
+/** Rust type of ABI `type` in the interactor; multi-values become Option, Vec and tuples */
+function rustType(type: string, abi: Abi): string {
+  const { name, args } = parseAbiType(type);
+  const inner = (arg: string) => rustType(arg, abi);
+  switch (name) {
+    case 'usize':
+      return 'u32';
+    case 'isize':
+      return 'i32';
+    case 'bytes':
+      return 'Vec<u8>';
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return 'String';
+    case 'Option':
+    case 'optional':
+      return `Option<${inner(args[0])}>`;
+    case 'List':
+    case 'variadic':
+      return `Vec<${inner(args[0])}>`;
+    case 'tuple':
+    case 'multi':
+      return tuple(args.map(inner));
+  }
+  const array = /^array(\d+)$/.exec(name);
+  if (array) return `[${inner(args[0])}; ${array[1]}]`;
+  if (/^[ui](8|16|32|64)$/.test(name) || ['bool', 'BigUint', 'BigInt', 'Address'].includes(name)) {
+    return name;
+  }
+  if (abi.types && Object.hasOwn(abi.types, name)) return name;
+  throw new Error(`ABI type "${type}" has no Rust equivalent in the interactor`);
+}
+
+/** Statements pushing the top encoding of `value` (of ABI `type`) onto `args` */
+function encodeArgument(value: string, type: string, indent: string, depth = 0): string[] {
+  const { name, args } = parseAbiType(type);
+  const suffix = depth === 0 ? '' : String(depth);
+  const nested = (inner: string, innerType: string) =>
+    encodeArgument(inner, innerType, `${indent}    `, depth + 1);
+  switch (name) {
+    case 'optional':
+      return [
+        `${indent}if let Some(value${suffix}) = ${value} {`,
+        ...nested(`value${suffix}`, args[0]),
+        `${indent}}`,
+      ];
+    case 'variadic':
+      return [
+        `${indent}for item${suffix} in ${value} {`,
+        ...nested(`item${suffix}`, args[0]),
+        `${indent}}`,
+      ];
+    case 'multi': {
+      const parts = args.map((_, i) => `part${suffix}_${i}`);
+      return [
+        `${indent}let ${tuple(parts)} = ${value};`,
+        ...args.flatMap((arg, i) => encodeArgument(parts[i], arg, indent, depth + 1)),
+      ];
+    }
+    default:
+      return [`${indent}args.push(${value}.top_encode());`];
+  }
+}
+
+/** Expression decoding the next results of ABI `type`; lines after the first get `indent` */
+function decodeResult(type: string, abi: Abi, indent: string): string {
+  const { name, args } = parseAbiType(type);
+  switch (name) {
+    case 'optional':
+      return [
+        'if results.is_empty() {',
+        `${indent}    None`,
+        `${indent}} else {`,
+        `${indent}    Some(${decodeResult(args[0], abi, `${indent}    `)})`,
+        `${indent}}`,
+      ].join('\n');
+    case 'variadic':
+      return [
+        '{',
+        `${indent}    let mut items = Vec::new();`,
+        `${indent}    while !results.is_empty() {`,
+        `${indent}        items.push(${decodeResult(args[0], abi, `${indent}        `)});`,
+        `${indent}    }`,
+        `${indent}    items`,
+        `${indent}}`,
+      ].join('\n');
+    case 'multi':
+      return tuple(args.map(arg => decodeResult(arg, abi, indent)));
+    default:
+      return `results.next::<${rustType(type, abi)}>()?`;
+  }
+}
+
+/** Statements decoding the results of a view from `results` */
+function decodeResults(outputs: AbiOutput[], abi: Abi): string[] {
+  if (outputs.length === 1 && !isMultiValue(outputs[0].type)) return ['        results.next()'];
+  if (outputs.length === 1) return [`        Ok(${decodeResult(outputs[0].type, abi, '        ')})`];
+  const names = outputs.map((_, i) => `result${i}`);
+  return [
+    ...outputs.map(
+      (output, i) => `        let ${names[i]} = ${decodeResult(output.type, abi, '        ')};`
+    ),
+    `        Ok(${tuple(names)})`,
+  ];
+}
+
+/** Tuple variants have fields named 0, 1, ... */
+const isTupleFields = (fields: AbiField[]) => fields.every(f => /^\d+$/.test(f.name));
+
+function renderStructCodec(name: string, fields: AbiField[], abi: Abi): string {
+  const names = fields.map(f => rustIdentifier(f.name));
+  const out = fields.length > 0 ? 'out' : '_out';
+  return [
+    `impl NestedEncode for ${name} {`,
+    `    fn dep_encode(&self, ${out}: &mut Vec<u8>) {`,
+    ...names.map(field => `        self.${field}.dep_encode(out);`),
+    '    }',
+    '}',
+    '',
+    `impl NestedDecode for ${name} {`,
+    `    fn dep_decode(${fields.length > 0 ? 'input' : '_input'}: &mut Reader) -> Result<Self> {`,
+    `        Ok(${name} {`,
+    ...fields.map(
+      (f, i) => `            ${names[i]}: <${rustType(f.type, abi)}>::dep_decode(input)?,`
+    ),
+    '        })',
+    '    }',
+    '}',
+  ].join('\n');
+}
+
+function renderEnum(name: string, def: Extract<AbiTypeDef, { type: 'enum' }>, abi: Abi): string {
+  const variants = [...def.variants].sort((a, b) => a.discriminant - b.discriminant);
+  const simple = variants.every(v => !v.fields || v.fields.length === 0);
+  const patterns = variants.map(variant => {
+    const fields = variant.fields || [];
+    const path = `${name}::${variant.name}`;
+    const tupleFields = isTupleFields(fields);
+    const bindings = fields.map((f, i) => (tupleFields ? `f${i}` : rustIdentifier(f.name)));
+    const list = bindings.join(', ');
+    const pattern =
+      fields.length === 0 ? path : tupleFields ? `${path}(${list})` : `${path} { ${list} }`;
+    return { variant, pattern, bindings };
+  });
+
+  const encodeArms = patterns.flatMap(({ variant, pattern, bindings }) =>
+    bindings.length === 0
+      ? [`            ${pattern} => out.push(${variant.discriminant}),`]
+      : [
+          `            ${pattern} => {`,
+          `                out.push(${variant.discriminant});`,
+          ...bindings.map(binding => `                ${binding}.dep_encode(out);`),
+          '            }',
+        ]
+  );
+  const decodeArms = patterns.map(({ variant, bindings }) => {
+    const fields = variant.fields || [];
+    const path = `${name}::${variant.name}`;
+    const values = fields.map(f => `<${rustType(f.type, abi)}>::dep_decode(input)?`);
+    const named = bindings.map((binding, i) => `${binding}: ${values[i]}`);
+    const built =
+      fields.length === 0
+        ? path
+        : isTupleFields(fields)
+          ? `${path}(${values.join(', ')})`
+          : `${path} { ${named.join(', ')} }`;
+    return `            ${variant.discriminant} => ${built},`;
+  });
+  const arm = (pattern: string, value: string) => `            ${pattern} => ${value},`;
+  // Field-less variants encode like a u8 discriminant at the top level
+  const topEncode = simple
+    ? [
+        '        let discriminant: u8 = match self {',
+        ...patterns.map(({ variant, pattern }) => arm(pattern, String(variant.discriminant))),
+        '        };',
+        '        discriminant.top_encode()',
+      ]
+    : [
+        '        match self {',
+        ...patterns
+          .filter(({ bindings }) => bindings.length === 0)
+          .map(({ variant, pattern }) => arm(pattern, `${variant.discriminant}u8.top_encode()`)),
+        '            _ => encode_nested(self),',
+        '        }',
+      ];
+  // A field-less variant of an enum with fields is a top-level discriminant too
+  const topDecode = simple
+    ? ['        decode_nested(&[u8::top_decode(bytes)?])']
+    : [
+        '        if bytes.len() <= 1 {',
+        '            decode_nested(&[u8::top_decode(bytes)?])',
+        '        } else {',
+        '            decode_nested(bytes)',
+        '        }',
+      ];
+
+  return [
+    `impl NestedEncode for ${name} {`,
+    '    fn dep_encode(&self, out: &mut Vec<u8>) {',
+    '        match self {',
+    ...encodeArms,
+    '        }',
+    '    }',
+    '}',
+    '',
+    `impl TopEncode for ${name} {`,
+    '    fn top_encode(&self) -> Vec<u8> {',
+    ...topEncode,
+    '    }',
+    '}',
+    '',
+    `impl NestedDecode for ${name} {`,
+    '    fn dep_decode(input: &mut Reader) -> Result<Self> {',
+    '        Ok(match u8::dep_decode(input)? {',
+    ...decodeArms,
+    `            other => bail!("${name} has no variant with discriminant {}", other),`,
+    '        })',
+    '    }',
+    '}',
+    '',
+    `impl TopDecode for ${name} {`,
+    '    fn top_decode(bytes: &[u8]) -> Result<Self> {',
+    ...topDecode,
+    '    }',
+    '}',
+  ].join('\n');
+}
+
+function renderTypeDeclaration(name: string, def: AbiTypeDef, abi: Abi): string {
+  const lines = [...docLines(def.docs, ''), '#[derive(Clone, Debug, PartialEq)]'];
+  if (def.type === 'struct') {
+    lines.push(
+      `pub struct ${name} {`,
+      ...def.fields.map(f => `    pub ${rustIdentifier(f.name)}: ${rustType(f.type, abi)},`),
+      '}',
+      '',
+      renderStructCodec(name, def.fields, abi),
+      '',
+      `impl TopEncode for ${name} {`,
+      '    fn top_encode(&self) -> Vec<u8> {',
+      '        encode_nested(self)',
+      '    }',
+      '}',
+      '',
+      `impl TopDecode for ${name} {`,
+      '    fn top_decode(bytes: &[u8]) -> Result<Self> {',
+      '        decode_nested(bytes)',
+      '    }',
+      '}'
+    );
+    return lines.join('\n');
+  }
+  lines.push(`pub enum ${name} {`);
+  for (const variant of [...def.variants].sort((a, b) => a.discriminant - b.discriminant)) {
+    const fields = variant.fields || [];
+    if (fields.length === 0) {
+      lines.push(`    ${variant.name},`);
+    } else if (isTupleFields(fields)) {
+      lines.push(`    ${variant.name}(${fields.map(f => rustType(f.type, abi)).join(', ')}),`);
+    } else {
+      const list = fields.map(f => `${rustIdentifier(f.name)}: ${rustType(f.type, abi)}`);
+      lines.push(`    ${variant.name} { ${list.join(', ')} },`);
+    }
+  }
+  lines.push('}', '', renderEnum(name, def, abi));
+  return lines.join('\n');
+}
+
+function renderTypes(abi: Abi): string {
+  const definitions = abi.types || {};
+  return [
+    [
+      `//! Custom types of the ${abi.name} contract and their ABI encoding`,
+      '',
+      '#![allow(unused_imports)]',
+      '',
+      'use anyhow::{bail, Result};',
+      'use num_bigint::{BigInt, BigUint};',
+      '',
+      'use crate::codec::*;',
+    ].join('\n'),
+    ...Object.keys(definitions).map(name => renderTypeDeclaration(name, definitions[name], abi)),
+  ].join('\n\n');
+}
+
+interface Method {
+  name: string;
+  params: { name: string; type: string }[];
+  body: string[];
+  returnType: string;
+  docs: string[];
+}
+
+function methodParams(inputs: AbiInput[], abi: Abi, reserved: string[]) {
+  const names: string[] = [];
+  return inputs.map(input => {
+    const name = rustIdentifier(input.name, [...reserved, ...names]);
+    names.push(name);
+    return { name, type: rustType(input.type, abi), abiType: input.type };
+  });
+}
+
+function encodeArguments(params: { name: string; abiType: string }[]): string[] {
+  if (params.length === 0) return ['        let args = Vec::new();'];
+  if (params.every(p => !isMultiValue(p.abiType))) {
+    return [`        let args = vec![${params.map(p => `${p.name}.top_encode()`).join(', ')}];`];
+  }
+  return [
+    '        let mut args = Vec::new();',
+    ...params.flatMap(p => encodeArgument(p.name, p.abiType, '        ')),
+  ];
+}
+
+function resultType(outputs: AbiOutput[], abi: Abi): string {
+  const types = outputs.map(o => rustType(o.type, abi));
+  return types.length === 1 ? types[0] : tuple(types);
+}
+
+function endpointDocs(endpoint: Pick<AbiEndpoint, 'docs' | 'payableInTokens' | 'onlyOwner'>) {
+  const docs = [...(endpoint.docs || [])];
+  const tokens = endpoint.payableInTokens || [];
+  if (tokens.length > 0) {
+    docs.push(` Payable in ${tokens.includes('*') ? 'any token' : tokens.join(', ')}.`);
+  }
+  if (endpoint.onlyOwner) docs.push(' Only the contract owner may call it.');
+  return docs;
+}
+
+function renderMethod(method: Method): string {
+  const params = ['&self', ...method.params.map(p => `${p.name}: ${p.type}`)];
+  const returns = `Result<${method.returnType}>`;
+  const signature = `    pub async fn ${method.name}(${params.join(', ')}) -> ${returns} {`;
+  return [
+    ...docLines(method.docs, '    '),
+    signature.length <= 100
+      ? signature
+      : [
+          `    pub async fn ${method.name}(`,
+          ...params.map(p => `        ${p},`),
+          `    ) -> ${returns} {`,
+        ].join('\n'),
+    ...method.body,
+    '    }',
+  ].join('\n');
+}
+
+function proxyMethods(abi: Abi): { methods: Method[]; names: Map<string, string> } {
+  const methods: Method[] = [];
+  const names = new Map<string, string>();
+  const taken = ['new', 'deploy', 'interactor'];
+
+  const init = methodParams(abi.constructor?.inputs || [], abi, ['wasm_path', 'args']);
+  methods.push({
+    name: 'deploy',
+    params: [{ name: 'wasm_path', type: '&str' }, ...init],
+    body: [
+      ...encodeArguments(init),
+      '        self.interactor.deploy(wasm_path, args).await',
+    ],
+    returnType: '(Address, Outcome)',
+    docs: [' Deploy the contract from `wasm_path`; returns the new contract address'],
+  });
+
+  for (const endpoint of abi.endpoints) {
+    const name = rustIdentifier(endpoint.name, taken);
+    taken.push(name);
+    names.set(endpoint.name, name);
+    const reserved = ['args', 'results', 'call_value'];
+    const params = methodParams(endpoint.inputs, abi, reserved);
+    const docs = endpointDocs(endpoint);
+    if (endpoint.mutability === 'readonly') {
+      const outputs = endpoint.outputs;
+      const query = `self.interactor.query("${endpoint.name}", args).await?`;
+      const body =
+        outputs.length === 0
+          ? [`        ${query};`, '        Ok(())']
+          : [
+              `        let mut results = Results::new(${query});`,
+              ...decodeResults(outputs, abi),
+            ];
+      methods.push({
+        name,
+        params,
+        body: [...encodeArguments(params), ...body],
+        returnType: outputs.length === 0 ? '()' : resultType(outputs, abi),
+        docs,
+      });
+      continue;
+    }
+    const payable = (endpoint.payableInTokens || []).length > 0;
+    const callValue = payable ? 'call_value' : '&[]';
+    methods.push({
+      name,
+      params: [...params, ...(payable ? [{ name: 'call_value', type: '&[(&str, u64)]' }] : [])],
+      body: [
+        ...encodeArguments(params),
+        `        self.interactor.call("${endpoint.name}", args, ${callValue}).await`,
+      ],
+      returnType: 'Outcome',
+      docs,
+    });
+  }
+  return { methods, names };
+}
+
+function renderProxy(abi: Abi, structName: string, methods: Method[]): string {
+  return [
+    [
+      `//! Typed calls to the ${abi.name} contract: one method per endpoint and view.`,
+      '//! Endpoints are sent as transactions and resolve when they are processed;',
+      '//! views are queried on the node and decoded.',
+      '',
+      '#![allow(unused_imports)]',
+      '',
+      'use anyhow::Result;',
+      'use num_bigint::{BigInt, BigUint};',
+      '',
+      'use crate::codec::{Address, Results, TopEncode};',
+      'use crate::interactor::{Interactor, Outcome};',
+      'use crate::types::*;',
+    ].join('\n'),
+    [
+      `pub struct ${structName} {`,
+      '    pub interactor: Interactor,',
+      '}',
+      '',
+      `impl ${structName} {`,
+      '    pub fn new(interactor: Interactor) -> Self {',
+      `        ${structName} { interactor }`,
+      '    }',
+      '',
+      methods.map(renderMethod).join('\n\n'),
+      '}',
+    ].join('\n'),
+  ].join('\n\n');
+}
+
+function renderMain(abi: Abi, structName: string, commands: Map<string, string>): string {
+  const arms = [...commands].map(([command, method]) =>
+    command === 'deploy'
+      ? [
+          '        "deploy" => {',
+          '            let (address, outcome) = contract.deploy(config::WASM).await?;',
+          '            println!("deployed {} in {}", address, outcome.hash);',
+          '        }',
+        ].join('\n')
+      : `        "${command}" => println!("{:?}", contract.${method}().await?),`
+  );
+  const usage = [...commands.keys()].join('|');
+  return `//! Interactor of the ${abi.name} contract. Run a command with
+//!     cargo run -- <${usage || 'command'}>
+//! and call the other endpoints from code through proxy.rs.
+
+// The proxy covers every endpoint, while the commands below use a few
+#![allow(dead_code)]
+
+mod codec;
+mod config;
+mod interactor;
+mod proxy;
+mod types;
+
+use anyhow::{bail, Result};
+
+use interactor::{Config, Interactor};
+use proxy::${structName};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let command = std::env::args().nth(1).unwrap_or_default();
+    let contract = ${structName}::new(Interactor::new(Config::from_env()?)?);
+    match command.as_str() {
+${arms.join('\n')}
+        _ => bail!("usage: cargo run -- <${usage}>"),
+    }
+    Ok(())
+}
+`;
+}
+
+function renderConfig(abi: Abi, options: InteractorOptions): string {
+  const wasm = `../output/${camelToSnake(abi.name).replace(/_/g, '-')}.wasm`;
+  const address = options.address ? `Some(${JSON.stringify(options.address)})` : 'None';
+  const gasLimit = options.gasLimit !== undefined ? `Some(${options.gasLimit})` : 'None';
+  return `//! Defaults of the interactor; environment variables override them (see interactor.rs)
+
+pub const NODE_URL: &str = ${JSON.stringify(options.nodeUrl)};
+pub const API_URL: &str = ${JSON.stringify(options.apiUrl)};
+/// Wallet PEM, e.g. one written by \`koperator account create\`
+pub const WALLET: &str = "wallet.pem";
+pub const CONTRACT_ADDRESS: Option<&str> = ${address};
+pub const GAS_LIMIT: Option<u64> = ${gasLimit};
+/// Contract code deployed by the deploy command
+pub const WASM: &str = ${JSON.stringify(wasm)};
+`;
+}
+
+function cargoToml(crateName: string): string {
+  return `[package]
+name = "${crateName}"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[[bin]]
+name = "${crateName}"
+path = "src/main.rs"
+
+[dependencies]
+anyhow = "1"
+base64 = "0.22"
+bech32 = "0.11"
+ed25519-dalek = "2"
+hex = "0.4"
+num-bigint = "0.4"
+reqwest = { version = "0.12", default-features = false, features = ["json", "rustls-tls"] }
+serde_json = "1"
+tokio = { version = "1", features = ["macros", "rt-multi-thread", "time"] }
+
+[workspace]
+members = ["."]
+`;
+}
+
+/** Render the interactor crate for `abi` */
+export function generateInteractor(abi: Abi, options: InteractorOptions): GeneratedInteractor {
+  const crateName = `${camelToSnake(abi.name).replace(/_/g, '-')}-interactor`;
+  const structName = pascalCase(abi.name);
+  const { methods, names } = proxyMethods(abi);
+
+  // Commands for the calls that need no arguments
+  const commands = new Map<string, string>();
+  if ((abi.constructor?.inputs || []).length === 0) commands.set('deploy', 'deploy');
+  for (const endpoint of abi.endpoints) {
+    const payable = (endpoint.payableInTokens || []).length > 0;
+    if (endpoint.inputs.length === 0 && !payable) {
+      commands.set(endpoint.name, names.get(endpoint.name)!);
+    }
+  }
+
+  const files: FileMap = {
+    'Cargo.toml': cargoToml(crateName),
+    'src/main.rs': renderMain(abi, structName, commands),
+    'src/config.rs': renderConfig(abi, options),
+    'src/interactor.rs': INTERACTOR_CLIENT_RS,
+    'src/codec.rs': INTERACTOR_CODEC_RS,
+    'src/types.rs': `${renderTypes(abi)}\n`,
+    'src/proxy.rs': `${renderProxy(abi, structName, methods)}\n`,
+    '.gitignore': '/target\nwallet.pem\n',
+  };
+
+  return {
+    crateName,
+    directory: 'interactor',
+    files,
+    endpoints: abi.endpoints.filter(e => e.mutability !== 'readonly').map(e => e.name),
+    views: abi.endpoints.filter(e => e.mutability === 'readonly').map(e => e.name),
+    types: Object.keys(abi.types || {}),
+    commands: [...commands.keys()],
+  };
+}
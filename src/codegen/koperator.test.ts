@@ -0,0 +1,140 @@
+import { parseAbi } from '../abi/index.js';
+import { encodeAddress } from '../chain/bech32.js';
+import {
+  koperatorArgument,
+  koperatorDeployCommand,
+  koperatorInvokeCommand,
+  koperatorUpgradeCommand,
+  shellQuote,
+} from './koperator.js';
+
+const ABI = parseAbi({
+  name: 'MyToken',
+  constructor: {
+    inputs: [
+      { name: 'fee', type: 'u64' },
+      { name: 'owner', type: 'Address' },
+    ],
+    outputs: [],
+  },
+  endpoints: [
+    {
+      name: 'buy',
+      mutability: 'mutable',
+      payableInTokens: ['*'],
+      inputs: [
+        { name: 'amount', type: 'BigUint' },
+        { name: 'memo', type: 'optional<bytes>' },
+      ],
+      outputs: [],
+    },
+  ],
+});
+
+const OWNER_BYTES = Buffer.alloc(32, 2);
+const OWNER = encodeAddress(OWNER_BYTES);
+const CONTRACT = encodeAddress(Buffer.alloc(32, 0));
+
+describe('koperatorArgument', () => {
+  it('writes scalars with their type prefix', () => {
+    expect(koperatorArgument('usize', '7', ABI)).toBe('u32:7');
+    expect(koperatorArgument('BigUint', 1000000, ABI)).toBe('bi:1000000');
+    expect(koperatorArgument('bool', true, ABI)).toBe('bool:true');
+    expect(koperatorArgument('Address', `0x${OWNER_BYTES.toString('hex')}`, ABI)).toBe(
+      `Address:${OWNER}`
+    );
+  });
+
+  it('writes bytes as text or hex', () => {
+    expect(koperatorArgument('bytes', 'hello', ABI)).toBe('String:hello');
+    expect(koperatorArgument('bytes', '0xcafe', ABI)).toBe('bytes:0xcafe');
+  });
+
+  it('writes options and flat lists', () => {
+    expect(koperatorArgument('Option<u8>', null, ABI)).toBe('empty');
+    expect(koperatorArgument('Option<u8>', 3, ABI)).toBe('Option:u8:3');
+    expect(koperatorArgument('List<u32>', [1, 2], ABI)).toBe('List:u32:1,u32:2');
+  });
+
+  it('falls back to raw bytes for nested lists', () => {
+    expect(koperatorArgument('List<List<u8>>', [[1], [2]], ABI)).toBe(
+      'bytes:0x00000001010000000102'
+    );
+  });
+});
+
+describe('shellQuote', () => {
+  it('single-quotes words the shell would expand', () => {
+    expect(shellQuote('u32:1')).toBe('"u32:1"');
+    expect(shellQuote('String:$HOME')).toBe("'String:$HOME'");
+    expect(shellQuote("String:it's $1")).toBe("'String:it'\\''s $1'");
+  });
+});
+
+describe('koperatorDeployCommand', () => {
+  it('renders sc create with metadata flags and constructor arguments', () => {
+    const result = koperatorDeployCommand(ABI, { args: { fee: 5, owner: OWNER } });
+    expect(result.args).toEqual(['u64:5', `Address:${OWNER}`]);
+    expect(result.hexArgs).toEqual(['05', OWNER_BYTES.toString('hex')]);
+    expect(result.command).toBe(
+      [
+        '~/klever-sdk/koperator',
+        '--key-file="$HOME/klever-sdk/walletKey.pem"',
+        'sc create',
+        '--wasm="output/my_token.wasm"',
+        '--upgradeable --readable --payable --payableBySC',
+        '--args "u64:5"',
+        `--args "Address:${OWNER}"`,
+        '--await --sign --result-only',
+      ].join(' \\\n    ')
+    );
+  });
+
+  it('leaves out metadata flags that are turned off', () => {
+    const { command } = koperatorDeployCommand(ABI, {
+      args: [1, OWNER],
+      upgradeable: false,
+      payableBySC: false,
+    });
+    expect(command).toContain('--readable --payable \\');
+    expect(command).not.toContain('--upgradeable');
+  });
+});
+
+describe('koperatorUpgradeCommand', () => {
+  it('passes the contract address and node', () => {
+    const { command } = koperatorUpgradeCommand(ABI, {
+      address: CONTRACT,
+      nodeUrl: 'https://node.testnet.klever.org',
+      keyFile: '/keys/owner.pem',
+    });
+    expect(command).toMatch(/^KLEVER_NODE=https:\/\/node\.testnet\.klever\.org \\/);
+    expect(command).toContain('--key-file="/keys/owner.pem"');
+    expect(command).toContain(`sc upgrade ${CONTRACT}`);
+  });
+});
+
+describe('koperatorInvokeCommand', () => {
+  it('renders arguments, payments and the call data', () => {
+    const result = koperatorInvokeCommand(ABI, {
+      address: CONTRACT,
+      endpoint: 'buy',
+      args: ['1000000'],
+      payments: [
+        { token: 'KLV', amount: 1000 },
+        { token: 'MYNFT-A1B2/01', amount: '1' },
+      ],
+    });
+    expect(result.args).toEqual(['bi:1000000']);
+    expect(result.values).toBe('KLV=1000,MYNFT-A1B2/01=1');
+    expect(result.callData).toBe('buy@0f4240');
+    expect(result.command).toContain(`sc invoke ${CONTRACT} buy`);
+    expect(result.command).toContain('--values "KLV=1000,MYNFT-A1B2/01=1"');
+  });
+
+  it('rejects unknown endpoints', () => {
+    expect(() => koperatorInvokeCommand(ABI, { address: CONTRACT, endpoint: 'sell' })).toThrow(
+      'Endpoint "sell" not found'
+    );
+  });
+});
@@ -0,0 +1,254 @@
+/**
+ * koperator command lines for deploying, upgrading and calling contracts:
+ * arguments as typed `--args` values, payments as `--values`, and the hex
+ * arguments the transaction will carry so the signer can check them.
+ */
+
+import { encodeAddress } from '../chain/bech32.js';
+import { findAbiEndpoint, findUpgradeSignature, type Abi } from '../abi/index.js';
+import { encodeArguments, encodeTopLevel } from './abi-codec.js';
+import {
+  booleanValue,
+  expandArguments,
+  fieldValues,
+  integerValue,
+  parseAbiType,
+  type AbiArgs,
+} from './abi-values.js';
+import { camelToSnake } from './rust-source.js';
+
+export const KOPERATOR_BIN = '~/klever-sdk/koperator';
+export const DEFAULT_KEY_FILE = '$HOME/klever-sdk/walletKey.pem';
+
+/** `--args` prefix koperator parses each ABI type with; other types are passed as raw bytes */
+export const KOPERATOR_ARG_PREFIXES: Record<string, string> = {
+  u8: 'u8',
+  u16: 'u16',
+  u32: 'u32',
+  usize: 'u32',
+  u64: 'u64',
+  i8: 'i8',
+  i16: 'i16',
+  i32: 'i32',
+  isize: 'i32',
+  i64: 'i64',
+  BigUint: 'bi',
+  BigInt: 'bi',
+  bool: 'bool',
+  Address: 'Address',
+  TokenIdentifier: 'TokenIdentifier',
+  bytes: 'String',
+  utf8string: 'String',
+  Option: 'Option',
+  List: 'List',
+  tuple: 'tuple',
+};
+
+export interface KoperatorPayment {
+  /** KLV, a KDA ID, or `ID/NONCE` for an NFT or SFT */
+  token: string;
+  amount: string | number;
+}
+
+export interface KoperatorCommonOptions {
+  /** Wallet key file on the signing machine (default: $HOME/klever-sdk/walletKey.pem) */
+  keyFile?: string;
+  /** Node URL, set as KLEVER_NODE; omitted to use the signing machine's setting */
+  nodeUrl?: string;
+}
+
+export interface KoperatorDeployOptions extends KoperatorCommonOptions {
+  /** Built contract (default: output/<snake name>.wasm) */
+  wasmPath?: string;
+  args?: AbiArgs;
+  /** Code metadata flags (default: all set); turn upgradeable off for an immutable contract */
+  upgradeable?: boolean;
+  readable?: boolean;
+  payable?: boolean;
+  payableBySC?: boolean;
+}
+
+export interface KoperatorUpgradeOptions extends KoperatorCommonOptions {
+  address: string;
+  wasmPath?: string;
+  args?: AbiArgs;
+}
+
+export interface KoperatorInvokeOptions extends KoperatorCommonOptions {
+  address: string;
+  endpoint: string;
+  args?: AbiArgs;
+  payments?: KoperatorPayment[];
+}
+
+export interface KoperatorCommand {
+  /** The command as a shell line continued with backslashes */
+  command: string;
+  /** Each `--args` value in order */
+  args: string[];
+  /** `--values` payment list, when the call pays */
+  values?: string;
+  /** Hex of each argument as the VM receives it */
+  hexArgs: string[];
+  /** Invoke data the transaction carries (`endpoint@arg@...`) */
+  callData?: string;
+}
+
+/** Quote a shell word: double quotes unless it contains characters they would expand */
+export function shellQuote(word: string): string {
+  return /[$`"\\!]/.test(word) ? `'${word.replace(/'/g, `'\\''`)}'` : `"${word}"`;
+}
+
+function rawBytesArg(type: string, value: unknown, abi: Abi): string {
+  return `bytes:0x${encodeTopLevel(type, value, abi).toString('hex')}`;
+}
+
+/** One koperator `--args` value for a single (non multi-value) argument */
+export function koperatorArgument(type: string, value: unknown, abi: Abi): string {
+  const { name, args } = parseAbiType(type);
+  const prefix = KOPERATOR_ARG_PREFIXES[name];
+  // Validates the value and gives the fallback encoding
+  const raw = rawBytesArg(type, value, abi);
+  switch (name) {
+    case 'bool':
+      return `bool:${booleanValue(value)}`;
+    case 'Address':
+      return `Address:${encodeAddress(encodeTopLevel(type, value, abi))}`;
+    case 'TokenIdentifier':
+      return `TokenIdentifier:${value}`;
+    case 'bytes':
+    case 'utf8string':
+      return /^0x([0-9a-fA-F]{2})*$/.test(value as string) ? `bytes:${value}` : `String:${value}`;
+    case 'Option':
+      return value === null || value === undefined
+        ? 'empty'
+        : `Option:${koperatorArgument(args[0], value, abi)}`;
+    case 'List':
+    case 'tuple': {
+      const items = name === 'List' ? (value as unknown[]) : fieldValues(value, args, type);
+      const rendered = items.map((item, i) =>
+        koperatorArgument(name === 'List' ? args[0] : args[i], item, abi)
+      );
+      // Nested wrappers and values containing commas cannot be written as a flat list
+      const flat = rendered.every(
+        item => !item.includes(',') && !/^(List|tuple|Option|empty)/.test(item)
+      );
+      return flat ? `${prefix}:${rendered.join(',')}` : raw;
+    }
+  }
+  if (prefix) return `${prefix}:${integerValue(value, name)}`;
+  return raw;
+}
+
+/** koperator `--args` values for a constructor or endpoint call, one per VM argument */
+export function koperatorArguments(
+  inputs: { name: string; type: string }[],
+  values: AbiArgs | undefined,
+  abi: Abi,
+  what: string
+): string[] {
+  return expandArguments(inputs, values, what, (type, value) =>
+    koperatorArgument(type, value, abi)
+  );
+}
+
+/** `--values` list: `KLV=1000000,USDT-A1B2=5,MYNFT-A1B2/01=1` */
+export function koperatorValues(payments: KoperatorPayment[]): string {
+  return payments
+    .map(({ token, amount }) => `${token}=${integerValue(amount, `${token} amount`)}`)
+    .join(',');
+}
+
+function render(options: KoperatorCommonOptions, subcommand: string[], flags: string[]): string {
+  const lines = [
+    ...(options.nodeUrl ? [`KLEVER_NODE=${options.nodeUrl}`] : []),
+    KOPERATOR_BIN,
+    // The default keeps $HOME expandable on the signing machine
+    `--key-file=${options.keyFile ? shellQuote(options.keyFile) : `"${DEFAULT_KEY_FILE}"`}`,
+    subcommand.join(' '),
+    ...flags,
+    '--await --sign --result-only',
+  ];
+  return lines.join(' \\\n    ');
+}
+
+function wasmFlag(options: { wasmPath?: string }, abi: Abi): string {
+  return `--wasm=${shellQuote(options.wasmPath ?? `output/${camelToSnake(abi.name)}.wasm`)}`;
+}
+
+function metadataFlags(options: KoperatorDeployOptions): string[] {
+  const flags = (['upgradeable', 'readable', 'payable', 'payableBySC'] as const)
+    .filter(flag => options[flag] !== false)
+    .map(flag => `--${flag}`);
+  return flags.length > 0 ? [flags.join(' ')] : [];
+}
+
+function argFlags(args: string[]): string[] {
+  return args.map(arg => `--args ${shellQuote(arg)}`);
+}
+
+/** `sc create` for a contract, with its constructor arguments */
+export function koperatorDeployCommand(
+  abi: Abi,
+  options: KoperatorDeployOptions = {}
+): KoperatorCommand {
+  const inputs = abi.constructor?.inputs || [];
+  const args = koperatorArguments(inputs, options.args, abi, 'constructor');
+  const hexArgs = encodeArguments(inputs, options.args, abi, 'constructor').map(a =>
+    a.toString('hex')
+  );
+  return {
+    command: render(
+      options,
+      ['sc', 'create'],
+      [wasmFlag(options, abi), ...metadataFlags(options), ...argFlags(args)]
+    ),
+    args,
+    hexArgs,
+  };
+}
+
+/** `sc upgrade` of a deployed contract, with the #[upgrade] arguments */
+export function koperatorUpgradeCommand(
+  abi: Abi,
+  options: KoperatorUpgradeOptions
+): KoperatorCommand {
+  const inputs = findUpgradeSignature(abi)?.inputs || [];
+  const args = koperatorArguments(inputs, options.args, abi, 'upgrade');
+  const hexArgs = encodeArguments(inputs, options.args, abi, 'upgrade').map(a =>
+    a.toString('hex')
+  );
+  return {
+    command: render(
+      options,
+      ['sc', 'upgrade', options.address],
+      [wasmFlag(options, abi), ...argFlags(args)]
+    ),
+    args,
+    hexArgs,
+  };
+}
+
+/** `sc invoke` of an endpoint, with arguments and payments */
+export function koperatorInvokeCommand(
+  abi: Abi,
+  options: KoperatorInvokeOptions
+): KoperatorCommand {
+  const endpoint = findAbiEndpoint(abi, options.endpoint);
+  const args = koperatorArguments(endpoint.inputs, options.args, abi, endpoint.name);
+  const hexArgs = encodeArguments(endpoint.inputs, options.args, abi, endpoint.name).map(a =>
+    a.toString('hex')
+  );
+  const values = options.payments?.length ? koperatorValues(options.payments) : undefined;
+  return {
+    command: render(
+      options,
+      ['sc', 'invoke', options.address, endpoint.name],
+      [...argFlags(args), ...(values ? [`--values ${shellQuote(values)}`] : [])]
+    ),
+    args,
+    ...(values ? { values } : {}),
+    hexArgs,
+    callData: [endpoint.name, ...hexArgs].join('@'),
+  };
+}
@@ -0,0 +1,92 @@
+import { generateMigration } from './migration.js';
+import { addUpgradeSupport } from './upgrade.js';
+
+const contract = (storage: string) => `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait Vault {
+    #[init]
+    fn init(&self) {}
+
+${storage}
+}
+`;
+
+const V1 = contract(`    #[storage_mapper("fee")]
+    fn fee(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("depositors")]
+    fn depositors(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("balance")]
+    fn balance(&self, user: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("legacyFlag")]
+    fn legacy_flag(&self) -> SingleValueMapper<bool>;`);
+
+const V2 = contract(`    #[storage_mapper("feeBps")]
+    fn fee(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("depositors")]
+    fn depositors(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("balance")]
+    fn balance(&self, user: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("maxDeposit")]
+    fn max_deposit(&self) -> SingleValueMapper<BigUint>;`);
+
+describe('generateMigration', () => {
+  it('copies renamed keys, clears removed ones and sets defaults', () => {
+    const { migration, changes } = generateMigration(V1, V2, {
+      defaults: { max_deposit: 'BigUint::from(1_000u64)' },
+    });
+
+    expect(changes.map(c => [c.kind, c.mapper, c.safe])).toEqual([
+      ['key-renamed', 'fee', true],
+      ['type-widened', 'balance', true],
+      ['removed', 'legacy_flag', true],
+      ['added', 'max_deposit', true],
+    ]);
+    expect(migration).toContain(
+      'let old_fee = SingleValueMapper::<Self::Api, u32>::new(StorageKey::new(b"fee"));'
+    );
+    expect(migration).toContain('self.fee().set(u64::from(old_fee.get()));');
+    expect(migration).toContain(
+      'SingleValueMapper::<Self::Api, bool>::new(StorageKey::new(b"legacyFlag")).clear();'
+    );
+    expect(migration).toContain(
+      'if self.max_deposit().is_empty() {\n            self.max_deposit().set(BigUint::from(1_000u64));'
+    );
+  });
+
+  it('flags changes it cannot migrate', () => {
+    const narrowed = V1.replace('SingleValueMapper<u32>', 'SingleValueMapper<ManagedBuffer>');
+    const rekeyed = V2.replace(
+      'fn balance(&self, user: &ManagedAddress)',
+      'fn balance(&self, id: u64)'
+    );
+    const { changes, notes } = generateMigration(narrowed, rekeyed);
+
+    expect(changes.find(c => c.mapper === 'fee')).toMatchObject({
+      kind: 'type-changed',
+      safe: false,
+    });
+    expect(changes.find(c => c.mapper === 'balance')).toMatchObject({
+      kind: 'key-params-changed',
+      safe: false,
+    });
+    expect(notes).toContain('2 change(s) cannot be migrated automatically: fee, balance');
+  });
+
+  it('calls the migration from the matching migrate() step', () => {
+    const upgraded = addUpgradeSupport(V2, 2).source;
+    const { source, functionName } = generateMigration(V1, upgraded, { version: 2 });
+
+    expect(functionName).toBe('migrate_storage_v2');
+    expect(source).toContain('if current < 2 {\n            self.migrate_storage_v2();\n        }');
+    expect(source).toContain('fn migrate_storage_v2(&self) {');
+  });
+});
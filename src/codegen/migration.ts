@@ -0,0 +1,380 @@
+/**
+ * Diff the storage mappers of two versions of a contract and generate the
+ * upgrade-time migration: copies for renamed keys, conversions for widened
+ * types and defaults for new values. Changes that cannot be migrated from
+ * inside the contract are reported instead of guessed at.
+ */
+
+import {
+  appendToTrait,
+  findTraitFunction,
+  listStorageMappers,
+  type StorageMapperDecl,
+} from './rust-source.js';
+
+export type MigrationChangeKind =
+  | 'key-renamed'
+  | 'type-widened'
+  | 'type-changed'
+  | 'mapper-changed'
+  | 'key-params-changed'
+  | 'added'
+  | 'removed';
+
+export interface MigrationChange {
+  kind: MigrationChangeKind;
+  /** Storage function name in the new contract (old name for removed mappers) */
+  mapper: string;
+  detail: string;
+  /** False when the change cannot be migrated by the generated code */
+  safe: boolean;
+}
+
+export interface MigrationOptions {
+  /** Schema version this migration upgrades to; names the function migrate_storage_v<N> */
+  version?: number;
+  /** Initial values for new mappers, as Rust expressions keyed by function name */
+  defaults?: Record<string, string>;
+}
+
+export interface GeneratedMigration {
+  functionName: string;
+  /** Migration function to add to the new contract trait */
+  migration: string;
+  changes: MigrationChange[];
+  /** New source with the migration added (and called from migrate() when possible) */
+  source: string;
+  notes: string[];
+}
+
+/** Integer families in widening order; the last entry is the arbitrary-precision type */
+const WIDENING = [
+  ['u8', 'u16', 'u32', 'u64', 'BigUint'],
+  ['i8', 'i16', 'i32', 'i64', 'BigInt'],
+];
+
+/** Mapper types the generated code can copy to a new key, by their mapper type name */
+const COPYABLE = [
+  'SingleValueMapper',
+  'VecMapper',
+  'SetMapper',
+  'UnorderedSetMapper',
+  'MapMapper',
+];
+
+/** Type argument holding top-encoded values; other arguments are nested-encoded */
+const VALUE_ARG: Record<string, number> = { SingleValueMapper: 0, VecMapper: 0, MapMapper: 1 };
+
+type TypeRelation = 'same' | 'widened' | 'incompatible';
+
+function normalize(type: string): string {
+  return type.replace(/\s+/g, '');
+}
+
+function relate(from: string, to: string): TypeRelation {
+  if (normalize(from) === normalize(to)) return 'same';
+  for (const family of WIDENING) {
+    const a = family.indexOf(from);
+    const b = family.indexOf(to);
+    if (a !== -1 && b !== -1 && a < b) return 'widened';
+  }
+  return 'incompatible';
+}
+
+function convert(expr: string, from: string, to: string): string {
+  return relate(from, to) === 'same' ? expr : `${to}::from(${expr})`;
+}
+
+function rawMapper(decl: StorageMapperDecl): string {
+  return `${decl.mapper}::<Self::Api, ${decl.typeArgs.join(', ')}>::new(StorageKey::new(b"${decl.storageKey}"))`;
+}
+
+/** Statements copying an old mapper's content into the new mapper, converting widened values */
+function copyStatements(oldDecl: StorageMapperDecl, newDecl: StorageMapperDecl): string[] {
+  const name = newDecl.functionName;
+  const old = `old_${name}`;
+  const value = (expr: string, i: number) =>
+    convert(expr, oldDecl.typeArgs[i], newDecl.typeArgs[i]);
+  switch (oldDecl.mapper) {
+    case 'SingleValueMapper':
+      return [
+        `let ${old} = ${rawMapper(oldDecl)};`,
+        `if !${old}.is_empty() {`,
+        `    self.${name}().set(${value(`${old}.get()`, 0)});`,
+        `    ${old}.clear();`,
+        '}',
+      ];
+    case 'VecMapper':
+      return [
+        `let mut ${old} = ${rawMapper(oldDecl)};`,
+        `for item in ${old}.iter() {`,
+        `    self.${name}().push(&${value('item', 0)});`,
+        '}',
+        `${old}.clear();`,
+      ];
+    case 'MapMapper':
+      return [
+        `let mut ${old} = ${rawMapper(oldDecl)};`,
+        `for (key, value) in ${old}.iter() {`,
+        `    self.${name}().insert(key, ${value('value', 1)});`,
+        '}',
+        `${old}.clear();`,
+      ];
+    default:
+      return [
+        `let mut ${old} = ${rawMapper(oldDecl)};`,
+        `for item in ${old}.iter() {`,
+        `    self.${name}().insert(item);`,
+        '}',
+        `${old}.clear();`,
+      ];
+  }
+}
+
+/** Describe and, where possible, migrate one mapper present in both versions */
+export function diffStorageMapper(
+  oldDecl: StorageMapperDecl,
+  newDecl: StorageMapperDecl
+): { change?: MigrationChange; statements: string[] } {
+  const mapper = newDecl.functionName;
+  const keyRenamed = oldDecl.storageKey !== newDecl.storageKey;
+  const oldParams = oldDecl.keyParams.map(p => normalize(p.type)).join(', ');
+  const newParams = newDecl.keyParams.map(p => normalize(p.type)).join(', ');
+
+  if (oldParams !== newParams) {
+    return {
+      change: {
+        kind: 'key-params-changed',
+        mapper,
+        detail: `key arguments changed from (${oldParams}) to (${newParams}); existing entries cannot be enumerated to re-key them`,
+        safe: false,
+      },
+      statements: [],
+    };
+  }
+  if (oldDecl.mapper !== newDecl.mapper || oldDecl.typeArgs.length !== newDecl.typeArgs.length) {
+    return {
+      change: {
+        kind: 'mapper-changed',
+        mapper,
+        detail: `${oldDecl.returnType} became ${newDecl.returnType}; the storage layouts differ and need a hand-written migration`,
+        safe: false,
+      },
+      statements: [],
+    };
+  }
+
+  const relations = oldDecl.typeArgs.map((t, i) => relate(t, newDecl.typeArgs[i]));
+  const retyped = relations.some(r => r !== 'same');
+  const valueArg = VALUE_ARG[newDecl.mapper];
+  const typeDetail = `${oldDecl.returnType} -> ${newDecl.returnType}`;
+  if (relations.some(r => r === 'incompatible')) {
+    return {
+      change: {
+        kind: 'type-changed',
+        mapper,
+        detail: `${typeDetail} is not a widening; stored values would no longer decode`,
+        safe: false,
+      },
+      statements: [],
+    };
+  }
+  if (relations.some((r, i) => r === 'widened' && i !== valueArg)) {
+    return {
+      change: {
+        kind: 'type-changed',
+        mapper,
+        detail: `${typeDetail} widens a nested-encoded key or item, which changes its encoded length`,
+        safe: false,
+      },
+      statements: [],
+    };
+  }
+
+  if (!keyRenamed) {
+    if (!retyped) return { statements: [] };
+    // Top-level encoding of integers is minimal big-endian, so widened values decode unchanged
+    return {
+      change: {
+        kind: 'type-widened',
+        mapper,
+        detail: `${typeDetail}: existing values decode as the wider type, no data rewrite needed`,
+        safe: true,
+      },
+      statements: [],
+    };
+  }
+
+  const rename = `key "${oldDecl.storageKey}" renamed to "${newDecl.storageKey}"`;
+  if (newDecl.keyParams.length > 0 || !COPYABLE.includes(newDecl.mapper)) {
+    return {
+      change: {
+        kind: 'key-renamed',
+        mapper,
+        detail:
+          newDecl.keyParams.length > 0
+            ? `${rename}, but per-key entries cannot be enumerated; keep the old key or migrate from an off-chain key list`
+            : `${rename}, but ${newDecl.mapper} has no generic copy; migrate it by hand`,
+        safe: false,
+      },
+      statements: [],
+    };
+  }
+  return {
+    change: {
+      kind: 'key-renamed',
+      mapper,
+      detail: retyped ? `${rename} (${typeDetail})` : rename,
+      safe: true,
+    },
+    statements: [`// ${mapper}: ${rename}`, ...copyStatements(oldDecl, newDecl)],
+  };
+}
+
+/**
+ * Generate a storage migration from `oldSource` to `newSource` (both src/lib.rs)
+ */
+export function generateMigration(
+  oldSource: string,
+  newSource: string,
+  options: MigrationOptions = {}
+): GeneratedMigration {
+  const version = options.version;
+  if (version !== undefined && (!Number.isInteger(version) || version < 1)) {
+    throw new Error('version must be a positive integer.');
+  }
+  const functionName = version !== undefined ? `migrate_storage_v${version}` : 'migrate_storage';
+  if (findTraitFunction(newSource, functionName)) {
+    throw new Error(`Contract already has a function named "${functionName}".`);
+  }
+
+  const oldMappers = listStorageMappers(oldSource);
+  const newMappers = listStorageMappers(newSource);
+  const defaults = options.defaults || {};
+  const unknownDefaults = Object.keys(defaults).filter(
+    name => !newMappers.some(m => m.functionName === name)
+  );
+  if (unknownDefaults.length > 0) {
+    throw new Error(`defaults refer to unknown storage mappers: ${unknownDefaults.join(', ')}`);
+  }
+
+  const changes: MigrationChange[] = [];
+  const notes: string[] = [];
+  const blocks: string[][] = [];
+  const matched = new Set<StorageMapperDecl>();
+
+  for (const oldDecl of oldMappers) {
+    // Same function name (possibly a new key), else same key (function renamed)
+    const newDecl =
+      newMappers.find(m => m.functionName === oldDecl.functionName) ||
+      newMappers.find(m => m.storageKey === oldDecl.storageKey && !matched.has(m));
+    if (!newDecl || matched.has(newDecl)) {
+      const clearable = oldDecl.mapper === 'SingleValueMapper' && oldDecl.keyParams.length === 0;
+      changes.push({
+        kind: 'removed',
+        mapper: oldDecl.functionName,
+        detail: clearable
+          ? `key "${oldDecl.storageKey}" is no longer used and is cleared`
+          : `key "${oldDecl.storageKey}" is no longer used; its entries stay in storage`,
+        safe: true,
+      });
+      if (clearable) {
+        blocks.push([
+          `// ${oldDecl.functionName}: removed`,
+          `${rawMapper(oldDecl)}.clear();`,
+        ]);
+      }
+      continue;
+    }
+    matched.add(newDecl);
+    const { change, statements } = diffStorageMapper(oldDecl, newDecl);
+    if (change) changes.push(change);
+    if (statements.length > 0) blocks.push(statements);
+  }
+
+  for (const newDecl of newMappers.filter(m => !matched.has(m))) {
+    const name = newDecl.functionName;
+    const initial = defaults[name];
+    const settable = newDecl.mapper === 'SingleValueMapper' && newDecl.keyParams.length === 0;
+    if (initial === undefined) {
+      changes.push({
+        kind: 'added',
+        mapper: name,
+        detail: `new key "${newDecl.storageKey}" starts empty (zero/default value)`,
+        safe: true,
+      });
+    } else if (!settable) {
+      changes.push({
+        kind: 'added',
+        mapper: name,
+        detail: `a default only applies to SingleValueMapper without key arguments, not ${newDecl.returnType}; initialise entries where they are first read`,
+        safe: false,
+      });
+    } else {
+      changes.push({
+        kind: 'added',
+        mapper: name,
+        detail: `new key "${newDecl.storageKey}" initialised to ${initial}`,
+        safe: true,
+      });
+      blocks.push([
+        `// ${name}: new, defaults to ${initial}`,
+        `if self.${name}().is_empty() {`,
+        `    self.${name}().set(${initial});`,
+        '}',
+      ]);
+    }
+  }
+
+  const possibleRenames = changes.filter(c => c.kind === 'removed');
+  for (const removed of possibleRenames) {
+    const oldDecl = oldMappers.find(m => m.functionName === removed.mapper)!;
+    const candidate = changes.find(
+      c =>
+        c.kind === 'added' &&
+        normalize(newMappers.find(m => m.functionName === c.mapper)!.returnType) ===
+          normalize(oldDecl.returnType)
+    );
+    if (candidate) {
+      notes.push(
+        `${removed.mapper} was removed and ${candidate.mapper} added with the same type; if this is a rename, keep the storage key "${oldDecl.storageKey}" so no data moves.`
+      );
+    }
+  }
+
+  const body = blocks.length > 0 ? blocks : [['// No storage changes to migrate']];
+  const migration = [
+    `    /// Storage migration${version !== undefined ? ` to schema v${version}` : ''}, generated from the storage mapper diff.`,
+    '    /// Run it once, from #[upgrade]; every step is safe to run on already-migrated storage.',
+    `    fn ${functionName}(&self) {`,
+    body.map(block => block.map(line => `        ${line}`).join('\n')).join('\n\n'),
+    '    }',
+  ].join('\n');
+
+  let source = appendToTrait(newSource, migration);
+  const step =
+    version !== undefined
+      ? new RegExp(`(if current < ${version} \\{\\n)\\s*// TODO: transform storage[^\\n]*\\n`)
+      : undefined;
+  if (step && step.test(source)) {
+    source = source.replace(step, `$1            self.${functionName}();\n`);
+    notes.push(`Called ${functionName}() from the v${version} step of migrate().`);
+  } else {
+    notes.push(
+      `Call self.${functionName}() from #[upgrade] (or a migrate() step added with add_upgrade_support).`
+    );
+  }
+  if (blocks.some(b => b.some(line => line.includes('.iter()')))) {
+    notes.push(
+      'Copying collections iterates every entry in one transaction; check the gas cost against the largest expected collection.'
+    );
+  }
+  const unsafe = changes.filter(c => !c.safe);
+  if (unsafe.length > 0) {
+    notes.push(
+      `${unsafe.length} change(s) cannot be migrated automatically: ${unsafe.map(c => c.mapper).join(', ')}`
+    );
+  }
+
+  return { functionName, migration, changes, source, notes };
+}
@@ -0,0 +1,51 @@
+import { splitIntoModules } from './modules.js';
+import { listTraitFunctions } from './rust-source.js';
+import { generateContractProject } from '../scaffold/index.js';
+
+const TOKEN = generateContractProject('basic_token', 'token').files['src/lib.rs'];
+
+describe('splitIntoModules', () => {
+  it('extracts storage, events and admin modules with supertrait wiring', () => {
+    const { files, modules, remaining } = splitIntoModules(TOKEN);
+
+    expect(modules.map(m => [m.traitName, m.functions])).toEqual([
+      ['StorageModule', ['balance', 'total_supply', 'owner']],
+      ['EventsModule', ['transfer_event', 'mint_event', 'burn_event']],
+      ['AdminModule', ['mint', 'require_owner']],
+    ]);
+    expect(remaining).toEqual([
+      'init',
+      'upgrade',
+      'transfer',
+      'burn',
+      'get_balance',
+      'get_total_supply',
+    ]);
+
+    expect(files['src/admin.rs']).toContain(
+      '#[klever_sc::module]\npub trait AdminModule: crate::storage::StorageModule + crate::events::EventsModule {'
+    );
+    expect(files['src/storage.rs']).toContain('#[klever_sc::module]\npub trait StorageModule {');
+    expect(files['src/lib.rs']).toContain(
+      'use klever_sc::imports::*;\n\npub mod storage;\npub mod events;\npub mod admin;\n\n'
+    );
+    expect(files['src/lib.rs']).toContain(
+      'pub trait Token: storage::StorageModule + events::EventsModule + admin::AdminModule {'
+    );
+    expect(files['src/lib.rs']).not.toContain('// Storage');
+    expect(listTraitFunctions(files['src/lib.rs']).map(f => f.name)).toEqual(remaining);
+  });
+
+  it('only extracts the requested module kinds', () => {
+    const { files, modules } = splitIntoModules(TOKEN, ['events']);
+
+    expect(modules.map(m => m.kind)).toEqual(['events']);
+    expect(files['src/lib.rs']).toContain('pub trait Token: events::EventsModule {');
+    expect(files['src/lib.rs']).toContain('fn require_owner(&self)');
+  });
+
+  it('rejects contracts with nothing to extract', () => {
+    const empty = '#[klever_sc::contract]\npub trait Empty {\n    #[init]\n    fn init(&self) {}\n}\n';
+    expect(() => splitIntoModules(empty)).toThrow('Nothing to extract');
+  });
+});
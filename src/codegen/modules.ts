@@ -0,0 +1,218 @@
+/**
+ * Split a monolithic contract trait into klever-sc modules (storage, events,
+ * admin), wiring the modules in as supertraits of the contract.
+ */
+
+import {
+  addContractSupertraits,
+  addModDeclarations,
+  findContractTrait,
+  formatTraitHeader,
+  listTraitFunctions,
+  type FunctionLocation,
+} from './rust-source.js';
+import type { FileMap } from '../scaffold/types.js';
+
+export type ModuleKind = 'storage' | 'events' | 'admin';
+
+export interface ExtractedModule {
+  kind: ModuleKind;
+  /** File path relative to the project root, e.g. src/storage.rs */
+  file: string;
+  traitName: string;
+  functions: string[];
+  /** Other extracted modules this module calls into */
+  supertraits: string[];
+}
+
+export interface SplitResult {
+  files: FileMap;
+  modules: ExtractedModule[];
+  /** Functions left in the contract trait */
+  remaining: string[];
+  notes: string[];
+}
+
+const MODULES: Record<ModuleKind, { file: string; traitName: string }> = {
+  storage: { file: 'storage', traitName: 'StorageModule' },
+  events: { file: 'events', traitName: 'EventsModule' },
+  admin: { file: 'admin', traitName: 'AdminModule' },
+};
+const MODULE_ORDER: ModuleKind[] = ['storage', 'events', 'admin'];
+
+const OWNER_GUARD = /^(require|ensure|check)_(owner|admin|only_owner)\w*$|^only_(owner|admin)$/;
+
+function hasAttribute(fn: FunctionLocation, pattern: RegExp): boolean {
+  return fn.attributes.some(a => pattern.test(a));
+}
+
+function calledFunctions(source: string, fn: FunctionLocation): Set<string> {
+  const calls = new Set<string>();
+  if (fn.bodyStart === -1) return calls;
+  for (const match of source.slice(fn.bodyStart, fn.end).matchAll(/\bself\.(\w+)\s*\(/g)) {
+    calls.add(match[1]);
+  }
+  return calls;
+}
+
+/** Assign each trait function to a module, or leave it in the contract (undefined) */
+function classify(source: string, functions: FunctionLocation[]): Map<string, ModuleKind> {
+  const placement = new Map<string, ModuleKind>();
+  const byName = new Map(functions.map(f => [f.name, f]));
+  const calls = new Map(functions.map(f => [f.name, calledFunctions(source, f)]));
+
+  for (const fn of functions) {
+    if (hasAttribute(fn, /^#\[storage_mapper\(/)) {
+      placement.set(fn.name, 'storage');
+    } else if (hasAttribute(fn, /^#\[event\(/)) {
+      placement.set(fn.name, 'events');
+    } else if (hasAttribute(fn, /^#\[(init|upgrade)\]/)) {
+      continue;
+    } else if (
+      OWNER_GUARD.test(fn.name) ||
+      hasAttribute(fn, /^#\[only_(owner|admin)\]/) ||
+      [...calls.get(fn.name)!].some(c => OWNER_GUARD.test(c))
+    ) {
+      placement.set(fn.name, 'admin');
+    }
+  }
+
+  // Admin functions may only call storage, events, other admin functions, or plain
+  // helpers (which move along); otherwise they stay in the contract trait
+  let changed = true;
+  while (changed) {
+    changed = false;
+    for (const [name, kind] of placement) {
+      if (kind !== 'admin') continue;
+      for (const callee of calls.get(name)!) {
+        const target = byName.get(callee);
+        if (!target || placement.has(callee)) continue;
+        if (target.attributes.length === 0) {
+          placement.set(callee, 'admin');
+        } else {
+          placement.delete(name);
+        }
+        changed = true;
+        break;
+      }
+    }
+  }
+  return placement;
+}
+
+/** Split the whitespace before a function into a detached part and attached `//` comments */
+function splitGap(gap: string): { detached: string; attached: string } {
+  const lines = gap.split('\n');
+  // The last line is the indentation before the function itself
+  let i = lines.length - 1;
+  while (i > 0 && /^\s*\/\/(?!\/)/.test(lines[i - 1])) i--;
+  return {
+    detached: lines.slice(0, i).join('\n'),
+    attached: lines
+      .slice(i, lines.length - 1)
+      .map(l => `${l}\n`)
+      .join(''),
+  };
+}
+
+function tidy(text: string): string {
+  return text.replace(/[ \t]+$/gm, '').replace(/\n{3,}/g, '\n\n');
+}
+
+/** Crate-level structs and enums a module body refers to */
+function referencedTypes(topLevel: string, body: string): { names: string[]; private: string[] } {
+  const names: string[] = [];
+  const hidden: string[] = [];
+  for (const match of topLevel.matchAll(/^(pub\s+)?(?:struct|enum)\s+(\w+)/gm)) {
+    if (new RegExp(`\\b${match[2]}\\b`).test(body)) {
+      names.push(match[2]);
+      if (!match[1]) hidden.push(match[2]);
+    }
+  }
+  return { names, private: hidden };
+}
+
+/**
+ * Refactor the contract trait in `source` (src/lib.rs) into module files
+ */
+export function splitIntoModules(
+  source: string,
+  kinds: ModuleKind[] = MODULE_ORDER
+): SplitResult {
+  const trait = findContractTrait(source);
+  const functions = listTraitFunctions(source);
+  const placement = classify(source, functions);
+  for (const [name, kind] of placement) {
+    if (!kinds.includes(kind)) placement.delete(name);
+  }
+  if (placement.size === 0) {
+    throw new Error('Nothing to extract: no storage mappers, events or owner-only functions found.');
+  }
+
+  const notes: string[] = [];
+  const bodies: Record<ModuleKind, string[]> = { storage: [], events: [], admin: [] };
+  let contractBody = '';
+  let cursor = trait.bodyStart;
+  for (const fn of functions) {
+    const { detached, attached } = splitGap(source.slice(cursor, fn.start));
+    const text = attached + source.slice(fn.start, fn.end + 1);
+    const kind = placement.get(fn.name);
+    if (kind) {
+      bodies[kind].push(text);
+    } else {
+      contractBody += `${detached}\n${text}`;
+    }
+    cursor = fn.end + 1;
+  }
+  contractBody += source.slice(cursor, trait.bodyEnd);
+
+  const present = MODULE_ORDER.filter(kind => bodies[kind].length > 0);
+  const topLevel = source.slice(0, trait.itemStart) + source.slice(trait.bodyEnd + 1);
+  const files: FileMap = {};
+  const modules: ExtractedModule[] = [];
+
+  for (const kind of present) {
+    const { file, traitName } = MODULES[kind];
+    const names = functions.filter(f => placement.get(f.name) === kind).map(f => f.name);
+    const called = new Set(
+      functions.filter(f => names.includes(f.name)).flatMap(f => [...calledFunctions(source, f)])
+    );
+    const deps = present.filter(
+      other =>
+        other !== kind &&
+        functions.some(f => placement.get(f.name) === other && called.has(f.name))
+    );
+    const supertraits = deps.map(d => `crate::${MODULES[d].file}::${MODULES[d].traitName}`);
+
+    const body = bodies[kind].join('\n\n');
+    const types = referencedTypes(topLevel, body);
+    const imports = ['use klever_sc::imports::*;'];
+    if (types.names.length > 0) imports.push(`use crate::{${types.names.join(', ')}};`);
+    for (const name of types.private) {
+      notes.push(`${name} is used by ${file}.rs; make it pub so the module can import it.`);
+    }
+
+    files[`src/${file}.rs`] = tidy(
+      `${imports.join('\n')}\n\n#[klever_sc::module]\n${formatTraitHeader(
+        `pub trait ${traitName}`,
+        supertraits
+      )}\n${body}\n}\n`
+    );
+    modules.push({ kind, file: `src/${file}.rs`, traitName, functions: names, supertraits });
+  }
+
+  // Rewrite lib.rs: remaining functions, supertraits and module declarations
+  let lib = `${source.slice(0, trait.bodyStart)}${contractBody.replace(/^\n*/, '\n')}${source.slice(
+    trait.bodyEnd
+  )}`;
+  lib = addContractSupertraits(
+    lib,
+    present.map(kind => `${MODULES[kind].file}::${MODULES[kind].traitName}`)
+  );
+  lib = addModDeclarations(lib, present.map(kind => MODULES[kind].file));
+  files['src/lib.rs'] = tidy(lib);
+
+  const remaining = functions.filter(f => !placement.has(f.name)).map(f => f.name);
+  notes.push('Endpoints keep their names, so the wasm crate endpoint list does not change.');
+  return { files, modules, remaining, notes };
+}
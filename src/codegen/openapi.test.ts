@@ -0,0 +1,122 @@
+import { parseAbi } from '../abi/index.js';
+import { abiTypeSchema, generateOpenApi } from './openapi.js';
+
+const ABI = parseAbi({
+  buildInfo: { contractCrate: { name: 'token-vault', version: '1.2.0' } },
+  name: 'token_vault',
+  endpoints: [
+    {
+      name: 'deposit',
+      mutability: 'mutable',
+      payableInTokens: ['KLV'],
+      inputs: [{ name: 'lock_until', type: 'optional<u64>' }],
+      outputs: [],
+    },
+    {
+      name: 'getPosition',
+      docs: ['Position of an account'],
+      mutability: 'readonly',
+      inputs: [
+        { name: 'owner', type: 'Address' },
+        { name: 'ids', type: 'List<u32>' },
+      ],
+      outputs: [{ type: 'Option<Position>' }],
+    },
+  ],
+  types: {
+    Position: {
+      type: 'struct',
+      fields: [
+        { name: 'amount', type: 'BigUint' },
+        { name: 'status', type: 'Status' },
+      ],
+    },
+    Status: {
+      type: 'enum',
+      variants: [
+        { name: 'Open', discriminant: 0 },
+        { name: 'Locked', discriminant: 1, fields: [{ name: 'until', type: 'u64' }] },
+      ],
+    },
+  },
+});
+
+type Operation = {
+  parameters: Record<string, unknown>[];
+  requestBody: { content: { 'application/json': { schema: Record<string, unknown> } } };
+  responses: Record<string, { content: { 'application/json': { schema: unknown } } }>;
+};
+
+describe('generateOpenApi', () => {
+  const { path, spec, views, endpoints } = generateOpenApi(ABI, {
+    serverUrl: 'https://vault.example.com',
+  });
+  const paths = spec.paths as Record<string, Record<string, Operation>>;
+
+  it('describes the contract', () => {
+    expect(path).toBe('token-vault.openapi.json');
+    expect(spec.openapi).toBe('3.1.0');
+    expect(spec.info).toMatchObject({ title: 'token_vault contract', version: '1.2.0' });
+    expect(spec.servers).toEqual([{ url: 'https://vault.example.com' }]);
+    expect(views).toEqual(['getPosition']);
+    expect(endpoints).toEqual(['deposit']);
+  });
+
+  it('queries views with GET and their arguments as query parameters', () => {
+    const view = paths['/views/getPosition'].get;
+    expect(view).toMatchObject({ operationId: 'getPosition', summary: 'Position of an account' });
+    expect(view.parameters[0]).toMatchObject({ name: 'owner', in: 'query', required: true });
+    // Compound values are JSON text
+    expect(view.parameters[1].content).toEqual({
+      'application/json': { schema: { type: 'array', items: abiTypeSchema('u32', ABI) } },
+    });
+    expect(view.responses['200'].content['application/json'].schema).toMatchObject({
+      properties: {
+        results: {
+          prefixItems: [{ oneOf: [{ $ref: '#/components/schemas/Position' }, { type: 'null' }] }],
+        },
+      },
+    });
+  });
+
+  it('builds transactions for endpoints with POST', () => {
+    const post = paths['/endpoints/deposit'].post;
+    const schema = post.requestBody.content['application/json'].schema;
+    expect(schema.required).toEqual(['sender', 'args']);
+    expect(schema.properties).toMatchObject({
+      args: { required: [] },
+      callValue: { $ref: '#/components/schemas/CallValue' },
+    });
+    expect(post.responses['200'].content['application/json'].schema).toEqual({
+      $ref: '#/components/schemas/UnsignedTransaction',
+    });
+  });
+
+  it('declares the custom types as JSON schemas', () => {
+    const schemas = (spec.components as { schemas: Record<string, unknown> }).schemas;
+    expect(schemas.Position).toMatchObject({ required: ['amount', 'status'] });
+    expect(schemas.Status).toEqual({
+      oneOf: [
+        { type: 'string', enum: ['Open'] },
+        {
+          type: 'object',
+          properties: {
+            Locked: {
+              type: 'object',
+              properties: { until: abiTypeSchema('u64', ABI) },
+              required: ['until'],
+              additionalProperties: false,
+            },
+          },
+          required: ['Locked'],
+          additionalProperties: false,
+        },
+      ],
+    });
+    expect(abiTypeSchema('u64', ABI)).toMatchObject({ type: ['integer', 'string'], minimum: 0 });
+    expect(abiTypeSchema('array32<u8>', ABI)).toEqual({
+      type: 'string',
+      pattern: '^0x[0-9a-fA-F]{64}$',
+    });
+  });
+});
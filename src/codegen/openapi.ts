@@ -0,0 +1,325 @@
+/**
+ * Describe a contract's views and endpoints as an OpenAPI 3.1 document, for
+ * gateways that expose a contract over REST: views are GET operations whose
+ * query parameters are the view arguments, endpoints are POST operations that
+ * build an unsigned transaction from a sender and arguments. Values follow the
+ * plain-JSON conventions of the ABI codec (see abi-values.ts).
+ */
+
+import type { Abi, AbiEndpoint, AbiInput, AbiTypeDef } from '../abi/index.js';
+import { parseAbiType } from './abi-values.js';
+import { camelToSnake } from './rust-source.js';
+
+export type JsonSchema = Record<string, unknown>;
+
+export interface OpenApiOptions {
+  /** Base URL of the gateway serving the operations */
+  serverUrl?: string;
+  /** Contract address (klv1...) the gateway targets, noted in the description */
+  address?: string;
+  /** Network name the contract is deployed on, noted in the description */
+  network?: string;
+}
+
+export interface GeneratedOpenApi {
+  /** Suggested file name */
+  path: string;
+  spec: Record<string, unknown>;
+  /** Operation IDs of the GET operations */
+  views: string[];
+  /** Operation IDs of the POST operations */
+  endpoints: string[];
+}
+
+const BOUNDS: Record<string, [number, number]> = {
+  u8: [0, 255],
+  u16: [0, 65535],
+  u32: [0, 4294967295],
+  usize: [0, 4294967295],
+  i8: [-128, 127],
+  i16: [-32768, 32767],
+  i32: [-2147483648, 2147483647],
+  isize: [-2147483648, 2147483647],
+};
+
+/** Integers that may not fit a JSON number: exact numbers or decimal strings */
+const LARGE_INTEGERS = ['u64', 'i64', 'BigUint', 'BigInt'];
+
+const ADDRESS: JsonSchema = {
+  type: 'string',
+  pattern: '^klv1[02-9ac-hj-np-z]{58}$',
+  description: 'Bech32 address',
+};
+
+/** Schemas the document adds next to the ABI types */
+const GATEWAY_SCHEMAS = ['CallValue', 'UnsignedTransaction', 'GatewayError'];
+
+const ref = (name: string): JsonSchema => ({ $ref: `#/components/schemas/${name}` });
+
+/** JSON Schema of ABI `type` as the codec reads and writes it */
+export function abiTypeSchema(type: string, abi: Abi): JsonSchema {
+  const { name, args } = parseAbiType(type);
+  if (Object.hasOwn(BOUNDS, name)) {
+    const [minimum, maximum] = BOUNDS[name];
+    return { type: 'integer', minimum, maximum };
+  }
+  if (LARGE_INTEGERS.includes(name)) {
+    const signed = name.startsWith('i') || name === 'BigInt';
+    return {
+      type: ['integer', 'string'],
+      pattern: signed ? '^-?\\d+$' : '^\\d+$',
+      ...(signed ? {} : { minimum: 0 }),
+      description: `${name}: a number while exact, otherwise a decimal string`,
+    };
+  }
+  switch (name) {
+    case 'bool':
+      return { type: 'boolean' };
+    case 'Address':
+      return ADDRESS;
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return { type: 'string' };
+    case 'bytes':
+      return { type: 'string', description: 'UTF-8 text, or 0x-prefixed hex' };
+    case 'Option':
+    case 'optional':
+      return { oneOf: [abiTypeSchema(args[0], abi), { type: 'null' }] };
+    case 'List':
+    case 'variadic':
+      return { type: 'array', items: abiTypeSchema(args[0], abi) };
+    case 'tuple':
+    case 'multi':
+      return {
+        type: 'array',
+        prefixItems: args.map(arg => abiTypeSchema(arg, abi)),
+        minItems: args.length,
+        maxItems: args.length,
+      };
+  }
+  if (/^array\d+$/.test(name)) {
+    const length = parseInt(name.slice('array'.length), 10);
+    if (args[0] === 'u8') return { type: 'string', pattern: `^0x[0-9a-fA-F]{${length * 2}}$` };
+    return {
+      type: 'array',
+      items: abiTypeSchema(args[0], abi),
+      minItems: length,
+      maxItems: length,
+    };
+  }
+  if (abi.types && Object.hasOwn(abi.types, name)) return ref(name);
+  throw new Error(`Unknown ABI type ${type}`);
+}
+
+function objectSchema(fields: { name: string; type: string }[], abi: Abi): JsonSchema {
+  return {
+    type: 'object',
+    properties: Object.fromEntries(fields.map(f => [f.name, abiTypeSchema(f.type, abi)])),
+    required: fields.map(f => f.name),
+    additionalProperties: false,
+  };
+}
+
+/** Structs are objects by field name, enums a unit variant name or `{ Variant: fields }` */
+function typeSchema(def: AbiTypeDef, abi: Abi): JsonSchema {
+  const docs = (def.docs || []).join(' ').trim();
+  const description = docs ? { description: docs } : {};
+  if (def.type === 'struct') return { ...objectSchema(def.fields, abi), ...description };
+  const units = def.variants.filter(v => !v.fields || v.fields.length === 0);
+  const fielded = def.variants.filter(v => v.fields && v.fields.length > 0);
+  const options: JsonSchema[] = [
+    ...(units.length > 0 ? [{ type: 'string', enum: units.map(v => v.name) }] : []),
+    ...fielded.map(v => ({
+      type: 'object',
+      properties: { [v.name]: objectSchema(v.fields || [], abi) },
+      required: [v.name],
+      additionalProperties: false,
+    })),
+  ];
+  return options.length === 1
+    ? { ...options[0], ...description }
+    : { oneOf: options, ...description };
+}
+
+function operationDocs(endpoint: AbiEndpoint): { summary?: string; description?: string } {
+  const docs = (endpoint.docs || []).map(d => d.trim()).filter(Boolean);
+  const notes = [...docs];
+  const tokens = endpoint.payableInTokens || [];
+  if (tokens.length > 0) {
+    notes.push(`Payable in ${tokens.includes('*') ? 'any token' : tokens.join(', ')}.`);
+  }
+  if (endpoint.onlyOwner) notes.push('Only the contract owner may call it.');
+  return {
+    ...(docs.length > 0 ? { summary: docs[0] } : {}),
+    ...(notes.length > 0 ? { description: notes.join('\n\n') } : {}),
+  };
+}
+
+/** Types passed as plain query parameters, with the small integers; others are JSON text */
+const SCALARS = ['bool', 'Address', 'utf8string', 'TokenIdentifier', 'bytes'];
+
+const isOptional = (input: AbiInput) => parseAbiType(input.type).name === 'optional';
+
+/** Values of `outputs`, one array item each as the codec decodes them */
+function resultsSchema(endpoint: AbiEndpoint, abi: Abi): JsonSchema {
+  const outputs = endpoint.outputs.map(output => abiTypeSchema(output.type, abi));
+  return {
+    type: 'object',
+    properties: {
+      results: {
+        type: 'array',
+        prefixItems: outputs,
+        minItems: outputs.length,
+        maxItems: outputs.length,
+      },
+    },
+    required: ['results'],
+  };
+}
+
+function viewOperation(endpoint: AbiEndpoint, abi: Abi): Record<string, unknown> {
+  const parameters = endpoint.inputs.map(input => {
+    const schema = abiTypeSchema(input.type, abi);
+    return {
+      name: input.name,
+      in: 'query',
+      required: !isOptional(input),
+      ...(SCALARS.includes(input.type) || Object.hasOwn(BOUNDS, input.type)
+        ? { schema }
+        : { content: { 'application/json': { schema } } }),
+    };
+  });
+  return {
+    operationId: endpoint.name,
+    tags: ['views'],
+    ...operationDocs(endpoint),
+    ...(parameters.length > 0 ? { parameters } : {}),
+    responses: {
+      '200': {
+        description: 'Decoded results of the view',
+        content: { 'application/json': { schema: resultsSchema(endpoint, abi) } },
+      },
+      default: { $ref: '#/components/responses/Error' },
+    },
+  };
+}
+
+function endpointOperation(endpoint: AbiEndpoint, abi: Abi): Record<string, unknown> {
+  const payable = (endpoint.payableInTokens || []).length > 0;
+  const properties: Record<string, JsonSchema> = {
+    sender: { ...ADDRESS, description: 'Address the transaction is built for and signed by' },
+    args: {
+      type: 'object',
+      properties: Object.fromEntries(
+        endpoint.inputs.map(input => [input.name, abiTypeSchema(input.type, abi)])
+      ),
+      required: endpoint.inputs.filter(input => !isOptional(input)).map(input => input.name),
+      additionalProperties: false,
+    },
+    ...(payable ? { callValue: ref('CallValue') } : {}),
+    gasLimit: { type: 'integer', minimum: 1 },
+  };
+  return {
+    operationId: endpoint.name,
+    tags: ['endpoints'],
+    ...operationDocs(endpoint),
+    requestBody: {
+      required: true,
+      content: {
+        'application/json': {
+          schema: {
+            type: 'object',
+            properties,
+            required: endpoint.inputs.length > 0 ? ['sender', 'args'] : ['sender'],
+            additionalProperties: false,
+          },
+        },
+      },
+    },
+    responses: {
+      '200': {
+        description: 'Unsigned transaction calling the endpoint',
+        content: { 'application/json': { schema: ref('UnsignedTransaction') } },
+      },
+      default: { $ref: '#/components/responses/Error' },
+    },
+  };
+}
+
+/** Render the OpenAPI document for `abi` */
+export function generateOpenApi(abi: Abi, options: OpenApiOptions = {}): GeneratedOpenApi {
+  const views = abi.endpoints.filter(e => e.mutability === 'readonly');
+  const endpoints = abi.endpoints.filter(e => e.mutability !== 'readonly');
+  const definitions = abi.types || {};
+  const clash = Object.keys(definitions).find(name => GATEWAY_SCHEMAS.includes(name));
+  if (clash) throw new Error(`ABI type ${clash} has the name of a gateway schema`);
+
+  const paths: Record<string, unknown> = {};
+  for (const view of views) paths[`/views/${view.name}`] = { get: viewOperation(view, abi) };
+  for (const endpoint of endpoints) {
+    paths[`/endpoints/${endpoint.name}`] = { post: endpointOperation(endpoint, abi) };
+  }
+
+  const deployment = options.address
+    ? `Deployed at ${options.address}${options.network ? ` on ${options.network}` : ''}.`
+    : '';
+  const description = [
+    ...(abi.docs || []).map(d => d.trim()).filter(Boolean),
+    deployment,
+    'GET /views/{name} queries a view; POST /endpoints/{name} builds an unsigned transaction, ' +
+      'whose txHash the sender signs with ed25519 before broadcasting it with the signature.',
+  ].filter(Boolean);
+
+  const spec = {
+    openapi: '3.1.0',
+    info: {
+      title: `${abi.name} contract`,
+      version: abi.buildInfo?.contractCrate?.version || '0.0.0',
+      description: description.join('\n\n'),
+    },
+    ...(options.serverUrl ? { servers: [{ url: options.serverUrl }] } : {}),
+    tags: [
+      { name: 'views', description: 'Read-only queries' },
+      { name: 'endpoints', description: 'Transactions calling the contract' },
+    ],
+    paths,
+    components: {
+      schemas: {
+        ...Object.fromEntries(
+          Object.entries(definitions).map(([name, def]) => [name, typeSchema(def, abi)])
+        ),
+        CallValue: {
+          type: 'object',
+          description: 'Amounts sent with the call, by token identifier',
+          additionalProperties: abiTypeSchema('BigUint', abi),
+        },
+        UnsignedTransaction: {
+          type: 'object',
+          properties: {
+            txHash: { type: 'string', description: 'Hash to sign' },
+            tx: { type: 'object', description: 'Transaction to broadcast with the signature' },
+          },
+          required: ['txHash', 'tx'],
+        },
+        GatewayError: {
+          type: 'object',
+          properties: { error: { type: 'string' } },
+          required: ['error'],
+        },
+      },
+      responses: {
+        Error: {
+          description: 'Invalid arguments, or the node rejected the query or transaction',
+          content: { 'application/json': { schema: ref('GatewayError') } },
+        },
+      },
+    },
+  };
+
+  return {
+    path: `${camelToSnake(abi.name).replace(/_/g, '-')}.openapi.json`,
+    spec,
+    views: views.map(e => e.name),
+    endpoints: endpoints.map(e => e.name),
+  };
+}
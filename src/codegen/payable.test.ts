@@ -0,0 +1,65 @@
+import { generatePayableEndpoint } from './payable.js';
+import { findTraitFunction } from './rust-source.js';
+
+const CONTRACT = `#[klever_sc::contract]
+pub trait Pool {
+    #[init]
+    fn init(&self) {}
+}
+`;
+
+describe('generatePayableEndpoint', () => {
+  it('handles KLV payments', () => {
+    const { endpoint } = generatePayableEndpoint({ name: 'fund', mode: 'klv', minAmount: '1000' });
+
+    expect(endpoint).toContain('#[payable("KLV")]\n    #[endpoint(fund)]\n    fn fund(&self) {');
+    expect(endpoint).toContain('let amount = self.call_value().klv_value().clone_value();');
+    expect(endpoint).toContain('require!(amount >= 1000u64, "Payment below minimum");');
+  });
+
+  it('validates a single KDA transfer against a token list', () => {
+    const result = generatePayableEndpoint({
+      name: 'depositStable',
+      mode: 'single',
+      acceptedTokens: ['USDT-A1B2', 'USDC-C3D4'],
+    });
+
+    expect(result.endpoint).toContain('#[payable("*")]\n    #[endpoint(depositStable)]');
+    expect(result.endpoint).toContain('let payment = self.call_value().single_kda();');
+    expect(result.endpoint).toContain('require!(payment.token_nonce == 0,');
+    expect(result.helpers[0]).toContain(
+      '*token_id == TokenIdentifier::from(ManagedBuffer::from(b"USDT-A1B2"))\n            || *token_id'
+    );
+  });
+
+  it('refunds rejected multi-transfer payments', () => {
+    const result = generatePayableEndpoint({
+      name: 'add_liquidity',
+      mode: 'multi',
+      acceptedTokensStorage: true,
+      source: CONTRACT,
+    });
+
+    expect(result.endpoint).toContain('let payments = self.call_value().all_kda_transfers();');
+    expect(result.endpoint).toContain('rejected.push(payment);');
+    expect(result.endpoint).toContain('self.send().direct_multi(&caller, &rejected);');
+    expect(findTraitFunction(result.source!, 'accepted_tokens')).toBeDefined();
+    expect(findTraitFunction(result.source!, 'is_accepted_token')).toBeDefined();
+  });
+
+  it('fails the whole call when refunds are disabled', () => {
+    const { endpoint } = generatePayableEndpoint({
+      name: 'batch',
+      mode: 'multi',
+      refundRejected: false,
+    });
+    expect(endpoint).not.toContain('direct_multi');
+    expect(endpoint).toContain('    require!(payment.amount > 0, "Payment must be positive");');
+  });
+
+  it('rejects malformed token identifiers', () => {
+    expect(() =>
+      generatePayableEndpoint({ name: 'x', mode: 'single', acceptedTokens: ['usdt'] })
+    ).toThrow('Invalid token identifier');
+  });
+});
@@ -0,0 +1,214 @@
+/**
+ * Generate payable endpoints with call_value() handling: KLV, a single KDA
+ * transfer, or multi-KDA transfers with token validation and refunds.
+ */
+
+import { appendToTrait, camelToSnake, findTraitFunction, snakeToCamel } from './rust-source.js';
+import { escapeRustBytes, parseU64 } from '../scaffold/rust.js';
+
+export type PaymentMode = 'klv' | 'single' | 'multi';
+
+export interface PayableEndpointOptions {
+  name: string;
+  mode: PaymentMode;
+  /** Token identifiers accepted for KDA modes; omit to accept any token */
+  acceptedTokens?: string[];
+  /** Check tokens against an `accepted_tokens` UnorderedSetMapper instead of a fixed list */
+  acceptedTokensStorage?: boolean;
+  /** Allow NFT/SFT payments (non-zero nonce); default false */
+  allowNonFungible?: boolean;
+  /** Minimum amount per payment, in raw units */
+  minAmount?: string;
+  /** multi mode: refund rejected payments instead of failing the transaction (default true) */
+  refundRejected?: boolean;
+  /** Contract source to insert the endpoint into */
+  source?: string;
+}
+
+export interface GeneratedPayableEndpoint {
+  functionName: string;
+  endpoint: string;
+  /** Helper functions and storage added alongside the endpoint */
+  helpers: string[];
+  source?: string;
+  notes: string[];
+}
+
+const TOKEN_ID = /^[A-Z0-9]{3,}(-[A-Z0-9]+)?$/;
+
+function tokenExpression(token: string): string {
+  if (token === 'KLV') return 'TokenIdentifier::klv()';
+  return `TokenIdentifier::from(ManagedBuffer::from(b"${escapeRustBytes(token)}"))`;
+}
+
+function acceptedTokenHelper(tokens: string[]): string {
+  const checks = tokens.map(t => `*token_id == ${tokenExpression(t)}`);
+  return `    fn is_accepted_token(&self, token_id: &TokenIdentifier) -> bool {
+        ${checks.join('\n            || ')}
+    }`;
+}
+
+const STORAGE_HELPER = `    fn is_accepted_token(&self, token_id: &TokenIdentifier) -> bool {
+        self.accepted_tokens().contains(token_id)
+    }`;
+
+const ACCEPTED_TOKENS_STORAGE = `    #[view(getAcceptedTokens)]
+    #[storage_mapper("acceptedTokens")]
+    fn accepted_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;`;
+
+/**
+ * Render a payable endpoint, optionally inserting it into `options.source`
+ */
+export function generatePayableEndpoint(
+  options: PayableEndpointOptions
+): GeneratedPayableEndpoint {
+  const functionName = camelToSnake(options.name);
+  if (!/^[a-z_][a-z0-9_]*$/.test(functionName)) {
+    throw new Error(`Invalid endpoint name "${options.name}".`);
+  }
+  const tokens = options.acceptedTokens || [];
+  const invalid = tokens.filter(t => !TOKEN_ID.test(t));
+  if (invalid.length > 0) {
+    throw new Error(
+      `Invalid token identifier(s): ${invalid.join(', ')} (expected e.g. USDT-A1B2).`
+    );
+  }
+  if (options.mode === 'klv' && (tokens.length > 0 || options.acceptedTokensStorage)) {
+    throw new Error('acceptedTokens only apply to the single and multi modes.');
+  }
+  const min =
+    options.minAmount !== undefined ? parseU64(options.minAmount, 'minAmount') : undefined;
+  const source = options.source;
+  if (source !== undefined && findTraitFunction(source, functionName)) {
+    throw new Error(`Contract already has a function named "${functionName}".`);
+  }
+
+  const notes: string[] = [];
+  const helpers: string[] = [];
+  const tokenCheck = tokens.length > 0 || options.acceptedTokensStorage === true;
+  if (tokenCheck && !(source && findTraitFunction(source, 'is_accepted_token'))) {
+    if (options.acceptedTokensStorage) {
+      helpers.push(STORAGE_HELPER);
+      if (!(source && findTraitFunction(source, 'accepted_tokens'))) {
+        helpers.push(ACCEPTED_TOKENS_STORAGE);
+      }
+      notes.push('Add owner-only endpoints to insert/remove entries in accepted_tokens().');
+    } else {
+      helpers.push(acceptedTokenHelper(tokens));
+    }
+  }
+  if (!tokenCheck && options.mode !== 'klv') {
+    notes.push('Any KDA token is accepted; pass acceptedTokens to restrict it.');
+  }
+
+  const amountCheck = (amount: string) =>
+    min !== undefined && min > 0n
+      ? `require!(${amount} >= ${min}u64, "Payment below minimum");`
+      : `require!(${amount} > 0, "Payment must be positive");`;
+  const body: string[] = [];
+
+  switch (options.mode) {
+    case 'klv':
+      body.push(
+        'let amount = self.call_value().klv_value().clone_value();',
+        amountCheck('amount'),
+        'let caller = self.blockchain().get_caller();',
+        '',
+        '// TODO: use `amount` (KLV, raw units)'
+      );
+      break;
+    case 'single':
+      // single_kda() fails the call unless exactly one KDA transfer was sent
+      body.push('let payment = self.call_value().single_kda();');
+      if (tokenCheck) {
+        body.push(
+          'require!(self.is_accepted_token(&payment.token_identifier), "Token not accepted");'
+        );
+      }
+      if (!options.allowNonFungible) {
+        body.push('require!(payment.token_nonce == 0, "Only fungible tokens accepted");');
+      }
+      body.push(
+        amountCheck('payment.amount'),
+        'let caller = self.blockchain().get_caller();',
+        '',
+        '// TODO: use payment.token_identifier, payment.token_nonce and payment.amount'
+      );
+      notes.push(
+        'single_kda() rejects calls with zero or several transfers; use mode "multi" to accept batches.'
+      );
+      break;
+    case 'multi': {
+      const refund = options.refundRejected !== false;
+      const conditions: string[] = [];
+      if (tokenCheck) conditions.push('self.is_accepted_token(&payment.token_identifier)');
+      if (!options.allowNonFungible) conditions.push('payment.token_nonce == 0');
+      conditions.push(
+        min !== undefined && min > 0n ? `payment.amount >= ${min}u64` : 'payment.amount > 0'
+      );
+
+      body.push(
+        'let payments = self.call_value().all_kda_transfers();',
+        'require!(!payments.is_empty(), "No KDA payment received");',
+        'let caller = self.blockchain().get_caller();'
+      );
+      if (refund) {
+        body.push(
+          'let mut rejected: ManagedVec<KdaTokenPayment<Self::Api>> = ManagedVec::new();',
+          '',
+          'for payment in payments.iter() {',
+          `    let acceptable = ${conditions.join('\n        && ')};`,
+          '    if !acceptable {',
+          '        rejected.push(payment);',
+          '        continue;',
+          '    }',
+          '',
+          '    // TODO: use payment.token_identifier, payment.token_nonce and payment.amount',
+          '}',
+          '',
+          '// Return everything that was not accepted in a single transfer',
+          'if !rejected.is_empty() {',
+          '    self.send().direct_multi(&caller, &rejected);',
+          '}'
+        );
+      } else {
+        body.push('', 'for payment in payments.iter() {');
+        if (tokenCheck) {
+          body.push(
+            '    require!(self.is_accepted_token(&payment.token_identifier), "Token not accepted");'
+          );
+        }
+        if (!options.allowNonFungible) {
+          body.push('    require!(payment.token_nonce == 0, "Only fungible tokens accepted");');
+        }
+        body.push(
+          `    ${amountCheck('payment.amount')}`,
+          '',
+          '    // TODO: use payment.token_identifier, payment.token_nonce and payment.amount',
+          '}'
+        );
+        notes.push('Any invalid payment fails the whole call; the VM returns all transfers.');
+      }
+      break;
+    }
+  }
+
+  const endpoint = [
+    `    #[payable("${options.mode === 'klv' ? 'KLV' : '*'}")]`,
+    `    #[endpoint(${snakeToCamel(functionName)})]`,
+    `    fn ${functionName}(&self) {`,
+    ...body.map(line => (line ? `        ${line}` : '')),
+    '    }',
+  ].join('\n');
+
+  return {
+    functionName,
+    endpoint,
+    helpers,
+    source:
+      source === undefined
+        ? undefined
+        : appendToTrait(source, [endpoint, ...helpers].join('\n\n')),
+    notes,
+  };
+}
@@ -0,0 +1,171 @@
+import { parseAbi } from '../abi/index.js';
+import { generateProptest } from './proptest.js';
+
+const TOKEN = parseAbi({
+  buildInfo: {
+    contractCrate: { name: 'basic_token', version: '0.1.0' },
+    framework: { name: 'klever-sc', version: '0.45.0' },
+  },
+  name: 'BasicToken',
+  constructor: { inputs: [{ name: 'initial_supply', type: 'BigUint' }], outputs: [] },
+  endpoints: [
+    {
+      name: 'transferOwnership',
+      mutability: 'mutable',
+      inputs: [{ name: 'new_owner', type: 'Address' }],
+      outputs: [],
+    },
+    {
+      name: 'transfer',
+      mutability: 'mutable',
+      inputs: [
+        { name: 'to', type: 'Address' },
+        { name: 'amount', type: 'BigUint' },
+      ],
+      outputs: [],
+    },
+    {
+      name: 'mint',
+      mutability: 'mutable',
+      inputs: [
+        { name: 'to', type: 'Address' },
+        { name: 'amount', type: 'BigUint' },
+      ],
+      outputs: [],
+    },
+    {
+      name: 'burn',
+      mutability: 'mutable',
+      inputs: [{ name: 'amount', type: 'BigUint' }],
+      outputs: [],
+    },
+    {
+      name: 'getBalance',
+      mutability: 'readonly',
+      inputs: [{ name: 'address', type: 'Address' }],
+      outputs: [{ type: 'BigUint' }],
+    },
+    {
+      name: 'getTotalSupply',
+      mutability: 'readonly',
+      inputs: [],
+      outputs: [{ type: 'BigUint' }],
+    },
+  ],
+  events: [
+    {
+      identifier: 'transfer',
+      inputs: [
+        { name: 'from', type: 'Address', indexed: true },
+        { name: 'to', type: 'Address', indexed: true },
+        { name: 'amount', type: 'BigUint' },
+      ],
+    },
+    {
+      identifier: 'mint',
+      inputs: [
+        { name: 'amount', type: 'BigUint' },
+        { name: 'recipient', type: 'Address', indexed: true },
+      ],
+    },
+  ],
+});
+
+describe('generateProptest', () => {
+  it('infers every token invariant from a token ABI', () => {
+    const generated = generateProptest(TOKEN, { ownerOnly: ['mint'] });
+    expect(generated.path).toBe('tests/basic_token_proptest.rs');
+    expect(generated.devDependencies).toEqual({ proptest: '1', 'num-bigint': '0.4' });
+    expect(generated.properties.map(p => p.name)).toEqual([
+      'transfer_conserves_supply',
+      'transfer_never_underflows',
+      'burn_never_underflows',
+      'only_owner_mints',
+      'mint_emits_event',
+    ]);
+    expect(generated.notInferred).toEqual([]);
+
+    const source = generated.files['tests/basic_token_proptest.rs'];
+    expect(source).toContain('world.register_contract(CODE, basic_token::ContractBuilder);');
+    expect(source).toContain('const CODE: &str = "file:output/basic-token.wasm";');
+    expect(source).toContain('const INIT_ARGS: &[&str] = &["0x3b9aca00"];');
+    expect(source).toContain('    query_biguint(world, "getTotalSupply", &[])');
+    expect(source).toContain('    query_biguint(world, "getBalance", &[address(account)])');
+    expect(source).toContain('let response = call(&mut world, from, "transfer", &args);');
+    expect(source).toContain('prop_assert_eq!(sum_of_balances(&mut world), supply.clone());');
+    expect(source).toContain('let amount = held.clone() + excess;');
+    expect(source).toContain(
+      '    fn only_owner_mints(caller in 1..ACCOUNTS.len(), to in account(), amount in 1..=u64::MAX) {'
+    );
+    expect(source).toContain('    #![proptest_config(ProptestConfig::with_cases(64))]');
+    // Events are checked with their indexed topics and data
+    expect(source).toContain(
+      '                    emitted(&response, "transfer", &[address(from), address(to)], &[biguint(&amount)]),'
+    );
+    expect(source).toContain(
+      '            emitted(&response, "mint", &[address(to)], &[biguint(&amount)]),'
+    );
+    expect(source).toContain('        identified && log.data == data.concat()');
+  });
+
+  it('reports the invariants the contract does not support', () => {
+    const abi = parseAbi({
+      name: 'Points',
+      constructor: { inputs: [], outputs: [] },
+      endpoints: [
+        {
+          name: 'mint',
+          mutability: 'mutable',
+          inputs: [{ name: 'amount', type: 'BigUint' }],
+          outputs: [],
+        },
+        {
+          name: 'burn',
+          mutability: 'mutable',
+          inputs: [{ name: 'amount', type: 'BigUint' }],
+          outputs: [],
+        },
+        {
+          name: 'balanceOf',
+          mutability: 'readonly',
+          inputs: [{ name: 'account', type: 'Address' }],
+          outputs: [{ type: 'BigUint' }],
+        },
+      ],
+    });
+    const generated = generateProptest(abi, { cases: 16 });
+    expect(generated.properties.map(p => p.name)).toEqual(['burn_never_underflows']);
+    expect(generated.notInferred.map(n => n.property)).toEqual([
+      'transfer_conserves_supply',
+      'transfer_never_underflows',
+      'only_owner_mints',
+      'mint_emits_event',
+    ]);
+    expect(generated.notInferred[2].reason).toContain('mint has no owner check');
+    expect(generated.notInferred[3].reason).toContain('no event named like *mint*');
+
+    const source = generated.files['tests/points_proptest.rs'];
+    expect(source).toContain('const INIT_ARGS: &[&str] = &[];');
+    expect(source).toContain('ProptestConfig::with_cases(16)');
+    // Helpers only the missing properties would call are left out
+    expect(source).not.toContain('fn total_supply');
+    expect(source).not.toContain('fn sum_of_balances');
+    expect(source).not.toContain('fn emitted');
+  });
+
+  it('deploys with the given arguments and rejects contracts without token invariants', () => {
+    const generated = generateProptest(TOKEN, { deployArgs: { initial_supply: '255' } });
+    expect(generated.files[generated.path]).toContain('const INIT_ARGS: &[&str] = &["0xff"];');
+    expect(generated.notInferred.map(n => n.property)).toEqual(['only_owner_mints']);
+
+    const counter = parseAbi({
+      name: 'Counter',
+      constructor: { inputs: [{ name: 'label', type: 'bytes' }], outputs: [] },
+      endpoints: [{ name: 'increment', mutability: 'mutable', inputs: [], outputs: [] }],
+    });
+    expect(() => generateProptest(counter)).toThrow('No token invariants apply to Counter');
+    expect(() => generateProptest({ ...TOKEN, constructor: counter.constructor })).toThrow(
+      'pass deployArgs'
+    );
+  });
+});
@@ -0,0 +1,597 @@
+/**
+ * Generate proptest property tests for a token contract from its ABI: the
+ * invariants a fungible token must keep (transfers conserve the supply, no
+ * balance goes below zero, only the owner mints) are inferred from the views
+ * and endpoints it declares, and each becomes a property run on random
+ * callers and amounts against the Rust VM of klever-sc-scenario. Transfers and
+ * mints are also checked for their event (identifier, indexed topics and data)
+ * when the ABI declares one. The test file is a starting point for the
+ * contract's own properties.
+ */
+
+import type { Abi, AbiEndpoint } from '../abi/index.js';
+import type { FileMap } from '../scaffold/types.js';
+import { escapeRustString } from '../scaffold/rust.js';
+import { encodeArguments } from './abi-codec.js';
+import type { AbiArgs } from './abi-values.js';
+import { camelToSnake } from './rust-source.js';
+
+export interface ProptestOptions {
+  /** Constructor arguments, in ABI order or keyed by name (default: an initial supply) */
+  deployArgs?: AbiArgs;
+  /**
+   * Endpoints an owner check guards in the source (e.g. from
+   * `auditAccessControl`), next to the ones the ABI marks `onlyOwner`
+   */
+  ownerOnly?: string[];
+  /** Random cases per property (default: 64) */
+  cases?: number;
+}
+
+export interface TokenProperty {
+  /** Rust test function name */
+  name: string;
+  description: string;
+}
+
+export interface GeneratedProptest {
+  /** Test file, relative to the contract crate */
+  path: string;
+  files: FileMap;
+  /** Crates the tests need in [dev-dependencies], next to klever-sc-scenario */
+  devDependencies: Record<string, string>;
+  properties: TokenProperty[];
+  /** Built-in invariants that do not apply to the contract, with the reason */
+  notInferred: { property: string; reason: string }[];
+}
+
+const DEFAULT_CASES = 64;
+/** Constructor argument when its only input is a number, taken as the initial supply */
+const DEFAULT_SUPPLY = '1000000000';
+
+const DEV_DEPENDENCIES: Record<string, string> = { proptest: '1', 'num-bigint': '0.4' };
+
+/** Views and endpoints of a token, found by name and signature */
+interface TokenShape {
+  supply?: AbiEndpoint;
+  balance?: AbiEndpoint;
+  transfer?: AbiEndpoint;
+  burn?: AbiEndpoint;
+  mint?: AbiEndpoint;
+}
+
+const returnsBigUint = (e: AbiEndpoint) =>
+  e.outputs.length === 1 && e.outputs[0].type === 'BigUint';
+const signature = (e: AbiEndpoint) => e.inputs.map(input => input.type).join(', ');
+
+function tokenShape(abi: Abi): TokenShape {
+  const views = abi.endpoints.filter(e => e.mutability === 'readonly');
+  const endpoints = abi.endpoints.filter(
+    e => e.mutability !== 'readonly' && (e.payableInTokens || []).length === 0
+  );
+  // The plain name wins over longer ones (transfer over transferOwnership)
+  const find = (word: string, signatures: string[]) => {
+    const candidates = endpoints.filter(
+      e => e.name.toLowerCase().includes(word) && signatures.includes(signature(e))
+    );
+    return candidates.find(e => e.name.toLowerCase() === word) || candidates[0];
+  };
+  return {
+    supply: views.find(v => v.inputs.length === 0 && returnsBigUint(v) && /supply/i.test(v.name)),
+    balance: views.find(
+      v => signature(v) === 'Address' && returnsBigUint(v) && /balance/i.test(v.name)
+    ),
+    transfer: find('transfer', ['Address, BigUint']),
+    burn: find('burn', ['BigUint']),
+    mint: find('mint', ['Address, BigUint', 'BigUint']),
+  };
+}
+
+/** An account an event input may name, as a Rust expression */
+interface EventAccount {
+  expression: string;
+  /** Input names that refer to it */
+  names: RegExp;
+}
+
+const SENDER: EventAccount = { expression: 'address(from)', names: /^(from|sender|caller)/ };
+const RECIPIENT: EventAccount = {
+  expression: 'address(to)',
+  names: /^(to|recipient|receiver|dest)/,
+};
+const OWNER: EventAccount = { expression: 'address(owner)', names: /^(owner|minter|caller)/ };
+
+/** Rust expressions of the topics and data an event is expected to carry */
+interface EventCheck {
+  identifier: string;
+  topics: string[];
+  data: string[];
+}
+
+/**
+ * The event named like `word`, with its Address inputs bound to `accounts` (by
+ * name, else in order) and its BigUint input to `amount`; undefined when the
+ * ABI has none or an input cannot be bound
+ */
+function eventCheck(abi: Abi, word: string, accounts: EventAccount[]): EventCheck | undefined {
+  const candidates = (abi.events || []).filter(e => e.identifier.toLowerCase().includes(word));
+  const event = candidates.find(e => e.identifier.toLowerCase() === word) || candidates[0];
+  if (!event || event.inputs.filter(input => input.type === 'BigUint').length !== 1) {
+    return undefined;
+  }
+  const unbound = [...accounts];
+  const check: EventCheck = { identifier: event.identifier, topics: [], data: [] };
+  for (const input of event.inputs) {
+    let expression = 'biguint(&amount)';
+    if (input.type === 'Address') {
+      const named = unbound.find(account => account.names.test(input.name.toLowerCase()));
+      const account = named || unbound[0];
+      if (!account) return undefined;
+      unbound.splice(unbound.indexOf(account), 1);
+      expression = account.expression;
+    } else if (input.type !== 'BigUint') {
+      return undefined;
+    }
+    (input.indexed ? check.topics : check.data).push(expression);
+  }
+  return check;
+}
+
+/** `prop_assert!` that the call emitted the event, indented for a property body */
+function assertEmitted(check: EventCheck, indent: string, message: string): string[] {
+  const topics = check.topics.join(', ');
+  const data = check.data.join(', ');
+  return [
+    `${indent}prop_assert!(`,
+    `${indent}    emitted(&response, ${rustString(check.identifier)}, &[${topics}], &[${data}]),`,
+    `${indent}    ${message}`,
+    `${indent});`,
+  ];
+}
+
+/** Scenario expressions of the constructor arguments */
+function deployArguments(abi: Abi, deployArgs: AbiArgs | undefined): string[] {
+  const inputs = abi.constructor?.inputs || [];
+  let values = deployArgs;
+  if (values === undefined && inputs.length > 0) {
+    if (inputs.length > 1 || !/^(u8|u16|u32|u64|usize|BigUint)$/.test(inputs[0].type)) {
+      throw new Error(
+        `The constructor takes ${inputs.map(i => i.name).join(', ')}: pass deployArgs to deploy it`
+      );
+    }
+    values = [DEFAULT_SUPPLY];
+  }
+  return encodeArguments(inputs, values, abi, 'the constructor').map(arg =>
+    arg.length === 0 ? '' : `0x${arg.toString('hex')}`
+  );
+}
+
+const rustString = (value: string) => `"${escapeRustString(value)}"`;
+
+/** `text` as /// lines of at most 100 columns */
+function docLines(text: string, indent: string): string[] {
+  const lines: string[] = [];
+  let line = '';
+  for (const word of text.split(' ')) {
+    if (line && indent.length + 4 + line.length + 1 + word.length > 100) {
+      lines.push(line);
+      line = word;
+    } else {
+      line = line ? `${line} ${word}` : word;
+    }
+  }
+  lines.push(line);
+  return lines.map(l => `${indent}/// ${l}`);
+}
+
+interface Property extends TokenProperty {
+  /** Test function without its doc comment, indented for the proptest! block */
+  lines: string[];
+}
+
+function transferConservesSupply(transfer: AbiEndpoint, event?: EventCheck): Property {
+  const description =
+    'Transfers between any accounts keep the supply and the sum of balances unchanged, ' +
+    'and a successful one moves exactly the amount' +
+    (event ? ` and emits ${event.identifier} with it` : '');
+  return {
+    name: 'transfer_conserves_supply',
+    description,
+    lines: [
+      '    #[test]',
+      '    fn transfer_conserves_supply(',
+      '        transfers in prop::collection::vec((account(), account(), any::<u64>()), 1..8)',
+      '    ) {',
+      '        let mut world = world();',
+      '        let supply = total_supply(&mut world);',
+      '        prop_assert_eq!(sum_of_balances(&mut world), supply.clone());',
+      '        for (from, to, amount) in transfers {',
+      '            let (from, to) = (ACCOUNTS[from], ACCOUNTS[to]);',
+      '            let from_before = balance(&mut world, from);',
+      '            let to_before = balance(&mut world, to);',
+      '            // Mostly amounts the sender holds, so most transfers go through',
+      '            let amount = BigUint::from(amount) % (from_before.clone() + 1u32);',
+      '            let args = [address(to), biguint(&amount)];',
+      `            let response = call(&mut world, from, ${rustString(transfer.name)}, &args);`,
+      '            if response.tx_error.status == 0 && from != to {',
+      '                prop_assert_eq!(balance(&mut world, from), from_before - &amount);',
+      '                prop_assert_eq!(balance(&mut world, to), to_before + &amount);',
+      ...(event
+        ? assertEmitted(
+            event,
+            '                ',
+            `"{} sent {} to {} without a ${escapeRustString(event.identifier)} event", from, amount, to`
+          )
+        : []),
+      '            } else {',
+      '                prop_assert_eq!(balance(&mut world, from), from_before);',
+      '                prop_assert_eq!(balance(&mut world, to), to_before);',
+      '            }',
+      '            prop_assert_eq!(total_supply(&mut world), supply.clone());',
+      '            prop_assert_eq!(sum_of_balances(&mut world), supply.clone());',
+      '        }',
+      '    }',
+    ],
+  };
+}
+
+function transferNeverUnderflows(transfer: AbiEndpoint): Property {
+  const name = rustString(transfer.name);
+  return {
+    name: 'transfer_never_underflows',
+    description: 'A transfer of more than the sender holds fails and changes no balance',
+    lines: [
+      '    #[test]',
+      '    fn transfer_never_underflows(from in account(), to in account(), excess in 1..=u64::MAX) {',
+      '        let mut world = world();',
+      '        let (from, to) = (ACCOUNTS[from], ACCOUNTS[to]);',
+      '        let from_before = balance(&mut world, from);',
+      '        let to_before = balance(&mut world, to);',
+      '        let amount = from_before.clone() + excess;',
+      `        let response = call(&mut world, from, ${name}, &[address(to), biguint(&amount)]);`,
+      '        prop_assert_ne!(response.tx_error.status, 0, "{} sent {} of {}", from, amount, from_before);',
+      '        prop_assert_eq!(balance(&mut world, from), from_before);',
+      '        prop_assert_eq!(balance(&mut world, to), to_before);',
+      '    }',
+    ],
+  };
+}
+
+function burnNeverUnderflows(burn: AbiEndpoint, withSupply: boolean): Property {
+  const description = withSupply
+    ? 'Burning more than the caller holds fails and changes neither the balance nor the supply'
+    : 'Burning more than the caller holds fails and changes no balance';
+  return {
+    name: 'burn_never_underflows',
+    description,
+    lines: [
+      '    #[test]',
+      '    fn burn_never_underflows(caller in account(), excess in 1..=u64::MAX) {',
+      '        let mut world = world();',
+      '        let caller = ACCOUNTS[caller];',
+      '        let held = balance(&mut world, caller);',
+      ...(withSupply ? ['        let supply = total_supply(&mut world);'] : []),
+      '        let amount = held.clone() + excess;',
+      `        let response = call(&mut world, caller, ${rustString(burn.name)}, &[biguint(&amount)]);`,
+      '        prop_assert_ne!(response.tx_error.status, 0, "{} burned {} of {}", caller, amount, held);',
+      '        prop_assert_eq!(balance(&mut world, caller), held);',
+      ...(withSupply ? ['        prop_assert_eq!(total_supply(&mut world), supply);'] : []),
+      '    }',
+    ],
+  };
+}
+
+function onlyOwnerMints(mint: AbiEndpoint, shape: TokenShape): Property {
+  const withRecipient = mint.inputs.length === 2;
+  const checks = [
+    ...(shape.supply ? ['total supply'] : []),
+    ...(withRecipient && shape.balance ? ['recipient balance'] : []),
+  ];
+  const description =
+    checks.length > 0
+      ? `Only the owner can call ${mint.name}: other callers fail and the ${checks.join(' and ')} stay unchanged`
+      : `Only the owner can call ${mint.name}: other callers fail`;
+  const args = withRecipient ? '&[address(to), biguint(&amount)]' : '&[biguint(&amount)]';
+  const params = [
+    'caller in 1..ACCOUNTS.len()',
+    ...(withRecipient ? ['to in account()'] : []),
+    'amount in 1..=u64::MAX',
+  ];
+  return {
+    name: 'only_owner_mints',
+    description,
+    lines: [
+      '    #[test]',
+      `    fn only_owner_mints(${params.join(', ')}) {`,
+      '        let mut world = world();',
+      '        let caller = ACCOUNTS[caller];',
+      ...(withRecipient ? ['        let to = ACCOUNTS[to];'] : []),
+      '        let amount = BigUint::from(amount);',
+      ...(shape.supply ? ['        let supply = total_supply(&mut world);'] : []),
+      ...(withRecipient && shape.balance
+        ? ['        let to_before = balance(&mut world, to);']
+        : []),
+      `        let response = call(&mut world, caller, ${rustString(mint.name)}, ${args});`,
+      '        prop_assert_ne!(response.tx_error.status, 0, "{} minted {}", caller, amount);',
+      ...(shape.supply ? ['        prop_assert_eq!(total_supply(&mut world), supply);'] : []),
+      ...(withRecipient && shape.balance
+        ? ['        prop_assert_eq!(balance(&mut world, to), to_before);']
+        : []),
+      '    }',
+    ],
+  };
+}
+
+function mintEmitsEvent(mint: AbiEndpoint, event: EventCheck): Property {
+  const withRecipient = mint.inputs.length === 2;
+  const args = withRecipient ? '&[address(to), biguint(&amount)]' : '&[biguint(&amount)]';
+  const name = rustString(mint.name);
+  return {
+    name: 'mint_emits_event',
+    description: `The owner's ${mint.name} succeeds and emits ${event.identifier} with the minted amount`,
+    lines: [
+      '    #[test]',
+      `    fn mint_emits_event(${withRecipient ? 'to in account(), ' : ''}amount in 1..=u64::MAX) {`,
+      '        let mut world = world();',
+      '        let owner = ACCOUNTS[0];',
+      ...(withRecipient ? ['        let to = ACCOUNTS[to];'] : []),
+      '        let amount = BigUint::from(amount);',
+      `        let response = call(&mut world, owner, ${name}, ${args});`,
+      '        let error = &response.tx_error;',
+      `        prop_assert_eq!(error.status, 0, "{} failed: {}", ${name}, error.message);`,
+      ...assertEmitted(
+        event,
+        '        ',
+        `"minting {} emitted no ${escapeRustString(event.identifier)} event", amount`
+      ),
+      '    }',
+    ],
+  };
+}
+
+/** Helpers the properties call, each only when used so the tests build without warnings */
+function renderHelpers(shape: TokenShape, used: string): string[] {
+  const helpers: string[] = [];
+  if (shape.supply && /\btotal_supply\(/.test(used)) {
+    helpers.push(
+      [
+        'fn total_supply(world: &mut ScenarioWorld) -> BigUint {',
+        `    query_biguint(world, ${rustString(shape.supply.name)}, &[])`,
+        '}',
+      ].join('\n')
+    );
+  }
+  if (shape.balance && /\bbalance\(/.test(used)) {
+    helpers.push(
+      [
+        'fn balance(world: &mut ScenarioWorld, account: &str) -> BigUint {',
+        `    query_biguint(world, ${rustString(shape.balance.name)}, &[address(account)])`,
+        '}',
+      ].join('\n')
+    );
+  }
+  if (/\bsum_of_balances\(/.test(used)) {
+    helpers.push(
+      [
+        '/// Balances of every account and of the contract itself',
+        'fn sum_of_balances(world: &mut ScenarioWorld) -> BigUint {',
+        '    let mut sum = BigUint::default();',
+        '    for name in ACCOUNTS.iter().chain([CONTRACT].iter()) {',
+        '        sum += balance(world, name);',
+        '    }',
+        '    sum',
+        '}',
+      ].join('\n')
+    );
+  }
+  if (/\bemitted\(/.test(used)) {
+    helpers.push(
+      [
+        '/// Whether the call emitted `identifier` with these indexed topics and data',
+        'fn emitted(response: &TxResponse, identifier: &str, topics: &[Vec<u8>], data: &[Vec<u8>]) -> bool {',
+        '    response.logs.iter().any(|log| {',
+        '        let identified = log.topics.split_first().map_or(false, |(first, rest)| {',
+        '            first.as_slice() == identifier.as_bytes() && rest == topics',
+        '        });',
+        '        identified && log.data == data.concat()',
+        '    })',
+        '}',
+      ].join('\n')
+    );
+  }
+  if (/\baccount\(\)/.test(used)) {
+    helpers.push(
+      [
+        '/// Index into ACCOUNTS',
+        'fn account() -> impl Strategy<Value = usize> {',
+        '    0..ACCOUNTS.len()',
+        '}',
+      ].join('\n')
+    );
+  }
+  return helpers;
+}
+
+/** Render the property tests for the token invariants `abi` supports */
+export function generateProptest(abi: Abi, options: ProptestOptions = {}): GeneratedProptest {
+  const cases = options.cases ?? DEFAULT_CASES;
+  if (!Number.isInteger(cases) || cases < 1) {
+    throw new Error('cases must be a positive integer');
+  }
+  const contractCrate = abi.buildInfo?.contractCrate?.name || camelToSnake(abi.name);
+  const crateIdent = contractCrate.replace(/-/g, '_');
+  const shape = tokenShape(abi);
+
+  const properties: Property[] = [];
+  const notInferred: { property: string; reason: string }[] = [];
+  const missing = {
+    supply: 'no view without arguments, named like *supply*, returning BigUint',
+    balance: 'no view taking an Address, named like *balance*, returning BigUint',
+    transfer: 'no non-payable endpoint named like *transfer* taking (Address, BigUint)',
+    burn: 'no non-payable endpoint named like *burn* taking a BigUint',
+    mint: 'no non-payable endpoint named like *mint* taking (Address, BigUint) or a BigUint',
+  };
+  const noEvent = (word: string) =>
+    `no event named like *${word}* with a BigUint amount and only Address inputs besides it`;
+  const requires = (property: string, parts: (keyof TokenShape)[]) => {
+    const absent = parts.filter(part => !shape[part]);
+    if (absent.length === 0) return true;
+    notInferred.push({ property, reason: absent.map(part => missing[part]).join('; ') });
+    return false;
+  };
+
+  if (requires('transfer_conserves_supply', ['supply', 'balance', 'transfer'])) {
+    properties.push(
+      transferConservesSupply(shape.transfer!, eventCheck(abi, 'transfer', [SENDER, RECIPIENT]))
+    );
+  }
+  if (requires('transfer_never_underflows', ['balance', 'transfer'])) {
+    properties.push(transferNeverUnderflows(shape.transfer!));
+  }
+  if (requires('burn_never_underflows', ['balance', 'burn'])) {
+    properties.push(burnNeverUnderflows(shape.burn!, shape.supply !== undefined));
+  }
+  if (requires('only_owner_mints', ['mint'])) {
+    const mint = shape.mint!;
+    if (mint.onlyOwner || (options.ownerOnly || []).includes(mint.name)) {
+      properties.push(onlyOwnerMints(mint, shape));
+    } else {
+      notInferred.push({
+        property: 'only_owner_mints',
+        reason: `${mint.name} has no owner check (#[only_owner] in the ABI, or a guard in the source when given); anyone can mint`,
+      });
+    }
+  }
+  if (requires('mint_emits_event', ['mint'])) {
+    const mint = shape.mint!;
+    const accounts = mint.inputs.length === 2 ? [RECIPIENT, OWNER] : [OWNER];
+    const event = eventCheck(abi, 'mint', accounts);
+    if (event) {
+      properties.push(mintEmitsEvent(mint, event));
+    } else {
+      notInferred.push({ property: 'mint_emits_event', reason: noEvent('mint') });
+    }
+  }
+  if (properties.length === 0) {
+    const reasons = notInferred.map(n => `${n.property}: ${n.reason}`);
+    throw new Error(`No token invariants apply to ${abi.name}. ${reasons.join('. ')}`);
+  }
+
+  const initArgs = deployArguments(abi, options.deployArgs);
+  const body = properties.flatMap((property, i) => [
+    ...(i > 0 ? [''] : []),
+    ...docLines(property.description, '    '),
+    ...property.lines,
+  ]);
+  const helpers = renderHelpers(shape, body.join('\n'));
+  const path = `tests/${crateIdent}_proptest.rs`;
+  const wasm = `output/${contractCrate.replace(/_/g, '-')}.wasm`;
+
+  const source = [
+    `//! Property tests for the ${abi.name} contract: token invariants inferred from its`,
+    '//! ABI, checked with proptest on random callers and amounts. Add the contract\'s',
+    '//! own properties to the proptest! block.',
+    '//!',
+    '//! Build the contract first (`ksc all build`), then run',
+    `//! \`cargo test --test ${crateIdent}_proptest\`.`,
+    '',
+    'use klever_sc_scenario::{scenario_model::*, *};',
+    'use num_bigint::BigUint;',
+    'use proptest::prelude::*;',
+    '',
+    `const CODE: &str = ${rustString(`file:${wasm}`)};`,
+    `const CONTRACT: &str = ${rustString(`sc:${camelToSnake(abi.name)}`)};`,
+    '/// Test accounts; the first deploys the contract and owns it',
+    'const ACCOUNTS: [&str; 4] = [',
+    '    "address:owner",',
+    '    "address:alice",',
+    '    "address:bob",',
+    '    "address:carol",',
+    '];',
+    '/// Constructor arguments',
+    `const INIT_ARGS: &[&str] = &[${initArgs.map(rustString).join(', ')}];`,
+    '',
+    '/// A world with the test accounts and the contract deployed by the owner',
+    'fn world() -> ScenarioWorld {',
+    '    let mut world = ScenarioWorld::new();',
+    `    world.register_contract(CODE, ${crateIdent}::ContractBuilder);`,
+    '',
+    '    let mut state = SetStateStep::new();',
+    '    for name in ACCOUNTS {',
+    '        state = state.put_account(name, Account::new().nonce(1).balance("1000000000000000000"));',
+    '    }',
+    '    world.set_state_step(state.new_address(ACCOUNTS[0], 1, CONTRACT));',
+    '',
+    '    let mut deploy = ScDeployStep::new().from(ACCOUNTS[0]).code(CODE);',
+    '    for arg in INIT_ARGS {',
+    '        deploy = deploy.argument(arg);',
+    '    }',
+    '    world.sc_deploy(&mut deploy);',
+    '    let error = &deploy.response().tx_error;',
+    '    assert_eq!(error.status, 0, "deploy failed: {}", error.message);',
+    '    world',
+    '}',
+    '',
+    '/// Call an endpoint; the response may be an error',
+    'fn call(world: &mut ScenarioWorld, from: &str, endpoint: &str, args: &[Vec<u8>]) -> TxResponse {',
+    '    let mut step = ScCallStep::new().from(from).to(CONTRACT).function(endpoint);',
+    '    for arg in args {',
+    '        step = step.argument(expression(arg).as_str());',
+    '    }',
+    '    world.sc_call(&mut step);',
+    '    step.response().clone()',
+    '}',
+    '',
+    '/// First result of a view returning a BigUint',
+    'fn query_biguint(world: &mut ScenarioWorld, view: &str, args: &[Vec<u8>]) -> BigUint {',
+    '    let mut step = ScQueryStep::new().to(CONTRACT).function(view);',
+    '    for arg in args {',
+    '        step = step.argument(expression(arg).as_str());',
+    '    }',
+    '    world.sc_query(&mut step);',
+    '    let response = step.response();',
+    '    let error = &response.tx_error;',
+    '    assert_eq!(error.status, 0, "{} failed: {}", view, error.message);',
+    '    BigUint::from_bytes_be(response.out.first().map_or(&[][..], Vec::as_slice))',
+    '}',
+    '',
+    '/// Scenario value expression of encoded bytes',
+    'fn expression(bytes: &[u8]) -> String {',
+    '    if bytes.is_empty() {',
+    '        return String::new();',
+    '    }',
+    '    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();',
+    '    format!("0x{}", hex)',
+    '}',
+    '',
+    '/// Address bytes of a scenario account (address:...) or contract (sc:...)',
+    'fn address(name: &str) -> Vec<u8> {',
+    '    AddressValue::from(name).value.as_bytes().to_vec()',
+    '}',
+    '',
+    '/// Top-level encoding of a BigUint argument: big-endian, empty for zero',
+    'fn biguint(value: &BigUint) -> Vec<u8> {',
+    '    if value.bits() == 0 {',
+    '        Vec::new()',
+    '    } else {',
+    '        value.to_bytes_be()',
+    '    }',
+    '}',
+    ...helpers.flatMap(helper => ['', helper]),
+    '',
+    'proptest! {',
+    `    #![proptest_config(ProptestConfig::with_cases(${cases}))]`,
+    '',
+    ...body,
+    '}',
+    '',
+  ].join('\n');
+
+  return {
+    path,
+    files: { [path]: source },
+    devDependencies: DEV_DEPENDENCIES,
+    properties: properties.map(({ name, description }) => ({ name, description })),
+    notInferred,
+  };
+}
@@ -0,0 +1,146 @@
+import { parseAbi } from '../abi/index.js';
+import { generateProxy, generateSourceProxy } from './proxy.js';
+
+const ABI = parseAbi({
+  name: 'Governance',
+  constructor: { inputs: [{ name: 'quorum', type: 'BigUint' }], outputs: [] },
+  endpoints: [
+    {
+      name: 'propose',
+      mutability: 'mutable',
+      inputs: [{ name: 'description', type: 'bytes' }],
+      outputs: [{ type: 'u64' }],
+    },
+    {
+      name: 'getProposal',
+      mutability: 'readonly',
+      inputs: [{ name: 'proposal_id', type: 'u64' }],
+      outputs: [{ type: 'Proposal' }],
+    },
+    {
+      name: 'lockTokens',
+      mutability: 'mutable',
+      payableInTokens: ['*'],
+      inputs: [],
+      outputs: [],
+    },
+  ],
+  types: {
+    Proposal: {
+      type: 'struct',
+      fields: [
+        { name: 'proposer', type: 'Address' },
+        { name: 'voters', type: 'List<Address>' },
+        { name: 'status', type: 'ProposalStatus' },
+      ],
+    },
+    ProposalStatus: {
+      type: 'enum',
+      variants: [
+        { name: 'Active', discriminant: 0 },
+        { name: 'Executed', discriminant: 1 },
+      ],
+    },
+    Unused: { type: 'struct', fields: [{ name: 'x', type: 'u8' }] },
+  },
+});
+
+describe('generateProxy', () => {
+  it('renders endpoints, views, init and payable annotations', () => {
+    const proxy = generateProxy(ABI);
+
+    expect(proxy.path).toBe('src/governance_proxy.rs');
+    expect(proxy.content).toContain('#[klever_sc::proxy]\npub trait Governance {');
+    expect(proxy.content).toContain('    #[init]\n    fn init(&self, quorum: BigUint);');
+    expect(proxy.content).toContain(
+      '    #[endpoint(propose)]\n    fn propose(&self, description: ManagedBuffer) -> u64;'
+    );
+    expect(proxy.content).toContain(
+      '    #[view(getProposal)]\n    fn get_proposal(&self, proposal_id: u64) -> Proposal<Self::Api>;'
+    );
+    expect(proxy.content).toContain('    #[payable("*")]\n    #[endpoint(lockTokens)]');
+  });
+
+  it('re-declares reachable custom types with managed generics', () => {
+    const proxy = generateProxy(ABI);
+
+    expect(proxy.types).toEqual(['Proposal', 'ProposalStatus']);
+    expect(proxy.content).toContain('use klever_sc::derive_imports::*;');
+    expect(proxy.content).toContain('pub struct Proposal<M: ManagedTypeApi> {');
+    expect(proxy.content).toContain('    pub voters: ManagedVec<M, ManagedAddress<M>>,');
+    expect(proxy.content).toContain('pub enum ProposalStatus {\n    Active,\n    Executed,\n}');
+  });
+
+  it('limits the proxy to selected endpoints', () => {
+    const proxy = generateProxy(ABI, { endpoints: ['propose'], includeInit: false });
+
+    expect(proxy.endpoints).toEqual(['propose']);
+    expect(proxy.types).toEqual([]);
+    expect(proxy.content).not.toContain('#[init]');
+    expect(() => generateProxy(ABI, { endpoints: ['nope'] })).toThrow('not found');
+  });
+});
+
+describe('generateSourceProxy', () => {
+  const SOURCE = `#![no_std]
+
+use klever_sc::imports::*;
+
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub enum Status {
+    Open,
+    Closed,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct Unused {
+    pub x: u8,
+}
+
+#[klever_sc::contract]
+pub trait Vault {
+    #[init]
+    fn init(&self, token_address: ManagedAddress) {}
+
+    /// Deposit the paid KLV
+    #[payable("KLV")]
+    #[endpoint]
+    fn deposit(&self) {}
+
+    #[view(getStatus)]
+    fn status(&self) -> Status {
+        Status::Open
+    }
+
+    #[view(getOwner)]
+    #[storage_mapper("owner")]
+    fn owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    fn helper(&self) {}
+}
+`;
+
+  it('declares init, endpoints and views with the signatures as written', () => {
+    const proxy = generateSourceProxy(SOURCE, { module: 'vault_proxy' });
+
+    expect(proxy.path).toBe('src/vault_proxy.rs');
+    expect(proxy.content).toContain(
+      '    #[init]\n    fn init(&self, token_address: ManagedAddress);'
+    );
+    expect(proxy.content).toContain(
+      '    /// Deposit the paid KLV\n    #[payable("KLV")]\n    #[endpoint(deposit)]\n    fn deposit(&self);'
+    );
+    expect(proxy.content).toContain('    #[view(getStatus)]\n    fn status(&self) -> Status;');
+    expect(proxy.content).not.toContain('helper');
+    expect(proxy.content).not.toContain('getOwner');
+  });
+
+  it('copies the custom types the calls use', () => {
+    const proxy = generateSourceProxy(SOURCE, { endpoints: ['deposit', 'getStatus'] });
+
+    expect(proxy.types).toEqual(['Status']);
+    expect(proxy.content).toContain('pub enum Status {\n    Open,\n    Closed,\n}');
+    expect(proxy.content).not.toContain('Unused');
+    expect(() => generateSourceProxy(SOURCE, { endpoints: ['nope'] })).toThrow('not found');
+  });
+});
@@ -0,0 +1,279 @@
+/**
+ * Generate a #[klever_sc::proxy] module from a contract ABI, or from the
+ * contract source when it has not been built yet, so other contracts can call
+ * it with typed arguments and results.
+ */
+
+import type { Abi, AbiEndpoint, AbiTypeDef } from '../abi/index.js';
+import { abiReturnType, abiTypeToRust, managedCustomTypes } from './abi.js';
+import {
+  camelToSnake,
+  findContractTrait,
+  findMatchingBrace,
+  listTraitFunctions,
+  parseParams,
+  parseReturnType,
+} from './rust-source.js';
+
+export interface ProxyOptions {
+  /** Only include these endpoints (ABI names); default: all */
+  endpoints?: string[];
+  /** Include the constructor as #[init] so the proxy can deploy the contract (default: true) */
+  includeInit?: boolean;
+}
+
+export interface SourceProxyOptions extends ProxyOptions {
+  /** Module name (default: the snake_case contract trait name + `_proxy`) */
+  module?: string;
+}
+
+export interface GeneratedProxy {
+  /** Proxy module file, relative to the project root */
+  path: string;
+  content: string;
+  /** `pub mod ...;` line for src/lib.rs */
+  modDeclaration: string;
+  /** #[proxy] accessor to add to the calling contract's trait */
+  proxyAccessor: string;
+  endpoints: string[];
+  /** Custom types re-declared in the proxy module */
+  types: string[];
+}
+
+function docLines(docs: string[] | undefined, indent: string): string[] {
+  return (docs || []).map(d => `${indent}///${d.startsWith(' ') || d === '' ? '' : ' '}${d}`);
+}
+
+function renderType(name: string, def: AbiTypeDef, managed: string[]): string {
+  const isManaged = managed.includes(name);
+  const generics = isManaged ? '<M: ManagedTypeApi>' : '';
+  const fieldType = (type: string) => abiTypeToRust(type, managed, 'M');
+  const lines = docLines(def.docs, '');
+
+  if (def.type === 'struct') {
+    lines.push(
+      '#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]',
+      `pub struct ${name}${generics} {`,
+      ...def.fields.map(f => `    pub ${f.name}: ${fieldType(f.type)},`),
+      '}'
+    );
+    return lines.join('\n');
+  }
+
+  const simple = def.variants.every(v => !v.fields || v.fields.length === 0);
+  lines.push(
+    simple
+      ? '#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Copy)]'
+      : '#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]',
+    `pub enum ${name}${generics} {`
+  );
+  const variants = [...def.variants].sort((a, b) => a.discriminant - b.discriminant);
+  for (const variant of variants) {
+    const fields = variant.fields || [];
+    if (fields.length === 0) {
+      lines.push(`    ${variant.name},`);
+    } else if (fields.every(f => /^\d+$/.test(f.name))) {
+      lines.push(`    ${variant.name}(${fields.map(f => fieldType(f.type)).join(', ')}),`);
+    } else {
+      lines.push(`    ${variant.name} {`);
+      lines.push(...fields.map(f => `        ${f.name}: ${fieldType(f.type)},`));
+      lines.push('    },');
+    }
+  }
+  lines.push('}');
+  return lines.join('\n');
+}
+
+function renderEndpoint(
+  endpoint: Omit<AbiEndpoint, 'name'>,
+  name: string | undefined,
+  managed: string[]
+): string {
+  const params = endpoint.inputs.map(
+    i => `${camelToSnake(i.name)}: ${abiTypeToRust(i.type, managed)}`
+  );
+  const returns = abiReturnType(endpoint.outputs, managed);
+  const tokens = endpoint.payableInTokens || [];
+  const attributes: string[] = [];
+  if (tokens.length > 0) {
+    attributes.push(`#[payable("${tokens.length === 1 && tokens[0] === 'KLV' ? 'KLV' : '*'}")]`);
+  }
+  if (name === undefined) {
+    attributes.push('#[init]');
+  } else {
+    attributes.push(
+      endpoint.mutability === 'readonly' ? `#[view(${name})]` : `#[endpoint(${name})]`
+    );
+  }
+  const fnName = name === undefined ? 'init' : camelToSnake(name);
+  return [
+    ...docLines(endpoint.docs, '    '),
+    ...attributes.map(a => `    ${a}`),
+    `    fn ${fnName}(${['&self', ...params].join(', ')})${returns ? ` -> ${returns}` : ''};`,
+  ].join('\n');
+}
+
+/**
+ * Render the proxy module for `abi`
+ */
+export function generateProxy(abi: Abi, options: ProxyOptions = {}): GeneratedProxy {
+  let endpoints = abi.endpoints;
+  if (options.endpoints) {
+    const missing = options.endpoints.filter(n => !abi.endpoints.some(e => e.name === n));
+    if (missing.length > 0) {
+      throw new Error(`Endpoints not found in ABI "${abi.name}": ${missing.join(', ')}`);
+    }
+    endpoints = abi.endpoints.filter(e => options.endpoints!.includes(e.name));
+  }
+
+  const managed = managedCustomTypes(abi);
+  // Only re-declare custom types reachable from the selected endpoints
+  const definitions = abi.types || {};
+  const used = new Set<string>();
+  const visit = (type: string) => {
+    for (const word of type.match(/\w+/g) || []) {
+      if (!Object.hasOwn(definitions, word) || used.has(word)) continue;
+      const def = definitions[word];
+      used.add(word);
+      const nested =
+        def.type === 'struct'
+          ? def.fields
+          : def.variants.flatMap(v => v.fields || []);
+      nested.forEach(f => visit(f.type));
+    }
+  };
+  // `constructor` is also inherited from Object.prototype, so check for an own property
+  const init = Object.hasOwn(abi, 'constructor') ? abi.constructor : undefined;
+  const includeInit = options.includeInit !== false && init !== undefined;
+  const signatures = [...endpoints, ...(init && includeInit ? [init] : [])];
+  for (const e of signatures) {
+    e.inputs.forEach(i => visit(i.type));
+    e.outputs.forEach(o => visit(o.type));
+  }
+  const types = Object.keys(definitions).filter(name => used.has(name));
+
+  const items = endpoints.map(e => renderEndpoint(e, e.name, managed));
+  if (init && includeInit) items.unshift(renderEndpoint(init, undefined, managed));
+
+  const header = ['use klever_sc::imports::*;'];
+  if (types.length > 0) header.push('use klever_sc::derive_imports::*;');
+  const sections = [
+    header.join('\n'),
+    ...types.map(name => renderType(name, definitions[name], managed)),
+    `/// Endpoints of the \`${abi.name}\` contract\n#[klever_sc::proxy]\npub trait ${abi.name} {\n${items.join('\n\n')}\n}`,
+  ];
+
+  const module = `${camelToSnake(abi.name)}_proxy`;
+  return {
+    path: `src/${module}.rs`,
+    content: `${sections.join('\n\n')}\n`,
+    modDeclaration: `pub mod ${module};`,
+    proxyAccessor: `    #[proxy]\n    fn ${camelToSnake(abi.name)}_contract(&self, sc_address: ManagedAddress) -> ${module}::Proxy<Self::Api>;`,
+    endpoints: endpoints.map(e => e.name),
+    types,
+  };
+}
+
+const TYPE_ITEM = /^(?:[ \t]*(?:#\[.*\]|\/\/\/.*)\n)*pub\s+(?:struct|enum)\s+(\w+)/gm;
+
+/** Top-level `pub struct` and `pub enum` items of `source` by name, with attributes and docs */
+function customTypeItems(source: string): Map<string, string> {
+  const items = new Map<string, string>();
+  for (const m of source.matchAll(TYPE_ITEM)) {
+    const open = source.indexOf('{', m.index!);
+    const semicolon = source.indexOf(';', m.index!);
+    const end =
+      semicolon !== -1 && (open === -1 || semicolon < open)
+        ? semicolon
+        : findMatchingBrace(source, open);
+    items.set(m[1], source.slice(m.index!, end + 1));
+  }
+  return items;
+}
+
+const CALLABLE_ATTRIBUTE = /^#\[(init|endpoint|view)(?:\((\w+)\))?\]/;
+
+function renderSourceSignature(name: string, signature: string): string {
+  const params = parseParams(signature).map(p => `${p.name}: ${p.type.replace(/\s+/g, ' ')}`);
+  const returnType = parseReturnType(signature)?.replace(/\s+/g, ' ');
+  const returns = returnType ? ` -> ${returnType}` : '';
+  const single = `    fn ${name}(${['&self', ...params].join(', ')})${returns};`;
+  if (single.length <= 100) return single;
+  // rustfmt puts each parameter on its own line when the signature does not fit
+  return [
+    `    fn ${name}(`,
+    ...['&self', ...params].map(p => `        ${p},`),
+    `    )${returns};`,
+  ].join('\n');
+}
+
+/**
+ * Render the proxy module for the contract in `source`: its #[init], endpoints
+ * and views with the signatures as written, and the custom types they use
+ */
+export function generateSourceProxy(
+  source: string,
+  options: SourceProxyOptions = {}
+): GeneratedProxy {
+  const trait = findContractTrait(source);
+  const callable = listTraitFunctions(source).flatMap(fn => {
+    const kind = fn.attributes.map(a => CALLABLE_ATTRIBUTE.exec(a)).find(Boolean);
+    if (!kind || fn.attributes.some(a => a.startsWith('#[storage_mapper('))) return [];
+    if (kind[1] === 'init' && options.includeInit === false) return [];
+    return [{ fn, kind: kind[1], name: kind[1] === 'init' ? undefined : kind[2] || fn.name }];
+  });
+  if (options.endpoints) {
+    const missing = options.endpoints.filter(n => !callable.some(c => c.name === n));
+    if (missing.length > 0) {
+      throw new Error(`Endpoints not found in contract "${trait.name}": ${missing.join(', ')}`);
+    }
+  }
+  const selected = callable.filter(
+    c => c.name === undefined || !options.endpoints || options.endpoints.includes(c.name)
+  );
+
+  const definitions = customTypeItems(source);
+  const used = new Set<string>();
+  const visit = (text: string) => {
+    for (const word of text.match(/\w+/g) || []) {
+      if (!definitions.has(word) || used.has(word)) continue;
+      used.add(word);
+      visit(definitions.get(word)!.replace(/^[\s\S]*?(?=pub\s+(struct|enum))/, ''));
+    }
+  };
+  selected.forEach(c => visit(c.fn.signature.replace(/^fn\s+\w+/, '')));
+  const types = [...definitions.keys()].filter(name => used.has(name));
+
+  const items = selected.map(({ fn, kind, name }) => {
+    const docs = source
+      .slice(fn.start, source.indexOf(fn.signature, fn.start))
+      .split('\n')
+      .map(line => line.trim())
+      .filter(line => line.startsWith('///'));
+    const payable = fn.attributes.filter(a => a.startsWith('#[payable('));
+    const attribute = name === undefined ? '#[init]' : `#[${kind}(${name})]`;
+    return [
+      ...[...docs, ...payable, attribute].map(line => `    ${line}`),
+      renderSourceSignature(fn.name, fn.signature),
+    ].join('\n');
+  });
+
+  const header = ['use klever_sc::imports::*;'];
+  if (types.length > 0) header.push('use klever_sc::derive_imports::*;');
+  const sections = [
+    header.join('\n'),
+    ...types.map(name => definitions.get(name)!.trim()),
+    `/// Endpoints of the \`${trait.name}\` contract\n#[klever_sc::proxy]\npub trait ${trait.name} {\n${items.join('\n\n')}\n}`,
+  ];
+
+  const module = options.module || `${camelToSnake(trait.name)}_proxy`;
+  const accessor = module.replace(/_proxy$/, '');
+  return {
+    path: `src/${module}.rs`,
+    content: `${sections.join('\n\n')}\n`,
+    modDeclaration: `pub mod ${module};`,
+    proxyAccessor: `    #[proxy]\n    fn ${accessor}_contract(&self, sc_address: ManagedAddress) -> ${module}::Proxy<Self::Api>;`,
+    endpoints: selected.flatMap(c => (c.name === undefined ? [] : [c.name])),
+    types,
+  };
+}
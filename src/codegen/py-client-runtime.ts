@@ -0,0 +1,479 @@
+/**
+ * ABI codec and node access embedded in generated Python clients (see
+ * py-client.ts). It mirrors abi-codec.ts on bytes and Python ints, reads the
+ * contract's `TYPES` and `STRUCTS` the generator emits, and depends only on
+ * the standard library; signing needs the `cryptography` package.
+ */
+
+export const PY_CLIENT_RUNTIME = String.raw`# ─── ABI codec ──────────────────────────────────────────────
+
+_WIDTHS = {
+    'u8': 1, 'u16': 2, 'u32': 4, 'usize': 4, 'u64': 8,
+    'i8': 1, 'i16': 2, 'i32': 4, 'isize': 4, 'i64': 8,
+}
+
+
+def _parse_type(type_: str) -> Tuple[str, List[str]]:
+    """Split an ABI type like List<tuple<u8,Address>> into its name and arguments"""
+    type_ = type_.strip()
+    open_ = type_.find('<')
+    if open_ < 0:
+        return type_, []
+    args, depth, start = [], 0, open_ + 1
+    for i in range(start, len(type_) - 1):
+        if type_[i] == '<':
+            depth += 1
+        elif type_[i] == '>':
+            depth -= 1
+        elif type_[i] == ',' and depth == 0:
+            args.append(type_[start:i].strip())
+            start = i + 1
+    args.append(type_[start:-1].strip())
+    return type_[:open_], args
+
+
+def _attribute(name: str) -> str:
+    """Python attribute of an ABI field name"""
+    return name + '_' if keyword.iskeyword(name) else name
+
+
+def _fixed(n: int, width: int, signed: bool) -> bytes:
+    return int(n).to_bytes(width, 'big', signed=signed)
+
+
+def _minimal(n: int, signed: bool) -> bytes:
+    """Shortest big-endian encoding of n; signed values keep their sign bit"""
+    n = int(n)
+    if n == 0:
+        return b''
+    if not signed:
+        return n.to_bytes((n.bit_length() + 7) // 8, 'big')
+    return n.to_bytes((n if n > 0 else ~n).bit_length() // 8 + 1, 'big', signed=True)
+
+
+def _length_prefixed(data: bytes) -> bytes:
+    return _fixed(len(data), 4, False) + data
+
+
+def _bytes_of(value: Any) -> bytes:
+    if isinstance(value, str):
+        return value.encode()
+    return bytes(value)
+
+
+def _field(value: Any, name: str, index: int) -> Any:
+    """Field of a struct given as a dataclass, a dict or a sequence in order"""
+    if isinstance(value, dict):
+        return value[name]
+    if isinstance(value, (list, tuple)):
+        return value[index]
+    return getattr(value, _attribute(name))
+
+
+# Bech32 with the klv prefix, for Address values
+
+_CHARSET = 'qpzry9x8gf2tvdw0s3jn54khce6mua7l'
+_HRP = [3, 3, 3, 0, 11, 12, 22]
+
+
+def _polymod(values: List[int]) -> int:
+    generator = [0x3B6A57B2, 0x26508E6D, 0x1EA119FA, 0x3D4233DD, 0x2A1462B3]
+    checksum = 1
+    for value in values:
+        top = checksum >> 25
+        checksum = ((checksum & 0x1FFFFFF) << 5) ^ value
+        for i in range(5):
+            if (top >> i) & 1:
+                checksum ^= generator[i]
+    return checksum
+
+
+def _convert_bits(data: Sequence[int], from_: int, to: int, pad: bool) -> List[int]:
+    out, accumulator, bits, maximum = [], 0, 0, (1 << to) - 1
+    for value in data:
+        accumulator = (accumulator << from_) | value
+        bits += from_
+        while bits >= to:
+            bits -= to
+            out.append((accumulator >> bits) & maximum)
+    if pad and bits:
+        out.append((accumulator << (to - bits)) & maximum)
+    elif not pad and (bits >= from_ or (accumulator << (to - bits)) & maximum):
+        raise ValueError('Invalid address padding')
+    return out
+
+
+def encode_address(data: bytes) -> str:
+    """klv1... address of a 32-byte public key"""
+    values = _convert_bits(data, 8, 5, True)
+    checksum = _polymod(_HRP + values + [0] * 6) ^ 1
+    values += [(checksum >> (5 * (5 - i))) & 31 for i in range(6)]
+    return 'klv1' + ''.join(_CHARSET[value] for value in values)
+
+
+def decode_address(value: Any) -> bytes:
+    """The 32 bytes of a klv1... address (or of 0x hex)"""
+    if isinstance(value, (bytes, bytearray)) and len(value) == 32:
+        return bytes(value)
+    if not isinstance(value, str):
+        raise ValueError('Expected an address, got ' + repr(value))
+    if value.startswith('0x') and len(value) == 66:
+        return bytes.fromhex(value[2:])
+    lower = value.lower()
+    data = [_CHARSET.find(char) for char in lower[4:]]
+    if not lower.startswith('klv1') or -1 in data or _polymod(_HRP + data) != 1:
+        raise ValueError('Invalid klv1... address: ' + value)
+    decoded = bytes(_convert_bits(data[:-6], 5, 8, False))
+    if len(decoded) != 32:
+        raise ValueError('Expected a 32-byte address: ' + value)
+    return decoded
+
+
+# Encoding
+
+
+def _encode_custom(name: str, value: Any, top_level: bool) -> bytes:
+    definition = TYPES.get(name)
+    if definition is None:
+        raise ValueError('Unknown ABI type ' + name)
+    if definition['type'] == 'struct':
+        fields = definition['fields']
+        return b''.join(
+            _encode_nested(field['type'], _field(value, field['name'], i))
+            for i, field in enumerate(fields)
+        )
+    key = value if isinstance(value, str) else next(iter(value))
+    variant = next((v for v in definition['variants'] if v['name'] == key), None)
+    if variant is None:
+        raise ValueError(name + ' has no variant ' + str(key))
+    fields = variant.get('fields', [])
+    values = {} if isinstance(value, str) else value[key]
+    # Field-less enums encode like a u8 discriminant
+    if top_level and not fields:
+        return _minimal(variant['discriminant'], False)
+    return bytes([variant['discriminant']]) + b''.join(
+        _encode_nested(field['type'], _field(values, field['name'], i))
+        for i, field in enumerate(fields)
+    )
+
+
+def _encode_nested(type_: str, value: Any) -> bytes:
+    name, args = _parse_type(type_)
+    if name in _WIDTHS:
+        return _fixed(value, _WIDTHS[name], name[0] == 'i')
+    if name in ('BigUint', 'BigInt'):
+        return _length_prefixed(_encode_top_level(name, value))
+    if name == 'bool':
+        return b'\x01' if value else b'\x00'
+    if name == 'Address':
+        return decode_address(value)
+    if name in ('bytes', 'utf8string', 'TokenIdentifier'):
+        return _length_prefixed(_bytes_of(value))
+    if name == 'Option':
+        return b'\x00' if value is None else b'\x01' + _encode_nested(args[0], value)
+    if name == 'List':
+        items = list(value)
+        return _fixed(len(items), 4, False) + b''.join(_encode_nested(args[0], i) for i in items)
+    if name == 'tuple':
+        return b''.join(_encode_nested(arg, value[i]) for i, arg in enumerate(args))
+    if re.fullmatch(r'array\d+', name):
+        if args[0] == 'u8':
+            return _bytes_of(value)
+        return b''.join(_encode_nested(args[0], item) for item in value)
+    return _encode_custom(name, value, False)
+
+
+def _encode_top_level(type_: str, value: Any) -> bytes:
+    name, args = _parse_type(type_)
+    if name in _WIDTHS:
+        # Top-level integers are minimal, but must still fit the declared width
+        _fixed(value, _WIDTHS[name], name[0] == 'i')
+        return _minimal(value, name[0] == 'i')
+    if name in ('BigUint', 'BigInt'):
+        if name == 'BigUint' and int(value) < 0:
+            raise ValueError('BigUint cannot hold ' + str(value))
+        return _minimal(value, name == 'BigInt')
+    if name == 'bool':
+        return b'\x01' if value else b''
+    if name in ('bytes', 'utf8string', 'TokenIdentifier'):
+        return _bytes_of(value)
+    if name == 'Option':
+        return b'' if value is None else b'\x01' + _encode_nested(args[0], value)
+    if name == 'List':
+        return b''.join(_encode_nested(args[0], item) for item in value)
+    if name in TYPES:
+        return _encode_custom(name, value, True)
+    return _encode_nested(type_, value)
+
+
+def encode_args(types: List[str], values: List[Any]) -> List[bytes]:
+    """VM arguments: optional<T> may be None, variadic<T> is a list, multi<...> a tuple"""
+    out: List[bytes] = []
+
+    def multi(type_: str, value: Any) -> None:
+        name, args = _parse_type(type_)
+        if name == 'multi':
+            for i, arg in enumerate(args):
+                multi(arg, value[i])
+        else:
+            out.append(_encode_top_level(type_, value))
+
+    for type_, value in zip(types, values):
+        name, args = _parse_type(type_)
+        if name == 'variadic':
+            for item in value or []:
+                multi(args[0], item)
+        elif name == 'optional':
+            if value is not None:
+                multi(args[0], value)
+        else:
+            multi(type_, value)
+    return out
+
+
+# Decoding
+
+
+class _Reader:
+    def __init__(self, data: bytes):
+        self.data = data
+        self.offset = 0
+
+    def take(self, length: int) -> bytes:
+        if self.offset + length > len(self.data):
+            raise ValueError('Not enough bytes to decode')
+        self.offset += length
+        return self.data[self.offset - length:self.offset]
+
+    def length(self) -> int:
+        return int.from_bytes(self.take(4), 'big')
+
+    @property
+    def done(self) -> bool:
+        return self.offset >= len(self.data)
+
+
+def _integer(name: str, data: bytes) -> int:
+    return int.from_bytes(data, 'big', signed=name[0] == 'i' or name == 'BigInt')
+
+
+def _decode_custom(name: str, reader: _Reader, discriminant: Optional[int] = None) -> Any:
+    definition = TYPES.get(name)
+    if definition is None:
+        raise ValueError('Unknown ABI type ' + name)
+    if definition['type'] == 'struct':
+        values = {
+            _attribute(field['name']): _decode_nested(field['type'], reader)
+            for field in definition['fields']
+        }
+        return STRUCTS[name](**values) if name in STRUCTS else values
+    index = reader.take(1)[0] if discriminant is None else discriminant
+    variant = next((v for v in definition['variants'] if v['discriminant'] == index), None)
+    if variant is None:
+        raise ValueError(name + ' has no variant with discriminant ' + str(index))
+    if not variant.get('fields'):
+        return variant['name']
+    fields = {field['name']: _decode_nested(field['type'], reader) for field in variant['fields']}
+    return {variant['name']: fields}
+
+
+def _decode_nested(type_: str, reader: _Reader) -> Any:
+    name, args = _parse_type(type_)
+    if name in _WIDTHS:
+        return _integer(name, reader.take(_WIDTHS[name]))
+    if name in ('BigUint', 'BigInt'):
+        return _integer(name, reader.take(reader.length()))
+    if name == 'bool':
+        return reader.take(1)[0] == 1
+    if name == 'Address':
+        return encode_address(reader.take(32))
+    if name == 'bytes':
+        return reader.take(reader.length())
+    if name in ('utf8string', 'TokenIdentifier'):
+        return reader.take(reader.length()).decode()
+    if name == 'Option':
+        return None if reader.take(1)[0] == 0 else _decode_nested(args[0], reader)
+    if name == 'List':
+        return [_decode_nested(args[0], reader) for _ in range(reader.length())]
+    if name == 'tuple':
+        return tuple(_decode_nested(arg, reader) for arg in args)
+    array = re.fullmatch(r'array(\d+)', name)
+    if array:
+        length = int(array.group(1))
+        if args[0] == 'u8':
+            return reader.take(length)
+        return [_decode_nested(args[0], reader) for _ in range(length)]
+    return _decode_custom(name, reader)
+
+
+def _decode_all(type_: str, data: bytes) -> Any:
+    reader = _Reader(data)
+    value = _decode_nested(type_, reader)
+    if not reader.done:
+        raise ValueError('Unexpected trailing bytes after ' + type_)
+    return value
+
+
+def _decode_top_level(type_: str, data: bytes) -> Any:
+    name, args = _parse_type(type_)
+    if name in _WIDTHS or name in ('BigUint', 'BigInt'):
+        return _integer(name, data)
+    if name == 'bool':
+        return len(data) > 0 and data[-1] == 1
+    if name == 'Address':
+        return encode_address(data)
+    if name == 'bytes':
+        return data
+    if name in ('utf8string', 'TokenIdentifier'):
+        return data.decode()
+    if name == 'Option':
+        return None if not data else _decode_all(args[0], data[1:])
+    if name == 'List':
+        reader, items = _Reader(data), []
+        while not reader.done:
+            items.append(_decode_nested(args[0], reader))
+        return items
+    definition = TYPES.get(name)
+    # Field-less enums are a top-level integer discriminant, and so are the
+    # field-less variants of others, down to no bytes for discriminant 0
+    if definition and definition['type'] == 'enum':
+        if not data or all(not v.get('fields') for v in definition['variants']):
+            return _decode_custom(name, _Reader(b''), int.from_bytes(data, 'big'))
+    return _decode_all(type_, data)
+
+
+def decode_results(types: List[str], results: List[bytes]) -> List[Any]:
+    """Decode results: variadic<T> takes the rest, optional<T> the next one if any"""
+    queue = list(results)
+
+    def multi(type_: str) -> Any:
+        name, args = _parse_type(type_)
+        if name == 'multi':
+            return tuple(multi(arg) for arg in args)
+        return _decode_top_level(type_, queue.pop(0) if queue else b'')
+
+    values: List[Any] = []
+    for type_ in types:
+        name, args = _parse_type(type_)
+        if name == 'variadic':
+            items = []
+            while queue:
+                items.append(multi(args[0]))
+            values.append(items)
+        elif name == 'optional':
+            values.append(multi(args[0]) if queue else None)
+        else:
+            values.append(multi(type_))
+    return values
+
+
+# ─── Node access ────────────────────────────────────────────
+
+# Transaction type of smart contract calls and its invoke sub-type
+_SMART_CONTRACT = 63
+_SC_INVOKE = 0
+
+
+class ContractError(Exception):
+    """A view query the VM rejected"""
+
+
+def _request(url: str, body: Any = None) -> Any:
+    """The data of a Klever API response; raises with its error"""
+    data = None if body is None else json.dumps(body).encode()
+    headers = {} if data is None else {'Content-Type': 'application/json'}
+    request = urllib.request.Request(url, data=data, headers=headers)
+    try:
+        with urllib.request.urlopen(request, timeout=30) as response:
+            payload = json.load(response)
+    except urllib.error.HTTPError as error:
+        try:
+            payload = json.load(error)
+        except ValueError:
+            raise error
+    if payload.get('error'):
+        raise RuntimeError(url + ': ' + str(payload['error']))
+    return payload.get('data')
+
+
+class Wallet:
+    """Signing key of a wallet PEM as koperator writes it; needs the cryptography package"""
+
+    def __init__(self, seed: bytes):
+        from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PrivateKey
+        from cryptography.hazmat.primitives.serialization import Encoding, PublicFormat
+
+        self._key = Ed25519PrivateKey.from_private_bytes(seed)
+        public_key = self._key.public_key().public_bytes(Encoding.Raw, PublicFormat.Raw)
+        self.address = encode_address(public_key)
+
+    @classmethod
+    def load(cls, path: str) -> 'Wallet':
+        with open(path) as file:
+            lines = [line.strip() for line in file if not line.startswith('-----')]
+        decoded = base64.b64decode(''.join(lines))
+        # koperator writes the hex of the seed (and public key), base64-encoded
+        try:
+            text = decoded.decode('ascii')
+            data = bytes.fromhex(text) if re.fullmatch(r'([0-9a-fA-F]{2})+', text) else decoded
+        except UnicodeDecodeError:
+            data = decoded
+        if len(data) not in (32, 64):
+            raise ValueError(
+                path + ': expected a 32-byte seed, optionally followed by the public key'
+            )
+        return cls(data[:32])
+
+    def sign(self, message: bytes) -> bytes:
+        return self._key.sign(message)
+
+
+class ContractClient:
+    """Views are queried on node_url; endpoints are built there as unsigned transactions"""
+
+    def __init__(self, address: str, node_url: str):
+        self.address = address
+        self.node_url = node_url.rstrip('/')
+
+    def _query(self, endpoint: str, inputs: List[str], values: List[Any], outputs: List[str]):
+        body = {
+            'scAddress': self.address,
+            'funcName': endpoint,
+            'args': [base64.b64encode(arg).decode() for arg in encode_args(inputs, values)],
+        }
+        data = _request(self.node_url + '/vm/query', body) or {}
+        code = data.get('returnCode') or 'ok'
+        if code.lower() != 'ok':
+            message = data.get('returnMessage', '')
+            raise ContractError(endpoint + ' failed (' + code + '): ' + message)
+        results = [base64.b64decode(item) for item in data.get('returnData') or []]
+        return decode_results(outputs, results)
+
+    def _build(
+        self,
+        endpoint: str,
+        inputs: List[str],
+        values: List[Any],
+        sender: str,
+        call_value: Optional[Dict[str, int]],
+    ) -> Dict[str, Any]:
+        nonce = _request(self.node_url + '/address/' + sender + '/nonce')['nonce']
+        contract: Dict[str, Any] = {'scType': _SC_INVOKE, 'address': self.address}
+        if call_value:
+            contract['callValue'] = dict(call_value)
+        args = [base64.b64encode(arg).decode() for arg in encode_args(inputs, values)]
+        request = {
+            'type': _SMART_CONTRACT,
+            'sender': sender,
+            'nonce': nonce,
+            'contracts': [contract],
+            'data': [endpoint] + args,
+        }
+        return _request(self.node_url + '/transaction/send', request)['result']
+
+    def send(self, transaction: Dict[str, Any], wallet: Wallet) -> str:
+        """Sign a transaction built by an endpoint method and broadcast it; returns its hash"""
+        signature = wallet.sign(bytes.fromhex(transaction['txHash']))
+        body = {'tx': transaction['tx'], 'signature': signature.hex()}
+        return _request(self.node_url + '/transactions/broadcast', body)['txHash']
+`;
@@ -0,0 +1,105 @@
+import { parseAbi } from '../abi/index.js';
+import { generatePyClient } from './py-client.js';
+
+const ABI = parseAbi({
+  name: 'token_vault',
+  endpoints: [
+    {
+      name: 'deposit',
+      mutability: 'mutable',
+      payableInTokens: ['KLV'],
+      inputs: [{ name: 'lock_until', type: 'optional<u64>' }],
+      outputs: [],
+    },
+    {
+      name: 'getPosition',
+      docs: ['Position of an account'],
+      mutability: 'readonly',
+      inputs: [{ name: 'owner', type: 'Address' }],
+      outputs: [{ type: 'Option<Position>' }],
+    },
+    {
+      name: 'getHistory',
+      mutability: 'readonly',
+      inputs: [{ name: 'from', type: 'u64' }],
+      outputs: [{ type: 'BigUint' }, { type: 'List<Status>' }],
+    },
+    {
+      name: 'remove',
+      mutability: 'mutable',
+      onlyOwner: true,
+      inputs: [{ name: 'accounts', type: 'variadic<multi<Address,BigUint>>' }],
+      outputs: [],
+    },
+  ],
+  types: {
+    Position: {
+      type: 'struct',
+      fields: [
+        { name: 'amount', type: 'BigUint' },
+        { name: 'status', type: 'Status' },
+        { name: 'class', type: 'bytes' },
+      ],
+    },
+    Status: {
+      type: 'enum',
+      variants: [
+        { name: 'Open', discriminant: 0 },
+        { name: 'Locked', discriminant: 1, fields: [{ name: 'until', type: 'u64' }] },
+      ],
+    },
+  },
+});
+
+describe('generatePyClient', () => {
+  const client = generatePyClient(ABI, {
+    nodeUrl: 'https://node.testnet.klever.org',
+    address: 'klv1qqrsu9guyv4rzwplgex4gkmzd9c8wl593jfe4gdg47mtm3xt6tvs6kad8h',
+  });
+
+  it('declares the custom types as dataclasses and aliases', () => {
+    expect(client.path).toBe('token_vault_client.py');
+    expect(client.types).toEqual(['Position', 'Status']);
+    // Reserved words get a suffix, as the runtime expects
+    expect(client.content).toContain(
+      '@dataclass\nclass Position:\n    amount: int\n    status: Status\n    class_: bytes'
+    );
+    expect(client.content).toContain(
+      "#   {'Locked': {'until': int}}\nStatus = Union[Literal['Open'], Dict[str, Dict[str, Any]]]"
+    );
+  });
+
+  it('renders a method per view and endpoint', () => {
+    expect(client.className).toBe('TokenVaultClient');
+    expect(client.views).toEqual(['getPosition', 'getHistory']);
+    expect(client.endpoints).toEqual(['deposit', 'remove']);
+    expect(client.content).toContain(
+      '    def get_position(self, owner: str) -> Optional[Position]:\n        """Position of an account"""'
+    );
+    expect(client.content).toContain(
+      "        return self._query('getPosition', ['Address'], [owner], ['Option<Position>'])[0]"
+    );
+    expect(client.content).toContain(
+      "    def get_history(self, from_: int) -> Tuple[int, List[Status]]:\n        return tuple(self._query('getHistory', ['u64'], [from_], ['BigUint', 'List<Status>']))"
+    );
+    expect(client.content).toContain(
+      '        lock_until: Optional[int] = None,\n        *,\n        sender: str,\n        call_value: Optional[Dict[str, int]] = None,\n    ) -> Dict[str, Any]:'
+    );
+    expect(client.content).toContain(
+      '    def remove(self, accounts: List[Tuple[str, int]], *, sender: str) -> Dict[str, Any]:\n        """Only the contract owner may call it."""'
+    );
+    expect(client.content).toContain(
+      "        return self._build('remove', ['variadic<multi<Address,BigUint>>'], [accounts], sender, None)"
+    );
+    expect(client.content).toContain(
+      "        address: str = 'klv1qqrsu9guyv4rzwplgex4gkmzd9c8wl593jfe4gdg47mtm3xt6tvs6kad8h',"
+    );
+  });
+
+  it('embeds the ABI data the codec needs', () => {
+    expect(client.content).toContain('TYPES: Dict[str, Any] = {');
+    expect(client.content).toContain("STRUCTS: Dict[str, Any] = {\n    'Position': Position,\n}");
+    expect(client.content).toContain('def encode_args(');
+    expect(client.content).toContain('class ContractClient:');
+  });
+});
@@ -0,0 +1,330 @@
+/**
+ * Generate a typed Python client for a contract from its ABI: dataclasses and
+ * aliases for its structs and enums, and a class with one method per view
+ * (queried on a node, results decoded) and per endpoint (built on the node as
+ * an unsigned transaction, signed and broadcast with `send`). The generated
+ * module carries its own ABI codec and needs only the standard library.
+ */
+
+import type { Abi, AbiEndpoint, AbiInput, AbiTypeDef } from '../abi/index.js';
+import { parseAbiType } from './abi-values.js';
+import { PY_CLIENT_RUNTIME } from './py-client-runtime.js';
+import { camelToSnake } from './rust-source.js';
+
+export interface PyClientOptions {
+  /** Node URL views are queried and transactions built on, the client's default */
+  nodeUrl: string;
+  /** Contract address (klv1...) the client targets by default */
+  address?: string;
+}
+
+export interface GeneratedPyClient {
+  /** Module file name */
+  path: string;
+  content: string;
+  className: string;
+  views: string[];
+  endpoints: string[];
+  /** Custom types declared as dataclasses or aliases */
+  types: string[];
+}
+
+type Direction = 'input' | 'output';
+
+const RESERVED = new Set(
+  (
+    'False None True and as assert async await break class continue def del elif else except ' +
+    'finally for from global if import in is lambda nonlocal not or pass raise return try while ' +
+    'with yield'
+  ).split(' ')
+);
+
+const INTEGERS = /^(u|i)(8|16|32|64|size)$|^Big(Uint|Int)$/;
+
+/** Python string literal */
+function quote(text: string): string {
+  return `'${text.replace(/\\/g, '\\\\').replace(/'/g, "\\'").replace(/\n/g, '\\n')}'`;
+}
+
+/** A valid, non-reserved snake_case identifier for an ABI name, unlike any of `taken` */
+function identifier(name: string, taken: Iterable<string> = []): string {
+  let id = camelToSnake(name).replace(/\W/g, '_');
+  if (/^\d/.test(id)) id = `_${id}`;
+  const used = new Set(taken);
+  while (RESERVED.has(id) || used.has(id)) id = `${id}_`;
+  return id;
+}
+
+/** Attribute of a struct field, as the runtime's _attribute() derives it */
+const attribute = (name: string) => (RESERVED.has(name) ? `${name}_` : name);
+
+function pascalCase(name: string): string {
+  return camelToSnake(name.replace(/[-\s]+/g, '_'))
+    .split('_')
+    .filter(Boolean)
+    .map(part => part.charAt(0).toUpperCase() + part.slice(1))
+    .join('');
+}
+
+function docstring(docs: string[], indent: string): string[] {
+  const lines = docs.map(d => d.trim().replace(/\\/g, '\\\\').replace(/"""/g, '\\"\\"\\"'));
+  const text = lines.filter(Boolean);
+  if (text.length === 0) return [];
+  if (text.length === 1) return [`${indent}"""${text[0]}"""`];
+  return [`${indent}"""`, ...text.map(line => `${indent}${line}`), `${indent}"""`];
+}
+
+/** Python type of ABI `type`; inputs take bytes as text too */
+function pyType(type: string, direction: Direction, abi: Abi): string {
+  const { name, args } = parseAbiType(type);
+  const inner = (arg: string) => pyType(arg, direction, abi);
+  if (INTEGERS.test(name)) return 'int';
+  switch (name) {
+    case 'bool':
+      return 'bool';
+    case 'Address':
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return 'str';
+    case 'bytes':
+      return direction === 'input' ? 'Union[bytes, str]' : 'bytes';
+    case 'Option':
+    case 'optional':
+      return `Optional[${inner(args[0])}]`;
+    case 'List':
+    case 'variadic':
+      return `List[${inner(args[0])}]`;
+    case 'tuple':
+    case 'multi':
+      return `Tuple[${args.map(inner).join(', ')}]`;
+  }
+  if (/^array\d+$/.test(name)) {
+    return args[0] === 'u8' ? pyType('bytes', direction, abi) : `List[${inner(args[0])}]`;
+  }
+  return abi.types && Object.hasOwn(abi.types, name) ? name : 'Any';
+}
+
+/** `head(args)`, with the arguments on their own line when it does not fit in 100 columns */
+function call(indent: string, head: string, args: string[], tail = ''): string[] {
+  const line = `${indent}${head}(${args.join(', ')})${tail}`;
+  if (line.length <= 100) return [line];
+  const inline = `${indent}    ${args.join(', ')}`;
+  if (inline.length <= 100) return [`${indent}${head}(`, inline, `${indent})${tail}`];
+  return [`${indent}${head}(`, ...args.map(arg => `${indent}    ${arg},`), `${indent})${tail}`];
+}
+
+function renderType(name: string, def: AbiTypeDef, abi: Abi): string {
+  if (def.type === 'struct') {
+    const fields = def.fields.map(
+      f => `    ${attribute(f.name)}: ${pyType(f.type, 'output', abi)}`
+    );
+    return [
+      '@dataclass',
+      `class ${name}:`,
+      ...docstring(def.docs || [], '    '),
+      ...(fields.length > 0 ? fields : ['    pass']),
+    ].join('\n');
+  }
+  // Enum values are the variant name, or {'Variant': {fields}} for variants with fields
+  const simple = def.variants.filter(v => !v.fields || v.fields.length === 0);
+  const names = `Literal[${simple.map(v => quote(v.name)).join(', ')}]`;
+  const alias =
+    simple.length === def.variants.length
+      ? names
+      : simple.length === 0
+        ? 'Dict[str, Dict[str, Any]]'
+        : `Union[${names}, Dict[str, Dict[str, Any]]]`;
+  const docs = (def.docs || []).map(d => `# ${d.trim()}`);
+  const variants = def.variants
+    .filter(v => v.fields && v.fields.length > 0)
+    .map(v => {
+      const fields = v.fields!.map(f => `${quote(f.name)}: ${pyType(f.type, 'output', abi)}`);
+      return `#   {${quote(v.name)}: {${fields.join(', ')}}}`;
+    });
+  return [...docs, ...variants, `${name} = ${alias}`].join('\n');
+}
+
+function renderParams(inputs: AbiInput[], abi: Abi, reserved: string[]) {
+  const names: string[] = [];
+  return inputs.map((input, index) => {
+    const name = identifier(input.name, [...reserved, ...names]);
+    names.push(name);
+    const { name: kind, args } = parseAbiType(input.type);
+    // Trailing optional arguments may be left out
+    const trailing = inputs.slice(index).every(i => parseAbiType(i.type).name === 'optional');
+    if (kind === 'optional' && trailing) {
+      return { name, param: `${name}: Optional[${pyType(args[0], 'input', abi)}] = None` };
+    }
+    return { name, param: `${name}: ${pyType(input.type, 'input', abi)}` };
+  });
+}
+
+const typeList = (types: { type: string }[]) => `[${types.map(t => quote(t.type)).join(', ')}]`;
+
+function endpointDocs(endpoint: AbiEndpoint): string[] {
+  const docs = [...(endpoint.docs || [])];
+  const tokens = endpoint.payableInTokens || [];
+  if (tokens.length > 0) {
+    docs.push(`Payable in ${tokens.includes('*') ? 'any token' : tokens.join(', ')}.`);
+  }
+  if (endpoint.onlyOwner) docs.push('Only the contract owner may call it.');
+  return docstring(docs, '        ');
+}
+
+function renderView(endpoint: AbiEndpoint, method: string, abi: Abi): string {
+  const params = renderParams(endpoint.inputs, abi, ['self']);
+  const outputs = endpoint.outputs.map(o => pyType(o.type, 'output', abi));
+  const returnType =
+    outputs.length === 0
+      ? 'None'
+      : outputs.length === 1
+        ? outputs[0]
+        : `Tuple[${outputs.join(', ')}]`;
+  const args = [
+    quote(endpoint.name),
+    typeList(endpoint.inputs),
+    `[${params.map(p => p.name).join(', ')}]`,
+    typeList(endpoint.outputs),
+  ];
+  const body =
+    outputs.length === 0
+      ? call('        ', 'self._query', args)
+      : outputs.length === 1
+        ? call('        ', 'return self._query', args, '[0]')
+        : call('        ', 'return tuple(self._query', args, ')');
+  return [
+    ...call('    ', `def ${method}`, ['self', ...params.map(p => p.param)], ` -> ${returnType}:`),
+    ...endpointDocs(endpoint),
+    ...body,
+  ].join('\n');
+}
+
+function renderEndpoint(endpoint: AbiEndpoint, method: string, abi: Abi): string {
+  const params = renderParams(endpoint.inputs, abi, ['self', 'sender', 'call_value']);
+  const payable = (endpoint.payableInTokens || []).length > 0;
+  const signature = [
+    'self',
+    ...params.map(p => p.param),
+    '*',
+    'sender: str',
+    ...(payable ? ['call_value: Optional[Dict[str, int]] = None'] : []),
+  ];
+  const args = [
+    quote(endpoint.name),
+    typeList(endpoint.inputs),
+    `[${params.map(p => p.name).join(', ')}]`,
+    'sender',
+    payable ? 'call_value' : 'None',
+  ];
+  return [
+    ...call('    ', `def ${method}`, signature, ' -> Dict[str, Any]:'),
+    ...endpointDocs(endpoint),
+    ...call('        ', 'return self._build', args),
+  ].join('\n');
+}
+
+/** Render the client module for `abi` */
+export function generatePyClient(abi: Abi, options: PyClientOptions): GeneratedPyClient {
+  const className = `${pascalCase(abi.name)}Client`;
+  const definitions = abi.types || {};
+  const types = Object.keys(definitions);
+  const structs = types.filter(name => definitions[name].type === 'struct');
+
+  const methods: string[] = [];
+  const members = ['address', 'node_url', 'send'];
+  for (const endpoint of abi.endpoints) {
+    const method = identifier(endpoint.name, members);
+    members.push(method);
+    methods.push(
+      endpoint.mutability === 'readonly'
+        ? renderView(endpoint, method, abi)
+        : renderEndpoint(endpoint, method, abi)
+    );
+  }
+
+  const nodeUrl = quote(options.nodeUrl);
+  const address = options.address ? `address: str = ${quote(options.address)}` : 'address: str';
+  const classSection = [
+    `class ${className}(ContractClient):`,
+    ...docstring(
+      [
+        `Client of the ${abi.name} contract. Views are queried on node_url; endpoint`,
+        'methods build unsigned transactions from sender on the node, which send()',
+        'signs with a Wallet and broadcasts.',
+      ],
+      '    '
+    ),
+    '',
+    ...call('    ', 'def __init__', ['self', address, `node_url: str = ${nodeUrl}`], ':'),
+    '        super().__init__(address, node_url)',
+    ...methods.flatMap(method => ['', method]),
+  ].join('\n');
+
+  // The codec only needs names and types; docs stay in the declarations above
+  const typeData = Object.fromEntries(
+    types.map(typeName => {
+      const def = definitions[typeName];
+      return [
+        typeName,
+        def.type === 'struct'
+          ? { type: 'struct', fields: def.fields.map(({ name, type }) => ({ name, type })) }
+          : {
+              type: 'enum',
+              variants: def.variants.map(({ name, discriminant, fields }) => ({
+                name,
+                discriminant,
+                ...(fields && fields.length > 0
+                  ? { fields: fields.map(({ name, type }) => ({ name, type })) }
+                  : {}),
+              })),
+            },
+      ];
+    })
+  );
+
+  const sections = [
+    [
+      '"""',
+      `Typed client of the ${abi.name} contract, generated from its ABI. Regenerate it`,
+      'when the ABI changes instead of editing it.',
+      '"""',
+      '',
+      'from __future__ import annotations',
+      '',
+      'import base64',
+      'import json',
+      'import keyword',
+      'import re',
+      'import urllib.error',
+      'import urllib.request',
+      'from dataclasses import dataclass',
+      'from typing import Any, Dict, List, Literal, Optional, Sequence, Tuple, Union',
+    ].join('\n'),
+    ...types.map(name => renderType(name, definitions[name], abi)),
+    // The client class extends ContractClient from the runtime
+    PY_CLIENT_RUNTIME.trimEnd(),
+    classSection,
+    [
+      '# ─── ABI data ───────────────────────────────────────────────',
+      '',
+      `TYPES: Dict[str, Any] = ${JSON.stringify(typeData, null, 4)}`,
+      '',
+      ...(structs.length === 0
+        ? ['STRUCTS: Dict[str, Any] = {}']
+        : [
+            'STRUCTS: Dict[str, Any] = {',
+            ...structs.map(name => `    ${quote(name)}: ${name},`),
+            '}',
+          ]),
+    ].join('\n'),
+  ];
+
+  return {
+    path: `${camelToSnake(abi.name).replace(/-/g, '_')}_client.py`,
+    content: `${sections.join('\n\n\n')}\n`,
+    className,
+    views: abi.endpoints.filter(e => e.mutability === 'readonly').map(e => e.name),
+    endpoints: abi.endpoints.filter(e => e.mutability !== 'readonly').map(e => e.name),
+    types,
+  };
+}
@@ -0,0 +1,409 @@
+/**
+ * Lightweight helpers for locating and editing items in klever-sc contract source.
+ *
+ * These work on source text (not a full Rust parser): brace matching skips
+ * string literals and comments, which is enough for idiomatic contract code.
+ */
+
+export interface ContractTraitLocation {
+  name: string;
+  /** Index of the `#[klever_sc::contract]` (or `#[klever_sc::module]`) attribute */
+  attributeStart: number;
+  /** Start of the trait item including doc comments above the attribute */
+  itemStart: number;
+  /** Index just after the trait's opening brace */
+  bodyStart: number;
+  /** Index of the trait's closing brace */
+  bodyEnd: number;
+}
+
+export interface FunctionLocation {
+  name: string;
+  /** Start of the first attribute, doc comment, or `fn` keyword belonging to the function */
+  start: number;
+  /** Index just after the body's opening brace; -1 for bodiless declarations */
+  bodyStart: number;
+  /** Index of the body's closing brace, or of the terminating `;` for declarations */
+  end: number;
+  /** Attribute lines preceding the function, e.g. `#[endpoint(transfer)]` */
+  attributes: string[];
+  /** Text from `fn` up to (not including) the body brace or semicolon */
+  signature: string;
+}
+
+export interface RustParam {
+  name: string;
+  type: string;
+  /** Attributes on the parameter, e.g. `indexed` */
+  attributes: string[];
+}
+
+/**
+ * Return the index of the brace that closes the one at `openIndex`
+ */
+export function findMatchingBrace(source: string, openIndex: number): number {
+  let depth = 0;
+  for (let i = openIndex; i < source.length; i++) {
+    const ch = source[i];
+    const next = source[i + 1];
+    if (ch === '/' && next === '/') {
+      const newline = source.indexOf('\n', i);
+      i = newline === -1 ? source.length : newline;
+    } else if (ch === '/' && next === '*') {
+      const close = source.indexOf('*/', i + 2);
+      i = close === -1 ? source.length : close + 1;
+    } else if (ch === '"') {
+      i++;
+      while (i < source.length && source[i] !== '"') {
+        if (source[i] === '\\') i++;
+        i++;
+      }
+    } else if (ch === '{') {
+      depth++;
+    } else if (ch === '}') {
+      depth--;
+      if (depth === 0) return i;
+    }
+  }
+  throw new Error('Unbalanced braces in contract source.');
+}
+
+/**
+ * Locate the `#[klever_sc::contract]` trait, or the `#[klever_sc::module]` trait
+ * when the source is a module file
+ */
+export function findContractTrait(source: string): ContractTraitLocation {
+  let attribute = '#[klever_sc::contract]';
+  let attributeStart = source.indexOf(attribute);
+  if (attributeStart === -1) {
+    attribute = '#[klever_sc::module]';
+    attributeStart = source.indexOf(attribute);
+  }
+  if (attributeStart === -1) {
+    throw new Error('No #[klever_sc::contract] trait found in source.');
+  }
+  const traitMatch = /pub\s+trait\s+(\w+)/.exec(source.slice(attributeStart));
+  if (!traitMatch) {
+    throw new Error(`No trait declaration follows ${attribute}.`);
+  }
+  const open = source.indexOf('{', attributeStart + traitMatch.index);
+  const docs = /(?:^[ \t]*\/\/\/.*\n)*(?![\s\S])/m.exec(source.slice(0, attributeStart));
+  return {
+    name: traitMatch[1],
+    attributeStart,
+    itemStart: docs ? attributeStart - docs[0].length : attributeStart,
+    bodyStart: open + 1,
+    bodyEnd: findMatchingBrace(source, open),
+  };
+}
+
+/**
+ * List functions declared directly inside the contract trait, in source order
+ */
+export function listTraitFunctions(source: string): FunctionLocation[] {
+  const trait = findContractTrait(source);
+  const functions: FunctionLocation[] = [];
+  const fnRegex = /\bfn\s+(\w+)/g;
+  fnRegex.lastIndex = trait.bodyStart;
+
+  let match;
+  while ((match = fnRegex.exec(source)) !== null && match.index < trait.bodyEnd) {
+    // Find the end of the signature: either the body brace or a terminating semicolon
+    let i = match.index;
+    let parens = 0;
+    while (i < trait.bodyEnd) {
+      const ch = source[i];
+      if (ch === '(' || ch === '<') parens++;
+      else if (ch === ')' || (ch === '>' && source[i - 1] !== '-')) parens--;
+      else if (parens === 0 && (ch === '{' || ch === ';')) break;
+      i++;
+    }
+
+    const { start, attributes } = leadingAttributes(source, match.index, trait.bodyStart);
+    const signature = source.slice(match.index, i).trim();
+    if (source[i] === '{') {
+      const end = findMatchingBrace(source, i);
+      functions.push({ name: match[1], start, bodyStart: i + 1, end, attributes, signature });
+      fnRegex.lastIndex = end + 1;
+    } else {
+      functions.push({ name: match[1], start, bodyStart: -1, end: i, attributes, signature });
+      fnRegex.lastIndex = i + 1;
+    }
+  }
+  return functions;
+}
+
+/** Walk backwards over attribute and doc-comment lines directly above `fnIndex` */
+function leadingAttributes(
+  source: string,
+  fnIndex: number,
+  floor: number
+): { start: number; attributes: string[] } {
+  let start = source.lastIndexOf('\n', fnIndex - 1) + 1;
+  const attributes: string[] = [];
+  while (start > floor) {
+    const prevEnd = start - 1;
+    const prevStart = source.lastIndexOf('\n', prevEnd - 1) + 1;
+    const line = source.slice(prevStart, prevEnd).trim();
+    if (line.startsWith('#[')) {
+      attributes.unshift(line);
+    } else if (!line.startsWith('///')) {
+      break;
+    }
+    start = prevStart;
+  }
+  return { start, attributes };
+}
+
+export function findTraitFunction(source: string, name: string): FunctionLocation | undefined {
+  return listTraitFunctions(source).find(f => f.name === name);
+}
+
+/** First trait function carrying the given attribute (e.g. `init`, `upgrade`) */
+export function findFunctionWithAttribute(
+  source: string,
+  attribute: string
+): FunctionLocation | undefined {
+  const pattern = new RegExp(`^#\\[${attribute}(\\(|\\])`);
+  return listTraitFunctions(source).find(f => f.attributes.some(a => pattern.test(a)));
+}
+
+/**
+ * Insert `snippet` just before the contract trait's closing brace
+ */
+export function appendToTrait(source: string, snippet: string): string {
+  const { bodyEnd } = findContractTrait(source);
+  const before = source.slice(0, bodyEnd).replace(/\s*$/, '\n');
+  return `${before}\n${snippet.replace(/\s*$/, '')}\n${source.slice(bodyEnd)}`;
+}
+
+/** Insert `text` at `index` */
+export function insertAt(source: string, index: number, text: string): string {
+  return source.slice(0, index) + text + source.slice(index);
+}
+
+const STATEMENT_INDENT = '        ';
+const CLOSING_INDENT = '    ';
+
+/** Add a statement as the last line of a trait function body */
+export function appendStatement(source: string, fn: FunctionLocation, statement: string): string {
+  const body = source.slice(fn.bodyStart, fn.end);
+  const kept = body.trim() === '' ? '' : body.replace(/\s*$/, '');
+  const newBody = `${kept}\n${STATEMENT_INDENT}${statement}\n${CLOSING_INDENT}`;
+  return source.slice(0, fn.bodyStart) + newBody + source.slice(fn.end);
+}
+
+/** Add a statement as the first line of a trait function body */
+export function prependStatement(source: string, fn: FunctionLocation, statement: string): string {
+  const body = source.slice(fn.bodyStart, fn.end);
+  const rest = body.trim() === '' ? `\n${CLOSING_INDENT}` : body;
+  const newBody = `\n${STATEMENT_INDENT}${statement}${rest}`;
+  return source.slice(0, fn.bodyStart) + newBody + source.slice(fn.end);
+}
+
+/** Remove trait functions by name, along with section comments left without items */
+export function removeTraitFunctions(source: string, names: string[]): string {
+  const targets = listTraitFunctions(source)
+    .filter(f => names.includes(f.name))
+    .reverse();
+  let updated = source;
+  for (const fn of targets) {
+    let end = fn.end + 1;
+    while (updated[end] === ' ' || updated[end] === '\t') end++;
+    if (updated[end] === '\n') end++;
+    updated = updated.slice(0, fn.start) + updated.slice(end);
+  }
+
+  const trait = findContractTrait(updated);
+  const lines = updated.slice(trait.bodyStart, trait.bodyEnd).split('\n');
+  const isSectionComment = (line: string | undefined) =>
+    line !== undefined && /^\s*\/\/(?!\/)/.test(line);
+  const kept = lines.filter((line, i) => {
+    if (!isSectionComment(line)) return true;
+    let next = i + 1;
+    while (next < lines.length && lines[next].trim() === '') next++;
+    // A detached comment followed by another section or the end of the trait is orphaned
+    const detached = next > i + 1;
+    return !(detached && (next >= lines.length - 1 || isSectionComment(lines[next])));
+  });
+  const body = kept
+    .join('\n')
+    .replace(/\n(?:[ \t]*\n){2,}/g, '\n\n')
+    .replace(/\n(?:[ \t]*\n)+([ \t]*)$/, '\n$1');
+  return updated.slice(0, trait.bodyStart) + body + updated.slice(trait.bodyEnd);
+}
+
+/** Trait header with supertraits, wrapped one per line (rustfmt style) when too long */
+export function formatTraitHeader(header: string, supertraits: string[]): string {
+  if (supertraits.length === 0) return `${header} {`;
+  const single = `${header}: ${supertraits.join(' + ')} {`;
+  if (single.length <= 100) return single;
+  return `${header}:\n    ${supertraits.join('\n    + ')}\n{`;
+}
+
+/** Supertraits currently declared on the contract trait */
+export function contractSupertraits(source: string): string[] {
+  const trait = findContractTrait(source);
+  const headerStart = source.indexOf('pub trait', trait.attributeStart);
+  const existing = source
+    .slice(headerStart, trait.bodyStart - 1)
+    .replace(/^pub\s+trait\s+\w+\s*:?/, '')
+    .trim();
+  return existing ? existing.split('+').map(s => s.trim()) : [];
+}
+
+/** Add supertraits to the contract trait, skipping ones already declared */
+export function addContractSupertraits(source: string, supertraits: string[]): string {
+  const trait = findContractTrait(source);
+  const headerStart = source.indexOf('pub trait', trait.attributeStart);
+  const existing = contractSupertraits(source);
+  const merged = [...existing, ...supertraits.filter(s => !existing.includes(s))];
+  const header = formatTraitHeader(`pub trait ${trait.name}`, merged);
+  return source.slice(0, headerStart) + header + source.slice(trait.bodyStart);
+}
+
+/** Add `pub mod` declarations after the last `use` line above the contract trait */
+export function addModDeclarations(source: string, modules: string[]): string {
+  const { itemStart } = findContractTrait(source);
+  const prefix = source.slice(0, itemStart);
+  const declared = (m: string) => new RegExp(`^\\s*(pub\\s+)?mod\\s+${m}\\s*;`, 'm').test(prefix);
+  const missing = modules.filter(m => !declared(m));
+  if (missing.length === 0) return source;
+
+  const decls = missing.map(m => `pub mod ${m};`).join('\n');
+  const existingMods = [...prefix.matchAll(/^(pub\s+)?mod\s+\w+\s*;[ \t]*\n/gm)];
+  if (existingMods.length > 0) {
+    const last = existingMods[existingMods.length - 1];
+    return insertAt(source, last.index! + last[0].length, `${decls}\n`);
+  }
+  const uses = [...prefix.matchAll(/^use\s[^;]+;[ \t]*\n/gm)];
+  if (uses.length > 0) {
+    const last = uses[uses.length - 1];
+    return insertAt(source, last.index! + last[0].length, `\n${decls}\n`);
+  }
+  return `${decls}\n\n${source}`;
+}
+
+/** Split on commas that are not nested inside brackets */
+export function splitTopLevel(text: string, separator = ','): string[] {
+  const parts: string[] = [];
+  let depth = 0;
+  let current = '';
+  for (let i = 0; i < text.length; i++) {
+    const ch = text[i];
+    if (ch === '(' || ch === '<' || ch === '[') depth++;
+    else if (ch === ')' || ch === ']' || (ch === '>' && text[i - 1] !== '-')) depth--;
+    if (ch === separator && depth === 0) {
+      parts.push(current);
+      current = '';
+    } else {
+      current += ch;
+    }
+  }
+  if (current.trim()) parts.push(current);
+  return parts.map(p => p.trim()).filter(Boolean);
+}
+
+/** Parameters of a function signature, excluding `&self` */
+export function parseParams(signature: string): RustParam[] {
+  const open = signature.indexOf('(');
+  let depth = 0;
+  let close = open;
+  for (let i = open; i < signature.length; i++) {
+    if (signature[i] === '(') depth++;
+    else if (signature[i] === ')' && --depth === 0) {
+      close = i;
+      break;
+    }
+  }
+  return splitTopLevel(signature.slice(open + 1, close))
+    .filter(part => !/^&?(mut\s+)?self$/.test(part))
+    .map(part => {
+      const attributes: string[] = [];
+      const rest = part
+        .replace(/#\[(\w+)[^\]]*\]\s*/g, (_, attr: string) => {
+          attributes.push(attr);
+          return '';
+        })
+        .trim();
+      const colon = rest.indexOf(':');
+      return {
+        name: rest.slice(0, colon).replace(/^mut\s+/, '').trim(),
+        type: rest.slice(colon + 1).trim(),
+        attributes,
+      };
+    });
+}
+
+/** Return type of a function signature, or undefined for `()` */
+export function parseReturnType(signature: string): string | undefined {
+  const arrow = /\)\s*->\s*([\s\S]+)$/.exec(signature);
+  return arrow ? arrow[1].trim() : undefined;
+}
+
+export interface StorageMapperDecl {
+  functionName: string;
+  /** Base storage key from #[storage_mapper("...")] */
+  storageKey: string;
+  keyParams: RustParam[];
+  /** Mapper type name, e.g. SingleValueMapper */
+  mapper: string;
+  /** Generic arguments of the mapper, e.g. ['ManagedAddress', 'BigUint'] for MapMapper */
+  typeArgs: string[];
+  /** Full return type as written */
+  returnType: string;
+  /** ABI view name when the mapper is also annotated with #[view(...)] */
+  view?: string;
+}
+
+const STORAGE_MAPPER_ATTR = /^#\[storage_mapper\("([^"]*)"\)\]/;
+
+export function listStorageMappers(source: string): StorageMapperDecl[] {
+  const mappers: StorageMapperDecl[] = [];
+  for (const fn of listTraitFunctions(source)) {
+    const keyAttr = fn.attributes.map(a => STORAGE_MAPPER_ATTR.exec(a)).find(Boolean);
+    if (!keyAttr) continue;
+    const returnType = parseReturnType(fn.signature) || '';
+    const generic = /^(\w+)\s*(?:<([\s\S]*)>)?$/.exec(returnType);
+    const viewAttr = fn.attributes.map(a => /^#\[view\((\w+)\)\]/.exec(a)).find(Boolean);
+    mappers.push({
+      functionName: fn.name,
+      storageKey: keyAttr[1],
+      keyParams: parseParams(fn.signature),
+      mapper: generic ? generic[1] : returnType,
+      typeArgs: generic && generic[2] ? splitTopLevel(generic[2]) : [],
+      returnType,
+      view: viewAttr ? viewAttr[1] : undefined,
+    });
+  }
+  return mappers;
+}
+
+export interface EventDecl {
+  functionName: string;
+  /** Event identifier from #[event("...")] */
+  eventName: string;
+  params: RustParam[];
+}
+
+export function listEvents(source: string): EventDecl[] {
+  const events: EventDecl[] = [];
+  for (const fn of listTraitFunctions(source)) {
+    const attr = fn.attributes.map(a => /^#\[event\("([^"]*)"\)\]/.exec(a)).find(Boolean);
+    if (attr) {
+      events.push({ functionName: fn.name, eventName: attr[1], params: parseParams(fn.signature) });
+    }
+  }
+  return events;
+}
+
+export function snakeToCamel(name: string): string {
+  return name.replace(/_([a-z0-9])/g, (_, ch: string) => ch.toUpperCase());
+}
+
+export function camelToSnake(name: string): string {
+  return name
+    .replace(/([a-z0-9])([A-Z])/g, '$1_$2')
+    .replace(/[-\s]+/g, '_')
+    .toLowerCase();
+}
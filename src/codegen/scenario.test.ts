@@ -0,0 +1,258 @@
+import { parseAbi } from '../abi/index.js';
+import {
+  forkScenario,
+  generateScenario,
+  nestedValue,
+  scenarioArguments,
+  topLevelValue,
+} from './scenario.js';
+
+const ABI = parseAbi({
+  name: 'Token',
+  constructor: { inputs: [{ name: 'initial_supply', type: 'BigUint' }], outputs: [] },
+  endpoints: [
+    {
+      name: 'transfer',
+      mutability: 'mutable',
+      inputs: [
+        { name: 'to', type: 'Address' },
+        { name: 'amount', type: 'BigUint' },
+      ],
+      outputs: [],
+    },
+    {
+      name: 'getBalance',
+      mutability: 'readonly',
+      inputs: [{ name: 'address', type: 'Address' }],
+      outputs: [{ type: 'BigUint' }],
+    },
+    {
+      name: 'register',
+      mutability: 'mutable',
+      inputs: [
+        { name: 'label', type: 'optional<bytes>' },
+        { name: 'names', type: 'variadic<bytes>', multi_arg: true },
+      ],
+      outputs: [],
+    },
+  ],
+  events: [
+    {
+      identifier: 'transfer',
+      inputs: [
+        { name: 'from', type: 'Address', indexed: true },
+        { name: 'to', type: 'Address', indexed: true },
+        { name: 'amount', type: 'BigUint' },
+      ],
+    },
+  ],
+  types: {
+    Config: {
+      type: 'struct',
+      fields: [
+        { name: 'owner', type: 'Address' },
+        { name: 'limit', type: 'u32' },
+      ],
+    },
+    Status: {
+      type: 'enum',
+      variants: [
+        { name: 'Active', discriminant: 0 },
+        { name: 'Paused', discriminant: 1 },
+        { name: 'Limited', discriminant: 2, fields: [{ name: 'max', type: 'BigUint' }] },
+      ],
+    },
+  },
+});
+
+const SOURCE = `#[klever_sc::contract]
+pub trait Token {
+    #[storage_mapper("balance")]
+    fn balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
+`;
+
+describe('scenario value expressions', () => {
+  it('encodes top-level and nested values from the ABI types', () => {
+    expect(topLevelValue('Config', { owner: 'alice', limit: 5 }, ABI)).toBe('address:alice|u32:5');
+    expect(topLevelValue('List<u64>', [1, '2'], ABI)).toBe('u64:1|u64:2');
+    expect(topLevelValue('Option<u8>', null, ABI)).toBe('');
+    expect(nestedValue('Option<bytes>', 'abc', ABI)).toBe('u8:1|nested:str:abc');
+    expect(nestedValue('List<Address>', ['sc:other'], ABI)).toBe('u32:1|sc:other');
+    expect(topLevelValue('Status', 'Paused', ABI)).toBe('1');
+    expect(nestedValue('Status', 'Paused', ABI)).toBe('u8:1');
+    expect(topLevelValue('Status', { Limited: { max: '1_000' } }, ABI)).toBe('u8:2|biguint:1000');
+    expect(topLevelValue('bytes', '0xff00', ABI)).toBe('0xff00');
+    expect(() => topLevelValue('u32', -1, ABI)).toThrow('u32 cannot hold -1');
+  });
+
+  it('expands optional and variadic arguments', () => {
+    const inputs = ABI.endpoints[2].inputs;
+    expect(scenarioArguments(inputs, [undefined, ['a', 'b']], ABI, 'register')).toEqual([
+      'str:a',
+      'str:b',
+    ]);
+    expect(scenarioArguments(inputs, { label: 'x', names: [] }, ABI, 'register')).toEqual([
+      'str:x',
+    ]);
+    expect(() => scenarioArguments(inputs, { names: [], extra: 1 }, ABI, 'register')).toThrow(
+      'register has no argument(s) extra'
+    );
+  });
+});
+
+describe('generateScenario', () => {
+  it('builds deploy, call, query and state checks from high-level steps', () => {
+    const result = generateScenario(ABI, {
+      source: SOURCE,
+      accounts: { owner: { balance: 1000, kda: { 'TKN-1A2B': 50 } } },
+      steps: [
+        { type: 'deploy', from: 'owner', args: [1000] },
+        {
+          type: 'call',
+          from: 'owner',
+          endpoint: 'transfer',
+          args: { to: 'alice', amount: 10 },
+          expect: { events: [{ identifier: 'transfer', args: ['owner', 'alice', 10] }] },
+        },
+        { type: 'query', endpoint: 'getBalance', args: ['alice'], expect: { out: [10] } },
+        {
+          type: 'checkState',
+          storage: [{ mapper: 'balance', args: ['alice'], value: 10 }],
+        },
+      ],
+    });
+
+    expect(result.path).toBe('scenarios/token.scen.json');
+    expect(result.scenario).toEqual({
+      name: 'token',
+      steps: [
+        {
+          step: 'setState',
+          accounts: {
+            'address:owner': { nonce: '0', balance: '1000', kda: { 'str:TKN-1A2B': '50' } },
+          },
+          newAddresses: [
+            { creatorAddress: 'address:owner', creatorNonce: '0', newAddress: 'sc:token' },
+          ],
+        },
+        {
+          step: 'scDeploy',
+          id: '1-deploy',
+          tx: {
+            from: 'address:owner',
+            contractCode: 'file:../output/token.wasm',
+            arguments: ['1000'],
+            gasLimit: '5,000,000',
+            gasPrice: '0',
+          },
+          expect: { status: '0', logs: '*', gas: '*', refund: '*' },
+        },
+        {
+          step: 'scCall',
+          id: '2-transfer',
+          tx: {
+            from: 'address:owner',
+            to: 'sc:token',
+            function: 'transfer',
+            arguments: ['address:alice', '10'],
+            gasLimit: '5,000,000',
+            gasPrice: '0',
+          },
+          expect: {
+            status: '0',
+            logs: [
+              {
+                address: 'sc:token',
+                endpoint: 'str:transfer',
+                topics: ['str:transfer', 'address:owner', 'address:alice'],
+                data: ['10'],
+              },
+            ],
+            gas: '*',
+            refund: '*',
+          },
+        },
+        {
+          step: 'scQuery',
+          id: '3-getBalance',
+          tx: { to: 'sc:token', function: 'getBalance', arguments: ['address:alice'] },
+          expect: { out: ['10'], status: '0' },
+        },
+        {
+          step: 'checkState',
+          id: '4-checkState',
+          accounts: {
+            'sc:token': {
+              nonce: '*',
+              balance: '*',
+              storage: { 'str:balance|address:alice': '10', '+': '' },
+              code: '*',
+            },
+            '+': '',
+          },
+        },
+      ],
+    });
+    expect(JSON.parse(result.json)).toEqual(result.scenario);
+  });
+
+  it('rejects calls before deploy and unknown endpoints', () => {
+    expect(() =>
+      generateScenario(ABI, { steps: [{ type: 'call', from: 'a', endpoint: 'transfer' }] })
+    ).toThrow('Step 1 calls transfer before deploy');
+    expect(() =>
+      generateScenario(ABI, {
+        steps: [
+          { type: 'deploy', from: 'owner', args: [1] },
+          { type: 'query', endpoint: 'nope' },
+        ],
+      })
+    ).toThrow('Endpoint "nope" not found');
+  });
+});
+
+describe('forkScenario', () => {
+  it('recreates fetched accounts in one setState step', () => {
+    const contract = 'klv1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq9q5zs2pg9q5zsat52mv';
+    const owner = 'klv1qyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqsy6zanq';
+    const result = forkScenario({
+      name: 'Token',
+      source: `${contract} on mainnet`,
+      blockNonce: 1234,
+      takenAt: new Date('2026-01-01T00:00:00Z'),
+      accounts: [
+        {
+          address: contract,
+          nonce: 0,
+          balance: 0,
+          storage: { '62616c616e6365': '64', '7a': '' },
+          owner,
+          codePath: '../output/token.wasm',
+        },
+        { address: owner, nonce: 7, balance: '500', kda: { 'TKN-1A2B': 3 } },
+      ],
+    });
+    expect(result.path).toBe('scenarios/token.fork.scen.json');
+    expect(result.scenario).toEqual({
+      name: 'Token',
+      comment: `State of ${contract} on mainnet as of 2026-01-01T00:00:00.000Z`,
+      steps: [
+        {
+          step: 'setState',
+          accounts: {
+            [`0x${'00'.repeat(24)}${'05'.repeat(8)}`]: {
+              nonce: '0',
+              balance: '0',
+              storage: { '0x62616c616e6365': '0x64' },
+              code: 'file:../output/token.wasm',
+              owner: `0x${'01'.repeat(32)}`,
+            },
+            [`0x${'01'.repeat(32)}`]: { nonce: '7', balance: '500', kda: { 'str:TKN-1A2B': '3' } },
+          },
+          currentBlockInfo: { blockNonce: '1234', blockTimestamp: '1767225600' },
+        },
+      ],
+    });
+  });
+});
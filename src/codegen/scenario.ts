@@ -0,0 +1,516 @@
+/**
+ * Scenario (`*.scen.json`) test generation. Steps name an endpoint and give
+ * argument values as plain JSON; the contract ABI decides how each value is
+ * written as a scenario value expression (`address:`, `str:`, `biguint:`,
+ * `u32:` ... joined with `|` for nested encodings).
+ */
+
+import { findAbiEndpoint, type Abi, type AbiEndpoint } from '../abi/index.js';
+import { rustTypeToAbi } from './abi.js';
+import {
+  booleanValue,
+  enumVariant,
+  expandArguments,
+  fieldValues,
+  integerValue,
+  parseAbiType,
+  type AbiArgs,
+} from './abi-values.js';
+import { listStorageMappers } from './rust-source.js';
+import { decodeAddress } from '../chain/bech32.js';
+
+/** Argument values in ABI input order, or keyed by input name */
+export type ScenarioArgs = AbiArgs;
+
+export interface ScenarioAccount {
+  /** KLV balance in the smallest unit */
+  balance?: string | number;
+  /** KDA balances keyed by token identifier */
+  kda?: Record<string, string | number>;
+}
+
+export interface ScenarioPayment {
+  token: string;
+  amount: string | number;
+  nonce?: number;
+}
+
+export interface ScenarioEvent {
+  identifier: string;
+  /** Event arguments in ABI order, or keyed by name */
+  args?: ScenarioArgs;
+}
+
+export interface ScenarioExpectation {
+  /** Return values, encoded with the endpoint's output types */
+  out?: unknown[];
+  /** 0 for success, 4 for a user error (require!/sc_panic!) */
+  status?: number;
+  message?: string;
+  events?: ScenarioEvent[];
+}
+
+export type ScenarioStorageCheck =
+  | { mapper: string; args?: unknown[]; value: unknown }
+  | { key: string; value: string };
+
+export type ScenarioStep =
+  | { type: 'deploy'; from: string; args?: ScenarioArgs; expect?: ScenarioExpectation }
+  | {
+      type: 'call';
+      from: string;
+      endpoint: string;
+      args?: ScenarioArgs;
+      klv?: string | number;
+      kda?: ScenarioPayment[];
+      expect?: ScenarioExpectation;
+    }
+  | { type: 'query'; endpoint: string; args?: ScenarioArgs; expect?: ScenarioExpectation }
+  | {
+      type: 'checkState';
+      /** Account to check (default: the contract) */
+      account?: string;
+      balance?: string | number;
+      storage?: ScenarioStorageCheck[];
+    };
+
+export interface ScenarioSpec {
+  /** Scenario name, also the file name (default: `<contract>`) */
+  name?: string;
+  /** Initial balances of the accounts taking part */
+  accounts?: Record<string, ScenarioAccount>;
+  steps: ScenarioStep[];
+  /** Contract code path as seen from the scenario file (default: `../output/<contract>.wasm`) */
+  codePath?: string;
+  /** Contract source, needed to check storage by mapper name */
+  source?: string;
+  gasLimit?: string;
+}
+
+export interface ScenarioResult {
+  /** Scenario file path relative to the crate root */
+  path: string;
+  scenario: Record<string, unknown>;
+  json: string;
+}
+
+const DEFAULT_GAS_LIMIT = '5,000,000';
+const INTEGER = /^(u8|u16|u32|u64|usize|i8|i16|i32|i64|isize)$/;
+
+function snakeCase(name: string): string {
+  return name
+    .replace(/([a-z0-9])([A-Z])/g, '$1_$2')
+    .replace(/-/g, '_')
+    .toLowerCase();
+}
+
+/** Scenario expression for an account: bech32 and hex stay literal, names become `address:` */
+export function scenarioAddress(value: unknown): string {
+  if (typeof value !== 'string' || value === '') {
+    throw new Error(`Expected an address, got ${JSON.stringify(value)}`);
+  }
+  if (/^(address|sc):/.test(value) || /^0x[0-9a-fA-F]{64}$/.test(value)) return value;
+  if (/^klv1/.test(value)) return `0x${decodeAddress(value).toString('hex')}`;
+  return `address:${value}`;
+}
+
+function bytesExpr(value: unknown, type: string): string {
+  if (typeof value !== 'string') {
+    throw new Error(`Expected a string for ${type}, got ${JSON.stringify(value)}`);
+  }
+  return /^0x([0-9a-fA-F]{2})*$/.test(value) ? value : `str:${value}`;
+}
+
+/** Nested encoding of `value`, as inside a struct, list or option */
+export function nestedValue(type: string, value: unknown, abi: Abi): string {
+  const { name, args } = parseAbiType(type);
+  if (INTEGER.test(name)) {
+    const width = name === 'usize' ? 'u32' : name === 'isize' ? 'i32' : name;
+    return `${width}:${integerValue(value, name)}`;
+  }
+  switch (name) {
+    case 'BigUint':
+      return `biguint:${integerValue(value, name)}`;
+    case 'BigInt':
+      return `nested:${integerValue(value, name)}`;
+    case 'bool':
+      return booleanValue(value) ? 'u8:1' : 'u8:0';
+    case 'Address':
+      return scenarioAddress(value);
+    case 'bytes':
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return `nested:${bytesExpr(value, name)}`;
+    case 'Option':
+      return value === null || value === undefined
+        ? 'u8:0'
+        : `u8:1|${nestedValue(args[0], value, abi)}`;
+    case 'List': {
+      if (!Array.isArray(value)) throw new Error(`Expected an array for ${type}`);
+      return [`u32:${value.length}`, ...value.map(v => nestedValue(args[0], v, abi))].join('|');
+    }
+    case 'tuple':
+      return fieldValues(value, args, type)
+        .map((v, i) => nestedValue(args[i], v, abi))
+        .join('|');
+  }
+  if (/^array\d+$/.test(name)) return arrayValue(type, name, args[0], value, abi);
+  return customValue(type, value, abi, false);
+}
+
+function arrayValue(type: string, name: string, item: string, value: unknown, abi: Abi): string {
+  const length = parseInt(name.slice('array'.length), 10);
+  if (item === 'u8' && typeof value === 'string') return bytesExpr(value, type);
+  if (!Array.isArray(value) || value.length !== length) {
+    throw new Error(`Expected ${length} values for ${type}`);
+  }
+  return value.map(v => nestedValue(item, v, abi)).join('|');
+}
+
+function customValue(type: string, value: unknown, abi: Abi, topLevel: boolean): string {
+  const def = abi.types?.[type];
+  if (!def) throw new Error(`Unknown ABI type ${type}`);
+  if (def.type === 'struct') {
+    const values = fieldValues(value, def.fields.map(f => f.name), type);
+    return values.map((v, i) => nestedValue(def.fields[i].type, v, abi)).join('|');
+  }
+  const { variant, values } = enumVariant(abi, type, value);
+  // Field-less enums encode like a u8 discriminant
+  if (topLevel && values.length === 0) return String(variant.discriminant);
+  return [
+    `u8:${variant.discriminant}`,
+    ...values.map((v, i) => nestedValue(variant.fields![i].type, v, abi)),
+  ].join('|');
+}
+
+/** Top-level encoding of a single (non multi-value) argument or result */
+export function topLevelValue(type: string, value: unknown, abi: Abi): string {
+  const { name, args } = parseAbiType(type);
+  if (INTEGER.test(name) || name === 'BigUint' || name === 'BigInt') {
+    return integerValue(value, name);
+  }
+  switch (name) {
+    case 'bool':
+      return booleanValue(value) ? 'true' : 'false';
+    case 'Address':
+      return scenarioAddress(value);
+    case 'bytes':
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return bytesExpr(value, name);
+    case 'Option':
+      return value === null || value === undefined
+        ? ''
+        : `0x01|${nestedValue(args[0], value, abi)}`;
+    case 'List':
+      if (!Array.isArray(value)) throw new Error(`Expected an array for ${type}`);
+      return value.map(v => nestedValue(args[0], v, abi)).join('|');
+    case 'tuple':
+      return nestedValue(type, value, abi);
+  }
+  if (/^array\d+$/.test(name)) return arrayValue(type, name, args[0], value, abi);
+  return customValue(type, value, abi, true);
+}
+
+/**
+ * Top-level encodings for a list of typed values; `optional<T>` may be
+ * omitted at the end, `variadic<T>` takes an array and `multi<...>` a tuple.
+ */
+export function scenarioArguments(
+  inputs: { name: string; type: string }[],
+  values: ScenarioArgs | undefined,
+  abi: Abi,
+  what: string
+): string[] {
+  return expandArguments(inputs, values, what, (type, value) => topLevelValue(type, value, abi));
+}
+
+function outputValues(endpoint: AbiEndpoint, out: unknown[], abi: Abi): string[] {
+  const outputs = endpoint.outputs.map((o, i) => ({ name: `output ${i}`, type: o.type }));
+  return scenarioArguments(outputs, out, abi, `${endpoint.name} output`);
+}
+
+function eventLog(event: ScenarioEvent, contract: string, fn: string, abi: Abi) {
+  const def = (abi.events || []).find(e => e.identifier === event.identifier);
+  if (!def) {
+    const available = (abi.events || []).map(e => e.identifier).join(', ') || 'none';
+    throw new Error(`Event "${event.identifier}" not found in the ABI. Available: ${available}`);
+  }
+  const values = fieldValues(
+    event.args ?? [],
+    def.inputs.map(i => i.name),
+    `event ${def.identifier}`
+  );
+  const encoded = def.inputs.map((input, i) => topLevelValue(input.type, values[i], abi));
+  return {
+    address: contract,
+    endpoint: `str:${fn}`,
+    topics: [`str:${def.identifier}`, ...encoded.filter((_, i) => def.inputs[i].indexed)],
+    data: encoded.filter((_, i) => !def.inputs[i].indexed),
+  };
+}
+
+function expectation(
+  expect: ScenarioExpectation | undefined,
+  endpoint: AbiEndpoint | undefined,
+  contract: string,
+  fn: string,
+  abi: Abi
+): Record<string, unknown> {
+  const result: Record<string, unknown> = {};
+  if (expect?.out && endpoint) result.out = outputValues(endpoint, expect.out, abi);
+  result.status = String(expect?.status ?? 0);
+  if (expect?.message !== undefined) result.message = `str:${expect.message}`;
+  result.logs = expect?.events ? expect.events.map(e => eventLog(e, contract, fn, abi)) : '*';
+  result.gas = '*';
+  result.refund = '*';
+  return result;
+}
+
+function storageEntries(
+  checks: ScenarioStorageCheck[],
+  source: string | undefined,
+  abi: Abi
+): Record<string, string> {
+  const mappers = source ? listStorageMappers(source) : [];
+  const storage: Record<string, string> = {};
+  for (const check of checks) {
+    if ('key' in check) {
+      storage[check.key] = check.value;
+      continue;
+    }
+    if (!source) throw new Error(`Pass the contract source to check storage of ${check.mapper}`);
+    const mapper = mappers.find(m => m.functionName === check.mapper);
+    if (!mapper) {
+      const available = mappers.map(m => m.functionName).join(', ');
+      throw new Error(`Storage mapper "${check.mapper}" not found. Available: ${available}`);
+    }
+    if (mapper.mapper !== 'SingleValueMapper') {
+      throw new Error(
+        `${check.mapper} is a ${mapper.mapper}; only SingleValueMapper can be checked by name. Use { key, value } with raw expressions instead.`
+      );
+    }
+    const keyArgs = check.args || [];
+    if (keyArgs.length !== mapper.keyParams.length) {
+      throw new Error(`${check.mapper} takes ${mapper.keyParams.length} key argument(s)`);
+    }
+    const key = [
+      `str:${mapper.storageKey}`,
+      ...mapper.keyParams.map((p, i) => nestedValue(rustTypeToAbi(p.type), keyArgs[i], abi)),
+    ].join('|');
+    storage[key] = topLevelValue(rustTypeToAbi(mapper.typeArgs[0]), check.value, abi);
+  }
+  return { ...storage, '+': '' };
+}
+
+/** Build a scenario for the contract described by `abi` from high-level steps */
+export function generateScenario(abi: Abi, spec: ScenarioSpec): ScenarioResult {
+  const contractName = snakeCase(abi.name);
+  const contract = `sc:${contractName}`;
+  const gasLimit = spec.gasLimit || DEFAULT_GAS_LIMIT;
+  const name = spec.name || contractName;
+  const accounts = spec.accounts || {};
+  const nonces: Record<string, number> = {};
+  const newAddresses: Record<string, string>[] = [];
+  const steps: Record<string, unknown>[] = [];
+  let deployed = false;
+
+  const nextNonce = (from: string) => {
+    const nonce = nonces[from] ?? 0;
+    nonces[from] = nonce + 1;
+    return nonce;
+  };
+
+  spec.steps.forEach((step, index) => {
+    const action = step.type === 'call' || step.type === 'query' ? step.endpoint : step.type;
+    const id = `${index + 1}-${action}`;
+    switch (step.type) {
+      case 'deploy': {
+        if (deployed) throw new Error('The scenario deploys the contract more than once');
+        deployed = true;
+        const constructor = abi.constructor || { inputs: [], outputs: [] };
+        newAddresses.push({
+          creatorAddress: scenarioAddress(step.from),
+          creatorNonce: String(nextNonce(step.from)),
+          newAddress: contract,
+        });
+        steps.push({
+          step: 'scDeploy',
+          id,
+          tx: {
+            from: scenarioAddress(step.from),
+            contractCode: `file:${spec.codePath || `../output/${contractName}.wasm`}`,
+            arguments: scenarioArguments(constructor.inputs, step.args, abi, 'constructor'),
+            gasLimit,
+            gasPrice: '0',
+          },
+          expect: expectation(step.expect, { name: 'init', ...constructor }, contract, 'init', abi),
+        });
+        break;
+      }
+      case 'call': {
+        if (!deployed) throw new Error(`Step ${index + 1} calls ${step.endpoint} before deploy`);
+        const endpoint = findAbiEndpoint(abi, step.endpoint);
+        nextNonce(step.from);
+        const tx: Record<string, unknown> = {
+          from: scenarioAddress(step.from),
+          to: contract,
+          function: endpoint.name,
+          arguments: scenarioArguments(endpoint.inputs, step.args, abi, endpoint.name),
+          gasLimit,
+          gasPrice: '0',
+        };
+        if (step.klv !== undefined) tx.klvValue = integerValue(step.klv, 'KLV amount');
+        if (step.kda && step.kda.length > 0) {
+          tx.kdaValue = step.kda.map(payment => ({
+            tokenIdentifier: `str:${payment.token}`,
+            ...(payment.nonce ? { tokenNonce: String(payment.nonce) } : {}),
+            value: integerValue(payment.amount, `${payment.token} amount`),
+          }));
+        }
+        steps.push({
+          step: 'scCall',
+          id,
+          tx,
+          expect: expectation(step.expect, endpoint, contract, endpoint.name, abi),
+        });
+        break;
+      }
+      case 'query': {
+        if (!deployed) throw new Error(`Step ${index + 1} queries ${step.endpoint} before deploy`);
+        const endpoint = findAbiEndpoint(abi, step.endpoint);
+        const expect = expectation(step.expect, endpoint, contract, endpoint.name, abi);
+        steps.push({
+          step: 'scQuery',
+          id,
+          tx: {
+            to: contract,
+            function: endpoint.name,
+            arguments: scenarioArguments(endpoint.inputs, step.args, abi, endpoint.name),
+          },
+          expect: { out: expect.out ?? '*', status: expect.status },
+        });
+        break;
+      }
+      case 'checkState': {
+        const account = step.account ? scenarioAddress(step.account) : contract;
+        const expected: Record<string, unknown> = {
+          nonce: '*',
+          balance: step.balance !== undefined ? integerValue(step.balance, 'balance') : '*',
+        };
+        if (step.storage) expected.storage = storageEntries(step.storage, spec.source, abi);
+        if (account === contract) expected.code = '*';
+        steps.push({ step: 'checkState', id, accounts: { [account]: expected, '+': '' } });
+        break;
+      }
+    }
+  });
+
+  const actors = new Set([
+    ...Object.keys(accounts),
+    ...spec.steps.flatMap(s => (s.type === 'deploy' || s.type === 'call' ? [s.from] : [])),
+  ]);
+  const initial: Record<string, unknown> = {};
+  for (const actor of actors) {
+    const account = accounts[actor] || {};
+    initial[scenarioAddress(actor)] = {
+      nonce: '0',
+      balance: integerValue(account.balance ?? 0, 'balance'),
+      ...(account.kda
+        ? {
+            kda: Object.fromEntries(
+              Object.entries(account.kda).map(([token, amount]) => [
+                `str:${token}`,
+                integerValue(amount, `${token} balance`),
+              ])
+            ),
+          }
+        : {}),
+    };
+  }
+  const setState: Record<string, unknown> = { step: 'setState', accounts: initial };
+  if (newAddresses.length > 0) setState.newAddresses = newAddresses;
+
+  const scenario = { name, steps: [setState, ...steps] };
+  return {
+    path: `scenarios/${snakeCase(name)}.scen.json`,
+    scenario,
+    json: JSON.stringify(scenario, null, 4) + '\n',
+  };
+}
+
+export interface ForkedAccount {
+  /** Account address (klv1...) */
+  address: string;
+  nonce: number;
+  /** KLV balance in the smallest unit */
+  balance: string | number;
+  /** KDA balances keyed by token identifier */
+  kda?: Record<string, string | number>;
+  /** Storage as hex key -> hex value */
+  storage?: Record<string, string>;
+  owner?: string;
+  /** Contract code path as seen from the scenario file */
+  codePath?: string;
+}
+
+export interface ForkSpec {
+  /** Scenario name, also the file name: `scenarios/<name>.fork.scen.json` */
+  name: string;
+  accounts: ForkedAccount[];
+  /** Where the state comes from, e.g. the contract address and network */
+  source: string;
+  /** Block the state was read at */
+  blockNonce?: number;
+  takenAt?: Date;
+}
+
+/** A scenario whose single `setState` step recreates accounts read from a network */
+export function forkScenario(spec: ForkSpec): ScenarioResult {
+  const takenAt = spec.takenAt || new Date();
+  const accounts: Record<string, unknown> = {};
+  for (const account of spec.accounts) {
+    const storage = Object.entries(account.storage || {}).filter(([, value]) => value !== '');
+    accounts[scenarioAddress(account.address)] = {
+      nonce: String(account.nonce),
+      balance: integerValue(account.balance, 'balance'),
+      ...(account.kda && Object.keys(account.kda).length > 0
+        ? {
+            kda: Object.fromEntries(
+              Object.entries(account.kda).map(([token, amount]) => [
+                `str:${token}`,
+                integerValue(amount, `${token} balance`),
+              ])
+            ),
+          }
+        : {}),
+      ...(storage.length > 0
+        ? {
+            storage: Object.fromEntries(storage.map(([key, value]) => [`0x${key}`, `0x${value}`])),
+          }
+        : {}),
+      ...(account.codePath ? { code: `file:${account.codePath}` } : {}),
+      ...(account.owner ? { owner: scenarioAddress(account.owner) } : {}),
+    };
+  }
+  const setState = {
+    step: 'setState',
+    accounts,
+    // Time-dependent logic sees the time of the snapshot
+    currentBlockInfo: {
+      ...(spec.blockNonce !== undefined ? { blockNonce: String(spec.blockNonce) } : {}),
+      blockTimestamp: String(Math.floor(takenAt.getTime() / 1000)),
+    },
+  };
+
+  const scenario = {
+    name: spec.name,
+    comment: `State of ${spec.source} as of ${takenAt.toISOString()}`,
+    steps: [setState],
+  };
+  return {
+    path: `scenarios/${snakeCase(spec.name)}.fork.scen.json`,
+    scenario,
+    json: JSON.stringify(scenario, null, 4) + '\n',
+  };
+}
@@ -0,0 +1,98 @@
+import { storageBenchmark, storageBenchmarkResult } from './storage-benchmark.js';
+import { adviseStorageMapper } from './storage-mappers.js';
+
+describe('storageBenchmark', () => {
+  const plan = storageBenchmark(['SingleValueMapper', 'MapMapper'], {
+    entries: 50,
+    operations: { read: 2, iterate: 1 },
+  });
+
+  it('generates one endpoint per mapper and workload operation', () => {
+    expect(plan.calls).toEqual([
+      { endpoint: 'single_value_read', mapper: 'SingleValueMapper', operation: 'read' },
+      { endpoint: 'map_read', mapper: 'MapMapper', operation: 'read' },
+      { endpoint: 'map_iterate', mapper: 'MapMapper', operation: 'iterate' },
+    ]);
+    const source = plan.files['src/lib.rs'];
+    expect(source).toContain(
+      '    #[storage_mapper("single_value")]\n    fn single_value(&self, key: u64) -> SingleValueMapper<BigUint>;'
+    );
+    expect(source).toContain(
+      '    fn map_fill(&self, count: u64) {\n        for key in 1..=count {\n            self.map().insert(key, BigUint::from(key));\n        }\n    }'
+    );
+    expect(source).toContain('    fn map_iterate(&self, _key: u64) {');
+    expect(source).not.toContain('single_value_iterate');
+    expect(plan.files['wasm/src/lib.rs']).toContain('        map_iterate => map_iterate');
+  });
+
+  it('fills each mapper before measuring its operations', () => {
+    const steps = plan.scenario.scenario.steps as { step: string; tx?: Record<string, unknown> }[];
+    expect(steps[1].tx?.contractCode).toBe('file:../output/storage-benchmark.wasm');
+    expect(steps.slice(2).map(s => s.tx?.function)).toEqual([
+      'noop',
+      'single_value_fill',
+      'single_value_read',
+      'map_fill',
+      'map_read',
+      'map_iterate',
+    ]);
+    expect(plan.files['scenarios/storage_benchmark.scen.json']).toBe(plan.scenario.json);
+    expect(() => storageBenchmark(['MapMapper'])).toThrow('Compare two or more');
+    expect(() => storageBenchmark(['MapMapper', 'VecMapper'], { entries: 0 })).toThrow(
+      'entries must be a whole number'
+    );
+  });
+
+  it('reports gas per operation and ranks mappers by the workload', () => {
+    const result = storageBenchmarkResult(plan, [
+      { endpoint: 'noop', gasUsed: 1000 },
+      { endpoint: 'single_value_fill', gasUsed: 90000 },
+      { endpoint: 'single_value_read', gasUsed: 1500 },
+      { endpoint: 'map_read', gasUsed: 1600 },
+      { endpoint: 'map_iterate', gasUsed: 9000 },
+    ]);
+    expect(result.callOverhead).toBe(1000);
+    expect(result.mappers).toEqual([
+      {
+        mapper: 'MapMapper',
+        operations: { read: 600, iterate: 8000 },
+        unsupported: [],
+        workloadGas: 9200,
+      },
+      { mapper: 'SingleValueMapper', operations: { read: 500 }, unsupported: ['iterate'] },
+    ]);
+  });
+});
+
+describe('adviseStorageMapper with a benchmark', () => {
+  it('weighs in the measured workload gas', () => {
+    const benchmark = {
+      entries: 100,
+      workload: { insert: 1, read: 1 },
+      callOverhead: 1000,
+      mappers: [
+        {
+          mapper: 'SingleValueMapper' as const,
+          operations: { insert: 3000, read: 500 },
+          unsupported: [],
+          workloadGas: 3500,
+        },
+        {
+          mapper: 'MapMapper' as const,
+          operations: { insert: 12000, read: 600 },
+          unsupported: [],
+          workloadGas: 12600,
+        },
+      ],
+    };
+    const advice = adviseStorageMapper('balance per address', { benchmark });
+    expect(advice.recommendation.mapper).toBe('SingleValueMapper');
+    expect(advice.recommendation.reasons).toContain(
+      'Cheapest measured for the workload: 3500 gas against 12600 for MapMapper (100 entries)'
+    );
+    expect(advice.recommendation.measured?.operations).toEqual({ insert: 3000, read: 500 });
+    expect(advice.alternatives.find(a => a.mapper === 'MapMapper')?.measured?.workloadGas).toBe(
+      12600
+    );
+  });
+});
@@ -0,0 +1,335 @@
+/**
+ * Storage mapper benchmarks: a generated contract with one endpoint per
+ * mapper and operation, and a scenario that fills each mapper with the
+ * entries of a workload and then makes each operation once, so the Go VM can
+ * measure what every operation costs on storage of that size (`profileGas`).
+ * Keys are `u64` and values `BigUint` for every mapper, so the numbers
+ * compare layouts rather than encodings.
+ */
+
+import { parseAbi, type Abi } from '../abi/index.js';
+import { KLEVER_SC_VERSION } from '../scaffold/framework-version.js';
+import { projectFiles } from '../scaffold/project.js';
+import type { FileMap } from '../scaffold/types.js';
+import { generateScenario, type ScenarioResult, type ScenarioStep } from './scenario.js';
+import type { MapperName } from './storage-mappers.js';
+
+/**
+ * `read` gets the value of an entry (`contains` for sets, the front node of a
+ * LinkedListMapper); `insert` adds an entry (`push` for lists)
+ */
+export type StorageOperation = 'read' | 'update' | 'iterate' | 'insert' | 'remove';
+
+/** Operations in the order the scenario makes them, on entry 1 and then a new entry */
+export const STORAGE_OPERATIONS: StorageOperation[] = [
+  'read',
+  'update',
+  'iterate',
+  'insert',
+  'remove',
+];
+
+export interface StorageWorkload {
+  /** Entries stored before the operations are measured (default: 100) */
+  entries?: number;
+  /** How often the workload makes each operation (default: insert, read, update, remove once) */
+  operations?: Partial<Record<StorageOperation, number>>;
+}
+
+interface MapperBenchmark {
+  /** Storage function, also the prefix of the mapper's endpoints */
+  storage: string;
+  declaration: string;
+  /** Rust statements making each operation on entry `key`; missing when the mapper lacks it */
+  operations: Partial<Record<StorageOperation, string>>;
+}
+
+const BENCHMARKS: Record<MapperName, MapperBenchmark> = {
+  SingleValueMapper: {
+    storage: 'single_value',
+    declaration: 'fn single_value(&self, key: u64) -> SingleValueMapper<BigUint>;',
+    operations: {
+      read: 'let _ = self.single_value(key).get();',
+      update: 'self.single_value(key).update(|value| *value += 1u32);',
+      insert: 'self.single_value(key).set(BigUint::from(key));',
+      remove: 'self.single_value(key).clear();',
+    },
+  },
+  MapMapper: {
+    storage: 'map',
+    declaration: 'fn map(&self) -> MapMapper<u64, BigUint>;',
+    operations: {
+      read: 'let _ = self.map().get(&key);',
+      update:
+        'let value = self.map().get(&key).unwrap_or_default() + 1u32;\nself.map().insert(key, value);',
+      iterate: 'for entry in self.map().iter() {\n    let _ = entry;\n}',
+      insert: 'self.map().insert(key, BigUint::from(key));',
+      remove: 'self.map().remove(&key);',
+    },
+  },
+  SetMapper: {
+    storage: 'set',
+    declaration: 'fn set(&self) -> SetMapper<u64>;',
+    operations: {
+      read: 'let _ = self.set().contains(&key);',
+      iterate: 'for member in self.set().iter() {\n    let _ = member;\n}',
+      insert: 'self.set().insert(key);',
+      remove: 'self.set().remove(&key);',
+    },
+  },
+  UnorderedSetMapper: {
+    storage: 'unordered_set',
+    declaration: 'fn unordered_set(&self) -> UnorderedSetMapper<u64>;',
+    operations: {
+      read: 'let _ = self.unordered_set().contains(&key);',
+      iterate: 'for member in self.unordered_set().iter() {\n    let _ = member;\n}',
+      insert: 'self.unordered_set().insert(key);',
+      remove: 'self.unordered_set().swap_remove(&key);',
+    },
+  },
+  VecMapper: {
+    storage: 'vec',
+    declaration: 'fn vec(&self) -> VecMapper<BigUint>;',
+    operations: {
+      read: 'let _ = self.vec().get(key as usize);',
+      update:
+        'let value = self.vec().get(key as usize) + 1u32;\nself.vec().set(key as usize, &value);',
+      iterate: 'for value in self.vec().iter() {\n    let _ = value;\n}',
+      insert: 'self.vec().push(&BigUint::from(key));',
+      remove: 'self.vec().swap_remove(key as usize);',
+    },
+  },
+  LinkedListMapper: {
+    storage: 'linked_list',
+    declaration: 'fn linked_list(&self) -> LinkedListMapper<BigUint>;',
+    operations: {
+      read: 'let _ = self.linked_list().front();',
+      iterate: 'for node in self.linked_list().iter() {\n    let _ = node.into_value();\n}',
+      insert: 'self.linked_list().push_back(BigUint::from(key));',
+      remove: 'let _ = self.linked_list().pop_front();',
+    },
+  },
+  WhitelistMapper: {
+    storage: 'whitelist',
+    declaration: 'fn whitelist(&self) -> WhitelistMapper<u64>;',
+    operations: {
+      read: 'let _ = self.whitelist().contains(&key);',
+      insert: 'self.whitelist().add(&key);',
+      remove: 'self.whitelist().remove(&key);',
+    },
+  },
+};
+
+export const BENCHMARK_PROJECT = 'storage-benchmark';
+const DEFAULT_ENTRIES = 100;
+const MAX_ENTRIES = 1000;
+const DEFAULT_OPERATIONS: Partial<Record<StorageOperation, number>> = {
+  insert: 1,
+  read: 1,
+  update: 1,
+  remove: 1,
+};
+/** Enough for filling a mapper with `MAX_ENTRIES` entries in one call */
+const GAS_LIMIT = '2,000,000,000';
+
+export interface BenchmarkCall {
+  endpoint: string;
+  mapper: MapperName;
+  operation: StorageOperation;
+}
+
+export interface StorageBenchmarkPlan {
+  mappers: MapperName[];
+  entries: number;
+  operations: Partial<Record<StorageOperation, number>>;
+  frameworkVersion: string;
+  /** Contract project, with the benchmark scenario */
+  files: FileMap;
+  abi: Abi;
+  scenario: ScenarioResult;
+  /** Measured endpoints and what they measure */
+  calls: BenchmarkCall[];
+}
+
+const indent = (code: string, prefix: string) =>
+  code
+    .split('\n')
+    .map(line => prefix + line)
+    .join('\n');
+
+/** Endpoint running `body`, taking `key` when the body uses it */
+function endpoint(name: string, body: string, param = 'key'): string {
+  const arg = new RegExp(`\\b${param}\\b`).test(body) ? param : `_${param}`;
+  return `    #[endpoint]
+    fn ${name}(&self, ${arg}: u64) {
+${indent(body, '        ')}
+    }`;
+}
+
+function benchmarkSource(
+  mappers: MapperName[],
+  operations: StorageOperation[]
+): { source: string; calls: BenchmarkCall[] } {
+  const calls: BenchmarkCall[] = [];
+  const sections = mappers.map(mapper => {
+    const { storage, declaration, operations: code } = BENCHMARKS[mapper];
+    const fill = `for key in 1..=count {\n${indent(code.insert!, '    ')}\n}`;
+    const endpoints = [endpoint(`${storage}_fill`, fill, 'count')];
+    for (const operation of operations) {
+      if (!code[operation]) continue;
+      const name = `${storage}_${operation}`;
+      endpoints.push(endpoint(name, code[operation]!));
+      calls.push({ endpoint: name, mapper, operation });
+    }
+    return `    #[storage_mapper("${storage}")]
+    ${declaration}
+
+${endpoints.join('\n\n')}`;
+  });
+
+  const source = `#![no_std]
+
+use klever_sc::imports::*;
+
+/// One endpoint per storage mapper and operation, measured by benchmark_storage
+#[klever_sc::contract]
+pub trait StorageBenchmark {
+    #[init]
+    fn init(&self) {}
+
+${endpoint('noop', 'let _ = key;')}
+
+${sections.join('\n\n')}
+}
+`;
+  return { source, calls };
+}
+
+/** Contract and scenario measuring `mappers` under `workload` */
+export function storageBenchmark(
+  mappers: MapperName[],
+  workload: StorageWorkload = {},
+  frameworkVersion = KLEVER_SC_VERSION
+): StorageBenchmarkPlan {
+  const unknown = mappers.filter(m => !BENCHMARKS[m]);
+  if (mappers.length < 2 || unknown.length > 0) {
+    throw new Error(
+      `Compare two or more of ${Object.keys(BENCHMARKS).join(', ')}` +
+        (unknown.length > 0 ? ` (unknown: ${unknown.join(', ')})` : '')
+    );
+  }
+  const entries = workload.entries ?? DEFAULT_ENTRIES;
+  if (!Number.isInteger(entries) || entries < 1 || entries > MAX_ENTRIES) {
+    throw new Error(`entries must be a whole number from 1 to ${MAX_ENTRIES}`);
+  }
+  const operations = workload.operations || DEFAULT_OPERATIONS;
+  const measured = STORAGE_OPERATIONS.filter(op => (operations[op] ?? 0) > 0);
+  if (measured.length === 0) {
+    throw new Error(`The workload makes no operations (${STORAGE_OPERATIONS.join(', ')})`);
+  }
+
+  const { source, calls } = benchmarkSource(mappers, measured);
+  const abi = parseAbi({
+    name: 'StorageBenchmark',
+    constructor: { inputs: [], outputs: [] },
+    endpoints: ['noop', ...mappers.map(m => `${BENCHMARKS[m].storage}_fill`)]
+      .concat(calls.map(c => c.endpoint))
+      .map(name => ({
+        name,
+        mutability: 'mutable',
+        inputs: [{ name: name.endsWith('_fill') ? 'count' : 'key', type: 'u64' }],
+        outputs: [],
+      })),
+  });
+
+  // Entry 1 is read, updated and removed; the inserted entry is a new one
+  const key = (operation: StorageOperation) => (operation === 'insert' ? entries + 1 : 1);
+  const call = (name: string, value: number): ScenarioStep => ({
+    type: 'call',
+    from: 'owner',
+    endpoint: name,
+    args: [value],
+  });
+  const steps: ScenarioStep[] = [{ type: 'deploy', from: 'owner' }, call('noop', 1)];
+  for (const mapper of mappers) {
+    steps.push(call(`${BENCHMARKS[mapper].storage}_fill`, entries));
+    for (const c of calls.filter(c => c.mapper === mapper)) {
+      steps.push(call(c.endpoint, key(c.operation)));
+    }
+  }
+  const scenario = generateScenario(abi, {
+    name: 'storage_benchmark',
+    steps,
+    codePath: `../output/${BENCHMARK_PROJECT}.wasm`,
+    gasLimit: GAS_LIMIT,
+  });
+
+  return {
+    mappers,
+    entries,
+    operations,
+    frameworkVersion,
+    files: {
+      ...projectFiles(BENCHMARK_PROJECT, source, frameworkVersion),
+      [scenario.path]: scenario.json,
+    },
+    abi,
+    scenario,
+    calls,
+  };
+}
+
+export interface MapperGas {
+  mapper: MapperName;
+  /** Gas of each operation, less the gas of a call that does nothing */
+  operations: Partial<Record<StorageOperation, number>>;
+  /** Workload operations the mapper does not have */
+  unsupported: StorageOperation[];
+  /** Gas of the whole workload, when the mapper has all its operations */
+  workloadGas?: number;
+}
+
+export interface StorageBenchmarkResult {
+  entries: number;
+  workload: Partial<Record<StorageOperation, number>>;
+  /** Gas of a call that does nothing, left out of the operation costs */
+  callOverhead: number;
+  /** Mappers from the cheapest workload; mappers lacking an operation last */
+  mappers: MapperGas[];
+}
+
+/** Gas per mapper and operation from the measured calls of the benchmark scenario */
+export function storageBenchmarkResult(
+  plan: StorageBenchmarkPlan,
+  measured: { endpoint: string; gasUsed: number }[]
+): StorageBenchmarkResult {
+  const gas = new Map(measured.map(call => [call.endpoint, call.gasUsed]));
+  const callOverhead = gas.get('noop') ?? 0;
+  const workload = Object.entries(plan.operations) as [StorageOperation, number][];
+  const mappers = plan.mappers.map((mapper): MapperGas => {
+    const operations: Partial<Record<StorageOperation, number>> = {};
+    for (const call of plan.calls.filter(c => c.mapper === mapper)) {
+      const used = gas.get(call.endpoint);
+      if (used !== undefined) operations[call.operation] = Math.max(0, used - callOverhead);
+    }
+    const unsupported = workload
+      .filter(([operation, count]) => count > 0 && !BENCHMARKS[mapper].operations[operation])
+      .map(([operation]) => operation);
+    let workloadGas: number | undefined = 0;
+    for (const [operation, count] of workload) {
+      if (count <= 0) continue;
+      const used = operations[operation];
+      workloadGas =
+        used === undefined || workloadGas === undefined ? undefined : workloadGas + count * used;
+    }
+    return {
+      mapper,
+      operations,
+      unsupported,
+      ...(workloadGas !== undefined ? { workloadGas } : {}),
+    };
+  });
+  const total = (m: MapperGas) => m.workloadGas ?? Number.MAX_SAFE_INTEGER;
+  mappers.sort((a, b) => total(a) - total(b));
+  return { entries: plan.entries, workload: plan.operations, callOverhead, mappers };
+}
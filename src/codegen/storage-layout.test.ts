@@ -0,0 +1,98 @@
+import { parseAbi } from '../abi/index.js';
+import { encodeAddress } from '../chain/bech32.js';
+import { decodeStorageEntry, storageKeyFor, storageLayout } from './storage-layout.js';
+
+const SOURCE = `#![no_std]
+klever_sc::imports!();
+
+#[klever_sc::contract]
+pub trait Vault {
+    #[storage_mapper("balance")]
+    fn balance(&self, owner: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("balanceLimit")]
+    fn balance_limit(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("depositors")]
+    fn depositors(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("config")]
+    fn config(&self) -> SingleValueMapper<Config<Self::Api>>;
+}
+`;
+
+const ABI = parseAbi({
+  name: 'Vault',
+  endpoints: [],
+  types: {
+    Config: {
+      type: 'struct',
+      fields: [
+        { name: 'enabled', type: 'bool' },
+        { name: 'limit', type: 'u32' },
+      ],
+    },
+  },
+});
+
+const ALICE_BYTES = Buffer.alloc(32, 1);
+const ALICE = encodeAddress(ALICE_BYTES);
+const layout = storageLayout([SOURCE]);
+const key = (...parts: (string | Buffer)[]) =>
+  Buffer.concat(parts.map(part => (typeof part === 'string' ? Buffer.from(part) : part)));
+
+describe('storageLayout', () => {
+  it('lists mappers with ABI key and value types', () => {
+    expect(layout[0]).toEqual({
+      functionName: 'balance',
+      storageKey: 'balance',
+      mapper: 'SingleValueMapper',
+      keyParams: [{ name: 'owner', type: 'Address' }],
+      typeArgs: ['BigUint'],
+    });
+    expect(layout[3].typeArgs).toEqual(['Config']);
+  });
+
+  it('builds the storage key of a mapper from its key arguments', () => {
+    expect(storageKeyFor(layout[0], [ALICE])).toEqual(key('balance', ALICE_BYTES));
+    expect(storageKeyFor(layout[1], undefined)).toEqual(key('balanceLimit'));
+  });
+});
+
+describe('decodeStorageEntry', () => {
+  it('decodes per-key values, preferring the longest matching key', () => {
+    const balance = decodeStorageEntry(
+      key('balance', ALICE_BYTES),
+      Buffer.from('0f4240', 'hex'),
+      layout
+    );
+    expect(balance).toMatchObject({
+      functionName: 'balance',
+      keyArgs: { owner: ALICE },
+      value: 1000000,
+    });
+    const limit = decodeStorageEntry(key('balanceLimit'), Buffer.from('64', 'hex'), layout);
+    expect(limit).toMatchObject({ functionName: 'balance_limit', value: 100 });
+  });
+
+  it('decodes internal entries of collection mappers', () => {
+    const len = decodeStorageEntry(key('depositors.len'), Buffer.from('02', 'hex'), layout);
+    expect(len).toMatchObject({ entry: 'len', value: 2 });
+    const item = decodeStorageEntry(
+      key('depositors.item', Buffer.from('00000001', 'hex')),
+      ALICE_BYTES,
+      layout
+    );
+    expect(item).toMatchObject({ entry: 'item', entryKey: 1, value: ALICE });
+  });
+
+  it('decodes custom types with the ABI', () => {
+    const entry = decodeStorageEntry(key('config'), Buffer.from('0100000005', 'hex'), layout, ABI);
+    expect(entry?.value).toEqual({ enabled: true, limit: 5 });
+    expect(decodeStorageEntry(key('config'), Buffer.from('01', 'hex'), layout)).toBeUndefined();
+  });
+
+  it('returns undefined for keys no mapper declares', () => {
+    expect(decodeStorageEntry(key('unknown'), Buffer.alloc(0), layout)).toBeUndefined();
+  });
+});
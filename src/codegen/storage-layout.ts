@@ -0,0 +1,173 @@
+/**
+ * Decoding of raw contract storage with the storage mappers declared in the
+ * contract source. A mapper stores its data under its `#[storage_mapper]` key
+ * followed by its nested-encoded key arguments; collection mappers append an
+ * internal suffix (`.len`, `.item<index>`, `.mapped<key>`, ...) to that.
+ */
+
+import type { Abi } from '../abi/index.js';
+import { rustTypeToAbi } from './abi.js';
+import { decodeNestedPrefix, decodeTopLevel, encodeNested } from './abi-codec.js';
+import { fieldValues } from './abi-values.js';
+import { listStorageMappers } from './rust-source.js';
+
+export interface StorageSlot {
+  functionName: string;
+  storageKey: string;
+  mapper: string;
+  /** Key arguments with ABI types */
+  keyParams: { name: string; type: string }[];
+  /** ABI types of the mapper's generic arguments, e.g. ['Address', 'BigUint'] for a MapMapper */
+  typeArgs: string[];
+}
+
+export interface DecodedStorageEntry {
+  /** Storage key, hex */
+  key: string;
+  functionName: string;
+  storageKey: string;
+  mapper: string;
+  /** Key arguments by name */
+  keyArgs: Record<string, unknown>;
+  /** Internal entry of a collection mapper, e.g. `len` or `item` */
+  entry?: string;
+  /** Index or key the internal entry belongs to */
+  entryKey?: unknown;
+  value: unknown;
+  /** Stored value, hex */
+  rawValue: string;
+}
+
+/** Empty ABI for decoding built-in types when no ABI is available */
+const NO_ABI: Abi = { name: '', endpoints: [] };
+
+/** Internal entries of a collection mapper: suffix, type of the bytes after it, value type */
+interface MapperEntry {
+  suffix: string;
+  keyType?: (slot: StorageSlot) => string;
+  /** Undefined for internal bookkeeping values, reported as hex */
+  valueType?: (slot: StorageSlot) => string;
+}
+
+const item = (slot: StorageSlot) => slot.typeArgs[0];
+const linkedSetEntries: MapperEntry[] = [
+  { suffix: '.info' },
+  { suffix: '.node_links', keyType: () => 'u32' },
+  { suffix: '.value', keyType: () => 'u32', valueType: item },
+  { suffix: '.node_id', keyType: item, valueType: () => 'u32' },
+];
+
+const MAPPER_ENTRIES: Record<string, MapperEntry[]> = {
+  SingleValueMapper: [{ suffix: '', valueType: item }],
+  VecMapper: [
+    { suffix: '.len', valueType: () => 'u32' },
+    { suffix: '.item', keyType: () => 'u32', valueType: item },
+  ],
+  UnorderedSetMapper: [
+    { suffix: '.len', valueType: () => 'u32' },
+    { suffix: '.item', keyType: () => 'u32', valueType: item },
+    { suffix: '.index', keyType: item, valueType: () => 'u32' },
+  ],
+  SetMapper: linkedSetEntries,
+  MapMapper: [
+    ...linkedSetEntries,
+    { suffix: '.mapped', keyType: item, valueType: slot => slot.typeArgs[1] },
+  ],
+  WhitelistMapper: [{ suffix: '', keyType: item, valueType: () => 'bool' }],
+};
+
+/** Storage mappers declared in the contract and module sources */
+export function storageLayout(sources: string[]): StorageSlot[] {
+  const slots = sources.flatMap(source => {
+    try {
+      return listStorageMappers(source);
+    } catch {
+      // Files without a contract or module trait declare no mappers
+      return [];
+    }
+  });
+  return slots.map(decl => ({
+    functionName: decl.functionName,
+    storageKey: decl.storageKey,
+    mapper: decl.mapper,
+    keyParams: decl.keyParams.map(p => ({ name: p.name, type: rustTypeToAbi(p.type) })),
+    typeArgs: decl.typeArgs.filter(arg => !/^(Self::Api|SA)$/.test(arg)).map(rustTypeToAbi),
+  }));
+}
+
+/** Storage key of a mapper for the given key arguments (array in order, or keyed by name) */
+export function storageKeyFor(slot: StorageSlot, args: unknown, abi: Abi = NO_ABI): Buffer {
+  const values =
+    slot.keyParams.length === 0 && args === undefined
+      ? []
+      : fieldValues(args, slot.keyParams.map(p => p.name), `Storage mapper ${slot.functionName}`);
+  return Buffer.concat([
+    Buffer.from(slot.storageKey, 'utf8'),
+    ...slot.keyParams.map((param, i) => encodeNested(param.type, values[i], abi)),
+  ]);
+}
+
+function decodeWith(
+  slot: StorageSlot,
+  key: Buffer,
+  value: Buffer,
+  abi: Abi
+): DecodedStorageEntry | undefined {
+  const base = Buffer.from(slot.storageKey, 'utf8');
+  if (!key.subarray(0, base.length).equals(base)) return undefined;
+  const { values, rest } = decodeNestedPrefix(
+    slot.keyParams.map(p => p.type),
+    key.subarray(base.length),
+    abi
+  );
+  const keyArgs = Object.fromEntries(slot.keyParams.map((p, i) => [p.name, values[i]]));
+  const decoded = { key: key.toString('hex'), rawValue: value.toString('hex') };
+  for (const entry of MAPPER_ENTRIES[slot.mapper] || []) {
+    const suffix = Buffer.from(entry.suffix, 'utf8');
+    if (!rest.subarray(0, suffix.length).equals(suffix)) continue;
+    const after = rest.subarray(suffix.length);
+    let entryKey: unknown;
+    if (entry.keyType) {
+      const parsed = decodeNestedPrefix([entry.keyType(slot)], after, abi);
+      if (parsed.rest.length > 0) continue;
+      entryKey = parsed.values[0];
+    } else if (after.length > 0) {
+      continue;
+    }
+    return {
+      ...decoded,
+      functionName: slot.functionName,
+      storageKey: slot.storageKey,
+      mapper: slot.mapper,
+      keyArgs,
+      entry: entry.suffix ? entry.suffix.slice(1) : undefined,
+      entryKey,
+      value: entry.valueType
+        ? decodeTopLevel(entry.valueType(slot), value, abi)
+        : `0x${value.toString('hex')}`,
+    };
+  }
+  return undefined;
+}
+
+/**
+ * Decode a storage entry with the first mapper (longest key first) whose key,
+ * key arguments and internal entry match it; undefined when none does.
+ */
+export function decodeStorageEntry(
+  key: Buffer,
+  value: Buffer,
+  layout: StorageSlot[],
+  abi: Abi = NO_ABI
+): DecodedStorageEntry | undefined {
+  const slots = [...layout].sort((a, b) => b.storageKey.length - a.storageKey.length);
+  for (const slot of slots) {
+    try {
+      const decoded = decodeWith(slot, key, value, abi);
+      if (decoded) return decoded;
+    } catch {
+      // Key arguments or value do not fit this mapper's types; try the next one
+    }
+  }
+  return undefined;
+}
@@ -0,0 +1,459 @@
+/**
+ * A complete example contract for each storage mapper, served as the
+ * `klever://storage-mappers/{mapper}` resources so the storage advisor can
+ * point at working code rather than a fragment.
+ */
+
+import { STORAGE_MAPPERS, storageMapperUri, type MapperName } from './storage-mappers.js';
+
+export interface StorageMapperExample {
+  mapper: MapperName;
+  /** What the example contract does */
+  title: string;
+  /** Notes on the calls the example makes and what they cost */
+  notes: string[];
+  /** Complete contract source (src/lib.rs) */
+  contract: string;
+}
+
+export const STORAGE_MAPPER_EXAMPLES: StorageMapperExample[] = [
+  {
+    mapper: 'SingleValueMapper',
+    title: 'Token balances keyed by address, with a running total supply',
+    notes: [
+      'balance(&address) is one storage key per address; it cannot be listed on-chain',
+      'update() reads and writes in one call; set() alone skips the read',
+      'Reading a key that was never set returns zero, so "no balance" needs no special case',
+    ],
+    contract: `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait Ledger {
+    #[init]
+    fn init(&self, initial_supply: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.balance(&caller).set(&initial_supply);
+        self.total_supply().set(&initial_supply);
+    }
+
+    #[endpoint]
+    fn transfer(&self, to: ManagedAddress, amount: BigUint) {
+        require!(!to.is_zero(), "Cannot transfer to zero address");
+        let caller = self.blockchain().get_caller();
+        let balance = self.balance(&caller).get();
+        require!(balance >= amount, "Insufficient balance");
+
+        self.balance(&caller).set(&(balance - &amount));
+        self.balance(&to).update(|value| *value += &amount);
+    }
+
+    #[view(getBalance)]
+    fn get_balance(&self, account: ManagedAddress) -> BigUint {
+        self.balance(&account).get()
+    }
+
+    #[view(getTotalSupply)]
+    #[storage_mapper("totalSupply")]
+    fn total_supply(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("balance")]
+    fn balance(&self, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
+`,
+  },
+  {
+    mapper: 'SetMapper',
+    title: 'Voter registry that lists voters in registration order',
+    notes: [
+      'insert() returns false when the voter is already registered',
+      'remove() relinks the neighbours, so the remaining voters keep their order',
+      'Iterating reads one key per voter; keep views that list voters off the hot path',
+    ],
+    contract: `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait VoterRegistry {
+    #[init]
+    fn init(&self) {}
+
+    #[endpoint]
+    fn register(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.voters().insert(caller), "Already registered");
+    }
+
+    #[endpoint]
+    fn leave(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.voters().remove(&caller), "Not registered");
+    }
+
+    #[view(isVoter)]
+    fn is_voter(&self, account: ManagedAddress) -> bool {
+        self.voters().contains(&account)
+    }
+
+    /// Voters in the order they registered
+    #[view(getVoters)]
+    fn get_voters(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.voters().iter().collect()
+    }
+
+    #[storage_mapper("voters")]
+    fn voters(&self) -> SetMapper<ManagedAddress>;
+}
+`,
+  },
+  {
+    mapper: 'UnorderedSetMapper',
+    title: 'Accepted payment tokens managed by the owner',
+    notes: [
+      'swap_remove() moves the last element into the removed slot: O(1), but order changes',
+      'contains() is one read, so it is cheap to check on every payment',
+      'len() is stored, so counting members does not iterate',
+    ],
+    contract: `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait PaymentTokens {
+    #[init]
+    fn init(&self) {}
+
+    #[only_owner]
+    #[endpoint(addToken)]
+    fn add_token(&self, token: TokenIdentifier) {
+        require!(self.accepted_tokens().insert(token), "Token already accepted");
+    }
+
+    #[only_owner]
+    #[endpoint(removeToken)]
+    fn remove_token(&self, token: TokenIdentifier) {
+        require!(self.accepted_tokens().swap_remove(&token), "Token not accepted");
+    }
+
+    #[payable("*")]
+    #[endpoint]
+    fn pay(&self) {
+        let payment = self.call_value().single_kda();
+        require!(
+            self.accepted_tokens().contains(&payment.token_identifier),
+            "Token not accepted"
+        );
+        require!(payment.amount > 0, "Amount must be positive");
+    }
+
+    #[view(getAcceptedTokens)]
+    fn get_accepted_tokens(&self) -> MultiValueEncoded<TokenIdentifier> {
+        self.accepted_tokens().iter().collect()
+    }
+
+    #[view(getAcceptedTokenCount)]
+    fn get_accepted_token_count(&self) -> usize {
+        self.accepted_tokens().len()
+    }
+
+    #[storage_mapper("acceptedTokens")]
+    fn accepted_tokens(&self) -> UnorderedSetMapper<TokenIdentifier>;
+}
+`,
+  },
+  {
+    mapper: 'MapMapper',
+    title: 'Staking pool that can list every staker with their stake',
+    notes: [
+      'insert() and remove() keep the key set in sync; each costs several writes',
+      'Use a per-address SingleValueMapper instead if the stakers never need to be listed',
+      'Removing the entry when the stake reaches zero keeps the listing short',
+    ],
+    contract: `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait StakingPool {
+    #[init]
+    fn init(&self) {}
+
+    #[payable("KLV")]
+    #[endpoint]
+    fn stake(&self) {
+        let amount = self.call_value().klv_value().clone_value();
+        require!(amount > 0, "Amount must be positive");
+        let caller = self.blockchain().get_caller();
+        let current = self.stakes().get(&caller).unwrap_or_default();
+        self.stakes().insert(caller, current + amount);
+    }
+
+    #[endpoint]
+    fn unstake(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let current = self.stakes().get(&caller).unwrap_or_default();
+        require!(amount > 0 && current >= amount, "Invalid amount");
+
+        let remaining = current - &amount;
+        if remaining == 0 {
+            self.stakes().remove(&caller);
+        } else {
+            self.stakes().insert(caller.clone(), remaining);
+        }
+        self.send().direct_klv(&caller, &amount);
+    }
+
+    #[view(getStake)]
+    fn get_stake(&self, account: ManagedAddress) -> BigUint {
+        self.stakes().get(&account).unwrap_or_default()
+    }
+
+    #[view(getStakers)]
+    fn get_stakers(&self) -> MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>> {
+        self.stakes().iter().map(|(address, stake)| (address, stake).into()).collect()
+    }
+
+    #[storage_mapper("stakes")]
+    fn stakes(&self) -> MapMapper<ManagedAddress, BigUint>;
+}
+`,
+  },
+  {
+    mapper: 'VecMapper',
+    title: 'Append-only proposal log addressed by 1-based id',
+    notes: [
+      'push() returns the new 1-based index, which doubles as the proposal id',
+      'get() panics on an index of 0 or past the end; check against len() first',
+      'Editing a field rewrites the whole element with set()',
+    ],
+    contract: `#![no_std]
+
+use klever_sc::imports::*;
+
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct Proposal<M: ManagedTypeApi> {
+    pub proposer: ManagedAddress<M>,
+    pub description: ManagedBuffer<M>,
+    pub votes: u64,
+}
+
+#[klever_sc::contract]
+pub trait Proposals {
+    #[init]
+    fn init(&self) {}
+
+    #[endpoint]
+    fn propose(&self, description: ManagedBuffer) -> usize {
+        require!(!description.is_empty(), "Description is required");
+        let proposal = Proposal {
+            proposer: self.blockchain().get_caller(),
+            description,
+            votes: 0,
+        };
+        self.proposals().push(&proposal)
+    }
+
+    #[endpoint]
+    fn vote(&self, id: usize) {
+        require!(id >= 1 && id <= self.proposals().len(), "Unknown proposal");
+        let mut proposal = self.proposals().get(id);
+        proposal.votes += 1;
+        self.proposals().set(id, &proposal);
+    }
+
+    #[view(getProposal)]
+    fn get_proposal(&self, id: usize) -> Proposal<Self::Api> {
+        require!(id >= 1 && id <= self.proposals().len(), "Unknown proposal");
+        self.proposals().get(id)
+    }
+
+    #[view(getProposalCount)]
+    fn get_proposal_count(&self) -> usize {
+        self.proposals().len()
+    }
+
+    #[storage_mapper("proposals")]
+    fn proposals(&self) -> VecMapper<Proposal<Self::Api>>;
+}
+`,
+  },
+  {
+    mapper: 'LinkedListMapper',
+    title: 'First-in first-out withdrawal queue processed in batches',
+    notes: [
+      'push_back() and pop_front() are O(1) at either end',
+      'Processing a bounded batch per call keeps gas predictable however long the queue grows',
+      'front() peeks without removing, e.g. to check the next request before paying it',
+    ],
+    contract: `#![no_std]
+
+use klever_sc::imports::*;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct Withdrawal<M: ManagedTypeApi> {
+    pub account: ManagedAddress<M>,
+    pub amount: BigUint<M>,
+}
+
+#[klever_sc::contract]
+pub trait WithdrawalQueue {
+    #[init]
+    fn init(&self) {}
+
+    #[payable("KLV")]
+    #[endpoint]
+    fn deposit(&self) {}
+
+    #[endpoint(requestWithdrawal)]
+    fn request_withdrawal(&self, amount: BigUint) {
+        require!(amount > 0, "Amount must be positive");
+        let account = self.blockchain().get_caller();
+        self.queue().push_back(Withdrawal { account, amount });
+    }
+
+    /// Pays up to \`max\` queued withdrawals, oldest first
+    #[only_owner]
+    #[endpoint(processQueue)]
+    fn process_queue(&self, max: usize) -> usize {
+        let mut processed = 0;
+        while processed < max {
+            let Some(node) = self.queue().pop_front() else {
+                break;
+            };
+            let withdrawal = node.into_value();
+            self.send().direct_klv(&withdrawal.account, &withdrawal.amount);
+            processed += 1;
+        }
+        processed
+    }
+
+    #[view(getQueueLength)]
+    fn get_queue_length(&self) -> usize {
+        self.queue().len()
+    }
+
+    #[storage_mapper("queue")]
+    fn queue(&self) -> LinkedListMapper<Withdrawal<Self::Api>>;
+}
+`,
+  },
+  {
+    mapper: 'WhitelistMapper',
+    title: 'Allow list checked on every mint and never listed on-chain',
+    notes: [
+      'require_whitelisted() is a single read that fails the call with a standard message',
+      'Members cannot be enumerated; the events let indexers rebuild the list off-chain',
+      'add() and remove() are one write each',
+    ],
+    contract: `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait AllowList {
+    #[init]
+    fn init(&self) {}
+
+    #[only_owner]
+    #[endpoint(allow)]
+    fn allow(&self, account: ManagedAddress) {
+        self.allowed().add(&account);
+        self.allowed_event(&account);
+    }
+
+    #[only_owner]
+    #[endpoint(revoke)]
+    fn revoke(&self, account: ManagedAddress) {
+        self.allowed().remove(&account);
+        self.revoked_event(&account);
+    }
+
+    #[endpoint]
+    fn mint(&self) {
+        let caller = self.blockchain().get_caller();
+        self.allowed().require_whitelisted(&caller);
+        self.minted(&caller).update(|count| *count += 1);
+    }
+
+    #[view(isAllowed)]
+    fn is_allowed(&self, account: ManagedAddress) -> bool {
+        self.allowed().contains(&account)
+    }
+
+    #[event("allowed")]
+    fn allowed_event(&self, #[indexed] account: &ManagedAddress);
+
+    #[event("revoked")]
+    fn revoked_event(&self, #[indexed] account: &ManagedAddress);
+
+    #[storage_mapper("allowed")]
+    fn allowed(&self) -> WhitelistMapper<ManagedAddress>;
+
+    #[storage_mapper("minted")]
+    fn minted(&self, account: &ManagedAddress) -> SingleValueMapper<u32>;
+}
+`,
+  },
+];
+
+/**
+ * Markdown reference for one mapper (costs, pitfalls and the example contract),
+ * or for `index` a table of all mappers; undefined for unknown names
+ */
+export function storageMapperReference(name: string): string | undefined {
+  if (name === 'index') {
+    const lines = [
+      '# Storage Mapper Reference',
+      '',
+      'Each page documents one klever-sc storage mapper with a complete example contract.',
+      '',
+      '| Mapper | Iterable | Summary |',
+      '|---|---|---|',
+      ...STORAGE_MAPPERS.map(
+        info =>
+          `| [${info.name}](${storageMapperUri(info.name)}) | ${info.iterable ? 'Yes' : 'No'} | ${info.summary} |`
+      ),
+      '',
+    ];
+    return lines.join('\n');
+  }
+  const info = STORAGE_MAPPERS.find(m => m.name === name);
+  const example = STORAGE_MAPPER_EXAMPLES.find(e => e.mapper === name);
+  if (!info || !example) return undefined;
+
+  const lines = [
+    `# ${info.name}`,
+    '',
+    info.summary,
+    '',
+    '## Best for',
+    '',
+    ...info.bestFor.map(item => `- ${item}`),
+    '',
+    '## Avoid when',
+    '',
+    ...info.avoidWhen.map(item => `- ${item}`),
+    '',
+    '## Operation costs',
+    '',
+    '| Operation | Storage access |',
+    '|---|---|',
+    ...Object.entries(info.operations).map(([op, cost]) => `| \`${op}\` | ${cost} |`),
+    '',
+    `## Example: ${example.title}`,
+    '',
+    '```rust',
+    example.contract.trimEnd(),
+    '```',
+    '',
+    ...example.notes.map(note => `- ${note}`),
+    '',
+    '## Migration',
+    '',
+    info.migrationNotes,
+    '',
+  ];
+  return lines.join('\n');
+}
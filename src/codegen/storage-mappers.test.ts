@@ -0,0 +1,73 @@
+import { adviseStorageMapper, STORAGE_MAPPERS } from './storage-mappers.js';
+import { STORAGE_MAPPER_EXAMPLES, storageMapperReference } from './storage-mapper-examples.js';
+
+describe('adviseStorageMapper', () => {
+  it.each([
+    ['set of whitelisted addresses iterated rarely', 'UnorderedSetMapper'],
+    ['whitelisted addresses checked on every call', 'WhitelistMapper'],
+    ['balance per address', 'SingleValueMapper'],
+    ['stake per address, must list all stakers with their stake', 'MapMapper'],
+    ['withdrawal queue processed first-in first-out', 'LinkedListMapper'],
+    ['append-only history of proposals referenced by id', 'VecMapper'],
+    ['set of voters, order of registration matters, iterated', 'SetMapper'],
+  ])('recommends a mapper for "%s"', (description, expected) => {
+    expect(adviseStorageMapper(description).recommendation.mapper).toBe(expected);
+  });
+
+  it('fills the snippet with the supplied key and value types', () => {
+    const advice = adviseStorageMapper('position per address, iterated by admin views', {
+      keyType: 'u64',
+      valueType: 'Position<Self::Api>',
+    });
+    expect(advice.recommendation.mapper).toBe('MapMapper');
+    expect(advice.recommendation.snippet).toContain('MapMapper<u64, Position<Self::Api>>');
+    expect(advice.alternatives).toHaveLength(2);
+  });
+
+  it('documents gas and migration notes for every mapper', () => {
+    for (const mapper of STORAGE_MAPPERS) {
+      expect(Object.keys(mapper.operations).length).toBeGreaterThan(0);
+      expect(mapper.migrationNotes).not.toBe('');
+      expect(mapper.snippet).toContain(`${mapper.name}<`);
+    }
+  });
+
+  it('links the recommendation and alternatives to their reference pages', () => {
+    const advice = adviseStorageMapper('withdrawal queue processed first-in first-out');
+    expect(advice.recommendation.reference).toBe('klever://storage-mappers/LinkedListMapper');
+    for (const alternative of advice.alternatives) {
+      expect(alternative.reference).toBe(`klever://storage-mappers/${alternative.mapper}`);
+    }
+  });
+
+  it('rejects empty descriptions', () => {
+    expect(() => adviseStorageMapper('  ')).toThrow('Describe the data');
+  });
+});
+
+describe('storageMapperReference', () => {
+  it('has a complete example contract for every mapper', () => {
+    expect(STORAGE_MAPPER_EXAMPLES.map(e => e.mapper).sort()).toEqual(
+      STORAGE_MAPPERS.map(m => m.name).sort()
+    );
+    for (const example of STORAGE_MAPPER_EXAMPLES) {
+      expect(example.contract).toContain('#[klever_sc::contract]');
+      expect(example.contract).toContain(`${example.mapper}<`);
+    }
+  });
+
+  it('renders costs, the example and migration notes for a mapper', () => {
+    const page = storageMapperReference('WhitelistMapper')!;
+    expect(page).toContain('| `contains` | 1 read |');
+    expect(page).toContain('self.allowed().require_whitelisted(&caller);');
+    expect(page).toContain('## Migration');
+  });
+
+  it('lists every mapper in the index and rejects unknown names', () => {
+    const index = storageMapperReference('index')!;
+    for (const mapper of STORAGE_MAPPERS) {
+      expect(index).toContain(`(klever://storage-mappers/${mapper.name})`);
+    }
+    expect(storageMapperReference('TreeMapper')).toBeUndefined();
+  });
+});
@@ -0,0 +1,426 @@
+/**
+ * Storage mapper catalog and a rule-based advisor that picks a mapper from a
+ * plain-language description of the data shape and access pattern, weighing
+ * in measured gas when a storage benchmark of the workload is given.
+ */
+
+import type { MapperGas, StorageBenchmarkResult } from './storage-benchmark.js';
+
+export type MapperName =
+  | 'SingleValueMapper'
+  | 'SetMapper'
+  | 'UnorderedSetMapper'
+  | 'MapMapper'
+  | 'VecMapper'
+  | 'LinkedListMapper'
+  | 'WhitelistMapper';
+
+export interface StorageMapperInfo {
+  name: MapperName;
+  summary: string;
+  bestFor: string[];
+  avoidWhen: string[];
+  /** Relative cost of common operations, in storage reads/writes */
+  operations: Record<string, string>;
+  iterable: boolean;
+  /** Declaration and usage; `{key}` and `{value}` are replaced with the caller's types */
+  snippet: string;
+  migrationNotes: string;
+}
+
+export const STORAGE_MAPPERS: StorageMapperInfo[] = [
+  {
+    name: 'SingleValueMapper',
+    summary:
+      'One value per storage key. Add arguments to the storage function to get one value per key (e.g. per address) without any index overhead.',
+    bestFor: [
+      'Config values, counters, totals, flags, owner/admin addresses',
+      'Per-key values (balances, allowances) that are looked up but never enumerated',
+    ],
+    avoidWhen: [
+      'You need to list or count the keys; per-key SingleValueMappers cannot be iterated',
+      'Storing a growing ManagedVec in one value: every update rewrites the whole vector',
+    ],
+    operations: { get: '1 read', set: '1 write', update: '1 read + 1 write', clear: '1 write' },
+    iterable: false,
+    snippet: `#[storage_mapper("balance")]
+fn balance(&self, key: &{key}) -> SingleValueMapper<{value}>;
+
+// usage
+self.balance(&key).set(&value);
+let current = self.balance(&key).get();
+self.balance(&key).update(|v| *v += 1u32);`,
+    migrationNotes:
+      'Moving per-key values into a MapMapper changes both key layout and encoding; copy entries in a migrate() step. Keys are not enumerable, so the migration needs the key list from events or off-chain indexing.',
+  },
+  {
+    name: 'SetMapper',
+    summary:
+      'Set of unique values that preserves insertion order, stored as a linked list plus an index.',
+    bestFor: [
+      'Membership checks that also need ordered iteration',
+      'Collections where removal must keep the remaining order stable',
+    ],
+    avoidWhen: [
+      'Order does not matter: UnorderedSetMapper is cheaper',
+      'Only membership checks are needed: WhitelistMapper is cheapest',
+    ],
+    operations: {
+      insert: '~3 reads + 3 writes (node, links, index)',
+      remove: '~3 reads + 3 writes',
+      contains: '1 read',
+      iterate: '1 read per element',
+    },
+    iterable: true,
+    snippet: `#[storage_mapper("members")]
+fn members(&self) -> SetMapper<{value}>;
+
+// usage
+let inserted = self.members().insert(value.clone()); // false if already present
+let is_member = self.members().contains(&value);
+for member in self.members().iter() { /* insertion order */ }`,
+    migrationNotes:
+      'SetMapper keys differ from UnorderedSetMapper and VecMapper layouts; switching requires re-inserting every element in a migrate() step, which may need to be batched for large sets.',
+  },
+  {
+    name: 'UnorderedSetMapper',
+    summary:
+      'Set of unique values backed by a VecMapper and an index map; removal swaps the last element into the gap.',
+    bestFor: [
+      'Sets iterated occasionally where order is irrelevant (stakers, voters, holders)',
+      'Picking a random member by index',
+    ],
+    avoidWhen: ['Insertion order must be preserved after removals'],
+    operations: {
+      insert: '~2 reads + 3 writes',
+      swap_remove: '~3 reads + 3 writes',
+      contains: '1 read',
+      len: '1 read',
+      iterate: '1 read per element',
+    },
+    iterable: true,
+    snippet: `#[storage_mapper("stakers")]
+fn stakers(&self) -> UnorderedSetMapper<{value}>;
+
+// usage
+self.stakers().insert(value.clone());
+self.stakers().swap_remove(&value);
+let count = self.stakers().len();
+let first = self.stakers().get_by_index(1); // 1-based`,
+    migrationNotes:
+      'Element indices change on swap_remove, so never persist indices elsewhere. Converting to SetMapper requires re-inserting all elements.',
+  },
+  {
+    name: 'MapMapper',
+    summary: 'Key-value map whose keys can be iterated (keys kept in an internal SetMapper).',
+    bestFor: [
+      'Per-key records that must also be enumerated (list all stakers with their stake)',
+      'Admin views that export the whole mapping',
+    ],
+    avoidWhen: [
+      'Keys are never iterated: per-key SingleValueMapper is roughly 3x cheaper per write',
+      'The map can grow without bound and is iterated inside endpoints',
+    ],
+    operations: {
+      insert: '~4 reads + 4 writes (value + key set)',
+      get: '1 read',
+      remove: '~4 reads + 4 writes',
+      iterate: '2 reads per entry',
+    },
+    iterable: true,
+    snippet: `#[storage_mapper("positions")]
+fn positions(&self) -> MapMapper<{key}, {value}>;
+
+// usage
+self.positions().insert(key.clone(), value);
+let position = self.positions().get(&key); // Option<{value}>
+for (key, value) in self.positions().iter() { /* ... */ }`,
+    migrationNotes:
+      'Enumerating an existing MapMapper makes it the easiest mapper to migrate away from; iterate keys in a migrate() step (batched if large) and write them to the new layout.',
+  },
+  {
+    name: 'VecMapper',
+    summary: 'Append-friendly 1-indexed list; each element under its own key plus a length.',
+    bestFor: [
+      'Append-only histories, proposals, orders referenced by id',
+      'Lists accessed by index that rarely shrink',
+    ],
+    avoidWhen: [
+      'You need uniqueness or membership checks (use a set mapper)',
+      'Elements are removed from the middle frequently',
+    ],
+    operations: {
+      push: '1 read + 2 writes',
+      get: '1 read',
+      set: '1 write',
+      swap_remove: '~3 reads + 3 writes',
+      iterate: '1 read per element',
+    },
+    iterable: true,
+    snippet: `#[storage_mapper("history")]
+fn history(&self) -> VecMapper<{value}>;
+
+// usage
+let id = self.history().push(&value); // returns the 1-based index
+let entry = self.history().get(id);
+let total = self.history().len();`,
+    migrationNotes:
+      'Because VecMapper indices are 1-based ids, keep them stable across upgrades; appending new fields to the element struct requires re-encoding every element.',
+  },
+  {
+    name: 'LinkedListMapper',
+    summary: 'Doubly linked list with O(1) push/pop at both ends and removal by node id.',
+    bestFor: ['FIFO/LIFO queues (withdrawal queues, order books)', 'Ordered lists with removal'],
+    avoidWhen: ['Random access by index is needed'],
+    operations: {
+      push_back: '~2 reads + 3 writes',
+      pop_front: '~2 reads + 3 writes',
+      remove_node: '~3 reads + 3 writes',
+      iterate: '1 read per node',
+    },
+    iterable: true,
+    snippet: `#[storage_mapper("queue")]
+fn queue(&self) -> LinkedListMapper<{value}>;
+
+// usage
+self.queue().push_back(value);
+if let Some(node) = self.queue().pop_front() {
+    let item = node.into_value();
+}`,
+    migrationNotes:
+      'Node ids are internal; migrating means draining the list in order into the new structure.',
+  },
+  {
+    name: 'WhitelistMapper',
+    summary: 'Membership-only set: one flag per item, no iteration, cheapest add/remove/check.',
+    bestFor: ['Allow/deny lists checked on every call and never listed on-chain'],
+    avoidWhen: ['You need to list or count members'],
+    operations: { add: '1 write', remove: '1 write', contains: '1 read' },
+    iterable: false,
+    snippet: `#[storage_mapper("whitelist")]
+fn whitelist(&self) -> WhitelistMapper<{value}>;
+
+// usage
+self.whitelist().add(&value);
+self.whitelist().require_whitelisted(&value);
+self.whitelist().remove(&value);`,
+    migrationNotes:
+      'Members cannot be enumerated; keep an off-chain record (events) if you might migrate to an iterable set later.',
+  },
+];
+
+/** Resource URI of a mapper's reference page with a complete example contract */
+export function storageMapperUri(mapper: MapperName | 'index'): string {
+  return `klever://storage-mappers/${mapper}`;
+}
+
+export interface AccessPatternFeatures {
+  collection: boolean;
+  keyed: boolean;
+  iterated: boolean;
+  iteratedRarely: boolean;
+  ordered: boolean;
+  queue: boolean;
+  membership: boolean;
+  removals: boolean;
+  appendOnly: boolean;
+  unique: boolean;
+}
+
+type PatternFeature = Exclude<keyof AccessPatternFeatures, 'iteratedRarely'>;
+
+const FEATURE_PATTERNS: Record<PatternFeature, RegExp[]> = {
+  collection: [
+    /\b(set|list|collection|many|multiple)\b/,
+    /\b(whitelist|members?|holders|stakers|voters|users|addresses|items|entries|orders|history|queue)\b/,
+  ],
+  keyed: [
+    /\b(per|keyed|lookup|associated|mapping|map)\b/,
+    /\bby (address|key|user|token)\b/,
+    /\b(balance|allowance|stake|position)s? (of|for)\b/,
+  ],
+  iterated: [/\b(iterat|enumerat|loop)/, /\blist (all|every)\b/, /\bfor each\b/, /\b(export|count)\b/],
+  ordered: [/\b(ordered|order|sequence|sorted|position)\b/, /\bby index\b/],
+  queue: [/\b(queue|fifo|lifo|pop)\b/, /\bfirst[- ]in\b/],
+  membership: [
+    /\b(whitelist|allow ?list|deny ?list|blacklist|membership|contains)/,
+    /\bis (whitelisted|allowed|a member)\b/,
+    /\bcheck(ed)? (if|whether)\b/,
+  ],
+  removals: [/\b(remov|delet|revok|unstak|leav)/],
+  appendOnly: [/\b(append|history|log)\b/, /\bnever (removed?|deleted?)\b/, /\bby id\b/],
+  unique: [/\b(unique|set|whitelist|members?|holders|stakers|voters)\b/, /\bno duplicates\b/],
+};
+
+const RARELY = /\b(rarely|occasional(ly)?|seldom|off-?chain|only in views?)\b/;
+
+export function detectFeatures(description: string): AccessPatternFeatures {
+  const text = description.toLowerCase();
+  const matches = (patterns: RegExp[]) => patterns.some(p => p.test(text));
+  const entries = Object.entries(FEATURE_PATTERNS).map(([key, list]) => [key, matches(list)]);
+  const features = Object.fromEntries(entries) as Record<PatternFeature, boolean>;
+  return { ...features, iteratedRarely: features.iterated && RARELY.test(text) };
+}
+
+function scoreMapper(
+  name: MapperName,
+  f: AccessPatternFeatures
+): { score: number; reasons: string[] } {
+  const reasons: string[] = [];
+  let score = 0;
+  const add = (points: number, reason: string) => {
+    score += points;
+    if (points > 0) reasons.push(reason);
+  };
+
+  switch (name) {
+    case 'SingleValueMapper':
+      if (!f.collection && !f.keyed) add(5, 'A single value with no collection semantics');
+      if (f.keyed && !f.iterated) add(5, 'Per-key lookups, never enumerated: cheapest layout');
+      if (f.iterated) add(-6, '');
+      if (f.collection && !f.keyed) add(-3, '');
+      break;
+    case 'WhitelistMapper':
+      if (f.membership && !f.iterated) add(6, 'Membership checks only, no enumeration needed');
+      if (f.iterated || f.ordered) add(-6, '');
+      if (f.keyed) add(-3, '');
+      break;
+    case 'UnorderedSetMapper':
+      if (f.collection && f.unique) add(3, 'Unique elements');
+      if (f.iterated) add(3, 'Iteration supported with minimal overhead');
+      if (f.iteratedRarely) add(1, 'Occasional iteration suits a compact set');
+      if (f.removals) add(1, 'O(1) swap_remove');
+      if (f.ordered || f.queue) add(-4, '');
+      if (f.keyed) add(-3, '');
+      if (f.membership && !f.iterated) add(-1, '');
+      break;
+    case 'SetMapper':
+      if (f.collection && f.unique) add(2, 'Unique elements');
+      if (f.iterated && f.ordered) add(4, 'Ordered iteration preserved across removals');
+      if (f.iterated && !f.ordered) add(1, 'Iterable');
+      if (f.removals && f.ordered) add(1, 'Removal keeps remaining order');
+      if (f.keyed) add(-3, '');
+      if (f.queue) add(-2, '');
+      break;
+    case 'MapMapper':
+      if (f.keyed && f.iterated) add(7, 'Per-key values that must also be enumerated');
+      if (f.keyed && !f.iterated) add(1, 'Key-value access');
+      if (!f.keyed) add(-4, '');
+      break;
+    case 'VecMapper':
+      if (f.appendOnly) add(5, 'Append-only data addressed by a stable 1-based id');
+      if (f.ordered && !f.unique) add(2, 'Index-ordered elements');
+      if (f.collection && !f.unique && !f.membership) add(1, 'Plain list without uniqueness');
+      if (f.removals && !f.appendOnly) add(-1, '');
+      if (f.membership || f.keyed) add(-3, '');
+      break;
+    case 'LinkedListMapper':
+      if (f.queue) add(7, 'Queue semantics: O(1) push/pop at both ends');
+      if (f.ordered && f.removals) add(1, 'Ordered with removals');
+      if (!f.queue) add(-2, '');
+      break;
+  }
+  return { score, reasons };
+}
+
+function fillSnippet(snippet: string, keyType: string, valueType: string): string {
+  return snippet.replace(/\{key\}/g, keyType).replace(/\{value\}/g, valueType);
+}
+
+export interface MapperAdvice {
+  recommendation: {
+    mapper: MapperName;
+    reasons: string[];
+    snippet: string;
+    operations: Record<string, string>;
+    /** Benchmarked gas per operation and for the workload */
+    measured?: MapperGas;
+    avoidWhen: string[];
+    migrationNotes: string;
+    reference: string;
+  };
+  alternatives: Array<{
+    mapper: MapperName;
+    score: number;
+    summary: string;
+    measured?: MapperGas;
+    reference: string;
+  }>;
+  detected: AccessPatternFeatures;
+}
+
+/** Score from benchmarked gas: the cheapest workload wins, lacking an operation loses */
+function benchmarkScore(
+  name: MapperName,
+  benchmark: StorageBenchmarkResult
+): { score: number; reasons: string[] } {
+  const measured = benchmark.mappers.find(m => m.mapper === name);
+  if (!measured) return { score: 0, reasons: [] };
+  if (measured.workloadGas === undefined) return { score: -3, reasons: [] };
+  const [cheapest, next] = benchmark.mappers;
+  if (cheapest !== measured || next?.workloadGas === undefined) return { score: 0, reasons: [] };
+  return {
+    score: 3,
+    reasons: [
+      `Cheapest measured for the workload: ${measured.workloadGas} gas against ${next.workloadGas} for ${next.mapper} (${benchmark.entries} entries)`,
+    ],
+  };
+}
+
+function measuredGas(
+  name: MapperName,
+  benchmark: StorageBenchmarkResult | undefined
+): { measured?: MapperGas } {
+  const measured = benchmark?.mappers.find(m => m.mapper === name);
+  return measured ? { measured } : {};
+}
+
+/**
+ * Recommend a storage mapper for a described data shape and access pattern
+ */
+export function adviseStorageMapper(
+  description: string,
+  options: { keyType?: string; valueType?: string; benchmark?: StorageBenchmarkResult } = {}
+): MapperAdvice {
+  if (!description.trim()) {
+    throw new Error(
+      'Describe the data shape and access pattern (e.g. "set of whitelisted addresses iterated rarely").'
+    );
+  }
+  const detected = detectFeatures(description);
+  const ranked = STORAGE_MAPPERS.map(info => {
+    const rules = scoreMapper(info.name, detected);
+    if (!options.benchmark) return { info, ...rules };
+    const measured = benchmarkScore(info.name, options.benchmark);
+    return {
+      info,
+      score: rules.score + measured.score,
+      reasons: [...rules.reasons, ...measured.reasons],
+    };
+  }).sort((a, b) => b.score - a.score);
+  const best = ranked[0];
+  const keyType = options.keyType || 'ManagedAddress';
+  const defaultValue = detected.collection && !detected.keyed ? 'ManagedAddress' : 'BigUint';
+  const valueType = options.valueType || defaultValue;
+
+  return {
+    recommendation: {
+      mapper: best.info.name,
+      reasons: best.reasons.length > 0 ? best.reasons : [best.info.summary],
+      snippet: fillSnippet(best.info.snippet, keyType, valueType),
+      operations: best.info.operations,
+      ...measuredGas(best.info.name, options.benchmark),
+      avoidWhen: best.info.avoidWhen,
+      migrationNotes: best.info.migrationNotes,
+      reference: storageMapperUri(best.info.name),
+    },
+    alternatives: ranked.slice(1, 3).map(r => ({
+      mapper: r.info.name,
+      score: r.score,
+      summary: r.info.summary,
+      ...measuredGas(r.info.name, options.benchmark),
+      reference: storageMapperUri(r.info.name),
+    })),
+    detected,
+  };
+}
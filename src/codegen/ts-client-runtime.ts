@@ -0,0 +1,507 @@
+/**
+ * ABI codec embedded in generated TypeScript clients (see ts-client.ts). It
+ * mirrors abi-codec.ts on Uint8Array instead of Buffer so the clients run in
+ * browsers and reads the contract's `TYPES` and `EVENTS` the generator emits.
+ * The entry points are exported so unused ones do not fail noUnusedLocals.
+ * Written without template literals so it can live in a String.raw block.
+ */
+
+export const TS_CLIENT_RUNTIME = String.raw`// ─── ABI codec ──────────────────────────────────────────────
+
+type Field = { name: string; type: string };
+type TypeDef =
+  | { type: 'struct'; fields: Field[] }
+  | { type: 'enum'; variants: { name: string; discriminant: number; fields?: Field[] }[] };
+
+const WIDTHS = new Map<string, number>([
+  ['u8', 1],
+  ['u16', 2],
+  ['u32', 4],
+  ['usize', 4],
+  ['u64', 8],
+  ['i8', 1],
+  ['i16', 2],
+  ['i32', 4],
+  ['isize', 4],
+  ['i64', 8],
+]);
+/** Integer types decoded as numbers; the others are bigints */
+const SMALL_INTEGERS = new Set(['u8', 'u16', 'u32', 'usize', 'i8', 'i16', 'i32', 'isize']);
+
+function typeDef(name: string): TypeDef | undefined {
+  return Object.prototype.hasOwnProperty.call(TYPES, name) ? TYPES[name] : undefined;
+}
+
+/** Split an ABI type like List<tuple<u8,Address>> into its name and arguments */
+function parseType(type: string): { name: string; args: string[] } {
+  const trimmed = type.trim();
+  const open = trimmed.indexOf('<');
+  if (open < 0) return { name: trimmed, args: [] };
+  const args: string[] = [];
+  let depth = 0;
+  let start = open + 1;
+  for (let i = start; i < trimmed.length - 1; i++) {
+    if (trimmed[i] === '<') depth++;
+    else if (trimmed[i] === '>') depth--;
+    else if (trimmed[i] === ',' && depth === 0) {
+      args.push(trimmed.slice(start, i).trim());
+      start = i + 1;
+    }
+  }
+  args.push(trimmed.slice(start, trimmed.length - 1).trim());
+  return { name: trimmed.slice(0, open), args };
+}
+
+function concat(parts: Uint8Array[]): Uint8Array {
+  const out = new Uint8Array(parts.reduce((length, part) => length + part.length, 0));
+  let offset = 0;
+  for (const part of parts) {
+    out.set(part, offset);
+    offset += part.length;
+  }
+  return out;
+}
+
+function toHex(bytes: Uint8Array): string {
+  return Array.from(bytes, byte => byte.toString(16).padStart(2, '0')).join('');
+}
+
+function fromHex(hex: string): Uint8Array {
+  return Uint8Array.from(hex.match(/../g) || [], byte => parseInt(byte, 16));
+}
+
+function toBase64(bytes: Uint8Array): string {
+  return btoa(Array.from(bytes, byte => String.fromCharCode(byte)).join(''));
+}
+
+function fromBase64(base64: string): Uint8Array {
+  return Uint8Array.from(atob(base64), char => char.charCodeAt(0));
+}
+
+function text(bytes: Uint8Array): string {
+  return new TextDecoder().decode(bytes);
+}
+
+function bytesOf(value: unknown): Uint8Array {
+  if (value instanceof Uint8Array) return value;
+  if (typeof value === 'string') return new TextEncoder().encode(value);
+  throw new Error('Expected a Uint8Array or a string, got ' + String(value));
+}
+
+/** Big-endian two's complement of n in exactly width bytes */
+function fixedBytes(n: bigint, width: number, signed: boolean): Uint8Array {
+  const bits = BigInt(width * 8);
+  const min = signed ? -(BigInt(1) << (bits - BigInt(1))) : BigInt(0);
+  const max = (signed ? BigInt(1) << (bits - BigInt(1)) : BigInt(1) << bits) - BigInt(1);
+  if (n < min || n > max) throw new Error(n + ' does not fit in ' + width + ' byte(s)');
+  const unsigned = n < BigInt(0) ? n + (BigInt(1) << bits) : n;
+  return fromHex(unsigned.toString(16).padStart(width * 2, '0'));
+}
+
+/** Shortest big-endian encoding of n; signed values keep their sign bit */
+function minimalBytes(n: bigint, signed: boolean): Uint8Array {
+  if (n === BigInt(0)) return new Uint8Array();
+  if (!signed || n > BigInt(0)) {
+    let hex = n.toString(16);
+    if (hex.length % 2) hex = '0' + hex;
+    if (signed && parseInt(hex[0], 16) >= 8) hex = '00' + hex;
+    return fromHex(hex);
+  }
+  let width = 1;
+  while (n < -(BigInt(1) << BigInt(width * 8 - 1))) width++;
+  return fixedBytes(n, width, true);
+}
+
+function readInteger(bytes: Uint8Array, signed: boolean): bigint {
+  let n = bytes.length === 0 ? BigInt(0) : BigInt('0x' + toHex(bytes));
+  if (signed && bytes.length > 0 && bytes[0] & 0x80) n -= BigInt(1) << BigInt(bytes.length * 8);
+  return n;
+}
+
+function integer(name: string, bytes: Uint8Array): number | bigint {
+  const n = readInteger(bytes, name[0] === 'i' || name === 'BigInt');
+  return SMALL_INTEGERS.has(name) ? Number(n) : n;
+}
+
+function lengthPrefixed(bytes: Uint8Array): Uint8Array {
+  return concat([fixedBytes(BigInt(bytes.length), 4, false), bytes]);
+}
+
+// Bech32 with the klv prefix, for Address values
+
+const CHARSET = 'qpzry9x8gf2tvdw0s3jn54khce6mua7l';
+const HRP = [3, 3, 3, 0, 11, 12, 22];
+
+function polymod(values: number[]): number {
+  const generator = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+  let checksum = 1;
+  for (const value of values) {
+    const top = checksum >> 25;
+    checksum = ((checksum & 0x1ffffff) << 5) ^ value;
+    for (let i = 0; i < 5; i++) if ((top >> i) & 1) checksum ^= generator[i];
+  }
+  return checksum;
+}
+
+function convertBits(data: ArrayLike<number>, from: number, to: number, pad: boolean): number[] {
+  const out: number[] = [];
+  const max = (1 << to) - 1;
+  let accumulator = 0;
+  let bits = 0;
+  for (let i = 0; i < data.length; i++) {
+    accumulator = ((accumulator << from) | data[i]) & ((1 << (from + to - 1)) - 1);
+    bits += from;
+    while (bits >= to) {
+      bits -= to;
+      out.push((accumulator >> bits) & max);
+    }
+  }
+  if (pad && bits > 0) out.push((accumulator << (to - bits)) & max);
+  else if (!pad && (bits >= from || (accumulator << (to - bits)) & max)) {
+    throw new Error('Invalid address padding');
+  }
+  return out;
+}
+
+function encodeAddress(bytes: Uint8Array): string {
+  const data = convertBits(bytes, 8, 5, true);
+  const checksum = polymod(HRP.concat(data, [0, 0, 0, 0, 0, 0])) ^ 1;
+  for (let i = 0; i < 6; i++) data.push((checksum >> (5 * (5 - i))) & 31);
+  return 'klv1' + data.map(value => CHARSET[value]).join('');
+}
+
+/** The 32 bytes of a klv1... address (or of 0x hex) */
+function decodeAddress(value: unknown): Uint8Array {
+  if (typeof value !== 'string') throw new Error('Expected an address, got ' + String(value));
+  if (/^0x[0-9a-fA-F]{64}$/.test(value)) return fromHex(value.slice(2));
+  const lower = value.toLowerCase();
+  const data = Array.from(lower.slice(4), char => CHARSET.indexOf(char));
+  if (!lower.startsWith('klv1') || data.includes(-1) || polymod(HRP.concat(data)) !== 1) {
+    throw new Error('Invalid klv1... address: ' + value);
+  }
+  const bytes = convertBits(data.slice(0, -6), 5, 8, false);
+  if (bytes.length !== 32) throw new Error('Expected a 32-byte address: ' + value);
+  return Uint8Array.from(bytes);
+}
+
+// Encoding
+
+function encodeCustom(name: string, value: unknown, topLevel: boolean): Uint8Array {
+  const def = typeDef(name);
+  if (!def) throw new Error('Unknown ABI type ' + name);
+  if (def.type === 'struct') {
+    const fields = value as Record<string, unknown>;
+    return concat(def.fields.map(field => encodeNested(field.type, fields[field.name])));
+  }
+  const key = typeof value === 'string' ? value : Object.keys(value as object)[0];
+  const variant = def.variants.find(candidate => candidate.name === key);
+  if (!variant) throw new Error(name + ' has no variant ' + key);
+  const fields =
+    typeof value === 'string' ? {} : (value as Record<string, Record<string, unknown>>)[key];
+  const variantFields = variant.fields || [];
+  // Field-less enums encode like a u8 discriminant
+  if (topLevel && variantFields.length === 0) {
+    return minimalBytes(BigInt(variant.discriminant), false);
+  }
+  return concat([
+    Uint8Array.of(variant.discriminant),
+    ...variantFields.map(field => encodeNested(field.type, fields[field.name])),
+  ]);
+}
+
+function encodeNested(type: string, value: unknown): Uint8Array {
+  const { name, args } = parseType(type);
+  const width = WIDTHS.get(name);
+  if (width) return fixedBytes(BigInt(value as Numeric), width, name[0] === 'i');
+  switch (name) {
+    case 'BigUint':
+    case 'BigInt':
+      return lengthPrefixed(encodeTopLevel(name, value));
+    case 'bool':
+      return Uint8Array.of(value ? 1 : 0);
+    case 'Address':
+      return decodeAddress(value);
+    case 'bytes':
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return lengthPrefixed(bytesOf(value));
+    case 'Option':
+      return value === null || value === undefined
+        ? Uint8Array.of(0)
+        : concat([Uint8Array.of(1), encodeNested(args[0], value)]);
+    case 'List': {
+      const items = value as unknown[];
+      return concat([
+        fixedBytes(BigInt(items.length), 4, false),
+        ...items.map(item => encodeNested(args[0], item)),
+      ]);
+    }
+    case 'tuple':
+      return concat(args.map((arg, i) => encodeNested(arg, (value as unknown[])[i])));
+  }
+  if (/^array\d+$/.test(name)) {
+    if (args[0] === 'u8') return bytesOf(value);
+    return concat((value as unknown[]).map(item => encodeNested(args[0], item)));
+  }
+  return encodeCustom(name, value, false);
+}
+
+function encodeTopLevel(type: string, value: unknown): Uint8Array {
+  const { name, args } = parseType(type);
+  const width = WIDTHS.get(name);
+  if (width) {
+    const n = BigInt(value as Numeric);
+    // Top-level integers are minimal, but must still fit the declared width
+    fixedBytes(n, width, name[0] === 'i');
+    return minimalBytes(n, name[0] === 'i');
+  }
+  switch (name) {
+    case 'BigUint':
+    case 'BigInt':
+      return minimalBytes(BigInt(value as Numeric), name === 'BigInt');
+    case 'bool':
+      return value ? Uint8Array.of(1) : new Uint8Array();
+    case 'bytes':
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return bytesOf(value);
+    case 'Option':
+      return value === null || value === undefined
+        ? new Uint8Array()
+        : concat([Uint8Array.of(1), encodeNested(args[0], value)]);
+    case 'List':
+      return concat((value as unknown[]).map(item => encodeNested(args[0], item)));
+  }
+  if (typeDef(name)) return encodeCustom(name, value, true);
+  return encodeNested(type, value);
+}
+
+/** VM arguments: optional<T> may be undefined, variadic<T> is an array, multi<...> a tuple */
+export function encodeArgs(types: string[], values: unknown[]): Uint8Array[] {
+  const out: Uint8Array[] = [];
+  const multi = (type: string, value: unknown): void => {
+    const { name, args } = parseType(type);
+    if (name === 'multi') args.forEach((arg, i) => multi(arg, (value as unknown[])[i]));
+    else out.push(encodeTopLevel(type, value));
+  };
+  types.forEach((type, i) => {
+    const { name, args } = parseType(type);
+    const value = values[i];
+    if (name === 'variadic') ((value as unknown[] | undefined) || []).forEach(v => multi(args[0], v));
+    else if (name === 'optional') {
+      if (value !== undefined && value !== null) multi(args[0], value);
+    } else multi(type, value);
+  });
+  return out;
+}
+
+// Decoding
+
+class Reader {
+  offset = 0;
+
+  constructor(private readonly bytes: Uint8Array) {}
+
+  take(length: number): Uint8Array {
+    if (this.offset + length > this.bytes.length) throw new Error('Not enough bytes to decode');
+    this.offset += length;
+    return this.bytes.subarray(this.offset - length, this.offset);
+  }
+
+  length(): number {
+    return Number(readInteger(this.take(4), false));
+  }
+
+  get done(): boolean {
+    return this.offset >= this.bytes.length;
+  }
+}
+
+function decodeCustom(name: string, reader: Reader, discriminant?: number): unknown {
+  const def = typeDef(name);
+  if (!def) throw new Error('Unknown ABI type ' + name);
+  if (def.type === 'struct') {
+    return Object.fromEntries(
+      def.fields.map(field => [field.name, decodeNested(field.type, reader)])
+    );
+  }
+  const index = discriminant === undefined ? reader.take(1)[0] : discriminant;
+  const variant = def.variants.find(candidate => candidate.discriminant === index);
+  if (!variant) throw new Error(name + ' has no variant with discriminant ' + index);
+  if (!variant.fields || variant.fields.length === 0) return variant.name;
+  return {
+    [variant.name]: Object.fromEntries(
+      variant.fields.map(field => [field.name, decodeNested(field.type, reader)])
+    ),
+  };
+}
+
+function decodeNested(type: string, reader: Reader): unknown {
+  const { name, args } = parseType(type);
+  const width = WIDTHS.get(name);
+  if (width) return integer(name, reader.take(width));
+  switch (name) {
+    case 'BigUint':
+    case 'BigInt':
+      return integer(name, reader.take(reader.length()));
+    case 'bool':
+      return reader.take(1)[0] === 1;
+    case 'Address':
+      return encodeAddress(reader.take(32));
+    case 'bytes':
+      return reader.take(reader.length()).slice();
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return text(reader.take(reader.length()));
+    case 'Option':
+      return reader.take(1)[0] === 0 ? null : decodeNested(args[0], reader);
+    case 'List':
+      return Array.from({ length: reader.length() }, () => decodeNested(args[0], reader));
+    case 'tuple':
+      return args.map(arg => decodeNested(arg, reader));
+  }
+  const array = /^array(\d+)$/.exec(name);
+  if (array) {
+    const length = Number(array[1]);
+    if (args[0] === 'u8') return reader.take(length).slice();
+    return Array.from({ length }, () => decodeNested(args[0], reader));
+  }
+  return decodeCustom(name, reader);
+}
+
+function decodeAll(type: string, bytes: Uint8Array): unknown {
+  const reader = new Reader(bytes);
+  const value = decodeNested(type, reader);
+  if (!reader.done) throw new Error('Unexpected trailing bytes after ' + type);
+  return value;
+}
+
+function decodeTopLevel(type: string, bytes: Uint8Array): unknown {
+  const { name, args } = parseType(type);
+  if (WIDTHS.has(name) || name === 'BigUint' || name === 'BigInt') return integer(name, bytes);
+  switch (name) {
+    case 'bool':
+      return bytes.length > 0 && bytes[bytes.length - 1] === 1;
+    case 'Address':
+      return encodeAddress(bytes);
+    case 'bytes':
+      return bytes;
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return text(bytes);
+    case 'Option':
+      return bytes.length === 0 ? null : decodeAll(args[0], bytes.subarray(1));
+    case 'List': {
+      const reader = new Reader(bytes);
+      const items: unknown[] = [];
+      while (!reader.done) items.push(decodeNested(args[0], reader));
+      return items;
+    }
+  }
+  const def = typeDef(name);
+  // Field-less enums are a top-level integer discriminant
+  if (def && def.type === 'enum' && def.variants.every(v => !v.fields || !v.fields.length)) {
+    return decodeCustom(name, new Reader(bytes), Number(readInteger(bytes, false)));
+  }
+  return decodeAll(type, bytes);
+}
+
+/** Decode results: variadic<T> takes the rest, optional<T> the next one if any */
+export function decodeResults(types: string[], results: Uint8Array[]): unknown[] {
+  let index = 0;
+  const multi = (type: string): unknown => {
+    const { name, args } = parseType(type);
+    if (name === 'multi') return args.map(multi);
+    return decodeTopLevel(type, results[index++] || new Uint8Array());
+  };
+  return types.map(type => {
+    const { name, args } = parseType(type);
+    if (name === 'variadic') {
+      const items: unknown[] = [];
+      while (index < results.length) items.push(multi(args[0]));
+      return items;
+    }
+    if (name === 'optional') return index < results.length ? multi(args[0]) : null;
+    return multi(type);
+  });
+}
+
+// Chain access
+
+let extension: Promise<void> | undefined;
+
+/** Send a call of the contract as a transaction signed in the Klever Extension */
+export async function sendCall(
+  client: ContractClient,
+  endpoint: string,
+  types: string[],
+  values: unknown[],
+  options: CallOptions
+): Promise<string> {
+  if (!extension) extension = web.initialize();
+  await extension;
+  const data = [endpoint, ...encodeArgs(types, values).map(toHex)].join('@');
+  const payload = {
+    scType: 0,
+    address: client.address,
+    ...(options.callValue ? { callValue: options.callValue } : {}),
+  };
+  const unsigned = await web.buildTransaction(
+    [{ type: TransactionType.SmartContract, payload }],
+    [toBase64(new TextEncoder().encode(data))]
+  );
+  const signed = await web.signTransaction(unsigned);
+  const response = await web.broadcastTransactions([signed]);
+  return response.data.txsHashes[0];
+}
+
+/** Run a view on the node and decode its results */
+export async function queryView(
+  client: ContractClient,
+  view: string,
+  types: string[],
+  values: unknown[],
+  outputs: string[]
+): Promise<unknown[]> {
+  const response = await fetch(client.nodeUrl + '/vm/query', {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify({
+      scAddress: client.address,
+      funcName: view,
+      args: encodeArgs(types, values).map(toBase64),
+    }),
+  });
+  const body = (await response.json()) as {
+    data?: { returnData?: string[] | null; returnCode?: string; returnMessage?: string };
+    error?: string;
+  };
+  const result = body.data;
+  if (!response.ok || body.error || !result) {
+    throw new Error(view + ' failed: ' + (body.error || 'HTTP ' + response.status));
+  }
+  if (result.returnCode && result.returnCode.toLowerCase() !== 'ok') {
+    throw new Error(view + ' failed: ' + (result.returnMessage || result.returnCode));
+  }
+  return decodeResults(outputs, (result.returnData || []).map(value => fromBase64(value || '')));
+}
+
+/** Decode an event log given as base64 topics and data, as nodes return them */
+export function decodeLog(topics: string[], data: string[]): { identifier: string; fields: unknown } {
+  const identifier = text(fromBase64(topics[0] || ''));
+  const inputs = Object.prototype.hasOwnProperty.call(EVENTS, identifier)
+    ? EVENTS[identifier]
+    : undefined;
+  if (!inputs) throw new Error('Unknown event ' + identifier);
+  const indexed = inputs.filter(input => input.indexed);
+  const other = inputs.filter(input => !input.indexed);
+  const fields: Record<string, unknown> = {};
+  indexed.forEach((input, i) => {
+    fields[input.name] = decodeTopLevel(input.type, fromBase64(topics[i + 1] || ''));
+  });
+  other.forEach((input, i) => {
+    fields[input.name] = decodeTopLevel(input.type, fromBase64(data[i] || ''));
+  });
+  return { identifier, fields };
+}
+`;
@@ -0,0 +1,97 @@
+import { parseAbi } from '../abi/index.js';
+import { generateTsClient } from './ts-client.js';
+
+const ABI = parseAbi({
+  name: 'token_vault',
+  endpoints: [
+    {
+      name: 'deposit',
+      mutability: 'mutable',
+      payableInTokens: ['KLV'],
+      inputs: [{ name: 'lock_until', type: 'optional<u64>' }],
+      outputs: [],
+    },
+    {
+      name: 'getPosition',
+      docs: ['Position of an account'],
+      mutability: 'readonly',
+      inputs: [{ name: 'owner', type: 'Address' }],
+      outputs: [{ type: 'Option<Position>' }],
+    },
+    {
+      name: 'delete',
+      mutability: 'mutable',
+      onlyOwner: true,
+      inputs: [{ name: 'accounts', type: 'variadic<Address>' }],
+      outputs: [],
+    },
+  ],
+  events: [
+    {
+      identifier: 'deposit',
+      inputs: [
+        { name: 'caller', type: 'Address', indexed: true },
+        { name: 'amount', type: 'BigUint' },
+      ],
+    },
+  ],
+  types: {
+    Position: {
+      type: 'struct',
+      fields: [
+        { name: 'amount', type: 'BigUint' },
+        { name: 'status', type: 'Status' },
+      ],
+    },
+    Status: {
+      type: 'enum',
+      variants: [
+        { name: 'Open', discriminant: 0 },
+        { name: 'Locked', discriminant: 1, fields: [{ name: 'until', type: 'u64' }] },
+      ],
+    },
+  },
+});
+
+describe('generateTsClient', () => {
+  const client = generateTsClient(ABI, {
+    nodeUrl: 'https://node.testnet.klever.org',
+    address: 'klv1qqrsu9guyv4rzwplgex4gkmzd9c8wl593jfe4gdg47mtm3xt6tvs6kad8h',
+  });
+
+  it('declares the custom types and events with TypeScript types', () => {
+    expect(client.path).toBe('src/contracts/token-vault.ts');
+    expect(client.content).toContain('export interface Position {\n  amount: bigint;\n');
+    expect(client.content).toContain("  | 'Open'\n  | { Locked: { until: bigint } };");
+    expect(client.content).toContain(
+      "export interface DepositEvent {\n  identifier: 'deposit';\n  fields: { caller: string; amount: bigint };"
+    );
+    expect(client.content).toContain('export function decodeTokenVaultEvent(');
+  });
+
+  it('renders a method per view and endpoint with encoded arguments', () => {
+    expect(client.className).toBe('TokenVaultClient');
+    expect(client.views).toEqual(['getPosition']);
+    expect(client.endpoints).toEqual(['deposit', 'delete']);
+    expect(client.content).toContain(
+      "async getPosition(owner: string): Promise<Position | null> {\n    const results = await queryView(this, 'getPosition', ['Address'], [owner], ['Option<Position>']);"
+    );
+    expect(client.content).toContain(
+      "async deposit(lockUntil?: Numeric, options: CallOptions = {}): Promise<string> {\n    return sendCall(this, 'deposit', ['optional<u64>'], [lockUntil], options);"
+    );
+    // Reserved words get a suffix
+    expect(client.content).toContain(
+      '  /** Only the contract owner may call it. */\n  async delete_(accounts: Array<string>,'
+    );
+    expect(client.content).toContain(
+      "readonly address: string = 'klv1qqrsu9guyv4rzwplgex4gkmzd9c8wl593jfe4gdg47mtm3xt6tvs6kad8h'"
+    );
+  });
+
+  it('embeds the ABI data the codec needs', () => {
+    expect(client.content).toContain('const TYPES: Record<string, TypeDef> = {');
+    expect(client.content).toContain('"indexed": true');
+    expect(client.content).toContain('export function encodeArgs(');
+    expect(client.content).not.toContain('${');
+  });
+});
@@ -0,0 +1,346 @@
+/**
+ * Generate a typed TypeScript client for a contract from its ABI: interfaces
+ * for its structs, enums and events, and a class with one method per view
+ * (queried on a node) and per endpoint (sent through the Klever Extension
+ * with @klever/sdk-web). The generated module carries its own ABI codec.
+ */
+
+import type { Abi, AbiEndpoint, AbiInput, AbiTypeDef } from '../abi/index.js';
+import { parseAbiType } from './abi-values.js';
+import { camelToSnake, snakeToCamel } from './rust-source.js';
+import { TS_CLIENT_RUNTIME } from './ts-client-runtime.js';
+
+export interface TsClientOptions {
+  /** Node URL views are queried on, the default of the client's constructor */
+  nodeUrl: string;
+  /** Contract address (klv1...) the client targets by default */
+  address?: string;
+}
+
+export interface GeneratedTsClient {
+  /** Module file, relative to the frontend project root */
+  path: string;
+  content: string;
+  className: string;
+  views: string[];
+  endpoints: string[];
+  events: string[];
+  /** Custom types declared as TypeScript types */
+  types: string[];
+}
+
+type Direction = 'input' | 'output';
+
+const SMALL_INTEGERS = ['u8', 'u16', 'u32', 'usize', 'i8', 'i16', 'i32', 'isize'];
+
+const RESERVED = new Set(
+  (
+    'break case catch class const continue debugger default delete do else enum export extends ' +
+    'false finally for function if import in instanceof new null return super switch this throw ' +
+    'true try typeof var void while with yield let static implements interface package private ' +
+    'protected public await'
+  ).split(' ')
+);
+
+function quote(text: string): string {
+  return `'${text.replace(/\\/g, '\\\\').replace(/'/g, "\\'")}'`;
+}
+
+/** Object key as written in a type or literal: quoted unless it is an identifier */
+function property(name: string): string {
+  return /^[A-Za-z_$][\w$]*$/.test(name) ? name : quote(name);
+}
+
+/** A valid, non-reserved identifier for an ABI name, unlike any of `taken` */
+function identifier(name: string, taken: Iterable<string> = []): string {
+  let id = snakeToCamel(name).replace(/[^\w$]/g, '_');
+  if (/^\d/.test(id)) id = `_${id}`;
+  const used = new Set(taken);
+  while (RESERVED.has(id) || used.has(id)) id = `${id}_`;
+  return id;
+}
+
+function pascalCase(name: string): string {
+  const camel = snakeToCamel(name.replace(/[-\s]+/g, '_'));
+  return camel.charAt(0).toUpperCase() + camel.slice(1);
+}
+
+function docComment(docs: string[], indent: string): string[] {
+  const lines = docs.map(d => d.trim().replace(/\*\//g, '*\\/')).filter(Boolean);
+  if (lines.length === 0) return [];
+  if (lines.length === 1) return [`${indent}/** ${lines[0]} */`];
+  return [`${indent}/**`, ...lines.map(line => `${indent} * ${line}`), `${indent} */`];
+}
+
+/**
+ * TypeScript type of ABI `type`. Inputs take integers beyond 32 bits as
+ * `Numeric` and bytes as text too; outputs are what the codec decodes.
+ */
+function tsType(type: string, direction: Direction, abi: Abi): string {
+  const { name, args } = parseAbiType(type);
+  const inner = (arg: string) => tsType(arg, direction, abi);
+  if (SMALL_INTEGERS.includes(name)) return 'number';
+  switch (name) {
+    case 'u64':
+    case 'i64':
+    case 'BigUint':
+    case 'BigInt':
+      return direction === 'input' ? 'Numeric' : 'bigint';
+    case 'bool':
+      return 'boolean';
+    case 'Address':
+    case 'utf8string':
+    case 'TokenIdentifier':
+      return 'string';
+    case 'bytes':
+      return direction === 'input' ? 'Uint8Array | string' : 'Uint8Array';
+    case 'Option':
+    case 'optional':
+      return `${inner(args[0])} | null`;
+    case 'List':
+    case 'variadic':
+      return `Array<${inner(args[0])}>`;
+    case 'tuple':
+    case 'multi':
+      return `[${args.map(inner).join(', ')}]`;
+  }
+  if (/^array\d+$/.test(name)) {
+    return args[0] === 'u8' ? tsType('bytes', direction, abi) : `Array<${inner(args[0])}>`;
+  }
+  return abi.types && Object.hasOwn(abi.types, name) ? name : 'unknown';
+}
+
+function renderType(name: string, def: AbiTypeDef, abi: Abi): string {
+  const lines = docComment(def.docs || [], '');
+  const fieldList = (fields: { name: string; type: string }[]) =>
+    fields.map(f => `${property(f.name)}: ${tsType(f.type, 'output', abi)}`);
+  if (def.type === 'struct') {
+    lines.push(
+      `export interface ${name} {`,
+      ...fieldList(def.fields).map(field => `  ${field};`),
+      '}'
+    );
+    return lines.join('\n');
+  }
+  // Enum values are the variant name, or { Variant: fields } for variants with fields
+  const variants = def.variants.map(v =>
+    v.fields && v.fields.length > 0
+      ? `{ ${property(v.name)}: { ${fieldList(v.fields).join('; ')} } }`
+      : quote(v.name)
+  );
+  lines.push(`export type ${name} =`, ...variants.map(v => `  | ${v}`));
+  lines[lines.length - 1] += ';';
+  return lines.join('\n');
+}
+
+function renderParams(inputs: AbiInput[], abi: Abi, reserved: string[]): string[] {
+  const names: string[] = [];
+  return inputs.map((input, index) => {
+    const name = identifier(input.name, [...reserved, ...names]);
+    names.push(name);
+    const { name: kind } = parseAbiType(input.type);
+    // Trailing optional arguments may be left out
+    const trailing = inputs.slice(index).every(i => parseAbiType(i.type).name === 'optional');
+    if (kind === 'optional') {
+      const type = tsType(parseAbiType(input.type).args[0], 'input', abi);
+      return trailing ? `${name}?: ${type}` : `${name}: ${type} | undefined`;
+    }
+    return `${name}: ${tsType(input.type, 'input', abi)}`;
+  });
+}
+
+const paramName = (param: string) => param.split(/\??:/)[0];
+
+const typeList = (types: { type: string }[]) => `[${types.map(t => quote(t.type)).join(', ')}]`;
+
+function endpointDocs(endpoint: AbiEndpoint): string[] {
+  const docs = [...(endpoint.docs || [])];
+  const tokens = endpoint.payableInTokens || [];
+  if (tokens.length > 0) {
+    docs.push(`Payable in ${tokens.includes('*') ? 'any token' : tokens.join(', ')}.`);
+  }
+  if (endpoint.onlyOwner) docs.push('Only the contract owner may call it.');
+  return docComment(docs, '  ');
+}
+
+function renderView(endpoint: AbiEndpoint, method: string, abi: Abi): string {
+  const params = renderParams(endpoint.inputs, abi, []);
+  const outputs = endpoint.outputs.map(o => tsType(o.type, 'output', abi));
+  const returnType =
+    outputs.length === 0 ? 'void' : outputs.length === 1 ? outputs[0] : `[${outputs.join(', ')}]`;
+  const values = `[${params.map(paramName).join(', ')}]`;
+  const [inputs, results] = [typeList(endpoint.inputs), typeList(endpoint.outputs)];
+  const query = `queryView(this, ${quote(endpoint.name)}, ${inputs}, ${values}, ${results})`;
+  const body =
+    outputs.length === 0
+      ? [`    await ${query};`]
+      : [
+          `    const results = await ${query};`,
+          `    return ${outputs.length === 1 ? 'results[0]' : 'results'} as ${returnType};`,
+        ];
+  return [
+    ...endpointDocs(endpoint),
+    `  async ${method}(${params.join(', ')}): Promise<${returnType}> {`,
+    ...body,
+    '  }',
+  ].join('\n');
+}
+
+function renderEndpoint(endpoint: AbiEndpoint, method: string, abi: Abi): string {
+  const params = renderParams(endpoint.inputs, abi, ['options']);
+  const values = `[${params.map(paramName).join(', ')}]`;
+  const signature = [...params, 'options: CallOptions = {}'].join(', ');
+  const types = typeList(endpoint.inputs);
+  return [
+    ...endpointDocs(endpoint),
+    `  async ${method}(${signature}): Promise<string> {`,
+    `    return sendCall(this, ${quote(endpoint.name)}, ${types}, ${values}, options);`,
+    '  }',
+  ].join('\n');
+}
+
+/** Render the client module for `abi` */
+export function generateTsClient(abi: Abi, options: TsClientOptions): GeneratedTsClient {
+  const className = `${pascalCase(abi.name)}Client`;
+  const definitions = abi.types || {};
+  const types = Object.keys(definitions);
+  const events = abi.events || [];
+  const views = abi.endpoints.filter(e => e.mutability === 'readonly');
+  const endpoints = abi.endpoints.filter(e => e.mutability !== 'readonly');
+
+  const methods: string[] = [];
+  const members = ['address', 'nodeUrl', 'constructor'];
+  for (const endpoint of abi.endpoints) {
+    const method = identifier(endpoint.name, members);
+    members.push(method);
+    methods.push(
+      endpoint.mutability === 'readonly'
+        ? renderView(endpoint, method, abi)
+        : renderEndpoint(endpoint, method, abi)
+    );
+  }
+
+  const eventTypes = events.map(event => `${pascalCase(event.identifier)}Event`);
+  const eventSection = events.map((event, i) => {
+    const fields = event.inputs.map(
+      input => `${property(input.name)}: ${tsType(input.type, 'output', abi)}`
+    );
+    return [
+      ...docComment(event.docs || [], ''),
+      `export interface ${eventTypes[i]} {`,
+      `  identifier: ${quote(event.identifier)};`,
+      `  fields: ${fields.length > 0 ? `{ ${fields.join('; ')} }` : 'Record<string, never>'};`,
+      '}',
+    ].join('\n');
+  });
+  if (events.length > 0) {
+    const union = `${pascalCase(abi.name)}Event`;
+    eventSection.push(
+      `export type ${union} = ${eventTypes.join(' | ')};`,
+      [
+        '/** Decode an event log of the contract from its base64 topics and data */',
+        `export function decode${union}(topics: string[], data: string[]): ${union} {`,
+        `  return decodeLog(topics, data) as ${union};`,
+        '}',
+      ].join('\n')
+    );
+  }
+
+  const address = options.address ? quote(options.address) : undefined;
+  const classSection = [
+    ...docComment(
+      [
+        `Client of the ${abi.name} contract. Views are queried on \`nodeUrl\`; endpoints are`,
+        'sent as transactions signed in the Klever Extension and resolve with the',
+        'transaction hash.',
+      ],
+      ''
+    ),
+    `export class ${className} implements ContractClient {`,
+    '  constructor(',
+    `    readonly address: string${address ? ` = ${address}` : ''},`,
+    `    readonly nodeUrl: string = ${quote(options.nodeUrl)}`,
+    '  ) {}',
+    ...(methods.length > 0 ? ['', methods.join('\n\n')] : []),
+    '}',
+  ].join('\n');
+
+  // The codec only needs names and types; docs stay in the declarations above
+  const typeData = Object.fromEntries(
+    types.map(typeName => {
+      const def = definitions[typeName];
+      return [
+        typeName,
+        def.type === 'struct'
+          ? { type: 'struct', fields: def.fields.map(({ name, type }) => ({ name, type })) }
+          : {
+              type: 'enum',
+              variants: def.variants.map(({ name, discriminant, fields }) => ({
+                name,
+                discriminant,
+                ...(fields && fields.length > 0
+                  ? { fields: fields.map(({ name, type }) => ({ name, type })) }
+                  : {}),
+              })),
+            },
+      ];
+    })
+  );
+  const eventData = Object.fromEntries(
+    events.map(event => [
+      event.identifier,
+      event.inputs.map(({ name, type, indexed }) => ({
+        name,
+        type,
+        ...(indexed ? { indexed } : {}),
+      })),
+    ])
+  );
+
+  const sections = [
+    [
+      '/**',
+      ` * Typed client of the ${abi.name} contract, generated from its ABI. Regenerate it`,
+      ' * when the ABI changes instead of editing it.',
+      ' */',
+      '',
+      "import { web, TransactionType } from '@klever/sdk-web';",
+    ].join('\n'),
+    [
+      '/** Integer arguments: a bigint, a safe-integer number or a decimal string */',
+      'export type Numeric = bigint | number | string;',
+      '',
+      'export interface CallOptions {',
+      '  /** Tokens sent with the call, in their smallest units, e.g. { KLV: 1000000 } */',
+      '  callValue?: Record<string, number>;',
+      '}',
+      '',
+      'export interface ContractClient {',
+      '  address: string;',
+      '  nodeUrl: string;',
+      '}',
+    ].join('\n'),
+    ...types.map(name => renderType(name, definitions[name], abi)),
+    ...eventSection,
+    classSection,
+    [
+      '// ─── ABI data ───────────────────────────────────────────────',
+      '',
+      `const TYPES: Record<string, TypeDef> = ${JSON.stringify(typeData, null, 2)};`,
+      '',
+      'const EVENTS: Record<string, { name: string; type: string; indexed?: boolean }[]> =',
+      `  ${JSON.stringify(eventData, null, 2).replace(/\n/g, '\n  ')};`,
+    ].join('\n'),
+    TS_CLIENT_RUNTIME.trimEnd(),
+  ];
+
+  return {
+    path: `src/contracts/${camelToSnake(abi.name).replace(/_/g, '-')}.ts`,
+    content: `${sections.join('\n\n')}\n`,
+    className,
+    views: views.map(e => e.name),
+    endpoints: endpoints.map(e => e.name),
+    events: events.map(e => e.identifier),
+    types,
+  };
+}
@@ -0,0 +1,76 @@
+import { addUpgradeSupport } from './upgrade.js';
+import { findFunctionWithAttribute, listTraitFunctions } from './rust-source.js';
+import { generateContractProject } from '../scaffold/index.js';
+
+const TOKEN = `#![no_std]
+
+use klever_sc::imports::*;
+
+/// Simple counter
+#[klever_sc::contract]
+pub trait Counter {
+    #[init]
+    fn init(&self, start: BigUint) {
+        self.count().set(start);
+    }
+
+    #[endpoint]
+    fn increment(&self) {
+        self.count().update(|c| *c += 1u32);
+    }
+
+    #[view(getCount)]
+    #[storage_mapper("count")]
+    fn count(&self) -> SingleValueMapper<BigUint>;
+}
+`;
+
+describe('rust-source', () => {
+  it('lists trait functions with their attributes', () => {
+    const fns = listTraitFunctions(TOKEN);
+    expect(fns.map(f => f.name)).toEqual(['init', 'increment', 'count']);
+    expect(fns[2].attributes).toEqual(['#[view(getCount)]', '#[storage_mapper("count")]']);
+    expect(fns[2].bodyStart).toBe(-1);
+    expect(findFunctionWithAttribute(TOKEN, 'init')?.name).toBe('init');
+  });
+});
+
+describe('addUpgradeSupport', () => {
+  it('adds schema version, upgrade endpoint and migrate skeleton', () => {
+    const { source, changes } = addUpgradeSupport(TOKEN);
+
+    expect(source).toContain('pub const SCHEMA_VERSION: u32 = 1;\n\n/// Simple counter\n');
+    expect(source).toContain(
+      '        self.count().set(start);\n        self.schema_version().set(SCHEMA_VERSION);\n    }'
+    );
+    expect(source).toContain('#[upgrade]\n    fn upgrade(&self) {\n        self.migrate();\n    }');
+    expect(source).toContain('fn schema_version(&self) -> SingleValueMapper<u32>;');
+    expect(source).toContain('if current < 1 {');
+    expect(changes).toContain('Added #[upgrade] endpoint calling migrate()');
+  });
+
+  it('calls migrate from an existing upgrade endpoint', () => {
+    const lib = generateContractProject('adder', 'counter').files['src/lib.rs'];
+    const { source } = addUpgradeSupport(lib, 2);
+
+    const upgrade = findFunctionWithAttribute(source, 'upgrade');
+    expect(source.slice(upgrade!.bodyStart, upgrade!.end)).toBe('\n        self.migrate();\n    ');
+    expect(source).toContain('if current < 2 {');
+    expect(source.match(/#\[upgrade\]/g)).toHaveLength(1);
+  });
+
+  it('bumps an already versioned contract', () => {
+    const v1 = addUpgradeSupport(TOKEN).source;
+    const v3 = addUpgradeSupport(v1, 3);
+
+    expect(v3.schemaVersion).toBe(3);
+    expect(v3.source).toContain('pub const SCHEMA_VERSION: u32 = 3;');
+    expect(v3.source).toContain('if current < 2 {');
+    expect(v3.source).toContain('if current < 3 {');
+    expect(() => addUpgradeSupport(v1, 1)).toThrow('already at schema version 1');
+  });
+
+  it('rejects sources without a contract trait', () => {
+    expect(() => addUpgradeSupport('fn main() {}')).toThrow('#[klever_sc::contract]');
+  });
+});
@@ -0,0 +1,139 @@
+/**
+ * Add an upgrade story to an existing contract: versioned storage, an
+ * #[upgrade] endpoint and a guarded, step-wise migration skeleton.
+ */
+
+import {
+  appendStatement,
+  appendToTrait,
+  findContractTrait,
+  findFunctionWithAttribute,
+  findTraitFunction,
+  insertAt,
+  prependStatement,
+} from './rust-source.js';
+
+export interface UpgradeSupportResult {
+  source: string;
+  schemaVersion: number;
+  changes: string[];
+}
+
+const VERSION_CONST = /pub\s+const\s+SCHEMA_VERSION\s*:\s*u32\s*=\s*(\d+)\s*;/;
+const MIGRATION_END =
+  '        self.schema_version().set(SCHEMA_VERSION);\n        self.schema_migrated_event';
+
+function migrationStep(version: number): string {
+  return `        if current < ${version} {
+            // TODO: transform storage written by schema v${version - 1} into v${version}
+        }
+`;
+}
+
+function migrationBlock(targetVersion: number): string {
+  const steps = Array.from({ length: targetVersion }, (_, i) => migrationStep(i + 1)).join('');
+  return `    // Upgrade support
+
+    /// Bring storage from the stored schema version up to SCHEMA_VERSION.
+    /// Every step runs once, in order; upgrading to an older schema is rejected.
+    fn migrate(&self) {
+        let current = self.schema_version().get();
+        require!(current <= SCHEMA_VERSION, "Cannot downgrade storage schema");
+        if current == SCHEMA_VERSION {
+            return;
+        }
+
+${steps}
+${MIGRATION_END}(current, SCHEMA_VERSION);
+    }
+
+    #[view(getSchemaVersion)]
+    #[storage_mapper("schemaVersion")]
+    fn schema_version(&self) -> SingleValueMapper<u32>;
+
+    #[event("schemaMigrated")]
+    fn schema_migrated_event(&self, #[indexed] from_version: u32, #[indexed] to_version: u32);
+`;
+}
+
+/** Bump an already-versioned contract and add the missing migration steps */
+function bumpSchemaVersion(source: string, existing: number, targetVersion: number) {
+  if (targetVersion <= existing) {
+    throw new Error(
+      `Contract is already at schema version ${existing}; targetVersion must be greater.`
+    );
+  }
+  const markerIndex = source.indexOf(MIGRATION_END);
+  if (markerIndex === -1) {
+    throw new Error('SCHEMA_VERSION exists but the generated migrate() function was not found.');
+  }
+
+  let steps = '';
+  for (let version = existing + 1; version <= targetVersion; version++) {
+    steps += migrationStep(version);
+  }
+  // Insert after the last existing step, keeping the blank line before the version bump
+  let updated = insertAt(source, markerIndex - 1, steps);
+  updated = updated.replace(VERSION_CONST, `pub const SCHEMA_VERSION: u32 = ${targetVersion};`);
+  return {
+    source: updated,
+    schemaVersion: targetVersion,
+    changes: [
+      `Bumped SCHEMA_VERSION from ${existing} to ${targetVersion}`,
+      `Added migration step(s) for v${existing + 1}..v${targetVersion} in migrate()`,
+    ],
+  };
+}
+
+/**
+ * Augment contract source with schema-versioned upgrade support
+ */
+export function addUpgradeSupport(source: string, targetVersion = 1): UpgradeSupportResult {
+  if (!Number.isInteger(targetVersion) || targetVersion < 1) {
+    throw new Error('targetVersion must be a positive integer.');
+  }
+
+  const existing = VERSION_CONST.exec(source);
+  if (existing) {
+    return bumpSchemaVersion(source, Number(existing[1]), targetVersion);
+  }
+  if (findTraitFunction(source, 'migrate') || findTraitFunction(source, 'schema_version')) {
+    throw new Error('Contract already defines migrate() or schema_version(); remove them first.');
+  }
+
+  const changes: string[] = [];
+  let updated = appendToTrait(source, migrationBlock(targetVersion));
+  changes.push('Added schema_version storage, migrate() skeleton and schemaMigrated event');
+
+  // Fresh deployments start at the current schema
+  const init = findFunctionWithAttribute(updated, 'init');
+  if (!init || init.bodyStart === -1) {
+    throw new Error('Contract has no #[init] function.');
+  }
+  updated = appendStatement(updated, init, 'self.schema_version().set(SCHEMA_VERSION);');
+  changes.push('init() now records SCHEMA_VERSION');
+
+  const upgrade = findFunctionWithAttribute(updated, 'upgrade');
+  if (upgrade && upgrade.bodyStart !== -1) {
+    updated = prependStatement(updated, upgrade, 'self.migrate();');
+    changes.push('Existing upgrade() now calls migrate() first');
+  } else {
+    const afterInit = findFunctionWithAttribute(updated, 'init')!.end + 1;
+    updated = insertAt(
+      updated,
+      afterInit,
+      `\n\n    #[upgrade]\n    fn upgrade(&self) {\n        self.migrate();\n    }`
+    );
+    changes.push('Added #[upgrade] endpoint calling migrate()');
+  }
+
+  const { itemStart } = findContractTrait(updated);
+  const versionConst = [
+    '/// Storage layout version; bump it and add a migrate() step for every breaking change',
+    `pub const SCHEMA_VERSION: u32 = ${targetVersion};`,
+  ].join('\n');
+  updated = insertAt(updated, itemStart, `${versionConst}\n\n`);
+  changes.push(`Added SCHEMA_VERSION = ${targetVersion}`);
+
+  return { source: updated, schemaVersion: targetVersion, changes };
+}
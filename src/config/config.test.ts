@@ -0,0 +1,164 @@
+import { mkdtemp, rm, writeFile } from 'node:fs/promises';
+import { homedir, tmpdir } from 'node:os';
+import { join } from 'node:path';
+import {
+  parseConfigFile,
+  readConfigFile,
+  resolveConfig,
+  toolEnabled,
+  validateConfig,
+} from './config.js';
+
+const READER = { name: 'ci-reader', key: 'reader-key-0123456789', readOnly: true };
+
+describe('parseConfigFile', () => {
+  it('reads snake_case TOML settings', () => {
+    const config = parseConfigFile(
+      [
+        '[network]',
+        'default = "testnet"',
+        'timeout_ms = 5000',
+        '[tools]',
+        'disabled = ["request_test_funds"]',
+        '[[rate_limits]]',
+        'tools = ["get_balance"]',
+        'limit = 10',
+        'window_seconds = 60',
+        '[[api_keys]]',
+        'name = "ci-reader"',
+        'key = "reader-key-0123456789"',
+        'read_only = true',
+        'networks = ["localnet"]',
+      ].join('\n'),
+      '/etc/klever/mcp-klever-vm.toml'
+    );
+    expect(config.network).toEqual({ default: 'testnet', timeoutMs: 5000 });
+    expect(config.tools).toEqual({ disabled: ['request_test_funds'] });
+    expect(config.rateLimits).toEqual([
+      { tools: ['get_balance'], limit: 10, windowSeconds: 60, per: 'session' },
+    ]);
+    expect(config.apiKeys).toEqual([{ ...READER, networks: ['local'] }]);
+  });
+
+  it('resolves paths against the file and the home directory', () => {
+    const toml = '[keys]\nwallet = "keys/wallet.pem"\nsdk_dir = "~/sdk"';
+    const config = parseConfigFile(toml, '/etc/klever/mcp-klever-vm.toml');
+    expect(config.keys).toEqual({
+      wallet: '/etc/klever/keys/wallet.pem',
+      sdkDir: join(homedir(), 'sdk'),
+    });
+  });
+
+  it('rejects unknown settings, unknown networks and duplicate API keys', () => {
+    expect(() => parseConfigFile('[network]\nnode = "x"')).toThrow();
+    const json = (config: unknown) => parseConfigFile(JSON.stringify(config), 'config.json');
+    expect(() => json({ apiKeys: [{ ...READER, networks: ['moon'] }] })).toThrow(
+      'API key "ci-reader": Invalid network "moon"'
+    );
+    expect(() => json({ apiKeys: [READER, READER] })).toThrow('Duplicate API key');
+  });
+});
+
+describe('resolveConfig', () => {
+  it('applies environment overrides over the file, then defaults', () => {
+    const file = parseConfigFile('[network]\ndefault = "testnet"\n[cache]\nresponses = true');
+    const { config, sources } = resolveConfig(file, {
+      KLEVER_NETWORK: 'devnet',
+      KLEVER_CACHE: 'off',
+      KLEVER_SDK_PATH: '/opt/klever-sdk',
+    });
+    expect(config.network.default).toBe('devnet');
+    expect(config.cache.responses).toBe(false);
+    expect(config.keys).toEqual({
+      sdkDir: '/opt/klever-sdk',
+      ksc: '/opt/klever-sdk/ksc',
+      wallet: '/opt/klever-sdk/walletKey.pem',
+    });
+    expect(config.network.timeoutMs).toBe(15000);
+    expect(sources).toMatchObject({
+      'network.default': 'env',
+      'keys.sdkDir': 'env',
+      'keys.ksc': 'default',
+      'network.timeoutMs': 'default',
+    });
+  });
+
+  it('reads local network, docs and logging settings from the file or the environment', () => {
+    const file = parseConfigFile(
+      '[localnet]\nimage = "klever-go:dev"\n[logging]\nformat = "json"\nbuffer_size = 200',
+      '/etc/klever/mcp-klever-vm.toml'
+    );
+    expect(resolveConfig(file, {}).config).toMatchObject({
+      localnet: { image: 'klever-go:dev' },
+      logging: { level: 'info', format: 'json', bufferSize: 200 },
+    });
+    const { config, sources } = resolveConfig(file, {
+      KLEVER_NODE_BIN: '/opt/klever/node',
+      KLEVER_SC_DOCS_URL: 'https://docs.example/klever-sc',
+      LOG_LEVEL: 'debug',
+      LOG_BUFFER_SIZE: '50',
+    });
+    expect(config.localnet).toEqual({ image: 'klever-go:dev', nodeBinary: '/opt/klever/node' });
+    expect(config.cache.docsUrl).toBe('https://docs.example/klever-sc');
+    expect(config.logging).toEqual({ level: 'debug', format: 'json', bufferSize: 50 });
+    expect(sources).toMatchObject({
+      'localnet.image': 'file',
+      'logging.level': 'env',
+      'logging.bufferSize': 'env',
+    });
+    expect(resolveConfig(undefined, {}).config.logging.bufferSize).toBe(1000);
+  });
+
+  it('turns on read-only mode from the file or the environment', () => {
+    expect(resolveConfig().config.tools.readOnly).toBe(false);
+    const file = parseConfigFile('[tools]\nread_only = true');
+    expect(resolveConfig(file, {}).config.tools).toEqual({ disabled: [], readOnly: true });
+    expect(resolveConfig(undefined, { KLEVER_READ_ONLY: 'true' }).config.tools.readOnly).toBe(true);
+  });
+});
+
+describe('toolEnabled', () => {
+  it('offers enabled tools unless disabled', () => {
+    expect(toolEnabled({ disabled: [] }, 'deploy_sc')).toBe(true);
+    expect(toolEnabled({ enabled: ['get_balance'], disabled: [] }, 'deploy_sc')).toBe(false);
+    expect(toolEnabled({ enabled: ['*'], disabled: ['deploy_sc'] }, 'deploy_sc')).toBe(false);
+  });
+});
+
+describe('readConfigFile and validateConfig', () => {
+  let dir: string;
+
+  beforeEach(async () => {
+    dir = await mkdtemp(join(tmpdir(), 'klever-config-'));
+  });
+
+  afterEach(async () => {
+    await rm(dir, { recursive: true, force: true });
+  });
+
+  it('names the file and setting of schema errors', async () => {
+    const path = join(dir, 'mcp-klever-vm.toml');
+    await writeFile(path, '[network]\ntimeout_ms = "soon"');
+    await expect(readConfigFile(path)).rejects.toThrow(
+      new RegExp(`^Invalid config ${path}: network\\.timeoutMs: `)
+    );
+  });
+
+  it('reports errors and warnings', async () => {
+    const path = join(dir, 'mcp-klever-vm.toml');
+    await writeFile(path, `[keys]\nwallet = "missing.pem"\n[network]\nnode_url = "not a url"`);
+    const result = await validateConfig(path, { KLEVER_NETWORK: 'moon' });
+    expect(result.valid).toBe(false);
+    expect(result.errors).toEqual([
+      expect.stringContaining('network.default (env): Invalid network "moon"'),
+      'network.nodeUrl is not a URL: not a url',
+    ]);
+    expect(result.warnings).toEqual([`keys.wallet does not exist: ${join(dir, 'missing.pem')}`]);
+  });
+
+  it('accepts a missing file with a warning', async () => {
+    const result = await validateConfig(undefined, {});
+    expect(result.valid).toBe(true);
+    expect(result.warnings).toEqual([expect.stringContaining('No mcp-klever-vm.toml found')]);
+  });
+});
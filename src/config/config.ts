@@ -0,0 +1,402 @@
+/**
+ * Server configuration from `mcp-klever-vm.toml`: networks, key and toolchain
+ * paths, caches, the local network, logging, enabled tools (and read-only
+ * mode), rate and concurrency limits and API keys. Environment
+ * variables override the file's settings, so existing deployments keep
+ * working; modules read settings through `getConfig()` instead of
+ * `process.env`.
+ */
+
+import { existsSync } from 'node:fs';
+import { readFile } from 'node:fs/promises';
+import { homedir } from 'node:os';
+import { dirname, extname, isAbsolute, join, resolve } from 'node:path';
+import { z } from 'zod';
+import { validateNetwork } from '../chain/index.js';
+import { ConcurrencyRuleSchema, type ConcurrencyRule } from '../mcp/concurrency.js';
+import { RateLimitRuleSchema, type RateLimitRule } from '../mcp/rate-limit.js';
+import { ApiKeySchema, type ApiKey } from '../transport/api-keys.js';
+import { parseToml } from './toml.js';
+
+export const CONFIG_FILE_NAME = 'mcp-klever-vm.toml';
+
+export const ConfigFileSchema = z.strictObject({
+  network: z
+    .strictObject({
+      /** Network tools use when none is given */
+      default: z.string().optional(),
+      /** URLs and chain ID of the `custom` network, also used for `default` when set */
+      nodeUrl: z.string().optional(),
+      apiUrl: z.string().optional(),
+      chainId: z.string().optional(),
+      explorerUrl: z.string().optional(),
+      eventsUrl: z.string().optional(),
+      timeoutMs: z.number().int().positive().optional(),
+    })
+    .default({}),
+  keys: z
+    .strictObject({
+      /** Klever SDK directory (koperator, ksc, the default wallet key) */
+      sdkDir: z.string().optional(),
+      ksc: z.string().optional(),
+      /** Key file transactions are signed with when a tool is given none */
+      wallet: z.string().optional(),
+    })
+    .default({}),
+  cache: z
+    .strictObject({
+      /** Cache node and API responses */
+      responses: z.boolean().optional(),
+      /** Refreshed framework docs pages */
+      docsDir: z.string().optional(),
+      /** Base URL `refresh_docs` downloads `<topic>.md` pages from */
+      docsUrl: z.string().optional(),
+      /** Data of the local network */
+      localnetDir: z.string().optional(),
+    })
+    .default({}),
+  localnet: z
+    .strictObject({
+      /** Docker image of the local node */
+      image: z.string().optional(),
+      /** Node binary the local network runs in binary mode */
+      nodeBinary: z.string().optional(),
+    })
+    .default({}),
+  logging: z
+    .strictObject({
+      /** Minimum level written to stderr */
+      level: z.enum(['debug', 'info', 'warn', 'error']).optional(),
+      /** `json` for JSON lines instead of text */
+      format: z.enum(['text', 'json']).optional(),
+      /** Records kept for `get_recent_logs` */
+      bufferSize: z.number().int().positive().optional(),
+    })
+    .default({}),
+  tools: z
+    .strictObject({
+      /** Only these tools are offered; all when omitted */
+      enabled: z.array(z.string()).optional(),
+      disabled: z.array(z.string()).default([]),
+      /** Offer only read-only tools: none that signs, sends, spends funds or writes files */
+      readOnly: z.boolean().optional(),
+    })
+    .default({ disabled: [] }),
+  /** Replace the default rate limits; `[]` disables rate limiting */
+  rateLimits: z.array(RateLimitRuleSchema).optional(),
+  /** Replace the default concurrency limits of subprocess-heavy tools */
+  concurrencyLimits: z.array(ConcurrencyRuleSchema).optional(),
+  apiKeys: z.array(ApiKeySchema).default([]),
+});
+
+export type ConfigFile = z.infer<typeof ConfigFileSchema>;
+
+/** Settings with every default applied */
+export interface Config {
+  network: {
+    default?: string;
+    nodeUrl?: string;
+    apiUrl?: string;
+    chainId?: string;
+    explorerUrl?: string;
+    eventsUrl?: string;
+    timeoutMs: number;
+  };
+  keys: { sdkDir: string; ksc: string; wallet: string };
+  cache: { responses: boolean; docsDir: string; docsUrl?: string; localnetDir: string };
+  localnet: { image?: string; nodeBinary?: string };
+  logging: { level: string; format: string; bufferSize: number };
+  tools: { enabled?: string[]; disabled: string[]; readOnly: boolean };
+  rateLimits?: RateLimitRule[];
+  concurrencyLimits?: ConcurrencyRule[];
+  apiKeys: ApiKey[];
+}
+
+export type ConfigSource = 'file' | 'env' | 'default';
+
+export interface ResolvedConfig {
+  config: Config;
+  /** Config file the settings were read from; undefined when none was found */
+  file?: string;
+  /** Where each `section.setting` came from */
+  sources: Record<string, ConfigSource>;
+}
+
+interface EnvOverride {
+  env: string;
+  setting: string;
+  parse?: (value: string) => unknown;
+}
+
+/** Environment variables that override a setting of the file */
+export const ENV_OVERRIDES: EnvOverride[] = [
+  { env: 'KLEVER_NETWORK', setting: 'network.default' },
+  { env: 'KLEVER_NODE_URL', setting: 'network.nodeUrl' },
+  { env: 'KLEVER_API_URL', setting: 'network.apiUrl' },
+  { env: 'KLEVER_CHAIN_ID', setting: 'network.chainId' },
+  { env: 'KLEVER_EXPLORER_URL', setting: 'network.explorerUrl' },
+  { env: 'KLEVER_EVENTS_URL', setting: 'network.eventsUrl' },
+  { env: 'KLEVER_TIMEOUT', setting: 'network.timeoutMs', parse: Number },
+  { env: 'KLEVER_SDK_PATH', setting: 'keys.sdkDir' },
+  { env: 'KSC_BIN', setting: 'keys.ksc' },
+  { env: 'KLEVER_WALLET_KEY', setting: 'keys.wallet' },
+  { env: 'KLEVER_CACHE', setting: 'cache.responses', parse: value => value !== 'off' },
+  { env: 'KLEVER_DOCS_DIR', setting: 'cache.docsDir' },
+  { env: 'KLEVER_SC_DOCS_URL', setting: 'cache.docsUrl' },
+  { env: 'KLEVER_LOCALNET_DIR', setting: 'cache.localnetDir' },
+  { env: 'KLEVER_LOCALNET_IMAGE', setting: 'localnet.image' },
+  { env: 'KLEVER_NODE_BIN', setting: 'localnet.nodeBinary' },
+  { env: 'LOG_LEVEL', setting: 'logging.level' },
+  { env: 'LOG_FORMAT', setting: 'logging.format' },
+  { env: 'LOG_BUFFER_SIZE', setting: 'logging.bufferSize', parse: Number },
+  { env: 'KLEVER_READ_ONLY', setting: 'tools.readOnly', parse: value => /^(true|1)$/i.test(value) },
+];
+
+const PATH_SETTINGS = [
+  'keys.sdkDir',
+  'keys.ksc',
+  'keys.wallet',
+  'cache.docsDir',
+  'cache.localnetDir',
+  'localnet.nodeBinary',
+];
+
+type PathSection = 'keys' | 'cache' | 'localnet';
+
+const expandHome = (path: string) =>
+  path === '~' || path.startsWith('~/') ? join(homedir(), path.slice(1)) : path;
+
+const camelCase = (key: string) =>
+  key.replace(/_([a-z])/g, (_, char: string) => char.toUpperCase());
+
+/** TOML's snake_case keys as the camelCase the schema uses; values are left alone */
+function camelCaseKeys(value: unknown): unknown {
+  if (Array.isArray(value)) return value.map(camelCaseKeys);
+  if (typeof value !== 'object' || value === null) return value;
+  return Object.fromEntries(
+    Object.entries(value).map(([key, item]) => [camelCase(key), camelCaseKeys(item)])
+  );
+}
+
+/**
+ * Parse the contents of a config file, TOML or (for `.json` paths) JSON;
+ * throws on an invalid config rather than starting a server whose settings
+ * do not mean what they say.
+ */
+export function parseConfigFile(text: string, path = CONFIG_FILE_NAME): ConfigFile {
+  const raw = extname(path) === '.json' ? JSON.parse(text) : parseToml(text);
+  const config = ConfigFileSchema.parse(camelCaseKeys(raw));
+  const names = new Set<string>();
+  for (const apiKey of config.apiKeys) {
+    if (names.has(apiKey.name)) throw new Error(`Duplicate API key name "${apiKey.name}"`);
+    names.add(apiKey.name);
+    try {
+      apiKey.networks = apiKey.networks?.map(network => validateNetwork(network)!);
+    } catch (error) {
+      throw new Error(`API key "${apiKey.name}": ${(error as Error).message}`);
+    }
+  }
+  // Relative paths are relative to the file
+  for (const setting of PATH_SETTINGS) {
+    const [section, key] = setting.split('.');
+    const values = config[section as PathSection] as Record<string, string | undefined>;
+    const value = values[key];
+    if (value) values[key] = resolve(dirname(resolve(path)), expandHome(value));
+  }
+  return config;
+}
+
+/** KLEVER_MCP_CONFIG, else mcp-klever-vm.toml in the working or the ~/.klever-mcp directory */
+export function findConfigFile(env: NodeJS.ProcessEnv = process.env): string | undefined {
+  if (env.KLEVER_MCP_CONFIG) return env.KLEVER_MCP_CONFIG;
+  return [join(process.cwd(), CONFIG_FILE_NAME), join(homedir(), '.klever-mcp', CONFIG_FILE_NAME)]
+    .filter(path => existsSync(path))
+    .shift();
+}
+
+/** Read and parse a config file; prefixes errors with its path */
+export async function readConfigFile(path: string): Promise<ConfigFile> {
+  const text = await readFile(path, 'utf8');
+  try {
+    return parseConfigFile(text, path);
+  } catch (error) {
+    const message =
+      error instanceof z.ZodError
+        ? error.issues.map(issue => `${issue.path.join('.') || 'config'}: ${issue.message}`)
+        : [(error as Error).message];
+    throw new Error(`Invalid config ${path}: ${message.join('; ')}`);
+  }
+}
+
+/** The settings of `file` with environment overrides and defaults applied */
+export function resolveConfig(
+  file: ConfigFile = ConfigFileSchema.parse({}),
+  env: NodeJS.ProcessEnv = process.env,
+  path?: string
+): ResolvedConfig {
+  const sources: Record<string, ConfigSource> = {};
+  const sections = {
+    network: { ...file.network } as Record<string, unknown>,
+    keys: { ...file.keys } as Record<string, unknown>,
+    cache: { ...file.cache } as Record<string, unknown>,
+    localnet: { ...file.localnet } as Record<string, unknown>,
+    logging: { ...file.logging } as Record<string, unknown>,
+    tools: { ...file.tools } as Record<string, unknown>,
+  };
+  for (const [section, values] of Object.entries(sections)) {
+    for (const [key, value] of Object.entries(values)) {
+      if (value !== undefined) sources[`${section}.${key}`] = 'file';
+    }
+  }
+  for (const { env: name, setting, parse } of ENV_OVERRIDES) {
+    const value = env[name];
+    if (!value) continue;
+    const [section, key] = setting.split('.') as [keyof typeof sections, string];
+    sections[section][key] = parse ? parse(value) : value;
+    sources[setting] = 'env';
+  }
+  const defaults = (setting: string, value: unknown) => {
+    const [section, key] = setting.split('.') as [keyof typeof sections, string];
+    if (sections[section][key] !== undefined) return;
+    sections[section][key] = value;
+    sources[setting] = 'default';
+  };
+  defaults('network.timeoutMs', 15000);
+  defaults('keys.sdkDir', join(homedir(), 'klever-sdk'));
+  for (const setting of PATH_SETTINGS) {
+    const [section, key] = setting.split('.') as [keyof typeof sections, string];
+    const value = sections[section][key];
+    if (typeof value === 'string') sections[section][key] = expandHome(value);
+  }
+  const sdkDir = sections.keys.sdkDir as string;
+  defaults('keys.ksc', join(sdkDir, 'ksc'));
+  defaults('keys.wallet', join(sdkDir, 'walletKey.pem'));
+  defaults('cache.responses', true);
+  defaults('cache.docsDir', join(homedir(), '.klever-mcp', 'docs'));
+  defaults('cache.localnetDir', join(sdkDir, 'localnet'));
+  defaults('logging.level', 'info');
+  defaults('logging.format', 'text');
+  defaults('logging.bufferSize', 1000);
+  defaults('tools.readOnly', false);
+
+  return {
+    config: {
+      ...(sections as unknown as Omit<Config, 'rateLimits' | 'concurrencyLimits' | 'apiKeys'>),
+      rateLimits: file.rateLimits,
+      concurrencyLimits: file.concurrencyLimits,
+      apiKeys: file.apiKeys,
+    },
+    file: path,
+    sources,
+  };
+}
+
+let loaded: { file?: string; values: ConfigFile } = { values: ConfigFileSchema.parse({}) };
+let loads = 0;
+
+/**
+ * Load the config file (found by `findConfigFile` unless given) for
+ * `getConfig`; without a file only environment variables and defaults apply.
+ */
+export async function loadConfig(path = findConfigFile()): Promise<Config> {
+  loaded = { file: path, values: path ? await readConfigFile(path) : ConfigFileSchema.parse({}) };
+  loads++;
+  return getConfig();
+}
+
+/** How often `loadConfig` ran, for modules that resolve a setting once per load */
+export const configLoads = () => loads;
+
+/** The loaded settings with the current environment overrides */
+export function getConfig(): Config {
+  return resolveConfig(loaded.values, process.env, loaded.file).config;
+}
+
+/** The loaded settings, file and where each setting came from */
+export function describeConfig(): ResolvedConfig {
+  return resolveConfig(loaded.values, process.env, loaded.file);
+}
+
+/** Whether the `tools` settings offer the tool `name` */
+export function toolEnabled(
+  tools: Pick<Config['tools'], 'enabled' | 'disabled'>,
+  name: string
+): boolean {
+  if (tools.disabled.includes(name)) return false;
+  return !tools.enabled || tools.enabled.includes('*') || tools.enabled.includes(name);
+}
+
+export interface ConfigValidation {
+  file?: string;
+  valid: boolean;
+  errors: string[];
+  /** Settings that parse but will not work as meant, e.g. paths that do not exist */
+  warnings: string[];
+}
+
+/** Check a config file (the one `findConfigFile` finds by default) and the environment overrides */
+export async function validateConfig(
+  path = findConfigFile(),
+  env: NodeJS.ProcessEnv = process.env
+): Promise<ConfigValidation> {
+  const errors: string[] = [];
+  const warnings: string[] = [];
+  let file: ConfigFile | undefined;
+  if (path) {
+    try {
+      file = await readConfigFile(path);
+    } catch (error) {
+      errors.push((error as Error).message);
+    }
+  } else {
+    warnings.push(`No ${CONFIG_FILE_NAME} found; using environment variables and defaults`);
+  }
+
+  const { config, sources } = resolveConfig(file, env, path);
+  if (config.network.default) {
+    try {
+      validateNetwork(config.network.default);
+    } catch (error) {
+      errors.push(`network.default (${sources['network.default']}): ${(error as Error).message}`);
+    }
+  }
+  if (!Number.isInteger(config.network.timeoutMs) || config.network.timeoutMs <= 0) {
+    errors.push(`network.timeoutMs (${sources['network.timeoutMs']}) must be a positive integer`);
+  }
+  const { level, format, bufferSize } = config.logging;
+  if (!['debug', 'info', 'warn', 'error'].includes(level)) {
+    errors.push(`logging.level (${sources['logging.level']}) must be debug, info, warn or error`);
+  }
+  if (!['text', 'json'].includes(format)) {
+    errors.push(`logging.format (${sources['logging.format']}) must be text or json`);
+  }
+  if (!Number.isInteger(bufferSize) || bufferSize <= 0) {
+    errors.push(`logging.bufferSize (${sources['logging.bufferSize']}) must be a positive integer`);
+  }
+  for (const setting of ['nodeUrl', 'apiUrl', 'explorerUrl', 'eventsUrl'] as const) {
+    const url = config.network[setting];
+    if (!url) continue;
+    try {
+      new URL(url);
+    } catch {
+      errors.push(`network.${setting} is not a URL: ${url}`);
+    }
+  }
+  for (const setting of PATH_SETTINGS) {
+    const [section, key] = setting.split('.');
+    const value = (config[section as PathSection] as Record<string, string | undefined>)[key];
+    if (!value) continue;
+    const explicit = sources[setting] !== 'default';
+    if (!isAbsolute(value)) {
+      warnings.push(`${setting} is relative to the working directory: ${value}`);
+    } else if (explicit && !existsSync(value)) {
+      warnings.push(`${setting} does not exist: ${value}`);
+    }
+  }
+  const both = config.tools.disabled.filter(name => config.tools.enabled?.includes(name));
+  if (both.length > 0) {
+    warnings.push(`Tools both enabled and disabled (disabled wins): ${both.join(', ')}`);
+  }
+
+  return { file: path, valid: errors.length === 0, errors, warnings };
+}
@@ -0,0 +1,7 @@
+/**
+ * Server configuration: the `mcp-klever-vm.toml` file with environment
+ * variable overrides.
+ */
+
+export * from './config.js';
+export * from './toml.js';
@@ -0,0 +1,63 @@
+import { parseToml, TomlError } from './toml.js';
+
+describe('parseToml', () => {
+  it('parses tables, dotted keys and arrays of tables', () => {
+    const toml = `
+# Server settings
+[network]
+default = "testnet" # trailing comment
+custom.node_url = 'https://node.example'
+
+[[api_keys]]
+name = "ci"
+networks = ["testnet", "devnet",]
+
+[[api_keys]]
+name = "deployer"
+[api_keys.limits]
+daily = 10
+`;
+    expect(parseToml(toml)).toEqual({
+      network: { default: 'testnet', custom: { node_url: 'https://node.example' } },
+      api_keys: [
+        { name: 'ci', networks: ['testnet', 'devnet'] },
+        { name: 'deployer', limits: { daily: 10 } },
+      ],
+    });
+  });
+
+  it('parses numbers, booleans, escapes and inline tables', () => {
+    const toml = [
+      'ints = [1_000, -2, 0xff, 0b101]',
+      'floats = [1.5, 2e3, -inf]',
+      'flags = { on = true, off = false }',
+      'text = "tab\\there \\u00e9"',
+      'raw = \'C:\\keys\\wallet.pem\'',
+      'multi = [',
+      '  1, # one',
+      '  2,',
+      ']',
+    ].join('\n');
+    expect(parseToml(toml)).toEqual({
+      ints: [1000, -2, 255, 5],
+      floats: [1.5, 2000, -Infinity],
+      flags: { on: true, off: false },
+      text: 'tab\there é',
+      raw: 'C:\\keys\\wallet.pem',
+      multi: [1, 2],
+    });
+  });
+
+  it('parses multi-line strings', () => {
+    const toml = 'basic = """\nline one\\n\\\n    joined"""\nliteral = \'\'\'\nkeep \\n\'\'\'';
+    expect(parseToml(toml)).toEqual({ basic: 'line one\njoined', literal: 'keep \\n' });
+  });
+
+  it('reports the line of errors', () => {
+    expect(() => parseToml('a = 1\na = 2')).toThrow(new TomlError('Duplicate key "a"', 2));
+    expect(() => parseToml('[t]\n[t]')).toThrow('Line 2: "t" is already defined');
+    expect(() => parseToml('a = "open')).toThrow('Line 1: Unterminated string');
+    expect(() => parseToml('a = 1979-05-27')).toThrow('Line 1: Unexpected "-"');
+    expect(() => parseToml('a = yes')).toThrow('Unsupported value "yes"');
+  });
+});
@@ -0,0 +1,299 @@
+/**
+ * TOML parser for the server config file: tables, arrays of tables, dotted
+ * keys, strings (basic, literal and multi-line), integers, floats, booleans,
+ * arrays and inline tables. Dates and times are not supported.
+ */
+
+export class TomlError extends Error {
+  constructor(
+    message: string,
+    public line: number
+  ) {
+    super(`Line ${line}: ${message}`);
+    this.name = 'TomlError';
+  }
+}
+
+type Table = Record<string, unknown>;
+
+const BARE_KEY = /[A-Za-z0-9_-]/;
+const NUMBER = /[+-]?(?:inf|nan|0x[0-9a-fA-F_]+|0o[0-7_]+|0b[01_]+|[0-9_]+(?:\.[0-9_]+)?(?:[eE][+-]?[0-9_]+)?)/y;
+const ESCAPES: Record<string, string> = {
+  b: '\b',
+  t: '\t',
+  n: '\n',
+  f: '\f',
+  r: '\r',
+  '"': '"',
+  '\\': '\\',
+};
+
+const isTable = (value: unknown): value is Table =>
+  typeof value === 'object' && value !== null && !Array.isArray(value);
+
+export function parseToml(text: string): Table {
+  return new Parser(text).parse();
+}
+
+class Parser {
+  private pos = 0;
+  private root: Table = {};
+  private current: Table = this.root;
+  /** Tables defined by a header or inline, which may not be defined again */
+  private defined = new Set<Table>();
+  /** Arrays created by `[[...]]` headers, the only arrays headers may extend */
+  private tableArrays = new Set<unknown[]>();
+
+  constructor(private text: string) {}
+
+  parse(): Table {
+    for (;;) {
+      this.skipBlank(true);
+      if (this.pos >= this.text.length) return this.root;
+      if (this.peek() === '[') this.header();
+      else this.keyValue(this.current);
+      this.endOfLine();
+    }
+  }
+
+  private fail(message: string): never {
+    throw new TomlError(message, this.text.slice(0, this.pos).split('\n').length);
+  }
+
+  private peek(offset = 0): string {
+    return this.text[this.pos + offset] || '';
+  }
+
+  private startsWith(token: string): boolean {
+    return this.text.startsWith(token, this.pos);
+  }
+
+  private expect(token: string): void {
+    if (!this.startsWith(token)) this.fail(`Expected ${token}`);
+    this.pos += token.length;
+  }
+
+  /** Skip spaces and comments, and newlines when `newlines` */
+  private skipBlank(newlines: boolean): void {
+    while (this.pos < this.text.length) {
+      const char = this.peek();
+      if (char === ' ' || char === '\t' || (newlines && (char === '\n' || char === '\r'))) {
+        this.pos++;
+      } else if (char === '#') {
+        while (this.pos < this.text.length && this.peek() !== '\n') this.pos++;
+      } else {
+        return;
+      }
+    }
+  }
+
+  private endOfLine(): void {
+    this.skipBlank(false);
+    if (this.startsWith('\r\n')) this.pos += 2;
+    else if (this.peek() === '\n') this.pos++;
+    else if (this.pos < this.text.length) this.fail(`Unexpected "${this.peek()}"`);
+  }
+
+  private header(): void {
+    const array = this.startsWith('[[');
+    this.pos += array ? 2 : 1;
+    this.skipBlank(false);
+    const path = this.keyPath();
+    this.skipBlank(false);
+    this.expect(array ? ']]' : ']');
+
+    const parent = this.descend(this.root, path.slice(0, -1));
+    const name = path[path.length - 1];
+    const existing = parent[name];
+    if (array) {
+      if (existing === undefined) {
+        const tables: Table[] = [];
+        this.tableArrays.add(tables);
+        parent[name] = tables;
+      } else if (!Array.isArray(existing) || !this.tableArrays.has(existing)) {
+        this.fail(`"${path.join('.')}" is already defined`);
+      }
+      this.current = {};
+      (parent[name] as Table[]).push(this.current);
+    } else {
+      if (existing !== undefined && (!isTable(existing) || this.defined.has(existing))) {
+        this.fail(`"${path.join('.')}" is already defined`);
+      }
+      this.current = (existing as Table | undefined) || (parent[name] = {});
+    }
+    this.defined.add(this.current);
+  }
+
+  /** The table at `path` under `table`, created as needed; a table array stands for its last */
+  private descend(table: Table, path: string[]): Table {
+    for (const key of path) {
+      let next = table[key];
+      if (next === undefined) next = table[key] = {};
+      if (Array.isArray(next) && this.tableArrays.has(next)) next = next[next.length - 1];
+      if (!isTable(next)) this.fail(`"${key}" is not a table`);
+      table = next;
+    }
+    return table;
+  }
+
+  private keyValue(table: Table): void {
+    const path = this.keyPath();
+    this.skipBlank(false);
+    this.expect('=');
+    this.skipBlank(false);
+    const value = this.value();
+    const parent = this.descend(table, path.slice(0, -1));
+    const name = path[path.length - 1];
+    if (name in parent) this.fail(`Duplicate key "${path.join('.')}"`);
+    parent[name] = value;
+  }
+
+  private keyPath(): string[] {
+    const path = [this.key()];
+    for (;;) {
+      this.skipBlank(false);
+      if (this.peek() !== '.') return path;
+      this.pos++;
+      this.skipBlank(false);
+      path.push(this.key());
+    }
+  }
+
+  private key(): string {
+    if (this.peek() === '"') return this.basicString();
+    if (this.peek() === "'") return this.literalString();
+    const start = this.pos;
+    while (BARE_KEY.test(this.peek())) this.pos++;
+    if (this.pos === start) this.fail('Expected a key');
+    return this.text.slice(start, this.pos);
+  }
+
+  private value(): unknown {
+    if (this.startsWith('"""')) return this.multilineString('"""');
+    if (this.startsWith("'''")) return this.multilineString("'''");
+    const char = this.peek();
+    if (char === '"') return this.basicString();
+    if (char === "'") return this.literalString();
+    if (char === '[') return this.array();
+    if (char === '{') return this.inlineTable();
+    const boolean = /^(?:true|false)/.exec(this.text.slice(this.pos, this.pos + 5));
+    if (boolean) {
+      this.pos += boolean[0].length;
+      return boolean[0] === 'true';
+    }
+    return this.number();
+  }
+
+  private basicString(): string {
+    this.pos++;
+    let result = '';
+    for (;;) {
+      const char = this.peek();
+      if (char === '"') break;
+      if (char === '' || char === '\n') this.fail('Unterminated string');
+      result += char === '\\' ? this.escape() : char;
+      if (char !== '\\') this.pos++;
+    }
+    this.pos++;
+    return result;
+  }
+
+  private literalString(): string {
+    const end = this.text.indexOf("'", this.pos + 1);
+    const newline = this.text.indexOf('\n', this.pos + 1);
+    if (end === -1 || (newline !== -1 && newline < end)) this.fail('Unterminated string');
+    const result = this.text.slice(this.pos + 1, end);
+    this.pos = end + 1;
+    return result;
+  }
+
+  private multilineString(quotes: string): string {
+    this.pos += 3;
+    if (this.startsWith('\r\n')) this.pos += 2;
+    else if (this.peek() === '\n') this.pos++;
+    let result = '';
+    while (!this.startsWith(quotes)) {
+      if (this.pos >= this.text.length) this.fail('Unterminated string');
+      if (quotes === '"""' && this.peek() === '\\') {
+        if (/^\\[ \t]*\r?\n/.test(this.text.slice(this.pos, this.pos + 64))) {
+          // A backslash at the end of a line joins it with the next non-blank text
+          this.pos++;
+          while (/\s/.test(this.peek())) this.pos++;
+        } else {
+          result += this.escape();
+        }
+      } else {
+        result += this.peek();
+        this.pos++;
+      }
+    }
+    this.pos += 3;
+    return result;
+  }
+
+  /** The character of the escape sequence at the cursor, which it moves past */
+  private escape(): string {
+    const code = this.peek(1);
+    if (ESCAPES[code]) {
+      this.pos += 2;
+      return ESCAPES[code];
+    }
+    const length = code === 'u' ? 4 : code === 'U' ? 8 : 0;
+    const hex = this.text.slice(this.pos + 2, this.pos + 2 + length);
+    if (!length || !/^[0-9a-fA-F]+$/.test(hex) || hex.length !== length) {
+      this.fail(`Invalid escape "\\${code}"`);
+    }
+    this.pos += 2 + length;
+    return String.fromCodePoint(parseInt(hex, 16));
+  }
+
+  private array(): unknown[] {
+    this.pos++;
+    const items: unknown[] = [];
+    for (;;) {
+      this.skipBlank(true);
+      if (this.peek() === ']') break;
+      items.push(this.value());
+      this.skipBlank(true);
+      if (this.peek() === ',') this.pos++;
+      else if (this.peek() !== ']') this.fail('Expected , or ] in array');
+    }
+    this.pos++;
+    return items;
+  }
+
+  private inlineTable(): Table {
+    this.pos++;
+    const table: Table = {};
+    this.skipBlank(false);
+    if (this.peek() !== '}') {
+      for (;;) {
+        this.skipBlank(false);
+        this.keyValue(table);
+        this.skipBlank(false);
+        if (this.peek() === '}') break;
+        this.expect(',');
+      }
+    }
+    this.pos++;
+    this.defined.add(table);
+    return table;
+  }
+
+  private number(): number {
+    NUMBER.lastIndex = this.pos;
+    const match = NUMBER.exec(this.text);
+    if (!match) this.fail(`Unsupported value "${this.text.slice(this.pos).split(/\s/)[0]}"`);
+    const token = match[0];
+    if (/(^|[^0-9a-fA-F])_|_($|[^0-9a-fA-F])/.test(token)) this.fail(`Invalid number "${token}"`);
+    this.pos += token.length;
+    const digits = token.replace(/_/g, '');
+    const sign = digits.startsWith('-') ? -1 : 1;
+    const unsigned = digits.replace(/^[+-]/, '');
+    if (unsigned === 'inf') return sign * Infinity;
+    if (unsigned === 'nan') return NaN;
+    const radix = { x: 16, o: 8, b: 2 }[unsigned[1] as 'x' | 'o' | 'b'];
+    if (unsigned.startsWith('0') && radix) return sign * parseInt(unsigned.slice(2), radix);
+    return Number(digits);
+  }
+}
@@ -4,11 +4,15 @@ import dotenv from 'dotenv';
 import { StorageFactory, StorageType, StorageOptions } from './storage/index.js';
 import { ContextService } from './context/service.js';
 import { createRoutes } from './api/routes.js';
-import { KleverMCPServer } from './mcp/server.js';
+import { KleverMCPServer, type ServerProfile } from './mcp/server.js';
+import { describeConfig, getConfig, loadConfig } from './config/index.js';
+import { ToolRateLimiter } from './mcp/rate-limit.js';
+import { ToolConcurrencyLimiter } from './mcp/concurrency.js';
 import { autoIngestKnowledge } from './utils/auto-ingest.js';
 import { getVersionInfo } from './version.js';
-import { KleverChainClient } from './chain/index.js';
+import { KleverChainClient, NETWORK_NAMES, validateNetwork } from './chain/index.js';
 import type { KleverNetwork } from './chain/types.js';
+import { createStreamableHttpHandler, LOOPBACK_HOSTS, SESSION_HEADER } from './transport/index.js';
 
 // Load environment variables
 dotenv.config({ quiet: true });
@@ -16,10 +20,11 @@ dotenv.config({ quiet: true });
 /**
  * Main entry point for Klever MCP Server
  *
- * Supports three modes:
+ * Supports four modes:
  * 1. HTTP API server for REST access
  * 2. MCP server for AI assistant integration (stdio)
  * 3. Public server for hosted MCP + read-only API (HTTP Streamable transport)
+ * 4. MCP server as a long-lived HTTP service with sessions (HTTP Streamable transport + SSE)
  */
 
 function createStorageAndService() {
@@ -37,23 +42,27 @@ function createStorageAndService() {
   return { storageType, contextService };
 }
 
-const VALID_NETWORKS = new Set(['mainnet', 'testnet', 'devnet', 'local']);
-
 function createChainClient(): KleverChainClient {
-  const envNetwork = process.env.KLEVER_NETWORK;
-  if (envNetwork && !VALID_NETWORKS.has(envNetwork)) {
+  const config = getConfig();
+  let network: KleverNetwork | undefined;
+  try {
+    network = validateNetwork(config.network.default || undefined);
+  } catch {
     // In MCP mode stdout is reserved for the JSON-RPC protocol; stderr is the only safe log channel.
     console.error(
-      `[WARN] Invalid KLEVER_NETWORK="${envNetwork}". Valid: mainnet, testnet, devnet, local. Defaulting to mainnet.`
+      `[WARN] Invalid network "${config.network.default}" (KLEVER_NETWORK or network.default). Valid: ${NETWORK_NAMES.join(', ')}. Defaulting to mainnet.`
     );
+    network = 'mainnet';
   }
-  const network: KleverNetwork =
-    envNetwork && VALID_NETWORKS.has(envNetwork) ? (envNetwork as KleverNetwork) : 'mainnet';
   return new KleverChainClient({
     network,
-    nodeUrl: process.env.KLEVER_NODE_URL,
-    apiUrl: process.env.KLEVER_API_URL,
-    timeout: parseInt(process.env.KLEVER_TIMEOUT || '15000'),
+    nodeUrl: config.network.nodeUrl,
+    apiUrl: config.network.apiUrl,
+    chainId: config.network.chainId,
+    explorerUrl: config.network.explorerUrl,
+    eventsUrl: config.network.eventsUrl,
+    timeout: config.network.timeoutMs,
+    cache: config.cache.responses ? undefined : false,
   });
 }
 
@@ -74,7 +83,7 @@ async function startHTTPServer() {
   app.use(express.json({ limit: '10mb' }));
 
   // Routes
-  app.use('/api', createRoutes(contextService));
+  app.use('/api', createRoutes(contextService, { readOnly: getConfig().tools.readOnly }));
 
   // Global error handling
   app.use(
@@ -110,7 +119,15 @@ async function startMCPServer() {
 
   // Create and start MCP server
   const chainClient = createChainClient();
-  const mcpServer = new KleverMCPServer(contextService, 'local', chainClient);
+  const { rateLimits, concurrencyLimits } = getConfig();
+  const mcpServer = new KleverMCPServer(
+    contextService,
+    'local',
+    chainClient,
+    undefined,
+    new ToolRateLimiter(rateLimits),
+    new ToolConcurrencyLimiter(concurrencyLimits)
+  );
   await mcpServer.start();
 }
 
@@ -198,6 +215,12 @@ async function startPublicServer() {
     message: { error: 'Too many requests, please try again later' },
   });
 
+  // Every request is its own session, so only the per-server tool rate limits apply
+  const { rateLimits } = getConfig();
+  const rateLimiter = new ToolRateLimiter(rateLimits);
+  // One chain client for all requests, so they share its response cache
+  const chainClient = createChainClient();
+
   // MCP endpoint — Stateless Streamable HTTP
   // Each request creates its own transport+server and is fully self-contained.
   // No session tracking needed — eliminates "Session not found" errors after
@@ -212,8 +235,13 @@ async function startPublicServer() {
         transport.close().catch(() => {});
       });
 
-      const chainClient = createChainClient();
-      const mcpServer = new KleverMCPServer(contextService, 'public', chainClient);
+      const mcpServer = new KleverMCPServer(
+        contextService,
+        'public',
+        chainClient,
+        undefined,
+        rateLimiter
+      );
       await mcpServer.connectTransport(transport);
 
       await transport.handleRequest(req, res, req.body);
@@ -305,13 +333,101 @@ async function startPublicServer() {
   process.on('SIGTERM', shutdown);
 }
 
-// Determine which mode to run
-const mode = process.env.MODE || 'http';
+async function startStreamableHttpServer() {
+  const { storageType, contextService } = createStorageAndService();
+
+  // Auto-ingest knowledge if using memory storage
+  if (storageType === 'memory') {
+    await autoIngestKnowledge(contextService);
+  }
 
-if (mode === 'mcp') {
-  startMCPServer().catch(console.error);
-} else if (mode === 'public') {
-  startPublicServer().catch(console.error);
-} else {
-  startHTTPServer().catch(console.error);
+  const profile = (process.env.MCP_PROFILE || 'local') as ServerProfile;
+  if (profile !== 'local' && profile !== 'public') {
+    throw new Error(`Invalid MCP_PROFILE="${profile}". Valid: local, public.`);
+  }
+  const host = process.env.HOST || '127.0.0.1';
+  const port = Number(process.env.PORT || 3000);
+  const authToken = process.env.MCP_AUTH_TOKEN || undefined;
+  const { apiKeys, rateLimits, concurrencyLimits } = getConfig();
+  if (apiKeys.length > 0) {
+    console.error(`[MCP HTTP] ${apiKeys.length} API key(s) from ${describeConfig().file}`);
+  }
+  const loopback = LOOPBACK_HOSTS.includes(host) || host === '::1';
+  const allowedHosts = process.env.MCP_ALLOWED_HOSTS
+    ? process.env.MCP_ALLOWED_HOSTS.split(',').map(h => h.trim().toLowerCase())
+    : loopback
+      ? LOOPBACK_HOSTS
+      : undefined;
+  if (profile === 'local' && !loopback && !authToken && apiKeys.length === 0) {
+    console.error(
+      `[WARN] Serving local-only tools (deploys, transfers, file access) on ${host} without MCP_AUTH_TOKEN or API keys; anyone reaching this port can use them.`
+    );
+  }
+
+  // One chain client and set of limiters for all sessions, so they share its response cache,
+  // nonces and per-server limits
+  const chainClient = createChainClient();
+  const rateLimiter = new ToolRateLimiter(rateLimits);
+  const concurrency = new ToolConcurrencyLimiter(concurrencyLimits);
+  const mcp = createStreamableHttpHandler({
+    createServer: scope =>
+      new KleverMCPServer(contextService, profile, chainClient, scope, rateLimiter, concurrency),
+    authToken,
+    apiKeys,
+    allowedHosts,
+    idleTimeoutMs: parseInt(process.env.MCP_SESSION_TTL || '1800') * 1000,
+  });
+
+  const app = express();
+  app.use(
+    cors({
+      allowedHeaders: ['Content-Type', 'Authorization', SESSION_HEADER, 'Last-Event-ID'],
+      exposedHeaders: [SESSION_HEADER],
+    })
+  );
+  app.use(express.json({ limit: process.env.BODY_SIZE_LIMIT || '1mb' }));
+  app.use('/mcp', mcp.router);
+  app.get('/health', (_req, res) => {
+    res.json({
+      status: 'ok',
+      timestamp: new Date().toISOString(),
+      mode: 'mcp-http',
+      profile,
+      sessions: mcp.sessions.size,
+      uptime: process.uptime(),
+      ...getVersionInfo(),
+    });
+  });
+
+  const server = app.listen(port, host, () => {
+    console.error(`Klever MCP Server (${profile} profile) on http://${host}:${port}/mcp`);
+  });
+
+  const shutdown = () => {
+    console.error('\n[MCP HTTP] Shutting down gracefully...');
+    mcp.close();
+    server.close(() => {
+      process.exit(0);
+    });
+  };
+
+  process.on('SIGINT', shutdown);
+  process.on('SIGTERM', shutdown);
 }
+
+// `--read-only` offers only tools that never sign, send, spend funds or write files
+if (process.argv.includes('--read-only')) process.env.KLEVER_READ_ONLY = 'true';
+
+// Determine which mode to run
+const mode = process.env.MODE || 'http';
+const start =
+  mode === 'mcp'
+    ? startMCPServer
+    : mode === 'public'
+      ? startPublicServer
+      : mode === 'mcp-http'
+        ? startStreamableHttpServer
+        : startHTTPServer;
+
+// mcp-klever-vm.toml applies to every mode; environment variables override its settings
+loadConfig().then(start).catch(console.error);
@@ -0,0 +1,110 @@
+import type { FrameworkDoc } from './types.js';
+
+export const annotationsDoc: FrameworkDoc = {
+  topic: 'annotations',
+  title: 'Contract annotations',
+  summary:
+    'Attributes that turn a Rust trait into a contract: init, endpoints, views, payments, events, storage, callbacks',
+  content: `# Contract annotations
+
+A contract is a trait annotated with \`#[klever_sc::contract]\`; the framework
+generates the WASM exports, argument decoding, result encoding and the ABI
+from the attributes on its methods.
+
+\`\`\`rust
+#![no_std]
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait Counter {
+    #[init]
+    fn init(&self, initial: u64) {
+        self.count().set(initial);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[endpoint]
+    fn increment(&self) {
+        self.count().update(|count| *count += 1);
+    }
+
+    #[view(getCount)]
+    #[storage_mapper("count")]
+    fn count(&self) -> SingleValueMapper<u64>;
+}
+\`\`\`
+
+## Contract and modules
+
+| Attribute | On | Meaning |
+|---|---|---|
+| \`#[klever_sc::contract]\` | trait | The contract; one per crate |
+| \`#[klever_sc::module]\` | trait | Reusable part of a contract; the contract lists it as a supertrait |
+| \`#[klever_sc::proxy]\` | trait | Typed description of another contract's endpoints for calls |
+| \`#[klever_sc::derive::type_abi]\` | struct/enum | Adds the type to the ABI (together with the codec derives) |
+
+## Lifecycle
+
+- \`#[init]\`: constructor, runs once at deploy. Its arguments are the deploy arguments.
+- \`#[upgrade]\`: runs when new code is deployed to the address. Storage is kept; only initialise what is new.
+
+## Endpoints and views
+
+- \`#[endpoint]\` / \`#[endpoint(camelName)]\`: callable by transactions. The optional name is the
+  exported name; without it the Rust name is used.
+- \`#[view]\` / \`#[view(getName)]\`: an endpoint meant for queries. Views can still be called by
+  transactions; the attribute only marks them read-only in the ABI.
+- \`#[only_owner]\`: rejects callers other than the contract owner (\`"Endpoint can only be called by owner"\`).
+- \`#[only_admin]\`: rejects callers without the admin role (needs the admin module).
+
+## Payments
+
+- \`#[payable("KLV")]\`: accepts KLV only; read it with \`self.call_value().klv_value()\`.
+- \`#[payable("*")]\`: accepts any token; read it with \`self.call_value().single_kda()\` or
+  \`self.call_value().all_kda_transfers()\`.
+- \`#[payable("TOKEN-ID")]\`: accepts that token only.
+- Without \`#[payable]\` any payment makes the call fail, so a forgotten attribute shows up as
+  "function does not accept KLV payment" at runtime.
+
+## Arguments
+
+- \`#[var_args] args: MultiValueEncoded<T>\` (or a trailing \`MultiValueEncoded\` argument): any number
+  of trailing arguments.
+- \`OptionalValue<T>\`: a trailing argument that may be omitted.
+- \`#[payment_token]\`, \`#[payment_amount]\`, \`#[payment_nonce]\`: bind the payment to arguments
+  instead of reading \`call_value()\`.
+
+## Events
+
+\`\`\`rust
+#[event("transfer")]
+fn transfer_event(
+    &self,
+    #[indexed] from: &ManagedAddress,
+    #[indexed] to: &ManagedAddress,
+    amount: &BigUint,
+);
+\`\`\`
+
+\`#[indexed]\` arguments become topics that indexers can filter on. At most one argument may be
+non-indexed: it becomes the event data.
+
+## Storage
+
+- \`#[storage_mapper("key")]\`: a method returning a mapper stored under \`key\` followed by the
+  nested-encoded method arguments.
+- \`#[storage_get("key")]\` / \`#[storage_set("key")]\` / \`#[storage_clear("key")]\`: direct accessors
+  without a mapper.
+- \`#[view(name)]\` on a storage mapper method exposes it as a view.
+
+## Calls to other contracts
+
+- \`#[proxy]\`: accessor returning a proxy of another contract, e.g.
+  \`fn pair(&self, sc_address: ManagedAddress) -> pair_proxy::Proxy<Self::Api>;\`
+- \`#[callback]\`: method run after an async call returns; \`#[call_result] result: ManagedAsyncCallResult<T>\`
+  receives the outcome.
+- \`#[promises_callback]\`: callback of a promise-style async call.
+`,
+};
@@ -0,0 +1,494 @@
+/**
+ * Catalog of the klever-sc attributes with the rules the contract macros
+ * enforce on them, in a structured form the linter and code generators can
+ * check against. Served as the `klever://reference/attributes` resource.
+ */
+
+export type AttributeTarget = 'trait' | 'function' | 'parameter' | 'type';
+
+/**
+ * What goes between the parentheses: nothing, an optional exported name
+ * (`#[endpoint(transfer)]`), or a required string literal (`#[payable("KLV")]`)
+ */
+export type AttributeArgument = 'none' | 'optional-name' | 'string';
+
+export interface AttributeError {
+  /** What the build or the call reports */
+  symptom: string;
+  cause: string;
+  fix: string;
+}
+
+export interface ContractAttribute {
+  /** Name without brackets or arguments, e.g. `payable` */
+  name: string;
+  /** Usage as written in source, e.g. `#[payable("KLV")]` */
+  syntax: string;
+  target: AttributeTarget;
+  summary: string;
+  argument: AttributeArgument;
+  /** A bodiless method the framework implements (storage, events, proxies) */
+  declaration?: boolean;
+  /** Function attributes of which at least one must also be present */
+  requiresOneOf?: string[];
+  /** Function attributes that cannot be combined with this one */
+  conflictsWith?: string[];
+  /** Signature rules in plain words */
+  rules: string[];
+  errors: AttributeError[];
+  example: string;
+}
+
+const ENTRY_POINTS = ['init', 'upgrade', 'endpoint', 'view', 'callback', 'promises_callback'];
+const CALLABLE = ['init', 'upgrade', 'endpoint'];
+
+export const CONTRACT_ATTRIBUTES: ContractAttribute[] = [
+  {
+    name: 'klever_sc::contract',
+    syntax: '#[klever_sc::contract]',
+    target: 'trait',
+    summary: 'Turns the trait into the contract: exports, argument decoding and the ABI',
+    argument: 'none',
+    rules: [
+      'Exactly one per crate, on a `pub trait`',
+      'Modules used by the contract are listed as supertraits',
+      'The crate needs `#![no_std]` and `use klever_sc::imports::*;`',
+    ],
+    errors: [
+      {
+        symptom: 'duplicate symbol errors for the generated endpoints at link time',
+        cause: 'Two traits in the crate are annotated as contracts',
+        fix: 'Keep one contract trait and turn the others into #[klever_sc::module]',
+      },
+    ],
+    example: '#[klever_sc::contract]\npub trait Counter: storage::StorageModule {}',
+  },
+  {
+    name: 'klever_sc::module',
+    syntax: '#[klever_sc::module]',
+    target: 'trait',
+    summary: 'A reusable part of a contract with its own endpoints, storage and events',
+    argument: 'none',
+    rules: ['The contract must list the module as a supertrait for its endpoints to be exported'],
+    errors: [
+      {
+        symptom: 'the trait bound `Self: StorageModule` is not satisfied',
+        cause: 'A module calls another module that is not among its supertraits',
+        fix: 'Add the module it uses to the supertrait list of the calling module',
+      },
+    ],
+    example: '#[klever_sc::module]\npub trait StorageModule {}',
+  },
+  {
+    name: 'klever_sc::proxy',
+    syntax: '#[klever_sc::proxy]',
+    target: 'trait',
+    summary: 'Typed description of another contract, used to build calls to it',
+    argument: 'none',
+    rules: ['Methods are bodiless and carry the same endpoint attributes as the callee'],
+    errors: [],
+    example:
+      '#[klever_sc::proxy]\npub trait Pair {\n    #[endpoint]\n    fn swap(&self, min_out: BigUint);\n}',
+  },
+  {
+    name: 'init',
+    syntax: '#[init]',
+    target: 'function',
+    summary: 'Constructor, runs once at deploy; its arguments are the deploy arguments',
+    argument: 'none',
+    conflictsWith: ['upgrade', 'endpoint', 'view', 'callback', 'promises_callback'],
+    rules: ['One per contract', 'Has a body', 'Modules cannot declare one'],
+    errors: [
+      {
+        symptom: 'deploy fails with "wrong number of arguments"',
+        cause: 'The deploy arguments do not match the #[init] parameters',
+        fix: 'Pass one argument per #[init] parameter, in order',
+      },
+    ],
+    example: '#[init]\nfn init(&self, initial: u64) {\n    self.count().set(initial);\n}',
+  },
+  {
+    name: 'upgrade',
+    syntax: '#[upgrade]',
+    target: 'function',
+    summary: 'Runs when new code is deployed to the contract; storage is kept',
+    argument: 'none',
+    conflictsWith: ['init', 'endpoint', 'view', 'callback', 'promises_callback'],
+    rules: [
+      'One per contract',
+      'Has a body',
+      'Only initialise storage that is new in this version',
+    ],
+    errors: [
+      {
+        symptom: 'upgrade transaction fails with "function not found"',
+        cause: 'The new code has no #[upgrade] function',
+        fix: 'Add `#[upgrade] fn upgrade(&self) {}`',
+      },
+    ],
+    example: '#[upgrade]\nfn upgrade(&self) {}',
+  },
+  {
+    name: 'endpoint',
+    syntax: '#[endpoint] or #[endpoint(exportedName)]',
+    target: 'function',
+    summary: 'A function transactions can call; the optional name is the exported name',
+    argument: 'optional-name',
+    conflictsWith: ['init', 'upgrade', 'view', 'callback', 'promises_callback', 'event'],
+    rules: [
+      'Has a body',
+      'Exported names are unique across the contract and its modules',
+      'The name argument is an identifier, not a string',
+    ],
+    errors: [
+      {
+        symptom: 'endpoint name conflicts with another endpoint',
+        cause: 'Two functions (possibly in different modules) export the same name',
+        fix: 'Rename one of them or give it an explicit #[endpoint(otherName)]',
+      },
+    ],
+    example:
+      '#[endpoint]\nfn increment(&self) {\n    self.count().update(|count| *count += 1);\n}',
+  },
+  {
+    name: 'view',
+    syntax: '#[view] or #[view(getName)]',
+    target: 'function',
+    summary: 'An endpoint meant for read-only queries, marked readonly in the ABI',
+    argument: 'optional-name',
+    conflictsWith: ['init', 'upgrade', 'endpoint', 'callback', 'promises_callback', 'event'],
+    rules: [
+      'Has a body, unless it also carries #[storage_mapper]',
+      'Must not write storage or send funds: queries run read-only',
+    ],
+    errors: [
+      {
+        symptom: 'query fails although the same call succeeds as a transaction',
+        cause: 'The view writes storage or transfers funds',
+        fix: 'Move the state change into an #[endpoint]',
+      },
+    ],
+    example:
+      '#[view(getCount)]\n#[storage_mapper("count")]\nfn count(&self) -> SingleValueMapper<u64>;',
+  },
+  {
+    name: 'payable',
+    syntax: '#[payable("KLV")], #[payable("*")] or #[payable("TOKEN-ID")]',
+    target: 'function',
+    summary: 'Lets the endpoint receive a payment: KLV, any token, or one token',
+    argument: 'string',
+    requiresOneOf: CALLABLE,
+    rules: [
+      'The token is a string literal',
+      'Only on #[init], #[upgrade] or #[endpoint]',
+      'Without it any payment makes the call fail',
+    ],
+    errors: [
+      {
+        symptom: 'call fails with "function does not accept KLV payment"',
+        cause: 'The endpoint receives a payment but has no #[payable]',
+        fix: 'Add #[payable("KLV")] or #[payable("*")]',
+      },
+      {
+        symptom: 'expected string literal',
+        cause: 'The token was written without quotes, e.g. #[payable(KLV)]',
+        fix: 'Quote the token: #[payable("KLV")]',
+      },
+    ],
+    example:
+      '#[payable("KLV")]\n#[endpoint]\nfn deposit(&self) {\n    let amount = self.call_value().klv_value();\n}',
+  },
+  {
+    name: 'only_owner',
+    syntax: '#[only_owner]',
+    target: 'function',
+    summary: 'Rejects callers other than the contract owner',
+    argument: 'none',
+    requiresOneOf: ['endpoint', 'view'],
+    rules: ['Only on #[endpoint] or #[view]; private helpers use self.require_owner() instead'],
+    errors: [
+      {
+        symptom: 'call fails with "Endpoint can only be called by owner"',
+        cause: 'The signer is not the contract owner',
+        fix: 'Sign with the owner, or transfer ownership first',
+      },
+    ],
+    example:
+      '#[only_owner]\n#[endpoint(setFee)]\nfn set_fee(&self, fee: BigUint) {\n    self.fee().set(fee);\n}',
+  },
+  {
+    name: 'only_admin',
+    syntax: '#[only_admin]',
+    target: 'function',
+    summary: 'Rejects callers without the admin role; needs the admin module',
+    argument: 'none',
+    requiresOneOf: ['endpoint', 'view'],
+    rules: ['Only on #[endpoint] or #[view]', 'The contract must include the admin module'],
+    errors: [
+      {
+        symptom: 'cannot find the admin check in this scope',
+        cause: 'The admin module is not among the contract supertraits',
+        fix: 'Add the admin module as a supertrait of the contract',
+      },
+    ],
+    example: '#[only_admin]\n#[endpoint]\nfn pause(&self) {}',
+  },
+  {
+    name: 'label',
+    syntax: '#[label("name")]',
+    target: 'function',
+    summary: 'Groups endpoints under a label in the ABI',
+    argument: 'string',
+    requiresOneOf: ['endpoint', 'view'],
+    rules: ['Only on #[endpoint] or #[view]'],
+    errors: [],
+    example: '#[label("admin")]\n#[endpoint]\nfn pause(&self) {}',
+  },
+  {
+    name: 'output_name',
+    syntax: '#[output_name("name")]',
+    target: 'function',
+    summary: 'Names the result of an endpoint or view in the ABI',
+    argument: 'string',
+    requiresOneOf: ['endpoint', 'view'],
+    rules: ['Only on #[endpoint] or #[view]; repeat it once per value of a multi-value result'],
+    errors: [],
+    example: '#[view(getPosition)]\n#[output_name("amount")]\nfn get_position(&self) -> BigUint {}',
+  },
+  {
+    name: 'event',
+    syntax: '#[event("name")]',
+    target: 'function',
+    summary: 'Declares an event; calling the method emits it',
+    argument: 'string',
+    declaration: true,
+    conflictsWith: ['init', 'upgrade', 'endpoint', 'view', 'storage_mapper'],
+    rules: [
+      'Bodiless and returns nothing',
+      'Parameters marked #[indexed] become topics',
+      'At most one parameter is not #[indexed]: it becomes the event data',
+    ],
+    errors: [
+      {
+        symptom: 'only one data argument allowed in an event',
+        cause: 'Two or more event parameters are not #[indexed]',
+        fix: 'Mark all but one parameter #[indexed], or group the data in a struct',
+      },
+    ],
+    example:
+      '#[event("deposit")]\nfn deposit_event(&self, #[indexed] caller: &ManagedAddress, amount: &BigUint);',
+  },
+  {
+    name: 'storage_mapper',
+    syntax: '#[storage_mapper("key")]',
+    target: 'function',
+    summary: 'Declares storage under `key` plus the encoded method arguments',
+    argument: 'string',
+    declaration: true,
+    conflictsWith: ['init', 'upgrade', 'event', 'callback', 'promises_callback'],
+    rules: [
+      'Bodiless and returns a mapper type',
+      'Keys are unique across the contract and its modules, and no key is a prefix of another',
+    ],
+    errors: [
+      {
+        symptom: 'the trait `StorageMapper` is not implemented',
+        cause: 'The return type is a plain value instead of a mapper',
+        fix: 'Return a mapper, e.g. SingleValueMapper<BigUint>',
+      },
+    ],
+    example:
+      '#[storage_mapper("balance")]\nfn balance(&self, account: &ManagedAddress) -> SingleValueMapper<BigUint>;',
+  },
+  {
+    name: 'storage_mapper_from_address',
+    syntax: '#[storage_mapper_from_address("key")]',
+    target: 'function',
+    summary: 'Read-only view of a mapper in another contract on the same shard',
+    argument: 'string',
+    declaration: true,
+    rules: [
+      'Bodiless; the first parameter is the contract address',
+      'Returns a mapper typed with ManagedAddress as its address parameter',
+    ],
+    errors: [],
+    example:
+      '#[storage_mapper_from_address("balance")]\nfn balance_of(&self, address: ManagedAddress) -> SingleValueMapper<BigUint, ManagedAddress>;',
+  },
+  {
+    name: 'storage_get',
+    syntax: '#[storage_get("key")]',
+    target: 'function',
+    summary: 'Direct storage read without a mapper',
+    argument: 'string',
+    declaration: true,
+    rules: ['Bodiless and returns the stored type'],
+    errors: [],
+    example: '#[storage_get("fee")]\nfn get_fee(&self) -> BigUint;',
+  },
+  {
+    name: 'storage_set',
+    syntax: '#[storage_set("key")]',
+    target: 'function',
+    summary: 'Direct storage write without a mapper',
+    argument: 'string',
+    declaration: true,
+    rules: ['Bodiless; the last parameter is the value'],
+    errors: [],
+    example: '#[storage_set("fee")]\nfn set_fee_storage(&self, fee: &BigUint);',
+  },
+  {
+    name: 'storage_clear',
+    syntax: '#[storage_clear("key")]',
+    target: 'function',
+    summary: 'Direct storage clear without a mapper',
+    argument: 'string',
+    declaration: true,
+    rules: ['Bodiless and returns nothing'],
+    errors: [],
+    example: '#[storage_clear("fee")]\nfn clear_fee(&self);',
+  },
+  {
+    name: 'proxy',
+    syntax: '#[proxy]',
+    target: 'function',
+    summary: 'Accessor returning a proxy of another contract',
+    argument: 'none',
+    declaration: true,
+    rules: ['Bodiless and returns the proxy type'],
+    errors: [],
+    example:
+      '#[proxy]\nfn pair(&self, sc_address: ManagedAddress) -> pair_proxy::Proxy<Self::Api>;',
+  },
+  {
+    name: 'callback',
+    syntax: '#[callback]',
+    target: 'function',
+    summary: 'Runs after an async call returns, with its result',
+    argument: 'none',
+    conflictsWith: ['init', 'upgrade', 'endpoint', 'view', 'promises_callback'],
+    rules: [
+      'Has a body',
+      'Receives the outcome through a #[call_result] parameter',
+      'Re-check state: it runs in a later transaction',
+    ],
+    errors: [
+      {
+        symptom: 'funds stuck in the contract after a failed async call',
+        cause: 'The callback ignores the error branch of the call result',
+        fix: 'Match ManagedAsyncCallResult::Err and refund or roll back',
+      },
+    ],
+    example:
+      '#[callback]\nfn swap_callback(&self, #[call_result] result: ManagedAsyncCallResult<BigUint>) {\n    match result {\n        ManagedAsyncCallResult::Ok(amount) => {}\n        ManagedAsyncCallResult::Err(_) => {}\n    }\n}',
+  },
+  {
+    name: 'promises_callback',
+    syntax: '#[promises_callback]',
+    target: 'function',
+    summary: 'Callback of a promise-style async call',
+    argument: 'none',
+    conflictsWith: ['init', 'upgrade', 'endpoint', 'view', 'callback'],
+    rules: ['Has a body'],
+    errors: [],
+    example:
+      '#[promises_callback]\nfn on_done(&self, #[call_result] result: ManagedAsyncCallResult<()>) {}',
+  },
+  {
+    name: 'indexed',
+    syntax: '#[indexed]',
+    target: 'parameter',
+    summary: 'Event parameter stored as a topic that indexers can filter on',
+    argument: 'none',
+    requiresOneOf: ['event'],
+    rules: ['Only on parameters of #[event] methods'],
+    errors: [
+      {
+        symptom: 'cannot find attribute `indexed` in this scope',
+        cause: '#[indexed] is used on a method that is not an event',
+        fix: 'Remove it, or declare the method with #[event("name")]',
+      },
+    ],
+    example:
+      '#[event("transfer")]\nfn transfer_event(&self, #[indexed] from: &ManagedAddress, amount: &BigUint);',
+  },
+  {
+    name: 'call_result',
+    syntax: '#[call_result]',
+    target: 'parameter',
+    summary: 'Callback parameter receiving the outcome of the async call',
+    argument: 'none',
+    requiresOneOf: ['callback', 'promises_callback'],
+    rules: ['Only on parameters of callbacks', 'Typed ManagedAsyncCallResult<T>'],
+    errors: [],
+    example: '#[callback]\nfn cb(&self, #[call_result] result: ManagedAsyncCallResult<()>) {}',
+  },
+  {
+    name: 'var_args',
+    syntax: '#[var_args]',
+    target: 'parameter',
+    summary: 'Marks a trailing MultiValueEncoded parameter taking any number of arguments',
+    argument: 'none',
+    requiresOneOf: ENTRY_POINTS,
+    rules: ['Only on the last parameter'],
+    errors: [],
+    example: '#[endpoint]\nfn add_all(&self, #[var_args] values: MultiValueEncoded<u64>) {}',
+  },
+  {
+    name: 'payment_token',
+    syntax: '#[payment_token]',
+    target: 'parameter',
+    summary: 'Binds the paid token identifier to a parameter',
+    argument: 'none',
+    requiresOneOf: ['payable'],
+    rules: ['Only on #[payable] functions'],
+    errors: [],
+    example:
+      '#[payable("*")]\n#[endpoint]\nfn pay(&self, #[payment_token] token: TokenIdentifier) {}',
+  },
+  {
+    name: 'payment_amount',
+    syntax: '#[payment_amount]',
+    target: 'parameter',
+    summary: 'Binds the paid amount to a parameter',
+    argument: 'none',
+    requiresOneOf: ['payable'],
+    rules: ['Only on #[payable] functions'],
+    errors: [],
+    example: '#[payable("KLV")]\n#[endpoint]\nfn pay(&self, #[payment_amount] amount: BigUint) {}',
+  },
+  {
+    name: 'payment_nonce',
+    syntax: '#[payment_nonce]',
+    target: 'parameter',
+    summary: 'Binds the paid token nonce to a parameter',
+    argument: 'none',
+    requiresOneOf: ['payable'],
+    rules: ['Only on #[payable] functions'],
+    errors: [],
+    example: '#[payable("*")]\n#[endpoint]\nfn pay(&self, #[payment_nonce] nonce: u64) {}',
+  },
+  {
+    name: 'klever_sc::derive::type_abi',
+    syntax: '#[klever_sc::derive::type_abi] (or #[type_abi] with the derive imports)',
+    target: 'type',
+    summary: 'Adds a struct or enum to the ABI, together with the codec derives',
+    argument: 'none',
+    rules: ['Also derive TopEncode/TopDecode (and NestedEncode/NestedDecode inside other types)'],
+    errors: [
+      {
+        symptom: 'the trait `TypeAbi` is not implemented',
+        cause: 'A type used in an endpoint or event is not in the ABI',
+        fix: 'Add #[klever_sc::derive::type_abi] (or derive TypeAbi) to the type',
+      },
+    ],
+    example:
+      '#[klever_sc::derive::type_abi]\n#[derive(TopEncode, TopDecode)]\npub struct Position<M: ManagedTypeApi> {\n    pub amount: BigUint<M>,\n}',
+  },
+];
+
+/** The catalog entry for an attribute as written (`#[payable("KLV")]`) or by name */
+export function findContractAttribute(attribute: string): ContractAttribute | undefined {
+  const name = attribute.replace(/^#\[/, '').replace(/[(\]][\s\S]*$/, '').trim();
+  return CONTRACT_ATTRIBUTES.find(a => a.name === name);
+}
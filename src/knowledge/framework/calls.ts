@@ -0,0 +1,100 @@
+import type { FrameworkDoc } from './types.js';
+
+export const callsDoc: FrameworkDoc = {
+  topic: 'calls',
+  title: 'Payments and calls',
+  summary:
+    'Receiving payments, sending tokens, and calling other contracts synchronously or asynchronously',
+  content: `# Payments and calls
+
+## Receiving payments
+
+\`\`\`rust
+#[payable("KLV")]
+#[endpoint]
+fn deposit(&self) {
+    let amount = self.call_value().klv_value();
+    require!(*amount > 0u32, "Send some KLV");
+}
+
+#[payable("*")]
+#[endpoint]
+fn stake(&self) {
+    let payment = self.call_value().single_kda();
+    require!(payment.token_identifier == self.staking_token().get(), "Wrong token");
+}
+\`\`\`
+
+- \`klv_value()\`: the KLV sent (zero when none).
+- \`single_kda()\`: exactly one token payment; fails otherwise.
+- \`all_kda_transfers()\`: every token payment, for multi-token endpoints.
+
+## Sending tokens
+
+\`\`\`rust
+let caller = self.blockchain().get_caller();
+self.send().direct_klv(&caller, &amount);
+self.send().direct_kda(&caller, &token_id, 0, &amount);
+self.send().direct_multi(&caller, &payments);
+\`\`\`
+
+Transfers to user accounts cannot fail for lack of a payable endpoint. Transfers to contracts
+execute that contract's code: update your own state before sending (checks-effects-interactions).
+
+## Calling other contracts
+
+Declare the target with a proxy (generated from its ABI, e.g. with generate_proxy) and an accessor:
+
+\`\`\`rust
+#[proxy]
+fn pair_contract(&self, sc_address: ManagedAddress) -> pair_proxy::Proxy<Self::Api>;
+\`\`\`
+
+### Synchronous calls
+
+The called contract runs inside the current execution and its result is available immediately.
+
+\`\`\`rust
+let price: BigUint = self
+    .pair_contract(pair_address)
+    .get_price(token_id)
+    .execute_on_dest_context();
+\`\`\`
+
+### Asynchronous calls
+
+The call runs after the current execution ends; a callback receives its result.
+
+\`\`\`rust
+self.pair_contract(pair_address)
+    .swap(token_out, min_out)
+    .with_kda_transfer(payment)
+    .with_gas_limit(gas_for_call)
+    .async_call()
+    .with_callback(self.callbacks().swap_callback(&caller))
+    .call_and_exit();
+
+#[callback]
+fn swap_callback(
+    &self,
+    caller: &ManagedAddress,
+    #[call_result] result: ManagedAsyncCallResult<BigUint>,
+) {
+    match result {
+        ManagedAsyncCallResult::Ok(amount_out) => { /* record the swap */ },
+        ManagedAsyncCallResult::Err(_) => { /* payments were returned to this contract: refund */ },
+    }
+}
+\`\`\`
+
+- \`call_and_exit()\` ends the endpoint: do all work that must happen before the call first.
+- Reserve gas for the callback; it runs with the gas left from the call.
+- Callback arguments are saved with the call; keep them small.
+
+## Blockchain information
+
+\`self.blockchain()\` gives the caller (\`get_caller\`), owner (\`get_owner_address\`), this contract's
+address and balances (\`get_sc_address\`, \`get_sc_balance\`), and block data (\`get_block_nonce\`,
+\`get_block_timestamp\`, \`get_block_epoch\`, \`get_block_round\`).
+`,
+};
@@ -0,0 +1,99 @@
+import { jest } from '@jest/globals';
+import { mkdtemp, readFile, rm, writeFile } from 'node:fs/promises';
+import { tmpdir } from 'node:os';
+import { join } from 'node:path';
+import {
+  FRAMEWORK_TOPICS,
+  frameworkDocForVersion,
+  frameworkVersionNote,
+  loadFrameworkDoc,
+  refreshFrameworkDocs,
+} from './index.js';
+
+function page(body: string, status = 200): Response {
+  return {
+    ok: status >= 200 && status < 300,
+    status,
+    statusText: status === 200 ? 'OK' : 'Not Found',
+    text: () => Promise.resolve(body),
+  } as Response;
+}
+
+describe('framework docs', () => {
+  let dir: string;
+
+  beforeEach(async () => {
+    dir = await mkdtemp(join(tmpdir(), 'klever-docs-'));
+  });
+
+  afterEach(async () => {
+    await rm(dir, { recursive: true, force: true });
+  });
+
+  it('ships a page for each topic', async () => {
+    expect(FRAMEWORK_TOPICS).toEqual(['annotations', 'managed-types', 'storage', 'calls', 'testing']);
+    const doc = await loadFrameworkDoc('storage', dir);
+    expect(doc?.refreshed).toBe(false);
+    expect(doc?.content).toContain('#[storage_mapper');
+    expect(await loadFrameworkDoc('unknown', dir)).toBeUndefined();
+  });
+
+  it('prefers refreshed pages', async () => {
+    await writeFile(join(dir, 'testing.md'), '# Testing (new)');
+    const doc = await loadFrameworkDoc('testing', dir);
+    expect(doc?.refreshed).toBe(true);
+    expect(doc?.content).toBe('# Testing (new)');
+    expect(doc?.title).toBe('Testing contracts');
+  });
+
+  it('downloads each topic and keeps the old page when a download fails', async () => {
+    await writeFile(join(dir, 'calls.md'), 'old calls');
+    const fetchPage = jest.fn<typeof fetch>(async url =>
+      String(url).endsWith('/calls.md') ? page('', 404) : page(`# ${url}`)
+    );
+
+    const results = await refreshFrameworkDocs('https://docs.example/klever-sc/', {
+      dir,
+      fetch: fetchPage,
+    });
+
+    expect(fetchPage).toHaveBeenCalledWith(
+      'https://docs.example/klever-sc/annotations.md',
+      expect.anything()
+    );
+    expect(results.find(r => r.topic === 'calls')).toEqual({
+      topic: 'calls',
+      updated: false,
+      error: 'HTTP 404 Not Found',
+    });
+    expect(results.filter(r => r.updated)).toHaveLength(4);
+    expect(await readFile(join(dir, 'calls.md'), 'utf8')).toBe('old calls');
+    expect(await readFile(join(dir, 'storage.md'), 'utf8')).toBe(
+      '# https://docs.example/klever-sc/storage.md'
+    );
+  });
+});
+
+describe('framework version notes', () => {
+  it('leaves pages for the documented version or an unknown version unchanged', () => {
+    expect(frameworkVersionNote('0.45.0')).toBe('');
+    expect(frameworkDocForVersion('# Storage', '0.45.0')).toBe('# Storage');
+    expect(frameworkDocForVersion('# Storage', undefined)).toBe('# Storage');
+  });
+
+  it('lists the earlier form of APIs changed after an older version', () => {
+    const page = frameworkDocForVersion('# Storage', '0.44.1');
+    expect(page).toContain('On 0.44.1, write these APIs in their earlier form');
+    expect(page).toContain(
+      '| `use klever_sc::derive_imports::*;` | `klever_sc::derive_imports!()` | 0.45.0 |'
+    );
+    // Changes without a version apply to every release and are not listed
+    expect(page).not.toContain('SCResult');
+    expect(page.endsWith('\n# Storage')).toBe(true);
+  });
+
+  it('points newer versions at refresh_docs', () => {
+    expect(frameworkVersionNote('0.46.0')).toContain('run `refresh_docs` for newer pages');
+    expect(frameworkVersionNote('0.46.0')).not.toContain('earlier form');
+  });
+});
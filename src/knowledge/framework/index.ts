@@ -0,0 +1,87 @@
+/**
+ * klever-sc framework documentation by topic. The pages ship with the server;
+ * `refreshFrameworkDocs` downloads newer ones (`<topic>.md` under a base URL)
+ * into a local directory, and cached pages take precedence over shipped ones.
+ */
+
+import { mkdir, readFile, writeFile } from 'node:fs/promises';
+import { join } from 'node:path';
+import { getConfig } from '../../config/index.js';
+import type { FrameworkDoc } from './types.js';
+import { annotationsDoc } from './annotations.js';
+import { managedTypesDoc } from './managed-types.js';
+import { storageDoc } from './storage.js';
+import { callsDoc } from './calls.js';
+import { testingDoc } from './testing.js';
+
+export type { FrameworkDoc } from './types.js';
+export { frameworkDocForVersion, frameworkVersionNote } from './versions.js';
+
+const FETCH_TIMEOUT_MS = 15000;
+
+export const frameworkDocs: FrameworkDoc[] = [
+  annotationsDoc,
+  managedTypesDoc,
+  storageDoc,
+  callsDoc,
+  testingDoc,
+];
+
+export const FRAMEWORK_TOPICS = frameworkDocs.map(doc => doc.topic);
+
+/** Directory of refreshed pages: `cache.docs_dir` or KLEVER_DOCS_DIR, default ~/.klever-mcp/docs */
+export function frameworkDocsDir(): string {
+  return getConfig().cache.docsDir;
+}
+
+/** The page of a topic, refreshed copy first; undefined for unknown topics */
+export async function loadFrameworkDoc(
+  topic: string,
+  dir = frameworkDocsDir()
+): Promise<(FrameworkDoc & { refreshed: boolean }) | undefined> {
+  const shipped = frameworkDocs.find(doc => doc.topic === topic);
+  if (!shipped) return undefined;
+  try {
+    const content = await readFile(join(dir, `${topic}.md`), 'utf8');
+    if (content.trim()) return { ...shipped, content, refreshed: true };
+  } catch {
+    // Not refreshed: use the shipped page
+  }
+  return { ...shipped, refreshed: false };
+}
+
+export interface RefreshResult {
+  topic: string;
+  updated: boolean;
+  error?: string;
+}
+
+/**
+ * Download `<baseUrl>/<topic>.md` for every topic into `dir`. Topics that fail
+ * keep their previous page; the others are reported as updated.
+ */
+export async function refreshFrameworkDocs(
+  baseUrl: string,
+  options: { dir?: string; fetch?: typeof fetch } = {}
+): Promise<RefreshResult[]> {
+  const { dir = frameworkDocsDir(), fetch: fetchPage = fetch } = options;
+  const base = baseUrl.replace(/\/+$/, '');
+  await mkdir(dir, { recursive: true });
+  return Promise.all(
+    FRAMEWORK_TOPICS.map(async (topic): Promise<RefreshResult> => {
+      try {
+        const response = await fetchPage(`${base}/${topic}.md`, {
+          headers: { Accept: 'text/markdown, text/plain' },
+          signal: AbortSignal.timeout(FETCH_TIMEOUT_MS),
+        });
+        if (!response.ok) throw new Error(`HTTP ${response.status} ${response.statusText}`);
+        const content = await response.text();
+        if (!content.trim()) throw new Error('empty page');
+        await writeFile(join(dir, `${topic}.md`), content);
+        return { topic, updated: true };
+      } catch (error) {
+        return { topic, updated: false, error: (error as Error).message };
+      }
+    })
+  );
+}
@@ -0,0 +1,97 @@
+import type { FrameworkDoc } from './types.js';
+
+export const managedTypesDoc: FrameworkDoc = {
+  topic: 'managed-types',
+  title: 'Managed types',
+  summary:
+    'BigUint, ManagedBuffer, ManagedAddress, ManagedVec and the other types the VM keeps for the contract',
+  content: `# Managed types
+
+Contracts are \`no_std\` and run without an allocator of their own: values like big numbers, byte
+buffers and lists live in the VM and the contract holds handles to them. These managed types are
+generic over the API (\`M: ManagedTypeApi\`); inside a contract trait write them without the
+parameter (\`BigUint\`) or with \`Self::Api\` in struct definitions.
+
+| Type | Use for |
+|---|---|
+| \`BigUint\` | Token amounts and any unsigned number that may exceed u64 |
+| \`BigInt\` | Signed big numbers |
+| \`ManagedBuffer\` | Bytes and text (names, hashes, arbitrary data) |
+| \`ManagedAddress\` | 32-byte account or contract addresses |
+| \`TokenIdentifier\` | KDA token identifiers (\`KFI\`, \`USDT-A1B2\`) |
+| \`KdaTokenPayment\` | A token payment: identifier, nonce and amount |
+| \`ManagedVec<T>\` | Lists of managed values or plain values |
+| \`ManagedByteArray<N>\` | Fixed-size byte arrays, e.g. 32-byte hashes |
+| \`BigFloat\` | Fixed-point arithmetic (costly; prefer integer math) |
+
+Plain Rust types (\`u8\`-\`u64\`, \`i8\`-\`i64\`, \`bool\`, \`usize\`) are fine for small values. \`String\`,
+\`Vec\` and \`Box\` need the allocator and should not be used in contracts.
+
+## BigUint
+
+\`\`\`rust
+let amount = BigUint::from(1_000_000u64);
+let fee = &amount * 3u32 / 100u32;
+let rest = &amount - &fee; // panics (fails the transaction) on underflow
+require!(rest > 0u32, "Nothing left after fees");
+\`\`\`
+
+- Operators work on owned values and references; use references (\`&a + &b\`) to avoid clones.
+- Subtraction below zero fails the transaction; check with \`require!\` first to give a clear error.
+- Convert with \`to_u64()\` (returns \`Option<u64>\`) and \`BigUint::from(u64)\`.
+- KLV has 6 decimals: 1 KLV is \`BigUint::from(1_000_000u64)\`.
+
+## ManagedBuffer
+
+\`\`\`rust
+let name = ManagedBuffer::from(b"Klever");
+let mut key = ManagedBuffer::new();
+key.append(&name);
+key.append_bytes(b"-suffix");
+require!(name.len() <= 32, "Name too long");
+\`\`\`
+
+Arguments and results declared as \`ManagedBuffer\` are raw bytes; text is UTF-8 by convention.
+
+## ManagedAddress
+
+\`\`\`rust
+let caller = self.blockchain().get_caller();
+let contract = self.blockchain().get_sc_address();
+require!(caller != ManagedAddress::zero(), "Invalid address");
+\`\`\`
+
+## ManagedVec
+
+\`\`\`rust
+let mut winners = ManagedVec::<Self::Api, ManagedAddress>::new();
+winners.push(caller.clone());
+for winner in winners.iter() {
+    // items of managed types are returned by reference (ManagedRef)
+}
+\`\`\`
+
+Only types with a fixed encoded size or managed handles can be stored in a \`ManagedVec\`.
+
+## Custom types
+
+\`\`\`rust
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Stake<M: ManagedTypeApi> {
+    pub owner: ManagedAddress<M>,
+    pub amount: BigUint<M>,
+    pub unlock_epoch: u64,
+}
+\`\`\`
+
+Use it in the contract as \`Stake<Self::Api>\`. Enums derive the same traits; their variant index is
+encoded as one byte.
+
+## Multi-values
+
+\`MultiValue2<A, B>\`, \`MultiValueEncoded<T>\` and \`OptionalValue<T>\` spread over several arguments or
+results instead of being encoded as one value. Return \`MultiValueEncoded\` from views that list many
+items so clients receive one result per item.
+`,
+};
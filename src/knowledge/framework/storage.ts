@@ -0,0 +1,64 @@
+import type { FrameworkDoc } from './types.js';
+
+export const storageDoc: FrameworkDoc = {
+  topic: 'storage',
+  title: 'Storage mappers',
+  summary: 'Declaring storage with #[storage_mapper], choosing a mapper, key layout and costs',
+  content: `# Storage mappers
+
+Contract state lives in a key-value store. A storage mapper method declares a typed view of part
+of it; the method's arguments become part of the key.
+
+\`\`\`rust
+#[storage_mapper("balance")]
+fn balance(&self, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+\`\`\`
+
+The key of \`balance(alice)\` is the bytes of \`"balance"\` followed by Alice's 32-byte address.
+Reads of unset keys return the type's default (zero, empty), so there is no difference between
+"never set" and "set to zero" unless you track it.
+
+## Choosing a mapper
+
+| Mapper | Stores | Iterable | Use for |
+|---|---|---|---|
+| \`SingleValueMapper<T>\` | One value per key | No | Config, counters, per-address values keyed by arguments |
+| \`VecMapper<T>\` | Indexed list (1-based) | Yes | Append-mostly lists read by index |
+| \`SetMapper<T>\` | Ordered set | Yes | Members that must be listed in insertion order |
+| \`UnorderedSetMapper<T>\` | Set with O(1) removal | Yes | Members where order does not matter |
+| \`WhitelistMapper<T>\` | Membership flags | No | Allow lists checked but never listed |
+| \`MapMapper<K, V>\` | Key-value map | Yes | Maps that must be listed; costly |
+| \`LinkedListMapper<T>\` | Doubly linked list | Yes | Queues with removal in the middle |
+| \`UniqueIdMapper\` | Permutation of ids | Yes | Random draws without repetition |
+
+Prefer \`SingleValueMapper\` with key arguments over \`MapMapper\` when nothing needs to iterate the
+entries: each \`MapMapper\` insert also writes the linked set that keeps its keys.
+
+## Common operations
+
+\`\`\`rust
+self.total_supply().set(&amount);
+let supply = self.total_supply().get();
+self.total_supply().update(|supply| *supply += &minted);
+self.balance(&caller).clear();
+require!(!self.owner().is_empty(), "Not initialised");
+
+self.members().insert(caller.clone()); // SetMapper / UnorderedSetMapper: false if present
+self.history().push(&entry);           // VecMapper: returns the 1-based index
+let first = self.history().get(1);
+\`\`\`
+
+## Key layout
+
+- Collection mappers store internal entries under suffixes of the base key: \`.len\`, \`.item\`,
+  \`.index\`, \`.info\`, \`.node_links\`, \`.value\`, \`.node_id\`, \`.mapped\`.
+- Keys of different mappers must not be prefixes of one another with compatible arguments, or their
+  entries collide. Namespacing keys (\`"staking:rewards"\`) avoids this across modules.
+- Renaming a storage key in an upgrade orphans the old data; keep keys stable.
+
+## Costs
+
+Every read and write is charged per byte of key and value. Iterating a collection in an endpoint
+costs gas proportional to its size: keep loops bounded, or paginate with views.
+`,
+};
@@ -0,0 +1,91 @@
+import type { FrameworkDoc } from './types.js';
+
+export const testingDoc: FrameworkDoc = {
+  topic: 'testing',
+  title: 'Testing contracts',
+  summary:
+    'Scenario tests with klever_sc_scenario: world setup, calls, queries and expected results',
+  content: `# Testing contracts
+
+Contracts are tested with \`klever_sc_scenario\`, either with Rust tests that drive a simulated
+blockchain or with JSON scenario files (\`scenarios/*.scen.json\`) run by the VM.
+
+## Setup
+
+\`\`\`toml
+[dev-dependencies]
+klever-sc-scenario = "<same version as klever-sc>"
+\`\`\`
+
+\`\`\`rust
+// tests/counter_scenario_rs_test.rs
+use klever_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.register_contract("file:output/counter.wasm", counter::ContractBuilder);
+    blockchain
+}
+
+#[test]
+fn counter_rs() {
+    world().run("scenarios/counter.scen.json");
+}
+\`\`\`
+
+\`ScenarioWorld::vm_go()\` runs the same scenarios against the Go VM instead of the Rust simulation.
+
+## Writing steps in Rust
+
+\`\`\`rust
+#[test]
+fn increment_test() {
+    let mut world = world();
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("address:owner", Account::new().nonce(1).balance("1000000"))
+            .new_address("address:owner", 1, "sc:counter"),
+    );
+    world.sc_deploy_step(
+        ScDeployStep::new()
+            .from("address:owner")
+            .code("file:output/counter.wasm")
+            .call(counter::contract_obj().init(5u64)),
+    );
+    world.sc_call_step(
+        ScCallStep::new()
+            .from("address:owner")
+            .to("sc:counter")
+            .call(counter::contract_obj().increment())
+            .expect(TxExpect::ok()),
+    );
+    world.sc_query_step(
+        ScQueryStep::new()
+            .to("sc:counter")
+            .call(counter::contract_obj().count())
+            .expect(TxExpect::ok().result("6")),
+    );
+}
+\`\`\`
+
+## Scenario values
+
+- \`"address:owner"\`: a test account address derived from the name.
+- \`"sc:counter"\`: a contract address derived from the name.
+- \`"str:text"\`: UTF-8 bytes; \`"0x..."\`: hex; plain numbers are big-endian integers.
+- \`"file:output/counter.wasm"\`: contract code from a file.
+
+## Expecting failures
+
+\`\`\`rust
+.expect(TxExpect::user_error("str:Endpoint can only be called by owner"))
+\`\`\`
+
+Test each \`require!\` message, payments with the wrong token, and calls from non-owners.
+
+## Running
+
+\`cargo test\` runs the Rust tests. Build the contract first (\`ksc all build\`) so the \`.wasm\` the
+scenarios load is current.
+`,
+};
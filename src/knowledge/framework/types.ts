@@ -0,0 +1,13 @@
+/**
+ * Framework documentation pages, one per topic
+ */
+
+export interface FrameworkDoc {
+  /** Topic id, also the resource name: klever://docs/{topic} */
+  topic: string;
+  title: string;
+  /** One line shown in the index */
+  summary: string;
+  /** Markdown */
+  content: string;
+}
@@ -0,0 +1,46 @@
+/**
+ * Version notes for the framework docs. The pages are written against
+ * KLEVER_SC_VERSION; a project on another klever-sc release gets the same page
+ * headed by the API differences that apply to its version.
+ */
+
+import { API_CHANGES } from '../../analysis/deprecated-api.js';
+import { KLEVER_SC_VERSION, compareVersions } from '../../scaffold/framework-version.js';
+
+/** How `version` differs from the version the docs target, as markdown; empty when it matches */
+export function frameworkVersionNote(version: string): string {
+  const diff = compareVersions(version, KLEVER_SC_VERSION);
+  if (diff === 0) return '';
+  if (diff > 0) {
+    return [
+      `> **klever-sc ${version}**: this page is written for ${KLEVER_SC_VERSION}. APIs added since then are not covered; run \`refresh_docs\` for newer pages.`,
+      '',
+    ].join('\n');
+  }
+  const newer = API_CHANGES.filter(
+    change => change.since && compareVersions(version, change.since) < 0
+  );
+  const lines = [
+    `> **klever-sc ${version}**: this page is written for ${KLEVER_SC_VERSION}. Upgrade klever-sc to use it as is (\`check_deprecated_apis\` lists the source changes).`,
+  ];
+  if (newer.length > 0) {
+    lines.push(
+      '',
+      `On ${version}, write these APIs in their earlier form:`,
+      '',
+      '| This page | Before | Since |',
+      '|---|---|---|',
+      ...newer.map(
+        change => `| \`${change.replacement}\` | \`${change.name}\` | ${change.since} |`
+      )
+    );
+  }
+  lines.push('');
+  return lines.join('\n');
+}
+
+/** A docs page as it applies to a project on `version` */
+export function frameworkDocForVersion(content: string, version: string | undefined): string {
+  const note = version ? frameworkVersionNote(version) : '';
+  return note ? `${note}\n${content}` : content;
+}
@@ -51,6 +51,44 @@ export {
   bestPracticesKnowledge,
 };
 
+// Framework documentation pages, served as klever://docs/{topic} resources
+export {
+  frameworkDocForVersion,
+  frameworkDocs,
+  frameworkDocsDir,
+  frameworkVersionNote,
+  FRAMEWORK_TOPICS,
+  loadFrameworkDoc,
+  refreshFrameworkDocs,
+} from './framework/index.js';
+export type { FrameworkDoc, RefreshResult } from './framework/index.js';
+
+// Example contracts with scenario tests, served as klever://examples/{name}
+export {
+  exampleLibrary,
+  EXAMPLE_NAMES,
+  exampleMarkdown,
+  exampleScenario,
+  getLibraryExample,
+} from './library/index.js';
+export type { LibraryExample } from './library/index.js';
+
+// koperator subcommands and argument encoding, served as klever://reference/koperator
+export { KOPERATOR_SUBCOMMANDS, koperatorReference } from './tools/koperator-reference.js';
+
+// klever-sc attribute catalog, served as klever://reference/attributes and used by the linter
+export { CONTRACT_ATTRIBUTES, findContractAttribute } from './framework/attributes.js';
+export type {
+  AttributeArgument,
+  AttributeError,
+  AttributeTarget,
+  ContractAttribute,
+} from './framework/attributes.js';
+
+// Ranked search over knowledge entries, docs pages and templates (search_docs)
+export { markdownSections, SearchIndex, tokenize } from './search.js';
+export type { SearchDocument, SearchHit } from './search.js';
+
 // Export types for external use
 export * from './types.js';
 
@@ -0,0 +1,162 @@
+import type { LibraryExample } from './types.js';
+
+export const asyncCallsExample: LibraryExample = {
+  name: 'async-calls',
+  title: 'Swap router with an async call and callback',
+  summary:
+    'Forwards a KLV payment to another contract through a proxy, then records or refunds it in the #[callback]',
+  topics: ['proxy', 'async_call', 'callback', 'call_result', 'gas', 'refunds'],
+  files: {
+    'src/lib.rs': `#![no_std]
+
+use klever_sc::imports::*;
+
+pub mod pair_proxy;
+
+/// Gas kept back for the callback
+const CALLBACK_GAS: u64 = 10_000_000;
+
+#[klever_sc::contract]
+pub trait Router {
+    #[init]
+    fn init(&self, pair: ManagedAddress) {
+        require!(!pair.is_zero(), "Pair address is required");
+        self.pair().set(&pair);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Swap the KLV sent with the call on the pair contract; refunded if the swap fails
+    #[payable("KLV")]
+    #[endpoint(swapKlv)]
+    fn swap_klv(&self, min_out: BigUint) {
+        let caller = self.blockchain().get_caller();
+        let payment = self.call_value().klv_value().clone_value();
+        require!(payment > 0, "Amount must be positive");
+        let gas_left = self.blockchain().get_gas_left();
+        require!(gas_left > CALLBACK_GAS, "Not enough gas for the async call and its callback");
+
+        // The endpoint ends with the call: everything that must happen first goes above
+        self.pair_contract(self.pair().get())
+            .swap(min_out)
+            .with_klv_transfer(payment.clone())
+            .with_gas_limit(gas_left - CALLBACK_GAS)
+            .async_call()
+            .with_callback(self.callbacks().swap_callback(caller, payment))
+            .call_and_exit();
+    }
+
+    #[callback]
+    fn swap_callback(
+        &self,
+        caller: ManagedAddress,
+        payment: BigUint,
+        #[call_result] result: ManagedAsyncCallResult<BigUint>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(amount_out) => {
+                self.swapped(&caller).update(|total| *total += &amount_out);
+                self.swap_event(&caller, &payment, &amount_out);
+            },
+            ManagedAsyncCallResult::Err(err) => {
+                // The payment came back to this contract: return it to the caller
+                self.send().direct_klv(&caller, &payment);
+                self.swap_failed_event(&caller, &err.err_msg);
+            },
+        }
+    }
+
+    #[view(getPair)]
+    #[storage_mapper("pair")]
+    fn pair(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getSwapped)]
+    fn get_swapped(&self, account: ManagedAddress) -> BigUint {
+        self.swapped(&account).get()
+    }
+
+    #[proxy]
+    fn pair_contract(&self, sc_address: ManagedAddress) -> pair_proxy::Proxy<Self::Api>;
+
+    #[event("swap")]
+    fn swap_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] amount_in: &BigUint,
+        amount_out: &BigUint,
+    );
+
+    #[event("swapFailed")]
+    fn swap_failed_event(&self, #[indexed] caller: &ManagedAddress, message: &ManagedBuffer);
+
+    #[storage_mapper("swapped")]
+    fn swapped(&self, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
+`,
+    'src/pair_proxy.rs': `use klever_sc::imports::*;
+
+/// Endpoints of the \`Pair\` contract
+#[klever_sc::proxy]
+pub trait Pair {
+    #[payable("KLV")]
+    #[endpoint]
+    fn swap(&self, min_out: BigUint) -> BigUint;
+}
+`,
+  },
+  abi: {
+    name: 'Router',
+    constructor: { inputs: [{ name: 'pair', type: 'Address' }], outputs: [] },
+    endpoints: [
+      {
+        name: 'swapKlv',
+        mutability: 'mutable',
+        payableInTokens: ['KLV'],
+        inputs: [{ name: 'min_out', type: 'BigUint' }],
+        outputs: [],
+      },
+      { name: 'getPair', mutability: 'readonly', inputs: [], outputs: [{ type: 'Address' }] },
+      {
+        name: 'getSwapped',
+        mutability: 'readonly',
+        inputs: [{ name: 'account', type: 'Address' }],
+        outputs: [{ type: 'BigUint' }],
+      },
+    ],
+    events: [
+      {
+        identifier: 'swap',
+        inputs: [
+          { name: 'caller', type: 'Address', indexed: true },
+          { name: 'amount_in', type: 'BigUint', indexed: true },
+          { name: 'amount_out', type: 'BigUint' },
+        ],
+      },
+      {
+        identifier: 'swapFailed',
+        inputs: [
+          { name: 'caller', type: 'Address', indexed: true },
+          { name: 'message', type: 'bytes' },
+        ],
+      },
+    ],
+  },
+  // The pair contract is not deployed here: the scenario covers the checks made before the call
+  scenario: {
+    accounts: { owner: {}, alice: { balance: 1000 } },
+    steps: [
+      { type: 'deploy', from: 'owner', args: ['sc:pair'] },
+      { type: 'query', endpoint: 'getPair', expect: { out: ['sc:pair'] } },
+      {
+        type: 'call',
+        from: 'alice',
+        endpoint: 'swapKlv',
+        args: [100],
+        expect: { status: 4, message: 'Amount must be positive' },
+      },
+      { type: 'query', endpoint: 'getSwapped', args: ['alice'], expect: { out: [0] } },
+      { type: 'checkState', account: 'alice', balance: 1000 },
+    ],
+  },
+};
@@ -0,0 +1,53 @@
+import { exampleLibrary, exampleMarkdown, exampleScenario, getLibraryExample } from './index.js';
+import { lintContract } from '../../analysis/index.js';
+
+describe('example library', () => {
+  it('generates a scenario for every example from its ABI', () => {
+    for (const example of exampleLibrary) {
+      const result = exampleScenario(example);
+      expect(result.path).toMatch(/^scenarios\/\w+\.scen\.json$/);
+      const steps = result.scenario.steps as { step: string }[];
+      expect(steps[0].step).toBe('setState');
+      expect(steps[1].step).toBe('scDeploy');
+    }
+  });
+
+  it('lints every example without errors', () => {
+    for (const example of exampleLibrary) {
+      const { 'src/lib.rs': source, ...modules } = example.files;
+      const { findings } = lintContract(source, { modules });
+      expect(findings.filter(f => f.severity === 'error')).toEqual([]);
+    }
+  });
+
+  it('declares every ABI endpoint in the contract source', () => {
+    for (const example of exampleLibrary) {
+      const source = Object.values(example.files).join('\n');
+      for (const endpoint of example.abi.endpoints) {
+        expect(source).toMatch(new RegExp(`#\\[(endpoint|view)(\\(${endpoint.name}\\))?\\]`));
+      }
+    }
+  });
+
+  it('encodes payments, expected errors and storage checks in the testing scenario', () => {
+    const { scenario, path } = exampleScenario(getLibraryExample('testing')!);
+    expect(path).toBe('scenarios/tip_jar.scen.json');
+    const steps = scenario.steps as Record<string, any>[];
+    expect(steps[2].tx.klvValue).toBe('5');
+    expect(steps[2].expect).toMatchObject({ status: '4', message: 'str:Tip below minimum' });
+    expect(steps[7].accounts['sc:tip_jar'].storage).toEqual({
+      'str:minTip': '10',
+      'str:tipped|address:alice': '100',
+      'str:tipped|address:bob': '20',
+      '+': '',
+    });
+  });
+
+  it('renders every file and the scenario as markdown', () => {
+    const page = exampleMarkdown(getLibraryExample('async-calls')!);
+    expect(page).toContain('## src/lib.rs');
+    expect(page).toContain('## src/pair_proxy.rs');
+    expect(page).toContain('## scenarios/router.scen.json');
+    expect(page).toContain('"str:Amount must be positive"');
+  });
+});
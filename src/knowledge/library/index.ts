@@ -0,0 +1,65 @@
+/**
+ * Library of complete example contracts, one per topic (payments, async calls,
+ * modules, NFTs, testing), each with the ABI it builds to and a scenario test
+ * generated from high-level steps. Served as klever://examples/{name}.
+ */
+
+import { parseAbi } from '../../abi/index.js';
+import { generateScenario, type ScenarioResult } from '../../codegen/index.js';
+import type { LibraryExample } from './types.js';
+import { paymentsExample } from './payments.js';
+import { asyncCallsExample } from './async-calls.js';
+import { modulesExample } from './modules.js';
+import { nftExample } from './nft.js';
+import { testingExample } from './testing.js';
+
+export type { LibraryExample } from './types.js';
+
+export const exampleLibrary: LibraryExample[] = [
+  paymentsExample,
+  asyncCallsExample,
+  modulesExample,
+  nftExample,
+  testingExample,
+];
+
+export const EXAMPLE_NAMES = exampleLibrary.map(example => example.name);
+
+export function getLibraryExample(name: string): LibraryExample | undefined {
+  return exampleLibrary.find(example => example.name === name);
+}
+
+/** The example's scenario test as written to `scenarios/<contract>.scen.json` */
+export function exampleScenario(example: LibraryExample): ScenarioResult {
+  return generateScenario(parseAbi(structuredClone(example.abi)), {
+    ...example.scenario,
+    source: example.files['src/lib.rs'],
+  });
+}
+
+/** Markdown page of an example: its files followed by the scenario test */
+export function exampleMarkdown(example: LibraryExample): string {
+  const scenario = exampleScenario(example);
+  const lines = [
+    `# ${example.title}`,
+    '',
+    example.summary,
+    '',
+    `**Topics**: ${example.topics.join(', ')}`,
+    '',
+  ];
+  for (const [path, content] of Object.entries(example.files)) {
+    lines.push(`## ${path}`, '', '```rust', content.trimEnd(), '```', '');
+  }
+  lines.push(
+    `## ${scenario.path}`,
+    '',
+    'Build the contract (`ksc all build`) and run the scenario from a Rust test with `world().run(...)`, as in the testing example.',
+    '',
+    '```json',
+    scenario.json.trimEnd(),
+    '```',
+    ''
+  );
+  return lines.join('\n');
+}
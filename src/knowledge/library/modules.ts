@@ -0,0 +1,117 @@
+import type { LibraryExample } from './types.js';
+
+export const modulesExample: LibraryExample = {
+  name: 'modules',
+  title: 'Counter composed from a pause module',
+  summary:
+    'Splits a contract into a #[klever_sc::module] with its own endpoints, storage and events, wired in as a supertrait',
+  topics: ['module', 'supertraits', 'only_owner', 'pause', 'storage'],
+  files: {
+    'src/lib.rs': `#![no_std]
+
+use klever_sc::imports::*;
+
+pub mod pause;
+
+#[klever_sc::contract]
+pub trait Counter: pause::PauseModule {
+    #[init]
+    fn init(&self, initial: u64) {
+        self.count().set(initial);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[endpoint]
+    fn increment(&self) -> u64 {
+        // Provided by the module, like its endpoints and storage
+        self.require_not_paused();
+        self.count().update(|count| {
+            *count += 1;
+            *count
+        })
+    }
+
+    #[view(getCount)]
+    #[storage_mapper("count")]
+    fn count(&self) -> SingleValueMapper<u64>;
+}
+`,
+    'src/pause.rs': `use klever_sc::imports::*;
+
+/// Owner-controlled pause switch for any contract that lists it as a supertrait
+#[klever_sc::module]
+pub trait PauseModule {
+    #[only_owner]
+    #[endpoint]
+    fn pause(&self) {
+        self.paused().set(true);
+        self.paused_event(true);
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn unpause(&self) {
+        self.paused().set(false);
+        self.paused_event(false);
+    }
+
+    #[view(isPaused)]
+    fn is_paused(&self) -> bool {
+        self.paused().get()
+    }
+
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), "Contract is paused");
+    }
+
+    #[event("paused")]
+    fn paused_event(&self, #[indexed] paused: bool);
+
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+}
+`,
+  },
+  abi: {
+    name: 'Counter',
+    constructor: { inputs: [{ name: 'initial', type: 'u64' }], outputs: [] },
+    endpoints: [
+      { name: 'increment', mutability: 'mutable', inputs: [], outputs: [{ type: 'u64' }] },
+      { name: 'getCount', mutability: 'readonly', inputs: [], outputs: [{ type: 'u64' }] },
+      { name: 'pause', onlyOwner: true, mutability: 'mutable', inputs: [], outputs: [] },
+      { name: 'unpause', onlyOwner: true, mutability: 'mutable', inputs: [], outputs: [] },
+      { name: 'isPaused', mutability: 'readonly', inputs: [], outputs: [{ type: 'bool' }] },
+    ],
+    events: [{ identifier: 'paused', inputs: [{ name: 'paused', type: 'bool', indexed: true }] }],
+  },
+  scenario: {
+    accounts: { owner: {}, alice: {} },
+    steps: [
+      { type: 'deploy', from: 'owner', args: [5] },
+      { type: 'call', from: 'alice', endpoint: 'increment', expect: { out: [6] } },
+      {
+        type: 'call',
+        from: 'alice',
+        endpoint: 'pause',
+        expect: { status: 4, message: 'Endpoint can only be called by owner' },
+      },
+      {
+        type: 'call',
+        from: 'owner',
+        endpoint: 'pause',
+        expect: { events: [{ identifier: 'paused', args: [true] }] },
+      },
+      {
+        type: 'call',
+        from: 'alice',
+        endpoint: 'increment',
+        expect: { status: 4, message: 'Contract is paused' },
+      },
+      { type: 'call', from: 'owner', endpoint: 'unpause' },
+      { type: 'call', from: 'alice', endpoint: 'increment', expect: { out: [7] } },
+      { type: 'query', endpoint: 'getCount', expect: { out: [7] } },
+    ],
+  },
+};
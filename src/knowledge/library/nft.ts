@@ -0,0 +1,162 @@
+import type { LibraryExample } from './types.js';
+
+export const nftExample: LibraryExample = {
+  name: 'nft',
+  title: 'Badge NFT collection',
+  summary:
+    'Issues an NFT collection owned by the contract, mints badges with typed attributes and burns returned ones',
+  topics: ['nft', 'NonFungibleTokenMapper', 'kda_nft_create', 'attributes', 'payable'],
+  files: {
+    'src/lib.rs': `#![no_std]
+
+use klever_sc::imports::*;
+use klever_sc::derive_imports::*;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct BadgeAttributes<M: ManagedTypeApi> {
+    pub event: ManagedBuffer<M>,
+    pub level: u32,
+}
+
+#[klever_sc::contract]
+pub trait Badges {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Issue the collection once; the issue fee is paid in KLV
+    #[only_owner]
+    #[payable("KLV")]
+    #[endpoint(issueCollection)]
+    fn issue_collection(&self, name: ManagedBuffer, ticker: ManagedBuffer) -> TokenIdentifier {
+        require!(self.collection().is_empty(), "Collection already issued");
+        self.collection().issue(&name, &ticker, 0)
+    }
+
+    /// Mint one badge to \`to\` and return its nonce
+    #[only_owner]
+    #[endpoint]
+    fn mint(&self, to: ManagedAddress, event: ManagedBuffer, level: u32) -> u64 {
+        require!(!self.collection().is_empty(), "Collection not issued");
+        require!(!to.is_zero(), "Cannot mint to zero address");
+
+        let token_id = self.collection().get_token_id();
+        let attributes = BadgeAttributes { event: event.clone(), level };
+        let one = BigUint::from(1u32);
+        let nonce = self.send().kda_nft_create(
+            &token_id,
+            &one,
+            &event,
+            &BigUint::zero(),
+            &ManagedBuffer::new(),
+            &attributes,
+            &ManagedVec::new(),
+        );
+        self.minted_count().update(|count| *count += 1);
+
+        self.send().direct_kda(&to, &token_id, nonce, &one);
+        self.mint_event(&to, nonce, level);
+        nonce
+    }
+
+    /// Burn a badge of this collection sent with the call
+    #[payable("*")]
+    #[endpoint]
+    fn burn(&self) {
+        let payment = self.call_value().single_kda();
+        require!(!self.collection().is_empty(), "Collection not issued");
+        require!(
+            payment.token_identifier == self.collection().get_token_id(),
+            "Wrong token"
+        );
+        self.send()
+            .kda_burn(&payment.token_identifier, payment.token_nonce, &payment.amount);
+    }
+
+    #[view(getMintedCount)]
+    #[storage_mapper("mintedCount")]
+    fn minted_count(&self) -> SingleValueMapper<u64>;
+
+    #[event("mint")]
+    fn mint_event(&self, #[indexed] to: &ManagedAddress, #[indexed] nonce: u64, level: u32);
+
+    #[storage_mapper("collection")]
+    fn collection(&self) -> NonFungibleTokenMapper;
+}
+`,
+  },
+  abi: {
+    name: 'Badges',
+    constructor: { inputs: [], outputs: [] },
+    endpoints: [
+      {
+        name: 'issueCollection',
+        onlyOwner: true,
+        mutability: 'mutable',
+        payableInTokens: ['KLV'],
+        inputs: [
+          { name: 'name', type: 'bytes' },
+          { name: 'ticker', type: 'bytes' },
+        ],
+        outputs: [{ type: 'TokenIdentifier' }],
+      },
+      {
+        name: 'mint',
+        onlyOwner: true,
+        mutability: 'mutable',
+        inputs: [
+          { name: 'to', type: 'Address' },
+          { name: 'event', type: 'bytes' },
+          { name: 'level', type: 'u32' },
+        ],
+        outputs: [{ type: 'u64' }],
+      },
+      { name: 'burn', mutability: 'mutable', payableInTokens: ['*'], inputs: [], outputs: [] },
+      { name: 'getMintedCount', mutability: 'readonly', inputs: [], outputs: [{ type: 'u64' }] },
+    ],
+    events: [
+      {
+        identifier: 'mint',
+        inputs: [
+          { name: 'to', type: 'Address', indexed: true },
+          { name: 'nonce', type: 'u64', indexed: true },
+          { name: 'level', type: 'u32' },
+        ],
+      },
+    ],
+    types: {
+      BadgeAttributes: {
+        type: 'struct',
+        fields: [
+          { name: 'event', type: 'bytes' },
+          { name: 'level', type: 'u32' },
+        ],
+      },
+    },
+  },
+  // Issuing runs on the KDA system contract, which scenarios do not provide: the steps cover
+  // the checks made before and without it
+  scenario: {
+    accounts: { owner: {}, alice: {} },
+    steps: [
+      { type: 'deploy', from: 'owner' },
+      {
+        type: 'call',
+        from: 'alice',
+        endpoint: 'mint',
+        args: ['alice', 'hackathon', 1],
+        expect: { status: 4, message: 'Endpoint can only be called by owner' },
+      },
+      {
+        type: 'call',
+        from: 'owner',
+        endpoint: 'mint',
+        args: { to: 'alice', event: 'hackathon', level: 1 },
+        expect: { status: 4, message: 'Collection not issued' },
+      },
+      { type: 'query', endpoint: 'getMintedCount', expect: { out: [0] } },
+    ],
+  },
+};
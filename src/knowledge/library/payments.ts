@@ -0,0 +1,203 @@
+import type { LibraryExample } from './types.js';
+
+export const paymentsExample: LibraryExample = {
+  name: 'payments',
+  title: 'KLV and KDA vault',
+  summary: 'Payable endpoints taking KLV or any fungible KDA, per-account deposits and withdrawals',
+  topics: ['payable', 'call_value', 'direct_klv', 'direct_kda', 'events'],
+  files: {
+    'src/lib.rs': `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait Vault {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Deposit the KLV sent with the call for the caller
+    #[payable("KLV")]
+    #[endpoint]
+    fn deposit(&self) {
+        let amount = self.call_value().klv_value().clone_value();
+        require!(amount > 0, "Amount must be positive");
+
+        let caller = self.blockchain().get_caller();
+        self.deposit_of(&caller).update(|balance| *balance += &amount);
+        self.deposit_event(&caller, &amount);
+    }
+
+    /// Deposit the fungible KDA token sent with the call for the caller
+    #[payable("*")]
+    #[endpoint(depositToken)]
+    fn deposit_token(&self) {
+        let payment = self.call_value().single_kda();
+        require!(payment.token_nonce == 0, "Only fungible tokens");
+        require!(payment.amount > 0, "Amount must be positive");
+
+        let caller = self.blockchain().get_caller();
+        self.token_deposit(&caller, &payment.token_identifier)
+            .update(|balance| *balance += &payment.amount);
+    }
+
+    #[endpoint]
+    fn withdraw(&self, amount: BigUint) {
+        require!(amount > 0, "Amount must be positive");
+        let caller = self.blockchain().get_caller();
+        let balance = self.deposit_of(&caller).get();
+        require!(balance >= amount, "Insufficient deposit");
+
+        // Update state before sending (checks-effects-interactions)
+        self.deposit_of(&caller).set(&(balance - &amount));
+        self.send().direct_klv(&caller, &amount);
+        self.withdraw_event(&caller, &amount);
+    }
+
+    #[endpoint(withdrawToken)]
+    fn withdraw_token(&self, token: TokenIdentifier, amount: BigUint) {
+        require!(amount > 0, "Amount must be positive");
+        let caller = self.blockchain().get_caller();
+        let balance = self.token_deposit(&caller, &token).get();
+        require!(balance >= amount, "Insufficient deposit");
+
+        self.token_deposit(&caller, &token).set(&(balance - &amount));
+        self.send().direct_kda(&caller, &token, 0, &amount);
+    }
+
+    #[view(getDeposit)]
+    fn get_deposit(&self, account: ManagedAddress) -> BigUint {
+        self.deposit_of(&account).get()
+    }
+
+    #[view(getTokenDeposit)]
+    fn get_token_deposit(&self, account: ManagedAddress, token: TokenIdentifier) -> BigUint {
+        self.token_deposit(&account, &token).get()
+    }
+
+    #[event("deposit")]
+    fn deposit_event(&self, #[indexed] account: &ManagedAddress, amount: &BigUint);
+
+    #[event("withdraw")]
+    fn withdraw_event(&self, #[indexed] account: &ManagedAddress, amount: &BigUint);
+
+    #[storage_mapper("deposit")]
+    fn deposit_of(&self, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("tokenDeposit")]
+    fn token_deposit(
+        &self,
+        account: &ManagedAddress,
+        token: &TokenIdentifier,
+    ) -> SingleValueMapper<BigUint>;
+}
+`,
+  },
+  abi: {
+    name: 'Vault',
+    constructor: { inputs: [], outputs: [] },
+    endpoints: [
+      { name: 'deposit', mutability: 'mutable', payableInTokens: ['KLV'], inputs: [], outputs: [] },
+      {
+        name: 'depositToken',
+        mutability: 'mutable',
+        payableInTokens: ['*'],
+        inputs: [],
+        outputs: [],
+      },
+      {
+        name: 'withdraw',
+        mutability: 'mutable',
+        inputs: [{ name: 'amount', type: 'BigUint' }],
+        outputs: [],
+      },
+      {
+        name: 'withdrawToken',
+        mutability: 'mutable',
+        inputs: [
+          { name: 'token', type: 'TokenIdentifier' },
+          { name: 'amount', type: 'BigUint' },
+        ],
+        outputs: [],
+      },
+      {
+        name: 'getDeposit',
+        mutability: 'readonly',
+        inputs: [{ name: 'account', type: 'Address' }],
+        outputs: [{ type: 'BigUint' }],
+      },
+      {
+        name: 'getTokenDeposit',
+        mutability: 'readonly',
+        inputs: [
+          { name: 'account', type: 'Address' },
+          { name: 'token', type: 'TokenIdentifier' },
+        ],
+        outputs: [{ type: 'BigUint' }],
+      },
+    ],
+    events: [
+      {
+        identifier: 'deposit',
+        inputs: [
+          { name: 'account', type: 'Address', indexed: true },
+          { name: 'amount', type: 'BigUint' },
+        ],
+      },
+      {
+        identifier: 'withdraw',
+        inputs: [
+          { name: 'account', type: 'Address', indexed: true },
+          { name: 'amount', type: 'BigUint' },
+        ],
+      },
+    ],
+  },
+  scenario: {
+    accounts: {
+      owner: {},
+      alice: { balance: 1000, kda: { 'USDT-A1B2': 500 } },
+    },
+    steps: [
+      { type: 'deploy', from: 'owner' },
+      {
+        type: 'call',
+        from: 'alice',
+        endpoint: 'deposit',
+        klv: 400,
+        expect: { events: [{ identifier: 'deposit', args: ['alice', 400] }] },
+      },
+      {
+        type: 'call',
+        from: 'alice',
+        endpoint: 'depositToken',
+        kda: [{ token: 'USDT-A1B2', amount: 200 }],
+      },
+      {
+        type: 'call',
+        from: 'alice',
+        endpoint: 'withdraw',
+        args: [500],
+        expect: { status: 4, message: 'Insufficient deposit' },
+      },
+      {
+        type: 'call',
+        from: 'alice',
+        endpoint: 'withdraw',
+        args: [150],
+        expect: { events: [{ identifier: 'withdraw', args: ['alice', 150] }] },
+      },
+      { type: 'query', endpoint: 'getDeposit', args: ['alice'], expect: { out: [250] } },
+      {
+        type: 'query',
+        endpoint: 'getTokenDeposit',
+        args: ['alice', 'USDT-A1B2'],
+        expect: { out: [200] },
+      },
+      { type: 'checkState', account: 'alice', balance: 750 },
+      { type: 'checkState', storage: [{ mapper: 'deposit_of', args: ['alice'], value: 250 }] },
+    ],
+  },
+};
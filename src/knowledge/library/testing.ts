@@ -0,0 +1,186 @@
+import type { LibraryExample } from './types.js';
+
+export const testingExample: LibraryExample = {
+  name: 'testing',
+  title: 'Tip jar with scenario and Rust tests',
+  summary:
+    'A small contract tested end to end: the scenario runner, a Rust test that drives calls directly, and a JSON scenario covering events, errors, balances and storage',
+  topics: ['testing', 'scenarios', 'klever_sc_scenario', 'expect', 'user_error'],
+  files: {
+    'src/lib.rs': `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait TipJar {
+    #[init]
+    fn init(&self, min_tip: BigUint) {
+        self.min_tip().set(&min_tip);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[payable("KLV")]
+    #[endpoint]
+    fn tip(&self, message: ManagedBuffer) {
+        let amount = self.call_value().klv_value().clone_value();
+        require!(amount >= self.min_tip().get(), "Tip below minimum");
+        require!(message.len() <= 64, "Message too long");
+
+        let caller = self.blockchain().get_caller();
+        self.tipped(&caller).update(|total| *total += &amount);
+        self.tip_event(&caller, &amount, &message);
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn claim(&self) {
+        let balance = self.blockchain().get_sc_balance(&TokenIdentifier::klv(), 0);
+        require!(balance > 0, "Nothing to claim");
+        let owner = self.blockchain().get_caller();
+        self.send().direct_klv(&owner, &balance);
+    }
+
+    #[view(getTipped)]
+    fn get_tipped(&self, account: ManagedAddress) -> BigUint {
+        self.tipped(&account).get()
+    }
+
+    #[event("tip")]
+    fn tip_event(
+        &self,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] amount: &BigUint,
+        message: &ManagedBuffer,
+    );
+
+    #[view(getMinTip)]
+    #[storage_mapper("minTip")]
+    fn min_tip(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("tipped")]
+    fn tipped(&self, account: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
+`,
+    'tests/tip_jar_scenario_rs_test.rs': `use klever_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.register_contract("file:output/tip_jar.wasm", tip_jar::ContractBuilder);
+    blockchain
+}
+
+/// Runs the JSON scenario
+#[test]
+fn tip_jar_rs() {
+    world().run("scenarios/tip_jar.scen.json");
+}
+
+/// The same checks written as Rust steps
+#[test]
+fn tip_below_minimum_test() {
+    let mut world = world();
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account("address:owner", Account::new().nonce(1))
+            .put_account("address:alice", Account::new().nonce(1).balance("1000"))
+            .new_address("address:owner", 1, "sc:tip_jar"),
+    );
+    world.sc_deploy_step(
+        ScDeployStep::new()
+            .from("address:owner")
+            .code("file:output/tip_jar.wasm")
+            .call(tip_jar::contract_obj().init(10u64)),
+    );
+    world.sc_call_step(
+        ScCallStep::new()
+            .from("address:alice")
+            .to("sc:tip_jar")
+            .klv_value("5")
+            .call(tip_jar::contract_obj().tip(ManagedBuffer::from("thanks")))
+            .expect(TxExpect::user_error("str:Tip below minimum")),
+    );
+    world.sc_query_step(
+        ScQueryStep::new()
+            .to("sc:tip_jar")
+            .call(tip_jar::contract_obj().min_tip())
+            .expect(TxExpect::ok().result("10")),
+    );
+}
+`,
+  },
+  abi: {
+    name: 'TipJar',
+    constructor: { inputs: [{ name: 'min_tip', type: 'BigUint' }], outputs: [] },
+    endpoints: [
+      {
+        name: 'tip',
+        mutability: 'mutable',
+        payableInTokens: ['KLV'],
+        inputs: [{ name: 'message', type: 'bytes' }],
+        outputs: [],
+      },
+      { name: 'claim', onlyOwner: true, mutability: 'mutable', inputs: [], outputs: [] },
+      {
+        name: 'getTipped',
+        mutability: 'readonly',
+        inputs: [{ name: 'account', type: 'Address' }],
+        outputs: [{ type: 'BigUint' }],
+      },
+      { name: 'getMinTip', mutability: 'readonly', inputs: [], outputs: [{ type: 'BigUint' }] },
+    ],
+    events: [
+      {
+        identifier: 'tip',
+        inputs: [
+          { name: 'from', type: 'Address', indexed: true },
+          { name: 'amount', type: 'BigUint', indexed: true },
+          { name: 'message', type: 'bytes' },
+        ],
+      },
+    ],
+  },
+  scenario: {
+    name: 'tip_jar',
+    accounts: { owner: {}, alice: { balance: 1000 }, bob: { balance: 50 } },
+    steps: [
+      { type: 'deploy', from: 'owner', args: [10] },
+      {
+        type: 'call',
+        from: 'alice',
+        endpoint: 'tip',
+        args: ['thanks'],
+        klv: 5,
+        expect: { status: 4, message: 'Tip below minimum' },
+      },
+      {
+        type: 'call',
+        from: 'alice',
+        endpoint: 'tip',
+        args: ['thanks'],
+        klv: 100,
+        expect: { events: [{ identifier: 'tip', args: ['alice', 100, 'thanks'] }] },
+      },
+      { type: 'call', from: 'bob', endpoint: 'tip', args: ['gm'], klv: 20 },
+      {
+        type: 'call',
+        from: 'bob',
+        endpoint: 'claim',
+        expect: { status: 4, message: 'Endpoint can only be called by owner' },
+      },
+      { type: 'query', endpoint: 'getTipped', args: ['alice'], expect: { out: [100] } },
+      {
+        type: 'checkState',
+        balance: 120,
+        storage: [
+          { mapper: 'min_tip', value: 10 },
+          { mapper: 'tipped', args: ['alice'], value: 100 },
+          { mapper: 'tipped', args: ['bob'], value: 20 },
+        ],
+      },
+      { type: 'call', from: 'owner', endpoint: 'claim' },
+      { type: 'checkState', account: 'owner', balance: 120 },
+    ],
+  },
+};
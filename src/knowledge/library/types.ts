@@ -0,0 +1,21 @@
+import type { Abi } from '../../abi/index.js';
+import type { ScenarioSpec } from '../../codegen/index.js';
+
+/**
+ * A complete example contract with the ABI it builds to and a scenario test
+ */
+export interface LibraryExample {
+  /** Resource name: klever://examples/{name} */
+  name: string;
+  title: string;
+  /** One line shown in the index and in resources/list */
+  summary: string;
+  /** What the example demonstrates, e.g. payable, callbacks */
+  topics: string[];
+  /** Crate files keyed by path relative to the crate root, src/lib.rs first */
+  files: Record<string, string>;
+  /** ABI of the built contract, used to encode the scenario */
+  abi: Abi;
+  /** Steps of the accompanying scenario test */
+  scenario: ScenarioSpec;
+}
@@ -0,0 +1,76 @@
+import { markdownSections, SearchIndex, tokenize } from './search.js';
+
+describe('tokenize', () => {
+  it('splits identifiers, drops stop words and stems', () => {
+    expect(tokenize('How do I accept payments?')).toEqual(['accept', 'payment']);
+    expect(tokenize('self.call_value().all_kda_transfers()')).toEqual([
+      'self',
+      'call_value',
+      'call',
+      'value',
+      'all_kda_transfer',
+      'all',
+      'kda',
+      'transfer',
+    ]);
+    expect(tokenize('SingleValueMapper')).toEqual([
+      'singlevaluemapper',
+      'single',
+      'value',
+      'mapper',
+    ]);
+  });
+});
+
+describe('SearchIndex', () => {
+  const index = new SearchIndex([
+    {
+      source: 'klever://docs/calls#receiving-payments',
+      kind: 'docs',
+      title: 'Payments and calls: Receiving payments',
+      text: '#[payable("*")]\n#[endpoint]\nfn stake(&self) {\n    let payments = self.call_value().all_kda_transfers();\n}',
+    },
+    {
+      source: 'klever://docs/storage',
+      kind: 'docs',
+      title: 'Storage mappers',
+      text: 'SingleValueMapper stores one value per key.',
+    },
+    {
+      source: 'template:adder/src/lib.rs',
+      kind: 'template',
+      title: 'adder template',
+      text: '#[endpoint]\nfn add(&self, value: BigUint) {}',
+      language: 'rust',
+    },
+  ]);
+
+  it('ranks documents for plain-language questions', () => {
+    const [first] = index.search('how do I accept multiple KDA payments');
+    expect(first.source).toBe('klever://docs/calls#receiving-payments');
+    expect(first.snippet).toContain('all_kda_transfers');
+    expect(first.lines[0]).toBe(1);
+  });
+
+  it('filters by kind and returns nothing without matching terms', () => {
+    expect(index.search('endpoint', { kinds: ['template'] }).map(hit => hit.source)).toEqual([
+      'template:adder/src/lib.rs',
+    ]);
+    expect(index.search('zzz unrelated')).toEqual([]);
+  });
+});
+
+describe('markdownSections', () => {
+  it('splits a page at its ## headings', () => {
+    const sections = markdownSections({
+      source: 'klever://docs/calls',
+      title: 'Payments and calls',
+      content: '# Payments and calls\n\nIntro.\n\n## Sending tokens\n\nUse direct_klv.',
+    });
+    expect(sections.map(s => [s.source, s.title])).toEqual([
+      ['klever://docs/calls', 'Payments and calls'],
+      ['klever://docs/calls#sending-tokens', 'Payments and calls: Sending tokens'],
+    ]);
+    expect(sections[1].text).toBe('Use direct_klv.');
+  });
+});
@@ -0,0 +1,239 @@
+/**
+ * Ranked keyword search over the knowledge base, framework docs and contract
+ * templates. Documents are indexed with BM25 over normalised terms: words and
+ * the parts of snake_case and camelCase identifiers, lowercased and lightly
+ * stemmed, with titles and tags weighted above the body. Query terms are
+ * expanded with a few Klever-specific synonyms so questions phrased in plain
+ * language ("accept multiple payments") reach the API names
+ * (`#[payable("*")]`, `all_kda_transfers`).
+ */
+
+export interface SearchDocument {
+  /** Where the text comes from, e.g. klever://docs/calls#receiving-payments */
+  source: string;
+  kind: 'knowledge' | 'docs' | 'template';
+  title: string;
+  text: string;
+  tags?: string[];
+  /** Language of code in the text, for the snippet fence */
+  language?: string;
+}
+
+export interface SearchHit {
+  source: string;
+  kind: SearchDocument['kind'];
+  title: string;
+  score: number;
+  /** The lines of the document that match the query best */
+  snippet: string;
+  /** 1-based line range of the snippet in the document text */
+  lines: [number, number];
+  language?: string;
+}
+
+const K1 = 1.2;
+const B = 0.75;
+const TITLE_WEIGHT = 3;
+const TAG_WEIGHT = 2;
+const SYNONYM_WEIGHT = 0.5;
+const SNIPPET_LINES = 12;
+
+const STOP_WORDS = new Set(
+  'a an and are as at be by can do does for from how i in is it my of on or the this to what when where which with you your'.split(' ')
+);
+
+/** Plain-language words and the framework terms they usually mean */
+const SYNONYMS: Record<string, string[]> = {
+  accept: ['payable', 'call_value'],
+  receive: ['payable', 'call_value'],
+  multiple: ['all', 'multi'],
+  many: ['all', 'multi'],
+  send: ['direct', 'transfer'],
+  pay: ['payable', 'payment'],
+  token: ['kda', 'token_identifier'],
+  tokens: ['kda', 'token_identifier'],
+  store: ['storage', 'mapper'],
+  save: ['storage', 'mapper'],
+  owner: ['only_owner'],
+  admin: ['only_admin'],
+  emit: ['event'],
+  log: ['event'],
+  test: ['scenario'],
+  call: ['proxy', 'async_call'],
+  error: ['require', 'sc_panic'],
+  number: ['biguint'],
+  amount: ['biguint'],
+};
+
+function stem(word: string): string {
+  if (word.length > 5 && word.endsWith('ing')) return word.slice(0, -3);
+  if (word.length > 4 && word.endsWith('ed')) return word.slice(0, -2);
+  if (word.length > 3 && word.endsWith('s') && !word.endsWith('ss')) return word.slice(0, -1);
+  return word;
+}
+
+/** Normalised terms of `text`; identifiers yield both their parts and the whole */
+export function tokenize(text: string): string[] {
+  const terms: string[] = [];
+  for (const word of text.match(/[A-Za-z0-9_]+/g) || []) {
+    const parts = word
+      .replace(/([a-z0-9])([A-Z])/g, '$1_$2')
+      .toLowerCase()
+      .split('_')
+      .filter(Boolean);
+    if (parts.length > 1) terms.push(word.toLowerCase());
+    terms.push(...parts);
+  }
+  return terms.filter(term => !STOP_WORDS.has(term)).map(stem);
+}
+
+/** Query terms with their weights: the query's own, then synonyms */
+function queryTerms(query: string): Map<string, number> {
+  const terms = new Map<string, number>();
+  for (const term of tokenize(query)) terms.set(term, 1);
+  for (const word of query.toLowerCase().match(/[a-z0-9_]+/g) || []) {
+    for (const synonym of SYNONYMS[word] || SYNONYMS[stem(word)] || []) {
+      for (const term of tokenize(synonym)) {
+        if (!terms.has(term)) terms.set(term, SYNONYM_WEIGHT);
+      }
+    }
+  }
+  return terms;
+}
+
+interface IndexedDocument {
+  doc: SearchDocument;
+  frequencies: Map<string, number>;
+  length: number;
+}
+
+export class SearchIndex {
+  private docs: IndexedDocument[];
+  private documentFrequency = new Map<string, number>();
+  private averageLength: number;
+
+  constructor(documents: SearchDocument[]) {
+    this.docs = documents.map(doc => {
+      const terms = [
+        ...Array(TITLE_WEIGHT).fill(tokenize(doc.title)).flat(),
+        ...Array(TAG_WEIGHT).fill(tokenize((doc.tags || []).join(' '))).flat(),
+        ...tokenize(doc.text),
+      ];
+      const frequencies = new Map<string, number>();
+      for (const term of terms) frequencies.set(term, (frequencies.get(term) || 0) + 1);
+      for (const term of frequencies.keys()) {
+        this.documentFrequency.set(term, (this.documentFrequency.get(term) || 0) + 1);
+      }
+      return { doc, frequencies, length: terms.length };
+    });
+    this.averageLength =
+      this.docs.reduce((sum, indexed) => sum + indexed.length, 0) / (this.docs.length || 1);
+  }
+
+  get size(): number {
+    return this.docs.length;
+  }
+
+  /** The best-matching documents, highest score first */
+  search(
+    query: string,
+    options: { limit?: number; kinds?: SearchDocument['kind'][] } = {}
+  ): SearchHit[] {
+    const { limit = 5, kinds } = options;
+    const terms = queryTerms(query);
+    const total = this.docs.length;
+    const scored = this.docs
+      .filter(indexed => !kinds || kinds.includes(indexed.doc.kind))
+      .map(indexed => {
+        let score = 0;
+        for (const [term, weight] of terms) {
+          const tf = indexed.frequencies.get(term);
+          if (!tf) continue;
+          const df = this.documentFrequency.get(term) || 0;
+          const idf = Math.log(1 + (total - df + 0.5) / (df + 0.5));
+          const norm = K1 * (1 - B + (B * indexed.length) / this.averageLength);
+          score += weight * idf * ((tf * (K1 + 1)) / (tf + norm));
+        }
+        return { indexed, score };
+      })
+      .filter(result => result.score > 0)
+      .sort((a, b) => b.score - a.score)
+      .slice(0, limit);
+
+    return scored.map(({ indexed, score }) => {
+      const { snippet, lines } = bestSnippet(indexed.doc.text, terms);
+      return {
+        source: indexed.doc.source,
+        kind: indexed.doc.kind,
+        title: indexed.doc.title,
+        score: Math.round(score * 100) / 100,
+        snippet,
+        lines,
+        language: indexed.doc.language,
+      };
+    });
+  }
+}
+
+/** The window of lines with the most weight of query terms */
+function bestSnippet(
+  text: string,
+  terms: Map<string, number>
+): { snippet: string; lines: [number, number] } {
+  const lines = text.split('\n');
+  const lineScores = lines.map(line =>
+    tokenize(line).reduce((sum, term) => sum + (terms.get(term) || 0), 0)
+  );
+  let best = 0;
+  let bestScore = -1;
+  let windowScore = 0;
+  for (let i = 0; i < lines.length; i++) {
+    windowScore += lineScores[i];
+    if (i >= SNIPPET_LINES) windowScore -= lineScores[i - SNIPPET_LINES];
+    const start = Math.max(0, i - SNIPPET_LINES + 1);
+    if (windowScore > bestScore) {
+      bestScore = windowScore;
+      best = start;
+    }
+  }
+  const end = Math.min(lines.length, best + SNIPPET_LINES);
+  return { snippet: lines.slice(best, end).join('\n').trim(), lines: [best + 1, end] };
+}
+
+/** A markdown page split at its `##` headings, one document per section */
+export function markdownSections(
+  page: { source: string; title: string; content: string },
+  kind: SearchDocument['kind'] = 'docs'
+): SearchDocument[] {
+  const sections: SearchDocument[] = [];
+  let title = page.title;
+  let anchor = '';
+  let body: string[] = [];
+  const flush = () => {
+    const text = body.join('\n').trim();
+    if (text) {
+      sections.push({
+        source: anchor ? `${page.source}#${anchor}` : page.source,
+        kind,
+        title: anchor ? `${page.title}: ${title}` : page.title,
+        text,
+      });
+    }
+  };
+  for (const line of page.content.split('\n')) {
+    const heading = line.match(/^##\s+(.+)$/);
+    if (heading) {
+      flush();
+      title = heading[1].trim();
+      anchor = title
+        .toLowerCase()
+        .replace(/[^a-z0-9]+/g, '-')
+        .replace(/^-|-$/g, '');
+      body = [];
+    } else {
+      body.push(line);
+    }
+  }
+  flush();
+  return sections;
+}
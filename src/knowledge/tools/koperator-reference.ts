@@ -0,0 +1,156 @@
+/**
+ * koperator subcommand reference, served as klever://reference/koperator.
+ * The argument table is the mapping generate_koperator_command uses.
+ */
+
+import { KOPERATOR_ARG_PREFIXES, KOPERATOR_BIN } from '../../codegen/koperator.js';
+
+export interface KoperatorFlag {
+  flag: string;
+  description: string;
+}
+
+export interface KoperatorSubcommand {
+  usage: string;
+  summary: string;
+  flags: KoperatorFlag[];
+}
+
+export const KOPERATOR_GLOBAL_FLAGS: KoperatorFlag[] = [
+  {
+    flag: '--key-file=<path>',
+    description: 'Wallet key file that signs (usually "$HOME/klever-sdk/walletKey.pem")',
+  },
+  {
+    flag: '--node=<url>',
+    description: 'Node to build and broadcast through; the KLEVER_NODE variable does the same',
+  },
+];
+
+const SEND_FLAGS: KoperatorFlag[] = [
+  { flag: '--await', description: 'Wait until the transaction is included in a block' },
+  { flag: '--sign', description: 'Sign and broadcast without prompting' },
+  { flag: '--result-only', description: 'Print only the JSON result (needed in scripts)' },
+];
+
+const CODE_METADATA_FLAGS: KoperatorFlag[] = [
+  { flag: '--upgradeable', description: 'The owner can upgrade the code later' },
+  { flag: '--readable', description: 'Storage can be read by external queries' },
+  { flag: '--payable', description: 'Can receive payments through its payable endpoints' },
+  { flag: '--payableBySC', description: 'Accepts payments from other contracts' },
+];
+
+export const KOPERATOR_SUBCOMMANDS: KoperatorSubcommand[] = [
+  {
+    usage: 'account address',
+    summary: 'Print the address of the key file',
+    flags: [],
+  },
+  {
+    usage: 'account balance',
+    summary: 'Print the KLV balance of the key file account',
+    flags: [],
+  },
+  {
+    usage: 'account info',
+    summary: 'Print the account as JSON: balance, nonce, assets and permissions',
+    flags: [],
+  },
+  {
+    usage: 'account nonce',
+    summary: 'Print the account nonce',
+    flags: [],
+  },
+  {
+    usage: 'sc create',
+    summary: 'Deploy a contract; the constructor arguments follow as --args',
+    flags: [
+      { flag: '--wasm=<path>', description: 'Built contract (output/<name>.wasm)' },
+      ...CODE_METADATA_FLAGS,
+      { flag: '--args "<type>:<value>"', description: 'One constructor argument per flag' },
+      ...SEND_FLAGS,
+    ],
+  },
+  {
+    usage: 'sc upgrade <contract>',
+    summary: 'Replace the code of a contract deployed as upgradeable; runs #[upgrade]',
+    flags: [
+      { flag: '--wasm=<path>', description: 'New build of the contract' },
+      { flag: '--args "<type>:<value>"', description: 'One #[upgrade] argument per flag' },
+      ...SEND_FLAGS,
+    ],
+  },
+  {
+    usage: 'sc invoke <contract> <endpoint>',
+    summary: 'Call an endpoint; contract and endpoint are positional, never --contract/--function',
+    flags: [
+      { flag: '--args "<type>:<value>"', description: 'One endpoint argument per flag' },
+      {
+        flag: '--values "<token>=<amount>,..."',
+        description: 'Payments in the smallest unit; NFTs and SFTs as <token>/<nonce>=<amount>',
+      },
+      ...SEND_FLAGS,
+    ],
+  },
+  {
+    usage: 'kda create <0|1|2>',
+    summary: 'Issue a fungible (0), NFT (1) or SFT (2) asset; costs 20,000 KLV',
+    flags: [
+      { flag: '--name / --ticker / --precision', description: 'Asset name, ticker and decimals' },
+      { flag: '--initialSupply / --maxSupply', description: 'Supply as a float' },
+      { flag: '--canMint --canBurn ...', description: 'Permissions fixed at creation' },
+      ...SEND_FLAGS,
+    ],
+  },
+  {
+    usage: 'kda trigger',
+    summary: 'Change the properties of an asset after creation',
+    flags: SEND_FLAGS,
+  },
+];
+
+function flagTable(flags: KoperatorFlag[]): string[] {
+  return [
+    '| Flag | Description |',
+    '|---|---|',
+    ...flags.map(f => `| \`${f.flag}\` | ${f.description} |`),
+  ];
+}
+
+/** Markdown reference of koperator subcommands and argument encoding */
+export function koperatorReference(): string {
+  const lines = [
+    '# koperator CLI Reference',
+    '',
+    `\`${KOPERATOR_BIN} [global flags] <subcommand> [flags]\``,
+    '',
+    'Views are read through the node API (`query_view`), not koperator.',
+    '',
+    '## Global flags',
+    '',
+    ...flagTable(KOPERATOR_GLOBAL_FLAGS),
+    '',
+  ];
+  for (const command of KOPERATOR_SUBCOMMANDS) {
+    lines.push(`## ${command.usage}`, '', command.summary, '');
+    if (command.flags.length > 0) lines.push(...flagTable(command.flags), '');
+  }
+  lines.push(
+    '## Arguments',
+    '',
+    'Every `--args` value carries a type prefix. `generate_koperator_command` renders them from the ABI as follows:',
+    '',
+    '| ABI type | --args |',
+    '|---|---|',
+    ...Object.entries(KOPERATOR_ARG_PREFIXES).map(
+      ([type, prefix]) => `| \`${type}\` | \`${prefix}:\` |`
+    ),
+    '| `bytes` given as 0x hex | `bytes:0x...` |',
+    '| `Option<T>` set to null | `empty` |',
+    '| structs, enums, arrays, nested lists | `bytes:0x<top-level encoding>` |',
+    '',
+    '`List` items and `tuple` fields are comma-separated (`List:u32:1,u32:2`); `variadic<T>` and `multi<...>` values become one `--args` each, and an omitted trailing `optional<T>` is left out.',
+    ''
+  );
+  return lines.join('\n');
+}
@@ -0,0 +1,29 @@
+import { mkdtempSync, rmSync } from 'fs';
+import { tmpdir } from 'os';
+import { join } from 'path';
+import { loadSigner, parseWalletKey } from '../chain/index.js';
+import { testAccount, testAccounts, walletKeyPem, writeWalletKeys } from './accounts.js';
+
+describe('test accounts', () => {
+  it('derives the same distinct keys for the same names', () => {
+    const [alice, bob] = testAccounts(2);
+    expect(alice.name).toBe('alice');
+    expect(alice.address).toMatch(/^klv1[02-9ac-hj-np-z]{58}$/);
+    expect(testAccount('alice')).toEqual(alice);
+    expect(bob.address).not.toBe(alice.address);
+    expect(() => testAccounts(9)).toThrow('between 0 and 8');
+  });
+
+  it('writes wallet key files the signer loads', async () => {
+    const dir = mkdtempSync(join(tmpdir(), 'klever-localnet-'));
+    try {
+      const [alice] = testAccounts(1);
+      expect(parseWalletKey(walletKeyPem(alice))).toEqual(alice.seed);
+      const paths = await writeWalletKeys([alice], dir);
+      expect(paths.alice).toBe(join(dir, 'alice.pem'));
+      expect((await loadSigner(paths.alice)).address).toBe(alice.address);
+    } finally {
+      rmSync(dir, { recursive: true, force: true });
+    }
+  });
+});
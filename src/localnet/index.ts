@@ -0,0 +1,7 @@
+/**
+ * Local Klever network for offline development: starting, stopping and
+ * resetting a node, and deterministic pre-funded test accounts.
+ */
+
+export * from './node.js';
+export * from './accounts.js';
@@ -0,0 +1,45 @@
+import { mkdtempSync, rmSync, writeFileSync } from 'fs';
+import { tmpdir } from 'os';
+import { join } from 'path';
+import { dockerRunArgs, localNetworkDir, readLocalNetworkState } from './node.js';
+
+describe('dockerRunArgs', () => {
+  it('publishes the node and API ports on localhost', () => {
+    expect(dockerRunArgs({ image: 'klever/node:dev', nodePort: 18080, args: ['--fast'] })).toEqual([
+      'run',
+      '--detach',
+      '--name',
+      'klever-mcp-localnet',
+      '--publish',
+      '127.0.0.1:18080:8080',
+      '--publish',
+      '127.0.0.1:9090:9090',
+      'klever/node:dev',
+      '--fast',
+    ]);
+  });
+});
+
+describe('readLocalNetworkState', () => {
+  const saved = process.env.KLEVER_LOCALNET_DIR;
+  let dir: string;
+
+  beforeEach(() => {
+    dir = mkdtempSync(join(tmpdir(), 'klever-localnet-'));
+    process.env.KLEVER_LOCALNET_DIR = dir;
+  });
+
+  afterEach(() => {
+    rmSync(dir, { recursive: true, force: true });
+    if (saved === undefined) delete process.env.KLEVER_LOCALNET_DIR;
+    else process.env.KLEVER_LOCALNET_DIR = saved;
+  });
+
+  it('reads the recorded node and is undefined without one', async () => {
+    expect(localNetworkDir()).toBe(dir);
+    expect(await readLocalNetworkState()).toBeUndefined();
+    const state = { mode: 'binary', nodeUrl: 'http://127.0.0.1:8080', pid: 42 };
+    writeFileSync(join(dir, 'state.json'), JSON.stringify(state));
+    expect(await readLocalNetworkState()).toEqual(state);
+  });
+});
@@ -0,0 +1,178 @@
+/**
+ * Lifecycle of a local Klever node: a docker container or a node binary
+ * started in the background. The running node is recorded in a state file so
+ * later calls (and later server sessions) can find, stop or reset it.
+ */
+
+import { spawn } from 'child_process';
+import { closeSync, existsSync, openSync } from 'fs';
+import { mkdir, readFile, rm, writeFile } from 'fs/promises';
+import { join } from 'path';
+import { getConfig } from '../config/index.js';
+import { runCommand } from '../toolchain/index.js';
+
+export type LocalNodeMode = 'docker' | 'binary';
+
+/** Image started in docker mode when neither `image` nor `localnet.image` is set */
+export const DEFAULT_LOCALNET_IMAGE = 'kleverapp/klever-go:latest';
+
+export const LOCALNET_CONTAINER = 'klever-mcp-localnet';
+
+export interface LocalNetworkOptions {
+  /** docker (default) or a node binary on this machine */
+  mode?: LocalNodeMode;
+  /** Docker image (default: the `localnet.image` setting or DEFAULT_LOCALNET_IMAGE) */
+  image?: string;
+  /** Node binary for binary mode (default: the `localnet.nodeBinary` setting) */
+  binary?: string;
+  /** Extra arguments for the container command or the binary */
+  args?: string[];
+  /** Host port of the node API (default: 8080) */
+  nodePort?: number;
+  /** Host port of the API proxy (default: 9090) */
+  apiPort?: number;
+}
+
+export interface LocalNetworkState {
+  mode: LocalNodeMode;
+  nodeUrl: string;
+  apiUrl: string;
+  /** Container name in docker mode */
+  container?: string;
+  image?: string;
+  /** Process id in binary mode */
+  pid?: number;
+  binary?: string;
+  startedAt: string;
+}
+
+/** Directory for the state file, chain data and test account keys (`cache.localnet_dir`) */
+export function localNetworkDir(): string {
+  return getConfig().cache.localnetDir;
+}
+
+const statePath = () => join(localNetworkDir(), 'state.json');
+const dataDir = () => join(localNetworkDir(), 'data');
+
+function localNetworkImage(options: LocalNetworkOptions): string {
+  return options.image || getConfig().localnet.image || DEFAULT_LOCALNET_IMAGE;
+}
+
+/** `docker run` arguments for the local node container */
+export function dockerRunArgs(options: LocalNetworkOptions = {}): string[] {
+  return [
+    'run',
+    '--detach',
+    '--name',
+    LOCALNET_CONTAINER,
+    '--publish',
+    `127.0.0.1:${options.nodePort ?? 8080}:8080`,
+    '--publish',
+    `127.0.0.1:${options.apiPort ?? 9090}:9090`,
+    localNetworkImage(options),
+    ...(options.args || []),
+  ];
+}
+
+export async function readLocalNetworkState(): Promise<LocalNetworkState | undefined> {
+  try {
+    return JSON.parse(await readFile(statePath(), 'utf8')) as LocalNetworkState;
+  } catch {
+    return undefined;
+  }
+}
+
+async function docker(args: string[]) {
+  try {
+    return await runCommand('docker', args, { cwd: process.cwd(), timeoutMs: 5 * 60 * 1000 });
+  } catch (error) {
+    throw new Error(
+      `Could not run docker (${(error as Error).message}); install it or use binary mode.`
+    );
+  }
+}
+
+/** Whether the node recorded in `state` is still running */
+export async function isRunning(state: LocalNetworkState): Promise<boolean> {
+  if (state.mode === 'docker') {
+    const result = await docker(['inspect', '--format', '{{.State.Running}}', state.container!]);
+    return result.exitCode === 0 && result.lines[0]?.trim() === 'true';
+  }
+  try {
+    process.kill(state.pid!, 0);
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+/**
+ * Start the local node unless the recorded one is still running. Resolves
+ * once the process is up; the node API may need a few more seconds.
+ */
+export async function startLocalNetwork(
+  options: LocalNetworkOptions = {}
+): Promise<{ state: LocalNetworkState; alreadyRunning: boolean }> {
+  const existing = await readLocalNetworkState();
+  if (existing && (await isRunning(existing))) return { state: existing, alreadyRunning: true };
+
+  await mkdir(dataDir(), { recursive: true });
+  const mode = options.mode || 'docker';
+  const common = {
+    nodeUrl: `http://127.0.0.1:${options.nodePort ?? 8080}`,
+    apiUrl: `http://127.0.0.1:${options.apiPort ?? 9090}`,
+    startedAt: new Date().toISOString(),
+  };
+  let state: LocalNetworkState;
+  if (mode === 'docker') {
+    await docker(['rm', '--force', LOCALNET_CONTAINER]);
+    const result = await docker(dockerRunArgs(options));
+    if (result.exitCode !== 0) throw new Error(`docker run failed:\n${result.output}`);
+    const image = localNetworkImage(options);
+    state = { mode, ...common, container: LOCALNET_CONTAINER, image };
+  } else {
+    const binary = options.binary || getConfig().localnet.nodeBinary;
+    if (!binary) {
+      throw new Error('Binary mode needs binary, localnet.node_binary or KLEVER_NODE_BIN.');
+    }
+    if (!existsSync(binary)) throw new Error(`Node binary not found: ${binary}`);
+    const log = openSync(join(localNetworkDir(), 'node.log'), 'a');
+    const child = spawn(binary, options.args || [], {
+      cwd: dataDir(),
+      detached: true,
+      stdio: ['ignore', log, log],
+    });
+    try {
+      await new Promise<void>((resolve, reject) => {
+        child.once('spawn', resolve);
+        child.once('error', reject);
+      });
+    } finally {
+      closeSync(log);
+    }
+    child.unref();
+    state = { mode, ...common, pid: child.pid, binary };
+  }
+  await writeFile(statePath(), JSON.stringify(state, null, 2));
+  return { state, alreadyRunning: false };
+}
+
+/** Stop the recorded node; resolves to its state, or undefined when none was recorded */
+export async function stopLocalNetwork(): Promise<LocalNetworkState | undefined> {
+  const state = await readLocalNetworkState();
+  if (!state) return undefined;
+  if (state.mode === 'docker') {
+    await docker(['rm', '--force', state.container!]);
+  } else if (await isRunning(state)) {
+    // Detached children lead their own process group; stop the whole group
+    process.kill(-state.pid!, 'SIGTERM');
+  }
+  await rm(statePath(), { force: true });
+  return state;
+}
+
+/** Stop the node and delete its chain data, so the next start begins from genesis */
+export async function resetLocalNetwork(): Promise<void> {
+  await stopLocalNetwork();
+  await rm(dataDir(), { recursive: true, force: true });
+}
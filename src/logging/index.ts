@@ -0,0 +1,8 @@
+/**
+ * Structured logging with spans: tool calls, node requests and toolchain
+ * subprocesses log within the span of the call that caused them, so the
+ * records of one failed call can be found by its trace ID.
+ */
+
+export * from './logger.js';
+export * from './spans.js';
@@ -0,0 +1,127 @@
+import { mkdtemp, rm, writeFile } from 'node:fs/promises';
+import { tmpdir } from 'node:os';
+import { join } from 'node:path';
+import { loadConfig } from '../config/config.js';
+import { LogBuffer, logger, recentLogs, withSpan } from './logger.js';
+import { currentSpan, runInSession } from './spans.js';
+
+describe('withSpan', () => {
+  let consoleErrorSpy: ReturnType<typeof jest.spyOn>;
+
+  beforeEach(() => {
+    recentLogs.clear();
+    consoleErrorSpy = jest.spyOn(console, 'error').mockImplementation((() => {}) as () => void);
+  });
+
+  afterEach(() => {
+    consoleErrorSpy.mockRestore();
+  });
+
+  it('logs within the span and shares the trace with child spans', async () => {
+    const traceId = await withSpan('tool_call', { tool: 'get_balance' }, async span => {
+      logger.info('Tool called');
+      await withSpan('http', { url: 'https://node.example/address/klv1' }, async child => {
+        expect(child.traceId).toBe(span.traceId);
+        expect(child.parentSpanId).toBe(span.spanId);
+      });
+      return span.traceId;
+    });
+    expect(currentSpan()).toBeUndefined();
+
+    const records = recentLogs.query({ traceId });
+    expect(records.map(r => [r.span, r.message])).toEqual([
+      ['tool_call', 'Tool called'],
+      ['http', 'http finished'],
+      ['tool_call', 'tool_call finished'],
+    ]);
+    expect(records[1].fields).toMatchObject({ url: 'https://node.example/address/klv1' });
+  });
+
+  it('logs failures as warnings and rethrows', async () => {
+    await expect(
+      withSpan('subprocess', { command: 'cargo build' }, async () => {
+        throw new Error('exit 101');
+      })
+    ).rejects.toThrow('exit 101');
+    const [record] = recentLogs.query({ level: 'warn' });
+    expect(record.message).toBe('subprocess failed');
+    expect(record.fields).toMatchObject({ command: 'cargo build', error: 'exit 101' });
+  });
+
+  it('writes only records at or above LOG_LEVEL to stderr', () => {
+    logger.debug('hidden');
+    logger.warn('shown', { code: 7 });
+    expect(consoleErrorSpy).toHaveBeenCalledTimes(1);
+    expect(consoleErrorSpy).toHaveBeenCalledWith('[WARN] shown code=7');
+  });
+
+  it('applies the logging settings of each config load', async () => {
+    const dir = await mkdtemp(join(tmpdir(), 'klever-logging-'));
+    const path = join(dir, 'mcp-klever-vm.toml');
+    try {
+      await writeFile(path, '[logging]\nlevel = "debug"\nformat = "json"');
+      await loadConfig(path);
+      logger.debug('shown');
+      expect(JSON.parse(String(consoleErrorSpy.mock.calls[0][0]))).toMatchObject({
+        level: 'debug',
+        message: 'shown',
+      });
+
+      await writeFile(path, '');
+      await loadConfig(path);
+      logger.debug('hidden');
+      expect(consoleErrorSpy).toHaveBeenCalledTimes(1);
+    } finally {
+      await rm(dir, { recursive: true, force: true });
+    }
+  });
+
+  it('tags records with the session they were logged for', async () => {
+    await runInSession('session-a', () =>
+      withSpan('tool_call', { tool: 'deploy_contract' }, async () => {
+        logger.info('Tool called', { args: { keyFile: 'a.pem' } });
+      })
+    );
+    runInSession('session-b', () => logger.info('Tool called', { args: { keyFile: 'b.pem' } }));
+
+    const own = recentLogs.query({ session: 'session-b' });
+    expect(own.map(r => [r.session, r.fields])).toEqual([
+      ['session-b', { args: { keyFile: 'b.pem' } }],
+    ]);
+    expect(recentLogs.query({ session: 'session-a' })).toHaveLength(2);
+  });
+});
+
+describe('LogBuffer', () => {
+  const record = (message: string, level: 'debug' | 'error' = 'debug') => ({
+    time: new Date().toISOString(),
+    level,
+    message,
+  });
+
+  it('keeps the most recent records up to its capacity', () => {
+    const buffer = new LogBuffer(2);
+    ['one', 'two', 'three'].forEach(message => buffer.push(record(message)));
+    expect(buffer.query().map(r => r.message)).toEqual(['two', 'three']);
+    expect(buffer.query({ limit: 1 }).map(r => r.message)).toEqual(['three']);
+  });
+
+  it('applies a capacity setting that changes after it was created', () => {
+    let capacity = 3;
+    const buffer = new LogBuffer(() => capacity);
+    ['one', 'two', 'three'].forEach(message => buffer.push(record(message)));
+    capacity = 1;
+    buffer.push(record('four'));
+    expect(buffer.query().map(r => r.message)).toEqual(['four']);
+  });
+
+  it('filters by level and text', () => {
+    const buffer = new LogBuffer();
+    buffer.push(record('node request'));
+    buffer.push(record('Tool failed: deploy_sc', 'error'));
+    expect(buffer.query({ level: 'warn' }).map(r => r.message)).toEqual([
+      'Tool failed: deploy_sc',
+    ]);
+    expect(buffer.query({ contains: 'NODE' }).map(r => r.message)).toEqual(['node request']);
+  });
+});
@@ -0,0 +1,144 @@
+import { configLoads, getConfig, type Config } from '../config/config.js';
+import { currentSession, currentSpan, runInSpan, type Span } from './spans.js';
+
+export type LogLevel = 'debug' | 'info' | 'warn' | 'error';
+
+export const LOG_LEVELS: LogLevel[] = ['debug', 'info', 'warn', 'error'];
+
+export interface LogRecord {
+  time: string;
+  level: LogLevel;
+  message: string;
+  traceId?: string;
+  spanId?: string;
+  /** Name of the span the record was logged in */
+  span?: string;
+  /** Server session the record was logged for */
+  session?: string;
+  fields?: Record<string, unknown>;
+}
+
+export interface LogQuery {
+  /** Minimum level (default: debug) */
+  level?: LogLevel;
+  traceId?: string;
+  /** Only records logged for this session */
+  session?: string;
+  /** Case-insensitive text in the message or fields */
+  contains?: string;
+  /** Only records newer than this epoch time in milliseconds */
+  since?: number;
+  /** Most recent records returned (default: 100) */
+  limit?: number;
+}
+
+const atLeast = (level: LogLevel, minimum: LogLevel) =>
+  LOG_LEVELS.indexOf(level) >= LOG_LEVELS.indexOf(minimum);
+
+/** The most recent records at every level, for inspecting failures after the fact */
+export class LogBuffer {
+  private records: LogRecord[] = [];
+
+  /** `capacity` may be read at each push, so a setting loaded later applies */
+  constructor(private capacity: number | (() => number) = 1000) {}
+
+  push(record: LogRecord): void {
+    this.records.push(record);
+    const capacity = typeof this.capacity === 'number' ? this.capacity : this.capacity();
+    if (this.records.length > capacity) this.records.splice(0, this.records.length - capacity);
+  }
+
+  /** Matching records, oldest first */
+  query(query: LogQuery = {}): LogRecord[] {
+    const { level = 'debug', traceId, session, since, limit = 100 } = query;
+    const text = query.contains?.toLowerCase();
+    const matches = this.records.filter(
+      record =>
+        atLeast(record.level, level) &&
+        (!traceId || record.traceId === traceId) &&
+        (!session || record.session === session) &&
+        (since === undefined || Date.parse(record.time) > since) &&
+        (!text || JSON.stringify([record.message, record.fields]).toLowerCase().includes(text))
+    );
+    return matches.slice(-limit);
+  }
+
+  clear(): void {
+    this.records = [];
+  }
+}
+
+let settings: { loads: number; logging: Config['logging'] } | undefined;
+
+/** The `logging` settings, resolved once per config load rather than per record */
+function loggingSettings(): Config['logging'] {
+  if (settings?.loads !== configLoads()) {
+    settings = { loads: configLoads(), logging: getConfig().logging };
+  }
+  return settings.logging;
+}
+
+export const recentLogs = new LogBuffer(() => loggingSettings().bufferSize || 1000);
+
+function formatText(record: LogRecord): string {
+  const fields = Object.entries(record.fields || {}).map(
+    ([key, value]) => `${key}=${typeof value === 'string' ? value : JSON.stringify(value)}`
+  );
+  const trace = record.traceId ? [`trace=${record.traceId}`] : [];
+  const level = record.level === 'info' ? '' : `[${record.level.toUpperCase()}] `;
+  return [`${level}${record.message}`, ...fields, ...trace].join(' ');
+}
+
+/**
+ * Record a log entry and write it to stderr when at or above `logging.level`
+ * (LOG_LEVEL, default: info), as JSON lines when `logging.format` is json.
+ * Stdout is reserved for the MCP protocol in stdio mode.
+ */
+function write(level: LogLevel, message: string, fields?: Record<string, unknown>): void {
+  const span = currentSpan();
+  const session = currentSession();
+  const record: LogRecord = {
+    time: new Date().toISOString(),
+    level,
+    message,
+    ...(span && { traceId: span.traceId, spanId: span.spanId, span: span.name }),
+    ...(session && { session }),
+    ...(fields && Object.keys(fields).length > 0 && { fields }),
+  };
+  recentLogs.push(record);
+  const { level: minimum, format } = loggingSettings();
+  if (!LOG_LEVELS.includes(minimum as LogLevel) || atLeast(level, minimum as LogLevel)) {
+    console.error(format === 'json' ? JSON.stringify(record) : formatText(record));
+  }
+}
+
+export const logger = {
+  debug: (message: string, fields?: Record<string, unknown>) => write('debug', message, fields),
+  info: (message: string, fields?: Record<string, unknown>) => write('info', message, fields),
+  warn: (message: string, fields?: Record<string, unknown>) => write('warn', message, fields),
+  error: (message: string, fields?: Record<string, unknown>) => write('error', message, fields),
+};
+
+/**
+ * Run `fn` in a new span, logging its duration when it ends at debug level,
+ * or as a warning with the error when it throws.
+ */
+export async function withSpan<T>(
+  name: string,
+  fields: Record<string, unknown>,
+  fn: (span: Span) => Promise<T>
+): Promise<T> {
+  return runInSpan(name, async span => {
+    const started = Date.now();
+    try {
+      const result = await fn(span);
+      logger.debug(`${name} finished`, { ...fields, durationMs: Date.now() - started });
+      return result;
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      const durationMs = Date.now() - started;
+      logger.warn(`${name} failed`, { ...fields, durationMs, error: message });
+      throw error;
+    }
+  });
+}
@@ -0,0 +1,46 @@
+import { AsyncLocalStorage } from 'node:async_hooks';
+import { randomBytes } from 'node:crypto';
+
+export interface Span {
+  /** ID shared by a tool call and everything it causes */
+  traceId: string;
+  spanId: string;
+  parentSpanId?: string;
+  /** What the span covers, e.g. `tool_call`, `http`, `subprocess` */
+  name: string;
+}
+
+const storage = new AsyncLocalStorage<Span>();
+const sessions = new AsyncLocalStorage<string>();
+
+export function newSpanId(): string {
+  return randomBytes(8).toString('hex');
+}
+
+/** The span the calling code runs in, if any */
+export function currentSpan(): Span | undefined {
+  return storage.getStore();
+}
+
+/** The server session the calling code runs for, if any */
+export function currentSession(): string | undefined {
+  return sessions.getStore();
+}
+
+/** Run `fn` for a server session; the records it logs are only shown to that session */
+export function runInSession<T>(session: string, fn: () => T): T {
+  return sessions.run(session, fn);
+}
+
+/** Run `fn` in a new span, a child of the current one; a new trace when there is none */
+export function runInSpan<T>(name: string, fn: (span: Span) => T): T {
+  const parent = storage.getStore();
+  const spanId = newSpanId();
+  const span: Span = {
+    traceId: parent?.traceId || spanId,
+    spanId,
+    parentSpanId: parent?.spanId,
+    name,
+  };
+  return storage.run(span, () => fn(span));
+}
@@ -0,0 +1,85 @@
+import { ToolConcurrencyLimiter } from './concurrency.js';
+
+/** A call that runs until `finish` is called */
+function call() {
+  let finish!: () => void;
+  const done = new Promise<void>(resolve => (finish = resolve));
+  const started = jest.fn();
+  const fn = async () => {
+    started();
+    await done;
+  };
+  return { fn, started, finish };
+}
+
+const flush = () => new Promise(resolve => setImmediate(resolve));
+
+describe('ToolConcurrencyLimiter', () => {
+  it('queues calls past the limit and runs them in order as slots free up', async () => {
+    const limits = new ToolConcurrencyLimiter([{ tools: ['build_contract'], limit: 1 }]);
+    const first = call();
+    const second = call();
+    const onWait = jest.fn();
+    const running = [
+      limits.run('build_contract', first.fn),
+      limits.run('build_contract', second.fn, { onWait }),
+    ];
+    await flush();
+    expect(first.started).toHaveBeenCalled();
+    expect(second.started).not.toHaveBeenCalled();
+    expect(onWait).toHaveBeenCalledWith({ tools: ['build_contract'], limit: 1 });
+    expect(limits.usage('build_contract')).toEqual([
+      expect.objectContaining({ running: 1, waiting: 1 }),
+    ]);
+
+    first.finish();
+    await flush();
+    expect(second.started).toHaveBeenCalled();
+    second.finish();
+    await Promise.all(running);
+    expect(limits.usage('build_contract')).toEqual([
+      expect.objectContaining({ running: 0, waiting: 0 }),
+    ]);
+  });
+
+  it('shares slots between the tools of a rule and leaves other tools alone', async () => {
+    const limits = new ToolConcurrencyLimiter([
+      { tools: ['build_contract', 'run_tests'], limit: 1 },
+    ]);
+    const build = call();
+    const tests = call();
+    const query = call();
+    void limits.run('build_contract', build.fn);
+    void limits.run('run_tests', tests.fn);
+    void limits.run('query_view', query.fn);
+    await flush();
+    expect(build.started).toHaveBeenCalled();
+    expect(tests.started).not.toHaveBeenCalled();
+    expect(query.started).toHaveBeenCalled();
+    build.finish();
+    await flush();
+    expect(tests.started).toHaveBeenCalled();
+  });
+
+  it('frees the slot of a failed call', async () => {
+    const limits = new ToolConcurrencyLimiter([{ tools: ['build_contract'], limit: 1 }]);
+    const failing = limits.run('build_contract', () => Promise.reject(new Error('cargo failed')));
+    await expect(failing).rejects.toThrow('cargo failed');
+    await expect(limits.run('build_contract', async () => 'built')).resolves.toBe('built');
+  });
+
+  it('stops waiting when the call is cancelled', async () => {
+    const limits = new ToolConcurrencyLimiter([{ tools: ['build_contract'], limit: 1 }]);
+    const first = call();
+    const second = call();
+    const controller = new AbortController();
+    void limits.run('build_contract', first.fn);
+    const cancelled = limits.run('build_contract', second.fn, { signal: controller.signal });
+    controller.abort();
+    await expect(cancelled).rejects.toThrow('Cancelled');
+    expect(limits.usage('build_contract')[0].waiting).toBe(0);
+    first.finish();
+    await flush();
+    expect(second.started).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,110 @@
+/**
+ * Concurrency limits of tools that spawn heavy subprocesses. Tool calls run
+ * concurrently, so a long build_contract does not hold up a quick query_view;
+ * these limits keep clients from starting more cargo builds than the machine
+ * can take, or two local network starts at once. Calls over a limit wait for
+ * a slot in the order they arrived.
+ */
+
+import { z } from 'zod';
+import { CancelledError } from '../utils/cancellation.js';
+
+export const ConcurrencyRuleSchema = z.object({
+  /** Tool names; the listed tools share the rule's slots */
+  tools: z.array(z.string()).min(1),
+  /** Calls that may run at the same time, across all sessions */
+  limit: z.number().int().positive(),
+});
+
+export type ConcurrencyRule = z.infer<typeof ConcurrencyRuleSchema>;
+
+/** Cargo builds contend for CPU and the target directory lock; there is one local node */
+export const DEFAULT_CONCURRENCY_LIMITS: ConcurrencyRule[] = [
+  {
+    tools: [
+      'build_contract',
+      'verify_build',
+      'run_tests',
+      'debug_call',
+      'profile_gas',
+      'simulate_call',
+      'mutation_test',
+      'benchmark_storage',
+    ],
+    limit: 2,
+  },
+  { tools: ['local_network'], limit: 1 },
+];
+
+export interface ConcurrencyOptions {
+  /** Stop waiting for a slot when aborted, e.g. when the client cancels the call */
+  signal?: AbortSignal;
+  /** Called when the call has to wait for a slot of `rule` */
+  onWait?: (rule: ConcurrencyRule) => void;
+}
+
+export class ToolConcurrencyLimiter {
+  /** Calls holding a slot, per rule */
+  private running: number[];
+  /** Calls waiting for a slot, per rule, in arrival order */
+  private queues: Array<Array<() => void>>;
+
+  constructor(private rules: ConcurrencyRule[] = DEFAULT_CONCURRENCY_LIMITS) {
+    this.running = rules.map(() => 0);
+    this.queues = rules.map(() => []);
+  }
+
+  /** Run `fn` once every rule of `tool` has a free slot, and free the slots when it settles */
+  async run<T>(tool: string, fn: () => Promise<T>, options: ConcurrencyOptions = {}): Promise<T> {
+    const acquired: number[] = [];
+    try {
+      // Rules are taken in a fixed order, so two calls cannot each hold a slot the other waits for
+      for (const [index, rule] of this.rules.entries()) {
+        if (!rule.tools.includes(tool)) continue;
+        if (this.running[index] >= rule.limit) options.onWait?.(rule);
+        await this.acquire(index, options.signal);
+        acquired.push(index);
+      }
+      return await fn();
+    } finally {
+      acquired.forEach(index => this.release(index));
+    }
+  }
+
+  /** Calls of the rules that limit `tool`, running and waiting */
+  usage(tool: string): { rule: ConcurrencyRule; running: number; waiting: number }[] {
+    return this.rules.flatMap((rule, index) =>
+      rule.tools.includes(tool)
+        ? [{ rule, running: this.running[index], waiting: this.queues[index].length }]
+        : []
+    );
+  }
+
+  private acquire(index: number, signal?: AbortSignal): Promise<void> {
+    if (signal?.aborted) return Promise.reject(new CancelledError('Cancelled while waiting for a slot'));
+    if (this.running[index] < this.rules[index].limit) {
+      this.running[index]++;
+      return Promise.resolve();
+    }
+    return new Promise((resolve, reject) => {
+      const queue = this.queues[index];
+      const abort = () => {
+        queue.splice(queue.indexOf(grant), 1);
+        reject(new CancelledError('Cancelled while waiting for a slot'));
+      };
+      const grant = () => {
+        signal?.removeEventListener('abort', abort);
+        resolve();
+      };
+      queue.push(grant);
+      signal?.addEventListener('abort', abort, { once: true });
+    });
+  }
+
+  /** Hand the slot to the next waiting call, or free it */
+  private release(index: number): void {
+    const next = this.queues[index].shift();
+    if (next) next();
+    else this.running[index]--;
+  }
+}
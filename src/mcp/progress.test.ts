@@ -0,0 +1,33 @@
+import { ProgressReporter, type ProgressUpdate } from './progress.js';
+
+describe('ProgressReporter', () => {
+  it('counts lines when the tool declares no phases', () => {
+    const updates: ProgressUpdate[] = [];
+    const reporter = new ProgressReporter(update => updates.push(update));
+    reporter.line('Compiling adder');
+    reporter.phase('compiling');
+    expect(updates).toEqual([
+      { progress: 1, message: 'Compiling adder' },
+      { progress: 2, message: 'compiling' },
+    ]);
+  });
+
+  it('reports phases out of the declared ones and keeps progress increasing', () => {
+    const updates: ProgressUpdate[] = [];
+    const phases = ['building transaction', 'signing', 'broadcasting', 'awaiting finality'];
+    const reporter = new ProgressReporter(update => updates.push(update), phases);
+    reporter.phase('building transaction', 'deploy from klv1...');
+    reporter.phase('broadcasting');
+    reporter.line('retrying');
+    reporter.phase('signing');
+    reporter.phase('awaiting finality', 'tx abc');
+    expect(updates.map(u => [u.progress, u.message])).toEqual([
+      [0.5, 'building transaction: deploy from klv1...'],
+      [2, 'broadcasting'],
+      [2.5, 'retrying'],
+      [2 + 2 / 3, 'signing'],
+      [3, 'awaiting finality: tx abc'],
+    ]);
+    expect(updates.every(u => u.total === 4)).toBe(true);
+  });
+});
@@ -0,0 +1,43 @@
+/**
+ * Progress notifications of a tool call. A tool declares its phases
+ * (compiling, optimizing wasm, awaiting finality, ...) and reports each as it
+ * starts, with output lines in between; clients get the phase index out of
+ * the number of phases. Progress must increase with every notification, so
+ * the lines of a phase move it towards the next phase without reaching it.
+ */
+
+export interface ProgressUpdate {
+  progress: number;
+  /** Number of phases, when the tool declares them */
+  total?: number;
+  message: string;
+}
+
+export class ProgressReporter {
+  /** Index of the running phase; calls start in the first */
+  private current = 0;
+  /** Lines reported since the running phase started */
+  private lines = 0;
+
+  constructor(
+    private send: (update: ProgressUpdate) => void,
+    private phases: readonly string[] = []
+  ) {}
+
+  /** Report that `name` started; a phase that is not declared or already passed is a line */
+  phase(name: string, detail?: string): void {
+    const message = detail ? `${name}: ${detail}` : name;
+    const index = this.phases.indexOf(name);
+    if (index <= this.current) return this.line(message);
+    this.current = index;
+    this.lines = 0;
+    this.send({ progress: index, total: this.phases.length, message });
+  }
+
+  line(message: string): void {
+    this.lines++;
+    if (this.phases.length === 0) return this.send({ progress: this.lines, message });
+    const progress = this.current + this.lines / (this.lines + 1);
+    this.send({ progress, total: this.phases.length, message });
+  }
+}
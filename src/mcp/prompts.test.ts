@@ -1,25 +1,36 @@
 import { getPromptDefinitions, getPromptMessages } from './prompts.js';
 
+type ResourceContent = {
+  type: 'resource';
+  resource: { uri: string; mimeType: string; text: string };
+};
+
 describe('getPromptDefinitions', () => {
-  it('returns 4 prompts for local profile', () => {
+  it('returns 7 prompts for local profile', () => {
     const prompts = getPromptDefinitions('local');
-    expect(prompts).toHaveLength(4);
+    expect(prompts).toHaveLength(7);
     expect(prompts.map(p => p.name)).toEqual([
       'create_smart_contract',
       'add_feature',
       'debug_error',
       'review_contract',
+      'audit_contract',
+      'write_tests',
+      'design_tokenomics',
     ]);
   });
 
-  it('returns 4 prompts for public profile', () => {
+  it('returns 7 prompts for public profile', () => {
     const prompts = getPromptDefinitions('public');
-    expect(prompts).toHaveLength(4);
+    expect(prompts).toHaveLength(7);
     expect(prompts.map(p => p.name)).toEqual([
       'create_smart_contract',
       'add_feature',
       'debug_error',
       'review_contract',
+      'audit_contract',
+      'write_tests',
+      'design_tokenomics',
     ]);
   });
 
@@ -58,6 +69,15 @@ describe('getPromptDefinitions', () => {
     expect(prompt.arguments![0].name).toBe('contractName');
     expect(prompt.arguments![0].required).toBe(false);
   });
+
+  it('audit_contract requires sourceCode', () => {
+    const prompts = getPromptDefinitions('local');
+    const prompt = prompts.find(p => p.name === 'audit_contract')!;
+    expect(prompt.arguments!.map(a => [a.name, a.required])).toEqual([
+      ['sourceCode', true],
+      ['contractName', false],
+    ]);
+  });
 });
 
 describe('getPromptMessages', () => {
@@ -294,6 +314,93 @@ describe('getPromptMessages', () => {
     });
   });
 
+  describe('audit_contract', () => {
+    const source = 'pub trait Vault {}';
+
+    it('includes the source and the lint and audit tools', () => {
+      const result = getPromptMessages('audit_contract', { sourceCode: source }, 'local');
+      const text = result.messages[0].content.text;
+      expect(text).toContain(source);
+      expect(text).toContain('lint_contract');
+      expect(text).toContain('audit_access_control');
+      expect(text).toContain('check_deprecated_apis');
+      expect(text).toContain('check_upgrade_safety');
+    });
+
+    it('attaches the analysis rule catalog', () => {
+      const result = getPromptMessages('audit_contract', { sourceCode: source }, 'local');
+      expect(result.messages).toHaveLength(2);
+      const content = result.messages[1].content as ResourceContent;
+      expect(content.type).toBe('resource');
+      expect(content.resource.uri).toBe('klever://analysis/rules');
+      const rules = JSON.parse(content.resource.text);
+      expect(rules.map((c: { name: string }) => c.name)).toContain('attribute-usage');
+    });
+
+    it('asks for the source when it is missing', () => {
+      const result = getPromptMessages('audit_contract', {}, 'local');
+      expect(result.messages[0].content.text).toContain('Ask the user for the contract source');
+    });
+  });
+
+  describe('write_tests', () => {
+    it('attaches the testing guide and example', () => {
+      const result = getPromptMessages('write_tests', {}, 'local');
+      const uris = result.messages.slice(1).map(m => (m.content as ResourceContent).resource.uri);
+      expect(uris).toEqual(['klever://docs/testing', 'klever://examples/testing']);
+      expect((result.messages[2].content as ResourceContent).resource.text).toContain(
+        'scenarios/tip_jar.scen.json'
+      );
+    });
+
+    it('includes the ABI and generate_scenario steps', () => {
+      const result = getPromptMessages(
+        'write_tests',
+        { abi: '{"name":"Vault"}', contractName: 'Vault' },
+        'local'
+      );
+      const text = result.messages[0].content.text;
+      expect(text).toContain('{"name":"Vault"}');
+      expect(text).toContain('generate_scenario');
+      expect(text).not.toContain('Ask the user for the contract ABI');
+      expect(result.description).toContain('Vault');
+    });
+
+    it('runs tests with run_tests locally and asks the user in public mode', () => {
+      expect(getPromptMessages('write_tests', {}, 'local').messages[0].content.text).toContain(
+        'run_tests'
+      );
+      const publicText = getPromptMessages('write_tests', {}, 'public').messages[0].content.text;
+      expect(publicText).not.toContain('run_tests');
+      expect(publicText).toContain('cargo test');
+    });
+  });
+
+  describe('design_tokenomics', () => {
+    it('includes the goals and the token templates', () => {
+      const result = getPromptMessages(
+        'design_tokenomics',
+        { tokenName: 'Acme', goals: '30% staking rewards' },
+        'local'
+      );
+      const text = result.messages[0].content.text;
+      expect(text).toContain('"Acme"');
+      expect(text).toContain('30% staking rewards');
+      for (const template of ['fungible_token', 'vesting', 'staking']) {
+        expect(text).toContain(`\`${template}\``);
+      }
+      expect(text).toContain('get_kda_info');
+    });
+
+    it('attaches the payments example', () => {
+      const result = getPromptMessages('design_tokenomics', {}, 'public');
+      const content = result.messages[1].content as ResourceContent;
+      expect(content.resource.uri).toBe('klever://examples/payments');
+      expect(content.resource.mimeType).toBe('text/markdown');
+      expect(result.messages[0].content.text).toContain('returns the project files');
+    });
+  });
+
   describe('profile-aware content', () => {
     it('public mode mentions template-returning tools for create_smart_contract', () => {
       const result = getPromptMessages('create_smart_contract', {}, 'public');
@@ -1,4 +1,6 @@
-import type { GetPromptResult, Prompt } from '@modelcontextprotocol/sdk/types.js';
+import type { GetPromptResult, Prompt, PromptMessage } from '@modelcontextprotocol/sdk/types.js';
+import { describeRules } from '../analysis/index.js';
+import { exampleMarkdown, frameworkDocs, getLibraryExample } from '../knowledge/index.js';
 import type { ServerProfile } from './server.js';
 
 export function getPromptDefinitions(_profile: ServerProfile): Prompt[] {
@@ -63,6 +65,63 @@ export function getPromptDefinitions(_profile: ServerProfile): Prompt[] {
         },
       ],
     },
+    {
+      name: 'audit_contract',
+      description:
+        'Audit a Klever smart contract with the lint and audit tools, with the analysis rule catalog attached',
+      arguments: [
+        {
+          name: 'sourceCode',
+          description: 'The contract source code (src/lib.rs) to audit',
+          required: true,
+        },
+        {
+          name: 'contractName',
+          description: 'Name of the contract being audited',
+          required: false,
+        },
+      ],
+    },
+    {
+      name: 'write_tests',
+      description:
+        'Write scenario and Rust tests for a Klever smart contract, with the testing guide and example attached',
+      arguments: [
+        {
+          name: 'sourceCode',
+          description: 'The contract source code (src/lib.rs) to test',
+          required: false,
+        },
+        {
+          name: 'abi',
+          description: 'The contract ABI JSON (output/<name>.abi.json)',
+          required: false,
+        },
+        {
+          name: 'contractName',
+          description: 'Name of the contract project',
+          required: false,
+        },
+      ],
+    },
+    {
+      name: 'design_tokenomics',
+      description:
+        'Design a token economy (supply, distribution, vesting, staking rewards) and the contracts that implement it',
+      arguments: [
+        {
+          name: 'tokenName',
+          description: 'Name of the token',
+          required: false,
+        },
+        {
+          name: 'goals',
+          description:
+            'What the token is for and how it should be distributed (e.g. "governance token, 20% team vested over 2 years, staking rewards")',
+          required: false,
+        },
+      ],
+    },
   ];
 }
 
@@ -80,6 +139,12 @@ export function getPromptMessages(
       return buildDebugErrorPrompt(args);
     case 'review_contract':
       return buildReviewContractPrompt(args);
+    case 'audit_contract':
+      return buildAuditContractPrompt(args);
+    case 'write_tests':
+      return buildWriteTestsPrompt(args, profile);
+    case 'design_tokenomics':
+      return buildDesignTokenomicsPrompt(args, profile);
     default:
       throw new Error(`Unknown prompt: ${name}`);
   }
@@ -390,3 +455,188 @@ For each finding:
     messages: [{ role: 'user', content: { type: 'text', text } }],
   };
 }
+
+/** A resource attached to a prompt, so the client does not have to read it separately */
+function resourceMessage(uri: string, mimeType: string, text: string): PromptMessage {
+  return { role: 'user', content: { type: 'resource', resource: { uri, mimeType, text } } };
+}
+
+function exampleResource(name: string): PromptMessage {
+  return resourceMessage(
+    `klever://examples/${name}`,
+    'text/markdown',
+    exampleMarkdown(getLibraryExample(name)!)
+  );
+}
+
+function docsResource(topic: string): PromptMessage {
+  const doc = frameworkDocs.find(d => d.topic === topic)!;
+  return resourceMessage(`klever://docs/${topic}`, 'text/markdown', doc.content);
+}
+
+function buildAuditContractPrompt(args: Record<string, string> | undefined): GetPromptResult {
+  const sourceCode = args?.sourceCode || '';
+  const contractName = args?.contractName || 'the contract';
+
+  const text = `You are auditing a Klever smart contract${contractName !== 'the contract' ? ` ("${contractName}")` : ''} before deployment. The analysis rule catalog (\`klever://analysis/rules\`) is attached: use its rule ids when reporting findings.
+
+${sourceCode ? `**Source code:**\n\`\`\`rust\n${sourceCode}\n\`\`\`\n` : 'Ask the user for the contract source (src/lib.rs and any module files) before starting.\n'}
+Follow each phase below in order. Do NOT skip phases.
+
+## Phase 1 — Automated Checks
+
+Run every tool below on the source (pass module files as \`modules\` when the contract has them) and collect the findings:
+
+1. \`lint_contract({ source: "<contract source>" })\` — validation, state ordering around transfers and calls, arithmetic, storage keys, views, events, attributes and deprecated APIs
+2. \`audit_access_control({ source: "<contract source>" })\` — endpoints that change privileged state without \`#[only_owner]\`, \`#[only_admin]\` or a caller check
+3. \`check_deprecated_apis({ source: "<contract source>" })\` — APIs removed or renamed in the current klever-sc
+4. \`analyze_contract({ sourceCode: "<contract source>" })\` — missing imports, annotations and event emissions
+
+## Phase 2 — Manual Review
+
+The tools do not follow business logic. For each endpoint, check:
+- **Funds**: every payable endpoint checks token and amount; every path that sends funds debits storage first; no endpoint lets a caller withdraw more than they are owed
+- **Async calls**: callbacks re-check state and refund the caller on \`ManagedAsyncCallResult::Err\`
+- **Access control**: owner and admin powers are the minimum needed, and their use emits an event
+- **Storage growth**: collections that anyone can grow are never iterated in full by one transaction
+- **Upgrades**: if a deployed version exists, run \`check_upgrade_safety\` against its source to catch storage layout changes
+
+Query the knowledge base for anything unclear: \`query_context({ query: "security", types: ["security_tip"] })\`.
+
+## Phase 3 — Report
+
+Produce a report grouped by severity (Critical, High, Medium, Low, Informational). For each finding give:
+1. The rule id (from the catalog) or "manual"
+2. Function and line
+3. Impact: what an attacker or a mistake could cause
+4. The fix as a before/after code change
+
+Finish with the list of checks that passed.`;
+
+  return {
+    description: `Security audit of ${contractName}`,
+    messages: [
+      { role: 'user', content: { type: 'text', text } },
+      resourceMessage(
+        'klever://analysis/rules',
+        'application/json',
+        JSON.stringify(describeRules(), null, 2)
+      ),
+    ],
+  };
+}
+
+function buildWriteTestsPrompt(
+  args: Record<string, string> | undefined,
+  profile: ServerProfile
+): GetPromptResult {
+  const sourceCode = args?.sourceCode;
+  const abi = args?.abi;
+  const contractName = args?.contractName || 'the contract';
+
+  const runNote =
+    profile === 'public'
+      ? 'Ask the user to run `cargo test` in the project directory and share the output; fix failures until every test passes.'
+      : 'Run `run_tests({ projectPath: "<project directory>" })` and fix failures until every test passes. Use `kind: "scenario"` to rerun only the scenario tests.';
+
+  const text = `You are writing tests for a Klever smart contract${contractName !== 'the contract' ? ` ("${contractName}")` : ''}. The testing guide (\`klever://docs/testing\`) and a fully tested example contract (\`klever://examples/testing\`) are attached: follow their layout.
+${sourceCode ? `\n**Source code:**\n\`\`\`rust\n${sourceCode}\n\`\`\`\n` : ''}${abi ? `\n**ABI:**\n\`\`\`json\n${abi}\n\`\`\`\n` : ''}
+Follow these steps in order:
+
+## Step 1 — Plan the Cases
+
+${abi ? 'From the ABI and source' : 'Ask the user for the contract ABI (output/<name>.abi.json, produced by `ksc all build`) and source, then from them'} list for every endpoint:
+- The happy path, with the expected outputs, events and storage changes
+- One failing case per \`require!\` or \`sc_panic!\`, with its exact error message
+- Access control: owner-only and admin-only endpoints called by another account
+- Payments: wrong token, zero amount, and the balances after each transfer
+
+## Step 2 — Scenario Tests
+
+Turn the cases into \`generate_scenario\` steps (deploy, call, query, checkState) and generate \`scenarios/<contract>.scen.json\`:
+- \`generate_scenario({ abi: <ABI>, accounts: { owner: {}, alice: { balance: 1000 } }, steps: [...], source: "<contract source>" })\`
+- Pass \`source\` so storage can be checked by mapper name
+- Failing calls use \`expect: { status: 4, message: "<error message>" }\`
+
+## Step 3 — Rust Tests
+
+Add \`tests/<contract>_scenario_rs_test.rs\` registering the contract and running the scenario, as in the attached example. Write Rust steps for cases that are easier to express in code than in JSON.
+
+## Step 4 — Run
+
+${runNote}
+
+Report which cases are covered and any behaviour the tests revealed that looks like a bug.`;
+
+  return {
+    description: `Write tests for ${contractName}`,
+    messages: [
+      { role: 'user', content: { type: 'text', text } },
+      docsResource('testing'),
+      exampleResource('testing'),
+    ],
+  };
+}
+
+function buildDesignTokenomicsPrompt(
+  args: Record<string, string> | undefined,
+  profile: ServerProfile
+): GetPromptResult {
+  const tokenName = args?.tokenName || 'the token';
+  const goals = args?.goals;
+
+  const scaffoldNote =
+    profile === 'public'
+      ? '`scaffold_contract` returns the project files for the user to create locally.'
+      : '`scaffold_contract` writes the project to disk.';
+
+  const text = `You are helping the user design the token economy of ${tokenName !== 'the token' ? `"${tokenName}"` : 'a new token'} on Klever and the contracts that implement it. A complete payments example (\`klever://examples/payments\`) is attached as a reference for handling KLV and KDA transfers.
+${goals ? `\n**Goals:** ${goals}\n` : ''}
+Follow each phase below in order. Do NOT skip phases.
+
+## Phase 1 — Requirements
+
+Ask the user (skip what the goals already answer):
+- Purpose of the token: utility, governance, rewards, payments
+- Total supply, whether it is fixed or mintable, and who may mint or burn
+- Decimals (precision) and ticker
+- Allocation: team, investors, treasury, community, liquidity, staking rewards (percentages that add up to 100%)
+- Vesting: cliff and duration per allocation, and whether schedules are revocable
+- Staking: reward per epoch, minimum stake, where rewards come from and how long they last
+
+## Phase 2 — Knowledge
+
+Query the knowledge base before designing:
+- \`query_context({ query: "token system KLV precision decimals" })\` — units and precision
+- \`search_documentation({ query: "KDA token issue mint burn roles" })\` — issuing and token roles
+- \`get_kda_info({ assetId: "<ticker>" })\` — inspect an existing token the design builds on
+
+## Phase 3 — Economic Model
+
+Produce:
+1. A supply table: allocation, percentage, amount in raw units (amount × 10^decimals), recipient, unlock schedule
+2. A monthly emission and circulating-supply schedule for the first 24 months, including staking rewards
+3. The risks: sell pressure at cliffs, reward funding running out, concentration of holders
+
+Confirm the model with the user before writing contracts.
+
+## Phase 4 — Contracts
+
+Map each part of the model to a contract template with \`scaffold_contract\` (${scaffoldNote}):
+- \`fungible_token\` — token metadata (tokenName, ticker, decimals)
+- \`vesting\` — team and investor schedules (tokenId, maxSchedules, revocable)
+- \`staking\` — staking rewards (tokenId, rewardPerEpoch, minStake)
+
+Use \`advise_storage_mapper\` for any new storage, emit an event for every supply-changing operation, and put minting and allocation behind \`#[only_owner]\` or a governance check.
+
+## Phase 5 — Verify
+
+1. \`lint_contract\` and \`audit_access_control\` on each contract
+2. \`generate_scenario\` tests checking that allocations add up to the total supply and that vesting releases match the schedule
+3. Summarise the final parameters and the deployment order (token, vesting, staking, then funding each contract)`;
+
+  return {
+    description: `Design tokenomics for ${tokenName}`,
+    messages: [{ role: 'user', content: { type: 'text', text } }, exampleResource('payments')],
+  };
+}
@@ -0,0 +1,60 @@
+import { DEFAULT_RATE_LIMITS, ToolRateLimiter } from './rate-limit.js';
+
+const balance = { name: 'get_balance', annotations: { openWorldHint: true } };
+const scaffold = { name: 'scaffold_contract', annotations: { openWorldHint: false } };
+
+function limiter(rules = DEFAULT_RATE_LIMITS) {
+  let time = 0;
+  const limits = new ToolRateLimiter(rules, { now: () => time });
+  return { limits, advance: (ms: number) => (time += ms) };
+}
+
+describe('ToolRateLimiter', () => {
+  const rules = [{ tools: ['*'], limit: 2, windowSeconds: 10, per: 'session' as const }];
+
+  it('refuses calls past the limit with the time until the window frees up', () => {
+    const { limits, advance } = limiter(rules);
+    expect(limits.take('a', balance)).toBeUndefined();
+    advance(4000);
+    expect(limits.take('a', balance)).toBeUndefined();
+    advance(1000);
+    expect(limits.take('a', balance)?.retryAfterSeconds).toBe(5);
+    advance(5000);
+    expect(limits.take('a', balance)).toBeUndefined();
+  });
+
+  it('counts sessions separately for session rules', () => {
+    const { limits } = limiter(rules);
+    limits.take('a', balance);
+    limits.take('a', balance);
+    expect(limits.take('a', balance)).toBeDefined();
+    expect(limits.take('b', balance)).toBeUndefined();
+    limits.forget('a');
+    expect(limits.take('a', balance)).toBeUndefined();
+  });
+
+  it('applies wildcards only to tools that reach the network', () => {
+    const { limits } = limiter(rules);
+    for (let i = 0; i < 5; i++) expect(limits.take('a', scaffold)).toBeUndefined();
+  });
+
+  it('limits the faucet across sessions by default', () => {
+    const { limits } = limiter();
+    const faucet = { name: 'request_test_funds', annotations: { openWorldHint: true } };
+    ['a', 'b', 'c'].forEach(session => expect(limits.take(session, faucet)).toBeUndefined());
+    const exceeded = limits.take('d', faucet);
+    expect(exceeded?.rule.per).toBe('server');
+    expect(exceeded?.retryAfterSeconds).toBe(600);
+  });
+
+  it('does not count refused calls', () => {
+    const { limits, advance } = limiter(rules);
+    limits.take('a', balance);
+    limits.take('a', balance);
+    advance(9000);
+    limits.take('a', balance);
+    advance(1000);
+    expect(limits.take('a', balance)).toBeUndefined();
+    expect(limits.take('a', balance)).toBeUndefined();
+  });
+});
@@ -0,0 +1,86 @@
+/**
+ * Rate limits of tools that reach public Klever nodes, API proxies or the
+ * faucet, so a client stuck in a loop cannot get the server's IP banned.
+ * Each rule counts calls per tool in a sliding window, either per session or
+ * across every session of the server.
+ */
+
+import { z } from 'zod';
+
+export const RateLimitRuleSchema = z.object({
+  /** Tool names; `*` for every tool that reaches the network (openWorldHint) */
+  tools: z.array(z.string()).min(1),
+  limit: z.number().int().positive(),
+  windowSeconds: z.number().positive(),
+  /** Count the calls of each session separately, or of all sessions together */
+  per: z.enum(['session', 'server']).default('session'),
+});
+
+export type RateLimitRule = z.infer<typeof RateLimitRuleSchema>;
+
+/** Faucets fund an address rarely and ban noisy IPs; nodes tolerate bursts of reads */
+export const DEFAULT_RATE_LIMITS: RateLimitRule[] = [
+  { tools: ['request_test_funds'], limit: 3, windowSeconds: 600, per: 'server' },
+  { tools: ['*'], limit: 60, windowSeconds: 60, per: 'session' },
+  { tools: ['*'], limit: 300, windowSeconds: 60, per: 'server' },
+];
+
+export interface RateLimitedTool {
+  name: string;
+  annotations?: { openWorldHint?: boolean };
+}
+
+export interface RateLimitExceeded {
+  rule: RateLimitRule;
+  /** Seconds until the next call is allowed */
+  retryAfterSeconds: number;
+}
+
+export class ToolRateLimiter {
+  /** Call times in the current window, per rule, tool and (for session rules) session */
+  private calls = new Map<string, number[]>();
+  private now: () => number;
+
+  constructor(
+    private rules: RateLimitRule[] = DEFAULT_RATE_LIMITS,
+    options: { now?: () => number } = {}
+  ) {
+    this.now = options.now || Date.now;
+  }
+
+  /**
+   * Record a call of `tool` by `session`, or return the rule it would exceed;
+   * a refused call is not counted.
+   */
+  take(session: string, tool: RateLimitedTool): RateLimitExceeded | undefined {
+    const now = this.now();
+    const buckets: number[][] = [];
+    for (const [index, rule] of this.rules.entries()) {
+      if (!this.applies(rule, tool)) continue;
+      const key = `${index}:${tool.name}:${rule.per === 'session' ? session : ''}`;
+      const windowMs = rule.windowSeconds * 1000;
+      const calls = (this.calls.get(key) || []).filter(time => time > now - windowMs);
+      this.calls.set(key, calls);
+      if (calls.length >= rule.limit) {
+        return { rule, retryAfterSeconds: Math.ceil((calls[0] + windowMs - now) / 1000) };
+      }
+      buckets.push(calls);
+    }
+    buckets.forEach(calls => calls.push(now));
+    return undefined;
+  }
+
+  /** Forget the calls of a closed session */
+  forget(session: string): void {
+    for (const key of [...this.calls.keys()]) {
+      if (key.endsWith(`:${session}`)) this.calls.delete(key);
+    }
+  }
+
+  private applies(rule: RateLimitRule, tool: RateLimitedTool): boolean {
+    return (
+      rule.tools.includes(tool.name) ||
+      (rule.tools.includes('*') && tool.annotations?.openWorldHint === true)
+    );
+  }
+}
@@ -47,31 +47,48 @@ describe('MCP Resources', () => {
   });
 
   describe('getResourceTemplates', () => {
-    it('returns category template for local profile', () => {
+    it('returns category, docs, storage mapper and example templates for local profile', () => {
       const templates = getResourceTemplates('local');
-      expect(templates).toHaveLength(1);
+      expect(templates).toHaveLength(4);
       expect(templates[0].uriTemplate).toBe('klever://knowledge/{category}');
       expect(templates[0].mimeType).toBe('text/markdown');
+      expect(templates[1].uriTemplate).toBe('klever://docs/{topic}{?version}');
+      expect(templates[2].uriTemplate).toBe('klever://storage-mappers/{mapper}');
+      expect(templates[3].uriTemplate).toBe('klever://examples/{name}');
     });
 
-    it('returns category template for public profile', () => {
+    it('returns category, docs, storage mapper and example templates for public profile', () => {
       const templates = getResourceTemplates('public');
-      expect(templates).toHaveLength(1);
+      expect(templates).toHaveLength(4);
       expect(templates[0].uriTemplate).toBe('klever://knowledge/{category}');
     });
   });
 
   describe('getStaticResources', () => {
-    it('returns index resource for local profile', () => {
+    it('returns static resources and one per example for local profile', () => {
       const resources = getStaticResources('local');
-      expect(resources).toHaveLength(1);
+      expect(resources).toHaveLength(12);
       expect(resources[0].uri).toBe('klever://knowledge/index');
       expect(resources[0].mimeType).toBe('text/markdown');
+      expect(resources[1].uri).toBe('klever://docs/index');
+      expect(resources[2].uri).toBe('klever://storage-mappers/index');
+      expect(resources[3].uri).toBe('klever://analysis/rules');
+      expect(resources[3].mimeType).toBe('application/json');
+      expect(resources[4].uri).toBe('klever://reference/attributes');
+      expect(resources[5].uri).toBe('klever://reference/koperator');
+      expect(resources[6].uri).toBe('klever://examples/index');
+      expect(resources.slice(7).map(r => r.uri)).toEqual([
+        'klever://examples/payments',
+        'klever://examples/async-calls',
+        'klever://examples/modules',
+        'klever://examples/nft',
+        'klever://examples/testing',
+      ]);
     });
 
-    it('returns index resource for public profile', () => {
+    it('returns static resources and one per example for public profile', () => {
       const resources = getStaticResources('public');
-      expect(resources).toHaveLength(1);
+      expect(resources).toHaveLength(12);
       expect(resources[0].uri).toBe('klever://knowledge/index');
     });
   });
@@ -100,6 +117,90 @@ describe('MCP Resources', () => {
       expect(result.text).toContain('Event Best Practice');
     });
 
+    it('returns lint rules as JSON for klever://analysis/rules', async () => {
+      const result = await readResource('klever://analysis/rules', contextService);
+      expect(result.mimeType).toBe('application/json');
+      const checks = JSON.parse(result.text);
+      const arithmetic = checks.find((c: { name: string }) => c.name === 'arithmetic');
+      expect(arithmetic.rules.map((r: { id: string }) => r.id)).toEqual([
+        'unchecked-subtraction',
+        'truncating-cast',
+        'unchecked-conversion',
+      ]);
+    });
+
+    it('returns the attribute catalog as JSON', async () => {
+      const result = await readResource('klever://reference/attributes', contextService);
+      expect(result.mimeType).toBe('application/json');
+      const attributes = JSON.parse(result.text);
+      const payable = attributes.find((a: { name: string }) => a.name === 'payable');
+      expect(payable.requiresOneOf).toEqual(['init', 'upgrade', 'endpoint']);
+    });
+
+    it('returns the koperator reference with subcommands and argument prefixes', async () => {
+      const result = await readResource('klever://reference/koperator', contextService);
+      expect(result.mimeType).toBe('text/markdown');
+      expect(result.text).toContain('## sc invoke <contract> <endpoint>');
+      expect(result.text).toContain('| `BigUint` | `bi:` |');
+    });
+
+    it('returns the example library index and examples with their scenario', async () => {
+      const index = await readResource('klever://examples/index', contextService);
+      expect(index.text).toContain('(klever://examples/async-calls)');
+
+      const page = await readResource('klever://examples/modules', contextService);
+      expect(page.text).toContain('## src/pause.rs');
+      expect(page.text).toContain('## scenarios/counter.scen.json');
+    });
+
+    it('throws error for unknown example', async () => {
+      await expect(readResource('klever://examples/dex', contextService)).rejects.toThrow(
+        'Unknown example: "dex"'
+      );
+    });
+
+    it('returns the framework docs index and topics', async () => {
+      const index = await readResource('klever://docs/index', contextService);
+      expect(index.mimeType).toBe('text/markdown');
+      expect(index.text).toContain('(klever://docs/managed-types)');
+
+      const page = await readResource('klever://docs/annotations', contextService);
+      expect(page.text).toContain('#[payable("KLV")]');
+    });
+
+    it('heads docs pages with the differences for an older klever-sc version', async () => {
+      const current = await readResource('klever://docs/storage', contextService);
+      const pinned = await readResource('klever://docs/storage?version=0.45.0', contextService);
+      expect(pinned.text).toBe(current.text);
+
+      const older = await readResource('klever://docs/storage?version=0.44.1', contextService);
+      expect(older.text).toContain('**klever-sc 0.44.1**: this page is written for 0.45.0');
+      expect(older.text).toContain(
+        '| `use klever_sc::imports::*;` | `klever_sc::imports!()` | 0.45.0 |'
+      );
+      expect(older.text.endsWith(current.text)).toBe(true);
+    });
+
+    it('returns the storage mapper index and example pages', async () => {
+      const index = await readResource('klever://storage-mappers/index', contextService);
+      expect(index.text).toContain('(klever://storage-mappers/LinkedListMapper)');
+
+      const page = await readResource('klever://storage-mappers/VecMapper', contextService);
+      expect(page.text).toContain('fn proposals(&self) -> VecMapper<Proposal<Self::Api>>;');
+    });
+
+    it('throws error for unknown storage mapper', async () => {
+      await expect(
+        readResource('klever://storage-mappers/TreeMapper', contextService)
+      ).rejects.toThrow('Unknown storage mapper');
+    });
+
+    it('throws error for unknown docs topic', async () => {
+      await expect(readResource('klever://docs/nonexistent', contextService)).rejects.toThrow(
+        'Unknown documentation topic'
+      );
+    });
+
     it('throws error for invalid URI format', async () => {
       await expect(readResource('invalid://uri', contextService)).rejects.toThrow(
         'Invalid resource URI'
@@ -1,6 +1,20 @@
 import type { Resource, ResourceTemplate } from '@modelcontextprotocol/sdk/types.js';
 import type { ServerProfile } from './server.js';
 import type { ContextService } from '../context/service.js';
+import { describeRules } from '../analysis/index.js';
+import { STORAGE_MAPPERS, storageMapperReference } from '../codegen/index.js';
+import {
+  CONTRACT_ATTRIBUTES,
+  EXAMPLE_NAMES,
+  exampleLibrary,
+  exampleMarkdown,
+  frameworkDocForVersion,
+  frameworkDocs,
+  FRAMEWORK_TOPICS,
+  getLibraryExample,
+  koperatorReference,
+  loadFrameworkDoc,
+} from '../knowledge/index.js';
 
 /**
  * Knowledge categories matching the folders under src/knowledge/
@@ -52,6 +66,30 @@ export function getResourceTemplates(_profile: ServerProfile): ResourceTemplate[
         KNOWLEDGE_CATEGORIES.join(', '),
       mimeType: 'text/markdown',
     },
+    {
+      uriTemplate: 'klever://docs/{topic}{?version}',
+      name: 'klever-sc Framework Docs',
+      description:
+        'klever-sc framework documentation by topic; pass the klever-sc version from the project Cargo.toml as ?version= to get the API differences for that release. Available topics: ' +
+        FRAMEWORK_TOPICS.join(', '),
+      mimeType: 'text/markdown',
+    },
+    {
+      uriTemplate: 'klever://storage-mappers/{mapper}',
+      name: 'Storage Mapper Reference',
+      description:
+        'One storage mapper with costs, pitfalls and a complete example contract. Available mappers: ' +
+        STORAGE_MAPPERS.map(m => m.name).join(', '),
+      mimeType: 'text/markdown',
+    },
+    {
+      uriTemplate: 'klever://examples/{name}',
+      name: 'Example Contract',
+      description:
+        'Complete example contract with its files and a scenario test. Available examples: ' +
+        EXAMPLE_NAMES.join(', '),
+      mimeType: 'text/markdown',
+    },
   ];
 }
 
@@ -67,6 +105,51 @@ export function getStaticResources(_profile: ServerProfile): Resource[] {
         'Overview of all knowledge categories with entry counts and descriptions',
       mimeType: 'text/markdown',
     },
+    {
+      uri: 'klever://docs/index',
+      name: 'klever-sc Framework Docs Index',
+      description: 'Topics of the klever-sc framework documentation with a summary of each',
+      mimeType: 'text/markdown',
+    },
+    {
+      uri: 'klever://storage-mappers/index',
+      name: 'Storage Mapper Reference Index',
+      description: 'Every storage mapper with a summary, linking to its example contract',
+      mimeType: 'text/markdown',
+    },
+    {
+      uri: 'klever://analysis/rules',
+      name: 'Contract Lint Rules',
+      description:
+        'Checks and rules lint_contract can run, with rule ids and default severities for building a per-project config',
+      mimeType: 'application/json',
+    },
+    {
+      uri: 'klever://reference/attributes',
+      name: 'klever-sc Attribute Reference',
+      description:
+        'Every klever-sc attribute (#[init], #[endpoint], #[view], #[payable], #[event], #[indexed], #[only_owner], #[callback], #[upgrade], ...) with where it goes, its argument, the attributes it needs or excludes, signature rules and common errors',
+      mimeType: 'application/json',
+    },
+    {
+      uri: 'klever://reference/koperator',
+      name: 'koperator CLI Reference',
+      description:
+        'koperator subcommands (account, sc create/upgrade/invoke, kda) with their flags, and how ABI argument types map to --args prefixes',
+      mimeType: 'text/markdown',
+    },
+    {
+      uri: 'klever://examples/index',
+      name: 'Example Contract Library',
+      description: 'Example contracts by topic, each with a scenario test',
+      mimeType: 'text/markdown',
+    },
+    ...exampleLibrary.map(example => ({
+      uri: `klever://examples/${example.name}`,
+      name: `Example: ${example.title}`,
+      description: example.summary,
+      mimeType: 'text/markdown',
+    })),
   ];
 }
 
@@ -77,6 +160,51 @@ export async function readResource(
   uri: string,
   contextService: ContextService
 ): Promise<{ uri: string; mimeType: string; text: string }> {
+  if (uri === 'klever://analysis/rules') {
+    return {
+      uri,
+      mimeType: 'application/json',
+      text: JSON.stringify(describeRules(), null, 2),
+    };
+  }
+
+  if (uri === 'klever://reference/attributes') {
+    return {
+      uri,
+      mimeType: 'application/json',
+      text: JSON.stringify(CONTRACT_ATTRIBUTES, null, 2),
+    };
+  }
+
+  if (uri === 'klever://reference/koperator') {
+    return { uri, mimeType: 'text/markdown', text: koperatorReference() };
+  }
+
+  const example = uri.match(/^klever:\/\/examples\/(.+)$/);
+  if (example) {
+    return { uri, mimeType: 'text/markdown', text: readExample(example[1]) };
+  }
+
+  const docs = uri.match(/^klever:\/\/docs\/([^?]+)(?:\?version=([^&]+))?$/);
+  if (docs) {
+    return {
+      uri,
+      mimeType: 'text/markdown',
+      text: await readFrameworkDoc(docs[1], docs[2] && decodeURIComponent(docs[2])),
+    };
+  }
+
+  const mapper = uri.match(/^klever:\/\/storage-mappers\/(.+)$/);
+  if (mapper) {
+    const text = storageMapperReference(mapper[1]);
+    if (!text) {
+      throw new Error(
+        `Unknown storage mapper: "${mapper[1]}". Available: ${STORAGE_MAPPERS.map(m => m.name).join(', ')}`
+      );
+    }
+    return { uri, mimeType: 'text/markdown', text };
+  }
+
   const parsed = parseResourceUri(uri);
 
   if (parsed.type === 'index') {
@@ -124,6 +252,53 @@ function parseResourceUri(uri: string): ParsedUri {
   );
 }
 
+async function readFrameworkDoc(topic: string, version?: string): Promise<string> {
+  if (topic === 'index') {
+    const lines = [
+      '# klever-sc Framework Documentation',
+      '',
+      '| Topic | Description |',
+      '|---|---|',
+      ...frameworkDocs.map(
+        doc => `| [${doc.title}](klever://docs/${doc.topic}) | ${doc.summary} |`
+      ),
+      '',
+    ];
+    return lines.join('\n');
+  }
+  const doc = await loadFrameworkDoc(topic);
+  if (!doc) {
+    throw new Error(
+      `Unknown documentation topic: "${topic}". Available: ${FRAMEWORK_TOPICS.join(', ')}`
+    );
+  }
+  return frameworkDocForVersion(doc.content, version);
+}
+
+function readExample(name: string): string {
+  if (name === 'index') {
+    const lines = [
+      '# Example Contract Library',
+      '',
+      'Each example is a complete contract with a scenario test (`scenarios/*.scen.json`).',
+      '',
+      '| Example | Topics | Description |',
+      '|---|---|---|',
+      ...exampleLibrary.map(
+        example =>
+          `| [${example.title}](klever://examples/${example.name}) | ${example.topics.join(', ')} | ${example.summary} |`
+      ),
+      '',
+    ];
+    return lines.join('\n');
+  }
+  const example = getLibraryExample(name);
+  if (!example) {
+    throw new Error(`Unknown example: "${name}". Available: ${EXAMPLE_NAMES.join(', ')}`);
+  }
+  return exampleMarkdown(example);
+}
+
 async function buildKnowledgeIndex(contextService: ContextService): Promise<string> {
   const lines: string[] = [
     '# Klever VM Knowledge Base',
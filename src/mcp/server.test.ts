@@ -141,15 +141,51 @@ describe('KleverMCPServer (public mode)', () => {
     });
   });
 
+  describe('scaffold_contract', () => {
+    it('is listed in public mode', async () => {
+      const { tools } = await client.listTools();
+      expect(tools.map(t => t.name)).toContain('scaffold_contract');
+    });
+
+    it('returns generated project files', async () => {
+      const result = await client.callTool({
+        name: 'scaffold_contract',
+        arguments: { template: 'basic_token', name: 'my-token' },
+      });
+
+      const content = result.content as Array<{ type: string; text: string }>;
+      const parsed = JSON.parse(content[0].text);
+      expect(parsed.success).toBe(true);
+      expect(parsed.template).toBe('basic_token');
+      expect(parsed.files['Cargo.toml']).toContain('name = "my-token"');
+      expect(parsed.files['src/lib.rs']).toContain('pub trait MyToken');
+    });
+
+    it('returns an error for unknown templates', async () => {
+      const result = await client.callTool({
+        name: 'scaffold_contract',
+        arguments: { template: 'does_not_exist', name: 'my-token' },
+      });
+
+      const content = result.content as Array<{ type: string; text: string }>;
+      const parsed = JSON.parse(content[0].text);
+      expect(parsed.success).toBe(false);
+      expect(parsed.error).toContain('Unknown template');
+    });
+  });
+
   describe('prompt listing', () => {
     it('lists prompts via client.listPrompts()', async () => {
       const { prompts } = await client.listPrompts();
-      expect(prompts).toHaveLength(4);
+      expect(prompts).toHaveLength(7);
       const names = prompts.map(p => p.name);
       expect(names).toContain('create_smart_contract');
       expect(names).toContain('add_feature');
       expect(names).toContain('debug_error');
       expect(names).toContain('review_contract');
+      expect(names).toContain('audit_contract');
+      expect(names).toContain('write_tests');
+      expect(names).toContain('design_tokenomics');
     });
   });
 
@@ -206,6 +242,18 @@ describe('KleverMCPServer (public mode)', () => {
       expect(text).toContain('Security Review');
       expect(text).toContain('Code Quality');
     });
+
+    it('returns text and attached resources for write_tests', async () => {
+      const result = await client.getPrompt({ name: 'write_tests' });
+
+      expect(result.messages).toHaveLength(3);
+      const text = (result.messages[0].content as { type: 'text'; text: string }).text;
+      expect(text).toContain('generate_scenario');
+      expect(result.messages[2].content).toMatchObject({
+        type: 'resource',
+        resource: { uri: 'klever://examples/testing', mimeType: 'text/markdown' },
+      });
+    });
   });
 
   describe('blocked tools (public mode)', () => {
@@ -232,9 +280,10 @@ describe('KleverMCPServer (public mode)', () => {
   describe('resources', () => {
     it('lists static resources via client.listResources()', async () => {
       const { resources } = await client.listResources();
-      expect(resources).toHaveLength(1);
+      expect(resources).toHaveLength(2);
       expect(resources[0].uri).toBe('klever://knowledge/index');
       expect(resources[0].mimeType).toBe('text/markdown');
+      expect(resources[1].uri).toBe('klever://analysis/rules');
     });
 
     it('lists resource templates via client.listResourceTemplates()', async () => {
@@ -621,6 +670,15 @@ describe('KleverMCPServer (local mode)', () => {
     expect(names).toContain('list_validators');
   });
 
+  it('keeps descriptions under 100 words, local-only tools included', async () => {
+    const { tools } = await client.listTools();
+
+    for (const tool of tools) {
+      const wordCount = tool.description!.trim().split(/\s+/).filter(Boolean).length;
+      expect(wordCount).toBeLessThanOrEqual(100);
+    }
+  });
+
   it('send_transfer builds unsigned transaction', async () => {
     // Mock getNonce
     mockFetch.mockResolvedValueOnce(
@@ -750,3 +808,44 @@ describe('KleverMCPServer (local mode)', () => {
     expect(parsed.details.formattedAmount).toBe('10.000000 KLV');
   });
 });
+
+describe('KleverMCPServer (API key scope)', () => {
+  let client: Client;
+
+  beforeAll(async () => {
+    const contextService = new ContextService(new InMemoryStorage());
+    const chainClient = new KleverChainClient({ network: 'testnet' });
+    const server = new KleverMCPServer(contextService, 'local', chainClient, {
+      name: 'ci-reader',
+      readOnly: true,
+      networks: ['testnet'],
+    });
+
+    const [clientTransport, serverTransport] = InMemoryTransport.createLinkedPair();
+    await server.connectTransport(serverTransport);
+
+    client = new Client({ name: 'test-client-scoped', version: '1.0.0' });
+    await client.connect(clientTransport);
+  });
+
+  afterAll(async () => {
+    await client.close();
+  });
+
+  it('refuses networks outside the scope, and unknown ones, with an error body', async () => {
+    for (const [network, error] of [
+      ['mainnet', 'API key "ci-reader" may not use network mainnet; allowed: testnet.'],
+      ['moon', 'Invalid network "moon".'],
+    ]) {
+      const result = await client.callTool({
+        name: 'get_balance',
+        arguments: { address: 'klv1sender', network },
+      });
+
+      const content = result.content as Array<{ type: string; text: string }>;
+      const parsed = JSON.parse(content[0].text);
+      expect(parsed.success).toBe(false);
+      expect(parsed.error).toContain(error);
+    }
+  });
+});
@@ -1,9 +1,14 @@
+import { randomUUID } from 'node:crypto';
 import { readFile, stat } from 'node:fs/promises';
 import { extname } from 'node:path';
 import { Server } from '@modelcontextprotocol/sdk/server/index.js';
 import { StdioServerTransport } from '@modelcontextprotocol/sdk/server/stdio.js';
 import {
   CallToolRequestSchema,
+  type CallToolRequest,
+  type CallToolResult,
+  type ServerNotification,
+  type ServerRequest,
   ListToolsRequestSchema,
   ListPromptsRequestSchema,
   GetPromptRequestSchema,
@@ -11,34 +16,50 @@ import {
   ListResourceTemplatesRequestSchema,
   ReadResourceRequestSchema,
 } from '@modelcontextprotocol/sdk/types.js';
+import type { RequestHandlerExtra } from '@modelcontextprotocol/sdk/shared/protocol.js';
 import type { Transport } from '@modelcontextprotocol/sdk/shared/transport.js';
 import { ContextService } from '../context/service.js';
 import { QueryContextSchema, ContextPayloadSchema } from '../types/index.js';
 import { VERSION, GIT_SHA } from '../version.js';
 import { KNOWLEDGE_CATEGORIES } from './resources.js';
-import { KleverChainClient } from '../chain/index.js';
-import type { KleverNetwork, VMQueryRequest } from '../chain/types.js';
+import {
+  KleverChainClient,
+  NETWORK_NAMES,
+  validateNetwork,
+  type KleverNetwork,
+} from '../chain/index.js';
+import type { VMQueryRequest } from '../chain/types.js';
+import { findTool, getToolsForProfile } from '../tools/index.js';
+import { AbiRegistry } from '../codegen/index.js';
+import { EventSubscriptions } from '../streaming/index.js';
+import {
+  networkInScope,
+  readOnlyTool,
+  toolInScope,
+  type ScopedToolDefinition,
+  type ToolScope,
+} from './tool-scope.js';
+import { ToolRateLimiter } from './rate-limit.js';
+import { ToolConcurrencyLimiter } from './concurrency.js';
+import { Workspace } from './workspace.js';
+import { ProgressReporter } from './progress.js';
+import { currentSpan, logger, runInSession, withSpan } from '../logging/index.js';
+import { getConfig, toolEnabled } from '../config/index.js';
+import { CancelledError, runCancellable } from '../utils/cancellation.js';
 
 export type ServerProfile = 'local' | 'public';
 
 /**
  * In MCP mode, stdout is reserved for the JSON-RPC protocol (stdio transport).
  * Any non-protocol output on stdout breaks the MCP client. All logging must go
- * to stderr via console.error. This alias improves readability for info-level logs.
+ * to stderr; this alias records info-level logs through the structured logger,
+ * in the span of the tool call that logs them.
  */
-const log = (...args: unknown[]) => console.error(...args);
-
-const VALID_NETWORKS = new Set<string>(['mainnet', 'testnet', 'devnet', 'local']);
+const log = (...args: unknown[]) =>
+  logger.info(args.map(arg => (typeof arg === 'string' ? arg : JSON.stringify(arg))).join(' '));
 
-function validateNetwork(network: string | undefined): KleverNetwork | undefined {
-  if (network === undefined) return undefined;
-  if (!VALID_NETWORKS.has(network)) {
-    throw new Error(
-      `Invalid network "${network}". Valid options: mainnet, testnet, devnet, local.`
-    );
-  }
-  return network as KleverNetwork;
-}
+/** Network names as listed in chain tool parameter descriptions */
+const networkOptions = NETWORK_NAMES.map(name => `"${name}"`).join(', ');
 
 interface ExecError {
   message: string;
@@ -62,14 +83,33 @@ export class KleverMCPServer {
   private server: Server;
   private profile: ServerProfile;
   private chainClient: KleverChainClient;
+  private abiRegistry = new AbiRegistry();
+  private subscriptions = new EventSubscriptions();
+  /** Project, ABI, network, signer and contract set with set_workspace */
+  private workspace = new Workspace();
+  private rateLimiter: ToolRateLimiter;
+  private concurrency: ToolConcurrencyLimiter;
+  /**
+   * Key of this server's calls in a rate limiter shared with other sessions,
+   * and of the log records only this session may read back
+   */
+  private sessionId = randomUUID();
 
   constructor(
     private contextService: ContextService,
     profile: ServerProfile = 'local',
-    chainClient?: KleverChainClient
+    chainClient?: KleverChainClient,
+    /** Tools and networks of the API key the client connected with; unrestricted when omitted */
+    private scope?: ToolScope,
+    /** Share one limiter between sessions for per-server limits (default: the default limits) */
+    rateLimiter?: ToolRateLimiter,
+    /** Share one limiter between sessions, so its limits hold across the server */
+    concurrency?: ToolConcurrencyLimiter
   ) {
     this.profile = profile;
     this.chainClient = chainClient || new KleverChainClient();
+    this.rateLimiter = rateLimiter || new ToolRateLimiter();
+    this.concurrency = concurrency || new ToolConcurrencyLimiter();
     this.server = new Server(
       {
         name: 'klever-vm-mcp',
@@ -77,12 +117,24 @@ export class KleverMCPServer {
       },
       {
         capabilities: {
-          tools: {},
+          // The required arguments of tools change with the workspace
+          tools: { listChanged: true },
           prompts: {},
           resources: {},
+          // Subscribed contract events are sent as logging messages
+          logging: {},
         },
       }
     );
+    this.server.onclose = () => {
+      this.subscriptions.unsubscribeAll();
+      this.rateLimiter.forget(this.sessionId);
+    };
+    this.workspace.onChange = () => {
+      this.server
+        .sendToolListChanged()
+        .catch(error => log(`[MCP] Notification failed: ${(error as Error).message}`));
+    };
 
     this.setupHandlers();
   }
@@ -313,7 +365,7 @@ export class KleverMCPServer {
   }
 
   private getChainReadToolDefinitions() {
-    const networkDesc = `Network to query. Options: "mainnet", "testnet", "devnet", "local". Defaults to server default (${this.chainClient.getDefaultNetwork()}).`;
+    const networkDesc = `Network to query. Options: ${networkOptions}. Defaults to server default (${this.chainClient.getDefaultNetwork()}).`;
     return [
       {
         name: 'get_balance',
@@ -331,7 +383,7 @@ export class KleverMCPServer {
               description:
                 'Optional KDA token ID (e.g. "USDT-A1B2", "LPKLVKFI-3I0N"). Omit for KLV balance.',
             },
-            network: { type: 'string', enum: ['mainnet', 'testnet', 'devnet', 'local'], description: networkDesc },
+            network: { type: 'string', enum: NETWORK_NAMES, description: networkDesc },
           },
           required: ['address'],
         },
@@ -343,29 +395,6 @@ export class KleverMCPServer {
           openWorldHint: true,
         },
       },
-      {
-        name: 'get_account',
-        description:
-          'Get full account details for a Klever blockchain address including nonce, balance, frozen balance, allowance, and permissions. Use this when you need comprehensive account state beyond just the balance.',
-        inputSchema: {
-          type: 'object' as const,
-          properties: {
-            address: {
-              type: 'string',
-              description: 'Klever address (klv1... bech32 format).',
-            },
-            network: { type: 'string', enum: ['mainnet', 'testnet', 'devnet', 'local'], description: networkDesc },
-          },
-          required: ['address'],
-        },
-        annotations: {
-          title: 'Get Account',
-          readOnlyHint: true,
-          destructiveHint: false,
-          idempotentHint: true,
-          openWorldHint: true,
-        },
-      },
       {
         name: 'get_asset_info',
         description:
@@ -378,7 +407,7 @@ export class KleverMCPServer {
               description:
                 'Asset identifier (e.g. "KLV", "KFI", "USDT-A1B2", "MYNFT-XY78").',
             },
-            network: { type: 'string', enum: ['mainnet', 'testnet', 'devnet', 'local'], description: networkDesc },
+            network: { type: 'string', enum: NETWORK_NAMES, description: networkDesc },
           },
           required: ['assetId'],
         },
@@ -417,7 +446,7 @@ export class KleverMCPServer {
               description:
                 'Optional caller address (klv1... bech32 format). Some view functions use the caller to look up address-keyed storage mappers.',
             },
-            network: { type: 'string', enum: ['mainnet', 'testnet', 'devnet', 'local'], description: networkDesc },
+            network: { type: 'string', enum: NETWORK_NAMES, description: networkDesc },
           },
           required: ['scAddress', 'funcName'],
         },
@@ -429,29 +458,6 @@ export class KleverMCPServer {
           openWorldHint: true,
         },
       },
-      {
-        name: 'get_transaction',
-        description:
-          'Get transaction details by hash from the Klever blockchain. Returns sender, receiver, status, block info, contracts, and receipts. Uses the API proxy for indexed data.',
-        inputSchema: {
-          type: 'object' as const,
-          properties: {
-            hash: {
-              type: 'string',
-              description: 'Transaction hash (hex string).',
-            },
-            network: { type: 'string', enum: ['mainnet', 'testnet', 'devnet', 'local'], description: networkDesc },
-          },
-          required: ['hash'],
-        },
-        annotations: {
-          title: 'Get Transaction',
-          readOnlyHint: true,
-          destructiveHint: false,
-          idempotentHint: true,
-          openWorldHint: true,
-        },
-      },
       {
         name: 'get_block',
         description:
@@ -465,7 +471,7 @@ export class KleverMCPServer {
               description:
                 'Block number (nonce). Omit to get the latest block.',
             },
-            network: { type: 'string', enum: ['mainnet', 'testnet', 'devnet', 'local'], description: networkDesc },
+            network: { type: 'string', enum: NETWORK_NAMES, description: networkDesc },
           },
         },
         annotations: {
@@ -483,7 +489,7 @@ export class KleverMCPServer {
         inputSchema: {
           type: 'object' as const,
           properties: {
-            network: { type: 'string', enum: ['mainnet', 'testnet', 'devnet', 'local'], description: networkDesc },
+            network: { type: 'string', enum: NETWORK_NAMES, description: networkDesc },
           },
         },
         annotations: {
@@ -498,7 +504,7 @@ export class KleverMCPServer {
   }
 
   private getChainWriteToolDefinitions() {
-    const networkDesc = `Network to use. Options: "mainnet", "testnet", "devnet", "local". Defaults to server default (${this.chainClient.getDefaultNetwork()}).`;
+    const networkDesc = `Network to use. Options: ${networkOptions}. Defaults to server default (${this.chainClient.getDefaultNetwork()}).`;
     return [
       {
         name: 'send_transfer',
@@ -526,7 +532,7 @@ export class KleverMCPServer {
               description:
                 'Optional KDA token ID for non-KLV transfers (e.g. "USDT-A1B2"). Omit for KLV.',
             },
-            network: { type: 'string', enum: ['mainnet', 'testnet', 'devnet', 'local'], description: networkDesc },
+            network: { type: 'string', enum: NETWORK_NAMES, description: networkDesc },
           },
           required: ['sender', 'receiver', 'amount'],
         },
@@ -565,7 +571,7 @@ export class KleverMCPServer {
               description:
                 'Optional base64-encoded init arguments for the contract constructor.',
             },
-            network: { type: 'string', enum: ['mainnet', 'testnet', 'devnet', 'local'], description: networkDesc },
+            network: { type: 'string', enum: NETWORK_NAMES, description: networkDesc },
           },
           required: ['sender'],
         },
@@ -607,7 +613,7 @@ export class KleverMCPServer {
               description:
                 'Optional token amounts to send with the call, as a map of token ID to amount (e.g. {"KLV": 1000000}). Required for payable endpoints.',
             },
-            network: { type: 'string', enum: ['mainnet', 'testnet', 'devnet', 'local'], description: networkDesc },
+            network: { type: 'string', enum: NETWORK_NAMES, description: networkDesc },
           },
           required: ['sender', 'scAddress', 'funcName'],
         },
@@ -636,7 +642,7 @@ export class KleverMCPServer {
               description:
                 'Amount of KLV to freeze in the smallest unit (1 KLV = 1,000,000 units).',
             },
-            network: { type: 'string', enum: ['mainnet', 'testnet', 'devnet', 'local'], description: networkDesc },
+            network: { type: 'string', enum: NETWORK_NAMES, description: networkDesc },
           },
           required: ['sender', 'amount'],
         },
@@ -763,37 +769,105 @@ export class KleverMCPServer {
     ];
   }
 
+  /** Every tool of the profile */
+  private async allToolDefinitions() {
+    const tools: Array<Record<string, unknown>> = [
+      ...this.getReadOnlyToolDefinitions(),
+      ...this.getChainReadToolDefinitions(),
+      ...getToolsForProfile(this.profile).map(t => t.definition),
+    ];
+
+    if (this.profile === 'public') {
+      const publicTools = await this.getPublicModeToolDefinitions();
+      tools.push(...publicTools);
+    } else {
+      tools.push(...this.getChainWriteToolDefinitions());
+      const localTools = await this.getLocalOnlyToolDefinitions();
+      tools.push(...localTools);
+    }
+    return tools as Array<Record<string, unknown> & ScopedToolDefinition>;
+  }
+
+  /**
+   * Tools of the profile that the config enables and the API key scope allows,
+   * without the arguments the workspace provides among the required ones
+   */
+  private async listToolDefinitions() {
+    const { tools } = getConfig();
+    return (await this.allToolDefinitions())
+      .filter(
+        tool =>
+          toolEnabled(tools, tool.name) &&
+          (!tools.readOnly || readOnlyTool(tool)) &&
+          toolInScope(this.scope, tool)
+      )
+      .map(tool => {
+        const schema = tool.inputSchema as {
+          properties?: Record<string, unknown>;
+          required?: string[];
+        };
+        const provided = this.workspace.provides(schema.properties);
+        if (!schema.required?.some(name => provided.includes(name))) return tool;
+        const required = schema.required.filter(name => !provided.includes(name));
+        return { ...tool, inputSchema: { ...schema, required } };
+      });
+  }
+
+  /** Why the config does not offer the tool `name`, or undefined when it does */
+  private async disabledReason(name: string): Promise<string | undefined> {
+    const { tools } = getConfig();
+    if (!toolEnabled(tools, name)) {
+      return `Tool "${name}" is disabled by the server config (tools.enabled / tools.disabled).`;
+    }
+    if (!tools.readOnly) return undefined;
+    const tool = (await this.allToolDefinitions()).find(t => t.name === name);
+    if (tool && readOnlyTool(tool)) return undefined;
+    return `Tool "${name}" is not available: the server runs in read-only mode (tools.read_only, KLEVER_READ_ONLY or --read-only), which offers no tool that signs, sends, spends funds or writes files.`;
+  }
+
+  /** Why the API key may not call `name` with `args`, or undefined when it may */
+  private scopeViolation(
+    name: string,
+    tool: Record<string, unknown> | undefined,
+    args: Record<string, unknown> | undefined
+  ): string | undefined {
+    if (!this.scope) return undefined;
+    if (!tool) return `API key "${this.scope.name}" may not call ${name}.`;
+    const properties = (tool.inputSchema as { properties?: Record<string, unknown> }).properties;
+    if (!properties?.network) return undefined;
+    let network: KleverNetwork;
+    try {
+      network =
+        validateNetwork(typeof args?.network === 'string' ? args.network : undefined) ||
+        this.chainClient.getDefaultNetwork();
+    } catch (error) {
+      return error instanceof Error ? error.message : String(error);
+    }
+    if (networkInScope(this.scope, network)) return undefined;
+    const allowed = this.scope.networks!.join(', ');
+    return `API key "${this.scope.name}" may not use network ${network}; allowed: ${allowed}.`;
+  }
+
   private setupHandlers() {
     // List available tools
     this.server.setRequestHandler(ListToolsRequestSchema, async () => {
-      const tools: Array<Record<string, unknown>> = [
-        ...this.getReadOnlyToolDefinitions(),
-        ...this.getChainReadToolDefinitions(),
-      ];
-
-      if (this.profile === 'public') {
-        const publicTools = await this.getPublicModeToolDefinitions();
-        tools.push(...publicTools);
-      } else {
-        tools.push(...this.getChainWriteToolDefinitions());
-        const localTools = await this.getLocalOnlyToolDefinitions();
-        tools.push(...localTools);
-      }
-
-      return { tools };
+      return { tools: await this.listToolDefinitions() };
     });
 
     // Handle tool calls
-    this.server.setRequestHandler(CallToolRequestSchema, async request => {
-      const { name, arguments: args } = request.params;
+    const callTool = async (
+      request: CallToolRequest,
+      extra: RequestHandlerExtra<ServerRequest, ServerNotification>
+    ): Promise<CallToolResult> => {
+      const { name, arguments: givenArgs } = request.params;
 
       // Debug logging to stderr (truncate large fields like wasmHex)
-      const safeArgs = args ? Object.fromEntries(
-        Object.entries(args).map(([k, v]) =>
+      const safeArgs = givenArgs ? Object.fromEntries(
+        Object.entries(givenArgs).map(([k, v]) =>
           typeof v === 'string' && v.length > 200 ? [k, `${v.slice(0, 100)}...(${v.length} chars)`] : [k, v]
         )
-      ) : args;
-      log(`[MCP] Tool called: ${name}`, JSON.stringify(safeArgs));
+      ) : givenArgs;
+      logger.info(`[MCP] Tool called: ${name}`, { args: safeArgs });
 
       // Block local-only tools in public profile
       const localOnlyTools = [
@@ -805,7 +879,11 @@ export class KleverMCPServer {
         'invoke_sc',
         'freeze_klv',
       ];
-      if (this.profile === 'public' && localOnlyTools.includes(name)) {
+      const registeredTool = findTool(name);
+      if (
+        this.profile === 'public' &&
+        (localOnlyTools.includes(name) || registeredTool?.localOnly)
+      ) {
         return {
           content: [
             {
@@ -825,14 +903,13 @@ export class KleverMCPServer {
                     'search_documentation',
                     'analyze_contract',
                     'get_balance',
-                    'get_account',
                     'get_asset_info',
                     'query_sc',
-                    'get_transaction',
                     'get_block',
                     'list_validators',
                     'init_klever_project',
                     'add_helper_scripts',
+                    ...getToolsForProfile('public').map(t => t.definition.name),
                   ],
                 },
                 null,
@@ -843,7 +920,137 @@ export class KleverMCPServer {
         };
       }
 
+      const disabled = await this.disabledReason(name);
+      if (disabled) {
+        return {
+          content: [
+            {
+              type: 'text',
+              text: JSON.stringify(
+                {
+                  success: false,
+                  error: disabled,
+                },
+                null,
+                2
+              ),
+            },
+          ],
+        };
+      }
+
+      const definition = (await this.listToolDefinitions()).find(t => t.name === name);
+      // Arguments left out that the session's workspace provides
+      const schema = definition?.inputSchema as { properties?: Record<string, unknown> };
+      // set_workspace takes the settings themselves, which must not default to their current values
+      const args =
+        name === 'set_workspace'
+          ? givenArgs || {}
+          : await this.workspace.fill(schema?.properties, givenArgs);
+      const fromWorkspace = Object.keys(args).filter(key => givenArgs?.[key] === undefined);
+      if (fromWorkspace.length > 0) {
+        logger.debug(`[MCP] ${name}: workspace provided ${fromWorkspace.join(', ')}`);
+      }
+      const violation = this.scopeViolation(name, definition, args);
+      if (violation) {
+        log(`[MCP] Refused ${name}: ${violation}`);
+        return {
+          content: [
+            {
+              type: 'text',
+              text: JSON.stringify(
+                {
+                  success: false,
+                  error: violation,
+                  suggestion: 'List the tools to see the ones this API key may call.',
+                },
+                null,
+                2
+              ),
+            },
+          ],
+        };
+      }
+
+      const exceeded =
+        definition &&
+        this.rateLimiter.take(
+          this.sessionId,
+          definition as { name: string; annotations?: { openWorldHint?: boolean } }
+        );
+      if (exceeded) {
+        const { rule, retryAfterSeconds } = exceeded;
+        const per = rule.per === 'session' ? 'per session' : 'across all sessions';
+        log(`[MCP] Rate limited ${name}: retry after ${retryAfterSeconds}s`);
+        return {
+          content: [
+            {
+              type: 'text',
+              text: JSON.stringify(
+                {
+                  success: false,
+                  error: `Rate limit exceeded for ${name}: ${rule.limit} calls per ${rule.windowSeconds}s ${per}.`,
+                  rateLimited: true,
+                  retryAfterSeconds,
+                  suggestion: `Wait ${retryAfterSeconds}s before calling ${name} again instead of retrying in a loop; the limit protects the public Klever nodes and faucet from banning this server.`,
+                },
+                null,
+                2
+              ),
+            },
+          ],
+        };
+      }
+
       try {
+        if (registeredTool) {
+          const progressToken = request.params._meta?.progressToken;
+          const reporter = new ProgressReporter(update => {
+            log(`[MCP] ${name}: ${update.message}`);
+            if (progressToken === undefined) return;
+            void extra.sendNotification({
+              method: 'notifications/progress',
+              params: { progressToken, ...update },
+            });
+          }, registeredTool.phases);
+          const progress = (message: string) => reporter.line(message);
+          // Calls run concurrently; subprocess-heavy tools wait for a free slot
+          const result = await this.concurrency.run(
+            name,
+            () =>
+              registeredTool.handler(args || {}, {
+                contextService: this.contextService,
+                chainClient: this.chainClient,
+                abiRegistry: this.abiRegistry,
+                subscriptions: this.subscriptions,
+                workspace: this.workspace,
+                profile: this.profile,
+                notify: data => {
+                  this.server
+                    .sendLoggingMessage({ level: 'info', logger: 'klever-events', data })
+                    .catch(error => log(`[MCP] Notification failed: ${(error as Error).message}`));
+                },
+                progress,
+                phase: (phase, detail) => reporter.phase(phase, detail),
+              }),
+            {
+              signal: extra.signal,
+              onWait: rule =>
+                progress(
+                  `Waiting for one of ${rule.limit} slot(s) shared by ${rule.tools.join(', ')}`
+                ),
+            }
+          );
+          return {
+            content: [
+              {
+                type: 'text',
+                text: JSON.stringify({ success: true, ...result }, null, 2),
+              },
+            ],
+          };
+        }
+
         switch (name) {
           case 'query_context': {
             const params = QueryContextSchema.parse(args);
@@ -1645,36 +1852,6 @@ export class KleverMCPServer {
             };
           }
 
-          case 'get_account': {
-            const { address, network } = args as {
-              address: string;
-              network?: string;
-            };
-            log(`[MCP] get_account: ${address} network=${network || 'default'}`);
-
-            const account = await this.chainClient.getAccount(
-              address,
-              validateNetwork(network)
-            );
-
-            return {
-              content: [
-                {
-                  type: 'text',
-                  text: JSON.stringify(
-                    {
-                      success: true,
-                      data: account,
-                      network: network || this.chainClient.getDefaultNetwork(),
-                    },
-                    null,
-                    2
-                  ),
-                },
-              ],
-            };
-          }
-
           case 'get_asset_info': {
             const { assetId, network } = args as {
               assetId: string;
@@ -1746,36 +1923,6 @@ export class KleverMCPServer {
             };
           }
 
-          case 'get_transaction': {
-            const { hash, network } = args as {
-              hash: string;
-              network?: string;
-            };
-            log(`[MCP] get_transaction: ${hash} network=${network || 'default'}`);
-
-            const tx = await this.chainClient.getTransaction(
-              hash,
-              validateNetwork(network)
-            );
-
-            return {
-              content: [
-                {
-                  type: 'text',
-                  text: JSON.stringify(
-                    {
-                      success: true,
-                      data: tx,
-                      network: network || this.chainClient.getDefaultNetwork(),
-                    },
-                    null,
-                    2
-                  ),
-                },
-              ],
-            };
-          }
-
           case 'get_block': {
             const { nonce, network } = args as {
               nonce?: number;
@@ -2115,7 +2262,24 @@ export class KleverMCPServer {
             };
         }
       } catch (error) {
+        if (error instanceof CancelledError) {
+          // The SDK drops responses to cancelled requests; this is for the log and the span
+          logger.info(`[MCP] Tool cancelled: ${name}`, { reason: error.message });
+          return {
+            content: [
+              {
+                type: 'text',
+                text: JSON.stringify(
+                  { success: false, cancelled: true, error: error.message, tool: name },
+                  null,
+                  2
+                ),
+              },
+            ],
+          };
+        }
         const message = error instanceof Error ? error.message : 'Unknown error';
+        logger.error(`[MCP] Tool failed: ${name}`, { error: message });
         return {
           content: [
             {
@@ -2125,6 +2289,8 @@ export class KleverMCPServer {
                   success: false,
                   error: message,
                   tool: name,
+                  // Operators find the call's logs with get_recent_logs or in the server log
+                  traceId: currentSpan()?.traceId,
                   suggestion:
                     'Check that all required parameters are provided and correctly typed. You can retry the call with corrected arguments. If the error persists, try a different approach or use search_documentation to find relevant guidance.',
                 },
@@ -2135,7 +2301,14 @@ export class KleverMCPServer {
           ],
         };
       }
-    });
+    };
+    this.server.setRequestHandler(CallToolRequestSchema, (request, extra) =>
+      runInSession(this.sessionId, () =>
+        withSpan('tool_call', { tool: request.params.name }, () =>
+          runCancellable(extra.signal, () => callTool(request, extra))
+        )
+      )
+    );
 
     // Prompt handlers
     this.server.setRequestHandler(ListPromptsRequestSchema, async () => {
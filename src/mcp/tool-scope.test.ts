@@ -0,0 +1,40 @@
+import { networkInScope, readOnlyTool, toolInScope } from './tool-scope.js';
+
+const query = { name: 'get_balance', annotations: { readOnlyHint: true } };
+const send = { name: 'send_transfer', annotations: { readOnlyHint: false } };
+
+describe('toolInScope', () => {
+  it('allows every tool without a scope or with a wildcard', () => {
+    expect(toolInScope(undefined, send)).toBe(true);
+    expect(toolInScope({ name: 'all', tools: ['*'] }, send)).toBe(true);
+  });
+
+  it('limits to the listed tools', () => {
+    const scope = { name: 'balances', tools: ['get_balance'] };
+    expect(toolInScope(scope, query)).toBe(true);
+    expect(toolInScope(scope, send)).toBe(false);
+  });
+
+  it('keeps only read-only tools for read-only keys', () => {
+    const scope = { name: 'reader', readOnly: true };
+    expect(toolInScope(scope, query)).toBe(true);
+    expect(toolInScope(scope, send)).toBe(false);
+    expect(toolInScope(scope, { name: 'unannotated' })).toBe(false);
+  });
+});
+
+describe('readOnlyTool', () => {
+  it('treats only tools annotated read-only as read-only', () => {
+    expect(readOnlyTool(query)).toBe(true);
+    expect(readOnlyTool(send)).toBe(false);
+    expect(readOnlyTool({ name: 'unannotated' })).toBe(false);
+  });
+});
+
+describe('networkInScope', () => {
+  it('limits to the listed networks', () => {
+    expect(networkInScope(undefined, 'mainnet')).toBe(true);
+    expect(networkInScope({ name: 'ci', networks: ['testnet'] }, 'testnet')).toBe(true);
+    expect(networkInScope({ name: 'ci', networks: ['testnet'] }, 'mainnet')).toBe(false);
+  });
+});
@@ -0,0 +1,37 @@
+/**
+ * What a client may do on a server it reaches with an API key: which tools
+ * it sees and may call, and which networks chain tools may use for it.
+ */
+
+import type { KleverNetwork } from '../chain/types.js';
+
+export interface ToolScope {
+  /** Name of the API key, for logs and errors */
+  name: string;
+  /** Tool names the key may call; `*` or omitted for every tool */
+  tools?: string[];
+  /** Only tools annotated read-only: queries and code generation, never signing or sending */
+  readOnly?: boolean;
+  /** Networks the key may use; omitted for every network */
+  networks?: string[];
+}
+
+export interface ScopedToolDefinition {
+  name: string;
+  annotations?: { readOnlyHint?: boolean };
+}
+
+/** Tools annotated read-only: they never sign, send, spend funds or write files */
+export function readOnlyTool(tool: ScopedToolDefinition): boolean {
+  return tool.annotations?.readOnlyHint === true;
+}
+
+export function toolInScope(scope: ToolScope | undefined, tool: ScopedToolDefinition): boolean {
+  if (!scope) return true;
+  if (scope.tools && !scope.tools.includes('*') && !scope.tools.includes(tool.name)) return false;
+  return !scope.readOnly || readOnlyTool(tool);
+}
+
+export function networkInScope(scope: ToolScope | undefined, network: KleverNetwork): boolean {
+  return !scope?.networks || scope.networks.includes(network);
+}
@@ -0,0 +1,87 @@
+import { mkdir, mkdtemp, rm, writeFile } from 'node:fs/promises';
+import { tmpdir } from 'node:os';
+import { join } from 'node:path';
+import { Workspace } from './workspace.js';
+
+const CONTRACT = 'klv1qqqqqqqqqqqqqpgqxwakt2g7u9atsnr03gqcgmhcv38pt7mkd94q6shuwt';
+
+const properties = (...names: string[]) =>
+  Object.fromEntries(names.map(name => [name, { type: 'string' }]));
+
+describe('Workspace', () => {
+  let project: string;
+  let workspace: Workspace;
+
+  beforeEach(async () => {
+    project = await mkdtemp(join(tmpdir(), 'klever-workspace-'));
+    await mkdir(join(project, 'src', 'storage'), { recursive: true });
+    await mkdir(join(project, 'output'));
+    await writeFile(join(project, 'src', 'lib.rs'), '#[klever_sc::contract]');
+    await writeFile(join(project, 'src', 'storage', 'mod.rs'), '#[klever_sc::module]');
+    await writeFile(join(project, 'output', 'adder.wasm'), '');
+    await writeFile(join(project, 'output', 'adder-dbg.wasm'), '');
+    await writeFile(join(project, 'output', 'adder.abi.json'), '{"name":"Adder"}');
+    workspace = new Workspace();
+    workspace.set({ projectPath: project, network: 'testnet', contract: CONTRACT });
+  });
+
+  afterEach(async () => {
+    await rm(project, { recursive: true, force: true });
+  });
+
+  it('fills the arguments a tool takes but was not given', async () => {
+    const deploy = properties('wasmPath', 'abi', 'abiUrl', 'network', 'signer');
+    expect(await workspace.fill(deploy, { network: 'mainnet' })).toEqual({
+      wasmPath: join(project, 'output', 'adder.wasm'),
+      abi: '{"name":"Adder"}',
+      network: 'mainnet',
+    });
+    expect(await workspace.fill(properties('projectPath'), {})).toEqual({ projectPath: project });
+  });
+
+  it('fills the wasm of a size analysis given only the source to cite', async () => {
+    const analyze = properties('wasmPath', 'wasmHex', 'source', 'topFunctions');
+    expect(await workspace.fill(analyze, { source: '#[klever_sc::contract]' })).toEqual({
+      wasmPath: join(project, 'output', 'adder.wasm'),
+      source: '#[klever_sc::contract]',
+    });
+    expect(await workspace.fill(analyze, { wasmHex: '0061736d' })).toEqual({ wasmHex: '0061736d' });
+  });
+
+  it('leaves alternatives of an argument alone', async () => {
+    const query = properties('address', 'view', 'abi', 'abiUrl');
+    expect(await workspace.fill(query, { abiUrl: 'https://example.com/abi.json' })).toEqual({
+      address: CONTRACT,
+      abiUrl: 'https://example.com/abi.json',
+    });
+    // An account address is not the contract's
+    expect(await workspace.fill(properties('address', 'network'), {})).toEqual({
+      network: 'testnet',
+    });
+  });
+
+  it('reads the crate sources for source analyses', async () => {
+    const lint = properties('source', 'modules');
+    expect(await workspace.fill(lint, {})).toEqual({
+      source: '#[klever_sc::contract]',
+      modules: { [join('src', 'storage', 'mod.rs')]: '#[klever_sc::module]' },
+    });
+    expect(await workspace.fill(lint, { source: 'fn main() {}' })).toEqual({
+      source: 'fn main() {}',
+    });
+  });
+
+  it('lists the arguments it provides and unsets settings with empty strings', () => {
+    const invoke = properties('address', 'endpoint', 'abi', 'abiUrl', 'network', 'signer');
+    expect(workspace.provides(invoke)).toEqual(['abi', 'network', 'address']);
+    const onChange = jest.fn();
+    workspace.onChange = onChange;
+    workspace.set({ network: '', signer: '/keys/wallet.pem' });
+    expect(workspace.settings).toEqual({
+      projectPath: project,
+      contract: CONTRACT,
+      signer: '/keys/wallet.pem',
+    });
+    expect(onChange).toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,179 @@
+/**
+ * Project context of a session, set with set_workspace: the contract project,
+ * its ABI, the network, the signer and the deployed contract. A tool call
+ * that leaves out one of those arguments gets the workspace's value, so an
+ * agent names the project once instead of on every build, lint, deploy and
+ * query.
+ */
+
+import { readdir, readFile } from 'node:fs/promises';
+import { join, relative } from 'node:path';
+import type { KleverNetwork } from '../chain/types.js';
+
+export interface WorkspaceSettings {
+  /** Contract project root (Cargo.toml, src/, meta/, output/) */
+  projectPath?: string;
+  /** ABI file; the project's output/*.abi.json when omitted */
+  abiPath?: string;
+  network?: KleverNetwork;
+  /** Wallet key file transactions are signed with */
+  signer?: string;
+  /** Address of the deployed contract (klv1...) */
+  contract?: string;
+}
+
+/** Settings to change; an empty string unsets one */
+export type WorkspaceChanges = { [K in keyof WorkspaceSettings]?: WorkspaceSettings[K] | '' };
+
+type Args = Record<string, unknown>;
+type Properties = Record<string, unknown>;
+
+interface WorkspaceDefault {
+  argument: string;
+  /** Whether a tool taking `properties`, called with `args`, gets the default */
+  applies: (properties: Properties, args: Args) => boolean;
+  /** Whether the settings can provide it */
+  available: (settings: WorkspaceSettings) => boolean;
+  value: (workspace: Workspace) => Promise<unknown>;
+}
+
+const given = (args: Args, name: string) => args[name] !== undefined;
+
+/** Source arguments of tools that analyze a whole crate: `source` (src/lib.rs) with `modules` */
+const takesCrate = (properties: Properties) => 'source' in properties && 'modules' in properties;
+
+const DEFAULTS: WorkspaceDefault[] = [
+  {
+    argument: 'projectPath',
+    applies: properties => 'projectPath' in properties,
+    available: settings => !!settings.projectPath,
+    value: async workspace => workspace.settings.projectPath,
+  },
+  {
+    argument: 'wasmPath',
+    applies: (properties, args) => 'wasmPath' in properties && !given(args, 'wasmHex'),
+    available: settings => !!settings.projectPath,
+    value: workspace => workspace.outputFile('.wasm'),
+  },
+  {
+    argument: 'abi',
+    applies: (properties, args) =>
+      'abi' in properties && 'abiUrl' in properties && !given(args, 'abiUrl'),
+    available: settings => !!(settings.abiPath || settings.projectPath),
+    value: workspace => workspace.abiJson(),
+  },
+  {
+    argument: 'source',
+    applies: (properties, args) => takesCrate(properties) && !given(args, 'modules'),
+    available: settings => !!settings.projectPath,
+    value: async workspace => (await workspace.crateSources())?.['src/lib.rs'],
+  },
+  {
+    argument: 'modules',
+    applies: (properties, args) => takesCrate(properties) && !given(args, 'source'),
+    available: settings => !!settings.projectPath,
+    value: async workspace => {
+      const sources = await workspace.crateSources();
+      if (!sources) return undefined;
+      return Object.fromEntries(Object.entries(sources).filter(([path]) => path !== 'src/lib.rs'));
+    },
+  },
+  {
+    argument: 'network',
+    applies: properties => 'network' in properties,
+    available: settings => !!settings.network,
+    value: async workspace => workspace.settings.network,
+  },
+  {
+    argument: 'signer',
+    applies: properties => 'signer' in properties,
+    available: settings => !!settings.signer,
+    value: async workspace => workspace.settings.signer,
+  },
+  {
+    // Contract tools: the ones that also take its ABI or project
+    argument: 'address',
+    applies: properties =>
+      'address' in properties && ('abi' in properties || 'projectPath' in properties),
+    available: settings => !!settings.contract,
+    value: async workspace => workspace.settings.contract,
+  },
+];
+
+export class Workspace {
+  settings: WorkspaceSettings = {};
+  /** Called after the settings change, e.g. to tell the client the tool list changed */
+  onChange?: () => void;
+
+  /** Change the given settings; an empty string unsets one */
+  set(changes: WorkspaceChanges): void {
+    const given = Object.entries(changes).filter(([, value]) => value !== undefined);
+    const merged = Object.entries({ ...this.settings, ...Object.fromEntries(given) });
+    const kept = merged.filter(([, value]) => value !== '');
+    this.settings = Object.fromEntries(kept) as WorkspaceSettings;
+    this.onChange?.();
+  }
+
+  clear(): void {
+    this.settings = {};
+    this.onChange?.();
+  }
+
+  /** Arguments of a tool taking `properties` that the workspace would fill when left out */
+  provides(properties: Properties = {}): string[] {
+    const defaults = DEFAULTS.filter(d => d.available(this.settings) && d.applies(properties, {}));
+    return defaults.map(d => d.argument);
+  }
+
+  /** `args` with the workspace's value for every argument the tool takes but was not given */
+  async fill(properties: Properties = {}, args: Args = {}): Promise<Args> {
+    const filled = { ...args };
+    for (const entry of DEFAULTS) {
+      if (given(args, entry.argument) || !(entry.argument in properties)) continue;
+      if (!entry.available(this.settings) || !entry.applies(properties, args)) continue;
+      const value = await entry.value(this);
+      if (value !== undefined) filled[entry.argument] = value;
+    }
+    return filled;
+  }
+
+  /** The ABI JSON of `abiPath`, else of the project's output directory */
+  async abiJson(): Promise<string | undefined> {
+    const path = this.settings.abiPath || (await this.outputFile('.abi.json'));
+    return path ? readFile(path, 'utf8').catch(() => undefined) : undefined;
+  }
+
+  /** First file of the project's output directory ending in `extension` (not a -dbg build) */
+  async outputFile(extension: string): Promise<string | undefined> {
+    if (!this.settings.projectPath) return undefined;
+    const output = join(this.settings.projectPath, 'output');
+    const files = await readdir(output).catch(() => [] as string[]);
+    const file = files
+      .filter(name => name.endsWith(extension) && !name.endsWith(`-dbg${extension}`))
+      .sort()[0];
+    return file && join(output, file);
+  }
+
+  /** Rust files under the project's src/, keyed by path relative to the project */
+  async crateSources(): Promise<Record<string, string> | undefined> {
+    const root = this.settings.projectPath;
+    if (!root) return undefined;
+    const sources: Record<string, string> = {};
+    const visit = async (dir: string): Promise<void> => {
+      for (const entry of await readdir(dir, { withFileTypes: true })) {
+        const path = join(dir, entry.name);
+        if (entry.isDirectory()) {
+          await visit(path);
+        } else if (entry.name.endsWith('.rs')) {
+          sources[relative(root, path)] = await readFile(path, 'utf8');
+        }
+      }
+    };
+    try {
+      await visit(join(root, 'src'));
+    } catch {
+      return undefined;
+    }
+    return sources['src/lib.rs'] !== undefined ? sources : undefined;
+  }
+}
@@ -0,0 +1,59 @@
+/**
+ * klever-sc framework versions: the version a project declares in Cargo.toml,
+ * the version templates are written against, and which one a generated
+ * project pins.
+ */
+
+import type { ContractTemplate } from './types.js';
+
+/** klever-sc framework version pinned in generated manifests */
+export const KLEVER_SC_VERSION = '0.45.0';
+
+/** klever-sc version requirement from a Cargo.toml, without operators (e.g. `0.45.0`) */
+export function kleverScVersion(cargoToml: string): string | undefined {
+  const match =
+    /^\s*klever-sc\s*=\s*"([^"]+)"/m.exec(cargoToml) ||
+    /^\s*klever-sc\s*=\s*\{[^}]*\bversion\s*=\s*"([^"]+)"/m.exec(cargoToml) ||
+    /^\s*\[dependencies\.klever-sc\][^[]*?^\s*version\s*=\s*"([^"]+)"/m.exec(cargoToml);
+  return match ? match[1].replace(/^[\^~=<>\s]+/, '') : undefined;
+}
+
+export function compareVersions(a: string, b: string): number {
+  const pa = a.split('.').map(n => parseInt(n, 10) || 0);
+  const pb = b.split('.').map(n => parseInt(n, 10) || 0);
+  for (let i = 0; i < 3; i++) {
+    const diff = (pa[i] || 0) - (pb[i] || 0);
+    if (diff !== 0) return diff;
+  }
+  return 0;
+}
+
+/** Oldest klever-sc a template builds with */
+export function templateMinVersion(template: ContractTemplate): string {
+  return template.minFrameworkVersion ?? KLEVER_SC_VERSION;
+}
+
+export interface FrameworkPin {
+  /** Version written to the generated manifests */
+  version: string;
+  /** Set when the project's version is too old for the template */
+  warning?: string;
+}
+
+/**
+ * Version a template should be generated with for a project on `projectVersion`:
+ * the project's own version when the template supports it, otherwise the
+ * template's minimum with a warning
+ */
+export function frameworkPin(
+  template: ContractTemplate,
+  projectVersion: string | undefined
+): FrameworkPin {
+  const required = templateMinVersion(template);
+  if (!projectVersion) return { version: required };
+  if (compareVersions(projectVersion, required) >= 0) return { version: projectVersion };
+  return {
+    version: required,
+    warning: `Template "${template.name}" requires klever-sc ${required} or newer, but the project uses ${projectVersion}. The generated manifests pin ${required}; upgrade klever-sc, klever-sc-scenario, klever-sc-meta and klever-sc-wasm-adapter together (check_deprecated_apis lists the source changes).`,
+  };
+}
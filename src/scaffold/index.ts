@@ -0,0 +1,40 @@
+export {
+  KLEVER_SC_VERSION,
+  generateContractProject,
+  renderContractTemplate,
+  projectFiles,
+  resolveTemplateParams,
+  extractEndpoints,
+  toSnakeCase,
+  toPascalCase,
+} from './project.js';
+export type {
+  ContractEndpoint,
+  GenerateProjectOptions,
+  ProjectManifestOptions,
+  RenderedContract,
+} from './project.js';
+export { generateContractWorkspace } from './workspace.js';
+export type {
+  DeploymentConfig,
+  GenerateWorkspaceOptions,
+  WorkspaceContract,
+  WorkspaceContractSummary,
+  WorkspaceResult,
+} from './workspace.js';
+export {
+  compareVersions,
+  frameworkPin,
+  kleverScVersion,
+  templateMinVersion,
+} from './framework-version.js';
+export type { FrameworkPin } from './framework-version.js';
+export { CONTRACT_TEMPLATES, getContractTemplate } from './templates/index.js';
+export type {
+  ContractTemplate,
+  TemplateParameter,
+  TemplateParamValue,
+  TemplateContext,
+  FileMap,
+  ScaffoldResult,
+} from './types.js';
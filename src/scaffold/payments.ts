@@ -0,0 +1,55 @@
+/**
+ * Shared payment-handling snippets for templates that accept either KLV or a KDA token
+ */
+
+import { escapeRustBytes } from './rust.js';
+
+/** True when the configured token is the native KLV coin */
+export function isKlv(tokenId: unknown): boolean {
+  return String(tokenId).toUpperCase() === 'KLV';
+}
+
+/**
+ * Rust helper functions `receive_payment` / `send_payment` for a single accepted token.
+ * Rendered inside the contract trait; `payableAttr` is the matching #[payable] annotation.
+ */
+export function paymentHelpers(tokenId: unknown): { payableAttr: string; helpers: string } {
+  if (isKlv(tokenId)) {
+    return {
+      payableAttr: '#[payable("KLV")]',
+      helpers: `    fn receive_payment(&self) -> BigUint {
+        let amount = self.call_value().klv_value().clone_value();
+        require!(amount > 0, "Payment must be positive");
+        amount
+    }
+
+    fn send_payment(&self, to: &ManagedAddress, amount: &BigUint) {
+        if *amount > 0 {
+            self.send().direct_klv(to, amount);
+        }
+    }`,
+    };
+  }
+
+  const token = escapeRustBytes(tokenId);
+  return {
+    payableAttr: '#[payable("*")]',
+    helpers: `    fn accepted_token(&self) -> TokenIdentifier {
+        TokenIdentifier::from(ManagedBuffer::from(b"${token}"))
+    }
+
+    fn receive_payment(&self) -> BigUint {
+        let payment = self.call_value().single_kda();
+        require!(payment.token_identifier == self.accepted_token(), "Wrong payment token");
+        require!(payment.token_nonce == 0, "Only fungible tokens accepted");
+        require!(payment.amount > 0, "Payment must be positive");
+        payment.amount
+    }
+
+    fn send_payment(&self, to: &ManagedAddress, amount: &BigUint) {
+        if *amount > 0 {
+            self.send().direct_kda(to, &self.accepted_token(), 0, amount);
+        }
+    }`,
+  };
+}
@@ -0,0 +1,261 @@
+import {
+  CONTRACT_TEMPLATES,
+  KLEVER_SC_VERSION,
+  extractEndpoints,
+  frameworkPin,
+  generateContractProject,
+  toPascalCase,
+  toSnakeCase,
+} from './index.js';
+
+describe('naming helpers', () => {
+  it('converts kebab-case to snake_case and PascalCase', () => {
+    expect(toSnakeCase('my-token')).toBe('my_token');
+    expect(toPascalCase('my-token')).toBe('MyToken');
+    expect(toPascalCase('nft')).toBe('Nft');
+  });
+});
+
+describe('extractEndpoints', () => {
+  it('uses the attribute argument as the external name', () => {
+    const endpoints = extractEndpoints(`
+      #[init]
+      fn init(&self) {}
+
+      #[view(getSum)]
+      fn get_sum(&self) -> BigUint { self.sum().get() }
+
+      #[payable("KLV")]
+      #[endpoint]
+      fn deposit(&self) {}
+    `);
+
+    expect(endpoints).toEqual([
+      { kind: 'init', functionName: 'init', externalName: 'init' },
+      { kind: 'view', functionName: 'get_sum', externalName: 'getSum' },
+      { kind: 'endpoint', functionName: 'deposit', externalName: 'deposit' },
+    ]);
+  });
+});
+
+describe('generateContractProject', () => {
+  const expectedFiles = [
+    'Cargo.toml',
+    'src/lib.rs',
+    'wasm/Cargo.toml',
+    'wasm/src/lib.rs',
+    'meta/Cargo.toml',
+    'meta/src/main.rs',
+  ];
+
+  it.each(CONTRACT_TEMPLATES.map(t => t.name))('generates a full project for "%s"', name => {
+    const result = generateContractProject(name, 'my-contract');
+
+    expect(Object.keys(result.files)).toEqual(expect.arrayContaining(expectedFiles));
+    expect(result.files['src/lib.rs']).toContain('#[klever_sc::contract]');
+    expect(result.files['src/lib.rs']).toContain('pub trait MyContract');
+    expect(result.files['src/lib.rs']).toContain('#[init]');
+    expect(result.files['wasm/src/lib.rs']).toContain('my_contract');
+    expect(result.files['meta/src/main.rs']).toContain('my_contract::AbiProvider');
+    for (const content of Object.values(result.files)) {
+      expect(content).not.toContain('undefined');
+    }
+  });
+
+  it('lists endpoints and views of the generated contract', () => {
+    const result = generateContractProject('basic_token', 'my-token');
+    expect(result.endpoints).toEqual(['transfer', 'mint', 'burn']);
+    expect(result.views).toEqual(['getBalance', 'getTotalSupply']);
+    expect(result.files['wasm/src/lib.rs']).toContain('getBalance => get_balance');
+  });
+
+  it('generates allowance endpoints for fungible_token', () => {
+    const result = generateContractProject('fungible_token', 'my-kda', { ticker: 'mkda' });
+    expect(result.endpoints).toEqual(['transfer', 'approve', 'transferFrom', 'mint', 'burn']);
+    expect(result.views).toContain('getAllowance');
+    expect(result.files['src/lib.rs']).toContain('b"MKDA"');
+    expect(result.files['src/lib.rs']).toContain('#[event("approval")]');
+  });
+
+  it('rejects fungible_token tickers and decimals the chain does not accept', () => {
+    const generate = (parameters: Record<string, unknown>) =>
+      generateContractProject('fungible_token', 'my-kda', parameters);
+    expect(() => generate({ ticker: 'MK' })).toThrow('"ticker" must be 3 to 10');
+    expect(() => generate({ ticker: 'MY-KDA' })).toThrow('"ticker" must be 3 to 10');
+    expect(() => generate({ ticker: 'MYKDATOKEN1' })).toThrow('"ticker" must be 3 to 10');
+    expect(() => generate({ decimals: 9 })).toThrow('"decimals" must be an integer from 0 to 8');
+    expect(() => generate({ decimals: 2.5 })).toThrow('"decimals" must be an integer');
+    expect(generate({ ticker: 'KDA8', decimals: 8 }).files['src/lib.rs']).toContain(
+      'pub const TOKEN_DECIMALS: u32 = 8;'
+    );
+  });
+
+  it('switches nft_collection mint to a quantity argument for SFTs', () => {
+    const nft = generateContractProject('nft_collection', 'my-nft');
+    expect(nft.files['src/lib.rs']).not.toContain('quantity: BigUint');
+    expect(nft.files['src/lib.rs']).toContain('NonFungibleTokenMapper');
+
+    const sft = generateContractProject('nft_collection', 'my-sft', {
+      semiFungible: true,
+      royaltiesBasisPoints: 20000,
+    });
+    expect(sft.files['src/lib.rs']).toContain('quantity: BigUint');
+    expect(sft.files['src/lib.rs']).toContain('self.royalties().set(10000u32)');
+    expect(sft.endpoints).toEqual(['issueCollection', 'mint', 'burn', 'setRoyalties']);
+  });
+
+  it('generates epoch-based staking for KLV or a KDA token', () => {
+    const klv = generateContractProject('staking', 'my-staking');
+    expect(klv.endpoints).toEqual(['stake', 'unstake', 'claim', 'fundRewards', 'setRewardPerEpoch']);
+    expect(klv.files['src/lib.rs']).toContain('get_block_epoch()');
+    expect(klv.files['src/lib.rs']).toContain('MapMapper<ManagedAddress, StakerInfo<Self::Api>>');
+    expect(klv.files['src/lib.rs']).toContain('direct_klv');
+
+    const kda = generateContractProject('staking', 'my-staking', { tokenId: 'MYKDA-1A2B' });
+    expect(kda.files['src/lib.rs']).toContain('b"MYKDA-1A2B"');
+    expect(kda.files['src/lib.rs']).toContain('#[payable("*")]');
+    const negative = { rewardPerEpoch: '-5' };
+    expect(() => generateContractProject('staking', 'my-staking', negative)).toThrow('u64');
+  });
+
+  it('makes the crowdfunding deadline an init argument unless fixed', () => {
+    const open = generateContractProject('crowdfunding', 'my-sale');
+    expect(open.endpoints).toEqual(['contribute', 'claim', 'refund']);
+    expect(open.files['src/lib.rs']).toContain('fn init(&self, deadline: u64)');
+
+    const fixed = generateContractProject('crowdfunding', 'my-sale', { deadline: 1800000000 });
+    expect(fixed.files['src/lib.rs']).toContain('fn init(&self)');
+    expect(fixed.files['src/lib.rs']).toContain('let deadline: u64 = 1800000000u64;');
+
+    const inverted = { softCap: '10', hardCap: '5' };
+    expect(() => generateContractProject('crowdfunding', 'my-sale', inverted)).toThrow('softCap');
+  });
+
+  it('generates multisig proposals with optional contract calls', () => {
+    const full = generateContractProject('multisig', 'my-multisig');
+    expect(full.endpoints).toContain('proposeScCall');
+    expect(full.endpoints).toEqual(
+      expect.arrayContaining(['sign', 'unsign', 'performAction', 'proposeChangeQuorum'])
+    );
+    expect(full.files['src/lib.rs']).toContain('execute_on_dest_context');
+
+    const transfersOnly = generateContractProject('multisig', 'my-multisig', {
+      allowContractCalls: false,
+    });
+    expect(transfersOnly.endpoints).not.toContain('proposeScCall');
+    expect(transfersOnly.files['src/lib.rs']).not.toContain('ScCall');
+  });
+
+  it('wires governance to the fungible_token ABI through a proxy', () => {
+    const result = generateContractProject('governance', 'my-dao');
+    expect(result.files['src/lib.rs']).toContain('pub mod token_proxy;');
+    expect(result.files['src/token_proxy.rs']).toContain('#[endpoint(transferFrom)]');
+    expect(result.endpoints).toEqual(['lockTokens', 'unlockTokens', 'propose', 'vote', 'execute']);
+    // Proxy endpoints must not leak into the governance wasm exports
+    expect(result.files['wasm/src/lib.rs']).not.toContain('transferFrom');
+    // Locked voting tokens sit in the governance contract's token balance
+    expect(result.files['src/lib.rs']).toContain(
+      '            target != self.token_address().get(),\n            "Proposals cannot call the token contract"'
+    );
+
+    const token = generateContractProject('fungible_token', 'my-kda');
+    for (const endpoint of ['transfer', 'transferFrom']) {
+      expect(token.endpoints).toContain(endpoint);
+    }
+    expect(token.views).toEqual(expect.arrayContaining(['getBalance', 'getTotalSupply']));
+  });
+
+  it('adds vesting revocation only when enabled', () => {
+    const fixed = generateContractProject('vesting', 'my-vesting', { maxSchedules: 3 });
+    expect(fixed.endpoints).toEqual(['addSchedule', 'claim', 'changeBeneficiary']);
+    expect(fixed.views).toContain('getClaimable');
+    expect(fixed.files['src/lib.rs']).toContain('MAX_SCHEDULES_PER_BENEFICIARY: usize = 3;');
+
+    const revocable = generateContractProject('vesting', 'my-vesting', { revocable: true });
+    expect(revocable.endpoints).toContain('revoke');
+    expect(revocable.files['src/lib.rs']).toContain('#[event("revoke")]');
+  });
+
+  it('generates escrow refunds after the configured timeout', () => {
+    const basic = generateContractProject('escrow', 'my-escrow', { timeoutSeconds: 3600 });
+    expect(basic.endpoints).toEqual(['createEscrow', 'confirm', 'claim', 'refund']);
+    expect(basic.files['src/lib.rs']).toContain('TIMEOUT_SECONDS: u64 = 3600;');
+    expect(basic.files['src/lib.rs']).toContain('fn init(&self) {}');
+
+    const arbitrated = generateContractProject('escrow', 'my-escrow', { withArbiter: true });
+    expect(arbitrated.endpoints).toEqual(expect.arrayContaining(['dispute', 'resolve']));
+    expect(arbitrated.files['src/lib.rs']).toContain('fn init(&self, arbiter: ManagedAddress)');
+  });
+
+  it('draws lottery winners from RandomnessSource in a later block', () => {
+    const result = generateContractProject('lottery', 'my-lottery');
+    const source = result.files['src/lib.rs'];
+    expect(result.endpoints).toEqual(['buyTickets', 'draw']);
+    expect(source).toContain('RandomnessSource::new()');
+    expect(source).toContain('get_block_nonce() > self.last_purchase_block().get()');
+    expect(source).not.toMatch(/get_block_timestamp\(\)\s*%/);
+
+    expect(() => generateContractProject('lottery', 'x', { ticketPrice: '0' })).toThrow(
+      'ticketPrice'
+    );
+  });
+
+  it('escapes string parameters embedded in Rust literals', () => {
+    const result = generateContractProject('fungible_token', 'my-kda', {
+      tokenName: 'My "Quoted" Token',
+    });
+    expect(result.files['src/lib.rs']).toContain('b"My \\"Quoted\\" Token"');
+
+    // Byte strings take only ASCII: other characters go in as their UTF-8 bytes
+    const accented = generateContractProject('fungible_token', 'my-kda', {
+      tokenName: 'Café\r\nToken',
+    });
+    expect(accented.files['src/lib.rs']).toContain('b"Caf\\xc3\\xa9\\r\\nToken"');
+  });
+
+  it('applies defaults and supplied parameters', () => {
+    expect(generateContractProject('adder', 'counter').parameters).toEqual({ initialValue: 0 });
+
+    const result = generateContractProject('adder', 'counter', { initialValue: '42' });
+    expect(result.parameters.initialValue).toBe(42);
+    expect(result.files['src/lib.rs']).toContain('BigUint::from(42u64)');
+  });
+
+  it('pins the project klever-sc version when the template supports it', () => {
+    const current = generateContractProject('adder', 'counter');
+    expect(current.frameworkVersion).toBe(KLEVER_SC_VERSION);
+    expect(current.warnings).toBeUndefined();
+
+    const newer = generateContractProject('adder', 'counter', {}, { frameworkVersion: '0.46.2' });
+    expect(newer.frameworkVersion).toBe('0.46.2');
+    expect(newer.warnings).toBeUndefined();
+    for (const path of ['Cargo.toml', 'wasm/Cargo.toml', 'meta/Cargo.toml']) {
+      expect(newer.files[path]).toContain('version = "0.46.2"');
+      expect(newer.files[path]).not.toContain(`version = "${KLEVER_SC_VERSION}"`);
+    }
+  });
+
+  it('warns when the template requires a newer klever-sc than the project', () => {
+    const result = generateContractProject('adder', 'counter', {}, { frameworkVersion: '0.44.1' });
+    expect(result.frameworkVersion).toBe(KLEVER_SC_VERSION);
+    expect(result.files['Cargo.toml']).toContain(`version = "${KLEVER_SC_VERSION}"`);
+    expect(result.warnings).toEqual([
+      expect.stringContaining(
+        `Template "adder" requires klever-sc ${KLEVER_SC_VERSION} or newer, but the project uses 0.44.1`
+      ),
+    ]);
+  });
+
+  it('uses a template minimum version below the current release', () => {
+    const template = { ...CONTRACT_TEMPLATES[0], minFrameworkVersion: '0.43.0' };
+    expect(frameworkPin(template, '0.44.1')).toEqual({ version: '0.44.1' });
+    expect(frameworkPin(template, '0.42.9').warning).toContain('requires klever-sc 0.43.0');
+    expect(frameworkPin(template, undefined)).toEqual({ version: '0.43.0' });
+  });
+
+  it('rejects unknown templates, invalid names, and unknown parameters', () => {
+    expect(() => generateContractProject('nope', 'x')).toThrow('Unknown template');
+    expect(() => generateContractProject('empty', 'My Token')).toThrow('Invalid project name');
+    expect(() => generateContractProject('empty', 'x', { foo: 1 })).toThrow('Unknown parameter');
+  });
+});
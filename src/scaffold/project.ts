@@ -0,0 +1,319 @@
+/**
+ * Klever contract project generator
+ *
+ * Produces the standard klever-sc layout (contract crate, wasm crate, meta crate)
+ * as an in-memory file map. Nothing is written to disk here — callers decide
+ * whether to persist the files.
+ */
+
+import type {
+  ContractTemplate,
+  FileMap,
+  ScaffoldResult,
+  TemplateContext,
+  TemplateParamValue,
+} from './types.js';
+import { CONTRACT_TEMPLATES, getContractTemplate } from './templates/index.js';
+import { frameworkPin, type FrameworkPin } from './framework-version.js';
+
+export { KLEVER_SC_VERSION } from './framework-version.js';
+
+export const PROJECT_NAME_PATTERN = /^[a-z][a-z0-9]*(-[a-z0-9]+)*$/;
+
+export function toSnakeCase(name: string): string {
+  return name.replace(/-/g, '_');
+}
+
+export function toPascalCase(name: string): string {
+  return name
+    .split(/[-_]/)
+    .filter(Boolean)
+    .map(part => part[0].toUpperCase() + part.slice(1))
+    .join('');
+}
+
+export interface ContractEndpoint {
+  /** Name exposed in the ABI (attribute argument or function name) */
+  externalName: string;
+  /** Rust function name */
+  functionName: string;
+  kind: 'init' | 'upgrade' | 'endpoint' | 'view';
+}
+
+/**
+ * Extract callable functions from contract source in declaration order
+ */
+export function extractEndpoints(source: string): ContractEndpoint[] {
+  const endpoints: ContractEndpoint[] = [];
+  const regex =
+    /#\[(init|upgrade|endpoint|view)(?:\((\w+)\))?\](?:\s*#\[[^\]]*\])*\s*(?:pub\s+)?fn\s+(\w+)/g;
+  let match;
+  while ((match = regex.exec(source)) !== null) {
+    const kind = match[1] as ContractEndpoint['kind'];
+    const functionName = match[3];
+    endpoints.push({
+      kind,
+      functionName,
+      externalName: kind === 'init' || kind === 'upgrade' ? kind : match[2] || functionName,
+    });
+  }
+  return endpoints;
+}
+
+/**
+ * Apply declared defaults and coerce supplied values to the declared types
+ */
+export function resolveTemplateParams(
+  template: ContractTemplate,
+  supplied: Record<string, unknown> = {}
+): Record<string, TemplateParamValue> {
+  const known = new Set(template.parameters.map(p => p.name));
+  const unknown = Object.keys(supplied).filter(key => !known.has(key));
+  if (unknown.length > 0) {
+    const available = template.parameters.map(p => p.name).join(', ') || '(none)';
+    throw new Error(
+      `Unknown parameter(s) for template "${template.name}": ${unknown.join(', ')}. Available: ${available}.`
+    );
+  }
+
+  const resolved: Record<string, TemplateParamValue> = {};
+  for (const param of template.parameters) {
+    const value = supplied[param.name];
+    if (value === undefined || value === null) {
+      resolved[param.name] = param.default;
+      continue;
+    }
+
+    if (param.type === 'number') {
+      const num = typeof value === 'number' ? value : Number(value);
+      if (!Number.isFinite(num)) {
+        throw new Error(`Parameter "${param.name}" must be a number, got "${String(value)}".`);
+      }
+      resolved[param.name] = num;
+    } else if (param.type === 'boolean') {
+      resolved[param.name] = value === true || value === 'true';
+    } else {
+      resolved[param.name] = String(value);
+    }
+  }
+  return resolved;
+}
+
+function contractCargoToml(
+  projectName: string,
+  dependencies: string[],
+  version: string,
+  options: ProjectManifestOptions
+): string {
+  const extraDeps = dependencies
+    .map(dep => `\n[dependencies.${dep}]\nversion = "${version}"\n`)
+    .join('');
+  const pathDeps = Object.entries(options.pathDependencies || {})
+    .map(([dep, path]) => `\n[dependencies.${dep}]\npath = "${path}"\n`)
+    .join('');
+  const workspace = options.workspaceMember ? '' : '\n[workspace]\nmembers = [".", "meta"]\n';
+
+  return `[package]
+name = "${projectName}"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[lib]
+path = "src/lib.rs"
+
+[dependencies.klever-sc]
+version = "${version}"
+${extraDeps}${pathDeps}
+[dev-dependencies.klever-sc-scenario]
+version = "${version}"
+${workspace}`;
+}
+
+function wasmCargoToml(projectName: string, version: string): string {
+  return `[package]
+name = "${projectName}-wasm"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[lib]
+crate-type = ["cdylib"]
+
+[profile.release]
+codegen-units = 1
+opt-level = "z"
+lto = true
+debug = false
+panic = "abort"
+overflow-checks = false
+
+[dependencies.${projectName}]
+path = ".."
+
+[dependencies.klever-sc-wasm-adapter]
+version = "${version}"
+
+[workspace]
+members = ["."]
+`;
+}
+
+function wasmLibRs(crateName: string, endpoints: ContractEndpoint[]): string {
+  const lines = endpoints.map(e => `        ${e.externalName} => ${e.functionName}`).join('\n');
+  return `// Code generated by the klever-sc build system. DO NOT EDIT.
+// Regenerated by \`ksc all build\` whenever endpoints change.
+
+#![no_std]
+
+klever_sc_wasm_adapter::allocator!();
+klever_sc_wasm_adapter::panic_handler!();
+
+klever_sc_wasm_adapter::endpoints! {
+    ${crateName}
+    (
+${lines}
+    )
+}
+
+klever_sc_wasm_adapter::async_callback_empty! {}
+`;
+}
+
+function metaCargoToml(projectName: string, version: string): string {
+  return `[package]
+name = "${projectName}-meta"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[dependencies.${projectName}]
+path = ".."
+
+[dependencies.klever-sc-meta]
+version = "${version}"
+default-features = false
+`;
+}
+
+function metaMainRs(crateName: string): string {
+  return `fn main() {
+    klever_sc_meta::cli_main::<${crateName}::AbiProvider>();
+}
+`;
+}
+
+const GITIGNORE = `/target/
+/output/
+*/target/
+wasm/Cargo.lock
+meta/Cargo.lock
+`;
+
+export interface ProjectManifestOptions {
+  /** Leave out the contract's [workspace] section so it joins an enclosing cargo workspace */
+  workspaceMember?: boolean;
+  /** Path dependencies by crate name, relative to the contract crate */
+  pathDependencies?: Record<string, string>;
+}
+
+/** Manifests, wasm and meta crates of a klever-sc project around its `src/lib.rs` */
+export function projectFiles(
+  projectName: string,
+  libRs: string,
+  version: string,
+  dependencies: string[] = [],
+  extraFiles: FileMap = {},
+  options: ProjectManifestOptions = {}
+): FileMap {
+  return {
+    'Cargo.toml': contractCargoToml(projectName, dependencies, version, options),
+    'src/lib.rs': libRs,
+    ...extraFiles,
+    'wasm/Cargo.toml': wasmCargoToml(projectName, version),
+    'wasm/src/lib.rs': wasmLibRs(toSnakeCase(projectName), extractEndpoints(libRs)),
+    'meta/Cargo.toml': metaCargoToml(projectName, version),
+    'meta/src/main.rs': metaMainRs(toSnakeCase(projectName)),
+    '.gitignore': GITIGNORE,
+  };
+}
+
+export interface GenerateProjectOptions {
+  /** klever-sc version of the project the contract joins (e.g. from its Cargo.toml) */
+  frameworkVersion?: string;
+}
+
+export interface RenderedContract {
+  template: ContractTemplate;
+  context: TemplateContext;
+  pin: FrameworkPin;
+  libRs: string;
+  /** Additional contract sources of the template, keyed by path */
+  extraFiles: FileMap;
+}
+
+/**
+ * Render the sources of a named template for a project, without its manifests
+ */
+export function renderContractTemplate(
+  templateName: string,
+  projectName: string,
+  parameters?: Record<string, unknown>,
+  options: GenerateProjectOptions = {}
+): RenderedContract {
+  const template = getContractTemplate(templateName);
+  if (!template) {
+    throw new Error(
+      `Unknown template "${templateName}". Available: ${CONTRACT_TEMPLATES.map(t => t.name).join(', ')}.`
+    );
+  }
+  if (!PROJECT_NAME_PATTERN.test(projectName)) {
+    throw new Error(
+      `Invalid project name "${projectName}". Use kebab-case starting with a letter (e.g. "my-token").`
+    );
+  }
+
+  const context: TemplateContext = {
+    projectName,
+    crateName: toSnakeCase(projectName),
+    traitName: toPascalCase(projectName),
+    params: resolveTemplateParams(template, parameters),
+  };
+  return {
+    template,
+    context,
+    pin: frameworkPin(template, options.frameworkVersion),
+    libRs: template.render(context),
+    extraFiles: template.extraFiles ? template.extraFiles(context) : {},
+  };
+}
+
+/**
+ * Generate a complete klever-sc project from a named template
+ */
+export function generateContractProject(
+  templateName: string,
+  projectName: string,
+  parameters?: Record<string, unknown>,
+  options: GenerateProjectOptions = {}
+): ScaffoldResult {
+  const { template, context, pin, libRs, extraFiles } = renderContractTemplate(
+    templateName,
+    projectName,
+    parameters,
+    options
+  );
+  const endpoints = extractEndpoints(libRs);
+  const files = projectFiles(projectName, libRs, pin.version, template.dependencies, extraFiles);
+
+  return {
+    template: template.name,
+    projectName,
+    parameters: context.params,
+    frameworkVersion: pin.version,
+    ...(pin.warning ? { warnings: [pin.warning] } : {}),
+    files,
+    endpoints: endpoints.filter(e => e.kind === 'endpoint').map(e => e.externalName),
+    views: endpoints.filter(e => e.kind === 'view').map(e => e.externalName),
+  };
+}
@@ -0,0 +1,62 @@
+/**
+ * Helpers for embedding user-supplied values in generated Rust source
+ */
+
+/** Escape a value for use inside a Rust string literal */
+export function escapeRustString(value: unknown): string {
+  return String(value)
+    .replace(/\\/g, '\\\\')
+    .replace(/"/g, '\\"')
+    .replace(/\n/g, '\\n')
+    .replace(/\r/g, '\\r');
+}
+
+/** Escape a value for use inside a Rust byte-string literal, which only takes ASCII */
+export function escapeRustBytes(value: unknown): string {
+  return escapeRustString(value).replace(/[\u0080-\uffff]+/g, text =>
+    [...Buffer.from(text, 'utf-8')].map(b => `\\x${b.toString(16).padStart(2, '0')}`).join('')
+  );
+}
+
+const U64_MAX = 18446744073709551615n;
+
+/** Validate an unsigned integer parameter that must fit in `u64` */
+export function parseU64(value: unknown, paramName: string): bigint {
+  const text = String(value).replace(/_/g, '').trim();
+  if (!/^\d+$/.test(text) || BigInt(text) > U64_MAX) {
+    throw new Error(`Parameter "${paramName}" must be an unsigned integer that fits in u64.`);
+  }
+  return BigInt(text);
+}
+
+/** Validate an integer parameter between `min` and `max` inclusive */
+export function parseIntegerInRange(
+  value: unknown,
+  paramName: string,
+  min: number,
+  max: number
+): number {
+  const num = Number(value);
+  if (!Number.isInteger(num) || num < min || num > max) {
+    throw new Error(
+      `Parameter "${paramName}" must be an integer from ${min} to ${max}, got "${String(value)}".`
+    );
+  }
+  return num;
+}
+
+/** Validate a token ticker: 3-10 letters or digits, returned upper-cased */
+export function parseTicker(value: unknown, paramName: string): string {
+  const ticker = String(value).toUpperCase();
+  if (!/^[A-Z0-9]{3,10}$/.test(ticker)) {
+    throw new Error(
+      `Parameter "${paramName}" must be 3 to 10 uppercase letters or digits, got "${String(value)}".`
+    );
+  }
+  return ticker;
+}
+
+/** Render a validated unsigned integer parameter as a `u64` literal */
+export function u64Literal(value: unknown, paramName: string): string {
+  return `${parseU64(value, paramName)}u64`;
+}
@@ -0,0 +1,43 @@
+import type { ContractTemplate } from '../types.js';
+
+export const adderTemplate: ContractTemplate = {
+  name: 'adder',
+  description: 'Counter contract with a single stored sum, an add endpoint and a getSum view',
+  parameters: [
+    {
+      name: 'initialValue',
+      type: 'number',
+      description: 'Value stored in the sum at deployment',
+      default: 0,
+    },
+  ],
+  render: ({ traitName, params }) => `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait ${traitName} {
+    #[init]
+    fn init(&self) {
+        self.sum().set(BigUint::from(${Number(params.initialValue)}u64));
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[endpoint]
+    fn add(&self, value: BigUint) {
+        require!(value > 0, "Value must be positive");
+        self.sum().update(|sum| *sum += &value);
+    }
+
+    #[view(getSum)]
+    fn get_sum(&self) -> BigUint {
+        self.sum().get()
+    }
+
+    #[storage_mapper("sum")]
+    fn sum(&self) -> SingleValueMapper<BigUint>;
+}
+`,
+};
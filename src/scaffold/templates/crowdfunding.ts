@@ -0,0 +1,191 @@
+import type { ContractTemplate } from '../types.js';
+import { paymentHelpers } from '../payments.js';
+import { parseU64 } from '../rust.js';
+
+/**
+ * Crowdfunding / ITO sale: capped contributions within a time window, refund on failure
+ */
+export const crowdfundingTemplate: ContractTemplate = {
+  name: 'crowdfunding',
+  description:
+    'Crowdsale accepting KLV/KDA up to a hard cap before a deadline, with refund-if-failed and claim-if-success',
+  parameters: [
+    {
+      name: 'tokenId',
+      type: 'string',
+      description: 'Accepted payment token: "KLV" or a KDA ID such as "USDT-A1B2"',
+      default: 'KLV',
+    },
+    {
+      name: 'softCap',
+      type: 'string',
+      description: 'Minimum total raise for the sale to succeed (raw units)',
+      default: '1000000000',
+    },
+    {
+      name: 'hardCap',
+      type: 'string',
+      description: 'Maximum total raise; contributions beyond it are rejected (raw units)',
+      default: '10000000000',
+    },
+    {
+      name: 'deadline',
+      type: 'number',
+      description: 'Sale end as a unix timestamp in seconds; 0 makes it an #[init] argument',
+      default: 0,
+    },
+  ],
+  render: ({ traitName, params }) => {
+    const { payableAttr, helpers } = paymentHelpers(params.tokenId);
+    const softCap = parseU64(params.softCap, 'softCap');
+    const hardCap = parseU64(params.hardCap, 'hardCap');
+    if (softCap > hardCap) {
+      throw new Error('Parameter "softCap" must not exceed "hardCap".');
+    }
+    const deadline = parseU64(Math.floor(Number(params.deadline)), 'deadline');
+    const fixedDeadline = deadline > 0n;
+    const initSignature = fixedDeadline ? 'fn init(&self)' : 'fn init(&self, deadline: u64)';
+    const deadlineBinding = fixedDeadline ? `let deadline: u64 = ${deadline}u64;\n        ` : '';
+
+    return `#![no_std]
+
+use klever_sc::imports::*;
+use klever_sc::derive_imports::*;
+
+#[derive(TopEncode, TopDecode, TypeAbi, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Status {
+    FundingPeriod,
+    Successful,
+    Failed,
+}
+
+#[klever_sc::contract]
+pub trait ${traitName} {
+    #[init]
+    ${initSignature} {
+        ${deadlineBinding}require!(
+            deadline > self.blockchain().get_block_timestamp(),
+            "Deadline must be in the future"
+        );
+        self.deadline().set(deadline);
+        self.soft_cap().set(BigUint::from(${softCap}u64));
+        self.hard_cap().set(BigUint::from(${hardCap}u64));
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    ${payableAttr}
+    #[endpoint]
+    fn contribute(&self) {
+        require!(self.status() == Status::FundingPeriod, "Sale is not open");
+        let amount = self.receive_payment();
+
+        let raised = self.total_raised().get();
+        require!(
+            &raised + &amount <= self.hard_cap().get(),
+            "Contribution exceeds the hard cap"
+        );
+
+        let caller = self.blockchain().get_caller();
+        self.contributions(&caller).update(|total| *total += &amount);
+        self.total_raised().set(raised + &amount);
+
+        self.contribute_event(&caller, &amount);
+    }
+
+    /// Owner withdraws the raised funds once the sale has succeeded.
+    #[only_owner]
+    #[endpoint]
+    fn claim(&self) {
+        require!(self.status() == Status::Successful, "Sale has not succeeded");
+        require!(!self.claimed().get(), "Funds already claimed");
+
+        self.claimed().set(true);
+        let owner = self.blockchain().get_caller();
+        let amount = self.total_raised().get();
+        self.send_payment(&owner, &amount);
+
+        self.claim_event(&owner, &amount);
+    }
+
+    /// Contributors recover their payment if the soft cap was not reached.
+    #[endpoint]
+    fn refund(&self) {
+        require!(self.status() == Status::Failed, "Refunds are only available if the sale failed");
+
+        let caller = self.blockchain().get_caller();
+        let amount = self.contributions(&caller).take();
+        require!(amount > 0, "Nothing to refund");
+        self.send_payment(&caller, &amount);
+
+        self.refund_event(&caller, &amount);
+    }
+
+    // Views
+
+    #[view]
+    fn status(&self) -> Status {
+        let raised = self.total_raised().get();
+        if raised >= self.hard_cap().get() {
+            return Status::Successful;
+        }
+        if self.blockchain().get_block_timestamp() <= self.deadline().get() {
+            return Status::FundingPeriod;
+        }
+        if raised >= self.soft_cap().get() {
+            Status::Successful
+        } else {
+            Status::Failed
+        }
+    }
+
+    #[view(getContribution)]
+    fn get_contribution(&self, address: ManagedAddress) -> BigUint {
+        self.contributions(&address).get()
+    }
+
+    #[view(getTotalRaised)]
+    fn get_total_raised(&self) -> BigUint {
+        self.total_raised().get()
+    }
+
+${helpers}
+
+    // Storage
+
+    #[view(getDeadline)]
+    #[storage_mapper("deadline")]
+    fn deadline(&self) -> SingleValueMapper<u64>;
+
+    #[view(getSoftCap)]
+    #[storage_mapper("softCap")]
+    fn soft_cap(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getHardCap)]
+    #[storage_mapper("hardCap")]
+    fn hard_cap(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("contributions")]
+    fn contributions(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("totalRaised")]
+    fn total_raised(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("claimed")]
+    fn claimed(&self) -> SingleValueMapper<bool>;
+
+    // Events
+
+    #[event("contribute")]
+    fn contribute_event(&self, #[indexed] contributor: &ManagedAddress, amount: &BigUint);
+
+    #[event("claim")]
+    fn claim_event(&self, #[indexed] owner: &ManagedAddress, amount: &BigUint);
+
+    #[event("refund")]
+    fn refund_event(&self, #[indexed] contributor: &ManagedAddress, amount: &BigUint);
+}
+`;
+  },
+};
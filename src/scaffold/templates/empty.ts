@@ -0,0 +1,20 @@
+import type { ContractTemplate } from '../types.js';
+
+export const emptyTemplate: ContractTemplate = {
+  name: 'empty',
+  description: 'Minimal contract with init and upgrade functions only',
+  parameters: [],
+  render: ({ traitName }) => `#![no_std]
+
+use klever_sc::imports::*;
+
+#[klever_sc::contract]
+pub trait ${traitName} {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
+`,
+};
@@ -0,0 +1,241 @@
+import type { ContractTemplate } from '../types.js';
+import { paymentHelpers } from '../payments.js';
+import { parseU64 } from '../rust.js';
+
+/**
+ * Buyer/seller escrow with confirmation and timeout-based refunds
+ */
+export const escrowTemplate: ContractTemplate = {
+  name: 'escrow',
+  description:
+    'Escrow: buyer locks KLV/KDA, seller claims after buyer confirmation, buyer is refunded after a timeout',
+  parameters: [
+    {
+      name: 'tokenId',
+      type: 'string',
+      description: 'Escrowed token: "KLV" or a KDA ID such as "USDT-A1B2"',
+      default: 'KLV',
+    },
+    {
+      name: 'timeoutSeconds',
+      type: 'number',
+      description: 'Seconds after creation when an unconfirmed escrow can be refunded',
+      default: 604800,
+    },
+    {
+      name: 'withArbiter',
+      type: 'boolean',
+      description: 'Let the buyer open a dispute that an arbiter (set at init) resolves',
+      default: false,
+    },
+  ],
+  render: ({ traitName, params }) => {
+    const { payableAttr, helpers } = paymentHelpers(params.tokenId);
+    const timeout = parseU64(Math.floor(Number(params.timeoutSeconds)), 'timeoutSeconds');
+    const arbiter = params.withArbiter === true;
+
+    const disputedVariant = arbiter ? '\n    Disputed,' : '';
+    const init = arbiter
+      ? `    #[init]
+    fn init(&self, arbiter: ManagedAddress) {
+        self.arbiter().set(arbiter);
+    }`
+      : `    #[init]
+    fn init(&self) {}`;
+
+    const disputeEndpoints = arbiter
+      ? `
+    /// Buyer freezes a pending escrow before the timeout; only the arbiter can then settle it.
+    #[endpoint]
+    fn dispute(&self, escrow_id: usize) {
+        let mut escrow = self.require_escrow(escrow_id, EscrowStatus::Pending);
+        require!(self.blockchain().get_caller() == escrow.buyer, "Only the buyer can dispute");
+        require!(
+            self.blockchain().get_block_timestamp() < escrow.deadline,
+            "Escrow already timed out"
+        );
+        escrow.status = EscrowStatus::Disputed;
+        self.escrows().set(escrow_id, &escrow);
+        self.dispute_event(escrow_id);
+    }
+
+    #[endpoint]
+    fn resolve(&self, escrow_id: usize, release_to_seller: bool) {
+        let mut escrow = self.require_escrow(escrow_id, EscrowStatus::Disputed);
+        require!(self.blockchain().get_caller() == self.arbiter().get(), "Only the arbiter");
+
+        if release_to_seller {
+            escrow.status = EscrowStatus::Released;
+            self.send_payment(&escrow.seller, &escrow.amount);
+        } else {
+            escrow.status = EscrowStatus::Refunded;
+            self.send_payment(&escrow.buyer, &escrow.amount);
+        }
+        self.escrows().set(escrow_id, &escrow);
+        self.resolve_event(escrow_id, release_to_seller);
+    }
+`
+      : '';
+
+    const arbiterStorage = arbiter
+      ? `
+
+    #[view(getArbiter)]
+    #[storage_mapper("arbiter")]
+    fn arbiter(&self) -> SingleValueMapper<ManagedAddress>;`
+      : '';
+
+    const disputeEvents = arbiter
+      ? `
+
+    #[event("dispute")]
+    fn dispute_event(&self, #[indexed] escrow_id: usize);
+
+    #[event("resolve")]
+    fn resolve_event(&self, #[indexed] escrow_id: usize, release_to_seller: bool);`
+      : '';
+
+    return `#![no_std]
+
+use klever_sc::imports::*;
+use klever_sc::derive_imports::*;
+
+/// Seconds after creation when an unconfirmed escrow becomes refundable
+pub const TIMEOUT_SECONDS: u64 = ${timeout};
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Copy)]
+pub enum EscrowStatus {
+    Pending,
+    Confirmed,
+    Released,
+    Refunded,${disputedVariant}
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct Escrow<M: ManagedTypeApi> {
+    pub buyer: ManagedAddress<M>,
+    pub seller: ManagedAddress<M>,
+    pub amount: BigUint<M>,
+    pub deadline: u64,
+    pub status: EscrowStatus,
+}
+
+#[klever_sc::contract]
+pub trait ${traitName} {
+${init}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Buyer locks the attached payment for \`seller\`.
+    ${payableAttr}
+    #[endpoint(createEscrow)]
+    fn create_escrow(&self, seller: ManagedAddress) -> usize {
+        let amount = self.receive_payment();
+        let buyer = self.blockchain().get_caller();
+        require!(seller != buyer, "Buyer and seller must differ");
+
+        let escrow = Escrow {
+            buyer: buyer.clone(),
+            seller: seller.clone(),
+            amount: amount.clone(),
+            deadline: self.blockchain().get_block_timestamp() + TIMEOUT_SECONDS,
+            status: EscrowStatus::Pending,
+        };
+        let escrow_id = self.escrows().push(&escrow);
+
+        self.create_event(escrow_id, &buyer, &seller, &amount);
+        escrow_id
+    }
+
+    /// Buyer confirms delivery; the seller can then claim.
+    #[endpoint]
+    fn confirm(&self, escrow_id: usize) {
+        let mut escrow = self.require_escrow(escrow_id, EscrowStatus::Pending);
+        require!(self.blockchain().get_caller() == escrow.buyer, "Only the buyer can confirm");
+        escrow.status = EscrowStatus::Confirmed;
+        self.escrows().set(escrow_id, &escrow);
+        self.confirm_event(escrow_id);
+    }
+
+    #[endpoint]
+    fn claim(&self, escrow_id: usize) {
+        let mut escrow = self.require_escrow(escrow_id, EscrowStatus::Confirmed);
+        require!(self.blockchain().get_caller() == escrow.seller, "Only the seller can claim");
+        escrow.status = EscrowStatus::Released;
+        self.escrows().set(escrow_id, &escrow);
+
+        self.send_payment(&escrow.seller, &escrow.amount);
+        self.release_event(escrow_id);
+    }
+
+    /// Return funds to the buyer: the seller may do so at any time, anyone after the timeout.
+    #[endpoint]
+    fn refund(&self, escrow_id: usize) {
+        let mut escrow = self.require_escrow(escrow_id, EscrowStatus::Pending);
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == escrow.seller || self.blockchain().get_block_timestamp() >= escrow.deadline,
+            "Escrow has not timed out"
+        );
+        escrow.status = EscrowStatus::Refunded;
+        self.escrows().set(escrow_id, &escrow);
+
+        self.send_payment(&escrow.buyer, &escrow.amount);
+        self.refund_event(escrow_id);
+    }
+${disputeEndpoints}
+    // Views
+
+    #[view(getEscrow)]
+    fn get_escrow(&self, escrow_id: usize) -> Escrow<Self::Api> {
+        self.escrows().get(escrow_id)
+    }
+
+    #[view(getEscrowCount)]
+    fn get_escrow_count(&self) -> usize {
+        self.escrows().len()
+    }
+
+    // Internal
+
+    fn require_escrow(&self, escrow_id: usize, status: EscrowStatus) -> Escrow<Self::Api> {
+        require!(
+            escrow_id >= 1 && escrow_id <= self.escrows().len(),
+            "Escrow does not exist"
+        );
+        let escrow = self.escrows().get(escrow_id);
+        require!(escrow.status == status, "Escrow is not in the required state");
+        escrow
+    }
+
+${helpers}
+
+    // Storage
+
+    #[storage_mapper("escrows")]
+    fn escrows(&self) -> VecMapper<Escrow<Self::Api>>;${arbiterStorage}
+
+    // Events
+
+    #[event("create")]
+    fn create_event(
+        &self,
+        #[indexed] escrow_id: usize,
+        #[indexed] buyer: &ManagedAddress,
+        #[indexed] seller: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("confirm")]
+    fn confirm_event(&self, #[indexed] escrow_id: usize);
+
+    #[event("release")]
+    fn release_event(&self, #[indexed] escrow_id: usize);
+
+    #[event("refund")]
+    fn refund_event(&self, #[indexed] escrow_id: usize);${disputeEvents}
+}
+`;
+  },
+};
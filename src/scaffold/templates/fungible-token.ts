@@ -0,0 +1,205 @@
+import type { ContractTemplate } from '../types.js';
+import { escapeRustBytes, parseIntegerInRange, parseTicker } from '../rust.js';
+
+/**
+ * Ledger token with ERC-20 style allowances (approve / allowance / transferFrom)
+ */
+export const fungibleTokenTemplate: ContractTemplate = {
+  name: 'fungible_token',
+  description:
+    'Fungible token ledger with transfer, approve, allowance, transferFrom, owner-only mint, burn and metadata views',
+  parameters: [
+    {
+      name: 'tokenName',
+      type: 'string',
+      description: 'Human-readable token name returned by getName',
+      default: 'My Token',
+    },
+    {
+      name: 'ticker',
+      type: 'string',
+      description: 'Token ticker returned by getTicker (uppercase, 3-10 chars)',
+      default: 'MTK',
+    },
+    {
+      name: 'decimals',
+      type: 'number',
+      description: 'Number of decimal places returned by getDecimals (0-8)',
+      default: 6,
+    },
+  ],
+  render: ({ traitName, params }) => {
+    const ticker = parseTicker(params.ticker, 'ticker');
+    const decimals = parseIntegerInRange(params.decimals, 'decimals', 0, 8);
+
+    return `#![no_std]
+
+use klever_sc::imports::*;
+
+pub const TOKEN_NAME: &[u8] = b"${escapeRustBytes(params.tokenName)}";
+pub const TOKEN_TICKER: &[u8] = b"${ticker}";
+pub const TOKEN_DECIMALS: u32 = ${decimals};
+
+#[klever_sc::contract]
+pub trait ${traitName} {
+    #[init]
+    fn init(&self, initial_supply: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.owner().set(&caller);
+        self.balance(&caller).set(&initial_supply);
+        self.total_supply().set(&initial_supply);
+
+        self.transfer_event(&ManagedAddress::zero(), &caller, &initial_supply);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    #[endpoint]
+    fn transfer(&self, to: ManagedAddress, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        self.move_balance(&caller, &to, &amount);
+    }
+
+    /// Allow \`spender\` to move up to \`amount\` from the caller's balance.
+    /// Setting a new value overwrites the previous allowance.
+    #[endpoint]
+    fn approve(&self, spender: ManagedAddress, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        require!(!spender.is_zero(), "Cannot approve zero address");
+        require!(spender != caller, "Cannot approve self");
+
+        self.allowance(&caller, &spender).set(&amount);
+
+        self.approval_event(&caller, &spender, &amount);
+    }
+
+    #[endpoint(transferFrom)]
+    fn transfer_from(&self, from: ManagedAddress, to: ManagedAddress, amount: BigUint) {
+        let spender = self.blockchain().get_caller();
+
+        let allowed = self.allowance(&from, &spender).get();
+        require!(allowed >= amount, "Insufficient allowance");
+
+        self.allowance(&from, &spender).set(&(allowed - &amount));
+        self.move_balance(&from, &to, &amount);
+    }
+
+    #[endpoint]
+    fn mint(&self, to: ManagedAddress, amount: BigUint) {
+        self.require_owner();
+        require!(!to.is_zero(), "Cannot mint to zero address");
+        require!(amount > 0, "Amount must be positive");
+
+        self.balance(&to).update(|balance| *balance += &amount);
+        self.total_supply().update(|supply| *supply += &amount);
+
+        self.transfer_event(&ManagedAddress::zero(), &to, &amount);
+    }
+
+    #[endpoint]
+    fn burn(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        require!(amount > 0, "Amount must be positive");
+
+        let caller_balance = self.balance(&caller).get();
+        require!(caller_balance >= amount, "Insufficient balance");
+
+        self.balance(&caller).set(&(caller_balance - &amount));
+        self.total_supply().update(|supply| *supply -= &amount);
+
+        self.transfer_event(&caller, &ManagedAddress::zero(), &amount);
+    }
+
+    // Views
+
+    #[view(getName)]
+    fn get_name(&self) -> ManagedBuffer {
+        ManagedBuffer::from(TOKEN_NAME)
+    }
+
+    #[view(getTicker)]
+    fn get_ticker(&self) -> ManagedBuffer {
+        ManagedBuffer::from(TOKEN_TICKER)
+    }
+
+    #[view(getDecimals)]
+    fn get_decimals(&self) -> u32 {
+        TOKEN_DECIMALS
+    }
+
+    #[view(getBalance)]
+    fn get_balance(&self, address: ManagedAddress) -> BigUint {
+        self.balance(&address).get()
+    }
+
+    #[view(getAllowance)]
+    fn get_allowance(&self, owner: ManagedAddress, spender: ManagedAddress) -> BigUint {
+        self.allowance(&owner, &spender).get()
+    }
+
+    #[view(getTotalSupply)]
+    fn get_total_supply(&self) -> BigUint {
+        self.total_supply().get()
+    }
+
+    // Storage
+
+    #[storage_mapper("balance")]
+    fn balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("allowance")]
+    fn allowance(
+        &self,
+        owner: &ManagedAddress,
+        spender: &ManagedAddress,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("totalSupply")]
+    fn total_supply(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("owner")]
+    fn owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // Events
+
+    /// Mints use the zero address as \`from\`, burns use it as \`to\`.
+    #[event("transfer")]
+    fn transfer_event(
+        &self,
+        #[indexed] from: &ManagedAddress,
+        #[indexed] to: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    #[event("approval")]
+    fn approval_event(
+        &self,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] spender: &ManagedAddress,
+        amount: &BigUint,
+    );
+
+    // Helper functions
+
+    fn move_balance(&self, from: &ManagedAddress, to: &ManagedAddress, amount: &BigUint) {
+        require!(!to.is_zero(), "Cannot transfer to zero address");
+        require!(*amount > 0, "Amount must be positive");
+
+        let from_balance = self.balance(from).get();
+        require!(from_balance >= *amount, "Insufficient balance");
+
+        self.balance(from).set(&(from_balance - amount));
+        self.balance(to).update(|balance| *balance += amount);
+
+        self.transfer_event(from, to, amount);
+    }
+
+    fn require_owner(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(caller == self.owner().get(), "Only owner can call this function");
+    }
+}
+`;
+  },
+};
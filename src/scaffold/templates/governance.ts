@@ -0,0 +1,318 @@
+import type { ContractTemplate } from '../types.js';
+import { parseU64 } from '../rust.js';
+
+/**
+ * Proxy for the contract generated by the fungible_token template. Keep the
+ * signatures in sync with templates/fungible-token.ts.
+ */
+const TOKEN_PROXY = `use klever_sc::imports::*;
+
+/// Endpoints of a contract generated from the \`fungible_token\` template
+#[klever_sc::proxy]
+pub trait TokenContract {
+    #[endpoint]
+    fn transfer(&self, to: ManagedAddress, amount: BigUint);
+
+    #[endpoint(transferFrom)]
+    fn transfer_from(&self, from: ManagedAddress, to: ManagedAddress, amount: BigUint);
+
+    #[view(getBalance)]
+    fn get_balance(&self, address: ManagedAddress) -> BigUint;
+
+    #[view(getTotalSupply)]
+    fn get_total_supply(&self) -> BigUint;
+}
+`;
+
+/**
+ * DAO governance voting with tokens locked from a fungible_token contract
+ */
+export const governanceTemplate: ContractTemplate = {
+  name: 'governance',
+  description:
+    'DAO governance: proposals, votes weighted by tokens locked from a fungible_token contract, quorum, execution via cross-contract call',
+  parameters: [
+    {
+      name: 'quorum',
+      type: 'string',
+      description: 'Minimum total votes (for + against, raw token units) for a proposal to pass',
+      default: '1000000000',
+    },
+    {
+      name: 'proposalThreshold',
+      type: 'string',
+      description: 'Locked tokens required to create a proposal (raw units)',
+      default: '1000000',
+    },
+    {
+      name: 'votingPeriodSeconds',
+      type: 'number',
+      description: 'How long a proposal stays open for voting',
+      default: 259200,
+    },
+  ],
+  extraFiles: () => ({ 'src/token_proxy.rs': TOKEN_PROXY }),
+  render: ({ traitName, params }) => {
+    const quorum = parseU64(params.quorum, 'quorum');
+    const threshold = parseU64(params.proposalThreshold, 'proposalThreshold');
+    const votingPeriod = parseU64(
+      Math.floor(Number(params.votingPeriodSeconds)),
+      'votingPeriodSeconds'
+    );
+
+    return `#![no_std]
+
+use klever_sc::imports::*;
+use klever_sc::derive_imports::*;
+
+pub mod token_proxy;
+
+pub const VOTING_PERIOD_SECONDS: u64 = ${votingPeriod};
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct Proposal<M: ManagedTypeApi> {
+    pub proposer: ManagedAddress<M>,
+    pub target: ManagedAddress<M>,
+    pub endpoint: ManagedBuffer<M>,
+    pub arguments: ManagedVec<M, ManagedBuffer<M>>,
+    pub description: ManagedBuffer<M>,
+    pub end_timestamp: u64,
+    pub for_votes: BigUint<M>,
+    pub against_votes: BigUint<M>,
+    pub executed: bool,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ProposalStatus {
+    Active,
+    Defeated,
+    Succeeded,
+    Executed,
+}
+
+#[klever_sc::contract]
+pub trait ${traitName} {
+    /// \`token_address\` is a contract deployed from the fungible_token template.
+    #[init]
+    fn init(&self, token_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&token_address),
+            "Token address must be a contract"
+        );
+        self.token_address().set(token_address);
+        self.quorum().set(BigUint::from(${quorum}u64));
+        self.proposal_threshold().set(BigUint::from(${threshold}u64));
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    // Voting power
+
+    /// Lock tokens as voting power. Call \`approve(governance, amount)\` on the token first.
+    #[endpoint(lockTokens)]
+    fn lock_tokens(&self, amount: BigUint) {
+        require!(amount > 0, "Amount must be positive");
+        let caller = self.blockchain().get_caller();
+        let own_address = self.blockchain().get_sc_address();
+
+        self.token_contract(self.token_address().get())
+            .transfer_from(caller.clone(), own_address, amount.clone())
+            .execute_on_dest_context::<()>();
+
+        self.locked(&caller).update(|locked| *locked += &amount);
+    }
+
+    /// Unlock tokens once every proposal the caller voted on has closed.
+    #[endpoint(unlockTokens)]
+    fn unlock_tokens(&self, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.blockchain().get_block_timestamp() > self.vote_lock_until(&caller).get(),
+            "Tokens are locked until your voted proposals close"
+        );
+        let locked = self.locked(&caller).get();
+        require!(amount > 0 && amount <= locked, "Invalid amount");
+
+        self.locked(&caller).set(&(locked - &amount));
+        self.token_contract(self.token_address().get())
+            .transfer(caller, amount)
+            .execute_on_dest_context::<()>();
+    }
+
+    // Proposal lifecycle
+
+    /// Propose a call of \`endpoint\` on \`target\`, any contract but the token.
+    #[endpoint]
+    fn propose(
+        &self,
+        target: ManagedAddress,
+        endpoint: ManagedBuffer,
+        description: ManagedBuffer,
+        arguments: MultiValueEncoded<ManagedBuffer>,
+    ) -> usize {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.locked(&caller).get() >= self.proposal_threshold().get(),
+            "Not enough locked tokens to propose"
+        );
+        // This contract holds every voter's locked tokens: a call on the token
+        // contract could transfer or approve them away
+        require!(
+            target != self.token_address().get(),
+            "Proposals cannot call the token contract"
+        );
+
+        let proposal = Proposal {
+            proposer: caller.clone(),
+            target,
+            endpoint,
+            arguments: arguments.to_vec(),
+            description,
+            end_timestamp: self.blockchain().get_block_timestamp() + VOTING_PERIOD_SECONDS,
+            for_votes: BigUint::zero(),
+            against_votes: BigUint::zero(),
+            executed: false,
+        };
+        let proposal_id = self.proposals().push(&proposal);
+
+        self.proposal_created_event(proposal_id, &caller);
+        proposal_id
+    }
+
+    #[endpoint]
+    fn vote(&self, proposal_id: usize, support: bool) {
+        require!(
+            self.get_proposal_status(proposal_id) == ProposalStatus::Active,
+            "Proposal is not active"
+        );
+        let caller = self.blockchain().get_caller();
+        require!(!self.has_voted(proposal_id, &caller).get(), "Already voted");
+
+        let weight = self.locked(&caller).get();
+        require!(weight > 0, "No voting power");
+
+        let mut proposal = self.proposals().get(proposal_id);
+        if support {
+            proposal.for_votes += &weight;
+        } else {
+            proposal.against_votes += &weight;
+        }
+        self.proposals().set(proposal_id, &proposal);
+        self.has_voted(proposal_id, &caller).set(true);
+        self.vote_lock_until(&caller)
+            .update(|until| *until = core::cmp::max(*until, proposal.end_timestamp));
+
+        self.vote_event(proposal_id, &caller, support, &weight);
+    }
+
+    /// Perform the proposal's call. Klever VM calls are synchronous, so a
+    /// failing target reverts the whole execution and the proposal stays executable.
+    #[endpoint]
+    fn execute(&self, proposal_id: usize) {
+        require!(
+            self.get_proposal_status(proposal_id) == ProposalStatus::Succeeded,
+            "Proposal has not succeeded"
+        );
+        let mut proposal = self.proposals().get(proposal_id);
+        proposal.executed = true;
+        self.proposals().set(proposal_id, &proposal);
+
+        let mut call = self
+            .send()
+            .contract_call::<()>(proposal.target, proposal.endpoint);
+        for argument in proposal.arguments.iter() {
+            call.push_raw_argument(argument.clone_value());
+        }
+        call.execute_on_dest_context::<()>();
+
+        self.proposal_executed_event(proposal_id);
+    }
+
+    // Views
+
+    #[view(getProposalStatus)]
+    fn get_proposal_status(&self, proposal_id: usize) -> ProposalStatus {
+        require!(
+            proposal_id >= 1 && proposal_id <= self.proposals().len(),
+            "Proposal does not exist"
+        );
+        let proposal = self.proposals().get(proposal_id);
+        if proposal.executed {
+            return ProposalStatus::Executed;
+        }
+        if self.blockchain().get_block_timestamp() <= proposal.end_timestamp {
+            return ProposalStatus::Active;
+        }
+        let total = &proposal.for_votes + &proposal.against_votes;
+        if total >= self.quorum().get() && proposal.for_votes > proposal.against_votes {
+            ProposalStatus::Succeeded
+        } else {
+            ProposalStatus::Defeated
+        }
+    }
+
+    #[view(getProposal)]
+    fn get_proposal(&self, proposal_id: usize) -> Proposal<Self::Api> {
+        self.proposals().get(proposal_id)
+    }
+
+    #[view(getLockedTokens)]
+    fn get_locked_tokens(&self, address: ManagedAddress) -> BigUint {
+        self.locked(&address).get()
+    }
+
+    // Proxies
+
+    #[proxy]
+    fn token_contract(&self, sc_address: ManagedAddress) -> token_proxy::Proxy<Self::Api>;
+
+    // Storage
+
+    #[view(getTokenAddress)]
+    #[storage_mapper("tokenAddress")]
+    fn token_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(getQuorum)]
+    #[storage_mapper("quorum")]
+    fn quorum(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("proposalThreshold")]
+    fn proposal_threshold(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("proposals")]
+    fn proposals(&self) -> VecMapper<Proposal<Self::Api>>;
+
+    #[storage_mapper("locked")]
+    fn locked(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("hasVoted")]
+    fn has_voted(&self, proposal_id: usize, address: &ManagedAddress) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("voteLockUntil")]
+    fn vote_lock_until(&self, address: &ManagedAddress) -> SingleValueMapper<u64>;
+
+    // Events
+
+    #[event("proposalCreated")]
+    fn proposal_created_event(
+        &self,
+        #[indexed] proposal_id: usize,
+        #[indexed] proposer: &ManagedAddress,
+    );
+
+    #[event("vote")]
+    fn vote_event(
+        &self,
+        #[indexed] proposal_id: usize,
+        #[indexed] voter: &ManagedAddress,
+        #[indexed] support: bool,
+        weight: &BigUint,
+    );
+
+    #[event("proposalExecuted")]
+    fn proposal_executed_event(&self, #[indexed] proposal_id: usize);
+}
+`;
+  },
+};
@@ -0,0 +1,35 @@
+import type { ContractTemplate } from '../types.js';
+import { emptyTemplate } from './empty.js';
+import { adderTemplate } from './adder.js';
+import { basicTokenTemplate } from './basic-token.js';
+import { fungibleTokenTemplate } from './fungible-token.js';
+import { nftCollectionTemplate } from './nft-collection.js';
+import { stakingTemplate } from './staking.js';
+import { crowdfundingTemplate } from './crowdfunding.js';
+import { multisigTemplate } from './multisig.js';
+import { governanceTemplate } from './governance.js';
+import { vestingTemplate } from './vesting.js';
+import { escrowTemplate } from './escrow.js';
+import { lotteryTemplate } from './lottery.js';
+
+/**
+ * All contract templates available to scaffold_contract, in listing order
+ */
+export const CONTRACT_TEMPLATES: ContractTemplate[] = [
+  emptyTemplate,
+  adderTemplate,
+  basicTokenTemplate,
+  fungibleTokenTemplate,
+  nftCollectionTemplate,
+  stakingTemplate,
+  crowdfundingTemplate,
+  multisigTemplate,
+  governanceTemplate,
+  vestingTemplate,
+  escrowTemplate,
+  lotteryTemplate,
+];
+
+export function getContractTemplate(name: string): ContractTemplate | undefined {
+  return CONTRACT_TEMPLATES.find(t => t.name === name);
+}
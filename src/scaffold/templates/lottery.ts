@@ -0,0 +1,209 @@
+import type { ContractTemplate } from '../types.js';
+import { paymentHelpers } from '../payments.js';
+import { parseU64 } from '../rust.js';
+
+/**
+ * Round-based lottery using the block-seeded RandomnessSource
+ */
+export const lotteryTemplate: ContractTemplate = {
+  name: 'lottery',
+  description:
+    'Lottery with committed ticket purchases and a draw that uses RandomnessSource in a later block',
+  parameters: [
+    {
+      name: 'tokenId',
+      type: 'string',
+      description: 'Ticket payment token: "KLV" or a KDA ID such as "MYKDA-1A2B"',
+      default: 'KLV',
+    },
+    {
+      name: 'ticketPrice',
+      type: 'string',
+      description: 'Price of one ticket (raw units)',
+      default: '10000000',
+    },
+    {
+      name: 'roundDurationSeconds',
+      type: 'number',
+      description: 'Ticket sales window, starting with the first purchase of a round',
+      default: 86400,
+    },
+    {
+      name: 'maxTicketsPerRound',
+      type: 'number',
+      description: 'Upper bound on tickets sold in one round',
+      default: 10000,
+    },
+  ],
+  render: ({ traitName, params }) => {
+    const { payableAttr, helpers } = paymentHelpers(params.tokenId);
+    const ticketPrice = parseU64(params.ticketPrice, 'ticketPrice');
+    if (ticketPrice === 0n) {
+      throw new Error('Parameter "ticketPrice" must be greater than zero.');
+    }
+    const duration = parseU64(
+      Math.floor(Number(params.roundDurationSeconds)),
+      'roundDurationSeconds'
+    );
+    const maxTickets = Math.max(Math.trunc(Number(params.maxTicketsPerRound)), 1);
+
+    return `#![no_std]
+
+use klever_sc::imports::*;
+
+pub const ROUND_DURATION_SECONDS: u64 = ${duration};
+pub const MAX_TICKETS_PER_ROUND: usize = ${maxTickets};
+
+// Randomness notes:
+// - Never derive winners from block timestamp/nonce arithmetic: validators and
+//   callers can predict or influence it.
+// - RandomnessSource is seeded from the block's random seed, so a ticket bought
+//   in block N must not be drawn with the seed of block N. Purchases record
+//   their block nonce and draw() only runs in a strictly later block.
+// - draw() rejects contract callers so nobody can wrap it and revert when the
+//   outcome is unfavourable.
+
+#[klever_sc::contract]
+pub trait ${traitName} {
+    #[init]
+    fn init(&self) {
+        self.ticket_price().set(BigUint::from(${ticketPrice}u64));
+        self.round_id().set(1u64);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Buy \`count\` tickets for the current round; payment must equal count * price.
+    ${payableAttr}
+    #[endpoint(buyTickets)]
+    fn buy_tickets(&self, count: usize) {
+        require!(count > 0, "Must buy at least one ticket");
+        let payment = self.receive_payment();
+        require!(
+            payment == self.ticket_price().get() * count as u64,
+            "Payment must equal count * ticket price"
+        );
+
+        let now = self.blockchain().get_block_timestamp();
+        let round_id = self.round_id().get();
+        if self.round_end().is_empty() {
+            self.round_end().set(now + ROUND_DURATION_SECONDS);
+        }
+        require!(now < self.round_end().get(), "Ticket sales closed for this round");
+
+        let mut tickets = self.tickets(round_id);
+        require!(
+            tickets.len() + count <= MAX_TICKETS_PER_ROUND,
+            "Round ticket limit reached"
+        );
+
+        let caller = self.blockchain().get_caller();
+        for _ in 0..count {
+            tickets.push(&caller);
+        }
+        self.prize_pool().update(|pool| *pool += &payment);
+        self.last_purchase_block().set(self.blockchain().get_block_nonce());
+
+        self.tickets_bought_event(round_id, &caller, count);
+    }
+
+    /// Pick the winner once the sales window closed, then start the next round.
+    #[endpoint]
+    fn draw(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            !self.blockchain().is_smart_contract(&caller),
+            "Draw cannot be called by a contract"
+        );
+        require!(!self.round_end().is_empty(), "No tickets sold");
+        require!(
+            self.blockchain().get_block_timestamp() >= self.round_end().get(),
+            "Round still open"
+        );
+        require!(
+            self.blockchain().get_block_nonce() > self.last_purchase_block().get(),
+            "Draw must happen in a later block than the last purchase"
+        );
+
+        let round_id = self.round_id().get();
+        let tickets = self.tickets(round_id);
+        let total = tickets.len() as u64;
+
+        let mut rand_source = RandomnessSource::new();
+        let winning_ticket = (rand_source.next_u64() % total) as usize + 1;
+        let winner = tickets.get(winning_ticket);
+
+        let prize = self.prize_pool().take();
+        self.round_end().clear();
+        self.round_id().set(round_id + 1);
+        self.last_winner().set(&winner);
+
+        self.send_payment(&winner, &prize);
+        self.winner_drawn_event(round_id, &winner, &prize);
+    }
+
+    // Views
+
+    #[view(getTicketCount)]
+    fn get_ticket_count(&self) -> usize {
+        self.tickets(self.round_id().get()).len()
+    }
+
+    #[view(getPrizePool)]
+    fn get_prize_pool(&self) -> BigUint {
+        self.prize_pool().get()
+    }
+
+${helpers}
+
+    // Storage
+
+    #[view(getTicketPrice)]
+    #[storage_mapper("ticketPrice")]
+    fn ticket_price(&self) -> SingleValueMapper<BigUint>;
+
+    #[view(getRoundId)]
+    #[storage_mapper("roundId")]
+    fn round_id(&self) -> SingleValueMapper<u64>;
+
+    /// Empty until the first ticket of a round is bought
+    #[view(getRoundEnd)]
+    #[storage_mapper("roundEnd")]
+    fn round_end(&self) -> SingleValueMapper<u64>;
+
+    /// One entry per ticket, keyed by round so old rounds never need clearing
+    #[storage_mapper("tickets")]
+    fn tickets(&self, round_id: u64) -> VecMapper<ManagedAddress>;
+
+    #[storage_mapper("prizePool")]
+    fn prize_pool(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("lastPurchaseBlock")]
+    fn last_purchase_block(&self) -> SingleValueMapper<u64>;
+
+    #[view(getLastWinner)]
+    #[storage_mapper("lastWinner")]
+    fn last_winner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    // Events
+
+    #[event("ticketsBought")]
+    fn tickets_bought_event(
+        &self,
+        #[indexed] round_id: u64,
+        #[indexed] buyer: &ManagedAddress,
+        count: usize,
+    );
+
+    #[event("winnerDrawn")]
+    fn winner_drawn_event(
+        &self,
+        #[indexed] round_id: u64,
+        #[indexed] winner: &ManagedAddress,
+        prize: &BigUint,
+    );
+}
+`;
+  },
+};
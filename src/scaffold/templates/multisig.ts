@@ -0,0 +1,315 @@
+import type { ContractTemplate } from '../types.js';
+
+/**
+ * Multisig wallet: board members propose, sign and perform actions once quorum is reached
+ */
+export const multisigTemplate: ContractTemplate = {
+  name: 'multisig',
+  description:
+    'Multisig wallet with propose/sign/perform actions, configurable quorum and board member management',
+  parameters: [
+    {
+      name: 'allowContractCalls',
+      type: 'boolean',
+      description:
+        'Include an action that calls another contract (e.g. to administer owned token contracts)',
+      default: true,
+    },
+  ],
+  render: ({ traitName, params }) => {
+    const scCalls = params.allowContractCalls !== false;
+
+    const scCallVariant = scCalls
+      ? `
+    ScCall {
+        to: ManagedAddress<M>,
+        klv_amount: BigUint<M>,
+        endpoint: ManagedBuffer<M>,
+        arguments: ManagedVec<M, ManagedBuffer<M>>,
+    },`
+      : '';
+
+    const scCallPropose = scCalls
+      ? `
+    #[endpoint(proposeScCall)]
+    fn propose_sc_call(
+        &self,
+        to: ManagedAddress,
+        klv_amount: BigUint,
+        endpoint: ManagedBuffer,
+        arguments: MultiValueEncoded<ManagedBuffer>,
+    ) -> usize {
+        self.propose(Action::ScCall {
+            to,
+            klv_amount,
+            endpoint,
+            arguments: arguments.to_vec(),
+        })
+    }
+`
+      : '';
+
+    const scCallPerform = scCalls
+      ? `
+            Action::ScCall {
+                to,
+                klv_amount,
+                endpoint,
+                arguments,
+            } => {
+                let mut call = self
+                    .send()
+                    .contract_call::<()>(to, endpoint)
+                    .with_klv_transfer(klv_amount);
+                for argument in arguments.iter() {
+                    call.push_raw_argument(argument.clone_value());
+                }
+                call.execute_on_dest_context::<()>();
+            },`
+      : '';
+
+    return `#![no_std]
+
+use klever_sc::imports::*;
+use klever_sc::derive_imports::*;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub enum Action<M: ManagedTypeApi> {
+    /// Placeholder left behind by performed or discarded actions
+    Nothing,
+    AddBoardMember(ManagedAddress<M>),
+    RemoveBoardMember(ManagedAddress<M>),
+    ChangeQuorum(usize),
+    SendKlv {
+        to: ManagedAddress<M>,
+        amount: BigUint<M>,
+    },
+    SendKda {
+        to: ManagedAddress<M>,
+        token: TokenIdentifier<M>,
+        nonce: u64,
+        amount: BigUint<M>,
+    },${scCallVariant}
+}
+
+#[klever_sc::contract]
+pub trait ${traitName} {
+    #[init]
+    fn init(&self, quorum: usize, board: MultiValueEncoded<ManagedAddress>) {
+        for member in board {
+            self.board_members().insert(member);
+        }
+        let board_size = self.board_members().len();
+        require!(quorum > 0 && quorum <= board_size, "Quorum must be between 1 and board size");
+        self.quorum().set(quorum);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Accept KLV or KDA deposits so the wallet can hold funds.
+    #[payable("*")]
+    #[endpoint]
+    fn deposit(&self) {}
+
+    // Proposals — the proposer signs automatically
+
+    #[endpoint(proposeAddBoardMember)]
+    fn propose_add_board_member(&self, member: ManagedAddress) -> usize {
+        self.propose(Action::AddBoardMember(member))
+    }
+
+    #[endpoint(proposeRemoveBoardMember)]
+    fn propose_remove_board_member(&self, member: ManagedAddress) -> usize {
+        self.propose(Action::RemoveBoardMember(member))
+    }
+
+    #[endpoint(proposeChangeQuorum)]
+    fn propose_change_quorum(&self, quorum: usize) -> usize {
+        self.propose(Action::ChangeQuorum(quorum))
+    }
+
+    #[endpoint(proposeSendKlv)]
+    fn propose_send_klv(&self, to: ManagedAddress, amount: BigUint) -> usize {
+        self.propose(Action::SendKlv { to, amount })
+    }
+
+    #[endpoint(proposeSendKda)]
+    fn propose_send_kda(
+        &self,
+        to: ManagedAddress,
+        token: TokenIdentifier,
+        nonce: u64,
+        amount: BigUint,
+    ) -> usize {
+        self.propose(Action::SendKda {
+            to,
+            token,
+            nonce,
+            amount,
+        })
+    }
+${scCallPropose}
+    // Signing
+
+    #[endpoint]
+    fn sign(&self, action_id: usize) {
+        let caller = self.require_board_member();
+        self.require_pending(action_id);
+        self.action_signers(action_id).insert(caller.clone());
+        self.sign_event(action_id, &caller);
+    }
+
+    #[endpoint]
+    fn unsign(&self, action_id: usize) {
+        let caller = self.require_board_member();
+        self.require_pending(action_id);
+        self.action_signers(action_id).swap_remove(&caller);
+        self.unsign_event(action_id, &caller);
+    }
+
+    #[endpoint(performAction)]
+    fn perform_action(&self, action_id: usize) {
+        let caller = self.require_board_member();
+        self.require_pending(action_id);
+        require!(self.quorum_reached(action_id), "Quorum has not been reached");
+
+        let action = self.actions().get(action_id);
+        self.clear_action(action_id);
+
+        match action {
+            Action::Nothing => {},
+            Action::AddBoardMember(member) => {
+                self.board_members().insert(member);
+            },
+            Action::RemoveBoardMember(member) => {
+                require!(self.board_members().swap_remove(&member), "Not a board member");
+                require!(
+                    self.quorum().get() <= self.board_members().len(),
+                    "Quorum would exceed board size"
+                );
+            },
+            Action::ChangeQuorum(quorum) => {
+                require!(
+                    quorum > 0 && quorum <= self.board_members().len(),
+                    "Quorum must be between 1 and board size"
+                );
+                self.quorum().set(quorum);
+            },
+            Action::SendKlv { to, amount } => {
+                self.send().direct_klv(&to, &amount);
+            },
+            Action::SendKda {
+                to,
+                token,
+                nonce,
+                amount,
+            } => {
+                self.send().direct_kda(&to, &token, nonce, &amount);
+            },${scCallPerform}
+        }
+
+        self.perform_event(action_id, &caller);
+    }
+
+    /// Drop a pending action that no longer has any signers.
+    #[endpoint(discardAction)]
+    fn discard_action(&self, action_id: usize) {
+        self.require_board_member();
+        self.require_pending(action_id);
+        require!(self.action_signers(action_id).is_empty(), "Action still has signers");
+        self.clear_action(action_id);
+    }
+
+    // Views
+
+    #[view(quorumReached)]
+    fn quorum_reached(&self, action_id: usize) -> bool {
+        let board = self.board_members();
+        let valid_signers = self
+            .action_signers(action_id)
+            .iter()
+            .filter(|signer| board.contains(signer))
+            .count();
+        valid_signers >= self.quorum().get()
+    }
+
+    #[view(getActionData)]
+    fn get_action_data(&self, action_id: usize) -> Action<Self::Api> {
+        self.actions().get(action_id)
+    }
+
+    #[view(getActionSigners)]
+    fn get_action_signers(&self, action_id: usize) -> MultiValueEncoded<ManagedAddress> {
+        self.action_signers(action_id).iter().collect()
+    }
+
+    #[view(getBoardMembers)]
+    fn get_board_members(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.board_members().iter().collect()
+    }
+
+    // Internal
+
+    fn propose(&self, action: Action<Self::Api>) -> usize {
+        let caller = self.require_board_member();
+        let action_id = self.actions().push(&action);
+        self.action_signers(action_id).insert(caller.clone());
+        self.propose_event(action_id, &caller);
+        action_id
+    }
+
+    fn require_board_member(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(self.board_members().contains(&caller), "Only board members");
+        caller
+    }
+
+    fn require_pending(&self, action_id: usize) {
+        require!(
+            action_id >= 1 && action_id <= self.actions().len(),
+            "Action does not exist"
+        );
+        require!(
+            !matches!(self.actions().get(action_id), Action::Nothing),
+            "Action already performed or discarded"
+        );
+    }
+
+    fn clear_action(&self, action_id: usize) {
+        self.actions().set(action_id, &Action::Nothing);
+        self.action_signers(action_id).clear();
+    }
+
+    // Storage
+
+    #[storage_mapper("boardMembers")]
+    fn board_members(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getQuorum)]
+    #[storage_mapper("quorum")]
+    fn quorum(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("actions")]
+    fn actions(&self) -> VecMapper<Action<Self::Api>>;
+
+    #[storage_mapper("actionSigners")]
+    fn action_signers(&self, action_id: usize) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Events
+
+    #[event("propose")]
+    fn propose_event(&self, #[indexed] action_id: usize, #[indexed] proposer: &ManagedAddress);
+
+    #[event("sign")]
+    fn sign_event(&self, #[indexed] action_id: usize, #[indexed] signer: &ManagedAddress);
+
+    #[event("unsign")]
+    fn unsign_event(&self, #[indexed] action_id: usize, #[indexed] signer: &ManagedAddress);
+
+    #[event("perform")]
+    fn perform_event(&self, #[indexed] action_id: usize, #[indexed] performer: &ManagedAddress);
+}
+`;
+  },
+};
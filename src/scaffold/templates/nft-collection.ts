@@ -0,0 +1,215 @@
+import type { ContractTemplate } from '../types.js';
+import { escapeRustBytes, parseTicker } from '../rust.js';
+
+/**
+ * NFT/SFT collection manager built on NonFungibleTokenMapper
+ */
+export const nftCollectionTemplate: ContractTemplate = {
+  name: 'nft_collection',
+  description:
+    'NFT/SFT collection: issue, owner-only mint with attributes, burn, royalties storage and token metadata views',
+  parameters: [
+    {
+      name: 'collectionName',
+      type: 'string',
+      description: 'Collection name used when issuing the token',
+      default: 'My Collection',
+    },
+    {
+      name: 'ticker',
+      type: 'string',
+      description: 'Collection ticker (uppercase, 3-10 chars)',
+      default: 'MYNFT',
+    },
+    {
+      name: 'royaltiesBasisPoints',
+      type: 'number',
+      description: 'Initial royalties in basis points (100 = 1%, max 10000)',
+      default: 500,
+    },
+    {
+      name: 'semiFungible',
+      type: 'boolean',
+      description: 'When true, mint takes a quantity so each nonce can have supply > 1 (SFT)',
+      default: false,
+    },
+  ],
+  render: ({ traitName, params }) => {
+    const sft = params.semiFungible === true;
+    const royalties = Math.min(
+      Math.max(Math.trunc(Number(params.royaltiesBasisPoints)), 0),
+      10_000
+    );
+    const mintArgs = [
+      'to: ManagedAddress',
+      ...(sft ? ['quantity: BigUint'] : []),
+      'name: ManagedBuffer',
+      'uri: ManagedBuffer',
+      'attributes: NftAttributes<Self::Api>',
+    ].join(', ');
+    const quantityLine = sft
+      ? '        require!(quantity > 0, "Quantity must be positive");\n'
+      : '        let quantity = BigUint::from(1u32);\n';
+
+    return `#![no_std]
+
+use klever_sc::imports::*;
+use klever_sc::derive_imports::*;
+
+pub const COLLECTION_NAME: &[u8] = b"${escapeRustBytes(params.collectionName)}";
+pub const COLLECTION_TICKER: &[u8] = b"${parseTicker(params.ticker, 'ticker')}";
+pub const MAX_ROYALTIES: u32 = 10_000;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct NftAttributes<M: ManagedTypeApi> {
+    pub rarity: ManagedBuffer<M>,
+    pub level: u32,
+}
+
+#[derive(TopEncode, TopDecode, TypeAbi)]
+pub struct TokenMetadata<M: ManagedTypeApi> {
+    pub name: ManagedBuffer<M>,
+    pub uri: ManagedBuffer<M>,
+    pub attributes: NftAttributes<M>,
+    pub creator: ManagedAddress<M>,
+    pub royalties: u32,
+}
+
+#[klever_sc::contract]
+pub trait ${traitName} {
+    #[init]
+    fn init(&self) {
+        self.royalties().set(${royalties}u32);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Issue the collection. Can only be called once; the issue fee is paid in KLV.
+    #[only_owner]
+    #[payable("KLV")]
+    #[endpoint(issueCollection)]
+    fn issue_collection(&self) -> TokenIdentifier {
+        require!(self.collection().is_empty(), "Collection already issued");
+
+        self.collection().issue(
+            &ManagedBuffer::from(COLLECTION_NAME),
+            &ManagedBuffer::from(COLLECTION_TICKER),
+            0,
+        )
+    }
+
+    #[only_owner]
+    #[endpoint]
+    fn mint(&self, ${mintArgs}) -> u64 {
+        require!(!self.collection().is_empty(), "Collection not issued");
+        require!(!to.is_zero(), "Cannot mint to zero address");
+        require!(!name.is_empty(), "Name is required");
+${quantityLine}
+        let token_id = self.collection().get_token_id();
+        let royalties = self.royalties().get();
+        let mut uris = ManagedVec::new();
+        uris.push(uri.clone());
+
+        let nonce = self.send().kda_nft_create(
+            &token_id,
+            &quantity,
+            &name,
+            &BigUint::from(royalties),
+            &ManagedBuffer::new(),
+            &attributes,
+            &uris,
+        );
+
+        self.token_metadata(nonce).set(TokenMetadata {
+            name,
+            uri,
+            attributes,
+            creator: self.blockchain().get_caller(),
+            royalties,
+        });
+        self.minted_count().update(|count| *count += 1);
+
+        self.send().direct_kda(&to, &token_id, nonce, &quantity);
+
+        self.mint_event(&to, nonce, &quantity);
+        nonce
+    }
+
+    /// Burn tokens of this collection sent with the call.
+    #[payable("*")]
+    #[endpoint]
+    fn burn(&self) {
+        let payment = self.call_value().single_kda();
+        require!(
+            payment.token_identifier == self.collection().get_token_id(),
+            "Wrong token"
+        );
+
+        self.send()
+            .kda_burn(&payment.token_identifier, payment.token_nonce, &payment.amount);
+
+        let caller = self.blockchain().get_caller();
+        self.burn_event(&caller, payment.token_nonce, &payment.amount);
+    }
+
+    #[only_owner]
+    #[endpoint(setRoyalties)]
+    fn set_royalties(&self, royalties: u32) {
+        require!(royalties <= MAX_ROYALTIES, "Royalties cannot exceed 100%");
+        self.royalties().set(royalties);
+        self.royalties_updated_event(royalties);
+    }
+
+    // Views
+
+    #[view(getCollectionId)]
+    fn get_collection_id(&self) -> TokenIdentifier {
+        self.collection().get_token_id()
+    }
+
+    #[view(getRoyalties)]
+    fn get_royalties(&self) -> u32 {
+        self.royalties().get()
+    }
+
+    #[view(getTokenMetadata)]
+    fn get_token_metadata(&self, nonce: u64) -> TokenMetadata<Self::Api> {
+        require!(!self.token_metadata(nonce).is_empty(), "Unknown token nonce");
+        self.token_metadata(nonce).get()
+    }
+
+    #[view(getMintedCount)]
+    fn get_minted_count(&self) -> u64 {
+        self.minted_count().get()
+    }
+
+    // Storage
+
+    #[storage_mapper("collection")]
+    fn collection(&self) -> NonFungibleTokenMapper;
+
+    /// Royalties in basis points applied to newly minted tokens
+    #[storage_mapper("royalties")]
+    fn royalties(&self) -> SingleValueMapper<u32>;
+
+    #[storage_mapper("tokenMetadata")]
+    fn token_metadata(&self, nonce: u64) -> SingleValueMapper<TokenMetadata<Self::Api>>;
+
+    #[storage_mapper("mintedCount")]
+    fn minted_count(&self) -> SingleValueMapper<u64>;
+
+    // Events
+
+    #[event("mint")]
+    fn mint_event(&self, #[indexed] to: &ManagedAddress, #[indexed] nonce: u64, quantity: &BigUint);
+
+    #[event("burn")]
+    fn burn_event(&self, #[indexed] from: &ManagedAddress, #[indexed] nonce: u64, quantity: &BigUint);
+
+    #[event("royaltiesUpdated")]
+    fn royalties_updated_event(&self, royalties: u32);
+}
+`;
+  },
+};
@@ -0,0 +1,250 @@
+import type { ContractTemplate } from '../types.js';
+import { paymentHelpers } from '../payments.js';
+import { u64Literal } from '../rust.js';
+
+/**
+ * Single-token staking with epoch-based reward accrual (reward-per-share accumulator)
+ */
+export const stakingTemplate: ContractTemplate = {
+  name: 'staking',
+  description:
+    'Staking with stake/unstake/claim, epoch-based reward accrual via get_block_epoch and a MapMapper of stakers',
+  parameters: [
+    {
+      name: 'tokenId',
+      type: 'string',
+      description: 'Token staked and paid as reward: "KLV" or a KDA ID such as "MYKDA-1A2B"',
+      default: 'KLV',
+    },
+    {
+      name: 'rewardPerEpoch',
+      type: 'string',
+      description: 'Default reward distributed per epoch across all stakers (raw units)',
+      default: '1000000',
+    },
+    {
+      name: 'minStake',
+      type: 'string',
+      description: 'Minimum amount accepted by a single stake call (raw units)',
+      default: '1000000',
+    },
+  ],
+  render: ({ traitName, params }) => {
+    const { payableAttr, helpers } = paymentHelpers(params.tokenId);
+    const rewardPerEpoch = u64Literal(params.rewardPerEpoch, 'rewardPerEpoch');
+    const minStake = u64Literal(params.minStake, 'minStake');
+
+    return `#![no_std]
+
+use klever_sc::imports::*;
+use klever_sc::derive_imports::*;
+
+/// Scaling factor for the reward-per-share accumulator
+pub const PRECISION: u64 = 1_000_000_000_000;
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct StakerInfo<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    /// amount * acc_reward_per_share at the last settlement
+    pub reward_debt: BigUint<M>,
+    /// Settled but unclaimed rewards
+    pub pending: BigUint<M>,
+}
+
+#[klever_sc::contract]
+pub trait ${traitName} {
+    #[init]
+    fn init(&self) {
+        self.reward_per_epoch().set(BigUint::from(${rewardPerEpoch}));
+        self.min_stake().set(BigUint::from(${minStake}));
+        self.last_reward_epoch().set(self.blockchain().get_block_epoch());
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    ${payableAttr}
+    #[endpoint]
+    fn stake(&self) {
+        let amount = self.receive_payment();
+        require!(amount >= self.min_stake().get(), "Below minimum stake");
+
+        let caller = self.blockchain().get_caller();
+        self.update_pool();
+        let mut info = self.settle(&caller);
+
+        info.amount += &amount;
+        info.reward_debt = self.accrued(&info.amount);
+        self.stakers().insert(caller.clone(), info);
+        self.total_staked().update(|total| *total += &amount);
+
+        self.stake_event(&caller, &amount);
+    }
+
+    #[endpoint]
+    fn unstake(&self, amount: BigUint) {
+        require!(amount > 0, "Amount must be positive");
+        let caller = self.blockchain().get_caller();
+        require!(self.stakers().contains_key(&caller), "Not a staker");
+
+        self.update_pool();
+        let mut info = self.settle(&caller);
+        require!(info.amount >= amount, "Insufficient staked amount");
+
+        info.amount -= &amount;
+        info.reward_debt = self.accrued(&info.amount);
+        if info.amount == 0 && info.pending == 0 {
+            self.stakers().remove(&caller);
+        } else {
+            self.stakers().insert(caller.clone(), info);
+        }
+        self.total_staked().update(|total| *total -= &amount);
+
+        self.send_payment(&caller, &amount);
+        self.unstake_event(&caller, &amount);
+    }
+
+    #[endpoint]
+    fn claim(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.stakers().contains_key(&caller), "Not a staker");
+
+        self.update_pool();
+        let mut info = self.settle(&caller);
+        let rewards = core::mem::replace(&mut info.pending, BigUint::zero());
+        require!(rewards > 0, "No rewards to claim");
+        require!(self.reward_reserve().get() >= rewards, "Reward reserve depleted");
+
+        if info.amount == 0 {
+            self.stakers().remove(&caller);
+        } else {
+            self.stakers().insert(caller.clone(), info);
+        }
+        self.reward_reserve().update(|reserve| *reserve -= &rewards);
+
+        self.send_payment(&caller, &rewards);
+        self.claim_event(&caller, &rewards);
+    }
+
+    /// Owner deposits the tokens that back future rewards.
+    #[only_owner]
+    ${payableAttr}
+    #[endpoint(fundRewards)]
+    fn fund_rewards(&self) {
+        let amount = self.receive_payment();
+        self.reward_reserve().update(|reserve| *reserve += &amount);
+    }
+
+    #[only_owner]
+    #[endpoint(setRewardPerEpoch)]
+    fn set_reward_per_epoch(&self, reward_per_epoch: BigUint) {
+        self.update_pool();
+        self.reward_per_epoch().set(reward_per_epoch);
+    }
+
+    // Views
+
+    #[view(getPendingRewards)]
+    fn get_pending_rewards(&self, address: ManagedAddress) -> BigUint {
+        let info = match self.stakers().get(&address) {
+            Some(info) => info,
+            None => return BigUint::zero(),
+        };
+        let acc = self.acc_reward_per_share().get() + self.unaccounted_per_share();
+        info.pending + &info.amount * &acc / PRECISION - &info.reward_debt
+    }
+
+    #[view(getStake)]
+    fn get_stake(&self, address: ManagedAddress) -> BigUint {
+        self.stakers().get(&address).map(|info| info.amount).unwrap_or_default()
+    }
+
+    #[view(getTotalStaked)]
+    fn get_total_staked(&self) -> BigUint {
+        self.total_staked().get()
+    }
+
+    #[view(getStakerCount)]
+    fn get_staker_count(&self) -> usize {
+        self.stakers().len()
+    }
+
+    // Reward accounting
+
+    /// Roll the accumulator forward to the current epoch.
+    fn update_pool(&self) {
+        let extra = self.unaccounted_per_share();
+        if extra > 0 {
+            self.acc_reward_per_share().update(|acc| *acc += extra);
+        }
+        self.last_reward_epoch().set(self.blockchain().get_block_epoch());
+    }
+
+    /// Reward per share accrued since last_reward_epoch but not yet added to the accumulator.
+    fn unaccounted_per_share(&self) -> BigUint {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let last_epoch = self.last_reward_epoch().get();
+        let total = self.total_staked().get();
+        if current_epoch <= last_epoch || total == 0 {
+            return BigUint::zero();
+        }
+        let epochs = current_epoch - last_epoch;
+        self.reward_per_epoch().get() * epochs * PRECISION / total
+    }
+
+    fn accrued(&self, amount: &BigUint) -> BigUint {
+        amount * &self.acc_reward_per_share().get() / PRECISION
+    }
+
+    /// Move rewards earned since the last settlement into \`pending\`.
+    fn settle(&self, address: &ManagedAddress) -> StakerInfo<Self::Api> {
+        let mut info = self.stakers().get(address).unwrap_or_else(|| StakerInfo {
+            amount: BigUint::zero(),
+            reward_debt: BigUint::zero(),
+            pending: BigUint::zero(),
+        });
+        let accrued = self.accrued(&info.amount);
+        info.pending += accrued - &info.reward_debt;
+        info.reward_debt = self.accrued(&info.amount);
+        info
+    }
+
+${helpers}
+
+    // Storage
+
+    #[storage_mapper("stakers")]
+    fn stakers(&self) -> MapMapper<ManagedAddress, StakerInfo<Self::Api>>;
+
+    #[storage_mapper("totalStaked")]
+    fn total_staked(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("accRewardPerShare")]
+    fn acc_reward_per_share(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("lastRewardEpoch")]
+    fn last_reward_epoch(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("rewardPerEpoch")]
+    fn reward_per_epoch(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("rewardReserve")]
+    fn reward_reserve(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("minStake")]
+    fn min_stake(&self) -> SingleValueMapper<BigUint>;
+
+    // Events
+
+    #[event("stake")]
+    fn stake_event(&self, #[indexed] staker: &ManagedAddress, amount: &BigUint);
+
+    #[event("unstake")]
+    fn unstake_event(&self, #[indexed] staker: &ManagedAddress, amount: &BigUint);
+
+    #[event("claim")]
+    fn claim_event(&self, #[indexed] staker: &ManagedAddress, amount: &BigUint);
+}
+`;
+  },
+};
@@ -0,0 +1,237 @@
+import type { ContractTemplate } from '../types.js';
+import { paymentHelpers } from '../payments.js';
+
+/**
+ * Token vesting: cliff followed by linear per-epoch release
+ */
+export const vestingTemplate: ContractTemplate = {
+  name: 'vesting',
+  description:
+    'Vesting schedules with a cliff and linear per-epoch release, beneficiary management and a getClaimable view',
+  parameters: [
+    {
+      name: 'tokenId',
+      type: 'string',
+      description: 'Vested token: "KLV" or a KDA ID such as "MYKDA-1A2B"',
+      default: 'KLV',
+    },
+    {
+      name: 'maxSchedules',
+      type: 'number',
+      description: 'Maximum number of schedules a single beneficiary can hold',
+      default: 10,
+    },
+    {
+      name: 'revocable',
+      type: 'boolean',
+      description: 'Allow the owner to revoke unvested tokens from a beneficiary',
+      default: false,
+    },
+  ],
+  render: ({ traitName, params }) => {
+    const { payableAttr, helpers } = paymentHelpers(params.tokenId);
+    const maxSchedules = Math.max(Math.trunc(Number(params.maxSchedules)), 1);
+    const revocable = params.revocable === true;
+
+    const revokeEndpoint = revocable
+      ? `
+    /// Stop vesting for \`beneficiary\`: already vested tokens stay claimable,
+    /// the unvested remainder goes back to the owner.
+    #[only_owner]
+    #[endpoint]
+    fn revoke(&self, beneficiary: ManagedAddress) {
+        require!(self.beneficiaries().contains(&beneficiary), "Unknown beneficiary");
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut returned = BigUint::zero();
+
+        let mut schedules = self.schedules(&beneficiary);
+        for index in 1..=schedules.len() {
+            let mut schedule = schedules.get(index);
+            let vested = self.vested_amount(&schedule, current_epoch);
+            returned += &schedule.total - &vested;
+            schedule.total = vested;
+            schedule.duration_epochs = 0;
+            schedules.set(index, &schedule);
+        }
+
+        let owner = self.blockchain().get_caller();
+        self.send_payment(&owner, &returned);
+        self.revoke_event(&beneficiary, &returned);
+    }
+`
+      : '';
+
+    const revokeEvent = revocable
+      ? `
+
+    #[event("revoke")]
+    fn revoke_event(&self, #[indexed] beneficiary: &ManagedAddress, returned: &BigUint);`
+      : '';
+
+    return `#![no_std]
+
+use klever_sc::imports::*;
+use klever_sc::derive_imports::*;
+
+pub const MAX_SCHEDULES_PER_BENEFICIARY: usize = ${maxSchedules};
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct Schedule<M: ManagedTypeApi> {
+    pub total: BigUint<M>,
+    pub claimed: BigUint<M>,
+    pub start_epoch: u64,
+    /// Nothing is claimable before start_epoch + cliff_epochs
+    pub cliff_epochs: u64,
+    /// Linear release from start_epoch over this many epochs (0 = fully vested)
+    pub duration_epochs: u64,
+}
+
+#[klever_sc::contract]
+pub trait ${traitName} {
+    #[init]
+    fn init(&self) {}
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// Fund a new schedule for \`beneficiary\` with the attached payment.
+    #[only_owner]
+    ${payableAttr}
+    #[endpoint(addSchedule)]
+    fn add_schedule(
+        &self,
+        beneficiary: ManagedAddress,
+        start_epoch: u64,
+        cliff_epochs: u64,
+        duration_epochs: u64,
+    ) {
+        let total = self.receive_payment();
+        require!(!beneficiary.is_zero(), "Invalid beneficiary");
+        require!(cliff_epochs <= duration_epochs, "Cliff longer than duration");
+        require!(
+            self.schedules(&beneficiary).len() < MAX_SCHEDULES_PER_BENEFICIARY,
+            "Too many schedules for beneficiary"
+        );
+
+        self.schedules(&beneficiary).push(&Schedule {
+            total: total.clone(),
+            claimed: BigUint::zero(),
+            start_epoch,
+            cliff_epochs,
+            duration_epochs,
+        });
+        self.beneficiaries().insert(beneficiary.clone());
+
+        self.schedule_added_event(&beneficiary, &total);
+    }
+
+    #[endpoint]
+    fn claim(&self) {
+        let caller = self.blockchain().get_caller();
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut amount = BigUint::zero();
+
+        let mut schedules = self.schedules(&caller);
+        for index in 1..=schedules.len() {
+            let mut schedule = schedules.get(index);
+            let vested = self.vested_amount(&schedule, current_epoch);
+            if vested > schedule.claimed {
+                amount += &vested - &schedule.claimed;
+                schedule.claimed = vested;
+                schedules.set(index, &schedule);
+            }
+        }
+        require!(amount > 0, "Nothing to claim");
+
+        self.send_payment(&caller, &amount);
+        self.claim_event(&caller, &amount);
+    }
+
+    /// Beneficiary hands all of its schedules to a new address (e.g. key rotation).
+    #[endpoint(changeBeneficiary)]
+    fn change_beneficiary(&self, new_beneficiary: ManagedAddress) {
+        let caller = self.blockchain().get_caller();
+        require!(self.beneficiaries().contains(&caller), "Not a beneficiary");
+        require!(
+            !self.beneficiaries().contains(&new_beneficiary),
+            "New address already has schedules"
+        );
+
+        let mut old_schedules = self.schedules(&caller);
+        let mut new_schedules = self.schedules(&new_beneficiary);
+        for schedule in old_schedules.iter() {
+            new_schedules.push(&schedule);
+        }
+        old_schedules.clear();
+
+        self.beneficiaries().swap_remove(&caller);
+        self.beneficiaries().insert(new_beneficiary.clone());
+        self.beneficiary_changed_event(&caller, &new_beneficiary);
+    }
+${revokeEndpoint}
+    // Views
+
+    #[view(getClaimable)]
+    fn get_claimable(&self, beneficiary: ManagedAddress) -> BigUint {
+        let current_epoch = self.blockchain().get_block_epoch();
+        let mut claimable = BigUint::zero();
+        for schedule in self.schedules(&beneficiary).iter() {
+            let vested = self.vested_amount(&schedule, current_epoch);
+            if vested > schedule.claimed {
+                claimable += vested - schedule.claimed;
+            }
+        }
+        claimable
+    }
+
+    #[view(getSchedules)]
+    fn get_schedules(&self, beneficiary: ManagedAddress) -> MultiValueEncoded<Schedule<Self::Api>> {
+        self.schedules(&beneficiary).iter().collect()
+    }
+
+    #[view(getBeneficiaries)]
+    fn get_beneficiaries(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.beneficiaries().iter().collect()
+    }
+
+    // Vesting math
+
+    fn vested_amount(&self, schedule: &Schedule<Self::Api>, current_epoch: u64) -> BigUint {
+        if current_epoch < schedule.start_epoch + schedule.cliff_epochs {
+            return BigUint::zero();
+        }
+        let elapsed = current_epoch - schedule.start_epoch;
+        if elapsed >= schedule.duration_epochs {
+            return schedule.total.clone();
+        }
+        &schedule.total * elapsed / schedule.duration_epochs
+    }
+
+${helpers}
+
+    // Storage
+
+    #[storage_mapper("schedules")]
+    fn schedules(&self, beneficiary: &ManagedAddress) -> VecMapper<Schedule<Self::Api>>;
+
+    #[storage_mapper("beneficiaries")]
+    fn beneficiaries(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    // Events
+
+    #[event("scheduleAdded")]
+    fn schedule_added_event(&self, #[indexed] beneficiary: &ManagedAddress, total: &BigUint);
+
+    #[event("claim")]
+    fn claim_event(&self, #[indexed] beneficiary: &ManagedAddress, amount: &BigUint);
+
+    #[event("beneficiaryChanged")]
+    fn beneficiary_changed_event(
+        &self,
+        #[indexed] old_beneficiary: &ManagedAddress,
+        #[indexed] new_beneficiary: &ManagedAddress,
+    );${revokeEvent}
+}
+`;
+  },
+};
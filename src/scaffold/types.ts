@@ -0,0 +1,55 @@
+/**
+ * Types for contract project scaffolding
+ */
+
+export type TemplateParamValue = string | number | boolean;
+
+export interface TemplateParameter {
+  name: string;
+  type: 'string' | 'number' | 'boolean';
+  description: string;
+  default: TemplateParamValue;
+}
+
+/** Values available to a template while rendering */
+export interface TemplateContext {
+  /** Project name in kebab-case (Cargo package name) */
+  projectName: string;
+  /** Rust crate name in snake_case */
+  crateName: string;
+  /** Contract trait name in PascalCase */
+  traitName: string;
+  /** Template parameters with defaults applied */
+  params: Record<string, TemplateParamValue>;
+}
+
+export interface ContractTemplate {
+  name: string;
+  description: string;
+  parameters: TemplateParameter[];
+  /** Oldest klever-sc the template builds with; defaults to KLEVER_SC_VERSION */
+  minFrameworkVersion?: string;
+  /** Extra crates added to the contract Cargo.toml (e.g. klever-sc-modules) */
+  dependencies?: string[];
+  /** Renders src/lib.rs */
+  render: (ctx: TemplateContext) => string;
+  /** Additional contract sources keyed by path relative to the project root (e.g. src/proxy.rs) */
+  extraFiles?: (ctx: TemplateContext) => FileMap;
+}
+
+export interface FileMap {
+  [relativePath: string]: string;
+}
+
+export interface ScaffoldResult {
+  template: string;
+  projectName: string;
+  parameters: Record<string, TemplateParamValue>;
+  /** klever-sc version pinned in the generated manifests */
+  frameworkVersion: string;
+  /** Version mismatches between the template and the target project */
+  warnings?: string[];
+  files: FileMap;
+  endpoints: string[];
+  views: string[];
+}
@@ -0,0 +1,103 @@
+/**
+ * Deployment script of generated contract workspaces (see `workspace.ts`):
+ * deploys the contracts listed in deploy.json in order with koperator,
+ * passing the addresses of contracts deployed before, and makes the wiring
+ * calls that set the remaining addresses.
+ */
+
+/** scripts/deploy.sh */
+export const WORKSPACE_DEPLOY_SH = `#!/bin/bash
+# Deploy the workspace contracts in the order of deploy.json, then make its
+# wiring calls. An argument may name another contract as {name}, which stands
+# for that contract's address; any other {placeholder} must be filled in first.
+# Addresses are written back to deploy.json, so a rerun skips the contracts
+# deployed already.
+#
+# Build the contracts first (ksc all build in each contracts/ directory).
+# Environment: NETWORK (default: the network in deploy.json), KEY_FILE, KOPERATOR.
+
+set -eo pipefail
+
+cd "$(dirname "\${BASH_SOURCE[0]}")/.."
+CONFIG="deploy.json"
+KOPERATOR="\${KOPERATOR:-$HOME/klever-sdk/koperator}"
+KEY_FILE="\${KEY_FILE:-$HOME/klever-sdk/walletKey.pem}"
+NETWORK="\${NETWORK:-$(jq -r '.network' "$CONFIG")}"
+
+case "$NETWORK" in
+    mainnet) export KLEVER_NODE="https://node.mainnet.klever.org" ;;
+    testnet) export KLEVER_NODE="https://node.testnet.klever.org" ;;
+    devnet) export KLEVER_NODE="https://node.devnet.klever.org" ;;
+    *) export KLEVER_NODE="http://localhost:8080" ;;
+esac
+
+PLACEHOLDER='\\{([a-z0-9_-]+)\\}'
+
+# Print an argument with every {contract} replaced by the contract's address
+resolve() {
+    local arg="$1" name address
+    while [[ "$arg" =~ $PLACEHOLDER ]]; do
+        name="\${BASH_REMATCH[1]}"
+        address=$(jq -r --arg name "$name" '.contracts[] | select(.name == $name) | .address' "$CONFIG")
+        if [ -z "$address" ]; then
+            echo "{$name} in $CONFIG is not a deployed contract; deploy it first or fill in the value" >&2
+            return 1
+        fi
+        arg="\${arg//"{$name}"/$address}"
+    done
+    echo "$arg"
+}
+
+# Collect the --args flags of a config entry into ARGS
+read_args() {
+    local arg value
+    ARGS=()
+    while IFS= read -r arg; do
+        value=$(resolve "$arg")
+        ARGS+=(--args "$value")
+    done < <(jq -r "$1.args[]" "$CONFIG")
+}
+
+echo "Deploying to $NETWORK ($KLEVER_NODE)"
+
+count=$(jq '.contracts | length' "$CONFIG")
+for ((i = 0; i < count; i++)); do
+    name=$(jq -r ".contracts[$i].name" "$CONFIG")
+    if [ -n "$(jq -r ".contracts[$i].address" "$CONFIG")" ]; then
+        echo "$name is deployed already"
+        continue
+    fi
+    read_args ".contracts[$i]"
+
+    echo "Deploying $name..."
+    output=$("$KOPERATOR" --key-file="$KEY_FILE" sc create \\
+        --upgradeable --readable --payable --payableBySC \\
+        --wasm="$(jq -r ".contracts[$i].wasm" "$CONFIG")" \\
+        "\${ARGS[@]}" \\
+        --await --sign --result-only)
+    address=$(echo "$output" | jq -r '.logs.events[]? | select(.identifier == "SCDeploy") | .address // empty' | head -1)
+    if [ -z "$address" ]; then
+        echo "No contract address in the deployment of $name:" >&2
+        echo "$output" >&2
+        exit 1
+    fi
+    jq --argjson i "$i" --arg address "$address" '.contracts[$i].address = $address' "$CONFIG" > "$CONFIG.tmp"
+    mv "$CONFIG.tmp" "$CONFIG"
+    echo "$name: $address"
+done
+
+count=$(jq '.wiring | length' "$CONFIG")
+for ((i = 0; i < count; i++)); do
+    contract=$(jq -r ".wiring[$i].contract" "$CONFIG")
+    endpoint=$(jq -r ".wiring[$i].endpoint" "$CONFIG")
+    target=$(resolve "{$contract}")
+    read_args ".wiring[$i]"
+
+    echo "Calling $endpoint on $contract..."
+    "$KOPERATOR" --key-file="$KEY_FILE" sc invoke "$target" "$endpoint" \\
+        "\${ARGS[@]}" \\
+        --await --sign --result-only > /dev/null
+done
+
+echo "Deployed and wired every contract"
+`;
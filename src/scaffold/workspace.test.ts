@@ -0,0 +1,92 @@
+import { generateContractWorkspace } from './workspace.js';
+
+describe('generateContractWorkspace', () => {
+  const workspace = generateContractWorkspace('my-dao', [
+    { name: 'token', template: 'fungible_token' },
+    { name: 'staking', template: 'staking', dependsOn: ['token'] },
+    { name: 'governance', template: 'governance', dependsOn: ['token'] },
+  ]);
+
+  it('lays out contracts, shared proxies and a common test crate', () => {
+    expect(Object.keys(workspace.files)).toEqual(
+      expect.arrayContaining([
+        'Cargo.toml',
+        'contracts/token/src/lib.rs',
+        'contracts/staking/meta/src/main.rs',
+        'proxies/src/lib.rs',
+        'proxies/src/token_proxy.rs',
+        'tests/src/lib.rs',
+        'tests/tests/deploy_rs_test.rs',
+        'deploy.json',
+        'scripts/deploy.sh',
+      ])
+    );
+    expect(workspace.files['Cargo.toml']).toContain(
+      '    "contracts/governance",\n    "contracts/governance/meta",\n    "proxies",\n    "tests",\n]'
+    );
+    expect(workspace.files['contracts/token/Cargo.toml']).not.toContain('[workspace]');
+    expect(workspace.files['contracts/token/Cargo.toml']).not.toContain('my-dao-proxies');
+    expect(workspace.files['contracts/staking/Cargo.toml']).toContain(
+      '[dependencies.my-dao-proxies]\npath = "../../proxies"'
+    );
+    expect(workspace.files['proxies/src/lib.rs']).toContain(
+      'pub mod governance_proxy;\npub mod staking_proxy;\npub mod token_proxy;\n'
+    );
+    expect(workspace.files['proxies/src/token_proxy.rs']).toContain(
+      '    #[endpoint(transferFrom)]\n    fn transfer_from(&self, from: ManagedAddress, to: ManagedAddress, amount: BigUint);'
+    );
+  });
+
+  it('wires dependencies through a setter or the constructor', () => {
+    const staking = workspace.files['contracts/staking/src/lib.rs'];
+    expect(staking).toContain('use my_dao_proxies::token_proxy;');
+    expect(staking).toContain(
+      '    #[proxy]\n    fn token_contract(&self, sc_address: ManagedAddress) -> token_proxy::Proxy<Self::Api>;'
+    );
+    expect(staking).toContain('    #[only_owner]\n    #[endpoint(setTokenAddress)]');
+    expect(staking).toContain('    #[storage_mapper("tokenAddress")]');
+
+    // governance takes the token address in init and drops its own proxy for the shared one
+    const governance = workspace.files['contracts/governance/src/lib.rs'];
+    expect(governance).toContain('use my_dao_proxies::token_proxy;');
+    expect(governance).not.toContain('pub mod token_proxy;');
+    expect(governance).not.toContain('setTokenAddress');
+    expect(workspace.files['contracts/governance/src/token_proxy.rs']).toBeUndefined();
+    expect(workspace.contracts.find(c => c.name === 'governance')?.initDependencies).toEqual([
+      'token',
+    ]);
+  });
+
+  it('deploys in dependency order with addresses passed through deploy.json', () => {
+    expect(workspace.deployment.contracts.map(c => [c.name, c.args])).toEqual([
+      ['token', ['bi:{initial_supply}']],
+      ['staking', []],
+      ['governance', ['Address:{token}']],
+    ]);
+    expect(workspace.deployment.wiring).toEqual([
+      { contract: 'staking', endpoint: 'setTokenAddress', args: ['Address:{token}'] },
+    ]);
+    expect(workspace.unsetArguments).toEqual(['token.initial_supply']);
+    expect(JSON.parse(workspace.files['deploy.json'])).toEqual(workspace.deployment);
+
+    const tests = workspace.files['tests/src/lib.rs'];
+    expect(tests).toContain('    deploy(world, GOVERNANCE_CODE, &[TOKEN]);');
+    expect(tests).toContain('    call(world, STAKING, "setTokenAddress", &[TOKEN]);');
+    expect(tests).toContain('            .new_address(OWNER, 3, GOVERNANCE),');
+  });
+
+  it('rejects unknown dependencies and duplicate names', () => {
+    expect(() =>
+      generateContractWorkspace('my-dao', [
+        { name: 'staking', template: 'staking', dependsOn: ['token'] },
+      ])
+    ).toThrow('which is not another contract of the workspace');
+    expect(() =>
+      generateContractWorkspace('my-dao', [
+        { name: 'token', template: 'fungible_token' },
+        { name: 'token', template: 'basic_token' },
+      ])
+    ).toThrow('Contract names must be unique: token.');
+    expect(() => generateContractWorkspace('My DAO', [])).toThrow('Invalid workspace name');
+  });
+});
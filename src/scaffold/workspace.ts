@@ -0,0 +1,509 @@
+/**
+ * Klever contract workspace generator
+ *
+ * Produces a cargo workspace of several template contracts that call each
+ * other: each under contracts/<name>, a shared proxies crate generated from
+ * their sources, a common test crate that deploys and wires them on the Rust
+ * VM, and a deployment config (deploy.json) with scripts/deploy.sh that passes
+ * the deployed addresses between contracts. Nothing is written to disk here.
+ */
+
+import { rustTypeToAbi } from '../codegen/abi.js';
+import { KOPERATOR_ARG_PREFIXES } from '../codegen/koperator.js';
+import { generateSourceProxy } from '../codegen/proxy.js';
+import {
+  appendToTrait,
+  findContractTrait,
+  findFunctionWithAttribute,
+  listTraitFunctions,
+  parseParams,
+  snakeToCamel,
+} from '../codegen/rust-source.js';
+import type { FileMap } from './types.js';
+import { compareVersions } from './framework-version.js';
+import {
+  PROJECT_NAME_PATTERN,
+  extractEndpoints,
+  projectFiles,
+  renderContractTemplate,
+  toPascalCase,
+  toSnakeCase,
+  type GenerateProjectOptions,
+  type RenderedContract,
+} from './project.js';
+import { WORKSPACE_DEPLOY_SH } from './workspace-deploy.js';
+
+export interface WorkspaceContract {
+  /** Crate name in kebab-case, unique in the workspace; names the contract in deploy.json */
+  name: string;
+  template: string;
+  parameters?: Record<string, unknown>;
+  /** Contracts of the workspace this one calls */
+  dependsOn?: string[];
+}
+
+export interface GenerateWorkspaceOptions extends GenerateProjectOptions {
+  /** Network written to deploy.json (default: testnet) */
+  network?: string;
+}
+
+export interface DeploymentConfig {
+  network: string;
+  /** Contracts in deployment order; `{name}` in an argument is the address of contract `name` */
+  contracts: { name: string; wasm: string; args: string[]; address: string }[];
+  /** Calls made once every contract is deployed, setting addresses init could not take */
+  wiring: { contract: string; endpoint: string; args: string[] }[];
+}
+
+export interface WorkspaceContractSummary {
+  name: string;
+  template: string;
+  directory: string;
+  endpoints: string[];
+  views: string[];
+  dependsOn: string[];
+  /** Dependencies whose address the constructor takes */
+  initDependencies: string[];
+}
+
+export interface WorkspaceResult {
+  workspaceName: string;
+  /** klever-sc version pinned in every manifest */
+  frameworkVersion: string;
+  warnings?: string[];
+  files: FileMap;
+  contracts: WorkspaceContractSummary[];
+  deployment: DeploymentConfig;
+  /** Constructor arguments left as placeholders in deploy.json, as `contract.argument` */
+  unsetArguments: string[];
+}
+
+interface WiredContract {
+  spec: WorkspaceContract;
+  rendered: RenderedContract;
+  crateName: string;
+  libRs: string;
+  extraFiles: FileMap;
+  initParams: { name: string; type: string; dependency?: string }[];
+  setters: { dependency: string; endpoint: string }[];
+}
+
+/** Sample scenario argument of each ABI type for the test deployment */
+const TEST_ARGUMENTS: Record<string, string> = {
+  Address: 'OWNER',
+  bool: '"false"',
+  bytes: '"str:test"',
+  utf8string: '"str:test"',
+  TokenIdentifier: '"str:KLV"',
+};
+
+const addressMapper = (dependency: string) => `${toSnakeCase(dependency)}_address`;
+
+/** Add `use` declarations after the last `use` line above the contract trait */
+function addUseDeclarations(source: string, paths: string[]): string {
+  const { itemStart } = findContractTrait(source);
+  const uses = [...source.slice(0, itemStart).matchAll(/^use\s[^;]+;[ \t]*\n/gm)];
+  const decls = paths.map(path => `use ${path};\n`).join('');
+  if (uses.length === 0) return `${decls}\n${source}`;
+  const last = uses[uses.length - 1];
+  const index = last.index! + last[0].length;
+  return source.slice(0, index) + decls + source.slice(index);
+}
+
+/** Proxy accessor, address mapper and (unless init takes it) setter for each dependency */
+function wireContract(
+  contract: WiredContract,
+  proxiesCrate: string,
+  proxies: Map<string, string[]>,
+  warnings: string[]
+): void {
+  const declared = new Set(listTraitFunctions(contract.libRs).map(fn => fn.name));
+  const items: string[] = [];
+  const uses: string[] = [];
+  for (const dependency of contract.spec.dependsOn || []) {
+    const ident = toSnakeCase(dependency);
+    const module = `${ident}_proxy`;
+    const local = contract.extraFiles[`src/${module}.rs`];
+    if (local !== undefined) {
+      // The template ships its own proxy; the shared one replaces it when it covers the same calls
+      const shared = proxies.get(dependency)!;
+      const missing = [...local.matchAll(/\bfn\s+(\w+)/g)]
+        .map(m => m[1])
+        .filter(fn => !shared.includes(fn));
+      if (missing.length > 0) {
+        warnings.push(
+          `${contract.spec.name} keeps its own ${module}: ${dependency} has no ${missing.join(', ')}`
+        );
+        continue;
+      }
+      delete contract.extraFiles[`src/${module}.rs`];
+      contract.libRs = contract.libRs
+        .replace(new RegExp(`^pub mod ${module};[ \\t]*\\n`, 'm'), '')
+        .replace(/\n{3,}/g, '\n\n');
+    }
+    uses.push(`${proxiesCrate}::${module}`);
+
+    if (!declared.has(`${ident}_contract`)) {
+      items.push(
+        `    #[proxy]\n    fn ${ident}_contract(&self, sc_address: ManagedAddress) -> ${module}::Proxy<Self::Api>;`
+      );
+    }
+    const param = contract.initParams.find(p => p.name === addressMapper(dependency));
+    if (param) {
+      param.dependency = dependency;
+    } else {
+      const endpoint = `set${toPascalCase(dependency)}Address`;
+      const label = dependency[0].toUpperCase() + dependency.slice(1).replace(/-/g, ' ');
+      items.push(
+        [
+          '    #[only_owner]',
+          `    #[endpoint(${endpoint})]`,
+          `    fn set_${ident}_address(&self, address: ManagedAddress) {`,
+          '        require!(',
+          '            self.blockchain().is_smart_contract(&address),',
+          `            "${label} address must be a smart contract"`,
+          '        );',
+          `        self.${ident}_address().set(address);`,
+          '    }',
+        ].join('\n')
+      );
+      contract.setters.push({ dependency, endpoint });
+    }
+    if (!declared.has(addressMapper(dependency))) {
+      items.push(
+        `    #[storage_mapper("${snakeToCamel(addressMapper(dependency))}")]\n    fn ${addressMapper(dependency)}(&self) -> SingleValueMapper<ManagedAddress>;`
+      );
+    }
+  }
+  if (uses.length > 0) contract.libRs = addUseDeclarations(contract.libRs, uses);
+  if (items.length > 0) {
+    contract.libRs = appendToTrait(
+      contract.libRs,
+      ['    // Workspace contracts', ...items].join('\n\n')
+    );
+  }
+}
+
+/** Contracts in deployment order: each after the contracts its constructor takes */
+function deploymentOrder(contracts: WiredContract[]): WiredContract[] {
+  const byName = new Map(contracts.map(c => [c.spec.name, c]));
+  const ordered: WiredContract[] = [];
+  const visiting = new Set<string>();
+  const visit = (contract: WiredContract) => {
+    if (ordered.includes(contract)) return;
+    if (visiting.has(contract.spec.name)) {
+      throw new Error(
+        `The constructors of ${[...visiting].join(', ')} take each other's address; ` +
+          'drop one of the dependencies so its address is set after deployment.'
+      );
+    }
+    visiting.add(contract.spec.name);
+    for (const param of contract.initParams) {
+      if (param.dependency) visit(byName.get(param.dependency)!);
+    }
+    visiting.delete(contract.spec.name);
+    ordered.push(contract);
+  };
+  contracts.forEach(visit);
+  return ordered;
+}
+
+function rootCargoToml(contracts: WiredContract[]): string {
+  const members = [
+    ...contracts.flatMap(c => [`contracts/${c.spec.name}`, `contracts/${c.spec.name}/meta`]),
+    'proxies',
+    'tests',
+  ];
+  return `[workspace]
+resolver = "2"
+members = [
+${members.map(m => `    "${m}",`).join('\n')}
+]
+`;
+}
+
+function proxiesCargoToml(crate: string, version: string): string {
+  return `[package]
+name = "${crate}"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[lib]
+path = "src/lib.rs"
+
+[dependencies.klever-sc]
+version = "${version}"
+`;
+}
+
+function testsCargoToml(crate: string, contracts: WiredContract[], version: string): string {
+  const paths = contracts
+    .map(c => `\n[dependencies.${c.spec.name}]\npath = "../contracts/${c.spec.name}"\n`)
+    .join('');
+  return `[package]
+name = "${crate}"
+version = "0.0.0"
+edition = "2021"
+publish = false
+
+[lib]
+path = "src/lib.rs"
+
+[dependencies.klever-sc-scenario]
+version = "${version}"
+${paths}`;
+}
+
+function testsLibRs(workspaceName: string, ordered: WiredContract[]): string {
+  const constant = (c: WiredContract) => c.crateName.toUpperCase();
+  const contracts = ordered.flatMap(c => [
+    `pub const ${constant(c)}: &str = "sc:${c.crateName}";`,
+    `pub const ${constant(c)}_CODE: &str = "file:../contracts/${c.spec.name}/output/${c.spec.name}.wasm";`,
+  ]);
+  const byName = new Map(ordered.map(c => [c.spec.name, c]));
+  const argument = (param: WiredContract['initParams'][number]) => {
+    if (param.dependency) return constant(byName.get(param.dependency)!);
+    const abiType = rustTypeToAbi(param.type);
+    return TEST_ARGUMENTS[abiType] || '"0"';
+  };
+  const deploys = ordered.map(
+    c => `    deploy(world, ${constant(c)}_CODE, &[${c.initParams.map(argument).join(', ')}]);`
+  );
+  const wiring = ordered.flatMap(c =>
+    c.setters.map(
+      s =>
+        `    call(world, ${constant(c)}, "${s.endpoint}", &[${constant(byName.get(s.dependency)!)}]);`
+    )
+  );
+  return `//! Test harness shared by the ${workspaceName} contracts: a world with every
+//! contract registered on the Rust VM, deployed and wired the way deploy.json
+//! deploys them on chain.
+//!
+//! Build the contracts first (\`ksc all build\` in each contracts/ directory).
+
+use klever_sc_scenario::{scenario_model::*, *};
+
+/// Account that deploys and owns every contract
+pub const OWNER: &str = "address:owner";
+
+${contracts.join('\n')}
+
+/// A world with every contract registered
+pub fn world() -> ScenarioWorld {
+    let mut world = ScenarioWorld::new();
+${ordered.map(c => `    world.register_contract(${constant(c)}_CODE, ${c.crateName}::ContractBuilder);`).join('\n')}
+    world
+}
+
+/// Deploy every contract from the owner in deployment order, then make the wiring calls.
+/// Constructor arguments other than contract addresses are sample values.
+pub fn deploy_all(world: &mut ScenarioWorld) {
+    let owner = Account::new().nonce(1).balance("1000000000000000000");
+    world.set_state_step(
+        SetStateStep::new()
+            .put_account(OWNER, owner)
+${ordered.map((c, i) => `            .new_address(OWNER, ${i + 1}, ${constant(c)})`).join('\n')},
+    );
+${[...deploys, ...wiring].join('\n')}
+}
+
+/// Deploy a contract from the owner; panics when the deployment fails
+pub fn deploy(world: &mut ScenarioWorld, code: &str, args: &[&str]) {
+    let mut step = ScDeployStep::new().from(OWNER).code(code);
+    for arg in args {
+        step = step.argument(arg);
+    }
+    world.sc_deploy(&mut step);
+    let error = &step.response().tx_error;
+    assert_eq!(
+        error.status, 0,
+        "deploying {code} failed: {}",
+        error.message
+    );
+}
+
+/// Call an endpoint from the owner; panics when the call fails
+pub fn call(world: &mut ScenarioWorld, to: &str, endpoint: &str, args: &[&str]) {
+    let mut step = ScCallStep::new().from(OWNER).to(to).function(endpoint);
+    for arg in args {
+        step = step.argument(arg);
+    }
+    world.sc_call(&mut step);
+    let error = &step.response().tx_error;
+    assert_eq!(
+        error.status, 0,
+        "{endpoint} on {to} failed: {}",
+        error.message
+    );
+}
+`;
+}
+
+function deployTestRs(testsCrate: string): string {
+  return `use ${testsCrate}::*;
+
+#[test]
+fn deploys_and_wires_every_contract() {
+    let mut world = world();
+    deploy_all(&mut world);
+}
+`;
+}
+
+/**
+ * Generate a cargo workspace of template contracts wired to each other
+ */
+export function generateContractWorkspace(
+  workspaceName: string,
+  contracts: WorkspaceContract[],
+  options: GenerateWorkspaceOptions = {}
+): WorkspaceResult {
+  if (!PROJECT_NAME_PATTERN.test(workspaceName)) {
+    throw new Error(
+      `Invalid workspace name "${workspaceName}". Use kebab-case starting with a letter (e.g. "my-dao").`
+    );
+  }
+  if (contracts.length === 0) {
+    throw new Error('A workspace needs at least one contract.');
+  }
+  const names = contracts.map(c => c.name);
+  const duplicates = names.filter((name, i) => names.indexOf(name) !== i);
+  if (duplicates.length > 0) {
+    throw new Error(`Contract names must be unique: ${[...new Set(duplicates)].join(', ')}.`);
+  }
+  for (const contract of contracts) {
+    const unknown = (contract.dependsOn || []).filter(
+      dep => dep === contract.name || !names.includes(dep)
+    );
+    if (unknown.length > 0) {
+      throw new Error(
+        `${contract.name} depends on ${unknown.join(', ')}, which is not another contract of the workspace.`
+      );
+    }
+  }
+
+  const wired: WiredContract[] = contracts.map(spec => {
+    const rendered = renderContractTemplate(spec.template, spec.name, spec.parameters, options);
+    const init = findFunctionWithAttribute(rendered.libRs, 'init');
+    return {
+      spec,
+      rendered,
+      crateName: toSnakeCase(spec.name),
+      libRs: rendered.libRs,
+      extraFiles: { ...rendered.extraFiles },
+      initParams: init
+        ? parseParams(init.signature).map(p => ({ name: p.name, type: p.type }))
+        : [],
+      setters: [],
+    };
+  });
+
+  // One klever-sc version for the whole workspace: the newest any template needs
+  const frameworkVersion = wired
+    .map(c => c.rendered.pin.version)
+    .reduce((a, b) => (compareVersions(a, b) >= 0 ? a : b));
+  const warnings = [
+    ...new Set(wired.flatMap(c => (c.rendered.pin.warning ? [c.rendered.pin.warning] : []))),
+  ];
+
+  const proxiesName = `${workspaceName}-proxies`;
+  const proxiesCrate = toSnakeCase(proxiesName);
+  // Functions the shared proxy of each contract declares, before wiring adds setters
+  const proxyFunctions = new Map(
+    wired.map(c => [
+      c.spec.name,
+      extractEndpoints(c.libRs)
+        .filter(e => e.kind !== 'upgrade')
+        .map(e => e.functionName),
+    ])
+  );
+  wired.forEach(c => wireContract(c, proxiesCrate, proxyFunctions, warnings));
+
+  const files: FileMap = {
+    'Cargo.toml': rootCargoToml(wired),
+    '.gitignore': '/target/\n',
+  };
+  for (const c of wired) {
+    const projectFileMap = projectFiles(
+      c.spec.name,
+      c.libRs,
+      frameworkVersion,
+      c.rendered.template.dependencies,
+      c.extraFiles,
+      {
+        workspaceMember: true,
+        pathDependencies: c.spec.dependsOn?.length ? { [proxiesName]: '../../proxies' } : {},
+      }
+    );
+    for (const [path, content] of Object.entries(projectFileMap)) {
+      files[`contracts/${c.spec.name}/${path}`] = content;
+    }
+  }
+
+  const proxies = wired.map(c => generateSourceProxy(c.libRs, { module: `${c.crateName}_proxy` }));
+  files['proxies/Cargo.toml'] = proxiesCargoToml(proxiesName, frameworkVersion);
+  files['proxies/src/lib.rs'] = `//! Proxies of the ${workspaceName} contracts, shared by the contracts that call each other
+
+#![no_std]
+
+${proxies
+  .map(p => p.modDeclaration)
+  .sort()
+  .join('\n')}
+`;
+  for (const proxy of proxies) files[`proxies/${proxy.path}`] = proxy.content;
+
+  const ordered = deploymentOrder(wired);
+  const testsName = `${workspaceName}-tests`;
+  files['tests/Cargo.toml'] = testsCargoToml(testsName, wired, frameworkVersion);
+  files['tests/src/lib.rs'] = testsLibRs(workspaceName, ordered);
+  files['tests/tests/deploy_rs_test.rs'] = deployTestRs(toSnakeCase(testsName));
+
+  const unsetArguments: string[] = [];
+  const deployArgument = (c: WiredContract, param: WiredContract['initParams'][number]) => {
+    if (param.dependency) return `Address:{${param.dependency}}`;
+    unsetArguments.push(`${c.spec.name}.${param.name}`);
+    const prefix = KOPERATOR_ARG_PREFIXES[rustTypeToAbi(param.type)];
+    return prefix ? `${prefix}:{${param.name}}` : `{${param.name}}`;
+  };
+  const deployment: DeploymentConfig = {
+    network: options.network || 'testnet',
+    contracts: ordered.map(c => ({
+      name: c.spec.name,
+      wasm: `contracts/${c.spec.name}/output/${c.spec.name}.wasm`,
+      args: c.initParams.map(param => deployArgument(c, param)),
+      address: '',
+    })),
+    wiring: ordered.flatMap(c =>
+      c.setters.map(s => ({
+        contract: c.spec.name,
+        endpoint: s.endpoint,
+        args: [`Address:{${s.dependency}}`],
+      }))
+    ),
+  };
+  files['deploy.json'] = `${JSON.stringify(deployment, null, 2)}\n`;
+  files['scripts/deploy.sh'] = WORKSPACE_DEPLOY_SH;
+
+  return {
+    workspaceName,
+    frameworkVersion,
+    ...(warnings.length > 0 ? { warnings } : {}),
+    files,
+    contracts: wired.map(c => {
+      const endpoints = extractEndpoints(c.libRs);
+      return {
+        name: c.spec.name,
+        template: c.rendered.template.name,
+        directory: `contracts/${c.spec.name}`,
+        endpoints: endpoints.filter(e => e.kind === 'endpoint').map(e => e.externalName),
+        views: endpoints.filter(e => e.kind === 'view').map(e => e.externalName),
+        dependsOn: c.spec.dependsOn || [],
+        initDependencies: c.initParams.flatMap(p => (p.dependency ? [p.dependency] : [])),
+      };
+    }),
+    deployment,
+    unsetArguments,
+  };
+}
@@ -0,0 +1,59 @@
+import { encodeAddress } from '../chain/bech32.js';
+import { matchesFilter, parseEventMessage, topicBytes } from './events.js';
+
+const ALICE_BYTES = Buffer.alloc(32, 3);
+const ALICE = encodeAddress(ALICE_BYTES);
+const b64 = (value: Buffer | string) => Buffer.from(value).toString('base64');
+
+describe('parseEventMessage', () => {
+  it('reads notifier events and puts the identifier first', () => {
+    const message = JSON.stringify({
+      type: 'all_events',
+      data: [
+        {
+          address: 'klv1token',
+          identifier: 'transfer',
+          topics: [b64(ALICE_BYTES)],
+          data: b64('\x0a'),
+          txHash: 'abc',
+        },
+      ],
+    });
+    const [event] = parseEventMessage(message);
+    expect(event.identifier).toBe('transfer');
+    expect(event.topics).toEqual([Buffer.from('transfer'), ALICE_BYTES]);
+    expect(event.data).toEqual([Buffer.from([10])]);
+    expect(event.txHash).toBe('abc');
+  });
+
+  it('keeps topics that already start with the identifier', () => {
+    const [event] = parseEventMessage({
+      events: [{ address: 'klv1token', identifier: 'mint', topics: [b64('mint')] }],
+    });
+    expect(event.topics).toEqual([Buffer.from('mint')]);
+    expect(event.data).toEqual([]);
+  });
+
+  it('ignores messages that are not JSON', () => {
+    expect(parseEventMessage('pong')).toEqual([]);
+  });
+});
+
+describe('matchesFilter', () => {
+  const event = {
+    address: 'klv1token',
+    identifier: 'transfer',
+    topics: [Buffer.from('transfer'), Buffer.alloc(32, 1), ALICE_BYTES],
+    data: [],
+  };
+
+  it('matches contract, identifier and indexed topics in any position', () => {
+    expect(matchesFilter(event, { address: 'klv1token' })).toBe(true);
+    expect(matchesFilter(event, { address: 'klv1token', topics: [topicBytes(ALICE)] })).toBe(true);
+    expect(matchesFilter(event, { address: 'klv1other' })).toBe(false);
+    expect(matchesFilter(event, { address: 'klv1token', identifier: 'mint' })).toBe(false);
+    expect(matchesFilter(event, { address: 'klv1token', topics: [topicBytes('0x01')] })).toBe(
+      false
+    );
+  });
+});
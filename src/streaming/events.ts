@@ -0,0 +1,87 @@
+/**
+ * Contract events pushed by a node or indexer websocket, and the filters that
+ * select them. Messages are JSON; events are found wherever an object carries
+ * a `topics` list (base64), as in the events notifier protocol
+ * (`{ "type": "all_events", "data": [{ address, identifier, topics, data }] }`).
+ */
+
+import { decodeAddress } from '../chain/index.js';
+
+export interface StreamedEvent {
+  address?: string;
+  identifier: string;
+  /** The event identifier first, then the indexed inputs */
+  topics: Buffer[];
+  data: Buffer[];
+  txHash?: string;
+}
+
+export interface EventFilter {
+  /** Emitting contract */
+  address: string;
+  /** Event identifier; any event when omitted */
+  identifier?: string;
+  /** Values that must all appear among the indexed topics, in any position */
+  topics?: Buffer[];
+}
+
+/** Events in a websocket message; anything that is not JSON carries none */
+export function parseEventMessage(message: unknown): StreamedEvent[] {
+  let payload = message;
+  if (typeof message === 'string' || Buffer.isBuffer(message)) {
+    try {
+      payload = JSON.parse(message.toString());
+    } catch {
+      return [];
+    }
+  }
+  const events: StreamedEvent[] = [];
+  const bytes = (value: unknown) => Buffer.from(String(value ?? ''), 'base64');
+  const visit = (value: unknown, txHash?: string) => {
+    if (Array.isArray(value)) {
+      value.forEach(item => visit(item, txHash));
+      return;
+    }
+    if (!value || typeof value !== 'object') return;
+    const entry = value as Record<string, unknown>;
+    const hash = typeof entry.txHash === 'string' ? entry.txHash : txHash;
+    if (!Array.isArray(entry.topics)) {
+      Object.values(entry).forEach(item => visit(item, hash));
+      return;
+    }
+    let topics = entry.topics.map(bytes);
+    let identifier = topics[0]?.toString('utf8') || '';
+    // Notifiers send the identifier separately and only the indexed inputs as topics
+    if (typeof entry.identifier === 'string' && entry.identifier !== identifier) {
+      identifier = entry.identifier;
+      topics = [Buffer.from(identifier, 'utf8'), ...topics];
+    }
+    const data = entry.data;
+    events.push({
+      address: typeof entry.address === 'string' ? entry.address : undefined,
+      identifier,
+      topics,
+      data: Array.isArray(data) ? data.map(bytes) : data ? [bytes(data)] : [],
+      txHash: hash,
+    });
+  };
+  visit(payload);
+  return events;
+}
+
+/**
+ * A topic filter value as the bytes it matches: klv1... addresses as their 32
+ * bytes, `0x` hex as bytes, anything else as UTF-8 text
+ */
+export function topicBytes(value: string): Buffer {
+  if (value.startsWith('klv1')) return decodeAddress(value);
+  if (/^0x([0-9a-fA-F]{2})*$/.test(value)) return Buffer.from(value.slice(2), 'hex');
+  return Buffer.from(value, 'utf8');
+}
+
+export function matchesFilter(event: StreamedEvent, filter: EventFilter): boolean {
+  if (event.address !== filter.address) return false;
+  if (filter.identifier && event.identifier !== filter.identifier) return false;
+  const indexed = event.topics.slice(1);
+  return (filter.topics || []).every(topic => indexed.some(t => t.equals(topic)));
+}
@@ -0,0 +1,7 @@
+/**
+ * Live contract events: websocket subscriptions with event filters, forwarded
+ * to the MCP client as they arrive.
+ */
+
+export * from './events.js';
+export * from './subscriptions.js';
@@ -0,0 +1,104 @@
+import { jest } from '@jest/globals';
+import { EventSubscriptions, type EventSocket } from './subscriptions.js';
+import type { StreamedEvent } from './events.js';
+
+class FakeSocket implements EventSocket {
+  sent: string[] = [];
+  closed = false;
+  onopen: (() => void) | null = null;
+  onmessage: ((event: { data: unknown }) => void) | null = null;
+  onclose: (() => void) | null = null;
+  onerror: ((event: unknown) => void) | null = null;
+
+  constructor(public url: string) {}
+
+  send(data: string) {
+    this.sent.push(data);
+  }
+
+  close() {
+    this.closed = true;
+  }
+
+  receive(message: unknown) {
+    this.onmessage?.({ data: JSON.stringify(message) });
+  }
+}
+
+const transfer = (to: string) => ({
+  address: 'klv1token',
+  identifier: 'transfer',
+  topics: [Buffer.from(to).toString('base64')],
+});
+
+describe('EventSubscriptions', () => {
+  let sockets: FakeSocket[];
+  let subscriptions: EventSubscriptions;
+
+  beforeEach(() => {
+    jest.useFakeTimers();
+    sockets = [];
+    subscriptions = new EventSubscriptions({
+      socketFactory: url => {
+        const socket = new FakeSocket(url);
+        sockets.push(socket);
+        return socket;
+      },
+      reconnectDelayMs: 1000,
+      maxReconnects: 1,
+    });
+  });
+
+  afterEach(() => {
+    subscriptions.unsubscribeAll();
+    jest.useRealTimers();
+  });
+
+  it('subscribes on open and forwards matching events', () => {
+    const received: StreamedEvent[] = [];
+    const info = subscriptions.subscribe({
+      url: 'ws://localhost:5000/events',
+      filter: { address: 'klv1token', identifier: 'transfer', topics: [Buffer.from('bob')] },
+      onEvent: event => received.push(event),
+    });
+    const [socket] = sockets;
+    socket.onopen?.();
+    expect(JSON.parse(socket.sent[0])).toEqual({
+      subscriptionEntries: [{ address: 'klv1token', identifier: 'transfer' }],
+    });
+
+    socket.receive({ data: [transfer('alice'), transfer('bob')] });
+    expect(received.map(event => event.topics[1].toString())).toEqual(['bob']);
+    expect(subscriptions.list()[0]).toMatchObject({ id: info.id, status: 'open', received: 1 });
+    expect(subscriptions.takeEvents(info.id)).toHaveLength(1);
+    expect(subscriptions.takeEvents(info.id)).toHaveLength(0);
+  });
+
+  it('reconnects a dropped connection, then gives up', () => {
+    const info = subscriptions.subscribe({
+      url: 'ws://localhost:5000/events',
+      filter: { address: 'klv1token' },
+      onEvent: () => undefined,
+    });
+    sockets[0].onclose?.();
+    expect(subscriptions.list()[0].status).toBe('reconnecting');
+    jest.advanceTimersByTime(1000);
+    expect(sockets).toHaveLength(2);
+    sockets[1].onclose?.();
+    expect(subscriptions.list()[0].status).toBe('closed');
+
+    expect(subscriptions.unsubscribe(info.id)).toBe(true);
+    expect(sockets[1].closed).toBe(true);
+    expect(subscriptions.list()).toEqual([]);
+  });
+
+  it('only accepts websocket URLs', () => {
+    expect(() =>
+      subscriptions.subscribe({
+        url: 'https://node.example',
+        filter: { address: 'klv1token' },
+        onEvent: () => undefined,
+      })
+    ).toThrow('ws:// or wss://');
+  });
+});
@@ -0,0 +1,187 @@
+/**
+ * Long-lived websocket subscriptions to contract events. Each subscription
+ * keeps its own connection, asks the server for the contract's events on
+ * connect, forwards matching events to its listener and keeps the latest ones
+ * for clients that poll instead of receiving notifications.
+ */
+
+import {
+  matchesFilter,
+  parseEventMessage,
+  type EventFilter,
+  type StreamedEvent,
+} from './events.js';
+
+/** The part of the WHATWG WebSocket API subscriptions use */
+export interface EventSocket {
+  send(data: string): void;
+  close(): void;
+  onopen: (() => void) | null;
+  onmessage: ((event: { data: unknown }) => void) | null;
+  onclose: (() => void) | null;
+  onerror: ((event: unknown) => void) | null;
+}
+
+export type SocketFactory = (url: string) => EventSocket;
+
+export interface SubscriptionOptions {
+  url: string;
+  filter: EventFilter;
+  onEvent: (event: StreamedEvent, subscription: SubscriptionInfo) => void;
+}
+
+export interface SubscriptionInfo {
+  id: string;
+  url: string;
+  address: string;
+  identifier?: string;
+  /** Topic filter values, hex */
+  topics: string[];
+  status: 'connecting' | 'open' | 'reconnecting' | 'closed';
+  received: number;
+  lastError?: string;
+}
+
+export interface EventSubscriptionsOptions {
+  /** Defaults to the global WebSocket (Node.js 22+) */
+  socketFactory?: SocketFactory;
+  /** Events kept per subscription for polling (default: 100) */
+  bufferSize?: number;
+  /** Delay before reconnecting a dropped connection (default: 5 seconds) */
+  reconnectDelayMs?: number;
+  /** Reconnect attempts in a row before giving up (default: 5) */
+  maxReconnects?: number;
+}
+
+interface Subscription {
+  info: SubscriptionInfo;
+  options: SubscriptionOptions;
+  socket?: EventSocket;
+  buffer: StreamedEvent[];
+  reconnects: number;
+  timer?: ReturnType<typeof setTimeout>;
+}
+
+type WebSocketConstructor = new (url: string) => EventSocket;
+
+function defaultSocketFactory(url: string): EventSocket {
+  const WebSocketClass = (globalThis as { WebSocket?: WebSocketConstructor }).WebSocket;
+  if (!WebSocketClass) {
+    throw new Error('Event subscriptions need a global WebSocket (Node.js 22 or later).');
+  }
+  return new WebSocketClass(url);
+}
+
+export class EventSubscriptions {
+  private subscriptions = new Map<string, Subscription>();
+  private nextId = 1;
+  private socketFactory: SocketFactory;
+  private bufferSize: number;
+  private reconnectDelayMs: number;
+  private maxReconnects: number;
+
+  constructor(options: EventSubscriptionsOptions = {}) {
+    this.socketFactory = options.socketFactory ?? defaultSocketFactory;
+    this.bufferSize = options.bufferSize ?? 100;
+    this.reconnectDelayMs = options.reconnectDelayMs ?? 5000;
+    this.maxReconnects = options.maxReconnects ?? 5;
+  }
+
+  subscribe(options: SubscriptionOptions): SubscriptionInfo {
+    const url = new URL(options.url);
+    if (url.protocol !== 'ws:' && url.protocol !== 'wss:') {
+      throw new Error(`Event subscriptions need a ws:// or wss:// URL, got ${options.url}`);
+    }
+    const id = `sub-${this.nextId++}`;
+    const subscription: Subscription = {
+      info: {
+        id,
+        url: options.url,
+        address: options.filter.address,
+        identifier: options.filter.identifier,
+        topics: (options.filter.topics || []).map(topic => topic.toString('hex')),
+        status: 'connecting',
+        received: 0,
+      },
+      options,
+      buffer: [],
+      reconnects: 0,
+    };
+    this.subscriptions.set(id, subscription);
+    try {
+      this.connect(subscription);
+    } catch (error) {
+      this.subscriptions.delete(id);
+      throw error;
+    }
+    return { ...subscription.info };
+  }
+
+  /** Close a subscription; false when there is none with this id */
+  unsubscribe(id: string): boolean {
+    const subscription = this.subscriptions.get(id);
+    if (!subscription) return false;
+    this.subscriptions.delete(id);
+    subscription.info.status = 'closed';
+    clearTimeout(subscription.timer);
+    subscription.socket?.close();
+    return true;
+  }
+
+  unsubscribeAll(): void {
+    [...this.subscriptions.keys()].forEach(id => this.unsubscribe(id));
+  }
+
+  list(): SubscriptionInfo[] {
+    return [...this.subscriptions.values()].map(s => ({ ...s.info }));
+  }
+
+  /** Events received since the last call (up to the buffer size), oldest first */
+  takeEvents(id: string): StreamedEvent[] {
+    const subscription = this.subscriptions.get(id);
+    if (!subscription) throw new Error(`No event subscription "${id}".`);
+    return subscription.buffer.splice(0);
+  }
+
+  private connect(subscription: Subscription): void {
+    const { info, options } = subscription;
+    const socket = this.socketFactory(info.url);
+    subscription.socket = socket;
+    socket.onopen = () => {
+      info.status = 'open';
+      subscription.reconnects = 0;
+      const { address, identifier } = options.filter;
+      socket.send(JSON.stringify({ subscriptionEntries: [{ address, identifier }] }));
+    };
+    socket.onmessage = message => {
+      for (const event of parseEventMessage(message.data)) {
+        if (!matchesFilter(event, options.filter)) continue;
+        info.received++;
+        subscription.buffer.push(event);
+        if (subscription.buffer.length > this.bufferSize) subscription.buffer.shift();
+        options.onEvent(event, { ...info });
+      }
+    };
+    socket.onerror = error => {
+      info.lastError = error instanceof Error ? error.message : 'WebSocket error';
+    };
+    socket.onclose = () => {
+      if (!this.subscriptions.has(info.id) || subscription.socket !== socket) return;
+      if (subscription.reconnects >= this.maxReconnects) {
+        info.status = 'closed';
+        return;
+      }
+      info.status = 'reconnecting';
+      subscription.reconnects++;
+      subscription.timer = setTimeout(() => {
+        if (!this.subscriptions.has(info.id)) return;
+        try {
+          this.connect(subscription);
+        } catch (error) {
+          info.status = 'closed';
+          info.lastError = (error as Error).message;
+        }
+      }, this.reconnectDelayMs);
+    };
+  }
+}
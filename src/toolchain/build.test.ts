@@ -0,0 +1,103 @@
+import { createHash } from 'crypto';
+import { mkdtempSync, mkdirSync, rmSync, writeFileSync } from 'fs';
+import { tmpdir } from 'os';
+import { join } from 'path';
+import { buildPhase, collectArtifacts, parseCompilerMessages } from './build.js';
+import { phaseLines, runCommand } from './process.js';
+import { CancelledError, runCancellable } from '../utils/cancellation.js';
+
+const CARGO_OUTPUT = `   Compiling vault v0.0.0 (/work/vault)
+warning: unused variable: \`amount\`
+  --> src/lib.rs:12:24
+   |
+error[E0308]: mismatched types
+  --> src/lib.rs:20:9
+   |
+error: cannot find macro \`sc_error\` in this scope
+warning: \`vault\` (lib) generated 1 warning
+error: could not compile \`vault\` (lib) due to 2 previous errors; 1 warning emitted`;
+
+describe('parseCompilerMessages', () => {
+  it('extracts errors and warnings with locations and skips cargo summaries', () => {
+    expect(parseCompilerMessages(CARGO_OUTPUT.split('\n'))).toEqual([
+      { level: 'warning', message: 'unused variable: `amount`', location: 'src/lib.rs:12:24' },
+      { level: 'error', message: 'mismatched types', location: 'src/lib.rs:20:9' },
+      {
+        level: 'error',
+        message: 'cannot find macro `sc_error` in this scope',
+        location: undefined,
+      },
+    ]);
+  });
+});
+
+describe('collectArtifacts', () => {
+  let root: string;
+
+  beforeAll(() => {
+    root = mkdtempSync(join(tmpdir(), 'klever-build-'));
+    mkdirSync(join(root, 'output'));
+    writeFileSync(join(root, 'output', 'vault.wasm'), 'wasm');
+    writeFileSync(join(root, 'output', 'vault.abi.json'), '{}');
+    writeFileSync(join(root, 'output', 'vault.mxsc.json'), '{}');
+    mkdirSync(join(root, 'proxy', 'output'), { recursive: true });
+    writeFileSync(join(root, 'proxy', 'output', 'proxy.wasm'), 'proxy');
+  });
+
+  afterAll(() => rmSync(root, { recursive: true, force: true }));
+
+  it('lists wasm and ABI files of the project and nested contracts with hashes', async () => {
+    const artifacts = await collectArtifacts(root);
+    expect(artifacts.map(a => [a.kind, a.path.slice(root.length + 1), a.size])).toEqual([
+      ['abi', join('output', 'vault.abi.json'), 2],
+      ['wasm', join('output', 'vault.wasm'), 4],
+      ['wasm', join('proxy', 'output', 'proxy.wasm'), 5],
+    ]);
+    expect(artifacts[1].sha256).toBe(createHash('sha256').update('wasm').digest('hex'));
+  });
+});
+
+describe('runCommand', () => {
+  it('streams lines from stdout and stderr and reports the exit code', async () => {
+    const streamed: string[] = [];
+    const result = await runCommand(
+      process.execPath,
+      ['-e', 'console.log("one"); console.error("two"); process.exit(3)'],
+      { cwd: tmpdir(), onLine: line => streamed.push(line) }
+    );
+    expect(result.exitCode).toBe(3);
+    expect(result.timedOut).toBe(false);
+    expect([...streamed].sort()).toEqual(['one', 'two']);
+    expect(result.lines.sort()).toEqual(['one', 'two']);
+  });
+
+  it('kills the command when the tool call is cancelled', async () => {
+    const controller = new AbortController();
+    const started = Date.now();
+    const run = runCancellable(controller.signal, () =>
+      runCommand(process.execPath, ['-e', 'console.log("up"); setTimeout(() => {}, 60000)'], {
+        cwd: tmpdir(),
+        onLine: () => controller.abort(),
+      })
+    );
+    await expect(run).rejects.toBeInstanceOf(CancelledError);
+    expect(Date.now() - started).toBeLessThan(10000);
+  });
+});
+
+describe('phaseLines', () => {
+  it('reports each build phase once, when its first line is printed', () => {
+    const phases: string[] = [];
+    const lines: string[] = [];
+    const onLine = phaseLines(buildPhase, line => lines.push(line), phase => phases.push(phase));
+    const output = [
+      '   Compiling klever-sc v0.45.0',
+      '   Compiling vault v0.0.0 (/work/vault)',
+      '    Finished release [optimized] target(s) in 20.1s',
+      'Calling wasm-opt on ../output/vault.wasm ...',
+    ];
+    output.forEach(onLine);
+    expect(phases).toEqual(['compiling', 'optimizing wasm']);
+    expect(lines).toEqual(output);
+  });
+});
@@ -0,0 +1,187 @@
+/**
+ * klever-sc build pipeline: the meta crate compiles the wasm crate for
+ * wasm32-unknown-unknown, runs wasm-opt and writes the `.wasm` and ABI JSON
+ * into `output/`. Runs through ksc (Klever's sc-meta) when the SDK is
+ * installed, otherwise through `cargo run` in the meta crate.
+ */
+
+import { createHash } from 'crypto';
+import { access, readdir, readFile, stat } from 'fs/promises';
+import { join, resolve } from 'path';
+import { kscPath, phaseLines, runCommand } from './process.js';
+
+export type BuildTool = 'ksc' | 'cargo';
+
+export interface BuildOptions {
+  /** Build driver (default: ksc when installed, otherwise cargo) */
+  tool?: BuildTool;
+  /** Run wasm-opt on the produced wasm (default: true) */
+  wasmOpt?: boolean;
+  /** Environment of the build process (default: the server's environment) */
+  env?: NodeJS.ProcessEnv;
+  /** Called with every line of compiler output as it is printed */
+  onLine?: (line: string) => void;
+  /** Called when one of the `BUILD_PHASES` starts */
+  onPhase?: (phase: string) => void;
+  timeoutMs?: number;
+}
+
+export const BUILD_PHASES = ['compiling', 'optimizing wasm', 'collecting artifacts'];
+
+/** The build phase a line of ksc or cargo output starts, if any */
+export function buildPhase(line: string): string | undefined {
+  if (/^\s*(Compiling|Building) /.test(line)) return 'compiling';
+  if (/wasm-opt/.test(line)) return 'optimizing wasm';
+  return undefined;
+}
+
+export interface BuildArtifact {
+  kind: 'wasm' | 'abi';
+  path: string;
+  size: number;
+  sha256: string;
+}
+
+export interface CompilerMessage {
+  level: 'error' | 'warning';
+  message: string;
+  /** `file:line:column` from the `-->` line that follows the message */
+  location?: string;
+}
+
+export interface BuildResult {
+  success: boolean;
+  tool: BuildTool;
+  command: string;
+  exitCode: number | null;
+  timedOut: boolean;
+  durationMs: number;
+  artifacts: BuildArtifact[];
+  errors: CompilerMessage[];
+  warnings: CompilerMessage[];
+  /** Tail of the compiler output */
+  output: string;
+}
+
+async function exists(path: string): Promise<boolean> {
+  try {
+    await access(path);
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+async function resolveTool(projectPath: string, requested?: BuildTool): Promise<BuildTool> {
+  const hasKsc = await exists(kscPath());
+  const hasMeta = await exists(join(projectPath, 'meta', 'Cargo.toml'));
+  if (requested === 'ksc' && !hasKsc) {
+    throw new Error(`ksc not found at ${kscPath()}. Run install_klever_sdk or set KSC_BIN.`);
+  }
+  if (requested === 'cargo' && !hasMeta) {
+    throw new Error(`No meta crate at ${join(projectPath, 'meta')}; cargo builds run through it.`);
+  }
+  if (requested) return requested;
+  if (hasKsc) return 'ksc';
+  if (hasMeta) return 'cargo';
+  throw new Error(
+    `Neither ksc (${kscPath()}) nor a meta crate in ${projectPath} was found. Install the Klever SDK with install_klever_sdk or build from the contract root.`
+  );
+}
+
+/**
+ * Compiler errors and warnings from rustc/cargo output, skipping the
+ * per-crate summary lines cargo prints after them
+ */
+export function parseCompilerMessages(lines: string[]): CompilerMessage[] {
+  const messages: CompilerMessage[] = [];
+  for (let i = 0; i < lines.length; i++) {
+    const match = /^(error|warning)(?:\[\w+\])?: (.+)$/.exec(lines[i]);
+    if (!match) continue;
+    const message = match[2];
+    const summary =
+      /^(could not compile|aborting due to|build failed|test failed|\d+ targets? failed)/;
+    if (summary.test(message) || /generated \d+ warnings?/.test(message)) continue;
+    const location = /^\s*--> (\S+)/.exec(lines[i + 1] || '');
+    messages.push({
+      level: match[1] as CompilerMessage['level'],
+      message,
+      location: location ? location[1] : undefined,
+    });
+  }
+  return messages;
+}
+
+/** `.wasm` and `.abi.json` files in `output/` of the project and of contracts one level below */
+export async function collectArtifacts(projectPath: string): Promise<BuildArtifact[]> {
+  const dirs = [join(projectPath, 'output')];
+  for (const entry of await readdir(projectPath, { withFileTypes: true })) {
+    if (entry.isDirectory() && !entry.name.startsWith('.') && entry.name !== 'target') {
+      dirs.push(join(projectPath, entry.name, 'output'));
+    }
+  }
+
+  const artifacts: BuildArtifact[] = [];
+  for (const dir of dirs) {
+    if (!(await exists(dir))) continue;
+    for (const name of (await readdir(dir)).sort()) {
+      const kind = name.endsWith('.wasm') ? 'wasm' : name.endsWith('.abi.json') ? 'abi' : null;
+      if (!kind) continue;
+      const path = join(dir, name);
+      const [info, bytes] = await Promise.all([stat(path), readFile(path)]);
+      artifacts.push({
+        kind,
+        path,
+        size: info.size,
+        sha256: createHash('sha256').update(bytes).digest('hex'),
+      });
+    }
+  }
+  return artifacts;
+}
+
+/** Build the contract project at `projectPath` and report the artifacts it produced */
+export async function buildContract(
+  projectPath: string,
+  options: BuildOptions = {}
+): Promise<BuildResult> {
+  const root = resolve(projectPath);
+  if (!(await exists(join(root, 'Cargo.toml')))) {
+    throw new Error(`No Cargo.toml in ${root}; pass the contract project root.`);
+  }
+  const tool = await resolveTool(root, options.tool);
+  const noWasmOpt = options.wasmOpt === false ? ['--no-wasm-opt'] : [];
+  options.onPhase?.('compiling');
+  const onLine = phaseLines(buildPhase, options.onLine, options.onPhase, 'compiling');
+
+  const run =
+    tool === 'ksc'
+      ? await runCommand(kscPath(), ['all', 'build', ...noWasmOpt], {
+          cwd: root,
+          env: options.env,
+          onLine,
+          timeoutMs: options.timeoutMs,
+        })
+      : await runCommand('cargo', ['run', '--', 'build', ...noWasmOpt], {
+          cwd: join(root, 'meta'),
+          env: options.env,
+          onLine,
+          timeoutMs: options.timeoutMs,
+        });
+
+  const messages = parseCompilerMessages(run.lines);
+  const success = run.exitCode === 0;
+  if (success) options.onPhase?.('collecting artifacts');
+  return {
+    success,
+    tool,
+    command: run.command,
+    exitCode: run.exitCode,
+    timedOut: run.timedOut,
+    durationMs: run.durationMs,
+    artifacts: success ? await collectArtifacts(root) : [],
+    errors: messages.filter(m => m.level === 'error'),
+    warnings: messages.filter(m => m.level === 'warning'),
+    output: run.output,
+  };
+}
@@ -0,0 +1,87 @@
+import { functionCoverage, parseLcov } from './coverage.js';
+
+const SOURCE = `#![no_std]
+
+klever_sc::imports!();
+
+#[klever_sc::contract]
+pub trait Token {
+    #[init]
+    fn init(&self) {}
+
+    #[endpoint(mintTokens)]
+    fn mint(&self, amount: BigUint) {
+        require!(amount > 0, "zero amount");
+        self.supply().update(|s| *s += amount);
+    }
+
+    #[endpoint]
+    fn burn(&self, amount: BigUint) {
+        self.supply().update(|s| *s -= amount);
+    }
+
+    #[view(getSupply)]
+    #[storage_mapper("supply")]
+    fn supply(&self) -> SingleValueMapper<BigUint>;
+}
+`;
+
+const LCOV = `SF:/work/token/src/lib.rs
+FN:11,_RNvXs_token4mint
+FNDA:3,_RNvXs_token4mint
+DA:11,3
+DA:12,3
+DA:13,2
+DA:14,2
+DA:17,0
+DA:18,0
+DA:19,0
+BRDA:12,0,0,1
+BRDA:12,0,1,2
+BRDA:18,0,0,-
+BRDA:18,0,1,-
+LF:7
+LH:4
+end_of_record
+SF:/work/token/tests/token_scenario_rs_test.rs
+DA:1,1
+end_of_record
+`;
+
+describe('parseLcov', () => {
+  it('reads line counts and branch outcomes per file', () => {
+    const files = parseLcov(LCOV);
+    expect([...files.keys()]).toEqual([
+      '/work/token/src/lib.rs',
+      '/work/token/tests/token_scenario_rs_test.rs',
+    ]);
+    const lib = files.get('/work/token/src/lib.rs')!;
+    expect(lib.lines.get(12)).toBe(3);
+    expect(lib.lines.get(17)).toBe(0);
+    expect(lib.branches.get(12)).toEqual({ taken: 2, total: 2 });
+    expect(lib.branches.get(18)).toEqual({ taken: 0, total: 2 });
+  });
+});
+
+describe('functionCoverage', () => {
+  it('attributes lines to endpoints under their exposed names', () => {
+    const lib = parseLcov(LCOV).get('/work/token/src/lib.rs')!;
+    const functions = functionCoverage(SOURCE, lib, 'src/lib.rs');
+    expect(functions.map(f => [f.name, f.function, f.kind])).toEqual([
+      ['mintTokens', 'mint', 'endpoint'],
+      ['burn', 'burn', 'endpoint'],
+    ]);
+    expect(functions[0]).toMatchObject({
+      file: 'src/lib.rs',
+      line: 11,
+      lines: { covered: 4, total: 4, percent: 100 },
+      branches: { covered: 2, total: 2, percent: 100 },
+      uncoveredLines: [],
+    });
+    expect(functions[1]).toMatchObject({
+      lines: { covered: 0, total: 3, percent: 0 },
+      branches: { covered: 0, total: 2, percent: 0 },
+      uncoveredLines: [17, 18, 19],
+    });
+  });
+});
@@ -0,0 +1,188 @@
+/**
+ * Code coverage of contract tests: the LCOV report `cargo llvm-cov` writes,
+ * attributed to the endpoints, views and helpers of the contract source so
+ * untested entry points and lines stand out. Only code compiled natively is
+ * instrumented: Rust tests and `*_scenario_rs_test.rs` targets count, scenarios
+ * replayed by the Go VM (`*_scenario_go_test.rs`) do not.
+ */
+
+import { readFile } from 'fs/promises';
+import { relative, resolve, sep } from 'path';
+import { contractFunctions, type FunctionKind } from '../analysis/source.js';
+
+export interface FileCoverage {
+  /** Execution count of every instrumented line, by 1-based line number */
+  lines: Map<number, number>;
+  /** Branch outcomes by line: how many were taken out of how many */
+  branches: Map<number, { taken: number; total: number }>;
+}
+
+export interface Coverage {
+  covered: number;
+  total: number;
+  /** Covered share rounded to one decimal; 100 when nothing is instrumented */
+  percent: number;
+}
+
+export interface FunctionCoverage {
+  /** Name callers use: the endpoint or view name, otherwise the Rust function name */
+  name: string;
+  /** Rust function name */
+  function: string;
+  kind: FunctionKind;
+  /** Source file relative to the project root */
+  file: string;
+  /** 1-based line of the `fn` keyword */
+  line: number;
+  lines: Coverage;
+  /** Present when the report has branch data */
+  branches?: Coverage;
+  /** Instrumented lines of the function no test executed */
+  uncoveredLines: number[];
+}
+
+export interface CoverageReport {
+  /** LCOV report written by cargo llvm-cov */
+  lcovPath: string;
+  /** Lines of the contract sources (src/) */
+  lines: Coverage;
+  branches?: Coverage;
+  /** Every contract function with a body, by file and line */
+  functions: FunctionCoverage[];
+}
+
+function coverage(covered: number, total: number): Coverage {
+  const percent = total === 0 ? 100 : Math.round((covered / total) * 1000) / 10;
+  return { covered, total, percent };
+}
+
+/** Line and branch counts by source file (as the report names it) from LCOV text */
+export function parseLcov(text: string): Map<string, FileCoverage> {
+  const files = new Map<string, FileCoverage>();
+  let current: FileCoverage | undefined;
+
+  for (const raw of text.split('\n')) {
+    const line = raw.trim();
+    if (line.startsWith('SF:')) {
+      const path = line.slice('SF:'.length);
+      current = files.get(path);
+      if (!current) {
+        current = { lines: new Map(), branches: new Map() };
+        files.set(path, current);
+      }
+    } else if (line === 'end_of_record') {
+      current = undefined;
+    } else if (current && line.startsWith('DA:')) {
+      const [number, count] = line.slice('DA:'.length).split(',');
+      const n = parseInt(number, 10);
+      // A line instantiated more than once (generics) is reported once per copy
+      current.lines.set(n, (current.lines.get(n) || 0) + (parseInt(count, 10) || 0));
+    } else if (current && line.startsWith('BRDA:')) {
+      const [number, , , taken] = line.slice('BRDA:'.length).split(',');
+      const n = parseInt(number, 10);
+      const branch = current.branches.get(n) || { taken: 0, total: 0 };
+      branch.total++;
+      if (taken !== '-' && parseInt(taken, 10) > 0) branch.taken++;
+      current.branches.set(n, branch);
+    }
+  }
+  return files;
+}
+
+function linesCoverage(file: FileCoverage, from: number, to: number) {
+  const uncovered: number[] = [];
+  let total = 0;
+  for (const [line, count] of file.lines) {
+    if (line < from || line > to) continue;
+    total++;
+    if (count === 0) uncovered.push(line);
+  }
+  uncovered.sort((a, b) => a - b);
+  return { lines: coverage(total - uncovered.length, total), uncovered };
+}
+
+function branchesCoverage(file: FileCoverage, from: number, to: number): Coverage {
+  let taken = 0;
+  let total = 0;
+  for (const [line, branch] of file.branches) {
+    if (line < from || line > to) continue;
+    taken += branch.taken;
+    total += branch.total;
+  }
+  return coverage(taken, total);
+}
+
+/** Name an endpoint or view is called by: its attribute argument, or the function name */
+function exposedName(attributes: string[], fallback: string): string {
+  for (const attribute of attributes) {
+    const named = /^#\[(?:endpoint|view)\((\w+)\)\]/.exec(attribute);
+    if (named) return named[1];
+  }
+  return fallback;
+}
+
+/** Coverage of every function with a body in `source`, as recorded in `file` */
+export function functionCoverage(
+  source: string,
+  file: FileCoverage,
+  path: string
+): FunctionCoverage[] {
+  const withBranches = file.branches.size > 0;
+  return contractFunctions(source)
+    .filter(fn => fn.body.trim() !== '')
+    .map(fn => {
+      const { lines, uncovered } = linesCoverage(file, fn.line, fn.endLine);
+      return {
+        name: exposedName(fn.attributes, fn.name),
+        function: fn.name,
+        kind: fn.kind,
+        file: path,
+        line: fn.line,
+        lines,
+        ...(withBranches ? { branches: branchesCoverage(file, fn.line, fn.endLine) } : {}),
+        uncoveredLines: uncovered,
+      };
+    });
+}
+
+/** Read the LCOV report at `lcovPath` and attribute it to the sources under `<root>/src` */
+export async function coverageReport(root: string, lcovPath: string): Promise<CoverageReport> {
+  const files = parseLcov(await readFile(lcovPath, 'utf-8'));
+  const sources = resolve(root, 'src') + sep;
+  const functions: FunctionCoverage[] = [];
+  let covered = 0;
+  let total = 0;
+  let taken = 0;
+  let branches = 0;
+
+  for (const [path, file] of files) {
+    if (!resolve(root, path).startsWith(sources)) continue;
+    for (const count of file.lines.values()) {
+      total++;
+      if (count > 0) covered++;
+    }
+    for (const branch of file.branches.values()) {
+      taken += branch.taken;
+      branches += branch.total;
+    }
+    let source: string;
+    try {
+      source = await readFile(resolve(root, path), 'utf-8');
+    } catch {
+      continue;
+    }
+    try {
+      functions.push(...functionCoverage(source, file, relative(root, resolve(root, path))));
+    } catch {
+      // Not a contract or module trait (plain Rust helpers): counted in the totals only
+    }
+  }
+
+  functions.sort((a, b) => a.file.localeCompare(b.file) || a.line - b.line);
+  return {
+    lcovPath,
+    lines: coverage(covered, total),
+    ...(branches > 0 ? { branches: coverage(taken, branches) } : {}),
+    functions,
+  };
+}
@@ -0,0 +1,283 @@
+import { parseAbi } from '../abi/index.js';
+import {
+  debugManifest,
+  debugTest,
+  decodeStorageChanges,
+  instrumentSource,
+  parseDebugOutput,
+} from './debug.js';
+
+const SOURCE = `#![no_std]
+
+klever_sc::imports!();
+
+#[klever_sc::contract]
+pub trait Token {
+    #[endpoint]
+    fn transfer(&self, to: ManagedAddress, amount: BigUint) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.balance(&caller).get() >= amount,
+            "insufficient balance"
+        );
+        let note = Note {
+            amount: amount.clone(),
+            to,
+        };
+        self.balance(&caller).update(|b| *b -= &amount);
+        self.send().direct_klv(&to, &amount);
+        self.transfer_event(&caller, &amount);
+    }
+
+    #[event("transfer")]
+    fn transfer_event(&self, #[indexed] from: &ManagedAddress, amount: &BigUint);
+
+    #[storage_mapper("balance")]
+    fn balance(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>;
+}
+`;
+
+const ABI = parseAbi({
+  buildInfo: {
+    contractCrate: { name: 'token', version: '0.1.0' },
+    framework: { name: 'klever-sc', version: '0.45.0' },
+  },
+  name: 'Token',
+  constructor: { inputs: [{ name: 'supply', type: 'BigUint' }], outputs: [] },
+  endpoints: [
+    {
+      name: 'transfer',
+      mutability: 'mutable',
+      inputs: [
+        { name: 'to', type: 'Address' },
+        { name: 'amount', type: 'BigUint' },
+      ],
+      outputs: [],
+    },
+  ],
+  events: [
+    {
+      identifier: 'transfer',
+      inputs: [
+        { name: 'from', type: 'Address', indexed: true },
+        { name: 'amount', type: 'BigUint' },
+      ],
+    },
+  ],
+});
+
+const RECIPIENT = `0x${'01'.repeat(32)}`;
+const SEP = '\u001f';
+const trace = (...fields: string[]) => `@@klever-debug${SEP}${fields.join(SEP)}`;
+
+describe('instrumentSource', () => {
+  it('traces statements, storage, transfers and events on their own lines', () => {
+    const instrumented = instrumentSource(SOURCE, 'src/lib.rs', new Set(['balance']), true);
+    const lines = instrumented.split('\n');
+    // Instrumentation goes on the lines it traces; the helpers come after the source
+    expect(instrumentSource(SOURCE, 'src/lib.rs', new Set()).split('\n')).toHaveLength(
+      SOURCE.split('\n').length
+    );
+
+    expect(lines[8]).toBe(
+      '        crate::__klever_trace("line\\u{1f}src/lib.rs\\u{1f}9"); let caller = self.blockchain().get_caller();'
+    );
+    expect(lines[9]).toContain(
+      'crate::__klever_trace("storage\\u{1f}balance\\u{1f}read\\u{1f}get\\u{1f}&caller"); require!('
+    );
+    expect(lines[10]).toBe('            self.balance(&caller).get() >= amount,');
+    // Struct literal fields are not statements
+    expect(lines[14]).toBe('            amount: amount.clone(),');
+    expect(lines[17]).toContain(
+      'crate::__klever_trace("storage\\u{1f}balance\\u{1f}write\\u{1f}update\\u{1f}&caller");'
+    );
+    expect(lines[18]).toContain('crate::__klever_trace("call\\u{1f}transfer");');
+
+    expect(lines[23]).toBe(
+      '    fn transfer_event__klever_traced(&self, #[indexed] from: &ManagedAddress, amount: &BigUint);' +
+        ' fn transfer_event(&self, from: &ManagedAddress, amount: &BigUint) {' +
+        ' crate::__klever_trace(&std::format!("event\\u{1f}transfer\\u{1f}{}\\u{1f}{}",' +
+        ' crate::__klever_hex(from), crate::__klever_hex(amount)));' +
+        ' self.transfer_event__klever_traced(from, amount) }'
+    );
+    expect(instrumented).toContain('pub fn __klever_trace(entry: &str) {');
+    expect(instrumentSource('pub struct Note;\n', 'src/note.rs', new Set())).toBe(
+      'pub struct Note;\n'
+    );
+  });
+});
+
+describe('debugManifest', () => {
+  it('detaches the copy from the workspace and keeps path dependencies resolvable', () => {
+    const manifest = debugManifest(
+      `[package]
+name = "token"
+
+[lib]
+path = "src/lib.rs"
+
+[dependencies.klever-sc]
+version = "0.45.0"
+path = "../framework/base"
+
+[dev-dependencies]
+klever-sc-scenario = { version = "0.45.0", path = "../framework/scenario" }
+
+[[test]]
+name = "custom"
+path = "tests/custom.rs"
+
+[workspace]
+members = [".", "meta"]
+`,
+      '/work/token'
+    );
+    expect(manifest).toContain('[lib]\npath = "src/lib.rs"');
+    expect(manifest).toContain('path = "/work/framework/base"');
+    expect(manifest).toContain('path = "/work/framework/scenario" }');
+    expect(manifest).not.toContain('[[test]]');
+    expect(manifest).not.toContain('members');
+    expect(manifest.endsWith('\n\n[workspace]\n')).toBe(true);
+  });
+});
+
+describe('debugTest', () => {
+  it('deploys the contract and makes the setup and traced calls', () => {
+    const test = debugTest('token', ABI, {
+      abi: ABI,
+      endpoint: 'transfer',
+      args: [RECIPIENT, '10'],
+      caller: 'alice',
+      payment: { amount: '5', token: 'TKN-123456' },
+      deployArgs: ['1000'],
+      setup: [{ endpoint: 'transfer', args: { to: RECIPIENT, amount: 1 } }],
+    });
+    expect(test).toContain('world.register_contract(CODE, token::ContractBuilder);');
+    expect(test).toContain(
+      '            .put_account("address:alice", Account::new().nonce(1).balance(BALANCE).kda_balance("str:TKN-123456", BALANCE))'
+    );
+    expect(test).toContain(
+      'ScDeployStep::new().from("address:owner").code(CODE).argument("0x03e8");'
+    );
+    expect(test).toContain('    phase("setup\\u{1f}0");');
+    expect(test).toContain('        .argument("0x01")');
+    expect(test).toContain('        .kda_transfer("str:TKN-123456", 0, "5");');
+  });
+
+  it('starts from the given state instead of deploying', () => {
+    const contract = `0x${'00'.repeat(24)}${'05'.repeat(8)}`;
+    const test = debugTest('token', ABI, {
+      abi: ABI,
+      endpoint: 'transfer',
+      args: [RECIPIENT, '10'],
+      caller: RECIPIENT,
+      state: {
+        contract,
+        accounts: {
+          [contract]: { balance: '7', storage: { '62616c616e6365': '64' }, owner: RECIPIENT },
+          [RECIPIENT]: { kda: { 'TKN-123456': '3' } },
+        },
+      },
+    });
+    expect(test).toContain(`const CONTRACT: &str = "${contract}";`);
+    expect(test).toContain(
+      `            .put_account("${contract}", Account::new().nonce(1).balance("7").storage("0x62616c616e6365", "0x64").code(CODE).owner("${RECIPIENT}"))`
+    );
+    expect(test).toContain(
+      `            .put_account("${RECIPIENT}", Account::new().nonce(1).kda_balance("str:TKN-123456", "3")),`
+    );
+    expect(test).not.toContain('phase("deploy")');
+    expect(test).not.toContain('BALANCE');
+    expect(test).toContain(`ScCallStep::new()\n        .from("${RECIPIENT}")`);
+  });
+
+  it('snapshots storage around the traced call only with storageDiff', () => {
+    const options = { abi: ABI, endpoint: 'transfer', args: [RECIPIENT, '10'] };
+    expect(debugTest('token', ABI, options)).not.toContain('storage_diff');
+    const test = debugTest('token', ABI, { ...options, storageDiff: true });
+    expect(test).toContain('fn storage_diff(before: &Storage, after: &Storage) {');
+    expect(test).toContain(
+      '    let before = storage(&mut world);\n    world.sc_call(&mut step);\n    storage_diff(&before, &storage(&mut world));'
+    );
+    expect(test.match(/let before = storage/g)).toHaveLength(1);
+  });
+});
+
+describe('parseDebugOutput', () => {
+  it('splits the trace by phase and decodes events and results', () => {
+    const phases = parseDebugOutput(
+      [
+        '   Compiling token v0.1.0 (/work/token/target/klever-debug)',
+        trace('phase', 'deploy'),
+        trace('result', '0', '', ''),
+        trace('phase', 'call'),
+        trace('line', 'src/lib.rs', '9'),
+        trace('line', 'src/lib.rs', '10'),
+        trace('storage', 'balance', 'read', 'get', '&caller'),
+        trace('event', 'transfer', '0'.repeat(64), '0a'),
+        trace('result', '4', Buffer.from('insufficient balance').toString('hex'), ''),
+      ],
+      ABI,
+      { 'src/lib.rs': SOURCE }
+    );
+    expect(phases.map(p => p.phase)).toEqual(['deploy', 'call']);
+    const [, call] = phases;
+    expect(call.result).toEqual({ status: 4, message: 'insufficient balance', out: [] });
+    expect(call.entries[0]).toEqual({
+      step: 1,
+      kind: 'line',
+      file: 'src/lib.rs',
+      line: 9,
+      code: 'let caller = self.blockchain().get_caller();',
+    });
+    expect(call.entries[2]).toMatchObject({
+      kind: 'storage',
+      line: 10,
+      mapper: 'balance',
+      access: 'read',
+      key: '&caller',
+    });
+    expect(call.entries[3]).toMatchObject({ kind: 'event', identifier: 'transfer' });
+    expect(call.changes).toEqual([]);
+    expect(call.entries[3].fields).toEqual({
+      from: 'klv1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpgm89z',
+      amount: 10,
+    });
+  });
+});
+
+describe('decodeStorageChanges', () => {
+  it('reports changed entries by mapper with decoded values', () => {
+    const contract = '00'.repeat(32);
+    const balanceKey = Buffer.concat([Buffer.from('balance'), Buffer.alloc(32, 1)]).toString('hex');
+    const [phase] = parseDebugOutput(
+      [
+        trace('phase', 'call'),
+        trace('storage-diff', contract, balanceKey, '64', '5a'),
+        trace('storage-diff', contract, Buffer.from('note').toString('hex'), '', '01'),
+        trace('result', '0', '', ''),
+      ],
+      ABI,
+      { 'src/lib.rs': SOURCE }
+    );
+    expect(phase.entries).toEqual([]);
+    expect(decodeStorageChanges(phase.changes, ABI, { 'src/lib.rs': SOURCE })).toEqual([
+      {
+        address: 'klv1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpgm89z',
+        key: balanceKey,
+        mapper: 'balance',
+        keyArgs: { address: 'klv1qyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqszqgpqyqsy6zanq' },
+        oldValue: '64',
+        newValue: '5a',
+        decodedOld: 100,
+        decodedNew: 90,
+      },
+      {
+        address: 'klv1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpgm89z',
+        key: Buffer.from('note').toString('hex'),
+        oldValue: '',
+        newValue: '01',
+      },
+    ]);
+  });
+});
@@ -0,0 +1,792 @@
+/**
+ * Whitebox debug runs: the contract crate is copied under `target/` with its
+ * sources instrumented, then a generated Rust test deploys it in the Rust VM
+ * and makes one call, printing a trace while the contract code runs natively.
+ * Every statement of the contract's functions reports its line, with the
+ * storage mappers it reads or writes and the transfers and contract calls it
+ * makes; event methods are wrapped to report their encoded arguments. The
+ * instrumentation is added on the lines it traces, so trace and compiler
+ * locations match the original sources. Instead of deploying, the test can
+ * start from given accounts and storage, e.g. chain state with overrides.
+ */
+
+import { cp, mkdir, readdir, readFile, rm, writeFile } from 'fs/promises';
+import { dirname, isAbsolute, join, relative, resolve } from 'path';
+import { findAbiEndpoint, type Abi } from '../abi/index.js';
+import { encodeAddress } from '../chain/index.js';
+import {
+  decodeResults,
+  decodeStorageEntry,
+  decodeTopLevel,
+  encodeArguments,
+  listEvents,
+  listStorageMappers,
+  listTraitFunctions,
+  scenarioAddress,
+  storageLayout,
+  type AbiArgs,
+} from '../codegen/index.js';
+import { escapeRustString } from '../scaffold/rust.js';
+import { lineAt, maskNonCode, SENDS_FUNDS, storageAccesses } from '../analysis/source.js';
+import { parseCompilerMessages, type CompilerMessage } from './build.js';
+import { phaseLines, runCommand } from './process.js';
+import { testPhase } from './tests.js';
+
+export interface DebugPayment {
+  /** Amount in the token's smallest unit */
+  amount: string;
+  /** KLV (default) or a KDA token identifier */
+  token?: string;
+  /** NFT/SFT nonce (default: 0) */
+  nonce?: number;
+}
+
+export interface DebugStep {
+  endpoint: string;
+  /** Arguments as plain JSON, in ABI order or keyed by name */
+  args?: AbiArgs;
+  /** Account name the call comes from (default: owner, which deploys the contract) */
+  caller?: string;
+  payment?: DebugPayment;
+}
+
+export interface DebugAccount {
+  /** KLV balance in the smallest unit */
+  balance?: string;
+  /** KDA balances keyed by token identifier */
+  kda?: Record<string, string>;
+  /** Storage as hex key -> hex value */
+  storage?: Record<string, string>;
+  /** Owner of the contract account */
+  owner?: string;
+}
+
+/** World state to start from instead of deploying the contract */
+export interface DebugState {
+  /** Contract address: klv1..., 32-byte hex or a scenario name */
+  contract: string;
+  /** Accounts keyed by address; the contract's entry gets the contract code */
+  accounts: Record<string, DebugAccount>;
+}
+
+export interface DebugOptions extends DebugStep {
+  abi: Abi;
+  deployArgs?: AbiArgs;
+  /** Start from these accounts (e.g. fetched chain state) instead of a fresh deploy */
+  state?: DebugState;
+  /** Calls made before the traced one, e.g. to fund an account */
+  setup?: DebugStep[];
+  /** Report every storage entry the traced call changes, with old and new values */
+  storageDiff?: boolean;
+  onLine?: (line: string) => void;
+  onPhase?: (phase: string) => void;
+  timeoutMs?: number;
+}
+
+export type DebugPhase = 'deploy' | 'setup' | 'call';
+
+export interface DebugTraceEntry {
+  /** Position in the trace, from 1 */
+  step: number;
+  kind: 'line' | 'storage' | 'event' | 'call';
+  /** Source file relative to the project root */
+  file: string;
+  /** 1-based line of the statement */
+  line: number;
+  /** Source line (line) or statement (call) */
+  code?: string;
+  mapper?: string;
+  access?: 'read' | 'write';
+  /** Mapper method, e.g. `get` */
+  method?: string;
+  /** Mapper key arguments as written */
+  key?: string;
+  /** Event identifier */
+  identifier?: string;
+  /** Event arguments, decoded with the ABI or as hex */
+  fields?: Record<string, unknown>;
+  /** Transfer or contract call (kind call) */
+  call?: 'transfer' | 'contract-call';
+}
+
+export interface StorageChange {
+  /** Account whose storage changed */
+  address: string;
+  /** Storage key, hex */
+  key: string;
+  /** Storage mapper the key belongs to, with its key arguments */
+  mapper?: string;
+  keyArgs?: Record<string, unknown>;
+  /** Internal entry of a collection mapper, e.g. `len` or `item` */
+  entry?: string;
+  /** Values as hex, empty when the entry is unset */
+  oldValue: string;
+  newValue: string;
+  /** Values decoded with the mapper value type */
+  decodedOld?: unknown;
+  decodedNew?: unknown;
+}
+
+export interface DebugCallResult {
+  success: boolean;
+  /** Phase the run ended in: the traced call, or the deploy or setup call that failed */
+  phase: DebugPhase;
+  /** Index of the failed setup call */
+  setupIndex?: number;
+  /** VM status: 0 for success, 4 for a user error (require!, sc_panic!) */
+  status?: number;
+  message?: string;
+  /** Results of the call, decoded with the ABI outputs */
+  returnData?: unknown[];
+  /** Trace of the phase the run ended in */
+  trace: DebugTraceEntry[];
+  /** True when the trace was cut at `MAX_TRACE_ENTRIES` */
+  truncated: boolean;
+  /** Statement that raised the error: the traced line with the message, else the last one */
+  failure?: { message: string; file: string; line: number; code: string };
+  /** Storage entries the traced call changed, when `storageDiff` is set */
+  storageDiff?: StorageChange[];
+  compileErrors: CompilerMessage[];
+  command: string;
+  exitCode: number | null;
+  timedOut: boolean;
+  durationMs: number;
+  /** Tail of the cargo output */
+  output: string;
+}
+
+export const DEBUG_PHASES = ['instrumenting', 'compiling', 'running'];
+
+/** Prefix of the lines the instrumented contract and the debug test print */
+const MARKER = '@@klever-debug';
+/** Field separator of trace lines, not found in source text */
+const SEP = '\u001f';
+/** `SEP` as a Rust string escape */
+const RUST_SEP = '\\u{1f}';
+const MAX_TRACE_ENTRIES = 5000;
+/** Balance of every test account, in KLV and in each payment token */
+const BALANCE = '1000000000000000000000000';
+/** Calls into other contracts; transfers are `SENDS_FUNDS` */
+const CONTRACT_CALL =
+  /\.\s*(async_call|async_call_promise|execute_on_dest_context|transfer_execute|register_promise)\s*\(/;
+
+const rustString = (value: string) =>
+  `"${escapeRustString(value).split(SEP).join(RUST_SEP)}"`;
+const traceCall = (fields: string[]) =>
+  `crate::__klever_trace(${rustString(fields.join(SEP))}); `;
+
+/** Helpers appended to the crate root: the trace printer and hex encoding of event arguments */
+const TRACE_HELPERS = `
+
+extern crate std;
+
+#[doc(hidden)]
+pub fn __klever_trace(entry: &str) {
+    std::eprintln!("${MARKER}${RUST_SEP}{}", entry);
+}
+
+#[doc(hidden)]
+pub fn __klever_hex<T: klever_sc::codec::TopEncode>(value: &T) -> std::string::String {
+    let mut bytes = std::vec::Vec::<u8>::new();
+    let _ = klever_sc::codec::TopEncode::top_encode(value, &mut bytes);
+    bytes.iter().map(|b| std::format!("{:02x}", b)).collect()
+}
+`;
+
+/** Debug test helpers printing the storage entries a step changes, in every account */
+const STORAGE_DIFF_HELPERS = [
+  '',
+  'type Storage = std::collections::BTreeMap<(Vec<u8>, Vec<u8>), Vec<u8>>;',
+  '',
+  '/// Storage of every account, keyed by address and key',
+  'fn storage(world: &mut ScenarioWorld) -> Storage {',
+  '    let state = &world.get_mut_debugger_backend().vm_runner.blockchain_mock.state;',
+  '    let mut storage = Storage::new();',
+  '    for (address, account) in state.accounts.iter() {',
+  '        for (key, value) in account.storage.iter() {',
+  '            storage.insert((address.as_bytes().to_vec(), key.clone()), value.clone());',
+  '        }',
+  '    }',
+  '    storage',
+  '}',
+  '',
+  'fn storage_diff(before: &Storage, after: &Storage) {',
+  '    let keys: std::collections::BTreeSet<_> = before.keys().chain(after.keys()).collect();',
+  '    for key in keys {',
+  '        let (old, new) = (before.get(key), after.get(key));',
+  '        if old != new {',
+  '            eprintln!(',
+  `                "${MARKER}${RUST_SEP}storage-diff${RUST_SEP}{}${RUST_SEP}{}${RUST_SEP}{}${RUST_SEP}{}",`,
+  '                hex(&key.0),',
+  '                hex(&key.1),',
+  '                hex(old.map_or(&[][..], |value| value.as_slice())),',
+  '                hex(new.map_or(&[][..], |value| value.as_slice()))',
+  '            );',
+  '        }',
+  '    }',
+  '}',
+];
+
+/** Line offsets where a statement starts in a function body, by the line before it */
+function statementStarts(source: string, bodyStart: number, end: number): number[] {
+  const masked = maskNonCode(source.slice(bodyStart, end));
+  const starts: number[] = [];
+  let previous = '{';
+  let offset = 0;
+  for (const line of masked.split('\n')) {
+    const code = line.trim();
+    const lineStart = bodyStart + offset;
+    offset += line.length + 1;
+    if (!code) continue;
+    const start =
+      /[;{}]$/.test(previous) &&
+      !/^([)\]}.?,|#]|else\b|&&)/.test(code) &&
+      !code.includes('=>') &&
+      // Struct literal fields, `name: value` or shorthand `name,`
+      !/^[a-z_]\w*\s*:(?!:)/.test(code) &&
+      !/^[a-z_]\w*,?$/.test(code);
+    if (start) starts.push(lineStart + line.length - line.trimStart().length);
+    previous = code;
+  }
+  return starts;
+}
+
+/**
+ * Instrument one source file: a trace call before every statement of the
+ * trait's functions and a wrapper around every event method. Files without a
+ * contract or module trait come back unchanged.
+ */
+export function instrumentSource(
+  source: string,
+  file: string,
+  mappers: Set<string>,
+  root = false
+): string {
+  let functions;
+  try {
+    functions = listTraitFunctions(source);
+  } catch {
+    return source;
+  }
+  const events = new Map(listEvents(source).map(e => [e.functionName, e]));
+  const edits: { index: number; text: string; remove?: number }[] = [];
+
+  for (const fn of functions) {
+    const event = events.get(fn.name);
+    if (event && fn.bodyStart === -1) {
+      // Rename the declaration and add a wrapper on the same line, after its `;`
+      const traced = `${fn.name}__klever_traced`;
+      const nameIndex = source.indexOf(fn.name, source.indexOf('fn ', fn.start));
+      edits.push({ index: nameIndex, text: traced, remove: fn.name.length });
+      const signature = fn.signature.replace(/#\[\w+[^\]]*\]\s*/g, '').replace(/\s+/g, ' ');
+      const hexes = event.params.map(
+        p => `crate::__klever_hex(${p.type.startsWith('&') ? '' : '&'}${p.name})`
+      );
+      const format = ['event', event.eventName, ...event.params.map(() => '{}')].join(SEP);
+      edits.push({
+        index: fn.end + 1,
+        text:
+          ` ${signature} { crate::__klever_trace(&std::format!(${rustString(format)}` +
+          `${hexes.map(h => `, ${h}`).join('')})); ` +
+          `self.${traced}(${event.params.map(p => p.name).join(', ')}) }`,
+      });
+      continue;
+    }
+    if (fn.bodyStart === -1) continue;
+
+    const starts = statementStarts(source, fn.bodyStart, fn.end);
+    starts.forEach((start, i) => {
+      const statement = source.slice(start, i + 1 < starts.length ? starts[i + 1] : fn.end);
+      const code = maskNonCode(statement);
+      const line = lineAt(source, start);
+      const calls = [traceCall(['line', file, String(line)])];
+      for (const access of storageAccesses(code, mappers)) {
+        const { mapper, method, key } = access;
+        calls.push(traceCall(['storage', mapper, access.access, method, key]));
+      }
+      if (SENDS_FUNDS.test(code)) calls.push(traceCall(['call', 'transfer']));
+      else if (CONTRACT_CALL.test(code)) calls.push(traceCall(['call', 'contract-call']));
+      edits.push({ index: start, text: calls.join('') });
+    });
+  }
+
+  let instrumented = source;
+  for (const edit of edits.sort((a, b) => b.index - a.index)) {
+    instrumented =
+      instrumented.slice(0, edit.index) +
+      edit.text +
+      instrumented.slice(edit.index + (edit.remove || 0));
+  }
+  return root ? instrumented + TRACE_HELPERS : instrumented;
+}
+
+/**
+ * Manifest of the instrumented copy: its own workspace, relative path
+ * dependencies made absolute and explicit targets (which point at files not
+ * copied) left out
+ */
+export function debugManifest(toml: string, root: string): string {
+  const absolute = (section: string) =>
+    section.replace(/(\bpath\s*=\s*)"([^"]+)"/g, (text, key: string, path: string) =>
+      isAbsolute(path) ? text : `${key}"${escapeRustString(resolve(root, path))}"`
+    );
+  const manifest = toml
+    .split(/^(?=\[)/m)
+    .filter(section => !/^\[(workspace[\].]|\[(test|bench|bin|example)\]\])/.test(section))
+    .map(section => (/^\[[^\]]*dependencies/.test(section) ? absolute(section) : section))
+    .join('');
+  return `${manifest.trimEnd()}\n\n[workspace]\n`;
+}
+
+function packageName(toml: string): string {
+  const section = /^\[package\]([\s\S]*?)(?=^\[|(?![\s\S]))/m.exec(toml);
+  const name = section && /^\s*name\s*=\s*"([^"]+)"/m.exec(section[1]);
+  if (!name) throw new Error('Cargo.toml has no [package] name');
+  return name[1];
+}
+
+const argumentExpression = (arg: Buffer) => (arg.length === 0 ? '' : `0x${arg.toString('hex')}`);
+
+/** Scenario expressions of the encoded arguments of `endpoint` */
+function argumentExpressions(abi: Abi, step: DebugStep): string[] {
+  const endpoint = findAbiEndpoint(abi, step.endpoint);
+  return encodeArguments(endpoint.inputs, step.args, abi, step.endpoint).map(argumentExpression);
+}
+
+const account = (name: string | undefined) => scenarioAddress(name || 'owner');
+
+function callStep(
+  abi: Abi,
+  step: DebugStep,
+  phase: string,
+  index?: number,
+  diff = false
+): string[] {
+  const chain = [
+    `ScCallStep::new()`,
+    `.from(${rustString(account(step.caller))})`,
+    '.to(CONTRACT)',
+    `.function(${rustString(step.endpoint)})`,
+    ...argumentExpressions(abi, step).map(arg => `.argument(${rustString(arg)})`),
+  ];
+  const payment = step.payment;
+  if (payment && (!payment.token || payment.token === 'KLV')) {
+    chain.push(`.klv_value(${rustString(payment.amount)})`);
+  } else if (payment) {
+    const token = rustString(`str:${payment.token}`);
+    chain.push(`.kda_transfer(${token}, ${payment.nonce || 0}, ${rustString(payment.amount)})`);
+  }
+  const label = index === undefined ? phase : `${phase}${SEP}${index}`;
+  return [
+    `    phase(${rustString(label)});`,
+    `    let mut step = ${chain.join('\n        ')};`,
+    ...(diff ? ['    let before = storage(&mut world);'] : []),
+    '    world.sc_call(&mut step);',
+    ...(diff ? ['    storage_diff(&before, &storage(&mut world));'] : []),
+    `    if !report(step.response()) {`,
+    '        return;',
+    '    }',
+  ];
+}
+
+function stateAccount(address: string, state: DebugAccount, contract: boolean): string {
+  const chain = ['Account::new().nonce(1)'];
+  if (state.balance) chain.push(`.balance(${rustString(state.balance)})`);
+  for (const [token, amount] of Object.entries(state.kda || {})) {
+    chain.push(`.kda_balance(${rustString(`str:${token}`)}, ${rustString(amount)})`);
+  }
+  for (const [key, value] of Object.entries(state.storage || {})) {
+    chain.push(`.storage(${rustString(`0x${key}`)}, ${rustString(value ? `0x${value}` : '')})`);
+  }
+  if (contract) chain.push('.code(CODE)');
+  if (state.owner) chain.push(`.owner(${rustString(scenarioAddress(state.owner))})`);
+  return `            .put_account(${rustString(scenarioAddress(address))}, ${chain.join('')})`;
+}
+
+/** The world state lines: the given state, or funded callers and the contract's future address */
+function setStateLines(options: DebugOptions, steps: DebugStep[]): string[] {
+  if (options.state) {
+    const { contract, accounts } = options.state;
+    const contractAddress = scenarioAddress(contract);
+    const lines = Object.entries(accounts).map(([address, state]) =>
+      stateAccount(address, state, scenarioAddress(address) === contractAddress)
+    );
+    if (!Object.keys(accounts).some(address => scenarioAddress(address) === contractAddress)) {
+      lines.push(stateAccount(contract, {}, true));
+    }
+    return lines;
+  }
+  const accounts = [...new Set(['owner', ...steps.map(s => s.caller || 'owner')])];
+  const tokens = [
+    ...new Set(
+      steps
+        .map(s => s.payment?.token)
+        .filter((token): token is string => !!token && token !== 'KLV')
+    ),
+  ];
+  const lines = accounts.map(name => {
+    const balances = tokens.map(token => `.kda_balance(${rustString(`str:${token}`)}, BALANCE)`);
+    const state = `Account::new().nonce(1).balance(BALANCE)${balances.join('')}`;
+    return `            .put_account(${rustString(account(name))}, ${state})`;
+  });
+  return [...lines, '            .new_address("address:owner", 1, CONTRACT)'];
+}
+
+/**
+ * The Rust test that deploys the instrumented contract (or sets up the given
+ * state with it) and makes the calls
+ */
+export function debugTest(crate: string, abi: Abi, options: DebugOptions): string {
+  const steps = [...(options.setup || []), options];
+  const stateLines = setStateLines(options, steps);
+  const contract = options.state ? scenarioAddress(options.state.contract) : `sc:${crate}`;
+  const deployArgs = options.state
+    ? []
+    : encodeArguments(abi.constructor?.inputs || [], options.deployArgs, abi, 'the constructor');
+  const deploy = options.state
+    ? []
+    : [
+        '',
+        '    phase("deploy");',
+        `    let mut deploy = ScDeployStep::new().from("address:owner").code(CODE)${deployArgs
+          .map(arg => `.argument(${rustString(argumentExpression(arg))})`)
+          .join('')};`,
+        '    world.sc_deploy(&mut deploy);',
+        '    if !report(deploy.response()) {',
+        '        return;',
+        '    }',
+      ];
+
+  return [
+    '//! Generated by debug_call: runs the instrumented contract in the Rust VM and',
+    '//! makes the traced call. Overwritten on every run.',
+    '',
+    'use klever_sc_scenario::{scenario_model::*, *};',
+    '',
+    '/// Any code bytes do: the registered contract runs natively, not from a .wasm',
+    'const CODE: &str = "str:klever-debug";',
+    `const CONTRACT: &str = ${rustString(contract)};`,
+    ...(options.state ? [] : [`const BALANCE: &str = "${BALANCE}";`]),
+    '',
+    'fn phase(name: &str) {',
+    `    eprintln!("${MARKER}${RUST_SEP}phase${RUST_SEP}{}", name);`,
+    '}',
+    '',
+    'fn hex(bytes: &[u8]) -> String {',
+    '    bytes.iter().map(|b| format!("{:02x}", b)).collect()',
+    '}',
+    '',
+    '/// Print the outcome of a step; false when it failed',
+    'fn report(response: &TxResponse) -> bool {',
+    '    let out: Vec<String> = response.out.iter().map(|value| hex(value)).collect();',
+    '    let error = &response.tx_error;',
+    '    eprintln!(',
+    `        "${MARKER}${RUST_SEP}result${RUST_SEP}{}${RUST_SEP}{}${RUST_SEP}{}",`,
+    '        error.status,',
+    '        hex(error.message.as_bytes()),',
+    '        out.join(",")',
+    '    );',
+    '    error.status == 0',
+    '}',
+    ...(options.storageDiff ? STORAGE_DIFF_HELPERS : []),
+    '',
+    '#[test]',
+    'fn debug_call() {',
+    '    let mut world = ScenarioWorld::new();',
+    `    world.register_contract(CODE, ${crate.replace(/-/g, '_')}::ContractBuilder);`,
+    '    world.set_state_step(',
+    '        SetStateStep::new()',
+    ...stateLines.map((line, i) => (i === stateLines.length - 1 ? `${line},` : line)),
+    '    );',
+    ...deploy,
+    ...(options.setup || []).flatMap((step, i) => ['', ...callStep(abi, step, 'setup', i)]),
+    '',
+    ...callStep(abi, options, 'call', undefined, options.storageDiff),
+    '}',
+    '',
+  ].join('\n');
+}
+
+interface PhaseOutput {
+  phase: DebugPhase;
+  setupIndex?: number;
+  entries: DebugTraceEntry[];
+  truncated: boolean;
+  result?: { status: number; message: string; out: Buffer[] };
+  /** Storage changes as hex address, key, old and new value */
+  changes: { address: string; key: string; oldValue: string; newValue: string }[];
+}
+
+function decodeEventFields(abi: Abi, identifier: string, hexes: string[]) {
+  const event = (abi.events || []).find(e => e.identifier === identifier);
+  const fields: Record<string, unknown> = {};
+  hexes.forEach((hex, i) => {
+    const input = event?.inputs[i];
+    const name = input?.name || String(i);
+    try {
+      fields[name] = input ? decodeTopLevel(input.type, Buffer.from(hex, 'hex'), abi) : hex;
+    } catch {
+      fields[name] = hex;
+    }
+  });
+  return fields;
+}
+
+/**
+ * The phases of a debug run from its output lines, each with its trace and
+ * result; `sources` (by file, relative to the project) fill in the code of
+ * traced lines
+ */
+export function parseDebugOutput(
+  lines: string[],
+  abi: Abi,
+  sources: Record<string, string>
+): PhaseOutput[] {
+  const phases: PhaseOutput[] = [];
+  const sourceLines = new Map(
+    Object.entries(sources).map(([file, text]) => [file, text.split('\n')])
+  );
+  let current: PhaseOutput | undefined;
+  let at = { file: '', line: 0 };
+
+  for (const raw of lines) {
+    const index = raw.indexOf(MARKER + SEP);
+    if (index === -1) continue;
+    const fields = raw.slice(index + MARKER.length + 1).split(SEP);
+    const [kind, ...rest] = fields;
+    if (kind === 'phase') {
+      current = {
+        phase: rest[0] as DebugPhase,
+        ...(rest[1] !== undefined ? { setupIndex: parseInt(rest[1], 10) } : {}),
+        entries: [],
+        truncated: false,
+        changes: [],
+      };
+      phases.push(current);
+      continue;
+    }
+    if (!current) continue;
+    if (kind === 'result') {
+      const [status, message, out] = rest;
+      current.result = {
+        status: parseInt(status, 10),
+        message: Buffer.from(message || '', 'hex').toString('utf8'),
+        out: out ? out.split(',').map(hex => Buffer.from(hex, 'hex')) : [],
+      };
+      continue;
+    }
+    if (kind === 'storage-diff') {
+      const [address, key, oldValue, newValue] = rest;
+      current.changes.push({ address, key, oldValue, newValue });
+      continue;
+    }
+    if (current.entries.length >= MAX_TRACE_ENTRIES) {
+      current.truncated = true;
+      continue;
+    }
+    const step = current.entries.length + 1;
+    if (kind === 'line') {
+      at = { file: rest[0], line: parseInt(rest[1], 10) };
+      const code = sourceLines.get(at.file)?.[at.line - 1]?.trim() || '';
+      current.entries.push({ step, kind: 'line', ...at, code });
+    } else if (kind === 'storage') {
+      const [mapper, access, method, key] = rest;
+      current.entries.push({
+        step,
+        kind: 'storage',
+        ...at,
+        mapper,
+        access: access as 'read' | 'write',
+        method,
+        key,
+      });
+    } else if (kind === 'call') {
+      const code = sourceLines.get(at.file)?.[at.line - 1]?.trim() || '';
+      const call = rest[0] as 'transfer' | 'contract-call';
+      current.entries.push({ step, kind: 'call', ...at, code, call });
+    } else if (kind === 'event') {
+      const [identifier, ...hexes] = rest;
+      const fields = decodeEventFields(abi, identifier, hexes);
+      current.entries.push({ step, kind: 'event', ...at, identifier, fields });
+    }
+  }
+  return phases;
+}
+
+/** Storage changes with bech32 addresses, decoded with the mappers declared in `sources` */
+export function decodeStorageChanges(
+  changes: PhaseOutput['changes'],
+  abi: Abi,
+  sources: Record<string, string>
+): StorageChange[] {
+  const layout = storageLayout(Object.values(sources));
+  return changes.map(({ address, key, oldValue, newValue }) => {
+    const decode = (value: string) => {
+      if (!value) return undefined;
+      try {
+        return decodeStorageEntry(Buffer.from(key, 'hex'), Buffer.from(value, 'hex'), layout, abi);
+      } catch {
+        // Values that do not decode as the mapper type are reported as hex
+        return undefined;
+      }
+    };
+    const before = decode(oldValue);
+    const after = decode(newValue);
+    const entry = after || before;
+    return {
+      address: encodeAddress(Buffer.from(address, 'hex')),
+      key,
+      ...(entry
+        ? {
+            mapper: entry.functionName,
+            keyArgs: entry.keyArgs,
+            ...(entry.entry ? { entry: entry.entry } : {}),
+          }
+        : {}),
+      oldValue,
+      newValue,
+      ...(before ? { decodedOld: before.value } : {}),
+      ...(after ? { decodedNew: after.value } : {}),
+    };
+  });
+}
+
+/** The statement that raised `message`: the last traced line quoting it, else the last line */
+function locateFailure(trace: DebugTraceEntry[], message: string, sources: Record<string, string>) {
+  const lines = trace.filter(entry => entry.kind === 'line');
+  const quoted = `"${message}"`;
+  const statement = (entry: DebugTraceEntry) =>
+    (sources[entry.file] || '').split('\n').slice(entry.line - 1, entry.line + 4).join('\n');
+  const match =
+    [...lines].reverse().find(entry => message && statement(entry).includes(quoted)) ||
+    lines[lines.length - 1];
+  return match && { message, file: match.file, line: match.line, code: match.code || '' };
+}
+
+async function rustFiles(dir: string): Promise<string[]> {
+  const files: string[] = [];
+  for (const entry of await readdir(dir, { withFileTypes: true })) {
+    const path = join(dir, entry.name);
+    if (entry.isDirectory()) files.push(...(await rustFiles(path)));
+    else if (entry.name.endsWith('.rs')) files.push(path);
+  }
+  return files;
+}
+
+/** The `src/**/*.rs` files of a contract project, keyed by path relative to its root */
+export async function projectSources(projectPath: string): Promise<Record<string, string>> {
+  const root = resolve(projectPath);
+  const sources: Record<string, string> = {};
+  for (const path of await rustFiles(join(root, 'src'))) {
+    sources[relative(root, path).split('\\').join('/')] = await readFile(path, 'utf-8');
+  }
+  return sources;
+}
+
+/**
+ * Deploy the contract at `projectPath` (or set up `options.state` with it) in
+ * the Rust VM and trace one call
+ */
+export async function debugCall(
+  projectPath: string,
+  options: DebugOptions
+): Promise<DebugCallResult> {
+  const root = resolve(projectPath);
+  const { abi } = options;
+  options.onPhase?.('instrumenting');
+
+  const manifest = await readFile(join(root, 'Cargo.toml'), 'utf-8');
+  if (!/klever-sc-scenario/.test(manifest)) {
+    throw new Error('Cargo.toml needs klever-sc-scenario in [dev-dependencies] to run the VM');
+  }
+  const crate = packageName(manifest);
+  const sources = await projectSources(root);
+  const mappers = new Set<string>();
+  for (const text of Object.values(sources)) {
+    try {
+      for (const mapper of listStorageMappers(text)) mappers.add(mapper.functionName);
+    } catch {
+      // No contract or module trait
+    }
+  }
+
+  const work = join(root, 'target', 'klever-debug');
+  await rm(work, { recursive: true, force: true });
+  await mkdir(join(work, 'tests'), { recursive: true });
+  for (const [file, text] of Object.entries(sources)) {
+    const target = join(work, file);
+    await mkdir(dirname(target), { recursive: true });
+    await writeFile(target, instrumentSource(text, file, mappers, file === 'src/lib.rs'));
+  }
+  await writeFile(join(work, 'Cargo.toml'), debugManifest(manifest, root));
+  await writeFile(join(work, 'tests', 'debug_call.rs'), debugTest(crate, abi, options));
+  // Reuse what the project's own builds compiled
+  await cp(join(root, 'Cargo.lock'), join(work, 'Cargo.lock')).catch(() => undefined);
+
+  options.onPhase?.('compiling');
+  const run = await runCommand(
+    'cargo',
+    ['test', '--test', 'debug_call', '--', '--nocapture', '--color', 'never'],
+    {
+      cwd: work,
+      env: { ...process.env, CARGO_TERM_COLOR: 'never', CARGO_TARGET_DIR: join(root, 'target') },
+      onLine: phaseLines(
+        line => (testPhase(line) === 'running tests' ? 'running' : testPhase(line)),
+        options.onLine,
+        options.onPhase,
+        'compiling'
+      ),
+      timeoutMs: options.timeoutMs,
+    }
+  );
+
+  // The copy keeps the project's layout, so locations point at the original files
+  const compileErrors = parseCompilerMessages(run.lines).filter(m => m.level === 'error');
+  const phases = parseDebugOutput(run.lines, abi, sources);
+  const last = phases[phases.length - 1];
+  const base = {
+    compileErrors,
+    command: run.command,
+    exitCode: run.exitCode,
+    timedOut: run.timedOut,
+    durationMs: run.durationMs,
+    output: run.output,
+  };
+  if (!last || !last.result) {
+    return {
+      success: false,
+      phase: last?.phase || 'deploy',
+      trace: last?.entries || [],
+      truncated: last?.truncated || false,
+      ...base,
+    };
+  }
+
+  const { status, message, out } = last.result;
+  const success = status === 0 && last.phase === 'call';
+  const endpoint = abi.endpoints.find(e => e.name === options.endpoint);
+  let returnData: unknown[] | undefined;
+  if (success && endpoint) {
+    try {
+      returnData = decodeResults(endpoint.outputs, out, abi);
+    } catch {
+      returnData = out.map(value => value.toString('hex'));
+    }
+  }
+  const failure = status === 0 ? undefined : locateFailure(last.entries, message, sources);
+  return {
+    success,
+    phase: last.phase,
+    ...(last.setupIndex !== undefined ? { setupIndex: last.setupIndex } : {}),
+    status,
+    ...(message ? { message } : {}),
+    ...(returnData ? { returnData } : {}),
+    trace: last.entries,
+    truncated: last.truncated,
+    ...(failure ? { failure } : {}),
+    ...(options.storageDiff && last.phase === 'call'
+      ? { storageDiff: decodeStorageChanges(last.changes, abi, sources) }
+      : {}),
+    ...base,
+  };
+}
@@ -0,0 +1,137 @@
+import { chmodSync, existsSync, mkdtempSync, mkdirSync, rmSync, writeFileSync } from 'fs';
+import { tmpdir } from 'os';
+import { join } from 'path';
+import {
+  compareGas,
+  gasBaseline,
+  parseGasMismatch,
+  profileGas,
+  scenarioNumber,
+  summarizeGas,
+  type GasCall,
+} from './gas.js';
+
+/** Stands in for the Go VM runner: every call costs 1,000 gas per letter of its function */
+const FAKE_RUNNER = `#!/usr/bin/env node
+const steps = JSON.parse(require('fs').readFileSync(process.argv[2], 'utf8')).steps;
+for (const step of steps) {
+  if (step.step !== 'scCall' && step.step !== 'scDeploy') continue;
+  const limit = Number(String(step.tx.gasLimit).replace(/,/g, ''));
+  const left = limit - 1000 * (step.tx.function || 'deploy').length;
+  const want = step.expect && step.expect.gas;
+  if (want !== undefined && want !== '*' && Number(want) !== left) {
+    console.log('FAIL: result gas mismatch. Want: "' + want + '". Got: ' + left + '.');
+    process.exit(1);
+  }
+}
+console.log('SUCCESS');
+`;
+
+const call = (step: number, endpoint: string, gasUsed: number): GasCall => ({
+  scenario: 'scenarios/vault.scen.json',
+  step,
+  endpoint,
+  gasLimit: 5000000,
+  gasUsed,
+});
+
+describe('gas helpers', () => {
+  it('reads scenario gas amounts and runner gas mismatches', () => {
+    expect(scenarioNumber('5,000,000')).toBe(5000000);
+    expect(scenarioNumber('0x10')).toBe(16);
+    expect(() => scenarioNumber(undefined)).toThrow('Expected a gas amount');
+    expect(parseGasMismatch('result gas mismatch. Want: "5000001". Got: 4993000.')).toBe(4993000);
+    expect(parseGasMismatch('SUCCESS')).toBeUndefined();
+  });
+
+  it('summarizes calls per endpoint and flags regressions against a baseline', () => {
+    const before = [call(1, 'deploy', 6000), call(2, 'deposit', 7000), call(3, 'deposit', 9000)];
+    expect(summarizeGas(before)).toEqual([
+      { endpoint: 'deploy', calls: 1, min: 6000, max: 6000, average: 6000 },
+      { endpoint: 'deposit', calls: 2, min: 7000, max: 9000, average: 8000 },
+    ]);
+    const baseline = gasBaseline(
+      { calls: before, endpoints: summarizeGas(before), failures: [] },
+      new Date('2026-01-01T00:00:00Z')
+    );
+    expect(baseline.calls).toEqual({
+      'scenarios/vault.scen.json#1': 6000,
+      'scenarios/vault.scen.json#2': 7000,
+      'scenarios/vault.scen.json#3': 9000,
+    });
+
+    const after = [call(1, 'deploy', 6100), call(2, 'deposit', 7700), call(4, 'claim', 500)];
+    const comparison = compareGas(after, baseline, 5);
+    expect(comparison.regressions).toEqual([
+      {
+        key: 'scenarios/vault.scen.json#2',
+        endpoint: 'deposit',
+        baseline: 7000,
+        current: 7700,
+        changePercent: 10,
+      },
+    ]);
+    expect(comparison.improvements).toEqual([]);
+    expect(comparison.added).toEqual(['scenarios/vault.scen.json#4']);
+    expect(comparison.removed).toEqual(['scenarios/vault.scen.json#3']);
+  });
+});
+
+describe('profileGas', () => {
+  let root: string;
+
+  beforeAll(() => {
+    root = mkdtempSync(join(tmpdir(), 'klever-gas-'));
+    mkdirSync(join(root, 'scenarios'));
+    writeFileSync(join(root, 'run-scenarios'), FAKE_RUNNER);
+    chmodSync(join(root, 'run-scenarios'), 0o755);
+    const tx = (fn?: string) => ({ from: 'address:owner', ...(fn ? { function: fn } : {}) });
+    writeFileSync(
+      join(root, 'scenarios', 'vault.scen.json'),
+      JSON.stringify({
+        steps: [
+          { step: 'setState', accounts: {} },
+          { step: 'scDeploy', id: 'deploy', tx: { ...tx(), gasLimit: '5,000,000' } },
+          { step: 'scCall', id: 'deposit', tx: { ...tx('deposit'), gasLimit: '5,000,000' } },
+          { step: 'scQuery', tx: { function: 'getBalance' } },
+          { step: 'scCall', tx: { ...tx('claim'), gasLimit: '100000' }, expect: { gas: '*' } },
+        ],
+      })
+    );
+  });
+
+  afterAll(() => rmSync(root, { recursive: true, force: true }));
+
+  it('measures every transaction step with the scenario runner', async () => {
+    const profile = await profileGas(root, { runner: join(root, 'run-scenarios') });
+    expect(profile.failures).toEqual([]);
+    expect(profile.calls).toEqual([
+      {
+        scenario: 'scenarios/vault.scen.json',
+        step: 1,
+        id: 'deploy',
+        endpoint: 'deploy',
+        gasLimit: 5000000,
+        gasUsed: 6000,
+      },
+      {
+        scenario: 'scenarios/vault.scen.json',
+        step: 2,
+        id: 'deposit',
+        endpoint: 'deposit',
+        gasLimit: 5000000,
+        gasUsed: 7000,
+      },
+      {
+        scenario: 'scenarios/vault.scen.json',
+        step: 4,
+        endpoint: 'claim',
+        gasLimit: 100000,
+        gasUsed: 5000,
+      },
+    ]);
+    expect(profile.endpoints.map(e => e.endpoint)).toEqual(['deploy', 'deposit', 'claim']);
+    // The measuring copy is removed
+    expect(existsSync(join(root, 'scenarios', 'vault.gas-profile.scen.json'))).toBe(false);
+  });
+});
@@ -0,0 +1,294 @@
+/**
+ * Gas profiles of scenario suites. Only the Go VM meters gas, and its
+ * scenario runner reports the gas of a transaction only when it differs from
+ * the `expect.gas` of the step, so every `scCall`/`scDeploy` step is measured
+ * by running a copy of its scenario with an impossible expectation on that
+ * step (and the gas already measured on the steps before it). Profiles are
+ * stored as baseline files and compared call by call.
+ */
+
+import { access, readdir, readFile, rm, writeFile } from 'fs/promises';
+import { basename, dirname, join, relative, resolve } from 'path';
+import { CancelledError } from '../utils/cancellation.js';
+import { runCommand } from './process.js';
+
+export interface GasCall {
+  /** Scenario file relative to the project root */
+  scenario: string;
+  /** Step index in the scenario */
+  step: number;
+  /** Step id, when the scenario gives one */
+  id?: string;
+  /** Called endpoint, or `deploy` */
+  endpoint: string;
+  gasLimit: number;
+  gasUsed: number;
+}
+
+export interface EndpointGas {
+  endpoint: string;
+  calls: number;
+  min: number;
+  max: number;
+  average: number;
+}
+
+export interface GasProfile {
+  calls: GasCall[];
+  endpoints: EndpointGas[];
+  /** Scenarios that could not be measured, with the runner's error */
+  failures: { scenario: string; step?: number; message: string }[];
+}
+
+export interface GasBaseline {
+  version: 1;
+  createdAt: string;
+  /** Gas used keyed by `callKey` */
+  calls: Record<string, number>;
+  endpoints: EndpointGas[];
+}
+
+export interface GasChange {
+  key: string;
+  endpoint: string;
+  baseline: number;
+  current: number;
+  /** Change relative to the baseline, in percent (one decimal) */
+  changePercent: number;
+}
+
+export interface GasComparison {
+  thresholdPercent: number;
+  /** Calls that use more gas than the baseline by more than the threshold */
+  regressions: GasChange[];
+  /** Calls that use less gas than the baseline by more than the threshold */
+  improvements: GasChange[];
+  /** Calls not in the baseline */
+  added: string[];
+  /** Baseline calls no scenario makes anymore */
+  removed: string[];
+}
+
+export interface GasProfileOptions {
+  /** Go VM scenario runner (default: `run-scenarios`, as `ScenarioWorld::vm_go()` uses) */
+  runner?: string;
+  /** Only profile scenario files whose path contains this string */
+  filter?: string;
+  onLine?: (line: string) => void;
+  /** Called with each scenario file as its measurement starts */
+  onScenario?: (scenario: string) => void;
+  timeoutMs?: number;
+}
+
+export const GAS_PHASES = ['measuring', 'comparing'];
+
+const DEFAULT_RUNNER = 'run-scenarios';
+/** What the runner prints when `expect.gas` differs from the gas left after the transaction */
+const GAS_MISMATCH = /gas mismatch[\s\S]*?(?:got|have):?\s*"?(\d+)/i;
+
+interface TxStep {
+  step: string;
+  id?: string;
+  tx?: { function?: string; gasLimit?: string | number };
+  expect?: Record<string, unknown>;
+}
+
+/** Scenario value of a gas limit (`5,000,000`, `5000000` or `0x...`) as a number */
+export function scenarioNumber(value: unknown): number {
+  const text = String(value ?? '').replace(/[,_]/g, '');
+  const number = /^0x[0-9a-f]+$/i.test(text) ? parseInt(text, 16) : Number(text);
+  if (!Number.isSafeInteger(number) || number < 0) {
+    throw new Error(`Expected a gas amount, got ${JSON.stringify(value)}`);
+  }
+  return number;
+}
+
+/** Indexes of the steps that send a transaction through the VM */
+export function transactionSteps(steps: TxStep[]): number[] {
+  return steps.flatMap((step, i) =>
+    step.step === 'scCall' || step.step === 'scDeploy' ? [i] : []
+  );
+}
+
+/** Gas left after the transaction, from runner output reporting a gas mismatch */
+export function parseGasMismatch(output: string): number | undefined {
+  const match = GAS_MISMATCH.exec(output);
+  return match ? parseInt(match[1], 10) : undefined;
+}
+
+/** Key of a call in baselines: scenario path and step id (or index) */
+export function callKey(call: GasCall): string {
+  return `${call.scenario}#${call.id ?? call.step}`;
+}
+
+/** Per-endpoint summary of measured calls, in first-call order */
+export function summarizeGas(calls: GasCall[]): EndpointGas[] {
+  const byEndpoint = new Map<string, number[]>();
+  for (const call of calls) {
+    byEndpoint.set(call.endpoint, [...(byEndpoint.get(call.endpoint) || []), call.gasUsed]);
+  }
+  return [...byEndpoint].map(([endpoint, used]) => ({
+    endpoint,
+    calls: used.length,
+    min: Math.min(...used),
+    max: Math.max(...used),
+    average: Math.round(used.reduce((sum, gas) => sum + gas, 0) / used.length),
+  }));
+}
+
+export function gasBaseline(profile: GasProfile, now = new Date()): GasBaseline {
+  return {
+    version: 1,
+    createdAt: now.toISOString(),
+    calls: Object.fromEntries(profile.calls.map(call => [callKey(call), call.gasUsed])),
+    endpoints: profile.endpoints,
+  };
+}
+
+/** Compare measured calls with a baseline, call by call */
+export function compareGas(
+  calls: GasCall[],
+  baseline: GasBaseline,
+  thresholdPercent: number
+): GasComparison {
+  const comparison: GasComparison = {
+    thresholdPercent,
+    regressions: [],
+    improvements: [],
+    added: [],
+    removed: [],
+  };
+  const seen = new Set<string>();
+  for (const call of calls) {
+    const key = callKey(call);
+    seen.add(key);
+    const before = baseline.calls[key];
+    if (before === undefined) {
+      comparison.added.push(key);
+      continue;
+    }
+    const change =
+      before === 0 ? (call.gasUsed === 0 ? 0 : Infinity) : ((call.gasUsed - before) / before) * 100;
+    const entry = {
+      key,
+      endpoint: call.endpoint,
+      baseline: before,
+      current: call.gasUsed,
+      changePercent: Math.round(change * 10) / 10,
+    };
+    if (change > thresholdPercent) comparison.regressions.push(entry);
+    else if (change < -thresholdPercent) comparison.improvements.push(entry);
+  }
+  comparison.removed = Object.keys(baseline.calls).filter(key => !seen.has(key));
+  return comparison;
+}
+
+export function parseGasBaseline(text: string): GasBaseline {
+  const baseline = JSON.parse(text) as Partial<GasBaseline>;
+  if (baseline.version !== 1 || typeof baseline.calls !== 'object' || !baseline.calls) {
+    throw new Error('Not a gas baseline file (expected version 1 with calls)');
+  }
+  return baseline as GasBaseline;
+}
+
+async function scenarioFiles(dir: string): Promise<string[]> {
+  try {
+    await access(dir);
+  } catch {
+    return [];
+  }
+  const files: string[] = [];
+  for (const entry of await readdir(dir, { withFileTypes: true })) {
+    const path = join(dir, entry.name);
+    if (entry.isDirectory()) files.push(...(await scenarioFiles(path)));
+    else if (entry.name.endsWith('.scen.json') && !entry.name.includes('.gas-profile.')) {
+      files.push(path);
+    }
+  }
+  return files.sort();
+}
+
+/** Measure the gas of every transaction step of one scenario file */
+async function profileScenario(
+  root: string,
+  file: string,
+  options: GasProfileOptions,
+  profile: GasProfile
+): Promise<void> {
+  const scenario = relative(root, file).split('\\').join('/');
+  const json = JSON.parse(await readFile(file, 'utf-8')) as { steps?: TxStep[] };
+  const steps = json.steps || [];
+  // Next to the original, so relative `file:` paths still resolve
+  const name = basename(file).replace(/\.scen\.json$/, '.gas-profile.scen.json');
+  const copy = join(dirname(file), name);
+  const remaining = new Map<number, number>();
+
+  try {
+    for (const index of transactionSteps(steps)) {
+      const step = steps[index];
+      const gasLimit = scenarioNumber(step.tx?.gasLimit);
+      const measuring = steps.map((s, i) => {
+        const gas = i === index ? gasLimit + 1 : remaining.get(i);
+        return gas === undefined ? s : { ...s, expect: { ...s.expect, gas: String(gas) } };
+      });
+      await writeFile(copy, JSON.stringify({ ...json, steps: measuring }, null, 2));
+      const run = await runCommand(options.runner || DEFAULT_RUNNER, [copy], {
+        cwd: root,
+        onLine: options.onLine,
+        timeoutMs: options.timeoutMs,
+      });
+      const left = parseGasMismatch(run.output);
+      if (left === undefined) {
+        profile.failures.push({
+          scenario,
+          step: index,
+          message: run.output.trim().split('\n').slice(-5).join('\n') || 'No gas reported',
+        });
+        return;
+      }
+      remaining.set(index, left);
+      profile.calls.push({
+        scenario,
+        step: index,
+        ...(step.id ? { id: step.id } : {}),
+        endpoint: step.step === 'scDeploy' ? 'deploy' : step.tx?.function || '',
+        gasLimit,
+        gasUsed: gasLimit - left,
+      });
+    }
+  } finally {
+    await rm(copy, { force: true });
+  }
+}
+
+/** Run the scenarios of the project at `projectPath` in the Go VM and measure every call */
+export async function profileGas(
+  projectPath: string,
+  options: GasProfileOptions = {}
+): Promise<GasProfile> {
+  const root = resolve(projectPath);
+  const files = (await scenarioFiles(join(root, 'scenarios'))).filter(
+    file => !options.filter || relative(root, file).includes(options.filter)
+  );
+  if (files.length === 0) {
+    throw new Error(`No scenarios/*.scen.json files to profile in ${root}`);
+  }
+
+  const profile: GasProfile = { calls: [], endpoints: [], failures: [] };
+  for (const file of files) {
+    options.onScenario?.(relative(root, file));
+    try {
+      await profileScenario(root, file, options, profile);
+    } catch (error) {
+      if (error instanceof CancelledError) throw error;
+      if ((error as NodeJS.ErrnoException).code === 'ENOENT') {
+        const runner = options.runner || DEFAULT_RUNNER;
+        throw new Error(`Scenario runner ${runner} not found; install it or pass its path`);
+      }
+      const message = error instanceof Error ? error.message : String(error);
+      profile.failures.push({ scenario: relative(root, file), message });
+    }
+  }
+  profile.endpoints = summarizeGas(profile.calls);
+  return profile;
+}
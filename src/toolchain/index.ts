@@ -0,0 +1,14 @@
+/**
+ * Local Rust toolchain integration: builds and tests of contract projects on
+ * disk. Everything here spawns processes, so tools built on it are local-only.
+ */
+
+export * from './process.js';
+export * from './build.js';
+export * from './tests.js';
+export * from './coverage.js';
+export * from './verify.js';
+export * from './debug.js';
+export * from './gas.js';
+export * from './mutation.js';
+export * from './storage-benchmark.js';
@@ -0,0 +1,52 @@
+import { applyMutant, generateMutants } from './mutation.js';
+
+const SOURCE = `#![no_std]
+
+klever_sc::imports!();
+
+#[klever_sc::contract]
+pub trait Vault {
+    #[endpoint]
+    fn deposit(&self, amount: BigUint) {
+        // amount > 0 is checked below
+        require!(amount > 0u32, "amount (must) be > 0");
+        let queue: Vec<u8> = Vec::new();
+        if amount == self.cap().get() {
+            self.full().set(true);
+        }
+        self.total().update(|t| *t += &amount);
+        match queue.len() {
+            0 => {}
+            _ => {}
+        }
+    }
+
+    fn under_limit(&self) -> bool {
+        self.total().get() <= 10u32
+    }
+}
+`;
+
+describe('generateMutants', () => {
+  it('mutates comparisons, require! and compound assignments in contract functions', () => {
+    const mutants = generateMutants(SOURCE, 'src/lib.rs');
+    expect(
+      mutants.map(m => [m.line, m.function, m.operator, m.original, m.replacement])
+    ).toEqual([
+      [10, 'deposit', 'require', 'require!(amount > 0u32, "amount (must) be > 0");', ''],
+      [10, 'deposit', 'comparison', '>', '<='],
+      [12, 'deposit', 'comparison', '==', '!='],
+      [15, 'deposit', 'compound-assignment', '+=', '-='],
+      [23, 'under_limit', 'comparison', '<=', '>'],
+    ]);
+    const lines = (m: (typeof mutants)[number]) => applyMutant(SOURCE, m).split('\n');
+    expect(lines(mutants[0])[9]).toBe('        ');
+    expect(lines(mutants[1])[9]).toBe('        require!(amount <= 0u32, "amount (must) be > 0");');
+    expect(lines(mutants[3])[14]).toBe('        self.total().update(|t| *t -= &amount);');
+  });
+
+  it('selects operators and skips files without a contract trait', () => {
+    expect(generateMutants(SOURCE, 'src/lib.rs', ['compound-assignment'])).toHaveLength(1);
+    expect(generateMutants('pub struct Note { amount: u64 }\n', 'src/note.rs')).toEqual([]);
+  });
+});
@@ -0,0 +1,299 @@
+/**
+ * Mutation testing of contract logic. Mutants are made in the bodies of the
+ * contract and module trait functions: comparisons are negated, `require!`
+ * statements dropped and `+=`/`-=` swapped. Each one is written into a copy of
+ * the project under `target/klever-mutation` and the Rust tests are rerun; a
+ * mutant the tests still pass with (a survivor) is logic no test checks. Go
+ * VM tests run the built wasm, which mutants do not change, so they are left
+ * out of the copy.
+ */
+
+import { cp, readFile, rm, writeFile } from 'fs/promises';
+import { join, relative, resolve } from 'path';
+import { lineAt, maskNonCode } from '../analysis/source.js';
+import { listTraitFunctions } from '../codegen/index.js';
+import { debugManifest, projectSources } from './debug.js';
+import { runTests } from './tests.js';
+
+export type MutationOperator = 'comparison' | 'require' | 'compound-assignment';
+
+export interface Mutant {
+  /** Position in the list of mutants, from 1 */
+  id: number;
+  /** Source file relative to the project root */
+  file: string;
+  line: number;
+  /** Function the mutant is in */
+  function: string;
+  operator: MutationOperator;
+  /** Offset and length of the replaced text in the file */
+  offset: number;
+  length: number;
+  original: string;
+  replacement: string;
+}
+
+export type MutantStatus = 'killed' | 'survived' | 'timeout' | 'invalid';
+
+export interface MutantResult extends Mutant {
+  status: MutantStatus;
+  /** Source line before and after the mutation */
+  code: string;
+  mutated: string;
+  /** Tests that failed against the mutant */
+  failedTests?: string[];
+  durationMs: number;
+}
+
+export interface MutationOptions {
+  /** Only mutate files whose path contains this string */
+  file?: string;
+  /** Only mutate functions with these names */
+  functions?: string[];
+  operators?: MutationOperator[];
+  /** Most mutants to test (default: 50); the rest are counted as skipped */
+  maxMutants?: number;
+  /** Only run tests whose name contains this string */
+  filter?: string;
+  onLine?: (line: string) => void;
+  onPhase?: (phase: string, detail?: string) => void;
+}
+
+export interface MutationReport {
+  /** Unmutated test run: passed tests and how long it took */
+  baseline: { passed: number; durationMs: number };
+  /** Mutants found, tested and left out by `maxMutants` */
+  total: number;
+  tested: number;
+  skipped: number;
+  killed: number;
+  survived: number;
+  timedOut: number;
+  invalid: number;
+  /** Killed and timed-out mutants out of those that compiled, in percent */
+  score: number;
+  mutants: MutantResult[];
+}
+
+export const MUTATION_PHASES = ['copying project', 'baseline tests', 'testing mutants'];
+
+const DEFAULT_MAX_MUTANTS = 50;
+/** Negation of each comparison, so a mutant flips the outcome of the check */
+const NEGATED: Record<string, string> = {
+  '==': '!=',
+  '!=': '==',
+  '<': '>=',
+  '>=': '<',
+  '>': '<=',
+  '<=': '>',
+};
+/** Spaced operators only: `<` and `>` without spaces are generics */
+const COMPARISON = / (==|!=|<=|>=|<|>) /g;
+const COMPOUND = / (\+=|-=) /g;
+const REQUIRE = /\brequire!\s*\(/g;
+/** A mutant's tests get this many times the baseline run, at least `MIN_TIMEOUT_MS` */
+const TIMEOUT_FACTOR = 3;
+const MIN_TIMEOUT_MS = 60_000;
+
+/** Index of the parenthesis closing the one at `open` in masked source */
+function closingParen(masked: string, open: number): number {
+  let depth = 0;
+  for (let i = open; i < masked.length; i++) {
+    if (masked[i] === '(') depth++;
+    else if (masked[i] === ')' && --depth === 0) return i;
+  }
+  return -1;
+}
+
+/** The mutants of one source file, in source order */
+export function generateMutants(
+  source: string,
+  file: string,
+  operators: MutationOperator[] = ['comparison', 'require', 'compound-assignment']
+): Omit<Mutant, 'id'>[] {
+  let functions;
+  try {
+    functions = listTraitFunctions(source);
+  } catch {
+    // No contract or module trait
+    return [];
+  }
+  const masked = maskNonCode(source);
+  const mutants: Omit<Mutant, 'id'>[] = [];
+  const add = (fn: string, operator: MutationOperator, offset: number, original: string) => {
+    const replacement =
+      operator === 'require'
+        ? ''
+        : operator === 'comparison'
+          ? NEGATED[original]
+          : original === '+='
+            ? '-='
+            : '+=';
+    mutants.push({
+      file,
+      line: lineAt(source, offset),
+      function: fn,
+      operator,
+      offset,
+      length: original.length,
+      original,
+      replacement,
+    });
+  };
+
+  for (const fn of functions) {
+    if (fn.bodyStart === -1) continue;
+    const body = masked.slice(fn.bodyStart, fn.end);
+    const found: { operator: MutationOperator; offset: number; original: string }[] = [];
+    if (operators.includes('comparison')) {
+      for (const match of body.matchAll(COMPARISON)) {
+        found.push({ operator: 'comparison', offset: match.index! + 1, original: match[1] });
+      }
+    }
+    if (operators.includes('compound-assignment')) {
+      for (const match of body.matchAll(COMPOUND)) {
+        found.push({
+          operator: 'compound-assignment',
+          offset: match.index! + 1,
+          original: match[1],
+        });
+      }
+    }
+    if (operators.includes('require')) {
+      for (const match of body.matchAll(REQUIRE)) {
+        const start = fn.bodyStart + match.index!;
+        let end = closingParen(masked, start + match[0].length - 1);
+        if (end === -1) continue;
+        end++;
+        while (/\s/.test(masked[end] || '')) end++;
+        if (masked[end] === ';') end++;
+        found.push({
+          operator: 'require',
+          offset: match.index!,
+          original: source.slice(start, end),
+        });
+      }
+    }
+    found
+      .sort((a, b) => a.offset - b.offset)
+      .forEach(m => add(fn.name, m.operator, fn.bodyStart + m.offset, m.original));
+  }
+  return mutants;
+}
+
+/** `source` with `mutant` applied */
+export function applyMutant(
+  source: string,
+  mutant: Pick<Mutant, 'offset' | 'length' | 'replacement'>
+): string {
+  const { offset, length, replacement } = mutant;
+  return source.slice(0, offset) + replacement + source.slice(offset + length);
+}
+
+/** Text of line `line` (1-based) of `source`, trimmed */
+const lineText = (source: string, line: number) => (source.split('\n')[line - 1] || '').trim();
+
+/** Copy the project without build output, VCS data or Go VM tests, detached from its workspace */
+async function copyProject(root: string, work: string): Promise<void> {
+  await rm(work, { recursive: true, force: true });
+  await cp(root, work, {
+    recursive: true,
+    filter: path => {
+      const rel = relative(root, path).split('\\').join('/');
+      return !/^(target|\.git)(\/|$)/.test(rel) && !/^tests\/.*_go_test\.rs$/.test(rel);
+    },
+  });
+  const manifest = await readFile(join(root, 'Cargo.toml'), 'utf-8');
+  await writeFile(join(work, 'Cargo.toml'), debugManifest(manifest, root));
+}
+
+/** Mutate the contract at `projectPath` and report which mutants its tests kill */
+export async function mutationTest(
+  projectPath: string,
+  options: MutationOptions = {}
+): Promise<MutationReport> {
+  const root = resolve(projectPath);
+  const sources = await projectSources(root);
+  const all: Omit<Mutant, 'id'>[] = [];
+  for (const [file, text] of Object.entries(sources)) {
+    if (options.file && !file.includes(options.file)) continue;
+    all.push(
+      ...generateMutants(text, file, options.operators).filter(
+        m => !options.functions || options.functions.includes(m.function)
+      )
+    );
+  }
+  if (all.length === 0) {
+    throw new Error('No mutants: no comparisons, require! or +=/-= in the selected functions');
+  }
+  const maxMutants = options.maxMutants ?? DEFAULT_MAX_MUTANTS;
+  const mutants = all.slice(0, maxMutants).map((m, i) => ({ ...m, id: i + 1 }));
+
+  options.onPhase?.('copying project');
+  const work = join(root, 'target', 'klever-mutation');
+  await copyProject(root, work);
+
+  options.onPhase?.('baseline tests');
+  const baseline = await runTests(work, { filter: options.filter, onLine: options.onLine });
+  if (!baseline.success) {
+    const failed = baseline.tests.filter(t => t.status === 'failed').map(t => t.name);
+    throw new Error(
+      baseline.compileErrors.length > 0
+        ? 'The tests do not build; fix them with run_tests first'
+        : `Tests fail without mutations (${failed.join(', ') || 'see run_tests'}); fix them first`
+    );
+  }
+  const timeoutMs = Math.max(MIN_TIMEOUT_MS, baseline.durationMs * TIMEOUT_FACTOR);
+
+  const results: MutantResult[] = [];
+  for (const mutant of mutants) {
+    options.onPhase?.(
+      'testing mutants',
+      `${mutant.id}/${mutants.length} ${mutant.file}:${mutant.line}`
+    );
+    const original = sources[mutant.file];
+    const mutated = applyMutant(original, mutant);
+    const path = join(work, mutant.file);
+    await writeFile(path, mutated);
+    let run;
+    try {
+      run = await runTests(work, { filter: options.filter, onLine: options.onLine, timeoutMs });
+    } finally {
+      await writeFile(path, original);
+    }
+    const failedTests = run.tests.filter(t => t.status === 'failed').map(t => t.name);
+    const status: MutantStatus = run.timedOut
+      ? 'timeout'
+      : run.compileErrors.length > 0
+        ? 'invalid'
+        : run.success
+          ? 'survived'
+          : 'killed';
+    results.push({
+      ...mutant,
+      status,
+      code: lineText(original, mutant.line),
+      mutated: lineText(mutated, mutant.line),
+      ...(failedTests.length > 0 ? { failedTests } : {}),
+      durationMs: run.durationMs,
+    });
+  }
+
+  const count = (status: MutantStatus) => results.filter(r => r.status === status).length;
+  const killed = count('killed');
+  const survived = count('survived');
+  const timedOut = count('timeout');
+  const valid = killed + survived + timedOut;
+  return {
+    baseline: { passed: baseline.summary.passed, durationMs: baseline.durationMs },
+    total: all.length,
+    tested: results.length,
+    skipped: all.length - results.length,
+    killed,
+    survived,
+    timedOut,
+    invalid: count('invalid'),
+    score: valid === 0 ? 0 : Math.round(((killed + timedOut) / valid) * 1000) / 10,
+    mutants: results,
+  };
+}
@@ -0,0 +1,177 @@
+/**
+ * Subprocess runner for the Rust toolchain: runs a command without a shell,
+ * reports its output line by line as it arrives and keeps the tail for the
+ * tool response. Cancelling the tool call kills the command with its child
+ * processes (cargo's rustc, wasm-opt) and rejects with a CancelledError.
+ */
+
+import { spawn } from 'child_process';
+import { getConfig } from '../config/index.js';
+import { logger, withSpan } from '../logging/index.js';
+import { CancelledError, currentSignal } from '../utils/cancellation.js';
+
+export interface RunOptions {
+  cwd: string;
+  env?: NodeJS.ProcessEnv;
+  /** Called with every stdout/stderr line as it is printed */
+  onLine?: (line: string) => void;
+  /** Kill the process after this many milliseconds (default: 10 minutes) */
+  timeoutMs?: number;
+  /** Output lines kept in the result (default: 200) */
+  keepLines?: number;
+  /** Kill the process when this aborts (default: the signal of the current tool call) */
+  signal?: AbortSignal;
+}
+
+export interface RunResult {
+  command: string;
+  /** Exit code; null when the process was killed */
+  exitCode: number | null;
+  timedOut: boolean;
+  durationMs: number;
+  /** Every output line, stdout and stderr interleaved */
+  lines: string[];
+  /** Last `keepLines` lines of output */
+  output: string;
+}
+
+const DEFAULT_TIMEOUT_MS = 10 * 60 * 1000;
+/** Time a killed process gets to exit after SIGTERM before it gets SIGKILL */
+const KILL_GRACE_MS = 5000;
+const PROCESS_GROUPS = process.platform !== 'win32';
+
+/** Klever SDK directory: `keys.sdk_dir`, honouring KLEVER_SDK_PATH like generated scripts do */
+export function kleverSdkPath(): string {
+  return getConfig().keys.sdkDir;
+}
+
+/** Path of the ksc binary (Klever's sc-meta): `keys.ksc`, honouring KSC_BIN */
+export function kscPath(): string {
+  return getConfig().keys.ksc;
+}
+
+/**
+ * An `onLine` callback that also reports the phase a line starts, as told by
+ * `detect`, once per change of phase; `current` is the phase already reported
+ */
+export function phaseLines(
+  detect: (line: string) => string | undefined,
+  onLine?: (line: string) => void,
+  onPhase?: (phase: string) => void,
+  current?: string
+): (line: string) => void {
+  return line => {
+    const phase = detect(line);
+    if (phase && phase !== current) {
+      current = phase;
+      onPhase?.(phase);
+    }
+    onLine?.(line);
+  };
+}
+
+/**
+ * Run `command` with `args` and resolve once it exits; spawn failures reject.
+ * Runs in a `subprocess` log span; a failed run logs the tail of its output.
+ */
+export function runCommand(
+  command: string,
+  args: string[],
+  options: RunOptions
+): Promise<RunResult> {
+  const commandLine = [command, ...args].join(' ');
+  return withSpan('subprocess', { command: commandLine, cwd: options.cwd }, async () => {
+    const result = await spawnCommand(command, args, options);
+    if (result.exitCode !== 0) {
+      logger.warn(`Command failed: ${commandLine}`, {
+        exitCode: result.exitCode,
+        timedOut: result.timedOut,
+        output: result.lines.slice(-20).join('\n'),
+      });
+    }
+    return result;
+  });
+}
+
+function spawnCommand(command: string, args: string[], options: RunOptions): Promise<RunResult> {
+  const { cwd, env = process.env, onLine, timeoutMs = DEFAULT_TIMEOUT_MS } = options;
+  const signal = options.signal ?? currentSignal();
+  const keepLines = options.keepLines ?? 200;
+  const started = Date.now();
+  const lines: string[] = [];
+
+  return new Promise((resolve, reject) => {
+    if (signal?.aborted) return reject(new CancelledError());
+    // In its own process group, so killing it also kills the processes it started
+    const child = spawn(command, args, {
+      cwd,
+      env,
+      stdio: ['ignore', 'pipe', 'pipe'],
+      detached: PROCESS_GROUPS,
+    });
+    let killTimer: NodeJS.Timeout | undefined;
+    const kill = () => {
+      if (killTimer || child.exitCode !== null || child.signalCode !== null) return;
+      sendSignal(child.pid, 'SIGTERM', () => child.kill('SIGTERM'));
+      killTimer = setTimeout(
+        () => sendSignal(child.pid, 'SIGKILL', () => child.kill('SIGKILL')),
+        KILL_GRACE_MS
+      );
+    };
+    let timedOut = false;
+    const timer = setTimeout(() => {
+      timedOut = true;
+      kill();
+    }, timeoutMs);
+    signal?.addEventListener('abort', kill, { once: true });
+    const cleanUp = () => {
+      clearTimeout(timer);
+      clearTimeout(killTimer);
+      signal?.removeEventListener('abort', kill);
+    };
+
+    const pending = { stdout: '', stderr: '' };
+    const collect = (stream: 'stdout' | 'stderr') => (chunk: Buffer) => {
+      const parts = (pending[stream] + chunk.toString('utf8')).split(/\r?\n/);
+      pending[stream] = parts.pop()!;
+      for (const line of parts) {
+        lines.push(line);
+        onLine?.(line);
+      }
+    };
+    child.stdout.on('data', collect('stdout'));
+    child.stderr.on('data', collect('stderr'));
+
+    child.on('error', error => {
+      cleanUp();
+      reject(error);
+    });
+    child.on('close', exitCode => {
+      cleanUp();
+      if (signal?.aborted) return reject(new CancelledError());
+      for (const rest of [pending.stdout, pending.stderr]) {
+        if (!rest) continue;
+        lines.push(rest);
+        onLine?.(rest);
+      }
+      resolve({
+        command: [command, ...args].join(' '),
+        exitCode,
+        timedOut,
+        durationMs: Date.now() - started,
+        lines,
+        output: lines.slice(-keepLines).join('\n'),
+      });
+    });
+  });
+}
+
+/** Send `signal` to the process group of `pid`, or to the process alone without groups */
+function sendSignal(pid: number | undefined, signal: NodeJS.Signals, fallback: () => void): void {
+  if (!PROCESS_GROUPS || pid === undefined) return fallback();
+  try {
+    process.kill(-pid, signal);
+  } catch {
+    fallback();
+  }
+}
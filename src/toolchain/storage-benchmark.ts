@@ -0,0 +1,86 @@
+/**
+ * Storage mapper benchmarks on the Go VM: the benchmark contract of
+ * `storageBenchmark` is written to a work directory, built, and its scenario
+ * profiled call by call. The work directory is kept between runs so later
+ * benchmarks only rebuild the contract crate.
+ */
+
+import { mkdir, readFile, rm, writeFile } from 'fs/promises';
+import { tmpdir } from 'os';
+import { dirname, join, resolve } from 'path';
+import {
+  storageBenchmark,
+  storageBenchmarkResult,
+  type MapperName,
+  type StorageBenchmarkResult,
+  type StorageWorkload,
+} from '../codegen/index.js';
+import { kleverScVersion } from '../scaffold/framework-version.js';
+import { BUILD_PHASES, buildContract } from './build.js';
+import { profileGas, type GasProfile } from './gas.js';
+
+export interface StorageBenchmarkOptions extends StorageWorkload {
+  /** Project whose klever-sc version to benchmark; the work directory goes in its target/ */
+  projectPath?: string;
+  /** Go VM scenario runner (default: `run-scenarios`) */
+  runner?: string;
+  onLine?: (line: string) => void;
+  /** Called when one of the `STORAGE_BENCHMARK_PHASES` starts */
+  onPhase?: (phase: string) => void;
+}
+
+export interface StorageBenchmarkRun extends StorageBenchmarkResult {
+  frameworkVersion: string;
+  /** Work directory with the benchmark contract and scenario */
+  workDir: string;
+  /** Steps the runner could not measure */
+  failures: GasProfile['failures'];
+}
+
+export const STORAGE_BENCHMARK_PHASES = ['generating contract', ...BUILD_PHASES, 'measuring'];
+
+/** Build the benchmark contract for `mappers` and measure the gas of each operation */
+export async function benchmarkStorage(
+  mappers: MapperName[],
+  options: StorageBenchmarkOptions = {}
+): Promise<StorageBenchmarkRun> {
+  let frameworkVersion: string | undefined;
+  let workDir = join(tmpdir(), 'klever-storage-benchmark');
+  if (options.projectPath) {
+    const root = resolve(options.projectPath);
+    frameworkVersion = kleverScVersion(await readFile(join(root, 'Cargo.toml'), 'utf-8'));
+    workDir = join(root, 'target', 'klever-storage-benchmark');
+  }
+
+  options.onPhase?.('generating contract');
+  const plan = storageBenchmark(mappers, options, frameworkVersion);
+  // Scenarios of earlier benchmarks would be profiled too
+  await rm(join(workDir, 'scenarios'), { recursive: true, force: true });
+  for (const [path, content] of Object.entries(plan.files)) {
+    await mkdir(dirname(join(workDir, path)), { recursive: true });
+    await writeFile(join(workDir, path), content);
+  }
+
+  const build = await buildContract(workDir, { onLine: options.onLine, onPhase: options.onPhase });
+  if (!build.success) {
+    const errors = build.errors.filter(e => e.level === 'error').slice(0, 3);
+    throw new Error(
+      `The benchmark contract did not build with klever-sc ${plan.frameworkVersion}: ` +
+        (errors.map(e => `${e.message} (${e.location})`).join('; ') || 'see the build output')
+    );
+  }
+
+  options.onPhase?.('measuring');
+  const profile = await profileGas(workDir, { runner: options.runner, onLine: options.onLine });
+  if (profile.calls.length === 0) {
+    throw new Error(
+      `No call was measured: ${profile.failures.map(f => f.message).join('; ') || 'no scenario'}`
+    );
+  }
+  return {
+    frameworkVersion: plan.frameworkVersion,
+    workDir,
+    ...storageBenchmarkResult(plan, profile.calls),
+    failures: profile.failures,
+  };
+}
@@ -0,0 +1,58 @@
+import { parseTestOutput } from './tests.js';
+
+const CARGO_TEST_OUTPUT = `   Compiling vault v0.0.0 (/work/vault)
+    Finished \`test\` profile [unoptimized + debuginfo] target(s) in 4.20s
+     Running unittests src/lib.rs (target/debug/deps/vault-1a2b)
+
+running 0 tests
+
+test result: ok. 0 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out
+
+     Running tests/vault_blackbox_test.rs (target/debug/deps/vault_blackbox_test-3c4d)
+
+running 2 tests
+test deposit_works ... ok
+test withdraw_too_much ... ignored, needs fixture
+
+test result: ok. 1 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out
+
+     Running tests/vault_scenario_rs_test.rs (target/debug/deps/vault_scenario_rs_test-5e6f)
+
+running 2 tests
+test deposit_rs ... ok
+test withdraw_rs ... FAILED
+
+failures:
+
+---- withdraw_rs stdout ----
+
+thread 'withdraw_rs' panicked at tests/vault_scenario_rs_test.rs:18:5:
+Check state - Account: address:owner, Balance mismatch. Want: "100". Have: "90"
+note: run with \`RUST_BACKTRACE=1\` environment variable to display a backtrace
+
+
+failures:
+    withdraw_rs
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out
+
+error: test failed, to rerun pass \`--test vault_scenario_rs_test\``;
+
+describe('parseTestOutput', () => {
+  it('attributes results to their target and keeps failure messages', () => {
+    const tests = parseTestOutput(CARGO_TEST_OUTPUT.split('\n'));
+    expect(tests.map(t => [t.name, t.kind, t.status])).toEqual([
+      ['deposit_works', 'rust', 'passed'],
+      ['withdraw_too_much', 'rust', 'ignored'],
+      ['deposit_rs', 'scenario', 'passed'],
+      ['withdraw_rs', 'scenario', 'failed'],
+    ]);
+    expect(tests[3].suite).toBe('tests/vault_scenario_rs_test.rs');
+    expect(tests[3].message).toBe(
+      `thread 'withdraw_rs' panicked at tests/vault_scenario_rs_test.rs:18:5:
+Check state - Account: address:owner, Balance mismatch. Want: "100". Have: "90"
+note: run with \`RUST_BACKTRACE=1\` environment variable to display a backtrace`
+    );
+    expect(tests[0].message).toBeUndefined();
+  });
+});
@@ -0,0 +1,192 @@
+/**
+ * Contract tests through cargo: scenario tests (the `tests/*scenario*.rs`
+ * targets that replay `scenarios/*.scen.json` steps) and plain Rust
+ * integration tests, with libtest output parsed into per-test results; with
+ * coverage, the same tests run under `cargo llvm-cov` (see coverage.ts).
+ */
+
+import { access, mkdir, readdir, rm } from 'fs/promises';
+import { basename, join, resolve } from 'path';
+import { parseCompilerMessages, type CompilerMessage } from './build.js';
+import { coverageReport, type CoverageReport } from './coverage.js';
+import { phaseLines, runCommand } from './process.js';
+
+export type TestKind = 'scenario' | 'rust';
+
+export interface TestOptions {
+  /** Which tests to run (default: all, including unit and doc tests) */
+  kind?: TestKind | 'all';
+  /** Only run tests whose name contains this string */
+  filter?: string;
+  /** Run under `cargo llvm-cov` and report line coverage of the contract sources */
+  coverage?: boolean;
+  /** With `coverage`, also report branch coverage (needs a nightly toolchain) */
+  branches?: boolean;
+  /** Called with every line of cargo output as it is printed */
+  onLine?: (line: string) => void;
+  /** Called when one of the `TEST_PHASES` starts */
+  onPhase?: (phase: string) => void;
+  timeoutMs?: number;
+}
+
+export const TEST_PHASES = ['compiling', 'running tests'];
+
+/** The test run phase a line of cargo output starts, if any */
+export function testPhase(line: string): string | undefined {
+  if (/^\s*Compiling /.test(line)) return 'compiling';
+  if (/^\s*Running /.test(line)) return 'running tests';
+  return undefined;
+}
+
+export interface TestCase {
+  name: string;
+  /** Test target the case belongs to, e.g. `tests/vault_scenario_rs_test.rs` */
+  suite: string;
+  kind: TestKind;
+  status: 'passed' | 'failed' | 'ignored';
+  /** Panic message and captured output of a failed test */
+  message?: string;
+}
+
+export interface TestRunResult {
+  success: boolean;
+  command: string;
+  exitCode: number | null;
+  timedOut: boolean;
+  durationMs: number;
+  summary: { passed: number; failed: number; ignored: number };
+  tests: TestCase[];
+  /** Compiler errors when the tests did not build */
+  compileErrors: CompilerMessage[];
+  /** Tail of the cargo output */
+  output: string;
+  /** Per-function coverage, when run with `coverage` and the tests built */
+  coverage?: CoverageReport;
+}
+
+const MAX_MESSAGE_LINES = 30;
+
+function isScenarioSuite(suite: string): boolean {
+  return /scenario/i.test(basename(suite));
+}
+
+/** Per-test results from `cargo test` output, attributed to the target that ran them */
+export function parseTestOutput(lines: string[]): TestCase[] {
+  const tests: TestCase[] = [];
+  let suite = '';
+
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i];
+    const running = /^\s*(?:Running (?:unittests )?|Doc-tests )(\S+)/.exec(line);
+    if (running) {
+      suite = running[1];
+      continue;
+    }
+
+    const result = /^test (\S+) \.\.\. (ok|FAILED|ignored)/.exec(line);
+    if (result) {
+      tests.push({
+        name: result[1],
+        suite,
+        kind: isScenarioSuite(suite) ? 'scenario' : 'rust',
+        status: result[2] === 'ok' ? 'passed' : result[2] === 'FAILED' ? 'failed' : 'ignored',
+      });
+      continue;
+    }
+
+    const failure = /^---- (\S+) stdout ----$/.exec(line);
+    if (failure) {
+      const body: string[] = [];
+      while (i + 1 < lines.length && !/^(---- \S+ stdout ----|failures:)$/.test(lines[i + 1])) {
+        body.push(lines[++i]);
+      }
+      const test = tests.find(t => t.suite === suite && t.name === failure[1]);
+      const message = body.join('\n').trim().split('\n');
+      if (test) test.message = message.slice(0, MAX_MESSAGE_LINES).join('\n');
+    }
+  }
+  return tests;
+}
+
+async function testTargets(root: string): Promise<string[]> {
+  try {
+    await access(join(root, 'tests'));
+  } catch {
+    return [];
+  }
+  return (await readdir(join(root, 'tests')))
+    .filter(name => name.endsWith('.rs'))
+    .map(name => name.slice(0, -'.rs'.length))
+    .sort();
+}
+
+/** Run the tests of the contract project at `projectPath` */
+export async function runTests(
+  projectPath: string,
+  options: TestOptions = {}
+): Promise<TestRunResult> {
+  const root = resolve(projectPath);
+  const kind = options.kind || 'all';
+  const lcovPath = join(root, 'target', 'coverage', 'lcov.info');
+  // --ignore-run-fail still writes the report when tests fail
+  const args = options.coverage
+    ? ['llvm-cov', '--ignore-run-fail', '--lcov', '--output-path', lcovPath]
+    : ['test', '--no-fail-fast'];
+  if (options.coverage && options.branches) args.push('--branch');
+
+  if (kind !== 'all') {
+    const targets = (await testTargets(root)).filter(
+      name => isScenarioSuite(name) === (kind === 'scenario')
+    );
+    if (kind === 'scenario' && targets.length === 0) {
+      throw new Error(`No scenario test targets (tests/*scenario*.rs) in ${root}`);
+    }
+    if (kind === 'rust') args.push('--lib');
+    for (const target of targets) args.push('--test', target);
+  }
+  args.push('--', ...(options.filter ? [options.filter] : []), '--color', 'never');
+
+  if (options.coverage) {
+    await mkdir(join(root, 'target', 'coverage'), { recursive: true });
+    await rm(lcovPath, { force: true });
+  }
+  options.onPhase?.('compiling');
+  const run = await runCommand('cargo', args, {
+    cwd: root,
+    env: { ...process.env, CARGO_TERM_COLOR: 'never' },
+    onLine: phaseLines(testPhase, options.onLine, options.onPhase, 'compiling'),
+    timeoutMs: options.timeoutMs,
+  });
+
+  const tests = parseTestOutput(run.lines);
+  const summary = { passed: 0, failed: 0, ignored: 0 };
+  for (const test of tests) summary[test.status]++;
+  const compileErrors = parseCompilerMessages(run.lines).filter(m => m.level === 'error');
+  if (options.coverage && run.lines.some(line => /no such command: `llvm-cov`/.test(line))) {
+    throw new Error(
+      'Coverage needs cargo-llvm-cov: cargo install cargo-llvm-cov && rustup component add llvm-tools-preview'
+    );
+  }
+
+  let coverage: CoverageReport | undefined;
+  if (options.coverage && compileErrors.length === 0) {
+    try {
+      coverage = await coverageReport(root, lcovPath);
+    } catch {
+      // No report: the run was killed or failed before tests ran
+    }
+  }
+
+  return {
+    success: run.exitCode === 0 && summary.failed === 0,
+    command: run.command,
+    exitCode: run.exitCode,
+    timedOut: run.timedOut,
+    durationMs: run.durationMs,
+    summary,
+    tests,
+    compileErrors,
+    output: run.output,
+    ...(coverage ? { coverage } : {}),
+  };
+}
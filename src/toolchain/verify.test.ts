@@ -0,0 +1,56 @@
+import type { BuildArtifact } from './build.js';
+import { codeHash, reproducibleEnv, selectWasm } from './verify.js';
+
+const artifact = (path: string): BuildArtifact => ({
+  kind: path.endsWith('.wasm') ? 'wasm' : 'abi',
+  path,
+  size: 1,
+  sha256: '',
+});
+
+describe('codeHash', () => {
+  it('hashes the wasm with BLAKE2b-256', () => {
+    expect(codeHash(Buffer.from('0061736d01000000', 'hex'))).toBe(
+      'f6a5dbf080e9c9d7834145653bce4c8cded62e664d7ddcdb5c526f5877006d74'
+    );
+  });
+});
+
+describe('reproducibleEnv', () => {
+  it('remaps the project and cargo paths and keeps inherited rustflags', () => {
+    const env = reproducibleEnv('/home/dev/my vault', {
+      PATH: '/usr/bin',
+      CARGO_HOME: '/home/dev/.cargo',
+      RUSTFLAGS: '-C link-arg=-s',
+    });
+    expect(env.PATH).toBe('/usr/bin');
+    expect(env.RUSTFLAGS).toBeUndefined();
+    expect(env.CARGO_INCREMENTAL).toBe('0');
+    expect(env.CARGO_ENCODED_RUSTFLAGS!.split('\x1f')).toEqual([
+      '-C',
+      'link-arg=-s',
+      '--remap-path-prefix=/home/dev/my vault=/contract',
+      '--remap-path-prefix=/home/dev/.cargo=/cargo',
+    ]);
+  });
+});
+
+describe('selectWasm', () => {
+  const artifacts = [
+    artifact('/p/output/vault.abi.json'),
+    artifact('/p/output/vault.wasm'),
+    artifact('/p/output/vault-view.wasm'),
+  ];
+
+  it('picks the named contract or the only wasm', () => {
+    expect(selectWasm(artifacts, 'vault').path).toBe('/p/output/vault.wasm');
+    expect(selectWasm(artifacts.slice(0, 2)).path).toBe('/p/output/vault.wasm');
+  });
+
+  it('asks for a contract name when the build produced several', () => {
+    expect(() => selectWasm(artifacts)).toThrow(
+      'several wasm files (vault.wasm, vault-view.wasm); pass contract'
+    );
+    expect(() => selectWasm(artifacts, 'other')).toThrow('no other.wasm');
+  });
+});
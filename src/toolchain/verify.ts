@@ -0,0 +1,145 @@
+/**
+ * Reproducible-build check: rebuild a contract with the machine-specific
+ * inputs removed (absolute source paths, incremental state), hash the wasm
+ * the way the VM does and compare it with the code hash of a deployed contract.
+ */
+
+import { access, readFile } from 'fs/promises';
+import { homedir } from 'os';
+import { basename, join, resolve } from 'path';
+import { blake2b } from '../chain/blake2b.js';
+import { buildContract, type BuildArtifact, type BuildOptions, type BuildResult } from './build.js';
+import { runCommand } from './process.js';
+
+export interface VerifyOptions extends Omit<BuildOptions, 'env'> {
+  /** Contract to compare (output/<contract>.wasm) when the project builds several */
+  contract?: string;
+}
+
+export interface ToolchainInfo {
+  /** `rustc --version` in the project directory */
+  rustc?: string;
+  /** Contents of rust-toolchain.toml / rust-toolchain, when the project pins one */
+  pinned?: string;
+  lockfile: boolean;
+}
+
+export interface VerifyResult {
+  match: boolean;
+  deployedCodeHash: string;
+  /** The rebuilt wasm and its code hash; missing when the build failed */
+  local?: BuildArtifact & { codeHash: string };
+  toolchain: ToolchainInfo;
+  /** Reasons the rebuild may not be reproducible */
+  warnings: string[];
+  build: BuildResult;
+}
+
+/** Code hash of a contract as the VM computes it: BLAKE2b-256 of the wasm, hex */
+export function codeHash(wasm: Uint8Array): string {
+  return Buffer.from(blake2b(wasm, 32)).toString('hex');
+}
+
+/**
+ * Build environment without machine-specific paths: the project root and the
+ * cargo home are remapped in panic locations and debug info, and incremental
+ * compilation is off. Uses CARGO_ENCODED_RUSTFLAGS so paths may contain spaces.
+ */
+export function reproducibleEnv(root: string, base: NodeJS.ProcessEnv = process.env) {
+  const cargoHome = base.CARGO_HOME || join(homedir(), '.cargo');
+  const inherited = base.CARGO_ENCODED_RUSTFLAGS
+    ? base.CARGO_ENCODED_RUSTFLAGS.split('\x1f')
+    : (base.RUSTFLAGS || '').split(/\s+/).filter(Boolean);
+  const flags = [
+    ...inherited,
+    `--remap-path-prefix=${root}=/contract`,
+    `--remap-path-prefix=${cargoHome}=/cargo`,
+  ];
+  const env: NodeJS.ProcessEnv = {
+    ...base,
+    CARGO_ENCODED_RUSTFLAGS: flags.join('\x1f'),
+    CARGO_INCREMENTAL: '0',
+    SOURCE_DATE_EPOCH: '0',
+  };
+  delete env.RUSTFLAGS;
+  return env;
+}
+
+/** The wasm to compare: `<contract>.wasm`, or the only one the build produced */
+export function selectWasm(artifacts: BuildArtifact[], contract?: string): BuildArtifact {
+  const wasms = artifacts.filter(a => a.kind === 'wasm');
+  const names = wasms.map(a => basename(a.path)).join(', ') || 'none';
+  if (contract) {
+    const found = wasms.find(a => basename(a.path) === `${contract}.wasm`);
+    if (!found) throw new Error(`The build produced no ${contract}.wasm (built: ${names}).`);
+    return found;
+  }
+  if (wasms.length === 1) return wasms[0];
+  if (wasms.length === 0) throw new Error('The build produced no .wasm file.');
+  throw new Error(`The build produced several wasm files (${names}); pass contract to pick one.`);
+}
+
+async function readOptional(path: string): Promise<string | undefined> {
+  try {
+    return (await readFile(path, 'utf8')).trim();
+  } catch {
+    return undefined;
+  }
+}
+
+async function toolchainInfo(root: string): Promise<ToolchainInfo> {
+  const pinned =
+    (await readOptional(join(root, 'rust-toolchain.toml'))) ??
+    (await readOptional(join(root, 'rust-toolchain')));
+  const lockfile = await access(join(root, 'Cargo.lock')).then(
+    () => true,
+    () => false
+  );
+  let rustc: string | undefined;
+  try {
+    const run = await runCommand('rustc', ['--version'], { cwd: root, timeoutMs: 60_000 });
+    if (run.exitCode === 0) rustc = run.lines.find(Boolean);
+  } catch {
+    rustc = undefined;
+  }
+  return { rustc, pinned, lockfile };
+}
+
+/** Rebuild the project at `projectPath` and compare its wasm with `deployedCodeHash` (hex) */
+export async function verifyBuild(
+  projectPath: string,
+  deployedCodeHash: string,
+  options: VerifyOptions = {}
+): Promise<VerifyResult> {
+  const root = resolve(projectPath);
+  const toolchain = await toolchainInfo(root);
+  const warnings: string[] = [];
+  if (!toolchain.lockfile) {
+    warnings.push(
+      'No Cargo.lock: dependency versions are resolved at build time and may differ from the deployed build.'
+    );
+  }
+  if (!toolchain.pinned) {
+    warnings.push(
+      'No rust-toolchain.toml: the compiler version is whatever is installed; pin the one used for the deployed build.'
+    );
+  }
+
+  const { contract, ...buildOptions } = options;
+  const build = await buildContract(root, { ...buildOptions, env: reproducibleEnv(root) });
+  const expected = deployedCodeHash.replace(/^0x/, '').toLowerCase();
+  if (!build.success) {
+    return { match: false, deployedCodeHash: expected, toolchain, warnings, build };
+  }
+
+  const artifact = selectWasm(build.artifacts, contract);
+  const local = { ...artifact, codeHash: codeHash(await readFile(artifact.path)) };
+  return {
+    match: local.codeHash === expected,
+    deployedCodeHash: expected,
+    local,
+    toolchain,
+    warnings,
+    build,
+  };
+}
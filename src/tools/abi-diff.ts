@@ -0,0 +1,62 @@
+import type { KleverTool } from './types.js';
+import { diffAbi } from '../analysis/index.js';
+import { loadAbi } from './abi-input.js';
+
+export const abiDiffTool: KleverTool = {
+  definition: {
+    name: 'abi_diff',
+    description:
+      'Compare two klever-sc ABI files (e.g. the on-chain verified ABI against a local build in output/<name>.abi.json) before an upgrade. Reports removed or added endpoints and views, changed argument and result types, owner-only/payable/readonly changes, removed, renamed or reshaped events and changed custom types. Each change is classified as breaking (existing callers, dApps or indexers stop working), additive or compatible. Pass each ABI as JSON or as a URL to fetch it from.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        oldAbi: {
+          type: 'string',
+          description: 'ABI JSON of the currently deployed version',
+        },
+        oldAbiUrl: {
+          type: 'string',
+          description: 'URL to fetch the deployed ABI from (used when oldAbi is not given)',
+        },
+        newAbi: {
+          type: 'string',
+          description: 'ABI JSON of the new build',
+        },
+        newAbiUrl: {
+          type: 'string',
+          description: 'URL to fetch the new ABI from (used when newAbi is not given)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Diff Contract ABIs',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { oldAbi, oldAbiUrl, newAbi, newAbiUrl } = args as {
+      oldAbi?: string;
+      oldAbiUrl?: string;
+      newAbi?: string;
+      newAbiUrl?: string;
+    };
+    const [before, after] = await Promise.all([
+      loadAbi(oldAbi, oldAbiUrl, context.profile, 'oldAbiUrl'),
+      loadAbi(newAbi, newAbiUrl, context.profile, 'newAbiUrl'),
+    ]);
+    const diff = diffAbi(before, after);
+    return {
+      ...diff,
+      nextSteps: diff.compatible
+        ? ['No breaking changes; existing callers keep working after the upgrade']
+        : [
+            'Breaking changes need coordinated updates to dApps, scripts and indexers',
+            'Keep removed endpoints as deprecated wrappers, or add new arguments as optional<T>, to stay compatible',
+            'Run generate_migration if storage mappers changed as well',
+          ],
+    };
+  },
+};
@@ -0,0 +1,48 @@
+import { jest } from '@jest/globals';
+import { loadAbi } from './abi-input.js';
+
+const originalFetch = global.fetch;
+const mockFetch = jest.fn<typeof fetch>();
+global.fetch = mockFetch;
+
+afterAll(() => {
+  global.fetch = originalFetch;
+});
+
+const ABI_JSON = JSON.stringify({
+  name: 'Adder',
+  endpoints: [{ name: 'getSum', mutability: 'readonly', inputs: [], outputs: [] }],
+});
+
+describe('loadAbi', () => {
+  beforeEach(() => {
+    mockFetch.mockReset();
+  });
+
+  it('parses inline ABI JSON without fetching', async () => {
+    const abi = await loadAbi(ABI_JSON, 'https://example.com/adder.abi.json', 'local');
+
+    expect(abi.name).toBe('Adder');
+    expect(abi.endpoints.map(e => e.name)).toEqual(['getSum']);
+    expect(mockFetch).not.toHaveBeenCalled();
+  });
+
+  it('fetches and parses the ABI from a URL', async () => {
+    mockFetch.mockResolvedValueOnce(new Response(ABI_JSON, { status: 200 }));
+
+    const abi = await loadAbi(undefined, 'https://example.com/adder.abi.json', 'public');
+
+    expect(abi.name).toBe('Adder');
+    expect(String(mockFetch.mock.calls[0][0])).toBe('https://example.com/adder.abi.json');
+  });
+
+  it('rejects a missing ABI and internal URLs in public mode', async () => {
+    await expect(loadAbi(undefined, undefined, 'local', 'oldAbiUrl')).rejects.toThrow(
+      'Provide either oldAbi (JSON) or oldAbiUrl.'
+    );
+    await expect(loadAbi(undefined, 'http://localhost/abi.json', 'public')).rejects.toThrow(
+      'public hostname'
+    );
+    expect(mockFetch).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,76 @@
+/**
+ * ABI input shared by tools that accept either ABI JSON or a URL to fetch it from.
+ */
+
+import { parseAbi, type Abi } from '../abi/index.js';
+import type { ServerProfile } from '../mcp/server.js';
+import { cancellable } from '../utils/cancellation.js';
+
+const FETCH_TIMEOUT_MS = 15000;
+
+/** Public deployments must not be usable to reach internal hosts */
+function checkAbiUrl(url: string, profile: ServerProfile, field: string): URL {
+  let parsed: URL;
+  try {
+    parsed = new URL(url);
+  } catch {
+    throw new Error(`Invalid ${field}: ${url}`);
+  }
+  if (parsed.protocol !== 'https:' && parsed.protocol !== 'http:') {
+    throw new Error(`${field} must use http or https.`);
+  }
+  if (profile === 'public') {
+    const host = parsed.hostname;
+    if (
+      parsed.protocol !== 'https:' ||
+      host === 'localhost' ||
+      /^[\d.]+$/.test(host) ||
+      host.includes(':') ||
+      host.endsWith('.local') ||
+      host.endsWith('.internal')
+    ) {
+      throw new Error(`In public mode ${field} must be an https URL with a public hostname.`);
+    }
+  }
+  return parsed;
+}
+
+async function fetchAbi(url: URL): Promise<string> {
+  const response = await cancellable(
+    fetch(url, {
+      headers: { Accept: 'application/json' },
+      signal: AbortSignal.timeout(FETCH_TIMEOUT_MS),
+    })
+  );
+  if (!response.ok) {
+    throw new Error(`Fetching ABI failed: HTTP ${response.status} ${response.statusText}`);
+  }
+  return response.text();
+}
+
+/**
+ * Return the ABI JSON passed inline, or fetch it from `url`.
+ * `field` names the URL argument in error messages (e.g. `oldAbiUrl`).
+ */
+export async function loadAbiJson(
+  json: string | undefined,
+  url: string | undefined,
+  profile: ServerProfile,
+  field = 'abiUrl'
+): Promise<string> {
+  if (json) return json;
+  if (!url) {
+    throw new Error(`Provide either ${field.replace(/Url$/, '')} (JSON) or ${field}.`);
+  }
+  return fetchAbi(checkAbiUrl(url, profile, field));
+}
+
+/** `loadAbiJson` parsed into the typed ABI model */
+export async function loadAbi(
+  json: string | undefined,
+  url: string | undefined,
+  profile: ServerProfile,
+  field = 'abiUrl'
+): Promise<Abi> {
+  return parseAbi(await loadAbiJson(json, url, profile, field));
+}
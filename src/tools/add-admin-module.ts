@@ -0,0 +1,56 @@
+import type { KleverTool } from './types.js';
+import { addAdminModule, type AdminModuleOptions } from '../codegen/index.js';
+
+export const addAdminModuleTool: KleverTool = {
+  definition: {
+    name: 'add_admin_module',
+    description:
+      'Inject a standard AdminModule (src/admin.rs) into an existing klever-sc contract: two-step ownership transfer (transferOwnership/acceptOwnership/cancelOwnershipTransfer), pause/unpause with a require_not_paused() guard, and fee_bps storage with an owner-only setFee bounded by MAX_FEE_BPS plus a compute_fee() helper. The contract\'s own owner mapper and require_owner() helper are replaced by the module versions (the owner storage key is kept), init() records the deployer as owner, and public endpoints get the pause guard. Returns the rewritten files; nothing is written to disk.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        source: {
+          type: 'string',
+          description:
+            'Full contract source (src/lib.rs) containing the #[klever_sc::contract] trait',
+        },
+        pause: {
+          type: 'boolean',
+          description: 'Include pause/unpause (default: true)',
+        },
+        fee: {
+          type: 'boolean',
+          description: 'Include fee storage and setFee (default: true)',
+        },
+        maxFeeBps: {
+          type: 'number',
+          description: 'Highest fee setFee accepts, in basis points (default: 1000 = 10%)',
+        },
+        guardEndpoints: {
+          type: 'boolean',
+          description:
+            'Add require_not_paused() to public endpoints that are not owner-only (default: true)',
+        },
+      },
+      required: ['source'],
+    },
+    annotations: {
+      title: 'Add Admin Module',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { source, ...options } = args as { source: string } & AdminModuleOptions;
+    const result = addAdminModule(source, options);
+    return {
+      ...result,
+      nextSteps: [
+        'Write src/admin.rs and replace src/lib.rs with the returned files',
+        'Rebuild with: ~/klever-sdk/ksc all build',
+      ],
+    };
+  },
+};
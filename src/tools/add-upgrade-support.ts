@@ -0,0 +1,47 @@
+import type { KleverTool } from './types.js';
+import { addUpgradeSupport } from '../codegen/index.js';
+
+export const addUpgradeSupportTool: KleverTool = {
+  definition: {
+    name: 'add_upgrade_support',
+    description:
+      'Augment an existing klever-sc contract with an upgrade story: a SCHEMA_VERSION constant, schema_version storage (SingleValueMapper<u32>), an #[upgrade] endpoint calling a guarded, step-wise migrate() skeleton, and init() recording the version. Re-run with a higher targetVersion to add migration steps. Returns the modified source; nothing is written to disk.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        source: {
+          type: 'string',
+          description:
+            'Full contract source (src/lib.rs) containing the #[klever_sc::contract] trait',
+        },
+        targetVersion: {
+          type: 'number',
+          description: 'Schema version after the upgrade (default: 1, or current + 1 when bumping)',
+        },
+      },
+      required: ['source'],
+    },
+    annotations: {
+      title: 'Add Upgrade Support',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { source, targetVersion } = args as { source: string; targetVersion?: number };
+    const current = /pub\s+const\s+SCHEMA_VERSION\s*:\s*u32\s*=\s*(\d+)/.exec(source);
+    const version = targetVersion ?? (current ? Number(current[1]) + 1 : 1);
+
+    const result = addUpgradeSupport(source, version);
+    return {
+      ...result,
+      nextSteps: [
+        'Fill in the TODO migration step(s) in migrate()',
+        'Rebuild with: ~/klever-sdk/ksc all build',
+        'Deploy the new code with an upgrade transaction (see add_helper_scripts upgrade.sh)',
+      ],
+    };
+  },
+};
@@ -0,0 +1,113 @@
+import type { KleverTool } from './types.js';
+import {
+  contractAddress,
+  decodeAddress,
+  decodeBech32,
+  encodeAddress,
+  isContractAddress,
+  KLEVER_HRP,
+} from '../chain/index.js';
+
+type Action = 'inspect' | 'contract-address';
+
+/** The 32 bytes of a klv1... address or a 64-character hex public key */
+function addressBytes(value: string): Buffer {
+  const trimmed = value.trim();
+  if (/^(0x)?[0-9a-fA-F]*$/.test(trimmed)) {
+    const hex = trimmed.replace(/^0x/, '');
+    if (hex.length !== 64) {
+      throw new Error(`A hex public key is 64 hex characters (32 bytes), got ${hex.length}`);
+    }
+    return Buffer.from(hex, 'hex');
+  }
+  const { hrp } = decodeBech32(trimmed);
+  if (hrp !== KLEVER_HRP) {
+    throw new Error(`"${hrp}1..." is not a Klever address; Klever addresses start with klv1`);
+  }
+  return decodeAddress(trimmed);
+}
+
+export const addressToolsTool: KleverTool = {
+  definition: {
+    name: 'address_tools',
+    description:
+      'Offline address utilities. "inspect" validates a klv1... address (bech32 checksum, prefix, 32-byte length) or a hex public key and returns both forms, and whether it is a smart contract address (eight leading zero bytes). "contract-address" derives the address of the contract a deployer creates with a deploy transaction of a given nonce. Use these instead of converting or guessing addresses by hand.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        action: {
+          type: 'string',
+          enum: ['inspect', 'contract-address'],
+          description: 'What to do (default: inspect)',
+        },
+        address: {
+          type: 'string',
+          description:
+            'klv1... address or 64-character hex public key; the deployer for contract-address',
+        },
+        nonce: {
+          type: 'integer',
+          minimum: 0,
+          description:
+            "Nonce of the deploy transaction (the deployer's account nonce when sending it)",
+        },
+      },
+      required: ['address'],
+    },
+    annotations: {
+      title: 'Address Tools',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { action = 'inspect', address, nonce } = args as {
+      action?: Action;
+      address: string;
+      nonce?: number;
+    };
+
+    if (action === 'contract-address') {
+      if (nonce === undefined) throw new Error('contract-address needs the deploy nonce.');
+      const deployer = encodeAddress(addressBytes(address));
+      const contract = contractAddress(deployer, nonce);
+      return {
+        deployer,
+        nonce,
+        contractAddress: contract,
+        hex: decodeAddress(contract).toString('hex'),
+        nextSteps: [
+          'The address is only used if the deploy transaction with this nonce succeeds; check with get_account after deploying',
+        ],
+      };
+    }
+    if (action !== 'inspect') throw new Error(`Unknown action "${action}".`);
+
+    let bytes: Buffer;
+    try {
+      bytes = addressBytes(address);
+    } catch (error) {
+      return {
+        input: address,
+        valid: false,
+        error: (error as Error).message,
+        nextSteps: ['Ask for the address again instead of correcting it by hand'],
+      };
+    }
+    const isContract = isContractAddress(bytes);
+    return {
+      input: address,
+      valid: true,
+      address: encodeAddress(bytes),
+      hex: bytes.toString('hex'),
+      isContract,
+      nextSteps: [
+        isContract
+          ? 'Check the deployed code and owner with get_account'
+          : 'Check the balance and nonce with get_account',
+      ],
+    };
+  },
+};
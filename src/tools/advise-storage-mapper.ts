@@ -0,0 +1,71 @@
+import type { KleverTool } from './types.js';
+import {
+  adviseStorageMapper,
+  STORAGE_MAPPERS,
+  type StorageBenchmarkResult,
+} from '../codegen/index.js';
+
+export const adviseStorageMapperTool: KleverTool = {
+  definition: {
+    name: 'advise_storage_mapper',
+    description:
+      'Recommend the right klever-sc storage mapper (SingleValueMapper, SetMapper, UnorderedSetMapper, MapMapper, VecMapper, LinkedListMapper, WhitelistMapper) for a described data shape and access pattern, e.g. "set of whitelisted addresses iterated rarely". Returns a declaration/usage snippet, per-operation storage costs, pitfalls, migration notes and the runner-up alternatives, each linking to its klever://storage-mappers/{mapper} reference page with a complete example contract. Pass the result of benchmark_storage as benchmark to weigh the measured gas of the workload in.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        description: {
+          type: 'string',
+          description:
+            'Data shape and access pattern: what is stored, whether it is keyed, iterated, ordered, or removed from',
+        },
+        keyType: {
+          type: 'string',
+          description: 'Rust key type used in the snippet (default: ManagedAddress)',
+        },
+        valueType: {
+          type: 'string',
+          description:
+            'Rust value/element type used in the snippet (e.g. BigUint, Position<Self::Api>)',
+        },
+        benchmark: {
+          type: 'object',
+          description:
+            'Result of benchmark_storage for the candidate mappers: the cheapest for the workload is favoured and the measured gas is shown with each mapper',
+        },
+      },
+      required: ['description'],
+    },
+    annotations: {
+      title: 'Advise Storage Mapper',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { description, keyType, valueType, benchmark } = args as {
+      description: string;
+      keyType?: string;
+      valueType?: string;
+      benchmark?: StorageBenchmarkResult;
+    };
+    if (benchmark && !Array.isArray(benchmark.mappers)) {
+      throw new Error('benchmark must be the result of benchmark_storage');
+    }
+    const advice = adviseStorageMapper(description, { keyType, valueType, benchmark });
+    const nextSteps = [
+      'Read the reference resource of the recommended mapper for a complete example contract',
+    ];
+    if (!benchmark) {
+      nextSteps.push(
+        `Measure ${advice.recommendation.mapper} against ${advice.alternatives[0].mapper} under the expected workload with benchmark_storage`
+      );
+    }
+    return {
+      ...advice,
+      availableMappers: STORAGE_MAPPERS.map(m => m.name),
+      nextSteps,
+    };
+  },
+};
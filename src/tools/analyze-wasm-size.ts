@@ -0,0 +1,75 @@
+import { readFile } from 'node:fs/promises';
+import type { KleverTool } from './types.js';
+import { analyzeWasmSize } from '../analysis/index.js';
+
+export const analyzeWasmSizeTool: KleverTool = {
+  definition: {
+    name: 'analyze_wasm_size',
+    description:
+      'Analyze a built contract .wasm: section sizes, imports and exports, the largest functions (named when the build kept the name section) and the data strings that usually cause bloat. Returns concrete suggestions ranked by estimated savings in bytes: require!/sc_panic! instead of panic!/unwrap, dropping core::fmt (format!, {:?}), overflow checks, managed types instead of Vec/String and the alloc feature, shorter error messages and stripping custom sections. Pass the contract source to point suggestions at the lines that cause them.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        wasmPath: {
+          type: 'string',
+          description: 'Path to the built .wasm (output/<name>.wasm); not available in public mode',
+        },
+        wasmHex: {
+          type: 'string',
+          description: 'The .wasm contents as hex (used when wasmPath is not given)',
+        },
+        source: {
+          type: 'string',
+          description: 'Contract source (src/lib.rs), to cite the lines behind each suggestion',
+        },
+        topFunctions: {
+          type: 'number',
+          description: 'Number of largest functions to list (default: 10)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Analyze Wasm Size',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async (args, context) => {
+    const { wasmPath, wasmHex, source, topFunctions } = args as {
+      wasmPath?: string;
+      wasmHex?: string;
+      source?: string;
+      topFunctions?: number;
+    };
+
+    let bytes: Uint8Array;
+    if (wasmPath) {
+      if (context.profile === 'public') {
+        throw new Error('wasmPath is not available in public mode; pass wasmHex instead.');
+      }
+      bytes = await readFile(wasmPath);
+    } else if (wasmHex) {
+      const hex = wasmHex.replace(/^0x/, '').replace(/\s+/g, '');
+      if (!/^([0-9a-f]{2})*$/i.test(hex)) throw new Error('wasmHex is not valid hex.');
+      bytes = Buffer.from(hex, 'hex');
+    } else {
+      throw new Error('Provide either wasmPath or wasmHex.');
+    }
+
+    const report = analyzeWasmSize(bytes, { source, topFunctions });
+
+    const nextSteps: string[] = [];
+    if (report.suggestions.length > 0) {
+      nextSteps.push(
+        `Start with ${report.suggestions[0].id} (about ${report.suggestions[0].estimatedSavings} bytes)`
+      );
+    }
+    if (!source && report.suggestions.length > 0) {
+      nextSteps.push('Pass the contract source to see which lines cause each suggestion');
+    }
+    nextSteps.push('Rebuild with build_contract and run analyze_wasm_size again to compare sizes');
+    return { ...report, nextSteps };
+  },
+};
@@ -0,0 +1,38 @@
+import type { KleverTool } from './types.js';
+import { auditAccessControl } from '../analysis/index.js';
+
+export const auditAccessControlTool: KleverTool = {
+  definition: {
+    name: 'audit_access_control',
+    description:
+      'Audit who can call each #[endpoint] of a klever-sc contract. Every endpoint is classified as owner-only (#[only_owner], require_owner()-style helpers, caller == owner checks), role-gated (#[only_admin], role/whitelist helpers, mapper.contains(&caller) checks) or public, following guard calls one level into private helpers and modules. The report lists guards, storage written and whether funds are sent, and flags public endpoints that mutate state; privileged-looking names such as mint or set_* are warnings.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        source: {
+          type: 'string',
+          description:
+            'Full contract source (src/lib.rs) containing the #[klever_sc::contract] trait',
+        },
+        modules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description:
+            'Module files of the same crate keyed by path (e.g. {"src/admin.rs": "..."}), audited together with the contract',
+        },
+      },
+      required: ['source'],
+    },
+    annotations: {
+      title: 'Audit Access Control',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { source, modules } = args as { source: string; modules?: Record<string, string> };
+    return auditAccessControl(source, modules);
+  },
+};
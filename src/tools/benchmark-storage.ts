@@ -0,0 +1,94 @@
+import type { KleverTool } from './types.js';
+import {
+  STORAGE_MAPPERS,
+  STORAGE_OPERATIONS,
+  type MapperName,
+  type StorageOperation,
+} from '../codegen/index.js';
+import { benchmarkStorage, STORAGE_BENCHMARK_PHASES } from '../toolchain/index.js';
+
+export const benchmarkStorageTool: KleverTool = {
+  definition: {
+    name: 'benchmark_storage',
+    description:
+      'Measure what candidate storage mapper layouts cost under a workload, e.g. MapMapper against keyed SingleValueMapper for balances: generates a benchmark contract with one endpoint per mapper and operation, builds it, fills each mapper with the workload entries and measures every operation with the Go VM scenario runner. Returns the gas of each operation less the cost of an empty call, the gas of the whole workload per mapper from cheapest, and the operations a mapper lacks. Pass the result as benchmark to advise_storage_mapper to weigh the measured numbers into its recommendation. Needs the klever-sc build toolchain and run-scenarios.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        mappers: {
+          type: 'array',
+          items: { type: 'string', enum: STORAGE_MAPPERS.map(m => m.name) },
+          description:
+            'Mappers to compare (two or more); each gets an endpoint per operation it supports, on u64 keys and BigUint values',
+        },
+        entries: {
+          type: 'number',
+          description:
+            'Entries stored in each mapper before the operations are measured (default: 100, at most 1000)',
+        },
+        operations: {
+          type: 'object',
+          properties: Object.fromEntries(STORAGE_OPERATIONS.map(op => [op, { type: 'number' }])),
+          description:
+            'How often the workload makes each operation (read, update, iterate, insert, remove), e.g. {"read": 100, "update": 20, "insert": 1} (default: insert, read, update and remove once). read is contains for sets; insert is push for lists',
+        },
+        projectPath: {
+          type: 'string',
+          description:
+            'Contract project whose klever-sc version to benchmark (default: the version the server scaffolds)',
+        },
+        runner: {
+          type: 'string',
+          description: 'Go VM scenario runner (default: run-scenarios on PATH)',
+        },
+      },
+      required: ['mappers'],
+    },
+    annotations: {
+      title: 'Benchmark Storage',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  localOnly: true,
+  phases: STORAGE_BENCHMARK_PHASES,
+  handler: async (args, context) => {
+    const { mappers, entries, operations, projectPath, runner } = args as {
+      mappers: MapperName[];
+      entries?: number;
+      operations?: Partial<Record<StorageOperation, number>>;
+      projectPath?: string;
+      runner?: string;
+    };
+    const result = await benchmarkStorage(mappers, {
+      entries,
+      operations,
+      projectPath,
+      runner,
+      onLine: context.progress,
+      onPhase: context.phase,
+    });
+    const [cheapest, next] = result.mappers;
+
+    const nextSteps: string[] = [];
+    if (cheapest.workloadGas !== undefined && next?.workloadGas !== undefined) {
+      nextSteps.push(
+        `${cheapest.mapper} is cheapest for this workload (${cheapest.workloadGas} gas against ${next.workloadGas} for ${next.mapper})`
+      );
+    }
+    for (const mapper of result.mappers.filter(m => m.unsupported.length > 0)) {
+      nextSteps.push(
+        `${mapper.mapper} cannot ${mapper.unsupported.join(', ')}; it does not fit the workload`
+      );
+    }
+    if (result.failures.length > 0) {
+      nextSteps.push('Some steps could not be measured; check failures and the runner output');
+    }
+    nextSteps.push(
+      'Pass this result as benchmark to advise_storage_mapper with a description of the data'
+    );
+    return { ...result, nextSteps };
+  },
+};
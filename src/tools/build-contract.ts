@@ -0,0 +1,68 @@
+import type { KleverTool } from './types.js';
+import { BUILD_PHASES, buildContract, type BuildTool } from '../toolchain/index.js';
+
+export const buildContractTool: KleverTool = {
+  definition: {
+    name: 'build_contract',
+    description:
+      "Build a klever-sc contract project on disk: the meta crate compiles the contract to wasm32-unknown-unknown, runs wasm-opt and writes the ABI. Uses ksc (Klever's sc-meta) from the Klever SDK when installed, otherwise `cargo run -- build` in the meta crate. Phases (compiling, optimizing wasm, collecting artifacts) and compiler output are streamed as progress notifications; the result lists compiler errors and warnings with their locations and the produced .wasm and .abi.json files with size and SHA-256.",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        projectPath: {
+          type: 'string',
+          description: 'Contract project root (the directory with Cargo.toml, wasm/ and meta/)',
+        },
+        tool: {
+          type: 'string',
+          enum: ['ksc', 'cargo'],
+          description: 'Build driver (default: ksc when installed, otherwise cargo)',
+        },
+        wasmOpt: {
+          type: 'boolean',
+          description: 'Optimize the wasm with wasm-opt (default: true)',
+        },
+      },
+      required: ['projectPath'],
+    },
+    annotations: {
+      title: 'Build Contract',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  localOnly: true,
+  phases: BUILD_PHASES,
+  handler: async (args, context) => {
+    const { projectPath, tool, wasmOpt } = args as {
+      projectPath: string;
+      tool?: BuildTool;
+      wasmOpt?: boolean;
+    };
+    const result = await buildContract(projectPath, {
+      tool,
+      wasmOpt,
+      onLine: context.progress,
+      onPhase: context.phase,
+    });
+
+    const nextSteps: string[] = [];
+    if (result.timedOut) {
+      nextSteps.push('The build timed out; run it once from a terminal to warm the cargo cache');
+    } else if (!result.success) {
+      nextSteps.push(
+        result.errors.length > 0
+          ? 'Fix the compiler errors at the listed locations and build again'
+          : 'Check the output for the failing step (often a missing wasm32 target or wasm-opt)'
+      );
+    } else {
+      nextSteps.push('Deploy the .wasm with deploy_sc, or upgrade an existing contract');
+      if (result.artifacts.some(a => a.kind === 'abi')) {
+        nextSteps.push('Compare the ABI with the deployed one using abi_diff');
+      }
+    }
+    return { ...result, nextSteps };
+  },
+};
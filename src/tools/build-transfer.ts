@@ -0,0 +1,230 @@
+import type { KleverTool } from './types.js';
+import {
+  decodeAddress,
+  isContractAddress,
+  NETWORK_NAMES,
+  validateNetwork,
+  type TransactionBuildData,
+} from '../chain/index.js';
+import { findAbiEndpoint } from '../abi/index.js';
+import { encodeArguments, type AbiArgs } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+import {
+  callValue,
+  checkPayable,
+  loadToolSigner,
+  PAYMENT_PROPERTIES,
+  SIGNER_PROPERTY,
+  submitTransaction,
+  TRANSACTION_PHASES,
+  WAIT_PROPERTY,
+  type Payment,
+} from './transaction-input.js';
+
+type Mode = 'unsigned' | 'sign' | 'send';
+
+export const buildTransferTool: KleverTool = {
+  definition: {
+    name: 'build_transfer',
+    description:
+      'Build a KLV and/or KDA transfer: several assets to one receiver in a single transaction, or a multi-asset payment to a payable endpoint of a contract. By default returns the unsigned transaction and its hash for signing in an external wallet; with mode "sign" signs it with a wallet key file on this machine and returns the signature without broadcasting, with mode "send" also broadcasts it and by default waits for the result.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        receiver: {
+          type: 'string',
+          description: 'Receiving account or contract (klv1...)',
+        },
+        ...PAYMENT_PROPERTIES,
+        endpoint: {
+          type: 'string',
+          description:
+            'Payable endpoint of the receiving contract to call with the payment; omit for a plain transfer',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the contract, to encode args and check accepted tokens',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        args: {
+          description: 'Endpoint arguments as plain JSON, as for invoke_endpoint (needs the ABI)',
+          oneOf: [{ type: 'array' }, { type: 'object' }],
+        },
+        gasLimit: {
+          type: 'number',
+          description: 'Gas limit of the endpoint call (default: chosen by the node)',
+        },
+        mode: {
+          type: 'string',
+          enum: ['unsigned', 'sign', 'send'],
+          description:
+            'unsigned: return the transaction to sign externally (default); sign: sign it here without broadcasting; send: sign and broadcast',
+        },
+        sender: {
+          type: 'string',
+          description: 'Sending address (klv1...); required for unsigned, otherwise the signer',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network to build for (default: the server default)',
+        },
+        signer: SIGNER_PROPERTY,
+        wait: WAIT_PROPERTY,
+      },
+      required: ['receiver'],
+    },
+    annotations: {
+      title: 'Build Transfer',
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: false,
+      openWorldHint: true,
+    },
+  },
+  localOnly: true,
+  phases: TRANSACTION_PHASES,
+  handler: async (args, context) => {
+    const {
+      receiver,
+      klv,
+      kda,
+      endpoint: endpointName,
+      abi,
+      abiUrl,
+      args: values,
+      gasLimit,
+      mode = 'unsigned',
+      sender: senderAddress,
+      network,
+      signer: signerPath,
+      wait,
+    } = args as {
+      receiver: string;
+      klv?: string | number;
+      kda?: Payment[];
+      endpoint?: string;
+      abi?: string;
+      abiUrl?: string;
+      args?: AbiArgs;
+      gasLimit?: number;
+      mode?: Mode;
+      sender?: string;
+      network?: string;
+      signer?: string;
+      wait?: boolean;
+    };
+    if (!['unsigned', 'sign', 'send'].includes(mode)) throw new Error(`Unknown mode "${mode}".`);
+    decodeAddress(receiver);
+    const net = validateNetwork(network);
+    const payment = callValue(klv, kda);
+    if (!payment && !endpointName) throw new Error('Nothing to transfer; pass klv and/or kda.');
+
+    let funcName: string | undefined;
+    let encoded: Buffer[] = [];
+    if (endpointName) {
+      if (!isContractAddress(receiver)) {
+        throw new Error(`${receiver} is not a contract address; omit endpoint for a transfer.`);
+      }
+      if (abi || abiUrl) {
+        const parsed = await loadAbi(abi, abiUrl, context.profile);
+        context.abiRegistry.register(receiver, parsed);
+        const endpoint = findAbiEndpoint(parsed, endpointName);
+        checkPayable(endpoint, payment);
+        encoded = encodeArguments(endpoint.inputs, values, parsed, endpoint.name);
+        funcName = endpoint.name;
+      } else if (values !== undefined) {
+        throw new Error('Encoding endpoint args needs the contract abi or abiUrl.');
+      } else {
+        funcName = endpointName;
+      }
+    }
+
+    const signer = mode === 'unsigned' ? undefined : await loadToolSigner(signerPath);
+    const sender = signer?.address || senderAddress;
+    if (!sender) throw new Error('Pass the sender address to build an unsigned transaction.');
+    decodeAddress(sender);
+    if (signer && senderAddress && senderAddress !== signer.address) {
+      throw new Error(`sender ${senderAddress} does not match the signer ${signer.address}.`);
+    }
+
+    const transfers = Object.entries(payment || {}).map(([token, amount]) => ({ token, amount }));
+    context.phase?.('building transaction', `from ${sender} to ${receiver}`);
+    let built: TransactionBuildData;
+    if (funcName) {
+      built = await context.chainClient.buildInvoke(
+        {
+          sender,
+          scAddress: receiver,
+          funcName,
+          args: encoded.map(arg => arg.toString('base64')),
+          callValue: payment,
+          gasLimit,
+        },
+        net
+      );
+    } else {
+      built = await context.chainClient.buildMultiTransfer(
+        {
+          sender,
+          transfers: transfers.map(({ token, amount }) => ({
+            receiver,
+            amount,
+            assetId: token === 'KLV' ? undefined : token,
+          })),
+        },
+        net
+      );
+    }
+
+    const details = {
+      sender,
+      receiver,
+      endpoint: funcName,
+      arguments: funcName ? encoded.map(arg => arg.toString('hex')) : undefined,
+      transfers,
+      network: net || context.chainClient.getDefaultNetwork(),
+    };
+    if (!signer) {
+      return {
+        ...details,
+        txHash: built.result.txHash,
+        unsignedTx: built.result.tx,
+        nextSteps: [
+          `Sign the transaction hash with the key of ${sender} and broadcast the transaction with the signature`,
+          'Amounts are in the smallest unit of each asset; check them with get_kda_info before signing',
+        ],
+      };
+    }
+    if (mode === 'sign') {
+      return {
+        ...details,
+        txHash: built.result.txHash,
+        tx: built.result.tx,
+        signature: signer.sign(Buffer.from(built.result.txHash, 'hex')),
+        nextSteps: ['Broadcast the transaction with its signature to send it'],
+      };
+    }
+
+    const submitted = await submitTransaction(context, built, signer, net, wait !== false);
+    const nextSteps: string[] = [];
+    if (!submitted.status) {
+      nextSteps.push(`Check the result with get_transaction ${submitted.txHash}`);
+    } else if (submitted.status !== 'success') {
+      nextSteps.push('The transfer failed; check resultCode, the balances and the receiver');
+    } else {
+      nextSteps.push(`Check the new balances with get_account ${receiver}`);
+    }
+    return {
+      ...details,
+      txHash: submitted.txHash,
+      explorerUrl: submitted.explorerUrl,
+      status: submitted.status,
+      resultCode: submitted.resultCode,
+      nextSteps,
+    };
+  },
+};
@@ -0,0 +1,40 @@
+import type { KleverTool } from './types.js';
+import { NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+
+export const cacheClearTool: KleverTool = {
+  definition: {
+    name: 'cache_clear',
+    description:
+      'Drop the cached node and API responses of one network, or of all networks, so the next queries fetch fresh data. Account state is only cached for a few seconds and is dropped whenever this server broadcasts a transaction; clear the cache after changes made elsewhere (another wallet, a reset local chain) that must be visible at once. Returns how many responses were dropped and the cache hit counts.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network whose responses to drop (default: every network)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Clear Response Cache',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  localOnly: true,
+  handler: async (args, context) => {
+    const { network } = args as { network?: string };
+    const net = validateNetwork(network);
+    const cleared = context.chainClient.clearCache(net);
+    const stats = context.chainClient.cacheStats();
+    return {
+      network: net || 'all',
+      cleared,
+      stats,
+      nextSteps: stats ? [] : ['Response caching is off (KLEVER_CACHE=off); nothing is cached'],
+    };
+  },
+};
@@ -0,0 +1,73 @@
+import type { KleverTool } from './types.js';
+import { checkDeprecatedApis, fixDeprecatedApis, kleverScVersion } from '../analysis/index.js';
+
+export const checkDeprecatedApisTool: KleverTool = {
+  definition: {
+    name: 'check_deprecated_apis',
+    description:
+      'Find deprecated or removed klever-sc APIs in contract source for the framework version declared in Cargo.toml: the imports!()/derive_imports!() macros, SCResult and sc_error!, OptionalArg/OptionalResult, VarArgs/MultiArgVec/MultiResultVec, MultiArgN/MultiResultN and #[var_args]. Each finding names the modern replacement, and an outdated klever-sc pin is reported. With fix: true, mechanical replacements are applied and the rewritten files returned along with what still needs a manual edit; nothing is written to disk.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        source: {
+          type: 'string',
+          description: 'Full contract source (src/lib.rs)',
+        },
+        modules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description:
+            'Other .rs files of the same crate keyed by path (e.g. {"src/storage.rs": "..."}), checked together with the contract',
+        },
+        cargoToml: {
+          type: 'string',
+          description:
+            'Contents of the contract Cargo.toml; without it every known change is reported',
+        },
+        fix: {
+          type: 'boolean',
+          description:
+            'Apply mechanical replacements and return the rewritten files (default: false)',
+        },
+      },
+      required: ['source'],
+    },
+    annotations: {
+      title: 'Check Deprecated APIs',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { source, modules, cargoToml, fix } = args as {
+      source: string;
+      modules?: Record<string, string>;
+      cargoToml?: string;
+      fix?: boolean;
+    };
+    const version = cargoToml ? kleverScVersion(cargoToml) : undefined;
+    if (fix) {
+      const result = fixDeprecatedApis(source, modules, { cargoToml });
+      return {
+        version,
+        ...result,
+        nextSteps: [
+          'Replace the returned files',
+          ...(result.remaining.length > 0 ? ['Edit the remaining findings by hand'] : []),
+          'Rebuild with: ~/klever-sdk/ksc all build',
+        ],
+      };
+    }
+    const findings = checkDeprecatedApis(source, modules || {}, { cargoToml });
+    return {
+      version,
+      findings,
+      nextSteps:
+        findings.length > 0
+          ? ['Call check_deprecated_apis again with fix: true to apply the mechanical replacements']
+          : ['No deprecated klever-sc APIs found'],
+    };
+  },
+};
@@ -0,0 +1,87 @@
+import type { KleverTool } from './types.js';
+import { parseAbi } from '../abi/index.js';
+import { checkUpgradeSafety, diffAbi, type AbiDiff } from '../analysis/index.js';
+import { loadAbi } from './abi-input.js';
+
+export const checkUpgradeSafetyTool: KleverTool = {
+  definition: {
+    name: 'check_upgrade_safety',
+    description:
+      'Check that a new version of a klever-sc contract can safely upgrade the deployed one. Storage survives upgrades unchanged, so the storage mappers of both versions are compared by storage key: keys read back with an incompatible mapper, value or key-argument type are errors (existing data would be reinterpreted), keys the new version no longer reads are reported as orphaned, integer widenings are noted as safe, and old keys read through raw StorageKey mappers count as migrated. When the deployed ABI (JSON or URL) and the new ABI are given, the endpoint/event/type diff from abi_diff is included.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        oldSource: {
+          type: 'string',
+          description: 'Contract source (src/lib.rs) of the currently deployed version',
+        },
+        newSource: {
+          type: 'string',
+          description: 'Contract source (src/lib.rs) of the version being deployed',
+        },
+        oldModules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description: 'Module files of the deployed version keyed by path',
+        },
+        newModules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description: 'Module files of the new version keyed by path',
+        },
+        oldAbi: {
+          type: 'string',
+          description: 'ABI JSON of the deployed version (optional)',
+        },
+        oldAbiUrl: {
+          type: 'string',
+          description: 'URL to fetch the deployed ABI from (used when oldAbi is not given)',
+        },
+        newAbi: {
+          type: 'string',
+          description: 'ABI JSON of the new build; required with oldAbi or oldAbiUrl',
+        },
+      },
+      required: ['oldSource', 'newSource'],
+    },
+    annotations: {
+      title: 'Check Upgrade Safety',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { oldSource, newSource, oldModules, newModules, oldAbi, oldAbiUrl, newAbi } = args as {
+      oldSource: string;
+      newSource: string;
+      oldModules?: Record<string, string>;
+      newModules?: Record<string, string>;
+      oldAbi?: string;
+      oldAbiUrl?: string;
+      newAbi?: string;
+    };
+    const report = checkUpgradeSafety(oldSource, newSource, { oldModules, newModules });
+
+    let abi: AbiDiff | undefined;
+    if (oldAbi || oldAbiUrl) {
+      if (!newAbi) throw new Error('Provide newAbi to compare it with the deployed ABI.');
+      const before = await loadAbi(oldAbi, oldAbiUrl, context.profile, 'oldAbiUrl');
+      abi = diffAbi(before, parseAbi(newAbi));
+    }
+
+    const nextSteps: string[] = [];
+    if (!report.safe) {
+      nextSteps.push('Do not upgrade until every storage-reinterpreted finding is resolved');
+    }
+    if (report.findings.some(f => f.rule === 'storage-orphaned')) {
+      nextSteps.push('Run generate_migration to copy or clear orphaned keys from #[upgrade]');
+    }
+    if (abi && !abi.compatible) {
+      nextSteps.push('Review the breaking ABI changes with the teams calling this contract');
+    }
+    if (nextSteps.length === 0) nextSteps.push('Storage layout is compatible; upgrade as planned');
+    return { ...report, abi, nextSteps };
+  },
+};
@@ -0,0 +1,159 @@
+import { readFile } from 'node:fs/promises';
+import type { KleverTool } from './types.js';
+import { parseAbi, findAbiEndpoint } from '../abi/index.js';
+import type { AbiArgs } from '../codegen/index.js';
+import {
+  collectArtifacts,
+  debugCall,
+  DEBUG_PHASES,
+  type DebugPayment,
+  type DebugStep,
+} from '../toolchain/index.js';
+
+export const PAYMENT_SCHEMA = {
+  type: 'object',
+  properties: {
+    amount: { type: 'string', description: "Amount in the token's smallest unit" },
+    token: { type: 'string', description: 'KLV (default) or a KDA token identifier' },
+    nonce: { type: 'number', description: 'NFT/SFT nonce (default: 0)' },
+  },
+  required: ['amount'],
+};
+
+export const ARGS_SCHEMA = {
+  description: 'Arguments as plain JSON, in ABI order or keyed by argument name',
+  oneOf: [{ type: 'array' }, { type: 'object' }],
+};
+
+export const debugCallTool: KleverTool = {
+  definition: {
+    name: 'debug_call',
+    description:
+      "Execute one endpoint call of a klever-sc contract project in the Rust VM with tracing and return what happened in order: every statement executed (file, line, code), the storage mappers each one reads or writes with their keys, the events emitted with their decoded arguments, the transfers and contract calls made, and the final status, error message and decoded results. A failed require! or sc_panic! is pinpointed to the statement that raised it. The project itself is not modified. Needs klever-sc-scenario in [dev-dependencies].",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        projectPath: {
+          type: 'string',
+          description:
+            'Contract project root (the directory with Cargo.toml and src/); the call runs on an instrumented copy under target/klever-debug',
+        },
+        endpoint: { type: 'string', description: 'Endpoint to call' },
+        args: ARGS_SCHEMA,
+        caller: {
+          type: 'string',
+          description:
+            'Test account name the call comes from (default: owner, the deployer); accounts are funded with KLV and every payment token',
+        },
+        payment: { ...PAYMENT_SCHEMA, description: 'Payment sent with the call' },
+        deployArgs: {
+          ...ARGS_SCHEMA,
+          description:
+            'Constructor arguments as plain JSON, in ABI order or keyed by argument name; the contract is deployed fresh by owner on every run',
+        },
+        setup: {
+          type: 'array',
+          description:
+            'Calls made after the deployment and before the traced one, in order, e.g. to give the caller a balance',
+          items: {
+            type: 'object',
+            properties: {
+              endpoint: { type: 'string' },
+              args: ARGS_SCHEMA,
+              caller: { type: 'string', description: 'Account name (default: owner)' },
+              payment: PAYMENT_SCHEMA,
+            },
+            required: ['endpoint'],
+          },
+        },
+        storageDiff: {
+          type: 'boolean',
+          description:
+            'Also return the complete storage diff of the traced call: every key it changed, in any account, with the old and new value, decoded where a storage mapper of the project declares the key (default: false)',
+        },
+        abi: {
+          type: 'string',
+          description:
+            'ABI JSON (default: output/*.abi.json of the project, written by build_contract)',
+        },
+      },
+      required: ['projectPath', 'endpoint'],
+    },
+    annotations: {
+      title: 'Debug Call',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  localOnly: true,
+  phases: DEBUG_PHASES,
+  handler: async (args, context) => {
+    const {
+      projectPath,
+      endpoint,
+      args: callArgs,
+      caller,
+      payment,
+      deployArgs,
+      setup,
+      storageDiff,
+      abi,
+    } = args as {
+      projectPath: string;
+      endpoint: string;
+      args?: AbiArgs;
+      caller?: string;
+      payment?: DebugPayment;
+      deployArgs?: AbiArgs;
+      setup?: DebugStep[];
+      storageDiff?: boolean;
+      abi?: string;
+    };
+    let abiJson = abi;
+    if (!abiJson) {
+      const artifact = (await collectArtifacts(projectPath)).find(a => a.kind === 'abi');
+      if (!artifact) {
+        throw new Error('No output/*.abi.json in the project; build it first or pass abi');
+      }
+      abiJson = await readFile(artifact.path, 'utf-8');
+    }
+    const parsed = parseAbi(abiJson);
+    findAbiEndpoint(parsed, endpoint);
+
+    const result = await debugCall(projectPath, {
+      abi: parsed,
+      endpoint,
+      args: callArgs,
+      caller,
+      payment,
+      deployArgs,
+      setup,
+      storageDiff,
+      onLine: context.progress,
+      onPhase: context.phase,
+    });
+
+    const nextSteps: string[] = [];
+    if (result.compileErrors.length > 0) {
+      nextSteps.push(
+        'Fix the compiler errors at the listed locations (they match the project sources)'
+      );
+    } else if (result.phase !== 'call' && result.status !== undefined) {
+      const which = result.phase === 'setup' ? `setup call ${result.setupIndex}` : 'deploy';
+      nextSteps.push(`The ${which} failed before the traced call; fix its arguments or payment`);
+    } else if (result.failure) {
+      nextSteps.push(
+        `"${result.failure.message}" is raised at ${result.failure.file}:${result.failure.line}; check the storage reads before it in the trace`
+      );
+    } else if (result.status === undefined) {
+      nextSteps.push('The debug test did not report a result; check the output');
+    } else if (result.truncated) {
+      nextSteps.push('The trace was cut short; trace a smaller call or check for a long loop');
+    } else {
+      nextSteps.push('The call succeeds; keep it as a test with generate_scenario');
+    }
+    return { ...result, nextSteps };
+  },
+};
@@ -0,0 +1,144 @@
+import type { KleverTool } from './types.js';
+import { NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { findAbiEndpoint } from '../abi/index.js';
+import { decodeEvent, decodeResults, type DecodedEvent } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+import { eventLogs, returnData, type EventLog } from './transaction-input.js';
+import { rawValue } from './value-input.js';
+
+interface EventInput {
+  topics: string[];
+  data?: string | string[];
+}
+
+export const decodeResultTool: KleverTool = {
+  definition: {
+    name: 'decode_result',
+    description:
+      'Decode contract return data and event logs with the contract ABI instead of guessing: field names from the ABI, integers as numbers (strings when beyond the safe range), addresses as klv1... bech32, bytes as text when printable, otherwise 0x hex. Takes raw returnData and/or events (topics and data), or a transaction hash whose return data and logs are fetched from the chain.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the contract',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        endpoint: {
+          type: 'string',
+          description: 'Endpoint or view that produced the return data; needed to decode it',
+        },
+        returnData: {
+          type: 'array',
+          items: { type: 'string' },
+          description: 'Returned values, base64 as the node returns them or 0x hex',
+        },
+        events: {
+          type: 'array',
+          items: {
+            type: 'object',
+            properties: {
+              topics: { type: 'array', items: { type: 'string' } },
+              data: {
+                oneOf: [{ type: 'string' }, { type: 'array', items: { type: 'string' } }],
+              },
+            },
+            required: ['topics'],
+          },
+          description:
+            'Event logs: topics (the first is the event identifier) and data, base64 or 0x hex',
+        },
+        txHash: {
+          type: 'string',
+          description: 'Transaction to read return data and logs from (instead of raw values)',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network of txHash (default: the server default)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Decode Result',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const {
+      abi,
+      abiUrl,
+      endpoint: endpointName,
+      returnData: rawReturnData,
+      events: rawEvents,
+      txHash,
+      network,
+    } = args as {
+      abi?: string;
+      abiUrl?: string;
+      endpoint?: string;
+      returnData?: string[];
+      events?: EventInput[];
+      txHash?: string;
+      network?: string;
+    };
+    if (!rawReturnData && !rawEvents && !txHash) {
+      throw new Error('Provide returnData, events or txHash to decode.');
+    }
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+
+    let data = rawReturnData?.map(rawValue);
+    let logs: EventLog[] | undefined = rawEvents?.map(event => ({
+      topics: event.topics.map(rawValue),
+      data: [event.data ?? []].flat().map(rawValue),
+    }));
+    if (txHash) {
+      const tx = await context.chainClient.getTransaction(txHash, validateNetwork(network));
+      data = data || returnData(tx);
+      logs = logs || eventLogs(tx);
+    }
+
+    const nextSteps: string[] = [];
+    let results: unknown[] | undefined;
+    if (data && endpointName) {
+      const endpoint = findAbiEndpoint(parsed, endpointName);
+      results = decodeResults(endpoint.outputs, data, parsed);
+    } else if (data && data.length > 0) {
+      nextSteps.push('Pass endpoint to decode the return data with its ABI outputs');
+    }
+
+    const events: (DecodedEvent & { address?: string })[] = [];
+    const undecoded: { topics: string[]; data: string[]; reason: string }[] = [];
+    for (const log of logs || []) {
+      try {
+        events.push({ ...decodeEvent(log.topics, log.data, parsed), address: log.address });
+      } catch (error) {
+        undecoded.push({
+          topics: log.topics.map(topic => `0x${topic.toString('hex')}`),
+          data: log.data.map(value => `0x${value.toString('hex')}`),
+          reason: (error as Error).message,
+        });
+      }
+    }
+    if (undecoded.length > 0) {
+      nextSteps.push(
+        'Undecoded logs are protocol events or come from another contract; decode them with that ABI'
+      );
+    }
+
+    return {
+      endpoint: endpointName,
+      results,
+      rawResults: data?.map(value => value.toString('hex')),
+      events,
+      undecodedEvents: undecoded.length > 0 ? undecoded : undefined,
+      nextSteps,
+    };
+  },
+};
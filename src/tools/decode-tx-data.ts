@@ -0,0 +1,92 @@
+import type { KleverTool } from './types.js';
+import { type Abi } from '../abi/index.js';
+import { decodeCallData, parseCallData } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+export const decodeTxDataTool: KleverTool = {
+  definition: {
+    name: 'decode_tx_data',
+    description:
+      'Explain the data field of a contract call transaction before signing it: which endpoint it calls and its arguments decoded by name with the contract ABI (addresses as klv1..., integers as numbers, text when printable), plus what the endpoint expects (mutability, owner-only, accepted payments). Takes the data as function@hexArg@... text or base64 as nodes return it.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        data: {
+          type: 'string',
+          description: 'Transaction data, e.g. transfer@<addr hex>@<amount hex>, or its base64',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the called contract',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        contract: {
+          type: 'string',
+          description:
+            'Called contract (klv1...), to use an ABI from earlier in the session when abi is not given',
+        },
+      },
+      required: ['data'],
+    },
+    annotations: {
+      title: 'Decode Transaction Data',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { data, abi, abiUrl, contract } = args as {
+      data: string;
+      abi?: string;
+      abiUrl?: string;
+      contract?: string;
+    };
+    const call = parseCallData(data);
+    const rawArgs = call.args.map(arg => arg.toString('hex'));
+    let contractAbi: Abi | undefined;
+    if (abi || abiUrl) {
+      contractAbi = await loadAbi(abi, abiUrl, context.profile);
+      if (contract) context.abiRegistry.register(contract, contractAbi);
+    } else if (contract) {
+      contractAbi = context.abiRegistry.get(contract);
+    }
+    if (!contractAbi) {
+      return {
+        endpoint: call.functionName,
+        rawArgs,
+        nextSteps: ['Pass the contract abi to decode the arguments'],
+      };
+    }
+
+    const { endpoint, args: decoded } = decodeCallData(data, contractAbi);
+    const nextSteps: string[] = [];
+    if (endpoint.onlyOwner) {
+      nextSteps.push('Only the contract owner can call this endpoint; check the signer');
+    }
+    if (endpoint.payableInTokens?.length) {
+      nextSteps.push(
+        `The endpoint accepts payments in ${endpoint.payableInTokens.join(', ')}; check the amount sent with the call`
+      );
+    }
+    if (endpoint.mutability === 'readonly') {
+      nextSteps.push('The endpoint is read-only; query it with query_view instead of a transaction');
+    }
+    nextSteps.push('Check each argument against what you intend to sign');
+
+    return {
+      endpoint: endpoint.name,
+      docs: endpoint.docs,
+      mutability: endpoint.mutability || 'mutable',
+      onlyOwner: endpoint.onlyOwner || false,
+      payableInTokens: endpoint.payableInTokens || [],
+      args: decoded,
+      rawArgs,
+      nextSteps,
+    };
+  },
+};
@@ -0,0 +1,69 @@
+import type { KleverTool } from './types.js';
+import type { AbiTypeDef } from '../abi/index.js';
+import { decodeNested, decodeResults, decodeTopLevel } from '../codegen/index.js';
+import {
+  ENCODING_PROPERTY,
+  loadTypes,
+  rawValue,
+  TYPE_PROPERTY,
+  TYPE_SOURCE_PROPERTIES,
+  valueType,
+  type Encoding,
+} from './value-input.js';
+
+export const decodeValueTool: KleverTool = {
+  definition: {
+    name: 'decode_value',
+    description:
+      'Deserialize klever-sc encoded bytes into a plain-JSON value, without the framework: the inverse of encode_value for top-level or nested encoding. Integers come back as numbers (strings beyond the safe range), addresses as klv1..., bytes as text when printable, otherwise 0x hex, Option as the value or null, structs as objects and enums as the variant name or { Variant: fields }. Custom types come from an ABI types section. Multi-value types take one data entry per top-level value. Nested decoding fails on trailing bytes.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        type: TYPE_PROPERTY,
+        data: {
+          description:
+            'Encoded bytes as 0x hex or base64 (as nodes return values); an array of them for multi-value types',
+          oneOf: [{ type: 'string' }, { type: 'array', items: { type: 'string' } }],
+        },
+        encoding: ENCODING_PROPERTY,
+        ...TYPE_SOURCE_PROPERTIES,
+      },
+      required: ['type', 'data'],
+    },
+    annotations: {
+      title: 'Decode Value',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { type: given, data, encoding = 'top', abi, abiUrl, types } = args as {
+      type: string;
+      data: string | string[];
+      encoding?: Encoding;
+      abi?: string;
+      abiUrl?: string;
+      types?: Record<string, AbiTypeDef>;
+    };
+    const { type, multi } = valueType(given, encoding);
+    const parsed = await loadTypes(abi, abiUrl, types, context.profile);
+    const buffers = [data].flat().map(rawValue);
+    if (!multi && buffers.length !== 1) {
+      throw new Error(`${type} is a single value; pass data as one string.`);
+    }
+
+    const value = multi
+      ? decodeResults([{ type }], buffers, parsed)[0]
+      : encoding === 'nested'
+        ? decodeNested(type, buffers[0], parsed)
+        : decodeTopLevel(type, buffers[0], parsed);
+    return {
+      type,
+      encoding,
+      value,
+      nextSteps: [],
+    };
+  },
+};
@@ -0,0 +1,144 @@
+import { readFile } from 'node:fs/promises';
+import type { KleverTool } from './types.js';
+import { NETWORK_NAMES, validateNetwork, type TransactionData } from '../chain/index.js';
+import { type Abi } from '../abi/index.js';
+import { encodeArguments, type AbiArgs } from '../codegen/index.js';
+import { codeHash } from '../toolchain/index.js';
+import { loadAbi } from './abi-input.js';
+import {
+  loadToolSigner,
+  SIGNER_PROPERTY,
+  submitTransaction,
+  TRANSACTION_PHASES,
+  WAIT_PROPERTY,
+} from './transaction-input.js';
+
+/** First klv1 address in the receipts that is not the deployer: the new contract */
+function contractAddress(tx: TransactionData, sender: string): string | undefined {
+  const found: string[] = [];
+  const visit = (value: unknown) => {
+    if (typeof value === 'string' && /^klv1[02-9ac-hj-np-z]{58}$/.test(value)) {
+      found.push(value);
+    } else if (value && typeof value === 'object') {
+      Object.values(value).forEach(visit);
+    }
+  };
+  visit(tx.receipts || []);
+  return found.find(address => address !== sender);
+}
+
+export const deployContractTool: KleverTool = {
+  definition: {
+    name: 'deploy_contract',
+    description:
+      "Deploy a contract: reads the .wasm, encodes the constructor arguments from plain JSON using the ABI (BigUint and integers as minimal big-endian bytes, addresses from klv1... bech32, structs, enums, Option, List, optional and variadic arguments), builds the deploy transaction on the node, signs it with a wallet key file on this machine (the key is never passed through the client), broadcasts it and by default waits for the result. Returns the transaction hash and the new contract address.",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        wasmPath: {
+          type: 'string',
+          description: 'Path to the built contract (output/<name>.wasm)',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON (contents of output/<name>.abi.json); needed for init arguments',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        args: {
+          description:
+            'Constructor arguments as plain JSON, in ABI order or keyed by argument name. Numbers may be strings; addresses are klv1... or 32-byte 0x hex; bytes are text or 0x hex.',
+          oneOf: [{ type: 'array' }, { type: 'object' }],
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network to deploy to (default: the server default)',
+        },
+        signer: SIGNER_PROPERTY,
+        wait: WAIT_PROPERTY,
+      },
+      required: ['wasmPath'],
+    },
+    annotations: {
+      title: 'Deploy Contract',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: true,
+    },
+  },
+  localOnly: true,
+  phases: TRANSACTION_PHASES,
+  handler: async (args, context) => {
+    const {
+      wasmPath,
+      abi,
+      abiUrl,
+      args: initValues,
+      network,
+      signer: signerPath,
+      wait,
+    } = args as {
+      wasmPath: string;
+      abi?: string;
+      abiUrl?: string;
+      args?: AbiArgs;
+      network?: string;
+      signer?: string;
+      wait?: boolean;
+    };
+    const net = validateNetwork(network);
+
+    let initArgs: Buffer[] = [];
+    let parsed: Abi | undefined;
+    if (abi || abiUrl) {
+      parsed = await loadAbi(abi, abiUrl, context.profile);
+      const inputs = parsed.constructor?.inputs || [];
+      initArgs = encodeArguments(inputs, initValues, parsed, 'constructor');
+    } else if (initValues && Object.keys(initValues).length > 0) {
+      throw new Error('Pass abi or abiUrl to encode constructor arguments.');
+    }
+
+    const wasm = await readFile(wasmPath);
+    const signer = await loadToolSigner(signerPath);
+    context.phase?.('building transaction', `deploy ${wasmPath} (${wasm.length} bytes)`);
+    const built = await context.chainClient.buildDeploy(
+      {
+        sender: signer.address,
+        wasmHex: wasm.toString('hex'),
+        initArgs: initArgs.map(arg => arg.toString('base64')),
+      },
+      net
+    );
+    const submitted = await submitTransaction(context, built, signer, net, wait !== false);
+    const address = submitted.transaction
+      ? contractAddress(submitted.transaction, signer.address)
+      : undefined;
+    if (address && parsed) context.abiRegistry.register(address, parsed);
+
+    const nextSteps: string[] = [];
+    if (!submitted.status) {
+      nextSteps.push(`Check the result with get_transaction ${submitted.txHash}`);
+    } else if (submitted.status !== 'success') {
+      nextSteps.push('The deploy failed; check resultCode and the constructor arguments');
+    } else {
+      nextSteps.push('Call the new contract with invoke_endpoint; read its views with query_view');
+      nextSteps.push('Record the address and run verify_build against it for audits');
+    }
+    return {
+      txHash: submitted.txHash,
+      explorerUrl: submitted.explorerUrl,
+      status: submitted.status,
+      resultCode: submitted.resultCode,
+      contractAddress: address,
+      sender: signer.address,
+      network: net || context.chainClient.getDefaultNetwork(),
+      wasm: { path: wasmPath, size: wasm.length, codeHash: codeHash(wasm) },
+      initArgs: initArgs.map(arg => arg.toString('hex')),
+      nextSteps,
+    };
+  },
+};
@@ -0,0 +1,82 @@
+import type { KleverTool } from './types.js';
+import type { AbiTypeDef } from '../abi/index.js';
+import { encodeArguments, encodeNested, encodeTopLevel } from '../codegen/index.js';
+import {
+  ENCODING_PROPERTY,
+  loadTypes,
+  TYPE_PROPERTY,
+  TYPE_SOURCE_PROPERTIES,
+  valueType,
+  type Encoding,
+} from './value-input.js';
+
+const bytes = (buffer: Buffer) => ({
+  hex: buffer.toString('hex'),
+  base64: buffer.toString('base64'),
+  length: buffer.length,
+});
+
+export const encodeValueTool: KleverTool = {
+  definition: {
+    name: 'encode_value',
+    description:
+      'Serialize a plain-JSON value with klever-sc encoding, without the framework: top-level (a whole call argument or result: minimal big-endian integers, empty for zero, false and None) or nested (inside a struct, list or option: fixed-width integers, u32 length prefixes for BigUint, ManagedBuffer and Vec). Custom types come from an ABI types section. Multi-value types (OptionalValue, MultiValueEncoded, MultiValueN) encode to several top-level arguments. Returns hex and base64; decode_value is the inverse.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        type: TYPE_PROPERTY,
+        value: {
+          description:
+            'Value as plain JSON: integers as numbers or strings, addresses klv1... or 32-byte 0x hex, bytes as text or 0x hex, Option as null or the value, structs as objects or arrays, enums as the variant name or { Variant: fields }',
+        },
+        encoding: ENCODING_PROPERTY,
+        ...TYPE_SOURCE_PROPERTIES,
+      },
+      required: ['type', 'value'],
+    },
+    annotations: {
+      title: 'Encode Value',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { type: given, value, encoding = 'top', abi, abiUrl, types } = args as {
+      type: string;
+      value: unknown;
+      encoding?: Encoding;
+      abi?: string;
+      abiUrl?: string;
+      types?: Record<string, AbiTypeDef>;
+    };
+    const { type, multi } = valueType(given, encoding);
+    const parsed = await loadTypes(abi, abiUrl, types, context.profile);
+
+    if (multi) {
+      const encoded = encodeArguments([{ name: 'value', type }], [value], parsed, type);
+      return {
+        type,
+        encoding,
+        arguments: encoded.map(bytes),
+        nextSteps: [
+          'Each entry is one top-level argument: append them to call data as @<hex> in order',
+        ],
+      };
+    }
+    const encoded =
+      encoding === 'nested'
+        ? encodeNested(type, value, parsed)
+        : encodeTopLevel(type, value, parsed);
+    return {
+      type,
+      encoding,
+      ...bytes(encoded),
+      nextSteps:
+        encoding === 'top'
+          ? ['Call data is the endpoint name followed by @<hex> for each argument, e.g. claim@0a']
+          : ['Nested bytes go inside a larger value; use encoding top for a whole argument'],
+    };
+  },
+};
@@ -0,0 +1,145 @@
+import type { KleverTool } from './types.js';
+import { decodeAddress, estimateGas, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { findAbiEndpoint } from '../abi/index.js';
+import { decodeResults, encodeArguments, type AbiArgs } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+import { callValue, checkPayable, PAYMENT_PROPERTIES, type Payment } from './transaction-input.js';
+
+export const estimateGasTool: KleverTool = {
+  definition: {
+    name: 'estimate_gas',
+    description:
+      'Estimate the gas of a contract call by simulating it against the current chain state (or the local node with network "local") without sending a transaction. Encodes the arguments with the ABI, includes optional KLV/KDA payments and the caller, and returns the gas used, a recommended gas limit with a safety margin, the gas forwarded to each contract it calls, and whether the call would fail. Pass the recommended limit as gasLimit to invoke_endpoint.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        address: {
+          type: 'string',
+          description: 'Contract address (klv1...)',
+        },
+        endpoint: {
+          type: 'string',
+          description: 'Endpoint to simulate, as in the ABI',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the contract',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        args: {
+          description: 'Endpoint arguments as plain JSON, in ABI order or keyed by argument name',
+          oneOf: [{ type: 'array' }, { type: 'object' }],
+        },
+        caller: {
+          type: 'string',
+          description: 'Address the call is simulated from (klv1...); use the future sender',
+        },
+        ...PAYMENT_PROPERTIES,
+        marginPercent: {
+          type: 'number',
+          description: 'Safety margin added to the gas used (default: 20)',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network whose state to simulate against (default: the server default)',
+        },
+      },
+      required: ['address', 'endpoint'],
+    },
+    annotations: {
+      title: 'Estimate Gas',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const {
+      address,
+      endpoint: endpointName,
+      abi,
+      abiUrl,
+      args: values,
+      caller,
+      klv,
+      kda,
+      marginPercent = 20,
+      network,
+    } = args as {
+      address: string;
+      endpoint: string;
+      abi?: string;
+      abiUrl?: string;
+      args?: AbiArgs;
+      caller?: string;
+      klv?: string | number;
+      kda?: Payment[];
+      marginPercent?: number;
+      network?: string;
+    };
+    decodeAddress(address);
+    if (caller) decodeAddress(caller);
+    const net = validateNetwork(network);
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    context.abiRegistry.register(address, parsed);
+    const endpoint = findAbiEndpoint(parsed, endpointName);
+    const encoded = encodeArguments(endpoint.inputs, values, parsed, endpoint.name);
+    const payment = callValue(klv, kda);
+    checkPayable(endpoint, payment);
+
+    const response = await context.chainClient.querySmartContract(
+      {
+        scAddress: address,
+        funcName: endpoint.name,
+        args: encoded.map(arg => arg.toString('base64')),
+        ...(caller ? { caller } : {}),
+        ...(payment ? { callValue: payment } : {}),
+      },
+      net
+    );
+    const failed = !!response.returnCode && response.returnCode.toLowerCase() !== 'ok';
+    const estimate = estimateGas(response, marginPercent);
+
+    const nextSteps: string[] = [];
+    if (failed) {
+      nextSteps.push(
+        'The simulated call fails; fix the cause in returnMessage before sending it (a different caller or payment may be needed)'
+      );
+    } else if (!estimate) {
+      nextSteps.push('The node did not report gas used; set gasLimit from a test transaction');
+    } else {
+      nextSteps.push(
+        `Call invoke_endpoint with gasLimit ${estimate.recommendedGasLimit} and the same arguments`
+      );
+    }
+    if (!caller) {
+      nextSteps.push('Pass caller for endpoints that check the sender (owner-only, balances)');
+    }
+
+    let results: unknown[] | undefined;
+    if (!failed && response.returnData) {
+      const raw = response.returnData.map(value => Buffer.from(value || '', 'base64'));
+      try {
+        results = decodeResults(endpoint.outputs, raw, parsed);
+      } catch {
+        // The estimate stands without them; query_view reports decoding errors
+      }
+    }
+    return {
+      endpoint: endpoint.name,
+      address,
+      network: net || context.chainClient.getDefaultNetwork(),
+      wouldFail: failed,
+      returnCode: response.returnCode,
+      returnMessage: response.returnMessage || undefined,
+      ...estimate,
+      results,
+      nextSteps,
+    };
+  },
+};
@@ -0,0 +1,111 @@
+import type { KleverTool } from './types.js';
+import { NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { explainVmError } from '../analysis/index.js';
+import { type Abi } from '../abi/index.js';
+import { loadAbi } from './abi-input.js';
+import { calledFunction, transactionContract, transactionErrors } from './transaction-input.js';
+
+export const explainErrorTool: KleverTool = {
+  definition: {
+    name: 'explain_error',
+    description:
+      'Explain why a contract call failed: matches the error against a catalog of Klever VM and klever-sc errors (execution failed, out of gas, argument or storage decode errors, signalError messages raised by the contract, payment and owner checks, ...) and returns the likely root cause and fix. Pass the error text, or the hash of the failed transaction to read its result code, error logs and called endpoint from the chain. With the contract ABI (passed, or used earlier in the session) adds what the endpoint expects: its signature, accepted payments or the closest existing endpoint name.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        error: {
+          type: 'string',
+          description: 'Error text, e.g. a returnMessage or the message of a failed call',
+        },
+        hash: {
+          type: 'string',
+          description: 'Hash of the failed transaction (used when error is not given)',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network of the transaction (default: the server default)',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the called contract',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        endpoint: {
+          type: 'string',
+          description: 'Called endpoint, when it cannot be read from the transaction',
+        },
+      },
+    },
+    annotations: {
+      title: 'Explain Error',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { error, hash, network, abi, abiUrl, endpoint } = args as {
+      error?: string;
+      hash?: string;
+      network?: string;
+      abi?: string;
+      abiUrl?: string;
+      endpoint?: string;
+    };
+    if (!error && !hash) throw new Error('Pass the error text or the transaction hash');
+
+    let texts = error ? [error] : [];
+    let fn = endpoint;
+    let contract: string | undefined;
+    let status: string | undefined;
+    if (!error) {
+      const tx = await context.chainClient.getTransaction(hash!, validateNetwork(network));
+      status = tx.status;
+      texts = transactionErrors(tx);
+      fn = fn || calledFunction(tx);
+      contract = transactionContract(tx);
+      if (texts.length === 0) {
+        return {
+          hash,
+          status,
+          matches: [],
+          nextSteps: [
+            status === 'success'
+              ? 'The transaction succeeded; nothing to explain'
+              : 'The transaction carries no error text yet; check it with get_transaction',
+          ],
+        };
+      }
+    }
+
+    let contractAbi: Abi | undefined;
+    if (abi || abiUrl) {
+      contractAbi = await loadAbi(abi, abiUrl, context.profile);
+      if (contract) context.abiRegistry.register(contract, contractAbi);
+    } else if (contract) {
+      contractAbi = context.abiRegistry.get(contract);
+    }
+
+    const explanation = explainVmError(texts, { abi: contractAbi, endpoint: fn });
+    const nextSteps: string[] = [];
+    if (explanation.matches.length === 0) {
+      nextSteps.push(
+        'The error is not in the catalog; search the contract source and the framework docs (search_docs) for it'
+      );
+    }
+    if (!contractAbi && explanation.matches.length > 0) {
+      nextSteps.push('Pass the contract abi to compare the call with what the endpoint expects');
+    }
+    return {
+      ...(hash && !error ? { hash, status, contract } : {}),
+      endpoint: fn,
+      ...explanation,
+      nextSteps,
+    };
+  },
+};
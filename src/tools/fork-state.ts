@@ -0,0 +1,154 @@
+import { mkdir, writeFile } from 'node:fs/promises';
+import { basename, dirname, join, relative, resolve } from 'node:path';
+import type { KleverTool } from './types.js';
+import {
+  decodeAddress,
+  NETWORK_NAMES,
+  validateNetwork,
+  type AccountData,
+} from '../chain/index.js';
+import { forkScenario, type ForkedAccount } from '../codegen/index.js';
+import { collectArtifacts } from '../toolchain/index.js';
+
+export const forkStateTool: KleverTool = {
+  definition: {
+    name: 'fork_state',
+    description:
+      "Snapshot a deployed contract's storage, balances and owner, together with the owner's and any other listed accounts, from a network into a scenario file of a local contract project (scenarios/<name>.fork.scen.json, a single setState step). The contract account runs the project's local build, so scenarios that start from the snapshot (externalSteps) rehearse upgrades and bug fixes against real data in the VM, with the block nonce and time of the snapshot. Overwrites an earlier snapshot of the same name.",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        projectPath: {
+          type: 'string',
+          description: 'Contract project root (the directory with Cargo.toml and scenarios/)',
+        },
+        address: { type: 'string', description: 'Deployed contract address (klv1...)' },
+        accounts: {
+          type: 'array',
+          items: { type: 'string' },
+          description:
+            'Other accounts to snapshot (klv1...), e.g. the users a rehearsed call comes from',
+        },
+        name: {
+          type: 'string',
+          description: 'Snapshot name, used in the file name (default: the contract wasm name)',
+        },
+        codePath: {
+          type: 'string',
+          description:
+            'Contract code as seen from scenarios/ (default: the output/*.wasm of the project)',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network to read the state from (default: the server default)',
+        },
+      },
+      required: ['projectPath', 'address'],
+    },
+    annotations: {
+      title: 'Fork State',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: true,
+    },
+  },
+  localOnly: true,
+  handler: async (args, context) => {
+    const { projectPath, address, accounts = [], name, codePath, network } = args as {
+      projectPath: string;
+      address: string;
+      accounts?: string[];
+      name?: string;
+      codePath?: string;
+      network?: string;
+    };
+    decodeAddress(address);
+    accounts.forEach(account => decodeAddress(account));
+    const net = validateNetwork(network);
+    const root = resolve(projectPath);
+
+    let code = codePath;
+    let codeName = name;
+    if (!code) {
+      const wasm = (await collectArtifacts(root)).find(a => a.kind === 'wasm');
+      if (!wasm) {
+        throw new Error('No output/*.wasm in the project; build it first or pass codePath');
+      }
+      code = relative(join(root, 'scenarios'), wasm.path).split('\\').join('/');
+      codeName = codeName || basename(wasm.path, '.wasm');
+    }
+
+    const [contract, storage] = await Promise.all([
+      context.chainClient.getAccount(address, net),
+      context.chainClient.getStorage(address, net),
+    ]);
+    const snapshot = (data: AccountData): ForkedAccount => ({
+      address: data.address,
+      nonce: data.nonce || 0,
+      balance: data.balance || 0,
+      kda: Object.fromEntries(
+        Object.values(data.assets || {})
+          .filter(asset => asset.assetId !== 'KLV')
+          .map(asset => [asset.assetId, asset.balance])
+      ),
+    });
+    const forked: ForkedAccount[] = [
+      {
+        ...snapshot(contract),
+        address,
+        storage,
+        owner: contract.ownerAddress,
+        codePath: code,
+      },
+    ];
+    const others = [
+      ...new Set([...(contract.ownerAddress ? [contract.ownerAddress] : []), ...accounts]),
+    ].filter(account => account !== address);
+    for (const account of others) {
+      try {
+        const data = await context.chainClient.getAccount(account, net);
+        forked.push({ ...snapshot(data), address: account });
+      } catch {
+        // Accounts the node does not know yet have no state
+        forked.push({ address: account, nonce: 0, balance: 0 });
+      }
+    }
+    let blockNonce: number | undefined;
+    try {
+      blockNonce = (await context.chainClient.getNodeStatus(net)).currentNonce;
+    } catch {
+      // The snapshot time still sets the block timestamp
+    }
+
+    const networkName = net || context.chainClient.getDefaultNetwork();
+    const result = forkScenario({
+      name: codeName || 'contract',
+      accounts: forked,
+      source: `${address} on ${networkName}`,
+      blockNonce,
+    });
+    const path = join(root, result.path);
+    await mkdir(dirname(path), { recursive: true });
+    await writeFile(path, result.json);
+
+    const file = result.path.replace(/^scenarios\//, '');
+    return {
+      path,
+      address,
+      network: networkName,
+      blockNonce,
+      accounts: forked.map(account => ({
+        address: account.address,
+        balance: String(account.balance),
+        storageEntries: Object.keys(account.storage || {}).length,
+      })),
+      nextSteps: [
+        `Start scenarios from the snapshot with {"step": "externalSteps", "path": "${file}"}, then add the calls to rehearse (generate_scenario writes them)`,
+        'The contract runs the local build: rebuild with build_contract after changing the code',
+        'Run the scenarios with run_tests; take a new snapshot with fork_state when the chain state moves on',
+      ],
+    };
+  },
+};
@@ -0,0 +1,57 @@
+import type { KleverTool } from './types.js';
+import { parseAbi } from '../abi/index.js';
+import { generateAsyncCall } from '../codegen/index.js';
+
+export const generateAsyncCallTool: KleverTool = {
+  definition: {
+    name: 'generate_async_call',
+    description:
+      'Scaffold a cross-contract async call to one endpoint of another contract, given its ABI (*.abi.json). Returns the proxy module (src/<name>_proxy.rs), the #[proxy] accessor, a calling endpoint that forwards the KLV/KDA payment and the remaining gas minus a callback reserve, and a #[callback] that matches ManagedAsyncCallResult::Ok / Err and refunds the payment on failure. Nothing is written to disk.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the target contract (contents of output/<name>.abi.json)',
+        },
+        endpoint: {
+          type: 'string',
+          description: 'Name of the target endpoint as it appears in the ABI',
+        },
+        callerName: {
+          type: 'string',
+          description: 'Name of the generated calling endpoint (default: call_<endpoint>)',
+        },
+        callbackGas: {
+          type: 'number',
+          description: 'Gas reserved for the callback (default: 10000000)',
+        },
+      },
+      required: ['abi', 'endpoint'],
+    },
+    annotations: {
+      title: 'Generate Async Call',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { abi, endpoint, callerName, callbackGas } = args as {
+      abi: string;
+      endpoint: string;
+      callerName?: string;
+      callbackGas?: number;
+    };
+    const result = generateAsyncCall(parseAbi(abi), endpoint, { callerName, callbackGas });
+    return {
+      ...result,
+      nextSteps: [
+        `Save ${result.proxyFile.path} and add "${result.modDeclaration}" to src/lib.rs`,
+        'Add the CALLBACK_GAS constant above the contract trait and traitItems inside it',
+        'Rebuild with: ~/klever-sdk/ksc all build',
+      ],
+    };
+  },
+};
@@ -0,0 +1,44 @@
+import type { KleverTool } from './types.js';
+import { generateEndpoint } from '../codegen/index.js';
+
+export const generateEndpointTool: KleverTool = {
+  definition: {
+    name: 'generate_endpoint',
+    description:
+      'Generate a complete klever-sc endpoint from a short spec such as "owner-only endpoint that freezes an account" and insert it into existing contract source. The endpoint gets require! validation, storage access and an event; storage mappers, events and require_owner() helpers already declared in the trait are reused, and only missing declarations are added. Supports flag actions (freeze/unfreeze, pause, ban, lock, enable), set/update <field>, add/remove <item> to/from <list>, deposit and withdraw. Returns the modified source; nothing is written to disk.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        spec: {
+          type: 'string',
+          description:
+            'What the endpoint does, e.g. "owner-only endpoint that sets the fee percent" or "add an address to the whitelist"',
+        },
+        source: {
+          type: 'string',
+          description:
+            'Full contract source (src/lib.rs) containing the #[klever_sc::contract] trait',
+        },
+      },
+      required: ['spec', 'source'],
+    },
+    annotations: {
+      title: 'Generate Endpoint',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { spec, source } = args as { spec: string; source: string };
+    const result = generateEndpoint(spec, source);
+    return {
+      ...result,
+      nextSteps: [
+        'Review the generated require! messages and event fields',
+        'Rebuild with: ~/klever-sdk/ksc all build',
+      ],
+    };
+  },
+};
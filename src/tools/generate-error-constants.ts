@@ -0,0 +1,45 @@
+import type { KleverTool } from './types.js';
+import { generateErrorConstants } from '../analysis/index.js';
+
+export const generateErrorConstantsTool: KleverTool = {
+  definition: {
+    name: 'generate_error_constants',
+    description:
+      'Move the literal error messages of require! and sc_panic! calls in a klever-sc contract into a shared src/errors.rs module of `pub const ERR_...: &str` constants and rewrite the calls to use them. Differently worded copies of the same message (e.g. "Insufficient balance" and "insufficient balance.") share one constant with the most common wording; messages with format arguments stay inline. Returns the rewritten files; nothing is written to disk.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        source: {
+          type: 'string',
+          description:
+            'Full contract source (src/lib.rs) containing the #[klever_sc::contract] trait',
+        },
+        modules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description:
+            'Module files of the same crate keyed by path (e.g. {"src/storage.rs": "..."}), rewritten together with the contract',
+        },
+      },
+      required: ['source'],
+    },
+    annotations: {
+      title: 'Generate Error Constants',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { source, modules } = args as { source: string; modules?: Record<string, string> };
+    const result = generateErrorConstants(source, modules);
+    return {
+      ...result,
+      nextSteps: [
+        'Write src/errors.rs and replace the rewritten files',
+        'Rebuild with: ~/klever-sdk/ksc all build',
+      ],
+    };
+  },
+};
@@ -0,0 +1,62 @@
+import type { KleverTool } from './types.js';
+import { DEFAULT_MAX_INDEXED, generateEvent, type EventFieldSpec } from '../codegen/index.js';
+
+export const generateEventTool: KleverTool = {
+  definition: {
+    name: 'generate_event',
+    description: `Generate a klever-sc #[event] declaration and its emit call. Fields are made #[indexed] topics based on how they are queried (addresses, token ids and entity ids first); the weakest candidate, usually an amount or payload, becomes the single allowed non-indexed data field. Warns when more than the recommended ${DEFAULT_MAX_INDEXED} topics are indexed or when several data fields are requested. Pass the contract source to get it back with the declaration inserted.`,
+    inputSchema: {
+      type: 'object',
+      properties: {
+        eventName: {
+          type: 'string',
+          description: 'Event identifier, e.g. "tokensMinted" (snake_case is converted)',
+        },
+        fields: {
+          type: 'array',
+          description: 'Event fields in emit order',
+          items: {
+            type: 'object',
+            properties: {
+              name: { type: 'string', description: 'Parameter name (snake_case)' },
+              type: {
+                type: 'string',
+                description: 'Rust type without reference, e.g. ManagedAddress, BigUint, u64',
+              },
+              indexed: {
+                type: 'boolean',
+                description: 'Force topic (true) or data field (false); omit to let the tool decide',
+              },
+            },
+            required: ['name', 'type'],
+          },
+        },
+        maxIndexed: {
+          type: 'number',
+          description: `Indexed fields allowed before warning (default: ${DEFAULT_MAX_INDEXED})`,
+        },
+        source: {
+          type: 'string',
+          description: 'Optional contract source (src/lib.rs) to insert the declaration into',
+        },
+      },
+      required: ['eventName', 'fields'],
+    },
+    annotations: {
+      title: 'Generate Event',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { eventName, fields, maxIndexed, source } = args as {
+      eventName: string;
+      fields: EventFieldSpec[];
+      maxIndexed?: number;
+      source?: string;
+    };
+    return { ...generateEvent(eventName, fields, { maxIndexed, source }) };
+  },
+};
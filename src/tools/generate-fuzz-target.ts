@@ -0,0 +1,106 @@
+import type { KleverTool } from './types.js';
+import { generateFuzzTarget, type AbiArgs, type FuzzInvariant } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+export const generateFuzzTargetTool: KleverTool = {
+  definition: {
+    name: 'generate_fuzz_target',
+    description:
+      'Generate a cargo-fuzz crate (fuzz/) for a contract from its ABI: a libFuzzer target whose input, derived with arbitrary, deploys the contract and makes a sequence of calls to the chosen endpoints with fuzzed callers, arguments and KLV/KDA payments, run against the Rust VM of klever-sc-scenario. After the deploy and every call it fails on errors other than require!/sc_panic! rejections and runs invariant hooks: a token ABI with a supply view and a balance-by-address view gets a supply-equals-sum-of-balances check, and invariants adds your own Rust checks. Endpoints whose argument types cannot be fuzzed are reported in skipped.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        abi: {
+          type: 'string',
+          description: 'ABI JSON (contents of output/<name>.abi.json)',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        endpoints: {
+          type: 'array',
+          items: { type: 'string' },
+          description: 'Endpoints to fuzz (default: every endpoint that is not a view)',
+        },
+        deployArgs: {
+          description:
+            'Constructor arguments as plain JSON, in ABI order or keyed by argument name; fuzzed when left out',
+          oneOf: [{ type: 'array' }, { type: 'object' }],
+        },
+        invariants: {
+          type: 'array',
+          items: {
+            type: 'object',
+            properties: {
+              name: { type: 'string', description: 'snake_case Rust function name' },
+              body: {
+                type: 'string',
+                description:
+                  'Rust statements checking the state, with world the scenario world; query, query_biguint, address, fail, ACCOUNTS and CONTRACT are in scope',
+              },
+              description: { type: 'string', description: 'What the check asserts' },
+            },
+            required: ['name', 'body'],
+          },
+          description: 'Extra checks run after the deploy and every call',
+        },
+        tokens: {
+          type: 'array',
+          items: { type: 'string' },
+          description: 'Token identifiers the accounts hold (default: FUZZ-0001)',
+        },
+        maxCalls: {
+          type: 'number',
+          description: 'Most calls one fuzz input makes after the deploy (default: 16)',
+        },
+        target: {
+          type: 'string',
+          description: 'Fuzz target name (default: the endpoint name, or endpoints)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Generate Fuzz Target',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { abi, abiUrl, endpoints, deployArgs, invariants, tokens, maxCalls, target } =
+      args as {
+        abi?: string;
+        abiUrl?: string;
+        endpoints?: string[];
+        deployArgs?: AbiArgs;
+        invariants?: FuzzInvariant[];
+        tokens?: string[];
+        maxCalls?: number;
+        target?: string;
+      };
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    const generated = generateFuzzTarget(parsed, {
+      endpoints,
+      deployArgs,
+      invariants,
+      tokens,
+      maxCalls,
+      target,
+    });
+    return {
+      ...generated,
+      nextSteps: [
+        `Save the files under ${generated.directory}/ in the contract crate`,
+        'Build the contract wasm first (ksc all build); the harness deploys output/*.wasm',
+        'Install cargo-fuzz once: cargo install cargo-fuzz (libFuzzer needs a nightly toolchain)',
+        `Run cargo +nightly fuzz run ${generated.target}; failing inputs land in ${generated.directory}/artifacts/`,
+        `Add contract-specific checks to check_invariants in fuzz_targets/${generated.target}.rs`,
+        ...(generated.skipped.length > 0
+          ? [`Not fuzzed (argument types): ${generated.skipped.map(s => s.endpoint).join(', ')}`]
+          : []),
+      ],
+    };
+  },
+};
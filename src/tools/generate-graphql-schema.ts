@@ -0,0 +1,59 @@
+import type { KleverTool } from './types.js';
+import { generateGraphql, storageLayout } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+export const generateGraphqlSchemaTool: KleverTool = {
+  definition: {
+    name: 'generate_graphql_schema',
+    description:
+      "Generate a GraphQL schema for a contract from its ABI and the storage mappers in its source, for indexer-style APIs: a Query field per view, a nested storage field with one field per mapper (single values by their key arguments; Vec, set, map and whitelist mappers as lists), a Subscription field per event, and types for the custom structs and enums. Also emits resolvers.ts, a resolver stub whose fields call this server's query_view, get_contract_storage and subscribe_events tools through an MCP client's callTool. Mappers without a list form (e.g. UserMapper) are reported in skipped.",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        abi: {
+          type: 'string',
+          description: 'ABI JSON (contents of output/<name>.abi.json)',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        source: {
+          type: 'string',
+          description: 'Contract source (src/lib.rs) declaring the storage mappers',
+        },
+        modules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description: 'Module files of the contract keyed by path',
+        },
+      },
+    },
+    annotations: {
+      title: 'Generate GraphQL Schema',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { abi, abiUrl, source, modules = {} } = args as {
+      abi?: string;
+      abiUrl?: string;
+      source?: string;
+      modules?: Record<string, string>;
+    };
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    const storage = storageLayout([...(source ? [source] : []), ...Object.values(modules)]);
+    const generated = generateGraphql(parsed, { storage });
+    return {
+      ...generated,
+      nextSteps: [
+        `Save the files under ${generated.directory}/ in the API project`,
+        'Serve schema.graphql with the resolvers of createResolvers(callTool, { address, abi })',
+        ...(source ? [] : ['Pass source (and modules) to query the storage mappers too']),
+      ],
+    };
+  },
+};
@@ -0,0 +1,78 @@
+import type { KleverTool } from './types.js';
+import { decodeAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { generateInteractor } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+export const generateInteractorTool: KleverTool = {
+  definition: {
+    name: 'generate_interactor',
+    description:
+      'Generate a standalone Rust binary crate (interactor) for deploying and calling a contract from off-chain code. The crate has a typed proxy with a deploy method and one async method per endpoint and view, mirroring the contract trait: endpoints are built on the node, signed with a koperator wallet PEM and awaited, views are queried and decoded. Node URL, wallet, contract address and gas limit default to config.rs and can be overridden with environment variables. Returns the files of the crate, meant for an interactor/ directory next to the contract.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        abi: {
+          type: 'string',
+          description:
+            'ABI JSON (contents of output/<name>.abi.json). Arguments are encoded from Rust types: BigUint from num-bigint, Address parsed from klv1..., optional<T> as Option<T>, variadic<T> as Vec<T>, custom structs and enums re-declared with their encoding',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        address: {
+          type: 'string',
+          description: 'Deployed contract address (klv1...) the interactor calls by default',
+        },
+        network: {
+          type: 'string',
+          enum: [...NETWORK_NAMES],
+          description: 'Network whose node and API the interactor uses by default',
+        },
+        gasLimit: {
+          type: 'number',
+          description: 'Gas limit sent with endpoint calls (default: left to the node)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Generate Rust Interactor',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { abi, abiUrl, address, network, gasLimit } = args as {
+      abi?: string;
+      abiUrl?: string;
+      address?: string;
+      network?: string;
+      gasLimit?: number;
+    };
+    if (address) decodeAddress(address);
+    if (gasLimit !== undefined && (!Number.isSafeInteger(gasLimit) || gasLimit <= 0)) {
+      throw new Error('gasLimit must be a positive integer');
+    }
+    const net = validateNetwork(network);
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    const interactor = generateInteractor(parsed, {
+      nodeUrl: context.chainClient.getNodeUrl(net),
+      apiUrl: context.chainClient.getApiUrl(net),
+      address,
+      gasLimit,
+    });
+    const { directory } = interactor;
+    const commands = interactor.commands.join(', ') || 'none';
+    return {
+      ...interactor,
+      nextSteps: [
+        `Save the files under ${directory}/ in the contract project`,
+        `Put the wallet PEM at ${directory}/wallet.pem or point KLEVER_WALLET at it`,
+        `Run cd ${directory} && cargo run -- <command>; commands: ${commands}`,
+        'Call the other endpoints from src/main.rs through the typed proxy in src/proxy.rs',
+      ],
+    };
+  },
+};
@@ -0,0 +1,191 @@
+import type { KleverTool } from './types.js';
+import { decodeAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { findAbiEndpoint } from '../abi/index.js';
+import {
+  koperatorDeployCommand,
+  koperatorInvokeCommand,
+  koperatorUpgradeCommand,
+  type AbiArgs,
+  type KoperatorCommand,
+} from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+import { checkPayable, PAYMENT_PROPERTIES, type Payment } from './transaction-input.js';
+
+type Action = 'deploy' | 'upgrade' | 'invoke';
+
+export const generateKoperatorCommandTool: KleverTool = {
+  definition: {
+    name: 'generate_koperator_command',
+    description:
+      'Render the koperator command that deploys, upgrades or calls a contract, for signing on another (e.g. air-gapped) machine: arguments are converted from plain JSON with the ABI into typed --args values, payments into --values. Also returns the hex arguments and invoke data the transaction will carry so they can be checked before signing. Nothing is signed or sent. Subcommands and flags are documented in klever://reference/koperator.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        action: {
+          type: 'string',
+          enum: ['deploy', 'upgrade', 'invoke'],
+          description: 'sc create, sc upgrade or sc invoke (default: invoke)',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the contract',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        address: {
+          type: 'string',
+          description: 'Contract address (klv1...), required for upgrade and invoke',
+        },
+        endpoint: {
+          type: 'string',
+          description: 'Endpoint name as in the ABI, required for invoke',
+        },
+        args: {
+          description:
+            'Constructor, upgrade or endpoint arguments as plain JSON, as for invoke_endpoint',
+          oneOf: [{ type: 'array' }, { type: 'object' }],
+        },
+        ...PAYMENT_PROPERTIES,
+        wasmPath: {
+          type: 'string',
+          description: 'Built contract for deploy and upgrade (default: output/<name>.wasm)',
+        },
+        upgradeable: {
+          type: 'boolean',
+          description: 'Deploy as upgradeable (default: true)',
+        },
+        readable: {
+          type: 'boolean',
+          description: 'Deploy with readable storage (default: true)',
+        },
+        payable: {
+          type: 'boolean',
+          description: 'Deploy as payable (default: true)',
+        },
+        payableBySC: {
+          type: 'boolean',
+          description: 'Deploy as payable by other contracts (default: true)',
+        },
+        keyFile: {
+          type: 'string',
+          description:
+            'Key file path on the signing machine (default: $HOME/klever-sdk/walletKey.pem)',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network to send to, set as KLEVER_NODE (default: koperator setting)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Generate koperator Command',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async (args, context) => {
+    const {
+      action = 'invoke',
+      abi,
+      abiUrl,
+      address,
+      endpoint,
+      args: values,
+      klv,
+      kda = [],
+      wasmPath,
+      upgradeable,
+      readable,
+      payable,
+      payableBySC,
+      keyFile,
+      network,
+    } = args as {
+      action?: Action;
+      abi?: string;
+      abiUrl?: string;
+      address?: string;
+      endpoint?: string;
+      args?: AbiArgs;
+      klv?: string | number;
+      kda?: Payment[];
+      wasmPath?: string;
+      upgradeable?: boolean;
+      readable?: boolean;
+      payable?: boolean;
+      payableBySC?: boolean;
+      keyFile?: string;
+      network?: string;
+    };
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    const net = validateNetwork(network);
+    const common = { keyFile, nodeUrl: net ? context.chainClient.getNodeUrl(net) : undefined };
+    const payments = [...(klv !== undefined ? [{ token: 'KLV', amount: klv }] : []), ...kda];
+    if (action !== 'invoke' && payments.length > 0) {
+      throw new Error('klv/kda payments are only supported for invoke');
+    }
+    if (action !== 'deploy') {
+      if (!address) throw new Error(`address is required for ${action}`);
+      decodeAddress(address);
+    }
+
+    let result: KoperatorCommand;
+    if (action === 'deploy') {
+      result = koperatorDeployCommand(parsed, {
+        ...common,
+        wasmPath,
+        args: values,
+        upgradeable,
+        readable,
+        payable,
+        payableBySC,
+      });
+    } else if (action === 'upgrade') {
+      result = koperatorUpgradeCommand(parsed, {
+        ...common,
+        address: address!,
+        wasmPath,
+        args: values,
+      });
+    } else {
+      if (!endpoint) throw new Error('endpoint is required for invoke');
+      const found = findAbiEndpoint(parsed, endpoint);
+      // NFT and SFT payments are accepted by collection; the nonce only selects the item
+      const tokens: Record<string, number> = {};
+      for (const { token } of payments) tokens[token.split('/')[0]] = 1;
+      checkPayable(found, payments.length > 0 ? tokens : undefined);
+      result = koperatorInvokeCommand(parsed, {
+        ...common,
+        address: address!,
+        endpoint: found.name,
+        args: values,
+        payments,
+      });
+    }
+
+    const nextSteps = [
+      'Copy the command to the signing machine; koperator must be installed there with the key file',
+    ];
+    if (action === 'deploy') {
+      nextSteps.push(
+        'Build the contract first (build_contract) and copy the .wasm next to the command'
+      );
+      if (upgradeable === false) {
+        nextSteps.push('The contract is deployed without --upgradeable and cannot be upgraded');
+      }
+    } else if (action === 'upgrade') {
+      nextSteps.push('The contract must have been deployed with --upgradeable');
+    }
+    if (result.callData) {
+      nextSteps.push(
+        'Compare the data field of the signed transaction with callData (decode_tx_data shows it per argument)'
+      );
+    }
+    return { action, ...result, nextSteps };
+  },
+};
@@ -0,0 +1,59 @@
+import type { KleverTool } from './types.js';
+import { generateMigration } from '../codegen/index.js';
+
+export const generateMigrationTool: KleverTool = {
+  definition: {
+    name: 'generate_migration',
+    description:
+      'Diff the storage mappers of two versions of a klever-sc contract and generate an upgrade-time migration function: renamed storage keys are copied to the new key (SingleValueMapper, VecMapper, SetMapper, UnorderedSetMapper, MapMapper), widened integer types (u32 -> u64 -> BigUint) are converted or confirmed encoding-compatible, new mappers get optional default values and removed single values are cleared. Changes that cannot be migrated safely (narrowed or incompatible types, changed key arguments, mapper type changes) are flagged with safe: false. Returns the new source; nothing is written to disk.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        oldSource: {
+          type: 'string',
+          description: 'Contract source (src/lib.rs) of the currently deployed version',
+        },
+        newSource: {
+          type: 'string',
+          description: 'Contract source (src/lib.rs) of the version being deployed',
+        },
+        version: {
+          type: 'number',
+          description:
+            'Schema version this migration upgrades to; names the function migrate_storage_v<N> and calls it from the matching migrate() step created by add_upgrade_support',
+        },
+        defaults: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description:
+            'Initial values for new storage mappers, as Rust expressions keyed by function name (e.g. {"max_deposit": "BigUint::from(1_000u64)"})',
+        },
+      },
+      required: ['oldSource', 'newSource'],
+    },
+    annotations: {
+      title: 'Generate Storage Migration',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { oldSource, newSource, version, defaults } = args as {
+      oldSource: string;
+      newSource: string;
+      version?: number;
+      defaults?: Record<string, string>;
+    };
+    const result = generateMigration(oldSource, newSource, { version, defaults });
+    return {
+      ...result,
+      nextSteps: [
+        'Review every change with safe: false and migrate it by hand',
+        'Rebuild with: ~/klever-sdk/ksc all build',
+        'Test the upgrade against a copy of the deployed state before upgrading on mainnet',
+      ],
+    };
+  },
+};
@@ -0,0 +1,69 @@
+import type { KleverTool } from './types.js';
+import { decodeAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { generateOpenApi } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+export const generateOpenApiTool: KleverTool = {
+  definition: {
+    name: 'generate_openapi',
+    description:
+      "Render a contract's views and endpoints as an OpenAPI 3.1 document from its ABI, for REST gateways and generated API docs. Views become GET /views/{name} operations taking their arguments as query parameters (compound values as JSON text) and answering the decoded results; endpoints become POST /endpoints/{name} operations taking a sender, arguments and, when payable, a callValue, and answering the unsigned transaction to sign and broadcast. Custom structs and enums become component schemas; values follow the plain-JSON conventions of the other ABI tools, with integers above 32 bits as numbers or decimal strings.",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        abi: {
+          type: 'string',
+          description: 'ABI JSON (contents of output/<name>.abi.json)',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        address: {
+          type: 'string',
+          description: 'Deployed contract address (klv1...), noted in the API description',
+        },
+        network: {
+          type: 'string',
+          enum: [...NETWORK_NAMES],
+          description: 'Network the contract is deployed on, noted with the address',
+        },
+        serverUrl: {
+          type: 'string',
+          description: 'Base URL of the gateway serving the API, listed under servers',
+        },
+      },
+    },
+    annotations: {
+      title: 'Generate OpenAPI Spec',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { abi, abiUrl, address, network, serverUrl } = args as {
+      abi?: string;
+      abiUrl?: string;
+      address?: string;
+      network?: string;
+      serverUrl?: string;
+    };
+    if (address) decodeAddress(address);
+    const net = validateNetwork(network);
+    if (serverUrl && !/^https?:\/\//.test(serverUrl)) {
+      throw new Error(`serverUrl must be an http(s) URL, got ${JSON.stringify(serverUrl)}`);
+    }
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    const generated = generateOpenApi(parsed, { serverUrl, address, network: net });
+    return {
+      ...generated,
+      nextSteps: [
+        `Save spec as ${generated.path}, or load it into Swagger UI or Redoc for API docs`,
+        'In the gateway, answer views with a VM query and endpoints with /transaction/send',
+        'Clients sign the returned txHash and broadcast { tx, signature } to the node',
+      ],
+    };
+  },
+};
@@ -0,0 +1,57 @@
+import type { KleverTool } from './types.js';
+import { generatePayableEndpoint, type PayableEndpointOptions } from '../codegen/index.js';
+
+export const generatePayableEndpointTool: KleverTool = {
+  definition: {
+    name: 'generate_payable_endpoint',
+    description:
+      'Generate a payable klever-sc endpoint with correct call_value() handling. Modes: "klv" (#[payable("KLV")] with klv_value()), "single" (#[payable("*")] with single_kda()) and "multi" (all_kda_transfers() with per-payment validation). Adds token identifier validation (fixed list or an accepted_tokens storage set), fungible-only and minimum-amount checks, and in multi mode refunds rejected payments with direct_multi instead of failing the call. Optionally inserts the endpoint into contract source.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        name: { type: 'string', description: 'Endpoint name, e.g. deposit or addLiquidity' },
+        mode: {
+          type: 'string',
+          enum: ['klv', 'single', 'multi'],
+          description: 'Payment shape the endpoint accepts',
+        },
+        acceptedTokens: {
+          type: 'array',
+          items: { type: 'string' },
+          description: 'KDA token identifiers to accept (e.g. ["USDT-A1B2"]); omit to accept any',
+        },
+        acceptedTokensStorage: {
+          type: 'boolean',
+          description: 'Validate against an accepted_tokens UnorderedSetMapper instead of a list',
+        },
+        allowNonFungible: {
+          type: 'boolean',
+          description: 'Accept NFT/SFT payments (non-zero nonce); default false',
+        },
+        minAmount: {
+          type: 'string',
+          description: 'Minimum amount per payment in raw units',
+        },
+        refundRejected: {
+          type: 'boolean',
+          description: 'multi mode: refund invalid payments instead of failing (default: true)',
+        },
+        source: {
+          type: 'string',
+          description: 'Optional contract source (src/lib.rs) to insert the endpoint into',
+        },
+      },
+      required: ['name', 'mode'],
+    },
+    annotations: {
+      title: 'Generate Payable Endpoint',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    return { ...generatePayableEndpoint(args as unknown as PayableEndpointOptions) };
+  },
+};
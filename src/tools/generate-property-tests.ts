@@ -0,0 +1,84 @@
+import type { KleverTool } from './types.js';
+import { auditAccessControl } from '../analysis/index.js';
+import { generateProptest, type AbiArgs } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+export const generatePropertyTestsTool: KleverTool = {
+  definition: {
+    name: 'generate_property_tests',
+    description:
+      "Generate proptest property tests (tests/<crate>_proptest.rs) for a token contract, asserting invariants inferred from its ABI on random callers and amounts in the Rust VM: transfers conserve the total supply and the sum of balances, transfers and burns of more than the balance fail without changing it (no underflow), and only the owner can mint. Successful transfers and mints must also emit their event with the expected topics and data. Invariants that do not apply are listed in notInferred. Extend the proptest! block with the contract's own properties.",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        abi: {
+          type: 'string',
+          description:
+            'ABI JSON (contents of output/<name>.abi.json). Supply and balance views, transfer, burn and mint endpoints are recognized by name and signature. Events named like transfer or mint are matched to those calls: their indexed topics must hold the accounts and their data the amount, so event payloads are tested, not just return codes',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        source: {
+          type: 'string',
+          description:
+            'Contract source (src/lib.rs), to find owner checks such as require_owner() the ABI does not record. Mint counts as owner-only when the ABI says #[only_owner] or an owner guard in the source protects it',
+        },
+        modules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description: 'Module files of the contract keyed by path',
+        },
+        deployArgs: {
+          description:
+            'Constructor arguments as plain JSON, in ABI order or keyed by argument name (default: an initial supply of 1000000000 when the constructor takes one number)',
+          oneOf: [{ type: 'array' }, { type: 'object' }],
+        },
+        cases: {
+          type: 'number',
+          description: 'Random cases proptest runs per property (default: 64)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Generate Property Tests',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { abi, abiUrl, source, modules, deployArgs, cases } = args as {
+      abi?: string;
+      abiUrl?: string;
+      source?: string;
+      modules?: Record<string, string>;
+      deployArgs?: AbiArgs;
+      cases?: number;
+    };
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    const ownerOnly = source
+      ? auditAccessControl(source, modules)
+          .endpoints.filter(e => e.access === 'owner-only')
+          .map(e => e.endpoint)
+      : [];
+    const generated = generateProptest(parsed, { deployArgs, ownerOnly, cases });
+    const devDependencies = Object.entries(generated.devDependencies)
+      .map(([name, version]) => `${name} = "${version}"`)
+      .join(', ');
+    return {
+      ...generated,
+      nextSteps: [
+        `Save ${generated.path} in the contract crate`,
+        `Add ${devDependencies} to [dev-dependencies] in Cargo.toml`,
+        'Build the contract wasm first (ksc all build); the tests deploy output/*.wasm',
+        `Run cargo test --test ${generated.path.replace(/^tests\/|\.rs$/g, '')}`,
+        ...(source || generated.properties.some(p => p.name === 'only_owner_mints')
+          ? []
+          : ['Pass source to recognize owner checks written as require_owner()-style guards']),
+      ],
+    };
+  },
+};
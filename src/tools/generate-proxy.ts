@@ -0,0 +1,58 @@
+import type { KleverTool } from './types.js';
+import { generateProxy } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+export const generateProxyTool: KleverTool = {
+  definition: {
+    name: 'generate_proxy',
+    description:
+      'Generate a #[klever_sc::proxy] module from a contract ABI (*.abi.json) so another contract can call it type-safely. ABI types are mapped back to managed Rust types (Address -> ManagedAddress, List<T> -> ManagedVec<T>, optional<T> -> OptionalValue<T>, variadic<T> -> MultiValueEncoded<T>, ...), custom structs and enums are re-declared with the right derives and <M: ManagedTypeApi> parameters, and views/payable endpoints keep their annotations. Pass the ABI JSON directly or an abiUrl to fetch it. Returns the proxy file, the pub mod line and the #[proxy] accessor.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        abi: {
+          type: 'string',
+          description: 'ABI JSON (contents of output/<name>.abi.json)',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        endpoints: {
+          type: 'array',
+          items: { type: 'string' },
+          description: 'Only include these endpoints (default: all)',
+        },
+        includeInit: {
+          type: 'boolean',
+          description: 'Include the constructor as #[init] for deploying via the proxy (default: true)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Generate Contract Proxy',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { abi, abiUrl, endpoints, includeInit } = args as {
+      abi?: string;
+      abiUrl?: string;
+      endpoints?: string[];
+      includeInit?: boolean;
+    };
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    const proxy = generateProxy(parsed, { endpoints, includeInit });
+    return {
+      ...proxy,
+      nextSteps: [
+        `Save ${proxy.path} and add "${proxy.modDeclaration}" to src/lib.rs`,
+        'Add proxyAccessor to the contract trait and call it with self.<name>_contract(address).<endpoint>(...)',
+        'Use .execute_on_dest_context::<T>() for synchronous calls or generate_async_call for callbacks',
+      ],
+    };
+  },
+};
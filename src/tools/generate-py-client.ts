@@ -0,0 +1,63 @@
+import type { KleverTool } from './types.js';
+import { decodeAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { generatePyClient } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+export const generatePyClientTool: KleverTool = {
+  definition: {
+    name: 'generate_py_client',
+    description:
+      'Generate a typed Python client module for a contract from its ABI, for backend services and notebooks. Emits dataclasses for the custom structs, type aliases for enums, and a class with one method per view (queried on a node, results decoded into Python values) and per endpoint (arguments ABI-encoded, an unsigned transaction built on the node from the given sender; payable endpoints take a call_value). send() signs a built transaction with a koperator wallet PEM and broadcasts it. The module carries its own ABI codec and only needs the standard library, plus the cryptography package for signing.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        abi: {
+          type: 'string',
+          description: 'ABI JSON (contents of output/<name>.abi.json)',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        address: {
+          type: 'string',
+          description: 'Deployed contract address (klv1...) the client uses by default',
+        },
+        network: {
+          type: 'string',
+          enum: [...NETWORK_NAMES],
+          description: 'Network whose node the client uses by default',
+        },
+      },
+    },
+    annotations: {
+      title: 'Generate Python Client',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { abi, abiUrl, address, network } = args as {
+      abi?: string;
+      abiUrl?: string;
+      address?: string;
+      network?: string;
+    };
+    if (address) decodeAddress(address);
+    const net = validateNetwork(network);
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    const nodeUrl = context.chainClient.getNodeUrl(net);
+    const client = generatePyClient(parsed, { nodeUrl, address });
+    const create = `${client.className}(${address ? '' : 'contract_address'})`;
+    return {
+      ...client,
+      nextSteps: [
+        `Save ${client.path} next to the code that imports it; Python 3.8 or later`,
+        `Create the client with ${create} and call its view methods`,
+        'pip install cryptography to sign: client.send(built, Wallet.load("wallet.pem"))',
+      ],
+    };
+  },
+};
@@ -0,0 +1,101 @@
+import type { KleverTool } from './types.js';
+import { generateScenario, type ScenarioAccount, type ScenarioStep } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+const ARGS_DESCRIPTION =
+  'Argument values as plain JSON, in ABI order or keyed by argument name. Numbers may be strings; addresses are account names (alice, owner), sc:<name>, klv1... or 32-byte 0x hex; bytes are text or 0x hex; structs are objects or arrays; enum values are the variant name or {"Variant": fields}; Option is null or the value; optional arguments may be omitted; variadic arguments are arrays.';
+
+export const generateScenarioTool: KleverTool = {
+  definition: {
+    name: 'generate_scenario',
+    description:
+      'Generate a scenario test (scenarios/<name>.scen.json) from a sequence of high-level steps: deploy with constructor arguments, endpoint calls with KLV/KDA payments, view queries and state checks. Argument and result values are given as plain JSON and encoded from the contract ABI into scenario value expressions (address:, str:, biguint:, u32:, nested encodings); expected events are built from the ABI event definitions (indexed fields as topics), and storage of SingleValueMappers can be checked by mapper name when the contract source is given. Accounts that send transactions are created in the initial setState step.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        abi: {
+          type: 'string',
+          description: 'ABI JSON (contents of output/<name>.abi.json)',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        steps: {
+          type: 'array',
+          description: `Steps in order. Each has a type: "deploy" (from, args), "call" (from, endpoint, args, klv, kda: [{token, amount, nonce?}]), "query" (endpoint, args) or "checkState" (account?, balance?, storage: [{mapper, args?, value} or {key, value} with raw expressions]). deploy, call and query accept expect: {out, status, message, events: [{identifier, args}]}. ${ARGS_DESCRIPTION}`,
+          items: {
+            type: 'object',
+            properties: {
+              type: { type: 'string', enum: ['deploy', 'call', 'query', 'checkState'] },
+            },
+            required: ['type'],
+          },
+        },
+        accounts: {
+          type: 'object',
+          additionalProperties: {
+            type: 'object',
+            properties: {
+              balance: { type: ['string', 'number'] },
+              kda: { type: 'object', additionalProperties: { type: ['string', 'number'] } },
+            },
+          },
+          description:
+            'Initial KLV balance and KDA balances (keyed by token identifier) of accounts, keyed by account name',
+        },
+        name: {
+          type: 'string',
+          description: 'Scenario name, also used for the file name (default: the contract name)',
+        },
+        source: {
+          type: 'string',
+          description: 'Contract source (src/lib.rs), required to check storage by mapper name',
+        },
+        codePath: {
+          type: 'string',
+          description:
+            'Contract wasm path relative to the scenario file (default: ../output/<contract>.wasm)',
+        },
+        gasLimit: {
+          type: 'string',
+          description: 'Gas limit of every transaction (default: 5,000,000)',
+        },
+      },
+      required: ['steps'],
+    },
+    annotations: {
+      title: 'Generate Scenario Test',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { abi, abiUrl, steps, accounts, name, source, codePath, gasLimit } = args as {
+      abi?: string;
+      abiUrl?: string;
+      steps: ScenarioStep[];
+      accounts?: Record<string, ScenarioAccount>;
+      name?: string;
+      source?: string;
+      codePath?: string;
+      gasLimit?: string;
+    };
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    const result = generateScenario(parsed, { steps, accounts, name, source, codePath, gasLimit });
+    const test = result.path.replace(/^scenarios\/(.*)\.scen\.json$/, '$1');
+
+    return {
+      path: result.path,
+      files: { [result.path]: result.json },
+      scenario: result.scenario,
+      nextSteps: [
+        `Save ${result.path} in the contract crate`,
+        `Run it from tests/<contract>_scenario_rs_test.rs with #[test] fn ${test}_rs() { world().run("${result.path}"); }, registering the contract in world() first`,
+        'Run the scenario with run_tests (kind: "scenario") and adjust the expectations to the observed results',
+      ],
+    };
+  },
+};
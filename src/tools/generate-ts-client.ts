@@ -0,0 +1,63 @@
+import type { KleverTool } from './types.js';
+import { decodeAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { generateTsClient } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+export const generateTsClientTool: KleverTool = {
+  definition: {
+    name: 'generate_ts_client',
+    description:
+      'Generate a typed TypeScript client module for a contract from its ABI, for frontends built on @klever/sdk-web. Emits interfaces for the custom structs and enums, an event type per ABI event with a decoder for event logs, and a class with one method per view (queried on a node, results decoded into typed values) and per endpoint (arguments ABI-encoded, sent as a transaction signed in the Klever Extension, resolves with the transaction hash; payable endpoints take a callValue). Integers above 32 bits are bigints, addresses klv1... strings. The module carries its own ABI codec, so @klever/sdk-web is its only dependency.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        abi: {
+          type: 'string',
+          description: 'ABI JSON (contents of output/<name>.abi.json)',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        address: {
+          type: 'string',
+          description: 'Deployed contract address (klv1...) the client uses by default',
+        },
+        network: {
+          type: 'string',
+          enum: [...NETWORK_NAMES],
+          description: 'Network whose node the client queries views on by default',
+        },
+      },
+    },
+    annotations: {
+      title: 'Generate TypeScript Client',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { abi, abiUrl, address, network } = args as {
+      abi?: string;
+      abiUrl?: string;
+      address?: string;
+      network?: string;
+    };
+    if (address) decodeAddress(address);
+    const net = validateNetwork(network);
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    const nodeUrl = context.chainClient.getNodeUrl(net);
+    const client = generateTsClient(parsed, { nodeUrl, address });
+    const create = `new ${client.className}(${address ? '' : 'contractAddress'})`;
+    return {
+      ...client,
+      nextSteps: [
+        `Save ${client.path} in the frontend project and install @klever/sdk-web`,
+        `Create the client with ${create} and await its methods`,
+        'Compile with target ES2020 or later; integers above 32 bits are bigints',
+      ],
+    };
+  },
+};
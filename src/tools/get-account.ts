@@ -0,0 +1,97 @@
+import type { KleverTool } from './types.js';
+import { isContractAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+
+const KLV_UNIT = 1_000_000;
+
+/** Code hash as lowercase hex; nodes report it base64-encoded */
+function codeHashHex(value: string): string {
+  if (/^(0x)?[0-9a-fA-F]{64}$/.test(value)) return value.replace(/^0x/, '').toLowerCase();
+  return Buffer.from(value, 'base64').toString('hex');
+}
+
+export const getAccountTool: KleverTool = {
+  definition: {
+    name: 'get_account',
+    description:
+      'Get the state of a Klever address: KLV balance (available and frozen), nonce, the KDA assets it holds with their balances and frozen amounts, and whether it is a smart contract (with its code hash and owner). Use it to sanity-check a sender or contract before building transactions.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        address: {
+          type: 'string',
+          description: 'Klever address (klv1... bech32 format).',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network to query (default: the server default)',
+        },
+      },
+      required: ['address'],
+    },
+    annotations: {
+      title: 'Get Account',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { address, network } = args as { address: string; network?: string };
+    const net = validateNetwork(network);
+    const account = await context.chainClient.getAccount(address, net);
+
+    const assets = Object.entries(account.assets || {}).map(([id, asset]) => ({
+      assetId: asset.assetId || id,
+      balance: asset.balance,
+      frozenBalance: asset.frozenBalance || 0,
+      unfrozenBalance: asset.unfrozenBalance || 0,
+    }));
+    const isContract = !!account.codeHash || isContractAddress(address);
+    const contract = isContract
+      ? {
+          codeHash: account.codeHash ? codeHashHex(account.codeHash) : undefined,
+          owner: account.ownerAddress,
+        }
+      : undefined;
+
+    const nextSteps: string[] = [];
+    if (!account.balance && !isContract) {
+      nextSteps.push(
+        'The address holds no KLV for fees; fund it (request_test_funds on testnet or devnet) before sending transactions'
+      );
+    }
+    if (isContract) {
+      nextSteps.push('Read its views with query_view, or check its code with verify_build');
+    }
+    // Nonces reserved by transactions this server built that have not executed yet
+    const nonce = account.nonce ?? 0;
+    const pending = context.chainClient
+      .pendingNonces(address, net)
+      .map(reservation => reservation.nonce)
+      .filter(reserved => reserved >= nonce);
+    if (pending.length > 0) {
+      nextSteps.push(
+        `Transactions with nonces ${pending.join(', ')} were built here but have not executed yet`
+      );
+    }
+
+    return {
+      address,
+      network: net || context.chainClient.getDefaultNetwork(),
+      explorerUrl: context.chainClient.getExplorerUrl('account', address, net),
+      nonce,
+      pendingNonces: pending.length > 0 ? pending : undefined,
+      balance: account.balance ?? 0,
+      balanceKLV: (account.balance ?? 0) / KLV_UNIT,
+      frozenBalance: account.frozenBalance ?? 0,
+      frozenBalanceKLV: (account.frozenBalance ?? 0) / KLV_UNIT,
+      assets,
+      isContract,
+      contract,
+      data: account,
+      nextSteps,
+    };
+  },
+};
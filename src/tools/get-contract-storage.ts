@@ -0,0 +1,174 @@
+import type { KleverTool } from './types.js';
+import { decodeAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { type Abi } from '../abi/index.js';
+import {
+  decodeStorageEntry,
+  storageKeyFor,
+  storageLayout,
+  type DecodedStorageEntry,
+} from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+/** Key as text when it is printable, for entries no mapper explains */
+function keyText(key: Buffer): string | undefined {
+  const text = key.toString('utf8');
+  return /^[\x20-\x7e]+$/.test(text) ? text : undefined;
+}
+
+export const getContractStorageTool: KleverTool = {
+  definition: {
+    name: 'get_contract_storage',
+    description:
+      'Read the raw storage of a deployed contract and decode it with the storage mappers declared in its source: keys like balance<address> become the mapper name and its key arguments, and values are decoded with the mapper value type (custom structs and enums need the ABI). Pass mapper (and keyArgs) to read one entry, e.g. mapper "balance" with keyArgs ["klv1alice..."] to read Alice\'s balance directly from storage. Entries no mapper explains are returned raw.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        address: {
+          type: 'string',
+          description: 'Contract address (klv1...)',
+        },
+        source: {
+          type: 'string',
+          description: 'Contract source (src/lib.rs) declaring the storage mappers',
+        },
+        modules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description: 'Module files of the contract keyed by path',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the contract, to decode custom types',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        mapper: {
+          type: 'string',
+          description: 'Only entries of this storage mapper (function name or storage key)',
+        },
+        keyArgs: {
+          description:
+            'Key arguments of mapper, in order or keyed by name: reads a single value, or the collection entries under those keys',
+          oneOf: [{ type: 'array' }, { type: 'object' }],
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network of the contract (default: the server default)',
+        },
+      },
+      required: ['address'],
+    },
+    annotations: {
+      title: 'Get Contract Storage',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const {
+      address,
+      source,
+      modules = {},
+      abi,
+      abiUrl,
+      mapper: mapperName,
+      keyArgs,
+      network,
+    } = args as {
+      address: string;
+      source?: string;
+      modules?: Record<string, string>;
+      abi?: string;
+      abiUrl?: string;
+      mapper?: string;
+      keyArgs?: unknown[] | Record<string, unknown>;
+      network?: string;
+    };
+    decodeAddress(address);
+    const net = validateNetwork(network);
+    let contractAbi: Abi | undefined;
+    if (abi || abiUrl) {
+      contractAbi = await loadAbi(abi, abiUrl, context.profile);
+      context.abiRegistry.register(address, contractAbi);
+    } else {
+      contractAbi = context.abiRegistry.get(address);
+    }
+
+    const layout = storageLayout([...(source ? [source] : []), ...Object.values(modules)]);
+    const slot = mapperName
+      ? layout.find(s => s.functionName === mapperName || s.storageKey === mapperName)
+      : undefined;
+    if (mapperName && !slot) {
+      const available = layout.map(s => s.functionName).join(', ') || 'none (pass source)';
+      throw new Error(`Storage mapper "${mapperName}" not found. Available: ${available}`);
+    }
+
+    const nextSteps: string[] = [];
+    const base = {
+      address,
+      network: net || context.chainClient.getDefaultNetwork(),
+    };
+
+    // A single value: build its key instead of reading the whole storage
+    const single = keyArgs !== undefined || slot?.keyParams.length === 0;
+    if (slot && single && slot.mapper === 'SingleValueMapper') {
+      const key = storageKeyFor(slot, keyArgs, contractAbi);
+      const value = Buffer.from(
+        await context.chainClient.getStorageValue(address, key.toString('hex'), net),
+        'hex'
+      );
+      const entry = decodeStorageEntry(key, value, [slot], contractAbi);
+      if (value.length === 0) {
+        nextSteps.push('The entry is empty: the mapper holds its default value for these keys');
+      }
+      return {
+        ...base,
+        mapper: slot.functionName,
+        key: key.toString('hex'),
+        value: entry ? entry.value : `0x${value.toString('hex')}`,
+        rawValue: value.toString('hex'),
+        nextSteps,
+      };
+    }
+
+    const pairs = await context.chainClient.getStorage(address, net);
+    const entries: DecodedStorageEntry[] = [];
+    const undecoded: { key: string; keyText?: string; value: string }[] = [];
+    for (const [hexKey, hexValue] of Object.entries(pairs)) {
+      const key = Buffer.from(hexKey, 'hex');
+      const entry = decodeStorageEntry(key, Buffer.from(hexValue, 'hex'), layout, contractAbi);
+      if (entry) {
+        entries.push(entry);
+      } else if (!slot) {
+        undecoded.push({ key: hexKey, keyText: keyText(key), value: hexValue });
+      }
+    }
+    // Collection entries of the given key arguments all start with the mapper key
+    const prefix =
+      slot && keyArgs !== undefined
+        ? storageKeyFor(slot, keyArgs, contractAbi).toString('hex')
+        : '';
+    const selected = entries.filter(
+      entry => (!slot || entry.functionName === slot.functionName) && entry.key.startsWith(prefix)
+    );
+
+    if (layout.length === 0) {
+      nextSteps.push('Pass the contract source (and modules) to decode keys and values');
+    } else if (undecoded.length > 0 && !contractAbi) {
+      nextSteps.push('Pass abi to decode values of custom types');
+    }
+
+    return {
+      ...base,
+      entries: selected,
+      undecoded: undecoded.length > 0 ? undecoded : undefined,
+      total: Object.keys(pairs).length,
+      nextSteps,
+    };
+  },
+};
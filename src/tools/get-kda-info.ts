@@ -0,0 +1,108 @@
+import type { KleverTool } from './types.js';
+import { formatUnits, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+
+const ASSET_TYPES = ['Fungible', 'NonFungible', 'SemiFungible'];
+
+/** `IsPaused` -> `isPaused` */
+function lowerFirst(key: string): string {
+  return key.charAt(0).toLowerCase() + key.slice(1);
+}
+
+export const getKdaInfoTool: KleverTool = {
+  definition: {
+    name: 'get_kda_info',
+    description:
+      'Get the on-chain properties of a KDA asset (e.g. "KFI", "USDT-A1B2"): name, ticker, type, precision, supply (initial, circulating, max, minted, burned; raw and with decimals), owner and admin, permissions (can mint, burn, freeze, pause, wipe...), state flags such as paused, roles per address, and royalties. Use the real precision and permissions when generating or validating token code.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        assetId: {
+          type: 'string',
+          description: 'KDA asset ID, e.g. "KLV", "KFI" or "USDT-A1B2"',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network to query (default: the server default)',
+        },
+      },
+      required: ['assetId'],
+    },
+    annotations: {
+      title: 'Get KDA Info',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { assetId, network } = args as { assetId: string; network?: string };
+    const net = validateNetwork(network);
+    const asset = await context.chainClient.getAssetInfo(assetId.trim().toUpperCase(), net);
+    const raw = asset as typeof asset & { AssetType?: number };
+    const precision = asset.Precision ?? 0;
+    const amount = (value: number | undefined) =>
+      value === undefined || value === null
+        ? undefined
+        : { units: value, value: formatUnits(value, precision) };
+
+    const properties = Object.fromEntries(
+      Object.entries(asset.Properties || {}).map(([key, value]) => [lowerFirst(key), !!value])
+    );
+    const flags = Object.fromEntries(
+      Object.entries(asset.Attributes || {}).map(([key, value]) => [lowerFirst(key), !!value])
+    );
+    const roles = (asset.Roles || []).map(role => ({
+      address: role.Address,
+      mint: !!role.HasRoleMint,
+      setITOPrices: !!role.HasRoleSetITOPrices,
+      deposit: !!role.HasRoleDeposit,
+      transfer: !!role.HasRoleTransfer,
+    }));
+    const royalties = asset.Royalties && {
+      address: asset.Royalties.Address,
+      transferPercentage: asset.Royalties.TransferPercentage,
+      marketPercentage: asset.Royalties.MarketPercentage,
+      marketFixedAmount: asset.Royalties.MarketFixedAmount,
+    };
+
+    const nextSteps: string[] = [
+      `Amounts of ${asset.ID} have ${precision} decimals: 1 ${asset.Ticker || asset.ID} = ${10n ** BigInt(precision)} units`,
+    ];
+    if (flags.isPaused) {
+      nextSteps.push('The asset is paused: transfers fail until it is resumed');
+    }
+    if (!properties.canMint) {
+      nextSteps.push('The asset cannot be minted; supply only changes by burning');
+    }
+
+    return {
+      assetId: asset.ID,
+      name: asset.Name,
+      ticker: asset.Ticker,
+      type: typeof raw.AssetType === 'number' ? ASSET_TYPES[raw.AssetType] : undefined,
+      precision,
+      owner: asset.OwnerAddress,
+      admin: asset.AdminAddress,
+      supply: {
+        initial: amount(asset.InitialSupply),
+        circulating: amount(asset.CirculatingSupply),
+        max: asset.MaxSupply ? amount(asset.MaxSupply) : undefined,
+        minted: amount(asset.MintedValue),
+        burned: amount(asset.BurnedValue),
+      },
+      properties,
+      flags,
+      paused: !!flags.isPaused,
+      roles,
+      royalties,
+      logo: asset.Logo,
+      uris: asset.URIs,
+      issueDate: asset.IssueDate,
+      network: net || context.chainClient.getDefaultNetwork(),
+      explorerUrl: context.chainClient.getExplorerUrl('asset', asset.ID, net),
+      nextSteps,
+    };
+  },
+};
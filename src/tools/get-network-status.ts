@@ -0,0 +1,71 @@
+import type { KleverTool } from './types.js';
+import { NETWORK_NAMES, networkStatus, validateNetwork } from '../chain/index.js';
+
+export const getNetworkStatusTool: KleverTool = {
+  definition: {
+    name: 'get_network_status',
+    description:
+      'Get the live status of a Klever network from its node: current block nonce, epoch, round (block) time, minimum gas price, chain ID, whether the node is synced, and its version. Use it to check which network a deployment targets, to compute deadlines in blocks or epochs, and to sanity-check gas prices before sending transactions.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network to query (default: the server default)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Get Network Status',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { network } = args as { network?: string };
+    const net = validateNetwork(network);
+    const { chainClient } = context;
+    const [status, config] = await Promise.allSettled([
+      chainClient.getNodeStatus(net),
+      chainClient.getNetworkConfig(net),
+    ]);
+    if (status.status === 'rejected' && config.status === 'rejected') {
+      throw status.reason;
+    }
+    const value = <T>(result: PromiseSettledResult<T>) =>
+      result.status === 'fulfilled' ? result.value : undefined;
+    const profile = chainClient.getNetworkProfile(net);
+    const metrics = networkStatus(value(status), value(config));
+    const chainId = metrics.chainId ?? profile.chainId;
+
+    const nextSteps: string[] = [];
+    if (metrics.chainId && profile.chainId && metrics.chainId !== profile.chainId) {
+      nextSteps.push(
+        `The node reports chain ID ${metrics.chainId} but the network is configured for ${profile.chainId}; check the node URL or KLEVER_CHAIN_ID`
+      );
+    }
+    if (metrics.isSynced === false) {
+      nextSteps.push('The node is still syncing; its block nonce and state may lag behind');
+    }
+    if (status.status === 'rejected' || config.status === 'rejected') {
+      nextSteps.push('Some metrics are missing because the node did not answer every request');
+    }
+
+    return {
+      network: net || chainClient.getDefaultNetwork(),
+      nodeUrl: profile.nodeUrl,
+      chainId,
+      blockNonce: metrics.blockNonce,
+      epoch: metrics.epoch,
+      roundTimeMs: metrics.roundTimeMs,
+      minGasPrice: metrics.minGasPrice,
+      isSynced: metrics.isSynced,
+      appVersion: metrics.appVersion,
+      explorerUrl: profile.explorerUrl,
+      nextSteps,
+    };
+  },
+};
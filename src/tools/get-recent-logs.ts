@@ -0,0 +1,70 @@
+import type { KleverTool } from './types.js';
+import { currentSession, LOG_LEVELS, recentLogs, type LogLevel } from '../logging/index.js';
+
+export const getRecentLogsTool: KleverTool = {
+  definition: {
+    name: 'get_recent_logs',
+    description:
+      "Read the most recent structured log records of this session, to diagnose why a tool call failed: every tool call, node or API request and toolchain subprocess is logged with a trace ID shared by everything the call caused. Failed tool calls return their traceId; pass it to see only that call's records. Records include debug-level network requests that are not written to stderr by default.",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        traceId: {
+          type: 'string',
+          description: 'Only records of this tool call (the traceId of a failed call)',
+        },
+        level: {
+          type: 'string',
+          enum: LOG_LEVELS,
+          description: 'Minimum level (default: debug)',
+        },
+        contains: {
+          type: 'string',
+          description: 'Only records whose message or fields contain this text (case-insensitive)',
+        },
+        sinceSeconds: {
+          type: 'number',
+          description: 'Only records of the last this many seconds',
+        },
+        limit: {
+          type: 'number',
+          description: 'Most recent records returned (default: 50)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Get Recent Logs',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  localOnly: true,
+  handler: async args => {
+    const { traceId, level, contains, sinceSeconds, limit = 50 } = args as {
+      traceId?: string;
+      level?: LogLevel;
+      contains?: string;
+      sinceSeconds?: number;
+      limit?: number;
+    };
+    if (level && !LOG_LEVELS.includes(level)) {
+      throw new Error(`Invalid level "${level}". Valid options: ${LOG_LEVELS.join(', ')}.`);
+    }
+    const since = sinceSeconds === undefined ? undefined : Date.now() - sinceSeconds * 1000;
+    // Records hold tool arguments: a session only reads back its own
+    const session = currentSession();
+    const records = recentLogs.query({ traceId, session, level, contains, since, limit });
+    return {
+      count: records.length,
+      records,
+      nextSteps:
+        records.length === 0
+          ? ['No matching records; older records are dropped once logging.buffer_size is reached']
+          : !traceId && records.some(record => record.level === 'error' || record.level === 'warn')
+            ? ['Pass the traceId of a failing record to see everything that call did']
+            : [],
+    };
+  },
+};
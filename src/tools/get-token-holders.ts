@@ -0,0 +1,220 @@
+import type { KleverTool } from './types.js';
+import {
+  decodeAddress,
+  formatUnits,
+  holderDistribution,
+  NETWORK_NAMES,
+  validateNetwork,
+  type HolderBalance,
+  type HolderDistribution,
+} from '../chain/index.js';
+import { decodeStorageEntry, storageLayout, type StorageSlot } from '../codegen/index.js';
+
+const NUMERIC_TYPE = /^(BigUint|u8|u16|u32|u64|usize)$/;
+
+/** Storage mapper of a contract-kept supply, read when the contract declares one */
+const SUPPLY_MAPPERS = ['total_supply', 'totalSupply'];
+
+/** Holder list of the distribution, amounts as decimal strings */
+function holderSummary(distribution: HolderDistribution, precision: number) {
+  return {
+    holderCount: distribution.holderCount,
+    total: distribution.total.toString(),
+    totalFormatted: formatUnits(distribution.total, precision),
+    topShare: distribution.topShare,
+    top: distribution.top.map((holder, i) => ({
+      rank: i + 1,
+      address: holder.address,
+      balance: holder.balance.toString(),
+      balanceFormatted: formatUnits(holder.balance, precision),
+      share: holder.share,
+    })),
+  };
+}
+
+/** Concentration warnings for tokenomics checks */
+function concentration(distribution: HolderDistribution): string[] {
+  const [largest] = distribution.top;
+  const steps: string[] = [];
+  if (largest && largest.share >= 0.5) {
+    steps.push(
+      `${largest.address} holds ${(largest.share * 100).toFixed(1)}% of the supply; check whether it is a treasury, contract or exchange`
+    );
+  }
+  if (distribution.holderCount > distribution.top.length) {
+    steps.push(
+      `The top ${distribution.top.length} holders hold ${(distribution.topShare * 100).toFixed(1)}% of the supply`
+    );
+  }
+  return steps;
+}
+
+/** The mapper keeping balances: one Address key and a numeric value */
+function balanceSlot(layout: StorageSlot[], name: string): StorageSlot {
+  const slot = layout.find(s => s.functionName === name || s.storageKey === name);
+  if (!slot) {
+    const available = layout.map(s => s.functionName).join(', ') || 'none (pass source)';
+    throw new Error(`Storage mapper "${name}" not found. Available: ${available}`);
+  }
+  if (
+    slot.mapper !== 'SingleValueMapper' ||
+    slot.keyParams.length !== 1 ||
+    slot.keyParams[0].type !== 'Address' ||
+    !NUMERIC_TYPE.test(slot.typeArgs[0] || '')
+  ) {
+    throw new Error(
+      `${slot.functionName} is not a balance mapper: expected fn ${slot.functionName}(&self, address: &ManagedAddress) -> SingleValueMapper<BigUint>`
+    );
+  }
+  return slot;
+}
+
+export const getTokenHoldersTool: KleverTool = {
+  definition: {
+    name: 'get_token_holders',
+    description:
+      "Get the holder distribution of a token for tokenomics sanity checks: holder count, the top N holders with their balances and share of the supply, and concentration warnings. For a KDA asset (assetId) the holders come from the indexer API; for a contract that keeps balances in a storage mapper like balance(address) -> BigUint (contract and source), its storage is walked, which also works on a local network without an indexer.",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        assetId: {
+          type: 'string',
+          description: 'KDA asset ID, e.g. "KFI" or "USDT-A1B2"',
+        },
+        contract: {
+          type: 'string',
+          description: 'Token contract (klv1...) keeping balances in storage, instead of assetId',
+        },
+        source: {
+          type: 'string',
+          description: 'Contract source (src/lib.rs) declaring the balance mapper; for contract',
+        },
+        modules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description: 'Module files of the contract keyed by path',
+        },
+        mapper: {
+          type: 'string',
+          description: 'Balance storage mapper, function name or storage key (default: balance)',
+        },
+        precision: {
+          type: 'integer',
+          minimum: 0,
+          description: 'Decimals of the contract token, to format balances (default: 0)',
+        },
+        top: {
+          type: 'integer',
+          minimum: 1,
+          maximum: 100,
+          description: 'How many of the largest holders to list (default: 10)',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network to query (default: the server default)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Get Token Holders',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const {
+      assetId,
+      contract,
+      source,
+      modules = {},
+      mapper = 'balance',
+      precision = 0,
+      top = 10,
+      network,
+    } = args as {
+      assetId?: string;
+      contract?: string;
+      source?: string;
+      modules?: Record<string, string>;
+      mapper?: string;
+      precision?: number;
+      top?: number;
+      network?: string;
+    };
+    if (!assetId === !contract) throw new Error('Pass either assetId or contract.');
+    const net = validateNetwork(network);
+    const topN = Math.min(Math.max(top, 1), 100);
+    const base = { network: net || context.chainClient.getDefaultNetwork() };
+
+    if (assetId) {
+      const id = assetId.trim().toUpperCase();
+      const [asset, { holders, pagination }] = await Promise.all([
+        context.chainClient.getAssetInfo(id, net),
+        context.chainClient.getAssetHolders(id, { limit: topN }, net),
+      ]);
+      const balances: HolderBalance[] = holders.map(holder => ({
+        address: holder.address,
+        balance: BigInt(holder.balance || 0),
+      }));
+      const supply = asset.CirculatingSupply ? BigInt(asset.CirculatingSupply) : undefined;
+      const distribution = holderDistribution(balances, topN, supply);
+      // The API pages holders: its record count covers holders beyond this page
+      distribution.holderCount = pagination?.totalRecords ?? distribution.holderCount;
+      return {
+        ...base,
+        assetId: asset.ID || id,
+        precision: asset.Precision ?? 0,
+        dataSource: 'indexer',
+        ...holderSummary(distribution, asset.Precision ?? 0),
+        nextSteps: concentration(distribution),
+      };
+    }
+
+    decodeAddress(contract as string);
+    if (!source && Object.keys(modules).length === 0) {
+      throw new Error('Pass the contract source declaring the balance mapper.');
+    }
+    const layout = storageLayout([...(source ? [source] : []), ...Object.values(modules)]);
+    const slot = balanceSlot(layout, mapper);
+    const supplySlot = layout.find(
+      s => SUPPLY_MAPPERS.includes(s.functionName) && s.keyParams.length === 0
+    );
+
+    const pairs = await context.chainClient.getStorage(contract as string, net);
+    const balances: HolderBalance[] = [];
+    let supply: bigint | undefined;
+    for (const [hexKey, hexValue] of Object.entries(pairs)) {
+      const key = Buffer.from(hexKey, 'hex');
+      const value = Buffer.from(hexValue, 'hex');
+      const entry = decodeStorageEntry(key, value, supplySlot ? [slot, supplySlot] : [slot]);
+      if (!entry) continue;
+      if (entry.functionName === slot.functionName) {
+        balances.push({
+          address: String(entry.keyArgs[slot.keyParams[0].name]),
+          balance: BigInt(entry.value as number | string),
+        });
+      } else {
+        supply = BigInt(entry.value as number | string);
+      }
+    }
+    const distribution = holderDistribution(balances, topN, supply);
+    const nextSteps = concentration(distribution);
+    const sum = balances.reduce((total, holder) => total + holder.balance, 0n);
+    if (supply !== undefined && sum !== supply) {
+      nextSteps.push(
+        `The balances add up to ${sum} but ${supplySlot?.functionName} is ${supply}; check mint, burn and transfer accounting`
+      );
+    }
+    return {
+      ...base,
+      contract,
+      mapper: slot.functionName,
+      dataSource: 'storage',
+      ...holderSummary(distribution, precision),
+      nextSteps,
+    };
+  },
+};
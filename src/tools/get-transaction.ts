@@ -0,0 +1,153 @@
+import type { KleverTool } from './types.js';
+import { NETWORK_NAMES, validateNetwork, type TransactionData } from '../chain/index.js';
+import { type Abi } from '../abi/index.js';
+import { decodeEvent, decodeResults, type DecodedEvent } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+import { calledFunction, eventLogs, returnData, transactionContract } from './transaction-input.js';
+
+/** Numeric cost fields the node reports on the transaction */
+function costs(tx: TransactionData): Record<string, number> | undefined {
+  const entries = ['gasUsed', 'gasLimit', 'kAppFee', 'bandwidthFee'].flatMap(key =>
+    typeof tx[key] === 'number' ? [[key, tx[key] as number] as const] : []
+  );
+  return entries.length > 0 ? Object.fromEntries(entries) : undefined;
+}
+
+export const getTransactionTool: KleverTool = {
+  definition: {
+    name: 'get_transaction',
+    description:
+      'Get a transaction by hash from the Klever blockchain, by default waiting until it is processed. Returns status, costs, the raw transaction (sender, receiver, contracts, receipts) and, for smart contract calls, the returned values and event logs decoded with the contract ABI: the abi passed here, or one used earlier in the session by deploy_contract, invoke_endpoint or query_view.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        hash: {
+          type: 'string',
+          description: 'Transaction hash (hex string).',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network of the transaction (default: the server default)',
+        },
+        wait: {
+          type: 'boolean',
+          description: 'Poll until the transaction is processed (default: true)',
+        },
+        timeoutSeconds: {
+          type: 'number',
+          description: 'How long to wait for the transaction (default: 60)',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the called contract, to decode results and events',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        endpoint: {
+          type: 'string',
+          description: 'Called endpoint, when it cannot be read from the transaction data',
+        },
+      },
+      required: ['hash'],
+    },
+    annotations: {
+      title: 'Get Transaction',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const {
+      hash,
+      network,
+      wait = true,
+      timeoutSeconds = 60,
+      abi,
+      abiUrl,
+      endpoint: endpointName,
+    } = args as {
+      hash: string;
+      network?: string;
+      wait?: boolean;
+      timeoutSeconds?: number;
+      abi?: string;
+      abiUrl?: string;
+      endpoint?: string;
+    };
+    const net = validateNetwork(network);
+    const timeoutMs = timeoutSeconds * 1000;
+    const tx = wait
+      ? await context.chainClient.waitForTransaction(hash, net, { timeoutMs })
+      : await context.chainClient.getTransaction(hash, net);
+
+    const contract = transactionContract(tx);
+    let contractAbi: Abi | undefined;
+    if (abi || abiUrl) {
+      contractAbi = await loadAbi(abi, abiUrl, context.profile);
+      if (contract) context.abiRegistry.register(contract, contractAbi);
+    } else if (contract) {
+      contractAbi = context.abiRegistry.get(contract);
+    }
+
+    const nextSteps: string[] = [];
+    const fn = endpointName || calledFunction(tx);
+    const raw = returnData(tx);
+    let results: unknown[] | undefined;
+    const endpoint = fn ? contractAbi?.endpoints.find(e => e.name === fn) : undefined;
+    if (raw && endpoint) {
+      results = decodeResults(endpoint.outputs, raw, contractAbi!);
+    } else if (raw && raw.length > 0) {
+      nextSteps.push('Pass abi (and endpoint) to decode the returned values');
+    }
+
+    const events: (DecodedEvent & { address?: string })[] = [];
+    const undecoded: { address?: string; topics: string[]; data: string[]; reason: string }[] = [];
+    for (const log of eventLogs(tx)) {
+      const identifier = log.topics[0]?.toString('utf8') || '';
+      const eventAbi = (contractAbi?.events || []).some(e => e.identifier === identifier)
+        ? contractAbi
+        : context.abiRegistry.forEvent(identifier, log.address);
+      try {
+        if (!eventAbi) throw new Error(`No known ABI declares event "${identifier}"`);
+        events.push({ ...decodeEvent(log.topics, log.data, eventAbi), address: log.address });
+      } catch (error) {
+        undecoded.push({
+          address: log.address,
+          topics: log.topics.map(topic => `0x${topic.toString('hex')}`),
+          data: log.data.map(value => `0x${value.toString('hex')}`),
+          reason: (error as Error).message,
+        });
+      }
+    }
+    if (undecoded.length > 0 && !contractAbi) {
+      nextSteps.push('Pass the contract abi to decode its events');
+    }
+    if (!tx.status) {
+      nextSteps.push('The transaction is not processed yet; call get_transaction again later');
+    } else if (tx.status !== 'success') {
+      nextSteps.push('The transaction failed; explain_error with this hash gives the likely cause');
+    }
+
+    return {
+      hash: tx.hash,
+      explorerUrl: context.chainClient.getExplorerUrl('transaction', tx.hash, net),
+      status: tx.status,
+      resultCode: tx.resultCode,
+      costs: costs(tx),
+      contract,
+      endpoint: fn,
+      results,
+      rawResults: raw?.map(value => value.toString('hex')),
+      events,
+      undecodedEvents: undecoded.length > 0 ? undecoded : undefined,
+      data: tx,
+      network: net || context.chainClient.getDefaultNetwork(),
+      nextSteps,
+    };
+  },
+};
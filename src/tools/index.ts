@@ -0,0 +1,157 @@
+/**
+ * Registry of MCP tools implemented as standalone modules.
+ *
+ * The built-in knowledge and chain tools are defined inline in src/mcp/server.ts;
+ * tools listed here are advertised and dispatched generically by the server.
+ */
+
+import type { KleverTool } from './types.js';
+import type { ServerProfile } from '../mcp/server.js';
+import { scaffoldContractTool } from './scaffold-contract.js';
+import { scaffoldWorkspaceTool } from './scaffold-workspace.js';
+import { addUpgradeSupportTool } from './add-upgrade-support.js';
+import { adviseStorageMapperTool } from './advise-storage-mapper.js';
+import { generateEndpointTool } from './generate-endpoint.js';
+import { generateEventTool } from './generate-event.js';
+import { splitIntoModulesTool } from './split-into-modules.js';
+import { generateAsyncCallTool } from './generate-async-call.js';
+import { generateProxyTool } from './generate-proxy.js';
+import { generateTsClientTool } from './generate-ts-client.js';
+import { generateInteractorTool } from './generate-interactor.js';
+import { generatePyClientTool } from './generate-py-client.js';
+import { generateOpenApiTool } from './generate-openapi.js';
+import { generateGraphqlSchemaTool } from './generate-graphql-schema.js';
+import { generateFuzzTargetTool } from './generate-fuzz-target.js';
+import { generatePropertyTestsTool } from './generate-property-tests.js';
+import { generatePayableEndpointTool } from './generate-payable-endpoint.js';
+import { addAdminModuleTool } from './add-admin-module.js';
+import { generateMigrationTool } from './generate-migration.js';
+import { lintContractTool } from './lint-contract.js';
+import { auditAccessControlTool } from './audit-access-control.js';
+import { visualizeContractTool } from './visualize-contract.js';
+import { abiDiffTool } from './abi-diff.js';
+import { generateErrorConstantsTool } from './generate-error-constants.js';
+import { checkUpgradeSafetyTool } from './check-upgrade-safety.js';
+import { checkDeprecatedApisTool } from './check-deprecated-apis.js';
+import { buildContractTool } from './build-contract.js';
+import { runTestsTool } from './run-tests.js';
+import { debugCallTool } from './debug-call.js';
+import { profileGasTool } from './profile-gas.js';
+import { simulateCallTool } from './simulate-call.js';
+import { forkStateTool } from './fork-state.js';
+import { mutationTestTool } from './mutation-test.js';
+import { benchmarkStorageTool } from './benchmark-storage.js';
+import { generateScenarioTool } from './generate-scenario.js';
+import { analyzeWasmSizeTool } from './analyze-wasm-size.js';
+import { verifyBuildTool } from './verify-build.js';
+import { deployContractTool } from './deploy-contract.js';
+import { upgradeContractTool } from './upgrade-contract.js';
+import { invokeEndpointTool } from './invoke-endpoint.js';
+import { queryViewTool } from './query-view.js';
+import { decodeResultTool } from './decode-result.js';
+import { encodeValueTool } from './encode-value.js';
+import { decodeValueTool } from './decode-value.js';
+import { localNetworkTool } from './local-network.js';
+import { requestTestFundsTool } from './request-test-funds.js';
+import { getTransactionTool } from './get-transaction.js';
+import { estimateGasTool } from './estimate-gas.js';
+import { getAccountTool } from './get-account.js';
+import { getContractStorageTool } from './get-contract-storage.js';
+import { decodeTxDataTool } from './decode-tx-data.js';
+import { subscribeEventsTool } from './subscribe-events.js';
+import { getKdaInfoTool } from './get-kda-info.js';
+import { getNetworkStatusTool } from './get-network-status.js';
+import { addressToolsTool } from './address-tools.js';
+import { buildTransferTool } from './build-transfer.js';
+import { listContractTransactionsTool } from './list-contract-transactions.js';
+import { getTokenHoldersTool } from './get-token-holders.js';
+import { refreshDocsTool } from './refresh-docs.js';
+import { searchDocsTool } from './search-docs.js';
+import { generateKoperatorCommandTool } from './generate-koperator-command.js';
+import { explainErrorTool } from './explain-error.js';
+import { cacheClearTool } from './cache-clear.js';
+import { getRecentLogsTool } from './get-recent-logs.js';
+import { showConfigTool } from './show-config.js';
+import { validateConfigTool } from './validate-config.js';
+import { setWorkspaceTool } from './set-workspace.js';
+
+export const TOOLS: KleverTool[] = [
+  scaffoldContractTool,
+  scaffoldWorkspaceTool,
+  addUpgradeSupportTool,
+  adviseStorageMapperTool,
+  generateEndpointTool,
+  generateEventTool,
+  splitIntoModulesTool,
+  generateAsyncCallTool,
+  generateProxyTool,
+  generateTsClientTool,
+  generateInteractorTool,
+  generatePyClientTool,
+  generateOpenApiTool,
+  generateGraphqlSchemaTool,
+  generateFuzzTargetTool,
+  generatePropertyTestsTool,
+  generatePayableEndpointTool,
+  addAdminModuleTool,
+  generateMigrationTool,
+  lintContractTool,
+  auditAccessControlTool,
+  visualizeContractTool,
+  abiDiffTool,
+  generateErrorConstantsTool,
+  checkUpgradeSafetyTool,
+  checkDeprecatedApisTool,
+  buildContractTool,
+  runTestsTool,
+  debugCallTool,
+  profileGasTool,
+  simulateCallTool,
+  forkStateTool,
+  mutationTestTool,
+  benchmarkStorageTool,
+  generateScenarioTool,
+  analyzeWasmSizeTool,
+  verifyBuildTool,
+  deployContractTool,
+  upgradeContractTool,
+  invokeEndpointTool,
+  queryViewTool,
+  decodeResultTool,
+  encodeValueTool,
+  decodeValueTool,
+  localNetworkTool,
+  requestTestFundsTool,
+  getTransactionTool,
+  estimateGasTool,
+  getAccountTool,
+  getContractStorageTool,
+  decodeTxDataTool,
+  subscribeEventsTool,
+  getKdaInfoTool,
+  getNetworkStatusTool,
+  addressToolsTool,
+  buildTransferTool,
+  listContractTransactionsTool,
+  getTokenHoldersTool,
+  refreshDocsTool,
+  searchDocsTool,
+  generateKoperatorCommandTool,
+  explainErrorTool,
+  cacheClearTool,
+  getRecentLogsTool,
+  showConfigTool,
+  validateConfigTool,
+  setWorkspaceTool,
+];
+
+export function findTool(name: string): KleverTool | undefined {
+  return TOOLS.find(t => t.definition.name === name);
+}
+
+/** Tools exposed for a given profile (local-only tools are hidden in public mode) */
+export function getToolsForProfile(profile: ServerProfile): KleverTool[] {
+  return profile === 'public' ? TOOLS.filter(t => !t.localOnly) : TOOLS;
+}
+
+export type { KleverTool, ToolContext, ToolDefinition, ToolAnnotations } from './types.js';
@@ -0,0 +1,152 @@
+import type { KleverTool } from './types.js';
+import { decodeAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { findAbiEndpoint } from '../abi/index.js';
+import { decodeResults, encodeArguments, type AbiArgs } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+import {
+  callValue,
+  checkPayable,
+  loadToolSigner,
+  PAYMENT_PROPERTIES,
+  returnData,
+  SIGNER_PROPERTY,
+  submitTransaction,
+  TRANSACTION_PHASES,
+  WAIT_PROPERTY,
+  type Payment,
+} from './transaction-input.js';
+
+export const invokeEndpointTool: KleverTool = {
+  definition: {
+    name: 'invoke_endpoint',
+    description:
+      'Call a state-changing endpoint of a deployed contract: encodes the arguments from plain JSON with the ABI, attaches optional KLV/KDA payments, builds the transaction on the node with the given gas limit, signs it with a wallet key file on this machine and broadcasts it. By default waits until the transaction is processed and decodes the returned values with the endpoint outputs. For read-only calls use query_view.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        address: {
+          type: 'string',
+          description: 'Contract address (klv1...)',
+        },
+        endpoint: {
+          type: 'string',
+          description: 'Endpoint name as in the ABI',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the contract',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        args: {
+          description:
+            'Endpoint arguments as plain JSON, in ABI order or keyed by argument name. Numbers may be strings; addresses are klv1... or 32-byte 0x hex; bytes are text or 0x hex.',
+          oneOf: [{ type: 'array' }, { type: 'object' }],
+        },
+        ...PAYMENT_PROPERTIES,
+        gasLimit: {
+          type: 'number',
+          description: 'Gas limit of the call (default: chosen by the node)',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network the contract is deployed on (default: the server default)',
+        },
+        signer: SIGNER_PROPERTY,
+        wait: WAIT_PROPERTY,
+      },
+      required: ['address', 'endpoint'],
+    },
+    annotations: {
+      title: 'Invoke Endpoint',
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: false,
+      openWorldHint: true,
+    },
+  },
+  localOnly: true,
+  phases: TRANSACTION_PHASES,
+  handler: async (args, context) => {
+    const {
+      address,
+      endpoint: endpointName,
+      abi,
+      abiUrl,
+      args: values,
+      klv,
+      kda,
+      gasLimit,
+      network,
+      signer: signerPath,
+      wait,
+    } = args as {
+      address: string;
+      endpoint: string;
+      abi?: string;
+      abiUrl?: string;
+      args?: AbiArgs;
+      klv?: string | number;
+      kda?: Payment[];
+      gasLimit?: number;
+      network?: string;
+      signer?: string;
+      wait?: boolean;
+    };
+    decodeAddress(address);
+    const net = validateNetwork(network);
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    context.abiRegistry.register(address, parsed);
+    const endpoint = findAbiEndpoint(parsed, endpointName);
+    const encoded = encodeArguments(endpoint.inputs, values, parsed, endpoint.name);
+    const payment = callValue(klv, kda);
+    checkPayable(endpoint, payment);
+
+    const signer = await loadToolSigner(signerPath);
+    context.phase?.('building transaction', `call ${endpoint.name} on ${address}`);
+    const built = await context.chainClient.buildInvoke(
+      {
+        sender: signer.address,
+        scAddress: address,
+        funcName: endpoint.name,
+        args: encoded.map(arg => arg.toString('base64')),
+        callValue: payment,
+        gasLimit,
+      },
+      net
+    );
+    const submitted = await submitTransaction(context, built, signer, net, wait !== false);
+
+    let results: unknown[] | undefined;
+    const raw = submitted.transaction && returnData(submitted.transaction);
+    if (raw && endpoint.outputs.length > 0) {
+      results = decodeResults(endpoint.outputs, raw, parsed);
+    }
+
+    const nextSteps: string[] = [];
+    if (!submitted.status) {
+      nextSteps.push(`Check the result with get_transaction ${submitted.txHash}`);
+    } else if (submitted.status !== 'success') {
+      nextSteps.push('The call failed; check resultCode, the arguments, payments and gas limit');
+    } else if (endpoint.outputs.length > 0 && !results) {
+      nextSteps.push('No return data was found; read the values with get_transaction');
+    }
+    return {
+      txHash: submitted.txHash,
+      explorerUrl: submitted.explorerUrl,
+      status: submitted.status,
+      resultCode: submitted.resultCode,
+      results,
+      endpoint: endpoint.name,
+      address,
+      sender: signer.address,
+      network: net || context.chainClient.getDefaultNetwork(),
+      arguments: encoded.map(arg => arg.toString('hex')),
+      callValue: payment,
+      nextSteps,
+    };
+  },
+};
@@ -0,0 +1,59 @@
+import type { KleverTool } from './types.js';
+import { CHECKS, lintContract, type RuleConfig } from '../analysis/index.js';
+
+export const lintContractTool: KleverTool = {
+  definition: {
+    name: 'lint_contract',
+    description: `Statically analyze klever-sc contract source and report findings with rule, severity, function, line and a suggested fix. Rules can be turned off or given another severity per project with config. Checks: ${CHECKS.map(c => `${c.name} (${c.description})`).join('; ')}.`,
+    inputSchema: {
+      type: 'object',
+      properties: {
+        source: {
+          type: 'string',
+          description:
+            'Full contract source (src/lib.rs) containing the #[klever_sc::contract] trait',
+        },
+        modules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description:
+            'Module files of the same crate keyed by path (e.g. {"src/storage.rs": "..."}), analyzed together with the contract',
+        },
+        checks: {
+          type: 'array',
+          items: { type: 'string', enum: CHECKS.map(c => c.name) },
+          description: 'Checks to run (default: all)',
+        },
+        config: {
+          type: 'object',
+          properties: {
+            rules: {
+              type: 'object',
+              additionalProperties: { type: 'string', enum: ['error', 'warning', 'info', 'off'] },
+              description:
+                'Severity override or "off" keyed by rule id or check name (e.g. {"arithmetic": "off", "unused-event": "info"}); a rule id takes precedence over its check. Read klever://analysis/rules for the rule ids.',
+            },
+          },
+          description: 'Per-project rule configuration',
+        },
+      },
+      required: ['source'],
+    },
+    annotations: {
+      title: 'Lint Contract',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { source, checks, modules, config } = args as {
+      source: string;
+      checks?: string[];
+      modules?: Record<string, string>;
+      config?: RuleConfig;
+    };
+    return lintContract(source, { checks, modules, config });
+  },
+};
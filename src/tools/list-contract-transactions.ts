@@ -0,0 +1,210 @@
+import type { KleverTool } from './types.js';
+import {
+  decodeAddress,
+  NETWORK_NAMES,
+  validateNetwork,
+  type TransactionData,
+} from '../chain/index.js';
+import { type Abi } from '../abi/index.js';
+import { decodeCallData, parseCallData, type CallData } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+/** API pages read per call when filtering by endpoint, which the API cannot do */
+const MAX_PAGES_SCANNED = 5;
+
+/** Unix milliseconds of an ISO date or a Unix timestamp in seconds or milliseconds */
+function timeMs(value: string | number | undefined, name: string): number | undefined {
+  if (value === undefined || value === '') return undefined;
+  const n = typeof value === 'number' || /^\d+$/.test(value) ? Number(value) : Date.parse(value);
+  if (!Number.isFinite(n)) throw new Error(`${name} must be an ISO date or Unix timestamp.`);
+  return n < 1e12 ? n * 1000 : n;
+}
+
+/** Call data of a transaction: the first (base64) data entry */
+function callData(tx: TransactionData): string | undefined {
+  const data = Array.isArray(tx.data) ? tx.data[0] : undefined;
+  return typeof data === 'string' && data ? data : undefined;
+}
+
+/** Endpoint, arguments and payment of a contract call, decoded with the ABI when known */
+function describeCall(tx: TransactionData, abi: Abi | undefined): Record<string, unknown> {
+  const data = callData(tx);
+  const callValue = tx.contract?.[0]?.parameter?.callValue;
+  if (!data) return { callValue };
+  let call: CallData;
+  try {
+    call = parseCallData(data);
+  } catch {
+    return { callValue };
+  }
+  const rawArgs = call.args.map(arg => `0x${arg.toString('hex')}`);
+  if (!abi) return { endpoint: call.functionName, rawArgs, callValue };
+  try {
+    const { endpoint, args } = decodeCallData(data, abi);
+    return { endpoint: endpoint.name, args, callValue };
+  } catch (error) {
+    const decodeError = (error as Error).message;
+    return { endpoint: call.functionName, rawArgs, callValue, decodeError };
+  }
+}
+
+export const listContractTransactionsTool: KleverTool = {
+  definition: {
+    name: 'list_contract_transactions',
+    description:
+      "Page through a contract's transactions from the Klever indexer API, newest first, filtered by endpoint name, status, sender and time range. Each result has the hash, sender, status, time, the called endpoint and its arguments decoded with the contract ABI (the abi passed here or one used earlier in the session), and the payment sent. Use nextPage to continue.",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        contract: {
+          type: 'string',
+          description: 'Contract address (klv1...)',
+        },
+        endpoint: {
+          type: 'string',
+          description: 'Only calls to this endpoint',
+        },
+        status: {
+          type: 'string',
+          enum: ['success', 'fail'],
+          description: 'Only successful or only failed transactions',
+        },
+        sender: {
+          type: 'string',
+          description: 'Only transactions sent by this address (klv1...)',
+        },
+        from: {
+          type: ['string', 'number'],
+          description: 'Start of the time range: ISO date or Unix timestamp',
+        },
+        to: {
+          type: ['string', 'number'],
+          description: 'End of the time range: ISO date or Unix timestamp',
+        },
+        page: {
+          type: 'integer',
+          minimum: 1,
+          description: 'Page to read, from nextPage of an earlier call (default: 1)',
+        },
+        limit: {
+          type: 'integer',
+          minimum: 1,
+          maximum: 100,
+          description: 'Transactions per page (default: 20)',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the contract, to decode the call arguments',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network the contract is deployed on (default: the server default)',
+        },
+      },
+      required: ['contract'],
+    },
+    annotations: {
+      title: 'List Contract Transactions',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const {
+      contract,
+      endpoint,
+      status,
+      sender,
+      from,
+      to,
+      page = 1,
+      limit = 20,
+      abi,
+      abiUrl,
+      network,
+    } = args as {
+      contract: string;
+      endpoint?: string;
+      status?: 'success' | 'fail';
+      sender?: string;
+      from?: string | number;
+      to?: string | number;
+      page?: number;
+      limit?: number;
+      abi?: string;
+      abiUrl?: string;
+      network?: string;
+    };
+    decodeAddress(contract);
+    if (sender) decodeAddress(sender);
+    const net = validateNetwork(network);
+    let contractAbi: Abi | undefined;
+    if (abi || abiUrl) {
+      contractAbi = await loadAbi(abi, abiUrl, context.profile);
+      context.abiRegistry.register(contract, contractAbi);
+    } else {
+      contractAbi = context.abiRegistry.get(contract);
+    }
+    const filter = {
+      address: contract,
+      fromAddress: sender,
+      status,
+      startTime: timeMs(from, 'from'),
+      endTime: timeMs(to, 'to'),
+      limit: Math.min(Math.max(limit, 1), 100),
+    };
+
+    const transactions: Record<string, unknown>[] = [];
+    let nextPage: number | undefined = page;
+    let totalRecords: number | undefined;
+    for (let scanned = 0; nextPage && scanned < (endpoint ? MAX_PAGES_SCANNED : 1); scanned++) {
+      const result = await context.chainClient.listTransactions({ ...filter, page: nextPage }, net);
+      totalRecords ??= result.pagination?.totalRecords;
+      const more =
+        result.pagination && result.pagination.next > nextPage ? result.pagination.next : undefined;
+      for (const tx of result.transactions) {
+        const call = describeCall(tx, contractAbi);
+        if (endpoint && call.endpoint !== endpoint) continue;
+        transactions.push({
+          hash: tx.hash,
+          sender: tx.sender,
+          status: tx.status,
+          resultCode: tx.resultCode,
+          timestamp: tx.timestamp,
+          blockNum: tx.blockNum,
+          ...call,
+        });
+      }
+      nextPage = more;
+      if (transactions.length >= filter.limit) break;
+    }
+
+    const nextSteps: string[] = [];
+    if (nextPage) {
+      nextSteps.push(`Call again with page ${nextPage} for older transactions`);
+    }
+    if (!contractAbi && transactions.length > 0) {
+      nextSteps.push('Pass the contract abi to decode the call arguments');
+    }
+    if (transactions.some(tx => tx.status && tx.status !== 'success')) {
+      nextSteps.push('Inspect a failed transaction with get_transaction for its logs');
+    }
+
+    return {
+      contract,
+      network: net || context.chainClient.getDefaultNetwork(),
+      page,
+      nextPage,
+      totalRecords: endpoint ? undefined : totalRecords,
+      transactions,
+      nextSteps,
+    };
+  },
+};
@@ -0,0 +1,212 @@
+import { mkdir } from 'fs/promises';
+import { join } from 'path';
+import type { KleverTool, ToolContext } from './types.js';
+import {
+  isRunning,
+  localNetworkDir,
+  readLocalNetworkState,
+  resetLocalNetwork,
+  startLocalNetwork,
+  stopLocalNetwork,
+  testAccounts,
+  writeWalletKeys,
+  type LocalNetworkOptions,
+  type LocalNetworkState,
+} from '../localnet/index.js';
+import { loadToolSigner, submitTransaction } from './transaction-input.js';
+import { CancelledError, sleep } from '../utils/cancellation.js';
+
+const READY_POLL_MS = 2000;
+
+/** Point the `local` network of the chain client at the node in `state` */
+function useLocalNode(context: ToolContext, state: LocalNetworkState | undefined) {
+  context.chainClient.setNetworkUrls(
+    'local',
+    state ? { nodeUrl: state.nodeUrl, apiUrl: state.apiUrl } : undefined
+  );
+}
+
+async function waitUntilReady(context: ToolContext, timeoutMs: number) {
+  const deadline = Date.now() + timeoutMs;
+  let lastError: unknown;
+  while (Date.now() < deadline) {
+    try {
+      return await context.chainClient.getNodeStatus('local');
+    } catch (error) {
+      if (error instanceof CancelledError) throw error;
+      lastError = error;
+      await sleep(READY_POLL_MS);
+    }
+  }
+  throw new Error(
+    `The local node did not answer within ${timeoutMs / 1000}s: ${(lastError as Error)?.message}`
+  );
+}
+
+export const localNetworkTool: KleverTool = {
+  definition: {
+    name: 'local_network',
+    description:
+      'Start, stop, reset or inspect a local Klever node (docker container or node binary) for offline development. On start it waits for the node, writes deterministic test account keys (alice, bob, ...) and funds them with KLV from a funder key that holds genesis funds, and points network "local" of the other tools (deploy_contract, invoke_endpoint, query_view, ...) at the node. Reset deletes the chain data and starts again from genesis.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        action: {
+          type: 'string',
+          enum: ['start', 'stop', 'reset', 'status'],
+          description: 'What to do (default: status)',
+        },
+        mode: {
+          type: 'string',
+          enum: ['docker', 'binary'],
+          description: 'Run the node in docker (default) or from a node binary',
+        },
+        image: {
+          type: 'string',
+          description:
+            'Docker image of the node (default: localnet.image or KLEVER_LOCALNET_IMAGE, else kleverapp/klever-go)',
+        },
+        binary: {
+          type: 'string',
+          description: 'Node binary for binary mode (default: localnet.node_binary or KLEVER_NODE_BIN)',
+        },
+        args: {
+          type: 'array',
+          items: { type: 'string' },
+          description: 'Extra arguments for the container command or the binary',
+        },
+        nodePort: {
+          type: 'number',
+          description: 'Host port of the node API (default: 8080)',
+        },
+        apiPort: {
+          type: 'number',
+          description: 'Host port of the API proxy (default: 9090)',
+        },
+        accounts: {
+          type: 'number',
+          description: 'Number of test accounts to create and fund, 0-8 (default: 4)',
+        },
+        fundAmount: {
+          type: 'number',
+          description:
+            'KLV per test account in the smallest unit (default: 1000 KLV); 0 skips funding',
+        },
+        funder: {
+          type: 'string',
+          description:
+            'Wallet key file holding the genesis funds of the local chain (default: walletKey.pem in the Klever SDK directory)',
+        },
+        timeoutSeconds: {
+          type: 'number',
+          description: 'How long to wait for the node to answer after starting (default: 120)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Local Network',
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+  },
+  localOnly: true,
+  phases: ['starting node', 'waiting for node', 'funding accounts'],
+  handler: async (args, context) => {
+    const {
+      action = 'status',
+      accounts = 4,
+      fundAmount = 1_000_000_000,
+      funder,
+      timeoutSeconds = 120,
+      ...options
+    } = args as LocalNetworkOptions & {
+      action?: 'start' | 'stop' | 'reset' | 'status';
+      accounts?: number;
+      fundAmount?: number;
+      funder?: string;
+      timeoutSeconds?: number;
+    };
+
+    if (action === 'stop') {
+      const stopped = await stopLocalNetwork();
+      useLocalNode(context, undefined);
+      return {
+        running: false,
+        stopped: stopped !== undefined,
+        nextSteps: stopped ? [] : ['No local node was recorded as running'],
+      };
+    }
+    if (action === 'status') {
+      const state = await readLocalNetworkState();
+      const running = state ? await isRunning(state) : false;
+      useLocalNode(context, running ? state : undefined);
+      return {
+        running,
+        ...(running ? state : {}),
+        accounts: running ? testAccounts(accounts).map(a => a.address) : undefined,
+        nextSteps: running ? [] : ['Start a node with local_network action "start"'],
+      };
+    }
+
+    if (action === 'reset') {
+      context.progress?.('Stopping the local node and deleting its chain data');
+      await resetLocalNetwork();
+      // The fresh chain starts every account at nonce 0
+      context.chainClient.resyncNonce();
+    }
+    context.phase?.('starting node', options.mode);
+    const { state, alreadyRunning } = await startLocalNetwork(options);
+    useLocalNode(context, state);
+    context.phase?.('waiting for node', state.nodeUrl);
+    const status = await waitUntilReady(context, timeoutSeconds * 1000);
+
+    const testAccountList = testAccounts(accounts);
+    const keyDir = join(localNetworkDir(), 'accounts');
+    await mkdir(keyDir, { recursive: true });
+    const keyFiles = await writeWalletKeys(testAccountList, keyDir);
+
+    const funded: Record<string, string> = {};
+    const warnings: string[] = [];
+    if (fundAmount > 0 && !alreadyRunning && testAccountList.length > 0) {
+      context.phase?.('funding accounts', `${testAccountList.length} accounts`);
+      const signer = await loadToolSigner(funder);
+      for (const account of testAccountList) {
+        context.progress?.(`Funding ${account.name} (${account.address})`);
+        const built = await context.chainClient.buildTransfer(
+          { sender: signer.address, receiver: account.address, amount: fundAmount },
+          'local'
+        );
+        const submitted = await submitTransaction(context, built, signer, 'local');
+        if (submitted.status !== 'success') {
+          const reason = submitted.resultCode || submitted.status;
+          warnings.push(`Funding ${account.name} failed: ${reason}`);
+        } else {
+          funded[account.name] = submitted.txHash;
+        }
+      }
+    }
+
+    return {
+      running: true,
+      alreadyRunning,
+      ...state,
+      node: status,
+      accounts: testAccountList.map(account => ({
+        name: account.name,
+        address: account.address,
+        keyFile: keyFiles[account.name],
+        fundingTx: funded[account.name],
+      })),
+      warnings,
+      nextSteps: [
+        'Pass network "local" to deploy_contract, invoke_endpoint and query_view',
+        'Sign with a test account by passing its keyFile as signer',
+        ...(alreadyRunning
+          ? ['The node was already running and was not refunded; use action "reset" for a fresh chain']
+          : []),
+      ],
+    };
+  },
+};
@@ -0,0 +1,107 @@
+import type { KleverTool } from './types.js';
+import { MUTATION_PHASES, mutationTest, type MutationOperator } from '../toolchain/index.js';
+
+export const mutationTestTool: KleverTool = {
+  definition: {
+    name: 'mutation_test',
+    description:
+      'Find contract logic no test checks: apply systematic mutations to the contract and module functions of a klever-sc project on disk, rerun the Rust tests against each mutant in a copy of the project under target/, and report the mutants the tests still pass with. Every surviving mutant is a check or an update the tests do not catch, with its file, line, function and the changed code. Scenarios replayed by the Go VM (*_go_test.rs) are not run. The tests must pass unmutated; each mutant is one cargo test run.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        projectPath: {
+          type: 'string',
+          description: 'Contract project root (the directory with Cargo.toml and tests/)',
+        },
+        file: {
+          type: 'string',
+          description:
+            'Only mutate source files whose path contains this string (e.g. src/lib.rs); narrows large contracts, where every mutant costs a cargo test run',
+        },
+        functions: {
+          type: 'array',
+          items: { type: 'string' },
+          description:
+            'Only mutate these functions (endpoint or helper names), e.g. the ones a change touched',
+        },
+        operators: {
+          type: 'array',
+          items: { type: 'string', enum: ['comparison', 'require', 'compound-assignment'] },
+          description:
+            'Mutations to apply (default: all): comparison negates a comparison, require drops a require! statement, compound-assignment swaps += and -=',
+        },
+        maxMutants: {
+          type: 'number',
+          description:
+            'Most mutants to test, in source order (default: 50); lower it with file or functions to bound the run time',
+        },
+        filter: {
+          type: 'string',
+          description: 'Only run tests whose name contains this string',
+        },
+      },
+      required: ['projectPath'],
+    },
+    annotations: {
+      title: 'Mutation Test',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  localOnly: true,
+  phases: MUTATION_PHASES,
+  handler: async (args, context) => {
+    const { projectPath, file, functions, operators, maxMutants, filter } = args as {
+      projectPath: string;
+      file?: string;
+      functions?: string[];
+      operators?: MutationOperator[];
+      maxMutants?: number;
+      filter?: string;
+    };
+    const report = await mutationTest(projectPath, {
+      file,
+      functions,
+      operators,
+      maxMutants,
+      filter,
+      onLine: context.progress,
+      onPhase: context.phase,
+    });
+    const survivors = report.mutants.filter(m => m.status === 'survived');
+
+    const nextSteps: string[] = [];
+    for (const m of survivors.slice(0, 5)) {
+      nextSteps.push(
+        m.operator === 'require'
+          ? `No test fails without \`${m.code}\` (${m.file}:${m.line}); add a test expecting its error`
+          : `No test tells \`${m.code}\` from \`${m.mutated}\` (${m.file}:${m.line}); add a test on the boundary of ${m.function}`
+      );
+    }
+    if (survivors.length > 5) {
+      nextSteps.push(`${survivors.length - 5} more surviving mutants are listed in mutants`);
+    }
+    if (survivors.length === 0) {
+      nextSteps.push('The tests kill every mutant tested');
+    }
+    if (report.skipped > 0) {
+      nextSteps.push(
+        `${report.skipped} mutants were not tested; raise maxMutants or narrow with file or functions`
+      );
+    }
+    return {
+      ...report,
+      survivors: survivors.map(({ id, file, line, function: fn, code, mutated }) => ({
+        id,
+        file,
+        line,
+        function: fn,
+        code,
+        mutated,
+      })),
+      nextSteps,
+    };
+  },
+};
@@ -0,0 +1,128 @@
+import { readFile, writeFile } from 'node:fs/promises';
+import { resolve } from 'node:path';
+import type { KleverTool } from './types.js';
+import {
+  compareGas,
+  gasBaseline,
+  GAS_PHASES,
+  parseGasBaseline,
+  profileGas,
+} from '../toolchain/index.js';
+
+const DEFAULT_BASELINE = 'gas-baseline.json';
+
+export const profileGasTool: KleverTool = {
+  definition: {
+    name: 'profile_gas',
+    description:
+      "Run a contract project's scenario suite (scenarios/*.scen.json) in the Go VM and report the gas used by every scCall and scDeploy step, with min, max and average per endpoint. The first run (or updateBaseline) stores the profile as a baseline file; later runs compare each call with its baseline entry and flag regressions above thresholdPercent, as well as improvements and calls added or removed since. Each transaction is measured with a separate run of the scenario runner, so large suites take a while; narrow them with filter.",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        projectPath: {
+          type: 'string',
+          description: 'Contract project root (the directory with Cargo.toml and scenarios/)',
+        },
+        filter: {
+          type: 'string',
+          description: 'Only profile scenario files whose path contains this string',
+        },
+        baseline: {
+          type: 'string',
+          description: `Baseline file, relative to the project root (default: ${DEFAULT_BASELINE})`,
+        },
+        updateBaseline: {
+          type: 'boolean',
+          description: 'Store this run as the baseline instead of comparing with it',
+        },
+        thresholdPercent: {
+          type: 'number',
+          description:
+            'Gas increase of a call over its baseline that counts as a regression (default: 5)',
+        },
+        runner: {
+          type: 'string',
+          description: 'Go VM scenario runner binary (default: run-scenarios on PATH)',
+        },
+      },
+      required: ['projectPath'],
+    },
+    annotations: {
+      title: 'Profile Gas',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: false,
+    },
+  },
+  localOnly: true,
+  phases: GAS_PHASES,
+  handler: async (args, context) => {
+    const {
+      projectPath,
+      filter,
+      baseline = DEFAULT_BASELINE,
+      updateBaseline,
+      thresholdPercent = 5,
+      runner,
+    } = args as {
+      projectPath: string;
+      filter?: string;
+      baseline?: string;
+      updateBaseline?: boolean;
+      thresholdPercent?: number;
+      runner?: string;
+    };
+    const profile = await profileGas(projectPath, {
+      runner,
+      filter,
+      onLine: context.progress,
+      onScenario: scenario => context.phase?.('measuring', scenario),
+    });
+
+    const baselinePath = resolve(projectPath, baseline);
+    let existing: string | undefined;
+    try {
+      existing = await readFile(baselinePath, 'utf-8');
+    } catch {
+      // No baseline yet
+    }
+
+    const nextSteps: string[] = [];
+    if (profile.failures.length > 0) {
+      const failed = profile.failures.length;
+      nextSteps.push(`${failed} scenario(s) could not be measured; make them pass with run_tests`);
+    }
+    if (updateBaseline || existing === undefined) {
+      if (profile.calls.length === 0) {
+        nextSteps.push('No calls were measured, so no baseline was stored');
+        return { ...profile, nextSteps };
+      }
+      await writeFile(baselinePath, `${JSON.stringify(gasBaseline(profile), null, 2)}\n`);
+      nextSteps.push(`Baseline stored in ${baseline}; commit it and run profile_gas after changes`);
+      return { ...profile, baseline: { path: baselinePath, stored: true }, nextSteps };
+    }
+
+    context.phase?.('comparing');
+    const comparison = compareGas(profile.calls, parseGasBaseline(existing), thresholdPercent);
+    const { regressions } = comparison;
+    if (regressions.length > 0) {
+      const worst = [...regressions].sort((a, b) => b.changePercent - a.changePercent)[0];
+      nextSteps.push(
+        `${regressions.length} call(s) regressed; the worst is ${worst.key} (${worst.endpoint}) at +${worst.changePercent}%`
+      );
+    } else {
+      nextSteps.push(`No call uses more than ${thresholdPercent}% more gas than the baseline`);
+    }
+    if (comparison.added.length > 0 || comparison.removed.length > 0) {
+      nextSteps.push('Scenario calls changed since the baseline; record them with updateBaseline');
+    }
+    return {
+      ...profile,
+      baseline: { path: baselinePath, stored: false },
+      comparison,
+      regressed: regressions.length > 0,
+      nextSteps,
+    };
+  },
+};
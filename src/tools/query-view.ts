@@ -0,0 +1,106 @@
+import type { KleverTool } from './types.js';
+import { decodeAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { findAbiEndpoint } from '../abi/index.js';
+import { decodeResults, encodeArguments, type AbiArgs } from '../codegen/index.js';
+import { loadAbi } from './abi-input.js';
+
+export const queryViewTool: KleverTool = {
+  definition: {
+    name: 'query_view',
+    description:
+      'Query a view of a deployed contract (e.g. getBalance, getTotalSupply) without sending a transaction. Encodes the arguments from plain JSON with the ABI and decodes the returned data with the view outputs: integers as numbers (or strings when too large), addresses as klv1..., structs as objects, Option as the value or null. Use query_sc for raw base64 queries without an ABI.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        address: {
+          type: 'string',
+          description: 'Contract address (klv1...)',
+        },
+        view: {
+          type: 'string',
+          description: 'View name as in the ABI',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the contract',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        args: {
+          description:
+            'View arguments as plain JSON, in ABI order or keyed by argument name. Numbers may be strings; addresses are klv1... or 32-byte 0x hex; bytes are text or 0x hex.',
+          oneOf: [{ type: 'array' }, { type: 'object' }],
+        },
+        caller: {
+          type: 'string',
+          description: 'Caller address (klv1...) for views that read the caller',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network the contract is deployed on (default: the server default)',
+        },
+      },
+      required: ['address', 'view'],
+    },
+    annotations: {
+      title: 'Query View',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  handler: async (args, context) => {
+    const { address, view, abi, abiUrl, args: values, caller, network } = args as {
+      address: string;
+      view: string;
+      abi?: string;
+      abiUrl?: string;
+      args?: AbiArgs;
+      caller?: string;
+      network?: string;
+    };
+    decodeAddress(address);
+    if (caller) decodeAddress(caller);
+    const net = validateNetwork(network);
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    context.abiRegistry.register(address, parsed);
+    const endpoint = findAbiEndpoint(parsed, view);
+    const encoded = encodeArguments(endpoint.inputs, values, parsed, endpoint.name);
+
+    const response = await context.chainClient.querySmartContract(
+      {
+        scAddress: address,
+        funcName: endpoint.name,
+        args: encoded.map(arg => arg.toString('base64')),
+        ...(caller ? { caller } : {}),
+      },
+      net
+    );
+    const raw = (response.returnData || []).map(value => Buffer.from(value || '', 'base64'));
+    const failed = !!response.returnCode && response.returnCode.toLowerCase() !== 'ok';
+
+    const nextSteps: string[] = [];
+    if (failed) {
+      nextSteps.push('The query failed; check returnMessage, the view name and its arguments');
+    } else if (endpoint.mutability !== 'readonly') {
+      nextSteps.push(
+        `${endpoint.name} is not a view; its state changes were not applied. Use invoke_endpoint to send it.`
+      );
+    }
+    return {
+      view: endpoint.name,
+      address,
+      network: net || context.chainClient.getDefaultNetwork(),
+      returnCode: response.returnCode,
+      returnMessage: response.returnMessage || undefined,
+      results: failed ? undefined : decodeResults(endpoint.outputs, raw, parsed),
+      rawResults: raw.map(value => value.toString('hex')),
+      gasUsed: response.gasUsed,
+      nextSteps,
+    };
+  },
+};
@@ -0,0 +1,48 @@
+import type { KleverTool } from './types.js';
+import { getConfig } from '../config/index.js';
+import { frameworkDocsDir, refreshFrameworkDocs } from '../knowledge/index.js';
+
+export const refreshDocsTool: KleverTool = {
+  definition: {
+    name: 'refresh_docs',
+    description:
+      'Download newer klever-sc framework documentation pages (<topic>.md for annotations, managed-types, storage, calls and testing) from a base URL into the local docs directory. The klever://docs/{topic} resources serve the downloaded pages instead of the ones shipped with the server from then on; topics that fail to download keep their previous page.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        baseUrl: {
+          type: 'string',
+          description:
+            'URL of the directory holding the pages (default: the cache.docs_url setting or KLEVER_SC_DOCS_URL)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Refresh Framework Docs',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  localOnly: true,
+  handler: async args => {
+    const { baseUrl = getConfig().cache.docsUrl } = args as { baseUrl?: string };
+    if (!baseUrl) throw new Error('Pass baseUrl, or set cache.docs_url or KLEVER_SC_DOCS_URL.');
+    if (!/^https?:\/\//.test(baseUrl)) throw new Error('baseUrl must use http or https.');
+
+    const results = await refreshFrameworkDocs(baseUrl);
+    const failed = results.filter(result => !result.updated);
+    return {
+      baseUrl,
+      dir: frameworkDocsDir(),
+      results,
+      nextSteps:
+        failed.length > 0
+          ? [
+              `${failed.map(result => result.topic).join(', ')} kept their previous pages; check that ${baseUrl} serves <topic>.md`,
+            ]
+          : ['Read klever://docs/index for the updated pages'],
+    };
+  },
+};
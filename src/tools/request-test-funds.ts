@@ -0,0 +1,122 @@
+import type { KleverTool } from './types.js';
+import { decodeAddress, validateNetwork, type KleverNetwork } from '../chain/index.js';
+
+const KLV_UNIT = 1_000_000;
+const BALANCE_POLL_MS = 2000;
+
+export const requestTestFundsTool: KleverTool = {
+  definition: {
+    name: 'request_test_funds',
+    description:
+      'Request test KLV for an address from the Klever faucet on testnet or devnet, then confirm the funds arrived by watching the balance. Faucets only fund an address every so often; a refused request is reported as rateLimited with the current balance rather than as an error. Use before deploy_contract when the deployer has too little KLV for fees.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        address: {
+          type: 'string',
+          description: 'Address to fund (klv1...)',
+        },
+        network: {
+          type: 'string',
+          enum: ['testnet', 'devnet'],
+          description:
+            'Network whose faucet to use (default: the server default when it is testnet or devnet, otherwise testnet)',
+        },
+        wait: {
+          type: 'boolean',
+          description: 'Wait until the balance increases (default: true)',
+        },
+        timeoutSeconds: {
+          type: 'number',
+          description: 'How long to wait for the funds (default: 60)',
+        },
+      },
+      required: ['address'],
+    },
+    annotations: {
+      title: 'Request Test Funds',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: true,
+    },
+  },
+  localOnly: true,
+  handler: async (args, context) => {
+    const { address, network, wait = true, timeoutSeconds = 60 } = args as {
+      address: string;
+      network?: string;
+      wait?: boolean;
+      timeoutSeconds?: number;
+    };
+    decodeAddress(address);
+    const requested = validateNetwork(network);
+    const fallback = context.chainClient.getDefaultNetwork();
+    const net: KleverNetwork =
+      requested || (fallback === 'testnet' || fallback === 'devnet' ? fallback : 'testnet');
+    if (net === 'local') {
+      throw new Error('The local network has no faucet; local_network funds test accounts itself.');
+    }
+
+    const balanceOf = () => context.chainClient.getBalance(address, undefined, net).catch(() => 0);
+    const before = await balanceOf();
+    const klv = (units: number) => units / KLV_UNIT;
+
+    let txHash: string | undefined;
+    try {
+      txHash = (await context.chainClient.requestTestFunds(address, net)).txHash;
+    } catch (error) {
+      const message = (error as Error).message;
+      if (!message.startsWith('Faucet rate limit')) throw error;
+      return {
+        funded: false,
+        rateLimited: true,
+        address,
+        network: net,
+        balance: before,
+        balanceKLV: klv(before),
+        message,
+        nextSteps: [
+          before > 0
+            ? 'The address already holds KLV; try deploying with the current balance'
+            : 'Try again later, or fund the address from another testnet wallet',
+        ],
+      };
+    }
+
+    let balance = before;
+    if (wait) {
+      context.progress?.(`Faucet accepted the request; waiting for ${address} to be funded`);
+      const deadline = Date.now() + timeoutSeconds * 1000;
+      if (txHash) {
+        await context.chainClient
+          .waitForTransaction(txHash, net, { timeoutMs: timeoutSeconds * 1000 })
+          .catch(() => undefined);
+      }
+      balance = await balanceOf();
+      while (balance <= before && Date.now() < deadline) {
+        await new Promise(resolve => setTimeout(resolve, BALANCE_POLL_MS));
+        balance = await balanceOf();
+      }
+    }
+
+    const confirmed = balance > before;
+    return {
+      funded: wait ? confirmed : undefined,
+      txHash,
+      address,
+      network: net,
+      balanceBefore: before,
+      balance,
+      receivedKLV: klv(balance - before),
+      balanceKLV: klv(balance),
+      nextSteps: !wait
+        ? ['Check the balance with get_balance before deploying']
+        : confirmed
+          ? ['Deploy with deploy_contract on the same network']
+          : [
+              `The balance did not change within ${timeoutSeconds}s; check it later with get_balance`,
+            ],
+    };
+  },
+};
@@ -0,0 +1,107 @@
+import type { KleverTool } from './types.js';
+import { runTests, TEST_PHASES, type TestKind } from '../toolchain/index.js';
+
+export const runTestsTool: KleverTool = {
+  definition: {
+    name: 'run_tests',
+    description:
+      'Run the tests of a klever-sc contract project on disk with cargo: scenario tests (tests/*scenario*.rs targets replaying scenarios/*.scen.json) and Rust integration tests. Output is streamed as progress notifications; the result lists every test with its target, kind and pass/fail/ignored status, the panic message of each failure, and compiler errors when the tests do not build. With coverage, the tests run under cargo llvm-cov and the result adds line (and optionally branch) coverage per endpoint, view and helper, with the source lines no test executed.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        projectPath: {
+          type: 'string',
+          description: 'Contract project root (the directory with Cargo.toml and tests/)',
+        },
+        kind: {
+          type: 'string',
+          enum: ['all', 'scenario', 'rust'],
+          description:
+            'Tests to run: scenario targets only, Rust unit and integration tests only, or everything including doc tests (default: all)',
+        },
+        filter: {
+          type: 'string',
+          description: 'Only run tests whose name contains this string',
+        },
+        coverage: {
+          type: 'boolean',
+          description:
+            'Measure coverage with cargo llvm-cov (cargo install cargo-llvm-cov). Only natively run code counts: Rust tests and *_scenario_rs_test.rs targets, not scenarios replayed by the Go VM (default: false)',
+        },
+        branches: {
+          type: 'boolean',
+          description: 'With coverage, also measure branch coverage; needs a nightly toolchain',
+        },
+      },
+      required: ['projectPath'],
+    },
+    annotations: {
+      title: 'Run Tests',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  localOnly: true,
+  phases: TEST_PHASES,
+  handler: async (args, context) => {
+    const { projectPath, kind, filter, coverage, branches } = args as {
+      projectPath: string;
+      kind?: TestKind | 'all';
+      filter?: string;
+      coverage?: boolean;
+      branches?: boolean;
+    };
+    const result = await runTests(projectPath, {
+      kind,
+      filter,
+      coverage,
+      branches,
+      onLine: context.progress,
+      onPhase: context.phase,
+    });
+    const failed = result.tests.filter(t => t.status === 'failed');
+
+    const nextSteps: string[] = [];
+    if (result.compileErrors.length > 0) {
+      nextSteps.push('Fix the compiler errors at the listed locations, then run the tests again');
+    } else if (failed.length > 0) {
+      nextSteps.push(
+        `Fix ${failed.length} failing test(s), then rerun just those with filter (e.g. "${failed[0].name}")`
+      );
+    } else if (result.timedOut) {
+      nextSteps.push('The test run timed out; narrow it with kind or filter');
+    } else if (result.tests.length === 0) {
+      nextSteps.push('No tests ran; add scenario tests under tests/ and scenarios/');
+    } else if (result.coverage) {
+      const untested = result.coverage.functions.filter(
+        f => f.kind !== 'private' && f.lines.total > 0 && f.lines.covered === 0
+      );
+      const partial = result.coverage.functions.filter(
+        f => f.kind !== 'private' && f.lines.covered > 0 && f.uncoveredLines.length > 0
+      );
+      if (untested.length > 0) {
+        nextSteps.push(
+          `No test reaches ${untested.map(f => f.name).join(', ')}; add tests calling them`
+        );
+      }
+      for (const f of partial.slice(0, 5)) {
+        nextSteps.push(
+          `${f.name} is ${f.lines.percent}% covered; lines ${f.uncoveredLines.join(', ')} of ${f.file} never ran`
+        );
+      }
+      if (untested.length === 0 && partial.length === 0) {
+        nextSteps.push(
+          'Every endpoint and view is fully covered; build the contract with build_contract'
+        );
+      }
+    } else {
+      nextSteps.push('All tests pass; build the contract with build_contract');
+    }
+    if (coverage && !result.coverage && result.compileErrors.length === 0) {
+      nextSteps.push('cargo llvm-cov wrote no coverage report; check the output');
+    }
+    return { ...result, nextSteps };
+  },
+};
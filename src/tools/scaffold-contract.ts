@@ -0,0 +1,68 @@
+import type { KleverTool } from './types.js';
+import { CONTRACT_TEMPLATES, generateContractProject, kleverScVersion } from '../scaffold/index.js';
+
+export const scaffoldContractTool: KleverTool = {
+  definition: {
+    name: 'scaffold_contract',
+    description:
+      'Generate a complete klever-sc contract project (Cargo.toml, src/lib.rs, wasm crate, meta crate) from a named template. Returns the files as a path-to-content map for the client to write to disk; nothing is written server-side. Each template accepts its own parameters with defaults. Given the Cargo.toml of the target project, the manifests pin its klever-sc version, with a warning when the template needs a newer one.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        template: {
+          type: 'string',
+          enum: CONTRACT_TEMPLATES.map(t => t.name),
+          description: `Template to generate. Available: ${CONTRACT_TEMPLATES.map(t => `"${t.name}" (${t.description})`).join('; ')}.`,
+        },
+        name: {
+          type: 'string',
+          description:
+            'Project name in kebab-case (e.g. "my-token"). Used as the Cargo package name; the contract trait is named in PascalCase.',
+        },
+        parameters: {
+          type: 'object',
+          additionalProperties: true,
+          description:
+            'Template-specific parameters (e.g. {"initialSupply": "1000000"}). Omitted parameters use template defaults; the resolved values are echoed in the response.',
+        },
+        cargoToml: {
+          type: 'string',
+          description:
+            'Contents of a Cargo.toml from the target project or workspace; its klever-sc version is used for the generated manifests (default: the version templates are written for)',
+        },
+      },
+      required: ['template', 'name'],
+    },
+    annotations: {
+      title: 'Scaffold Contract Project',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { template, name, parameters, cargoToml } = args as {
+      template: string;
+      name: string;
+      parameters?: Record<string, unknown>;
+      cargoToml?: string;
+    };
+
+    const frameworkVersion = cargoToml ? kleverScVersion(cargoToml) : undefined;
+    const result = generateContractProject(template, name, parameters, { frameworkVersion });
+    return {
+      ...result,
+      nextSteps: [
+        ...(result.warnings
+          ? [
+              'Upgrade the project klever-sc dependencies to the pinned version, or pick another template',
+            ]
+          : []),
+        `Write each file under a "${name}/" directory`,
+        'Build with: ~/klever-sdk/ksc all build',
+        'Use add_helper_scripts to add deploy/upgrade/query scripts',
+      ],
+    };
+  },
+};
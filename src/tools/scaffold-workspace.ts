@@ -0,0 +1,95 @@
+import type { KleverTool } from './types.js';
+import {
+  CONTRACT_TEMPLATES,
+  generateContractWorkspace,
+  kleverScVersion,
+  type WorkspaceContract,
+} from '../scaffold/index.js';
+
+export const scaffoldWorkspaceTool: KleverTool = {
+  definition: {
+    name: 'scaffold_workspace',
+    description:
+      'Generate a cargo workspace of several template contracts that call each other (e.g. token + staking + governance): each contract under contracts/<name>, a shared proxies crate generated from their sources, and a common test crate that deploys and wires them on the Rust VM. Addresses are never hardcoded: deploy.json lists the contracts in deployment order with {name} placeholders for the addresses of other contracts, and scripts/deploy.sh deploys them with koperator, fills the placeholders and makes the wiring calls. Returns the files as a path-to-content map; nothing is written server-side.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        name: {
+          type: 'string',
+          description:
+            'Workspace name in kebab-case (e.g. "my-dao"); names the proxies and tests crates',
+        },
+        contracts: {
+          type: 'array',
+          items: {
+            type: 'object',
+            properties: {
+              name: {
+                type: 'string',
+                description: 'Contract crate name in kebab-case, unique in the workspace',
+              },
+              template: {
+                type: 'string',
+                enum: CONTRACT_TEMPLATES.map(t => t.name),
+              },
+              parameters: {
+                type: 'object',
+                additionalProperties: true,
+                description: 'Template-specific parameters, as for scaffold_contract',
+              },
+              dependsOn: {
+                type: 'array',
+                items: { type: 'string' },
+                description:
+                  'Names of the workspace contracts this one calls. Each gets a proxy accessor and an address mapper, set by the constructor when init takes <dependency>_address and otherwise by an owner-only set<Dependency>Address endpoint',
+              },
+            },
+            required: ['name', 'template'],
+          },
+          description:
+            'Contracts of the workspace, e.g. [{"name": "token", "template": "fungible_token"}, {"name": "staking", "template": "staking", "dependsOn": ["token"]}]',
+        },
+        network: {
+          type: 'string',
+          description: 'Network written to deploy.json (default: testnet)',
+        },
+        cargoToml: {
+          type: 'string',
+          description:
+            'Contents of a Cargo.toml whose klever-sc version to pin (default: the version templates are written for)',
+        },
+      },
+      required: ['name', 'contracts'],
+    },
+    annotations: {
+      title: 'Scaffold Contract Workspace',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { name, contracts, network, cargoToml } = args as {
+      name: string;
+      contracts: WorkspaceContract[];
+      network?: string;
+      cargoToml?: string;
+    };
+
+    const frameworkVersion = cargoToml ? kleverScVersion(cargoToml) : undefined;
+    const result = generateContractWorkspace(name, contracts, { frameworkVersion, network });
+    return {
+      ...result,
+      nextSteps: [
+        `Write each file under a "${name}/" directory`,
+        'Build each contract with: ~/klever-sdk/ksc all build (in contracts/<name>)',
+        `Run the deployment test with: cargo test -p ${name}-tests`,
+        ...(result.unsetArguments.length > 0
+          ? [`Fill in ${result.unsetArguments.join(', ')} in deploy.json`]
+          : []),
+        'Deploy and wire the contracts with: bash scripts/deploy.sh (KEY_FILE and NETWORK override the wallet and network)',
+      ],
+    };
+  },
+};
@@ -0,0 +1,133 @@
+import type { KleverTool } from './types.js';
+import {
+  bestPracticesKnowledge,
+  coreKnowledge,
+  documentationKnowledge,
+  errorsKnowledge,
+  eventKnowledge,
+  examplesKnowledge,
+  frameworkDocs,
+  loadFrameworkDoc,
+  markdownSections,
+  modulesKnowledge,
+  scriptsKnowledge,
+  SearchIndex,
+  storageKnowledge,
+  tokenKnowledge,
+  toolsKnowledge,
+  type KnowledgeEntry,
+  type SearchDocument,
+} from '../knowledge/index.js';
+import { CONTRACT_TEMPLATES, generateContractProject } from '../scaffold/index.js';
+
+const KNOWLEDGE_BY_CATEGORY: Record<string, KnowledgeEntry[]> = {
+  core: coreKnowledge,
+  storage: storageKnowledge,
+  events: eventKnowledge,
+  tokens: tokenKnowledge,
+  modules: modulesKnowledge,
+  tools: toolsKnowledge,
+  scripts: scriptsKnowledge,
+  examples: examplesKnowledge,
+  errors: errorsKnowledge,
+  'best-practices': bestPracticesKnowledge,
+  documentation: documentationKnowledge,
+};
+
+const KINDS: SearchDocument['kind'][] = ['knowledge', 'docs', 'template'];
+
+/** Built on first use; refresh_docs pages are picked up when the server restarts */
+let index: Promise<SearchIndex> | undefined;
+
+async function searchDocuments(): Promise<SearchDocument[]> {
+  const knowledge = Object.entries(KNOWLEDGE_BY_CATEGORY).flatMap(([category, entries]) =>
+    entries.map(
+      (entry): SearchDocument => ({
+        source: `klever://knowledge/${category}`,
+        kind: 'knowledge',
+        title: entry.metadata.title,
+        text: [entry.metadata.description, entry.content].filter(Boolean).join('\n\n'),
+        tags: entry.metadata.tags,
+        language: entry.metadata.language,
+      })
+    )
+  );
+  const pages = await Promise.all(frameworkDocs.map(doc => loadFrameworkDoc(doc.topic)));
+  const docs = pages.flatMap(page =>
+    page
+      ? markdownSections({
+          source: `klever://docs/${page.topic}`,
+          title: page.title,
+          content: page.content,
+        })
+      : []
+  );
+  const templates = CONTRACT_TEMPLATES.map(
+    (template): SearchDocument => ({
+      source: `template:${template.name}/src/lib.rs`,
+      kind: 'template',
+      title: `${template.name} template: ${template.description}`,
+      text: generateContractProject(template.name, template.name).files['src/lib.rs'],
+      language: 'rust',
+    })
+  );
+  return [...knowledge, ...docs, ...templates];
+}
+
+export const searchDocsTool: KleverTool = {
+  definition: {
+    name: 'search_docs',
+    description:
+      'Ranked search over the built-in knowledge base, the klever-sc framework docs (klever://docs/...) and the scaffold_contract templates, for questions in plain language like "how do I accept multiple KDA payments". Returns the best-matching snippets with their source (resource URI or template file) and line range, so you can ground code in them or read the full source.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        query: {
+          type: 'string',
+          description: 'What to look for, in plain language or with API names',
+        },
+        kinds: {
+          type: 'array',
+          items: { type: 'string', enum: KINDS },
+          description: 'Only these sources: knowledge, docs, template (default: all)',
+        },
+        limit: {
+          type: 'integer',
+          minimum: 1,
+          maximum: 20,
+          description: 'Number of results (default: 5)',
+        },
+      },
+      required: ['query'],
+    },
+    annotations: {
+      title: 'Search Docs',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { query, kinds, limit = 5 } = args as {
+      query: string;
+      kinds?: SearchDocument['kind'][];
+      limit?: number;
+    };
+    if (!query.trim()) throw new Error('query must not be empty.');
+    index ??= searchDocuments().then(documents => new SearchIndex(documents));
+    const results = (await index).search(query, {
+      limit: Math.min(Math.max(limit, 1), 20),
+      kinds,
+    });
+
+    return {
+      query,
+      results,
+      nextSteps:
+        results.length === 0
+          ? ['Nothing matched; try API names (e.g. call_value, MapMapper) or fewer words']
+          : ['Read the source resource for the full page when a snippet is not enough'],
+    };
+  },
+};
@@ -0,0 +1,114 @@
+import { existsSync } from 'node:fs';
+import { join, resolve } from 'node:path';
+import type { KleverTool } from './types.js';
+import { decodeAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { parseAbi } from '../abi/index.js';
+import type { WorkspaceChanges } from '../mcp/workspace.js';
+import { loadToolSigner } from './transaction-input.js';
+
+export const setWorkspaceTool: KleverTool = {
+  definition: {
+    name: 'set_workspace',
+    description:
+      'Set the project context of this session so later tool calls can leave out the same arguments: the contract project (projectPath of build_contract, run_tests and verify_build; the built .wasm and .abi.json in output/ for deploy_contract, upgrade_contract and the ABI of query_view, invoke_endpoint and the other contract tools; src/ as source and modules of lint_contract and the other source analyses), the network, the signer and the deployed contract address. Arguments given to a tool still win. Only the given settings change; an empty string unsets one. Call it without arguments to see the current workspace.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        projectPath: {
+          type: 'string',
+          description: 'Contract project root (the directory with Cargo.toml, src/ and meta/)',
+        },
+        abiPath: {
+          type: 'string',
+          description: 'ABI JSON file (default: the .abi.json in the project output/ directory)',
+        },
+        network: {
+          type: 'string',
+          enum: [...NETWORK_NAMES, ''],
+          description: 'Network tools use when given none',
+        },
+        signer: {
+          type: 'string',
+          description: 'Wallet key file transactions are signed with when a tool is given none',
+        },
+        contract: {
+          type: 'string',
+          description: 'Address (klv1...) of the deployed contract that contract tools target',
+        },
+        clear: {
+          type: 'boolean',
+          description: 'Forget the whole workspace before applying the other settings',
+        },
+      },
+    },
+    annotations: {
+      title: 'Set Workspace',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  localOnly: true,
+  handler: async (args, context) => {
+    const { projectPath, abiPath, network, signer, contract, clear } = args as {
+      projectPath?: string;
+      abiPath?: string;
+      network?: string;
+      signer?: string;
+      contract?: string;
+      clear?: boolean;
+    };
+    const path = (value?: string) => (value ? resolve(value) : value);
+    const changes: WorkspaceChanges = {
+      projectPath: path(projectPath),
+      abiPath: path(abiPath),
+      network: network ? validateNetwork(network) : (network as '' | undefined),
+      signer: path(signer),
+      contract,
+    };
+    if (changes.projectPath && !existsSync(join(changes.projectPath, 'Cargo.toml'))) {
+      throw new Error(`No Cargo.toml in ${changes.projectPath}; pass the contract project root.`);
+    }
+    if (changes.abiPath && !existsSync(changes.abiPath)) {
+      throw new Error(`ABI file not found: ${changes.abiPath}`);
+    }
+    if (contract) decodeAddress(contract);
+    if (changes.signer) await loadToolSigner(changes.signer);
+
+    const { workspace } = context;
+    if (clear) workspace.clear();
+    workspace.set(changes);
+
+    const current = workspace.settings;
+    const abiJson = await workspace.abiJson();
+    const abi = abiJson ? parseAbi(abiJson) : undefined;
+    if (abi && current.contract) context.abiRegistry.register(current.contract, abi);
+    const signerAddress = current.signer
+      ? (await loadToolSigner(current.signer)).address
+      : undefined;
+    const wasmPath = await workspace.outputFile('.wasm');
+
+    const nextSteps: string[] = [];
+    if (current.projectPath && !wasmPath) {
+      nextSteps.push('Build the project with build_contract; deploys then use its output/ files');
+    }
+    if (wasmPath && !current.contract) {
+      nextSteps.push('Deploy with deploy_contract, then set contract to the new address');
+    }
+    if (current.contract) {
+      nextSteps.push('Query views with query_view and call endpoints with invoke_endpoint');
+    }
+    return {
+      workspace: current,
+      wasmPath,
+      abi: abi && {
+        name: abi.name,
+        endpoints: abi.endpoints.map(endpoint => endpoint.name),
+        events: (abi.events || []).map(event => event.identifier),
+      },
+      signerAddress,
+      nextSteps,
+    };
+  },
+};
@@ -0,0 +1,48 @@
+import type { KleverTool } from './types.js';
+import { CONFIG_FILE_NAME, describeConfig, ENV_OVERRIDES } from '../config/index.js';
+
+export const showConfigTool: KleverTool = {
+  definition: {
+    name: 'show_config',
+    description: `Show the server's effective settings: networks, key and toolchain paths, caches, enabled tools, rate limits and API key names, with where each setting came from (the ${CONFIG_FILE_NAME} file, an environment variable or the default). API keys are shown without their secrets. Use it to find out which wallet key, SDK directory or default network tools will use.`,
+    inputSchema: {
+      type: 'object',
+      properties: {},
+    },
+    annotations: {
+      title: 'Show Config',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  localOnly: true,
+  handler: async () => {
+    const { config, file, sources } = describeConfig();
+    return {
+      file: file || null,
+      settings: {
+        ...config,
+        apiKeys: config.apiKeys.map(({ name, tools, readOnly, networks }) => ({
+          name,
+          tools,
+          readOnly,
+          networks,
+        })),
+      },
+      sources,
+      envOverrides: ENV_OVERRIDES.map(({ env, setting }) => ({
+        env,
+        setting,
+        set: !!process.env[env],
+      })),
+      nextSteps: [
+        file
+          ? `Edit ${file} and restart the server to change settings; environment variables override it`
+          : `Create ${CONFIG_FILE_NAME} in the working directory or ~/.klever-mcp (or set KLEVER_MCP_CONFIG) to configure the server`,
+        'Run validate_config after editing the file',
+      ],
+    };
+  },
+};
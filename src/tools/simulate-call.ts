@@ -0,0 +1,335 @@
+import { readFile } from 'node:fs/promises';
+import type { KleverTool, ToolContext } from './types.js';
+import { ARGS_SCHEMA, PAYMENT_SCHEMA } from './debug-call.js';
+import { loadAbi } from './abi-input.js';
+import {
+  decodeAddress,
+  NETWORK_NAMES,
+  validateNetwork,
+  type KleverNetwork,
+} from '../chain/index.js';
+import { findAbiEndpoint, parseAbi, type Abi } from '../abi/index.js';
+import {
+  encodeTopLevel,
+  storageKeyFor,
+  storageLayout,
+  type AbiArgs,
+  type StorageSlot,
+} from '../codegen/index.js';
+import {
+  collectArtifacts,
+  debugCall,
+  DEBUG_PHASES,
+  projectSources,
+  type DebugAccount,
+  type DebugPayment,
+} from '../toolchain/index.js';
+
+interface BalanceOverride {
+  address: string;
+  amount: string | number;
+  token?: string;
+}
+
+interface StorageOverride {
+  address?: string;
+  key?: string;
+  mapper?: string;
+  keyArgs?: AbiArgs;
+  value: unknown;
+}
+
+const HEX_VALUE = /^0x([0-9a-fA-F]{2})*$/;
+
+/** ABI from the arguments, the registry, or the project's build output */
+async function contractAbi(
+  address: string,
+  projectPath: string,
+  abi: string | undefined,
+  abiUrl: string | undefined,
+  context: ToolContext
+): Promise<Abi> {
+  if (abi || abiUrl) {
+    const parsed = await loadAbi(abi, abiUrl, context.profile);
+    context.abiRegistry.register(address, parsed);
+    return parsed;
+  }
+  const registered = context.abiRegistry.get(address);
+  if (registered) return registered;
+  const artifact = (await collectArtifacts(projectPath)).find(a => a.kind === 'abi');
+  if (!artifact) {
+    throw new Error('No output/*.abi.json in the project; build it first or pass abi or abiUrl');
+  }
+  return parseAbi(await readFile(artifact.path, 'utf-8'));
+}
+
+/** Account state as the chain reports it; accounts the node does not know start empty */
+async function chainAccount(
+  address: string,
+  network: KleverNetwork | undefined,
+  context: ToolContext
+): Promise<DebugAccount> {
+  try {
+    const data = await context.chainClient.getAccount(address, network);
+    const kda = Object.fromEntries(
+      Object.values(data.assets || {})
+        .filter(asset => asset.assetId !== 'KLV')
+        .map(asset => [asset.assetId, String(asset.balance)])
+    );
+    return { balance: String(data.balance || 0), kda, owner: data.ownerAddress };
+  } catch {
+    return {};
+  }
+}
+
+/** Hex storage key and value of an override */
+function storageEntry(
+  override: StorageOverride,
+  layout: StorageSlot[],
+  abi: Abi
+): { key: string; value: string } {
+  const { key, mapper, keyArgs, value } = override;
+  let slot: StorageSlot | undefined;
+  let rawKey: Buffer;
+  if (mapper) {
+    slot = layout.find(s => s.functionName === mapper || s.storageKey === mapper);
+    if (!slot) {
+      const available = layout.map(s => s.functionName).join(', ') || 'none';
+      throw new Error(`Storage mapper "${mapper}" not found. Available: ${available}`);
+    }
+    rawKey = storageKeyFor(slot, keyArgs, abi);
+  } else if (key) {
+    rawKey = HEX_VALUE.test(key) ? Buffer.from(key.slice(2), 'hex') : Buffer.from(key, 'utf8');
+  } else {
+    throw new Error('Each storage override needs key or mapper');
+  }
+
+  let rawValue: Buffer;
+  if (typeof value === 'string' && HEX_VALUE.test(value)) {
+    rawValue = Buffer.from(value.slice(2), 'hex');
+  } else if (slot?.mapper === 'SingleValueMapper' && slot.typeArgs[0]) {
+    rawValue = encodeTopLevel(slot.typeArgs[0], value, abi);
+  } else {
+    throw new Error(
+      `Storage value ${JSON.stringify(value)} must be 0x hex unless it sets a SingleValueMapper`
+    );
+  }
+  return { key: rawKey.toString('hex'), value: rawValue.toString('hex') };
+}
+
+export const simulateCallTool: KleverTool = {
+  definition: {
+    name: 'simulate_call',
+    description:
+      "Answer \"what would happen if\" questions about a deployed contract without touching the chain: fetch the contract's balances, storage and owner (and the caller's balances) from the network, apply the balances and storage overrides, then execute one endpoint call on that state in the Rust VM with the contract code of a local klever-sc project. Returns the status, error message and decoded results, the events emitted, the storage written and the statement that raised a failure; pass trace for every statement executed. Needs klever-sc-scenario in [dev-dependencies].",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        projectPath: {
+          type: 'string',
+          description: 'Contract project root; its sources should match the deployed code',
+        },
+        address: { type: 'string', description: 'Deployed contract address (klv1...)' },
+        endpoint: { type: 'string', description: 'Endpoint to call' },
+        args: ARGS_SCHEMA,
+        caller: {
+          type: 'string',
+          description: 'Address the call comes from (default: the contract owner)',
+        },
+        payment: { ...PAYMENT_SCHEMA, description: 'Payment sent with the call' },
+        balances: {
+          type: 'array',
+          description:
+            'Balances to pretend accounts hold, replacing their chain balances, e.g. to try a call from an address that holds no tokens',
+          items: {
+            type: 'object',
+            properties: {
+              address: { type: 'string', description: 'Account address (klv1...)' },
+              amount: { type: ['string', 'number'], description: 'Amount in the smallest unit' },
+              token: { type: 'string', description: 'KLV (default) or a KDA token identifier' },
+            },
+            required: ['address', 'amount'],
+          },
+        },
+        storage: {
+          type: 'array',
+          description:
+            'Storage entries to set before the call, replacing the fetched values: by raw key, or by storage mapper (function name or storage key) and its key arguments',
+          items: {
+            type: 'object',
+            properties: {
+              address: {
+                type: 'string',
+                description: 'Account whose storage is set (default: the contract)',
+              },
+              key: { type: 'string', description: 'Raw key, as 0x hex or text' },
+              mapper: {
+                type: 'string',
+                description: 'Storage mapper (function name or storage key) of the project',
+              },
+              keyArgs: {
+                description: 'Key arguments of mapper, in order or keyed by name',
+                oneOf: [{ type: 'array' }, { type: 'object' }],
+              },
+              value: {
+                description:
+                  'Value as 0x hex ("0x" clears the entry), or plain JSON of the SingleValueMapper value type',
+              },
+            },
+            required: ['value'],
+          },
+        },
+        abi: {
+          type: 'string',
+          description:
+            'ABI JSON (default: the one registered for address, else output/*.abi.json of the project)',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        trace: {
+          type: 'boolean',
+          description: 'Include every statement executed in the result (default: false)',
+        },
+        storageDiff: {
+          type: 'boolean',
+          description:
+            'Also return every storage entry the call changes, in any account, with the old and new value, decoded where a storage mapper of the project declares the key (default: false)',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network to read the state from (default: the server default)',
+        },
+      },
+      required: ['projectPath', 'address', 'endpoint'],
+    },
+    annotations: {
+      title: 'Simulate Call',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  localOnly: true,
+  phases: ['fetching state', ...DEBUG_PHASES],
+  handler: async (args, context) => {
+    const {
+      projectPath,
+      address,
+      endpoint,
+      args: callArgs,
+      caller: callerArg,
+      payment,
+      balances = [],
+      storage = [],
+      abi,
+      abiUrl,
+      trace,
+      storageDiff,
+      network,
+    } = args as {
+      projectPath: string;
+      address: string;
+      endpoint: string;
+      args?: AbiArgs;
+      caller?: string;
+      payment?: DebugPayment;
+      balances?: BalanceOverride[];
+      storage?: StorageOverride[];
+      abi?: string;
+      abiUrl?: string;
+      trace?: boolean;
+      storageDiff?: boolean;
+      network?: string;
+    };
+    decodeAddress(address);
+    if (callerArg) decodeAddress(callerArg);
+    const net = validateNetwork(network);
+    const parsed = await contractAbi(address, projectPath, abi, abiUrl, context);
+    findAbiEndpoint(parsed, endpoint);
+
+    context.phase?.('fetching state');
+    const [contract, pairs] = await Promise.all([
+      chainAccount(address, net, context),
+      context.chainClient.getStorage(address, net),
+    ]);
+    const caller = callerArg || contract.owner;
+    if (!caller) {
+      throw new Error(`The node reports no owner for ${address}; pass caller`);
+    }
+    const accounts: Record<string, DebugAccount> = {
+      [address]: { ...contract, storage: { ...pairs } },
+    };
+    if (caller !== address) accounts[caller] = await chainAccount(caller, net, context);
+
+    for (const { address: holder, amount, token = 'KLV' } of balances) {
+      decodeAddress(holder);
+      const state = (accounts[holder] = accounts[holder] || {});
+      if (token === 'KLV') state.balance = String(amount);
+      else state.kda = { ...state.kda, [token]: String(amount) };
+    }
+    const layout = storageLayout(
+      storage.some(s => s.mapper) ? Object.values(await projectSources(projectPath)) : []
+    );
+    const overridden: { address: string; key: string; value: string }[] = [];
+    for (const override of storage) {
+      const holder = override.address || address;
+      decodeAddress(holder);
+      const entry = storageEntry(override, layout, parsed);
+      const state = (accounts[holder] = accounts[holder] || {});
+      state.storage = { ...state.storage, [entry.key]: entry.value };
+      overridden.push({ address: holder, ...entry });
+    }
+
+    const result = await debugCall(projectPath, {
+      abi: parsed,
+      endpoint,
+      args: callArgs,
+      caller,
+      payment,
+      state: { contract: address, accounts },
+      storageDiff,
+      onLine: context.progress,
+      onPhase: context.phase,
+    });
+    const { trace: entries, ...rest } = result;
+    const events = entries
+      .filter(entry => entry.kind === 'event')
+      .map(({ identifier, fields, file, line }) => ({ identifier, fields, file, line }));
+    const storageWrites = entries
+      .filter(entry => entry.kind === 'storage' && entry.access === 'write')
+      .map(({ mapper, method, key, file, line }) => ({ mapper, method, key, file, line }));
+
+    const nextSteps: string[] = [];
+    if (result.compileErrors.length > 0) {
+      nextSteps.push('Fix the compiler errors at the listed locations; the project must build');
+    } else if (result.failure) {
+      nextSteps.push(
+        `With this state the call fails with "${result.failure.message}" at ${result.failure.file}:${result.failure.line}`
+      );
+    } else if (result.status === undefined) {
+      nextSteps.push('The simulation did not report a result; check the output');
+    } else {
+      nextSteps.push(
+        'Nothing was sent; with the real state, call estimate_gas and then invoke_endpoint'
+      );
+    }
+    if (!trace && result.status !== undefined) {
+      nextSteps.push('Pass trace: true for every statement executed');
+    }
+    return {
+      address,
+      network: net || context.chainClient.getDefaultNetwork(),
+      caller,
+      overrides: { balances, storage: overridden },
+      ...rest,
+      events,
+      storageWrites,
+      ...(trace ? { trace: entries } : {}),
+      nextSteps,
+    };
+  },
+};
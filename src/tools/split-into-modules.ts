@@ -0,0 +1,44 @@
+import type { KleverTool } from './types.js';
+import { splitIntoModules, type ModuleKind } from '../codegen/index.js';
+
+export const splitIntoModulesTool: KleverTool = {
+  definition: {
+    name: 'split_into_modules',
+    description:
+      'Refactor a monolithic klever-sc contract trait into #[klever_sc::module] traits: storage mappers into StorageModule (src/storage.rs), events into EventsModule (src/events.rs), and owner-only endpoints plus their guard helpers into AdminModule (src/admin.rs). Modules get the supertraits they call into, the contract trait inherits every module, and lib.rs gets the pub mod declarations. Returns the rewritten files; nothing is written to disk.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        source: {
+          type: 'string',
+          description:
+            'Full contract source (src/lib.rs) containing the #[klever_sc::contract] trait',
+        },
+        modules: {
+          type: 'array',
+          items: { type: 'string', enum: ['storage', 'events', 'admin'] },
+          description: 'Modules to extract (default: all three)',
+        },
+      },
+      required: ['source'],
+    },
+    annotations: {
+      title: 'Split Into Modules',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { source, modules } = args as { source: string; modules?: ModuleKind[] };
+    const result = splitIntoModules(source, modules);
+    return {
+      ...result,
+      nextSteps: [
+        'Write each returned file into the contract crate (src/lib.rs is replaced)',
+        'Rebuild with: ~/klever-sdk/ksc all build',
+      ],
+    };
+  },
+};
@@ -0,0 +1,167 @@
+import type { KleverTool, ToolContext } from './types.js';
+import { decodeAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { decodeEvent } from '../codegen/index.js';
+import { topicBytes, type StreamedEvent } from '../streaming/index.js';
+import { loadAbi } from './abi-input.js';
+
+type Action = 'subscribe' | 'unsubscribe' | 'list' | 'poll';
+
+/** An event as sent to the client: fields decoded with a known ABI, otherwise hex */
+function describeEvent(event: StreamedEvent, context: ToolContext): Record<string, unknown> {
+  const abi =
+    (event.address && context.abiRegistry.get(event.address)) ||
+    context.abiRegistry.forEvent(event.identifier, event.address);
+  const base = { contract: event.address, identifier: event.identifier, txHash: event.txHash };
+  if (abi) {
+    try {
+      return { ...base, fields: decodeEvent(event.topics, event.data, abi).fields };
+    } catch {
+      // Reported raw below
+    }
+  }
+  return {
+    ...base,
+    topics: event.topics.slice(1).map(topic => `0x${topic.toString('hex')}`),
+    data: event.data.map(value => `0x${value.toString('hex')}`),
+  };
+}
+
+export const subscribeEventsTool: KleverTool = {
+  definition: {
+    name: 'subscribe_events',
+    description:
+      'Subscribe to the events of a contract on a node or indexer websocket and receive matching events as they happen, as logging notifications (logger "klever-events") decoded with the contract ABI. Filter by event identifier and by indexed topic values, e.g. every "transfer" involving a given klv1... address. Subscriptions last until unsubscribed or the session ends; "poll" returns the events received since the last poll for clients without notifications.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        action: {
+          type: 'string',
+          enum: ['subscribe', 'unsubscribe', 'list', 'poll'],
+          description: 'What to do (default: subscribe)',
+        },
+        contract: {
+          type: 'string',
+          description: 'Contract whose events to receive (klv1...); for subscribe',
+        },
+        identifier: {
+          type: 'string',
+          description: 'Only events with this identifier, e.g. "transfer"',
+        },
+        topics: {
+          type: 'array',
+          items: { type: 'string' },
+          description:
+            'Values that must all appear among the indexed event inputs: klv1... addresses, 0x hex, or text',
+        },
+        url: {
+          type: 'string',
+          description:
+            'Event websocket (ws:// or wss://); default: the one configured for the network',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network whose event websocket to use (default: the server default)',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the contract, to decode the events',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+        },
+        id: {
+          type: 'string',
+          description: 'Subscription id, for unsubscribe and poll',
+        },
+      },
+    },
+    annotations: {
+      title: 'Subscribe to Contract Events',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: false,
+      openWorldHint: true,
+    },
+  },
+  localOnly: true,
+  handler: async (args, context) => {
+    const {
+      action = 'subscribe',
+      contract,
+      identifier,
+      topics = [],
+      url,
+      network,
+      abi,
+      abiUrl,
+      id,
+    } = args as {
+      action?: Action;
+      contract?: string;
+      identifier?: string;
+      topics?: string[];
+      url?: string;
+      network?: string;
+      abi?: string;
+      abiUrl?: string;
+      id?: string;
+    };
+    const { subscriptions } = context;
+
+    if (action === 'list') {
+      return { subscriptions: subscriptions.list(), nextSteps: [] };
+    }
+    if (action === 'unsubscribe' || action === 'poll') {
+      if (!id) throw new Error(`${action} needs the subscription id.`);
+      if (action === 'unsubscribe') {
+        if (!subscriptions.unsubscribe(id)) throw new Error(`No event subscription "${id}".`);
+        return { id, unsubscribed: true, nextSteps: [] };
+      }
+      const events = subscriptions.takeEvents(id).map(event => describeEvent(event, context));
+      const info = subscriptions.list().find(s => s.id === id);
+      return {
+        id,
+        status: info?.status,
+        events,
+        nextSteps:
+          info?.status === 'closed'
+            ? ['The connection was lost; unsubscribe and subscribe again']
+            : ['Poll again later for newer events'],
+      };
+    }
+    if (action !== 'subscribe') throw new Error(`Unknown action "${action}".`);
+
+    if (!contract) throw new Error('subscribe needs the contract address.');
+    decodeAddress(contract);
+    const net = validateNetwork(network);
+    const eventsUrl = url || context.chainClient.getNetworkProfile(net).eventsUrl;
+    if (!eventsUrl) {
+      const name = net || context.chainClient.getDefaultNetwork();
+      throw new Error(
+        `No event websocket is configured for network "${name}"; pass url (ws:// or wss://), or set KLEVER_EVENTS_URL for the custom network.`
+      );
+    }
+    if (abi || abiUrl) {
+      const parsed = await loadAbi(abi, abiUrl, context.profile);
+      context.abiRegistry.register(contract, parsed);
+    }
+
+    const info = subscriptions.subscribe({
+      url: eventsUrl,
+      filter: { address: contract, identifier, topics: topics.map(topicBytes) },
+      onEvent: (event, subscription) => {
+        context.notify?.({ subscription: subscription.id, ...describeEvent(event, context) });
+      },
+    });
+    return {
+      ...info,
+      nextSteps: [
+        'Matching events arrive as logging notifications from logger "klever-events"',
+        `Call subscribe_events with action "poll" and id "${info.id}" to fetch them instead`,
+        `Call subscribe_events with action "unsubscribe" and id "${info.id}" when done`,
+      ],
+    };
+  },
+};
@@ -0,0 +1,238 @@
+/**
+ * Signing and submission shared by tools that send transactions. The wallet
+ * key is read from a file on the server; the node builds the transaction,
+ * the tool signs its hash, broadcasts it and optionally waits for the result.
+ */
+
+import {
+  loadSigner,
+  type KleverNetwork,
+  type Signer,
+  type TransactionBuildData,
+  type TransactionData,
+} from '../chain/index.js';
+import { integerValue } from '../codegen/index.js';
+import { getConfig } from '../config/index.js';
+import type { ToolContext } from './types.js';
+
+/** Phases of tools that send a transaction; `submitTransaction` reports all but the first */
+export const TRANSACTION_PHASES = [
+  'building transaction',
+  'signing',
+  'broadcasting',
+  'awaiting finality',
+];
+
+export const SIGNER_PROPERTY = {
+  type: 'string',
+  description:
+    'Path of the wallet key file to sign with (default: keys.wallet of the server config, else walletKey.pem in the Klever SDK directory, the key koperator uses)',
+};
+
+export const WAIT_PROPERTY = {
+  type: 'boolean',
+  description: 'Wait until the transaction is processed and report its result (default: true)',
+};
+
+export const PAYMENT_PROPERTIES = {
+  klv: {
+    type: ['string', 'number'],
+    description: 'KLV to send with the call, in the smallest unit (1 KLV = 1,000,000)',
+  },
+  kda: {
+    type: 'array',
+    items: {
+      type: 'object',
+      properties: {
+        token: { type: 'string' },
+        amount: { type: ['string', 'number'] },
+      },
+      required: ['token', 'amount'],
+    },
+    description: 'KDA payments, amounts in the smallest unit of each token',
+  },
+};
+
+export interface Payment {
+  token: string;
+  amount: string | number;
+}
+
+/** callValue map of a contract call; amounts must be exact JavaScript numbers */
+export function callValue(
+  klv: string | number | undefined,
+  kda: Payment[] = []
+): Record<string, number> | undefined {
+  const payments = [...(klv !== undefined ? [{ token: 'KLV', amount: klv }] : []), ...kda];
+  const value: Record<string, number> = {};
+  for (const { token, amount } of payments) {
+    const n = Number(integerValue(amount, `${token} amount`));
+    if (!Number.isSafeInteger(n)) throw new Error(`${token} amount ${amount} is too large`);
+    value[token] = (value[token] || 0) + n;
+  }
+  return Object.keys(value).length > 0 ? value : undefined;
+}
+
+/** Reject payments the endpoint does not accept according to its ABI */
+export function checkPayable(
+  endpoint: { name: string; payableInTokens?: string[] },
+  payment: Record<string, number> | undefined
+): void {
+  const accepted = endpoint.payableInTokens || [];
+  if (payment && accepted.length === 0) {
+    throw new Error(`${endpoint.name} is not payable; remove klv/kda.`);
+  }
+  const rejected = accepted.includes('*')
+    ? []
+    : Object.keys(payment || {}).filter(token => !accepted.includes(token));
+  if (rejected.length > 0) {
+    throw new Error(
+      `${endpoint.name} accepts ${accepted.join(', ')} but not ${rejected.join(', ')}.`
+    );
+  }
+}
+
+export interface SubmittedTransaction {
+  txHash: string;
+  /** Explorer page of the transaction, when the network has an explorer */
+  explorerUrl?: string;
+  /** Present when the tool waited for the transaction */
+  status?: string;
+  resultCode?: string;
+  transaction?: TransactionData;
+}
+
+/** The signer at `path`, or the configured wallet key (`keys.wallet`) */
+export function loadToolSigner(path?: string): Promise<Signer> {
+  return loadSigner(path || getConfig().keys.wallet);
+}
+
+/** Sign a node-built transaction, broadcast it and, when `wait` is set, wait for its result */
+export async function submitTransaction(
+  context: ToolContext,
+  built: TransactionBuildData,
+  signer: Signer,
+  network: KleverNetwork | undefined,
+  wait = true
+): Promise<SubmittedTransaction> {
+  context.phase?.('signing', signer.address);
+  const signature = signer.sign(Buffer.from(built.result.txHash, 'hex'));
+  context.phase?.('broadcasting');
+  let txHash: string;
+  try {
+    txHash = await context.chainClient.broadcastTransaction(built.result.tx, signature, network);
+  } catch (error) {
+    // Not sent: let the next transaction of the signer reuse the nonce
+    context.chainClient.releaseTransaction(built.result.txHash);
+    throw error;
+  }
+  const explorerUrl = context.chainClient.getExplorerUrl('transaction', txHash, network);
+  if (!wait) return { txHash, explorerUrl };
+
+  context.phase?.('awaiting finality', txHash);
+  const transaction = await context.chainClient.waitForTransaction(txHash, network);
+  const { status, resultCode } = transaction;
+  return { txHash, explorerUrl, status, resultCode, transaction };
+}
+
+/**
+ * Values returned by a contract call, from the processed transaction: a
+ * `returnData` list (base64, as nodes serialize byte slices) or a smart
+ * contract result in `@6f6b@<hex>@<hex>` form. Undefined when neither is found.
+ */
+export function returnData(tx: TransactionData): Buffer[] | undefined {
+  let found: Buffer[] | undefined;
+  const visit = (value: unknown, key?: string) => {
+    if (found) return;
+    if (key === 'returnData' && Array.isArray(value)) {
+      found = value.map(v => Buffer.from(String(v), 'base64'));
+    } else if (typeof value === 'string' && value.startsWith('@6f6b')) {
+      found = value.split('@').slice(2).map(part => Buffer.from(part, 'hex'));
+    } else if (value && typeof value === 'object') {
+      Object.entries(value).forEach(([k, v]) => visit(v, k));
+    }
+  };
+  visit(tx);
+  return found;
+}
+
+/** Contract a smart contract transaction targets: its contract parameter, else the receiver */
+export function transactionContract(tx: TransactionData): string | undefined {
+  const address = tx.contract?.[0]?.parameter?.address;
+  return typeof address === 'string' ? address : tx.receiver;
+}
+
+/** Endpoint of an invocation: the part of the (base64) data before the first `@` */
+export function calledFunction(tx: TransactionData): string | undefined {
+  const data = Array.isArray(tx.data) ? tx.data[0] : undefined;
+  if (typeof data !== 'string') return undefined;
+  const name = Buffer.from(data, 'base64').toString('utf8').split('@')[0];
+  return /^\w+$/.test(name) ? name : undefined;
+}
+
+export interface EventLog {
+  address?: string;
+  topics: Buffer[];
+  data: Buffer[];
+}
+
+/** Event logs of a processed transaction: the objects carrying a base64 `topics` list */
+export function eventLogs(tx: TransactionData): EventLog[] {
+  const logs: EventLog[] = [];
+  const bytes = (value: unknown) => Buffer.from(String(value ?? ''), 'base64');
+  const visit = (value: unknown) => {
+    if (Array.isArray(value)) {
+      value.forEach(visit);
+    } else if (value && typeof value === 'object') {
+      const entry = value as Record<string, unknown>;
+      if (Array.isArray(entry.topics)) {
+        const data = entry.data ?? entry.additionalData;
+        logs.push({
+          address: typeof entry.address === 'string' ? entry.address : undefined,
+          topics: entry.topics.map(bytes),
+          data: Array.isArray(data) ? data.map(bytes) : data ? [bytes(data)] : [],
+        });
+      } else {
+        Object.values(entry).forEach(visit);
+      }
+    }
+  };
+  visit(tx);
+  return logs;
+}
+
+/** `bytes` as text when it is printable ASCII */
+function printable(bytes: Buffer): string | undefined {
+  const text = bytes.toString('utf8');
+  return /^[\x20-\x7e]+$/.test(text) ? text : undefined;
+}
+
+/**
+ * Error texts of a failed transaction: its result code, any `returnMessage`,
+ * the messages of `signalError` and `internalVMErrors` logs, and the code of
+ * failed `@<hex code>@...` contract results. Empty when it carries none.
+ */
+export function transactionErrors(tx: TransactionData): string[] {
+  const errors: string[] = [];
+  if (tx.resultCode && !/^(ok|success)$/i.test(tx.resultCode)) errors.push(tx.resultCode);
+  const visit = (value: unknown, key?: string) => {
+    if (key === 'returnMessage' && typeof value === 'string' && value) {
+      errors.push(value);
+    } else if (typeof value === 'string' && /^@[0-9a-f]+(@|$)/.test(value)) {
+      const code = printable(Buffer.from(value.split('@')[1], 'hex'));
+      if (code && code !== 'ok') errors.push(code);
+    } else if (value && typeof value === 'object') {
+      Object.entries(value).forEach(([k, v]) => visit(v, k));
+    }
+  };
+  visit(tx);
+  for (const log of eventLogs(tx)) {
+    const identifier = log.topics[0]?.toString('utf8');
+    if (identifier !== 'signalError' && identifier !== 'internalVMErrors') continue;
+    for (const value of [...log.topics.slice(1), ...log.data]) {
+      const text = printable(value);
+      if (text) errors.push(text);
+    }
+  }
+  return [...new Set(errors)];
+}
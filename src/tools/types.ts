@@ -0,0 +1,59 @@
+/**
+ * Shared types for MCP tool modules
+ */
+
+import type { ContextService } from '../context/service.js';
+import type { KleverChainClient } from '../chain/index.js';
+import type { AbiRegistry } from '../codegen/index.js';
+import type { EventSubscriptions } from '../streaming/index.js';
+import type { ServerProfile } from '../mcp/server.js';
+import type { Workspace } from '../mcp/workspace.js';
+
+export type ToolAnnotations = {
+  title: string;
+  readOnlyHint: boolean;
+  destructiveHint: boolean;
+  idempotentHint: boolean;
+  openWorldHint: boolean;
+};
+
+// Type aliases (not interfaces) so definitions stay assignable to Record<string, unknown>
+export type ToolDefinition = {
+  name: string;
+  description: string;
+  inputSchema: {
+    type: 'object';
+    properties: Record<string, unknown>;
+    required?: string[];
+  };
+  annotations: ToolAnnotations;
+};
+
+/** Services available to a tool handler at call time */
+export interface ToolContext {
+  contextService: ContextService;
+  chainClient: KleverChainClient;
+  /** ABIs of contracts used earlier in the session, for decoding their transactions */
+  abiRegistry: AbiRegistry;
+  /** Websocket event subscriptions that outlive the tool call */
+  subscriptions: EventSubscriptions;
+  /** Project, ABI, network, signer and contract of the session, set with set_workspace */
+  workspace: Workspace;
+  profile: ServerProfile;
+  /** Sends a notification to the client outside of any tool call, e.g. a subscribed event */
+  notify?: (data: Record<string, unknown>) => void;
+  /** Reports intermediate output of long-running tools to the client, when it asked for progress */
+  progress?: (message: string) => void;
+  /** Reports that one of the tool's `phases` started, e.g. `awaiting finality` */
+  phase?: (phase: string, detail?: string) => void;
+}
+
+export interface KleverTool {
+  definition: ToolDefinition;
+  /** Hidden and blocked in the public profile (filesystem, subprocess, or transaction tools) */
+  localOnly?: boolean;
+  /** Phases a long-running tool reports through `context.phase`, in order */
+  phases?: readonly string[];
+  /** Returns the response body; the server adds `success: true` and serializes it */
+  handler: (args: Record<string, unknown>, context: ToolContext) => Promise<Record<string, unknown>>;
+}
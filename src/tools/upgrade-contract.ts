@@ -0,0 +1,235 @@
+import { readFile } from 'node:fs/promises';
+import type { KleverTool } from './types.js';
+import { decodeAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { checkUpgradeSafety, type UpgradeSafetyReport } from '../analysis/index.js';
+import { findUpgradeSignature, type Abi } from '../abi/index.js';
+import { encodeArguments, type AbiArgs } from '../codegen/index.js';
+import { codeHash } from '../toolchain/index.js';
+import { loadAbi } from './abi-input.js';
+import {
+  loadToolSigner,
+  SIGNER_PROPERTY,
+  submitTransaction,
+  TRANSACTION_PHASES,
+  WAIT_PROPERTY,
+} from './transaction-input.js';
+
+export const upgradeContractTool: KleverTool = {
+  definition: {
+    name: 'upgrade_contract',
+    description:
+      "Upgrade a deployed contract to a new .wasm. Before anything is sent, runs the upgrade-safety check on the deployed and new sources (storage reinterpreted by the new code blocks the upgrade unless force is set), checks that the signer owns the contract and that the code actually changes, and encodes the #[upgrade] arguments from plain JSON using the new ABI. With dryRun the transaction is only built by the node (validating sender, nonce and data) and returned unsigned; otherwise it is signed with a wallet key file on this machine and broadcast.",
+    inputSchema: {
+      type: 'object',
+      properties: {
+        address: {
+          type: 'string',
+          description: 'Address of the contract to upgrade (klv1...)',
+        },
+        wasmPath: {
+          type: 'string',
+          description: 'Path to the new build (output/<name>.wasm)',
+        },
+        abi: {
+          type: 'string',
+          description: 'ABI JSON of the new build; needed for upgrade arguments',
+        },
+        abiUrl: {
+          type: 'string',
+          description: 'URL to fetch the new ABI JSON from (used when abi is not given)',
+        },
+        args: {
+          description:
+            'Arguments of the #[upgrade] function as plain JSON, in ABI order or keyed by argument name',
+          oneOf: [{ type: 'array' }, { type: 'object' }],
+        },
+        oldSource: {
+          type: 'string',
+          description: 'Contract source (src/lib.rs) of the deployed version, for the safety check',
+        },
+        newSource: {
+          type: 'string',
+          description: 'Contract source (src/lib.rs) of the new version, for the safety check',
+        },
+        oldModules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description: 'Module files of the deployed version keyed by path',
+        },
+        newModules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description: 'Module files of the new version keyed by path',
+        },
+        skipSafetyCheck: {
+          type: 'boolean',
+          description: 'Upgrade without the storage safety check (when sources are unavailable)',
+        },
+        force: {
+          type: 'boolean',
+          description: 'Upgrade even when the safety check reports reinterpreted storage',
+        },
+        dryRun: {
+          type: 'boolean',
+          description: 'Run the checks and build the transaction without signing or sending it',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network the contract is deployed on (default: the server default)',
+        },
+        signer: SIGNER_PROPERTY,
+        wait: WAIT_PROPERTY,
+      },
+      required: ['address', 'wasmPath'],
+    },
+    annotations: {
+      title: 'Upgrade Contract',
+      readOnlyHint: false,
+      destructiveHint: true,
+      idempotentHint: false,
+      openWorldHint: true,
+    },
+  },
+  localOnly: true,
+  phases: TRANSACTION_PHASES,
+  handler: async (args, context) => {
+    const {
+      address,
+      wasmPath,
+      abi,
+      abiUrl,
+      args: upgradeValues,
+      oldSource,
+      newSource,
+      oldModules,
+      newModules,
+      skipSafetyCheck,
+      force,
+      dryRun,
+      network,
+      signer: signerPath,
+      wait,
+    } = args as {
+      address: string;
+      wasmPath: string;
+      abi?: string;
+      abiUrl?: string;
+      args?: AbiArgs;
+      oldSource?: string;
+      newSource?: string;
+      oldModules?: Record<string, string>;
+      newModules?: Record<string, string>;
+      skipSafetyCheck?: boolean;
+      force?: boolean;
+      dryRun?: boolean;
+      network?: string;
+      signer?: string;
+      wait?: boolean;
+    };
+    decodeAddress(address);
+    const net = validateNetwork(network);
+
+    let safety: UpgradeSafetyReport | undefined;
+    if (!skipSafetyCheck) {
+      if (!oldSource || !newSource) {
+        throw new Error(
+          'Provide oldSource and newSource for the upgrade-safety check, or set skipSafetyCheck.'
+        );
+      }
+      safety = checkUpgradeSafety(oldSource, newSource, { oldModules, newModules });
+      if (!safety.safe && !force) {
+        return {
+          upgraded: false,
+          safety,
+          nextSteps: [
+            'The new version would reinterpret existing storage; fix the findings or migrate the data first',
+            'Run generate_migration for orphaned or renamed keys',
+          ],
+        };
+      }
+    }
+
+    let upgradeArgs: Buffer[] = [];
+    let parsed: Abi | undefined;
+    if (abi || abiUrl) {
+      parsed = await loadAbi(abi, abiUrl, context.profile);
+      const inputs = findUpgradeSignature(parsed)?.inputs || [];
+      upgradeArgs = encodeArguments(inputs, upgradeValues, parsed, 'upgrade');
+    } else if (upgradeValues && Object.keys(upgradeValues).length > 0) {
+      throw new Error('Pass abi or abiUrl to encode upgrade arguments.');
+    }
+
+    const wasm = await readFile(wasmPath);
+    const newCodeHash = codeHash(wasm);
+    const [account, deployedCodeHash, signer] = await Promise.all([
+      context.chainClient.getAccount(address, net),
+      context.chainClient.getCodeHash(address, net),
+      loadToolSigner(signerPath),
+    ]);
+    if (account.ownerAddress && account.ownerAddress !== signer.address) {
+      throw new Error(
+        `${address} is owned by ${account.ownerAddress}; the signer ${signer.address} cannot upgrade it.`
+      );
+    }
+    const warnings: string[] = [];
+    if (deployedCodeHash === newCodeHash) {
+      warnings.push(
+        'The deployed code is identical to this build; the upgrade only reruns #[upgrade].'
+      );
+    }
+    if (skipSafetyCheck) warnings.push('The storage safety check was skipped.');
+    if (safety && !safety.safe) warnings.push('Upgrading despite reinterpreted storage (force).');
+
+    context.phase?.('building transaction', `upgrade ${address} with ${wasmPath}`);
+    const built = await context.chainClient.buildUpgrade(
+      {
+        sender: signer.address,
+        scAddress: address,
+        wasmHex: wasm.toString('hex'),
+        upgradeArgs: upgradeArgs.map(arg => arg.toString('base64')),
+      },
+      net
+    );
+    const details = {
+      address,
+      sender: signer.address,
+      network: net || context.chainClient.getDefaultNetwork(),
+      code: { path: wasmPath, size: wasm.length, deployedCodeHash, newCodeHash },
+      upgradeArgs: upgradeArgs.map(arg => arg.toString('hex')),
+      safety,
+      warnings,
+    };
+
+    if (dryRun) {
+      return {
+        upgraded: false,
+        dryRun: true,
+        txHash: built.result.txHash,
+        unsignedTx: built.result.tx,
+        ...details,
+        nextSteps: ['Run upgrade_contract again without dryRun to sign and send the upgrade'],
+      };
+    }
+
+    const submitted = await submitTransaction(context, built, signer, net, wait !== false);
+    if (submitted.status === 'success' && parsed) context.abiRegistry.register(address, parsed);
+    const nextSteps: string[] = [];
+    if (!submitted.status) {
+      nextSteps.push(`Check the result with get_transaction ${submitted.txHash}`);
+    } else if (submitted.status !== 'success') {
+      nextSteps.push('The upgrade failed; check resultCode and the #[upgrade] arguments');
+    } else {
+      nextSteps.push('Run verify_build against the address to confirm the deployed code');
+    }
+    return {
+      upgraded: submitted.status === 'success',
+      txHash: submitted.txHash,
+      explorerUrl: submitted.explorerUrl,
+      status: submitted.status,
+      resultCode: submitted.resultCode,
+      ...details,
+      nextSteps,
+    };
+  },
+};
@@ -0,0 +1,41 @@
+import type { KleverTool } from './types.js';
+import { CONFIG_FILE_NAME, validateConfig } from '../config/index.js';
+
+export const validateConfigTool: KleverTool = {
+  definition: {
+    name: 'validate_config',
+    description: `Check a server config file (${CONFIG_FILE_NAME}) and the environment variables overriding it: TOML syntax, unknown or mistyped settings, invalid networks and URLs, duplicate API key names, and paths that do not exist. Validate a new file before pointing the server at it; the server refuses to start with an invalid config.`,
+    inputSchema: {
+      type: 'object',
+      properties: {
+        path: {
+          type: 'string',
+          description: 'Config file to check (default: the file the server uses)',
+        },
+      },
+    },
+    annotations: {
+      title: 'Validate Config',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  localOnly: true,
+  handler: async args => {
+    const { path } = args as { path?: string };
+    const result = await validateConfig(path);
+    return {
+      ...result,
+      nextSteps: result.valid
+        ? [
+            'Restart the server to apply changes to the file',
+            'Run show_config to see the effective settings',
+          ]
+        : [
+            'Fix the errors; settings use snake_case names under [network], [keys], [cache], [tools], [[rate_limits]] and [[api_keys]]',
+          ],
+    };
+  },
+};
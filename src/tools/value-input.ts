@@ -0,0 +1,79 @@
+/**
+ * Inputs shared by tools that encode or decode single ABI values: the type
+ * (ABI or Rust notation), where custom types come from, and raw bytes as
+ * nodes return them.
+ */
+
+import { parseAbi, type Abi, type AbiTypeDef } from '../abi/index.js';
+import { parseAbiType, rustTypeToAbi } from '../codegen/index.js';
+import type { ServerProfile } from '../mcp/server.js';
+import { loadAbi } from './abi-input.js';
+
+export type Encoding = 'top' | 'nested';
+
+/** Multi-value types only exist as a sequence of top-level arguments or results */
+export const MULTI_VALUE_TYPES = ['optional', 'variadic', 'multi'];
+
+export const TYPE_PROPERTY = {
+  type: 'string',
+  description:
+    'ABI type (BigUint, Address, bytes, Option<u64>, List<Address>, a custom type...) or the Rust type (ManagedAddress, ManagedBuffer, ManagedVec<Self::Api, u32>, OptionalValue<u64>...)',
+};
+
+export const ENCODING_PROPERTY = {
+  type: 'string',
+  enum: ['top', 'nested'],
+  description:
+    'top: as a whole argument or result (minimal integers, no length prefixes); nested: as inside a struct, list or option (default: top)',
+};
+
+export const TYPE_SOURCE_PROPERTIES = {
+  abi: {
+    type: 'string',
+    description: 'ABI JSON whose types section declares the custom structs and enums',
+  },
+  abiUrl: {
+    type: 'string',
+    description: 'URL to fetch the ABI JSON from (used when abi is not given)',
+  },
+  types: {
+    type: 'object',
+    additionalProperties: { type: 'object' },
+    description: 'The types section of an ABI, instead of the whole ABI',
+  },
+};
+
+/** Base64 as nodes return it, or hex when prefixed with 0x */
+export function rawValue(value: string): Buffer {
+  if (/^0x/i.test(value)) {
+    const hex = value.slice(2);
+    if (!/^([0-9a-fA-F]{2})*$/.test(hex)) throw new Error(`Invalid hex value: ${value}`);
+    return Buffer.from(hex, 'hex');
+  }
+  return Buffer.from(value, 'base64');
+}
+
+/** The ABI type of `type` given in ABI or Rust notation, and whether it is a multi-value type */
+export function valueType(type: string, encoding: Encoding): { type: string; multi: boolean } {
+  if (encoding !== 'top' && encoding !== 'nested') {
+    throw new Error(`Invalid encoding "${encoding}". Valid options: top, nested.`);
+  }
+  const abiType = rustTypeToAbi(type);
+  const multi = MULTI_VALUE_TYPES.includes(parseAbiType(abiType).name);
+  if (multi && encoding === 'nested') {
+    throw new Error(`${abiType} is a multi-value type; it has no nested encoding.`);
+  }
+  return { type: abiType, multi };
+}
+
+/** An ABI carrying the custom types from `types`, the given ABI, or none */
+export async function loadTypes(
+  abi: string | undefined,
+  abiUrl: string | undefined,
+  types: Record<string, AbiTypeDef> | undefined,
+  profile: ServerProfile
+): Promise<Abi> {
+  if (types) return parseAbi({ name: 'types', endpoints: [], types });
+  if (abi || abiUrl) return loadAbi(abi, abiUrl, profile);
+  return { name: '', endpoints: [] };
+}
@@ -0,0 +1,90 @@
+import type { KleverTool } from './types.js';
+import { decodeAddress, NETWORK_NAMES, validateNetwork } from '../chain/index.js';
+import { BUILD_PHASES, verifyBuild, type BuildTool } from '../toolchain/index.js';
+
+export const verifyBuildTool: KleverTool = {
+  definition: {
+    name: 'verify_build',
+    description:
+      'Check that a contract project on disk is the source of a deployed contract. Fetches the code hash of the contract account from a node, rebuilds the project with machine-specific paths remapped and incremental compilation off, hashes the produced wasm with BLAKE2b-256 (the VM code hash) and reports whether they match, together with the rustc version, the pinned toolchain and whether Cargo.lock is present. Builds only reproduce with the same compiler, dependency versions and klever-sc version that produced the deployed code.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        projectPath: {
+          type: 'string',
+          description: 'Contract project root (the directory with Cargo.toml, wasm/ and meta/)',
+        },
+        address: {
+          type: 'string',
+          description: 'Address of the deployed contract (klv1...)',
+        },
+        network: {
+          type: 'string',
+          enum: NETWORK_NAMES,
+          description: 'Network the contract is deployed on (default: the server default)',
+        },
+        contract: {
+          type: 'string',
+          description: 'Contract name (output/<contract>.wasm) when the project builds several',
+        },
+        tool: {
+          type: 'string',
+          enum: ['ksc', 'cargo'],
+          description: 'Build driver (default: ksc when installed, otherwise cargo)',
+        },
+      },
+      required: ['projectPath', 'address'],
+    },
+    annotations: {
+      title: 'Verify Build',
+      readOnlyHint: false,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: true,
+    },
+  },
+  localOnly: true,
+  phases: ['fetching deployed code', ...BUILD_PHASES],
+  handler: async (args, context) => {
+    const { projectPath, address, network, contract, tool } = args as {
+      projectPath: string;
+      address: string;
+      network?: string;
+      contract?: string;
+      tool?: BuildTool;
+    };
+    decodeAddress(address);
+    const net = validateNetwork(network);
+    context.phase?.('fetching deployed code', address);
+    const deployedCodeHash = await context.chainClient.getCodeHash(address, net);
+    context.progress?.(`Deployed code hash ${deployedCodeHash}; rebuilding ${projectPath}`);
+
+    const result = await verifyBuild(projectPath, deployedCodeHash, {
+      contract,
+      tool,
+      onLine: context.progress,
+      onPhase: context.phase,
+    });
+
+    const nextSteps: string[] = [];
+    if (!result.build.success) {
+      nextSteps.push('The rebuild failed; fix it with build_contract before verifying');
+    } else if (result.match) {
+      nextSteps.push('The source matches the deployed code; record the commit and toolchain');
+    } else {
+      nextSteps.push(
+        'Check out the commit that was deployed and build with the same rustc and klever-sc versions'
+      );
+      if (result.warnings.length > 0) {
+        nextSteps.push('Pin the toolchain and commit Cargo.lock so builds are reproducible');
+      }
+      nextSteps.push('Compare the rebuilt ABI with the deployed one using abi_diff');
+    }
+    return {
+      ...result,
+      address,
+      network: net || context.chainClient.getDefaultNetwork(),
+      nextSteps,
+    };
+  },
+};
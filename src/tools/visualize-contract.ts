@@ -0,0 +1,63 @@
+import type { KleverTool } from './types.js';
+import {
+  architectureGraph,
+  contractArchitecture,
+  foldHelpers,
+  type GraphFormat,
+} from '../analysis/index.js';
+
+export const visualizeContractTool: KleverTool = {
+  definition: {
+    name: 'visualize_contract',
+    description:
+      'Draw the architecture of a klever-sc contract as a Mermaid flowchart or Graphviz DOT digraph: endpoints, views and callbacks, the storage mappers each reads (dotted) and writes (solid), the events it emits and the other contracts it calls through #[proxy] accessors, typed proxies or contract_call (thick, labelled with the endpoint and whether the call is async, with an edge back to the callback). Private helpers are folded into the functions calling them unless helpers is set. Returns the graph text and the model it was drawn from.',
+    inputSchema: {
+      type: 'object',
+      properties: {
+        source: {
+          type: 'string',
+          description:
+            'Full contract source (src/lib.rs) containing the #[klever_sc::contract] trait',
+        },
+        modules: {
+          type: 'object',
+          additionalProperties: { type: 'string' },
+          description:
+            'Module files of the same crate keyed by path (e.g. {"src/admin.rs": "..."}), drawn together with the contract',
+        },
+        format: {
+          type: 'string',
+          enum: ['mermaid', 'dot'],
+          description: 'Graph language (default: mermaid)',
+        },
+        helpers: {
+          type: 'boolean',
+          description: 'Draw private helpers as nodes of their own (default: false)',
+        },
+      },
+      required: ['source'],
+    },
+    annotations: {
+      title: 'Visualize Contract',
+      readOnlyHint: true,
+      destructiveHint: false,
+      idempotentHint: true,
+      openWorldHint: false,
+    },
+  },
+  handler: async args => {
+    const { source, modules, format, helpers } = args as {
+      source: string;
+      modules?: Record<string, string>;
+      format?: GraphFormat;
+      helpers?: boolean;
+    };
+    const full = contractArchitecture(source, modules);
+    const architecture = helpers ? full : foldHelpers(full);
+    return {
+      format: format || 'mermaid',
+      graph: architectureGraph(architecture, format),
+      architecture,
+    };
+  },
+};
@@ -0,0 +1,15 @@
+import { findApiKey } from './api-keys.js';
+
+const READER = { name: 'ci-reader', key: 'reader-key-0123456789', readOnly: true };
+
+describe('findApiKey', () => {
+  it('matches plain keys and returns their scope', () => {
+    expect(findApiKey([READER], 'reader-key-0123456789')).toEqual({
+      name: 'ci-reader',
+      readOnly: true,
+      tools: undefined,
+      networks: undefined,
+    });
+    expect(findApiKey([READER], 'reader-key')).toBeUndefined();
+  });
+});
@@ -0,0 +1,35 @@
+/**
+ * API keys of the HTTP transport, read from the server config file
+ * (`config/config.ts`). Each key maps to a tool scope, e.g. a CI key that
+ * may query testnet but never sign or send transactions.
+ */
+
+import { createHash, timingSafeEqual } from 'node:crypto';
+import { z } from 'zod';
+import type { ToolScope } from '../mcp/tool-scope.js';
+
+export const ApiKeySchema = z.object({
+  name: z.string().min(1),
+  /** The key itself, or `sha256:<hex>` of it to keep it out of the file */
+  key: z.string().min(16),
+  tools: z.array(z.string()).optional(),
+  readOnly: z.boolean().optional(),
+  networks: z.array(z.string()).optional(),
+});
+
+export type ApiKey = z.infer<typeof ApiKeySchema>;
+
+function keyMatches(apiKey: ApiKey, given: string): boolean {
+  const hashed = apiKey.key.startsWith('sha256:');
+  const expected = Buffer.from(hashed ? apiKey.key.slice(7).toLowerCase() : apiKey.key);
+  const actual = Buffer.from(hashed ? createHash('sha256').update(given).digest('hex') : given);
+  return actual.length === expected.length && timingSafeEqual(actual, expected);
+}
+
+/** The scope of the API key `given`, or undefined when no key matches */
+export function findApiKey(apiKeys: ApiKey[], given: string): ToolScope | undefined {
+  const apiKey = apiKeys.find(k => keyMatches(k, given));
+  if (!apiKey) return undefined;
+  const { name, tools, readOnly, networks } = apiKey;
+  return { name, tools, readOnly, networks };
+}
@@ -0,0 +1,9 @@
+/**
+ * Transports besides stdio (`KleverMCPServer.start`): streamable HTTP with
+ * sessions for long-lived, shared deployments, and the API keys scoping what
+ * its clients may do.
+ */
+
+export * from './api-keys.js';
+export * from './sessions.js';
+export * from './streamable-http.js';
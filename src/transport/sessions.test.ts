@@ -0,0 +1,51 @@
+import { SessionStore, type Closeable } from './sessions.js';
+
+class FakeTransport implements Closeable {
+  closed = false;
+  async close() {
+    this.closed = true;
+  }
+}
+
+describe('SessionStore', () => {
+  let now: number;
+  let store: SessionStore<FakeTransport>;
+
+  beforeEach(() => {
+    now = 0;
+    store = new SessionStore({ idleTimeoutMs: 1000, maxSessions: 2, now: () => now });
+  });
+
+  it('closes sessions idle past the timeout', () => {
+    const a = new FakeTransport();
+    const b = new FakeTransport();
+    store.add('a', a);
+    store.add('b', b);
+    now = 600;
+    expect(store.get('a')).toBe(a);
+    now = 1200;
+    expect(store.closeIdle()).toBe(1);
+    expect(b.closed).toBe(true);
+    expect(store.get('b')).toBeUndefined();
+    expect(store.size).toBe(1);
+  });
+
+  it('closes the least recently used session beyond the limit', () => {
+    const [a, b, c] = [new FakeTransport(), new FakeTransport(), new FakeTransport()];
+    store.add('a', a);
+    store.add('b', b);
+    store.get('a');
+    store.add('c', c);
+    expect(b.closed).toBe(true);
+    expect([store.get('a'), store.get('c')]).toEqual([a, c]);
+  });
+
+  it('forgets closed transports without closing them again', () => {
+    const a = new FakeTransport();
+    store.add('a', a);
+    store.delete('a');
+    store.closeAll();
+    expect(a.closed).toBe(false);
+    expect(store.size).toBe(0);
+  });
+});
@@ -0,0 +1,82 @@
+/**
+ * Sessions of the streamable HTTP transport: one MCP server and transport
+ * per connected client, closed when the client deletes the session or after
+ * it has been idle too long.
+ */
+
+export interface Closeable {
+  close(): Promise<void>;
+}
+
+export interface SessionStoreOptions {
+  /** Sessions idle longer than this are closed (default: 30 minutes) */
+  idleTimeoutMs?: number;
+  /** New sessions beyond this close the least recently used one (default: 100) */
+  maxSessions?: number;
+  now?: () => number;
+}
+
+interface Session<T> {
+  value: T;
+  lastSeen: number;
+}
+
+export class SessionStore<T extends Closeable> {
+  private sessions = new Map<string, Session<T>>();
+  private idleTimeoutMs: number;
+  private maxSessions: number;
+  private now: () => number;
+
+  constructor(options: SessionStoreOptions = {}) {
+    this.idleTimeoutMs = options.idleTimeoutMs ?? 30 * 60_000;
+    this.maxSessions = options.maxSessions ?? 100;
+    this.now = options.now || Date.now;
+  }
+
+  get size(): number {
+    return this.sessions.size;
+  }
+
+  add(id: string, value: T): void {
+    this.sessions.set(id, { value, lastSeen: this.now() });
+    while (this.sessions.size > this.maxSessions) {
+      const [oldest] = this.sessions.keys();
+      this.close(oldest);
+    }
+  }
+
+  /** The session, marked as used; undefined when unknown or closed */
+  get(id: string): T | undefined {
+    const session = this.sessions.get(id);
+    if (!session) return undefined;
+    session.lastSeen = this.now();
+    // Keep the map ordered from least to most recently used
+    this.sessions.delete(id);
+    this.sessions.set(id, session);
+    return session.value;
+  }
+
+  /** Forget a session without closing it, e.g. once its transport has closed */
+  delete(id: string): void {
+    this.sessions.delete(id);
+  }
+
+  close(id: string): void {
+    const session = this.sessions.get(id);
+    if (!session) return;
+    this.sessions.delete(id);
+    session.value.close().catch(() => {});
+  }
+
+  /** Close sessions idle past the timeout; returns how many */
+  closeIdle(): number {
+    const cutoff = this.now() - this.idleTimeoutMs;
+    const idle = [...this.sessions].filter(([, s]) => s.lastSeen <= cutoff).map(([id]) => id);
+    idle.forEach(id => this.close(id));
+    return idle.length;
+  }
+
+  closeAll(): void {
+    [...this.sessions.keys()].forEach(id => this.close(id));
+  }
+}
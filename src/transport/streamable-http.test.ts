@@ -0,0 +1,54 @@
+import { createHash } from 'node:crypto';
+import { bearerMatches, hostAllowed, LOOPBACK_HOSTS, resolveAccess } from './streamable-http.js';
+
+describe('hostAllowed', () => {
+  it('accepts loopback names on any port and rejects others', () => {
+    expect(hostAllowed('localhost:3000', LOOPBACK_HOSTS)).toBe(true);
+    expect(hostAllowed('127.0.0.1', LOOPBACK_HOSTS)).toBe(true);
+    expect(hostAllowed('[::1]:3000', LOOPBACK_HOSTS)).toBe(true);
+    expect(hostAllowed('attacker.example:3000', LOOPBACK_HOSTS)).toBe(false);
+    expect(hostAllowed(undefined, LOOPBACK_HOSTS)).toBe(false);
+  });
+
+  it('accepts any host when no list is configured', () => {
+    expect(hostAllowed('mcp.example.org', undefined)).toBe(true);
+  });
+});
+
+describe('bearerMatches', () => {
+  it('compares the bearer token', () => {
+    expect(bearerMatches('Bearer s3cret', 's3cret')).toBe(true);
+    expect(bearerMatches('bearer s3cret', 's3cret')).toBe(true);
+    expect(bearerMatches('Bearer s3cre', 's3cret')).toBe(false);
+    expect(bearerMatches('Basic s3cret', 's3cret')).toBe(false);
+    expect(bearerMatches(undefined, 's3cret')).toBe(false);
+  });
+});
+
+describe('resolveAccess', () => {
+  const hash = createHash('sha256').update('reader-key-0123456789').digest('hex');
+  const options = {
+    authToken: 'admin-token',
+    apiKeys: [{ name: 'ci-reader', key: `sha256:${hash}`, readOnly: true, networks: ['testnet'] }],
+  };
+
+  it('grants full access without authentication configured', () => {
+    expect(resolveAccess(undefined, {})).toEqual({});
+  });
+
+  it('grants full access to the auth token and the key scope to an API key', () => {
+    expect(resolveAccess('Bearer admin-token', options)).toEqual({});
+    expect(resolveAccess('Bearer reader-key-0123456789', options)?.scope).toEqual({
+      name: 'ci-reader',
+      readOnly: true,
+      networks: ['testnet'],
+      tools: undefined,
+    });
+  });
+
+  it('rejects unknown and missing tokens', () => {
+    expect(resolveAccess('Bearer reader-key-012345678', options)).toBeNull();
+    expect(resolveAccess(undefined, options)).toBeNull();
+    expect(resolveAccess(undefined, { apiKeys: options.apiKeys })).toBeNull();
+  });
+});
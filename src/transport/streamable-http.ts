@@ -0,0 +1,190 @@
+/**
+ * MCP streamable HTTP transport with sessions, for running the server as a
+ * long-lived service several clients connect to. Clients POST JSON-RPC
+ * messages, keep a GET open as an SSE stream for server notifications
+ * (e.g. subscribed contract events) and DELETE the session when done.
+ */
+
+import { randomUUID, timingSafeEqual } from 'node:crypto';
+import express from 'express';
+import { StreamableHTTPServerTransport } from '@modelcontextprotocol/sdk/server/streamableHttp.js';
+import { isInitializeRequest } from '@modelcontextprotocol/sdk/types.js';
+import { logger } from '../logging/index.js';
+import type { KleverMCPServer } from '../mcp/server.js';
+import type { ToolScope } from '../mcp/tool-scope.js';
+import { findApiKey, type ApiKey } from './api-keys.js';
+import { SessionStore, type SessionStoreOptions } from './sessions.js';
+
+export const SESSION_HEADER = 'mcp-session-id';
+
+/** Host names a server bound to a loopback address answers to */
+export const LOOPBACK_HOSTS = ['localhost', '127.0.0.1', '[::1]'];
+
+export interface StreamableHttpOptions extends SessionStoreOptions {
+  /** MCP server of a new session, limited to the scope of its API key if any */
+  createServer: (scope?: ToolScope) => KleverMCPServer;
+  /** When set, requests must send `Authorization: Bearer <token>`; it grants every tool */
+  authToken?: string;
+  /** Keys accepted as bearer tokens, each limited to its tools and networks */
+  apiKeys?: ApiKey[];
+  /**
+   * Host names accepted in the Host header, against DNS rebinding from web
+   * pages; undefined accepts any host
+   */
+  allowedHosts?: string[];
+}
+
+/** A session's transport and the API key that opened it */
+export interface HttpSession {
+  transport: StreamableHTTPServerTransport;
+  /** Undefined for sessions opened with the auth token or without authentication */
+  keyName?: string;
+  close(): Promise<void>;
+}
+
+export interface StreamableHttpHandler {
+  /** Mount at the MCP endpoint path, after a JSON body parser */
+  router: express.Router;
+  sessions: SessionStore<HttpSession>;
+  /** Close every session and stop the idle sweep */
+  close(): void;
+}
+
+/** Whether the Host header names one of `allowedHosts` (any port) */
+export function hostAllowed(host: string | undefined, allowedHosts: string[] | undefined): boolean {
+  if (!allowedHosts) return true;
+  if (!host) return false;
+  const name = host.startsWith('[') ? host.slice(0, host.indexOf(']') + 1) : host.split(':')[0];
+  return allowedHosts.includes(name.toLowerCase());
+}
+
+/** The bearer token of an Authorization header */
+export function bearerToken(header: string | undefined): string | undefined {
+  return /^Bearer\s+(.+)$/i.exec(header || '')?.[1];
+}
+
+/** Whether an Authorization header carries `token` as a bearer token */
+export function bearerMatches(header: string | undefined, token: string): boolean {
+  const bearer = bearerToken(header);
+  if (!bearer) return false;
+  const given = Buffer.from(bearer);
+  const expected = Buffer.from(token);
+  return given.length === expected.length && timingSafeEqual(given, expected);
+}
+
+/**
+ * What a request may do: `null` when it is not authorized, undefined scope for
+ * full access (the auth token, or no authentication configured), else the
+ * scope of its API key.
+ */
+export function resolveAccess(
+  header: string | undefined,
+  options: Pick<StreamableHttpOptions, 'authToken' | 'apiKeys'>
+): { scope?: ToolScope } | null {
+  const apiKeys = options.apiKeys || [];
+  if (!options.authToken && apiKeys.length === 0) return {};
+  if (options.authToken && bearerMatches(header, options.authToken)) return {};
+  const token = bearerToken(header);
+  const scope = token ? findApiKey(apiKeys, token) : undefined;
+  return scope ? { scope } : null;
+}
+
+function rpcError(res: express.Response, status: number, code: number, message: string) {
+  res.status(status).json({ jsonrpc: '2.0', error: { code, message }, id: null });
+}
+
+export function createStreamableHttpHandler(options: StreamableHttpOptions): StreamableHttpHandler {
+  const sessions = new SessionStore<HttpSession>(options);
+  const router = express.Router();
+
+  router.use((req, res, next) => {
+    if (!hostAllowed(req.headers.host, options.allowedHosts)) {
+      rpcError(res, 403, -32000, `Host "${req.headers.host}" is not allowed`);
+      return;
+    }
+    const access = resolveAccess(req.headers.authorization, options);
+    if (!access) {
+      rpcError(res, 401, -32001, 'Unauthorized');
+      return;
+    }
+    res.locals.scope = access.scope;
+    next();
+  });
+
+  /** The transport of the request's session, or undefined after answering with the error */
+  const sessionTransport = (req: express.Request, res: express.Response) => {
+    const id = req.header(SESSION_HEADER);
+    if (!id) {
+      rpcError(res, 400, -32000, `Missing ${SESSION_HEADER} header; send an initialize request`);
+      return undefined;
+    }
+    const session = sessions.get(id);
+    if (!session) {
+      rpcError(res, 404, -32001, 'Session not found; initialize a new session');
+      return undefined;
+    }
+    // A session keeps the scope it was opened with; other keys may not use it
+    if (session.keyName !== (res.locals.scope as ToolScope | undefined)?.name) {
+      rpcError(res, 403, -32001, 'Session belongs to another API key');
+      return undefined;
+    }
+    return session.transport;
+  };
+
+  router.post('/', async (req, res) => {
+    try {
+      if (req.header(SESSION_HEADER)) {
+        const transport = sessionTransport(req, res);
+        if (transport) await transport.handleRequest(req, res, req.body);
+        return;
+      }
+      if (!isInitializeRequest(req.body)) {
+        rpcError(res, 400, -32000, `Missing ${SESSION_HEADER} header; send an initialize request`);
+        return;
+      }
+      const scope = res.locals.scope as ToolScope | undefined;
+      const transport = new StreamableHTTPServerTransport({
+        sessionIdGenerator: () => randomUUID(),
+        onsessioninitialized: id =>
+          sessions.add(id, { transport, keyName: scope?.name, close: () => transport.close() }),
+      });
+      transport.onclose = () => {
+        if (transport.sessionId) sessions.delete(transport.sessionId);
+      };
+      await options.createServer(scope).connectTransport(transport);
+      await transport.handleRequest(req, res, req.body);
+    } catch (error) {
+      logger.error('[MCP HTTP] Request error', {
+        error: error instanceof Error ? error.message : String(error),
+      });
+      if (!res.headersSent) rpcError(res, 500, -32603, 'Internal server error');
+    }
+  });
+
+  // GET opens the SSE stream of a session, DELETE ends it
+  const sessionRequest = async (req: express.Request, res: express.Response) => {
+    try {
+      const transport = sessionTransport(req, res);
+      if (transport) await transport.handleRequest(req, res);
+    } catch (error) {
+      logger.error('[MCP HTTP] Request error', {
+        error: error instanceof Error ? error.message : String(error),
+      });
+      if (!res.headersSent) rpcError(res, 500, -32603, 'Internal server error');
+    }
+  };
+  router.get('/', sessionRequest);
+  router.delete('/', sessionRequest);
+
+  const sweep = setInterval(() => sessions.closeIdle(), 60_000);
+  sweep.unref();
+
+  return {
+    router,
+    sessions,
+    close: () => {
+      clearInterval(sweep);
+      sessions.closeAll();
+    },
+  };
+}
@@ -0,0 +1,40 @@
+import {
+  CancelledError,
+  cancellable,
+  currentSignal,
+  onCancel,
+  runCancellable,
+  runDetached,
+  sleep,
+  throwIfCancelled,
+} from './cancellation.js';
+
+describe('cancellation', () => {
+  it('makes the signal of the call available to the work it starts', async () => {
+    const controller = new AbortController();
+    await runCancellable(controller.signal, async () => {
+      await Promise.resolve();
+      expect(currentSignal()).toBe(controller.signal);
+      expect(runDetached(() => currentSignal())).toBeUndefined();
+      controller.abort();
+      expect(() => throwIfCancelled()).toThrow(CancelledError);
+    });
+    expect(currentSignal()).toBeUndefined();
+  });
+
+  it('stops sleeps and waits as soon as the call is cancelled', async () => {
+    const controller = new AbortController();
+    const listener = jest.fn();
+    const run = runCancellable(controller.signal, async () => {
+      const stop = onCancel(listener);
+      stop();
+      await sleep(60_000);
+    });
+    controller.abort();
+    await expect(run).rejects.toBeInstanceOf(CancelledError);
+    expect(listener).not.toHaveBeenCalled();
+
+    const pending = new Promise<string>(resolve => setTimeout(() => resolve('done'), 10));
+    expect(await cancellable(pending)).toBe('done');
+  });
+});
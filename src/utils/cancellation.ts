@@ -0,0 +1,71 @@
+/**
+ * Cancellation of the tool call in progress. The server runs each call with
+ * the abort signal of the client's request in async context, so subprocesses,
+ * HTTP requests and polling loops anywhere below it stop when the client
+ * cancels the call, without passing the signal through every layer.
+ */
+
+import { AsyncLocalStorage } from 'node:async_hooks';
+
+const storage = new AsyncLocalStorage<AbortSignal>();
+
+export class CancelledError extends Error {
+  constructor(message = 'Cancelled by the client') {
+    super(message);
+    this.name = 'CancelledError';
+  }
+}
+
+/** Run `fn` so that work it starts stops when `signal` aborts */
+export function runCancellable<T>(signal: AbortSignal | undefined, fn: () => T): T {
+  return signal ? storage.run(signal, fn) : fn();
+}
+
+/** Run `fn` outside of the current call's cancellation, e.g. for work shared with other calls */
+export function runDetached<T>(fn: () => T): T {
+  return storage.exit(fn);
+}
+
+/** Abort signal of the tool call the calling code runs in, if any */
+export function currentSignal(): AbortSignal | undefined {
+  return storage.getStore();
+}
+
+export function throwIfCancelled(): void {
+  if (currentSignal()?.aborted) throw new CancelledError();
+}
+
+/** Call `onCancel` when the current call is cancelled; returns a function that stops listening */
+export function onCancel(onCancel: () => void): () => void {
+  const signal = currentSignal();
+  if (!signal) return () => {};
+  if (signal.aborted) {
+    onCancel();
+    return () => {};
+  }
+  signal.addEventListener('abort', onCancel, { once: true });
+  return () => signal.removeEventListener('abort', onCancel);
+}
+
+/** `promise`, or a CancelledError as soon as the current call is cancelled */
+export function cancellable<T>(promise: Promise<T>): Promise<T> {
+  if (!currentSignal()) return promise;
+  return new Promise((resolve, reject) => {
+    const stop = onCancel(() => reject(new CancelledError()));
+    promise.then(resolve, reject).finally(stop);
+  });
+}
+
+/** Wait `ms`, or throw a CancelledError as soon as the current call is cancelled */
+export function sleep(ms: number): Promise<void> {
+  return new Promise((resolve, reject) => {
+    const timer = setTimeout(() => {
+      stop();
+      resolve();
+    }, ms);
+    const stop = onCancel(() => {
+      clearTimeout(timer);
+      reject(new CancelledError());
+    });
+  });
+}